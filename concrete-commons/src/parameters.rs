@@ -43,6 +43,11 @@ impl LweCiphertextRange {
     }
 }
 
+/// The index of a GGSW ciphertext in a list of GGSW ciphertexts, such as a bootstrap key.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+pub struct GgswCiphertextIndex(pub usize);
+
 /// The number of ciphertexts in a glwe ciphertext list.
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
 #[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
@@ -98,6 +103,26 @@ impl GlweSize {
     pub fn to_glwe_dimension(&self) -> GlweDimension {
         GlweDimension(self.0 - 1)
     }
+
+    /// Returns the [`GlweSize`] of the tensor product of two GLWE ciphertexts of this size.
+    ///
+    /// See [`GlweDimension::tensor_product_dimension`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size is zero, or if the tensor product size overflows `usize`.
+    pub fn tensor_product_size(&self) -> GlweSize {
+        self.checked_tensor_product_size()
+            .expect("The GLWE tensor product size overflows usize.")
+    }
+
+    /// Returns the [`GlweSize`] of the tensor product of two GLWE ciphertexts of this size, or
+    /// `None` if the size is zero or if the tensor product size overflows `usize`.
+    pub fn checked_tensor_product_size(&self) -> Option<GlweSize> {
+        let dimension = GlweDimension(self.0.checked_sub(1)?);
+        let tensor_product_dimension = dimension.checked_tensor_product_dimension()?;
+        tensor_product_dimension.0.checked_add(1).map(GlweSize)
+    }
 }
 
 /// The number of polynomials of an GLWE mask, or the size of an GLWE secret key.
@@ -110,6 +135,35 @@ impl GlweDimension {
     pub fn to_glwe_size(&self) -> GlweSize {
         GlweSize(self.0 + 1)
     }
+
+    /// Returns the [`GlweDimension`] of the tensor product of two GLWE ciphertexts of this
+    /// dimension.
+    ///
+    /// For a GLWE dimension $k$, the mask of the tensor product contains the $k$ terms in $s\_i$,
+    /// the $k$ terms in $s\_i^2$ and the $k(k-1)/2$ terms in $s\_i s\_j$ for $i < j$, that is
+    /// $k(k+3)/2$ polynomials.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the tensor product dimension overflows `usize`.
+    pub fn tensor_product_dimension(&self) -> GlweDimension {
+        self.checked_tensor_product_dimension()
+            .expect("The GLWE tensor product dimension overflows usize.")
+    }
+
+    /// Returns the [`GlweDimension`] of the tensor product of two GLWE ciphertexts of this
+    /// dimension, or `None` if it overflows `usize`.
+    pub fn checked_tensor_product_dimension(&self) -> Option<GlweDimension> {
+        let k = self.0;
+        let k_plus_three = k.checked_add(3)?;
+        // One of k and k + 3 is even, so the division is exact when performed first.
+        let dimension = if k.is_multiple_of(2) {
+            (k / 2).checked_mul(k_plus_three)?
+        } else {
+            k.checked_mul(k_plus_three / 2)?
+        };
+        Some(GlweDimension(dimension))
+    }
 }
 
 /// The number of coefficients of a polynomial.
@@ -160,6 +214,13 @@ pub struct MonomialDegree(pub usize);
 #[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
 pub struct MonomialIndex(pub usize);
 
+/// The number of monomials in a sparse polynomial.
+///
+/// Assuming a sparse polynomial $\sum\_{i} a\_i X^{d\_i}$, this returns the number of terms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+pub struct MonomialCount(pub usize);
+
 /// The logarithm of the base used in a decomposition.
 ///
 /// When decomposing an integer over powers of the $2^B$ basis, this type represents the $B$ value.
@@ -194,6 +255,46 @@ pub struct ModulusSwitchOffset(pub usize);
 #[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
 pub struct DeltaLog(pub usize);
 
+/// The scaling factor (generally written $\Delta$) used to store the message in the MSB of
+/// ciphertexts.
+///
+/// The value must fit in the integers used to store the ciphertexts, hence the constructors
+/// validating it against a given precision.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+pub struct ScalingFactor(pub u64);
+
+impl ScalingFactor {
+    /// Returns the scaling factor $2^{\log\_2(\Delta)}$ for 32 bits ciphertexts, or `None` if it
+    /// does not fit in a `u32`.
+    pub fn for_precision32(delta_log: DeltaLog) -> Option<ScalingFactor> {
+        Self::for_precision(delta_log, 32)
+    }
+
+    /// Returns the scaling factor $2^{\log\_2(\Delta)}$ for 64 bits ciphertexts, or `None` if it
+    /// does not fit in a `u64`.
+    pub fn for_precision64(delta_log: DeltaLog) -> Option<ScalingFactor> {
+        Self::for_precision(delta_log, 64)
+    }
+
+    /// Returns the associated [`DeltaLog`], if the scaling factor is a power of two.
+    pub fn delta_log(&self) -> Option<DeltaLog> {
+        if self.0.is_power_of_two() {
+            Some(DeltaLog(self.0.trailing_zeros() as usize))
+        } else {
+            None
+        }
+    }
+
+    fn for_precision(delta_log: DeltaLog, bits: usize) -> Option<ScalingFactor> {
+        if delta_log.0 < bits {
+            Some(ScalingFactor(1 << delta_log.0))
+        } else {
+            None
+        }
+    }
+}
+
 /// The number of bits to extract in a bit extraction.
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 #[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
@@ -208,3 +309,169 @@ pub struct FunctionalPackingKeyswitchKeyCount(pub usize);
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 #[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
 pub struct CiphertextModulusLog(pub usize);
+
+/// The modulus of the integers used for the mask coefficients and the body of a ciphertext.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Default)]
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+pub enum CiphertextModulus {
+    /// The native modulus $2^w$, where $w$ is the bit width of the integers storing the
+    /// ciphertext (e.g. $2^{64}$ for `u64` ciphertexts).
+    #[default]
+    Native,
+    /// A custom modulus, smaller than the native one.
+    Custom(u128),
+}
+
+impl CiphertextModulus {
+    /// Returns whether the modulus is the native one.
+    pub fn is_native(&self) -> bool {
+        matches!(self, CiphertextModulus::Native)
+    }
+
+    /// Returns the modulus for ciphertexts stored in integers of `bits` bits.
+    ///
+    /// Returns `None` for the native modulus of 128 bits integers, which does not fit in a `u128`.
+    pub fn value(&self, bits: usize) -> Option<u128> {
+        match self {
+            CiphertextModulus::Native => 1_u128.checked_shl(bits as u32),
+            CiphertextModulus::Custom(modulus) => Some(*modulus),
+        }
+    }
+}
+
+/// The structure of the ring of polynomials of size $N$ the ciphertexts are defined over, i.e. the
+/// polynomial the products are reduced by.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Default)]
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+pub enum RingStructure {
+    /// The negacyclic ring $\mathbb{Z}\[X\] / (X^N + 1)$, used by all the schemes of the library.
+    #[default]
+    Negacyclic,
+    /// The cyclic ring $\mathbb{Z}\[X\] / (X^N - 1)$.
+    Cyclic,
+}
+
+impl RingStructure {
+    /// Returns whether the ring is the negacyclic one.
+    pub fn is_negacyclic(&self) -> bool {
+        matches!(self, RingStructure::Negacyclic)
+    }
+}
+
+/// The rounding applied when a torus value is rounded to a coarser precision, e.g. when decoding a
+/// plaintext or before a signed decomposition.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Default)]
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+pub enum RoundingMode {
+    /// Rounds to the nearest value, ties being rounded up.
+    #[default]
+    NearestUp,
+    /// Rounds to the nearest value, ties being rounded to the value with an even last kept bit.
+    NearestEven,
+    /// Rounds up with a probability equal to the distance to the value below, in units of the
+    /// coarser precision, and down otherwise. The rounding is unbiased on average, but requires a
+    /// source of randomness.
+    Stochastic,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tensor_product_dimension() {
+        assert_eq!(
+            GlweDimension(1).tensor_product_dimension(),
+            GlweDimension(2)
+        );
+        assert_eq!(
+            GlweDimension(2).tensor_product_dimension(),
+            GlweDimension(5)
+        );
+        assert_eq!(
+            GlweDimension(3).tensor_product_dimension(),
+            GlweDimension(9)
+        );
+        for k in 1..64 {
+            let squares_and_linear_terms = 2 * k;
+            let cross_terms = k * (k - 1) / 2;
+            assert_eq!(
+                GlweDimension(k).tensor_product_dimension(),
+                GlweDimension(squares_and_linear_terms + cross_terms)
+            );
+        }
+    }
+
+    #[test]
+    fn test_checked_tensor_product_dimension() {
+        assert_eq!(
+            GlweDimension(0).checked_tensor_product_dimension(),
+            Some(GlweDimension(0))
+        );
+        // Close to the overflow limit, the result is exact when it fits.
+        let k = 1 << (usize::BITS / 2 - 1);
+        assert_eq!(
+            GlweDimension(k).checked_tensor_product_dimension(),
+            Some(GlweDimension((k / 2) * (k + 3)))
+        );
+        assert_eq!(
+            GlweDimension(usize::MAX).checked_tensor_product_dimension(),
+            None
+        );
+        assert_eq!(
+            GlweDimension(usize::MAX - 2).checked_tensor_product_dimension(),
+            None
+        );
+        assert_eq!(GlweSize(0).checked_tensor_product_size(), None);
+        assert_eq!(GlweSize(usize::MAX).checked_tensor_product_size(), None);
+        assert_eq!(GlweSize(3).checked_tensor_product_size(), Some(GlweSize(6)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_tensor_product_dimension_overflow() {
+        let _ = GlweDimension(usize::MAX).tensor_product_dimension();
+    }
+
+    #[test]
+    fn test_tensor_product_size() {
+        assert_eq!(GlweSize(2).tensor_product_size(), GlweSize(3));
+        assert_eq!(GlweSize(3).tensor_product_size(), GlweSize(6));
+        for k in 1..64 {
+            assert_eq!(
+                GlweDimension(k).to_glwe_size().tensor_product_size(),
+                GlweDimension(k).tensor_product_dimension().to_glwe_size()
+            );
+        }
+    }
+
+    #[test]
+    fn test_scaling_factor_precision() {
+        assert_eq!(
+            ScalingFactor::for_precision32(DeltaLog(31)),
+            Some(ScalingFactor(1 << 31))
+        );
+        assert_eq!(ScalingFactor::for_precision32(DeltaLog(32)), None);
+        assert_eq!(
+            ScalingFactor::for_precision64(DeltaLog(63)),
+            Some(ScalingFactor(1 << 63))
+        );
+        assert_eq!(ScalingFactor::for_precision64(DeltaLog(64)), None);
+        for delta_log in 0..64 {
+            let scaling_factor = ScalingFactor::for_precision64(DeltaLog(delta_log)).unwrap();
+            assert_eq!(scaling_factor.delta_log(), Some(DeltaLog(delta_log)));
+        }
+        assert_eq!(ScalingFactor(3).delta_log(), None);
+    }
+
+    #[test]
+    fn test_ciphertext_modulus() {
+        assert!(CiphertextModulus::default().is_native());
+        assert_eq!(CiphertextModulus::Native.value(32), Some(1 << 32));
+        assert_eq!(CiphertextModulus::Native.value(64), Some(1 << 64));
+        assert_eq!(CiphertextModulus::Native.value(128), None);
+        assert!(!CiphertextModulus::Custom(1 << 20).is_native());
+        assert_eq!(CiphertextModulus::Custom(1 << 20).value(64), Some(1 << 20));
+        assert_eq!(CiphertextModulus::Custom(1 << 20).value(128), Some(1 << 20));
+    }
+}
@@ -0,0 +1,178 @@
+//! A module containing the auto-tuning of the decomposition parameters of the evaluation keys.
+//!
+//! The auto-tuning is started with `concrete-core-bench autotune <operation> <arguments>`, where
+//! the operation is either:
+//!
+//! + `keyswitch <input_lwe_dimension> <output_lwe_dimension> <log2_std_lwe> <log2_std_ksk>
+//!   <log2_std_target>`
+//! + `relinearization <glwe_dimension> <polynomial_size> <log2_std_rlk> <log2_std_target>` (needs
+//!   the `backend_fftw` feature)
+//!
+//! The standard deviations are given as the base 2 logarithms of their values on the torus. The
+//! decomposition parameters whose output noise, as estimated by `concrete-npe`, stays below the
+//! target are timed on the current machine on 64 bits integers, and the fastest ones are printed.
+use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
+use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+#[cfg(feature = "backend_fftw")]
+use concrete_commons::parameters::{GlweDimension, PolynomialSize, ScalingFactor};
+use concrete_core::prelude::*;
+use concrete_csprng::seeders::UnixSeeder;
+#[cfg(feature = "backend_fftw")]
+use concrete_npe::find_relinearization_decompositions;
+use concrete_npe::{find_keyswitch_decompositions, tune_decomposition};
+
+// The number of timed executions of the operation for every candidate.
+const RUNS: usize = 10;
+
+const USAGE: &str = "usage: concrete-core-bench autotune keyswitch <input_lwe_dimension> \
+<output_lwe_dimension> <log2_std_lwe> <log2_std_ksk> <log2_std_target>
+       concrete-core-bench autotune relinearization <glwe_dimension> <polynomial_size> \
+<log2_std_rlk> <log2_std_target>";
+
+/// Runs the auto-tuning described by the given command line arguments.
+pub fn autotune(args: &[String]) {
+    match args.first().map(String::as_str) {
+        Some("keyswitch") if args.len() == 6 => {
+            let decomposition = autotune_keyswitch(
+                LweDimension(parse(&args[1])),
+                LweDimension(parse(&args[2])),
+                LogStandardDev(parse(&args[3])),
+                LogStandardDev(parse(&args[4])),
+                LogStandardDev(parse(&args[5])),
+            );
+            report(decomposition);
+        }
+        #[cfg(feature = "backend_fftw")]
+        Some("relinearization") if args.len() == 5 => {
+            let decomposition = autotune_relinearization(
+                GlweDimension(parse(&args[1])),
+                PolynomialSize(parse(&args[2])),
+                LogStandardDev(parse(&args[3])),
+                LogStandardDev(parse(&args[4])),
+            );
+            report(decomposition);
+        }
+        _ => panic!("{}", USAGE),
+    }
+}
+
+fn parse<T: std::str::FromStr>(arg: &str) -> T {
+    arg.parse()
+        .unwrap_or_else(|_| panic!("Invalid argument `{}`.\n{}", arg, USAGE))
+}
+
+fn report(decomposition: Option<(DecompositionBaseLog, DecompositionLevelCount)>) {
+    match decomposition {
+        Some((base_log, level)) => println!(
+            "Fastest decomposition: base log {}, level count {}",
+            base_log.0, level.0
+        ),
+        None => println!("No decomposition reaches the target noise."),
+    }
+}
+
+fn new_default_engine() -> DefaultEngine {
+    DefaultEngine::new(Box::new(UnixSeeder::new(0))).unwrap()
+}
+
+/// Returns the fastest decomposition parameters of a keyswitch key, among the ones for which the
+/// noise of the keyswitched ciphertexts stays below `target_noise`.
+pub fn autotune_keyswitch(
+    input_lwe_dimension: LweDimension,
+    output_lwe_dimension: LweDimension,
+    lwe_noise: impl DispersionParameter,
+    ksk_noise: impl DispersionParameter,
+    target_noise: impl DispersionParameter,
+) -> Option<(DecompositionBaseLog, DecompositionLevelCount)> {
+    let candidates = find_keyswitch_decompositions::<_, _, _, BinaryKeyKind>(
+        input_lwe_dimension,
+        lwe_noise,
+        ksk_noise,
+        target_noise,
+        64,
+    );
+    let mut engine = new_default_engine();
+    let input_key: LweSecretKey64 = engine
+        .generate_new_lwe_secret_key(input_lwe_dimension)
+        .unwrap();
+    let output_key: LweSecretKey64 = engine
+        .generate_new_lwe_secret_key(output_lwe_dimension)
+        .unwrap();
+    let input: LweCiphertext64 = engine
+        .zero_encrypt_lwe_ciphertext(&input_key, Variance(lwe_noise.get_variance()))
+        .unwrap();
+    let output: LweCiphertext64 = engine
+        .zero_encrypt_lwe_ciphertext(&output_key, Variance(lwe_noise.get_variance()))
+        .unwrap();
+    let mut keygen_engine = new_default_engine();
+    tune_decomposition(
+        &candidates,
+        RUNS,
+        |base_log, level| {
+            let ksk: LweKeyswitchKey64 = keygen_engine
+                .generate_new_lwe_keyswitch_key(
+                    &input_key,
+                    &output_key,
+                    level,
+                    base_log,
+                    Variance(ksk_noise.get_variance()),
+                )
+                .unwrap();
+            (ksk, output.clone())
+        },
+        |(ksk, output)| {
+            engine
+                .discard_keyswitch_lwe_ciphertext(output, &input, ksk)
+                .unwrap()
+        },
+    )
+}
+
+/// Returns the fastest decomposition parameters of a relinearization key, among the ones for
+/// which the noise of the relinearized ciphertexts stays below `target_noise`.
+#[cfg(feature = "backend_fftw")]
+pub fn autotune_relinearization(
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    rlk_noise: impl DispersionParameter,
+    target_noise: impl DispersionParameter,
+) -> Option<(DecompositionBaseLog, DecompositionLevelCount)> {
+    let candidates = find_relinearization_decompositions::<_, _, BinaryKeyKind>(
+        polynomial_size,
+        glwe_dimension,
+        rlk_noise,
+        target_noise,
+        64,
+    );
+    let mut default_engine = new_default_engine();
+    let mut fftw_engine = FftwEngine::new(()).unwrap();
+    let key: GlweSecretKey64 = default_engine
+        .generate_new_glwe_secret_key(glwe_dimension, polynomial_size)
+        .unwrap();
+    let ciphertext: GlweCiphertext64 = default_engine
+        .zero_encrypt_glwe_ciphertext(&key, Variance(rlk_noise.get_variance()))
+        .unwrap();
+    let product: GlweTensorProductCiphertext64 = fftw_engine
+        .tensor_product_glwe_ciphertext_same_key(&ciphertext, &ciphertext, ScalingFactor(1))
+        .unwrap();
+    tune_decomposition(
+        &candidates,
+        RUNS,
+        |base_log, level| -> GlweRelinearizationKey64 {
+            default_engine
+                .generate_new_glwe_relinearization_key(
+                    &key,
+                    level,
+                    base_log,
+                    Variance(rlk_noise.get_variance()),
+                )
+                .unwrap()
+        },
+        |rlk| {
+            let _: GlweCiphertext64 = fftw_engine
+                .relinearize_glwe_ciphertext(&product, rlk)
+                .unwrap();
+        },
+    )
+}
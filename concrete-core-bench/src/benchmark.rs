@@ -53,7 +53,7 @@ pub trait BenchmarkFixture<
             type_name::<Engine>(),
             type_name::<RelatedEntities>()
         ));
-        for params in Self::generate_parameters_iterator() {
+        for params in Self::parameters_iterator() {
             Self::bench(maker, engine, params, &mut group, batch_size);
         }
         group.finish();
@@ -198,5 +198,7 @@ macro_rules! bench_parallel {
 #[cfg(feature = "backend_default_parallel")]
 bench_parallel! {
     ((BinaryKeyDistribution, BinaryKeyDistribution), LweBootstrapKeyGenerationFixture, (LweSecretKey, GlweSecretKey, LweBootstrapKey)),
-    ((BinaryKeyDistribution, BinaryKeyDistribution), LweSeededBootstrapKeyGenerationFixture, (LweSecretKey, GlweSecretKey, LweSeededBootstrapKey))
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweSeededBootstrapKeyGenerationFixture, (LweSecretKey, GlweSecretKey, LweSeededBootstrapKey)),
+    ((BinaryKeyDistribution), LweCiphertextVectorEncryptionFixture, (PlaintextVector, LweSecretKey, LweCiphertextVector)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingKeyswitchFixture, (LweKeyswitchKey, LweCiphertextVector, LweCiphertextVector))
 }
@@ -2,7 +2,8 @@ use crate::benchmark::BenchmarkFixture;
 use concrete_core::prelude::*;
 use concrete_core_fixture::fixture::*;
 use concrete_core_fixture::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
-use criterion::Criterion;
+use concrete_csprng::seeders::UnixSeeder;
+use criterion::{BenchmarkId, Criterion};
 
 use paste::paste;
 
@@ -36,6 +37,65 @@ macro_rules! bench {
 bench! {
     ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingBootstrapFixture1, (FftFourierLweBootstrapKey, GlweCiphertext, LweCiphertext, LweCiphertext)),
     ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingBootstrapFixture2, (FftFourierLweBootstrapKey, GlweCiphertext, LweCiphertext, LweCiphertext)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingBootstrapFixture1, (FftFourierLweBootstrapKey, GlweCiphertextVector, LweCiphertextVector, LweCiphertextVector)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingBootstrapFixture2, (FftFourierLweBootstrapKey, GlweCiphertextVector, LweCiphertextVector, LweCiphertextVector)),
     ((BinaryKeyDistribution), GlweCiphertextGgswCiphertextDiscardingExternalProductFixture, (GlweCiphertext, FftFourierGgswCiphertext, GlweCiphertext)),
     ((BinaryKeyDistribution), GlweCiphertextsGgswCiphertextFusingCmuxFixture, (GlweCiphertext, GlweCiphertext, FftFourierGgswCiphertext))
 }
+
+/// Compares the bootstrap of an LWE ciphertext for the different layouts of Fourier bootstrap keys.
+pub fn bench_bootstrap_key_layouts() {
+    let mut criterion = Criterion::default().configure_from_args();
+    let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(0))).unwrap();
+    let mut fft_engine = FftEngine::new(()).unwrap();
+
+    let (lwe_dim, glwe_dim, poly_size) =
+        (LweDimension(630), GlweDimension(1), PolynomialSize(1024));
+    let (base_log, level_count) = (DecompositionBaseLog(3), DecompositionLevelCount(5));
+    let noise = Variance(2_f64.powf(-25.));
+    let lwe_sk: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dim).unwrap();
+    let glwe_sk: GlweSecretKey64 = default_engine
+        .generate_new_glwe_secret_key(glwe_dim, poly_size)
+        .unwrap();
+    let bsk: LweBootstrapKey64 = default_engine
+        .generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, base_log, level_count, noise)
+        .unwrap();
+
+    let plaintext = default_engine
+        .create_plaintext_from(&(3_u64 << 60))
+        .unwrap();
+    let input: LweCiphertext64 = default_engine
+        .encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)
+        .unwrap();
+    let lut = default_engine
+        .create_plaintext_vector_from(&vec![1_u64 << 60; poly_size.0])
+        .unwrap();
+    let acc: GlweCiphertext64 = default_engine
+        .trivially_encrypt_glwe_ciphertext(glwe_dim.to_glwe_size(), &lut)
+        .unwrap();
+    let zero = default_engine.create_plaintext_from(&0_u64).unwrap();
+    let mut output: LweCiphertext64 = default_engine
+        .trivially_encrypt_lwe_ciphertext(
+            LweDimension(glwe_dim.0 * poly_size.0).to_lwe_size(),
+            &zero,
+        )
+        .unwrap();
+
+    let mut group = criterion.benchmark_group("fft_bootstrap_key_layout");
+    for layout in [
+        FourierLweBootstrapKeyLayout::GgswMajor,
+        FourierLweBootstrapKeyLayout::LevelMajor,
+    ] {
+        fft_engine.set_bootstrap_key_layout(layout);
+        let fourier_bsk: FftFourierLweBootstrapKey64 =
+            fft_engine.convert_lwe_bootstrap_key(&bsk).unwrap();
+        group.bench_function(BenchmarkId::from_parameter(format!("{:?}", layout)), |b| {
+            b.iter(|| {
+                fft_engine
+                    .discard_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &fourier_bsk)
+                    .unwrap();
+            })
+        });
+    }
+    group.finish();
+}
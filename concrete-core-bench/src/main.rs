@@ -5,6 +5,7 @@
 //! (matching the module name), and to contain the instantiation of a generic benchmarking
 //! for every implemented operator.
 
+pub mod autotune;
 pub mod benchmark;
 
 #[cfg(feature = "backend_default")]
@@ -21,6 +22,14 @@ mod cuda;
 
 // The main entry point. Uses criterion as benchmark harness.
 fn main() {
+    // The auto-tuning of the decomposition parameters is run instead of the benchmarks when
+    // requested.
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().map(String::as_str) == Some("autotune") {
+        autotune::autotune(&args[1..]);
+        return;
+    }
+
     // We instantiate the benchmarks for different backends depending on the feature flag activated.
     #[cfg(feature = "backend_default")]
     default::bench();
@@ -30,6 +39,8 @@ fn main() {
     fftw::bench();
     #[cfg(feature = "backend_fft")]
     fft::bench();
+    #[cfg(feature = "backend_fft")]
+    fft::bench_bootstrap_key_layouts();
     #[cfg(all(feature = "backend_cuda", not(feature = "_ci_do_not_compile")))]
     cuda::bench();
     #[cfg(all(feature = "backend_cuda", not(feature = "_ci_do_not_compile")))]
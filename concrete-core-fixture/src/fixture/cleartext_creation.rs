@@ -7,10 +7,13 @@ use crate::raw::statistical_test::assert_noise_distribution;
 use concrete_commons::dispersion::Variance;
 
 use concrete_core::prelude::{CleartextCreationEngine, CleartextEntity};
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `CleartextCreationEngine` trait.
 pub struct CleartextCreationFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct CleartextCreationParameters;
 
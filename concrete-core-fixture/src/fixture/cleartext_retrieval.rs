@@ -7,10 +7,13 @@ use crate::raw::statistical_test::assert_noise_distribution;
 use concrete_commons::dispersion::Variance;
 
 use concrete_core::prelude::{CleartextEntity, CleartextRetrievalEngine};
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `CleartextRetrievalEngine` trait.
 pub struct CleartextRetrievalFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct CleartextRetrievalParameters;
 
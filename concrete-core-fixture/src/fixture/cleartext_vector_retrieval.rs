@@ -8,10 +8,13 @@ use concrete_commons::dispersion::Variance;
 use concrete_commons::parameters::CleartextCount;
 
 use concrete_core::prelude::{CleartextVectorEntity, CleartextVectorRetrievalEngine};
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `CleartextVectorRetrievalEngine` trait.
 pub struct CleartextVectorRetrievalFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct CleartextVectorRetrievalParameters {
     count: CleartextCount,
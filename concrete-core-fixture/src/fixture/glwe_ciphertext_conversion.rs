@@ -10,10 +10,13 @@ use crate::generation::synthesizing::SynthesizesGlweCiphertext;
 use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `GlweCiphertextConversionEngine` trait.
 pub struct GlweCiphertextConversionFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct GlweCiphertextConversionParameters {
     pub noise: Variance,
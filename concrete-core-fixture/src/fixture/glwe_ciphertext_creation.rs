@@ -8,7 +8,10 @@ use concrete_commons::dispersion::Variance;
 use concrete_commons::parameters::{GlweDimension, PolynomialSize};
 
 use concrete_core::prelude::{GlweCiphertextCreationEngine, GlweCiphertextEntity};
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct GlweCiphertextCreationParameters {
     pub glwe_dimension: GlweDimension,
@@ -14,10 +14,13 @@ use concrete_core::prelude::{
     GlweCiphertextDecryptionEngine, GlweCiphertextEntity, GlweSecretKeyEntity,
     PlaintextVectorEntity,
 };
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `GlweCiphertextDecryptionEngine` trait.
 pub struct GlweCiphertextDecryptionFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct GlweCiphertextDecryptionParameters {
     pub noise: Variance,
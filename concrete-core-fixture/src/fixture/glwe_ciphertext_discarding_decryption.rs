@@ -14,10 +14,13 @@ use concrete_core::prelude::{
     GlweCiphertextDiscardingDecryptionEngine, GlweCiphertextEntity, GlweSecretKeyEntity,
     PlaintextVectorEntity,
 };
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `GlweCiphertextDiscardingDecryptionEngine` trait.
 pub struct GlweCiphertextDiscardingDecryptionFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct GlweCiphertextDiscardingDecryptionParameters {
     pub noise: Variance,
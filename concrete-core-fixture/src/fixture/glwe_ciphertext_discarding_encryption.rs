@@ -14,10 +14,13 @@ use concrete_core::prelude::{
     GlweCiphertextDiscardingEncryptionEngine, GlweCiphertextEntity, GlweSecretKeyEntity,
     PlaintextVectorEntity,
 };
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `GlweCiphertextDiscardingEncryptionEngine` trait.
 pub struct GlweCiphertextDiscardingEncryptionFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct GlweCiphertextDiscardingEncryptionParameters {
     pub noise: Variance,
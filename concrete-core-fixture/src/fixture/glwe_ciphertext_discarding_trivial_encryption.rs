@@ -9,11 +9,14 @@ use concrete_commons::parameters::{GlweDimension, PolynomialSize};
 use concrete_core::prelude::{
     GlweCiphertextDiscardingTrivialEncryptionEngine, GlweCiphertextEntity, PlaintextVectorEntity,
 };
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `GlweCiphertextDiscardingTrivialEncryptionEngine`
 /// trait.
 pub struct GlweCiphertextDiscardingTrivialEncryptionFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct GlweCiphertextDiscardingTrivialEncryptionParameters {
     pub glwe_dimension: GlweDimension,
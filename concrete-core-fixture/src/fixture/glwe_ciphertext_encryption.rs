@@ -14,10 +14,13 @@ use concrete_core::prelude::{
     GlweCiphertextEncryptionEngine, GlweCiphertextEntity, GlweSecretKeyEntity,
     PlaintextVectorEntity,
 };
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `GlweCiphertextEncryptionEngine` trait.
 pub struct GlweCiphertextEncryptionFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct GlweCiphertextEncryptionParameters {
     pub noise: Variance,
@@ -20,12 +20,15 @@ use concrete_core::prelude::{
     GgswCiphertextEntity, GlweCiphertextEntity,
     GlweCiphertextGgswCiphertextDiscardingExternalProductEngine,
 };
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 use std::any::TypeId;
 
 /// A fixture for the types implementing the `GlweCiphertextGgswCiphertextDiscardingExternalProduct`
 /// trait.
 pub struct GlweCiphertextGgswCiphertextDiscardingExternalProductFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct GlweCiphertextGgswCiphertextDiscardingExternalProductParameters {
     pub ggsw_noise: Variance,
@@ -19,12 +19,15 @@ use concrete_commons::parameters::{
 use concrete_core::prelude::{
     GgswCiphertextEntity, GlweCiphertextEntity, GlweCiphertextGgswCiphertextExternalProductEngine,
 };
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 use std::any::TypeId;
 
 /// A fixture for the types implementing the `GlweCiphertextGgswCiphertextExternalProduct`
 /// trait.
 pub struct GlweCiphertextGgswCiphertextExternalProductFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct GlweCiphertextGgswCiphertextExternalProductParameters {
     pub ggsw_noise: Variance,
@@ -228,6 +231,168 @@ where
     }
 }
 
+/// A fixture for the types implementing the `GlweCiphertextGgswCiphertextExternalProduct`
+/// trait, with GLWE ciphertexts of dimension 1 only.
+///
+/// Apart from its parameters, this fixture is the same as
+/// [`GlweCiphertextGgswCiphertextExternalProductFixture`]. It is meant for the backends which
+/// only support this GLWE dimension.
+pub struct GlweCiphertextGgswCiphertextExternalProductFixture2;
+
+impl<Precision, KeyDistribution, Engine, GlweInput, GgswInput, GlweOutput>
+    Fixture<Precision, (KeyDistribution,), Engine, (GlweInput, GgswInput, GlweOutput)>
+    for GlweCiphertextGgswCiphertextExternalProductFixture2
+where
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    Engine: GlweCiphertextGgswCiphertextExternalProductEngine<GlweInput, GgswInput, GlweOutput>,
+    GlweInput: GlweCiphertextEntity,
+    GgswInput: GgswCiphertextEntity,
+    GlweOutput: GlweCiphertextEntity,
+    Maker: SynthesizesGlweCiphertext<Precision, KeyDistribution, GlweInput>
+        + SynthesizesGlweCiphertext<Precision, KeyDistribution, GlweOutput>
+        + SynthesizesGgswCiphertext<Precision, KeyDistribution, GgswInput>,
+{
+    type Parameters = GlweCiphertextGgswCiphertextExternalProductParameters;
+    type Criteria = (Variance,);
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+    type RepetitionPrototypes = (
+        <Maker as PrototypesPlaintext<Precision>>::PlaintextProto,
+        <Maker as PrototypesGlweSecretKey<Precision, KeyDistribution>>::GlweSecretKeyProto,
+        <Maker as PrototypesGgswCiphertext<Precision, KeyDistribution>>::GgswCiphertextProto,
+    );
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesGlweCiphertext<Precision, KeyDistribution>>::GlweCiphertextProto,
+    );
+    type PreExecutionContext = (GlweInput, GgswInput);
+    type PostExecutionContext = (GlweInput, GgswInput, GlweOutput);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                GlweCiphertextGgswCiphertextExternalProductParameters {
+                    ggsw_noise: Variance(LogStandardDev(-25.).get_variance()),
+                    glwe_noise: Variance(LogStandardDev(-20.).get_variance()),
+                    glwe_dimension: GlweDimension(1),
+                    ggsw_encrypted_value: 0,
+                    polynomial_size: PolynomialSize(512),
+                    decomposition_base_log: DecompositionBaseLog(6),
+                    decomposition_level_count: DecompositionLevelCount(4),
+                },
+                GlweCiphertextGgswCiphertextExternalProductParameters {
+                    ggsw_noise: Variance(LogStandardDev(-25.).get_variance()),
+                    glwe_noise: Variance(LogStandardDev(-20.).get_variance()),
+                    glwe_dimension: GlweDimension(1),
+                    ggsw_encrypted_value: 1,
+                    polynomial_size: PolynomialSize(1024),
+                    decomposition_base_log: DecompositionBaseLog(6),
+                    decomposition_level_count: DecompositionLevelCount(4),
+                },
+                GlweCiphertextGgswCiphertextExternalProductParameters {
+                    ggsw_noise: Variance(LogStandardDev(-25.).get_variance()),
+                    glwe_noise: Variance(LogStandardDev(-20.).get_variance()),
+                    glwe_dimension: GlweDimension(1),
+                    ggsw_encrypted_value: 2,
+                    polynomial_size: PolynomialSize(2048),
+                    decomposition_base_log: DecompositionBaseLog(6),
+                    decomposition_level_count: DecompositionLevelCount(4),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        <GlweCiphertextGgswCiphertextExternalProductFixture as Fixture<
+            Precision,
+            (KeyDistribution,),
+            Engine,
+            (GlweInput, GgswInput, GlweOutput),
+        >>::generate_random_repetition_prototypes(parameters, maker)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        <GlweCiphertextGgswCiphertextExternalProductFixture as Fixture<
+            Precision,
+            (KeyDistribution,),
+            Engine,
+            (GlweInput, GgswInput, GlweOutput),
+        >>::generate_random_sample_prototypes(parameters, maker, repetition_proto)
+    }
+
+    fn prepare_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        <GlweCiphertextGgswCiphertextExternalProductFixture as Fixture<
+            Precision,
+            (KeyDistribution,),
+            Engine,
+            (GlweInput, GgswInput, GlweOutput),
+        >>::prepare_context(parameters, maker, repetition_proto, sample_proto)
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        <GlweCiphertextGgswCiphertextExternalProductFixture as Fixture<
+            Precision,
+            (KeyDistribution,),
+            Engine,
+            (GlweInput, GgswInput, GlweOutput),
+        >>::execute_engine(parameters, engine, context)
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        <GlweCiphertextGgswCiphertextExternalProductFixture as Fixture<
+            Precision,
+            (KeyDistribution,),
+            Engine,
+            (GlweInput, GgswInput, GlweOutput),
+        >>::process_context(parameters, maker, repetition_proto, sample_proto, context)
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        <GlweCiphertextGgswCiphertextExternalProductFixture as Fixture<
+            Precision,
+            (KeyDistribution,),
+            Engine,
+            (GlweInput, GgswInput, GlweOutput),
+        >>::compute_criteria(parameters, maker, repetition_proto)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        <GlweCiphertextGgswCiphertextExternalProductFixture as Fixture<
+            Precision,
+            (KeyDistribution,),
+            Engine,
+            (GlweInput, GgswInput, GlweOutput),
+        >>::verify(criteria, outputs)
+    }
+}
+
 // FIXME:
 // The current NPE does not use the key distribution markers of concrete-core. This function makes
 // the mapping. This function should be removed as soon as the npe uses the types of concrete-core.
@@ -0,0 +1,263 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGlweCiphertext, PrototypesGlweRelinearizationKey, PrototypesGlweSecretKey,
+    PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{
+    SynthesizesGlweCiphertext, SynthesizesGlweRelinearizationKey,
+};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
+use concrete_commons::numeric::{Numeric, UnsignedInteger};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, ScalingFactor,
+};
+use concrete_core::prelude::{
+    GlweCiphertextEntity, GlweCiphertextLeveledMultiplicationEngine, GlweRelinearizationKeyEntity,
+};
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
+
+/// A fixture for the types implementing the `GlweCiphertextLeveledMultiplicationEngine` trait.
+pub struct GlweCiphertextLeveledMultiplicationFixture;
+
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
+#[derive(Debug)]
+pub struct GlweCiphertextLeveledMultiplicationParameters {
+    pub glwe_noise: Variance,
+    pub rlk_noise: Variance,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub decomposition_base_log: DecompositionBaseLog,
+    pub decomposition_level_count: DecompositionLevelCount,
+}
+
+// The messages are encoded in the most significant bits, leaving room for the product of two
+// messages below `MESSAGE_BOUND` and one bit of padding.
+const MESSAGE_BOUND: usize = 4;
+const MESSAGE_SHIFT: usize = 5;
+
+impl<
+        Precision,
+        KeyDistribution,
+        Engine,
+        InputCiphertext1,
+        InputCiphertext2,
+        RelinearizationKey,
+        OutputCiphertext,
+    >
+    Fixture<
+        Precision,
+        (KeyDistribution,),
+        Engine,
+        (
+            InputCiphertext1,
+            InputCiphertext2,
+            RelinearizationKey,
+            OutputCiphertext,
+        ),
+    > for GlweCiphertextLeveledMultiplicationFixture
+where
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    Engine: GlweCiphertextLeveledMultiplicationEngine<
+        InputCiphertext1,
+        InputCiphertext2,
+        RelinearizationKey,
+        OutputCiphertext,
+    >,
+    InputCiphertext1: GlweCiphertextEntity,
+    InputCiphertext2: GlweCiphertextEntity,
+    RelinearizationKey: GlweRelinearizationKeyEntity,
+    OutputCiphertext: GlweCiphertextEntity,
+    Maker: SynthesizesGlweCiphertext<Precision, KeyDistribution, InputCiphertext1>
+        + SynthesizesGlweCiphertext<Precision, KeyDistribution, InputCiphertext2>
+        + SynthesizesGlweCiphertext<Precision, KeyDistribution, OutputCiphertext>
+        + SynthesizesGlweRelinearizationKey<Precision, KeyDistribution, RelinearizationKey>,
+{
+    type Parameters = GlweCiphertextLeveledMultiplicationParameters;
+    type Criteria = ();
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+    type RepetitionPrototypes = (
+        <Maker as PrototypesGlweSecretKey<Precision, KeyDistribution>>::GlweSecretKeyProto,
+        <Maker as PrototypesGlweRelinearizationKey<
+            Precision,
+            KeyDistribution,
+        >>::GlweRelinearizationKeyProto,
+    );
+    type SamplePrototypes = (
+        Vec<Precision::Raw>,
+        <Maker as PrototypesGlweCiphertext<Precision, KeyDistribution>>::GlweCiphertextProto,
+        <Maker as PrototypesGlweCiphertext<Precision, KeyDistribution>>::GlweCiphertextProto,
+    );
+    type PreExecutionContext = (InputCiphertext1, InputCiphertext2, RelinearizationKey);
+    type PostExecutionContext = (
+        InputCiphertext1,
+        InputCiphertext2,
+        RelinearizationKey,
+        OutputCiphertext,
+    );
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                GlweCiphertextLeveledMultiplicationParameters {
+                    glwe_noise: Variance(LogStandardDev(-25.).get_variance()),
+                    rlk_noise: Variance(LogStandardDev(-25.).get_variance()),
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(256),
+                    decomposition_base_log: DecompositionBaseLog(4),
+                    decomposition_level_count: DecompositionLevelCount(7),
+                },
+                GlweCiphertextLeveledMultiplicationParameters {
+                    glwe_noise: Variance(LogStandardDev(-25.).get_variance()),
+                    rlk_noise: Variance(LogStandardDev(-25.).get_variance()),
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(512),
+                    decomposition_base_log: DecompositionBaseLog(4),
+                    decomposition_level_count: DecompositionLevelCount(7),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key =
+            maker.new_glwe_secret_key(parameters.glwe_dimension, parameters.polynomial_size);
+        let proto_relinearization_key = maker.new_glwe_relinearization_key(
+            &proto_secret_key,
+            parameters.decomposition_level_count,
+            parameters.decomposition_base_log,
+            parameters.rlk_noise,
+        );
+        (proto_secret_key, proto_relinearization_key)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_secret_key, _) = repetition_proto;
+        let shift = Precision::Raw::BITS - MESSAGE_SHIFT;
+        let message_1 = Precision::Raw::uniform_between(0..MESSAGE_BOUND);
+        let message_2 = Precision::Raw::uniform_between(0..MESSAGE_BOUND);
+
+        // The messages are constant polynomials, so that their product is a constant polynomial
+        let mut raw_plaintext_vector_1 = Precision::Raw::zero_vec(parameters.polynomial_size.0);
+        raw_plaintext_vector_1[0] = message_1 << shift;
+        let mut raw_plaintext_vector_2 = Precision::Raw::zero_vec(parameters.polynomial_size.0);
+        raw_plaintext_vector_2[0] = message_2 << shift;
+        let mut raw_expected_messages = Precision::Raw::zero_vec(parameters.polynomial_size.0);
+        raw_expected_messages[0] = message_1 * message_2;
+
+        let proto_plaintext_vector_1 =
+            maker.transform_raw_vec_to_plaintext_vector(&raw_plaintext_vector_1);
+        let proto_plaintext_vector_2 =
+            maker.transform_raw_vec_to_plaintext_vector(&raw_plaintext_vector_2);
+        let proto_ciphertext_1 = maker.encrypt_plaintext_vector_to_glwe_ciphertext(
+            proto_secret_key,
+            &proto_plaintext_vector_1,
+            parameters.glwe_noise,
+        );
+        let proto_ciphertext_2 = maker.encrypt_plaintext_vector_to_glwe_ciphertext(
+            proto_secret_key,
+            &proto_plaintext_vector_2,
+            parameters.glwe_noise,
+        );
+        (
+            raw_expected_messages,
+            proto_ciphertext_1,
+            proto_ciphertext_2,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, proto_relinearization_key) = repetition_proto;
+        let (_, proto_ciphertext_1, proto_ciphertext_2) = sample_proto;
+        let synth_ciphertext_1 = maker.synthesize_glwe_ciphertext(proto_ciphertext_1);
+        let synth_ciphertext_2 = maker.synthesize_glwe_ciphertext(proto_ciphertext_2);
+        let synth_relinearization_key =
+            maker.synthesize_glwe_relinearization_key(proto_relinearization_key);
+        (
+            synth_ciphertext_1,
+            synth_ciphertext_2,
+            synth_relinearization_key,
+        )
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (ciphertext_1, ciphertext_2, relinearization_key) = context;
+        let scaling_factor = ScalingFactor(1 << (Precision::Raw::BITS - MESSAGE_SHIFT));
+        let output_ciphertext = unsafe {
+            engine.mul_glwe_ciphertext_leveled_unchecked(
+                &ciphertext_1,
+                &ciphertext_2,
+                &relinearization_key,
+                scaling_factor,
+                scaling_factor,
+                scaling_factor,
+            )
+        };
+        (
+            ciphertext_1,
+            ciphertext_2,
+            relinearization_key,
+            output_ciphertext,
+        )
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (ciphertext_1, ciphertext_2, relinearization_key, output_ciphertext) = context;
+        let (proto_secret_key, _) = repetition_proto;
+        let (raw_expected_messages, ..) = sample_proto;
+        let proto_output_ciphertext = maker.unsynthesize_glwe_ciphertext(output_ciphertext);
+        maker.destroy_glwe_ciphertext(ciphertext_1);
+        maker.destroy_glwe_ciphertext(ciphertext_2);
+        maker.destroy_glwe_relinearization_key(relinearization_key);
+        let proto_output_plaintext_vector = maker.decrypt_glwe_ciphertext_to_plaintext_vector(
+            proto_secret_key,
+            &proto_output_ciphertext,
+        );
+        // Round the outputs to the closest multiple of the scaling factor
+        let shift = Precision::Raw::BITS - MESSAGE_SHIFT;
+        let raw_output_messages = maker
+            .transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector)
+            .into_iter()
+            .map(|raw| raw.wrapping_add(Precision::Raw::ONE << (shift - 1)) >> shift)
+            .collect();
+        (raw_expected_messages.to_owned(), raw_output_messages)
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+    }
+
+    fn verify(_criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        let (expected, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        expected == actual
+    }
+}
@@ -0,0 +1,326 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGlweCiphertext, PrototypesGlweRelinearizationKey, PrototypesGlweSecretKey,
+    PrototypesGlweTensorProductCiphertext, PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{
+    SynthesizesGlweCiphertext, SynthesizesGlweRelinearizationKey,
+    SynthesizesGlweTensorProductCiphertext,
+};
+use crate::generation::{
+    BinaryKeyDistribution, GaussianKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker,
+    TernaryKeyDistribution,
+};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
+use concrete_commons::key_kinds::{BinaryKeyKind, GaussianKeyKind, TernaryKeyKind};
+use concrete_commons::numeric::{Numeric, UnsignedInteger};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, ScalingFactor,
+};
+use concrete_core::prelude::{
+    GlweCiphertextEntity, GlweCiphertextRelinearizationEngine, GlweRelinearizationKeyEntity,
+    GlweTensorProductCiphertextEntity,
+};
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
+use std::any::TypeId;
+
+/// A fixture for the types implementing the `GlweCiphertextRelinearizationEngine` trait.
+pub struct GlweCiphertextRelinearizationFixture;
+
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
+#[derive(Debug)]
+pub struct GlweCiphertextRelinearizationParameters {
+    pub glwe_noise: Variance,
+    pub rlk_noise: Variance,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub decomposition_base_log: DecompositionBaseLog,
+    pub decomposition_level_count: DecompositionLevelCount,
+}
+
+// The messages are encoded in the most significant bits, leaving room for the product of two
+// messages below `MESSAGE_BOUND` and one bit of padding.
+const MESSAGE_BOUND: usize = 4;
+const MESSAGE_SHIFT: usize = 5;
+
+impl<
+        Precision,
+        KeyDistribution,
+        Engine,
+        TensorProductCiphertext,
+        RelinearizationKey,
+        Ciphertext,
+    >
+    Fixture<
+        Precision,
+        (KeyDistribution,),
+        Engine,
+        (TensorProductCiphertext, RelinearizationKey, Ciphertext),
+    > for GlweCiphertextRelinearizationFixture
+where
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    Engine: GlweCiphertextRelinearizationEngine<
+        TensorProductCiphertext,
+        RelinearizationKey,
+        Ciphertext,
+    >,
+    TensorProductCiphertext: GlweTensorProductCiphertextEntity,
+    RelinearizationKey: GlweRelinearizationKeyEntity,
+    Ciphertext: GlweCiphertextEntity,
+    Maker: SynthesizesGlweTensorProductCiphertext<Precision, KeyDistribution, TensorProductCiphertext>
+        + SynthesizesGlweRelinearizationKey<Precision, KeyDistribution, RelinearizationKey>
+        + SynthesizesGlweCiphertext<Precision, KeyDistribution, Ciphertext>,
+{
+    type Parameters = GlweCiphertextRelinearizationParameters;
+    type Criteria = (Variance,);
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+    type RepetitionPrototypes = (
+        <Maker as PrototypesGlweSecretKey<Precision, KeyDistribution>>::GlweSecretKeyProto,
+        <Maker as PrototypesGlweRelinearizationKey<
+            Precision,
+            KeyDistribution,
+        >>::GlweRelinearizationKeyProto,
+    );
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesGlweTensorProductCiphertext<
+            Precision,
+            KeyDistribution,
+        >>::GlweTensorProductCiphertextProto,
+    );
+    type PreExecutionContext = (TensorProductCiphertext, RelinearizationKey);
+    type PostExecutionContext = (TensorProductCiphertext, RelinearizationKey, Ciphertext);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                GlweCiphertextRelinearizationParameters {
+                    glwe_noise: Variance(LogStandardDev(-25.).get_variance()),
+                    rlk_noise: Variance(LogStandardDev(-25.).get_variance()),
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(256),
+                    decomposition_base_log: DecompositionBaseLog(4),
+                    decomposition_level_count: DecompositionLevelCount(7),
+                },
+                GlweCiphertextRelinearizationParameters {
+                    glwe_noise: Variance(LogStandardDev(-25.).get_variance()),
+                    rlk_noise: Variance(LogStandardDev(-25.).get_variance()),
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(512),
+                    decomposition_base_log: DecompositionBaseLog(4),
+                    decomposition_level_count: DecompositionLevelCount(7),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key =
+            maker.new_glwe_secret_key(parameters.glwe_dimension, parameters.polynomial_size);
+        let proto_relinearization_key = maker.new_glwe_relinearization_key(
+            &proto_secret_key,
+            parameters.decomposition_level_count,
+            parameters.decomposition_base_log,
+            parameters.rlk_noise,
+        );
+        (proto_secret_key, proto_relinearization_key)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_secret_key, _) = repetition_proto;
+        let shift = Precision::Raw::BITS - MESSAGE_SHIFT;
+        let message_1 = Precision::Raw::uniform_between(0..MESSAGE_BOUND);
+        let message_2 = Precision::Raw::uniform_between(0..MESSAGE_BOUND);
+
+        // The messages are constant polynomials, so that their product is a constant polynomial
+        let mut raw_plaintext_vector_1 = Precision::Raw::zero_vec(parameters.polynomial_size.0);
+        raw_plaintext_vector_1[0] = message_1 << shift;
+        let mut raw_plaintext_vector_2 = Precision::Raw::zero_vec(parameters.polynomial_size.0);
+        raw_plaintext_vector_2[0] = message_2 << shift;
+        let mut raw_expected_plaintext_vector =
+            Precision::Raw::zero_vec(parameters.polynomial_size.0);
+        raw_expected_plaintext_vector[0] = (message_1 * message_2) << shift;
+
+        let proto_plaintext_vector_1 =
+            maker.transform_raw_vec_to_plaintext_vector(&raw_plaintext_vector_1);
+        let proto_plaintext_vector_2 =
+            maker.transform_raw_vec_to_plaintext_vector(&raw_plaintext_vector_2);
+        let proto_ciphertext_1 = maker.encrypt_plaintext_vector_to_glwe_ciphertext(
+            proto_secret_key,
+            &proto_plaintext_vector_1,
+            parameters.glwe_noise,
+        );
+        let proto_ciphertext_2 = maker.encrypt_plaintext_vector_to_glwe_ciphertext(
+            proto_secret_key,
+            &proto_plaintext_vector_2,
+            parameters.glwe_noise,
+        );
+        let proto_tensor_product = maker.tensor_product_glwe_ciphertexts(
+            &proto_ciphertext_1,
+            &proto_ciphertext_2,
+            ScalingFactor(1 << shift),
+        );
+        (
+            maker.transform_raw_vec_to_plaintext_vector(&raw_expected_plaintext_vector),
+            proto_tensor_product,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, proto_relinearization_key) = repetition_proto;
+        let (_, proto_tensor_product) = sample_proto;
+        let synth_tensor_product =
+            maker.synthesize_glwe_tensor_product_ciphertext(proto_tensor_product);
+        let synth_relinearization_key =
+            maker.synthesize_glwe_relinearization_key(proto_relinearization_key);
+        (synth_tensor_product, synth_relinearization_key)
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (tensor_product, relinearization_key) = context;
+        let output_ciphertext = unsafe {
+            engine.relinearize_glwe_ciphertext_unchecked(&tensor_product, &relinearization_key)
+        };
+        (tensor_product, relinearization_key, output_ciphertext)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (tensor_product, relinearization_key, output_ciphertext) = context;
+        let (proto_secret_key, _) = repetition_proto;
+        let (proto_expected_plaintext_vector, _) = sample_proto;
+        let proto_output_ciphertext = maker.unsynthesize_glwe_ciphertext(output_ciphertext);
+        maker.destroy_glwe_tensor_product_ciphertext(tensor_product);
+        maker.destroy_glwe_relinearization_key(relinearization_key);
+        let proto_output_plaintext_vector = maker.decrypt_glwe_ciphertext_to_plaintext_vector(
+            proto_secret_key,
+            &proto_output_ciphertext,
+        );
+        (
+            maker.transform_plaintext_vector_to_raw_vec(proto_expected_plaintext_vector),
+            maker.transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        let delta = 2_f64.powi((Precision::Raw::BITS - MESSAGE_SHIFT) as i32);
+        let max_message = (MESSAGE_BOUND - 1) as f64;
+        let output_variance = fix_estimate_multiplication_noise::<Precision::Raw, KeyDistribution>(
+            parameters.polynomial_size,
+            parameters.glwe_dimension,
+            parameters.glwe_noise,
+            delta,
+            max_message,
+            parameters.rlk_noise,
+            parameters.decomposition_base_log,
+            parameters.decomposition_level_count,
+        );
+        (output_variance,)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        let means = means.into_iter().flatten().collect::<Vec<_>>();
+        let actual = actual.into_iter().flatten().collect::<Vec<_>>();
+        assert_noise_distribution(&actual, means.as_slice(), criteria.0)
+    }
+}
+
+// FIXME:
+// The current NPE does not use the key distribution markers of concrete-core. This function makes
+// the mapping. This function should be removed as soon as the npe uses the types of concrete-core.
+#[allow(clippy::too_many_arguments)]
+fn fix_estimate_multiplication_noise<T, K>(
+    poly_size: PolynomialSize,
+    glwe_dimension: GlweDimension,
+    var_glwe: Variance,
+    delta: f64,
+    max_message: f64,
+    var_rlk: Variance,
+    base_log: DecompositionBaseLog,
+    level: DecompositionLevelCount,
+) -> Variance
+where
+    T: UnsignedInteger,
+    K: KeyDistributionMarker,
+{
+    let k_type_id = TypeId::of::<K>();
+    if k_type_id == TypeId::of::<BinaryKeyDistribution>() {
+        concrete_npe::estimate_multiplication_noise::<_, _, _, BinaryKeyKind>(
+            poly_size,
+            glwe_dimension,
+            var_glwe,
+            var_glwe,
+            delta,
+            delta,
+            max_message,
+            max_message,
+            var_rlk,
+            base_log,
+            level,
+            T::BITS as u32,
+        )
+    } else if k_type_id == TypeId::of::<TernaryKeyDistribution>() {
+        concrete_npe::estimate_multiplication_noise::<_, _, _, TernaryKeyKind>(
+            poly_size,
+            glwe_dimension,
+            var_glwe,
+            var_glwe,
+            delta,
+            delta,
+            max_message,
+            max_message,
+            var_rlk,
+            base_log,
+            level,
+            T::BITS as u32,
+        )
+    } else if k_type_id == TypeId::of::<GaussianKeyDistribution>() {
+        concrete_npe::estimate_multiplication_noise::<_, _, _, GaussianKeyKind>(
+            poly_size,
+            glwe_dimension,
+            var_glwe,
+            var_glwe,
+            delta,
+            delta,
+            max_message,
+            max_message,
+            var_rlk,
+            base_log,
+            level,
+            T::BITS as u32,
+        )
+    } else {
+        panic!("Unknown key distribution encountered.")
+    }
+}
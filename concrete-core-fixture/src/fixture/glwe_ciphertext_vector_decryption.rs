@@ -14,10 +14,13 @@ use crate::generation::synthesizing::{
 use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `GlweCiphertextVectorDecryptionEngine` trait.
 pub struct GlweCiphertextVectorDecryptionFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct GlweCiphertextVectorDecryptionParameters {
     pub glwe_dimension: GlweDimension,
@@ -15,10 +15,13 @@ use crate::generation::synthesizing::{
 use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `GlweCiphertextVectorDiscardingDecryptionEngine` trait.
 pub struct GlweCiphertextVectorDiscardingDecryptionFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct GlweCiphertextVectorDiscardingDecryptionParameters {
     pub noise: Variance,
@@ -14,10 +14,13 @@ use concrete_core::prelude::{
     GlweCiphertextVectorEncryptionEngine, GlweCiphertextVectorEntity, GlweSecretKeyEntity,
     PlaintextVectorEntity,
 };
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `GlweCiphertextVectorEncryptionEngine` trait.
 pub struct GlweCiphertextVectorEncryptionFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct GlweCiphertextVectorEncryptionParameters {
     pub noise: Variance,
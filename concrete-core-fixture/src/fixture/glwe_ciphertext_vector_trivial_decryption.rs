@@ -11,10 +11,13 @@ use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, Polynomia
 use concrete_core::prelude::{
     GlweCiphertextVectorEntity, GlweCiphertextVectorTrivialDecryptionEngine, PlaintextVectorEntity,
 };
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `GlweCiphertextVectorTrivialDecryptionEngine` trait.
 pub struct GlweCiphertextVectorTrivialDecryptionFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct GlweCiphertextVectorTrivialDecryptionParameters {
     pub glwe_dimension: GlweDimension,
@@ -13,10 +13,13 @@ use crate::generation::synthesizing::{SynthesizesGlweCiphertextVector, Synthesiz
 use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `GlweCiphertextVectorZeroEncryptionEngine` trait.
 pub struct GlweCiphertextVectorZeroEncryptionFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct GlweCiphertextVectorZeroEncryptionParameters {
     pub noise: Variance,
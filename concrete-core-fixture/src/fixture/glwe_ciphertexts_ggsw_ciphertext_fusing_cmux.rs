@@ -19,11 +19,14 @@ use concrete_commons::parameters::{
 use concrete_core::prelude::{
     GgswCiphertextEntity, GlweCiphertextEntity, GlweCiphertextsGgswCiphertextFusingCmuxEngine,
 };
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 use std::any::TypeId;
 
 /// A fixture for the types implementing the `GlweCiphertextsGgswCiphertextFusingCmux` trait.
 pub struct GlweCiphertextsGgswCiphertextFusingCmuxFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct GlweCiphertextsGgswCiphertextFusingCmuxParameters {
     pub ggsw_noise: Variance,
@@ -254,6 +257,170 @@ where
     }
 }
 
+/// A fixture for the types implementing the `GlweCiphertextsGgswCiphertextFusingCmux`
+/// trait, with GLWE ciphertexts of dimension 1 only.
+///
+/// Apart from its parameters, this fixture is the same as
+/// [`GlweCiphertextsGgswCiphertextFusingCmuxFixture`]. It is meant for the backends which
+/// only support this GLWE dimension.
+pub struct GlweCiphertextsGgswCiphertextFusingCmuxFixture2;
+
+impl<Precision, KeyDistribution, Engine, GlweInput, GlweOutput, GgswInput>
+    Fixture<Precision, (KeyDistribution,), Engine, (GlweInput, GlweOutput, GgswInput)>
+    for GlweCiphertextsGgswCiphertextFusingCmuxFixture2
+where
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    Engine: GlweCiphertextsGgswCiphertextFusingCmuxEngine<GlweInput, GlweOutput, GgswInput>,
+    GlweInput: GlweCiphertextEntity,
+    GlweOutput: GlweCiphertextEntity,
+    GgswInput: GgswCiphertextEntity,
+    Maker: SynthesizesGlweCiphertext<Precision, KeyDistribution, GlweInput>
+        + SynthesizesGlweCiphertext<Precision, KeyDistribution, GlweOutput>
+        + SynthesizesGgswCiphertext<Precision, KeyDistribution, GgswInput>,
+{
+    type Parameters = GlweCiphertextsGgswCiphertextFusingCmuxParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesPlaintext<Precision>>::PlaintextProto,
+        <Maker as PrototypesGlweSecretKey<Precision, KeyDistribution>>::GlweSecretKeyProto,
+        <Maker as PrototypesGgswCiphertext<Precision, KeyDistribution>>::GgswCiphertextProto,
+    );
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesGlweCiphertext<Precision, KeyDistribution>>::GlweCiphertextProto,
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesGlweCiphertext<Precision, KeyDistribution>>::GlweCiphertextProto,
+    );
+    type PreExecutionContext = (GlweInput, GgswInput, GlweOutput);
+    type PostExecutionContext = (GlweInput, GgswInput, GlweOutput);
+    type Criteria = (Variance,);
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                GlweCiphertextsGgswCiphertextFusingCmuxParameters {
+                    ggsw_noise: Variance(LogStandardDev(-20.).get_variance()),
+                    glwe_noise: Variance(LogStandardDev(-25.).get_variance()),
+                    glwe_dimension: GlweDimension(1),
+                    ggsw_encrypted_value: 0,
+                    polynomial_size: PolynomialSize(512),
+                    decomposition_base_log: DecompositionBaseLog(7),
+                    decomposition_level_count: DecompositionLevelCount(4),
+                },
+                GlweCiphertextsGgswCiphertextFusingCmuxParameters {
+                    ggsw_noise: Variance(LogStandardDev(-20.).get_variance()),
+                    glwe_noise: Variance(LogStandardDev(-25.).get_variance()),
+                    glwe_dimension: GlweDimension(1),
+                    ggsw_encrypted_value: 1,
+                    polynomial_size: PolynomialSize(1024),
+                    decomposition_base_log: DecompositionBaseLog(7),
+                    decomposition_level_count: DecompositionLevelCount(4),
+                },
+                GlweCiphertextsGgswCiphertextFusingCmuxParameters {
+                    ggsw_noise: Variance(LogStandardDev(-20.).get_variance()),
+                    glwe_noise: Variance(LogStandardDev(-25.).get_variance()),
+                    glwe_dimension: GlweDimension(1),
+                    ggsw_encrypted_value: 2,
+                    polynomial_size: PolynomialSize(2048),
+                    decomposition_base_log: DecompositionBaseLog(7),
+                    decomposition_level_count: DecompositionLevelCount(4),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        <GlweCiphertextsGgswCiphertextFusingCmuxFixture as Fixture<
+            Precision,
+            (KeyDistribution,),
+            Engine,
+            (GlweInput, GlweOutput, GgswInput),
+        >>::generate_random_repetition_prototypes(parameters, maker)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        <GlweCiphertextsGgswCiphertextFusingCmuxFixture as Fixture<
+            Precision,
+            (KeyDistribution,),
+            Engine,
+            (GlweInput, GlweOutput, GgswInput),
+        >>::generate_random_sample_prototypes(parameters, maker, repetition_proto)
+    }
+
+    fn prepare_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        <GlweCiphertextsGgswCiphertextFusingCmuxFixture as Fixture<
+            Precision,
+            (KeyDistribution,),
+            Engine,
+            (GlweInput, GlweOutput, GgswInput),
+        >>::prepare_context(parameters, maker, repetition_proto, sample_proto)
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        <GlweCiphertextsGgswCiphertextFusingCmuxFixture as Fixture<
+            Precision,
+            (KeyDistribution,),
+            Engine,
+            (GlweInput, GlweOutput, GgswInput),
+        >>::execute_engine(parameters, engine, context)
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        <GlweCiphertextsGgswCiphertextFusingCmuxFixture as Fixture<
+            Precision,
+            (KeyDistribution,),
+            Engine,
+            (GlweInput, GlweOutput, GgswInput),
+        >>::process_context(parameters, maker, repetition_proto, sample_proto, context)
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        <GlweCiphertextsGgswCiphertextFusingCmuxFixture as Fixture<
+            Precision,
+            (KeyDistribution,),
+            Engine,
+            (GlweInput, GlweOutput, GgswInput),
+        >>::compute_criteria(parameters, maker, repetition_proto)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        <GlweCiphertextsGgswCiphertextFusingCmuxFixture as Fixture<
+            Precision,
+            (KeyDistribution,),
+            Engine,
+            (GlweInput, GlweOutput, GgswInput),
+        >>::verify(criteria, outputs)
+    }
+}
+
 // FIXME:
 // The current NPE does not use the key distribution markers of concrete-core. This function makes
 // the mapping. This function should be removed as soon as the npe uses the types of concrete-core.
@@ -3,10 +3,13 @@ use crate::generation::synthesizing::SynthesizesGlweSecretKey;
 use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
 use concrete_commons::parameters::{GlweDimension, PolynomialSize};
 use concrete_core::prelude::{GlweSecretKeyEntity, GlweSecretKeyGenerationEngine};
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `GlweSecretKeyGenerationEngine` trait.
 pub struct GlweSecretKeyGenerationFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct GlweSecretKeyGenerationParameters {
     pub glwe_dimension: GlweDimension,
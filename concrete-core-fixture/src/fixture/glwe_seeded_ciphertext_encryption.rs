@@ -15,10 +15,13 @@ use concrete_core::prelude::{
     GlweSecretKeyEntity, GlweSeededCiphertextEncryptionEngine, GlweSeededCiphertextEntity,
     PlaintextVectorEntity,
 };
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `GlweSeededCiphertextEncryptionEngine` trait.
 pub struct GlweSeededCiphertextEncryptionFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct GlweSeededCiphertextEncryptionParameters {
     pub noise: Variance,
@@ -16,11 +16,14 @@ use concrete_core::prelude::{
     GlweCiphertextEntity, GlweSecretKeyEntity, GlweSeededCiphertextEntity,
     GlweSeededCiphertextToGlweCiphertextTransformationEngine, PlaintextVectorEntity,
 };
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the
 /// `GlweSeededCiphertextToGlweCiphertextTransformationEngine` trait.
 pub struct GlweSeededCiphertextToGlweCiphertextTransformationFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct GlweSeededCiphertextToGlweCiphertextTransformationParameters {
     pub noise: Variance,
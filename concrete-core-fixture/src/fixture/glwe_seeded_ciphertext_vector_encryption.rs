@@ -15,10 +15,13 @@ use concrete_core::prelude::{
     GlweSecretKeyEntity, GlweSeededCiphertextVectorEncryptionEngine,
     GlweSeededCiphertextVectorEntity, PlaintextVectorEntity,
 };
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `GlweSeededCiphertextVectorEncryptionEngine` trait.
 pub struct GlweSeededCiphertextVectorEncryptionFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct GlweSeededCiphertextVectorEncryptionParameters {
     pub noise: Variance,
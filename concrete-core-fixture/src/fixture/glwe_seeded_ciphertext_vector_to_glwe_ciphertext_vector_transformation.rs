@@ -16,11 +16,14 @@ use concrete_core::prelude::{
     GlweCiphertextVectorEntity, GlweSecretKeyEntity, GlweSeededCiphertextVectorEntity,
     GlweSeededCiphertextVectorToGlweCiphertextVectorTransformationEngine, PlaintextVectorEntity,
 };
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the
 /// `GlweSeededCiphertextVectorToGlweCiphertextVectorTransformationEngine` trait.
 pub struct GlweSeededCiphertextVectorToGlweCiphertextVectorTransformationFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct GlweSeededCiphertextVectorToGlweCiphertextVectorTransformationParameters {
     pub noise: Variance,
@@ -6,10 +6,13 @@ use concrete_commons::parameters::{GlweDimension, LweDimension, PolynomialSize};
 use concrete_core::prelude::{
     GlweSecretKeyEntity, GlweToLweSecretKeyTransformationEngine, LweSecretKeyEntity,
 };
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `GlweToLweSecretKeyTransformationEngine` trait.
 pub struct GlweToLweSecretKeyTransformationFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct GlweToLweSecretKeyTransformationParameters {
     pub glwe_dimension: GlweDimension,
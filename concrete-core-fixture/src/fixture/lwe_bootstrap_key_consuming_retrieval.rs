@@ -8,7 +8,10 @@ use concrete_commons::parameters::{
 };
 
 use concrete_core::prelude::{LweBootstrapKeyConsumingRetrievalEngine, LweBootstrapKeyEntity};
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct LweBootstrapKeyConsumingRetrievalParameters {
     pub lwe_dimension: LweDimension,
@@ -7,10 +7,13 @@ use concrete_commons::parameters::{
     DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweDimension, PolynomialSize,
 };
 use concrete_core::prelude::{LweBootstrapKeyCreationEngine, LweBootstrapKeyEntity};
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `LweBootstrapKeyCreationEngine` trait.
 pub struct LweBootstrapKeyCreationFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct LweBootstrapKeyCreationParameters {
     pub lwe_dimension: LweDimension,
@@ -9,10 +9,13 @@ use concrete_commons::parameters::{
     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
 };
 use concrete_core::prelude::{LweBootstrapKeyDiscardingConversionEngine, LweBootstrapKeyEntity};
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `LweBootstrapKeyDiscardingConversionEngine` trait.
 pub struct LweSecretKeyDiscardingConversionFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct LweSecretKeyDiscardingConversionParameters {
     pub lwe_dimension: LweDimension,
@@ -11,10 +11,13 @@ use concrete_commons::parameters::{
 use concrete_core::prelude::{
     GlweSecretKeyEntity, LweBootstrapKeyEntity, LweBootstrapKeyGenerationEngine, LweSecretKeyEntity,
 };
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `LweBootstrapKeyGenerationEngine` trait.
 pub struct LweBootstrapKeyGenerationFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct LweBootstrapKeyGenerationParameters {
     pub lwe_dimension: LweDimension,
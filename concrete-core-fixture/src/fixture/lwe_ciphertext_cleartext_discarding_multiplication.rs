@@ -12,11 +12,14 @@ use concrete_commons::parameters::LweDimension;
 use concrete_core::prelude::{
     CleartextEntity, LweCiphertextCleartextDiscardingMultiplicationEngine, LweCiphertextEntity,
 };
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `LweCiphertextCleartextDiscardingMultiplicationEngine`
 /// trait.
 pub struct LweCiphertextCleartextDiscardingMultiplicationFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct LweCiphertextCleartextDiscardingMultiplicationParameters {
     pub noise: Variance,
@@ -13,11 +13,14 @@ use crate::generation::synthesizing::{SynthesizesCleartext, SynthesizesLweCipher
 use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `LweCiphertextCleartextFusingMultiplicationEngine`
 /// trait.
 pub struct LweCiphertextCleartextFusingMultiplicationFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct LweCiphertextCleartextFusingMultiplicationParameters {
     pub noise: Variance,
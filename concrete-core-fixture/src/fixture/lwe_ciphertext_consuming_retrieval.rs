@@ -8,7 +8,10 @@ use concrete_commons::dispersion::Variance;
 use concrete_commons::parameters::LweDimension;
 
 use concrete_core::prelude::{LweCiphertextConsumingRetrievalEngine, LweCiphertextEntity};
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct LweCiphertextConsumingRetrievalParameters {
     pub lwe_dimension: LweDimension,
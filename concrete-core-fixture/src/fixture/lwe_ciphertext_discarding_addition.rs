@@ -10,11 +10,14 @@ use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance
 use concrete_commons::numeric::{Numeric, UnsignedInteger};
 use concrete_commons::parameters::LweDimension;
 use concrete_core::prelude::{LweCiphertextDiscardingAdditionEngine, LweCiphertextEntity};
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `LweCiphertextDiscardingAdditionEngine`
 /// trait.
 pub struct LweCiphertextDiscardingAdditionFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct LweCiphertextDiscardingAdditionParameters {
     pub noise: Variance,
@@ -22,11 +22,14 @@ use concrete_core::prelude::{
     GlweCiphertextEntity, LweBootstrapKeyEntity, LweCiphertextDiscardingBootstrapEngine,
     LweCiphertextEntity,
 };
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 use std::any::TypeId;
 
 /// A fixture for the types implementing the `LweCiphertextDiscardingBootstrapEngine` trait.
 pub struct LweCiphertextDiscardingBootstrapFixture1;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct LweCiphertextDiscardingBootstrapParameters1 {
     pub noise: Variance,
@@ -14,10 +14,13 @@ use concrete_core::prelude::{
     LweCiphertextDiscardingDecryptionEngine, LweCiphertextEntity, LweSecretKeyEntity,
     PlaintextEntity,
 };
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `LweCiphertextDiscardingDecryptionEngine` trait.
 pub struct LweCiphertextDiscardingDecryptionFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct LweCiphertextDiscardingDecryptionParameters {
     pub noise: Variance,
@@ -14,10 +14,13 @@ use concrete_core::prelude::{
     LweCiphertextDiscardingEncryptionEngine, LweCiphertextEntity, LweSecretKeyEntity,
     PlaintextEntity,
 };
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `LweCiphertextDiscardingEncryptionEngine` trait.
 pub struct LweCiphertextDiscardingEncryptionFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct LweCiphertextDiscardingEncryptionParameters {
     pub noise: Variance,
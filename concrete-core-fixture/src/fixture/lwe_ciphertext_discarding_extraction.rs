@@ -12,10 +12,13 @@ use concrete_commons::parameters::{GlweDimension, LweDimension, MonomialIndex, P
 use concrete_core::prelude::{
     GlweCiphertextEntity, LweCiphertextDiscardingExtractionEngine, LweCiphertextEntity,
 };
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `LweCiphertextDiscardingExtractionEngine` trait.
 pub struct LweCiphertextDiscardingExtractionFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct LweCiphertextDiscardingExtractionParameters {
     pub noise: Variance,
@@ -16,11 +16,14 @@ use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount
 use concrete_core::prelude::{
     LweCiphertextDiscardingKeyswitchEngine, LweCiphertextEntity, LweKeyswitchKeyEntity,
 };
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 use std::any::TypeId;
 
 /// A fixture for the types implementing the `LweCiphertextDiscardingKeyswitchEngine` trait.
 pub struct LweCiphertextDiscardingKeyswitchFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct LweCiphertextDiscardingKeyswitchParameters {
     pub n_bit_msg: usize,
@@ -13,10 +13,13 @@ use concrete_commons::parameters::LweDimension;
 use concrete_core::prelude::{
     LweCiphertextEncryptionEngine, LweCiphertextEntity, LweSecretKeyEntity, PlaintextEntity,
 };
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `LweCiphertextEncryptionEngine` trait.
 pub struct LweCiphertextEncryptionFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct LweCiphertextEncryptionParameters {
     pub noise: Variance,
@@ -12,11 +12,14 @@ use concrete_commons::parameters::LweDimension;
 use concrete_core::prelude::{
     LweCiphertextEntity, LweCiphertextPlaintextDiscardingAdditionEngine, PlaintextEntity,
 };
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `LweCiphertextPlaintextDiscardingAdditionEngine`
 /// trait.
 pub struct LweCiphertextPlaintextDiscardingAdditionFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct LweCiphertextPlaintextDiscardingAdditionParameters {
     pub noise: Variance,
@@ -12,11 +12,14 @@ use concrete_commons::parameters::LweDimension;
 use concrete_core::prelude::{
     LweCiphertextEntity, LweCiphertextPlaintextDiscardingSubtractionEngine, PlaintextEntity,
 };
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `LweCiphertextPlaintextDiscardingSubtractionEngine`
 /// trait.
 pub struct LweCiphertextPlaintextDiscardingSubtractionFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct LweCiphertextPlaintextDiscardingSubtractionParameters {
     pub noise: Variance,
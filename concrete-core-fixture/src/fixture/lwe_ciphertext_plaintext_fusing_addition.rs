@@ -12,11 +12,14 @@ use concrete_commons::parameters::LweDimension;
 use concrete_core::prelude::{
     LweCiphertextEntity, LweCiphertextPlaintextFusingAdditionEngine, PlaintextEntity,
 };
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `LweCiphertextPlaintextFusingAdditionEngine`
 /// trait.
 pub struct LweCiphertextPlaintextFusingAdditionFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct LweCiphertextPlaintextFusingAdditionParameters {
     pub noise: Variance,
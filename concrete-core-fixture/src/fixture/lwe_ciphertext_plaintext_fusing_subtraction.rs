@@ -12,11 +12,14 @@ use concrete_commons::parameters::LweDimension;
 use concrete_core::prelude::{
     LweCiphertextEntity, LweCiphertextPlaintextFusingSubtractionEngine, PlaintextEntity,
 };
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `LweCiphertextPlaintextFusingSubtractionEngine`
 /// trait.
 pub struct LweCiphertextPlaintextFusingSubtractionFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct LweCiphertextPlaintextFusingSubtractionParameters {
     pub noise: Variance,
@@ -9,10 +9,13 @@ use concrete_commons::parameters::LweDimension;
 use concrete_core::prelude::{
     LweCiphertextEntity, LweCiphertextTrivialDecryptionEngine, PlaintextEntity,
 };
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `LweCiphertextTrivialDecryptionEngine` trait.
 pub struct LweCiphertextTrivialDecryptionFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct LweCiphertextTrivialDecryptionParameters {
     pub lwe_dimension: LweDimension,
@@ -14,10 +14,13 @@ use concrete_core::prelude::{
     LweCiphertextVectorDecryptionEngine, LweCiphertextVectorEntity, LweSecretKeyEntity,
     PlaintextVectorEntity,
 };
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `LweCiphertextVectorDecryptionEngine` trait.
 pub struct LweCiphertextVectorDecryptionFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct LweCiphertextVectorDecryptionParameters {
     pub noise: Variance,
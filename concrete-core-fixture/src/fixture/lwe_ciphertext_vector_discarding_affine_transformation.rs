@@ -18,11 +18,14 @@ use concrete_core::prelude::{
     LweCiphertextVectorDiscardingAffineTransformationEngine, LweCiphertextVectorEntity,
     PlaintextEntity,
 };
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the
 /// `LweCiphertextVectorDiscardingAffineTransformationEngine` trait.
 pub struct LweCiphertextVectorDiscardingAffineTransformationFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct LweCiphertextVectorDiscardingAffineTransformationParameters {
     pub nb_ct: LweCiphertextCount,
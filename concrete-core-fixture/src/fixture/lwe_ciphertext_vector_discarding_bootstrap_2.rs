@@ -16,10 +16,13 @@ use concrete_core::prelude::{
     GlweCiphertextCount, GlweCiphertextVectorEntity, LweBootstrapKeyEntity, LweCiphertextCount,
     LweCiphertextVectorDiscardingBootstrapEngine, LweCiphertextVectorEntity,
 };
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `LweCiphertextVectorDiscardingBootstrapEngine` trait.
 pub struct LweCiphertextVectorDiscardingBootstrapFixture2;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct LweCiphertextVectorDiscardingBootstrapParameters2 {
     pub noise: Variance,
@@ -13,10 +13,13 @@ use concrete_core::prelude::{
     LweCiphertextCount, LweCiphertextVectorDiscardingKeyswitchEngine, LweCiphertextVectorEntity,
     LweKeyswitchKeyEntity,
 };
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `LweCiphertextVectorDiscardingKeyswitchEngine` trait.
 pub struct LweCiphertextVectorDiscardingKeyswitchFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct LweCiphertextVectorDiscardingKeyswitchParameters {
     pub n_bit_msg: usize,
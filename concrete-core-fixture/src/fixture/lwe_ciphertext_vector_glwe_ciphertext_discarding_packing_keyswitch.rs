@@ -18,11 +18,14 @@ use concrete_core::prelude::{
     LweCiphertextVectorEntity, LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchEngine,
     LwePackingKeyswitchKeyEntity,
 };
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the
 /// `LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchEngine` trait.
 pub struct LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchParameters {
     pub input_lwe_noise: Variance,
@@ -27,12 +27,15 @@ use concrete_core::prelude::{
     LweCiphertextVectorGlweCiphertextDiscardingPrivateFunctionalPackingKeyswitchEngine,
     LwePrivateFunctionalPackingKeyswitchKeyEntity,
 };
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 use std::any::TypeId;
 
 /// A fixture for the types implementing the
 /// `LweCiphertextVectorGlweCiphertextDiscardingPrivateFunctionalPackingKeyswitchEngine` trait.
 pub struct LweCiphertextVectorGlweCiphertextDiscardingPrivateFunctionalPackingKeyswitchFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct LweCiphertextVectorGlweCiphertextDiscardingPrivateFunctionalPackingKeyswitchParameters {
     pub input_lwe_noise: Variance,
@@ -10,10 +10,13 @@ use crate::generation::synthesizing::{SynthesizesLweCiphertextVector, Synthesize
 use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
 use crate::raw::generation::RawUnsignedIntegers;
 use crate::raw::statistical_test::assert_noise_distribution;
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `LweCiphertextVectorTrivialDecryptionEngine` trait.
 pub struct LweCiphertextVectorTrivialDecryptionFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct LweCiphertextVectorTrivialDecryptionParameters {
     pub lwe_dimension: LweDimension,
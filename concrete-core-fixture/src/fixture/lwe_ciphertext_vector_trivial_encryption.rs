@@ -9,10 +9,13 @@ use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
 use concrete_core::prelude::{
     LweCiphertextVectorEntity, LweCiphertextVectorTrivialEncryptionEngine, PlaintextVectorEntity,
 };
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `LweCiphertextVectorTrivialEncryptionEngine` trait.
 pub struct LweCiphertextVectorTrivialEncryptionFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct LweCiphertextVectorTrivialEncryptionParameters {
     pub lwe_dimension: LweDimension,
@@ -12,10 +12,13 @@ use crate::generation::prototyping::{
 use crate::generation::synthesizing::{SynthesizesLweCiphertext, SynthesizesLweSecretKey};
 use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
 use crate::raw::statistical_test::assert_noise_distribution;
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `LweCiphertextZeroEncryptionEngine` trait.
 pub struct LweCiphertextZeroEncryptionFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct LweCiphertextZeroEncryptionParameters {
     pub noise: Variance,
@@ -13,11 +13,14 @@ use concrete_core::prelude::{
     GlweSecretKeyEntity, LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity,
     LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysGenerationEngine, LweSecretKeyEntity,
 };
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the
 /// `LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysGenerationEngine` trait.
 pub struct LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysGenerationFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysGenerationParameters {
     pub noise: Variance,
@@ -7,10 +7,13 @@ use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount
 use concrete_core::prelude::{
     LweKeyswitchKeyEntity, LweKeyswitchKeyGenerationEngine, LweSecretKeyEntity,
 };
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `LweKeyswitchKeyGenerationEngine` trait.
 pub struct LweKeyswitchKeyGenerationFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct LweKeyswitchKeyGenerationParameters {
     pub noise: Variance,
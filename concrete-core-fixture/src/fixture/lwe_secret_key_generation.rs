@@ -3,10 +3,13 @@ use crate::generation::synthesizing::SynthesizesLweSecretKey;
 use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
 use concrete_commons::parameters::LweDimension;
 use concrete_core::prelude::{LweSecretKeyEntity, LweSecretKeyGenerationEngine};
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `LweSecretKeyGenerationEngine` trait.
 pub struct LweSecretKeyGenerationFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct LweSecretKeyGenerationParameters {
     pub lwe_dimension: LweDimension,
@@ -12,10 +12,13 @@ use concrete_core::prelude::{
     GlweSecretKeyEntity, LweSecretKeyEntity, LweSeededBootstrapKeyEntity,
     LweSeededBootstrapKeyGenerationEngine,
 };
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `LweSeededBootstrapKeyGenerationEngine` trait.
 pub struct LweSeededBootstrapKeyGenerationFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct LweSeededBootstrapKeyGenerationParameters {
     pub lwe_dimension: LweDimension,
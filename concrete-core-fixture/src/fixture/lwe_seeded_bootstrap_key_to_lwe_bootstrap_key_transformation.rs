@@ -15,11 +15,14 @@ use concrete_core::prelude::{
     GlweSecretKeyEntity, LweBootstrapKeyEntity, LweSecretKeyEntity, LweSeededBootstrapKeyEntity,
     LweSeededBootstrapKeyToLweBootstrapKeyTransformationEngine,
 };
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the
 /// `LweSeededBootstrapKeyToLweBootstrapKeyTransformationEngine` trait.
 pub struct LweSeededBootstrapKeyToLweBootstrapKeyTransformationFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct LweSeededToLweBootstrapKeyTransformationParameters {
     pub lwe_dimension: LweDimension,
@@ -15,10 +15,13 @@ use concrete_core::prelude::{
     LweSecretKeyEntity, LweSeededCiphertextEncryptionEngine, LweSeededCiphertextEntity,
     PlaintextEntity,
 };
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `LweSeededCiphertextEncryptionEngine` trait.
 pub struct LweSeededCiphertextEncryptionFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct LweSeededCiphertextEncryptionParameters {
     pub noise: Variance,
@@ -16,11 +16,14 @@ use concrete_core::prelude::{
     LweCiphertextEntity, LweSecretKeyEntity, LweSeededCiphertextEntity,
     LweSeededCiphertextToLweCiphertextTransformationEngine, PlaintextEntity,
 };
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the
 /// `LweSeededCiphertextToLweCiphertextTransformationEngine` trait.
 pub struct LweSeededCiphertextToLweCiphertextTransformationFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct LweSeededCiphertextToLweCiphertextTransformationParameters {
     pub noise: Variance,
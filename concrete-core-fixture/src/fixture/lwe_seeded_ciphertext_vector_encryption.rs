@@ -15,10 +15,13 @@ use concrete_core::prelude::{
     LweSecretKeyEntity, LweSeededCiphertextVectorEncryptionEngine, LweSeededCiphertextVectorEntity,
     PlaintextVectorEntity,
 };
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `LweSeededCiphertextEncryptionEngine` trait.
 pub struct LweSeededCiphertextVectorEncryptionFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct LweSeededCiphertextVectorEncryptionParameters {
     pub noise: Variance,
@@ -16,11 +16,14 @@ use concrete_core::prelude::{
     LweCiphertextVectorEntity, LweSecretKeyEntity, LweSeededCiphertextVectorEntity,
     LweSeededCiphertextVectorToLweCiphertextVectorTransformationEngine, PlaintextVectorEntity,
 };
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the
 /// `LweSeededCiphertextVectorToLweCiphertextVectorTransformationEngine` trait.
 pub struct LweSeededCiphertextVectorToLweCiphertextVectorTransformationFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct LweSeededCiphertextVectorToLweCiphertextVectorTransformationParameters {
     pub noise: Variance,
@@ -7,10 +7,13 @@ use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount
 use concrete_core::prelude::{
     LweSecretKeyEntity, LweSeededKeyswitchKeyEntity, LweSeededKeyswitchKeyGenerationEngine,
 };
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `LweSeededKeyswitchKeyGenerationEngine` trait.
 pub struct LweSeededKeyswitchKeyGenerationFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct LweSeededKeyswitchKeyGenerationParameters {
     pub noise: Variance,
@@ -10,11 +10,14 @@ use concrete_core::prelude::{
     LweKeyswitchKeyEntity, LweSecretKeyEntity, LweSeededKeyswitchKeyEntity,
     LweSeededKeyswitchKeyToLweKeyswitchKeyTransformationEngine,
 };
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the
 /// `LweSeededKeyswitchKeyToLweKeyswitchKeyTransformationEngine` trait.
 pub struct LweSeededKeyswitchKeyToLweKeyswitchKeyTransformationFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct LweSeededKeyswitchKeyToLweSeededKeyswitchKeyTransformationParameters {
     pub noise: Variance,
@@ -6,10 +6,13 @@ use concrete_commons::parameters::{GlweDimension, LweDimension, PolynomialSize};
 use concrete_core::prelude::{
     GlweSecretKeyEntity, LweSecretKeyEntity, LweToGlweSecretKeyTransformationEngine,
 };
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `LweToGlweSecretKeyTransformationEngine` trait.
 pub struct LweToGlweSecretKeyTransformationFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct LweToGlweSecretKeyTransformationParameters {
     pub lwe_dimension: LweDimension,
@@ -37,11 +37,23 @@
 //!
 //! In particular, once the [`Fixture`] mandatory methods and types are defined, the user can
 //! benefit from the default methods [`Fixture::sample`], [`Fixture::test`] or [`Fixture::stress`].
+//! The repetitions being independent, they can also be executed in parallel with
+//! [`Fixture::stress_parallel`].
 use crate::generation::{IntegerPrecision, Maker};
 use crate::{Repetitions, SampleSize};
 use concrete_core::prelude::AbstractEngine;
+use rayon::prelude::*;
+#[cfg(feature = "parameters_serialization")]
+use serde::de::DeserializeOwned;
+#[cfg(feature = "parameters_serialization")]
+use std::any::type_name;
+#[cfg(feature = "parameters_serialization")]
+use std::collections::HashMap;
 use std::fmt::Debug;
 use std::ops::BitAnd;
+use std::sync::atomic::{AtomicU64, Ordering};
+#[cfg(feature = "parameters_serialization")]
+use std::{env, fs};
 
 /// A trait providing a generic implementation of fixtures.
 ///
@@ -64,7 +76,7 @@ pub trait Fixture<
 >
 {
     /// A type containing the parameters needed to generate the execution context.
-    type Parameters: Debug;
+    type Parameters: FixtureParameters;
 
     /// A type containing the input prototypes generated at the level of the repetition (reused).
     type RepetitionPrototypes;
@@ -87,6 +99,22 @@ pub trait Fixture<
     /// A method which outputs an iterator over parameters.
     fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>>;
 
+    /// A method which outputs an iterator over the parameters actually used by the fixture.
+    ///
+    /// When the `parameters_serialization` feature is enabled and the `PARAMETERS_OVERRIDE_VAR`
+    /// environment variable is set, the parameters of the fixture are read from the JSON file it
+    /// points to, instead of being generated by [`Fixture::generate_parameters_iterator`]. See
+    /// `PARAMETERS_OVERRIDE_VAR` for the format of the file.
+    fn parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        #[cfg(feature = "parameters_serialization")]
+        if let Some(parameters) =
+            load_parameters_override::<Self::Parameters>(fixture_name::<Self>())
+        {
+            return Box::new(parameters.into_iter());
+        }
+        Self::generate_parameters_iterator()
+    }
+
     /// Generate a random set of repetition-level prototypes.
     fn generate_random_repetition_prototypes(
         parameters: &Self::Parameters,
@@ -146,12 +174,72 @@ pub trait Fixture<
         repetitions: Repetitions,
         sample_size: SampleSize,
     ) -> bool {
-        Self::generate_parameters_iterator()
+        Self::parameters_iterator()
             .map(|param| Self::stress(maker, engine, &param, repetitions, sample_size))
             .reduce(BitAnd::bitand)
             .unwrap()
     }
 
+    /// A method which verifies the statistical properties of a sample of engine executions, over
+    /// multiple randomly generated raw inputs, over multiple sets of parameters, executing the
+    /// repetitions in parallel.
+    ///
+    /// Each worker thread uses its own [`Maker`], and its own engine built by `engine_builder`.
+    /// Both are seeded with a secret forked for the thread, which is passed to `engine_builder`.
+    fn stress_all_parameters_parallel<EngineBuilder>(
+        engine_builder: EngineBuilder,
+        repetitions: Repetitions,
+        sample_size: SampleSize,
+    ) -> bool
+    where
+        Self::Parameters: Sync,
+        EngineBuilder: Fn(u128) -> Engine + Sync,
+    {
+        Self::parameters_iterator()
+            .map(|param| Self::stress_parallel(&engine_builder, &param, repetitions, sample_size))
+            .reduce(BitAnd::bitand)
+            .unwrap()
+    }
+
+    /// A method which verifies the statistical properties of a sample of engine executions, over
+    /// multiple randomly generated raw inputs, for a fixed set of parameters, executing the
+    /// repetitions in parallel.
+    ///
+    /// See [`Fixture::stress_all_parameters_parallel`] for how the makers and engines are built.
+    fn stress_parallel<EngineBuilder>(
+        engine_builder: &EngineBuilder,
+        parameters: &Self::Parameters,
+        repetitions: Repetitions,
+        sample_size: SampleSize,
+    ) -> bool
+    where
+        Self::Parameters: Sync,
+        EngineBuilder: Fn(u128) -> Engine + Sync,
+    {
+        // The secrets are forked from a counter, so that no two workers share their seeds.
+        let next_secret = AtomicU64::new(1);
+        (0..repetitions.0)
+            .into_par_iter()
+            .map_init(
+                || {
+                    let secret = next_secret.fetch_add(1, Ordering::Relaxed) as u128;
+                    (Maker::new(secret), engine_builder(secret))
+                },
+                |(maker, engine), _| {
+                    let repetition_prototypes =
+                        Self::generate_random_repetition_prototypes(parameters, maker);
+                    Self::test(
+                        maker,
+                        engine,
+                        parameters,
+                        &repetition_prototypes,
+                        sample_size,
+                    )
+                },
+            )
+            .all(|output| output)
+    }
+
     /// A method which verifies the statistical properties of a sample of engine executions, over
     /// multiple randomly generated raw inputs, for a fixed set of parameters.
     fn stress(
@@ -222,6 +310,74 @@ pub trait Fixture<
     }
 }
 
+/// A trait gathering the bounds of the parameters of the fixtures.
+///
+/// When the `parameters_serialization` feature is enabled, the parameters must be deserializable,
+/// so that they can be overridden from a file.
+#[cfg(feature = "parameters_serialization")]
+pub trait FixtureParameters: Debug + DeserializeOwned + 'static {}
+
+#[cfg(feature = "parameters_serialization")]
+impl<T> FixtureParameters for T where T: Debug + DeserializeOwned + 'static {}
+
+/// A trait gathering the bounds of the parameters of the fixtures.
+#[cfg(not(feature = "parameters_serialization"))]
+pub trait FixtureParameters: Debug + 'static {}
+
+#[cfg(not(feature = "parameters_serialization"))]
+impl<T> FixtureParameters for T where T: Debug + 'static {}
+
+/// The environment variable used to override the parameters of the fixtures.
+///
+/// When set, it must contain the path to a JSON file holding an object, which maps the names of
+/// the fixture types to arrays of parameters. For instance:
+/// ```json
+/// {
+///     "LweCiphertextEncryptionFixture": [
+///         { "noise": 0.00000001, "lwe_dimension": 630 }
+///     ]
+/// }
+/// ```
+/// The fixtures missing from the file keep using the parameters hard-coded in their
+/// [`Fixture::generate_parameters_iterator`] implementation.
+#[cfg(feature = "parameters_serialization")]
+pub const PARAMETERS_OVERRIDE_VAR: &str = "CONCRETE_CORE_FIXTURE_PARAMETERS";
+
+// Returns the name of the fixture type, without its module path.
+#[cfg(feature = "parameters_serialization")]
+fn fixture_name<F: ?Sized>() -> &'static str {
+    let name = type_name::<F>();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+// Returns the parameters of the given fixture found in the override file, if any.
+#[cfg(feature = "parameters_serialization")]
+fn load_parameters_override<Parameters: DeserializeOwned>(
+    fixture_name: &str,
+) -> Option<Vec<Parameters>> {
+    let path = env::var_os(PARAMETERS_OVERRIDE_VAR)?;
+    let content = fs::read_to_string(&path).unwrap_or_else(|err| {
+        panic!(
+            "Failed to read the fixture parameters file {:?}: {}",
+            path, err
+        )
+    });
+    let mut overrides: HashMap<String, serde_json::Value> = serde_json::from_str(&content)
+        .unwrap_or_else(|err| {
+            panic!(
+                "Failed to parse the fixture parameters file {:?}: {}",
+                path, err
+            )
+        });
+    let parameters = overrides.remove(fixture_name)?;
+    Some(serde_json::from_value(parameters).unwrap_or_else(|err| {
+        panic!(
+            "Invalid parameters for {} in the fixture parameters file {:?}: {}",
+            fixture_name, path, err
+        )
+    }))
+}
+
 mod cleartext_creation;
 pub use cleartext_creation::*;
 
@@ -393,6 +549,15 @@ pub use lwe_ciphertext_vector_fusing_addition::*;
 mod lwe_ciphertext_vector_discarding_addition;
 pub use lwe_ciphertext_vector_discarding_addition::*;
 
+mod lwe_ciphertext_vector_discarding_permutation;
+pub use lwe_ciphertext_vector_discarding_permutation::*;
+
+mod lwe_ciphertext_vector_discarding_gathering;
+pub use lwe_ciphertext_vector_discarding_gathering::*;
+
+mod lwe_ciphertext_vector_discarding_scattering;
+pub use lwe_ciphertext_vector_discarding_scattering::*;
+
 mod lwe_ciphertext_vector_fusing_subtraction;
 pub use lwe_ciphertext_vector_fusing_subtraction::*;
 
@@ -411,6 +576,9 @@ pub use lwe_ciphertext_discarding_bootstrap_1::*;
 mod lwe_ciphertext_discarding_bootstrap_2;
 pub use lwe_ciphertext_discarding_bootstrap_2::*;
 
+mod lwe_ciphertext_discarding_stochastic_bootstrap;
+pub use lwe_ciphertext_discarding_stochastic_bootstrap::*;
+
 mod lwe_ciphertext_vector_discarding_bootstrap_1;
 pub use lwe_ciphertext_vector_discarding_bootstrap_1::*;
 
@@ -426,6 +594,9 @@ pub use lwe_ciphertext_vector_discarding_bootstrap_2::*;
 mod glwe_ciphertext_ggsw_ciphertext_discarding_external_product;
 pub use glwe_ciphertext_ggsw_ciphertext_discarding_external_product::*;
 
+mod glwe_ciphertext_ggsw_ciphertext_discarding_external_product_add;
+pub use glwe_ciphertext_ggsw_ciphertext_discarding_external_product_add::*;
+
 mod glwe_ciphertext_ggsw_ciphertext_external_product;
 pub use glwe_ciphertext_ggsw_ciphertext_external_product::*;
 
@@ -521,3 +692,9 @@ pub use lwe_ciphertext_vector_glwe_ciphertext_discarding_private_functional_pack
 
 mod glwe_ciphertext_conversion;
 pub use glwe_ciphertext_conversion::*;
+
+mod glwe_ciphertext_relinearization;
+pub use glwe_ciphertext_relinearization::*;
+
+mod glwe_ciphertext_leveled_multiplication;
+pub use glwe_ciphertext_leveled_multiplication::*;
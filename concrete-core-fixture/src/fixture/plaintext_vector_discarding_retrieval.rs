@@ -8,10 +8,13 @@ use concrete_commons::dispersion::Variance;
 use concrete_commons::parameters::PlaintextCount;
 
 use concrete_core::prelude::{PlaintextVectorDiscardingRetrievalEngine, PlaintextVectorEntity};
+#[cfg(feature = "parameters_serialization")]
+use serde::Deserialize;
 
 /// A fixture for the types implementing the `PlaintextVectorDiscardingRetrievalEngine` trait.
 pub struct PlaintextVectorDiscardingRetrievalFixture;
 
+#[cfg_attr(feature = "parameters_serialization", derive(Deserialize))]
 #[derive(Debug)]
 pub struct PlaintextVectorDiscardingRetrievalParameters {
     count: PlaintextCount,
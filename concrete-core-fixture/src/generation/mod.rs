@@ -18,12 +18,12 @@
 //! Of course, we can also go in the reverse direction by _unsynthesizing_ entities into
 //! prototypical ones, and extracting _raw_ outputs.
 //! Also, the fixture developer should ensure that
-//! the entities are destroyed after the execution of the engine. This is actually only
-//! necessary for views entities, to avoid memory leak, but we don't have any simple way to track
-//! which entities have view implementations, so we have to call functions to destroy the data.
-//! This can be done by the
-//! [`Maker`] instance and the `Synthesizes*` traits, which contains functions to destroy data
-//! (which are empty for all entities that are not actually views).
+//! the entities are destroyed after the execution of the engine. Most entities release their
+//! memory (including device memory) when dropped, but view entities are synthesized over leaked
+//! containers, which must be reclaimed explicitly to avoid memory leaks. Since we don't have any
+//! simple way to track which entities have view implementations, the `Synthesizes*` traits expose
+//! `destroy_*` functions, which simply drop the entity by default, and are only overridden by the
+//! [`Maker`] for view entities.
 use crate::raw::generation::RawUnsignedIntegers;
 use concrete_core::prelude::{AbstractEngine, DefaultEngine, DefaultParallelEngine};
 use concrete_csprng::seeders::UnixSeeder;
@@ -79,20 +79,43 @@ pub struct Maker {
     fftw_engine: concrete_core::backends::fftw::engines::FftwEngine,
     #[cfg(feature = "backend_fft")]
     fft_engine: concrete_core::backends::fft::engines::FftEngine,
+    #[cfg(feature = "backend_ntt")]
+    ntt_engine: concrete_core::backends::ntt::engines::NttEngine,
+    #[cfg(all(feature = "backend_fft", feature = "backend_ntt"))]
+    auto_ntt_engine: concrete_core::backends::ntt::engines::AutoNttEngine,
     #[cfg(all(feature = "backend_cuda", not(feature = "_ci_do_not_compile")))]
     cuda_engine: concrete_core::backends::cuda::engines::CudaEngine,
 }
 
 impl Default for Maker {
     fn default() -> Self {
+        Maker::new(0)
+    }
+}
+
+impl Maker {
+    /// Creates a maker whose engines are seeded by a [`UnixSeeder`] using the given secret.
+    ///
+    /// Makers used concurrently should be given different secrets, to fork their seeds.
+    pub fn new(secret: u128) -> Self {
         Maker {
-            default_engine: DefaultEngine::new(Box::new(UnixSeeder::new(0))).unwrap(),
-            default_parallel_engine: DefaultParallelEngine::new(Box::new(UnixSeeder::new(0)))
+            default_engine: DefaultEngine::new(Box::new(UnixSeeder::new(secret))).unwrap(),
+            default_parallel_engine: DefaultParallelEngine::new(Box::new(UnixSeeder::new(secret)))
                 .unwrap(),
             #[cfg(feature = "backend_fftw")]
             fftw_engine: concrete_core::backends::fftw::engines::FftwEngine::new(()).unwrap(),
             #[cfg(feature = "backend_fft")]
             fft_engine: concrete_core::backends::fft::engines::FftEngine::new(()).unwrap(),
+            #[cfg(feature = "backend_ntt")]
+            ntt_engine: concrete_core::backends::ntt::engines::NttEngine::new(Box::new(
+                UnixSeeder::new(secret),
+            ))
+            .unwrap(),
+            #[cfg(all(feature = "backend_fft", feature = "backend_ntt"))]
+            auto_ntt_engine: concrete_core::backends::ntt::engines::AutoNttEngine::new(Box::new(
+                UnixSeeder::new(secret),
+            ))
+            .unwrap(),
             #[cfg(all(feature = "backend_cuda", not(feature = "_ci_do_not_compile")))]
             cuda_engine: concrete_core::backends::cuda::engines::CudaEngine::new(()).unwrap(),
         }
@@ -0,0 +1,31 @@
+#[cfg(feature = "backend_fftw")]
+use crate::generation::{BinaryKeyDistribution, Precision32, Precision64};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker};
+#[cfg(feature = "backend_fftw")]
+use concrete_core::prelude::{GlweTensorProductCiphertext32, GlweTensorProductCiphertext64};
+
+/// A trait implemented by GLWE tensor product ciphertext prototypes.
+pub trait GlweTensorProductCiphertextPrototype {
+    type KeyDistribution: KeyDistributionMarker;
+    type Precision: IntegerPrecision;
+}
+
+// The prototypes are generated with the fftw backend, which computes the tensor products.
+
+/// A type representing the prototype of a 32 bit binary GLWE tensor product ciphertext entity.
+#[cfg(feature = "backend_fftw")]
+pub struct ProtoBinaryGlweTensorProductCiphertext32(pub(crate) GlweTensorProductCiphertext32);
+#[cfg(feature = "backend_fftw")]
+impl GlweTensorProductCiphertextPrototype for ProtoBinaryGlweTensorProductCiphertext32 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision32;
+}
+
+/// A type representing the prototype of a 64 bit binary GLWE tensor product ciphertext entity.
+#[cfg(feature = "backend_fftw")]
+pub struct ProtoBinaryGlweTensorProductCiphertext64(pub(crate) GlweTensorProductCiphertext64);
+#[cfg(feature = "backend_fftw")]
+impl GlweTensorProductCiphertextPrototype for ProtoBinaryGlweTensorProductCiphertext64 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision64;
+}
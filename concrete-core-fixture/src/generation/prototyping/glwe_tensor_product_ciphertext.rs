@@ -0,0 +1,76 @@
+use crate::generation::prototypes::GlweTensorProductCiphertextPrototype;
+use crate::generation::prototyping::glwe_ciphertext::PrototypesGlweCiphertext;
+use crate::generation::{IntegerPrecision, KeyDistributionMarker};
+use concrete_commons::parameters::ScalingFactor;
+
+/// A trait allowing to manipulate GLWE tensor product ciphertext prototypes.
+pub trait PrototypesGlweTensorProductCiphertext<
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+>: PrototypesGlweCiphertext<Precision, KeyDistribution>
+{
+    type GlweTensorProductCiphertextProto: GlweTensorProductCiphertextPrototype<
+        Precision = Precision,
+        KeyDistribution = KeyDistribution,
+    >;
+    fn tensor_product_glwe_ciphertexts(
+        &mut self,
+        ciphertext_1: &Self::GlweCiphertextProto,
+        ciphertext_2: &Self::GlweCiphertextProto,
+        scaling_factor: ScalingFactor,
+    ) -> Self::GlweTensorProductCiphertextProto;
+}
+
+// The tensor product of GLWE ciphertexts is only implemented by the fftw backend.
+#[cfg(feature = "backend_fftw")]
+mod backend_fftw {
+    use crate::generation::prototypes::{
+        ProtoBinaryGlweTensorProductCiphertext32, ProtoBinaryGlweTensorProductCiphertext64,
+    };
+    use crate::generation::prototyping::PrototypesGlweTensorProductCiphertext;
+    use crate::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
+    use concrete_commons::parameters::ScalingFactor;
+    use concrete_core::prelude::GlweCiphertextTensorProductSameKeyEngine;
+
+    impl PrototypesGlweTensorProductCiphertext<Precision32, BinaryKeyDistribution> for Maker {
+        type GlweTensorProductCiphertextProto = ProtoBinaryGlweTensorProductCiphertext32;
+
+        fn tensor_product_glwe_ciphertexts(
+            &mut self,
+            ciphertext_1: &Self::GlweCiphertextProto,
+            ciphertext_2: &Self::GlweCiphertextProto,
+            scaling_factor: ScalingFactor,
+        ) -> Self::GlweTensorProductCiphertextProto {
+            ProtoBinaryGlweTensorProductCiphertext32(
+                self.fftw_engine
+                    .tensor_product_glwe_ciphertext_same_key(
+                        &ciphertext_1.0,
+                        &ciphertext_2.0,
+                        scaling_factor,
+                    )
+                    .unwrap(),
+            )
+        }
+    }
+
+    impl PrototypesGlweTensorProductCiphertext<Precision64, BinaryKeyDistribution> for Maker {
+        type GlweTensorProductCiphertextProto = ProtoBinaryGlweTensorProductCiphertext64;
+
+        fn tensor_product_glwe_ciphertexts(
+            &mut self,
+            ciphertext_1: &Self::GlweCiphertextProto,
+            ciphertext_2: &Self::GlweCiphertextProto,
+            scaling_factor: ScalingFactor,
+        ) -> Self::GlweTensorProductCiphertextProto {
+            ProtoBinaryGlweTensorProductCiphertext64(
+                self.fftw_engine
+                    .tensor_product_glwe_ciphertext_same_key(
+                        &ciphertext_1.0,
+                        &ciphertext_2.0,
+                        scaling_factor,
+                    )
+                    .unwrap(),
+            )
+        }
+    }
+}
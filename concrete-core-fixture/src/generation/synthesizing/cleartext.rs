@@ -10,7 +10,7 @@ where
 {
     fn synthesize_cleartext(&mut self, prototype: &Self::CleartextProto) -> Cleartext;
     fn unsynthesize_cleartext(&mut self, entity: Cleartext) -> Self::CleartextProto;
-    fn destroy_cleartext(&mut self, entity: Cleartext);
+    fn destroy_cleartext(&mut self, _entity: Cleartext) {}
 }
 
 mod backend_default {
@@ -27,8 +27,6 @@ mod backend_default {
         fn unsynthesize_cleartext(&mut self, entity: Cleartext32) -> Self::CleartextProto {
             ProtoCleartext32(entity)
         }
-
-        fn destroy_cleartext(&mut self, _entity: Cleartext32) {}
     }
 
     impl SynthesizesCleartext<Precision64, Cleartext64> for Maker {
@@ -39,7 +37,5 @@ mod backend_default {
         fn unsynthesize_cleartext(&mut self, entity: Cleartext64) -> Self::CleartextProto {
             ProtoCleartext64(entity)
         }
-
-        fn destroy_cleartext(&mut self, _entity: Cleartext64) {}
     }
 }
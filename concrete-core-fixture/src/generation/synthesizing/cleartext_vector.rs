@@ -16,7 +16,7 @@ where
         &mut self,
         entity: CleartextVector,
     ) -> Self::CleartextVectorProto;
-    fn destroy_cleartext_vector(&mut self, entity: CleartextVector);
+    fn destroy_cleartext_vector(&mut self, _entity: CleartextVector) {}
 }
 
 mod backend_default {
@@ -39,8 +39,6 @@ mod backend_default {
         ) -> Self::CleartextVectorProto {
             ProtoCleartextVector32(entity)
         }
-
-        fn destroy_cleartext_vector(&mut self, _entity: CleartextVector32) {}
     }
 
     impl SynthesizesCleartextVector<Precision64, CleartextVector64> for Maker {
@@ -57,7 +55,5 @@ mod backend_default {
         ) -> Self::CleartextVectorProto {
             ProtoCleartextVector64(entity)
         }
-
-        fn destroy_cleartext_vector(&mut self, _entity: CleartextVector64) {}
     }
 }
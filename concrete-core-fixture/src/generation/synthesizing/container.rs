@@ -9,7 +9,7 @@ where
 {
     fn synthesize_container(&mut self, prototype: &Self::ContainerProto) -> Container;
     fn unsynthesize_container(&mut self, container: Container) -> Self::ContainerProto;
-    fn destroy_container(&mut self, container: Container);
+    fn destroy_container(&mut self, _container: Container) {}
 }
 
 mod backend_default {
@@ -25,8 +25,6 @@ mod backend_default {
         fn unsynthesize_container(&mut self, container: Vec<u32>) -> Self::ContainerProto {
             ProtoVec32(container)
         }
-
-        fn destroy_container(&mut self, _container: Vec<u32>) {}
     }
 
     impl SynthesizesContainer<Precision64, Vec<u64>> for Maker {
@@ -37,8 +35,6 @@ mod backend_default {
         fn unsynthesize_container(&mut self, container: Vec<u64>) -> Self::ContainerProto {
             ProtoVec64(container)
         }
-
-        fn destroy_container(&mut self, _container: Vec<u64>) {}
     }
 
     impl<'a> SynthesizesContainer<Precision32, &'a [u32]> for Maker {
@@ -16,14 +16,17 @@ pub trait SynthesizesGgswCiphertext<
     ) -> GgswCiphertext;
     fn unsynthesize_ggsw_ciphertext(&mut self, entity: GgswCiphertext)
         -> Self::GgswCiphertextProto;
-    fn destroy_ggsw_ciphertext(&mut self, entity: GgswCiphertext);
+    fn destroy_ggsw_ciphertext(&mut self, _entity: GgswCiphertext) {}
 }
 
 mod backend_default {
     use crate::generation::prototypes::{ProtoBinaryGgswCiphertext32, ProtoBinaryGgswCiphertext64};
     use crate::generation::synthesizing::SynthesizesGgswCiphertext;
     use crate::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
-    use concrete_core::prelude::{GgswCiphertext32, GgswCiphertext64};
+    use concrete_core::prelude::{
+        GgswCiphertext32, GgswCiphertext64, GgswCiphertextConversionEngine,
+        PreparedGgswCiphertext32, PreparedGgswCiphertext64,
+    };
 
     impl SynthesizesGgswCiphertext<Precision32, BinaryKeyDistribution, GgswCiphertext32> for Maker {
         fn synthesize_ggsw_ciphertext(
@@ -39,8 +42,6 @@ mod backend_default {
         ) -> Self::GgswCiphertextProto {
             ProtoBinaryGgswCiphertext32(entity)
         }
-
-        fn destroy_ggsw_ciphertext(&mut self, _entity: GgswCiphertext32) {}
     }
 
     impl SynthesizesGgswCiphertext<Precision64, BinaryKeyDistribution, GgswCiphertext64> for Maker {
@@ -57,8 +58,50 @@ mod backend_default {
         ) -> Self::GgswCiphertextProto {
             ProtoBinaryGgswCiphertext64(entity)
         }
+    }
+
+    impl SynthesizesGgswCiphertext<Precision32, BinaryKeyDistribution, PreparedGgswCiphertext32>
+        for Maker
+    {
+        fn synthesize_ggsw_ciphertext(
+            &mut self,
+            prototype: &Self::GgswCiphertextProto,
+        ) -> PreparedGgswCiphertext32 {
+            self.default_engine
+                .convert_ggsw_ciphertext(&prototype.0)
+                .unwrap()
+        }
+
+        fn unsynthesize_ggsw_ciphertext(
+            &mut self,
+            _entity: PreparedGgswCiphertext32,
+        ) -> Self::GgswCiphertextProto {
+            unimplemented!(
+                "The backward conversion of prepared GGSW ciphertexts is not implemented"
+            );
+        }
+    }
+
+    impl SynthesizesGgswCiphertext<Precision64, BinaryKeyDistribution, PreparedGgswCiphertext64>
+        for Maker
+    {
+        fn synthesize_ggsw_ciphertext(
+            &mut self,
+            prototype: &Self::GgswCiphertextProto,
+        ) -> PreparedGgswCiphertext64 {
+            self.default_engine
+                .convert_ggsw_ciphertext(&prototype.0)
+                .unwrap()
+        }
 
-        fn destroy_ggsw_ciphertext(&mut self, _entity: GgswCiphertext64) {}
+        fn unsynthesize_ggsw_ciphertext(
+            &mut self,
+            _entity: PreparedGgswCiphertext64,
+        ) -> Self::GgswCiphertextProto {
+            unimplemented!(
+                "The backward conversion of prepared GGSW ciphertexts is not implemented"
+            );
+        }
     }
 }
 
@@ -89,8 +132,6 @@ mod backend_fftw {
             // FIXME:
             unimplemented!("The backward fourier conversion was not yet implemented");
         }
-
-        fn destroy_ggsw_ciphertext(&mut self, _entity: FftwFourierGgswCiphertext32) {}
     }
 
     impl SynthesizesGgswCiphertext<Precision64, BinaryKeyDistribution, FftwFourierGgswCiphertext64>
@@ -112,8 +153,6 @@ mod backend_fftw {
             // FIXME:
             unimplemented!("The backward fourier conversion was not yet implemented");
         }
-
-        fn destroy_ggsw_ciphertext(&mut self, _entity: FftwFourierGgswCiphertext64) {}
     }
 }
 
@@ -144,8 +183,6 @@ mod backend_fft {
             // FIXME:
             unimplemented!("The backward fourier conversion was not yet implemented");
         }
-
-        fn destroy_ggsw_ciphertext(&mut self, _entity: FftFourierGgswCiphertext32) {}
     }
 
     impl SynthesizesGgswCiphertext<Precision64, BinaryKeyDistribution, FftFourierGgswCiphertext64>
@@ -167,7 +204,5 @@ mod backend_fft {
             // FIXME:
             unimplemented!("The backward fourier conversion was not yet implemented");
         }
-
-        fn destroy_ggsw_ciphertext(&mut self, _entity: FftFourierGgswCiphertext64) {}
     }
 }
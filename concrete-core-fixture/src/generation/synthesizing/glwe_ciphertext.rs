@@ -16,7 +16,7 @@ pub trait SynthesizesGlweCiphertext<
     ) -> GlweCiphertext;
     fn unsynthesize_glwe_ciphertext(&mut self, entity: GlweCiphertext)
         -> Self::GlweCiphertextProto;
-    fn destroy_glwe_ciphertext(&mut self, entity: GlweCiphertext);
+    fn destroy_glwe_ciphertext(&mut self, _entity: GlweCiphertext) {}
 }
 
 mod backend_default {
@@ -39,8 +39,6 @@ mod backend_default {
         ) -> Self::GlweCiphertextProto {
             ProtoBinaryGlweCiphertext32(entity)
         }
-
-        fn destroy_glwe_ciphertext(&mut self, _entity: GlweCiphertext32) {}
     }
 
     impl SynthesizesGlweCiphertext<Precision64, BinaryKeyDistribution, GlweCiphertext64> for Maker {
@@ -57,8 +55,6 @@ mod backend_default {
         ) -> Self::GlweCiphertextProto {
             ProtoBinaryGlweCiphertext64(entity)
         }
-
-        fn destroy_glwe_ciphertext(&mut self, _entity: GlweCiphertext64) {}
     }
 
     use concrete_core::prelude::{
@@ -291,8 +287,6 @@ mod backend_fftw {
             let proto = self.fftw_engine.convert_glwe_ciphertext(&entity).unwrap();
             ProtoBinaryGlweCiphertext32(proto)
         }
-
-        fn destroy_glwe_ciphertext(&mut self, _entity: FftwFourierGlweCiphertext32) {}
     }
 
     impl SynthesizesGlweCiphertext<Precision64, BinaryKeyDistribution, FftwFourierGlweCiphertext64>
@@ -314,8 +308,6 @@ mod backend_fftw {
             let proto = self.fftw_engine.convert_glwe_ciphertext(&entity).unwrap();
             ProtoBinaryGlweCiphertext64(proto)
         }
-
-        fn destroy_glwe_ciphertext(&mut self, _entity: FftwFourierGlweCiphertext64) {}
     }
 }
 
@@ -345,8 +337,6 @@ mod backend_cuda {
             let proto = self.cuda_engine.convert_glwe_ciphertext(&entity).unwrap();
             ProtoBinaryGlweCiphertext32(proto)
         }
-
-        fn destroy_glwe_ciphertext(&mut self, _entity: CudaGlweCiphertext32) {}
     }
 
     impl SynthesizesGlweCiphertext<Precision64, BinaryKeyDistribution, CudaGlweCiphertext64> for Maker {
@@ -366,7 +356,5 @@ mod backend_cuda {
             let proto = self.cuda_engine.convert_glwe_ciphertext(&entity).unwrap();
             ProtoBinaryGlweCiphertext64(proto)
         }
-
-        fn destroy_glwe_ciphertext(&mut self, _entity: CudaGlweCiphertext64) {}
     }
 }
@@ -18,7 +18,7 @@ pub trait SynthesizesGlweCiphertextVector<
         &mut self,
         entity: GlweCiphertextVector,
     ) -> Self::GlweCiphertextVectorProto;
-    fn destroy_glwe_ciphertext_vector(&mut self, entity: GlweCiphertextVector);
+    fn destroy_glwe_ciphertext_vector(&mut self, _entity: GlweCiphertextVector) {}
 }
 
 mod backend_default {
@@ -45,8 +45,6 @@ mod backend_default {
         ) -> Self::GlweCiphertextVectorProto {
             ProtoBinaryGlweCiphertextVector32(entity)
         }
-
-        fn destroy_glwe_ciphertext_vector(&mut self, _entity: GlweCiphertextVector32) {}
     }
 
     impl SynthesizesGlweCiphertextVector<Precision64, BinaryKeyDistribution, GlweCiphertextVector64>
@@ -65,8 +63,6 @@ mod backend_default {
         ) -> Self::GlweCiphertextVectorProto {
             ProtoBinaryGlweCiphertextVector64(entity)
         }
-
-        fn destroy_glwe_ciphertext_vector(&mut self, _entity: GlweCiphertextVector64) {}
     }
 }
 
@@ -108,8 +104,6 @@ mod backend_cuda {
                 .unwrap();
             ProtoBinaryGlweCiphertextVector32(proto)
         }
-
-        fn destroy_glwe_ciphertext_vector(&mut self, _entity: CudaGlweCiphertextVector32) {}
     }
 
     impl
@@ -138,7 +132,5 @@ mod backend_cuda {
                 .unwrap();
             ProtoBinaryGlweCiphertextVector64(proto)
         }
-
-        fn destroy_glwe_ciphertext_vector(&mut self, _entity: CudaGlweCiphertextVector64) {}
     }
 }
@@ -0,0 +1,75 @@
+use crate::generation::prototyping::PrototypesGlweRelinearizationKey;
+use crate::generation::{IntegerPrecision, KeyDistributionMarker};
+use concrete_core::prelude::GlweRelinearizationKeyEntity;
+
+/// A trait allowing to synthesize an actual GlweRelinearizationKeyEntity from a prototype.
+pub trait SynthesizesGlweRelinearizationKey<
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    GlweRelinearizationKey,
+>: PrototypesGlweRelinearizationKey<Precision, KeyDistribution> where
+    GlweRelinearizationKey: GlweRelinearizationKeyEntity,
+{
+    fn synthesize_glwe_relinearization_key(
+        &mut self,
+        prototype: &Self::GlweRelinearizationKeyProto,
+    ) -> GlweRelinearizationKey;
+    fn unsynthesize_glwe_relinearization_key(
+        &mut self,
+        entity: GlweRelinearizationKey,
+    ) -> Self::GlweRelinearizationKeyProto;
+    fn destroy_glwe_relinearization_key(&mut self, _entity: GlweRelinearizationKey) {}
+}
+
+mod backend_default {
+    use crate::generation::prototypes::{
+        ProtoBinaryGlweRelinearizationKey32, ProtoBinaryGlweRelinearizationKey64,
+    };
+    use crate::generation::synthesizing::SynthesizesGlweRelinearizationKey;
+    use crate::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
+    use concrete_core::prelude::{GlweRelinearizationKey32, GlweRelinearizationKey64};
+
+    impl
+        SynthesizesGlweRelinearizationKey<
+            Precision32,
+            BinaryKeyDistribution,
+            GlweRelinearizationKey32,
+        > for Maker
+    {
+        fn synthesize_glwe_relinearization_key(
+            &mut self,
+            prototype: &Self::GlweRelinearizationKeyProto,
+        ) -> GlweRelinearizationKey32 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_glwe_relinearization_key(
+            &mut self,
+            entity: GlweRelinearizationKey32,
+        ) -> Self::GlweRelinearizationKeyProto {
+            ProtoBinaryGlweRelinearizationKey32(entity)
+        }
+    }
+
+    impl
+        SynthesizesGlweRelinearizationKey<
+            Precision64,
+            BinaryKeyDistribution,
+            GlweRelinearizationKey64,
+        > for Maker
+    {
+        fn synthesize_glwe_relinearization_key(
+            &mut self,
+            prototype: &Self::GlweRelinearizationKeyProto,
+        ) -> GlweRelinearizationKey64 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_glwe_relinearization_key(
+            &mut self,
+            entity: GlweRelinearizationKey64,
+        ) -> Self::GlweRelinearizationKeyProto {
+            ProtoBinaryGlweRelinearizationKey64(entity)
+        }
+    }
+}
@@ -13,7 +13,7 @@ pub trait SynthesizesGlweSecretKey<
     fn synthesize_glwe_secret_key(&mut self, prototype: &Self::GlweSecretKeyProto)
         -> GlweSecretKey;
     fn unsynthesize_glwe_secret_key(&mut self, entity: GlweSecretKey) -> Self::GlweSecretKeyProto;
-    fn destroy_glwe_secret_key(&mut self, entity: GlweSecretKey);
+    fn destroy_glwe_secret_key(&mut self, _entity: GlweSecretKey) {}
 }
 
 mod backend_default {
@@ -36,8 +36,6 @@ mod backend_default {
         ) -> Self::GlweSecretKeyProto {
             ProtoBinaryGlweSecretKey32(entity)
         }
-
-        fn destroy_glwe_secret_key(&mut self, _entity: GlweSecretKey32) {}
     }
 
     impl SynthesizesGlweSecretKey<Precision64, BinaryKeyDistribution, GlweSecretKey64> for Maker {
@@ -54,7 +52,5 @@ mod backend_default {
         ) -> Self::GlweSecretKeyProto {
             ProtoBinaryGlweSecretKey64(entity)
         }
-
-        fn destroy_glwe_secret_key(&mut self, _entity: GlweSecretKey64) {}
     }
 }
@@ -18,7 +18,7 @@ pub trait SynthesizesGlweSeededCiphertext<
         &mut self,
         entity: GlweSeededCiphertext,
     ) -> Self::GlweSeededCiphertextProto;
-    fn destroy_glwe_seeded_ciphertext(&mut self, entity: GlweSeededCiphertext);
+    fn destroy_glwe_seeded_ciphertext(&mut self, _entity: GlweSeededCiphertext) {}
 }
 
 mod backend_default {
@@ -45,8 +45,6 @@ mod backend_default {
         ) -> Self::GlweSeededCiphertextProto {
             ProtoBinaryGlweSeededCiphertext32(entity)
         }
-
-        fn destroy_glwe_seeded_ciphertext(&mut self, _entity: GlweSeededCiphertext32) {}
     }
 
     impl SynthesizesGlweSeededCiphertext<Precision64, BinaryKeyDistribution, GlweSeededCiphertext64>
@@ -65,7 +63,5 @@ mod backend_default {
         ) -> Self::GlweSeededCiphertextProto {
             ProtoBinaryGlweSeededCiphertext64(entity)
         }
-
-        fn destroy_glwe_seeded_ciphertext(&mut self, _entity: GlweSeededCiphertext64) {}
     }
 }
@@ -18,7 +18,7 @@ pub trait SynthesizesGlweSeededCiphertextVector<
         &mut self,
         entity: GlweSeededCiphertextVector,
     ) -> Self::GlweSeededCiphertextVectorProto;
-    fn destroy_glwe_seeded_ciphertext_vector(&mut self, entity: GlweSeededCiphertextVector);
+    fn destroy_glwe_seeded_ciphertext_vector(&mut self, _entity: GlweSeededCiphertextVector) {}
 }
 
 mod backend_default {
@@ -49,9 +49,6 @@ mod backend_default {
         ) -> Self::GlweSeededCiphertextVectorProto {
             ProtoBinaryGlweSeededCiphertextVector32(entity)
         }
-
-        fn destroy_glwe_seeded_ciphertext_vector(&mut self, _entity: GlweSeededCiphertextVector32) {
-        }
     }
 
     impl
@@ -74,8 +71,5 @@ mod backend_default {
         ) -> Self::GlweSeededCiphertextVectorProto {
             ProtoBinaryGlweSeededCiphertextVector64(entity)
         }
-
-        fn destroy_glwe_seeded_ciphertext_vector(&mut self, _entity: GlweSeededCiphertextVector64) {
-        }
     }
 }
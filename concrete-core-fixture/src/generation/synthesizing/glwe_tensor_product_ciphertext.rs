@@ -0,0 +1,77 @@
+use crate::generation::prototyping::PrototypesGlweTensorProductCiphertext;
+use crate::generation::{IntegerPrecision, KeyDistributionMarker};
+use concrete_core::prelude::GlweTensorProductCiphertextEntity;
+
+/// A trait allowing to synthesize an actual GlweTensorProductCiphertextEntity from a prototype.
+pub trait SynthesizesGlweTensorProductCiphertext<
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    GlweTensorProductCiphertext,
+>: PrototypesGlweTensorProductCiphertext<Precision, KeyDistribution> where
+    GlweTensorProductCiphertext: GlweTensorProductCiphertextEntity,
+{
+    fn synthesize_glwe_tensor_product_ciphertext(
+        &mut self,
+        prototype: &Self::GlweTensorProductCiphertextProto,
+    ) -> GlweTensorProductCiphertext;
+    fn unsynthesize_glwe_tensor_product_ciphertext(
+        &mut self,
+        entity: GlweTensorProductCiphertext,
+    ) -> Self::GlweTensorProductCiphertextProto;
+    fn destroy_glwe_tensor_product_ciphertext(&mut self, _entity: GlweTensorProductCiphertext) {}
+}
+
+// The prototypes of GLWE tensor product ciphertexts are generated with the fftw backend.
+#[cfg(feature = "backend_fftw")]
+mod backend_default {
+    use crate::generation::prototypes::{
+        ProtoBinaryGlweTensorProductCiphertext32, ProtoBinaryGlweTensorProductCiphertext64,
+    };
+    use crate::generation::synthesizing::SynthesizesGlweTensorProductCiphertext;
+    use crate::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
+    use concrete_core::prelude::{GlweTensorProductCiphertext32, GlweTensorProductCiphertext64};
+
+    impl
+        SynthesizesGlweTensorProductCiphertext<
+            Precision32,
+            BinaryKeyDistribution,
+            GlweTensorProductCiphertext32,
+        > for Maker
+    {
+        fn synthesize_glwe_tensor_product_ciphertext(
+            &mut self,
+            prototype: &Self::GlweTensorProductCiphertextProto,
+        ) -> GlweTensorProductCiphertext32 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_glwe_tensor_product_ciphertext(
+            &mut self,
+            entity: GlweTensorProductCiphertext32,
+        ) -> Self::GlweTensorProductCiphertextProto {
+            ProtoBinaryGlweTensorProductCiphertext32(entity)
+        }
+    }
+
+    impl
+        SynthesizesGlweTensorProductCiphertext<
+            Precision64,
+            BinaryKeyDistribution,
+            GlweTensorProductCiphertext64,
+        > for Maker
+    {
+        fn synthesize_glwe_tensor_product_ciphertext(
+            &mut self,
+            prototype: &Self::GlweTensorProductCiphertextProto,
+        ) -> GlweTensorProductCiphertext64 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_glwe_tensor_product_ciphertext(
+            &mut self,
+            entity: GlweTensorProductCiphertext64,
+        ) -> Self::GlweTensorProductCiphertextProto {
+            ProtoBinaryGlweTensorProductCiphertext64(entity)
+        }
+    }
+}
@@ -19,7 +19,7 @@ pub trait SynthesizesLweBootstrapKey<
         &mut self,
         entity: LweBootstrapKey,
     ) -> Self::LweBootstrapKeyProto;
-    fn destroy_lwe_bootstrap_key(&mut self, entity: LweBootstrapKey);
+    fn destroy_lwe_bootstrap_key(&mut self, _entity: LweBootstrapKey) {}
 }
 
 mod backend_default {
@@ -51,8 +51,6 @@ mod backend_default {
         ) -> Self::LweBootstrapKeyProto {
             ProtoBinaryBinaryLweBootstrapKey32(entity)
         }
-
-        fn destroy_lwe_bootstrap_key(&mut self, _entity: LweBootstrapKey32) {}
     }
 
     impl
@@ -76,8 +74,6 @@ mod backend_default {
         ) -> Self::LweBootstrapKeyProto {
             ProtoBinaryBinaryLweBootstrapKey64(entity)
         }
-
-        fn destroy_lwe_bootstrap_key(&mut self, _entity: LweBootstrapKey64) {}
     }
 
     use concrete_core::prelude::{
@@ -416,8 +412,6 @@ mod backend_fftw {
         ) -> Self::LweBootstrapKeyProto {
             todo!()
         }
-
-        fn destroy_lwe_bootstrap_key(&mut self, _entity: FftwFourierLweBootstrapKey32) {}
     }
 
     impl
@@ -443,8 +437,6 @@ mod backend_fftw {
         ) -> Self::LweBootstrapKeyProto {
             todo!()
         }
-
-        fn destroy_lwe_bootstrap_key(&mut self, _entity: FftwFourierLweBootstrapKey64) {}
     }
 }
 
@@ -479,8 +471,6 @@ mod backend_fft {
         ) -> Self::LweBootstrapKeyProto {
             todo!()
         }
-
-        fn destroy_lwe_bootstrap_key(&mut self, _entity: FftFourierLweBootstrapKey32) {}
     }
 
     impl
@@ -506,8 +496,124 @@ mod backend_fft {
         ) -> Self::LweBootstrapKeyProto {
             todo!()
         }
+    }
+}
+
+#[cfg(feature = "backend_ntt")]
+mod backend_ntt {
+    use crate::generation::synthesizing::SynthesizesLweBootstrapKey;
+    use crate::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
+    use concrete_core::prelude::{
+        LweBootstrapKeyConversionEngine, NttLweBootstrapKey32, NttLweBootstrapKey64,
+    };
+
+    impl
+        SynthesizesLweBootstrapKey<
+            Precision32,
+            BinaryKeyDistribution,
+            BinaryKeyDistribution,
+            NttLweBootstrapKey32,
+        > for Maker
+    {
+        fn synthesize_lwe_bootstrap_key(
+            &mut self,
+            prototype: &Self::LweBootstrapKeyProto,
+        ) -> NttLweBootstrapKey32 {
+            self.ntt_engine
+                .convert_lwe_bootstrap_key(&prototype.0)
+                .unwrap()
+        }
+
+        fn unsynthesize_lwe_bootstrap_key(
+            &mut self,
+            _entity: NttLweBootstrapKey32,
+        ) -> Self::LweBootstrapKeyProto {
+            todo!()
+        }
+    }
+
+    impl
+        SynthesizesLweBootstrapKey<
+            Precision64,
+            BinaryKeyDistribution,
+            BinaryKeyDistribution,
+            NttLweBootstrapKey64,
+        > for Maker
+    {
+        fn synthesize_lwe_bootstrap_key(
+            &mut self,
+            prototype: &Self::LweBootstrapKeyProto,
+        ) -> NttLweBootstrapKey64 {
+            self.ntt_engine
+                .convert_lwe_bootstrap_key(&prototype.0)
+                .unwrap()
+        }
+
+        fn unsynthesize_lwe_bootstrap_key(
+            &mut self,
+            _entity: NttLweBootstrapKey64,
+        ) -> Self::LweBootstrapKeyProto {
+            todo!()
+        }
+    }
+}
+
+#[cfg(all(feature = "backend_fft", feature = "backend_ntt"))]
+mod backend_auto_ntt {
+    use crate::generation::synthesizing::SynthesizesLweBootstrapKey;
+    use crate::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
+    use concrete_core::prelude::{
+        AutoLweBootstrapKey32, AutoLweBootstrapKey64, LweBootstrapKeyConversionEngine,
+    };
+
+    impl
+        SynthesizesLweBootstrapKey<
+            Precision32,
+            BinaryKeyDistribution,
+            BinaryKeyDistribution,
+            AutoLweBootstrapKey32,
+        > for Maker
+    {
+        fn synthesize_lwe_bootstrap_key(
+            &mut self,
+            prototype: &Self::LweBootstrapKeyProto,
+        ) -> AutoLweBootstrapKey32 {
+            self.auto_ntt_engine
+                .convert_lwe_bootstrap_key(&prototype.0)
+                .unwrap()
+        }
 
-        fn destroy_lwe_bootstrap_key(&mut self, _entity: FftFourierLweBootstrapKey64) {}
+        fn unsynthesize_lwe_bootstrap_key(
+            &mut self,
+            _entity: AutoLweBootstrapKey32,
+        ) -> Self::LweBootstrapKeyProto {
+            todo!()
+        }
+    }
+
+    impl
+        SynthesizesLweBootstrapKey<
+            Precision64,
+            BinaryKeyDistribution,
+            BinaryKeyDistribution,
+            AutoLweBootstrapKey64,
+        > for Maker
+    {
+        fn synthesize_lwe_bootstrap_key(
+            &mut self,
+            prototype: &Self::LweBootstrapKeyProto,
+        ) -> AutoLweBootstrapKey64 {
+            self.auto_ntt_engine
+                .convert_lwe_bootstrap_key(&prototype.0)
+                .unwrap()
+        }
+
+        fn unsynthesize_lwe_bootstrap_key(
+            &mut self,
+            _entity: AutoLweBootstrapKey64,
+        ) -> Self::LweBootstrapKeyProto {
+            todo!()
+        }
     }
 }
 
@@ -545,8 +651,6 @@ mod backend_cuda {
             implemented"
             );
         }
-
-        fn destroy_lwe_bootstrap_key(&mut self, _entity: CudaFourierLweBootstrapKey32) {}
     }
 
     impl
@@ -575,7 +679,5 @@ mod backend_cuda {
             implemented"
             );
         }
-
-        fn destroy_lwe_bootstrap_key(&mut self, _entity: CudaFourierLweBootstrapKey64) {}
     }
 }
@@ -12,7 +12,7 @@ pub trait SynthesizesLweCiphertext<
 {
     fn synthesize_lwe_ciphertext(&mut self, prototype: &Self::LweCiphertextProto) -> LweCiphertext;
     fn unsynthesize_lwe_ciphertext(&mut self, entity: LweCiphertext) -> Self::LweCiphertextProto;
-    fn destroy_lwe_ciphertext(&mut self, entity: LweCiphertext);
+    fn destroy_lwe_ciphertext(&mut self, _entity: LweCiphertext) {}
 }
 
 mod backend_default {
@@ -35,8 +35,6 @@ mod backend_default {
         ) -> Self::LweCiphertextProto {
             ProtoBinaryLweCiphertext32(entity)
         }
-
-        fn destroy_lwe_ciphertext(&mut self, _entity: LweCiphertext32) {}
     }
 
     impl SynthesizesLweCiphertext<Precision64, BinaryKeyDistribution, LweCiphertext64> for Maker {
@@ -53,8 +51,6 @@ mod backend_default {
         ) -> Self::LweCiphertextProto {
             ProtoBinaryLweCiphertext64(entity)
         }
-
-        fn destroy_lwe_ciphertext(&mut self, _entity: LweCiphertext64) {}
     }
 
     use concrete_core::prelude::{
@@ -270,7 +266,6 @@ mod backend_cuda {
             let proto = self.cuda_engine.convert_lwe_ciphertext(&entity).unwrap();
             ProtoBinaryLweCiphertext32(proto)
         }
-        fn destroy_lwe_ciphertext(&mut self, _entity: CudaLweCiphertext32) {}
     }
 
     impl SynthesizesLweCiphertext<Precision64, BinaryKeyDistribution, CudaLweCiphertext64> for Maker {
@@ -289,6 +284,5 @@ mod backend_cuda {
             let proto = self.cuda_engine.convert_lwe_ciphertext(&entity).unwrap();
             ProtoBinaryLweCiphertext64(proto)
         }
-        fn destroy_lwe_ciphertext(&mut self, _entity: CudaLweCiphertext64) {}
     }
 }
@@ -18,7 +18,7 @@ pub trait SynthesizesLweCiphertextVector<
         &mut self,
         entity: LweCiphertextVector,
     ) -> Self::LweCiphertextVectorProto;
-    fn destroy_lwe_ciphertext_vector(&mut self, entity: LweCiphertextVector);
+    fn destroy_lwe_ciphertext_vector(&mut self, _entity: LweCiphertextVector) {}
 }
 
 mod backend_default {
@@ -45,8 +45,6 @@ mod backend_default {
         ) -> Self::LweCiphertextVectorProto {
             ProtoBinaryLweCiphertextVector32(entity)
         }
-
-        fn destroy_lwe_ciphertext_vector(&mut self, _entity: LweCiphertextVector32) {}
     }
 
     impl SynthesizesLweCiphertextVector<Precision64, BinaryKeyDistribution, LweCiphertextVector64>
@@ -65,8 +63,6 @@ mod backend_default {
         ) -> Self::LweCiphertextVectorProto {
             ProtoBinaryLweCiphertextVector64(entity)
         }
-
-        fn destroy_lwe_ciphertext_vector(&mut self, _entity: LweCiphertextVector64) {}
     }
 }
 #[cfg(all(feature = "backend_cuda", not(feature = "_ci_do_not_compile")))]
@@ -105,7 +101,6 @@ mod backend_cuda {
                 .unwrap();
             ProtoBinaryLweCiphertextVector32(proto)
         }
-        fn destroy_lwe_ciphertext_vector(&mut self, _entity: CudaLweCiphertextVector32) {}
     }
 
     impl
@@ -133,6 +128,5 @@ mod backend_cuda {
                 .unwrap();
             ProtoBinaryLweCiphertextVector64(proto)
         }
-        fn destroy_lwe_ciphertext_vector(&mut self, _entity: CudaLweCiphertextVector64) {}
     }
 }
@@ -19,7 +19,7 @@ pub trait SynthesizesPackingKeyswitchKey<
         &mut self,
         entity: LwePackingKeyswitchKey,
     ) -> Self::PackingKeyswitchKeyProto;
-    fn destroy_packing_keyswitch_key(&mut self, entity: LwePackingKeyswitchKey);
+    fn destroy_packing_keyswitch_key(&mut self, _entity: LwePackingKeyswitchKey) {}
 }
 
 mod backend_default {
@@ -51,8 +51,6 @@ mod backend_default {
         ) -> Self::PackingKeyswitchKeyProto {
             ProtoBinaryBinaryLwePackingKeyswitchKey32(entity)
         }
-
-        fn destroy_packing_keyswitch_key(&mut self, _entity: LwePackingKeyswitchKey32) {}
     }
 
     impl
@@ -76,7 +74,5 @@ mod backend_default {
         ) -> Self::PackingKeyswitchKeyProto {
             ProtoBinaryBinaryLwePackingKeyswitchKey64(entity)
         }
-
-        fn destroy_packing_keyswitch_key(&mut self, _entity: LwePackingKeyswitchKey64) {}
     }
 }
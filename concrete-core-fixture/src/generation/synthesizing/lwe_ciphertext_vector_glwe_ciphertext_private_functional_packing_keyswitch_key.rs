@@ -25,8 +25,9 @@ pub trait SynthesizesPrivateFunctionalPackingKeyswitchKey<
     ) -> Self::LwePrivateFunctionalPackingKeyswitchKeyProto;
     fn destroy_private_functional_packing_keyswitch_key(
         &mut self,
-        entity: LwePrivateFunctionalPackingKeyswitchKey,
-    );
+        _entity: LwePrivateFunctionalPackingKeyswitchKey,
+    ) {
+    }
 }
 
 mod backend_default {
@@ -61,12 +62,6 @@ mod backend_default {
         ) -> Self::LwePrivateFunctionalPackingKeyswitchKeyProto {
             ProtoBinaryBinaryLwePrivateFunctionalPackingKeyswitchKey32(entity)
         }
-
-        fn destroy_private_functional_packing_keyswitch_key(
-            &mut self,
-            _entity: LwePrivateFunctionalPackingKeyswitchKey32,
-        ) {
-        }
     }
 
     impl
@@ -90,11 +85,5 @@ mod backend_default {
         ) -> Self::LwePrivateFunctionalPackingKeyswitchKeyProto {
             ProtoBinaryBinaryLwePrivateFunctionalPackingKeyswitchKey64(entity)
         }
-
-        fn destroy_private_functional_packing_keyswitch_key(
-            &mut self,
-            _entity: LwePrivateFunctionalPackingKeyswitchKey64,
-        ) {
-        }
     }
 }
@@ -26,8 +26,9 @@ pub trait SynthesizesLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys<
     ) -> Self::LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysProto;
     fn destroy_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys(
         &mut self,
-        entity: LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
-    );
+        _entity: LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+    ) {
+    }
 }
 
 mod backend_default {
@@ -63,12 +64,6 @@ mod backend_default {
         ) -> Self::LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysProto {
             ProtoBinaryBinaryLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32(entity)
         }
-
-        fn destroy_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys(
-            &mut self,
-            _entity: LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
-        ) {
-        }
     }
 
     impl
@@ -92,11 +87,5 @@ mod backend_default {
         ) -> Self::LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysProto {
             ProtoBinaryBinaryLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64(entity)
         }
-
-        fn destroy_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys(
-            &mut self,
-            _entity: LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
-        ) {
-        }
     }
 }
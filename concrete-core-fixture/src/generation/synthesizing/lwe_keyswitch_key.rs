@@ -18,7 +18,7 @@ pub trait SynthesizesLweKeyswitchKey<
         &mut self,
         entity: LweKeyswitchKey,
     ) -> Self::LweKeyswitchKeyProto;
-    fn destroy_lwe_keyswitch_key(&mut self, entity: LweKeyswitchKey);
+    fn destroy_lwe_keyswitch_key(&mut self, _entity: LweKeyswitchKey) {}
 }
 
 mod backend_default {
@@ -27,7 +27,10 @@ mod backend_default {
     };
     use crate::generation::synthesizing::SynthesizesLweKeyswitchKey;
     use crate::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
-    use concrete_core::prelude::{LweKeyswitchKey32, LweKeyswitchKey64};
+    use concrete_core::prelude::{
+        LweKeyswitchKey32, LweKeyswitchKey64, LweKeyswitchKeyConversionEngine,
+        LweLevelMajorKeyswitchKey32, LweLevelMajorKeyswitchKey64,
+    };
 
     impl
         SynthesizesLweKeyswitchKey<
@@ -50,8 +53,6 @@ mod backend_default {
         ) -> Self::LweKeyswitchKeyProto {
             ProtoBinaryBinaryLweKeyswitchKey32(entity)
         }
-
-        fn destroy_lwe_keyswitch_key(&mut self, _entity: LweKeyswitchKey32) {}
     }
 
     impl
@@ -75,8 +76,64 @@ mod backend_default {
         ) -> Self::LweKeyswitchKeyProto {
             ProtoBinaryBinaryLweKeyswitchKey64(entity)
         }
+    }
+
+    impl
+        SynthesizesLweKeyswitchKey<
+            Precision32,
+            BinaryKeyDistribution,
+            BinaryKeyDistribution,
+            LweLevelMajorKeyswitchKey32,
+        > for Maker
+    {
+        fn synthesize_lwe_keyswitch_key(
+            &mut self,
+            prototype: &Self::LweKeyswitchKeyProto,
+        ) -> LweLevelMajorKeyswitchKey32 {
+            self.default_engine
+                .convert_lwe_keyswitch_key(&prototype.0)
+                .unwrap()
+        }
+
+        fn unsynthesize_lwe_keyswitch_key(
+            &mut self,
+            entity: LweLevelMajorKeyswitchKey32,
+        ) -> Self::LweKeyswitchKeyProto {
+            let proto = self
+                .default_engine
+                .convert_lwe_keyswitch_key(&entity)
+                .unwrap();
+            ProtoBinaryBinaryLweKeyswitchKey32(proto)
+        }
+    }
 
-        fn destroy_lwe_keyswitch_key(&mut self, _entity: LweKeyswitchKey64) {}
+    impl
+        SynthesizesLweKeyswitchKey<
+            Precision64,
+            BinaryKeyDistribution,
+            BinaryKeyDistribution,
+            LweLevelMajorKeyswitchKey64,
+        > for Maker
+    {
+        fn synthesize_lwe_keyswitch_key(
+            &mut self,
+            prototype: &Self::LweKeyswitchKeyProto,
+        ) -> LweLevelMajorKeyswitchKey64 {
+            self.default_engine
+                .convert_lwe_keyswitch_key(&prototype.0)
+                .unwrap()
+        }
+
+        fn unsynthesize_lwe_keyswitch_key(
+            &mut self,
+            entity: LweLevelMajorKeyswitchKey64,
+        ) -> Self::LweKeyswitchKeyProto {
+            let proto = self
+                .default_engine
+                .convert_lwe_keyswitch_key(&entity)
+                .unwrap();
+            ProtoBinaryBinaryLweKeyswitchKey64(proto)
+        }
     }
 }
 
@@ -115,8 +172,6 @@ mod backend_cuda {
             let proto = self.cuda_engine.convert_lwe_keyswitch_key(&entity).unwrap();
             ProtoBinaryBinaryLweKeyswitchKey32(proto)
         }
-
-        fn destroy_lwe_keyswitch_key(&mut self, _entity: CudaLweKeyswitchKey32) {}
     }
 
     impl
@@ -143,7 +198,5 @@ mod backend_cuda {
             let proto = self.cuda_engine.convert_lwe_keyswitch_key(&entity).unwrap();
             ProtoBinaryBinaryLweKeyswitchKey64(proto)
         }
-
-        fn destroy_lwe_keyswitch_key(&mut self, _entity: CudaLweKeyswitchKey64) {}
     }
 }
@@ -12,7 +12,7 @@ pub trait SynthesizesLweSecretKey<
 {
     fn synthesize_lwe_secret_key(&mut self, prototype: &Self::LweSecretKeyProto) -> LweSecretKey;
     fn unsynthesize_lwe_secret_key(&mut self, entity: LweSecretKey) -> Self::LweSecretKeyProto;
-    fn destroy_lwe_secret_key(&mut self, entity: LweSecretKey);
+    fn destroy_lwe_secret_key(&mut self, _entity: LweSecretKey) {}
 }
 
 mod backend_default {
@@ -35,8 +35,6 @@ mod backend_default {
         ) -> Self::LweSecretKeyProto {
             ProtoBinaryLweSecretKey32(entity)
         }
-
-        fn destroy_lwe_secret_key(&mut self, _entity: LweSecretKey32) {}
     }
 
     impl SynthesizesLweSecretKey<Precision64, BinaryKeyDistribution, LweSecretKey64> for Maker {
@@ -53,7 +51,5 @@ mod backend_default {
         ) -> Self::LweSecretKeyProto {
             ProtoBinaryLweSecretKey64(entity)
         }
-
-        fn destroy_lwe_secret_key(&mut self, _entity: LweSecretKey64) {}
     }
 }
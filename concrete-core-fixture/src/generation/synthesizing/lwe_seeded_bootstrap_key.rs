@@ -19,7 +19,7 @@ pub trait SynthesizesLweSeededBootstrapKey<
         &mut self,
         entity: LweSeededBootstrapKey,
     ) -> Self::LweSeededBootstrapKeyProto;
-    fn destroy_lwe_seeded_bootstrap_key(&mut self, entity: LweSeededBootstrapKey);
+    fn destroy_lwe_seeded_bootstrap_key(&mut self, _entity: LweSeededBootstrapKey) {}
 }
 
 mod backend_default {
@@ -51,8 +51,6 @@ mod backend_default {
         ) -> Self::LweSeededBootstrapKeyProto {
             ProtoBinaryBinaryLweSeededBootstrapKey32(entity)
         }
-
-        fn destroy_lwe_seeded_bootstrap_key(&mut self, _entity: LweSeededBootstrapKey32) {}
     }
 
     impl
@@ -76,7 +74,5 @@ mod backend_default {
         ) -> Self::LweSeededBootstrapKeyProto {
             ProtoBinaryBinaryLweSeededBootstrapKey64(entity)
         }
-
-        fn destroy_lwe_seeded_bootstrap_key(&mut self, _entity: LweSeededBootstrapKey64) {}
     }
 }
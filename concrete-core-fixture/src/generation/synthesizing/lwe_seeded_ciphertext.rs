@@ -18,7 +18,7 @@ pub trait SynthesizesLweSeededCiphertext<
         &mut self,
         entity: LweSeededCiphertext,
     ) -> Self::LweSeededCiphertextProto;
-    fn destroy_lwe_seeded_ciphertext(&mut self, entity: LweSeededCiphertext);
+    fn destroy_lwe_seeded_ciphertext(&mut self, _entity: LweSeededCiphertext) {}
 }
 
 mod backend_default {
@@ -45,8 +45,6 @@ mod backend_default {
         ) -> Self::LweSeededCiphertextProto {
             ProtoBinaryLweSeededCiphertext32(entity)
         }
-
-        fn destroy_lwe_seeded_ciphertext(&mut self, _entity: LweSeededCiphertext32) {}
     }
 
     impl SynthesizesLweSeededCiphertext<Precision64, BinaryKeyDistribution, LweSeededCiphertext64>
@@ -65,7 +63,5 @@ mod backend_default {
         ) -> Self::LweSeededCiphertextProto {
             ProtoBinaryLweSeededCiphertext64(entity)
         }
-
-        fn destroy_lwe_seeded_ciphertext(&mut self, _entity: LweSeededCiphertext64) {}
     }
 }
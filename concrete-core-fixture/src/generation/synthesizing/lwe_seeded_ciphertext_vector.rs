@@ -18,7 +18,7 @@ pub trait SynthesizesLweSeededCiphertextVector<
         &mut self,
         entity: LweSeededCiphertextVector,
     ) -> Self::LweSeededCiphertextVectorProto;
-    fn destroy_lwe_seeded_ciphertext_vector(&mut self, entity: LweSeededCiphertextVector);
+    fn destroy_lwe_seeded_ciphertext_vector(&mut self, _entity: LweSeededCiphertextVector) {}
 }
 
 mod backend_default {
@@ -49,8 +49,6 @@ mod backend_default {
         ) -> Self::LweSeededCiphertextVectorProto {
             ProtoBinaryLweSeededCiphertextVector32(entity)
         }
-
-        fn destroy_lwe_seeded_ciphertext_vector(&mut self, _entity: LweSeededCiphertextVector32) {}
     }
 
     impl
@@ -73,7 +71,5 @@ mod backend_default {
         ) -> Self::LweSeededCiphertextVectorProto {
             ProtoBinaryLweSeededCiphertextVector64(entity)
         }
-
-        fn destroy_lwe_seeded_ciphertext_vector(&mut self, _entity: LweSeededCiphertextVector64) {}
     }
 }
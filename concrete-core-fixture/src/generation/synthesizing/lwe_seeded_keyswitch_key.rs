@@ -18,7 +18,7 @@ pub trait SynthesizesLweSeededKeyswitchKey<
         &mut self,
         entity: LweSeededKeyswitchKey,
     ) -> Self::LweSeededKeyswitchKeyProto;
-    fn destroy_lwe_seeded_keyswitch_key(&mut self, entity: LweSeededKeyswitchKey);
+    fn destroy_lwe_seeded_keyswitch_key(&mut self, _entity: LweSeededKeyswitchKey) {}
 }
 
 mod backend_default {
@@ -50,8 +50,6 @@ mod backend_default {
         ) -> Self::LweSeededKeyswitchKeyProto {
             ProtoBinaryBinaryLweSeededKeyswitchKey32(entity)
         }
-
-        fn destroy_lwe_seeded_keyswitch_key(&mut self, _entity: LweSeededKeyswitchKey32) {}
     }
 
     impl
@@ -75,7 +73,5 @@ mod backend_default {
         ) -> Self::LweSeededKeyswitchKeyProto {
             ProtoBinaryBinaryLweSeededKeyswitchKey64(entity)
         }
-
-        fn destroy_lwe_seeded_keyswitch_key(&mut self, _entity: LweSeededKeyswitchKey64) {}
     }
 }
@@ -10,7 +10,7 @@ where
 {
     fn synthesize_plaintext(&mut self, prototype: &Self::PlaintextProto) -> Plaintext;
     fn unsynthesize_plaintext(&mut self, entity: Plaintext) -> Self::PlaintextProto;
-    fn destroy_plaintext(&mut self, entity: Plaintext);
+    fn destroy_plaintext(&mut self, _entity: Plaintext) {}
 }
 
 mod backend_default {
@@ -27,8 +27,6 @@ mod backend_default {
         fn unsynthesize_plaintext(&mut self, entity: Plaintext32) -> Self::PlaintextProto {
             ProtoPlaintext32(entity)
         }
-
-        fn destroy_plaintext(&mut self, _entity: Plaintext32) {}
     }
 
     impl SynthesizesPlaintext<Precision64, Plaintext64> for Maker {
@@ -39,7 +37,5 @@ mod backend_default {
         fn unsynthesize_plaintext(&mut self, entity: Plaintext64) -> Self::PlaintextProto {
             ProtoPlaintext64(entity)
         }
-
-        fn destroy_plaintext(&mut self, _entity: Plaintext64) {}
     }
 }
@@ -16,7 +16,7 @@ where
         &mut self,
         entity: PlaintextVector,
     ) -> Self::PlaintextVectorProto;
-    fn destroy_plaintext_vector(&mut self, entity: PlaintextVector);
+    fn destroy_plaintext_vector(&mut self, _entity: PlaintextVector) {}
 }
 
 mod backend_default {
@@ -39,8 +39,6 @@ mod backend_default {
         ) -> Self::PlaintextVectorProto {
             ProtoPlaintextVector32(entity)
         }
-
-        fn destroy_plaintext_vector(&mut self, _entity: PlaintextVector32) {}
     }
 
     impl SynthesizesPlaintextVector<Precision64, PlaintextVector64> for Maker {
@@ -57,7 +55,5 @@ mod backend_default {
         ) -> Self::PlaintextVectorProto {
             ProtoPlaintextVector64(entity)
         }
-
-        fn destroy_plaintext_vector(&mut self, _entity: PlaintextVector64) {}
     }
 }
@@ -0,0 +1,82 @@
+use concrete_core::prelude as core;
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+
+macro_rules! param {
+    ($(($public: ident, $private: ident, $typ: ty)),*) => {
+        $(
+            #[napi]
+            #[derive(Clone, Copy)]
+            pub struct $public(pub(crate) core::$private);
+
+            #[napi]
+            impl $public {
+                #[napi(constructor)]
+                pub fn new(val: $typ) -> $public {
+                    $public(core::$private(val as _))
+                }
+            }
+        )*
+    };
+}
+
+param! {
+    (Variance, Variance, f64),
+    (DecompositionBaseLog, DecompositionBaseLog, u32),
+    (DecompositionLevelCount, DecompositionLevelCount, u32),
+    (LweDimension, LweDimension, u32),
+    (GlweDimension, GlweDimension, u32),
+    (PolynomialSize, PolynomialSize, u32)
+}
+
+/// The configuration of a float encoder defined by the interval `[min, max]`.
+#[napi(object)]
+pub struct FloatEncoderMinMaxConfig {
+    pub min: f64,
+    pub max: f64,
+    pub nb_bit_precision: u32,
+    pub nb_bit_padding: u32,
+}
+
+impl From<FloatEncoderMinMaxConfig> for core::FloatEncoderMinMaxConfig {
+    fn from(config: FloatEncoderMinMaxConfig) -> Self {
+        core::FloatEncoderMinMaxConfig {
+            min: config.min,
+            max: config.max,
+            nb_bit_precision: config.nb_bit_precision as usize,
+            nb_bit_padding: config.nb_bit_padding as usize,
+        }
+    }
+}
+
+/// The configuration of a float encoder defined by the interval `[center - radius, center +
+/// radius]`.
+#[napi(object)]
+pub struct FloatEncoderCenterRadiusConfig {
+    pub center: f64,
+    pub radius: f64,
+    pub nb_bit_precision: u32,
+    pub nb_bit_padding: u32,
+}
+
+impl From<FloatEncoderCenterRadiusConfig> for core::FloatEncoderCenterRadiusConfig {
+    fn from(config: FloatEncoderCenterRadiusConfig) -> Self {
+        core::FloatEncoderCenterRadiusConfig {
+            center: config.center,
+            radius: config.radius,
+            nb_bit_precision: config.nb_bit_precision as usize,
+            nb_bit_padding: config.nb_bit_padding as usize,
+        }
+    }
+}
+
+/// Converts a javascript `BigInt` to a `u64`, failing if it does not fit.
+pub(crate) fn bigint_to_u64(input: BigInt) -> Result<u64> {
+    match input.get_u64() {
+        (false, value, true) => Ok(value),
+        _ => Err(Error::from_reason(format!(
+            "The BigInt {:?} does not fit in an unsigned 64 bits integer.",
+            input
+        ))),
+    }
+}
@@ -0,0 +1,573 @@
+use crate::*;
+use concrete_core::prelude as core;
+use concrete_core::prelude::AbstractEngine;
+use concrete_core::specification::engines::*;
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+
+#[napi]
+pub struct DefaultEngine(core::DefaultEngine);
+
+#[napi]
+impl DefaultEngine {
+    #[napi(constructor)]
+    pub fn new(seeder: &UnixSeeder) -> Result<DefaultEngine> {
+        wrap!(
+            DefaultEngine,
+            core::DefaultEngine::new(Box::new(seeder.to_core_seeder()))
+        )
+    }
+
+    #[napi]
+    pub fn create_plaintext_64(&mut self, input: BigInt) -> Result<Plaintext64> {
+        wrap!(
+            Plaintext64,
+            self.0.create_plaintext_from(&bigint_to_u64(input)?)
+        )
+    }
+
+    #[napi]
+    pub fn create_plaintext_vector_64(
+        &mut self,
+        input: BigUint64Array,
+    ) -> Result<PlaintextVector64> {
+        wrap!(
+            PlaintextVector64,
+            self.0.create_plaintext_vector_from(&input)
+        )
+    }
+
+    #[napi]
+    pub fn retrieve_plaintext_64(&mut self, input: &Plaintext64) -> Result<BigInt> {
+        napierr!(self.0.retrieve_plaintext(&input.0)).map(BigInt::from)
+    }
+
+    #[napi]
+    pub fn retrieve_plaintext_vector_64(
+        &mut self,
+        input: &PlaintextVector64,
+    ) -> Result<BigUint64Array> {
+        napierr!(self.0.retrieve_plaintext_vector(&input.0)).map(BigUint64Array::new)
+    }
+
+    #[napi]
+    pub fn create_plaintext_32(&mut self, input: u32) -> Result<Plaintext32> {
+        wrap!(Plaintext32, self.0.create_plaintext_from(&input))
+    }
+
+    #[napi]
+    pub fn create_plaintext_vector_32(&mut self, input: Uint32Array) -> Result<PlaintextVector32> {
+        wrap!(
+            PlaintextVector32,
+            self.0.create_plaintext_vector_from(&input)
+        )
+    }
+
+    #[napi]
+    pub fn retrieve_plaintext_32(&mut self, input: &Plaintext32) -> Result<u32> {
+        napierr!(self.0.retrieve_plaintext(&input.0))
+    }
+
+    #[napi]
+    pub fn retrieve_plaintext_vector_32(
+        &mut self,
+        input: &PlaintextVector32,
+    ) -> Result<Uint32Array> {
+        napierr!(self.0.retrieve_plaintext_vector(&input.0)).map(Uint32Array::new)
+    }
+
+    #[napi]
+    pub fn create_cleartext_f64(&mut self, input: f64) -> Result<CleartextF64> {
+        wrap!(CleartextF64, self.0.create_cleartext_from(&input))
+    }
+
+    #[napi]
+    pub fn create_cleartext_vector_f64(
+        &mut self,
+        input: Float64Array,
+    ) -> Result<CleartextVectorF64> {
+        wrap!(
+            CleartextVectorF64,
+            self.0.create_cleartext_vector_from(&input)
+        )
+    }
+
+    #[napi]
+    pub fn retrieve_cleartext_f64(&mut self, cleartext: &CleartextF64) -> Result<f64> {
+        napierr!(self.0.retrieve_cleartext(&cleartext.0))
+    }
+
+    #[napi]
+    pub fn retrieve_cleartext_vector_f64(
+        &mut self,
+        input: &CleartextVectorF64,
+    ) -> Result<Float64Array> {
+        napierr!(self.0.retrieve_cleartext_vector(&input.0)).map(Float64Array::new)
+    }
+
+    #[napi]
+    pub fn create_encoder_f64_min_max(
+        &mut self,
+        config: FloatEncoderMinMaxConfig,
+    ) -> Result<FloatEncoder> {
+        wrap!(
+            FloatEncoder,
+            self.0
+                .create_encoder_from(&core::FloatEncoderMinMaxConfig::from(config))
+        )
+    }
+
+    #[napi]
+    pub fn create_encoder_f64_center_radius(
+        &mut self,
+        config: FloatEncoderCenterRadiusConfig,
+    ) -> Result<FloatEncoder> {
+        wrap!(
+            FloatEncoder,
+            self.0
+                .create_encoder_from(&core::FloatEncoderCenterRadiusConfig::from(config))
+        )
+    }
+
+    #[napi]
+    pub fn create_encoder_vector_f64_min_max(
+        &mut self,
+        configs: Vec<FloatEncoderMinMaxConfig>,
+    ) -> Result<FloatEncoderVector> {
+        let configs: Vec<core::FloatEncoderMinMaxConfig> =
+            configs.into_iter().map(Into::into).collect();
+        wrap!(
+            FloatEncoderVector,
+            self.0.create_encoder_vector_from(configs.as_slice())
+        )
+    }
+
+    #[napi]
+    pub fn create_encoder_vector_f64_center_radius(
+        &mut self,
+        configs: Vec<FloatEncoderCenterRadiusConfig>,
+    ) -> Result<FloatEncoderVector> {
+        let configs: Vec<core::FloatEncoderCenterRadiusConfig> =
+            configs.into_iter().map(Into::into).collect();
+        wrap!(
+            FloatEncoderVector,
+            self.0.create_encoder_vector_from(configs.as_slice())
+        )
+    }
+
+    pub fn encode_cleartext_f64_plaintext_32(
+        &mut self,
+        encoder: &FloatEncoder,
+        cleartext: &CleartextF64,
+    ) -> Result<Plaintext32> {
+        wrap!(
+            Plaintext32,
+            self.0.encode_cleartext(&encoder.0, &cleartext.0)
+        )
+    }
+
+    #[napi]
+
+    pub fn encode_cleartext_f64_plaintext_64(
+        &mut self,
+        encoder: &FloatEncoder,
+        cleartext: &CleartextF64,
+    ) -> Result<Plaintext64> {
+        wrap!(
+            Plaintext64,
+            self.0.encode_cleartext(&encoder.0, &cleartext.0)
+        )
+    }
+
+    #[napi]
+
+    pub fn encode_cleartext_vector_f64_plaintext_vector_32(
+        &mut self,
+        encoder_vector: &FloatEncoderVector,
+        cleartext_vector: &CleartextVectorF64,
+    ) -> Result<PlaintextVector32> {
+        wrap!(
+            PlaintextVector32,
+            self.0
+                .encode_cleartext_vector(&encoder_vector.0, &cleartext_vector.0)
+        )
+    }
+
+    #[napi]
+
+    pub fn encode_cleartext_vector_f64_plaintext_vector_64(
+        &mut self,
+        encoder_vector: &FloatEncoderVector,
+        cleartext_vector: &CleartextVectorF64,
+    ) -> Result<PlaintextVector64> {
+        wrap!(
+            PlaintextVector64,
+            self.0
+                .encode_cleartext_vector(&encoder_vector.0, &cleartext_vector.0)
+        )
+    }
+
+    #[napi]
+
+    pub fn decode_plaintext_32_cleartext_f64(
+        &mut self,
+        encoder: &FloatEncoder,
+        plaintext: &Plaintext32,
+    ) -> Result<CleartextF64> {
+        wrap!(
+            CleartextF64,
+            self.0.decode_plaintext(&encoder.0, &plaintext.0)
+        )
+    }
+
+    #[napi]
+
+    pub fn decode_plaintext_64_cleartext_f64(
+        &mut self,
+        encoder: &FloatEncoder,
+        plaintext: &Plaintext64,
+    ) -> Result<CleartextF64> {
+        wrap!(
+            CleartextF64,
+            self.0.decode_plaintext(&encoder.0, &plaintext.0)
+        )
+    }
+
+    #[napi]
+
+    pub fn decode_plaintext_vector_32_cleartext_vector_f64(
+        &mut self,
+        encoder_vector: &FloatEncoderVector,
+        plaintext_vector: &PlaintextVector32,
+    ) -> Result<CleartextVectorF64> {
+        wrap!(
+            CleartextVectorF64,
+            self.0
+                .decode_plaintext_vector(&encoder_vector.0, &plaintext_vector.0)
+        )
+    }
+
+    #[napi]
+
+    pub fn decode_plaintext_vector_64_cleartext_vector_f64(
+        &mut self,
+        encoder_vector: &FloatEncoderVector,
+        plaintext_vector: &PlaintextVector64,
+    ) -> Result<CleartextVectorF64> {
+        wrap!(
+            CleartextVectorF64,
+            self.0
+                .decode_plaintext_vector(&encoder_vector.0, &plaintext_vector.0)
+        )
+    }
+
+    #[napi]
+
+    pub fn create_lwe_secret_key_32(
+        &mut self,
+        lwe_dimension: &LweDimension,
+    ) -> Result<LweSecretKey32> {
+        wrap!(
+            LweSecretKey32,
+            self.0.generate_new_lwe_secret_key(lwe_dimension.0)
+        )
+    }
+
+    #[napi]
+
+    pub fn create_lwe_secret_key_64(
+        &mut self,
+        lwe_dimension: &LweDimension,
+    ) -> Result<LweSecretKey64> {
+        wrap!(
+            LweSecretKey64,
+            self.0.generate_new_lwe_secret_key(lwe_dimension.0)
+        )
+    }
+
+    #[napi]
+
+    pub fn create_glwe_secret_key_32(
+        &mut self,
+        glwe_dimension: &GlweDimension,
+        poly_size: &PolynomialSize,
+    ) -> Result<GlweSecretKey32> {
+        wrap!(
+            GlweSecretKey32,
+            self.0
+                .generate_new_glwe_secret_key(glwe_dimension.0, poly_size.0)
+        )
+    }
+
+    #[napi]
+
+    pub fn create_glwe_secret_key_64(
+        &mut self,
+        glwe_dimension: &GlweDimension,
+        poly_size: &PolynomialSize,
+    ) -> Result<GlweSecretKey64> {
+        wrap!(
+            GlweSecretKey64,
+            self.0
+                .generate_new_glwe_secret_key(glwe_dimension.0, poly_size.0)
+        )
+    }
+
+    #[napi]
+
+    pub fn create_lwe_bootstrap_key_32(
+        &mut self,
+        input_key: &LweSecretKey32,
+        output_key: &GlweSecretKey32,
+        decomposition_base_log: &DecompositionBaseLog,
+        decomposition_level_count: &DecompositionLevelCount,
+        noise: &Variance,
+    ) -> Result<LweBootstrapKey32> {
+        wrap!(
+            LweBootstrapKey32,
+            self.0.generate_new_lwe_bootstrap_key(
+                &input_key.0,
+                &output_key.0,
+                decomposition_base_log.0,
+                decomposition_level_count.0,
+                noise.0
+            )
+        )
+    }
+
+    #[napi]
+
+    pub fn create_lwe_bootstrap_key_64(
+        &mut self,
+        input_key: &LweSecretKey64,
+        output_key: &GlweSecretKey64,
+        decomposition_base_log: &DecompositionBaseLog,
+        decomposition_level_count: &DecompositionLevelCount,
+        noise: &Variance,
+    ) -> Result<LweBootstrapKey64> {
+        wrap!(
+            LweBootstrapKey64,
+            self.0.generate_new_lwe_bootstrap_key(
+                &input_key.0,
+                &output_key.0,
+                decomposition_base_log.0,
+                decomposition_level_count.0,
+                noise.0
+            )
+        )
+    }
+
+    #[napi]
+
+    pub fn encrypt_lwe_ciphertext_32(
+        &mut self,
+        key: &LweSecretKey32,
+        input: &Plaintext32,
+        noise: &Variance,
+    ) -> Result<LweCiphertext32> {
+        wrap!(
+            LweCiphertext32,
+            self.0.encrypt_lwe_ciphertext(&key.0, &input.0, noise.0)
+        )
+    }
+
+    #[napi]
+
+    pub fn encrypt_lwe_ciphertext_64(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &Plaintext64,
+        noise: &Variance,
+    ) -> Result<LweCiphertext64> {
+        wrap!(
+            LweCiphertext64,
+            self.0.encrypt_lwe_ciphertext(&key.0, &input.0, noise.0)
+        )
+    }
+
+    #[napi]
+
+    pub fn encrypt_lwe_ciphertext_vector_32(
+        &mut self,
+        key: &LweSecretKey32,
+        input: &PlaintextVector32,
+        noise: &Variance,
+    ) -> Result<LweCiphertextVector32> {
+        wrap!(
+            LweCiphertextVector32,
+            self.0
+                .encrypt_lwe_ciphertext_vector(&key.0, &input.0, noise.0)
+        )
+    }
+
+    #[napi]
+
+    pub fn encrypt_lwe_ciphertext_vector_64(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &PlaintextVector64,
+        noise: &Variance,
+    ) -> Result<LweCiphertextVector64> {
+        wrap!(
+            LweCiphertextVector64,
+            self.0
+                .encrypt_lwe_ciphertext_vector(&key.0, &input.0, noise.0)
+        )
+    }
+
+    #[napi]
+
+    pub fn encrypt_glwe_ciphertext_32(
+        &mut self,
+        key: &GlweSecretKey32,
+        input: &PlaintextVector32,
+        noise: &Variance,
+    ) -> Result<GlweCiphertext32> {
+        wrap!(
+            GlweCiphertext32,
+            self.0.encrypt_glwe_ciphertext(&key.0, &input.0, noise.0)
+        )
+    }
+
+    #[napi]
+
+    pub fn encrypt_glwe_ciphertext_64(
+        &mut self,
+        key: &GlweSecretKey64,
+        input: &PlaintextVector64,
+        noise: &Variance,
+    ) -> Result<GlweCiphertext64> {
+        wrap!(
+            GlweCiphertext64,
+            self.0.encrypt_glwe_ciphertext(&key.0, &input.0, noise.0)
+        )
+    }
+
+    #[napi]
+
+    pub fn encrypt_glwe_ciphertext_vector_32(
+        &mut self,
+        key: &GlweSecretKey32,
+        input: &PlaintextVector32,
+        noise: &Variance,
+    ) -> Result<GlweCiphertextVector32> {
+        wrap!(
+            GlweCiphertextVector32,
+            self.0
+                .encrypt_glwe_ciphertext_vector(&key.0, &input.0, noise.0)
+        )
+    }
+
+    #[napi]
+
+    pub fn encrypt_glwe_ciphertext_vector_64(
+        &mut self,
+        key: &GlweSecretKey64,
+        input: &PlaintextVector64,
+        noise: &Variance,
+    ) -> Result<GlweCiphertextVector64> {
+        wrap!(
+            GlweCiphertextVector64,
+            self.0
+                .encrypt_glwe_ciphertext_vector(&key.0, &input.0, noise.0)
+        )
+    }
+
+    #[napi]
+
+    pub fn decrypt_lwe_ciphertext_32(
+        &mut self,
+        key: &LweSecretKey32,
+        input: &LweCiphertext32,
+    ) -> Result<Plaintext32> {
+        wrap!(Plaintext32, self.0.decrypt_lwe_ciphertext(&key.0, &input.0))
+    }
+
+    #[napi]
+
+    pub fn decrypt_lwe_ciphertext_64(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &LweCiphertext64,
+    ) -> Result<Plaintext64> {
+        wrap!(Plaintext64, self.0.decrypt_lwe_ciphertext(&key.0, &input.0))
+    }
+
+    #[napi]
+
+    pub fn decrypt_lwe_ciphertext_vector_32(
+        &mut self,
+        key: &LweSecretKey32,
+        input: &LweCiphertextVector32,
+    ) -> Result<PlaintextVector32> {
+        wrap!(
+            PlaintextVector32,
+            self.0.decrypt_lwe_ciphertext_vector(&key.0, &input.0)
+        )
+    }
+
+    #[napi]
+
+    pub fn decrypt_lwe_ciphertext_vector_64(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &LweCiphertextVector64,
+    ) -> Result<PlaintextVector64> {
+        wrap!(
+            PlaintextVector64,
+            self.0.decrypt_lwe_ciphertext_vector(&key.0, &input.0)
+        )
+    }
+
+    #[napi]
+
+    pub fn decrypt_glwe_ciphertext_32(
+        &mut self,
+        key: &GlweSecretKey32,
+        input: &GlweCiphertext32,
+    ) -> Result<PlaintextVector32> {
+        wrap!(
+            PlaintextVector32,
+            self.0.decrypt_glwe_ciphertext(&key.0, &input.0)
+        )
+    }
+
+    #[napi]
+
+    pub fn decrypt_glwe_ciphertext_64(
+        &mut self,
+        key: &GlweSecretKey64,
+        input: &GlweCiphertext64,
+    ) -> Result<PlaintextVector64> {
+        wrap!(
+            PlaintextVector64,
+            self.0.decrypt_glwe_ciphertext(&key.0, &input.0)
+        )
+    }
+
+    #[napi]
+
+    pub fn decrypt_glwe_ciphertext_vector_32(
+        &mut self,
+        key: &GlweSecretKey32,
+        input: &GlweCiphertextVector32,
+    ) -> Result<PlaintextVector32> {
+        wrap!(
+            PlaintextVector32,
+            self.0.decrypt_glwe_ciphertext_vector(&key.0, &input.0)
+        )
+    }
+
+    #[napi]
+
+    pub fn decrypt_glwe_ciphertext_vector_64(
+        &mut self,
+        key: &GlweSecretKey64,
+        input: &GlweCiphertextVector64,
+    ) -> Result<PlaintextVector64> {
+        wrap!(
+            PlaintextVector64,
+            self.0.decrypt_glwe_ciphertext_vector(&key.0, &input.0)
+        )
+    }
+}
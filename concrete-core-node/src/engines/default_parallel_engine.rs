@@ -0,0 +1,64 @@
+use crate::*;
+use concrete_core::prelude as core;
+use concrete_core::prelude::AbstractEngine;
+use concrete_core::specification::engines::*;
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+
+#[napi]
+pub struct DefaultParallelEngine(core::DefaultParallelEngine);
+
+#[napi]
+impl DefaultParallelEngine {
+    #[napi(constructor)]
+    pub fn new(seeder: &UnixSeeder) -> Result<DefaultParallelEngine> {
+        wrap!(
+            DefaultParallelEngine,
+            core::DefaultParallelEngine::new(Box::new(seeder.to_core_seeder()))
+        )
+    }
+
+    #[napi]
+
+    pub fn create_lwe_bootstrap_key_32(
+        &mut self,
+        input_key: &LweSecretKey32,
+        output_key: &GlweSecretKey32,
+        decomposition_base_log: &DecompositionBaseLog,
+        decomposition_level_count: &DecompositionLevelCount,
+        noise: &Variance,
+    ) -> Result<LweBootstrapKey32> {
+        wrap!(
+            LweBootstrapKey32,
+            self.0.generate_new_lwe_bootstrap_key(
+                &input_key.0,
+                &output_key.0,
+                decomposition_base_log.0,
+                decomposition_level_count.0,
+                noise.0
+            )
+        )
+    }
+
+    #[napi]
+
+    pub fn create_lwe_bootstrap_key_64(
+        &mut self,
+        input_key: &LweSecretKey64,
+        output_key: &GlweSecretKey64,
+        decomposition_base_log: &DecompositionBaseLog,
+        decomposition_level_count: &DecompositionLevelCount,
+        noise: &Variance,
+    ) -> Result<LweBootstrapKey64> {
+        wrap!(
+            LweBootstrapKey64,
+            self.0.generate_new_lwe_bootstrap_key(
+                &input_key.0,
+                &output_key.0,
+                decomposition_base_log.0,
+                decomposition_level_count.0,
+                noise.0
+            )
+        )
+    }
+}
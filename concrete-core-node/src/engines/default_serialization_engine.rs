@@ -0,0 +1,69 @@
+use crate::*;
+use concrete_core::prelude as core;
+use concrete_core::prelude::AbstractEngine;
+use concrete_core::specification::engines::*;
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+use paste::paste;
+
+macro_rules! implserde {
+    ($(($type: ident, $name: ident),)*) => {
+        paste!{
+        #[napi]
+        impl DefaultSerializationEngine{
+                #[napi(constructor)]
+                pub fn new() -> Result<DefaultSerializationEngine> {
+                    wrap!(
+                        DefaultSerializationEngine,
+                        core::DefaultSerializationEngine::new(())
+                    )
+                }
+        $(
+                #[napi]
+                pub fn [< serialize_ $name >](&mut self, entity: &$type) -> Result<Buffer> {
+                    napierr!(self.0.serialize(&entity.0)).map(Buffer::from)
+                }
+
+                #[napi]
+                pub fn [< deserialize_ $name >](&mut self, buffer: Buffer) -> Result<$type> {
+                    wrap!($type, self.0.deserialize(buffer.as_ref()))
+                }
+        )*
+        }
+        }
+    };
+}
+
+#[napi]
+pub struct DefaultSerializationEngine(core::DefaultSerializationEngine);
+
+implserde! {
+    (FloatEncoder, float_encoder),
+    (FloatEncoderVector, float_encoder_vector),
+    (CleartextF64, cleartext_f64),
+    (CleartextVectorF64, cleartext_vector_f64),
+    (Cleartext64, cleartext_64),
+    (CleartextVector64, cleartext_vector_64),
+    (Plaintext64, plaintext_64),
+    (PlaintextVector64, plaintext_vector_64),
+    (LweCiphertext64, lwe_ciphertext_64),
+    (LweCiphertextVector64, lwe_ciphertext_vector_64),
+    (LweSecretKey64, lwe_secret_key_64),
+    (LweKeyswitchKey64, lwe_keyswitch_key_64),
+    (LweBootstrapKey64, lwe_bootstrap_key_64),
+    (GlweCiphertext64, glwe_ciphertext_64),
+    (GlweCiphertextVector64, glwe_ciphertext_vector_64),
+    (GlweSecretKey64, glwe_secret_key_64),
+    (Cleartext32, cleartext_32),
+    (CleartextVector32, cleartext_vector_32),
+    (Plaintext32, plaintext_32),
+    (PlaintextVector32, plaintext_vector_32),
+    (LweCiphertext32, lwe_ciphertext_32),
+    (LweCiphertextVector32, lwe_ciphertext_vector_32),
+    (LweSecretKey32, lwe_secret_key_32),
+    (LweKeyswitchKey32, lwe_keyswitch_key_32),
+    (LweBootstrapKey32, lwe_bootstrap_key_32),
+    (GlweCiphertext32, glwe_ciphertext_32),
+    (GlweCiphertextVector32, glwe_ciphertext_vector_32),
+    (GlweSecretKey32, glwe_secret_key_32),
+}
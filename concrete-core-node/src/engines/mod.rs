@@ -0,0 +1,25 @@
+macro_rules! napierr {
+    ($expr: expr) => {
+        $expr.map_err(|e| napi::Error::from_reason(format!("{}", e)))
+    };
+}
+
+macro_rules! wrap {
+    ($newtype: ident, $expr: expr) => {
+        napierr!($expr).map($newtype)
+    };
+}
+
+mod default_engine;
+pub use default_engine::*;
+
+mod default_parallel_engine;
+pub use default_parallel_engine::*;
+
+mod default_serialization_engine;
+pub use default_serialization_engine::*;
+
+#[cfg(feature = "backend_fftw")]
+mod fftw_engine;
+#[cfg(feature = "backend_fftw")]
+pub use fftw_engine::*;
@@ -0,0 +1,11 @@
+mod engines;
+pub use engines::*;
+
+mod entities;
+pub use entities::*;
+
+mod seeder;
+pub use seeder::*;
+
+mod commons;
+pub use commons::*;
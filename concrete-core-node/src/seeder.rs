@@ -0,0 +1,35 @@
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+
+const SECRET_BYTES_COUNT: usize = 16;
+
+/// A seeder using the `/dev/random` source, combined with a user defined secret of 16 bytes,
+/// e.g. `crypto.randomBytes(16)`.
+#[napi]
+pub struct UnixSeeder {
+    secret: u128,
+}
+
+#[napi]
+impl UnixSeeder {
+    #[napi(constructor)]
+    pub fn new(secret: Buffer) -> Result<UnixSeeder> {
+        let bytes: &[u8] = secret.as_ref();
+        if bytes.len() != SECRET_BYTES_COUNT {
+            return Err(Error::from_reason(
+                "The seeder secret must contain 16 bytes.".to_string(),
+            ));
+        }
+        let mut buffer = [0u8; SECRET_BYTES_COUNT];
+        buffer.copy_from_slice(bytes);
+        Ok(UnixSeeder {
+            secret: u128::from_le_bytes(buffer),
+        })
+    }
+}
+
+impl UnixSeeder {
+    pub(crate) fn to_core_seeder(&self) -> concrete_core::prelude::UnixSeeder {
+        concrete_core::prelude::UnixSeeder::new(self.secret)
+    }
+}
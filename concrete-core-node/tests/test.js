@@ -0,0 +1,83 @@
+const crypto = require('crypto');
+const test = require('node:test');
+const assert = require('node:assert').strict;
+const {
+    DefaultEngine,
+    DefaultParallelEngine,
+    DefaultSerializationEngine,
+    GlweDimension,
+    LweDimension,
+    PolynomialSize,
+    DecompositionBaseLog,
+    DecompositionLevelCount,
+    UnixSeeder,
+    Variance,
+} = require('..');
+
+function seeder() {
+    return new UnixSeeder(crypto.randomBytes(16));
+}
+
+test('create_cleartext_f64', (t) => {
+    let eng = new DefaultEngine(seeder());
+    let val = 3.
+    let cleartext = eng.createCleartextF64(val);
+    let raw = eng.retrieveCleartextF64(cleartext);
+    assert.strictEqual(val, raw);
+});
+
+test('create_cleartext_vector_f64', (t) => {
+    let eng = new DefaultEngine(seeder());
+    let val = new Float64Array([3., 2., 5.])
+    let cleartext_vector = eng.createCleartextVectorF64(val);
+    let raw = eng.retrieveCleartextVectorF64(cleartext_vector);
+    assert.deepEqual(val, raw);
+});
+
+test('encrypt_decrypt_lwe_ciphertext_64', (t) => {
+    let eng = new DefaultEngine(seeder());
+    let val = 3n << 60n;
+    let key = eng.createLweSecretKey64(new LweDimension(512));
+    let plaintext = eng.createPlaintext64(val);
+    let ciphertext = eng.encryptLweCiphertext64(key, plaintext, new Variance(2 ** -50));
+    let decrypted = eng.retrievePlaintext64(eng.decryptLweCiphertext64(key, ciphertext));
+    assert.ok(Math.abs(Number((decrypted - val + (1n << 63n)) % (1n << 64n) - (1n << 63n))) < 2 ** 40);
+});
+
+test('encode_decode_plaintext_vector_32', (t) => {
+    let eng = new DefaultEngine(seeder());
+    let val = new Float64Array([1., 2., 3.])
+    let encoder_vector = eng.createEncoderVectorF64MinMax([
+        {min: 0., max: 10., nbBitPrecision: 8, nbBitPadding: 1},
+        {min: 0., max: 10., nbBitPrecision: 8, nbBitPadding: 1},
+        {min: 0., max: 10., nbBitPrecision: 8, nbBitPadding: 1},
+    ]);
+    let cleartext_vector = eng.createCleartextVectorF64(val);
+    let plaintext_vector = eng.encodeCleartextVectorF64PlaintextVector32(encoder_vector, cleartext_vector);
+    let decoded = eng.retrieveCleartextVectorF64(
+        eng.decodePlaintextVector32CleartextVectorF64(encoder_vector, plaintext_vector)
+    );
+    decoded.forEach((v, i) => assert.ok(Math.abs(v - val[i]) < 0.1));
+});
+
+test('serialize_deserialize_lwe_bootstrap_key_32', (t) => {
+    let eng = new DefaultParallelEngine(seeder());
+    let default_eng = new DefaultEngine(seeder());
+    let ser_eng = new DefaultSerializationEngine();
+    let lwe_key = default_eng.createLweSecretKey32(new LweDimension(10));
+    let glwe_key = default_eng.createGlweSecretKey32(new GlweDimension(1), new PolynomialSize(256));
+    let bsk = eng.createLweBootstrapKey32(
+        lwe_key,
+        glwe_key,
+        new DecompositionBaseLog(3),
+        new DecompositionLevelCount(2),
+        new Variance(2 ** -50),
+    );
+    let buffer = ser_eng.serializeLweBootstrapKey32(bsk);
+    let recovered = ser_eng.deserializeLweBootstrapKey32(buffer);
+    assert.deepEqual(buffer, ser_eng.serializeLweBootstrapKey32(recovered));
+});
+
+test('invalid_seeder_secret', (t) => {
+    assert.throws(() => new UnixSeeder(crypto.randomBytes(3)));
+});
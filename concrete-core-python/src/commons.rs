@@ -0,0 +1,33 @@
+use pyo3::prelude::*;
+
+macro_rules! param {
+    ($(($public: ident, $private: ident, $typ: ty)),*) => {
+        $(
+            #[pyclass]
+            #[derive(Clone, Copy)]
+            pub struct $public(pub(crate) concrete_core::prelude::$private);
+
+            #[pymethods]
+            impl $public {
+                #[new]
+                pub fn new(val: $typ) -> $public {
+                    $public(concrete_core::prelude::$private(val))
+                }
+            }
+        )*
+
+        pub(crate) fn register_parameters(m: &PyModule) -> PyResult<()> {
+            $(m.add_class::<$public>()?;)*
+            Ok(())
+        }
+    };
+}
+
+param! {
+    (Variance, Variance, f64),
+    (DecompositionBaseLog, DecompositionBaseLog, usize),
+    (DecompositionLevelCount, DecompositionLevelCount, usize),
+    (LweDimension, LweDimension, usize),
+    (GlweDimension, GlweDimension, usize),
+    (PolynomialSize, PolynomialSize, usize)
+}
@@ -0,0 +1,524 @@
+use crate::*;
+use concrete_core::prelude as core;
+use concrete_core::prelude::{
+    AbstractEngine, FloatEncoderCenterRadiusConfig, FloatEncoderMinMaxConfig,
+};
+use concrete_core::specification::engines::*;
+use pyo3::prelude::*;
+
+#[pyclass(unsendable)]
+pub struct DefaultEngine(core::DefaultEngine);
+
+#[pymethods]
+impl DefaultEngine {
+    #[new]
+    pub fn new(seeder: crate::PyFunctionSeeder) -> PyResult<DefaultEngine> {
+        wrap!(DefaultEngine, core::DefaultEngine::new(Box::new(seeder)))
+    }
+
+    pub fn create_plaintext_64(&mut self, input: u64) -> PyResult<Plaintext64> {
+        wrap!(Plaintext64, self.0.create_plaintext_from(&input))
+    }
+
+    pub fn create_plaintext_vector_64(&mut self, input: Vec<u64>) -> PyResult<PlaintextVector64> {
+        wrap!(
+            PlaintextVector64,
+            self.0.create_plaintext_vector_from(&input)
+        )
+    }
+
+    pub fn retrieve_plaintext_64(&mut self, input: &Plaintext64) -> PyResult<u64> {
+        pyerr!(self.0.retrieve_plaintext(&input.0))
+    }
+
+    pub fn retrieve_plaintext_vector_64(
+        &mut self,
+        input: &PlaintextVector64,
+    ) -> PyResult<Vec<u64>> {
+        pyerr!(self.0.retrieve_plaintext_vector(&input.0))
+    }
+
+    pub fn create_plaintext_32(&mut self, input: u32) -> PyResult<Plaintext32> {
+        wrap!(Plaintext32, self.0.create_plaintext_from(&input))
+    }
+
+    pub fn create_plaintext_vector_32(&mut self, input: Vec<u32>) -> PyResult<PlaintextVector32> {
+        wrap!(
+            PlaintextVector32,
+            self.0.create_plaintext_vector_from(&input)
+        )
+    }
+
+    pub fn retrieve_plaintext_32(&mut self, input: &Plaintext32) -> PyResult<u32> {
+        pyerr!(self.0.retrieve_plaintext(&input.0))
+    }
+
+    pub fn retrieve_plaintext_vector_32(
+        &mut self,
+        input: &PlaintextVector32,
+    ) -> PyResult<Vec<u32>> {
+        pyerr!(self.0.retrieve_plaintext_vector(&input.0))
+    }
+
+    pub fn create_cleartext_f64(&mut self, input: f64) -> PyResult<CleartextF64> {
+        wrap!(CleartextF64, self.0.create_cleartext_from(&input))
+    }
+
+    pub fn create_cleartext_vector_f64(&mut self, input: Vec<f64>) -> PyResult<CleartextVectorF64> {
+        wrap!(
+            CleartextVectorF64,
+            self.0.create_cleartext_vector_from(input.as_slice())
+        )
+    }
+
+    pub fn retrieve_cleartext_f64(&mut self, cleartext: &CleartextF64) -> PyResult<f64> {
+        pyerr!(self.0.retrieve_cleartext(&cleartext.0))
+    }
+
+    pub fn retrieve_cleartext_vector_f64(
+        &mut self,
+        input: &CleartextVectorF64,
+    ) -> PyResult<Vec<f64>> {
+        pyerr!(self.0.retrieve_cleartext_vector(&input.0))
+    }
+
+    pub fn create_encoder_f64_min_max(
+        &mut self,
+        min: f64,
+        max: f64,
+        nb_bit_precision: usize,
+        nb_bit_padding: usize,
+    ) -> PyResult<FloatEncoder> {
+        wrap!(
+            FloatEncoder,
+            self.0.create_encoder_from(&FloatEncoderMinMaxConfig {
+                min,
+                max,
+                nb_bit_padding,
+                nb_bit_precision
+            })
+        )
+    }
+
+    pub fn create_encoder_f64_center_radius(
+        &mut self,
+        center: f64,
+        radius: f64,
+        nb_bit_precision: usize,
+        nb_bit_padding: usize,
+    ) -> PyResult<FloatEncoder> {
+        wrap!(
+            FloatEncoder,
+            self.0.create_encoder_from(&FloatEncoderCenterRadiusConfig {
+                center,
+                radius,
+                nb_bit_padding,
+                nb_bit_precision
+            })
+        )
+    }
+
+    /// Creates an encoder vector from a list of `(min, max, nb_bit_precision, nb_bit_padding)`
+    /// tuples.
+    pub fn create_encoder_vector_f64_min_max(
+        &mut self,
+        configs: Vec<(f64, f64, usize, usize)>,
+    ) -> PyResult<FloatEncoderVector> {
+        let configs: Vec<FloatEncoderMinMaxConfig> = configs
+            .into_iter()
+            .map(
+                |(min, max, nb_bit_precision, nb_bit_padding)| FloatEncoderMinMaxConfig {
+                    min,
+                    max,
+                    nb_bit_padding,
+                    nb_bit_precision,
+                },
+            )
+            .collect();
+        wrap!(
+            FloatEncoderVector,
+            self.0.create_encoder_vector_from(configs.as_slice())
+        )
+    }
+
+    /// Creates an encoder vector from a list of `(center, radius, nb_bit_precision,
+    /// nb_bit_padding)` tuples.
+    pub fn create_encoder_vector_f64_center_radius(
+        &mut self,
+        configs: Vec<(f64, f64, usize, usize)>,
+    ) -> PyResult<FloatEncoderVector> {
+        let configs: Vec<FloatEncoderCenterRadiusConfig> = configs
+            .into_iter()
+            .map(|(center, radius, nb_bit_precision, nb_bit_padding)| {
+                FloatEncoderCenterRadiusConfig {
+                    center,
+                    radius,
+                    nb_bit_padding,
+                    nb_bit_precision,
+                }
+            })
+            .collect();
+        wrap!(
+            FloatEncoderVector,
+            self.0.create_encoder_vector_from(configs.as_slice())
+        )
+    }
+
+    pub fn encode_cleartext_f64_plaintext_32(
+        &mut self,
+        encoder: &FloatEncoder,
+        cleartext: &CleartextF64,
+    ) -> PyResult<Plaintext32> {
+        wrap!(
+            Plaintext32,
+            self.0.encode_cleartext(&encoder.0, &cleartext.0)
+        )
+    }
+
+    pub fn encode_cleartext_f64_plaintext_64(
+        &mut self,
+        encoder: &FloatEncoder,
+        cleartext: &CleartextF64,
+    ) -> PyResult<Plaintext64> {
+        wrap!(
+            Plaintext64,
+            self.0.encode_cleartext(&encoder.0, &cleartext.0)
+        )
+    }
+
+    pub fn encode_cleartext_vector_f64_plaintext_vector_32(
+        &mut self,
+        encoder_vector: &FloatEncoderVector,
+        cleartext_vector: &CleartextVectorF64,
+    ) -> PyResult<PlaintextVector32> {
+        wrap!(
+            PlaintextVector32,
+            self.0
+                .encode_cleartext_vector(&encoder_vector.0, &cleartext_vector.0)
+        )
+    }
+
+    pub fn encode_cleartext_vector_f64_plaintext_vector_64(
+        &mut self,
+        encoder_vector: &FloatEncoderVector,
+        cleartext_vector: &CleartextVectorF64,
+    ) -> PyResult<PlaintextVector64> {
+        wrap!(
+            PlaintextVector64,
+            self.0
+                .encode_cleartext_vector(&encoder_vector.0, &cleartext_vector.0)
+        )
+    }
+
+    pub fn decode_plaintext_32_cleartext_f64(
+        &mut self,
+        encoder: &FloatEncoder,
+        plaintext: &Plaintext32,
+    ) -> PyResult<CleartextF64> {
+        wrap!(
+            CleartextF64,
+            self.0.decode_plaintext(&encoder.0, &plaintext.0)
+        )
+    }
+
+    pub fn decode_plaintext_64_cleartext_f64(
+        &mut self,
+        encoder: &FloatEncoder,
+        plaintext: &Plaintext64,
+    ) -> PyResult<CleartextF64> {
+        wrap!(
+            CleartextF64,
+            self.0.decode_plaintext(&encoder.0, &plaintext.0)
+        )
+    }
+
+    pub fn decode_plaintext_vector_32_cleartext_vector_f64(
+        &mut self,
+        encoder_vector: &FloatEncoderVector,
+        plaintext_vector: &PlaintextVector32,
+    ) -> PyResult<CleartextVectorF64> {
+        wrap!(
+            CleartextVectorF64,
+            self.0
+                .decode_plaintext_vector(&encoder_vector.0, &plaintext_vector.0)
+        )
+    }
+
+    pub fn decode_plaintext_vector_64_cleartext_vector_f64(
+        &mut self,
+        encoder_vector: &FloatEncoderVector,
+        plaintext_vector: &PlaintextVector64,
+    ) -> PyResult<CleartextVectorF64> {
+        wrap!(
+            CleartextVectorF64,
+            self.0
+                .decode_plaintext_vector(&encoder_vector.0, &plaintext_vector.0)
+        )
+    }
+
+    pub fn create_lwe_secret_key_32(
+        &mut self,
+        lwe_dimension: LweDimension,
+    ) -> PyResult<LweSecretKey32> {
+        wrap!(
+            LweSecretKey32,
+            self.0.generate_new_lwe_secret_key(lwe_dimension.0)
+        )
+    }
+
+    pub fn create_lwe_secret_key_64(
+        &mut self,
+        lwe_dimension: LweDimension,
+    ) -> PyResult<LweSecretKey64> {
+        wrap!(
+            LweSecretKey64,
+            self.0.generate_new_lwe_secret_key(lwe_dimension.0)
+        )
+    }
+
+    pub fn create_glwe_secret_key_32(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        poly_size: PolynomialSize,
+    ) -> PyResult<GlweSecretKey32> {
+        wrap!(
+            GlweSecretKey32,
+            self.0
+                .generate_new_glwe_secret_key(glwe_dimension.0, poly_size.0)
+        )
+    }
+
+    pub fn create_glwe_secret_key_64(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        poly_size: PolynomialSize,
+    ) -> PyResult<GlweSecretKey64> {
+        wrap!(
+            GlweSecretKey64,
+            self.0
+                .generate_new_glwe_secret_key(glwe_dimension.0, poly_size.0)
+        )
+    }
+
+    pub fn create_lwe_bootstrap_key_32(
+        &mut self,
+        input_key: &LweSecretKey32,
+        output_key: &GlweSecretKey32,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> PyResult<LweBootstrapKey32> {
+        wrap!(
+            LweBootstrapKey32,
+            self.0.generate_new_lwe_bootstrap_key(
+                &input_key.0,
+                &output_key.0,
+                decomposition_base_log.0,
+                decomposition_level_count.0,
+                noise.0
+            )
+        )
+    }
+
+    pub fn create_lwe_bootstrap_key_64(
+        &mut self,
+        input_key: &LweSecretKey64,
+        output_key: &GlweSecretKey64,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> PyResult<LweBootstrapKey64> {
+        wrap!(
+            LweBootstrapKey64,
+            self.0.generate_new_lwe_bootstrap_key(
+                &input_key.0,
+                &output_key.0,
+                decomposition_base_log.0,
+                decomposition_level_count.0,
+                noise.0
+            )
+        )
+    }
+
+    pub fn encrypt_lwe_ciphertext_32(
+        &mut self,
+        key: &LweSecretKey32,
+        input: &Plaintext32,
+        noise: Variance,
+    ) -> PyResult<LweCiphertext32> {
+        wrap!(
+            LweCiphertext32,
+            self.0.encrypt_lwe_ciphertext(&key.0, &input.0, noise.0)
+        )
+    }
+
+    pub fn encrypt_lwe_ciphertext_64(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &Plaintext64,
+        noise: Variance,
+    ) -> PyResult<LweCiphertext64> {
+        wrap!(
+            LweCiphertext64,
+            self.0.encrypt_lwe_ciphertext(&key.0, &input.0, noise.0)
+        )
+    }
+
+    pub fn encrypt_lwe_ciphertext_vector_32(
+        &mut self,
+        key: &LweSecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> PyResult<LweCiphertextVector32> {
+        wrap!(
+            LweCiphertextVector32,
+            self.0
+                .encrypt_lwe_ciphertext_vector(&key.0, &input.0, noise.0)
+        )
+    }
+
+    pub fn encrypt_lwe_ciphertext_vector_64(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> PyResult<LweCiphertextVector64> {
+        wrap!(
+            LweCiphertextVector64,
+            self.0
+                .encrypt_lwe_ciphertext_vector(&key.0, &input.0, noise.0)
+        )
+    }
+
+    pub fn encrypt_glwe_ciphertext_32(
+        &mut self,
+        key: &GlweSecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> PyResult<GlweCiphertext32> {
+        wrap!(
+            GlweCiphertext32,
+            self.0.encrypt_glwe_ciphertext(&key.0, &input.0, noise.0)
+        )
+    }
+
+    pub fn encrypt_glwe_ciphertext_64(
+        &mut self,
+        key: &GlweSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> PyResult<GlweCiphertext64> {
+        wrap!(
+            GlweCiphertext64,
+            self.0.encrypt_glwe_ciphertext(&key.0, &input.0, noise.0)
+        )
+    }
+
+    pub fn encrypt_glwe_ciphertext_vector_32(
+        &mut self,
+        key: &GlweSecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> PyResult<GlweCiphertextVector32> {
+        wrap!(
+            GlweCiphertextVector32,
+            self.0
+                .encrypt_glwe_ciphertext_vector(&key.0, &input.0, noise.0)
+        )
+    }
+
+    pub fn encrypt_glwe_ciphertext_vector_64(
+        &mut self,
+        key: &GlweSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> PyResult<GlweCiphertextVector64> {
+        wrap!(
+            GlweCiphertextVector64,
+            self.0
+                .encrypt_glwe_ciphertext_vector(&key.0, &input.0, noise.0)
+        )
+    }
+
+    pub fn decrypt_lwe_ciphertext_32(
+        &mut self,
+        key: &LweSecretKey32,
+        input: &LweCiphertext32,
+    ) -> PyResult<Plaintext32> {
+        wrap!(Plaintext32, self.0.decrypt_lwe_ciphertext(&key.0, &input.0))
+    }
+
+    pub fn decrypt_lwe_ciphertext_64(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &LweCiphertext64,
+    ) -> PyResult<Plaintext64> {
+        wrap!(Plaintext64, self.0.decrypt_lwe_ciphertext(&key.0, &input.0))
+    }
+
+    pub fn decrypt_lwe_ciphertext_vector_32(
+        &mut self,
+        key: &LweSecretKey32,
+        input: &LweCiphertextVector32,
+    ) -> PyResult<PlaintextVector32> {
+        wrap!(
+            PlaintextVector32,
+            self.0.decrypt_lwe_ciphertext_vector(&key.0, &input.0)
+        )
+    }
+
+    pub fn decrypt_lwe_ciphertext_vector_64(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &LweCiphertextVector64,
+    ) -> PyResult<PlaintextVector64> {
+        wrap!(
+            PlaintextVector64,
+            self.0.decrypt_lwe_ciphertext_vector(&key.0, &input.0)
+        )
+    }
+
+    pub fn decrypt_glwe_ciphertext_32(
+        &mut self,
+        key: &GlweSecretKey32,
+        input: &GlweCiphertext32,
+    ) -> PyResult<PlaintextVector32> {
+        wrap!(
+            PlaintextVector32,
+            self.0.decrypt_glwe_ciphertext(&key.0, &input.0)
+        )
+    }
+
+    pub fn decrypt_glwe_ciphertext_64(
+        &mut self,
+        key: &GlweSecretKey64,
+        input: &GlweCiphertext64,
+    ) -> PyResult<PlaintextVector64> {
+        wrap!(
+            PlaintextVector64,
+            self.0.decrypt_glwe_ciphertext(&key.0, &input.0)
+        )
+    }
+
+    pub fn decrypt_glwe_ciphertext_vector_32(
+        &mut self,
+        key: &GlweSecretKey32,
+        input: &GlweCiphertextVector32,
+    ) -> PyResult<PlaintextVector32> {
+        wrap!(
+            PlaintextVector32,
+            self.0.decrypt_glwe_ciphertext_vector(&key.0, &input.0)
+        )
+    }
+
+    pub fn decrypt_glwe_ciphertext_vector_64(
+        &mut self,
+        key: &GlweSecretKey64,
+        input: &GlweCiphertextVector64,
+    ) -> PyResult<PlaintextVector64> {
+        wrap!(
+            PlaintextVector64,
+            self.0.decrypt_glwe_ciphertext_vector(&key.0, &input.0)
+        )
+    }
+}
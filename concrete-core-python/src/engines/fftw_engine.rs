@@ -0,0 +1,60 @@
+use crate::*;
+use concrete_core::prelude as core;
+use concrete_core::prelude::AbstractEngine;
+use concrete_core::specification::engines::*;
+use pyo3::prelude::*;
+
+#[pyclass(unsendable)]
+pub struct FftwEngine(core::FftwEngine);
+
+#[pymethods]
+impl FftwEngine {
+    #[new]
+    pub fn new() -> PyResult<FftwEngine> {
+        wrap!(FftwEngine, core::FftwEngine::new(()))
+    }
+
+    pub fn convert_lwe_bootstrap_key_32(
+        &mut self,
+        input: &LweBootstrapKey32,
+    ) -> PyResult<FftwFourierLweBootstrapKey32> {
+        wrap!(
+            FftwFourierLweBootstrapKey32,
+            self.0.convert_lwe_bootstrap_key(&input.0)
+        )
+    }
+
+    pub fn convert_lwe_bootstrap_key_64(
+        &mut self,
+        input: &LweBootstrapKey64,
+    ) -> PyResult<FftwFourierLweBootstrapKey64> {
+        wrap!(
+            FftwFourierLweBootstrapKey64,
+            self.0.convert_lwe_bootstrap_key(&input.0)
+        )
+    }
+
+    pub fn discard_bootstrap_lwe_ciphertext_32(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &LweCiphertext32,
+        acc: &GlweCiphertext32,
+        bsk: &FftwFourierLweBootstrapKey32,
+    ) -> PyResult<()> {
+        pyerr!(self
+            .0
+            .discard_bootstrap_lwe_ciphertext(&mut output.0, &input.0, &acc.0, &bsk.0))
+    }
+
+    pub fn discard_bootstrap_lwe_ciphertext_64(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &LweCiphertext64,
+        acc: &GlweCiphertext64,
+        bsk: &FftwFourierLweBootstrapKey64,
+    ) -> PyResult<()> {
+        pyerr!(self
+            .0
+            .discard_bootstrap_lwe_ciphertext(&mut output.0, &input.0, &acc.0, &bsk.0))
+    }
+}
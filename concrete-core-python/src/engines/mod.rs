@@ -0,0 +1,36 @@
+use pyo3::prelude::*;
+
+macro_rules! pyerr {
+    ($expr: expr) => {
+        $expr.map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("{}", e)))
+    };
+}
+
+macro_rules! wrap {
+    ($newtype: ident, $expr: expr) => {
+        pyerr!($expr).map($newtype)
+    };
+}
+
+mod default_engine;
+pub use default_engine::*;
+
+mod default_parallel_engine;
+pub use default_parallel_engine::*;
+
+mod default_serialization_engine;
+pub use default_serialization_engine::*;
+
+#[cfg(feature = "backend_fftw")]
+mod fftw_engine;
+#[cfg(feature = "backend_fftw")]
+pub use fftw_engine::*;
+
+pub(crate) fn register_engines(m: &PyModule) -> PyResult<()> {
+    m.add_class::<DefaultEngine>()?;
+    m.add_class::<DefaultParallelEngine>()?;
+    m.add_class::<DefaultSerializationEngine>()?;
+    #[cfg(feature = "backend_fftw")]
+    m.add_class::<FftwEngine>()?;
+    Ok(())
+}
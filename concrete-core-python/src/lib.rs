@@ -0,0 +1,22 @@
+use pyo3::prelude::{pymodule, PyModule, PyResult, Python};
+
+mod engines;
+pub use engines::*;
+
+mod entities;
+pub use entities::*;
+
+mod seeder;
+pub use seeder::*;
+
+mod commons;
+pub use commons::*;
+
+#[pymodule]
+fn concrete_core_python(_py: Python, m: &PyModule) -> PyResult<()> {
+    register_parameters(m)?;
+    register_entities(m)?;
+    m.add_class::<PyFunctionSeeder>()?;
+    register_engines(m)?;
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use concrete_core::commons::math::random::Seed;
+use concrete_core::prelude::Seeder;
+use pyo3::prelude::*;
+
+const SEED_BYTES_COUNT: usize = 16;
+
+/// A seeder calling a python function, which must return 16 random bytes, e.g. `os.urandom(16)`.
+#[pyclass]
+#[derive(Clone)]
+pub struct PyFunctionSeeder {
+    py_func: PyObject,
+}
+
+#[pymethods]
+impl PyFunctionSeeder {
+    #[new]
+    pub fn new(py_func: PyObject) -> PyFunctionSeeder {
+        PyFunctionSeeder { py_func }
+    }
+}
+
+impl Seeder for PyFunctionSeeder {
+    fn seed(&mut self) -> Seed {
+        Python::with_gil(|py| {
+            let output = self.py_func.call0(py).unwrap();
+            let bytes: Vec<u8> = output
+                .extract(py)
+                .expect("The seeder function must return bytes.");
+            if bytes.len() != SEED_BYTES_COUNT {
+                panic!("The seeder function must return 16 bytes.");
+            }
+            let mut buffer = [0u8; SEED_BYTES_COUNT];
+            buffer.copy_from_slice(&bytes);
+            Seed(u128::from_le_bytes(buffer))
+        })
+    }
+
+    fn is_available() -> bool
+    where
+        Self: Sized,
+    {
+        true
+    }
+}
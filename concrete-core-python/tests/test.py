@@ -0,0 +1,50 @@
+import os
+
+from concrete_core_python import (
+    DefaultEngine,
+    DefaultSerializationEngine,
+    LweDimension,
+    PyFunctionSeeder,
+    Variance,
+)
+
+
+def seed():
+    return os.urandom(16)
+
+
+def test_create_cleartext_f64():
+    eng = DefaultEngine(PyFunctionSeeder(seed))
+    val = 3.
+    cleartext = eng.create_cleartext_f64(val)
+    raw = eng.retrieve_cleartext_f64(cleartext)
+    assert raw == val
+
+
+def test_create_cleartext_vector_f64():
+    eng = DefaultEngine(PyFunctionSeeder(seed))
+    val = [3., 2., 5.]
+    cleartext_vector = eng.create_cleartext_vector_f64(val)
+    raw = eng.retrieve_cleartext_vector_f64(cleartext_vector)
+    assert raw == val
+
+
+def test_encrypt_decrypt_lwe_ciphertext_64():
+    eng = DefaultEngine(PyFunctionSeeder(seed))
+    key = eng.create_lwe_secret_key_64(LweDimension(10))
+    plaintext = eng.create_plaintext_64(3 << 60)
+    ciphertext = eng.encrypt_lwe_ciphertext_64(key, plaintext, Variance(2 ** -50))
+    decrypted = eng.retrieve_plaintext_64(eng.decrypt_lwe_ciphertext_64(key, ciphertext))
+    assert abs(decrypted - (3 << 60)) < 1 << 40
+
+
+def test_serialize_lwe_ciphertext_64():
+    eng = DefaultEngine(PyFunctionSeeder(seed))
+    ser = DefaultSerializationEngine()
+    key = eng.create_lwe_secret_key_64(LweDimension(10))
+    plaintext = eng.create_plaintext_64(3 << 60)
+    ciphertext = eng.encrypt_lwe_ciphertext_64(key, plaintext, Variance(2 ** -50))
+    serialized = ser.serialize_lwe_ciphertext_64(ciphertext)
+    assert isinstance(serialized, bytes)
+    deserialized = ser.deserialize_lwe_ciphertext_64(serialized)
+    assert ser.serialize_lwe_ciphertext_64(deserialized) == serialized
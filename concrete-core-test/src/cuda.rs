@@ -35,6 +35,7 @@ macro_rules! test {
 test! {
     ((BinaryKeyDistribution), LweCiphertextVectorConversionFixture, (CudaLweCiphertextVector, LweCiphertextVector)),
     ((BinaryKeyDistribution), LweCiphertextConversionFixture, (CudaLweCiphertext, LweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextVectorTrivialEncryptionFixture, (PlaintextVector, CudaGlweCiphertextVector)),
     ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingKeyswitchFixture, (CudaLweKeyswitchKey, CudaLweCiphertextVector,
         CudaLweCiphertextVector)),
     ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingKeyswitchFixture, (CudaLweKeyswitchKey, CudaLweCiphertext,
@@ -89,3 +90,40 @@ test_amortized! {
         CudaGlweCiphertextVector,
         CudaLweCiphertextVector, CudaLweCiphertextVector))
 }
+
+macro_rules! test_auto {
+    (($($key_dist:ident),*), $fixture: ident, $precision: ident, ($($types:ident),+)) => {
+        paste!{
+            #[test]
+            fn [< test_auto_ $fixture:snake _ $precision:snake _ $($types:snake)_+ >]() {
+                let mut maker = Maker::default();
+                let mut engine = AutoCudaEngine::new(()).unwrap();
+                let test_result =
+                    <$fixture as Fixture<
+                        $precision,
+                        ($($key_dist,)*),
+                        AutoCudaEngine,
+                        ($($types,)+),
+                    >>::stress_all_parameters(&mut maker, &mut engine, REPETITIONS, SAMPLE_SIZE);
+                assert!(test_result);
+            }
+        }
+    };
+    ($((($($key_dist:ident),*), $fixture: ident, ($($types:ident),+))),+) => {
+        $(
+            paste!{
+                test_auto!{($($key_dist),*), $fixture, Precision32, ($([< $types 32 >]),+)}
+                test_auto!{($($key_dist),*), $fixture, Precision64, ($([< $types 64 >]),+)}
+            }
+        )+
+    };
+}
+
+test_auto! {
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingBootstrapFixture1, (CudaFourierLweBootstrapKey,
+        CudaGlweCiphertextVector,
+        CudaLweCiphertextVector, CudaLweCiphertextVector)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingBootstrapFixture2, (CudaFourierLweBootstrapKey,
+        CudaGlweCiphertextVector,
+        CudaLweCiphertextVector, CudaLweCiphertextVector))
+}
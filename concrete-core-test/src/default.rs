@@ -1,7 +1,8 @@
 use crate::{REPETITIONS, SAMPLE_SIZE};
 use concrete_core::prelude::*;
 use concrete_core_fixture::fixture::*;
-use concrete_core_fixture::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
+use concrete_core_fixture::generation::{BinaryKeyDistribution, Precision32, Precision64};
+use concrete_core_fixture::{Repetitions, SampleSize};
 use concrete_csprng::seeders::UnixSeeder;
 use paste::paste;
 
@@ -10,15 +11,17 @@ macro_rules! test {
         paste!{
             #[test]
             fn [< test_ $fixture:snake _ $precision:snake _ $($types:snake)_+ >]() {
-                let mut maker = Maker::default();
-                let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(0))).unwrap();
                 let test_result =
                     <$fixture as Fixture<
                         $precision,
                         ($($key_dist,)*),
                         DefaultEngine,
                         ($($types,)+),
-                    >>::stress_all_parameters(&mut maker, &mut engine, REPETITIONS, SAMPLE_SIZE);
+                    >>::stress_all_parameters_parallel(
+                        |secret| DefaultEngine::new(Box::new(UnixSeeder::new(secret))).unwrap(),
+                        REPETITIONS,
+                        SAMPLE_SIZE,
+                    );
                 assert!(test_result);
             }
         }
@@ -57,6 +60,9 @@ test! {
     ((BinaryKeyDistribution), GlweCiphertextEncryptionFixture, (PlaintextVector, GlweSecretKey, GlweCiphertext)),
     ((BinaryKeyDistribution), GlweCiphertextTrivialEncryptionFixture, (PlaintextVector, GlweCiphertext)),
     ((BinaryKeyDistribution), GlweCiphertextZeroEncryptionFixture, (GlweSecretKey, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextGgswCiphertextDiscardingExternalProductFixture, (GlweCiphertext, PreparedGgswCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextGgswCiphertextDiscardingExternalProductAddFixture, (GlweCiphertext, PreparedGgswCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextsGgswCiphertextFusingCmuxFixture, (GlweCiphertext, GlweCiphertext, PreparedGgswCiphertext)),
     ((BinaryKeyDistribution), GlweCiphertextVectorEncryptionFixture, (PlaintextVector, GlweSecretKey, GlweCiphertextVector)),
     ((BinaryKeyDistribution), GlweCiphertextVectorDecryptionFixture, (PlaintextVector, GlweSecretKey, GlweCiphertextVector)),
     ((BinaryKeyDistribution), GlweCiphertextVectorTrivialDecryptionFixture, (PlaintextVector, GlweCiphertextVector)),
@@ -113,9 +119,14 @@ test! {
     ((BinaryKeyDistribution), LweCiphertextVectorFusingSubtractionFixture, (LweCiphertextVector, LweCiphertextVector)),
     ((BinaryKeyDistribution), LweCiphertextVectorDiscardingSubtractionFixture, (LweCiphertextVector, LweCiphertextVector)),
     ((BinaryKeyDistribution), LweCiphertextVectorDiscardingAdditionFixture, (LweCiphertextVector, LweCiphertextVector)),
+    ((BinaryKeyDistribution), LweCiphertextVectorDiscardingPermutationFixture, (LweCiphertextVector, LweCiphertextVector)),
+    ((BinaryKeyDistribution), LweCiphertextVectorDiscardingGatheringFixture, (LweCiphertextVector, LweCiphertextVector)),
+    ((BinaryKeyDistribution), LweCiphertextVectorDiscardingScatteringFixture, (LweCiphertextVector, LweCiphertextVector)),
     ((BinaryKeyDistribution), LweCiphertextVectorDiscardingAffineTransformationFixture, (LweCiphertextVector, CleartextVector, Plaintext, LweCiphertext)),
     ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingKeyswitchFixture, (LweKeyswitchKey, LweCiphertext, LweCiphertext)),
     ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingKeyswitchFixture, (LweKeyswitchKey, LweCiphertextView, LweCiphertextMutView)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingKeyswitchFixture, (LweLevelMajorKeyswitchKey, LweCiphertext, LweCiphertext)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingKeyswitchFixture, (LweKeyswitchKey, LweCiphertextVector, LweCiphertextVector)),
     ((BinaryKeyDistribution), LweCiphertextDiscardingAdditionFixture, (LweCiphertext, LweCiphertext)),
     ((BinaryKeyDistribution), LweCiphertextDiscardingAdditionFixture, (LweCiphertextView, LweCiphertextMutView)),
     ((BinaryKeyDistribution), LweCiphertextDiscardingOppositeFixture, (LweCiphertext, LweCiphertext)),
@@ -153,3 +164,47 @@ test! {
     ((), PlaintextVectorCreationFixture, (PlaintextVector)),
     ((), PlaintextVectorRetrievalFixture, (PlaintextVector))
 }
+
+// The reference bootstrap of the default backend uses a naive polynomial multiplication, which is
+// too slow to run at the fixture parameters within the regular test suite. These tests are run
+// on demand with `cargo test -- --ignored`, on a reduced number of repetitions.
+const REFERENCE_REPETITIONS: Repetitions = Repetitions(1);
+const REFERENCE_SAMPLE_SIZE: SampleSize = SampleSize(10);
+
+macro_rules! test_reference {
+    (($($key_dist:ident),*), $fixture: ident, $precision: ident, ($($types:ident),+)) => {
+        paste!{
+            #[test]
+            #[ignore]
+            fn [< test_reference_ $fixture:snake _ $precision:snake _ $($types:snake)_+ >]() {
+                let test_result =
+                    <$fixture as Fixture<
+                        $precision,
+                        ($($key_dist,)*),
+                        DefaultEngine,
+                        ($($types,)+),
+                    >>::stress_all_parameters_parallel(
+                        |secret| DefaultEngine::new(Box::new(UnixSeeder::new(secret))).unwrap(),
+                        REFERENCE_REPETITIONS,
+                        REFERENCE_SAMPLE_SIZE,
+                    );
+                assert!(test_result);
+            }
+        }
+    };
+    ($((($($key_dist:ident),*), $fixture: ident, ($($types:ident),+))),+) => {
+        $(
+            paste!{
+                test_reference!{($($key_dist),*), $fixture, Precision32, ($([< $types 32 >]),+)}
+                test_reference!{($($key_dist),*), $fixture, Precision64, ($([< $types 64 >]),+)}
+            }
+        )+
+    };
+}
+
+test_reference! {
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingBootstrapFixture1, (LweBootstrapKey,
+        GlweCiphertextVector, LweCiphertextVector, LweCiphertextVector)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingBootstrapFixture2, (LweBootstrapKey,
+        GlweCiphertextVector, LweCiphertextVector, LweCiphertextVector))
+}
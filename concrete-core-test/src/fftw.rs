@@ -38,9 +38,14 @@ test! {
     ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingBootstrapFixture1, (FftwFourierLweBootstrapKey, GlweCiphertextView, LweCiphertextView, LweCiphertextMutView)),
     ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingBootstrapFixture2, (FftwFourierLweBootstrapKey, GlweCiphertextView, LweCiphertextView, LweCiphertextMutView)),
     ((BinaryKeyDistribution), GlweCiphertextGgswCiphertextExternalProductFixture, (GlweCiphertext, FftwFourierGgswCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextGgswCiphertextExternalProductFixture2, (GlweCiphertext, FftwFourierGgswCiphertext, GlweCiphertext)),
     ((BinaryKeyDistribution), GlweCiphertextGgswCiphertextDiscardingExternalProductFixture, (GlweCiphertext, FftwFourierGgswCiphertext, GlweCiphertext)),
     ((BinaryKeyDistribution), GlweCiphertextConversionFixture, (GlweCiphertext, FftwFourierGlweCiphertext)),
     ((BinaryKeyDistribution), GlweCiphertextConversionFixture, (FftwFourierGlweCiphertext, GlweCiphertext)),
     ((BinaryKeyDistribution), GlweCiphertextsGgswCiphertextFusingCmuxFixture, (GlweCiphertext, GlweCiphertext,
-        FftwFourierGgswCiphertext))
+        FftwFourierGgswCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextsGgswCiphertextFusingCmuxFixture2, (GlweCiphertext, GlweCiphertext,
+        FftwFourierGgswCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextRelinearizationFixture, (GlweTensorProductCiphertext, GlweRelinearizationKey, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextLeveledMultiplicationFixture, (GlweCiphertext, GlweCiphertext, GlweRelinearizationKey, GlweCiphertext))
 }
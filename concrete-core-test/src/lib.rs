@@ -20,3 +20,5 @@ pub mod default;
 pub mod fft;
 #[cfg(all(test, feature = "backend_fftw"))]
 pub mod fftw;
+#[cfg(all(test, feature = "backend_ntt"))]
+pub mod ntt;
@@ -2,6 +2,7 @@ use crate::*;
 use concrete_core::prelude as core;
 use concrete_core::prelude::{
     AbstractEngine, FloatEncoderCenterRadiusConfig, FloatEncoderMinMaxConfig,
+    LweCiphertextVectorEntity, LweKeyswitchKeyEntity,
 };
 use concrete_core::specification::engines::*;
 use serde::Deserialize;
@@ -399,6 +400,82 @@ impl DefaultEngine {
         )
     }
 
+    pub fn create_lwe_keyswitch_key_32(
+        &mut self,
+        input_key: &LweSecretKey32,
+        output_key: &LweSecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> JsResult<LweKeyswitchKey32> {
+        wrap!(
+            LweKeyswitchKey32,
+            self.0.generate_new_lwe_keyswitch_key(
+                &input_key.0,
+                &output_key.0,
+                decomposition_level_count.0,
+                decomposition_base_log.0,
+                noise.0
+            )
+        )
+    }
+
+    pub fn create_lwe_keyswitch_key_64(
+        &mut self,
+        input_key: &LweSecretKey64,
+        output_key: &LweSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> JsResult<LweKeyswitchKey64> {
+        wrap!(
+            LweKeyswitchKey64,
+            self.0.generate_new_lwe_keyswitch_key(
+                &input_key.0,
+                &output_key.0,
+                decomposition_level_count.0,
+                decomposition_base_log.0,
+                noise.0
+            )
+        )
+    }
+
+    pub fn create_glwe_relinearization_key_32(
+        &mut self,
+        key: &GlweSecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> JsResult<GlweRelinearizationKey32> {
+        wrap!(
+            GlweRelinearizationKey32,
+            self.0.generate_new_glwe_relinearization_key(
+                &key.0,
+                decomposition_level_count.0,
+                decomposition_base_log.0,
+                noise.0
+            )
+        )
+    }
+
+    pub fn create_glwe_relinearization_key_64(
+        &mut self,
+        key: &GlweSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> JsResult<GlweRelinearizationKey64> {
+        wrap!(
+            GlweRelinearizationKey64,
+            self.0.generate_new_glwe_relinearization_key(
+                &key.0,
+                decomposition_level_count.0,
+                decomposition_base_log.0,
+                noise.0
+            )
+        )
+    }
+
     pub fn encrypt_lwe_ciphertext_32(
         &mut self,
         key: &LweSecretKey32,
@@ -580,4 +657,113 @@ impl DefaultEngine {
             self.0.decrypt_glwe_ciphertext_vector(&key.0, &input.0)
         )
     }
+
+    // The following operations work on whole ciphertext vectors and loop inside of rust, to avoid
+    // paying the js <-> wasm call overhead once per ciphertext.
+
+    pub fn add_many_lwe_ciphertexts_32(
+        &mut self,
+        input: &LweCiphertextVector32,
+    ) -> JsResult<LweCiphertext32> {
+        let weights =
+            jserr!(self
+                .0
+                .create_cleartext_vector_from(&vec![1u32; input.0.lwe_ciphertext_count().0]))?;
+        let bias = jserr!(self.0.create_plaintext_from(&0u32))?;
+        let mut output = jserr!(self
+            .0
+            .trivially_encrypt_lwe_ciphertext(input.0.lwe_dimension().to_lwe_size(), &bias))?;
+        jserr!(self.0.discard_affine_transform_lwe_ciphertext_vector(
+            &mut output,
+            &input.0,
+            &weights,
+            &bias
+        ))?;
+        Ok(LweCiphertext32(output))
+    }
+
+    pub fn add_lwe_ciphertext_vectors_32(
+        &mut self,
+        input_1: &LweCiphertextVector32,
+        input_2: &LweCiphertextVector32,
+    ) -> JsResult<LweCiphertextVector32> {
+        let mut output = input_1.0.clone();
+        jserr!(self
+            .0
+            .fuse_add_lwe_ciphertext_vector(&mut output, &input_2.0))?;
+        Ok(LweCiphertextVector32(output))
+    }
+
+    pub fn keyswitch_lwe_ciphertext_vector_32(
+        &mut self,
+        ksk: &LweKeyswitchKey32,
+        input: &LweCiphertextVector32,
+    ) -> JsResult<LweCiphertextVector32> {
+        let zeros =
+            jserr!(self
+                .0
+                .create_plaintext_vector_from(&vec![0u32; input.0.lwe_ciphertext_count().0]))?;
+        let mut output: core::LweCiphertextVector32 =
+            jserr!(self.0.trivially_encrypt_lwe_ciphertext_vector(
+                ksk.0.output_lwe_dimension().to_lwe_size(),
+                &zeros
+            ))?;
+        jserr!(self
+            .0
+            .discard_keyswitch_lwe_ciphertext_vector(&mut output, &input.0, &ksk.0))?;
+        Ok(LweCiphertextVector32(output))
+    }
+
+    pub fn add_many_lwe_ciphertexts_64(
+        &mut self,
+        input: &LweCiphertextVector64,
+    ) -> JsResult<LweCiphertext64> {
+        let weights =
+            jserr!(self
+                .0
+                .create_cleartext_vector_from(&vec![1u64; input.0.lwe_ciphertext_count().0]))?;
+        let bias = jserr!(self.0.create_plaintext_from(&0u64))?;
+        let mut output = jserr!(self
+            .0
+            .trivially_encrypt_lwe_ciphertext(input.0.lwe_dimension().to_lwe_size(), &bias))?;
+        jserr!(self.0.discard_affine_transform_lwe_ciphertext_vector(
+            &mut output,
+            &input.0,
+            &weights,
+            &bias
+        ))?;
+        Ok(LweCiphertext64(output))
+    }
+
+    pub fn add_lwe_ciphertext_vectors_64(
+        &mut self,
+        input_1: &LweCiphertextVector64,
+        input_2: &LweCiphertextVector64,
+    ) -> JsResult<LweCiphertextVector64> {
+        let mut output = input_1.0.clone();
+        jserr!(self
+            .0
+            .fuse_add_lwe_ciphertext_vector(&mut output, &input_2.0))?;
+        Ok(LweCiphertextVector64(output))
+    }
+
+    pub fn keyswitch_lwe_ciphertext_vector_64(
+        &mut self,
+        ksk: &LweKeyswitchKey64,
+        input: &LweCiphertextVector64,
+    ) -> JsResult<LweCiphertextVector64> {
+        let zeros =
+            jserr!(self
+                .0
+                .create_plaintext_vector_from(&vec![0u64; input.0.lwe_ciphertext_count().0]))?;
+        let mut output: core::LweCiphertextVector64 =
+            jserr!(self.0.trivially_encrypt_lwe_ciphertext_vector(
+                ksk.0.output_lwe_dimension().to_lwe_size(),
+                &zeros
+            ))?;
+        jserr!(self
+            .0
+            .discard_keyswitch_lwe_ciphertext_vector(&mut output, &input.0, &ksk.0))?;
+        Ok(LweCiphertextVector64(output))
+    }
 }
@@ -60,6 +60,7 @@ implserde! {
     (GlweCiphertext64, glwe_ciphertext_64),
     (GlweCiphertextVector64, glwe_ciphertext_vector_64),
     (GlweSecretKey64, glwe_secret_key_64),
+    (GlweRelinearizationKey64, glwe_relinearization_key_64),
     (Cleartext32, cleartext_32),
     (CleartextVector32, cleartext_vector_32),
     (Plaintext32, plaintext_32),
@@ -72,4 +73,5 @@ implserde! {
     (GlweCiphertext32, glwe_ciphertext_32),
     (GlweCiphertextVector32, glwe_ciphertext_vector_32),
     (GlweSecretKey32, glwe_secret_key_32),
+    (GlweRelinearizationKey32, glwe_relinearization_key_32),
 }
@@ -1,15 +1,3 @@
-macro_rules! jserr {
-    ($expr: expr) => {
-        $expr.map_err(|e| wasm_bindgen::JsError::new(format!("{}", e).as_str()))
-    };
-}
-
-macro_rules! wrap {
-    ($newtype: ident, $expr: expr) => {
-        jserr!($expr).map($newtype)
-    };
-}
-
 mod default_engine;
 pub use default_engine::*;
 
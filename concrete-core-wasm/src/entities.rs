@@ -1,3 +1,9 @@
+use crate::JsResult;
+use concrete_core::prelude::{
+    AbstractEngine, DefaultSerializationEngine, EntityDeserializationEngine,
+    EntitySerializationEngine,
+};
+use js_sys::{ArrayBuffer, Uint8Array};
 use wasm_bindgen::prelude::*;
 
 macro_rules! reexp_entities {
@@ -7,6 +13,23 @@ macro_rules! reexp_entities {
         #[wasm_bindgen]
         pub struct $struct(pub(crate) concrete_core::prelude::$default_struct);
 
+        #[wasm_bindgen]
+        impl $struct {
+            /// Exports the entity to a new `ArrayBuffer`, which can be transferred to a web
+            /// worker without being copied.
+            pub fn to_array_buffer(&self) -> JsResult<ArrayBuffer> {
+                let mut engine = jserr!(DefaultSerializationEngine::new(()))?;
+                let bytes = jserr!(engine.serialize(&self.0))?;
+                Ok(Uint8Array::from(bytes.as_slice()).buffer())
+            }
+
+            /// Imports an entity from an `ArrayBuffer` generated by `to_array_buffer`.
+            pub fn from_array_buffer(buffer: &ArrayBuffer) -> JsResult<$struct> {
+                let mut engine = jserr!(DefaultSerializationEngine::new(()))?;
+                wrap!($struct, engine.deserialize(Uint8Array::new(buffer).to_vec().as_slice()))
+            }
+        }
+
         )+
     };
 }
@@ -28,6 +51,7 @@ reexp_entities! {
     (GlweCiphertext64, GlweCiphertext64),
     (GlweCiphertextVector64, GlweCiphertextVector64),
     (GlweSecretKey64, GlweSecretKey64),
+    (GlweRelinearizationKey64, GlweRelinearizationKey64),
     (Cleartext32, Cleartext32),
     (CleartextVector32, CleartextVector32),
     (Plaintext32, Plaintext32),
@@ -40,4 +64,5 @@ reexp_entities! {
     (GlweCiphertext32, GlweCiphertext32),
     (GlweCiphertextVector32, GlweCiphertextVector32),
     (GlweSecretKey32, GlweSecretKey32),
+    (GlweRelinearizationKey32, GlweRelinearizationKey32),
 }
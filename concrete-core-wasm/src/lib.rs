@@ -1,3 +1,32 @@
+//! Wasm interface to the `concrete-core` library.
+//!
+//! # Thread-safety contract
+//!
+//! A wasm module is single threaded: every web worker which loads it gets its own instance, with
+//! its own linear memory. As a consequence:
+//!
+//! + The engines carry a mutable state (their random generators) which is never shared. Each
+//!   worker must create its own engines, with its own seeder.
+//! + The entities are handles to the memory of the instance which created them, and can not be
+//!   posted to another worker as is. They must be exported with `to_array_buffer`, and the
+//!   resulting buffer given in the transfer list of `postMessage` so that it is moved rather than
+//!   copied. The receiving worker then uses `from_array_buffer` to import the entity in its own
+//!   instance.
+//! + The exported buffers contain the entities in clear, which is harmless for ciphertexts, but
+//!   means that secret keys must only be sent to trusted workers.
+
+macro_rules! jserr {
+    ($expr: expr) => {
+        $expr.map_err(|e| wasm_bindgen::JsError::new(format!("{}", e).as_str()))
+    };
+}
+
+macro_rules! wrap {
+    ($newtype: ident, $expr: expr) => {
+        jserr!($expr).map($newtype)
+    };
+}
+
 mod engines;
 pub use engines::*;
 
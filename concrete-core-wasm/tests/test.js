@@ -1,7 +1,18 @@
 const crypto = require('crypto');
 const test = require('node:test');
 const assert = require('node:assert').strict;
-const {DefaultEngine, JsFunctionSeeder} = require("../pkg");
+const {
+    DefaultEngine,
+    DefaultSerializationEngine,
+    JsFunctionSeeder,
+    LweCiphertextVector64,
+    LweDimension,
+    GlweDimension,
+    PolynomialSize,
+    DecompositionBaseLog,
+    DecompositionLevelCount,
+    Variance,
+} = require("../pkg");
 
 function seed() {
     return crypto.randomBytes(16);
@@ -25,3 +36,56 @@ test('create_cleartext_vector_f64', (t) => {
     assert.deepEqual(val, raw);
 });
 
+
+test('keyswitch_lwe_ciphertext_vector_64', (t) => {
+    let seeder = new JsFunctionSeeder(seed);
+    let eng = new DefaultEngine(seeder);
+    let input_key = eng.create_lwe_secret_key_64(new LweDimension(10));
+    let output_key = eng.create_lwe_secret_key_64(new LweDimension(5));
+    let ksk = eng.create_lwe_keyswitch_key_64(
+        input_key,
+        output_key,
+        new DecompositionLevelCount(3),
+        new DecompositionBaseLog(5),
+        new Variance(2 ** -50),
+    );
+    let val = new BigUint64Array([1n << 60n, 2n << 60n, 3n << 60n]);
+    let plaintext_vector = eng.create_plaintext_vector_64(val);
+    let ciphertext_vector = eng.encrypt_lwe_ciphertext_vector_64(input_key, plaintext_vector, new Variance(2 ** -50));
+    let switched = eng.keyswitch_lwe_ciphertext_vector_64(ksk, ciphertext_vector);
+    let sum = eng.add_many_lwe_ciphertexts_64(switched);
+    let decrypted = eng.retrieve_plaintext_64(eng.decrypt_lwe_ciphertext_64(output_key, sum));
+    assert.strictEqual((decrypted + (1n << 59n)) >> 60n, 6n);
+});
+
+test('lwe_ciphertext_vector_array_buffer', (t) => {
+    let seeder = new JsFunctionSeeder(seed);
+    let eng = new DefaultEngine(seeder);
+    let key = eng.create_lwe_secret_key_64(new LweDimension(10));
+    let val = new BigUint64Array([1n << 60n, 2n << 60n]);
+    let plaintext_vector = eng.create_plaintext_vector_64(val);
+    let ciphertext_vector = eng.encrypt_lwe_ciphertext_vector_64(key, plaintext_vector, new Variance(2 ** -50));
+    let buffer = ciphertext_vector.to_array_buffer();
+    assert.ok(buffer instanceof ArrayBuffer);
+    let transferred = structuredClone(buffer, {transfer: [buffer]});
+    assert.strictEqual(buffer.byteLength, 0);
+    let recovered = LweCiphertextVector64.from_array_buffer(transferred);
+    let decrypted = eng.retrieve_plaintext_vector_64(eng.decrypt_lwe_ciphertext_vector_64(key, recovered));
+    decrypted.forEach((v, i) => assert.strictEqual((v + (1n << 59n)) >> 60n, val[i] >> 60n));
+});
+
+test('glwe_relinearization_key_serialization_64', (t) => {
+    let seeder = new JsFunctionSeeder(seed);
+    let eng = new DefaultEngine(seeder);
+    let ser_eng = new DefaultSerializationEngine();
+    let key = eng.create_glwe_secret_key_64(new GlweDimension(2), new PolynomialSize(256));
+    let rlk = eng.create_glwe_relinearization_key_64(
+        key,
+        new DecompositionLevelCount(3),
+        new DecompositionBaseLog(7),
+        new Variance(2 ** -50),
+    );
+    let bytes = ser_eng.serialize_glwe_relinearization_key_64(rlk);
+    let recovered = ser_eng.deserialize_glwe_relinearization_key_64(bytes);
+    assert.deepEqual(ser_eng.serialize_glwe_relinearization_key_64(recovered), bytes);
+});
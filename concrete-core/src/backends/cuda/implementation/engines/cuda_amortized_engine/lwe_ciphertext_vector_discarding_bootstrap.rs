@@ -4,7 +4,9 @@ use crate::backends::cuda::implementation::entities::{
     CudaFourierLweBootstrapKey32, CudaFourierLweBootstrapKey64, CudaGlweCiphertextVector32,
     CudaGlweCiphertextVector64, CudaLweCiphertextVector32, CudaLweCiphertextVector64,
 };
-use crate::backends::cuda::private::crypto::bootstrap::execute_lwe_ciphertext_vector_amortized_bootstrap_on_gpu;
+use crate::backends::cuda::private::crypto::bootstrap::{
+    execute_lwe_ciphertext_vector_amortized_bootstrap_on_gpu, synchronize_and_recycle_buffers,
+};
 use crate::specification::engines::{
     LweCiphertextVectorDiscardingBootstrapEngine, LweCiphertextVectorDiscardingBootstrapError,
 };
@@ -87,7 +89,6 @@ impl
     ///
     /// // 2. cuda engine
     /// let mut cuda_engine = CudaEngine::new(())?;
-    /// let mut cuda_amortized_engine = AmortizedCudaEngine::new(())?;
     /// // convert input to GPU (split over the GPUs)
     /// let d_input_ciphertext_vector: CudaLweCiphertextVector32 =
     ///     cuda_engine.convert_lwe_ciphertext_vector(&h_input_ciphertext_vector)?;
@@ -102,6 +103,8 @@ impl
     ///     .zero_encrypt_lwe_ciphertext_vector(&h_dummy_key, noise, LweCiphertextCount(3))?;
     /// let mut d_output_ciphertext_vector: CudaLweCiphertextVector32 =
     ///     cuda_engine.convert_lwe_ciphertext_vector(&h_zero_output_ciphertext_vector)?;
+    /// // the amortized engine reuses the streams of the engine which converted the entities
+    /// let mut cuda_amortized_engine = AmortizedCudaEngine::from(cuda_engine);
     /// cuda_amortized_engine.discard_bootstrap_lwe_ciphertext_vector(
     ///     &mut d_output_ciphertext_vector,
     ///     &d_input_ciphertext_vector,
@@ -123,8 +126,9 @@ impl
         LweCiphertextVectorDiscardingBootstrapError::perform_generic_checks(
             output, input, acc, bsk,
         )?;
-        let poly_size = bsk.polynomial_size().0;
+        let poly_size = bsk.polynomial_size();
         check_poly_size!(poly_size);
+        check_entities_origin!(self, output, input, acc, bsk);
         unsafe { self.discard_bootstrap_lwe_ciphertext_vector_unchecked(output, input, acc, bsk) };
         Ok(())
     }
@@ -136,7 +140,7 @@ impl
         acc: &CudaGlweCiphertextVector32,
         bsk: &CudaFourierLweBootstrapKey32,
     ) {
-        execute_lwe_ciphertext_vector_amortized_bootstrap_on_gpu::<u32>(
+        let buffers = execute_lwe_ciphertext_vector_amortized_bootstrap_on_gpu::<u32>(
             self.get_cuda_streams(),
             &mut output.0,
             &input.0,
@@ -145,6 +149,7 @@ impl
             self.get_number_of_gpus(),
             self.get_cuda_shared_memory(),
         );
+        synchronize_and_recycle_buffers(self.get_cuda_streams(), buffers);
     }
 }
 
@@ -225,7 +230,6 @@ impl
     ///
     /// // 2. cuda engine
     /// let mut cuda_engine = CudaEngine::new(())?;
-    /// let mut cuda_amortized_engine = AmortizedCudaEngine::new(())?;
     /// // convert input to GPU (split over the GPUs)
     /// let d_input_ciphertext_vector: CudaLweCiphertextVector64 =
     ///     cuda_engine.convert_lwe_ciphertext_vector(&h_input_ciphertext_vector)?;
@@ -240,6 +244,8 @@ impl
     ///     .zero_encrypt_lwe_ciphertext_vector(&h_dummy_key, noise, LweCiphertextCount(3))?;
     /// let mut d_output_ciphertext_vector: CudaLweCiphertextVector64 =
     ///     cuda_engine.convert_lwe_ciphertext_vector(&h_zero_output_ciphertext_vector)?;
+    /// // the amortized engine reuses the streams of the engine which converted the entities
+    /// let mut cuda_amortized_engine = AmortizedCudaEngine::from(cuda_engine);
     /// cuda_amortized_engine.discard_bootstrap_lwe_ciphertext_vector(
     ///     &mut d_output_ciphertext_vector,
     ///     &d_input_ciphertext_vector,
@@ -261,8 +267,9 @@ impl
         LweCiphertextVectorDiscardingBootstrapError::perform_generic_checks(
             output, input, acc, bsk,
         )?;
-        let poly_size = bsk.polynomial_size().0;
+        let poly_size = bsk.polynomial_size();
         check_poly_size!(poly_size);
+        check_entities_origin!(self, output, input, acc, bsk);
         unsafe { self.discard_bootstrap_lwe_ciphertext_vector_unchecked(output, input, acc, bsk) };
         Ok(())
     }
@@ -274,7 +281,7 @@ impl
         acc: &CudaGlweCiphertextVector64,
         bsk: &CudaFourierLweBootstrapKey64,
     ) {
-        execute_lwe_ciphertext_vector_amortized_bootstrap_on_gpu::<u64>(
+        let buffers = execute_lwe_ciphertext_vector_amortized_bootstrap_on_gpu::<u64>(
             self.get_cuda_streams(),
             &mut output.0,
             &input.0,
@@ -283,5 +290,6 @@ impl
             self.get_number_of_gpus(),
             self.get_cuda_shared_memory(),
         );
+        synchronize_and_recycle_buffers(self.get_cuda_streams(), buffers);
     }
 }
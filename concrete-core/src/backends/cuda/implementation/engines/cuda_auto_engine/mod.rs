@@ -0,0 +1,88 @@
+use crate::backends::cuda::engines::{CudaEngine, CudaError};
+use crate::backends::cuda::private::device::{CudaStream, NumberOfGpus};
+use crate::prelude::numeric::UnsignedInteger;
+use crate::prelude::sealed::AbstractEngineSeal;
+use crate::prelude::{
+    AbstractEngine, CudaBootstrapImplementation, DecompositionLevelCount, LweCiphertextCount,
+    PolynomialSize, SharedMemoryAmount,
+};
+
+/// A variant of CudaEngine exposed by the cuda backend.
+///
+/// This engine selects, for each bootstrap, the implementation expected to be the fastest
+/// between the low latency bootstrap of [`CudaEngine`](super::CudaEngine) and the amortized
+/// bootstrap of [`AmortizedCudaEngine`](super::AmortizedCudaEngine), depending on the parameters
+/// and on the number of input ciphertexts. The selection can be queried beforehand with
+/// [`AutoCudaEngine::get_fastest_bootstrap_implementation`].
+///
+/// The streams are set up as the ones of the engine returned by
+/// [`CudaEngine::new`](super::CudaEngine), and the same checks are performed on the inputs.
+/// An [`AutoCudaEngine`] can also be obtained from an existing [`CudaEngine`], in which case it
+/// reuses its streams: this is needed to operate on the entities converted by this engine when
+/// the `backend_cuda_debug` feature is enabled.
+#[derive(Debug)]
+pub struct AutoCudaEngine {
+    engine: CudaEngine,
+}
+
+impl AbstractEngineSeal for AutoCudaEngine {}
+
+impl AbstractEngine for AutoCudaEngine {
+    type EngineError = CudaError;
+
+    type Parameters = ();
+
+    fn new(parameters: Self::Parameters) -> Result<Self, Self::EngineError> {
+        Ok(AutoCudaEngine {
+            engine: CudaEngine::new(parameters)?,
+        })
+    }
+}
+
+impl From<CudaEngine> for AutoCudaEngine {
+    fn from(engine: CudaEngine) -> Self {
+        AutoCudaEngine { engine }
+    }
+}
+
+impl AutoCudaEngine {
+    /// Get the number of available GPUs from the engine
+    pub fn get_number_of_gpus(&self) -> NumberOfGpus {
+        self.engine.get_number_of_gpus()
+    }
+    /// Get the Cuda streams from the engine
+    pub fn get_cuda_streams(&self) -> &Vec<CudaStream> {
+        self.engine.get_cuda_streams()
+    }
+    /// Get the size of the shared memory (on device 0)
+    pub fn get_cuda_shared_memory(&self) -> SharedMemoryAmount {
+        self.engine.get_cuda_shared_memory()
+    }
+    /// Frees the device buffers kept in the memory pools of the engine
+    ///
+    /// See [`CudaEngine::clear_memory_pool`](super::CudaEngine::clear_memory_pool).
+    pub fn clear_memory_pool(&mut self) {
+        self.engine.clear_memory_pool();
+    }
+    /// Get the total size of the device buffers kept in the memory pools, in bytes
+    pub fn get_memory_pool_size(&self) -> usize {
+        self.engine.get_memory_pool_size()
+    }
+    /// Get the bootstrap implementation this engine uses to bootstrap `lwe_ciphertext_count`
+    /// ciphertexts of `Scalar` integers, with a key using the given polynomial size and
+    /// decomposition level count.
+    pub fn get_fastest_bootstrap_implementation<Scalar: UnsignedInteger>(
+        &self,
+        polynomial_size: PolynomialSize,
+        level_count: DecompositionLevelCount,
+        lwe_ciphertext_count: LweCiphertextCount,
+    ) -> CudaBootstrapImplementation {
+        self.engine.get_fastest_bootstrap_implementation::<Scalar>(
+            polynomial_size,
+            level_count,
+            lwe_ciphertext_count,
+        )
+    }
+}
+
+mod lwe_ciphertext_vector_discarding_bootstrap;
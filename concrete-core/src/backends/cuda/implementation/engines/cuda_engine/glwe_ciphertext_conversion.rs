@@ -130,6 +130,7 @@ impl GlweCiphertextConversionEngine<CudaGlweCiphertext32, GlweCiphertext32> for
         &mut self,
         input: &CudaGlweCiphertext32,
     ) -> Result<GlweCiphertext32, GlweCiphertextConversionError<CudaError>> {
+        check_entities_origin!(self, input);
         Ok(unsafe { self.convert_glwe_ciphertext_unchecked(input) })
     }
 
@@ -264,6 +265,7 @@ impl GlweCiphertextConversionEngine<CudaGlweCiphertext64, GlweCiphertext64> for
         &mut self,
         input: &CudaGlweCiphertext64,
     ) -> Result<GlweCiphertext64, GlweCiphertextConversionError<CudaError>> {
+        check_entities_origin!(self, input);
         Ok(unsafe { self.convert_glwe_ciphertext_unchecked(input) })
     }
 
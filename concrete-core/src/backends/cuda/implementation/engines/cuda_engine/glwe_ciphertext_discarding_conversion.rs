@@ -3,6 +3,12 @@ use crate::backends::cuda::implementation::entities::CudaGlweCiphertext64;
 use crate::prelude::{GlweCiphertextDiscardingConversionError, GlweCiphertextMutView64};
 use crate::specification::engines::GlweCiphertextDiscardingConversionEngine;
 
+impl From<CudaError> for GlweCiphertextDiscardingConversionError<CudaError> {
+    fn from(err: CudaError) -> Self {
+        Self::Engine(err)
+    }
+}
+
 /// # Description
 /// Convert a GLWE ciphertext vector with 64 bits of precision from GPU 0 to a view on the CPU.
 impl GlweCiphertextDiscardingConversionEngine<CudaGlweCiphertext64, GlweCiphertextMutView64<'_>>
@@ -67,6 +73,7 @@ impl GlweCiphertextDiscardingConversionEngine<CudaGlweCiphertext64, GlweCipherte
         input: &CudaGlweCiphertext64,
     ) -> Result<(), GlweCiphertextDiscardingConversionError<CudaError>> {
         GlweCiphertextDiscardingConversionError::perform_generic_checks(output, input)?;
+        check_entities_origin!(self, input);
         unsafe { self.discard_convert_glwe_ciphertext_unchecked(output, input) };
         Ok(())
     }
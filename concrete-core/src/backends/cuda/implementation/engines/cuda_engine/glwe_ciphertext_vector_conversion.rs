@@ -159,6 +159,7 @@ impl GlweCiphertextVectorConversionEngine<CudaGlweCiphertextVector32, GlweCipher
         &mut self,
         input: &CudaGlweCiphertextVector32,
     ) -> Result<GlweCiphertextVector32, GlweCiphertextVectorConversionError<CudaError>> {
+        check_entities_origin!(self, input);
         Ok(unsafe { self.convert_glwe_ciphertext_vector_unchecked(input) })
     }
 
@@ -325,6 +326,7 @@ impl GlweCiphertextVectorConversionEngine<CudaGlweCiphertextVector64, GlweCipher
         &mut self,
         input: &CudaGlweCiphertextVector64,
     ) -> Result<GlweCiphertextVector64, GlweCiphertextVectorConversionError<CudaError>> {
+        check_entities_origin!(self, input);
         Ok(unsafe { self.convert_glwe_ciphertext_vector_unchecked(input) })
     }
 
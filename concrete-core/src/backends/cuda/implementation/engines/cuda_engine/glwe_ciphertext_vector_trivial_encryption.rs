@@ -0,0 +1,240 @@
+use crate::backends::cuda::engines::{CudaEngine, CudaError};
+use crate::backends::cuda::implementation::entities::{
+    CudaGlweCiphertextVector32, CudaGlweCiphertextVector64,
+};
+use crate::backends::cuda::private::crypto::glwe::list::CudaGlweList;
+use crate::commons::crypto::glwe::GlweList;
+use crate::commons::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::prelude::{
+    CiphertextCount, GlweCiphertextCount, GlweSize, PlaintextVector32, PlaintextVector64,
+    PlaintextVectorEntity, PolynomialSize,
+};
+use crate::specification::engines::{
+    GlweCiphertextVectorTrivialEncryptionEngine, GlweCiphertextVectorTrivialEncryptionError,
+};
+
+impl From<CudaError> for GlweCiphertextVectorTrivialEncryptionError<CudaError> {
+    fn from(err: CudaError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description
+/// Trivially encrypt a plaintext vector with 32 bits of precision into a GLWE ciphertext vector
+/// on the GPU.
+/// Like for the conversion engine, the whole vector of GLWE ciphertexts is copied to all the
+/// GPUs, so that it can be used as the input vector of lookup tables for the bootstrap.
+impl GlweCiphertextVectorTrivialEncryptionEngine<PlaintextVector32, CudaGlweCiphertextVector32>
+    for CudaEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(512);
+    /// let ciphertext_count = GlweCiphertextCount(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; ciphertext_count.0 * polynomial_size.0];
+    ///
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let h_plaintext_vector: PlaintextVector32 =
+    ///     default_engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let mut cuda_engine = CudaEngine::new(())?;
+    /// // DISCLAIMER: trivial encryption is NOT secure, and DOES NOT hide the message at all.
+    /// let d_ciphertext_vector: CudaGlweCiphertextVector32 = cuda_engine
+    ///     .trivially_encrypt_glwe_ciphertext_vector(
+    ///         glwe_dimension.to_glwe_size(),
+    ///         ciphertext_count,
+    ///         &h_plaintext_vector,
+    ///     )?;
+    ///
+    /// assert_eq!(d_ciphertext_vector.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(d_ciphertext_vector.polynomial_size(), polynomial_size);
+    /// assert_eq!(d_ciphertext_vector.glwe_ciphertext_count(), ciphertext_count);
+    ///
+    /// let h_ciphertext_vector: GlweCiphertextVector32 =
+    ///     cuda_engine.convert_glwe_ciphertext_vector(&d_ciphertext_vector)?;
+    /// let h_output: PlaintextVector32 =
+    ///     default_engine.trivially_decrypt_glwe_ciphertext_vector(&h_ciphertext_vector)?;
+    /// assert_eq!(
+    ///     default_engine.retrieve_plaintext_vector(&h_output)?,
+    ///     input
+    /// );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn trivially_encrypt_glwe_ciphertext_vector(
+        &mut self,
+        glwe_size: GlweSize,
+        glwe_ciphertext_count: GlweCiphertextCount,
+        input: &PlaintextVector32,
+    ) -> Result<CudaGlweCiphertextVector32, GlweCiphertextVectorTrivialEncryptionError<CudaError>>
+    {
+        GlweCiphertextVectorTrivialEncryptionError::perform_generic_checks(
+            glwe_ciphertext_count,
+            input,
+        )?;
+        let data_per_gpu = glwe_size.0 * input.plaintext_count().0;
+        let size = data_per_gpu as u64 * std::mem::size_of::<u32>() as u64;
+        for stream in self.streams.iter() {
+            stream.check_device_memory(size)?;
+        }
+        Ok(unsafe {
+            self.trivially_encrypt_glwe_ciphertext_vector_unchecked(
+                glwe_size,
+                glwe_ciphertext_count,
+                input,
+            )
+        })
+    }
+
+    unsafe fn trivially_encrypt_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        glwe_size: GlweSize,
+        glwe_ciphertext_count: GlweCiphertextCount,
+        input: &PlaintextVector32,
+    ) -> CudaGlweCiphertextVector32 {
+        let polynomial_size = PolynomialSize(input.plaintext_count().0 / glwe_ciphertext_count.0);
+        let mut h_ciphertext_vector = GlweList::allocate(
+            0_u32,
+            polynomial_size,
+            glwe_size.to_glwe_dimension(),
+            CiphertextCount(glwe_ciphertext_count.0),
+        );
+        h_ciphertext_vector.fill_with_trivial_encryption(&input.0);
+
+        // Copy the entire vector over all GPUs
+        let data_per_gpu = glwe_size.0 * input.plaintext_count().0;
+        let mut vecs = Vec::with_capacity(self.get_number_of_gpus().0);
+        for stream in self.streams.iter() {
+            let mut vec = stream.malloc::<u32>(data_per_gpu as u32);
+            stream.copy_to_gpu::<u32>(&mut vec, h_ciphertext_vector.as_tensor().as_slice());
+            vecs.push(vec);
+        }
+        CudaGlweCiphertextVector32(CudaGlweList::<u32> {
+            d_vecs: vecs,
+            glwe_ciphertext_count,
+            glwe_dimension: glwe_size.to_glwe_dimension(),
+            polynomial_size,
+        })
+    }
+}
+
+/// # Description
+/// Trivially encrypt a plaintext vector with 64 bits of precision into a GLWE ciphertext vector
+/// on the GPU.
+/// Like for the conversion engine, the whole vector of GLWE ciphertexts is copied to all the
+/// GPUs, so that it can be used as the input vector of lookup tables for the bootstrap.
+impl GlweCiphertextVectorTrivialEncryptionEngine<PlaintextVector64, CudaGlweCiphertextVector64>
+    for CudaEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(512);
+    /// let ciphertext_count = GlweCiphertextCount(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; ciphertext_count.0 * polynomial_size.0];
+    ///
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let h_plaintext_vector: PlaintextVector64 =
+    ///     default_engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let mut cuda_engine = CudaEngine::new(())?;
+    /// // DISCLAIMER: trivial encryption is NOT secure, and DOES NOT hide the message at all.
+    /// let d_ciphertext_vector: CudaGlweCiphertextVector64 = cuda_engine
+    ///     .trivially_encrypt_glwe_ciphertext_vector(
+    ///         glwe_dimension.to_glwe_size(),
+    ///         ciphertext_count,
+    ///         &h_plaintext_vector,
+    ///     )?;
+    ///
+    /// assert_eq!(d_ciphertext_vector.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(d_ciphertext_vector.polynomial_size(), polynomial_size);
+    /// assert_eq!(d_ciphertext_vector.glwe_ciphertext_count(), ciphertext_count);
+    ///
+    /// let h_ciphertext_vector: GlweCiphertextVector64 =
+    ///     cuda_engine.convert_glwe_ciphertext_vector(&d_ciphertext_vector)?;
+    /// let h_output: PlaintextVector64 =
+    ///     default_engine.trivially_decrypt_glwe_ciphertext_vector(&h_ciphertext_vector)?;
+    /// assert_eq!(
+    ///     default_engine.retrieve_plaintext_vector(&h_output)?,
+    ///     input
+    /// );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn trivially_encrypt_glwe_ciphertext_vector(
+        &mut self,
+        glwe_size: GlweSize,
+        glwe_ciphertext_count: GlweCiphertextCount,
+        input: &PlaintextVector64,
+    ) -> Result<CudaGlweCiphertextVector64, GlweCiphertextVectorTrivialEncryptionError<CudaError>>
+    {
+        GlweCiphertextVectorTrivialEncryptionError::perform_generic_checks(
+            glwe_ciphertext_count,
+            input,
+        )?;
+        let data_per_gpu = glwe_size.0 * input.plaintext_count().0;
+        let size = data_per_gpu as u64 * std::mem::size_of::<u64>() as u64;
+        for stream in self.streams.iter() {
+            stream.check_device_memory(size)?;
+        }
+        Ok(unsafe {
+            self.trivially_encrypt_glwe_ciphertext_vector_unchecked(
+                glwe_size,
+                glwe_ciphertext_count,
+                input,
+            )
+        })
+    }
+
+    unsafe fn trivially_encrypt_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        glwe_size: GlweSize,
+        glwe_ciphertext_count: GlweCiphertextCount,
+        input: &PlaintextVector64,
+    ) -> CudaGlweCiphertextVector64 {
+        let polynomial_size = PolynomialSize(input.plaintext_count().0 / glwe_ciphertext_count.0);
+        let mut h_ciphertext_vector = GlweList::allocate(
+            0_u64,
+            polynomial_size,
+            glwe_size.to_glwe_dimension(),
+            CiphertextCount(glwe_ciphertext_count.0),
+        );
+        h_ciphertext_vector.fill_with_trivial_encryption(&input.0);
+
+        // Copy the entire vector over all GPUs
+        let data_per_gpu = glwe_size.0 * input.plaintext_count().0;
+        let mut vecs = Vec::with_capacity(self.get_number_of_gpus().0);
+        for stream in self.streams.iter() {
+            let mut vec = stream.malloc::<u64>(data_per_gpu as u32);
+            stream.copy_to_gpu::<u64>(&mut vec, h_ciphertext_vector.as_tensor().as_slice());
+            vecs.push(vec);
+        }
+        CudaGlweCiphertextVector64(CudaGlweList::<u64> {
+            d_vecs: vecs,
+            glwe_ciphertext_count,
+            glwe_dimension: glwe_size.to_glwe_dimension(),
+            polynomial_size,
+        })
+    }
+}
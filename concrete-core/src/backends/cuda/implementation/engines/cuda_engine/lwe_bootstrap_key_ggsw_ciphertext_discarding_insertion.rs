@@ -0,0 +1,190 @@
+use crate::backends::cuda::engines::CudaError;
+use crate::backends::cuda::implementation::engines::CudaEngine;
+use crate::backends::cuda::implementation::entities::{
+    CudaFourierLweBootstrapKey32, CudaFourierLweBootstrapKey64,
+};
+use crate::backends::cuda::private::crypto::bootstrap::insert_ggsw_ciphertext_in_lwe_bootstrap_key_on_gpu;
+use crate::prelude::{GgswCiphertext32, GgswCiphertext64};
+use crate::specification::engines::{
+    LweBootstrapKeyGgswCiphertextDiscardingInsertionEngine,
+    LweBootstrapKeyGgswCiphertextDiscardingInsertionError,
+};
+use concrete_commons::parameters::GgswCiphertextIndex;
+
+impl From<CudaError> for LweBootstrapKeyGgswCiphertextDiscardingInsertionError<CudaError> {
+    fn from(err: CudaError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description
+/// Convert a GGSW ciphertext corresponding to 32 bits of precision from the CPU to the Fourier
+/// domain on all the GPUs, and write it in place of a GGSW ciphertext of a bootstrap key.
+impl
+    LweBootstrapKeyGgswCiphertextDiscardingInsertionEngine<
+        GgswCiphertext32,
+        CudaFourierLweBootstrapKey32,
+    > for CudaEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextIndex, GlweDimension,
+    ///     LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(1), PolynomialSize(512));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey32 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey32 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let mut cuda_engine = CudaEngine::new(())?;
+    /// let mut d_fourier_bsk: CudaFourierLweBootstrapKey32 =
+    ///     cuda_engine.convert_lwe_bootstrap_key(&bsk)?;
+    ///
+    /// // Refresh the first GGSW ciphertext of the key
+    /// let ggsw: GgswCiphertext32 =
+    ///     default_engine.extract_lwe_bootstrap_key_ggsw_ciphertext(&bsk, GgswCiphertextIndex(0))?;
+    /// cuda_engine.discard_insert_lwe_bootstrap_key_ggsw_ciphertext(
+    ///     &mut d_fourier_bsk,
+    ///     &ggsw,
+    ///     GgswCiphertextIndex(0),
+    /// )?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_insert_lwe_bootstrap_key_ggsw_ciphertext(
+        &mut self,
+        bootstrap_key: &mut CudaFourierLweBootstrapKey32,
+        input: &GgswCiphertext32,
+        index: GgswCiphertextIndex,
+    ) -> Result<(), LweBootstrapKeyGgswCiphertextDiscardingInsertionError<CudaError>> {
+        LweBootstrapKeyGgswCiphertextDiscardingInsertionError::perform_generic_checks(
+            bootstrap_key,
+            input,
+            index,
+        )?;
+        check_entities_origin!(self, bootstrap_key);
+        unsafe {
+            self.discard_insert_lwe_bootstrap_key_ggsw_ciphertext_unchecked(
+                bootstrap_key,
+                input,
+                index,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_insert_lwe_bootstrap_key_ggsw_ciphertext_unchecked(
+        &mut self,
+        bootstrap_key: &mut CudaFourierLweBootstrapKey32,
+        input: &GgswCiphertext32,
+        index: GgswCiphertextIndex,
+    ) {
+        insert_ggsw_ciphertext_in_lwe_bootstrap_key_on_gpu::<u32, _>(
+            self.get_cuda_streams(),
+            &mut bootstrap_key.0,
+            &input.0,
+            index,
+        );
+    }
+}
+
+/// # Description
+/// Convert a GGSW ciphertext corresponding to 64 bits of precision from the CPU to the Fourier
+/// domain on all the GPUs, and write it in place of a GGSW ciphertext of a bootstrap key.
+impl
+    LweBootstrapKeyGgswCiphertextDiscardingInsertionEngine<
+        GgswCiphertext64,
+        CudaFourierLweBootstrapKey64,
+    > for CudaEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextIndex, GlweDimension,
+    ///     LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(1), PolynomialSize(512));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey64 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let mut cuda_engine = CudaEngine::new(())?;
+    /// let mut d_fourier_bsk: CudaFourierLweBootstrapKey64 =
+    ///     cuda_engine.convert_lwe_bootstrap_key(&bsk)?;
+    ///
+    /// // Refresh the first GGSW ciphertext of the key
+    /// let ggsw: GgswCiphertext64 =
+    ///     default_engine.extract_lwe_bootstrap_key_ggsw_ciphertext(&bsk, GgswCiphertextIndex(0))?;
+    /// cuda_engine.discard_insert_lwe_bootstrap_key_ggsw_ciphertext(
+    ///     &mut d_fourier_bsk,
+    ///     &ggsw,
+    ///     GgswCiphertextIndex(0),
+    /// )?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_insert_lwe_bootstrap_key_ggsw_ciphertext(
+        &mut self,
+        bootstrap_key: &mut CudaFourierLweBootstrapKey64,
+        input: &GgswCiphertext64,
+        index: GgswCiphertextIndex,
+    ) -> Result<(), LweBootstrapKeyGgswCiphertextDiscardingInsertionError<CudaError>> {
+        LweBootstrapKeyGgswCiphertextDiscardingInsertionError::perform_generic_checks(
+            bootstrap_key,
+            input,
+            index,
+        )?;
+        check_entities_origin!(self, bootstrap_key);
+        unsafe {
+            self.discard_insert_lwe_bootstrap_key_ggsw_ciphertext_unchecked(
+                bootstrap_key,
+                input,
+                index,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_insert_lwe_bootstrap_key_ggsw_ciphertext_unchecked(
+        &mut self,
+        bootstrap_key: &mut CudaFourierLweBootstrapKey64,
+        input: &GgswCiphertext64,
+        index: GgswCiphertextIndex,
+    ) {
+        insert_ggsw_ciphertext_in_lwe_bootstrap_key_on_gpu::<u64, _>(
+            self.get_cuda_streams(),
+            &mut bootstrap_key.0,
+            &input.0,
+            index,
+        );
+    }
+}
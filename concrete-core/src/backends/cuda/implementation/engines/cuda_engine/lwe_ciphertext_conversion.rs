@@ -112,6 +112,7 @@ impl LweCiphertextConversionEngine<CudaLweCiphertext32, LweCiphertext32> for Cud
         &mut self,
         input: &CudaLweCiphertext32,
     ) -> Result<LweCiphertext32, LweCiphertextConversionError<CudaError>> {
+        check_entities_origin!(self, input);
         Ok(unsafe { self.convert_lwe_ciphertext_unchecked(input) })
     }
 
@@ -225,6 +226,7 @@ impl LweCiphertextConversionEngine<CudaLweCiphertext64, LweCiphertext64> for Cud
         &mut self,
         input: &CudaLweCiphertext64,
     ) -> Result<LweCiphertext64, LweCiphertextConversionError<CudaError>> {
+        check_entities_origin!(self, input);
         Ok(unsafe { self.convert_lwe_ciphertext_unchecked(input) })
     }
 
@@ -119,6 +119,7 @@ impl
         bsk: &CudaFourierLweBootstrapKey32,
     ) -> Result<(), LweCiphertextDiscardingBootstrapError<CudaError>> {
         LweCiphertextDiscardingBootstrapError::perform_generic_checks(output, input, acc, bsk)?;
+        check_entities_origin!(self, output, input, acc, bsk);
         let poly_size = bsk.polynomial_size();
         check_poly_size!(poly_size);
         let glwe_dim = bsk.glwe_dimension();
@@ -135,7 +136,7 @@ impl
         bsk: &CudaFourierLweBootstrapKey32,
     ) {
         let stream = self.streams.first().unwrap();
-        let mut test_vector_indexes = stream.malloc::<u32>(1);
+        let mut test_vector_indexes = stream.malloc_pooled::<u32>(1);
         stream.copy_to_gpu(&mut test_vector_indexes, &[0]);
 
         stream.discard_bootstrap_low_latency_lwe_ciphertext_vector::<u32>(
@@ -152,6 +153,8 @@ impl
             LweCiphertextIndex(0),
             self.get_cuda_shared_memory(),
         );
+        stream.synchronize_stream();
+        stream.recycle(test_vector_indexes);
     }
 }
 
@@ -257,6 +260,7 @@ impl
         bsk: &CudaFourierLweBootstrapKey64,
     ) -> Result<(), LweCiphertextDiscardingBootstrapError<CudaError>> {
         LweCiphertextDiscardingBootstrapError::perform_generic_checks(output, input, acc, bsk)?;
+        check_entities_origin!(self, output, input, acc, bsk);
         let poly_size = bsk.polynomial_size();
         check_poly_size!(poly_size);
         let glwe_dim = bsk.glwe_dimension();
@@ -273,7 +277,7 @@ impl
         bsk: &CudaFourierLweBootstrapKey64,
     ) {
         let stream = self.streams.first().unwrap();
-        let mut test_vector_indexes = stream.malloc::<u32>(1);
+        let mut test_vector_indexes = stream.malloc_pooled::<u32>(1);
         stream.copy_to_gpu(&mut test_vector_indexes, &[0]);
 
         stream.discard_bootstrap_low_latency_lwe_ciphertext_vector::<u64>(
@@ -290,5 +294,7 @@ impl
             LweCiphertextIndex(0),
             self.get_cuda_shared_memory(),
         );
+        stream.synchronize_stream();
+        stream.recycle(test_vector_indexes);
     }
 }
@@ -6,6 +6,12 @@ use crate::specification::engines::{
     LweCiphertextDiscardingConversionEngine, LweCiphertextDiscardingConversionError,
 };
 
+impl From<CudaError> for LweCiphertextDiscardingConversionError<CudaError> {
+    fn from(err: CudaError) -> Self {
+        Self::Engine(err)
+    }
+}
+
 /// # Description
 ///
 /// Convert an LWE ciphertext with 32 bits of precision from GPU 0 to a view on the CPU.
@@ -61,6 +67,7 @@ impl LweCiphertextDiscardingConversionEngine<CudaLweCiphertext32, LweCiphertextM
         output: &mut LweCiphertextMutView32,
         input: &CudaLweCiphertext32,
     ) -> Result<(), LweCiphertextDiscardingConversionError<CudaError>> {
+        check_entities_origin!(self, input);
         unsafe { self.discard_convert_lwe_ciphertext_unchecked(output, input) };
         Ok(())
     }
@@ -128,6 +135,7 @@ impl LweCiphertextDiscardingConversionEngine<CudaLweCiphertext32, LweCiphertext3
         output: &mut LweCiphertext32,
         input: &CudaLweCiphertext32,
     ) -> Result<(), LweCiphertextDiscardingConversionError<CudaError>> {
+        check_entities_origin!(self, input);
         unsafe { self.discard_convert_lwe_ciphertext_unchecked(output, input) };
         Ok(())
     }
@@ -197,6 +205,7 @@ impl LweCiphertextDiscardingConversionEngine<LweCiphertext32, CudaLweCiphertext3
         output: &mut CudaLweCiphertext32,
         input: &LweCiphertext32,
     ) -> Result<(), LweCiphertextDiscardingConversionError<CudaError>> {
+        check_entities_origin!(self, output);
         unsafe { self.discard_convert_lwe_ciphertext_unchecked(output, input) };
         Ok(())
     }
@@ -267,6 +276,7 @@ impl LweCiphertextDiscardingConversionEngine<CudaLweCiphertext64, LweCiphertextM
         output: &mut LweCiphertextMutView64,
         input: &CudaLweCiphertext64,
     ) -> Result<(), LweCiphertextDiscardingConversionError<CudaError>> {
+        check_entities_origin!(self, input);
         unsafe { self.discard_convert_lwe_ciphertext_unchecked(output, input) };
         Ok(())
     }
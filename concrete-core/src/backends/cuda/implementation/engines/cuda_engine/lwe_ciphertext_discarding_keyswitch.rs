@@ -99,6 +99,7 @@ impl
         ksk: &CudaLweKeyswitchKey32,
     ) -> Result<(), LweCiphertextDiscardingKeyswitchError<CudaError>> {
         LweCiphertextDiscardingKeyswitchError::perform_generic_checks(output, input, ksk)?;
+        check_entities_origin!(self, output, input, ksk);
         unsafe { self.discard_keyswitch_lwe_ciphertext_unchecked(output, input, ksk) };
         Ok(())
     }
@@ -208,6 +209,7 @@ impl
         ksk: &CudaLweKeyswitchKey64,
     ) -> Result<(), LweCiphertextDiscardingKeyswitchError<CudaError>> {
         LweCiphertextDiscardingKeyswitchError::perform_generic_checks(output, input, ksk)?;
+        check_entities_origin!(self, output, input, ksk);
         unsafe { self.discard_keyswitch_lwe_ciphertext_unchecked(output, input, ksk) };
         Ok(())
     }
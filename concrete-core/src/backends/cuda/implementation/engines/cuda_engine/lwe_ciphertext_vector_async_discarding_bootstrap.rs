@@ -0,0 +1,91 @@
+use crate::backends::cuda::engines::{
+    CudaError, CudaLweCiphertextVectorAsyncDiscardingBootstrapEngine, CudaOperationHandle,
+};
+use crate::backends::cuda::implementation::engines::CudaEngine;
+use crate::backends::cuda::implementation::entities::{
+    CudaFourierLweBootstrapKey32, CudaFourierLweBootstrapKey64, CudaGlweCiphertextVector32,
+    CudaGlweCiphertextVector64, CudaLweCiphertextVector32, CudaLweCiphertextVector64,
+};
+use crate::backends::cuda::private::crypto::bootstrap::execute_lwe_ciphertext_vector_low_latency_bootstrap_on_gpu;
+use crate::specification::engines::LweCiphertextVectorDiscardingBootstrapError;
+use crate::specification::entities::LweBootstrapKeyEntity;
+
+/// # Description
+/// An asynchronous discard bootstrap on a vector of input ciphertext vectors with 32 bits of
+/// precision, using the low latency bootstrap.
+impl
+    CudaLweCiphertextVectorAsyncDiscardingBootstrapEngine<
+        CudaFourierLweBootstrapKey32,
+        CudaGlweCiphertextVector32,
+        CudaLweCiphertextVector32,
+        CudaLweCiphertextVector32,
+    > for CudaEngine
+{
+    unsafe fn discard_bootstrap_lwe_ciphertext_vector_async<'a>(
+        &'a self,
+        output: &'a mut CudaLweCiphertextVector32,
+        input: &'a CudaLweCiphertextVector32,
+        acc: &'a CudaGlweCiphertextVector32,
+        bsk: &'a CudaFourierLweBootstrapKey32,
+    ) -> Result<CudaOperationHandle<'a>, LweCiphertextVectorDiscardingBootstrapError<CudaError>>
+    {
+        LweCiphertextVectorDiscardingBootstrapError::perform_generic_checks(
+            output, input, acc, bsk,
+        )?;
+        check_entities_origin!(self, output, input, acc, bsk);
+        let poly_size = bsk.polynomial_size();
+        check_poly_size!(poly_size);
+        let glwe_dim = bsk.glwe_dimension();
+        check_glwe_dim!(glwe_dim);
+        let buffers = execute_lwe_ciphertext_vector_low_latency_bootstrap_on_gpu::<u32>(
+            self.get_cuda_streams(),
+            &mut output.0,
+            &input.0,
+            &acc.0,
+            &bsk.0,
+            self.get_number_of_gpus(),
+            self.get_cuda_shared_memory(),
+        );
+        Ok(CudaOperationHandle::new(self.get_cuda_streams(), buffers))
+    }
+}
+
+/// # Description
+/// An asynchronous discard bootstrap on a vector of input ciphertext vectors with 64 bits of
+/// precision, using the low latency bootstrap.
+impl
+    CudaLweCiphertextVectorAsyncDiscardingBootstrapEngine<
+        CudaFourierLweBootstrapKey64,
+        CudaGlweCiphertextVector64,
+        CudaLweCiphertextVector64,
+        CudaLweCiphertextVector64,
+    > for CudaEngine
+{
+    unsafe fn discard_bootstrap_lwe_ciphertext_vector_async<'a>(
+        &'a self,
+        output: &'a mut CudaLweCiphertextVector64,
+        input: &'a CudaLweCiphertextVector64,
+        acc: &'a CudaGlweCiphertextVector64,
+        bsk: &'a CudaFourierLweBootstrapKey64,
+    ) -> Result<CudaOperationHandle<'a>, LweCiphertextVectorDiscardingBootstrapError<CudaError>>
+    {
+        LweCiphertextVectorDiscardingBootstrapError::perform_generic_checks(
+            output, input, acc, bsk,
+        )?;
+        check_entities_origin!(self, output, input, acc, bsk);
+        let poly_size = bsk.polynomial_size();
+        check_poly_size!(poly_size);
+        let glwe_dim = bsk.glwe_dimension();
+        check_glwe_dim!(glwe_dim);
+        let buffers = execute_lwe_ciphertext_vector_low_latency_bootstrap_on_gpu::<u64>(
+            self.get_cuda_streams(),
+            &mut output.0,
+            &input.0,
+            &acc.0,
+            &bsk.0,
+            self.get_number_of_gpus(),
+            self.get_cuda_shared_memory(),
+        );
+        Ok(CudaOperationHandle::new(self.get_cuda_streams(), buffers))
+    }
+}
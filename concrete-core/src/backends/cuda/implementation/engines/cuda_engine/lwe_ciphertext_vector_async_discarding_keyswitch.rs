@@ -0,0 +1,156 @@
+use crate::backends::cuda::engines::{
+    CudaError, CudaLweCiphertextVectorAsyncDiscardingKeyswitchEngine, CudaOperationHandle,
+};
+use crate::backends::cuda::implementation::engines::CudaEngine;
+use crate::backends::cuda::implementation::entities::{
+    CudaLweCiphertextVector32, CudaLweCiphertextVector64, CudaLweKeyswitchKey32,
+    CudaLweKeyswitchKey64,
+};
+use crate::backends::cuda::private::crypto::keyswitch::execute_lwe_ciphertext_vector_keyswitch_on_gpu;
+use crate::specification::engines::LweCiphertextVectorDiscardingKeyswitchError;
+
+/// # Description
+/// An asynchronous discard keyswitch on a vector of input ciphertext vectors with 32 bits of
+/// precision.
+impl
+    CudaLweCiphertextVectorAsyncDiscardingKeyswitchEngine<
+        CudaLweKeyswitchKey32,
+        CudaLweCiphertextVector32,
+        CudaLweCiphertextVector32,
+    > for CudaEngine
+{
+    unsafe fn discard_keyswitch_lwe_ciphertext_vector_async<'a>(
+        &'a self,
+        output: &'a mut CudaLweCiphertextVector32,
+        input: &'a CudaLweCiphertextVector32,
+        ksk: &'a CudaLweKeyswitchKey32,
+    ) -> Result<CudaOperationHandle<'a>, LweCiphertextVectorDiscardingKeyswitchError<CudaError>>
+    {
+        LweCiphertextVectorDiscardingKeyswitchError::perform_generic_checks(output, input, ksk)?;
+        check_entities_origin!(self, output, input, ksk);
+        execute_lwe_ciphertext_vector_keyswitch_on_gpu::<u32>(
+            self.get_cuda_streams(),
+            &mut output.0,
+            &input.0,
+            &ksk.0,
+            self.get_number_of_gpus(),
+        );
+        Ok(CudaOperationHandle::new(
+            self.get_cuda_streams(),
+            Vec::new(),
+        ))
+    }
+}
+
+/// # Description
+/// An asynchronous discard keyswitch on a vector of input ciphertext vectors with 64 bits of
+/// precision.
+impl
+    CudaLweCiphertextVectorAsyncDiscardingKeyswitchEngine<
+        CudaLweKeyswitchKey64,
+        CudaLweCiphertextVector64,
+        CudaLweCiphertextVector64,
+    > for CudaEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    ///
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Generate two secret keys
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 =
+    ///     default_engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 =
+    ///     default_engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// // Generate keyswitch keys to switch between first_key and second_key
+    /// let h_ksk = default_engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// // Encrypt something
+    /// let h_plaintext_vector: PlaintextVector64 =
+    ///     default_engine.create_plaintext_vector_from(&input)?;
+    /// let h_ciphertext_vector: LweCiphertextVector64 =
+    ///     default_engine.encrypt_lwe_ciphertext_vector(&input_key, &h_plaintext_vector, noise)?;
+    /// let h_zero_ciphertext_vector: LweCiphertextVector64 = default_engine
+    ///     .zero_encrypt_lwe_ciphertext_vector(
+    ///         &output_key,
+    ///         noise,
+    ///         h_ciphertext_vector.lwe_ciphertext_count(),
+    ///     )?;
+    ///
+    /// // Copy to the GPU
+    /// let mut cuda_engine = CudaEngine::new(())?;
+    /// let d_ciphertext_vector: CudaLweCiphertextVector64 =
+    ///     cuda_engine.convert_lwe_ciphertext_vector(&h_ciphertext_vector)?;
+    /// let d_ksk: CudaLweKeyswitchKey64 = cuda_engine.convert_lwe_keyswitch_key(&h_ksk)?;
+    /// let mut d_keyswitched_ciphertext_vector: CudaLweCiphertextVector64 =
+    ///     cuda_engine.convert_lwe_ciphertext_vector(&h_zero_ciphertext_vector)?;
+    ///
+    /// // Enqueue the keyswitch on the GPU, and do some other work on the host meanwhile
+    /// // The handle is not leaked: it is synchronized below.
+    /// let handle = unsafe {
+    ///     cuda_engine.discard_keyswitch_lwe_ciphertext_vector_async(
+    ///         &mut d_keyswitched_ciphertext_vector,
+    ///         &d_ciphertext_vector,
+    ///         &d_ksk,
+    ///     )?
+    /// };
+    /// let _next_input: PlaintextVector64 = default_engine.create_plaintext_vector_from(&input)?;
+    /// handle.synchronize();
+    ///
+    /// assert_eq!(
+    ///     d_keyswitched_ciphertext_vector.lwe_dimension(),
+    ///     output_lwe_dimension
+    /// );
+    /// assert_eq!(
+    ///     d_keyswitched_ciphertext_vector.lwe_ciphertext_count(),
+    ///     LweCiphertextCount(3)
+    /// );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    unsafe fn discard_keyswitch_lwe_ciphertext_vector_async<'a>(
+        &'a self,
+        output: &'a mut CudaLweCiphertextVector64,
+        input: &'a CudaLweCiphertextVector64,
+        ksk: &'a CudaLweKeyswitchKey64,
+    ) -> Result<CudaOperationHandle<'a>, LweCiphertextVectorDiscardingKeyswitchError<CudaError>>
+    {
+        LweCiphertextVectorDiscardingKeyswitchError::perform_generic_checks(output, input, ksk)?;
+        check_entities_origin!(self, output, input, ksk);
+        execute_lwe_ciphertext_vector_keyswitch_on_gpu::<u64>(
+            self.get_cuda_streams(),
+            &mut output.0,
+            &input.0,
+            &ksk.0,
+            self.get_number_of_gpus(),
+        );
+        Ok(CudaOperationHandle::new(
+            self.get_cuda_streams(),
+            Vec::new(),
+        ))
+    }
+}
@@ -154,6 +154,7 @@ impl LweCiphertextVectorConversionEngine<CudaLweCiphertextVector32, LweCiphertex
         &mut self,
         input: &CudaLweCiphertextVector32,
     ) -> Result<LweCiphertextVector32, LweCiphertextVectorConversionError<CudaError>> {
+        check_entities_origin!(self, input);
         Ok(unsafe { self.convert_lwe_ciphertext_vector_unchecked(input) })
     }
 
@@ -306,6 +307,7 @@ impl LweCiphertextVectorConversionEngine<CudaLweCiphertextVector64, LweCiphertex
         &mut self,
         input: &CudaLweCiphertextVector64,
     ) -> Result<LweCiphertextVector64, LweCiphertextVectorConversionError<CudaError>> {
+        check_entities_origin!(self, input);
         Ok(unsafe { self.convert_lwe_ciphertext_vector_unchecked(input) })
     }
 
@@ -4,7 +4,9 @@ use crate::backends::cuda::implementation::entities::{
     CudaFourierLweBootstrapKey32, CudaFourierLweBootstrapKey64, CudaGlweCiphertextVector32,
     CudaGlweCiphertextVector64, CudaLweCiphertextVector32, CudaLweCiphertextVector64,
 };
-use crate::backends::cuda::private::crypto::bootstrap::execute_lwe_ciphertext_vector_low_latency_bootstrap_on_gpu;
+use crate::backends::cuda::private::crypto::bootstrap::{
+    execute_lwe_ciphertext_vector_low_latency_bootstrap_on_gpu, synchronize_and_recycle_buffers,
+};
 use crate::specification::engines::{
     LweCiphertextVectorDiscardingBootstrapEngine, LweCiphertextVectorDiscardingBootstrapError,
 };
@@ -122,6 +124,7 @@ impl
         LweCiphertextVectorDiscardingBootstrapError::perform_generic_checks(
             output, input, acc, bsk,
         )?;
+        check_entities_origin!(self, output, input, acc, bsk);
         let poly_size = bsk.polynomial_size();
         check_poly_size!(poly_size);
         let glwe_dim = bsk.glwe_dimension();
@@ -137,7 +140,7 @@ impl
         acc: &CudaGlweCiphertextVector32,
         bsk: &CudaFourierLweBootstrapKey32,
     ) {
-        execute_lwe_ciphertext_vector_low_latency_bootstrap_on_gpu::<u32>(
+        let buffers = execute_lwe_ciphertext_vector_low_latency_bootstrap_on_gpu::<u32>(
             self.get_cuda_streams(),
             &mut output.0,
             &input.0,
@@ -146,6 +149,7 @@ impl
             self.get_number_of_gpus(),
             self.get_cuda_shared_memory(),
         );
+        synchronize_and_recycle_buffers(self.get_cuda_streams(), buffers);
     }
 }
 
@@ -261,6 +265,7 @@ impl
         LweCiphertextVectorDiscardingBootstrapError::perform_generic_checks(
             output, input, acc, bsk,
         )?;
+        check_entities_origin!(self, output, input, acc, bsk);
         let poly_size = bsk.polynomial_size();
         check_poly_size!(poly_size);
         let glwe_dim = bsk.glwe_dimension();
@@ -276,7 +281,7 @@ impl
         acc: &CudaGlweCiphertextVector64,
         bsk: &CudaFourierLweBootstrapKey64,
     ) {
-        execute_lwe_ciphertext_vector_low_latency_bootstrap_on_gpu::<u64>(
+        let buffers = execute_lwe_ciphertext_vector_low_latency_bootstrap_on_gpu::<u64>(
             self.get_cuda_streams(),
             &mut output.0,
             &input.0,
@@ -285,5 +290,6 @@ impl
             self.get_number_of_gpus(),
             self.get_cuda_shared_memory(),
         );
+        synchronize_and_recycle_buffers(self.get_cuda_streams(), buffers);
     }
 }
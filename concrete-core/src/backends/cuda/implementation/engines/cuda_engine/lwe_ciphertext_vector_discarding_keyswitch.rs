@@ -109,6 +109,7 @@ impl
         ksk: &CudaLweKeyswitchKey32,
     ) -> Result<(), LweCiphertextVectorDiscardingKeyswitchError<CudaError>> {
         LweCiphertextVectorDiscardingKeyswitchError::perform_generic_checks(output, input, ksk)?;
+        check_entities_origin!(self, output, input, ksk);
         unsafe { self.discard_keyswitch_lwe_ciphertext_vector_unchecked(output, input, ksk) };
         Ok(())
     }
@@ -223,6 +224,7 @@ impl
         ksk: &CudaLweKeyswitchKey64,
     ) -> Result<(), LweCiphertextVectorDiscardingKeyswitchError<CudaError>> {
         LweCiphertextVectorDiscardingKeyswitchError::perform_generic_checks(output, input, ksk)?;
+        check_entities_origin!(self, output, input, ksk);
         unsafe { self.discard_keyswitch_lwe_ciphertext_vector_unchecked(output, input, ksk) };
         Ok(())
     }
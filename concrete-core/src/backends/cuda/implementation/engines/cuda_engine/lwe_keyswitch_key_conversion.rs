@@ -163,6 +163,7 @@ impl LweKeyswitchKeyConversionEngine<CudaLweKeyswitchKey32, LweKeyswitchKey32> f
         &mut self,
         input: &CudaLweKeyswitchKey32,
     ) -> Result<LweKeyswitchKey32, LweKeyswitchKeyConversionError<CudaError>> {
+        check_entities_origin!(self, input);
         Ok(unsafe { self.convert_lwe_keyswitch_key_unchecked(input) })
     }
 
@@ -328,6 +329,7 @@ impl LweKeyswitchKeyConversionEngine<CudaLweKeyswitchKey64, LweKeyswitchKey64> f
         &mut self,
         input: &CudaLweKeyswitchKey64,
     ) -> Result<LweKeyswitchKey64, LweKeyswitchKeyConversionError<CudaError>> {
+        check_entities_origin!(self, input);
         Ok(unsafe { self.convert_lwe_keyswitch_key_unchecked(input) })
     }
 
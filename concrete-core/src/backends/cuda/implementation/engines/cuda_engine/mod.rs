@@ -1,6 +1,13 @@
+use crate::backends::cuda::private::crypto::bootstrap::select_bootstrap_implementation;
+#[cfg(feature = "backend_cuda_debug")]
+use crate::backends::cuda::private::device::EngineId;
 use crate::backends::cuda::private::device::{CudaStream, GpuIndex, NumberOfGpus};
+use crate::prelude::numeric::UnsignedInteger;
 use crate::prelude::sealed::AbstractEngineSeal;
-use crate::prelude::{AbstractEngine, CudaError, SharedMemoryAmount};
+use crate::prelude::{
+    AbstractEngine, CudaBootstrapImplementation, CudaDeviceInfo, CudaError,
+    DecompositionLevelCount, LweCiphertextCount, PolynomialSize, SharedMemoryAmount,
+};
 use concrete_cuda::cuda_bind::cuda_get_number_of_gpus;
 
 /// The main engine exposed by the cuda backend.
@@ -11,13 +18,18 @@ use concrete_cuda::cuda_bind::cuda_get_number_of_gpus;
 /// number of input ciphertexts). This engine does not give control over the streams, nor the GPU
 /// load balancing. In this way, we can overlap computations done on different GPUs, but not
 /// computations done on a given GPU, which are executed in a sequence.
+///
+/// When the `backend_cuda_debug` feature is enabled, the engine checks that the entities it
+/// operates on were converted by itself, and returns a [`CudaError::EntityFromOtherEngine`] error
+/// otherwise.
 // A finer access to streams could allow for more overlapping of computations
 // on a given device. We'll probably want to support it in the future, in an AdvancedCudaEngine
 // for example.
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub struct CudaEngine {
     streams: Vec<CudaStream>,
     max_shared_memory: usize,
+    number_of_sms: usize,
 }
 
 impl AbstractEngineSeal for CudaEngine {}
@@ -29,24 +41,55 @@ impl AbstractEngine for CudaEngine {
 
     fn new(_parameters: Self::Parameters) -> Result<Self, Self::EngineError> {
         let number_of_gpus = unsafe { cuda_get_number_of_gpus() as usize };
-        if number_of_gpus == 0 {
+        let gpu_indexes: Vec<GpuIndex> = (0..number_of_gpus).map(GpuIndex).collect();
+        Self::new_on_gpus(&gpu_indexes)
+    }
+}
+
+impl CudaEngine {
+    /// Creates an engine splitting the computations over the given GPUs only.
+    ///
+    /// The input ciphertexts of the vector operations are split evenly over the given GPUs, in the
+    /// given order, and the GPUs process their share concurrently. This makes it possible to leave
+    /// some of the GPUs of the machine to other processes. Every index must refer to an available
+    /// GPU, and appear only once, otherwise a [`CudaError::InvalidDeviceIndex`] error is returned.
+    ///
+    /// The shared memory and the number of streaming multiprocessors used to size the kernels
+    /// are the ones of the first given GPU, so the GPUs are expected to be of the same model.
+    pub fn new_on_devices(gpu_indexes: &[GpuIndex]) -> Result<Self, CudaError> {
+        for (position, gpu_index) in gpu_indexes.iter().enumerate() {
+            if gpu_indexes[..position].contains(gpu_index) {
+                return Err(CudaError::InvalidDeviceIndex(*gpu_index));
+            }
+        }
+        Self::new_on_gpus(gpu_indexes)
+    }
+
+    fn new_on_gpus(gpu_indexes: &[GpuIndex]) -> Result<Self, CudaError> {
+        if gpu_indexes.is_empty() {
             Err(CudaError::DeviceNotFound)
         } else {
+            #[cfg(feature = "backend_cuda_debug")]
+            let engine_id = EngineId::new();
             let mut streams: Vec<CudaStream> = Vec::new();
-            for gpu_index in 0..number_of_gpus {
-                streams.push(CudaStream::new(GpuIndex(gpu_index))?);
+            for gpu_index in gpu_indexes.iter() {
+                #[allow(unused_mut)]
+                let mut stream = CudaStream::new(*gpu_index)?;
+                #[cfg(feature = "backend_cuda_debug")]
+                stream.attach_to_engine(engine_id);
+                streams.push(stream);
             }
             let max_shared_memory = streams[0].get_max_shared_memory()?;
+            let number_of_sms = streams[0].get_number_of_sms()?;
 
             Ok(CudaEngine {
                 streams,
                 max_shared_memory: max_shared_memory as usize,
+                number_of_sms: number_of_sms as usize,
             })
         }
     }
-}
 
-impl CudaEngine {
     /// Get the number of available GPUs from the engine
     pub fn get_number_of_gpus(&self) -> NumberOfGpus {
         NumberOfGpus(self.streams.len())
@@ -59,37 +102,104 @@ impl CudaEngine {
     pub fn get_cuda_shared_memory(&self) -> SharedMemoryAmount {
         SharedMemoryAmount(self.max_shared_memory)
     }
-}
-
-macro_rules! check_poly_size {
-    ($poly_size: ident) => {
-        if $poly_size.0 != 512
-            && $poly_size.0 != 1024
-            && $poly_size.0 != 2048
-            && $poly_size.0 != 4096
-            && $poly_size.0 != 8192
-        {
-            return Err(CudaError::PolynomialSizeNotSupported.into());
+    /// Get the bootstrap implementation expected to be the fastest to bootstrap
+    /// `lwe_ciphertext_count` ciphertexts of `Scalar` integers, with a key using the given
+    /// polynomial size and decomposition level count.
+    ///
+    /// The low latency bootstrap of this engine is preferred as long as the device can execute all
+    /// the bootstraps at once; the amortized bootstrap of [`AmortizedCudaEngine`] is preferred
+    /// for larger batches. See [`AutoCudaEngine`] for an engine applying this choice on each call.
+    pub fn get_fastest_bootstrap_implementation<Scalar: UnsignedInteger>(
+        &self,
+        polynomial_size: PolynomialSize,
+        level_count: DecompositionLevelCount,
+        lwe_ciphertext_count: LweCiphertextCount,
+    ) -> CudaBootstrapImplementation {
+        select_bootstrap_implementation::<Scalar>(
+            polynomial_size,
+            level_count,
+            lwe_ciphertext_count,
+            self.get_number_of_gpus(),
+            self.get_cuda_shared_memory(),
+            self.number_of_sms,
+        )
+    }
+    /// Stages the copies done by the conversion engines in page-locked host buffers
+    ///
+    /// Copies between page-locked host memory and the GPUs are significantly faster than copies
+    /// from pageable memory, which mostly benefits the conversion of large entities. One buffer
+    /// is kept per GPU, and grows to the size of the largest entity converted since then.
+    pub fn enable_pinned_host_memory(&mut self) {
+        for stream in self.streams.iter_mut() {
+            stream.enable_pinned_staging();
         }
-    };
-}
-
-macro_rules! check_glwe_dim {
-    ($glwe_dimension: ident) => {
-        if $glwe_dimension.0 != 1 {
-            return Err(CudaError::GlweDimensionNotSupported.into());
+    }
+    /// Stops staging the copies in page-locked host buffers, and frees them
+    pub fn disable_pinned_host_memory(&mut self) {
+        for stream in self.streams.iter_mut() {
+            stream.disable_pinned_staging();
+        }
+    }
+    /// Checks whether the copies are staged in page-locked host buffers
+    pub fn is_pinned_host_memory_enabled(&self) -> bool {
+        self.streams[0].is_pinned_staging_enabled()
+    }
+    /// Frees the device buffers kept in the memory pools of the engine
+    ///
+    /// The temporary device buffers of the operations, such as the indexes of the test vectors
+    /// of the bootstraps, are kept in one pool per GPU once the operations are complete, and are
+    /// reused by the next operations needing buffers of the same size. This avoids allocating
+    /// and freeing device memory on each call, which is slow.
+    pub fn clear_memory_pool(&mut self) {
+        for stream in self.streams.iter_mut() {
+            stream.clear_memory_pool();
         }
-    };
+    }
+    /// Get the total size of the device buffers kept in the memory pools, in bytes
+    pub fn get_memory_pool_size(&self) -> usize {
+        self.streams
+            .iter()
+            .map(|stream| stream.get_memory_pool_size())
+            .sum()
+    }
+    /// Get the indexes of the GPUs used by the engine, in the order the computations are split
+    pub fn get_gpu_indexes(&self) -> Vec<GpuIndex> {
+        self.streams
+            .iter()
+            .map(|stream| stream.gpu_index())
+            .collect()
+    }
+    /// Get the capabilities of the GPU with the given index
+    pub fn get_cuda_device_info(&self, gpu_index: GpuIndex) -> Result<CudaDeviceInfo, CudaError> {
+        let stream = self
+            .streams
+            .iter()
+            .find(|stream| stream.gpu_index() == gpu_index)
+            .ok_or(CudaError::InvalidDeviceIndex(gpu_index))?;
+        let shared_memory = stream.get_max_shared_memory()?;
+        let number_of_sms = stream.get_number_of_sms()?;
+        Ok(CudaDeviceInfo {
+            gpu_index,
+            compute_capability: stream.get_compute_capability()?,
+            global_memory: stream.get_global_memory()?,
+            shared_memory: SharedMemoryAmount(shared_memory as usize),
+            number_of_sms: number_of_sms as usize,
+        })
+    }
 }
 
 mod glwe_ciphertext_conversion;
 mod glwe_ciphertext_discarding_conversion;
 mod glwe_ciphertext_vector_conversion;
+mod glwe_ciphertext_vector_trivial_encryption;
 mod lwe_bootstrap_key_conversion;
+mod lwe_bootstrap_key_ggsw_ciphertext_discarding_insertion;
 mod lwe_ciphertext_conversion;
 mod lwe_ciphertext_discarding_bootstrap;
 mod lwe_ciphertext_discarding_conversion;
 mod lwe_ciphertext_discarding_keyswitch;
+mod lwe_ciphertext_vector_async_discarding_bootstrap;
+mod lwe_ciphertext_vector_async_discarding_keyswitch;
 mod lwe_ciphertext_vector_conversion;
 mod lwe_ciphertext_vector_discarding_bootstrap;
 mod lwe_ciphertext_vector_discarding_keyswitch;
@@ -1,10 +1,62 @@
 //! A module containing the [engines](crate::specification::engines) exposed by the fftw backend.
 
-use crate::backends::cuda::private::device::GpuIndex;
-use crate::specification::engines::LweCiphertextVectorDiscardingBootstrapError;
+use crate::backends::cuda::private::crypto::bootstrap::{
+    compute_low_latency_bootstrap_max_samples, synchronize_and_recycle_buffers,
+};
+use crate::backends::cuda::private::device::{CudaStream, GpuIndex};
+use crate::backends::cuda::private::vec::CudaVec;
+use crate::prelude::numeric::UnsignedInteger;
+use crate::specification::engines::{
+    LweCiphertextVectorDiscardingBootstrapError, LweCiphertextVectorDiscardingKeyswitchError,
+};
+use crate::specification::entities::{
+    GlweCiphertextVectorEntity, LweBootstrapKeyEntity, LweCiphertextVectorEntity,
+    LweKeyswitchKeyEntity,
+};
+use concrete_commons::parameters::{DecompositionLevelCount, LweCiphertextCount, PolynomialSize};
 
 use std::error::Error;
 use std::fmt::{Display, Formatter};
+use std::marker::PhantomData;
+
+macro_rules! check_poly_size {
+    ($poly_size: ident) => {
+        if $poly_size.0 != 512
+            && $poly_size.0 != 1024
+            && $poly_size.0 != 2048
+            && $poly_size.0 != 4096
+            && $poly_size.0 != 8192
+        {
+            return Err(CudaError::PolynomialSizeNotSupported.into());
+        }
+    };
+}
+
+macro_rules! check_glwe_dim {
+    ($glwe_dimension: ident) => {
+        if $glwe_dimension.0 != 1 {
+            return Err(CudaError::GlweDimensionNotSupported.into());
+        }
+    };
+}
+
+// When the `backend_cuda_debug` feature is enabled, checks that the device memory of the given
+// entities was allocated by this engine. Using an entity converted by another engine would
+// otherwise go unnoticed, and could lead to computations on the wrong devices.
+macro_rules! check_entities_origin {
+    ($engine: expr, $($entity: expr),+) => {
+        #[cfg(feature = "backend_cuda_debug")]
+        {
+            use crate::backends::cuda::private::CudaEntityOrigin;
+            let engine_id = $engine.get_cuda_streams()[0].engine_id().unwrap();
+            $(
+                if !$entity.0.was_allocated_by(engine_id) {
+                    return Err(CudaError::EntityFromOtherEngine.into());
+                }
+            )+
+        }
+    };
+}
 
 mod cuda_engine;
 pub use cuda_engine::*;
@@ -12,9 +64,178 @@ pub use cuda_engine::*;
 mod cuda_amortized_engine;
 pub use cuda_amortized_engine::*;
 
+mod cuda_auto_engine;
+pub use cuda_auto_engine::*;
+
+mod registry;
+pub(crate) use registry::implementations;
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct SharedMemoryAmount(pub usize);
 
+/// The implementations of the bootstrap available in the cuda backend.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CudaBootstrapImplementation {
+    /// The low latency bootstrap, executed by [`CudaEngine`].
+    LowLatency,
+    /// The amortized bootstrap, executed by [`AmortizedCudaEngine`].
+    Amortized,
+}
+
+/// The capabilities of a GPU used by a cuda engine.
+///
+/// It can be retrieved with [`CudaEngine::get_cuda_device_info`], for instance to decide where to
+/// place some computations without querying the device directly.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CudaDeviceInfo {
+    /// The index of the GPU.
+    pub gpu_index: GpuIndex,
+    /// The compute capability of the GPU, as a (major, minor) pair.
+    pub compute_capability: (u32, u32),
+    /// The amount of global memory of the GPU, in bytes.
+    pub global_memory: u64,
+    /// The amount of shared memory of the GPU, in bytes.
+    pub shared_memory: SharedMemoryAmount,
+    /// The number of streaming multiprocessors of the GPU.
+    pub number_of_sms: usize,
+}
+
+impl CudaDeviceInfo {
+    /// Returns the maximum number of ciphertexts of `Scalar` integers the GPU can bootstrap at
+    /// once with the low latency bootstrap of [`CudaEngine`], with a key using the given
+    /// polynomial size and decomposition level count.
+    ///
+    /// Larger batches are better handled by the amortized bootstrap of [`AmortizedCudaEngine`].
+    /// A count of 0 is returned if the polynomial size or the level count is 0.
+    pub fn max_batch_size<Scalar: UnsignedInteger>(
+        &self,
+        polynomial_size: PolynomialSize,
+        level_count: DecompositionLevelCount,
+    ) -> LweCiphertextCount {
+        LweCiphertextCount(compute_low_latency_bootstrap_max_samples::<Scalar>(
+            polynomial_size,
+            level_count,
+            self.shared_memory,
+            self.number_of_sms,
+        ))
+    }
+}
+
+/// A handle on operations enqueued on the streams of a cuda engine, which may still be running.
+///
+/// The handle borrows the engine and the entities used by the operations, which can thus neither
+/// be modified nor dropped before the operations are complete. Meanwhile, the host is free to do
+/// other work. Dropping the handle waits for the completion of the operations.
+///
+/// The borrows only hold as long as the handle is dropped: a handle which is leaked, for instance
+/// with [`std::mem::forget`], releases them while the operations may still be running. This is why
+/// the methods returning a handle are `unsafe`.
+#[must_use = "dropping the handle waits for the completion of the operations"]
+#[derive(Debug)]
+pub struct CudaOperationHandle<'a> {
+    streams: &'a [CudaStream],
+    // The device buffers used by the operations, which are given back to the memory pools of the
+    // streams once the operations complete.
+    buffers: Vec<CudaVec<u32>>,
+    _entities: PhantomData<&'a mut ()>,
+}
+
+impl<'a> CudaOperationHandle<'a> {
+    pub(crate) fn new(streams: &'a [CudaStream], buffers: Vec<CudaVec<u32>>) -> Self {
+        CudaOperationHandle {
+            streams,
+            buffers,
+            _entities: PhantomData,
+        }
+    }
+
+    /// Checks whether the operations are complete, without blocking.
+    ///
+    /// An error is returned if one of the streams reports a failure, in which case the operations
+    /// will never complete.
+    pub fn is_complete(&self) -> Result<bool, CudaError> {
+        for stream in self.streams.iter() {
+            if !stream.is_idle()? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /// Waits for the completion of the operations.
+    pub fn synchronize(self) {}
+}
+
+impl Drop for CudaOperationHandle<'_> {
+    fn drop(&mut self) {
+        // The buffers are only given back to the memory pools once the operations are complete.
+        synchronize_and_recycle_buffers(self.streams, std::mem::take(&mut self.buffers));
+    }
+}
+
+/// A trait for cuda engines enqueuing discarding bootstraps of LWE ciphertext vectors, without
+/// waiting for their completion.
+///
+/// This is the asynchronous counterpart of the
+/// [`LweCiphertextVectorDiscardingBootstrapEngine`](crate::specification::engines::LweCiphertextVectorDiscardingBootstrapEngine)
+/// trait: the same checks are performed before the bootstraps are enqueued, and the result is
+/// available in `output` once the returned handle is synchronized.
+pub trait CudaLweCiphertextVectorAsyncDiscardingBootstrapEngine<
+    BootstrapKey,
+    AccumulatorVector,
+    InputCiphertextVector,
+    OutputCiphertextVector,
+> where
+    BootstrapKey: LweBootstrapKeyEntity,
+    AccumulatorVector: GlweCiphertextVectorEntity,
+    InputCiphertextVector: LweCiphertextVectorEntity,
+    OutputCiphertextVector: LweCiphertextVectorEntity,
+{
+    /// Enqueues the bootstrap of an LWE ciphertext vector.
+    ///
+    /// # Safety
+    /// The returned handle must be dropped or synchronized, and not leaked (e.g. with
+    /// [`std::mem::forget`]): otherwise, the entities could be used, modified or dropped while the
+    /// bootstrap is still running on the device.
+    unsafe fn discard_bootstrap_lwe_ciphertext_vector_async<'a>(
+        &'a self,
+        output: &'a mut OutputCiphertextVector,
+        input: &'a InputCiphertextVector,
+        acc: &'a AccumulatorVector,
+        bsk: &'a BootstrapKey,
+    ) -> Result<CudaOperationHandle<'a>, LweCiphertextVectorDiscardingBootstrapError<CudaError>>;
+}
+
+/// A trait for cuda engines enqueuing discarding keyswitches of LWE ciphertext vectors, without
+/// waiting for their completion.
+///
+/// This is the asynchronous counterpart of the
+/// [`LweCiphertextVectorDiscardingKeyswitchEngine`](crate::specification::engines::LweCiphertextVectorDiscardingKeyswitchEngine)
+/// trait: the same checks are performed before the keyswitches are enqueued, and the result is
+/// available in `output` once the returned handle is synchronized.
+pub trait CudaLweCiphertextVectorAsyncDiscardingKeyswitchEngine<
+    KeyswitchKey,
+    InputCiphertextVector,
+    OutputCiphertextVector,
+> where
+    KeyswitchKey: LweKeyswitchKeyEntity,
+    InputCiphertextVector: LweCiphertextVectorEntity,
+    OutputCiphertextVector: LweCiphertextVectorEntity,
+{
+    /// Enqueues the keyswitch of an LWE ciphertext vector.
+    ///
+    /// # Safety
+    /// The returned handle must be dropped or synchronized, and not leaked (e.g. with
+    /// [`std::mem::forget`]): otherwise, the entities could be used, modified or dropped while the
+    /// keyswitch is still running on the device.
+    unsafe fn discard_keyswitch_lwe_ciphertext_vector_async<'a>(
+        &'a self,
+        output: &'a mut OutputCiphertextVector,
+        input: &'a InputCiphertextVector,
+        ksk: &'a KeyswitchKey,
+    ) -> Result<CudaOperationHandle<'a>, LweCiphertextVectorDiscardingKeyswitchError<CudaError>>;
+}
+
 #[derive(Debug)]
 pub enum CudaError {
     DeviceNotFound,
@@ -24,6 +245,7 @@ pub enum CudaError {
     UnspecifiedDeviceError(GpuIndex),
     PolynomialSizeNotSupported,
     GlweDimensionNotSupported,
+    EntityFromOtherEngine,
 }
 impl Display for CudaError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -58,6 +280,13 @@ impl Display for CudaError {
             CudaError::GlweDimensionNotSupported => {
                 write!(f, "The only supported GLWE dimension is 1.")
             }
+            CudaError::EntityFromOtherEngine => {
+                write!(
+                    f,
+                    "An input entity was allocated by another engine than the one executing \
+                the operation. Entities must be converted with the engine that uses them."
+                )
+            }
             CudaError::UnspecifiedDeviceError(gpu_index) => {
                 write!(f, "Unspecified device error on GPU #{}.", gpu_index.0)
             }
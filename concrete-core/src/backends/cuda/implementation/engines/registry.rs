@@ -0,0 +1,123 @@
+//! The declaration of the engine traits implemented by the engines of the cuda backend.
+use crate::backends::support::declare_implementations;
+use crate::prelude::*;
+
+declare_implementations! {
+    "cuda",
+    CudaEngine => [
+        GlweCiphertextConversionEngine<GlweCiphertext32, CudaGlweCiphertext32>,
+        GlweCiphertextConversionEngine<CudaGlweCiphertext32, GlweCiphertext32>,
+        GlweCiphertextConversionEngine<GlweCiphertext64, CudaGlweCiphertext64>,
+        GlweCiphertextConversionEngine<CudaGlweCiphertext64, GlweCiphertext64>,
+        GlweCiphertextConversionEngine<GlweCiphertextView64<'data>, CudaGlweCiphertext64>,
+        GlweCiphertextDiscardingConversionEngine<
+            CudaGlweCiphertext64,
+            GlweCiphertextMutView64<'data>,
+        >,
+        GlweCiphertextVectorConversionEngine<GlweCiphertextVector32, CudaGlweCiphertextVector32>,
+        GlweCiphertextVectorConversionEngine<CudaGlweCiphertextVector32, GlweCiphertextVector32>,
+        GlweCiphertextVectorConversionEngine<GlweCiphertextVector64, CudaGlweCiphertextVector64>,
+        GlweCiphertextVectorConversionEngine<CudaGlweCiphertextVector64, GlweCiphertextVector64>,
+        GlweCiphertextVectorTrivialEncryptionEngine<PlaintextVector32, CudaGlweCiphertextVector32>,
+        GlweCiphertextVectorTrivialEncryptionEngine<PlaintextVector64, CudaGlweCiphertextVector64>,
+        LweBootstrapKeyConversionEngine<LweBootstrapKey32, CudaFourierLweBootstrapKey32>,
+        LweBootstrapKeyConversionEngine<LweBootstrapKey64, CudaFourierLweBootstrapKey64>,
+        LweBootstrapKeyGgswCiphertextDiscardingInsertionEngine<
+            GgswCiphertext32,
+            CudaFourierLweBootstrapKey32,
+        >,
+        LweBootstrapKeyGgswCiphertextDiscardingInsertionEngine<
+            GgswCiphertext64,
+            CudaFourierLweBootstrapKey64,
+        >,
+        LweCiphertextConversionEngine<LweCiphertext32, CudaLweCiphertext32>,
+        LweCiphertextConversionEngine<CudaLweCiphertext32, LweCiphertext32>,
+        LweCiphertextConversionEngine<LweCiphertext64, CudaLweCiphertext64>,
+        LweCiphertextConversionEngine<CudaLweCiphertext64, LweCiphertext64>,
+        LweCiphertextConversionEngine<LweCiphertextView64<'data>, CudaLweCiphertext64>,
+        LweCiphertextDiscardingBootstrapEngine<
+            CudaFourierLweBootstrapKey32,
+            CudaGlweCiphertext32,
+            CudaLweCiphertext32,
+            CudaLweCiphertext32,
+        >,
+        LweCiphertextDiscardingBootstrapEngine<
+            CudaFourierLweBootstrapKey64,
+            CudaGlweCiphertext64,
+            CudaLweCiphertext64,
+            CudaLweCiphertext64,
+        >,
+        LweCiphertextDiscardingConversionEngine<CudaLweCiphertext32, LweCiphertextMutView32<'data>>,
+        LweCiphertextDiscardingConversionEngine<CudaLweCiphertext32, LweCiphertext32>,
+        LweCiphertextDiscardingConversionEngine<LweCiphertext32, CudaLweCiphertext32>,
+        LweCiphertextDiscardingConversionEngine<CudaLweCiphertext64, LweCiphertextMutView64<'data>>,
+        LweCiphertextDiscardingKeyswitchEngine<
+            CudaLweKeyswitchKey32,
+            CudaLweCiphertext32,
+            CudaLweCiphertext32,
+        >,
+        LweCiphertextDiscardingKeyswitchEngine<
+            CudaLweKeyswitchKey64,
+            CudaLweCiphertext64,
+            CudaLweCiphertext64,
+        >,
+        LweCiphertextVectorConversionEngine<LweCiphertextVector32, CudaLweCiphertextVector32>,
+        LweCiphertextVectorConversionEngine<CudaLweCiphertextVector32, LweCiphertextVector32>,
+        LweCiphertextVectorConversionEngine<LweCiphertextVector64, CudaLweCiphertextVector64>,
+        LweCiphertextVectorConversionEngine<CudaLweCiphertextVector64, LweCiphertextVector64>,
+        LweCiphertextVectorDiscardingBootstrapEngine<
+            CudaFourierLweBootstrapKey32,
+            CudaGlweCiphertextVector32,
+            CudaLweCiphertextVector32,
+            CudaLweCiphertextVector32,
+        >,
+        LweCiphertextVectorDiscardingBootstrapEngine<
+            CudaFourierLweBootstrapKey64,
+            CudaGlweCiphertextVector64,
+            CudaLweCiphertextVector64,
+            CudaLweCiphertextVector64,
+        >,
+        LweCiphertextVectorDiscardingKeyswitchEngine<
+            CudaLweKeyswitchKey32,
+            CudaLweCiphertextVector32,
+            CudaLweCiphertextVector32,
+        >,
+        LweCiphertextVectorDiscardingKeyswitchEngine<
+            CudaLweKeyswitchKey64,
+            CudaLweCiphertextVector64,
+            CudaLweCiphertextVector64,
+        >,
+        LweKeyswitchKeyConversionEngine<LweKeyswitchKey32, CudaLweKeyswitchKey32>,
+        LweKeyswitchKeyConversionEngine<CudaLweKeyswitchKey32, LweKeyswitchKey32>,
+        LweKeyswitchKeyConversionEngine<LweKeyswitchKey64, CudaLweKeyswitchKey64>,
+        LweKeyswitchKeyConversionEngine<CudaLweKeyswitchKey64, LweKeyswitchKey64>,
+    ],
+    AmortizedCudaEngine => [
+        LweCiphertextVectorDiscardingBootstrapEngine<
+            CudaFourierLweBootstrapKey32,
+            CudaGlweCiphertextVector32,
+            CudaLweCiphertextVector32,
+            CudaLweCiphertextVector32,
+        >,
+        LweCiphertextVectorDiscardingBootstrapEngine<
+            CudaFourierLweBootstrapKey64,
+            CudaGlweCiphertextVector64,
+            CudaLweCiphertextVector64,
+            CudaLweCiphertextVector64,
+        >,
+    ],
+    AutoCudaEngine => [
+        LweCiphertextVectorDiscardingBootstrapEngine<
+            CudaFourierLweBootstrapKey32,
+            CudaGlweCiphertextVector32,
+            CudaLweCiphertextVector32,
+            CudaLweCiphertextVector32,
+        >,
+        LweCiphertextVectorDiscardingBootstrapEngine<
+            CudaFourierLweBootstrapKey64,
+            CudaGlweCiphertextVector64,
+            CudaLweCiphertextVector64,
+            CudaLweCiphertextVector64,
+        >,
+    ],
+}
@@ -1,22 +1,36 @@
 //! Bootstrap key with Cuda.
-use crate::backends::cuda::engines::SharedMemoryAmount;
+use crate::backends::cuda::engines::{CudaBootstrapImplementation, SharedMemoryAmount};
 use crate::backends::cuda::private::crypto::glwe::list::CudaGlweList;
 use crate::backends::cuda::private::crypto::lwe::list::CudaLweList;
+#[cfg(feature = "backend_cuda_debug")]
+use crate::backends::cuda::private::device::EngineId;
 use crate::backends::cuda::private::device::{CudaStream, GpuIndex, NumberOfGpus};
 use crate::backends::cuda::private::vec::CudaVec;
+#[cfg(feature = "backend_cuda_debug")]
+use crate::backends::cuda::private::CudaEntityOrigin;
 use crate::backends::cuda::private::{compute_number_of_samples_on_gpu, number_of_active_gpus};
 use crate::commons::crypto::bootstrap::StandardBootstrapKey;
+use crate::commons::crypto::ggsw::StandardGgswCiphertext;
 use crate::commons::math::tensor::{AsRefSlice, AsRefTensor};
 use crate::prelude::numeric::UnsignedInteger;
 use crate::prelude::{CiphertextCount, LweCiphertextIndex};
 use concrete_commons::parameters::{
-    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextIndex, GlweDimension,
+    LweCiphertextCount, LweDimension, PolynomialSize,
 };
 use std::marker::PhantomData;
+use std::mem::size_of;
 
 #[derive(Debug)]
 pub(crate) struct CudaBootstrapKey<T: UnsignedInteger> {
     // Pointers to GPU data: one cuda vec per GPU
+    // TODO
+    //   An opt-in f32 Fourier representation of the key, roughly doubling the bootstrap
+    //   throughput, was requested. It is blocked on the cuda side: the FFT, the twiddle tables,
+    //   the key conversion and both bootstrap kernels of concrete-cuda are written for double2
+    //   only, and must be made generic over the complex type (with their shared memory sizing)
+    //   before this field can hold single precision values. The extra FFT noise must then be
+    //   measured on a GPU to document the parameter sets for which it is acceptable.
     pub(crate) d_vecs: Vec<CudaVec<f64>>,
     // Input LWE dimension
     pub(crate) input_lwe_dimension: LweDimension,
@@ -32,6 +46,15 @@ pub(crate) struct CudaBootstrapKey<T: UnsignedInteger> {
     pub(crate) _phantom: PhantomData<T>,
 }
 
+#[cfg(feature = "backend_cuda_debug")]
+impl<T: UnsignedInteger> CudaEntityOrigin for CudaBootstrapKey<T> {
+    fn was_allocated_by(&self, engine_id: EngineId) -> bool {
+        self.d_vecs
+            .iter()
+            .all(|d_vec| d_vec.engine_id() == Some(engine_id))
+    }
+}
+
 pub(crate) unsafe fn convert_lwe_bootstrap_key_from_cpu_to_gpu<T: UnsignedInteger, Cont>(
     streams: &[CudaStream],
     input: &StandardBootstrapKey<Cont>,
@@ -68,6 +91,37 @@ where
     vecs
 }
 
+pub(crate) unsafe fn insert_ggsw_ciphertext_in_lwe_bootstrap_key_on_gpu<T: UnsignedInteger, Cont>(
+    streams: &[CudaStream],
+    bsk: &mut CudaBootstrapKey<T>,
+    input: &StandardGgswCiphertext<Cont>,
+    index: GgswCiphertextIndex,
+) where
+    Cont: AsRefSlice<Element = T>,
+{
+    // The GGSW ciphertexts are stored one after the other on each GPU, with the same number of
+    // f64 values as of torus values in the standard domain.
+    let ggsw_size = input.glwe_size().0
+        * input.glwe_size().0
+        * input.decomposition_level_count().0
+        * input.polynomial_size().0;
+    for (stream, d_vec) in streams.iter().zip(bsk.d_vecs.iter_mut()) {
+        stream.initialize_twiddles(input.polynomial_size());
+        stream.convert_lwe_bootstrap_key_ggsw::<T>(
+            d_vec,
+            index.0 * ggsw_size,
+            input.as_tensor().as_slice(),
+            input.glwe_size().to_glwe_dimension(),
+            input.decomposition_level_count(),
+            input.polynomial_size(),
+        );
+    }
+}
+
+// Enqueues the bootstraps on the streams, and returns the device buffers holding the indexes of
+// the test vectors, one per active stream. The buffers are taken from the memory pools of the
+// streams, and are returned to let the caller decide when to wait for the bootstraps before
+// giving them back with `synchronize_and_recycle_buffers`.
 pub(crate) unsafe fn execute_lwe_ciphertext_vector_low_latency_bootstrap_on_gpu<
     T: UnsignedInteger,
 >(
@@ -78,7 +132,7 @@ pub(crate) unsafe fn execute_lwe_ciphertext_vector_low_latency_bootstrap_on_gpu<
     bsk: &CudaBootstrapKey<T>,
     number_of_available_gpus: NumberOfGpus,
     cuda_shared_memory: SharedMemoryAmount,
-) {
+) -> Vec<CudaVec<u32>> {
     let number_of_gpus = number_of_active_gpus(
         number_of_available_gpus,
         CiphertextCount(input.lwe_ciphertext_count.0),
@@ -89,6 +143,7 @@ pub(crate) unsafe fn execute_lwe_ciphertext_vector_low_latency_bootstrap_on_gpu<
         GpuIndex(0),
     );
 
+    let mut d_test_vector_indexes_per_gpu = Vec::with_capacity(number_of_gpus.0);
     for (gpu_index, stream) in streams.iter().enumerate().take(number_of_gpus.0) {
         let samples = compute_number_of_samples_on_gpu(
             number_of_gpus,
@@ -98,7 +153,7 @@ pub(crate) unsafe fn execute_lwe_ciphertext_vector_low_latency_bootstrap_on_gpu<
         // FIXME this is hard set at the moment because concrete-core does not support a more
         //   general API for the bootstrap
         let test_vector_indexes = (0..samples.0 as u32).collect::<Vec<u32>>();
-        let mut d_test_vector_indexes = stream.malloc::<u32>(samples.0 as u32);
+        let mut d_test_vector_indexes = stream.malloc_pooled::<u32>(samples.0 as u32);
         stream.copy_to_gpu(&mut d_test_vector_indexes, &test_vector_indexes);
 
         stream.discard_bootstrap_low_latency_lwe_ciphertext_vector::<T>(
@@ -115,9 +170,13 @@ pub(crate) unsafe fn execute_lwe_ciphertext_vector_low_latency_bootstrap_on_gpu<
             LweCiphertextIndex(samples_on_gpu_0.0 * gpu_index),
             cuda_shared_memory,
         );
+        d_test_vector_indexes_per_gpu.push(d_test_vector_indexes);
     }
+    d_test_vector_indexes_per_gpu
 }
 
+// Enqueues the bootstraps on the streams, and returns the device buffers holding the indexes of
+// the test vectors, like `execute_lwe_ciphertext_vector_low_latency_bootstrap_on_gpu`.
 pub(crate) unsafe fn execute_lwe_ciphertext_vector_amortized_bootstrap_on_gpu<
     T: UnsignedInteger,
 >(
@@ -128,7 +187,7 @@ pub(crate) unsafe fn execute_lwe_ciphertext_vector_amortized_bootstrap_on_gpu<
     bsk: &CudaBootstrapKey<T>,
     number_of_available_gpus: NumberOfGpus,
     cuda_shared_memory: SharedMemoryAmount,
-) {
+) -> Vec<CudaVec<u32>> {
     let number_of_gpus = number_of_active_gpus(
         number_of_available_gpus,
         CiphertextCount(input.lwe_ciphertext_count.0),
@@ -139,6 +198,7 @@ pub(crate) unsafe fn execute_lwe_ciphertext_vector_amortized_bootstrap_on_gpu<
         GpuIndex(0),
     );
 
+    let mut d_test_vector_indexes_per_gpu = Vec::with_capacity(number_of_gpus.0);
     for (gpu_index, stream) in streams.iter().enumerate().take(number_of_gpus.0) {
         let samples = compute_number_of_samples_on_gpu(
             number_of_gpus,
@@ -148,7 +208,7 @@ pub(crate) unsafe fn execute_lwe_ciphertext_vector_amortized_bootstrap_on_gpu<
         // FIXME this is hard set at the moment because concrete-core does not support a more
         //   general API for the bootstrap
         let test_vector_indexes = (0..samples.0 as u32).collect::<Vec<u32>>();
-        let mut d_test_vector_indexes = stream.malloc::<u32>(samples.0 as u32);
+        let mut d_test_vector_indexes = stream.malloc_pooled::<u32>(samples.0 as u32);
         stream.copy_to_gpu(&mut d_test_vector_indexes, &test_vector_indexes);
 
         stream.discard_bootstrap_amortized_lwe_ciphertext_vector::<T>(
@@ -165,5 +225,80 @@ pub(crate) unsafe fn execute_lwe_ciphertext_vector_amortized_bootstrap_on_gpu<
             LweCiphertextIndex(samples_on_gpu_0.0 * gpu_index),
             cuda_shared_memory,
         );
+        d_test_vector_indexes_per_gpu.push(d_test_vector_indexes);
+    }
+    d_test_vector_indexes_per_gpu
+}
+
+// Waits for the operations enqueued on the streams, and gives the buffers they used back to the
+// memory pools of the streams. The buffer of index `i` must come from the stream of index `i`.
+pub(crate) fn synchronize_and_recycle_buffers(streams: &[CudaStream], buffers: Vec<CudaVec<u32>>) {
+    for (stream, buffer) in streams.iter().zip(buffers) {
+        stream.synchronize_stream();
+        stream.recycle(buffer);
+    }
+}
+
+// Returns the bootstrap implementation expected to be the fastest for the given parameters.
+//
+// The low latency bootstrap is faster than the amortized bootstrap as long as a GPU can execute
+// all its bootstraps at once (see `compute_low_latency_bootstrap_max_samples`), and the amortized
+// bootstrap must be used above this bound.
+pub(crate) fn select_bootstrap_implementation<T: UnsignedInteger>(
+    polynomial_size: PolynomialSize,
+    level_count: DecompositionLevelCount,
+    lwe_ciphertext_count: LweCiphertextCount,
+    number_of_available_gpus: NumberOfGpus,
+    cuda_shared_memory: SharedMemoryAmount,
+    number_of_sms: usize,
+) -> CudaBootstrapImplementation {
+    let number_of_gpus = number_of_active_gpus(
+        number_of_available_gpus,
+        CiphertextCount(lwe_ciphertext_count.0),
+    );
+    if number_of_gpus.0 == 0 {
+        return CudaBootstrapImplementation::LowLatency;
+    }
+    // The last GPU is the most loaded one.
+    let samples = compute_number_of_samples_on_gpu(
+        number_of_gpus,
+        CiphertextCount(lwe_ciphertext_count.0),
+        GpuIndex(number_of_gpus.0 - 1),
+    );
+    let max_samples = compute_low_latency_bootstrap_max_samples::<T>(
+        polynomial_size,
+        level_count,
+        cuda_shared_memory,
+        number_of_sms,
+    );
+    if samples.0 <= max_samples {
+        CudaBootstrapImplementation::LowLatency
+    } else {
+        CudaBootstrapImplementation::Amortized
+    }
+}
+
+// Returns the number of ciphertexts a GPU can process at once with the low latency bootstrap.
+//
+// The low latency bootstrap is launched as a cooperative kernel, with one block per
+// decomposition level, for both the mask and the body of each input ciphertext. All these blocks
+// must be resident on the device at the same time.
+//
+// The number of resident blocks is estimated from the shared memory needed by each block, which
+// makes this an approximation of the actual occupancy of the device. No ciphertext can be
+// processed with a null polynomial size or level count, in which case 0 is returned.
+pub(crate) fn compute_low_latency_bootstrap_max_samples<T: UnsignedInteger>(
+    polynomial_size: PolynomialSize,
+    level_count: DecompositionLevelCount,
+    cuda_shared_memory: SharedMemoryAmount,
+    number_of_sms: usize,
+) -> usize {
+    if polynomial_size.0 == 0 || level_count.0 == 0 {
+        return 0;
     }
+    let shared_memory_per_block = size_of::<i16>() * polynomial_size.0 // accumulator_decomp
+        + size_of::<T>() * polynomial_size.0 // accumulator
+        + 2 * size_of::<f64>() * polynomial_size.0 / 2; // accumulator fft
+    let blocks_per_sm = cuda_shared_memory.0 / shared_memory_per_block;
+    blocks_per_sm * number_of_sms / (level_count.0 * 2)
 }
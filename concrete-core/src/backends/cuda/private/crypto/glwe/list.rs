@@ -1,4 +1,8 @@
+#[cfg(feature = "backend_cuda_debug")]
+use crate::backends::cuda::private::device::EngineId;
 use crate::backends::cuda::private::vec::CudaVec;
+#[cfg(feature = "backend_cuda_debug")]
+use crate::backends::cuda::private::CudaEntityOrigin;
 use concrete_commons::numeric::UnsignedInteger;
 use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
 
@@ -22,3 +26,12 @@ pub(crate) struct CudaGlweList<T: UnsignedInteger> {
     // Polynomial size
     pub(crate) polynomial_size: PolynomialSize,
 }
+
+#[cfg(feature = "backend_cuda_debug")]
+impl<T: UnsignedInteger> CudaEntityOrigin for CudaGlweList<T> {
+    fn was_allocated_by(&self, engine_id: EngineId) -> bool {
+        self.d_vecs
+            .iter()
+            .all(|d_vec| d_vec.engine_id() == Some(engine_id))
+    }
+}
@@ -1,7 +1,11 @@
 //! Keyswitch key with Cuda.
 use crate::backends::cuda::private::crypto::lwe::list::CudaLweList;
+#[cfg(feature = "backend_cuda_debug")]
+use crate::backends::cuda::private::device::EngineId;
 use crate::backends::cuda::private::device::{CudaStream, GpuIndex, NumberOfGpus};
 use crate::backends::cuda::private::vec::CudaVec;
+#[cfg(feature = "backend_cuda_debug")]
+use crate::backends::cuda::private::CudaEntityOrigin;
 use crate::backends::cuda::private::{compute_number_of_samples_on_gpu, number_of_active_gpus};
 use crate::prelude::CiphertextCount;
 use concrete_commons::numeric::UnsignedInteger;
@@ -21,6 +25,15 @@ pub(crate) struct CudaLweKeyswitchKey<T: UnsignedInteger> {
     pub(crate) decomp_base_log: DecompositionBaseLog,
 }
 
+#[cfg(feature = "backend_cuda_debug")]
+impl<T: UnsignedInteger> CudaEntityOrigin for CudaLweKeyswitchKey<T> {
+    fn was_allocated_by(&self, engine_id: EngineId) -> bool {
+        self.d_vecs
+            .iter()
+            .all(|d_vec| d_vec.engine_id() == Some(engine_id))
+    }
+}
+
 pub(crate) unsafe fn execute_lwe_ciphertext_vector_keyswitch_on_gpu<T: UnsignedInteger>(
     streams: &[CudaStream],
     output: &mut CudaLweList<T>,
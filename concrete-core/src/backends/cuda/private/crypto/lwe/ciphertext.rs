@@ -1,4 +1,8 @@
+#[cfg(feature = "backend_cuda_debug")]
+use crate::backends::cuda::private::device::EngineId;
 use crate::backends::cuda::private::vec::CudaVec;
+#[cfg(feature = "backend_cuda_debug")]
+use crate::backends::cuda::private::CudaEntityOrigin;
 use concrete_commons::numeric::UnsignedInteger;
 use concrete_commons::parameters::LweDimension;
 
@@ -15,3 +19,10 @@ pub(crate) struct CudaLweCiphertext<T: UnsignedInteger> {
     // Lwe dimension
     pub(crate) lwe_dimension: LweDimension,
 }
+
+#[cfg(feature = "backend_cuda_debug")]
+impl<T: UnsignedInteger> CudaEntityOrigin for CudaLweCiphertext<T> {
+    fn was_allocated_by(&self, engine_id: EngineId) -> bool {
+        self.d_vec.engine_id() == Some(engine_id)
+    }
+}
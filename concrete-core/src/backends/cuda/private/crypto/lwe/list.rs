@@ -1,5 +1,9 @@
+#[cfg(feature = "backend_cuda_debug")]
+use crate::backends::cuda::private::device::EngineId;
 use crate::backends::cuda::private::device::{CudaStream, GpuIndex, NumberOfGpus};
 use crate::backends::cuda::private::vec::CudaVec;
+#[cfg(feature = "backend_cuda_debug")]
+use crate::backends::cuda::private::CudaEntityOrigin;
 use crate::backends::cuda::private::{compute_number_of_samples_on_gpu, number_of_active_gpus};
 use crate::commons::crypto::lwe::LweList;
 use crate::commons::math::tensor::{AsRefSlice, AsRefTensor};
@@ -33,6 +37,15 @@ pub(crate) struct CudaLweList<T: UnsignedInteger> {
     pub(crate) lwe_dimension: LweDimension,
 }
 
+#[cfg(feature = "backend_cuda_debug")]
+impl<T: UnsignedInteger> CudaEntityOrigin for CudaLweList<T> {
+    fn was_allocated_by(&self, engine_id: EngineId) -> bool {
+        self.d_vecs
+            .iter()
+            .all(|d_vec| d_vec.engine_id() == Some(engine_id))
+    }
+}
+
 pub(crate) unsafe fn copy_lwe_ciphertext_vector_from_cpu_to_gpu<T: UnsignedInteger, Cont>(
     streams: &[CudaStream],
     input: &LweList<Cont>,
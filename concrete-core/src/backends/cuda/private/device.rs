@@ -1,6 +1,6 @@
 use crate::backends::cuda::engines::CudaError;
 use crate::backends::cuda::private::pointers::StreamPointer;
-use crate::backends::cuda::private::vec::CudaVec;
+use crate::backends::cuda::private::vec::{CudaPinnedBuffer, CudaVec};
 use crate::prelude::numeric::UnsignedInteger;
 use crate::prelude::SharedMemoryAmount;
 use concrete_commons::numeric::Numeric;
@@ -9,10 +9,12 @@ use concrete_commons::parameters::{
     PolynomialSize,
 };
 use concrete_cuda::cuda_bind::*;
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::ffi::c_void;
 use std::marker::PhantomData;
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct GpuIndex(pub usize);
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -21,10 +23,88 @@ pub struct NumberOfSamples(pub usize);
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct NumberOfGpus(pub usize);
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+/// A unique identifier given to each cuda engine at creation.
+#[cfg(feature = "backend_cuda_debug")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EngineId(pub usize);
+
+#[cfg(feature = "backend_cuda_debug")]
+impl EngineId {
+    /// Returns a new identifier, different from all the previously returned ones.
+    pub(crate) fn new() -> Self {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        static NEXT_ENGINE_ID: AtomicUsize = AtomicUsize::new(0);
+        EngineId(NEXT_ENGINE_ID.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+/// A pool of device buffers, kept to be reused instead of being freed.
+///
+/// The buffers are keyed by the index of the GPU they live on and by their size in bytes, and
+/// are only handed back for an allocation of the exact same size. Allocating and freeing device
+/// memory is slow (freeing even synchronizes the whole device), so reusing the temporary buffers
+/// of the operations saves a significant part of their latency. The buffers are freed when the
+/// pool is cleared or dropped.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct CudaMemoryPool {
+    buffers: HashMap<(GpuIndex, usize), Vec<*mut c_void>>,
+}
+
+impl CudaMemoryPool {
+    /// Creates an empty pool.
+    pub(crate) fn new() -> Self {
+        CudaMemoryPool::default()
+    }
+
+    /// Takes a buffer of `size` bytes out of the pool, if one is available.
+    pub(crate) fn take(&mut self, gpu_index: GpuIndex, size: usize) -> Option<*mut c_void> {
+        self.buffers
+            .get_mut(&(gpu_index, size))
+            .and_then(|buffers| buffers.pop())
+    }
+
+    /// Puts a buffer of `size` bytes back in the pool.
+    pub(crate) fn put(&mut self, gpu_index: GpuIndex, size: usize, ptr: *mut c_void) {
+        self.buffers.entry((gpu_index, size)).or_default().push(ptr);
+    }
+
+    /// Returns the total size of the buffers held by the pool, in bytes.
+    pub(crate) fn size(&self) -> usize {
+        self.buffers
+            .iter()
+            .map(|((_, size), buffers)| size * buffers.len())
+            .sum()
+    }
+
+    /// Frees all the buffers held by the pool.
+    pub(crate) fn clear(&mut self) {
+        for ((gpu_index, _), buffers) in self.buffers.drain() {
+            for ptr in buffers {
+                unsafe { cuda_drop(ptr, gpu_index.0 as u32) };
+            }
+        }
+    }
+}
+
+impl Drop for CudaMemoryPool {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+// A stream is destroyed on drop, hence it must not be cloned.
+#[derive(Debug, PartialEq, Eq)]
 pub struct CudaStream {
     gpu_index: GpuIndex,
     stream: StreamPointer,
+    // The page-locked host buffer through which the blocking copies go, if enabled.
+    staging: Option<RefCell<CudaPinnedBuffer>>,
+    // The device buffers released by the operations enqueued on the stream, to be reused by the
+    // next ones. Since the work of a stream is executed in order, a buffer released while still
+    // used by an operation can only be overwritten once this operation is complete.
+    pool: RefCell<CudaMemoryPool>,
+    #[cfg(feature = "backend_cuda_debug")]
+    engine_id: Option<EngineId>,
 }
 
 impl CudaStream {
@@ -34,10 +114,29 @@ impl CudaStream {
             Err(CudaError::InvalidDeviceIndex(gpu_index))
         } else {
             let stream = StreamPointer(unsafe { cuda_create_stream(gpu_index.0 as u32) });
-            Ok(CudaStream { gpu_index, stream })
+            Ok(CudaStream {
+                gpu_index,
+                stream,
+                staging: None,
+                pool: RefCell::new(CudaMemoryPool::new()),
+                #[cfg(feature = "backend_cuda_debug")]
+                engine_id: None,
+            })
         }
     }
 
+    /// Attaches the stream to an engine, whose id will be stamped on every allocation
+    #[cfg(feature = "backend_cuda_debug")]
+    pub(crate) fn attach_to_engine(&mut self, engine_id: EngineId) {
+        self.engine_id = Some(engine_id);
+    }
+
+    /// Gets the id of the engine the stream is attached to
+    #[cfg(feature = "backend_cuda_debug")]
+    pub(crate) fn engine_id(&self) -> Option<EngineId> {
+        self.engine_id
+    }
+
     /// Gets the GPU index the stream is associated to
     pub(crate) fn gpu_index(&self) -> GpuIndex {
         self.gpu_index
@@ -48,6 +147,23 @@ impl CudaStream {
         self.stream
     }
 
+    /// Stages the blocking copies between the host and the GPU in a page-locked host buffer
+    pub(crate) fn enable_pinned_staging(&mut self) {
+        if self.staging.is_none() {
+            self.staging = Some(RefCell::new(CudaPinnedBuffer::new()));
+        }
+    }
+
+    /// Stops staging the copies, and frees the page-locked host buffer
+    pub(crate) fn disable_pinned_staging(&mut self) {
+        self.staging = None;
+    }
+
+    /// Checks whether the blocking copies are staged in a page-locked host buffer
+    pub(crate) fn is_pinned_staging_enabled(&self) -> bool {
+        self.staging.is_some()
+    }
+
     /// Check that the GPU has enough global memory
     pub(crate) fn check_device_memory(&self, size: u64) -> Result<(), CudaError> {
         let valid = unsafe { cuda_check_valid_malloc(size, self.gpu_index().0 as u32) };
@@ -70,10 +186,60 @@ impl CudaStream {
             ptr,
             idx: self.gpu_index.0 as u32,
             len: elements as usize,
+            #[cfg(feature = "backend_cuda_debug")]
+            engine_id: self.engine_id,
             _phantom: PhantomData::default(),
         }
     }
 
+    /// Allocates `elements` on the GPU, reusing a buffer of the memory pool if possible
+    ///
+    /// The returned vector should be given back to the pool with [`CudaStream::recycle`] once
+    /// the operations using it are enqueued, and must only be used by work enqueued on this
+    /// stream.
+    pub(crate) fn malloc_pooled<T>(&self, elements: u32) -> CudaVec<T>
+    where
+        T: Numeric,
+    {
+        let size = elements as usize * std::mem::size_of::<T>();
+        match self.pool.borrow_mut().take(self.gpu_index, size) {
+            Some(ptr) => CudaVec {
+                ptr,
+                idx: self.gpu_index.0 as u32,
+                len: elements as usize,
+                #[cfg(feature = "backend_cuda_debug")]
+                engine_id: self.engine_id,
+                _phantom: PhantomData,
+            },
+            None => self.malloc(elements),
+        }
+    }
+
+    /// Gives a vector back to the memory pool of the stream, instead of freeing it
+    ///
+    /// The vector may still be used by the work enqueued on the stream, but it must not be used
+    /// by the work enqueued on other streams.
+    pub(crate) fn recycle<T>(&self, vec: CudaVec<T>)
+    where
+        T: Numeric,
+    {
+        let size = vec.len() * std::mem::size_of::<T>();
+        self.pool
+            .borrow_mut()
+            .put(self.gpu_index, size, vec.as_c_ptr() as *mut c_void);
+        std::mem::forget(vec);
+    }
+
+    /// Frees the buffers of the memory pool
+    pub(crate) fn clear_memory_pool(&mut self) {
+        self.pool.get_mut().clear();
+    }
+
+    /// Gets the total size of the buffers held by the memory pool, in bytes
+    pub(crate) fn get_memory_pool_size(&self) -> usize {
+        self.pool.borrow().size()
+    }
+
     /// Copies data from slice into GPU pointer
     ///
     /// # Safety
@@ -98,6 +264,11 @@ impl CudaStream {
 
     /// Copies data from slice into GPU pointer
     ///
+    /// When pinned staging is enabled, the data goes through the page-locked host buffer of the
+    /// stream.
+    /// The copy is enqueued after the work already enqueued on the stream, and only waits for
+    /// this work, so that it can overlap with the work of the other streams of the device.
+    ///
     /// # Safety
     ///
     /// - `dest` __must__ be a valid pointer
@@ -106,8 +277,19 @@ impl CudaStream {
     where
         T: Numeric,
     {
-        self.copy_to_gpu_async(dest, src);
-        self.synchronize_device();
+        match &self.staging {
+            Some(staging) => {
+                let mut staging = staging.borrow_mut();
+                let pinned = staging.as_mut_slice::<T>(src.len());
+                pinned.copy_from_slice(src);
+                self.copy_to_gpu_async(dest, pinned);
+                self.synchronize_stream();
+            }
+            None => {
+                self.copy_to_gpu_async(dest, src);
+                self.synchronize_stream();
+            }
+        }
     }
 
     /// Copies data from GPU pointer into slice
@@ -134,6 +316,11 @@ impl CudaStream {
 
     /// Copies data from GPU pointer into slice
     ///
+    /// When pinned staging is enabled, the data goes through the page-locked host buffer of the
+    /// stream.
+    /// The copy is enqueued after the work already enqueued on the stream, and only waits for
+    /// this work, so that it can overlap with the work of the other streams of the device.
+    ///
     /// # Safety
     ///
     /// - `dest` __must__ be a valid pointer
@@ -142,8 +329,19 @@ impl CudaStream {
     where
         T: Numeric,
     {
-        self.copy_to_cpu_async(dest, src);
-        self.synchronize_device();
+        match &self.staging {
+            Some(staging) => {
+                let mut staging = staging.borrow_mut();
+                let pinned = staging.as_mut_slice::<T>(dest.len());
+                self.copy_to_cpu_async(pinned, src);
+                self.synchronize_stream();
+                dest.copy_from_slice(pinned);
+            }
+            None => {
+                self.copy_to_cpu_async(dest, src);
+                self.synchronize_stream();
+            }
+        }
     }
 
     /// Synchronizes the device
@@ -152,6 +350,23 @@ impl CudaStream {
         unsafe { cuda_synchronize_device(self.gpu_index().0 as u32) };
     }
 
+    /// Waits for the completion of the work enqueued on the stream
+    pub(crate) fn synchronize_stream(&self) {
+        unsafe { cuda_synchronize_stream(self.stream_handle().0, self.gpu_index().0 as u32) };
+    }
+
+    /// Checks whether the work enqueued on the stream is complete, without blocking
+    pub(crate) fn is_idle(&self) -> Result<bool, CudaError> {
+        let status =
+            unsafe { cuda_query_stream(self.stream_handle().0, self.gpu_index().0 as u32) };
+        match status {
+            1 => Ok(true),
+            0 => Ok(false),
+            -2 => Err(CudaError::InvalidDeviceIndex(self.gpu_index())),
+            _ => Err(CudaError::UnspecifiedDeviceError(self.gpu_index())),
+        }
+    }
+
     /// Get the maximum amount of shared memory
     pub(crate) fn get_max_shared_memory(&self) -> Result<i32, CudaError> {
         let max_shared_memory = unsafe { cuda_get_max_shared_memory(self.gpu_index().0 as u32) };
@@ -162,6 +377,42 @@ impl CudaStream {
         }
     }
 
+    /// Get the number of streaming multiprocessors
+    pub(crate) fn get_number_of_sms(&self) -> Result<i32, CudaError> {
+        let number_of_sms = unsafe { cuda_get_number_of_sms(self.gpu_index().0 as u32) };
+        match number_of_sms {
+            -2 => Err(CudaError::InvalidDeviceIndex(self.gpu_index())),
+            n if n <= 0 => Err(CudaError::UnspecifiedDeviceError(self.gpu_index())),
+            _ => Ok(number_of_sms),
+        }
+    }
+
+    /// Get the compute capability, as a (major, minor) pair
+    pub(crate) fn get_compute_capability(&self) -> Result<(u32, u32), CudaError> {
+        let mut major = 0;
+        let mut minor = 0;
+        let status = unsafe {
+            cuda_get_compute_capability(self.gpu_index().0 as u32, &mut major, &mut minor)
+        };
+        match status {
+            0 => Ok((major as u32, minor as u32)),
+            -2 => Err(CudaError::InvalidDeviceIndex(self.gpu_index())),
+            _ => Err(CudaError::UnspecifiedDeviceError(self.gpu_index())),
+        }
+    }
+
+    /// Get the amount of global memory, in bytes
+    pub(crate) fn get_global_memory(&self) -> Result<u64, CudaError> {
+        let mut global_memory = 0;
+        let status =
+            unsafe { cuda_get_global_memory(self.gpu_index().0 as u32, &mut global_memory) };
+        match status {
+            0 => Ok(global_memory),
+            -2 => Err(CudaError::InvalidDeviceIndex(self.gpu_index())),
+            _ => Err(CudaError::UnspecifiedDeviceError(self.gpu_index())),
+        }
+    }
+
     /// Initialize twiddles
     #[allow(dead_code)]
     pub fn initialize_twiddles(&self, polynomial_size: PolynomialSize) {
@@ -178,10 +429,50 @@ impl CudaStream {
         glwe_dim: GlweDimension,
         l_gadget: DecompositionLevelCount,
         polynomial_size: PolynomialSize,
+    ) {
+        self.convert_lwe_bootstrap_key_to_ptr(
+            dest.as_mut_c_ptr(),
+            src,
+            input_lwe_dim,
+            glwe_dim,
+            l_gadget,
+            polynomial_size,
+        )
+    }
+
+    /// Convert a GGSW ciphertext of a bootstrap key, and writes it in place of the GGSW
+    /// ciphertext starting at `offset` in `dest`
+    pub unsafe fn convert_lwe_bootstrap_key_ggsw<T: UnsignedInteger>(
+        &self,
+        dest: &mut CudaVec<f64>,
+        offset: usize,
+        src: &[T],
+        glwe_dim: GlweDimension,
+        l_gadget: DecompositionLevelCount,
+        polynomial_size: PolynomialSize,
+    ) {
+        self.convert_lwe_bootstrap_key_to_ptr(
+            (dest.as_mut_c_ptr() as *mut f64).add(offset) as *mut c_void,
+            src,
+            LweDimension(1),
+            glwe_dim,
+            l_gadget,
+            polynomial_size,
+        )
+    }
+
+    unsafe fn convert_lwe_bootstrap_key_to_ptr<T: UnsignedInteger>(
+        &self,
+        dest: *mut c_void,
+        src: &[T],
+        input_lwe_dim: LweDimension,
+        glwe_dim: GlweDimension,
+        l_gadget: DecompositionLevelCount,
+        polynomial_size: PolynomialSize,
     ) {
         if T::BITS == 32 {
             cuda_convert_lwe_bootstrap_key_32(
-                dest.as_mut_c_ptr(),
+                dest,
                 src.as_ptr() as *mut c_void,
                 self.stream.0,
                 self.gpu_index.0 as u32,
@@ -192,7 +483,7 @@ impl CudaStream {
             )
         } else if T::BITS == 64 {
             cuda_convert_lwe_bootstrap_key_64(
-                dest.as_mut_c_ptr(),
+                dest,
                 src.as_ptr() as *mut c_void,
                 self.stream.0,
                 self.gpu_index.0 as u32,
@@ -391,4 +682,40 @@ mod tests {
         }
         assert_eq!(vec, empty);
     }
+    #[test]
+    fn allocate_and_copy_with_pinned_staging() {
+        let vec = vec![1_u64, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+        let gpu_index = GpuIndex(0);
+        let mut stream = CudaStream::new(gpu_index).unwrap();
+        stream.enable_pinned_staging();
+        stream.check_device_memory(vec.len() as u64).unwrap();
+        let mut d_vec: CudaVec<u64> = stream.malloc::<u64>(vec.len() as u32);
+        unsafe {
+            stream.copy_to_gpu(&mut d_vec, &vec);
+        }
+        let mut empty = vec![0_u64; vec.len()];
+        unsafe {
+            stream.copy_to_cpu(&mut empty, &d_vec);
+        }
+        assert_eq!(vec, empty);
+    }
+
+    #[test]
+    fn reuse_pooled_buffer() {
+        let gpu_index = GpuIndex(0);
+        let mut stream = CudaStream::new(gpu_index).unwrap();
+        let d_vec: CudaVec<u32> = stream.malloc_pooled::<u32>(12);
+        let ptr = d_vec.as_c_ptr();
+        stream.recycle(d_vec);
+        assert_eq!(
+            stream.get_memory_pool_size(),
+            12 * std::mem::size_of::<u32>()
+        );
+        let d_vec: CudaVec<u32> = stream.malloc_pooled::<u32>(12);
+        assert_eq!(d_vec.as_c_ptr(), ptr);
+        assert_eq!(stream.get_memory_pool_size(), 0);
+        stream.recycle(d_vec);
+        stream.clear_memory_pool();
+        assert_eq!(stream.get_memory_pool_size(), 0);
+    }
 }
@@ -1,3 +1,5 @@
+#[cfg(feature = "backend_cuda_debug")]
+use crate::backends::cuda::private::device::EngineId;
 use crate::backends::cuda::private::device::{GpuIndex, NumberOfGpus, NumberOfSamples};
 use concrete_commons::parameters::CiphertextCount;
 use std::cmp::min;
@@ -7,6 +9,13 @@ pub mod device;
 pub mod pointers;
 pub mod vec;
 
+/// A trait implemented by the entities stored on the GPUs, to check which engine they come from.
+#[cfg(feature = "backend_cuda_debug")]
+pub(crate) trait CudaEntityOrigin {
+    /// Returns `true` if the whole device memory of the entity was allocated by the given engine.
+    fn was_allocated_by(&self, engine_id: EngineId) -> bool;
+}
+
 pub(crate) fn number_of_active_gpus(
     total_number_of_gpus: NumberOfGpus,
     lwe_ciphertext_count: CiphertextCount,
@@ -1,5 +1,7 @@
+#[cfg(feature = "backend_cuda_debug")]
+use crate::backends::cuda::private::device::EngineId;
 use concrete_commons::numeric::Numeric;
-use concrete_cuda::cuda_bind::cuda_drop;
+use concrete_cuda::cuda_bind::{cuda_drop, cuda_drop_host, cuda_malloc_host};
 use std::ffi::c_void;
 use std::marker::PhantomData;
 
@@ -20,6 +22,8 @@ pub struct CudaVec<T: Numeric> {
     pub(super) ptr: *mut c_void,
     pub(super) idx: u32,
     pub(super) len: usize,
+    #[cfg(feature = "backend_cuda_debug")]
+    pub(super) engine_id: Option<EngineId>,
     pub(super) _phantom: PhantomData<T>,
 }
 
@@ -43,6 +47,12 @@ impl<T: Numeric> CudaVec<T> {
     pub fn is_empty(&self) -> bool {
         self.len == 0
     }
+
+    /// Returns the id of the engine whose stream allocated the vector, if any.
+    #[cfg(feature = "backend_cuda_debug")]
+    pub(crate) fn engine_id(&self) -> Option<EngineId> {
+        self.engine_id
+    }
 }
 
 impl<T: Numeric> Drop for CudaVec<T> {
@@ -50,3 +60,53 @@ impl<T: Numeric> Drop for CudaVec<T> {
         unsafe { cuda_drop(self.ptr, self.idx) };
     }
 }
+
+/// A page-locked host buffer, used to stage the data copied between the host and the gpus.
+///
+/// Copies from and to page-locked memory are significantly faster than copies from and to
+/// pageable memory. The buffer is grown on demand, and frees the host memory on drop.
+#[derive(Debug, PartialEq, Eq)]
+pub struct CudaPinnedBuffer {
+    ptr: *mut c_void,
+    size: usize,
+}
+
+impl CudaPinnedBuffer {
+    /// Creates an empty buffer, no memory is allocated until it is used.
+    pub(crate) fn new() -> Self {
+        CudaPinnedBuffer {
+            ptr: std::ptr::null_mut(),
+            size: 0,
+        }
+    }
+
+    /// Returns a slice of `len` elements of the buffer, growing it if needed.
+    ///
+    /// The content of the returned slice is unspecified.
+    pub(crate) fn as_mut_slice<T: Numeric>(&mut self, len: usize) -> &mut [T] {
+        let size = len * std::mem::size_of::<T>();
+        if size > self.size {
+            self.release();
+            self.ptr = unsafe { cuda_malloc_host(size as u64) };
+            self.size = size;
+        }
+        if len == 0 {
+            return &mut [];
+        }
+        unsafe { std::slice::from_raw_parts_mut(self.ptr as *mut T, len) }
+    }
+
+    fn release(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe { cuda_drop_host(self.ptr) };
+            self.ptr = std::ptr::null_mut();
+            self.size = 0;
+        }
+    }
+}
+
+impl Drop for CudaPinnedBuffer {
+    fn drop(&mut self) {
+        self.release();
+    }
+}
@@ -1,9 +1,42 @@
 use crate::backends::default::implementation::engines::DefaultEngine;
-use crate::backends::default::implementation::entities::{Cleartext32, Cleartext64};
+use crate::backends::default::implementation::entities::{Cleartext16, Cleartext32, Cleartext64};
 use crate::commons::crypto::encoding::Cleartext as ImplCleartext;
 use crate::prelude::CleartextF64;
 use crate::specification::engines::{CleartextCreationEngine, CleartextCreationError};
 
+/// # Description:
+/// Implementation of [`CleartextCreationEngine`] for [`DefaultEngine`] that operates on 16 bits
+/// integers.
+impl CleartextCreationEngine<u16, Cleartext16> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input: u16 = 3;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let cleartext: Cleartext16 = engine.create_cleartext_from(&input)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_cleartext_from(
+        &mut self,
+        input: &u16,
+    ) -> Result<Cleartext16, CleartextCreationError<Self::EngineError>> {
+        Ok(unsafe { self.create_cleartext_from_unchecked(input) })
+    }
+
+    unsafe fn create_cleartext_from_unchecked(&mut self, input: &u16) -> Cleartext16 {
+        Cleartext16(ImplCleartext(*input))
+    }
+}
+
 /// # Description:
 /// Implementation of [`CleartextCreationEngine`] for [`DefaultEngine`] that operates on 32 bits
 /// integers.
@@ -1,7 +1,7 @@
 use crate::commons::crypto::encoding::Encoder;
 use crate::prelude::{
-    CleartextEncodingEngine, CleartextEncodingError, CleartextF64, DefaultEngine, DefaultError,
-    FloatEncoder, Plaintext32, Plaintext64,
+    Cleartext32, Cleartext64, CleartextEncodingEngine, CleartextEncodingError, CleartextF64,
+    DefaultEngine, DefaultError, FloatEncoder, ModularEncoder, Plaintext32, Plaintext64,
 };
 
 /// # Description:
@@ -97,3 +97,103 @@ impl CleartextEncodingEngine<FloatEncoder, CleartextF64, Plaintext64> for Defaul
         Plaintext64(encoder.0.encode(cleartext.0))
     }
 }
+
+/// # Description:
+/// Implementation of [`CleartextEncodingEngine`] for [`DefaultEngine`] that encodes 32 bits
+/// integers modulo an arbitrary modulus to 32 bits integers.
+impl CleartextEncodingEngine<ModularEncoder, Cleartext32, Plaintext32> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let encoder = engine.create_encoder_from(&ModularEncoderConfig {
+    ///     modulus: 5,
+    ///     nb_bit_padding: 1,
+    /// })?;
+    /// let cleartext: Cleartext32 = engine.create_cleartext_from(&3_u32)?;
+    /// let plaintext: Plaintext32 = engine.encode_cleartext(&encoder, &cleartext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encode_cleartext(
+        &mut self,
+        encoder: &ModularEncoder,
+        cleartext: &Cleartext32,
+    ) -> Result<Plaintext32, CleartextEncodingError<Self::EngineError>> {
+        if !encoder.0.fits_in_precision(32) {
+            return Err(CleartextEncodingError::Engine(
+                DefaultError::ModularEncoderModulusTooLarge,
+            ));
+        }
+        if u64::from(cleartext.0 .0) >= encoder.0.modulus {
+            return Err(CleartextEncodingError::Engine(
+                DefaultError::ModularEncoderMessageOutsideModulus,
+            ));
+        }
+        Ok(unsafe { self.encode_cleartext_unchecked(encoder, cleartext) })
+    }
+
+    unsafe fn encode_cleartext_unchecked(
+        &mut self,
+        encoder: &ModularEncoder,
+        cleartext: &Cleartext32,
+    ) -> Plaintext32 {
+        Plaintext32(encoder.0.encode(cleartext.0))
+    }
+}
+
+/// # Description:
+/// Implementation of [`CleartextEncodingEngine`] for [`DefaultEngine`] that encodes 64 bits
+/// integers modulo an arbitrary modulus to 64 bits integers.
+impl CleartextEncodingEngine<ModularEncoder, Cleartext64, Plaintext64> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let encoder = engine.create_encoder_from(&ModularEncoderConfig {
+    ///     modulus: 5,
+    ///     nb_bit_padding: 1,
+    /// })?;
+    /// let cleartext: Cleartext64 = engine.create_cleartext_from(&3_u64)?;
+    /// let plaintext: Plaintext64 = engine.encode_cleartext(&encoder, &cleartext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encode_cleartext(
+        &mut self,
+        encoder: &ModularEncoder,
+        cleartext: &Cleartext64,
+    ) -> Result<Plaintext64, CleartextEncodingError<Self::EngineError>> {
+        if !encoder.0.fits_in_precision(64) {
+            return Err(CleartextEncodingError::Engine(
+                DefaultError::ModularEncoderModulusTooLarge,
+            ));
+        }
+        if cleartext.0 .0 >= encoder.0.modulus {
+            return Err(CleartextEncodingError::Engine(
+                DefaultError::ModularEncoderMessageOutsideModulus,
+            ));
+        }
+        Ok(unsafe { self.encode_cleartext_unchecked(encoder, cleartext) })
+    }
+
+    unsafe fn encode_cleartext_unchecked(
+        &mut self,
+        encoder: &ModularEncoder,
+        cleartext: &Cleartext64,
+    ) -> Plaintext64 {
+        Plaintext64(encoder.0.encode(cleartext.0))
+    }
+}
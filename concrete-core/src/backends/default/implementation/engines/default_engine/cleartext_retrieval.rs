@@ -1,8 +1,44 @@
 use crate::backends::default::implementation::engines::DefaultEngine;
-use crate::backends::default::implementation::entities::{Cleartext32, Cleartext64};
+use crate::backends::default::implementation::entities::{Cleartext16, Cleartext32, Cleartext64};
 use crate::prelude::CleartextF64;
 use crate::specification::engines::{CleartextRetrievalEngine, CleartextRetrievalError};
 
+/// # Description:
+/// Implementation of [`CleartextRetrievalEngine`] for [`DefaultEngine`] that operates on 16 bits
+/// integers.
+impl CleartextRetrievalEngine<Cleartext16, u16> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input: u16 = 3;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let cleartext: Cleartext16 = engine.create_cleartext_from(&input)?;
+    /// let output: u16 = engine.retrieve_cleartext(&cleartext)?;
+    ///
+    /// assert_eq!(output, 3_u16);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn retrieve_cleartext(
+        &mut self,
+        cleartext: &Cleartext16,
+    ) -> Result<u16, CleartextRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.retrieve_cleartext_unchecked(cleartext) })
+    }
+
+    unsafe fn retrieve_cleartext_unchecked(&mut self, cleartext: &Cleartext16) -> u16 {
+        cleartext.0 .0
+    }
+}
+
 /// # Description:
 /// Implementation of [`CleartextRetrievalEngine`] for [`DefaultEngine`] that operates on 32 bits
 /// integers.
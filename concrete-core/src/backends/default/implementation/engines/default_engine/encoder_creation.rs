@@ -1,6 +1,6 @@
 use crate::prelude::{
     DefaultEngine, DefaultError, EncoderCreationEngine, EncoderCreationError, FloatEncoder,
-    FloatEncoderCenterRadiusConfig, FloatEncoderMinMaxConfig,
+    FloatEncoderCenterRadiusConfig, FloatEncoderMinMaxConfig, ModularEncoder, ModularEncoderConfig,
 };
 
 /// # Description:
@@ -96,3 +96,44 @@ impl EncoderCreationEngine<FloatEncoderCenterRadiusConfig, FloatEncoder> for Def
         FloatEncoder(config.to_commons())
     }
 }
+
+/// # Description:
+/// Implementation of [`EncoderCreationEngine`] for [`DefaultEngine`] that creates an encoder to
+/// encode integers modulo an arbitrary modulus.
+impl EncoderCreationEngine<ModularEncoderConfig, ModularEncoder> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let encoder = engine.create_encoder_from(&ModularEncoderConfig {
+    ///     modulus: 5,
+    ///     nb_bit_padding: 1,
+    /// })?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_encoder_from(
+        &mut self,
+        config: &ModularEncoderConfig,
+    ) -> Result<ModularEncoder, EncoderCreationError<Self::EngineError>> {
+        if config.modulus < 2 {
+            return Err(EncoderCreationError::Engine(
+                DefaultError::ModularEncoderInvalidModulus,
+            ));
+        }
+        Ok(unsafe { self.create_encoder_from_unchecked(config) })
+    }
+
+    unsafe fn create_encoder_from_unchecked(
+        &mut self,
+        config: &ModularEncoderConfig,
+    ) -> ModularEncoder {
+        ModularEncoder(config.to_commons())
+    }
+}
@@ -0,0 +1,333 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GlweCiphertext32, GlweCiphertext64, GlweSecretKey32, GlweSecretKey64, LweCiphertext32,
+    LweCiphertext64, LweSecretKey32, LweSecretKey64,
+};
+use crate::commons::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::commons::utils::write_raw_dump;
+use crate::specification::engines::{EntityRawExportEngine, EntityRawExportError};
+use crate::specification::entities::{
+    GlweCiphertextEntity, GlweSecretKeyEntity, LweCiphertextEntity, LweSecretKeyEntity,
+};
+
+/// # Description:
+/// Implementation of [`EntityRawExportEngine`] for [`DefaultEngine`] that operates on 32 bits
+/// LWE ciphertexts.
+impl EntityRawExportEngine<LweCiphertext32> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_dimension = LweDimension(4);
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let entity: LweCiphertext32 = engine.zero_encrypt_lwe_ciphertext(&key, Variance(2_f64.powf(-25.)))?;
+    ///
+    /// let raw = engine.export_raw(&entity)?;
+    /// #
+    /// // The header words are followed by the 32 bits coefficients.
+    /// assert_eq!(raw[8..16], 32_u64.to_le_bytes());
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn export_raw(
+        &mut self,
+        entity: &LweCiphertext32,
+    ) -> Result<Vec<u8>, EntityRawExportError<Self::EngineError>> {
+        Ok(unsafe { self.export_raw_unchecked(entity) })
+    }
+
+    unsafe fn export_raw_unchecked(&mut self, entity: &LweCiphertext32) -> Vec<u8> {
+        write_raw_dump(&[entity.lwe_dimension().0], entity.0.as_tensor().as_slice())
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityRawExportEngine`] for [`DefaultEngine`] that operates on 64 bits
+/// LWE ciphertexts.
+impl EntityRawExportEngine<LweCiphertext64> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_dimension = LweDimension(4);
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let entity: LweCiphertext64 = engine.zero_encrypt_lwe_ciphertext(&key, Variance(2_f64.powf(-25.)))?;
+    ///
+    /// let raw = engine.export_raw(&entity)?;
+    /// #
+    /// // The header words are followed by the 64 bits coefficients.
+    /// assert_eq!(raw[8..16], 64_u64.to_le_bytes());
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn export_raw(
+        &mut self,
+        entity: &LweCiphertext64,
+    ) -> Result<Vec<u8>, EntityRawExportError<Self::EngineError>> {
+        Ok(unsafe { self.export_raw_unchecked(entity) })
+    }
+
+    unsafe fn export_raw_unchecked(&mut self, entity: &LweCiphertext64) -> Vec<u8> {
+        write_raw_dump(&[entity.lwe_dimension().0], entity.0.as_tensor().as_slice())
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityRawExportEngine`] for [`DefaultEngine`] that operates on 32 bits
+/// GLWE ciphertexts.
+impl EntityRawExportEngine<GlweCiphertext32> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let (glwe_dimension, polynomial_size) = (GlweDimension(2), PolynomialSize(4));
+    /// let key: GlweSecretKey32 = engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let entity: GlweCiphertext32 = engine.zero_encrypt_glwe_ciphertext(&key, Variance(2_f64.powf(-25.)))?;
+    ///
+    /// let raw = engine.export_raw(&entity)?;
+    /// #
+    /// // The header words are followed by the 32 bits coefficients.
+    /// assert_eq!(raw[16..24], 32_u64.to_le_bytes());
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn export_raw(
+        &mut self,
+        entity: &GlweCiphertext32,
+    ) -> Result<Vec<u8>, EntityRawExportError<Self::EngineError>> {
+        Ok(unsafe { self.export_raw_unchecked(entity) })
+    }
+
+    unsafe fn export_raw_unchecked(&mut self, entity: &GlweCiphertext32) -> Vec<u8> {
+        write_raw_dump(
+            &[entity.glwe_dimension().0, entity.polynomial_size().0],
+            entity.0.as_tensor().as_slice(),
+        )
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityRawExportEngine`] for [`DefaultEngine`] that operates on 64 bits
+/// GLWE ciphertexts.
+impl EntityRawExportEngine<GlweCiphertext64> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let (glwe_dimension, polynomial_size) = (GlweDimension(2), PolynomialSize(4));
+    /// let key: GlweSecretKey64 = engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let entity: GlweCiphertext64 = engine.zero_encrypt_glwe_ciphertext(&key, Variance(2_f64.powf(-25.)))?;
+    ///
+    /// let raw = engine.export_raw(&entity)?;
+    /// #
+    /// // The header words are followed by the 64 bits coefficients.
+    /// assert_eq!(raw[16..24], 64_u64.to_le_bytes());
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn export_raw(
+        &mut self,
+        entity: &GlweCiphertext64,
+    ) -> Result<Vec<u8>, EntityRawExportError<Self::EngineError>> {
+        Ok(unsafe { self.export_raw_unchecked(entity) })
+    }
+
+    unsafe fn export_raw_unchecked(&mut self, entity: &GlweCiphertext64) -> Vec<u8> {
+        write_raw_dump(
+            &[entity.glwe_dimension().0, entity.polynomial_size().0],
+            entity.0.as_tensor().as_slice(),
+        )
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityRawExportEngine`] for [`DefaultEngine`] that operates on 32 bits
+/// LWE secret keys.
+impl EntityRawExportEngine<LweSecretKey32> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let entity: LweSecretKey32 = engine.generate_new_lwe_secret_key(LweDimension(4))?;
+    ///
+    /// let raw = engine.export_raw(&entity)?;
+    /// #
+    /// // The header words are followed by the 32 bits coefficients.
+    /// assert_eq!(raw[8..16], 32_u64.to_le_bytes());
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn export_raw(
+        &mut self,
+        entity: &LweSecretKey32,
+    ) -> Result<Vec<u8>, EntityRawExportError<Self::EngineError>> {
+        Ok(unsafe { self.export_raw_unchecked(entity) })
+    }
+
+    unsafe fn export_raw_unchecked(&mut self, entity: &LweSecretKey32) -> Vec<u8> {
+        write_raw_dump(&[entity.lwe_dimension().0], entity.0.as_tensor().as_slice())
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityRawExportEngine`] for [`DefaultEngine`] that operates on 64 bits
+/// LWE secret keys.
+impl EntityRawExportEngine<LweSecretKey64> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let entity: LweSecretKey64 = engine.generate_new_lwe_secret_key(LweDimension(4))?;
+    ///
+    /// let raw = engine.export_raw(&entity)?;
+    /// #
+    /// // The header words are followed by the 64 bits coefficients.
+    /// assert_eq!(raw[8..16], 64_u64.to_le_bytes());
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn export_raw(
+        &mut self,
+        entity: &LweSecretKey64,
+    ) -> Result<Vec<u8>, EntityRawExportError<Self::EngineError>> {
+        Ok(unsafe { self.export_raw_unchecked(entity) })
+    }
+
+    unsafe fn export_raw_unchecked(&mut self, entity: &LweSecretKey64) -> Vec<u8> {
+        write_raw_dump(&[entity.lwe_dimension().0], entity.0.as_tensor().as_slice())
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityRawExportEngine`] for [`DefaultEngine`] that operates on 32 bits
+/// GLWE secret keys.
+impl EntityRawExportEngine<GlweSecretKey32> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let entity: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(GlweDimension(2), PolynomialSize(4))?;
+    ///
+    /// let raw = engine.export_raw(&entity)?;
+    /// #
+    /// // The header words are followed by the 32 bits coefficients.
+    /// assert_eq!(raw[16..24], 32_u64.to_le_bytes());
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn export_raw(
+        &mut self,
+        entity: &GlweSecretKey32,
+    ) -> Result<Vec<u8>, EntityRawExportError<Self::EngineError>> {
+        Ok(unsafe { self.export_raw_unchecked(entity) })
+    }
+
+    unsafe fn export_raw_unchecked(&mut self, entity: &GlweSecretKey32) -> Vec<u8> {
+        write_raw_dump(
+            &[entity.glwe_dimension().0, entity.polynomial_size().0],
+            entity.0.as_tensor().as_slice(),
+        )
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityRawExportEngine`] for [`DefaultEngine`] that operates on 64 bits
+/// GLWE secret keys.
+impl EntityRawExportEngine<GlweSecretKey64> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let entity: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(GlweDimension(2), PolynomialSize(4))?;
+    ///
+    /// let raw = engine.export_raw(&entity)?;
+    /// #
+    /// // The header words are followed by the 64 bits coefficients.
+    /// assert_eq!(raw[16..24], 64_u64.to_le_bytes());
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn export_raw(
+        &mut self,
+        entity: &GlweSecretKey64,
+    ) -> Result<Vec<u8>, EntityRawExportError<Self::EngineError>> {
+        Ok(unsafe { self.export_raw_unchecked(entity) })
+    }
+
+    unsafe fn export_raw_unchecked(&mut self, entity: &GlweSecretKey64) -> Vec<u8> {
+        write_raw_dump(
+            &[entity.glwe_dimension().0, entity.polynomial_size().0],
+            entity.0.as_tensor().as_slice(),
+        )
+    }
+}
@@ -0,0 +1,421 @@
+use concrete_commons::parameters::PolynomialSize;
+
+use crate::backends::default::implementation::engines::{DefaultEngine, DefaultError};
+use crate::backends::default::implementation::entities::{
+    GlweCiphertext32, GlweCiphertext64, GlweSecretKey32, GlweSecretKey64, LweCiphertext32,
+    LweCiphertext64, LweSecretKey32, LweSecretKey64,
+};
+use crate::commons::crypto::glwe::GlweCiphertext as ImplGlweCiphertext;
+use crate::commons::crypto::lwe::LweCiphertext as ImplLweCiphertext;
+use crate::commons::crypto::secret::{
+    GlweSecretKey as ImplGlweSecretKey, LweSecretKey as ImplLweSecretKey,
+};
+use crate::commons::utils::{read_raw_dump, RawDumpError};
+use crate::specification::engines::{EntityRawImportEngine, EntityRawImportError};
+
+impl<EngineError: std::error::Error> From<RawDumpError> for EntityRawImportError<EngineError> {
+    fn from(error: RawDumpError) -> Self {
+        match error {
+            RawDumpError::InvalidLength => EntityRawImportError::InvalidLength,
+            RawDumpError::ScalarSizeMismatch => EntityRawImportError::ScalarSizeMismatch,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityRawImportEngine`] for [`DefaultEngine`] that operates on 32 bits
+/// LWE ciphertexts.
+impl EntityRawImportEngine<LweCiphertext32> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_dimension = LweDimension(4);
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let entity: LweCiphertext32 = engine.zero_encrypt_lwe_ciphertext(&key, Variance(2_f64.powf(-25.)))?;
+    ///
+    /// let raw = engine.export_raw(&entity)?;
+    /// let imported: LweCiphertext32 = engine.import_raw(&raw)?;
+    /// #
+    /// assert_eq!(imported, entity);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn import_raw(
+        &mut self,
+        raw: &[u8],
+    ) -> Result<LweCiphertext32, EntityRawImportError<Self::EngineError>> {
+        let (_header, coefficients) =
+            read_raw_dump::<u32, _>(raw, 1, |header| header[0].checked_add(1))?;
+        Ok(LweCiphertext32(ImplLweCiphertext::from_container(
+            coefficients,
+        )))
+    }
+
+    unsafe fn import_raw_unchecked(&mut self, raw: &[u8]) -> LweCiphertext32 {
+        let (_header, coefficients) =
+            read_raw_dump::<u32, _>(raw, 1, |header| header[0].checked_add(1)).unwrap();
+        LweCiphertext32(ImplLweCiphertext::from_container(coefficients))
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityRawImportEngine`] for [`DefaultEngine`] that operates on 64 bits
+/// LWE ciphertexts.
+impl EntityRawImportEngine<LweCiphertext64> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_dimension = LweDimension(4);
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let entity: LweCiphertext64 = engine.zero_encrypt_lwe_ciphertext(&key, Variance(2_f64.powf(-25.)))?;
+    ///
+    /// let raw = engine.export_raw(&entity)?;
+    /// let imported: LweCiphertext64 = engine.import_raw(&raw)?;
+    /// #
+    /// assert_eq!(imported, entity);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn import_raw(
+        &mut self,
+        raw: &[u8],
+    ) -> Result<LweCiphertext64, EntityRawImportError<Self::EngineError>> {
+        let (_header, coefficients) =
+            read_raw_dump::<u64, _>(raw, 1, |header| header[0].checked_add(1))?;
+        Ok(LweCiphertext64(ImplLweCiphertext::from_container(
+            coefficients,
+        )))
+    }
+
+    unsafe fn import_raw_unchecked(&mut self, raw: &[u8]) -> LweCiphertext64 {
+        let (_header, coefficients) =
+            read_raw_dump::<u64, _>(raw, 1, |header| header[0].checked_add(1)).unwrap();
+        LweCiphertext64(ImplLweCiphertext::from_container(coefficients))
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityRawImportEngine`] for [`DefaultEngine`] that operates on 32 bits
+/// GLWE ciphertexts.
+impl EntityRawImportEngine<GlweCiphertext32> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let (glwe_dimension, polynomial_size) = (GlweDimension(2), PolynomialSize(4));
+    /// let key: GlweSecretKey32 = engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let entity: GlweCiphertext32 = engine.zero_encrypt_glwe_ciphertext(&key, Variance(2_f64.powf(-25.)))?;
+    ///
+    /// let raw = engine.export_raw(&entity)?;
+    /// let imported: GlweCiphertext32 = engine.import_raw(&raw)?;
+    /// #
+    /// assert_eq!(imported, entity);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn import_raw(
+        &mut self,
+        raw: &[u8],
+    ) -> Result<GlweCiphertext32, EntityRawImportError<Self::EngineError>> {
+        let (header, coefficients) = read_raw_dump::<u32, _>(raw, 2, |header| {
+            (header[0].checked_add(1)?).checked_mul(header[1])
+        })?;
+        Ok(GlweCiphertext32(ImplGlweCiphertext::from_container(
+            coefficients,
+            PolynomialSize(header[1]),
+        )))
+    }
+
+    unsafe fn import_raw_unchecked(&mut self, raw: &[u8]) -> GlweCiphertext32 {
+        let (header, coefficients) = read_raw_dump::<u32, _>(raw, 2, |header| {
+            (header[0].checked_add(1)?).checked_mul(header[1])
+        })
+        .unwrap();
+        GlweCiphertext32(ImplGlweCiphertext::from_container(
+            coefficients,
+            PolynomialSize(header[1]),
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityRawImportEngine`] for [`DefaultEngine`] that operates on 64 bits
+/// GLWE ciphertexts.
+impl EntityRawImportEngine<GlweCiphertext64> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let (glwe_dimension, polynomial_size) = (GlweDimension(2), PolynomialSize(4));
+    /// let key: GlweSecretKey64 = engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let entity: GlweCiphertext64 = engine.zero_encrypt_glwe_ciphertext(&key, Variance(2_f64.powf(-25.)))?;
+    ///
+    /// let raw = engine.export_raw(&entity)?;
+    /// let imported: GlweCiphertext64 = engine.import_raw(&raw)?;
+    /// #
+    /// assert_eq!(imported, entity);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn import_raw(
+        &mut self,
+        raw: &[u8],
+    ) -> Result<GlweCiphertext64, EntityRawImportError<Self::EngineError>> {
+        let (header, coefficients) = read_raw_dump::<u64, _>(raw, 2, |header| {
+            (header[0].checked_add(1)?).checked_mul(header[1])
+        })?;
+        Ok(GlweCiphertext64(ImplGlweCiphertext::from_container(
+            coefficients,
+            PolynomialSize(header[1]),
+        )))
+    }
+
+    unsafe fn import_raw_unchecked(&mut self, raw: &[u8]) -> GlweCiphertext64 {
+        let (header, coefficients) = read_raw_dump::<u64, _>(raw, 2, |header| {
+            (header[0].checked_add(1)?).checked_mul(header[1])
+        })
+        .unwrap();
+        GlweCiphertext64(ImplGlweCiphertext::from_container(
+            coefficients,
+            PolynomialSize(header[1]),
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityRawImportEngine`] for [`DefaultEngine`] that operates on 32 bits
+/// LWE secret keys.
+impl EntityRawImportEngine<LweSecretKey32> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let entity: LweSecretKey32 = engine.generate_new_lwe_secret_key(LweDimension(4))?;
+    ///
+    /// let raw = engine.export_raw(&entity)?;
+    /// let imported: LweSecretKey32 = engine.import_raw(&raw)?;
+    /// #
+    /// assert_eq!(imported, entity);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn import_raw(
+        &mut self,
+        raw: &[u8],
+    ) -> Result<LweSecretKey32, EntityRawImportError<Self::EngineError>> {
+        let (_header, coefficients) = read_raw_dump::<u32, _>(raw, 1, |header| Some(header[0]))?;
+        if coefficients.iter().any(|coefficient| *coefficient > 1) {
+            return Err(EntityRawImportError::Engine(
+                DefaultError::RawDumpNonBinarySecretKey,
+            ));
+        }
+        Ok(LweSecretKey32(ImplLweSecretKey::binary_from_container(
+            coefficients,
+        )))
+    }
+
+    unsafe fn import_raw_unchecked(&mut self, raw: &[u8]) -> LweSecretKey32 {
+        let (_header, coefficients) =
+            read_raw_dump::<u32, _>(raw, 1, |header| Some(header[0])).unwrap();
+        LweSecretKey32(ImplLweSecretKey::binary_from_container(coefficients))
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityRawImportEngine`] for [`DefaultEngine`] that operates on 64 bits
+/// LWE secret keys.
+impl EntityRawImportEngine<LweSecretKey64> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let entity: LweSecretKey64 = engine.generate_new_lwe_secret_key(LweDimension(4))?;
+    ///
+    /// let raw = engine.export_raw(&entity)?;
+    /// let imported: LweSecretKey64 = engine.import_raw(&raw)?;
+    /// #
+    /// assert_eq!(imported, entity);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn import_raw(
+        &mut self,
+        raw: &[u8],
+    ) -> Result<LweSecretKey64, EntityRawImportError<Self::EngineError>> {
+        let (_header, coefficients) = read_raw_dump::<u64, _>(raw, 1, |header| Some(header[0]))?;
+        if coefficients.iter().any(|coefficient| *coefficient > 1) {
+            return Err(EntityRawImportError::Engine(
+                DefaultError::RawDumpNonBinarySecretKey,
+            ));
+        }
+        Ok(LweSecretKey64(ImplLweSecretKey::binary_from_container(
+            coefficients,
+        )))
+    }
+
+    unsafe fn import_raw_unchecked(&mut self, raw: &[u8]) -> LweSecretKey64 {
+        let (_header, coefficients) =
+            read_raw_dump::<u64, _>(raw, 1, |header| Some(header[0])).unwrap();
+        LweSecretKey64(ImplLweSecretKey::binary_from_container(coefficients))
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityRawImportEngine`] for [`DefaultEngine`] that operates on 32 bits
+/// GLWE secret keys.
+impl EntityRawImportEngine<GlweSecretKey32> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let entity: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(GlweDimension(2), PolynomialSize(4))?;
+    ///
+    /// let raw = engine.export_raw(&entity)?;
+    /// let imported: GlweSecretKey32 = engine.import_raw(&raw)?;
+    /// #
+    /// assert_eq!(imported, entity);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn import_raw(
+        &mut self,
+        raw: &[u8],
+    ) -> Result<GlweSecretKey32, EntityRawImportError<Self::EngineError>> {
+        let (header, coefficients) =
+            read_raw_dump::<u32, _>(raw, 2, |header| header[0].checked_mul(header[1]))?;
+        if coefficients.iter().any(|coefficient| *coefficient > 1) {
+            return Err(EntityRawImportError::Engine(
+                DefaultError::RawDumpNonBinarySecretKey,
+            ));
+        }
+        Ok(GlweSecretKey32(ImplGlweSecretKey::binary_from_container(
+            coefficients,
+            PolynomialSize(header[1]),
+        )))
+    }
+
+    unsafe fn import_raw_unchecked(&mut self, raw: &[u8]) -> GlweSecretKey32 {
+        let (header, coefficients) =
+            read_raw_dump::<u32, _>(raw, 2, |header| header[0].checked_mul(header[1])).unwrap();
+        GlweSecretKey32(ImplGlweSecretKey::binary_from_container(
+            coefficients,
+            PolynomialSize(header[1]),
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityRawImportEngine`] for [`DefaultEngine`] that operates on 64 bits
+/// GLWE secret keys.
+impl EntityRawImportEngine<GlweSecretKey64> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let entity: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(GlweDimension(2), PolynomialSize(4))?;
+    ///
+    /// let raw = engine.export_raw(&entity)?;
+    /// let imported: GlweSecretKey64 = engine.import_raw(&raw)?;
+    /// #
+    /// assert_eq!(imported, entity);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn import_raw(
+        &mut self,
+        raw: &[u8],
+    ) -> Result<GlweSecretKey64, EntityRawImportError<Self::EngineError>> {
+        let (header, coefficients) =
+            read_raw_dump::<u64, _>(raw, 2, |header| header[0].checked_mul(header[1]))?;
+        if coefficients.iter().any(|coefficient| *coefficient > 1) {
+            return Err(EntityRawImportError::Engine(
+                DefaultError::RawDumpNonBinarySecretKey,
+            ));
+        }
+        Ok(GlweSecretKey64(ImplGlweSecretKey::binary_from_container(
+            coefficients,
+            PolynomialSize(header[1]),
+        )))
+    }
+
+    unsafe fn import_raw_unchecked(&mut self, raw: &[u8]) -> GlweSecretKey64 {
+        let (header, coefficients) =
+            read_raw_dump::<u64, _>(raw, 2, |header| header[0].checked_mul(header[1])).unwrap();
+        GlweSecretKey64(ImplGlweSecretKey::binary_from_container(
+            coefficients,
+            PolynomialSize(header[1]),
+        ))
+    }
+}
@@ -0,0 +1,681 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GgswCiphertext32, GgswCiphertext64, GlweCiphertext32, GlweCiphertext64, GlweCiphertextVector32,
+    GlweCiphertextVector64, LweBootstrapKey32, LweBootstrapKey64, LweCiphertext16, LweCiphertext32,
+    LweCiphertext64, LweCiphertextVector32, LweCiphertextVector64, LweKeyswitchKey16,
+    LweKeyswitchKey32, LweKeyswitchKey64,
+};
+use crate::commons::utils::ContainerValidationError;
+use crate::specification::engines::{EntityValidationEngine, EntityValidationError};
+
+impl<EngineError: std::error::Error> From<ContainerValidationError>
+    for EntityValidationError<EngineError>
+{
+    fn from(error: ContainerValidationError) -> Self {
+        match error {
+            ContainerValidationError::EmptyContainer => EntityValidationError::EmptyContainer,
+            ContainerValidationError::NullDimension => EntityValidationError::NullDimension,
+            ContainerValidationError::InvalidContainerLength => {
+                EntityValidationError::InvalidContainerLength
+            }
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityValidationEngine`] for [`DefaultEngine`] that operates on 16 bits
+/// LWE ciphertexts.
+impl EntityValidationEngine<LweCiphertext16> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(4);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey16 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let entity: LweCiphertext16 = engine.zero_encrypt_lwe_ciphertext(&key, noise)?;
+    ///
+    /// engine.validate(&entity)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn validate(
+        &mut self,
+        entity: &LweCiphertext16,
+    ) -> Result<(), EntityValidationError<Self::EngineError>> {
+        entity.0.validate()?;
+        Ok(())
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityValidationEngine`] for [`DefaultEngine`] that operates on 32 bits
+/// LWE ciphertexts.
+impl EntityValidationEngine<LweCiphertext32> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let owned_container = vec![0_u32; 5];
+    /// let entity: LweCiphertext32 = engine.create_lwe_ciphertext_from(owned_container)?;
+    ///
+    /// engine.validate(&entity)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn validate(
+        &mut self,
+        entity: &LweCiphertext32,
+    ) -> Result<(), EntityValidationError<Self::EngineError>> {
+        entity.0.validate()?;
+        Ok(())
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityValidationEngine`] for [`DefaultEngine`] that operates on 64 bits
+/// LWE ciphertexts.
+impl EntityValidationEngine<LweCiphertext64> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let owned_container = vec![0_u64; 5];
+    /// let entity: LweCiphertext64 = engine.create_lwe_ciphertext_from(owned_container)?;
+    ///
+    /// engine.validate(&entity)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn validate(
+        &mut self,
+        entity: &LweCiphertext64,
+    ) -> Result<(), EntityValidationError<Self::EngineError>> {
+        entity.0.validate()?;
+        Ok(())
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityValidationEngine`] for [`DefaultEngine`] that operates on 32 bits
+/// LWE ciphertext vectors.
+impl EntityValidationEngine<LweCiphertextVector32> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweSize;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_size = LweSize(5);
+    /// let ciphertext_count = 3;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let owned_container = vec![0_u32; lwe_size.0 * ciphertext_count];
+    /// let entity: LweCiphertextVector32 =
+    ///     engine.create_lwe_ciphertext_vector_from(owned_container, lwe_size)?;
+    ///
+    /// engine.validate(&entity)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn validate(
+        &mut self,
+        entity: &LweCiphertextVector32,
+    ) -> Result<(), EntityValidationError<Self::EngineError>> {
+        entity.0.validate()?;
+        Ok(())
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityValidationEngine`] for [`DefaultEngine`] that operates on 64 bits
+/// LWE ciphertext vectors.
+impl EntityValidationEngine<LweCiphertextVector64> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweSize;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_size = LweSize(5);
+    /// let ciphertext_count = 3;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let owned_container = vec![0_u64; lwe_size.0 * ciphertext_count];
+    /// let entity: LweCiphertextVector64 =
+    ///     engine.create_lwe_ciphertext_vector_from(owned_container, lwe_size)?;
+    ///
+    /// engine.validate(&entity)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn validate(
+        &mut self,
+        entity: &LweCiphertextVector64,
+    ) -> Result<(), EntityValidationError<Self::EngineError>> {
+        entity.0.validate()?;
+        Ok(())
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityValidationEngine`] for [`DefaultEngine`] that operates on 32 bits
+/// GLWE ciphertexts.
+impl EntityValidationEngine<GlweCiphertext32> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweSize, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_size = GlweSize(3);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let owned_container = vec![0_u32; glwe_size.0 * polynomial_size.0];
+    /// let entity: GlweCiphertext32 =
+    ///     engine.create_glwe_ciphertext_from(owned_container, polynomial_size)?;
+    ///
+    /// engine.validate(&entity)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn validate(
+        &mut self,
+        entity: &GlweCiphertext32,
+    ) -> Result<(), EntityValidationError<Self::EngineError>> {
+        entity.0.validate()?;
+        Ok(())
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityValidationEngine`] for [`DefaultEngine`] that operates on 64 bits
+/// GLWE ciphertexts.
+impl EntityValidationEngine<GlweCiphertext64> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweSize, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_size = GlweSize(3);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let owned_container = vec![0_u64; glwe_size.0 * polynomial_size.0];
+    /// let entity: GlweCiphertext64 =
+    ///     engine.create_glwe_ciphertext_from(owned_container, polynomial_size)?;
+    ///
+    /// engine.validate(&entity)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn validate(
+        &mut self,
+        entity: &GlweCiphertext64,
+    ) -> Result<(), EntityValidationError<Self::EngineError>> {
+        entity.0.validate()?;
+        Ok(())
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityValidationEngine`] for [`DefaultEngine`] that operates on 32 bits
+/// GLWE ciphertext vectors.
+impl EntityValidationEngine<GlweCiphertextVector32> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let ciphertext_count = GlweCiphertextCount(3);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let entity = engine.zero_encrypt_glwe_ciphertext_vector(&key, noise, ciphertext_count)?;
+    ///
+    /// engine.validate(&entity)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn validate(
+        &mut self,
+        entity: &GlweCiphertextVector32,
+    ) -> Result<(), EntityValidationError<Self::EngineError>> {
+        entity.0.validate()?;
+        Ok(())
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityValidationEngine`] for [`DefaultEngine`] that operates on 64 bits
+/// GLWE ciphertext vectors.
+impl EntityValidationEngine<GlweCiphertextVector64> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let ciphertext_count = GlweCiphertextCount(3);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let entity = engine.zero_encrypt_glwe_ciphertext_vector(&key, noise, ciphertext_count)?;
+    ///
+    /// engine.validate(&entity)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn validate(
+        &mut self,
+        entity: &GlweCiphertextVector64,
+    ) -> Result<(), EntityValidationError<Self::EngineError>> {
+        entity.0.validate()?;
+        Ok(())
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityValidationEngine`] for [`DefaultEngine`] that operates on 16 bits
+/// LWE keyswitch keys.
+impl EntityValidationEngine<LweKeyswitchKey16> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(4);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey16 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey16 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    /// let entity = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// engine.validate(&entity)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn validate(
+        &mut self,
+        entity: &LweKeyswitchKey16,
+    ) -> Result<(), EntityValidationError<Self::EngineError>> {
+        entity.0.validate()?;
+        Ok(())
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityValidationEngine`] for [`DefaultEngine`] that operates on 32 bits
+/// LWE keyswitch keys.
+impl EntityValidationEngine<LweKeyswitchKey32> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    /// let entity = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// engine.validate(&entity)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn validate(
+        &mut self,
+        entity: &LweKeyswitchKey32,
+    ) -> Result<(), EntityValidationError<Self::EngineError>> {
+        entity.0.validate()?;
+        Ok(())
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityValidationEngine`] for [`DefaultEngine`] that operates on 64 bits
+/// LWE keyswitch keys.
+impl EntityValidationEngine<LweKeyswitchKey64> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    /// let entity = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// engine.validate(&entity)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn validate(
+        &mut self,
+        entity: &LweKeyswitchKey64,
+    ) -> Result<(), EntityValidationError<Self::EngineError>> {
+        entity.0.validate()?;
+        Ok(())
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityValidationEngine`] for [`DefaultEngine`] that operates on 32 bits
+/// LWE bootstrap keys.
+impl EntityValidationEngine<LweBootstrapKey32> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// let glwe_size = GlweSize(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(2);
+    /// let base_log = DecompositionBaseLog(1);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let owned_container =
+    ///     vec![0_u32; lwe_dimension.0 * level.0 * glwe_size.0 * glwe_size.0 * polynomial_size.0];
+    /// let entity: LweBootstrapKey32 = engine.create_lwe_bootstrap_key_from(
+    ///     owned_container,
+    ///     glwe_size,
+    ///     polynomial_size,
+    ///     base_log,
+    ///     level,
+    /// )?;
+    ///
+    /// engine.validate(&entity)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn validate(
+        &mut self,
+        entity: &LweBootstrapKey32,
+    ) -> Result<(), EntityValidationError<Self::EngineError>> {
+        entity.0.validate()?;
+        Ok(())
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityValidationEngine`] for [`DefaultEngine`] that operates on 64 bits
+/// LWE bootstrap keys.
+impl EntityValidationEngine<LweBootstrapKey64> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// let glwe_size = GlweSize(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(2);
+    /// let base_log = DecompositionBaseLog(1);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let owned_container =
+    ///     vec![0_u64; lwe_dimension.0 * level.0 * glwe_size.0 * glwe_size.0 * polynomial_size.0];
+    /// let entity: LweBootstrapKey64 = engine.create_lwe_bootstrap_key_from(
+    ///     owned_container,
+    ///     glwe_size,
+    ///     polynomial_size,
+    ///     base_log,
+    ///     level,
+    /// )?;
+    ///
+    /// engine.validate(&entity)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn validate(
+        &mut self,
+        entity: &LweBootstrapKey64,
+    ) -> Result<(), EntityValidationError<Self::EngineError>> {
+        entity.0.validate()?;
+        Ok(())
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityValidationEngine`] for [`DefaultEngine`] that operates on 32 bits
+/// GGSW ciphertexts.
+impl EntityValidationEngine<GgswCiphertext32> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let entity = engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    ///
+    /// engine.validate(&entity)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn validate(
+        &mut self,
+        entity: &GgswCiphertext32,
+    ) -> Result<(), EntityValidationError<Self::EngineError>> {
+        entity.0.validate()?;
+        Ok(())
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityValidationEngine`] for [`DefaultEngine`] that operates on 64 bits
+/// GGSW ciphertexts.
+impl EntityValidationEngine<GgswCiphertext64> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let entity = engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    ///
+    /// engine.validate(&entity)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn validate(
+        &mut self,
+        entity: &GgswCiphertext64,
+    ) -> Result<(), EntityValidationError<Self::EngineError>> {
+        entity.0.validate()?;
+        Ok(())
+    }
+}
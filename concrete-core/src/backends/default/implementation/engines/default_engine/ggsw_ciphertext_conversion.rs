@@ -0,0 +1,288 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GgswCiphertext32, GgswCiphertext64, PreparedGgswCiphertext32, PreparedGgswCiphertext64,
+};
+use crate::commons::crypto::ggsw::PreparedGgswCiphertext as ImplPreparedGgswCiphertext;
+use crate::specification::engines::{
+    GgswCiphertextConversionEngine, GgswCiphertextConversionError,
+    GgswCiphertextDiscardingConversionEngine, GgswCiphertextDiscardingConversionError,
+};
+use crate::specification::entities::GgswCiphertextEntity;
+
+/// # Description:
+/// Implementation of [`GgswCiphertextConversionEngine`] for [`DefaultEngine`] that operates on
+/// 32 bits integers. It prepares a GGSW ciphertext for repeated external products.
+impl GgswCiphertextConversionEngine<GgswCiphertext32, PreparedGgswCiphertext32> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let ciphertext =
+    ///     engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    ///
+    /// let prepared_ciphertext: PreparedGgswCiphertext32 =
+    ///     engine.convert_ggsw_ciphertext(&ciphertext)?;
+    /// #
+    /// assert_eq!(prepared_ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(prepared_ciphertext.polynomial_size(), polynomial_size);
+    /// assert_eq!(prepared_ciphertext.decomposition_base_log(), base_log);
+    /// assert_eq!(prepared_ciphertext.decomposition_level_count(), level);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_ggsw_ciphertext(
+        &mut self,
+        input: &GgswCiphertext32,
+    ) -> Result<PreparedGgswCiphertext32, GgswCiphertextConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_ggsw_ciphertext_unchecked(input) })
+    }
+
+    unsafe fn convert_ggsw_ciphertext_unchecked(
+        &mut self,
+        input: &GgswCiphertext32,
+    ) -> PreparedGgswCiphertext32 {
+        let mut output = PreparedGgswCiphertext32(ImplPreparedGgswCiphertext::allocate(
+            0u32,
+            input.polynomial_size(),
+            input.glwe_dimension().to_glwe_size(),
+            input.decomposition_level_count(),
+            input.decomposition_base_log(),
+        ));
+        self.discard_convert_ggsw_ciphertext_unchecked(&mut output, input);
+        output
+    }
+}
+
+/// # Description:
+/// Implementation of [`GgswCiphertextDiscardingConversionEngine`] for [`DefaultEngine`] that
+/// operates on 32 bits integers. It prepares a GGSW ciphertext for repeated external products.
+impl GgswCiphertextDiscardingConversionEngine<GgswCiphertext32, PreparedGgswCiphertext32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key_1: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let mut ciphertext =
+    ///     engine.encrypt_scalar_ggsw_ciphertext(&key_1, &plaintext, noise, level, base_log)?;
+    /// let mut prepared_ciphertext: PreparedGgswCiphertext32 =
+    ///     engine.convert_ggsw_ciphertext(&ciphertext)?;
+    ///
+    /// // We're going to re-encrypt and re-prepare the input with another secret key.
+    /// let key_2: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// engine.discard_encrypt_scalar_ggsw_ciphertext(&key_2, &mut ciphertext, &plaintext, noise)?;
+    /// engine.discard_convert_ggsw_ciphertext(&mut prepared_ciphertext, &ciphertext)?;
+    /// #
+    /// assert_eq!(prepared_ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(prepared_ciphertext.polynomial_size(), polynomial_size);
+    /// assert_eq!(prepared_ciphertext.decomposition_base_log(), base_log);
+    /// assert_eq!(prepared_ciphertext.decomposition_level_count(), level);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_ggsw_ciphertext(
+        &mut self,
+        output: &mut PreparedGgswCiphertext32,
+        input: &GgswCiphertext32,
+    ) -> Result<(), GgswCiphertextDiscardingConversionError<Self::EngineError>> {
+        GgswCiphertextDiscardingConversionError::perform_generic_checks(output, input)?;
+        unsafe { self.discard_convert_ggsw_ciphertext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_ggsw_ciphertext_unchecked(
+        &mut self,
+        output: &mut PreparedGgswCiphertext32,
+        input: &GgswCiphertext32,
+    ) {
+        output.0.fill_with_standard(&input.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`GgswCiphertextConversionEngine`] for [`DefaultEngine`] that operates on
+/// 64 bits integers. It prepares a GGSW ciphertext for repeated external products.
+impl GgswCiphertextConversionEngine<GgswCiphertext64, PreparedGgswCiphertext64> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let ciphertext =
+    ///     engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    ///
+    /// let prepared_ciphertext: PreparedGgswCiphertext64 =
+    ///     engine.convert_ggsw_ciphertext(&ciphertext)?;
+    /// #
+    /// assert_eq!(prepared_ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(prepared_ciphertext.polynomial_size(), polynomial_size);
+    /// assert_eq!(prepared_ciphertext.decomposition_base_log(), base_log);
+    /// assert_eq!(prepared_ciphertext.decomposition_level_count(), level);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_ggsw_ciphertext(
+        &mut self,
+        input: &GgswCiphertext64,
+    ) -> Result<PreparedGgswCiphertext64, GgswCiphertextConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_ggsw_ciphertext_unchecked(input) })
+    }
+
+    unsafe fn convert_ggsw_ciphertext_unchecked(
+        &mut self,
+        input: &GgswCiphertext64,
+    ) -> PreparedGgswCiphertext64 {
+        let mut output = PreparedGgswCiphertext64(ImplPreparedGgswCiphertext::allocate(
+            0u64,
+            input.polynomial_size(),
+            input.glwe_dimension().to_glwe_size(),
+            input.decomposition_level_count(),
+            input.decomposition_base_log(),
+        ));
+        self.discard_convert_ggsw_ciphertext_unchecked(&mut output, input);
+        output
+    }
+}
+
+/// # Description:
+/// Implementation of [`GgswCiphertextDiscardingConversionEngine`] for [`DefaultEngine`] that
+/// operates on 64 bits integers. It prepares a GGSW ciphertext for repeated external products.
+impl GgswCiphertextDiscardingConversionEngine<GgswCiphertext64, PreparedGgswCiphertext64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key_1: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let mut ciphertext =
+    ///     engine.encrypt_scalar_ggsw_ciphertext(&key_1, &plaintext, noise, level, base_log)?;
+    /// let mut prepared_ciphertext: PreparedGgswCiphertext64 =
+    ///     engine.convert_ggsw_ciphertext(&ciphertext)?;
+    ///
+    /// // We're going to re-encrypt and re-prepare the input with another secret key.
+    /// let key_2: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// engine.discard_encrypt_scalar_ggsw_ciphertext(&key_2, &mut ciphertext, &plaintext, noise)?;
+    /// engine.discard_convert_ggsw_ciphertext(&mut prepared_ciphertext, &ciphertext)?;
+    /// #
+    /// assert_eq!(prepared_ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(prepared_ciphertext.polynomial_size(), polynomial_size);
+    /// assert_eq!(prepared_ciphertext.decomposition_base_log(), base_log);
+    /// assert_eq!(prepared_ciphertext.decomposition_level_count(), level);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_ggsw_ciphertext(
+        &mut self,
+        output: &mut PreparedGgswCiphertext64,
+        input: &GgswCiphertext64,
+    ) -> Result<(), GgswCiphertextDiscardingConversionError<Self::EngineError>> {
+        GgswCiphertextDiscardingConversionError::perform_generic_checks(output, input)?;
+        unsafe { self.discard_convert_ggsw_ciphertext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_ggsw_ciphertext_unchecked(
+        &mut self,
+        output: &mut PreparedGgswCiphertext64,
+        input: &GgswCiphertext64,
+    ) {
+        output.0.fill_with_standard(&input.0);
+    }
+}
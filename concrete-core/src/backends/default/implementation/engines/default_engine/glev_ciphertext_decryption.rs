@@ -0,0 +1,138 @@
+use concrete_commons::parameters::PlaintextCount;
+
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GlevCiphertext32, GlevCiphertext64, GlweSecretKey32, GlweSecretKey64, PlaintextVector32,
+    PlaintextVector64,
+};
+use crate::commons::crypto::encoding::PlaintextList as ImplPlaintextList;
+use crate::specification::engines::{
+    GlevCiphertextDecryptionEngine, GlevCiphertextDecryptionError,
+};
+use crate::specification::entities::GlweSecretKeyEntity;
+
+/// # Description:
+/// Implementation of [`GlevCiphertextDecryptionEngine`] for [`DefaultEngine`] that operates on
+/// 32 bits integers.
+impl GlevCiphertextDecryptionEngine<GlweSecretKey32, GlevCiphertext32, PlaintextVector32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let level = DecompositionLevelCount(3);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // The plaintexts are scaled by each level of the decomposition during the encryption
+    /// let input = vec![3_u32; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 = engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext =
+    ///     engine.encrypt_glev_ciphertext(&key, &plaintext_vector, noise, level, base_log)?;
+    ///
+    /// let decrypted_plaintext_vector = engine.decrypt_glev_ciphertext(&key, &ciphertext)?;
+    /// #
+    /// assert_eq!(engine.retrieve_plaintext_vector(&decrypted_plaintext_vector)?, input);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_glev_ciphertext(
+        &mut self,
+        key: &GlweSecretKey32,
+        input: &GlevCiphertext32,
+    ) -> Result<PlaintextVector32, GlevCiphertextDecryptionError<Self::EngineError>> {
+        GlevCiphertextDecryptionError::perform_generic_checks(key, input)?;
+        Ok(unsafe { self.decrypt_glev_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_glev_ciphertext_unchecked(
+        &mut self,
+        key: &GlweSecretKey32,
+        input: &GlevCiphertext32,
+    ) -> PlaintextVector32 {
+        let mut plaintext =
+            ImplPlaintextList::allocate(0u32, PlaintextCount(key.polynomial_size().0));
+        key.0.decrypt_glev(&mut plaintext, &input.0);
+        PlaintextVector32(plaintext)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlevCiphertextDecryptionEngine`] for [`DefaultEngine`] that operates on
+/// 64 bits integers.
+impl GlevCiphertextDecryptionEngine<GlweSecretKey64, GlevCiphertext64, PlaintextVector64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let level = DecompositionLevelCount(3);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // The plaintexts are scaled by each level of the decomposition during the encryption
+    /// let input = vec![3_u64; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 = engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext =
+    ///     engine.encrypt_glev_ciphertext(&key, &plaintext_vector, noise, level, base_log)?;
+    ///
+    /// let decrypted_plaintext_vector = engine.decrypt_glev_ciphertext(&key, &ciphertext)?;
+    /// #
+    /// assert_eq!(engine.retrieve_plaintext_vector(&decrypted_plaintext_vector)?, input);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_glev_ciphertext(
+        &mut self,
+        key: &GlweSecretKey64,
+        input: &GlevCiphertext64,
+    ) -> Result<PlaintextVector64, GlevCiphertextDecryptionError<Self::EngineError>> {
+        GlevCiphertextDecryptionError::perform_generic_checks(key, input)?;
+        Ok(unsafe { self.decrypt_glev_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_glev_ciphertext_unchecked(
+        &mut self,
+        key: &GlweSecretKey64,
+        input: &GlevCiphertext64,
+    ) -> PlaintextVector64 {
+        let mut plaintext =
+            ImplPlaintextList::allocate(0u64, PlaintextCount(key.polynomial_size().0));
+        key.0.decrypt_glev(&mut plaintext, &input.0);
+        PlaintextVector64(plaintext)
+    }
+}
@@ -0,0 +1,208 @@
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GlweAutomorphismKeys32, GlweAutomorphismKeys64, GlweSecretKey32, GlweSecretKey64,
+};
+use crate::commons::crypto::glwe::GlweAutomorphismKeys as ImplGlweAutomorphismKeys;
+use crate::specification::engines::{
+    GlweAutomorphismKeysGenerationEngine, GlweAutomorphismKeysGenerationError,
+};
+use crate::specification::entities::GlweSecretKeyEntity;
+
+/// # Description:
+/// Implementation of [`GlweAutomorphismKeysGenerationEngine`] for [`DefaultEngine`] that operates
+/// on 32 bits integers.
+impl GlweAutomorphismKeysGenerationEngine<GlweSecretKey32, GlweAutomorphismKeys32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(5);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let automorphism_keys: GlweAutomorphismKeys32 = engine.generate_new_glwe_automorphism_keys(
+    ///     &key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    /// #     automorphism_keys.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     automorphism_keys.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(automorphism_keys.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(automorphism_keys.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_glwe_automorphism_keys(
+        &mut self,
+        key: &GlweSecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<GlweAutomorphismKeys32, GlweAutomorphismKeysGenerationError<Self::EngineError>>
+    {
+        GlweAutomorphismKeysGenerationError::perform_generic_checks(
+            key,
+            decomposition_level_count,
+            decomposition_base_log,
+            32,
+        )?;
+        Ok(unsafe {
+            self.generate_new_glwe_automorphism_keys_unchecked(
+                key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn generate_new_glwe_automorphism_keys_unchecked(
+        &mut self,
+        key: &GlweSecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> GlweAutomorphismKeys32 {
+        let mut automorphism_keys = ImplGlweAutomorphismKeys::allocate(
+            0,
+            decomposition_level_count,
+            decomposition_base_log,
+            key.glwe_dimension(),
+            key.polynomial_size(),
+        );
+        automorphism_keys.fill_with_automorphism_keys(
+            &key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        GlweAutomorphismKeys32(automorphism_keys)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweAutomorphismKeysGenerationEngine`] for [`DefaultEngine`] that operates
+/// on 64 bits integers.
+impl GlweAutomorphismKeysGenerationEngine<GlweSecretKey64, GlweAutomorphismKeys64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(5);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let automorphism_keys: GlweAutomorphismKeys64 = engine.generate_new_glwe_automorphism_keys(
+    ///     &key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    /// #     automorphism_keys.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     automorphism_keys.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(automorphism_keys.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(automorphism_keys.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_glwe_automorphism_keys(
+        &mut self,
+        key: &GlweSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<GlweAutomorphismKeys64, GlweAutomorphismKeysGenerationError<Self::EngineError>>
+    {
+        GlweAutomorphismKeysGenerationError::perform_generic_checks(
+            key,
+            decomposition_level_count,
+            decomposition_base_log,
+            64,
+        )?;
+        Ok(unsafe {
+            self.generate_new_glwe_automorphism_keys_unchecked(
+                key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn generate_new_glwe_automorphism_keys_unchecked(
+        &mut self,
+        key: &GlweSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> GlweAutomorphismKeys64 {
+        let mut automorphism_keys = ImplGlweAutomorphismKeys::allocate(
+            0,
+            decomposition_level_count,
+            decomposition_base_log,
+            key.glwe_dimension(),
+            key.polynomial_size(),
+        );
+        automorphism_keys.fill_with_automorphism_keys(
+            &key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        GlweAutomorphismKeys64(automorphism_keys)
+    }
+}
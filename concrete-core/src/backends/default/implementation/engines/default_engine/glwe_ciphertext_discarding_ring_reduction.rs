@@ -0,0 +1,177 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GlweCiphertext32, GlweCiphertext64, GlweRingReductionKey32, GlweRingReductionKey64,
+};
+use crate::specification::engines::{
+    GlweCiphertextDiscardingRingReductionEngine, GlweCiphertextDiscardingRingReductionError,
+};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingRingReductionEngine`] for [`DefaultEngine`] that
+/// operates on 32 bits integers.
+impl
+    GlweCiphertextDiscardingRingReductionEngine<
+        GlweRingReductionKey32,
+        GlweCiphertext32,
+        GlweCiphertext32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_glwe_dimension = GlweDimension(1);
+    /// let input_polynomial_size = PolynomialSize(1024);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let output_polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(5);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; input_polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(input_glwe_dimension, input_polynomial_size)?;
+    /// let output_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, output_polynomial_size)?;
+    /// let ring_reduction_key = engine.generate_new_glwe_ring_reduction_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&input_key, &plaintext_vector, noise)?;
+    /// let mut reduced_ciphertext = engine.zero_encrypt_glwe_ciphertext(&output_key, noise)?;
+    ///
+    /// // The output message contains the coefficients of degree 4i of the input message
+    /// engine.discard_ring_reduce_glwe_ciphertext(
+    ///     &mut reduced_ciphertext,
+    ///     &ciphertext,
+    ///     &ring_reduction_key,
+    /// )?;
+    /// #
+    /// assert_eq!(reduced_ciphertext.glwe_dimension(), output_glwe_dimension);
+    /// assert_eq!(reduced_ciphertext.polynomial_size(), output_polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_ring_reduce_glwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &GlweCiphertext32,
+        rrk: &GlweRingReductionKey32,
+    ) -> Result<(), GlweCiphertextDiscardingRingReductionError<Self::EngineError>> {
+        GlweCiphertextDiscardingRingReductionError::perform_generic_checks(output, input, rrk)?;
+        unsafe { self.discard_ring_reduce_glwe_ciphertext_unchecked(output, input, rrk) };
+        Ok(())
+    }
+
+    unsafe fn discard_ring_reduce_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &GlweCiphertext32,
+        rrk: &GlweRingReductionKey32,
+    ) {
+        rrk.0.ring_reduce(&mut output.0, &input.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingRingReductionEngine`] for [`DefaultEngine`] that
+/// operates on 64 bits integers.
+impl
+    GlweCiphertextDiscardingRingReductionEngine<
+        GlweRingReductionKey64,
+        GlweCiphertext64,
+        GlweCiphertext64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_glwe_dimension = GlweDimension(1);
+    /// let input_polynomial_size = PolynomialSize(1024);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let output_polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(5);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; input_polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(input_glwe_dimension, input_polynomial_size)?;
+    /// let output_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, output_polynomial_size)?;
+    /// let ring_reduction_key = engine.generate_new_glwe_ring_reduction_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&input_key, &plaintext_vector, noise)?;
+    /// let mut reduced_ciphertext = engine.zero_encrypt_glwe_ciphertext(&output_key, noise)?;
+    ///
+    /// // The output message contains the coefficients of degree 4i of the input message
+    /// engine.discard_ring_reduce_glwe_ciphertext(
+    ///     &mut reduced_ciphertext,
+    ///     &ciphertext,
+    ///     &ring_reduction_key,
+    /// )?;
+    /// #
+    /// assert_eq!(reduced_ciphertext.glwe_dimension(), output_glwe_dimension);
+    /// assert_eq!(reduced_ciphertext.polynomial_size(), output_polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_ring_reduce_glwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &GlweCiphertext64,
+        rrk: &GlweRingReductionKey64,
+    ) -> Result<(), GlweCiphertextDiscardingRingReductionError<Self::EngineError>> {
+        GlweCiphertextDiscardingRingReductionError::perform_generic_checks(output, input, rrk)?;
+        unsafe { self.discard_ring_reduce_glwe_ciphertext_unchecked(output, input, rrk) };
+        Ok(())
+    }
+
+    unsafe fn discard_ring_reduce_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &GlweCiphertext64,
+        rrk: &GlweRingReductionKey64,
+    ) {
+        rrk.0.ring_reduce(&mut output.0, &input.0);
+    }
+}
@@ -0,0 +1,171 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GlweCiphertext32, GlweCiphertext64, GlweRingSwitchingKey32, GlweRingSwitchingKey64,
+};
+use crate::specification::engines::{
+    GlweCiphertextDiscardingRingSwitchingEngine, GlweCiphertextDiscardingRingSwitchingError,
+};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingRingSwitchingEngine`] for [`DefaultEngine`] that
+/// operates on 32 bits integers.
+impl
+    GlweCiphertextDiscardingRingSwitchingEngine<
+        GlweRingSwitchingKey32,
+        GlweCiphertext32,
+        GlweCiphertext32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(5);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0 / 2];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let output_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let input_key: GlweSecretKey32 = engine.split_glwe_secret_key_ring(&output_key)?;
+    /// let ring_switching_key = engine.generate_new_glwe_ring_switching_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&input_key, &plaintext_vector, noise)?;
+    /// let mut switched_ciphertext = engine.zero_encrypt_glwe_ciphertext(&output_key, noise)?;
+    ///
+    /// // The message M(X) is switched to the message M(X^2)
+    /// engine.discard_ring_switch_glwe_ciphertext(
+    ///     &mut switched_ciphertext,
+    ///     &ciphertext,
+    ///     &ring_switching_key,
+    /// )?;
+    /// #
+    /// assert_eq!(switched_ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(switched_ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_ring_switch_glwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &GlweCiphertext32,
+        rsk: &GlweRingSwitchingKey32,
+    ) -> Result<(), GlweCiphertextDiscardingRingSwitchingError<Self::EngineError>> {
+        GlweCiphertextDiscardingRingSwitchingError::perform_generic_checks(output, input, rsk)?;
+        unsafe { self.discard_ring_switch_glwe_ciphertext_unchecked(output, input, rsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_ring_switch_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &GlweCiphertext32,
+        rsk: &GlweRingSwitchingKey32,
+    ) {
+        rsk.0.ring_switch(&mut output.0, &input.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingRingSwitchingEngine`] for [`DefaultEngine`] that
+/// operates on 64 bits integers.
+impl
+    GlweCiphertextDiscardingRingSwitchingEngine<
+        GlweRingSwitchingKey64,
+        GlweCiphertext64,
+        GlweCiphertext64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(5);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0 / 2];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let output_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let input_key: GlweSecretKey64 = engine.split_glwe_secret_key_ring(&output_key)?;
+    /// let ring_switching_key = engine.generate_new_glwe_ring_switching_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&input_key, &plaintext_vector, noise)?;
+    /// let mut switched_ciphertext = engine.zero_encrypt_glwe_ciphertext(&output_key, noise)?;
+    ///
+    /// // The message M(X) is switched to the message M(X^2)
+    /// engine.discard_ring_switch_glwe_ciphertext(
+    ///     &mut switched_ciphertext,
+    ///     &ciphertext,
+    ///     &ring_switching_key,
+    /// )?;
+    /// #
+    /// assert_eq!(switched_ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(switched_ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_ring_switch_glwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &GlweCiphertext64,
+        rsk: &GlweRingSwitchingKey64,
+    ) -> Result<(), GlweCiphertextDiscardingRingSwitchingError<Self::EngineError>> {
+        GlweCiphertextDiscardingRingSwitchingError::perform_generic_checks(output, input, rsk)?;
+        unsafe { self.discard_ring_switch_glwe_ciphertext_unchecked(output, input, rsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_ring_switch_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &GlweCiphertext64,
+        rsk: &GlweRingSwitchingKey64,
+    ) {
+        rsk.0.ring_switch(&mut output.0, &input.0);
+    }
+}
@@ -0,0 +1,223 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GlweCiphertext32, GlweCiphertext64, LweBootstrapKey32, LweBootstrapKey64, LweCiphertext32,
+    LweCiphertext64,
+};
+use crate::specification::engines::{
+    GlweCiphertextFusingPartialBlindRotationEngine, GlweCiphertextFusingPartialBlindRotationError,
+};
+use concrete_commons::parameters::GgswCiphertextIndex;
+
+/// # Description:
+/// Implementation of [`GlweCiphertextFusingPartialBlindRotationEngine`] for [`DefaultEngine`] that
+/// operates on 32 bits integers.
+impl
+    GlweCiphertextFusingPartialBlindRotationEngine<
+        LweCiphertext32,
+        LweBootstrapKey32,
+        GlweCiphertext32,
+    > for DefaultEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextIndex, GlweDimension,
+    ///     LweDimension, MonomialIndex, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(10), GlweDimension(1), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(7));
+    /// let noise = Variance(2_f64.powf(-58.));
+    /// // Here a hard-set encoding is applied: the message is shifted by 25 bits, and encrypted
+    /// // in the middle of the range of the input values mapped to it by the accumulator.
+    /// let message = 42_u32;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey32 =
+    ///     engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let plaintext = engine.create_plaintext_from(&((4 * message + 2) << 23))?;
+    /// let input: LweCiphertext32 = engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    ///
+    /// // The accumulator is a trivial encryption of the identity function
+    /// let lut: Vec<u32> = (0..poly_size.0 as u32).map(|i| (i / 4) << 25).collect();
+    /// let lut = engine.create_plaintext_vector_from(&lut)?;
+    /// let mut accumulator: GlweCiphertext32 =
+    ///     engine.trivially_encrypt_glwe_ciphertext(glwe_dim.to_glwe_size(), &lut)?;
+    ///
+    /// // The blind rotation is performed with two shards of the key
+    /// for first in [GgswCiphertextIndex(0), GgswCiphertextIndex(5)] {
+    ///     let shard: LweBootstrapKey32 =
+    ///         engine.extract_lwe_bootstrap_key_shard(&bsk, first, LweDimension(5))?;
+    ///     engine.fuse_partial_blind_rotate_glwe_ciphertext(
+    ///         &mut accumulator,
+    ///         &input,
+    ///         &shard,
+    ///         first,
+    ///     )?;
+    /// }
+    ///
+    /// // The bootstrap is completed by a sample extraction
+    /// let output_sk: LweSecretKey32 = engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_sk)?;
+    /// let mut output: LweCiphertext32 = engine.zero_encrypt_lwe_ciphertext(&output_sk, noise)?;
+    /// engine.discard_extract_lwe_ciphertext(&mut output, &accumulator, MonomialIndex(0))?;
+    /// let decrypted = engine.decrypt_lwe_ciphertext(&output_sk, &output)?;
+    /// let decoded = engine.retrieve_plaintext(&decrypted)?;
+    /// #
+    /// assert_eq!((decoded.wrapping_add(1 << 24)) >> 25, message);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_partial_blind_rotate_glwe_ciphertext(
+        &mut self,
+        accumulator: &mut GlweCiphertext32,
+        input: &LweCiphertext32,
+        shard: &LweBootstrapKey32,
+        first: GgswCiphertextIndex,
+    ) -> Result<(), GlweCiphertextFusingPartialBlindRotationError<Self::EngineError>> {
+        GlweCiphertextFusingPartialBlindRotationError::perform_generic_checks(
+            accumulator,
+            input,
+            shard,
+            first,
+        )?;
+        unsafe {
+            self.fuse_partial_blind_rotate_glwe_ciphertext_unchecked(
+                accumulator,
+                input,
+                shard,
+                first,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn fuse_partial_blind_rotate_glwe_ciphertext_unchecked(
+        &mut self,
+        accumulator: &mut GlweCiphertext32,
+        input: &LweCiphertext32,
+        shard: &LweBootstrapKey32,
+        first: GgswCiphertextIndex,
+    ) {
+        shard
+            .0
+            .partial_blind_rotate(&mut accumulator.0, &input.0, first);
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextFusingPartialBlindRotationEngine`] for [`DefaultEngine`] that
+/// operates on 64 bits integers.
+impl
+    GlweCiphertextFusingPartialBlindRotationEngine<
+        LweCiphertext64,
+        LweBootstrapKey64,
+        GlweCiphertext64,
+    > for DefaultEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextIndex, GlweDimension,
+    ///     LweDimension, MonomialIndex, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(10), GlweDimension(1), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(7));
+    /// let noise = Variance(2_f64.powf(-58.));
+    /// // Here a hard-set encoding is applied: the message is shifted by 57 bits, and encrypted
+    /// // in the middle of the range of the input values mapped to it by the accumulator.
+    /// let message = 42_u64;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey64 =
+    ///     engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let plaintext = engine.create_plaintext_from(&((4 * message + 2) << 55))?;
+    /// let input: LweCiphertext64 = engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    ///
+    /// // The accumulator is a trivial encryption of the identity function
+    /// let lut: Vec<u64> = (0..poly_size.0 as u64).map(|i| (i / 4) << 57).collect();
+    /// let lut = engine.create_plaintext_vector_from(&lut)?;
+    /// let mut accumulator: GlweCiphertext64 =
+    ///     engine.trivially_encrypt_glwe_ciphertext(glwe_dim.to_glwe_size(), &lut)?;
+    ///
+    /// // The blind rotation is performed with two shards of the key
+    /// for first in [GgswCiphertextIndex(0), GgswCiphertextIndex(5)] {
+    ///     let shard: LweBootstrapKey64 =
+    ///         engine.extract_lwe_bootstrap_key_shard(&bsk, first, LweDimension(5))?;
+    ///     engine.fuse_partial_blind_rotate_glwe_ciphertext(
+    ///         &mut accumulator,
+    ///         &input,
+    ///         &shard,
+    ///         first,
+    ///     )?;
+    /// }
+    ///
+    /// // The bootstrap is completed by a sample extraction
+    /// let output_sk: LweSecretKey64 = engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_sk)?;
+    /// let mut output: LweCiphertext64 = engine.zero_encrypt_lwe_ciphertext(&output_sk, noise)?;
+    /// engine.discard_extract_lwe_ciphertext(&mut output, &accumulator, MonomialIndex(0))?;
+    /// let decrypted = engine.decrypt_lwe_ciphertext(&output_sk, &output)?;
+    /// let decoded = engine.retrieve_plaintext(&decrypted)?;
+    /// #
+    /// assert_eq!((decoded.wrapping_add(1 << 56)) >> 57, message);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_partial_blind_rotate_glwe_ciphertext(
+        &mut self,
+        accumulator: &mut GlweCiphertext64,
+        input: &LweCiphertext64,
+        shard: &LweBootstrapKey64,
+        first: GgswCiphertextIndex,
+    ) -> Result<(), GlweCiphertextFusingPartialBlindRotationError<Self::EngineError>> {
+        GlweCiphertextFusingPartialBlindRotationError::perform_generic_checks(
+            accumulator,
+            input,
+            shard,
+            first,
+        )?;
+        unsafe {
+            self.fuse_partial_blind_rotate_glwe_ciphertext_unchecked(
+                accumulator,
+                input,
+                shard,
+                first,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn fuse_partial_blind_rotate_glwe_ciphertext_unchecked(
+        &mut self,
+        accumulator: &mut GlweCiphertext64,
+        input: &LweCiphertext64,
+        shard: &LweBootstrapKey64,
+        first: GgswCiphertextIndex,
+    ) {
+        shard
+            .0
+            .partial_blind_rotate(&mut accumulator.0, &input.0, first);
+    }
+}
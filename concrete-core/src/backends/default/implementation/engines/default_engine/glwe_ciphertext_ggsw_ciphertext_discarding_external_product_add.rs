@@ -0,0 +1,194 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GlweCiphertext32, GlweCiphertext64, PreparedGgswCiphertext32, PreparedGgswCiphertext64,
+};
+use crate::specification::engines::{
+    GlweCiphertextGgswCiphertextDiscardingExternalProductAddEngine,
+    GlweCiphertextGgswCiphertextDiscardingExternalProductAddError,
+};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextGgswCiphertextDiscardingExternalProductAddEngine`] for
+/// [`DefaultEngine`] that operates on 32 bits integers.
+impl
+    GlweCiphertextGgswCiphertextDiscardingExternalProductAddEngine<
+        GlweCiphertext32,
+        PreparedGgswCiphertext32,
+        GlweCiphertext32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input_ggsw = 3_u32 << 20;
+    /// let input_glwe = vec![3_u32 << 20; polynomial_size.0];
+    /// let accumulator_glwe = vec![1_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_ggsw = engine.create_plaintext_from(&input_ggsw)?;
+    /// let plaintext_glwe = engine.create_plaintext_vector_from(&input_glwe)?;
+    /// let plaintext_accumulator = engine.create_plaintext_vector_from(&accumulator_glwe)?;
+    ///
+    /// let ggsw =
+    ///     engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext_ggsw, noise, level, base_log)?;
+    /// let prepared_ggsw: PreparedGgswCiphertext32 = engine.convert_ggsw_ciphertext(&ggsw)?;
+    /// let glwe = engine.encrypt_glwe_ciphertext(&key, &plaintext_glwe, noise)?;
+    ///
+    /// // The external product is added to the content of the accumulator.
+    /// let mut accumulator = engine.encrypt_glwe_ciphertext(&key, &plaintext_accumulator, noise)?;
+    /// engine.discard_add_external_product_glwe_ciphertext_ggsw_ciphertext(
+    ///     &glwe,
+    ///     &prepared_ggsw,
+    ///     &mut accumulator,
+    /// )?;
+    /// #
+    /// # assert_eq!(
+    /// #     accumulator.polynomial_size(),
+    /// #     glwe.polynomial_size(),
+    /// # );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_add_external_product_glwe_ciphertext_ggsw_ciphertext(
+        &mut self,
+        glwe_input: &GlweCiphertext32,
+        ggsw_input: &PreparedGgswCiphertext32,
+        output: &mut GlweCiphertext32,
+    ) -> Result<
+        (),
+        GlweCiphertextGgswCiphertextDiscardingExternalProductAddError<Self::EngineError>,
+    > {
+        GlweCiphertextGgswCiphertextDiscardingExternalProductAddError::perform_generic_checks(
+            glwe_input, ggsw_input, output,
+        )?;
+        unsafe {
+            self.discard_add_external_product_glwe_ciphertext_ggsw_ciphertext_unchecked(
+                glwe_input, ggsw_input, output,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_add_external_product_glwe_ciphertext_ggsw_ciphertext_unchecked(
+        &mut self,
+        glwe_input: &GlweCiphertext32,
+        ggsw_input: &PreparedGgswCiphertext32,
+        output: &mut GlweCiphertext32,
+    ) {
+        ggsw_input.0.external_product(&mut output.0, &glwe_input.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextGgswCiphertextDiscardingExternalProductAddEngine`] for
+/// [`DefaultEngine`] that operates on 64 bits integers.
+impl
+    GlweCiphertextGgswCiphertextDiscardingExternalProductAddEngine<
+        GlweCiphertext64,
+        PreparedGgswCiphertext64,
+        GlweCiphertext64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input_ggsw = 3_u64 << 50;
+    /// let input_glwe = vec![3_u64 << 50; polynomial_size.0];
+    /// let accumulator_glwe = vec![1_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_ggsw = engine.create_plaintext_from(&input_ggsw)?;
+    /// let plaintext_glwe = engine.create_plaintext_vector_from(&input_glwe)?;
+    /// let plaintext_accumulator = engine.create_plaintext_vector_from(&accumulator_glwe)?;
+    ///
+    /// let ggsw =
+    ///     engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext_ggsw, noise, level, base_log)?;
+    /// let prepared_ggsw: PreparedGgswCiphertext64 = engine.convert_ggsw_ciphertext(&ggsw)?;
+    /// let glwe = engine.encrypt_glwe_ciphertext(&key, &plaintext_glwe, noise)?;
+    ///
+    /// // The external product is added to the content of the accumulator.
+    /// let mut accumulator = engine.encrypt_glwe_ciphertext(&key, &plaintext_accumulator, noise)?;
+    /// engine.discard_add_external_product_glwe_ciphertext_ggsw_ciphertext(
+    ///     &glwe,
+    ///     &prepared_ggsw,
+    ///     &mut accumulator,
+    /// )?;
+    /// #
+    /// # assert_eq!(
+    /// #     accumulator.polynomial_size(),
+    /// #     glwe.polynomial_size(),
+    /// # );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_add_external_product_glwe_ciphertext_ggsw_ciphertext(
+        &mut self,
+        glwe_input: &GlweCiphertext64,
+        ggsw_input: &PreparedGgswCiphertext64,
+        output: &mut GlweCiphertext64,
+    ) -> Result<
+        (),
+        GlweCiphertextGgswCiphertextDiscardingExternalProductAddError<Self::EngineError>,
+    > {
+        GlweCiphertextGgswCiphertextDiscardingExternalProductAddError::perform_generic_checks(
+            glwe_input, ggsw_input, output,
+        )?;
+        unsafe {
+            self.discard_add_external_product_glwe_ciphertext_ggsw_ciphertext_unchecked(
+                glwe_input, ggsw_input, output,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_add_external_product_glwe_ciphertext_ggsw_ciphertext_unchecked(
+        &mut self,
+        glwe_input: &GlweCiphertext64,
+        ggsw_input: &PreparedGgswCiphertext64,
+        output: &mut GlweCiphertext64,
+    ) {
+        ggsw_input.0.external_product(&mut output.0, &glwe_input.0);
+    }
+}
@@ -0,0 +1,146 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GlweCiphertext32, GlweCiphertext64, GlweCiphertextVector32, GlweCiphertextVector64,
+};
+use crate::commons::crypto::glwe::GlweList as ImplGlweList;
+use crate::specification::engines::{
+    GlweCiphertextRingSplittingEngine, GlweCiphertextRingSplittingError,
+};
+use crate::specification::entities::GlweCiphertextEntity;
+use concrete_commons::parameters::{CiphertextCount, GlweDimension, PolynomialSize};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextRingSplittingEngine`] for [`DefaultEngine`] that operates on
+/// 32 bits integers.
+impl GlweCiphertextRingSplittingEngine<GlweCiphertext32, GlweCiphertextVector32> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     GlweCiphertextCount, GlweDimension, PlaintextCount, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// // The even and odd coefficients of the message are encrypted in two ciphertexts
+    /// let split_ciphertexts: GlweCiphertextVector32 = engine.split_glwe_ciphertext_ring(&ciphertext)?;
+    /// #
+    /// assert_eq!(split_ciphertexts.glwe_ciphertext_count(), GlweCiphertextCount(2));
+    /// assert_eq!(split_ciphertexts.glwe_dimension(), GlweDimension(4));
+    /// assert_eq!(split_ciphertexts.polynomial_size(), PolynomialSize(128));
+    ///
+    /// let split_key: GlweSecretKey32 = engine.split_glwe_secret_key_ring(&key)?;
+    /// let decrypted = engine.decrypt_glwe_ciphertext_vector(&split_key, &split_ciphertexts)?;
+    /// assert_eq!(decrypted.plaintext_count(), PlaintextCount(polynomial_size.0));
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn split_glwe_ciphertext_ring(
+        &mut self,
+        input: &GlweCiphertext32,
+    ) -> Result<GlweCiphertextVector32, GlweCiphertextRingSplittingError<Self::EngineError>> {
+        GlweCiphertextRingSplittingError::perform_generic_checks(input)?;
+        Ok(unsafe { self.split_glwe_ciphertext_ring_unchecked(input) })
+    }
+
+    unsafe fn split_glwe_ciphertext_ring_unchecked(
+        &mut self,
+        input: &GlweCiphertext32,
+    ) -> GlweCiphertextVector32 {
+        let mut output = ImplGlweList::allocate(
+            0u32,
+            PolynomialSize(input.polynomial_size().0 / 2),
+            GlweDimension(2 * input.glwe_dimension().0),
+            CiphertextCount(2),
+        );
+        input.0.fill_glwe_list_with_ring_split(&mut output);
+        GlweCiphertextVector32(output)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextRingSplittingEngine`] for [`DefaultEngine`] that operates on
+/// 64 bits integers.
+impl GlweCiphertextRingSplittingEngine<GlweCiphertext64, GlweCiphertextVector64> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     GlweCiphertextCount, GlweDimension, PlaintextCount, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// // The even and odd coefficients of the message are encrypted in two ciphertexts
+    /// let split_ciphertexts: GlweCiphertextVector64 = engine.split_glwe_ciphertext_ring(&ciphertext)?;
+    /// #
+    /// assert_eq!(split_ciphertexts.glwe_ciphertext_count(), GlweCiphertextCount(2));
+    /// assert_eq!(split_ciphertexts.glwe_dimension(), GlweDimension(4));
+    /// assert_eq!(split_ciphertexts.polynomial_size(), PolynomialSize(128));
+    ///
+    /// let split_key: GlweSecretKey64 = engine.split_glwe_secret_key_ring(&key)?;
+    /// let decrypted = engine.decrypt_glwe_ciphertext_vector(&split_key, &split_ciphertexts)?;
+    /// assert_eq!(decrypted.plaintext_count(), PlaintextCount(polynomial_size.0));
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn split_glwe_ciphertext_ring(
+        &mut self,
+        input: &GlweCiphertext64,
+    ) -> Result<GlweCiphertextVector64, GlweCiphertextRingSplittingError<Self::EngineError>> {
+        GlweCiphertextRingSplittingError::perform_generic_checks(input)?;
+        Ok(unsafe { self.split_glwe_ciphertext_ring_unchecked(input) })
+    }
+
+    unsafe fn split_glwe_ciphertext_ring_unchecked(
+        &mut self,
+        input: &GlweCiphertext64,
+    ) -> GlweCiphertextVector64 {
+        let mut output = ImplGlweList::allocate(
+            0u64,
+            PolynomialSize(input.polynomial_size().0 / 2),
+            GlweDimension(2 * input.glwe_dimension().0),
+            CiphertextCount(2),
+        );
+        input.0.fill_glwe_list_with_ring_split(&mut output);
+        GlweCiphertextVector64(output)
+    }
+}
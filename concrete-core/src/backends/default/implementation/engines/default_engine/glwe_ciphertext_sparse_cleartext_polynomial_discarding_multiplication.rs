@@ -0,0 +1,180 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GlweCiphertext32, GlweCiphertext64, SparseCleartextPolynomial32, SparseCleartextPolynomial64,
+};
+use crate::specification::engines::{
+    GlweCiphertextSparseCleartextPolynomialDiscardingMultiplicationEngine,
+    GlweCiphertextSparseCleartextPolynomialDiscardingMultiplicationError,
+};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextSparseCleartextPolynomialDiscardingMultiplicationEngine`] for
+/// [`DefaultEngine`] that operates on 32 bits integers.
+impl
+    GlweCiphertextSparseCleartextPolynomialDiscardingMultiplicationEngine<
+        GlweCiphertext32,
+        SparseCleartextPolynomial32,
+        GlweCiphertext32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, MonomialIndex, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The sparse polynomial 2.X^3
+    /// let values = vec![2_u32];
+    /// let indices = vec![MonomialIndex(3)];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let sparse_polynomial: SparseCleartextPolynomial32 =
+    ///     engine.create_sparse_cleartext_polynomial_from(&values, &indices, polynomial_size)?;
+    /// let ciphertext_1 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// let mut ciphertext_2 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// engine.discard_mul_glwe_ciphertext_sparse_cleartext_polynomial(
+    ///     &mut ciphertext_2,
+    ///     &ciphertext_1,
+    ///     &sparse_polynomial,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_2.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_2.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_mul_glwe_ciphertext_sparse_cleartext_polynomial(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input_1: &GlweCiphertext32,
+        input_2: &SparseCleartextPolynomial32,
+    ) -> Result<
+        (),
+        GlweCiphertextSparseCleartextPolynomialDiscardingMultiplicationError<Self::EngineError>,
+    > {
+        GlweCiphertextSparseCleartextPolynomialDiscardingMultiplicationError
+        ::perform_generic_checks(
+            output, input_1, input_2,
+        )?;
+        unsafe {
+            self.discard_mul_glwe_ciphertext_sparse_cleartext_polynomial_unchecked(
+                output, input_1, input_2,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_mul_glwe_ciphertext_sparse_cleartext_polynomial_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input_1: &GlweCiphertext32,
+        input_2: &SparseCleartextPolynomial32,
+    ) {
+        output
+            .0
+            .fill_with_sparse_polynomial_mul(&input_1.0, &input_2.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextSparseCleartextPolynomialDiscardingMultiplicationEngine`] for
+/// [`DefaultEngine`] that operates on 64 bits integers.
+impl
+    GlweCiphertextSparseCleartextPolynomialDiscardingMultiplicationEngine<
+        GlweCiphertext64,
+        SparseCleartextPolynomial64,
+        GlweCiphertext64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, MonomialIndex, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The sparse polynomial 2.X^3
+    /// let values = vec![2_u64];
+    /// let indices = vec![MonomialIndex(3)];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let sparse_polynomial: SparseCleartextPolynomial64 =
+    ///     engine.create_sparse_cleartext_polynomial_from(&values, &indices, polynomial_size)?;
+    /// let ciphertext_1 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// let mut ciphertext_2 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// engine.discard_mul_glwe_ciphertext_sparse_cleartext_polynomial(
+    ///     &mut ciphertext_2,
+    ///     &ciphertext_1,
+    ///     &sparse_polynomial,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_2.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_2.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_mul_glwe_ciphertext_sparse_cleartext_polynomial(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input_1: &GlweCiphertext64,
+        input_2: &SparseCleartextPolynomial64,
+    ) -> Result<
+        (),
+        GlweCiphertextSparseCleartextPolynomialDiscardingMultiplicationError<Self::EngineError>,
+    > {
+        GlweCiphertextSparseCleartextPolynomialDiscardingMultiplicationError
+        ::perform_generic_checks(
+            output, input_1, input_2,
+        )?;
+        unsafe {
+            self.discard_mul_glwe_ciphertext_sparse_cleartext_polynomial_unchecked(
+                output, input_1, input_2,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_mul_glwe_ciphertext_sparse_cleartext_polynomial_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input_1: &GlweCiphertext64,
+        input_2: &SparseCleartextPolynomial64,
+    ) {
+        output
+            .0
+            .fill_with_sparse_polynomial_mul(&input_1.0, &input_2.0);
+    }
+}
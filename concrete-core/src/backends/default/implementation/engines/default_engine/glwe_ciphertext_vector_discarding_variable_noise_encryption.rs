@@ -0,0 +1,195 @@
+use concrete_commons::dispersion::Variance;
+
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GlweCiphertextVector32, GlweCiphertextVector64, GlweSecretKey32, GlweSecretKey64,
+    PlaintextVector32, PlaintextVector64,
+};
+use crate::specification::engines::{
+    GlweCiphertextVectorDiscardingVariableNoiseEncryptionEngine,
+    GlweCiphertextVectorDiscardingVariableNoiseEncryptionError,
+};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorDiscardingVariableNoiseEncryptionEngine`] for
+/// [`DefaultEngine`] that operates on 32 bits integers.
+impl
+    GlweCiphertextVectorDiscardingVariableNoiseEncryptionEngine<
+        GlweSecretKey32,
+        PlaintextVector32,
+        GlweCiphertextVector32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The first ciphertext is encrypted with a smaller noise than the second one
+    /// let noises = vec![Variance(2_f64.powf(-40.)), Variance(2_f64.powf(-25.))];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key_1: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let key_2: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let mut ciphertext_vector =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key_1, &plaintext_vector, noise)?;
+    ///
+    /// engine.discard_encrypt_glwe_ciphertext_vector_with_variable_noise(
+    ///     &key_2,
+    ///     &mut ciphertext_vector,
+    ///     &plaintext_vector,
+    ///     &noises,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_vector.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_vector.polynomial_size(), polynomial_size);
+    /// assert_eq!(
+    /// #     ciphertext_vector.glwe_ciphertext_count(),
+    /// #     GlweCiphertextCount(2)
+    /// # );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_encrypt_glwe_ciphertext_vector_with_variable_noise(
+        &mut self,
+        key: &GlweSecretKey32,
+        output: &mut GlweCiphertextVector32,
+        input: &PlaintextVector32,
+        noises: &[Variance],
+    ) -> Result<(), GlweCiphertextVectorDiscardingVariableNoiseEncryptionError<Self::EngineError>>
+    {
+        GlweCiphertextVectorDiscardingVariableNoiseEncryptionError::perform_generic_checks(
+            key, output, input, noises,
+        )?;
+        unsafe {
+            self.discard_encrypt_glwe_ciphertext_vector_with_variable_noise_unchecked(
+                key, output, input, noises,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_encrypt_glwe_ciphertext_vector_with_variable_noise_unchecked(
+        &mut self,
+        key: &GlweSecretKey32,
+        output: &mut GlweCiphertextVector32,
+        input: &PlaintextVector32,
+        noises: &[Variance],
+    ) {
+        key.0.encrypt_glwe_list_with_variable_noise(
+            &mut output.0,
+            &input.0,
+            noises,
+            &mut self.encryption_generator,
+        );
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorDiscardingVariableNoiseEncryptionEngine`] for
+/// [`DefaultEngine`] that operates on 64 bits integers.
+impl
+    GlweCiphertextVectorDiscardingVariableNoiseEncryptionEngine<
+        GlweSecretKey64,
+        PlaintextVector64,
+        GlweCiphertextVector64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The first ciphertext is encrypted with a smaller noise than the second one
+    /// let noises = vec![Variance(2_f64.powf(-40.)), Variance(2_f64.powf(-25.))];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key_1: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let key_2: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let mut ciphertext_vector =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key_1, &plaintext_vector, noise)?;
+    ///
+    /// engine.discard_encrypt_glwe_ciphertext_vector_with_variable_noise(
+    ///     &key_2,
+    ///     &mut ciphertext_vector,
+    ///     &plaintext_vector,
+    ///     &noises,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_vector.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_vector.polynomial_size(), polynomial_size);
+    /// assert_eq!(
+    /// #     ciphertext_vector.glwe_ciphertext_count(),
+    /// #     GlweCiphertextCount(2)
+    /// # );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_encrypt_glwe_ciphertext_vector_with_variable_noise(
+        &mut self,
+        key: &GlweSecretKey64,
+        output: &mut GlweCiphertextVector64,
+        input: &PlaintextVector64,
+        noises: &[Variance],
+    ) -> Result<(), GlweCiphertextVectorDiscardingVariableNoiseEncryptionError<Self::EngineError>>
+    {
+        GlweCiphertextVectorDiscardingVariableNoiseEncryptionError::perform_generic_checks(
+            key, output, input, noises,
+        )?;
+        unsafe {
+            self.discard_encrypt_glwe_ciphertext_vector_with_variable_noise_unchecked(
+                key, output, input, noises,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_encrypt_glwe_ciphertext_vector_with_variable_noise_unchecked(
+        &mut self,
+        key: &GlweSecretKey64,
+        output: &mut GlweCiphertextVector64,
+        input: &PlaintextVector64,
+        noises: &[Variance],
+    ) {
+        key.0.encrypt_glwe_list_with_variable_noise(
+            &mut output.0,
+            &input.0,
+            noises,
+            &mut self.encryption_generator,
+        );
+    }
+}
@@ -0,0 +1,191 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GlweCiphertext32, GlweCiphertext64, PreparedGgswCiphertext32, PreparedGgswCiphertext64,
+};
+use crate::specification::engines::{
+    GlweCiphertextsGgswCiphertextFusingCmuxEngine, GlweCiphertextsGgswCiphertextFusingCmuxError,
+};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextsGgswCiphertextFusingCmuxEngine`] for [`DefaultEngine`] that
+/// operates on 32 bits integers.
+impl
+    GlweCiphertextsGgswCiphertextFusingCmuxEngine<
+        GlweCiphertext32,
+        GlweCiphertext32,
+        PreparedGgswCiphertext32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purposes, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input_ggsw = 1_u32 << 20;
+    /// let output_glwe = vec![1_u32 << 20; polynomial_size.0];
+    /// let input_glwe = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_ggsw = engine.create_plaintext_from(&input_ggsw)?;
+    /// let plaintext_output_glwe = engine.create_plaintext_vector_from(&output_glwe)?;
+    /// let plaintext_input_glwe = engine.create_plaintext_vector_from(&input_glwe)?;
+    ///
+    /// let ggsw =
+    ///     engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext_ggsw, noise, level, base_log)?;
+    /// let prepared_ggsw: PreparedGgswCiphertext32 = engine.convert_ggsw_ciphertext(&ggsw)?;
+    /// let mut glwe_output = engine.encrypt_glwe_ciphertext(&key, &plaintext_output_glwe, noise)?;
+    /// let mut glwe_input = engine.encrypt_glwe_ciphertext(&key, &plaintext_input_glwe, noise)?;
+    ///
+    /// // Compute the cmux.
+    /// engine.fuse_cmux_glwe_ciphertexts_ggsw_ciphertext(
+    ///     &mut glwe_output,
+    ///     &mut glwe_input,
+    ///     &prepared_ggsw,
+    /// )?;
+    /// #
+    /// assert_eq!(glwe_output.polynomial_size(), glwe_input.polynomial_size(),);
+    /// assert_eq!(glwe_output.glwe_dimension(), glwe_input.glwe_dimension(),);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_cmux_glwe_ciphertexts_ggsw_ciphertext(
+        &mut self,
+        glwe_output: &mut GlweCiphertext32,
+        glwe_input: &mut GlweCiphertext32,
+        ggsw_input: &PreparedGgswCiphertext32,
+    ) -> Result<(), GlweCiphertextsGgswCiphertextFusingCmuxError<Self::EngineError>> {
+        GlweCiphertextsGgswCiphertextFusingCmuxError::perform_generic_checks(
+            glwe_output,
+            glwe_input,
+            ggsw_input,
+        )?;
+        unsafe {
+            self.fuse_cmux_glwe_ciphertexts_ggsw_ciphertext_unchecked(
+                glwe_output,
+                glwe_input,
+                ggsw_input,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn fuse_cmux_glwe_ciphertexts_ggsw_ciphertext_unchecked(
+        &mut self,
+        glwe_output: &mut GlweCiphertext32,
+        glwe_input: &mut GlweCiphertext32,
+        ggsw_input: &PreparedGgswCiphertext32,
+    ) {
+        ggsw_input.0.cmux(&mut glwe_output.0, &mut glwe_input.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextsGgswCiphertextFusingCmuxEngine`] for [`DefaultEngine`] that
+/// operates on 64 bits integers.
+impl
+    GlweCiphertextsGgswCiphertextFusingCmuxEngine<
+        GlweCiphertext64,
+        GlweCiphertext64,
+        PreparedGgswCiphertext64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purposes, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input_ggsw = 1_u64 << 50;
+    /// let output_glwe = vec![1_u64 << 50; polynomial_size.0];
+    /// let input_glwe = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_ggsw = engine.create_plaintext_from(&input_ggsw)?;
+    /// let plaintext_output_glwe = engine.create_plaintext_vector_from(&output_glwe)?;
+    /// let plaintext_input_glwe = engine.create_plaintext_vector_from(&input_glwe)?;
+    ///
+    /// let ggsw =
+    ///     engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext_ggsw, noise, level, base_log)?;
+    /// let prepared_ggsw: PreparedGgswCiphertext64 = engine.convert_ggsw_ciphertext(&ggsw)?;
+    /// let mut glwe_output = engine.encrypt_glwe_ciphertext(&key, &plaintext_output_glwe, noise)?;
+    /// let mut glwe_input = engine.encrypt_glwe_ciphertext(&key, &plaintext_input_glwe, noise)?;
+    ///
+    /// // Compute the cmux.
+    /// engine.fuse_cmux_glwe_ciphertexts_ggsw_ciphertext(
+    ///     &mut glwe_output,
+    ///     &mut glwe_input,
+    ///     &prepared_ggsw,
+    /// )?;
+    /// #
+    /// assert_eq!(glwe_output.polynomial_size(), glwe_input.polynomial_size(),);
+    /// assert_eq!(glwe_output.glwe_dimension(), glwe_input.glwe_dimension(),);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_cmux_glwe_ciphertexts_ggsw_ciphertext(
+        &mut self,
+        glwe_output: &mut GlweCiphertext64,
+        glwe_input: &mut GlweCiphertext64,
+        ggsw_input: &PreparedGgswCiphertext64,
+    ) -> Result<(), GlweCiphertextsGgswCiphertextFusingCmuxError<Self::EngineError>> {
+        GlweCiphertextsGgswCiphertextFusingCmuxError::perform_generic_checks(
+            glwe_output,
+            glwe_input,
+            ggsw_input,
+        )?;
+        unsafe {
+            self.fuse_cmux_glwe_ciphertexts_ggsw_ciphertext_unchecked(
+                glwe_output,
+                glwe_input,
+                ggsw_input,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn fuse_cmux_glwe_ciphertexts_ggsw_ciphertext_unchecked(
+        &mut self,
+        glwe_output: &mut GlweCiphertext64,
+        glwe_input: &mut GlweCiphertext64,
+        ggsw_input: &PreparedGgswCiphertext64,
+    ) {
+        ggsw_input.0.cmux(&mut glwe_output.0, &mut glwe_input.0);
+    }
+}
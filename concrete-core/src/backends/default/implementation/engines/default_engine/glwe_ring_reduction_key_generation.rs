@@ -0,0 +1,238 @@
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GlweRingReductionKey32, GlweRingReductionKey64, GlweSecretKey32, GlweSecretKey64,
+};
+use crate::commons::crypto::glwe::GlweRingReductionKey as ImplGlweRingReductionKey;
+use crate::specification::engines::{
+    GlweRingReductionKeyGenerationEngine, GlweRingReductionKeyGenerationError,
+};
+use crate::specification::entities::GlweSecretKeyEntity;
+
+/// # Description:
+/// Implementation of [`GlweRingReductionKeyGenerationEngine`] for [`DefaultEngine`] that operates
+/// on 32 bits integers.
+impl GlweRingReductionKeyGenerationEngine<GlweSecretKey32, GlweSecretKey32, GlweRingReductionKey32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_glwe_dimension = GlweDimension(1);
+    /// let input_polynomial_size = PolynomialSize(1024);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let output_polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(5);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(input_glwe_dimension, input_polynomial_size)?;
+    /// let output_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, output_polynomial_size)?;
+    ///
+    /// let ring_reduction_key = engine.generate_new_glwe_ring_reduction_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    /// #     ring_reduction_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     ring_reduction_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(ring_reduction_key.input_glwe_dimension(), input_glwe_dimension);
+    /// assert_eq!(ring_reduction_key.input_polynomial_size(), input_polynomial_size);
+    /// assert_eq!(ring_reduction_key.output_glwe_dimension(), output_glwe_dimension);
+    /// assert_eq!(ring_reduction_key.output_polynomial_size(), output_polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_glwe_ring_reduction_key(
+        &mut self,
+        input_key: &GlweSecretKey32,
+        output_key: &GlweSecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<GlweRingReductionKey32, GlweRingReductionKeyGenerationError<Self::EngineError>>
+    {
+        GlweRingReductionKeyGenerationError::perform_generic_checks(
+            input_key,
+            output_key,
+            decomposition_level_count,
+            decomposition_base_log,
+            32,
+        )?;
+        Ok(unsafe {
+            self.generate_new_glwe_ring_reduction_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn generate_new_glwe_ring_reduction_key_unchecked(
+        &mut self,
+        input_key: &GlweSecretKey32,
+        output_key: &GlweSecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> GlweRingReductionKey32 {
+        let mut rrk = ImplGlweRingReductionKey::allocate(
+            0,
+            decomposition_level_count,
+            decomposition_base_log,
+            input_key.glwe_dimension(),
+            input_key.polynomial_size(),
+            output_key.glwe_dimension(),
+            output_key.polynomial_size(),
+        );
+        rrk.fill_with_ring_reduction_key(
+            &input_key.0,
+            &output_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        GlweRingReductionKey32(rrk)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweRingReductionKeyGenerationEngine`] for [`DefaultEngine`] that operates
+/// on 64 bits integers.
+impl GlweRingReductionKeyGenerationEngine<GlweSecretKey64, GlweSecretKey64, GlweRingReductionKey64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_glwe_dimension = GlweDimension(1);
+    /// let input_polynomial_size = PolynomialSize(1024);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let output_polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(5);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(input_glwe_dimension, input_polynomial_size)?;
+    /// let output_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, output_polynomial_size)?;
+    ///
+    /// let ring_reduction_key = engine.generate_new_glwe_ring_reduction_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    /// #     ring_reduction_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     ring_reduction_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(ring_reduction_key.input_glwe_dimension(), input_glwe_dimension);
+    /// assert_eq!(ring_reduction_key.input_polynomial_size(), input_polynomial_size);
+    /// assert_eq!(ring_reduction_key.output_glwe_dimension(), output_glwe_dimension);
+    /// assert_eq!(ring_reduction_key.output_polynomial_size(), output_polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_glwe_ring_reduction_key(
+        &mut self,
+        input_key: &GlweSecretKey64,
+        output_key: &GlweSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<GlweRingReductionKey64, GlweRingReductionKeyGenerationError<Self::EngineError>>
+    {
+        GlweRingReductionKeyGenerationError::perform_generic_checks(
+            input_key,
+            output_key,
+            decomposition_level_count,
+            decomposition_base_log,
+            64,
+        )?;
+        Ok(unsafe {
+            self.generate_new_glwe_ring_reduction_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn generate_new_glwe_ring_reduction_key_unchecked(
+        &mut self,
+        input_key: &GlweSecretKey64,
+        output_key: &GlweSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> GlweRingReductionKey64 {
+        let mut rrk = ImplGlweRingReductionKey::allocate(
+            0,
+            decomposition_level_count,
+            decomposition_base_log,
+            input_key.glwe_dimension(),
+            input_key.polynomial_size(),
+            output_key.glwe_dimension(),
+            output_key.polynomial_size(),
+        );
+        rrk.fill_with_ring_reduction_key(
+            &input_key.0,
+            &output_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        GlweRingReductionKey64(rrk)
+    }
+}
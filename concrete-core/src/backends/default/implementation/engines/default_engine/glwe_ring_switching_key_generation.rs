@@ -0,0 +1,230 @@
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GlweRingSwitchingKey32, GlweRingSwitchingKey64, GlweSecretKey32, GlweSecretKey64,
+};
+use crate::commons::crypto::glwe::GlweRingSwitchingKey as ImplGlweRingSwitchingKey;
+use crate::specification::engines::{
+    GlweRingSwitchingKeyGenerationEngine, GlweRingSwitchingKeyGenerationError,
+};
+use crate::specification::entities::GlweSecretKeyEntity;
+
+/// # Description:
+/// Implementation of [`GlweRingSwitchingKeyGenerationEngine`] for [`DefaultEngine`] that operates
+/// on 32 bits integers.
+impl GlweRingSwitchingKeyGenerationEngine<GlweSecretKey32, GlweSecretKey32, GlweRingSwitchingKey32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(5);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let output_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let input_key: GlweSecretKey32 = engine.split_glwe_secret_key_ring(&output_key)?;
+    ///
+    /// let ring_switching_key = engine.generate_new_glwe_ring_switching_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    /// #     ring_switching_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     ring_switching_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(ring_switching_key.input_glwe_dimension(), GlweDimension(4));
+    /// assert_eq!(ring_switching_key.input_polynomial_size(), PolynomialSize(128));
+    /// assert_eq!(ring_switching_key.output_glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ring_switching_key.output_polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_glwe_ring_switching_key(
+        &mut self,
+        input_key: &GlweSecretKey32,
+        output_key: &GlweSecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<GlweRingSwitchingKey32, GlweRingSwitchingKeyGenerationError<Self::EngineError>>
+    {
+        GlweRingSwitchingKeyGenerationError::perform_generic_checks(
+            input_key,
+            output_key,
+            decomposition_level_count,
+            decomposition_base_log,
+            32,
+        )?;
+        Ok(unsafe {
+            self.generate_new_glwe_ring_switching_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn generate_new_glwe_ring_switching_key_unchecked(
+        &mut self,
+        input_key: &GlweSecretKey32,
+        output_key: &GlweSecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> GlweRingSwitchingKey32 {
+        let mut rsk = ImplGlweRingSwitchingKey::allocate(
+            0,
+            decomposition_level_count,
+            decomposition_base_log,
+            input_key.glwe_dimension(),
+            output_key.glwe_dimension(),
+            output_key.polynomial_size(),
+        );
+        rsk.fill_with_ring_switching_key(
+            &input_key.0,
+            &output_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        GlweRingSwitchingKey32(rsk)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweRingSwitchingKeyGenerationEngine`] for [`DefaultEngine`] that operates
+/// on 64 bits integers.
+impl GlweRingSwitchingKeyGenerationEngine<GlweSecretKey64, GlweSecretKey64, GlweRingSwitchingKey64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(5);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let output_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let input_key: GlweSecretKey64 = engine.split_glwe_secret_key_ring(&output_key)?;
+    ///
+    /// let ring_switching_key = engine.generate_new_glwe_ring_switching_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    /// #     ring_switching_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     ring_switching_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(ring_switching_key.input_glwe_dimension(), GlweDimension(4));
+    /// assert_eq!(ring_switching_key.input_polynomial_size(), PolynomialSize(128));
+    /// assert_eq!(ring_switching_key.output_glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ring_switching_key.output_polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_glwe_ring_switching_key(
+        &mut self,
+        input_key: &GlweSecretKey64,
+        output_key: &GlweSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<GlweRingSwitchingKey64, GlweRingSwitchingKeyGenerationError<Self::EngineError>>
+    {
+        GlweRingSwitchingKeyGenerationError::perform_generic_checks(
+            input_key,
+            output_key,
+            decomposition_level_count,
+            decomposition_base_log,
+            64,
+        )?;
+        Ok(unsafe {
+            self.generate_new_glwe_ring_switching_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn generate_new_glwe_ring_switching_key_unchecked(
+        &mut self,
+        input_key: &GlweSecretKey64,
+        output_key: &GlweSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> GlweRingSwitchingKey64 {
+        let mut rsk = ImplGlweRingSwitchingKey::allocate(
+            0,
+            decomposition_level_count,
+            decomposition_base_log,
+            input_key.glwe_dimension(),
+            output_key.glwe_dimension(),
+            output_key.polynomial_size(),
+        );
+        rsk.fill_with_ring_switching_key(
+            &input_key.0,
+            &output_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        GlweRingSwitchingKey64(rsk)
+    }
+}
@@ -0,0 +1,124 @@
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GlweSecretKeyGaussian32, GlweSecretKeyGaussian64,
+};
+use crate::commons::crypto::secret::GlweSecretKey as ImplGlweSecretKey;
+use crate::specification::engines::{
+    GlweSecretKeyGaussianGenerationEngine, GlweSecretKeyGaussianGenerationError,
+};
+
+/// # Description:
+/// Implementation of [`GlweSecretKeyGaussianGenerationEngine`] for [`DefaultEngine`] that
+/// operates on 32 bits integers.
+impl GlweSecretKeyGaussianGenerationEngine<GlweSecretKeyGaussian32> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let glwe_secret_key: GlweSecretKeyGaussian32 =
+    ///     engine.generate_new_glwe_secret_key_gaussian(glwe_dimension, polynomial_size)?;
+    /// #
+    /// assert_eq!(glwe_secret_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(glwe_secret_key.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_glwe_secret_key_gaussian(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<GlweSecretKeyGaussian32, GlweSecretKeyGaussianGenerationError<Self::EngineError>>
+    {
+        GlweSecretKeyGaussianGenerationError::perform_generic_checks(
+            glwe_dimension,
+            polynomial_size,
+        )?;
+        Ok(unsafe {
+            self.generate_new_glwe_secret_key_gaussian_unchecked(glwe_dimension, polynomial_size)
+        })
+    }
+
+    unsafe fn generate_new_glwe_secret_key_gaussian_unchecked(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> GlweSecretKeyGaussian32 {
+        GlweSecretKeyGaussian32(ImplGlweSecretKey::generate_gaussian(
+            glwe_dimension,
+            polynomial_size,
+            &mut self.secret_generator,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweSecretKeyGaussianGenerationEngine`] for [`DefaultEngine`] that
+/// operates on 64 bits integers.
+impl GlweSecretKeyGaussianGenerationEngine<GlweSecretKeyGaussian64> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let glwe_secret_key: GlweSecretKeyGaussian64 =
+    ///     engine.generate_new_glwe_secret_key_gaussian(glwe_dimension, polynomial_size)?;
+    /// #
+    /// assert_eq!(glwe_secret_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(glwe_secret_key.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_glwe_secret_key_gaussian(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<GlweSecretKeyGaussian64, GlweSecretKeyGaussianGenerationError<Self::EngineError>>
+    {
+        GlweSecretKeyGaussianGenerationError::perform_generic_checks(
+            glwe_dimension,
+            polynomial_size,
+        )?;
+        Ok(unsafe {
+            self.generate_new_glwe_secret_key_gaussian_unchecked(glwe_dimension, polynomial_size)
+        })
+    }
+
+    unsafe fn generate_new_glwe_secret_key_gaussian_unchecked(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> GlweSecretKeyGaussian64 {
+        GlweSecretKeyGaussian64(ImplGlweSecretKey::generate_gaussian(
+            glwe_dimension,
+            polynomial_size,
+            &mut self.secret_generator,
+        ))
+    }
+}
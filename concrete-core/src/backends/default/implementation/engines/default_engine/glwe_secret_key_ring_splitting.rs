@@ -0,0 +1,99 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{GlweSecretKey32, GlweSecretKey64};
+use crate::specification::engines::{
+    GlweSecretKeyRingSplittingEngine, GlweSecretKeyRingSplittingError,
+};
+
+/// # Description:
+/// Implementation of [`GlweSecretKeyRingSplittingEngine`] for [`DefaultEngine`] that operates on
+/// 32 bits integers.
+impl GlweSecretKeyRingSplittingEngine<GlweSecretKey32, GlweSecretKey32> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// let split_key: GlweSecretKey32 = engine.split_glwe_secret_key_ring(&key)?;
+    /// #
+    /// assert_eq!(split_key.glwe_dimension(), GlweDimension(4));
+    /// assert_eq!(split_key.polynomial_size(), PolynomialSize(128));
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn split_glwe_secret_key_ring(
+        &mut self,
+        input: &GlweSecretKey32,
+    ) -> Result<GlweSecretKey32, GlweSecretKeyRingSplittingError<Self::EngineError>> {
+        GlweSecretKeyRingSplittingError::perform_generic_checks(input)?;
+        Ok(unsafe { self.split_glwe_secret_key_ring_unchecked(input) })
+    }
+
+    unsafe fn split_glwe_secret_key_ring_unchecked(
+        &mut self,
+        input: &GlweSecretKey32,
+    ) -> GlweSecretKey32 {
+        GlweSecretKey32(input.0.to_ring_split_key())
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweSecretKeyRingSplittingEngine`] for [`DefaultEngine`] that operates on
+/// 64 bits integers.
+impl GlweSecretKeyRingSplittingEngine<GlweSecretKey64, GlweSecretKey64> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// let split_key: GlweSecretKey64 = engine.split_glwe_secret_key_ring(&key)?;
+    /// #
+    /// assert_eq!(split_key.glwe_dimension(), GlweDimension(4));
+    /// assert_eq!(split_key.polynomial_size(), PolynomialSize(128));
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn split_glwe_secret_key_ring(
+        &mut self,
+        input: &GlweSecretKey64,
+    ) -> Result<GlweSecretKey64, GlweSecretKeyRingSplittingError<Self::EngineError>> {
+        GlweSecretKeyRingSplittingError::perform_generic_checks(input)?;
+        Ok(unsafe { self.split_glwe_secret_key_ring_unchecked(input) })
+    }
+
+    unsafe fn split_glwe_secret_key_ring_unchecked(
+        &mut self,
+        input: &GlweSecretKey64,
+    ) -> GlweSecretKey64 {
+        GlweSecretKey64(input.0.to_ring_split_key())
+    }
+}
@@ -0,0 +1,101 @@
+use crate::backends::default::engines::DefaultEngine;
+use crate::backends::default::entities::{
+    GlweSecretKey32, GlweSecretKey64, GlweTensorProductSecretKey32, GlweTensorProductSecretKey64,
+};
+use crate::specification::engines::{
+    GlweSecretKeyTensorProductSameKeyEngine, GlweSecretKeyTensorProductSameKeyError,
+};
+
+impl GlweSecretKeyTensorProductSameKeyEngine<GlweSecretKey32, GlweTensorProductSecretKey32>
+    for DefaultEngine
+{
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    ///
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    ///
+    /// let glwe_secret_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let tensor_product_key: GlweTensorProductSecretKey32 =
+    ///     engine.create_tensor_product_secret_key_same_key(&glwe_secret_key)?;
+    /// assert_eq!(tensor_product_key.glwe_dimension(), GlweDimension(5));
+    /// assert_eq!(tensor_product_key.polynomial_size(), polynomial_size);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_tensor_product_secret_key_same_key(
+        &mut self,
+        input: &GlweSecretKey32,
+    ) -> Result<
+        GlweTensorProductSecretKey32,
+        GlweSecretKeyTensorProductSameKeyError<Self::EngineError>,
+    > {
+        Ok(unsafe { self.create_tensor_product_secret_key_same_key_unchecked(input) })
+    }
+
+    unsafe fn create_tensor_product_secret_key_same_key_unchecked(
+        &mut self,
+        input: &GlweSecretKey32,
+    ) -> GlweTensorProductSecretKey32 {
+        GlweTensorProductSecretKey32(input.0.create_tensor_product_key())
+    }
+}
+
+impl GlweSecretKeyTensorProductSameKeyEngine<GlweSecretKey64, GlweTensorProductSecretKey64>
+    for DefaultEngine
+{
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    ///
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    ///
+    /// let glwe_secret_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let tensor_product_key: GlweTensorProductSecretKey64 =
+    ///     engine.create_tensor_product_secret_key_same_key(&glwe_secret_key)?;
+    /// assert_eq!(tensor_product_key.glwe_dimension(), GlweDimension(5));
+    /// assert_eq!(tensor_product_key.polynomial_size(), polynomial_size);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_tensor_product_secret_key_same_key(
+        &mut self,
+        input: &GlweSecretKey64,
+    ) -> Result<
+        GlweTensorProductSecretKey64,
+        GlweSecretKeyTensorProductSameKeyError<Self::EngineError>,
+    > {
+        Ok(unsafe { self.create_tensor_product_secret_key_same_key_unchecked(input) })
+    }
+
+    unsafe fn create_tensor_product_secret_key_same_key_unchecked(
+        &mut self,
+        input: &GlweSecretKey64,
+    ) -> GlweTensorProductSecretKey64 {
+        GlweTensorProductSecretKey64(input.0.create_tensor_product_key())
+    }
+}
@@ -0,0 +1,124 @@
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GlweSecretKeyTernary32, GlweSecretKeyTernary64,
+};
+use crate::commons::crypto::secret::GlweSecretKey as ImplGlweSecretKey;
+use crate::specification::engines::{
+    GlweSecretKeyTernaryGenerationEngine, GlweSecretKeyTernaryGenerationError,
+};
+
+/// # Description:
+/// Implementation of [`GlweSecretKeyTernaryGenerationEngine`] for [`DefaultEngine`] that
+/// operates on 32 bits integers.
+impl GlweSecretKeyTernaryGenerationEngine<GlweSecretKeyTernary32> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let glwe_secret_key: GlweSecretKeyTernary32 =
+    ///     engine.generate_new_glwe_secret_key_ternary(glwe_dimension, polynomial_size)?;
+    /// #
+    /// assert_eq!(glwe_secret_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(glwe_secret_key.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_glwe_secret_key_ternary(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<GlweSecretKeyTernary32, GlweSecretKeyTernaryGenerationError<Self::EngineError>>
+    {
+        GlweSecretKeyTernaryGenerationError::perform_generic_checks(
+            glwe_dimension,
+            polynomial_size,
+        )?;
+        Ok(unsafe {
+            self.generate_new_glwe_secret_key_ternary_unchecked(glwe_dimension, polynomial_size)
+        })
+    }
+
+    unsafe fn generate_new_glwe_secret_key_ternary_unchecked(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> GlweSecretKeyTernary32 {
+        GlweSecretKeyTernary32(ImplGlweSecretKey::generate_ternary(
+            glwe_dimension,
+            polynomial_size,
+            &mut self.secret_generator,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweSecretKeyTernaryGenerationEngine`] for [`DefaultEngine`] that
+/// operates on 64 bits integers.
+impl GlweSecretKeyTernaryGenerationEngine<GlweSecretKeyTernary64> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let glwe_secret_key: GlweSecretKeyTernary64 =
+    ///     engine.generate_new_glwe_secret_key_ternary(glwe_dimension, polynomial_size)?;
+    /// #
+    /// assert_eq!(glwe_secret_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(glwe_secret_key.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_glwe_secret_key_ternary(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<GlweSecretKeyTernary64, GlweSecretKeyTernaryGenerationError<Self::EngineError>>
+    {
+        GlweSecretKeyTernaryGenerationError::perform_generic_checks(
+            glwe_dimension,
+            polynomial_size,
+        )?;
+        Ok(unsafe {
+            self.generate_new_glwe_secret_key_ternary_unchecked(glwe_dimension, polynomial_size)
+        })
+    }
+
+    unsafe fn generate_new_glwe_secret_key_ternary_unchecked(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> GlweSecretKeyTernary64 {
+        GlweSecretKeyTernary64(ImplGlweSecretKey::generate_ternary(
+            glwe_dimension,
+            polynomial_size,
+            &mut self.secret_generator,
+        ))
+    }
+}
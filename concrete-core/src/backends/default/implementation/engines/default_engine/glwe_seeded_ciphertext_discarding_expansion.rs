@@ -0,0 +1,142 @@
+use crate::backends::default::engines::DefaultEngine;
+use crate::backends::default::entities::{
+    GlweCiphertext32, GlweCiphertext64, GlweSeededCiphertext32, GlweSeededCiphertext64,
+};
+use crate::commons::math::random::ByteRandomGenerator;
+use crate::specification::engines::{
+    GlweSeededCiphertextDiscardingExpansionEngine, GlweSeededCiphertextDiscardingExpansionError,
+};
+
+/// # Description:
+/// Implementation of [`GlweSeededCiphertextDiscardingExpansionEngine`] for [`DefaultEngine`] that operates on 32 bits integers.
+impl GlweSeededCiphertextDiscardingExpansionEngine<GlweSeededCiphertext32, GlweCiphertext32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let seeded_ciphertext =
+    ///     engine.encrypt_glwe_seeded_ciphertext(&key, &plaintext_vector, noise)?;
+    /// // The output ciphertext was allocated beforehand, for instance by a previous expansion.
+    /// let mut ciphertext = engine
+    ///     .transform_glwe_seeded_ciphertext_to_glwe_ciphertext(seeded_ciphertext.clone())?;
+    ///
+    /// // The masks are regenerated with a generator chosen by the caller.
+    /// engine.discard_expand_glwe_seeded_ciphertext::<SoftwareRandomGenerator>(
+    ///     &mut ciphertext,
+    ///     &seeded_ciphertext,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_expand_glwe_seeded_ciphertext<Generator: ByteRandomGenerator>(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &GlweSeededCiphertext32,
+    ) -> Result<(), GlweSeededCiphertextDiscardingExpansionError<Self::EngineError>> {
+        GlweSeededCiphertextDiscardingExpansionError::perform_generic_checks(output, input)?;
+        unsafe { self.discard_expand_glwe_seeded_ciphertext_unchecked::<Generator>(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_expand_glwe_seeded_ciphertext_unchecked<Generator: ByteRandomGenerator>(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &GlweSeededCiphertext32,
+    ) {
+        input.0.expand_into::<_, _, Generator>(&mut output.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweSeededCiphertextDiscardingExpansionEngine`] for [`DefaultEngine`] that operates on 64 bits integers.
+impl GlweSeededCiphertextDiscardingExpansionEngine<GlweSeededCiphertext64, GlweCiphertext64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let seeded_ciphertext =
+    ///     engine.encrypt_glwe_seeded_ciphertext(&key, &plaintext_vector, noise)?;
+    /// // The output ciphertext was allocated beforehand, for instance by a previous expansion.
+    /// let mut ciphertext = engine
+    ///     .transform_glwe_seeded_ciphertext_to_glwe_ciphertext(seeded_ciphertext.clone())?;
+    ///
+    /// // The masks are regenerated with a generator chosen by the caller.
+    /// engine.discard_expand_glwe_seeded_ciphertext::<SoftwareRandomGenerator>(
+    ///     &mut ciphertext,
+    ///     &seeded_ciphertext,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_expand_glwe_seeded_ciphertext<Generator: ByteRandomGenerator>(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &GlweSeededCiphertext64,
+    ) -> Result<(), GlweSeededCiphertextDiscardingExpansionError<Self::EngineError>> {
+        GlweSeededCiphertextDiscardingExpansionError::perform_generic_checks(output, input)?;
+        unsafe { self.discard_expand_glwe_seeded_ciphertext_unchecked::<Generator>(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_expand_glwe_seeded_ciphertext_unchecked<Generator: ByteRandomGenerator>(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &GlweSeededCiphertext64,
+    ) {
+        input.0.expand_into::<_, _, Generator>(&mut output.0);
+    }
+}
@@ -0,0 +1,162 @@
+use crate::backends::default::engines::DefaultEngine;
+use crate::backends::default::entities::{
+    GlweCiphertextVector32, GlweCiphertextVector64, GlweSeededCiphertextVector32,
+    GlweSeededCiphertextVector64,
+};
+use crate::commons::math::random::ByteRandomGenerator;
+use crate::specification::engines::{
+    GlweSeededCiphertextVectorDiscardingExpansionEngine,
+    GlweSeededCiphertextVectorDiscardingExpansionError,
+};
+
+/// # Description:
+/// Implementation of [`GlweSeededCiphertextVectorDiscardingExpansionEngine`] for [`DefaultEngine`] that operates on 32 bits integers.
+impl
+    GlweSeededCiphertextVectorDiscardingExpansionEngine<
+        GlweSeededCiphertextVector32,
+        GlweCiphertextVector32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let seeded_ciphertext_vector =
+    ///     engine.encrypt_glwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// // The output vector was allocated beforehand, for instance by a previous expansion.
+    /// let mut ciphertext_vector = engine
+    ///     .transform_glwe_seeded_ciphertext_vector_to_glwe_ciphertext_vector(
+    ///         seeded_ciphertext_vector.clone(),
+    ///     )?;
+    ///
+    /// // The masks are regenerated with a generator chosen by the caller.
+    /// engine.discard_expand_glwe_seeded_ciphertext_vector::<SoftwareRandomGenerator>(
+    ///     &mut ciphertext_vector,
+    ///     &seeded_ciphertext_vector,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_vector.glwe_ciphertext_count(), GlweCiphertextCount(2));
+    /// assert_eq!(ciphertext_vector.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext_vector.glwe_dimension(), glwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_expand_glwe_seeded_ciphertext_vector<Generator: ByteRandomGenerator>(
+        &mut self,
+        output: &mut GlweCiphertextVector32,
+        input: &GlweSeededCiphertextVector32,
+    ) -> Result<(), GlweSeededCiphertextVectorDiscardingExpansionError<Self::EngineError>> {
+        GlweSeededCiphertextVectorDiscardingExpansionError::perform_generic_checks(output, input)?;
+        unsafe {
+            self.discard_expand_glwe_seeded_ciphertext_vector_unchecked::<Generator>(output, input)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_expand_glwe_seeded_ciphertext_vector_unchecked<
+        Generator: ByteRandomGenerator,
+    >(
+        &mut self,
+        output: &mut GlweCiphertextVector32,
+        input: &GlweSeededCiphertextVector32,
+    ) {
+        input.0.expand_into::<_, _, Generator>(&mut output.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweSeededCiphertextVectorDiscardingExpansionEngine`] for [`DefaultEngine`] that operates on 64 bits integers.
+impl
+    GlweSeededCiphertextVectorDiscardingExpansionEngine<
+        GlweSeededCiphertextVector64,
+        GlweCiphertextVector64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let seeded_ciphertext_vector =
+    ///     engine.encrypt_glwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// // The output vector was allocated beforehand, for instance by a previous expansion.
+    /// let mut ciphertext_vector = engine
+    ///     .transform_glwe_seeded_ciphertext_vector_to_glwe_ciphertext_vector(
+    ///         seeded_ciphertext_vector.clone(),
+    ///     )?;
+    ///
+    /// // The masks are regenerated with a generator chosen by the caller.
+    /// engine.discard_expand_glwe_seeded_ciphertext_vector::<SoftwareRandomGenerator>(
+    ///     &mut ciphertext_vector,
+    ///     &seeded_ciphertext_vector,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_vector.glwe_ciphertext_count(), GlweCiphertextCount(2));
+    /// assert_eq!(ciphertext_vector.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext_vector.glwe_dimension(), glwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_expand_glwe_seeded_ciphertext_vector<Generator: ByteRandomGenerator>(
+        &mut self,
+        output: &mut GlweCiphertextVector64,
+        input: &GlweSeededCiphertextVector64,
+    ) -> Result<(), GlweSeededCiphertextVectorDiscardingExpansionError<Self::EngineError>> {
+        GlweSeededCiphertextVectorDiscardingExpansionError::perform_generic_checks(output, input)?;
+        unsafe {
+            self.discard_expand_glwe_seeded_ciphertext_vector_unchecked::<Generator>(output, input)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_expand_glwe_seeded_ciphertext_vector_unchecked<
+        Generator: ByteRandomGenerator,
+    >(
+        &mut self,
+        output: &mut GlweCiphertextVector64,
+        input: &GlweSeededCiphertextVector64,
+    ) {
+        input.0.expand_into::<_, _, Generator>(&mut output.0);
+    }
+}
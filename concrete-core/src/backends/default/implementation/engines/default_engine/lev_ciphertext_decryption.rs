@@ -0,0 +1,122 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    LevCiphertext32, LevCiphertext64, LweSecretKey32, LweSecretKey64, Plaintext32, Plaintext64,
+};
+use crate::commons::crypto::encoding::Plaintext as ImplPlaintext;
+use crate::specification::engines::{LevCiphertextDecryptionEngine, LevCiphertextDecryptionError};
+
+/// # Description:
+/// Implementation of [`LevCiphertextDecryptionEngine`] for [`DefaultEngine`] that operates on
+/// 32 bits integers.
+impl LevCiphertextDecryptionEngine<LweSecretKey32, LevCiphertext32, Plaintext32> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// let level = DecompositionLevelCount(3);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // The plaintext is scaled by each level of the decomposition during the encryption
+    /// let input = 3_u32;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let ciphertext = engine.encrypt_lev_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    ///
+    /// let decrypted_plaintext = engine.decrypt_lev_ciphertext(&key, &ciphertext)?;
+    /// #
+    /// assert_eq!(engine.retrieve_plaintext(&decrypted_plaintext)?, input);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lev_ciphertext(
+        &mut self,
+        key: &LweSecretKey32,
+        input: &LevCiphertext32,
+    ) -> Result<Plaintext32, LevCiphertextDecryptionError<Self::EngineError>> {
+        LevCiphertextDecryptionError::perform_generic_checks(key, input)?;
+        Ok(unsafe { self.decrypt_lev_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lev_ciphertext_unchecked(
+        &mut self,
+        key: &LweSecretKey32,
+        input: &LevCiphertext32,
+    ) -> Plaintext32 {
+        let mut plaintext = ImplPlaintext(0u32);
+        key.0.decrypt_lev(&mut plaintext, &input.0);
+        Plaintext32(plaintext)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LevCiphertextDecryptionEngine`] for [`DefaultEngine`] that operates on
+/// 64 bits integers.
+impl LevCiphertextDecryptionEngine<LweSecretKey64, LevCiphertext64, Plaintext64> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// let level = DecompositionLevelCount(3);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // The plaintext is scaled by each level of the decomposition during the encryption
+    /// let input = 3_u64;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let ciphertext = engine.encrypt_lev_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    ///
+    /// let decrypted_plaintext = engine.decrypt_lev_ciphertext(&key, &ciphertext)?;
+    /// #
+    /// assert_eq!(engine.retrieve_plaintext(&decrypted_plaintext)?, input);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lev_ciphertext(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &LevCiphertext64,
+    ) -> Result<Plaintext64, LevCiphertextDecryptionError<Self::EngineError>> {
+        LevCiphertextDecryptionError::perform_generic_checks(key, input)?;
+        Ok(unsafe { self.decrypt_lev_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lev_ciphertext_unchecked(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &LevCiphertext64,
+    ) -> Plaintext64 {
+        let mut plaintext = ImplPlaintext(0u64);
+        key.0.decrypt_lev(&mut plaintext, &input.0);
+        Plaintext64(plaintext)
+    }
+}
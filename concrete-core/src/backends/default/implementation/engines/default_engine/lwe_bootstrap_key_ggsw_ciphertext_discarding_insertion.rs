@@ -0,0 +1,184 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GgswCiphertext32, GgswCiphertext64, LweBootstrapKey32, LweBootstrapKey64,
+};
+use crate::commons::math::tensor::{AsMutTensor, AsRefTensor};
+use crate::specification::engines::{
+    LweBootstrapKeyGgswCiphertextDiscardingInsertionEngine,
+    LweBootstrapKeyGgswCiphertextDiscardingInsertionError,
+};
+use concrete_commons::parameters::GgswCiphertextIndex;
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyGgswCiphertextDiscardingInsertionEngine`] for
+/// [`DefaultEngine`] that operates on 32 bits integers.
+impl LweBootstrapKeyGgswCiphertextDiscardingInsertionEngine<GgswCiphertext32, LweBootstrapKey32>
+    for DefaultEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextIndex, GlweDimension,
+    ///     LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey32 =
+    ///     engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// // Copy the key, one GGSW ciphertext at a time
+    /// let glwe_size = glwe_dim.to_glwe_size();
+    /// let mut copy: LweBootstrapKey32 = engine.create_lwe_bootstrap_key_from(
+    ///     vec![0; lwe_dim.0 * dec_lc.0 * glwe_size.0 * glwe_size.0 * poly_size.0],
+    ///     glwe_size,
+    ///     poly_size,
+    ///     dec_bl,
+    ///     dec_lc,
+    /// )?;
+    /// for index in 0..lwe_dim.0 {
+    ///     let ggsw: GgswCiphertext32 =
+    ///         engine.extract_lwe_bootstrap_key_ggsw_ciphertext(&bsk, GgswCiphertextIndex(index))?;
+    ///     engine.discard_insert_lwe_bootstrap_key_ggsw_ciphertext(
+    ///         &mut copy,
+    ///         &ggsw,
+    ///         GgswCiphertextIndex(index),
+    ///     )?;
+    /// }
+    /// #
+    /// assert_eq!(copy, bsk);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_insert_lwe_bootstrap_key_ggsw_ciphertext(
+        &mut self,
+        bootstrap_key: &mut LweBootstrapKey32,
+        input: &GgswCiphertext32,
+        index: GgswCiphertextIndex,
+    ) -> Result<(), LweBootstrapKeyGgswCiphertextDiscardingInsertionError<Self::EngineError>> {
+        LweBootstrapKeyGgswCiphertextDiscardingInsertionError::perform_generic_checks(
+            bootstrap_key,
+            input,
+            index,
+        )?;
+        unsafe {
+            self.discard_insert_lwe_bootstrap_key_ggsw_ciphertext_unchecked(
+                bootstrap_key,
+                input,
+                index,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_insert_lwe_bootstrap_key_ggsw_ciphertext_unchecked(
+        &mut self,
+        bootstrap_key: &mut LweBootstrapKey32,
+        input: &GgswCiphertext32,
+        index: GgswCiphertextIndex,
+    ) {
+        let mut ggsw = bootstrap_key.0.ggsw_iter_mut().nth(index.0).unwrap();
+        ggsw.as_mut_tensor().fill_with_copy(input.0.as_tensor());
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyGgswCiphertextDiscardingInsertionEngine`] for
+/// [`DefaultEngine`] that operates on 64 bits integers.
+impl LweBootstrapKeyGgswCiphertextDiscardingInsertionEngine<GgswCiphertext64, LweBootstrapKey64>
+    for DefaultEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextIndex, GlweDimension,
+    ///     LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey64 =
+    ///     engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// // Copy the key, one GGSW ciphertext at a time
+    /// let glwe_size = glwe_dim.to_glwe_size();
+    /// let mut copy: LweBootstrapKey64 = engine.create_lwe_bootstrap_key_from(
+    ///     vec![0; lwe_dim.0 * dec_lc.0 * glwe_size.0 * glwe_size.0 * poly_size.0],
+    ///     glwe_size,
+    ///     poly_size,
+    ///     dec_bl,
+    ///     dec_lc,
+    /// )?;
+    /// for index in 0..lwe_dim.0 {
+    ///     let ggsw: GgswCiphertext64 =
+    ///         engine.extract_lwe_bootstrap_key_ggsw_ciphertext(&bsk, GgswCiphertextIndex(index))?;
+    ///     engine.discard_insert_lwe_bootstrap_key_ggsw_ciphertext(
+    ///         &mut copy,
+    ///         &ggsw,
+    ///         GgswCiphertextIndex(index),
+    ///     )?;
+    /// }
+    /// #
+    /// assert_eq!(copy, bsk);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_insert_lwe_bootstrap_key_ggsw_ciphertext(
+        &mut self,
+        bootstrap_key: &mut LweBootstrapKey64,
+        input: &GgswCiphertext64,
+        index: GgswCiphertextIndex,
+    ) -> Result<(), LweBootstrapKeyGgswCiphertextDiscardingInsertionError<Self::EngineError>> {
+        LweBootstrapKeyGgswCiphertextDiscardingInsertionError::perform_generic_checks(
+            bootstrap_key,
+            input,
+            index,
+        )?;
+        unsafe {
+            self.discard_insert_lwe_bootstrap_key_ggsw_ciphertext_unchecked(
+                bootstrap_key,
+                input,
+                index,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_insert_lwe_bootstrap_key_ggsw_ciphertext_unchecked(
+        &mut self,
+        bootstrap_key: &mut LweBootstrapKey64,
+        input: &GgswCiphertext64,
+        index: GgswCiphertextIndex,
+    ) {
+        let mut ggsw = bootstrap_key.0.ggsw_iter_mut().nth(index.0).unwrap();
+        ggsw.as_mut_tensor().fill_with_copy(input.0.as_tensor());
+    }
+}
@@ -0,0 +1,148 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GgswCiphertext32, GgswCiphertext64, LweBootstrapKey32, LweBootstrapKey64,
+};
+use crate::commons::crypto::ggsw::StandardGgswCiphertext as ImplStandardGgswCiphertext;
+use crate::commons::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::specification::engines::{
+    LweBootstrapKeyGgswCiphertextExtractionEngine, LweBootstrapKeyGgswCiphertextExtractionError,
+};
+use concrete_commons::parameters::GgswCiphertextIndex;
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyGgswCiphertextExtractionEngine`] for [`DefaultEngine`] that
+/// operates on 32 bits integers.
+impl LweBootstrapKeyGgswCiphertextExtractionEngine<LweBootstrapKey32, GgswCiphertext32>
+    for DefaultEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextIndex, GlweDimension,
+    ///     LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey32 =
+    ///     engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let ggsw: GgswCiphertext32 =
+    ///     engine.extract_lwe_bootstrap_key_ggsw_ciphertext(&bsk, GgswCiphertextIndex(2))?;
+    /// #
+    /// assert_eq!(ggsw.glwe_dimension(), glwe_dim);
+    /// assert_eq!(ggsw.polynomial_size(), poly_size);
+    /// assert_eq!(ggsw.decomposition_base_log(), dec_bl);
+    /// assert_eq!(ggsw.decomposition_level_count(), dec_lc);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn extract_lwe_bootstrap_key_ggsw_ciphertext(
+        &mut self,
+        bootstrap_key: &LweBootstrapKey32,
+        index: GgswCiphertextIndex,
+    ) -> Result<GgswCiphertext32, LweBootstrapKeyGgswCiphertextExtractionError<Self::EngineError>>
+    {
+        LweBootstrapKeyGgswCiphertextExtractionError::perform_generic_checks(bootstrap_key, index)?;
+        Ok(unsafe {
+            self.extract_lwe_bootstrap_key_ggsw_ciphertext_unchecked(bootstrap_key, index)
+        })
+    }
+
+    unsafe fn extract_lwe_bootstrap_key_ggsw_ciphertext_unchecked(
+        &mut self,
+        bootstrap_key: &LweBootstrapKey32,
+        index: GgswCiphertextIndex,
+    ) -> GgswCiphertext32 {
+        let ggsw = bootstrap_key.0.ggsw_iter().nth(index.0).unwrap();
+        GgswCiphertext32(ImplStandardGgswCiphertext::from_container(
+            ggsw.as_tensor().as_slice().to_vec(),
+            ggsw.glwe_size(),
+            ggsw.polynomial_size(),
+            ggsw.decomposition_base_log(),
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyGgswCiphertextExtractionEngine`] for [`DefaultEngine`] that
+/// operates on 64 bits integers.
+impl LweBootstrapKeyGgswCiphertextExtractionEngine<LweBootstrapKey64, GgswCiphertext64>
+    for DefaultEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextIndex, GlweDimension,
+    ///     LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey64 =
+    ///     engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let ggsw: GgswCiphertext64 =
+    ///     engine.extract_lwe_bootstrap_key_ggsw_ciphertext(&bsk, GgswCiphertextIndex(2))?;
+    /// #
+    /// assert_eq!(ggsw.glwe_dimension(), glwe_dim);
+    /// assert_eq!(ggsw.polynomial_size(), poly_size);
+    /// assert_eq!(ggsw.decomposition_base_log(), dec_bl);
+    /// assert_eq!(ggsw.decomposition_level_count(), dec_lc);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn extract_lwe_bootstrap_key_ggsw_ciphertext(
+        &mut self,
+        bootstrap_key: &LweBootstrapKey64,
+        index: GgswCiphertextIndex,
+    ) -> Result<GgswCiphertext64, LweBootstrapKeyGgswCiphertextExtractionError<Self::EngineError>>
+    {
+        LweBootstrapKeyGgswCiphertextExtractionError::perform_generic_checks(bootstrap_key, index)?;
+        Ok(unsafe {
+            self.extract_lwe_bootstrap_key_ggsw_ciphertext_unchecked(bootstrap_key, index)
+        })
+    }
+
+    unsafe fn extract_lwe_bootstrap_key_ggsw_ciphertext_unchecked(
+        &mut self,
+        bootstrap_key: &LweBootstrapKey64,
+        index: GgswCiphertextIndex,
+    ) -> GgswCiphertext64 {
+        let ggsw = bootstrap_key.0.ggsw_iter().nth(index.0).unwrap();
+        GgswCiphertext64(ImplStandardGgswCiphertext::from_container(
+            ggsw.as_tensor().as_slice().to_vec(),
+            ggsw.glwe_size(),
+            ggsw.polynomial_size(),
+            ggsw.decomposition_base_log(),
+        ))
+    }
+}
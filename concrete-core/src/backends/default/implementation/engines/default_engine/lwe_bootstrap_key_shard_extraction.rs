@@ -0,0 +1,161 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{LweBootstrapKey32, LweBootstrapKey64};
+use crate::commons::crypto::bootstrap::StandardBootstrapKey as ImplStandardBootstrapKey;
+use crate::commons::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::commons::math::torus::UnsignedTorus;
+use crate::specification::engines::{
+    LweBootstrapKeyShardExtractionEngine, LweBootstrapKeyShardExtractionError,
+};
+use concrete_commons::parameters::{GgswCiphertextIndex, LweDimension};
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyShardExtractionEngine`] for [`DefaultEngine`] that operates
+/// on 32 bits integers.
+impl LweBootstrapKeyShardExtractionEngine<LweBootstrapKey32, LweBootstrapKey32> for DefaultEngine {
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextIndex, GlweDimension,
+    ///     LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey32 =
+    ///     engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// // The second half of the key
+    /// let shard: LweBootstrapKey32 =
+    ///     engine.extract_lwe_bootstrap_key_shard(&bsk, GgswCiphertextIndex(2), LweDimension(2))?;
+    /// #
+    /// assert_eq!(shard.input_lwe_dimension(), LweDimension(2));
+    /// assert_eq!(shard.glwe_dimension(), glwe_dim);
+    /// assert_eq!(shard.polynomial_size(), poly_size);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn extract_lwe_bootstrap_key_shard(
+        &mut self,
+        bootstrap_key: &LweBootstrapKey32,
+        first: GgswCiphertextIndex,
+        shard_size: LweDimension,
+    ) -> Result<LweBootstrapKey32, LweBootstrapKeyShardExtractionError<Self::EngineError>> {
+        LweBootstrapKeyShardExtractionError::perform_generic_checks(
+            bootstrap_key,
+            first,
+            shard_size,
+        )?;
+        Ok(unsafe {
+            self.extract_lwe_bootstrap_key_shard_unchecked(bootstrap_key, first, shard_size)
+        })
+    }
+
+    unsafe fn extract_lwe_bootstrap_key_shard_unchecked(
+        &mut self,
+        bootstrap_key: &LweBootstrapKey32,
+        first: GgswCiphertextIndex,
+        shard_size: LweDimension,
+    ) -> LweBootstrapKey32 {
+        LweBootstrapKey32(extract_shard(&bootstrap_key.0, first, shard_size))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyShardExtractionEngine`] for [`DefaultEngine`] that operates
+/// on 64 bits integers.
+impl LweBootstrapKeyShardExtractionEngine<LweBootstrapKey64, LweBootstrapKey64> for DefaultEngine {
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextIndex, GlweDimension,
+    ///     LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey64 =
+    ///     engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// // The second half of the key
+    /// let shard: LweBootstrapKey64 =
+    ///     engine.extract_lwe_bootstrap_key_shard(&bsk, GgswCiphertextIndex(2), LweDimension(2))?;
+    /// #
+    /// assert_eq!(shard.input_lwe_dimension(), LweDimension(2));
+    /// assert_eq!(shard.glwe_dimension(), glwe_dim);
+    /// assert_eq!(shard.polynomial_size(), poly_size);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn extract_lwe_bootstrap_key_shard(
+        &mut self,
+        bootstrap_key: &LweBootstrapKey64,
+        first: GgswCiphertextIndex,
+        shard_size: LweDimension,
+    ) -> Result<LweBootstrapKey64, LweBootstrapKeyShardExtractionError<Self::EngineError>> {
+        LweBootstrapKeyShardExtractionError::perform_generic_checks(
+            bootstrap_key,
+            first,
+            shard_size,
+        )?;
+        Ok(unsafe {
+            self.extract_lwe_bootstrap_key_shard_unchecked(bootstrap_key, first, shard_size)
+        })
+    }
+
+    unsafe fn extract_lwe_bootstrap_key_shard_unchecked(
+        &mut self,
+        bootstrap_key: &LweBootstrapKey64,
+        first: GgswCiphertextIndex,
+        shard_size: LweDimension,
+    ) -> LweBootstrapKey64 {
+        LweBootstrapKey64(extract_shard(&bootstrap_key.0, first, shard_size))
+    }
+}
+
+fn extract_shard<Scalar: UnsignedTorus>(
+    bootstrap_key: &ImplStandardBootstrapKey<Vec<Scalar>>,
+    first: GgswCiphertextIndex,
+    shard_size: LweDimension,
+) -> ImplStandardBootstrapKey<Vec<Scalar>> {
+    let ggsw_size = bootstrap_key.glwe_size().0
+        * bootstrap_key.glwe_size().0
+        * bootstrap_key.polynomial_size().0
+        * bootstrap_key.level_count().0;
+    let shard = &bootstrap_key.as_tensor().as_slice()
+        [first.0 * ggsw_size..(first.0 + shard_size.0) * ggsw_size];
+    ImplStandardBootstrapKey::from_container(
+        shard.to_vec(),
+        bootstrap_key.glwe_size(),
+        bootstrap_key.polynomial_size(),
+        bootstrap_key.level_count(),
+        bootstrap_key.base_log(),
+    )
+}
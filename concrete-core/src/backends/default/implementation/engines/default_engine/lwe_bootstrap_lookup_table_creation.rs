@@ -0,0 +1,169 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    LweBootstrapLookupTable32, LweBootstrapLookupTable64,
+};
+use crate::commons::crypto::glwe::GlweCiphertext as ImplGlweCiphertext;
+use crate::specification::engines::{
+    LweBootstrapLookupTableCreationEngine, LweBootstrapLookupTableCreationError,
+};
+use concrete_commons::parameters::{GlweDimension, PolynomialSize, ScalingFactor};
+
+/// # Description:
+/// Implementation of [`LweBootstrapLookupTableCreationEngine`] for [`DefaultEngine`] that operates
+/// on 32 bits integers.
+impl LweBootstrapLookupTableCreationEngine<LweBootstrapLookupTable32> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{DeltaLog, GlweDimension, PolynomialSize, ScalingFactor};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// // The messages are encoded on 3 bits, with a padding bit
+    /// let input_scaling_factor = ScalingFactor::for_precision32(DeltaLog(28)).unwrap();
+    /// let output_scaling_factor = ScalingFactor::for_precision32(DeltaLog(28)).unwrap();
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lut: LweBootstrapLookupTable32 = engine.create_lwe_bootstrap_lookup_table(
+    ///     &|m| (m * m) % 8,
+    ///     glwe_dimension,
+    ///     polynomial_size,
+    ///     input_scaling_factor,
+    ///     output_scaling_factor,
+    /// )?;
+    /// #
+    /// assert_eq!(lut.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(lut.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_bootstrap_lookup_table(
+        &mut self,
+        function: &dyn Fn(u64) -> u64,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        input_scaling_factor: ScalingFactor,
+        output_scaling_factor: ScalingFactor,
+    ) -> Result<LweBootstrapLookupTable32, LweBootstrapLookupTableCreationError<Self::EngineError>>
+    {
+        LweBootstrapLookupTableCreationError::perform_generic_checks(
+            polynomial_size,
+            input_scaling_factor,
+            output_scaling_factor,
+            32,
+        )?;
+        Ok(unsafe {
+            self.create_lwe_bootstrap_lookup_table_unchecked(
+                function,
+                glwe_dimension,
+                polynomial_size,
+                input_scaling_factor,
+                output_scaling_factor,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_bootstrap_lookup_table_unchecked(
+        &mut self,
+        function: &dyn Fn(u64) -> u64,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        input_scaling_factor: ScalingFactor,
+        output_scaling_factor: ScalingFactor,
+    ) -> LweBootstrapLookupTable32 {
+        let mut lut =
+            ImplGlweCiphertext::allocate(0u32, polynomial_size, glwe_dimension.to_glwe_size());
+        lut.fill_with_lookup_table(
+            function,
+            input_scaling_factor.0 as u32,
+            output_scaling_factor.0 as u32,
+        );
+        LweBootstrapLookupTable32(lut)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapLookupTableCreationEngine`] for [`DefaultEngine`] that operates
+/// on 64 bits integers.
+impl LweBootstrapLookupTableCreationEngine<LweBootstrapLookupTable64> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{DeltaLog, GlweDimension, PolynomialSize, ScalingFactor};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// // The messages are encoded on 3 bits, with a padding bit
+    /// let input_scaling_factor = ScalingFactor::for_precision64(DeltaLog(60)).unwrap();
+    /// let output_scaling_factor = ScalingFactor::for_precision64(DeltaLog(60)).unwrap();
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lut: LweBootstrapLookupTable64 = engine.create_lwe_bootstrap_lookup_table(
+    ///     &|m| (m * m) % 8,
+    ///     glwe_dimension,
+    ///     polynomial_size,
+    ///     input_scaling_factor,
+    ///     output_scaling_factor,
+    /// )?;
+    /// #
+    /// assert_eq!(lut.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(lut.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_bootstrap_lookup_table(
+        &mut self,
+        function: &dyn Fn(u64) -> u64,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        input_scaling_factor: ScalingFactor,
+        output_scaling_factor: ScalingFactor,
+    ) -> Result<LweBootstrapLookupTable64, LweBootstrapLookupTableCreationError<Self::EngineError>>
+    {
+        LweBootstrapLookupTableCreationError::perform_generic_checks(
+            polynomial_size,
+            input_scaling_factor,
+            output_scaling_factor,
+            64,
+        )?;
+        Ok(unsafe {
+            self.create_lwe_bootstrap_lookup_table_unchecked(
+                function,
+                glwe_dimension,
+                polynomial_size,
+                input_scaling_factor,
+                output_scaling_factor,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_bootstrap_lookup_table_unchecked(
+        &mut self,
+        function: &dyn Fn(u64) -> u64,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        input_scaling_factor: ScalingFactor,
+        output_scaling_factor: ScalingFactor,
+    ) -> LweBootstrapLookupTable64 {
+        let mut lut =
+            ImplGlweCiphertext::allocate(0u64, polynomial_size, glwe_dimension.to_glwe_size());
+        lut.fill_with_lookup_table(function, input_scaling_factor.0, output_scaling_factor.0);
+        LweBootstrapLookupTable64(lut)
+    }
+}
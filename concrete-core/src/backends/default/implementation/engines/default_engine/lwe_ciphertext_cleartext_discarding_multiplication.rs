@@ -1,13 +1,79 @@
 use crate::backends::default::implementation::engines::DefaultEngine;
 use crate::backends::default::implementation::entities::{
-    Cleartext32, Cleartext64, LweCiphertext32, LweCiphertext64, LweCiphertextMutView32,
-    LweCiphertextMutView64, LweCiphertextView32, LweCiphertextView64,
+    Cleartext16, Cleartext32, Cleartext64, LweCiphertext16, LweCiphertext32, LweCiphertext64,
+    LweCiphertextMutView32, LweCiphertextMutView64, LweCiphertextView32, LweCiphertextView64,
 };
 use crate::specification::engines::{
     LweCiphertextCleartextDiscardingMultiplicationEngine,
     LweCiphertextCleartextDiscardingMultiplicationError,
 };
 
+/// # Description:
+/// Implementation of [`LweCiphertextCleartextDiscardingMultiplicationEngine`] for [`DefaultEngine`]
+/// that operates on 16 bits integers.
+impl
+    LweCiphertextCleartextDiscardingMultiplicationEngine<
+        LweCiphertext16,
+        Cleartext16,
+        LweCiphertext16,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 12 bits)
+    /// let input = 3_u16 << 12;
+    /// let cleartext_input = 12_u16;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let cleartext: Cleartext16 = engine.create_cleartext_from(&cleartext_input)?;
+    /// let key: LweSecretKey16 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let mut ciphertext_2 = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// engine.discard_mul_lwe_ciphertext_cleartext(&mut ciphertext_2, &ciphertext_1, &cleartext)?;
+    /// #
+    /// assert_eq!(ciphertext_2.lwe_dimension(), lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_mul_lwe_ciphertext_cleartext(
+        &mut self,
+        output: &mut LweCiphertext16,
+        input_1: &LweCiphertext16,
+        input_2: &Cleartext16,
+    ) -> Result<(), LweCiphertextCleartextDiscardingMultiplicationError<Self::EngineError>> {
+        LweCiphertextCleartextDiscardingMultiplicationError::perform_generic_checks(
+            output, input_1,
+        )?;
+        unsafe { self.discard_mul_lwe_ciphertext_cleartext_unchecked(output, input_1, input_2) };
+        Ok(())
+    }
+
+    unsafe fn discard_mul_lwe_ciphertext_cleartext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext16,
+        input_1: &LweCiphertext16,
+        input_2: &Cleartext16,
+    ) {
+        output.0.fill_with_scalar_mul(&input_1.0, &input_2.0);
+    }
+}
+
 /// # Description:
 /// Implementation of [`LweCiphertextCleartextDiscardingMultiplicationEngine`] for [`DefaultEngine`]
 /// that operates on 32 bits integers.
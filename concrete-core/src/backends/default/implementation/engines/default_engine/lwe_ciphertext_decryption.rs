@@ -1,11 +1,65 @@
 use crate::backends::default::implementation::engines::DefaultEngine;
 use crate::backends::default::implementation::entities::{
-    LweCiphertext32, LweCiphertext64, LweCiphertextView32, LweCiphertextView64, LweSecretKey32,
-    LweSecretKey64, Plaintext32, Plaintext64,
+    LweCiphertext16, LweCiphertext32, LweCiphertext64, LweCiphertextView32, LweCiphertextView64,
+    LweSecretKey16, LweSecretKey32, LweSecretKey64, LweSecretKeyGaussian32, LweSecretKeyGaussian64,
+    LweSecretKeyTernary32, LweSecretKeyTernary64, Plaintext16, Plaintext32, Plaintext64,
+    SolinasLweCiphertext64, SolinasLweSecretKey64,
 };
 use crate::commons::crypto::encoding::Plaintext as ImplPlaintext;
+use crate::commons::math::modular::SolinasPrime64;
 use crate::specification::engines::{LweCiphertextDecryptionEngine, LweCiphertextDecryptionError};
 
+/// # Description:
+/// Implementation of [`LweCiphertextDecryptionEngine`] for [`DefaultEngine`] that operates on
+/// 16 bits integers.
+impl LweCiphertextDecryptionEngine<LweSecretKey16, LweCiphertext16, Plaintext16> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 12 bits)
+    /// let input = 3_u16 << 12;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey16 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let decrypted_plaintext = engine.decrypt_lwe_ciphertext(&key, &ciphertext)?;
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext(
+        &mut self,
+        key: &LweSecretKey16,
+        input: &LweCiphertext16,
+    ) -> Result<Plaintext16, LweCiphertextDecryptionError<Self::EngineError>> {
+        Ok(unsafe { self.decrypt_lwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &LweSecretKey16,
+        input: &LweCiphertext16,
+    ) -> Plaintext16 {
+        let mut plaintext = ImplPlaintext(0u16);
+        key.0.decrypt_lwe(&mut plaintext, &input.0);
+        Plaintext16(plaintext)
+    }
+}
+
 /// # Description:
 /// Implementation of [`LweCiphertextDecryptionEngine`] for [`DefaultEngine`] that operates on
 /// 32 bits integers.
@@ -231,3 +285,286 @@ impl LweCiphertextDecryptionEngine<LweSecretKey64, LweCiphertextView64<'_>, Plai
         Plaintext64(plaintext)
     }
 }
+
+/// # Description:
+/// Implementation of [`LweCiphertextDecryptionEngine`] for [`DefaultEngine`] that operates on
+/// 32 bits integers.
+/// This implementation uses a ternary secret key.
+impl LweCiphertextDecryptionEngine<LweSecretKeyTernary32, LweCiphertext32, Plaintext32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKeyTernary32 = engine.generate_new_lwe_secret_key_ternary(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let decrypted_plaintext = engine.decrypt_lwe_ciphertext(&key, &ciphertext)?;
+    /// let decrypted = engine.retrieve_plaintext(&decrypted_plaintext)?;
+    /// assert_eq!(decrypted.wrapping_add(1 << 19) >> 20, 3);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext(
+        &mut self,
+        key: &LweSecretKeyTernary32,
+        input: &LweCiphertext32,
+    ) -> Result<Plaintext32, LweCiphertextDecryptionError<Self::EngineError>> {
+        Ok(unsafe { self.decrypt_lwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &LweSecretKeyTernary32,
+        input: &LweCiphertext32,
+    ) -> Plaintext32 {
+        let mut plaintext = ImplPlaintext(0u32);
+        key.0.decrypt_lwe(&mut plaintext, &input.0);
+        Plaintext32(plaintext)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDecryptionEngine`] for [`DefaultEngine`] that operates on
+/// 64 bits integers.
+/// This implementation uses a ternary secret key.
+impl LweCiphertextDecryptionEngine<LweSecretKeyTernary64, LweCiphertext64, Plaintext64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKeyTernary64 = engine.generate_new_lwe_secret_key_ternary(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let decrypted_plaintext = engine.decrypt_lwe_ciphertext(&key, &ciphertext)?;
+    /// let decrypted = engine.retrieve_plaintext(&decrypted_plaintext)?;
+    /// assert_eq!(decrypted.wrapping_add(1 << 49) >> 50, 3);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext(
+        &mut self,
+        key: &LweSecretKeyTernary64,
+        input: &LweCiphertext64,
+    ) -> Result<Plaintext64, LweCiphertextDecryptionError<Self::EngineError>> {
+        Ok(unsafe { self.decrypt_lwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &LweSecretKeyTernary64,
+        input: &LweCiphertext64,
+    ) -> Plaintext64 {
+        let mut plaintext = ImplPlaintext(0u64);
+        key.0.decrypt_lwe(&mut plaintext, &input.0);
+        Plaintext64(plaintext)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDecryptionEngine`] for [`DefaultEngine`] that operates on
+/// 32 bits integers.
+/// This implementation uses a gaussian secret key.
+impl LweCiphertextDecryptionEngine<LweSecretKeyGaussian32, LweCiphertext32, Plaintext32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKeyGaussian32 =
+    ///     engine.generate_new_lwe_secret_key_gaussian(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let decrypted_plaintext = engine.decrypt_lwe_ciphertext(&key, &ciphertext)?;
+    /// let decrypted = engine.retrieve_plaintext(&decrypted_plaintext)?;
+    /// assert_eq!(decrypted.wrapping_add(1 << 19) >> 20, 3);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext(
+        &mut self,
+        key: &LweSecretKeyGaussian32,
+        input: &LweCiphertext32,
+    ) -> Result<Plaintext32, LweCiphertextDecryptionError<Self::EngineError>> {
+        Ok(unsafe { self.decrypt_lwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &LweSecretKeyGaussian32,
+        input: &LweCiphertext32,
+    ) -> Plaintext32 {
+        let mut plaintext = ImplPlaintext(0u32);
+        key.0.decrypt_lwe(&mut plaintext, &input.0);
+        Plaintext32(plaintext)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDecryptionEngine`] for [`DefaultEngine`] that operates on
+/// 64 bits integers.
+/// This implementation uses a gaussian secret key.
+impl LweCiphertextDecryptionEngine<LweSecretKeyGaussian64, LweCiphertext64, Plaintext64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKeyGaussian64 =
+    ///     engine.generate_new_lwe_secret_key_gaussian(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let decrypted_plaintext = engine.decrypt_lwe_ciphertext(&key, &ciphertext)?;
+    /// let decrypted = engine.retrieve_plaintext(&decrypted_plaintext)?;
+    /// assert_eq!(decrypted.wrapping_add(1 << 49) >> 50, 3);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext(
+        &mut self,
+        key: &LweSecretKeyGaussian64,
+        input: &LweCiphertext64,
+    ) -> Result<Plaintext64, LweCiphertextDecryptionError<Self::EngineError>> {
+        Ok(unsafe { self.decrypt_lwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &LweSecretKeyGaussian64,
+        input: &LweCiphertext64,
+    ) -> Plaintext64 {
+        let mut plaintext = ImplPlaintext(0u64);
+        key.0.decrypt_lwe(&mut plaintext, &input.0);
+        Plaintext64(plaintext)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDecryptionEngine`] for [`DefaultEngine`] that operates on
+/// 64 bits integers, modulo the Solinas prime $q = 2^{64} - 2^{32} + 1$.
+impl LweCiphertextDecryptionEngine<SolinasLweSecretKey64, SolinasLweCiphertext64, Plaintext64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied, on 4 bits modulo q = 2^64 - 2^32 + 1
+    /// let delta = 0xFFFF_FFFF_0000_0001_u64 / 16;
+    /// let input = 3 * delta;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: SolinasLweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let decrypted_plaintext = engine.decrypt_lwe_ciphertext(&key, &ciphertext)?;
+    /// let decrypted = engine.retrieve_plaintext(&decrypted_plaintext)?;
+    /// assert_eq!((decrypted + delta / 2) / delta, 3);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext(
+        &mut self,
+        key: &SolinasLweSecretKey64,
+        input: &SolinasLweCiphertext64,
+    ) -> Result<Plaintext64, LweCiphertextDecryptionError<Self::EngineError>> {
+        Ok(unsafe { self.decrypt_lwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &SolinasLweSecretKey64,
+        input: &SolinasLweCiphertext64,
+    ) -> Plaintext64 {
+        let mut plaintext = ImplPlaintext(0u64);
+        key.0
+            .decrypt_lwe_modular(&mut plaintext, &input.0, &SolinasPrime64);
+        Plaintext64(plaintext)
+    }
+}
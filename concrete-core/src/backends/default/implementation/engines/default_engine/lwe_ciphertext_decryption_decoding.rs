@@ -0,0 +1,144 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    CleartextF64, FloatEncoder, LweCiphertext32, LweCiphertext64, LweSecretKey32, LweSecretKey64,
+};
+use crate::commons::crypto::encoding::Plaintext as ImplPlaintext;
+use crate::specification::engines::{
+    LweCiphertextDecryptionDecodingEngine, LweCiphertextDecryptionDecodingError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextDecryptionDecodingEngine`] for [`DefaultEngine`] that
+/// decrypts 32 bits integers and decodes them to 64 bits floating point numbers.
+impl
+    LweCiphertextDecryptionDecodingEngine<
+        LweSecretKey32,
+        FloatEncoder,
+        LweCiphertext32,
+        CleartextF64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let encoder = engine.create_encoder_from(&FloatEncoderMinMaxConfig {
+    ///     min: 0.,
+    ///     max: 10.,
+    ///     nb_bit_precision: 8,
+    ///     nb_bit_padding: 1,
+    /// })?;
+    /// let cleartext: CleartextF64 = engine.create_cleartext_from(&5.)?;
+    /// let plaintext: Plaintext32 = engine.encode_cleartext(&encoder, &cleartext)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let decrypted_cleartext: CleartextF64 =
+    ///     engine.decrypt_and_decode_lwe_ciphertext(&key, &encoder, &ciphertext)?;
+    /// let output: f64 = engine.retrieve_cleartext(&decrypted_cleartext)?;
+    /// assert!((output - 5.).abs() < 0.1);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_and_decode_lwe_ciphertext(
+        &mut self,
+        key: &LweSecretKey32,
+        encoder: &FloatEncoder,
+        input: &LweCiphertext32,
+    ) -> Result<CleartextF64, LweCiphertextDecryptionDecodingError<Self::EngineError>> {
+        LweCiphertextDecryptionDecodingError::perform_generic_checks(key, input)?;
+        Ok(unsafe { self.decrypt_and_decode_lwe_ciphertext_unchecked(key, encoder, input) })
+    }
+
+    unsafe fn decrypt_and_decode_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &LweSecretKey32,
+        encoder: &FloatEncoder,
+        input: &LweCiphertext32,
+    ) -> CleartextF64 {
+        let mut plaintext = ImplPlaintext(0u32);
+        key.0.decrypt_lwe(&mut plaintext, &input.0);
+        CleartextF64(self.decode_with_rounding_mode(&encoder.0, plaintext))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDecryptionDecodingEngine`] for [`DefaultEngine`] that
+/// decrypts 64 bits integers and decodes them to 64 bits floating point numbers.
+impl
+    LweCiphertextDecryptionDecodingEngine<
+        LweSecretKey64,
+        FloatEncoder,
+        LweCiphertext64,
+        CleartextF64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let encoder = engine.create_encoder_from(&FloatEncoderMinMaxConfig {
+    ///     min: 0.,
+    ///     max: 10.,
+    ///     nb_bit_precision: 8,
+    ///     nb_bit_padding: 1,
+    /// })?;
+    /// let cleartext: CleartextF64 = engine.create_cleartext_from(&5.)?;
+    /// let plaintext: Plaintext64 = engine.encode_cleartext(&encoder, &cleartext)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let decrypted_cleartext: CleartextF64 =
+    ///     engine.decrypt_and_decode_lwe_ciphertext(&key, &encoder, &ciphertext)?;
+    /// let output: f64 = engine.retrieve_cleartext(&decrypted_cleartext)?;
+    /// assert!((output - 5.).abs() < 0.1);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_and_decode_lwe_ciphertext(
+        &mut self,
+        key: &LweSecretKey64,
+        encoder: &FloatEncoder,
+        input: &LweCiphertext64,
+    ) -> Result<CleartextF64, LweCiphertextDecryptionDecodingError<Self::EngineError>> {
+        LweCiphertextDecryptionDecodingError::perform_generic_checks(key, input)?;
+        Ok(unsafe { self.decrypt_and_decode_lwe_ciphertext_unchecked(key, encoder, input) })
+    }
+
+    unsafe fn decrypt_and_decode_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &LweSecretKey64,
+        encoder: &FloatEncoder,
+        input: &LweCiphertext64,
+    ) -> CleartextF64 {
+        let mut plaintext = ImplPlaintext(0u64);
+        key.0.decrypt_lwe(&mut plaintext, &input.0);
+        CleartextF64(self.decode_with_rounding_mode(&encoder.0, plaintext))
+    }
+}
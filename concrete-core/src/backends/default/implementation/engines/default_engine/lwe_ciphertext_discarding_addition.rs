@@ -1,13 +1,77 @@
 use crate::backends::default::implementation::engines::DefaultEngine;
 use crate::backends::default::implementation::entities::{
-    LweCiphertext32, LweCiphertext64, LweCiphertextMutView32, LweCiphertextMutView64,
-    LweCiphertextView32, LweCiphertextView64,
+    LweCiphertext16, LweCiphertext32, LweCiphertext64, LweCiphertextMutView32,
+    LweCiphertextMutView64, LweCiphertextView32, LweCiphertextView64, SolinasLweCiphertext64,
 };
+use crate::commons::math::modular::SolinasPrime64;
 use crate::commons::math::tensor::{AsMutTensor, AsRefTensor};
 use crate::specification::engines::{
     LweCiphertextDiscardingAdditionEngine, LweCiphertextDiscardingAdditionError,
 };
 
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingAdditionEngine`] for [`DefaultEngine`] that operates
+/// on 16 bits integers.
+impl LweCiphertextDiscardingAdditionEngine<LweCiphertext16, LweCiphertext16> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 12 bits)
+    /// let input_1 = 3_u16 << 12;
+    /// let input_2 = 7_u16 << 12;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey16 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_1 = engine.create_plaintext_from(&input_1)?;
+    /// let plaintext_2 = engine.create_plaintext_from(&input_2)?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&key, &plaintext_1, noise)?;
+    /// let ciphertext_2 = engine.encrypt_lwe_ciphertext(&key, &plaintext_2, noise)?;
+    /// let mut ciphertext_3 = engine.zero_encrypt_lwe_ciphertext(&key, noise)?;
+    ///
+    /// engine.discard_add_lwe_ciphertext(&mut ciphertext_3, &ciphertext_1, &ciphertext_2)?;
+    /// #
+    /// assert_eq!(ciphertext_3.lwe_dimension(), lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_add_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext16,
+        input_1: &LweCiphertext16,
+        input_2: &LweCiphertext16,
+    ) -> Result<(), LweCiphertextDiscardingAdditionError<Self::EngineError>> {
+        LweCiphertextDiscardingAdditionError::perform_generic_checks(output, input_1, input_2)?;
+        unsafe { self.discard_add_lwe_ciphertext_unchecked(output, input_1, input_2) };
+        Ok(())
+    }
+
+    unsafe fn discard_add_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext16,
+        input_1: &LweCiphertext16,
+        input_2: &LweCiphertext16,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_copy(input_1.0.as_tensor());
+        output.0.update_with_add(&input_2.0);
+    }
+}
+
 /// # Description:
 /// Implementation of [`LweCiphertextDiscardingAdditionEngine`] for [`DefaultEngine`] that operates
 /// on 32 bits integers.
@@ -299,3 +363,88 @@ impl LweCiphertextDiscardingAdditionEngine<LweCiphertextView64<'_>, LweCiphertex
         output.0.update_with_add(&input_2.0);
     }
 }
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingAdditionEngine`] for [`DefaultEngine`] that operates
+/// on 64 bits integers, modulo the Solinas prime $q = 2^{64} - 2^{32} + 1$.
+impl LweCiphertextDiscardingAdditionEngine<SolinasLweCiphertext64, SolinasLweCiphertext64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied, on 4 bits modulo q = 2^64 - 2^32 + 1
+    /// let delta = 0xFFFF_FFFF_0000_0001_u64 / 16;
+    /// let input_1 = 3 * delta;
+    /// let input_2 = 7 * delta;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: SolinasLweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_1 = engine.create_plaintext_from(&input_1)?;
+    /// let plaintext_2 = engine.create_plaintext_from(&input_2)?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&key, &plaintext_1, noise)?;
+    /// let ciphertext_2 = engine.encrypt_lwe_ciphertext(&key, &plaintext_2, noise)?;
+    /// let mut ciphertext_3 = engine.zero_encrypt_lwe_ciphertext(&key, noise)?;
+    ///
+    /// engine.discard_add_lwe_ciphertext(&mut ciphertext_3, &ciphertext_1, &ciphertext_2)?;
+    /// #
+    /// assert_eq!(ciphertext_3.lwe_dimension(), lwe_dimension);
+    /// let decrypted_plaintext = engine.decrypt_lwe_ciphertext(&key, &ciphertext_3)?;
+    /// let decrypted = engine.retrieve_plaintext(&decrypted_plaintext)?;
+    /// assert_eq!((decrypted + delta / 2) / delta, 10);
+    ///
+    /// // Ciphertexts with the native modulus can not be mixed with the Solinas ones.
+    /// let native_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let native_ciphertext: LweCiphertext64 =
+    ///     engine.zero_encrypt_lwe_ciphertext(&native_key, noise)?;
+    /// let checks = LweCiphertextDiscardingAdditionError::<DefaultError>::perform_generic_checks(
+    ///     &ciphertext_3,
+    ///     &native_ciphertext,
+    ///     &native_ciphertext,
+    /// );
+    /// assert!(matches!(
+    ///     checks,
+    ///     Err(LweCiphertextDiscardingAdditionError::CiphertextModulusMismatch)
+    /// ));
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_add_lwe_ciphertext(
+        &mut self,
+        output: &mut SolinasLweCiphertext64,
+        input_1: &SolinasLweCiphertext64,
+        input_2: &SolinasLweCiphertext64,
+    ) -> Result<(), LweCiphertextDiscardingAdditionError<Self::EngineError>> {
+        LweCiphertextDiscardingAdditionError::perform_generic_checks(output, input_1, input_2)?;
+        unsafe { self.discard_add_lwe_ciphertext_unchecked(output, input_1, input_2) };
+        Ok(())
+    }
+
+    unsafe fn discard_add_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut SolinasLweCiphertext64,
+        input_1: &SolinasLweCiphertext64,
+        input_2: &SolinasLweCiphertext64,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_copy(input_1.0.as_tensor());
+        output
+            .0
+            .update_with_add_modular(&input_2.0, &SolinasPrime64);
+    }
+}
@@ -1,12 +1,95 @@
 use crate::backends::default::implementation::engines::DefaultEngine;
 use crate::backends::default::implementation::entities::{
-    LweCiphertext32, LweCiphertext64, LweCiphertextMutView32, LweCiphertextMutView64,
-    LweCiphertextView32, LweCiphertextView64, LweKeyswitchKey32, LweKeyswitchKey64,
+    LweCiphertext16, LweCiphertext32, LweCiphertext64, LweCiphertextMutView32,
+    LweCiphertextMutView64, LweCiphertextView32, LweCiphertextView64, LweKeyswitchKey16,
+    LweKeyswitchKey32, LweKeyswitchKey64, LweLevelMajorKeyswitchKey32, LweLevelMajorKeyswitchKey64,
+    SolinasLweCiphertext64, SolinasLweKeyswitchKey64,
 };
+use crate::commons::math::modular::SolinasPrime64;
 use crate::specification::engines::{
     LweCiphertextDiscardingKeyswitchEngine, LweCiphertextDiscardingKeyswitchError,
 };
 
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingKeyswitchEngine`] for [`DefaultEngine`] that operates
+/// on 16 bits integers.
+impl LweCiphertextDiscardingKeyswitchEngine<LweKeyswitchKey16, LweCiphertext16, LweCiphertext16>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(4);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 12 bits)
+    /// let input = 3_u16 << 12;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey16 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey16 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&input_key, &plaintext, noise)?;
+    /// let mut ciphertext_2 = engine.zero_encrypt_lwe_ciphertext(&output_key, noise)?;
+    ///
+    /// engine.discard_keyswitch_lwe_ciphertext(&mut ciphertext_2, &ciphertext_1, &keyswitch_key)?;
+    /// #
+    /// assert_eq!(ciphertext_2.lwe_dimension(), output_lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext16,
+        input: &LweCiphertext16,
+        ksk: &LweKeyswitchKey16,
+    ) -> Result<(), LweCiphertextDiscardingKeyswitchError<Self::EngineError>> {
+        LweCiphertextDiscardingKeyswitchError::perform_generic_checks(output, input, ksk)?;
+        check_key_fingerprint!(LweCiphertextDiscardingKeyswitchError, ksk.0, input.0);
+        unsafe { self.discard_keyswitch_lwe_ciphertext_unchecked(output, input, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext16,
+        input: &LweCiphertext16,
+        ksk: &LweKeyswitchKey16,
+    ) {
+        match self.rounding_mode {
+            None => ksk.0.keyswitch_ciphertext(&mut output.0, &input.0),
+            Some(rounding) => ksk.0.keyswitch_ciphertext_with_rounding(
+                &mut output.0,
+                &input.0,
+                rounding,
+                self.rounding_generator.as_mut(),
+            ),
+        }
+    }
+}
+
 /// # Description:
 /// Implementation of [`LweCiphertextDiscardingKeyswitchEngine`] for [`DefaultEngine`] that operates
 /// on 32 bits integers.
@@ -64,6 +147,7 @@ impl LweCiphertextDiscardingKeyswitchEngine<LweKeyswitchKey32, LweCiphertext32,
         ksk: &LweKeyswitchKey32,
     ) -> Result<(), LweCiphertextDiscardingKeyswitchError<Self::EngineError>> {
         LweCiphertextDiscardingKeyswitchError::perform_generic_checks(output, input, ksk)?;
+        check_key_fingerprint!(LweCiphertextDiscardingKeyswitchError, ksk.0, input.0);
         unsafe { self.discard_keyswitch_lwe_ciphertext_unchecked(output, input, ksk) };
         Ok(())
     }
@@ -74,7 +158,15 @@ impl LweCiphertextDiscardingKeyswitchEngine<LweKeyswitchKey32, LweCiphertext32,
         input: &LweCiphertext32,
         ksk: &LweKeyswitchKey32,
     ) {
-        ksk.0.keyswitch_ciphertext(&mut output.0, &input.0);
+        match self.rounding_mode {
+            None => ksk.0.keyswitch_ciphertext(&mut output.0, &input.0),
+            Some(rounding) => ksk.0.keyswitch_ciphertext_with_rounding(
+                &mut output.0,
+                &input.0,
+                rounding,
+                self.rounding_generator.as_mut(),
+            ),
+        }
     }
 }
 
@@ -135,6 +227,7 @@ impl LweCiphertextDiscardingKeyswitchEngine<LweKeyswitchKey64, LweCiphertext64,
         ksk: &LweKeyswitchKey64,
     ) -> Result<(), LweCiphertextDiscardingKeyswitchError<Self::EngineError>> {
         LweCiphertextDiscardingKeyswitchError::perform_generic_checks(output, input, ksk)?;
+        check_key_fingerprint!(LweCiphertextDiscardingKeyswitchError, ksk.0, input.0);
         unsafe { self.discard_keyswitch_lwe_ciphertext_unchecked(output, input, ksk) };
         Ok(())
     }
@@ -145,7 +238,15 @@ impl LweCiphertextDiscardingKeyswitchEngine<LweKeyswitchKey64, LweCiphertext64,
         input: &LweCiphertext64,
         ksk: &LweKeyswitchKey64,
     ) {
-        ksk.0.keyswitch_ciphertext(&mut output.0, &input.0);
+        match self.rounding_mode {
+            None => ksk.0.keyswitch_ciphertext(&mut output.0, &input.0),
+            Some(rounding) => ksk.0.keyswitch_ciphertext_with_rounding(
+                &mut output.0,
+                &input.0,
+                rounding,
+                self.rounding_generator.as_mut(),
+            ),
+        }
     }
 }
 
@@ -222,6 +323,7 @@ impl
         ksk: &LweKeyswitchKey32,
     ) -> Result<(), LweCiphertextDiscardingKeyswitchError<Self::EngineError>> {
         LweCiphertextDiscardingKeyswitchError::perform_generic_checks(output, input, ksk)?;
+        check_key_fingerprint!(LweCiphertextDiscardingKeyswitchError, ksk.0, input.0);
         unsafe { self.discard_keyswitch_lwe_ciphertext_unchecked(output, input, ksk) };
         Ok(())
     }
@@ -232,7 +334,15 @@ impl
         input: &LweCiphertextView32<'_>,
         ksk: &LweKeyswitchKey32,
     ) {
-        ksk.0.keyswitch_ciphertext(&mut output.0, &input.0);
+        match self.rounding_mode {
+            None => ksk.0.keyswitch_ciphertext(&mut output.0, &input.0),
+            Some(rounding) => ksk.0.keyswitch_ciphertext_with_rounding(
+                &mut output.0,
+                &input.0,
+                rounding,
+                self.rounding_generator.as_mut(),
+            ),
+        }
     }
 }
 
@@ -309,6 +419,7 @@ impl
         ksk: &LweKeyswitchKey64,
     ) -> Result<(), LweCiphertextDiscardingKeyswitchError<Self::EngineError>> {
         LweCiphertextDiscardingKeyswitchError::perform_generic_checks(output, input, ksk)?;
+        check_key_fingerprint!(LweCiphertextDiscardingKeyswitchError, ksk.0, input.0);
         unsafe { self.discard_keyswitch_lwe_ciphertext_unchecked(output, input, ksk) };
         Ok(())
     }
@@ -319,6 +430,277 @@ impl
         input: &LweCiphertextView64<'_>,
         ksk: &LweKeyswitchKey64,
     ) {
-        ksk.0.keyswitch_ciphertext(&mut output.0, &input.0);
+        match self.rounding_mode {
+            None => ksk.0.keyswitch_ciphertext(&mut output.0, &input.0),
+            Some(rounding) => ksk.0.keyswitch_ciphertext_with_rounding(
+                &mut output.0,
+                &input.0,
+                rounding,
+                self.rounding_generator.as_mut(),
+            ),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingKeyswitchEngine`] for [`DefaultEngine`] that operates
+/// on 32 bits integers, with a keyswitch key stored in level-major order.
+impl
+    LweCiphertextDiscardingKeyswitchEngine<
+        LweLevelMajorKeyswitchKey32,
+        LweCiphertext32,
+        LweCiphertext32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key: LweKeyswitchKey32 = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let level_major_keyswitch_key: LweLevelMajorKeyswitchKey32 =
+    ///     engine.convert_lwe_keyswitch_key(&keyswitch_key)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&input_key, &plaintext, noise)?;
+    /// let mut ciphertext_2 = engine.zero_encrypt_lwe_ciphertext(&output_key, noise)?;
+    ///
+    /// engine.discard_keyswitch_lwe_ciphertext(
+    ///     &mut ciphertext_2,
+    ///     &ciphertext_1,
+    ///     &level_major_keyswitch_key,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_2.lwe_dimension(), output_lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &LweCiphertext32,
+        ksk: &LweLevelMajorKeyswitchKey32,
+    ) -> Result<(), LweCiphertextDiscardingKeyswitchError<Self::EngineError>> {
+        LweCiphertextDiscardingKeyswitchError::perform_generic_checks(output, input, ksk)?;
+        check_key_fingerprint!(LweCiphertextDiscardingKeyswitchError, ksk.0, input.0);
+        unsafe { self.discard_keyswitch_lwe_ciphertext_unchecked(output, input, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &LweCiphertext32,
+        ksk: &LweLevelMajorKeyswitchKey32,
+    ) {
+        match self.rounding_mode {
+            None => ksk.0.keyswitch_ciphertext(&mut output.0, &input.0),
+            Some(rounding) => ksk.0.keyswitch_ciphertext_with_rounding(
+                &mut output.0,
+                &input.0,
+                rounding,
+                self.rounding_generator.as_mut(),
+            ),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingKeyswitchEngine`] for [`DefaultEngine`] that operates
+/// on 64 bits integers, with a keyswitch key stored in level-major order.
+impl
+    LweCiphertextDiscardingKeyswitchEngine<
+        LweLevelMajorKeyswitchKey64,
+        LweCiphertext64,
+        LweCiphertext64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key: LweKeyswitchKey64 = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let level_major_keyswitch_key: LweLevelMajorKeyswitchKey64 =
+    ///     engine.convert_lwe_keyswitch_key(&keyswitch_key)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&input_key, &plaintext, noise)?;
+    /// let mut ciphertext_2 = engine.zero_encrypt_lwe_ciphertext(&output_key, noise)?;
+    ///
+    /// engine.discard_keyswitch_lwe_ciphertext(
+    ///     &mut ciphertext_2,
+    ///     &ciphertext_1,
+    ///     &level_major_keyswitch_key,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_2.lwe_dimension(), output_lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &LweCiphertext64,
+        ksk: &LweLevelMajorKeyswitchKey64,
+    ) -> Result<(), LweCiphertextDiscardingKeyswitchError<Self::EngineError>> {
+        LweCiphertextDiscardingKeyswitchError::perform_generic_checks(output, input, ksk)?;
+        check_key_fingerprint!(LweCiphertextDiscardingKeyswitchError, ksk.0, input.0);
+        unsafe { self.discard_keyswitch_lwe_ciphertext_unchecked(output, input, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &LweCiphertext64,
+        ksk: &LweLevelMajorKeyswitchKey64,
+    ) {
+        match self.rounding_mode {
+            None => ksk.0.keyswitch_ciphertext(&mut output.0, &input.0),
+            Some(rounding) => ksk.0.keyswitch_ciphertext_with_rounding(
+                &mut output.0,
+                &input.0,
+                rounding,
+                self.rounding_generator.as_mut(),
+            ),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingKeyswitchEngine`] for [`DefaultEngine`] that operates
+/// on 64 bits integers, modulo the Solinas prime $q = 2^{64} - 2^{32} + 1$.
+impl
+    LweCiphertextDiscardingKeyswitchEngine<
+        SolinasLweKeyswitchKey64,
+        SolinasLweCiphertext64,
+        SolinasLweCiphertext64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // Here a hard-set encoding is applied, on 4 bits modulo q = 2^64 - 2^32 + 1
+    /// let delta = 0xFFFF_FFFF_0000_0001_u64 / 16;
+    /// let input = 3 * delta;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: SolinasLweSecretKey64 =
+    ///     engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: SolinasLweSecretKey64 =
+    ///     engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&input_key, &plaintext, noise)?;
+    /// let mut ciphertext_2 = engine.zero_encrypt_lwe_ciphertext(&output_key, noise)?;
+    ///
+    /// engine.discard_keyswitch_lwe_ciphertext(&mut ciphertext_2, &ciphertext_1, &keyswitch_key)?;
+    /// #
+    /// assert_eq!(ciphertext_2.lwe_dimension(), output_lwe_dimension);
+    /// let decrypted_plaintext = engine.decrypt_lwe_ciphertext(&output_key, &ciphertext_2)?;
+    /// let decrypted = engine.retrieve_plaintext(&decrypted_plaintext)?;
+    /// assert_eq!((decrypted + delta / 2) / delta, 3);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_lwe_ciphertext(
+        &mut self,
+        output: &mut SolinasLweCiphertext64,
+        input: &SolinasLweCiphertext64,
+        ksk: &SolinasLweKeyswitchKey64,
+    ) -> Result<(), LweCiphertextDiscardingKeyswitchError<Self::EngineError>> {
+        LweCiphertextDiscardingKeyswitchError::perform_generic_checks(output, input, ksk)?;
+        check_key_fingerprint!(LweCiphertextDiscardingKeyswitchError, ksk.0, input.0);
+        unsafe { self.discard_keyswitch_lwe_ciphertext_unchecked(output, input, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut SolinasLweCiphertext64,
+        input: &SolinasLweCiphertext64,
+        ksk: &SolinasLweKeyswitchKey64,
+    ) {
+        ksk.0
+            .keyswitch_ciphertext_modular(&mut output.0, &input.0, &SolinasPrime64);
     }
 }
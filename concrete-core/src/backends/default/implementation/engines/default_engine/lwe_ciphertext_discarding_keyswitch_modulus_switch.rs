@@ -0,0 +1,225 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    LweCiphertext32, LweCiphertext64, LweKeyswitchKey32, LweKeyswitchKey64,
+};
+use crate::specification::engines::{
+    LweCiphertextDiscardingKeyswitchEngine, LweCiphertextDiscardingKeyswitchModulusSwitchEngine,
+    LweCiphertextDiscardingKeyswitchModulusSwitchError,
+};
+use concrete_commons::parameters::CiphertextModulusLog;
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingKeyswitchModulusSwitchEngine`] for [`DefaultEngine`]
+/// that operates on 32 bits integers.
+impl
+    LweCiphertextDiscardingKeyswitchModulusSwitchEngine<
+        LweKeyswitchKey32,
+        LweCiphertext32,
+        LweCiphertext32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     CiphertextModulusLog, DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The output is prepared for a bootstrap with polynomials of size 1024.
+    /// let polynomial_size = PolynomialSize(1024);
+    /// let modulus_log = CiphertextModulusLog(polynomial_size.log2().0 + 1);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&input_key, &plaintext, noise)?;
+    /// let mut ciphertext_2 = engine.zero_encrypt_lwe_ciphertext(&output_key, noise)?;
+    ///
+    /// engine.discard_keyswitch_modulus_switch_lwe_ciphertext(
+    ///     &mut ciphertext_2,
+    ///     &ciphertext_1,
+    ///     &keyswitch_key,
+    ///     modulus_log,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_2.lwe_dimension(), output_lwe_dimension);
+    /// let elements = engine.consume_retrieve_lwe_ciphertext(ciphertext_2)?;
+    /// let step = 1_u32 << (32 - modulus_log.0);
+    /// assert!(elements.iter().all(|element| element % step == 0));
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_modulus_switch_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &LweCiphertext32,
+        ksk: &LweKeyswitchKey32,
+        modulus_log: CiphertextModulusLog,
+    ) -> Result<(), LweCiphertextDiscardingKeyswitchModulusSwitchError<Self::EngineError>> {
+        LweCiphertextDiscardingKeyswitchModulusSwitchError::perform_generic_checks(
+            output,
+            input,
+            ksk,
+            modulus_log,
+            CiphertextModulusLog(32),
+        )?;
+        check_key_fingerprint!(
+            LweCiphertextDiscardingKeyswitchModulusSwitchError,
+            ksk.0,
+            input.0
+        );
+        unsafe {
+            self.discard_keyswitch_modulus_switch_lwe_ciphertext_unchecked(
+                output,
+                input,
+                ksk,
+                modulus_log,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_modulus_switch_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &LweCiphertext32,
+        ksk: &LweKeyswitchKey32,
+        modulus_log: CiphertextModulusLog,
+    ) {
+        self.discard_keyswitch_lwe_ciphertext_unchecked(output, input, ksk);
+        output.0.round_to_modulus(modulus_log);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingKeyswitchModulusSwitchEngine`] for [`DefaultEngine`]
+/// that operates on 64 bits integers.
+impl
+    LweCiphertextDiscardingKeyswitchModulusSwitchEngine<
+        LweKeyswitchKey64,
+        LweCiphertext64,
+        LweCiphertext64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     CiphertextModulusLog, DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The output is prepared for a bootstrap with polynomials of size 1024.
+    /// let polynomial_size = PolynomialSize(1024);
+    /// let modulus_log = CiphertextModulusLog(polynomial_size.log2().0 + 1);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&input_key, &plaintext, noise)?;
+    /// let mut ciphertext_2 = engine.zero_encrypt_lwe_ciphertext(&output_key, noise)?;
+    ///
+    /// engine.discard_keyswitch_modulus_switch_lwe_ciphertext(
+    ///     &mut ciphertext_2,
+    ///     &ciphertext_1,
+    ///     &keyswitch_key,
+    ///     modulus_log,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_2.lwe_dimension(), output_lwe_dimension);
+    /// let elements = engine.consume_retrieve_lwe_ciphertext(ciphertext_2)?;
+    /// let step = 1_u64 << (64 - modulus_log.0);
+    /// assert!(elements.iter().all(|element| element % step == 0));
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_modulus_switch_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &LweCiphertext64,
+        ksk: &LweKeyswitchKey64,
+        modulus_log: CiphertextModulusLog,
+    ) -> Result<(), LweCiphertextDiscardingKeyswitchModulusSwitchError<Self::EngineError>> {
+        LweCiphertextDiscardingKeyswitchModulusSwitchError::perform_generic_checks(
+            output,
+            input,
+            ksk,
+            modulus_log,
+            CiphertextModulusLog(64),
+        )?;
+        check_key_fingerprint!(
+            LweCiphertextDiscardingKeyswitchModulusSwitchError,
+            ksk.0,
+            input.0
+        );
+        unsafe {
+            self.discard_keyswitch_modulus_switch_lwe_ciphertext_unchecked(
+                output,
+                input,
+                ksk,
+                modulus_log,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_modulus_switch_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &LweCiphertext64,
+        ksk: &LweKeyswitchKey64,
+        modulus_log: CiphertextModulusLog,
+    ) {
+        self.discard_keyswitch_lwe_ciphertext_unchecked(output, input, ksk);
+        output.0.round_to_modulus(modulus_log);
+    }
+}
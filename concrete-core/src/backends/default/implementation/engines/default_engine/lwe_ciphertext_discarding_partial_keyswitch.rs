@@ -0,0 +1,189 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    LweCiphertext32, LweCiphertext64, LwePartialKeyswitchKey32, LwePartialKeyswitchKey64,
+};
+use crate::specification::engines::{
+    LweCiphertextDiscardingPartialKeyswitchEngine, LweCiphertextDiscardingPartialKeyswitchError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingPartialKeyswitchEngine`] for [`DefaultEngine`] that
+/// operates on 32 bits integers.
+impl
+    LweCiphertextDiscardingPartialKeyswitchEngine<
+        LwePartialKeyswitchKey32,
+        LweCiphertext32,
+        LweCiphertext32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let switched_lwe_dimension = LweDimension(4);
+    /// let replacement_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // Here a hard-set encoding is applied (shift by 28 bits)
+    /// let input = 3_u32 << 28;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let replacement_key: LweSecretKey32 =
+    ///     engine.generate_new_lwe_secret_key(replacement_lwe_dimension)?;
+    /// let partial_keyswitch_key: LwePartialKeyswitchKey32 = engine
+    ///     .generate_new_lwe_partial_keyswitch_key(
+    ///         &input_key,
+    ///         &replacement_key,
+    ///         switched_lwe_dimension,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         noise,
+    ///     )?;
+    /// let output_key: LweSecretKey32 = engine.partially_keyswitch_lwe_secret_key(
+    ///     &input_key,
+    ///     &replacement_key,
+    ///     &partial_keyswitch_key,
+    /// )?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&input_key, &plaintext, noise)?;
+    /// let mut ciphertext_2 = engine.zero_encrypt_lwe_ciphertext(&output_key, noise)?;
+    ///
+    /// engine.discard_partial_keyswitch_lwe_ciphertext(
+    ///     &mut ciphertext_2,
+    ///     &ciphertext_1,
+    ///     &partial_keyswitch_key,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_2.lwe_dimension(), LweDimension(5));
+    /// let decrypted = engine.decrypt_lwe_ciphertext(&output_key, &ciphertext_2)?;
+    /// let output = engine.retrieve_plaintext(&decrypted)?;
+    /// assert_eq!(output.wrapping_add(1 << 27) >> 28, 3);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_partial_keyswitch_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &LweCiphertext32,
+        ksk: &LwePartialKeyswitchKey32,
+    ) -> Result<(), LweCiphertextDiscardingPartialKeyswitchError<Self::EngineError>> {
+        LweCiphertextDiscardingPartialKeyswitchError::perform_generic_checks(output, input, ksk)?;
+        unsafe { self.discard_partial_keyswitch_lwe_ciphertext_unchecked(output, input, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_partial_keyswitch_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &LweCiphertext32,
+        ksk: &LwePartialKeyswitchKey32,
+    ) {
+        ksk.0.partial_keyswitch_ciphertext(&mut output.0, &input.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingPartialKeyswitchEngine`] for [`DefaultEngine`] that
+/// operates on 64 bits integers.
+impl
+    LweCiphertextDiscardingPartialKeyswitchEngine<
+        LwePartialKeyswitchKey64,
+        LweCiphertext64,
+        LweCiphertext64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let switched_lwe_dimension = LweDimension(4);
+    /// let replacement_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // Here a hard-set encoding is applied (shift by 60 bits)
+    /// let input = 3_u64 << 60;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let replacement_key: LweSecretKey64 =
+    ///     engine.generate_new_lwe_secret_key(replacement_lwe_dimension)?;
+    /// let partial_keyswitch_key: LwePartialKeyswitchKey64 = engine
+    ///     .generate_new_lwe_partial_keyswitch_key(
+    ///         &input_key,
+    ///         &replacement_key,
+    ///         switched_lwe_dimension,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         noise,
+    ///     )?;
+    /// let output_key: LweSecretKey64 = engine.partially_keyswitch_lwe_secret_key(
+    ///     &input_key,
+    ///     &replacement_key,
+    ///     &partial_keyswitch_key,
+    /// )?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&input_key, &plaintext, noise)?;
+    /// let mut ciphertext_2 = engine.zero_encrypt_lwe_ciphertext(&output_key, noise)?;
+    ///
+    /// engine.discard_partial_keyswitch_lwe_ciphertext(
+    ///     &mut ciphertext_2,
+    ///     &ciphertext_1,
+    ///     &partial_keyswitch_key,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_2.lwe_dimension(), LweDimension(5));
+    /// let decrypted = engine.decrypt_lwe_ciphertext(&output_key, &ciphertext_2)?;
+    /// let output = engine.retrieve_plaintext(&decrypted)?;
+    /// assert_eq!(output.wrapping_add(1 << 59) >> 60, 3);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_partial_keyswitch_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &LweCiphertext64,
+        ksk: &LwePartialKeyswitchKey64,
+    ) -> Result<(), LweCiphertextDiscardingPartialKeyswitchError<Self::EngineError>> {
+        LweCiphertextDiscardingPartialKeyswitchError::perform_generic_checks(output, input, ksk)?;
+        unsafe { self.discard_partial_keyswitch_lwe_ciphertext_unchecked(output, input, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_partial_keyswitch_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &LweCiphertext64,
+        ksk: &LwePartialKeyswitchKey64,
+    ) {
+        ksk.0.partial_keyswitch_ciphertext(&mut output.0, &input.0);
+    }
+}
@@ -0,0 +1,160 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    Cleartext32, Cleartext64, GlweCiphertext32, GlweCiphertext64, LweCiphertext32, LweCiphertext64,
+};
+use crate::specification::engines::{
+    LweCiphertextDiscardingPolynomialEvaluationEngine,
+    LweCiphertextDiscardingPolynomialEvaluationError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingPolynomialEvaluationEngine`] for [`DefaultEngine`]
+/// that operates on 32 bits integers.
+impl
+    LweCiphertextDiscardingPolynomialEvaluationEngine<
+        GlweCiphertext32,
+        Cleartext32,
+        LweCiphertext32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, LweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // The output LWE is encrypted under the LWE key obtained from the GLWE key
+    /// let lwe_dimension = LweDimension(glwe_dimension.0 * polynomial_size.0);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![1_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let glwe_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let lwe_key: LweSecretKey32 =
+    ///     engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_key.clone())?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let glwe_ciphertext = engine.encrypt_glwe_ciphertext(&glwe_key, &plaintext_vector, noise)?;
+    /// let point = engine.create_cleartext_from(&2_u32)?;
+    /// let mut lwe_ciphertext = engine.zero_encrypt_lwe_ciphertext(&lwe_key, noise)?;
+    ///
+    /// // The output encrypts 1 + 2 + 4 + 8 = 15
+    /// engine.discard_evaluate_polynomial_lwe_ciphertext(
+    ///     &mut lwe_ciphertext,
+    ///     &glwe_ciphertext,
+    ///     &point,
+    /// )?;
+    /// #
+    /// assert_eq!(lwe_ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_evaluate_polynomial_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &GlweCiphertext32,
+        point: &Cleartext32,
+    ) -> Result<(), LweCiphertextDiscardingPolynomialEvaluationError<Self::EngineError>> {
+        LweCiphertextDiscardingPolynomialEvaluationError::perform_generic_checks(output, input)?;
+        unsafe { self.discard_evaluate_polynomial_lwe_ciphertext_unchecked(output, input, point) };
+        Ok(())
+    }
+
+    unsafe fn discard_evaluate_polynomial_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &GlweCiphertext32,
+        point: &Cleartext32,
+    ) {
+        input
+            .0
+            .fill_lwe_with_polynomial_evaluation(&mut output.0, point.0 .0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingPolynomialEvaluationEngine`] for [`DefaultEngine`]
+/// that operates on 64 bits integers.
+impl
+    LweCiphertextDiscardingPolynomialEvaluationEngine<
+        GlweCiphertext64,
+        Cleartext64,
+        LweCiphertext64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, LweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // The output LWE is encrypted under the LWE key obtained from the GLWE key
+    /// let lwe_dimension = LweDimension(glwe_dimension.0 * polynomial_size.0);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![1_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let glwe_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let lwe_key: LweSecretKey64 =
+    ///     engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_key.clone())?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let glwe_ciphertext = engine.encrypt_glwe_ciphertext(&glwe_key, &plaintext_vector, noise)?;
+    /// let point = engine.create_cleartext_from(&2_u64)?;
+    /// let mut lwe_ciphertext = engine.zero_encrypt_lwe_ciphertext(&lwe_key, noise)?;
+    ///
+    /// // The output encrypts 1 + 2 + 4 + 8 = 15
+    /// engine.discard_evaluate_polynomial_lwe_ciphertext(
+    ///     &mut lwe_ciphertext,
+    ///     &glwe_ciphertext,
+    ///     &point,
+    /// )?;
+    /// #
+    /// assert_eq!(lwe_ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_evaluate_polynomial_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &GlweCiphertext64,
+        point: &Cleartext64,
+    ) -> Result<(), LweCiphertextDiscardingPolynomialEvaluationError<Self::EngineError>> {
+        LweCiphertextDiscardingPolynomialEvaluationError::perform_generic_checks(output, input)?;
+        unsafe { self.discard_evaluate_polynomial_lwe_ciphertext_unchecked(output, input, point) };
+        Ok(())
+    }
+
+    unsafe fn discard_evaluate_polynomial_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &GlweCiphertext64,
+        point: &Cleartext64,
+    ) {
+        input
+            .0
+            .fill_lwe_with_polynomial_evaluation(&mut output.0, point.0 .0);
+    }
+}
@@ -1,10 +1,75 @@
 use crate::backends::default::implementation::engines::DefaultEngine;
-use crate::backends::default::implementation::entities::{LweCiphertext32, LweCiphertext64};
+use crate::backends::default::implementation::entities::{
+    LweCiphertext16, LweCiphertext32, LweCiphertext64,
+};
 use crate::commons::math::tensor::{AsMutTensor, AsRefTensor};
 use crate::specification::engines::{
     LweCiphertextDiscardingSubtractionEngine, LweCiphertextDiscardingSubtractionError,
 };
 
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingSubtractionEngine`] for [`DefaultEngine`] that
+/// operates on 16 bits integers.
+impl LweCiphertextDiscardingSubtractionEngine<LweCiphertext16, LweCiphertext16> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 12 bits)
+    /// let input_1 = 3_u16 << 12;
+    /// let input_2 = 7_u16 << 12;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey16 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_1 = engine.create_plaintext_from(&input_1)?;
+    /// let plaintext_2 = engine.create_plaintext_from(&input_2)?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&key, &plaintext_1, noise)?;
+    /// let ciphertext_2 = engine.encrypt_lwe_ciphertext(&key, &plaintext_2, noise)?;
+    /// let mut ciphertext_3 = engine.zero_encrypt_lwe_ciphertext(&key, noise)?;
+    ///
+    /// engine.discard_sub_lwe_ciphertext(&mut ciphertext_3, &ciphertext_1, &ciphertext_2)?;
+    /// #
+    /// assert_eq!(ciphertext_3.lwe_dimension(), lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_sub_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext16,
+        input_1: &LweCiphertext16,
+        input_2: &LweCiphertext16,
+    ) -> Result<(), LweCiphertextDiscardingSubtractionError<Self::EngineError>> {
+        LweCiphertextDiscardingSubtractionError::perform_generic_checks(output, input_1, input_2)?;
+        unsafe { self.discard_sub_lwe_ciphertext_unchecked(output, input_1, input_2) };
+        Ok(())
+    }
+
+    unsafe fn discard_sub_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext16,
+        input_1: &LweCiphertext16,
+        input_2: &LweCiphertext16,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_copy(input_1.0.as_tensor());
+        output.0.update_with_sub(&input_2.0);
+    }
+}
+
 /// # Description:
 /// Implementation of [`LweCiphertextDiscardingSubtractionEngine`] for [`DefaultEngine`] that
 /// operates on 32 bits integers.
@@ -1,9 +1,68 @@
 use crate::backends::default::implementation::engines::DefaultEngine;
-use crate::backends::default::implementation::entities::{LweCiphertext32, LweCiphertext64};
+use crate::backends::default::implementation::entities::{
+    LweCiphertext16, LweCiphertext32, LweCiphertext64, SolinasLweCiphertext64,
+};
+use crate::commons::math::modular::SolinasPrime64;
 use crate::specification::engines::{
     LweCiphertextFusingAdditionEngine, LweCiphertextFusingAdditionError,
 };
 
+/// # Description:
+/// Implementation of [`LweCiphertextFusingAdditionEngine`] for [`DefaultEngine`] that operates on
+/// 16 bits integers.
+impl LweCiphertextFusingAdditionEngine<LweCiphertext16, LweCiphertext16> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 12 bits)
+    /// let input_1 = 3_u16 << 12;
+    /// let input_2 = 5_u16 << 12;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey16 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_1 = engine.create_plaintext_from(&input_1)?;
+    /// let plaintext_2 = engine.create_plaintext_from(&input_2)?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&key, &plaintext_1, noise)?;
+    /// let mut ciphertext_2 = engine.encrypt_lwe_ciphertext(&key, &plaintext_2, noise)?;
+    ///
+    /// engine.fuse_add_lwe_ciphertext(&mut ciphertext_2, &ciphertext_1)?;
+    /// #
+    /// assert_eq!(ciphertext_2.lwe_dimension(), lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_add_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext16,
+        input: &LweCiphertext16,
+    ) -> Result<(), LweCiphertextFusingAdditionError<Self::EngineError>> {
+        LweCiphertextFusingAdditionError::perform_generic_checks(output, input)?;
+        unsafe { self.fuse_add_lwe_ciphertext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_add_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext16,
+        input: &LweCiphertext16,
+    ) {
+        output.0.update_with_add(&input.0);
+    }
+}
+
 /// # Description:
 /// Implementation of [`LweCiphertextFusingAdditionEngine`] for [`DefaultEngine`] that operates on
 /// 32 bits integers.
@@ -115,3 +174,66 @@ impl LweCiphertextFusingAdditionEngine<LweCiphertext64, LweCiphertext64> for Def
         output.0.update_with_add(&input.0);
     }
 }
+
+/// # Description:
+/// Implementation of [`LweCiphertextFusingAdditionEngine`] for [`DefaultEngine`] that operates on
+/// 64 bits integers, modulo the Solinas prime $q = 2^{64} - 2^{32} + 1$.
+impl LweCiphertextFusingAdditionEngine<SolinasLweCiphertext64, SolinasLweCiphertext64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied, on 4 bits modulo q = 2^64 - 2^32 + 1
+    /// let delta = 0xFFFF_FFFF_0000_0001_u64 / 16;
+    /// let input_1 = 3 * delta;
+    /// let input_2 = 15 * delta;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: SolinasLweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_1 = engine.create_plaintext_from(&input_1)?;
+    /// let plaintext_2 = engine.create_plaintext_from(&input_2)?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&key, &plaintext_1, noise)?;
+    /// let mut ciphertext_2 = engine.encrypt_lwe_ciphertext(&key, &plaintext_2, noise)?;
+    ///
+    /// engine.fuse_add_lwe_ciphertext(&mut ciphertext_2, &ciphertext_1)?;
+    /// #
+    /// assert_eq!(ciphertext_2.lwe_dimension(), lwe_dimension);
+    /// // The addition wraps around modulo q
+    /// let decrypted_plaintext = engine.decrypt_lwe_ciphertext(&key, &ciphertext_2)?;
+    /// let decrypted = engine.retrieve_plaintext(&decrypted_plaintext)?;
+    /// assert_eq!(((decrypted + delta / 2) / delta) % 16, 2);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_add_lwe_ciphertext(
+        &mut self,
+        output: &mut SolinasLweCiphertext64,
+        input: &SolinasLweCiphertext64,
+    ) -> Result<(), LweCiphertextFusingAdditionError<Self::EngineError>> {
+        LweCiphertextFusingAdditionError::perform_generic_checks(output, input)?;
+        unsafe { self.fuse_add_lwe_ciphertext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_add_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut SolinasLweCiphertext64,
+        input: &SolinasLweCiphertext64,
+    ) {
+        output.0.update_with_add_modular(&input.0, &SolinasPrime64);
+    }
+}
@@ -1,13 +1,76 @@
 use crate::backends::default::implementation::engines::DefaultEngine;
 use crate::backends::default::implementation::entities::{
-    LweCiphertext32, LweCiphertext64, LweCiphertextMutView32, LweCiphertextMutView64,
-    LweCiphertextView32, LweCiphertextView64, Plaintext32, Plaintext64,
+    LweCiphertext16, LweCiphertext32, LweCiphertext64, LweCiphertextMutView32,
+    LweCiphertextMutView64, LweCiphertextView32, LweCiphertextView64, Plaintext16, Plaintext32,
+    Plaintext64,
 };
 use crate::commons::math::tensor::{AsMutTensor, AsRefTensor};
 use crate::specification::engines::{
     LweCiphertextPlaintextDiscardingAdditionEngine, LweCiphertextPlaintextDiscardingAdditionError,
 };
 
+/// # Description:
+/// Implementation of [`LweCiphertextPlaintextDiscardingAdditionEngine`] for [`DefaultEngine`] that
+/// operates on 16 bits integers.
+impl LweCiphertextPlaintextDiscardingAdditionEngine<LweCiphertext16, Plaintext16, LweCiphertext16>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 12 bits)
+    /// let input = 3_u16 << 12;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey16 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let mut ciphertext_2 = engine.zero_encrypt_lwe_ciphertext(&key, noise)?;
+    ///
+    /// engine.discard_add_lwe_ciphertext_plaintext(&mut ciphertext_2, &ciphertext_1, &plaintext)?;
+    /// #
+    /// assert_eq!(ciphertext_2.lwe_dimension(), lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_add_lwe_ciphertext_plaintext(
+        &mut self,
+        output: &mut LweCiphertext16,
+        input_1: &LweCiphertext16,
+        input_2: &Plaintext16,
+    ) -> Result<(), LweCiphertextPlaintextDiscardingAdditionError<Self::EngineError>> {
+        LweCiphertextPlaintextDiscardingAdditionError::perform_generic_checks(output, input_1)?;
+        unsafe { self.discard_add_lwe_ciphertext_plaintext_unchecked(output, input_1, input_2) };
+        Ok(())
+    }
+
+    unsafe fn discard_add_lwe_ciphertext_plaintext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext16,
+        input_1: &LweCiphertext16,
+        input_2: &Plaintext16,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_copy(input_1.0.as_tensor());
+        output.0.get_mut_body().0 = output.0.get_body().0.wrapping_add(input_2.0 .0);
+    }
+}
+
 /// # Description:
 /// Implementation of [`LweCiphertextPlaintextDiscardingAdditionEngine`] for [`DefaultEngine`] that
 /// operates on 32 bits integers.
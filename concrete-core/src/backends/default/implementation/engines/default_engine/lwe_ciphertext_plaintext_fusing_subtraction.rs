@@ -1,11 +1,65 @@
 use crate::backends::default::implementation::engines::DefaultEngine;
 use crate::backends::default::implementation::entities::{
-    LweCiphertext32, LweCiphertext64, Plaintext32, Plaintext64,
+    LweCiphertext16, LweCiphertext32, LweCiphertext64, Plaintext16, Plaintext32, Plaintext64,
 };
 use crate::specification::engines::{
     LweCiphertextPlaintextFusingSubtractionEngine, LweCiphertextPlaintextFusingSubtractionError,
 };
 
+/// # Description:
+/// Implementation of [`LweCiphertextPlaintextFusingSubtractionEngine`] for [`DefaultEngine`] that
+/// operates on 16 bits integers.
+impl LweCiphertextPlaintextFusingSubtractionEngine<LweCiphertext16, Plaintext16> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 12 bits)
+    /// let input_1 = 3_u16 << 12;
+    /// let input_2 = 5_u16 << 12;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey16 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_1 = engine.create_plaintext_from(&input_1)?;
+    /// let plaintext_2 = engine.create_plaintext_from(&input_2)?;
+    /// let mut ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext_1, noise)?;
+    ///
+    /// engine.fuse_sub_lwe_ciphertext_plaintext(&mut ciphertext, &plaintext_2)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_sub_lwe_ciphertext_plaintext(
+        &mut self,
+        output: &mut LweCiphertext16,
+        input: &Plaintext16,
+    ) -> Result<(), LweCiphertextPlaintextFusingSubtractionError<Self::EngineError>> {
+        unsafe { self.fuse_sub_lwe_ciphertext_plaintext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_sub_lwe_ciphertext_plaintext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext16,
+        input: &Plaintext16,
+    ) {
+        output.0.get_mut_body().0 = output.0.get_body().0.wrapping_sub(input.0 .0);
+    }
+}
+
 /// # Description:
 /// Implementation of [`LweCiphertextPlaintextFusingSubtractionEngine`] for [`DefaultEngine`] that
 /// operates on 32 bits integers.
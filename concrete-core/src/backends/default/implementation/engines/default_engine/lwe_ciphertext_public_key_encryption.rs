@@ -0,0 +1,150 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    LweCiphertext32, LweCiphertext64, LwePublicKey32, LwePublicKey64, Plaintext32, Plaintext64,
+};
+use crate::commons::crypto::lwe::LweCiphertext as ImplLweCiphertext;
+use crate::specification::engines::{
+    LweCiphertextDiscardingPublicKeyEncryptionEngine, LweCiphertextPublicKeyEncryptionEngine,
+    LweCiphertextPublicKeyEncryptionError,
+};
+use crate::specification::entities::LwePublicKeyEntity;
+
+/// # Description:
+/// Implementation of [`LweCiphertextPublicKeyEncryptionEngine`] for [`DefaultEngine`] that
+/// operates on 32 bits integers.
+impl LweCiphertextPublicKeyEncryptionEngine<LwePublicKey32, Plaintext32, LweCiphertext32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweDimension, LwePublicKeyZeroEncryptionCount};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// let lwe_public_key_zero_encryption_count = LwePublicKeyZeroEncryptionCount(7);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let secret_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let public_key: LwePublicKey32 = engine.generate_new_lwe_public_key(
+    ///     &secret_key,
+    ///     noise,
+    ///     lwe_public_key_zero_encryption_count,
+    /// )?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// // The encryption only requires the public key.
+    /// let ciphertext: LweCiphertext32 =
+    ///     engine.encrypt_lwe_ciphertext_with_public_key(&public_key, &plaintext)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// // The ciphertext is decrypted with the secret key.
+    /// let decrypted = engine.decrypt_lwe_ciphertext(&secret_key, &ciphertext)?;
+    /// let output = engine.retrieve_plaintext(&decrypted)?;
+    /// assert_eq!(output.wrapping_add(1 << 19) >> 20, 3);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_ciphertext_with_public_key(
+        &mut self,
+        key: &LwePublicKey32,
+        input: &Plaintext32,
+    ) -> Result<LweCiphertext32, LweCiphertextPublicKeyEncryptionError<Self::EngineError>> {
+        Ok(unsafe { self.encrypt_lwe_ciphertext_with_public_key_unchecked(key, input) })
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_with_public_key_unchecked(
+        &mut self,
+        key: &LwePublicKey32,
+        input: &Plaintext32,
+    ) -> LweCiphertext32 {
+        let mut ciphertext = LweCiphertext32(ImplLweCiphertext::allocate(
+            0u32,
+            key.lwe_dimension().to_lwe_size(),
+        ));
+        self.discard_encrypt_lwe_ciphertext_with_public_key_unchecked(key, &mut ciphertext, input);
+        ciphertext
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextPublicKeyEncryptionEngine`] for [`DefaultEngine`] that
+/// operates on 64 bits integers.
+impl LweCiphertextPublicKeyEncryptionEngine<LwePublicKey64, Plaintext64, LweCiphertext64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweDimension, LwePublicKeyZeroEncryptionCount};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// let lwe_public_key_zero_encryption_count = LwePublicKeyZeroEncryptionCount(7);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let secret_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let public_key: LwePublicKey64 = engine.generate_new_lwe_public_key(
+    ///     &secret_key,
+    ///     noise,
+    ///     lwe_public_key_zero_encryption_count,
+    /// )?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// // The encryption only requires the public key.
+    /// let ciphertext: LweCiphertext64 =
+    ///     engine.encrypt_lwe_ciphertext_with_public_key(&public_key, &plaintext)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// // The ciphertext is decrypted with the secret key.
+    /// let decrypted = engine.decrypt_lwe_ciphertext(&secret_key, &ciphertext)?;
+    /// let output = engine.retrieve_plaintext(&decrypted)?;
+    /// assert_eq!(output.wrapping_add(1 << 49) >> 50, 3);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_ciphertext_with_public_key(
+        &mut self,
+        key: &LwePublicKey64,
+        input: &Plaintext64,
+    ) -> Result<LweCiphertext64, LweCiphertextPublicKeyEncryptionError<Self::EngineError>> {
+        Ok(unsafe { self.encrypt_lwe_ciphertext_with_public_key_unchecked(key, input) })
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_with_public_key_unchecked(
+        &mut self,
+        key: &LwePublicKey64,
+        input: &Plaintext64,
+    ) -> LweCiphertext64 {
+        let mut ciphertext = LweCiphertext64(ImplLweCiphertext::allocate(
+            0u64,
+            key.lwe_dimension().to_lwe_size(),
+        ));
+        self.discard_encrypt_lwe_ciphertext_with_public_key_unchecked(key, &mut ciphertext, input);
+        ciphertext
+    }
+}
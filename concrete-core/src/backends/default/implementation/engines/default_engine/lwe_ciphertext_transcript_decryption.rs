@@ -0,0 +1,189 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    LweCiphertext32, LweCiphertext64, LweSecretKey32, LweSecretKey64,
+};
+use crate::commons::crypto::lwe::LweCiphertext as ImplLweCiphertext;
+use crate::commons::crypto::secret::LweSecretKey as ImplLweSecretKey;
+use crate::commons::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::commons::math::torus::UnsignedTorus;
+use crate::prelude::DeltaLog;
+use crate::specification::engines::{
+    LweCiphertextTranscriptDecryptionEngine, LweCiphertextTranscriptDecryptionError,
+    LweDecryptionTranscript,
+};
+use concrete_commons::key_kinds::BinaryKeyKind;
+
+/// # Description:
+/// Implementation of [`LweCiphertextTranscriptDecryptionEngine`] for [`DefaultEngine`] that
+/// operates on 32 bits integers.
+impl LweCiphertextTranscriptDecryptionEngine<LweSecretKey32, LweCiphertext32, u32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{DeltaLog, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let transcript =
+    ///     engine.decrypt_lwe_ciphertext_with_transcript(&key, &ciphertext, DeltaLog(20))?;
+    /// #
+    /// assert_eq!(transcript.message, 3);
+    /// assert_eq!(transcript.rounded_phase, input);
+    /// assert_eq!(
+    ///     transcript.phase,
+    ///     transcript
+    ///         .rounded_phase
+    ///         .wrapping_add(transcript.rounding_error)
+    /// );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext_with_transcript(
+        &mut self,
+        key: &LweSecretKey32,
+        input: &LweCiphertext32,
+        delta_log: DeltaLog,
+    ) -> Result<
+        LweDecryptionTranscript<u32>,
+        LweCiphertextTranscriptDecryptionError<Self::EngineError>,
+    > {
+        LweCiphertextTranscriptDecryptionError::perform_generic_checks::<_, _, u32>(
+            key, input, delta_log,
+        )?;
+        Ok(unsafe { self.decrypt_lwe_ciphertext_with_transcript_unchecked(key, input, delta_log) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_with_transcript_unchecked(
+        &mut self,
+        key: &LweSecretKey32,
+        input: &LweCiphertext32,
+        delta_log: DeltaLog,
+    ) -> LweDecryptionTranscript<u32> {
+        decrypt_with_transcript(&key.0, &input.0, delta_log)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextTranscriptDecryptionEngine`] for [`DefaultEngine`] that
+/// operates on 64 bits integers.
+impl LweCiphertextTranscriptDecryptionEngine<LweSecretKey64, LweCiphertext64, u64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{DeltaLog, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let transcript =
+    ///     engine.decrypt_lwe_ciphertext_with_transcript(&key, &ciphertext, DeltaLog(50))?;
+    /// #
+    /// assert_eq!(transcript.message, 3);
+    /// assert_eq!(transcript.rounded_phase, input);
+    /// assert_eq!(
+    ///     transcript.phase,
+    ///     transcript
+    ///         .rounded_phase
+    ///         .wrapping_add(transcript.rounding_error)
+    /// );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext_with_transcript(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &LweCiphertext64,
+        delta_log: DeltaLog,
+    ) -> Result<
+        LweDecryptionTranscript<u64>,
+        LweCiphertextTranscriptDecryptionError<Self::EngineError>,
+    > {
+        LweCiphertextTranscriptDecryptionError::perform_generic_checks::<_, _, u64>(
+            key, input, delta_log,
+        )?;
+        Ok(unsafe { self.decrypt_lwe_ciphertext_with_transcript_unchecked(key, input, delta_log) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_with_transcript_unchecked(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &LweCiphertext64,
+        delta_log: DeltaLog,
+    ) -> LweDecryptionTranscript<u64> {
+        decrypt_with_transcript(&key.0, &input.0, delta_log)
+    }
+}
+
+fn decrypt_with_transcript<Scalar>(
+    key: &ImplLweSecretKey<BinaryKeyKind, Vec<Scalar>>,
+    input: &ImplLweCiphertext<Vec<Scalar>>,
+    delta_log: DeltaLog,
+) -> LweDecryptionTranscript<Scalar>
+where
+    Scalar: UnsignedTorus,
+{
+    let (body, mask) = input.get_body_and_mask();
+    let mask_key_dot_product = mask.compute_multisum(key);
+    let phase = body.0.wrapping_sub(mask_key_dot_product);
+
+    // The phase is rounded up when its bit of weight delta / 2 is set, in which case the carry
+    // is added to the message before it is reduced modulo q / delta.
+    let rounded_up = (phase >> (delta_log.0 - 1)) & Scalar::ONE == Scalar::ONE;
+    let message_mask = (Scalar::ONE << (Scalar::BITS - delta_log.0)).wrapping_sub(Scalar::ONE);
+    let carry = if rounded_up {
+        Scalar::ONE
+    } else {
+        Scalar::ZERO
+    };
+    let message = (phase >> delta_log.0).wrapping_add(carry) & message_mask;
+    let rounded_phase = message << delta_log.0;
+
+    LweDecryptionTranscript {
+        mask: mask.as_tensor().as_slice().to_vec(),
+        body: body.0,
+        mask_key_dot_product,
+        phase,
+        delta_log,
+        rounded_up,
+        rounded_phase,
+        rounding_error: phase.wrapping_sub(rounded_phase),
+        message,
+    }
+}
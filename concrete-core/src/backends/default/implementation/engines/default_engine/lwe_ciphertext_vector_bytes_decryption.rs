@@ -0,0 +1,198 @@
+use concrete_commons::parameters::PlaintextCount;
+
+use crate::backends::default::implementation::engines::{DefaultEngine, DefaultError};
+use crate::backends::default::implementation::entities::{
+    LweCiphertextVector32, LweCiphertextVector64, LweSecretKey32, LweSecretKey64, ModularEncoder,
+};
+use crate::commons::crypto::encoding::{Encoder, Plaintext, PlaintextList as ImplPlaintextList};
+use crate::commons::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::specification::engines::{
+    LweCiphertextVectorBytesDecryptionEngine, LweCiphertextVectorBytesDecryptionError,
+};
+use crate::specification::entities::LweCiphertextVectorEntity;
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorBytesDecryptionEngine`] for [`DefaultEngine`] that
+/// operates on 32 bits integers.
+///
+/// The encoder must be the [`ModularEncoder`] used at encryption time.
+impl LweCiphertextVectorBytesDecryptionEngine<LweSecretKey32, ModularEncoder, LweCiphertextVector32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // A 128 bits symmetric key, each byte of which is split in two messages modulo 16.
+    /// let symmetric_key: Vec<u8> = (0..16).map(|i| i * 17).collect();
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let encoder = engine.create_encoder_from(&ModularEncoderConfig {
+    ///     modulus: 16,
+    ///     nb_bit_padding: 1,
+    /// })?;
+    ///
+    /// let ciphertext_vector: LweCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector_from_bytes(&key, &encoder, &symmetric_key, noise)?;
+    ///
+    /// let decrypted = engine.decrypt_lwe_ciphertext_vector_to_bytes(&key, &encoder, &ciphertext_vector)?;
+    /// #
+    /// assert_eq!(decrypted, symmetric_key);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext_vector_to_bytes(
+        &mut self,
+        key: &LweSecretKey32,
+        encoder: &ModularEncoder,
+        input: &LweCiphertextVector32,
+    ) -> Result<Vec<u8>, LweCiphertextVectorBytesDecryptionError<Self::EngineError>> {
+        LweCiphertextVectorBytesDecryptionError::perform_generic_checks(key, input)?;
+        let digit_count =
+            encoder
+                .0
+                .byte_digit_count()
+                .ok_or(LweCiphertextVectorBytesDecryptionError::Engine(
+                    DefaultError::ModularEncoderInvalidByteModulus,
+                ))?;
+        if !encoder.0.fits_in_precision(32) {
+            return Err(LweCiphertextVectorBytesDecryptionError::Engine(
+                DefaultError::ModularEncoderModulusTooLarge,
+            ));
+        }
+        if !input.lwe_ciphertext_count().0.is_multiple_of(digit_count) {
+            return Err(LweCiphertextVectorBytesDecryptionError::Engine(
+                DefaultError::ModularEncoderByteCountMismatch,
+            ));
+        }
+        Ok(unsafe { self.decrypt_lwe_ciphertext_vector_to_bytes_unchecked(key, encoder, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_vector_to_bytes_unchecked(
+        &mut self,
+        key: &LweSecretKey32,
+        encoder: &ModularEncoder,
+        input: &LweCiphertextVector32,
+    ) -> Vec<u8> {
+        let mut plaintexts =
+            ImplPlaintextList::allocate(0u32, PlaintextCount(input.lwe_ciphertext_count().0));
+        key.0.decrypt_lwe_list(&mut plaintexts, &input.0);
+        let digit_count = encoder.0.byte_digit_count().unwrap();
+        let digit_bits = 8 / digit_count;
+        plaintexts
+            .as_tensor()
+            .as_slice()
+            .chunks(digit_count)
+            .map(|digits| {
+                digits.iter().enumerate().fold(0_u8, |byte, (i, digit)| {
+                    byte | ((encoder.0.decode(Plaintext(*digit)).0 as u8) << (i * digit_bits))
+                })
+            })
+            .collect()
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorBytesDecryptionEngine`] for [`DefaultEngine`] that
+/// operates on 64 bits integers.
+///
+/// The encoder must be the [`ModularEncoder`] used at encryption time.
+impl LweCiphertextVectorBytesDecryptionEngine<LweSecretKey64, ModularEncoder, LweCiphertextVector64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // A 128 bits symmetric key, each byte of which is split in two messages modulo 16.
+    /// let symmetric_key: Vec<u8> = (0..16).map(|i| i * 17).collect();
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let encoder = engine.create_encoder_from(&ModularEncoderConfig {
+    ///     modulus: 16,
+    ///     nb_bit_padding: 1,
+    /// })?;
+    ///
+    /// let ciphertext_vector: LweCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector_from_bytes(&key, &encoder, &symmetric_key, noise)?;
+    ///
+    /// let decrypted = engine.decrypt_lwe_ciphertext_vector_to_bytes(&key, &encoder, &ciphertext_vector)?;
+    /// #
+    /// assert_eq!(decrypted, symmetric_key);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext_vector_to_bytes(
+        &mut self,
+        key: &LweSecretKey64,
+        encoder: &ModularEncoder,
+        input: &LweCiphertextVector64,
+    ) -> Result<Vec<u8>, LweCiphertextVectorBytesDecryptionError<Self::EngineError>> {
+        LweCiphertextVectorBytesDecryptionError::perform_generic_checks(key, input)?;
+        let digit_count =
+            encoder
+                .0
+                .byte_digit_count()
+                .ok_or(LweCiphertextVectorBytesDecryptionError::Engine(
+                    DefaultError::ModularEncoderInvalidByteModulus,
+                ))?;
+        if !encoder.0.fits_in_precision(64) {
+            return Err(LweCiphertextVectorBytesDecryptionError::Engine(
+                DefaultError::ModularEncoderModulusTooLarge,
+            ));
+        }
+        if !input.lwe_ciphertext_count().0.is_multiple_of(digit_count) {
+            return Err(LweCiphertextVectorBytesDecryptionError::Engine(
+                DefaultError::ModularEncoderByteCountMismatch,
+            ));
+        }
+        Ok(unsafe { self.decrypt_lwe_ciphertext_vector_to_bytes_unchecked(key, encoder, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_vector_to_bytes_unchecked(
+        &mut self,
+        key: &LweSecretKey64,
+        encoder: &ModularEncoder,
+        input: &LweCiphertextVector64,
+    ) -> Vec<u8> {
+        let mut plaintexts =
+            ImplPlaintextList::allocate(0u64, PlaintextCount(input.lwe_ciphertext_count().0));
+        key.0.decrypt_lwe_list(&mut plaintexts, &input.0);
+        let digit_count = encoder.0.byte_digit_count().unwrap();
+        let digit_bits = 8 / digit_count;
+        plaintexts
+            .as_tensor()
+            .as_slice()
+            .chunks(digit_count)
+            .map(|digits| {
+                digits.iter().enumerate().fold(0_u8, |byte, (i, digit)| {
+                    byte | ((encoder.0.decode(Plaintext(*digit)).0 as u8) << (i * digit_bits))
+                })
+            })
+            .collect()
+    }
+}
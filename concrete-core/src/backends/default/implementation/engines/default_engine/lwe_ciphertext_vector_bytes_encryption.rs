@@ -0,0 +1,227 @@
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::CiphertextCount;
+
+use crate::backends::default::implementation::engines::{DefaultEngine, DefaultError};
+use crate::backends::default::implementation::entities::{
+    LweCiphertextVector32, LweCiphertextVector64, LweSecretKey32, LweSecretKey64, ModularEncoder,
+};
+use crate::commons::crypto::encoding::{Cleartext, Encoder, PlaintextList as ImplPlaintextList};
+use crate::commons::crypto::lwe::LweList as ImplLweList;
+use crate::specification::engines::{
+    LweCiphertextVectorBytesEncryptionEngine, LweCiphertextVectorBytesEncryptionError,
+};
+use crate::specification::entities::LweSecretKeyEntity;
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorBytesEncryptionEngine`] for [`DefaultEngine`] that
+/// operates on 32 bits integers.
+///
+/// The encoder must be a [`ModularEncoder`] whose modulus is 2, 4, 16 or 256, so that each byte
+/// is split in 8, 4, 2 or 1 messages, stored least significant first. The encryption fails with
+/// [`DefaultError::ModularEncoderNoiseTooLarge`] if the noise does not guarantee the exact
+/// decryption of the messages. The output vector, as well as the encoder, can be serialized with
+/// the `DefaultSerializationEngine` to be sent along with the symmetrically encrypted data.
+impl LweCiphertextVectorBytesEncryptionEngine<LweSecretKey32, ModularEncoder, LweCiphertextVector32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // A 128 bits symmetric key, each byte of which is split in two messages modulo 16.
+    /// let symmetric_key: Vec<u8> = (0..16).map(|i| i * 17).collect();
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let encoder = engine.create_encoder_from(&ModularEncoderConfig {
+    ///     modulus: 16,
+    ///     nb_bit_padding: 1,
+    /// })?;
+    ///
+    /// let ciphertext_vector: LweCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector_from_bytes(&key, &encoder, &symmetric_key, noise)?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(ciphertext_vector.lwe_ciphertext_count(), LweCiphertextCount(32));
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_ciphertext_vector_from_bytes(
+        &mut self,
+        key: &LweSecretKey32,
+        encoder: &ModularEncoder,
+        input: &[u8],
+        noise: Variance,
+    ) -> Result<LweCiphertextVector32, LweCiphertextVectorBytesEncryptionError<Self::EngineError>>
+    {
+        LweCiphertextVectorBytesEncryptionError::perform_generic_checks(input)?;
+        if encoder.0.byte_digit_count().is_none() {
+            return Err(LweCiphertextVectorBytesEncryptionError::Engine(
+                DefaultError::ModularEncoderInvalidByteModulus,
+            ));
+        }
+        if !encoder.0.fits_in_precision(32) {
+            return Err(LweCiphertextVectorBytesEncryptionError::Engine(
+                DefaultError::ModularEncoderModulusTooLarge,
+            ));
+        }
+        if !encoder.0.guarantees_exact_decoding(32, noise) {
+            return Err(LweCiphertextVectorBytesEncryptionError::Engine(
+                DefaultError::ModularEncoderNoiseTooLarge,
+            ));
+        }
+        Ok(unsafe {
+            self.encrypt_lwe_ciphertext_vector_from_bytes_unchecked(key, encoder, input, noise)
+        })
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_vector_from_bytes_unchecked(
+        &mut self,
+        key: &LweSecretKey32,
+        encoder: &ModularEncoder,
+        input: &[u8],
+        noise: Variance,
+    ) -> LweCiphertextVector32 {
+        let digit_count = encoder.0.byte_digit_count().unwrap();
+        let digit_bits = 8 / digit_count;
+        let digit_mask = encoder.0.modulus as u32 - 1;
+        let plaintexts: Vec<u32> = input
+            .iter()
+            .flat_map(|byte| {
+                (0..digit_count).map(move |i| (u32::from(*byte) >> (i * digit_bits)) & digit_mask)
+            })
+            .map(|digit| encoder.0.encode(Cleartext(digit)).0)
+            .collect();
+        let plaintexts = ImplPlaintextList::from_container(plaintexts);
+        let mut vector = ImplLweList::allocate(
+            0u32,
+            key.lwe_dimension().to_lwe_size(),
+            CiphertextCount(plaintexts.count().0),
+        );
+        key.0.encrypt_lwe_list(
+            &mut vector,
+            &plaintexts,
+            noise,
+            &mut self.encryption_generator,
+        );
+        LweCiphertextVector32(vector)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorBytesEncryptionEngine`] for [`DefaultEngine`] that
+/// operates on 64 bits integers.
+///
+/// The encoder must be a [`ModularEncoder`] whose modulus is 2, 4, 16 or 256, so that each byte
+/// is split in 8, 4, 2 or 1 messages, stored least significant first. The encryption fails with
+/// [`DefaultError::ModularEncoderNoiseTooLarge`] if the noise does not guarantee the exact
+/// decryption of the messages. The output vector, as well as the encoder, can be serialized with
+/// the `DefaultSerializationEngine` to be sent along with the symmetrically encrypted data.
+impl LweCiphertextVectorBytesEncryptionEngine<LweSecretKey64, ModularEncoder, LweCiphertextVector64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // A 128 bits symmetric key, each byte of which is split in two messages modulo 16.
+    /// let symmetric_key: Vec<u8> = (0..16).map(|i| i * 17).collect();
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let encoder = engine.create_encoder_from(&ModularEncoderConfig {
+    ///     modulus: 16,
+    ///     nb_bit_padding: 1,
+    /// })?;
+    ///
+    /// let ciphertext_vector: LweCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector_from_bytes(&key, &encoder, &symmetric_key, noise)?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(ciphertext_vector.lwe_ciphertext_count(), LweCiphertextCount(32));
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_ciphertext_vector_from_bytes(
+        &mut self,
+        key: &LweSecretKey64,
+        encoder: &ModularEncoder,
+        input: &[u8],
+        noise: Variance,
+    ) -> Result<LweCiphertextVector64, LweCiphertextVectorBytesEncryptionError<Self::EngineError>>
+    {
+        LweCiphertextVectorBytesEncryptionError::perform_generic_checks(input)?;
+        if encoder.0.byte_digit_count().is_none() {
+            return Err(LweCiphertextVectorBytesEncryptionError::Engine(
+                DefaultError::ModularEncoderInvalidByteModulus,
+            ));
+        }
+        if !encoder.0.fits_in_precision(64) {
+            return Err(LweCiphertextVectorBytesEncryptionError::Engine(
+                DefaultError::ModularEncoderModulusTooLarge,
+            ));
+        }
+        if !encoder.0.guarantees_exact_decoding(64, noise) {
+            return Err(LweCiphertextVectorBytesEncryptionError::Engine(
+                DefaultError::ModularEncoderNoiseTooLarge,
+            ));
+        }
+        Ok(unsafe {
+            self.encrypt_lwe_ciphertext_vector_from_bytes_unchecked(key, encoder, input, noise)
+        })
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_vector_from_bytes_unchecked(
+        &mut self,
+        key: &LweSecretKey64,
+        encoder: &ModularEncoder,
+        input: &[u8],
+        noise: Variance,
+    ) -> LweCiphertextVector64 {
+        let digit_count = encoder.0.byte_digit_count().unwrap();
+        let digit_bits = 8 / digit_count;
+        let digit_mask = encoder.0.modulus - 1;
+        let plaintexts: Vec<u64> = input
+            .iter()
+            .flat_map(|byte| {
+                (0..digit_count).map(move |i| (u64::from(*byte) >> (i * digit_bits)) & digit_mask)
+            })
+            .map(|digit| encoder.0.encode(Cleartext(digit)).0)
+            .collect();
+        let plaintexts = ImplPlaintextList::from_container(plaintexts);
+        let mut vector = ImplLweList::allocate(
+            0u64,
+            key.lwe_dimension().to_lwe_size(),
+            CiphertextCount(plaintexts.count().0),
+        );
+        key.0.encrypt_lwe_list(
+            &mut vector,
+            &plaintexts,
+            noise,
+            &mut self.encryption_generator,
+        );
+        LweCiphertextVector64(vector)
+    }
+}
@@ -0,0 +1,183 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    CleartextVectorF64, FloatEncoderVector, LweCiphertextVector32, LweCiphertextVector64,
+    LweSecretKey32, LweSecretKey64,
+};
+use crate::commons::crypto::encoding::{CleartextList, Plaintext as ImplPlaintext};
+use crate::specification::engines::{
+    LweCiphertextVectorDecryptionDecodingEngine, LweCiphertextVectorDecryptionDecodingError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDecryptionDecodingEngine`] for [`DefaultEngine`] that
+/// decrypts 32 bits integers and decodes them to 64 bits floating point numbers.
+///
+/// The ciphertexts are decoded as soon as they are decrypted, without allocating an intermediate
+/// plaintext vector.
+impl
+    LweCiphertextVectorDecryptionDecodingEngine<
+        LweSecretKey32,
+        FloatEncoderVector,
+        LweCiphertextVector32,
+        CleartextVectorF64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let encoder_vector = engine.create_encoder_vector_from(&vec![
+    ///     FloatEncoderMinMaxConfig {
+    ///         min: 0.,
+    ///         max: 10.,
+    ///         nb_bit_precision: 8,
+    ///         nb_bit_padding: 1,
+    ///     };
+    ///     3
+    /// ])?;
+    /// let cleartext_vector: CleartextVectorF64 =
+    ///     engine.create_cleartext_vector_from(&vec![5.; 3])?;
+    /// let plaintext_vector: PlaintextVector32 =
+    ///     engine.encode_cleartext_vector(&encoder_vector, &cleartext_vector)?;
+    /// let ciphertext_vector: LweCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let decrypted_cleartext_vector: CleartextVectorF64 = engine
+    ///     .decrypt_and_decode_lwe_ciphertext_vector(&key, &encoder_vector, &ciphertext_vector)?;
+    /// let output: Vec<f64> = engine.retrieve_cleartext_vector(&decrypted_cleartext_vector)?;
+    /// assert!(output.iter().all(|x| (x - 5.).abs() < 0.1));
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_and_decode_lwe_ciphertext_vector(
+        &mut self,
+        key: &LweSecretKey32,
+        encoder: &FloatEncoderVector,
+        input: &LweCiphertextVector32,
+    ) -> Result<CleartextVectorF64, LweCiphertextVectorDecryptionDecodingError<Self::EngineError>>
+    {
+        LweCiphertextVectorDecryptionDecodingError::perform_generic_checks(key, encoder, input)?;
+        Ok(unsafe { self.decrypt_and_decode_lwe_ciphertext_vector_unchecked(key, encoder, input) })
+    }
+
+    unsafe fn decrypt_and_decode_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &LweSecretKey32,
+        encoder: &FloatEncoderVector,
+        input: &LweCiphertextVector32,
+    ) -> CleartextVectorF64 {
+        CleartextVectorF64(CleartextList::from_container(
+            encoder
+                .0
+                .iter()
+                .zip(input.0.ciphertext_iter())
+                .map(|(enc, ciphertext)| {
+                    let mut plaintext = ImplPlaintext(0u32);
+                    key.0.decrypt_lwe(&mut plaintext, &ciphertext);
+                    self.decode_with_rounding_mode(enc, plaintext).0
+                })
+                .collect::<Vec<_>>(),
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDecryptionDecodingEngine`] for [`DefaultEngine`] that
+/// decrypts 64 bits integers and decodes them to 64 bits floating point numbers.
+///
+/// The ciphertexts are decoded as soon as they are decrypted, without allocating an intermediate
+/// plaintext vector.
+impl
+    LweCiphertextVectorDecryptionDecodingEngine<
+        LweSecretKey64,
+        FloatEncoderVector,
+        LweCiphertextVector64,
+        CleartextVectorF64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let encoder_vector = engine.create_encoder_vector_from(&vec![
+    ///     FloatEncoderMinMaxConfig {
+    ///         min: 0.,
+    ///         max: 10.,
+    ///         nb_bit_precision: 8,
+    ///         nb_bit_padding: 1,
+    ///     };
+    ///     3
+    /// ])?;
+    /// let cleartext_vector: CleartextVectorF64 =
+    ///     engine.create_cleartext_vector_from(&vec![5.; 3])?;
+    /// let plaintext_vector: PlaintextVector64 =
+    ///     engine.encode_cleartext_vector(&encoder_vector, &cleartext_vector)?;
+    /// let ciphertext_vector: LweCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let decrypted_cleartext_vector: CleartextVectorF64 = engine
+    ///     .decrypt_and_decode_lwe_ciphertext_vector(&key, &encoder_vector, &ciphertext_vector)?;
+    /// let output: Vec<f64> = engine.retrieve_cleartext_vector(&decrypted_cleartext_vector)?;
+    /// assert!(output.iter().all(|x| (x - 5.).abs() < 0.1));
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_and_decode_lwe_ciphertext_vector(
+        &mut self,
+        key: &LweSecretKey64,
+        encoder: &FloatEncoderVector,
+        input: &LweCiphertextVector64,
+    ) -> Result<CleartextVectorF64, LweCiphertextVectorDecryptionDecodingError<Self::EngineError>>
+    {
+        LweCiphertextVectorDecryptionDecodingError::perform_generic_checks(key, encoder, input)?;
+        Ok(unsafe { self.decrypt_and_decode_lwe_ciphertext_vector_unchecked(key, encoder, input) })
+    }
+
+    unsafe fn decrypt_and_decode_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &LweSecretKey64,
+        encoder: &FloatEncoderVector,
+        input: &LweCiphertextVector64,
+    ) -> CleartextVectorF64 {
+        CleartextVectorF64(CleartextList::from_container(
+            encoder
+                .0
+                .iter()
+                .zip(input.0.ciphertext_iter())
+                .map(|(enc, ciphertext)| {
+                    let mut plaintext = ImplPlaintext(0u64);
+                    key.0.decrypt_lwe(&mut plaintext, &ciphertext);
+                    self.decode_with_rounding_mode(enc, plaintext).0
+                })
+                .collect::<Vec<_>>(),
+        ))
+    }
+}
@@ -0,0 +1,273 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    CleartextVector32, CleartextVector64, LweCiphertext32, LweCiphertext64, LweCiphertextVector32,
+    LweCiphertextVector64, LweKeyswitchKey32, LweKeyswitchKey64, Plaintext32, Plaintext64,
+};
+use crate::specification::engines::{
+    LweCiphertextVectorDiscardingAffineTransformationKeyswitchEngine,
+    LweCiphertextVectorDiscardingAffineTransformationKeyswitchError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingAffineTransformationKeyswitchEngine`] for
+/// [`DefaultEngine`] that operates on 32 bits integers.
+impl
+    LweCiphertextVectorDiscardingAffineTransformationKeyswitchEngine<
+        LweCiphertextVector32,
+        CleartextVector32,
+        Plaintext32,
+        LweKeyswitchKey32,
+        LweCiphertext32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input_vector = vec![3_u32 << 20; 8];
+    /// let weights_input = vec![2_u32; 8];
+    /// let bias_input = 8_u32 << 20;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let weights: CleartextVector32 = engine.create_cleartext_vector_from(&weights_input)?;
+    /// let bias: Plaintext32 = engine.create_plaintext_from(&bias_input)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input_vector)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_lwe_ciphertext_vector(&input_key, &plaintext_vector, noise)?;
+    /// let mut output_ciphertext = engine.zero_encrypt_lwe_ciphertext(&output_key, noise)?;
+    ///
+    /// engine.discard_affine_transform_keyswitch_lwe_ciphertext_vector(
+    ///     &mut output_ciphertext,
+    ///     &ciphertext_vector,
+    ///     &weights,
+    ///     &bias,
+    ///     &keyswitch_key,
+    /// )?;
+    /// #
+    /// assert_eq!(output_ciphertext.lwe_dimension(), output_lwe_dimension);
+    ///
+    /// // The result is the one of the affine transformation followed by the keyswitch.
+    /// let zero = engine.create_plaintext_from(&0_u32)?;
+    /// let mut transformed = engine.trivially_encrypt_lwe_ciphertext(input_lwe_dimension.to_lwe_size(), &zero)?;
+    /// engine.discard_affine_transform_lwe_ciphertext_vector(
+    ///     &mut transformed,
+    ///     &ciphertext_vector,
+    ///     &weights,
+    ///     &bias,
+    /// )?;
+    /// let mut switched = engine.zero_encrypt_lwe_ciphertext(&output_key, noise)?;
+    /// engine.discard_keyswitch_lwe_ciphertext(&mut switched, &transformed, &keyswitch_key)?;
+    /// assert_eq!(output_ciphertext, switched);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_affine_transform_keyswitch_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertext32,
+        inputs: &LweCiphertextVector32,
+        weights: &CleartextVector32,
+        bias: &Plaintext32,
+        ksk: &LweKeyswitchKey32,
+    ) -> Result<
+        (),
+        LweCiphertextVectorDiscardingAffineTransformationKeyswitchError<Self::EngineError>,
+    > {
+        LweCiphertextVectorDiscardingAffineTransformationKeyswitchError::perform_generic_checks(
+            output, inputs, weights, ksk,
+        )?;
+        check_key_fingerprint!(
+            LweCiphertextVectorDiscardingAffineTransformationKeyswitchError,
+            ksk.0,
+            inputs.0
+        );
+        unsafe {
+            self.discard_affine_transform_keyswitch_lwe_ciphertext_vector_unchecked(
+                output, inputs, weights, bias, ksk,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_affine_transform_keyswitch_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertext32,
+        inputs: &LweCiphertextVector32,
+        weights: &CleartextVector32,
+        bias: &Plaintext32,
+        ksk: &LweKeyswitchKey32,
+    ) {
+        match self.rounding_mode {
+            None => {
+                ksk.0
+                    .keyswitch_multisum_with_bias(&mut output.0, &inputs.0, &weights.0, &bias.0)
+            }
+            Some(rounding) => ksk.0.keyswitch_multisum_with_bias_with_rounding(
+                &mut output.0,
+                &inputs.0,
+                &weights.0,
+                &bias.0,
+                rounding,
+                self.rounding_generator.as_mut(),
+            ),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingAffineTransformationKeyswitchEngine`] for
+/// [`DefaultEngine`] that operates on 64 bits integers.
+impl
+    LweCiphertextVectorDiscardingAffineTransformationKeyswitchEngine<
+        LweCiphertextVector64,
+        CleartextVector64,
+        Plaintext64,
+        LweKeyswitchKey64,
+        LweCiphertext64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input_vector = vec![3_u64 << 50; 8];
+    /// let weights_input = vec![2_u64; 8];
+    /// let bias_input = 8_u64 << 50;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let weights: CleartextVector64 = engine.create_cleartext_vector_from(&weights_input)?;
+    /// let bias: Plaintext64 = engine.create_plaintext_from(&bias_input)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input_vector)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_lwe_ciphertext_vector(&input_key, &plaintext_vector, noise)?;
+    /// let mut output_ciphertext = engine.zero_encrypt_lwe_ciphertext(&output_key, noise)?;
+    ///
+    /// engine.discard_affine_transform_keyswitch_lwe_ciphertext_vector(
+    ///     &mut output_ciphertext,
+    ///     &ciphertext_vector,
+    ///     &weights,
+    ///     &bias,
+    ///     &keyswitch_key,
+    /// )?;
+    /// #
+    /// assert_eq!(output_ciphertext.lwe_dimension(), output_lwe_dimension);
+    ///
+    /// // The result is the one of the affine transformation followed by the keyswitch.
+    /// let zero = engine.create_plaintext_from(&0_u64)?;
+    /// let mut transformed = engine.trivially_encrypt_lwe_ciphertext(input_lwe_dimension.to_lwe_size(), &zero)?;
+    /// engine.discard_affine_transform_lwe_ciphertext_vector(
+    ///     &mut transformed,
+    ///     &ciphertext_vector,
+    ///     &weights,
+    ///     &bias,
+    /// )?;
+    /// let mut switched = engine.zero_encrypt_lwe_ciphertext(&output_key, noise)?;
+    /// engine.discard_keyswitch_lwe_ciphertext(&mut switched, &transformed, &keyswitch_key)?;
+    /// assert_eq!(output_ciphertext, switched);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_affine_transform_keyswitch_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertext64,
+        inputs: &LweCiphertextVector64,
+        weights: &CleartextVector64,
+        bias: &Plaintext64,
+        ksk: &LweKeyswitchKey64,
+    ) -> Result<
+        (),
+        LweCiphertextVectorDiscardingAffineTransformationKeyswitchError<Self::EngineError>,
+    > {
+        LweCiphertextVectorDiscardingAffineTransformationKeyswitchError::perform_generic_checks(
+            output, inputs, weights, ksk,
+        )?;
+        check_key_fingerprint!(
+            LweCiphertextVectorDiscardingAffineTransformationKeyswitchError,
+            ksk.0,
+            inputs.0
+        );
+        unsafe {
+            self.discard_affine_transform_keyswitch_lwe_ciphertext_vector_unchecked(
+                output, inputs, weights, bias, ksk,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_affine_transform_keyswitch_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertext64,
+        inputs: &LweCiphertextVector64,
+        weights: &CleartextVector64,
+        bias: &Plaintext64,
+        ksk: &LweKeyswitchKey64,
+    ) {
+        match self.rounding_mode {
+            None => {
+                ksk.0
+                    .keyswitch_multisum_with_bias(&mut output.0, &inputs.0, &weights.0, &bias.0)
+            }
+            Some(rounding) => ksk.0.keyswitch_multisum_with_bias_with_rounding(
+                &mut output.0,
+                &inputs.0,
+                &weights.0,
+                &bias.0,
+                rounding,
+                self.rounding_generator.as_mut(),
+            ),
+        }
+    }
+}
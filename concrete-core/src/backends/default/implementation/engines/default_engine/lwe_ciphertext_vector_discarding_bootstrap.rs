@@ -0,0 +1,218 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GlweCiphertextVector32, GlweCiphertextVector64, LweBootstrapKey32, LweBootstrapKey64,
+    LweCiphertextVector32, LweCiphertextVector64,
+};
+use crate::specification::engines::{
+    LweCiphertextVectorDiscardingBootstrapEngine, LweCiphertextVectorDiscardingBootstrapError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingBootstrapEngine`] for [`DefaultEngine`] that
+/// operates on 32 bits integers.
+///
+/// This is a reference implementation performing the bootstrap in the standard domain, with the
+/// naive polynomial multiplication. It is much slower than the implementations of the other
+/// backends, and is meant for correctness cross-checks, or for platforms where neither the
+/// `fftw` nor the `cuda` backends are available.
+impl
+    LweCiphertextVectorDiscardingBootstrapEngine<
+        LweBootstrapKey32,
+        GlweCiphertextVector32,
+        LweCiphertextVector32,
+        LweCiphertextVector32,
+    > for DefaultEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweCiphertextCount,
+    ///     LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(10), GlweDimension(1), PolynomialSize(256));
+    /// let log_degree = f64::log2(poly_size.0 as f64) as i32;
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-29.));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(7));
+    /// // An identity function is applied during the bootstrap
+    /// let mut lut = vec![0u32; poly_size.0 * 3];
+    /// for i in 0..poly_size.0 {
+    ///     let l = (i as f64 * 2_f64.powi(32 - log_degree - 1)) as u32;
+    ///     lut[i] = l;
+    ///     lut[i + poly_size.0] = l;
+    ///     lut[i + 2 * poly_size.0] = l;
+    /// }
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_key: GlweSecretKey32 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey32 =
+    ///     engine.generate_new_lwe_bootstrap_key(&lwe_key, &glwe_key, dec_bl, dec_lc, noise)?;
+    /// let output_key = engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_key.clone())?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let input = engine.encrypt_lwe_ciphertext_vector(&lwe_key, &plaintext_vector, noise)?;
+    /// let lut_plaintext_vector = engine.create_plaintext_vector_from(&lut)?;
+    /// let acc = engine.trivially_encrypt_glwe_ciphertext_vector(
+    ///     glwe_dim.to_glwe_size(),
+    ///     GlweCiphertextCount(3),
+    ///     &lut_plaintext_vector,
+    /// )?;
+    /// let mut output =
+    ///     engine.zero_encrypt_lwe_ciphertext_vector(&output_key, noise, LweCiphertextCount(3))?;
+    ///
+    /// engine.discard_bootstrap_lwe_ciphertext_vector(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), output_key.lwe_dimension());
+    /// assert_eq!(output.lwe_ciphertext_count(), LweCiphertextCount(3));
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &LweCiphertextVector32,
+        acc: &GlweCiphertextVector32,
+        bsk: &LweBootstrapKey32,
+    ) -> Result<(), LweCiphertextVectorDiscardingBootstrapError<Self::EngineError>> {
+        LweCiphertextVectorDiscardingBootstrapError::perform_generic_checks(
+            output, input, acc, bsk,
+        )?;
+        check_key_fingerprint!(LweCiphertextVectorDiscardingBootstrapError, bsk.0, input.0);
+        unsafe { self.discard_bootstrap_lwe_ciphertext_vector_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &LweCiphertextVector32,
+        acc: &GlweCiphertextVector32,
+        bsk: &LweBootstrapKey32,
+    ) {
+        for (mut out, (ct, lut)) in output
+            .0
+            .ciphertext_iter_mut()
+            .zip(input.0.ciphertext_iter().zip(acc.0.ciphertext_iter()))
+        {
+            bsk.0.bootstrap(&mut out, &ct, &lut);
+        }
+        output.0.set_key_fingerprint(bsk.0.output_key_fingerprint());
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingBootstrapEngine`] for [`DefaultEngine`] that
+/// operates on 64 bits integers.
+///
+/// This is a reference implementation performing the bootstrap in the standard domain, with the
+/// naive polynomial multiplication. It is much slower than the implementations of the other
+/// backends, and is meant for correctness cross-checks, or for platforms where neither the
+/// `fftw` nor the `cuda` backends are available.
+impl
+    LweCiphertextVectorDiscardingBootstrapEngine<
+        LweBootstrapKey64,
+        GlweCiphertextVector64,
+        LweCiphertextVector64,
+        LweCiphertextVector64,
+    > for DefaultEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweCiphertextCount,
+    ///     LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(10), GlweDimension(1), PolynomialSize(256));
+    /// let log_degree = f64::log2(poly_size.0 as f64) as i32;
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-29.));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(7));
+    /// // An identity function is applied during the bootstrap
+    /// let mut lut = vec![0u64; poly_size.0 * 3];
+    /// for i in 0..poly_size.0 {
+    ///     let l = (i as f64 * 2_f64.powi(64 - log_degree - 1)) as u64;
+    ///     lut[i] = l;
+    ///     lut[i + poly_size.0] = l;
+    ///     lut[i + 2 * poly_size.0] = l;
+    /// }
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_key: GlweSecretKey64 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey64 =
+    ///     engine.generate_new_lwe_bootstrap_key(&lwe_key, &glwe_key, dec_bl, dec_lc, noise)?;
+    /// let output_key = engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_key.clone())?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let input = engine.encrypt_lwe_ciphertext_vector(&lwe_key, &plaintext_vector, noise)?;
+    /// let lut_plaintext_vector = engine.create_plaintext_vector_from(&lut)?;
+    /// let acc = engine.trivially_encrypt_glwe_ciphertext_vector(
+    ///     glwe_dim.to_glwe_size(),
+    ///     GlweCiphertextCount(3),
+    ///     &lut_plaintext_vector,
+    /// )?;
+    /// let mut output =
+    ///     engine.zero_encrypt_lwe_ciphertext_vector(&output_key, noise, LweCiphertextCount(3))?;
+    ///
+    /// engine.discard_bootstrap_lwe_ciphertext_vector(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), output_key.lwe_dimension());
+    /// assert_eq!(output.lwe_ciphertext_count(), LweCiphertextCount(3));
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &LweCiphertextVector64,
+        acc: &GlweCiphertextVector64,
+        bsk: &LweBootstrapKey64,
+    ) -> Result<(), LweCiphertextVectorDiscardingBootstrapError<Self::EngineError>> {
+        LweCiphertextVectorDiscardingBootstrapError::perform_generic_checks(
+            output, input, acc, bsk,
+        )?;
+        check_key_fingerprint!(LweCiphertextVectorDiscardingBootstrapError, bsk.0, input.0);
+        unsafe { self.discard_bootstrap_lwe_ciphertext_vector_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &LweCiphertextVector64,
+        acc: &GlweCiphertextVector64,
+        bsk: &LweBootstrapKey64,
+    ) {
+        for (mut out, (ct, lut)) in output
+            .0
+            .ciphertext_iter_mut()
+            .zip(input.0.ciphertext_iter().zip(acc.0.ciphertext_iter()))
+        {
+            bsk.0.bootstrap(&mut out, &ct, &lut);
+        }
+        output.0.set_key_fingerprint(bsk.0.output_key_fingerprint());
+    }
+}
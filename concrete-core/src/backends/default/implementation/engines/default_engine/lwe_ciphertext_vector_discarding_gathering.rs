@@ -0,0 +1,145 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    LweCiphertextVector32, LweCiphertextVector64,
+};
+use crate::specification::engines::{
+    LweCiphertextVectorDiscardingGatheringEngine, LweCiphertextVectorDiscardingGatheringError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingGatheringEngine`] for [`DefaultEngine`]
+/// that operates on 32 bits integers.
+impl LweCiphertextVectorDiscardingGatheringEngine<LweCiphertextVector32, LweCiphertextVector32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input_vector = vec![1_u32 << 20, 2 << 20, 3 << 20, 4 << 20];
+    /// let indices = vec![3, 3, 0];
+    /// let noise = Variance::from_variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input_vector)?;
+    /// let ciphertext_vector = engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let mut output_ciphertext_vector =
+    ///     engine.zero_encrypt_lwe_ciphertext_vector(&key, noise, LweCiphertextCount(3))?;
+    ///
+    /// engine.discard_gather_lwe_ciphertext_vector(
+    ///     &mut output_ciphertext_vector,
+    ///     &ciphertext_vector,
+    ///     &indices,
+    /// )?;
+    /// #
+    /// let decrypted = engine.decrypt_lwe_ciphertext_vector(&key, &output_ciphertext_vector)?;
+    /// let output = engine.retrieve_plaintext_vector(&decrypted)?;
+    /// let output: Vec<_> = output.iter().map(|o| o.wrapping_add(1 << 19) >> 20).collect();
+    /// assert_eq!(output, vec![4, 4, 1]);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_gather_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &LweCiphertextVector32,
+        indices: &[usize],
+    ) -> Result<(), LweCiphertextVectorDiscardingGatheringError<Self::EngineError>> {
+        LweCiphertextVectorDiscardingGatheringError::perform_generic_checks(
+            output, input, indices,
+        )?;
+        unsafe { self.discard_gather_lwe_ciphertext_vector_unchecked(output, input, indices) };
+        Ok(())
+    }
+
+    unsafe fn discard_gather_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &LweCiphertextVector32,
+        indices: &[usize],
+    ) {
+        output.0.fill_with_gather(&input.0, indices);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingGatheringEngine`] for [`DefaultEngine`]
+/// that operates on 64 bits integers.
+impl LweCiphertextVectorDiscardingGatheringEngine<LweCiphertextVector64, LweCiphertextVector64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input_vector = vec![1_u64 << 50, 2 << 50, 3 << 50, 4 << 50];
+    /// let indices = vec![3, 3, 0];
+    /// let noise = Variance::from_variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input_vector)?;
+    /// let ciphertext_vector = engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let mut output_ciphertext_vector =
+    ///     engine.zero_encrypt_lwe_ciphertext_vector(&key, noise, LweCiphertextCount(3))?;
+    ///
+    /// engine.discard_gather_lwe_ciphertext_vector(
+    ///     &mut output_ciphertext_vector,
+    ///     &ciphertext_vector,
+    ///     &indices,
+    /// )?;
+    /// #
+    /// let decrypted = engine.decrypt_lwe_ciphertext_vector(&key, &output_ciphertext_vector)?;
+    /// let output = engine.retrieve_plaintext_vector(&decrypted)?;
+    /// let output: Vec<_> = output.iter().map(|o| o.wrapping_add(1 << 49) >> 50).collect();
+    /// assert_eq!(output, vec![4, 4, 1]);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_gather_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &LweCiphertextVector64,
+        indices: &[usize],
+    ) -> Result<(), LweCiphertextVectorDiscardingGatheringError<Self::EngineError>> {
+        LweCiphertextVectorDiscardingGatheringError::perform_generic_checks(
+            output, input, indices,
+        )?;
+        unsafe { self.discard_gather_lwe_ciphertext_vector_unchecked(output, input, indices) };
+        Ok(())
+    }
+
+    unsafe fn discard_gather_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &LweCiphertextVector64,
+        indices: &[usize],
+    ) {
+        output.0.fill_with_gather(&input.0, indices);
+    }
+}
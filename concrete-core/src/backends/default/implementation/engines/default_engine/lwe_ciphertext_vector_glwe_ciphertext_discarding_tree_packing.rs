@@ -0,0 +1,185 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GlweAutomorphismKeys32, GlweAutomorphismKeys64, GlweCiphertext32, GlweCiphertext64,
+    LweCiphertextVector32, LweCiphertextVector64,
+};
+use crate::specification::engines::{
+    LweCiphertextVectorGlweCiphertextDiscardingTreePackingEngine,
+    LweCiphertextVectorGlweCiphertextDiscardingTreePackingError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorGlweCiphertextDiscardingTreePackingEngine`] for
+/// [`DefaultEngine`] that operates on 32 bits integers.
+impl
+    LweCiphertextVectorGlweCiphertextDiscardingTreePackingEngine<
+        GlweAutomorphismKeys32,
+        LweCiphertextVector32,
+        GlweCiphertext32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(6);
+    /// let decomposition_base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 28 bits)
+    /// let input = vec![3_u32 << 28; 4];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let automorphism_keys: GlweAutomorphismKeys32 = engine.generate_new_glwe_automorphism_keys(
+    ///     &key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let lwe_key: LweSecretKey32 = engine.transform_glwe_secret_key_to_lwe_secret_key(key.clone())?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext_vector = engine.encrypt_lwe_ciphertext_vector(&lwe_key, &plaintext_vector, noise)?;
+    /// let mut packed = engine.zero_encrypt_glwe_ciphertext(&key, noise)?;
+    ///
+    /// // The messages are placed on the coefficients of degree 0, 64, 128 and 192
+    /// engine.discard_tree_pack_lwe_ciphertext_vector(
+    ///     &mut packed,
+    ///     &ciphertext_vector,
+    ///     &automorphism_keys,
+    /// )?;
+    /// #
+    /// assert_eq!(packed.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(packed.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_tree_pack_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &LweCiphertextVector32,
+        automorphism_keys: &GlweAutomorphismKeys32,
+    ) -> Result<(), LweCiphertextVectorGlweCiphertextDiscardingTreePackingError<Self::EngineError>>
+    {
+        LweCiphertextVectorGlweCiphertextDiscardingTreePackingError::perform_generic_checks(
+            output,
+            input,
+            automorphism_keys,
+        )?;
+        unsafe {
+            self.discard_tree_pack_lwe_ciphertext_vector_unchecked(output, input, automorphism_keys)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_tree_pack_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &LweCiphertextVector32,
+        automorphism_keys: &GlweAutomorphismKeys32,
+    ) {
+        automorphism_keys.0.tree_pack(&mut output.0, &input.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorGlweCiphertextDiscardingTreePackingEngine`] for
+/// [`DefaultEngine`] that operates on 64 bits integers.
+impl
+    LweCiphertextVectorGlweCiphertextDiscardingTreePackingEngine<
+        GlweAutomorphismKeys64,
+        LweCiphertextVector64,
+        GlweCiphertext64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(6);
+    /// let decomposition_base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 60 bits)
+    /// let input = vec![3_u64 << 60; 4];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let automorphism_keys: GlweAutomorphismKeys64 = engine.generate_new_glwe_automorphism_keys(
+    ///     &key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let lwe_key: LweSecretKey64 = engine.transform_glwe_secret_key_to_lwe_secret_key(key.clone())?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext_vector = engine.encrypt_lwe_ciphertext_vector(&lwe_key, &plaintext_vector, noise)?;
+    /// let mut packed = engine.zero_encrypt_glwe_ciphertext(&key, noise)?;
+    ///
+    /// // The messages are placed on the coefficients of degree 0, 64, 128 and 192
+    /// engine.discard_tree_pack_lwe_ciphertext_vector(
+    ///     &mut packed,
+    ///     &ciphertext_vector,
+    ///     &automorphism_keys,
+    /// )?;
+    /// #
+    /// assert_eq!(packed.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(packed.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_tree_pack_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &LweCiphertextVector64,
+        automorphism_keys: &GlweAutomorphismKeys64,
+    ) -> Result<(), LweCiphertextVectorGlweCiphertextDiscardingTreePackingError<Self::EngineError>>
+    {
+        LweCiphertextVectorGlweCiphertextDiscardingTreePackingError::perform_generic_checks(
+            output,
+            input,
+            automorphism_keys,
+        )?;
+        unsafe {
+            self.discard_tree_pack_lwe_ciphertext_vector_unchecked(output, input, automorphism_keys)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_tree_pack_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &LweCiphertextVector64,
+        automorphism_keys: &GlweAutomorphismKeys64,
+    ) {
+        automorphism_keys.0.tree_pack(&mut output.0, &input.0);
+    }
+}
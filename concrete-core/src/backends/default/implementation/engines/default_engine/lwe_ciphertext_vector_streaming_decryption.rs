@@ -0,0 +1,195 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    LweCiphertextVector32, LweCiphertextVector64, LweSecretKey32, LweSecretKey64,
+    PlaintextVector32, PlaintextVector64,
+};
+use crate::specification::engines::{
+    LweCiphertextVectorDecryptionEngine, LweCiphertextVectorStreamingDecryptionEngine,
+    LweCiphertextVectorStreamingDecryptionError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorStreamingDecryptionEngine`] for [`DefaultEngine`] that
+/// operates on 32 bits integers.
+impl
+    LweCiphertextVectorStreamingDecryptionEngine<
+        LweSecretKey32,
+        LweCiphertextVector32,
+        PlaintextVector32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 10];
+    /// let chunk_size = 4;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// // The plaintexts are retrieved with an engine distinct from the one decrypting them.
+    /// let mut retrieval_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let mut ciphertext_vectors: Vec<LweCiphertextVector32> = Vec::new();
+    /// for chunk in input.chunks(chunk_size) {
+    ///     let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(chunk)?;
+    ///     ciphertext_vectors.push(engine.encrypt_lwe_ciphertext_vector(
+    ///         &key,
+    ///         &plaintext_vector,
+    ///         noise,
+    ///     )?);
+    /// }
+    ///
+    /// let mut output = Vec::new();
+    /// engine.stream_decrypt_lwe_ciphertext_vector(
+    ///     &key,
+    ///     ciphertext_vectors,
+    ///     |plaintext_vector: PlaintextVector32| {
+    ///         output.extend(
+    ///             retrieval_engine
+    ///                 .retrieve_plaintext_vector(&plaintext_vector)
+    ///                 .unwrap(),
+    ///         );
+    ///     },
+    /// )?;
+    /// #
+    /// assert_eq!(output.len(), input.len());
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn stream_decrypt_lwe_ciphertext_vector<Input, Output>(
+        &mut self,
+        key: &LweSecretKey32,
+        input: Input,
+        mut output: Output,
+    ) -> Result<(), LweCiphertextVectorStreamingDecryptionError<Self::EngineError>>
+    where
+        Input: IntoIterator<Item = LweCiphertextVector32>,
+        Output: FnMut(PlaintextVector32),
+    {
+        for chunk in input {
+            LweCiphertextVectorStreamingDecryptionError::perform_generic_checks(key, &chunk)?;
+            output(unsafe { self.decrypt_lwe_ciphertext_vector_unchecked(key, &chunk) });
+        }
+        Ok(())
+    }
+
+    unsafe fn stream_decrypt_lwe_ciphertext_vector_unchecked<Input, Output>(
+        &mut self,
+        key: &LweSecretKey32,
+        input: Input,
+        mut output: Output,
+    ) where
+        Input: IntoIterator<Item = LweCiphertextVector32>,
+        Output: FnMut(PlaintextVector32),
+    {
+        for chunk in input {
+            output(self.decrypt_lwe_ciphertext_vector_unchecked(key, &chunk));
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorStreamingDecryptionEngine`] for [`DefaultEngine`] that
+/// operates on 64 bits integers.
+impl
+    LweCiphertextVectorStreamingDecryptionEngine<
+        LweSecretKey64,
+        LweCiphertextVector64,
+        PlaintextVector64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 10];
+    /// let chunk_size = 4;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// // The plaintexts are retrieved with an engine distinct from the one decrypting them.
+    /// let mut retrieval_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let mut ciphertext_vectors: Vec<LweCiphertextVector64> = Vec::new();
+    /// for chunk in input.chunks(chunk_size) {
+    ///     let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(chunk)?;
+    ///     ciphertext_vectors.push(engine.encrypt_lwe_ciphertext_vector(
+    ///         &key,
+    ///         &plaintext_vector,
+    ///         noise,
+    ///     )?);
+    /// }
+    ///
+    /// let mut output = Vec::new();
+    /// engine.stream_decrypt_lwe_ciphertext_vector(
+    ///     &key,
+    ///     ciphertext_vectors,
+    ///     |plaintext_vector: PlaintextVector64| {
+    ///         output.extend(
+    ///             retrieval_engine
+    ///                 .retrieve_plaintext_vector(&plaintext_vector)
+    ///                 .unwrap(),
+    ///         );
+    ///     },
+    /// )?;
+    /// #
+    /// assert_eq!(output.len(), input.len());
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn stream_decrypt_lwe_ciphertext_vector<Input, Output>(
+        &mut self,
+        key: &LweSecretKey64,
+        input: Input,
+        mut output: Output,
+    ) -> Result<(), LweCiphertextVectorStreamingDecryptionError<Self::EngineError>>
+    where
+        Input: IntoIterator<Item = LweCiphertextVector64>,
+        Output: FnMut(PlaintextVector64),
+    {
+        for chunk in input {
+            LweCiphertextVectorStreamingDecryptionError::perform_generic_checks(key, &chunk)?;
+            output(unsafe { self.decrypt_lwe_ciphertext_vector_unchecked(key, &chunk) });
+        }
+        Ok(())
+    }
+
+    unsafe fn stream_decrypt_lwe_ciphertext_vector_unchecked<Input, Output>(
+        &mut self,
+        key: &LweSecretKey64,
+        input: Input,
+        mut output: Output,
+    ) where
+        Input: IntoIterator<Item = LweCiphertextVector64>,
+        Output: FnMut(PlaintextVector64),
+    {
+        for chunk in input {
+            output(self.decrypt_lwe_ciphertext_vector_unchecked(key, &chunk));
+        }
+    }
+}
@@ -0,0 +1,197 @@
+use concrete_commons::dispersion::Variance;
+
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    LweCiphertextVector32, LweCiphertextVector64, LweSecretKey32, LweSecretKey64,
+    PlaintextVector32, PlaintextVector64,
+};
+use crate::specification::engines::{
+    LweCiphertextVectorEncryptionEngine, LweCiphertextVectorStreamingEncryptionEngine,
+    LweCiphertextVectorStreamingEncryptionError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorStreamingEncryptionEngine`] for [`DefaultEngine`] that
+/// operates on 32 bits integers.
+impl
+    LweCiphertextVectorStreamingEncryptionEngine<
+        LweSecretKey32,
+        PlaintextVector32,
+        LweCiphertextVector32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 10];
+    /// let chunk_size = 4;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// // The chunks are created lazily, with an engine distinct from the one encrypting them.
+    /// let mut creation_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let chunks = input.chunks(chunk_size).map(|chunk| {
+    ///     let plaintext_vector: PlaintextVector32 =
+    ///         creation_engine.create_plaintext_vector_from(chunk).unwrap();
+    ///     plaintext_vector
+    /// });
+    ///
+    /// let mut counts = Vec::new();
+    /// engine.stream_encrypt_lwe_ciphertext_vector(
+    ///     &key,
+    ///     chunks,
+    ///     noise,
+    ///     |ciphertext_vector: LweCiphertextVector32| {
+    ///         // Here, the ciphertexts would typically be written to disk or sent over the network.
+    ///         counts.push(ciphertext_vector.lwe_ciphertext_count());
+    ///     },
+    /// )?;
+    /// #
+    /// assert_eq!(
+    ///     counts,
+    ///     vec![
+    ///         LweCiphertextCount(4),
+    ///         LweCiphertextCount(4),
+    ///         LweCiphertextCount(2)
+    ///     ]
+    /// );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn stream_encrypt_lwe_ciphertext_vector<Input, Output>(
+        &mut self,
+        key: &LweSecretKey32,
+        input: Input,
+        noise: Variance,
+        output: Output,
+    ) -> Result<(), LweCiphertextVectorStreamingEncryptionError<Self::EngineError>>
+    where
+        Input: IntoIterator<Item = PlaintextVector32>,
+        Output: FnMut(LweCiphertextVector32),
+    {
+        unsafe { self.stream_encrypt_lwe_ciphertext_vector_unchecked(key, input, noise, output) };
+        Ok(())
+    }
+
+    unsafe fn stream_encrypt_lwe_ciphertext_vector_unchecked<Input, Output>(
+        &mut self,
+        key: &LweSecretKey32,
+        input: Input,
+        noise: Variance,
+        mut output: Output,
+    ) where
+        Input: IntoIterator<Item = PlaintextVector32>,
+        Output: FnMut(LweCiphertextVector32),
+    {
+        for chunk in input {
+            output(self.encrypt_lwe_ciphertext_vector_unchecked(key, &chunk, noise));
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorStreamingEncryptionEngine`] for [`DefaultEngine`] that
+/// operates on 64 bits integers.
+impl
+    LweCiphertextVectorStreamingEncryptionEngine<
+        LweSecretKey64,
+        PlaintextVector64,
+        LweCiphertextVector64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 10];
+    /// let chunk_size = 4;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// // The chunks are created lazily, with an engine distinct from the one encrypting them.
+    /// let mut creation_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let chunks = input.chunks(chunk_size).map(|chunk| {
+    ///     let plaintext_vector: PlaintextVector64 =
+    ///         creation_engine.create_plaintext_vector_from(chunk).unwrap();
+    ///     plaintext_vector
+    /// });
+    ///
+    /// let mut counts = Vec::new();
+    /// engine.stream_encrypt_lwe_ciphertext_vector(
+    ///     &key,
+    ///     chunks,
+    ///     noise,
+    ///     |ciphertext_vector: LweCiphertextVector64| {
+    ///         // Here, the ciphertexts would typically be written to disk or sent over the network.
+    ///         counts.push(ciphertext_vector.lwe_ciphertext_count());
+    ///     },
+    /// )?;
+    /// #
+    /// assert_eq!(
+    ///     counts,
+    ///     vec![
+    ///         LweCiphertextCount(4),
+    ///         LweCiphertextCount(4),
+    ///         LweCiphertextCount(2)
+    ///     ]
+    /// );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn stream_encrypt_lwe_ciphertext_vector<Input, Output>(
+        &mut self,
+        key: &LweSecretKey64,
+        input: Input,
+        noise: Variance,
+        output: Output,
+    ) -> Result<(), LweCiphertextVectorStreamingEncryptionError<Self::EngineError>>
+    where
+        Input: IntoIterator<Item = PlaintextVector64>,
+        Output: FnMut(LweCiphertextVector64),
+    {
+        unsafe { self.stream_encrypt_lwe_ciphertext_vector_unchecked(key, input, noise, output) };
+        Ok(())
+    }
+
+    unsafe fn stream_encrypt_lwe_ciphertext_vector_unchecked<Input, Output>(
+        &mut self,
+        key: &LweSecretKey64,
+        input: Input,
+        noise: Variance,
+        mut output: Output,
+    ) where
+        Input: IntoIterator<Item = PlaintextVector64>,
+        Output: FnMut(LweCiphertextVector64),
+    {
+        for chunk in input {
+            output(self.encrypt_lwe_ciphertext_vector_unchecked(key, &chunk, noise));
+        }
+    }
+}
@@ -2,15 +2,71 @@ use concrete_commons::dispersion::Variance;
 
 use crate::backends::default::implementation::engines::DefaultEngine;
 use crate::backends::default::implementation::entities::{
-    LweCiphertext32, LweCiphertext64, LweSecretKey32, LweSecretKey64,
+    LweCiphertext16, LweCiphertext32, LweCiphertext64, LweSecretKey16, LweSecretKey32,
+    LweSecretKey64, SolinasLweCiphertext64, SolinasLweSecretKey64,
 };
 use crate::commons::crypto::encoding::Plaintext as ImplPlaintext;
 use crate::commons::crypto::lwe::LweCiphertext as ImplLweCiphertext;
+use crate::commons::math::modular::SolinasPrime64;
 use crate::specification::engines::{
     LweCiphertextZeroEncryptionEngine, LweCiphertextZeroEncryptionError,
 };
 use crate::specification::entities::LweSecretKeyEntity;
 
+/// # Description:
+/// Implementation of [`LweCiphertextZeroEncryptionEngine`] for [`DefaultEngine`] that
+/// operates on 16 bits integers.
+impl LweCiphertextZeroEncryptionEngine<LweSecretKey16, LweCiphertext16> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey16 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let ciphertext = engine.zero_encrypt_lwe_ciphertext(&key, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn zero_encrypt_lwe_ciphertext(
+        &mut self,
+        key: &LweSecretKey16,
+        noise: Variance,
+    ) -> Result<LweCiphertext16, LweCiphertextZeroEncryptionError<Self::EngineError>> {
+        Ok(unsafe { self.zero_encrypt_lwe_ciphertext_unchecked(key, noise) })
+    }
+
+    unsafe fn zero_encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &LweSecretKey16,
+        noise: Variance,
+    ) -> LweCiphertext16 {
+        let mut ciphertext = ImplLweCiphertext::allocate(0u16, key.lwe_dimension().to_lwe_size());
+        key.0.encrypt_lwe(
+            &mut ciphertext,
+            &ImplPlaintext(0u16),
+            noise,
+            &mut self.encryption_generator,
+        );
+        LweCiphertext16(ciphertext)
+    }
+}
+
 /// # Description:
 /// Implementation of [`LweCiphertextZeroEncryptionEngine`] for [`DefaultEngine`] that
 /// operates on 32 bits integers.
@@ -118,3 +174,60 @@ impl LweCiphertextZeroEncryptionEngine<LweSecretKey64, LweCiphertext64> for Defa
         LweCiphertext64(ciphertext)
     }
 }
+
+/// # Description:
+/// Implementation of [`LweCiphertextZeroEncryptionEngine`] for [`DefaultEngine`] that operates on
+/// 64 bits integers, modulo the Solinas prime $q = 2^{64} - 2^{32} + 1$.
+impl LweCiphertextZeroEncryptionEngine<SolinasLweSecretKey64, SolinasLweCiphertext64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: SolinasLweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let ciphertext = engine.zero_encrypt_lwe_ciphertext(&key, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn zero_encrypt_lwe_ciphertext(
+        &mut self,
+        key: &SolinasLweSecretKey64,
+        noise: Variance,
+    ) -> Result<SolinasLweCiphertext64, LweCiphertextZeroEncryptionError<Self::EngineError>> {
+        Ok(unsafe { self.zero_encrypt_lwe_ciphertext_unchecked(key, noise) })
+    }
+
+    unsafe fn zero_encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &SolinasLweSecretKey64,
+        noise: Variance,
+    ) -> SolinasLweCiphertext64 {
+        let mut ciphertext = ImplLweCiphertext::allocate(0u64, key.lwe_dimension().to_lwe_size());
+        key.0.encrypt_lwe_modular(
+            &mut ciphertext,
+            &ImplPlaintext(0u64),
+            noise,
+            &mut self.encryption_generator,
+            &SolinasPrime64,
+        );
+        SolinasLweCiphertext64(ciphertext)
+    }
+}
@@ -0,0 +1,332 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    LweKeyswitchKey32, LweKeyswitchKey64, LweLevelMajorKeyswitchKey32, LweLevelMajorKeyswitchKey64,
+};
+use crate::commons::crypto::lwe::{
+    LweKeyswitchKey as ImplLweKeyswitchKey,
+    LweLevelMajorKeyswitchKey as ImplLweLevelMajorKeyswitchKey,
+};
+use crate::specification::engines::{
+    LweKeyswitchKeyConversionEngine, LweKeyswitchKeyConversionError,
+};
+use crate::specification::entities::LweKeyswitchKeyEntity;
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyConversionEngine`] for [`DefaultEngine`] that operates on
+/// 32 bits integers. It stores the keyswitch key in level-major order.
+impl LweKeyswitchKeyConversionEngine<LweKeyswitchKey32, LweLevelMajorKeyswitchKey32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key: LweKeyswitchKey32 = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let level_major_keyswitch_key: LweLevelMajorKeyswitchKey32 =
+    ///     engine.convert_lwe_keyswitch_key(&keyswitch_key)?;
+    /// #
+    /// assert_eq!(level_major_keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(level_major_keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    /// assert_eq!(
+    ///     level_major_keyswitch_key.decomposition_level_count(),
+    ///     decomposition_level_count
+    /// );
+    /// assert_eq!(
+    ///     level_major_keyswitch_key.decomposition_base_log(),
+    ///     decomposition_base_log
+    /// );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_keyswitch_key(
+        &mut self,
+        input: &LweKeyswitchKey32,
+    ) -> Result<LweLevelMajorKeyswitchKey32, LweKeyswitchKeyConversionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.convert_lwe_keyswitch_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_keyswitch_key_unchecked(
+        &mut self,
+        input: &LweKeyswitchKey32,
+    ) -> LweLevelMajorKeyswitchKey32 {
+        let mut output = ImplLweLevelMajorKeyswitchKey::allocate(
+            0u32,
+            input.decomposition_level_count(),
+            input.decomposition_base_log(),
+            input.input_lwe_dimension(),
+            input.output_lwe_dimension(),
+        );
+        output.fill_with_standard_keyswitch_key(&input.0);
+        LweLevelMajorKeyswitchKey32(output)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyConversionEngine`] for [`DefaultEngine`] that operates on
+/// 32 bits integers. It stores a level-major keyswitch key back in the standard order.
+impl LweKeyswitchKeyConversionEngine<LweLevelMajorKeyswitchKey32, LweKeyswitchKey32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key: LweKeyswitchKey32 = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let level_major_keyswitch_key: LweLevelMajorKeyswitchKey32 =
+    ///     engine.convert_lwe_keyswitch_key(&keyswitch_key)?;
+    /// let converted_keyswitch_key: LweKeyswitchKey32 =
+    ///     engine.convert_lwe_keyswitch_key(&level_major_keyswitch_key)?;
+    /// #
+    /// assert_eq!(converted_keyswitch_key, keyswitch_key);
+    /// assert_eq!(converted_keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(converted_keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    /// assert_eq!(
+    ///     converted_keyswitch_key.decomposition_level_count(),
+    ///     decomposition_level_count
+    /// );
+    /// assert_eq!(
+    ///     converted_keyswitch_key.decomposition_base_log(),
+    ///     decomposition_base_log
+    /// );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_keyswitch_key(
+        &mut self,
+        input: &LweLevelMajorKeyswitchKey32,
+    ) -> Result<LweKeyswitchKey32, LweKeyswitchKeyConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_lwe_keyswitch_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_keyswitch_key_unchecked(
+        &mut self,
+        input: &LweLevelMajorKeyswitchKey32,
+    ) -> LweKeyswitchKey32 {
+        let mut output = ImplLweKeyswitchKey::allocate(
+            0u32,
+            input.decomposition_level_count(),
+            input.decomposition_base_log(),
+            input.input_lwe_dimension(),
+            input.output_lwe_dimension(),
+        );
+        output.fill_with_level_major_keyswitch_key(&input.0);
+        LweKeyswitchKey32(output)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyConversionEngine`] for [`DefaultEngine`] that operates on
+/// 64 bits integers. It stores the keyswitch key in level-major order.
+impl LweKeyswitchKeyConversionEngine<LweKeyswitchKey64, LweLevelMajorKeyswitchKey64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key: LweKeyswitchKey64 = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let level_major_keyswitch_key: LweLevelMajorKeyswitchKey64 =
+    ///     engine.convert_lwe_keyswitch_key(&keyswitch_key)?;
+    /// #
+    /// assert_eq!(level_major_keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(level_major_keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    /// assert_eq!(
+    ///     level_major_keyswitch_key.decomposition_level_count(),
+    ///     decomposition_level_count
+    /// );
+    /// assert_eq!(
+    ///     level_major_keyswitch_key.decomposition_base_log(),
+    ///     decomposition_base_log
+    /// );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_keyswitch_key(
+        &mut self,
+        input: &LweKeyswitchKey64,
+    ) -> Result<LweLevelMajorKeyswitchKey64, LweKeyswitchKeyConversionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.convert_lwe_keyswitch_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_keyswitch_key_unchecked(
+        &mut self,
+        input: &LweKeyswitchKey64,
+    ) -> LweLevelMajorKeyswitchKey64 {
+        let mut output = ImplLweLevelMajorKeyswitchKey::allocate(
+            0u64,
+            input.decomposition_level_count(),
+            input.decomposition_base_log(),
+            input.input_lwe_dimension(),
+            input.output_lwe_dimension(),
+        );
+        output.fill_with_standard_keyswitch_key(&input.0);
+        LweLevelMajorKeyswitchKey64(output)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyConversionEngine`] for [`DefaultEngine`] that operates on
+/// 64 bits integers. It stores a level-major keyswitch key back in the standard order.
+impl LweKeyswitchKeyConversionEngine<LweLevelMajorKeyswitchKey64, LweKeyswitchKey64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key: LweKeyswitchKey64 = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let level_major_keyswitch_key: LweLevelMajorKeyswitchKey64 =
+    ///     engine.convert_lwe_keyswitch_key(&keyswitch_key)?;
+    /// let converted_keyswitch_key: LweKeyswitchKey64 =
+    ///     engine.convert_lwe_keyswitch_key(&level_major_keyswitch_key)?;
+    /// #
+    /// assert_eq!(converted_keyswitch_key, keyswitch_key);
+    /// assert_eq!(converted_keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(converted_keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    /// assert_eq!(
+    ///     converted_keyswitch_key.decomposition_level_count(),
+    ///     decomposition_level_count
+    /// );
+    /// assert_eq!(
+    ///     converted_keyswitch_key.decomposition_base_log(),
+    ///     decomposition_base_log
+    /// );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_keyswitch_key(
+        &mut self,
+        input: &LweLevelMajorKeyswitchKey64,
+    ) -> Result<LweKeyswitchKey64, LweKeyswitchKeyConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_lwe_keyswitch_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_keyswitch_key_unchecked(
+        &mut self,
+        input: &LweLevelMajorKeyswitchKey64,
+    ) -> LweKeyswitchKey64 {
+        let mut output = ImplLweKeyswitchKey::allocate(
+            0u64,
+            input.decomposition_level_count(),
+            input.decomposition_base_log(),
+            input.input_lwe_dimension(),
+            input.output_lwe_dimension(),
+        );
+        output.fill_with_level_major_keyswitch_key(&input.0);
+        LweKeyswitchKey64(output)
+    }
+}
@@ -0,0 +1,248 @@
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    LwePartialKeyswitchKey32, LwePartialKeyswitchKey64, LweSecretKey32, LweSecretKey64,
+};
+use crate::commons::crypto::lwe::LwePartialKeyswitchKey as ImplLwePartialKeyswitchKey;
+use crate::specification::engines::{
+    LwePartialKeyswitchKeyGenerationEngine, LwePartialKeyswitchKeyGenerationError,
+};
+use crate::specification::entities::LweSecretKeyEntity;
+
+/// # Description:
+/// Implementation of [`LwePartialKeyswitchKeyGenerationEngine`] for [`DefaultEngine`] that
+/// operates on 32 bits integers.
+impl
+    LwePartialKeyswitchKeyGenerationEngine<LweSecretKey32, LweSecretKey32, LwePartialKeyswitchKey32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let switched_lwe_dimension = LweDimension(4);
+    /// let replacement_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let replacement_key: LweSecretKey32 =
+    ///     engine.generate_new_lwe_secret_key(replacement_lwe_dimension)?;
+    /// let partial_keyswitch_key: LwePartialKeyswitchKey32 = engine
+    ///     .generate_new_lwe_partial_keyswitch_key(
+    ///         &input_key,
+    ///         &replacement_key,
+    ///         switched_lwe_dimension,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         noise,
+    ///     )?;
+    /// #
+    /// assert_eq!(
+    /// #     partial_keyswitch_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     partial_keyswitch_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(partial_keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(partial_keyswitch_key.switched_lwe_dimension(), switched_lwe_dimension);
+    /// assert_eq!(
+    ///     partial_keyswitch_key.replacement_lwe_dimension(),
+    ///     replacement_lwe_dimension
+    /// );
+    /// assert_eq!(partial_keyswitch_key.output_lwe_dimension(), LweDimension(5));
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_lwe_partial_keyswitch_key(
+        &mut self,
+        input_key: &LweSecretKey32,
+        replacement_key: &LweSecretKey32,
+        switched_lwe_dimension: LweDimension,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<LwePartialKeyswitchKey32, LwePartialKeyswitchKeyGenerationError<Self::EngineError>>
+    {
+        LwePartialKeyswitchKeyGenerationError::perform_generic_checks(
+            input_key,
+            switched_lwe_dimension,
+            decomposition_level_count,
+            decomposition_base_log,
+            32,
+        )?;
+        Ok(unsafe {
+            self.generate_new_lwe_partial_keyswitch_key_unchecked(
+                input_key,
+                replacement_key,
+                switched_lwe_dimension,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn generate_new_lwe_partial_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &LweSecretKey32,
+        replacement_key: &LweSecretKey32,
+        switched_lwe_dimension: LweDimension,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> LwePartialKeyswitchKey32 {
+        let mut ksk = ImplLwePartialKeyswitchKey::allocate(
+            0,
+            decomposition_level_count,
+            decomposition_base_log,
+            input_key.lwe_dimension(),
+            switched_lwe_dimension,
+            replacement_key.lwe_dimension(),
+        );
+        ksk.fill_with_partial_keyswitch_key(
+            &input_key.0,
+            &replacement_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        LwePartialKeyswitchKey32(ksk)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LwePartialKeyswitchKeyGenerationEngine`] for [`DefaultEngine`] that
+/// operates on 64 bits integers.
+impl
+    LwePartialKeyswitchKeyGenerationEngine<LweSecretKey64, LweSecretKey64, LwePartialKeyswitchKey64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let switched_lwe_dimension = LweDimension(4);
+    /// let replacement_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let replacement_key: LweSecretKey64 =
+    ///     engine.generate_new_lwe_secret_key(replacement_lwe_dimension)?;
+    /// let partial_keyswitch_key: LwePartialKeyswitchKey64 = engine
+    ///     .generate_new_lwe_partial_keyswitch_key(
+    ///         &input_key,
+    ///         &replacement_key,
+    ///         switched_lwe_dimension,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         noise,
+    ///     )?;
+    /// #
+    /// assert_eq!(
+    /// #     partial_keyswitch_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     partial_keyswitch_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(partial_keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(partial_keyswitch_key.switched_lwe_dimension(), switched_lwe_dimension);
+    /// assert_eq!(
+    ///     partial_keyswitch_key.replacement_lwe_dimension(),
+    ///     replacement_lwe_dimension
+    /// );
+    /// assert_eq!(partial_keyswitch_key.output_lwe_dimension(), LweDimension(5));
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_lwe_partial_keyswitch_key(
+        &mut self,
+        input_key: &LweSecretKey64,
+        replacement_key: &LweSecretKey64,
+        switched_lwe_dimension: LweDimension,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<LwePartialKeyswitchKey64, LwePartialKeyswitchKeyGenerationError<Self::EngineError>>
+    {
+        LwePartialKeyswitchKeyGenerationError::perform_generic_checks(
+            input_key,
+            switched_lwe_dimension,
+            decomposition_level_count,
+            decomposition_base_log,
+            64,
+        )?;
+        Ok(unsafe {
+            self.generate_new_lwe_partial_keyswitch_key_unchecked(
+                input_key,
+                replacement_key,
+                switched_lwe_dimension,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn generate_new_lwe_partial_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &LweSecretKey64,
+        replacement_key: &LweSecretKey64,
+        switched_lwe_dimension: LweDimension,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> LwePartialKeyswitchKey64 {
+        let mut ksk = ImplLwePartialKeyswitchKey::allocate(
+            0,
+            decomposition_level_count,
+            decomposition_base_log,
+            input_key.lwe_dimension(),
+            switched_lwe_dimension,
+            replacement_key.lwe_dimension(),
+        );
+        ksk.fill_with_partial_keyswitch_key(
+            &input_key.0,
+            &replacement_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        LwePartialKeyswitchKey64(ksk)
+    }
+}
@@ -0,0 +1,102 @@
+use concrete_commons::parameters::LweDimension;
+
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    LweSecretKeyGaussian32, LweSecretKeyGaussian64,
+};
+use crate::commons::crypto::secret::LweSecretKey as ImplLweSecretKey;
+use crate::specification::engines::{
+    LweSecretKeyGaussianGenerationEngine, LweSecretKeyGaussianGenerationError,
+};
+
+/// # Description:
+/// Implementation of [`LweSecretKeyGaussianGenerationEngine`] for [`DefaultEngine`] that
+/// operates on 32 bits integers.
+impl LweSecretKeyGaussianGenerationEngine<LweSecretKeyGaussian32> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweSecretKeyGaussian32 =
+    ///     engine.generate_new_lwe_secret_key_gaussian(lwe_dimension)?;
+    /// #
+    /// assert_eq!(lwe_secret_key.lwe_dimension(), lwe_dimension);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_lwe_secret_key_gaussian(
+        &mut self,
+        lwe_dimension: LweDimension,
+    ) -> Result<LweSecretKeyGaussian32, LweSecretKeyGaussianGenerationError<Self::EngineError>>
+    {
+        LweSecretKeyGaussianGenerationError::perform_generic_checks(lwe_dimension)?;
+        Ok(unsafe { self.generate_new_lwe_secret_key_gaussian_unchecked(lwe_dimension) })
+    }
+
+    unsafe fn generate_new_lwe_secret_key_gaussian_unchecked(
+        &mut self,
+        lwe_dimension: LweDimension,
+    ) -> LweSecretKeyGaussian32 {
+        LweSecretKeyGaussian32(ImplLweSecretKey::generate_gaussian(
+            lwe_dimension,
+            &mut self.secret_generator,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweSecretKeyGaussianGenerationEngine`] for [`DefaultEngine`] that
+/// operates on 64 bits integers.
+impl LweSecretKeyGaussianGenerationEngine<LweSecretKeyGaussian64> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweSecretKeyGaussian64 =
+    ///     engine.generate_new_lwe_secret_key_gaussian(lwe_dimension)?;
+    /// #
+    /// assert_eq!(lwe_secret_key.lwe_dimension(), lwe_dimension);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_lwe_secret_key_gaussian(
+        &mut self,
+        lwe_dimension: LweDimension,
+    ) -> Result<LweSecretKeyGaussian64, LweSecretKeyGaussianGenerationError<Self::EngineError>>
+    {
+        LweSecretKeyGaussianGenerationError::perform_generic_checks(lwe_dimension)?;
+        Ok(unsafe { self.generate_new_lwe_secret_key_gaussian_unchecked(lwe_dimension) })
+    }
+
+    unsafe fn generate_new_lwe_secret_key_gaussian_unchecked(
+        &mut self,
+        lwe_dimension: LweDimension,
+    ) -> LweSecretKeyGaussian64 {
+        LweSecretKeyGaussian64(ImplLweSecretKey::generate_gaussian(
+            lwe_dimension,
+            &mut self.secret_generator,
+        ))
+    }
+}
@@ -0,0 +1,196 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    LwePartialKeyswitchKey32, LwePartialKeyswitchKey64, LweSecretKey32, LweSecretKey64,
+};
+use crate::commons::crypto::secret::LweSecretKey as ImplLweSecretKey;
+use crate::commons::math::tensor::AsRefTensor;
+use crate::specification::engines::{
+    LweSecretKeyPartialKeyswitchEngine, LweSecretKeyPartialKeyswitchError,
+};
+use crate::specification::entities::LwePartialKeyswitchKeyEntity;
+
+/// # Description:
+/// Implementation of [`LweSecretKeyPartialKeyswitchEngine`] for [`DefaultEngine`] that operates on
+/// 32 bits integers.
+impl
+    LweSecretKeyPartialKeyswitchEngine<
+        LweSecretKey32,
+        LweSecretKey32,
+        LwePartialKeyswitchKey32,
+        LweSecretKey32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let switched_lwe_dimension = LweDimension(4);
+    /// let replacement_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let replacement_key: LweSecretKey32 =
+    ///     engine.generate_new_lwe_secret_key(replacement_lwe_dimension)?;
+    /// let partial_keyswitch_key: LwePartialKeyswitchKey32 = engine
+    ///     .generate_new_lwe_partial_keyswitch_key(
+    ///         &input_key,
+    ///         &replacement_key,
+    ///         switched_lwe_dimension,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         noise,
+    ///     )?;
+    ///
+    /// let output_key: LweSecretKey32 = engine.partially_keyswitch_lwe_secret_key(
+    ///     &input_key,
+    ///     &replacement_key,
+    ///     &partial_keyswitch_key,
+    /// )?;
+    /// #
+    /// assert_eq!(output_key.lwe_dimension(), LweDimension(5));
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn partially_keyswitch_lwe_secret_key(
+        &mut self,
+        input_key: &LweSecretKey32,
+        replacement_key: &LweSecretKey32,
+        ksk: &LwePartialKeyswitchKey32,
+    ) -> Result<LweSecretKey32, LweSecretKeyPartialKeyswitchError<Self::EngineError>> {
+        LweSecretKeyPartialKeyswitchError::perform_generic_checks(input_key, replacement_key, ksk)?;
+        Ok(unsafe {
+            self.partially_keyswitch_lwe_secret_key_unchecked(input_key, replacement_key, ksk)
+        })
+    }
+
+    unsafe fn partially_keyswitch_lwe_secret_key_unchecked(
+        &mut self,
+        input_key: &LweSecretKey32,
+        replacement_key: &LweSecretKey32,
+        ksk: &LwePartialKeyswitchKey32,
+    ) -> LweSecretKey32 {
+        let output_key = replacement_key
+            .0
+            .as_tensor()
+            .iter()
+            .chain(
+                input_key
+                    .0
+                    .as_tensor()
+                    .iter()
+                    .skip(ksk.switched_lwe_dimension().0),
+            )
+            .copied()
+            .collect::<Vec<_>>();
+        LweSecretKey32(ImplLweSecretKey::binary_from_container(output_key))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweSecretKeyPartialKeyswitchEngine`] for [`DefaultEngine`] that operates on
+/// 64 bits integers.
+impl
+    LweSecretKeyPartialKeyswitchEngine<
+        LweSecretKey64,
+        LweSecretKey64,
+        LwePartialKeyswitchKey64,
+        LweSecretKey64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let switched_lwe_dimension = LweDimension(4);
+    /// let replacement_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let replacement_key: LweSecretKey64 =
+    ///     engine.generate_new_lwe_secret_key(replacement_lwe_dimension)?;
+    /// let partial_keyswitch_key: LwePartialKeyswitchKey64 = engine
+    ///     .generate_new_lwe_partial_keyswitch_key(
+    ///         &input_key,
+    ///         &replacement_key,
+    ///         switched_lwe_dimension,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         noise,
+    ///     )?;
+    ///
+    /// let output_key: LweSecretKey64 = engine.partially_keyswitch_lwe_secret_key(
+    ///     &input_key,
+    ///     &replacement_key,
+    ///     &partial_keyswitch_key,
+    /// )?;
+    /// #
+    /// assert_eq!(output_key.lwe_dimension(), LweDimension(5));
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn partially_keyswitch_lwe_secret_key(
+        &mut self,
+        input_key: &LweSecretKey64,
+        replacement_key: &LweSecretKey64,
+        ksk: &LwePartialKeyswitchKey64,
+    ) -> Result<LweSecretKey64, LweSecretKeyPartialKeyswitchError<Self::EngineError>> {
+        LweSecretKeyPartialKeyswitchError::perform_generic_checks(input_key, replacement_key, ksk)?;
+        Ok(unsafe {
+            self.partially_keyswitch_lwe_secret_key_unchecked(input_key, replacement_key, ksk)
+        })
+    }
+
+    unsafe fn partially_keyswitch_lwe_secret_key_unchecked(
+        &mut self,
+        input_key: &LweSecretKey64,
+        replacement_key: &LweSecretKey64,
+        ksk: &LwePartialKeyswitchKey64,
+    ) -> LweSecretKey64 {
+        let output_key = replacement_key
+            .0
+            .as_tensor()
+            .iter()
+            .chain(
+                input_key
+                    .0
+                    .as_tensor()
+                    .iter()
+                    .skip(ksk.switched_lwe_dimension().0),
+            )
+            .copied()
+            .collect::<Vec<_>>();
+        LweSecretKey64(ImplLweSecretKey::binary_from_container(output_key))
+    }
+}
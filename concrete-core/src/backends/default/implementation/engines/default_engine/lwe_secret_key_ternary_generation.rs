@@ -0,0 +1,100 @@
+use concrete_commons::parameters::LweDimension;
+
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    LweSecretKeyTernary32, LweSecretKeyTernary64,
+};
+use crate::commons::crypto::secret::LweSecretKey as ImplLweSecretKey;
+use crate::specification::engines::{
+    LweSecretKeyTernaryGenerationEngine, LweSecretKeyTernaryGenerationError,
+};
+
+/// # Description:
+/// Implementation of [`LweSecretKeyTernaryGenerationEngine`] for [`DefaultEngine`] that operates on
+/// 32 bits integers.
+impl LweSecretKeyTernaryGenerationEngine<LweSecretKeyTernary32> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweSecretKeyTernary32 =
+    ///     engine.generate_new_lwe_secret_key_ternary(lwe_dimension)?;
+    /// #
+    /// assert_eq!(lwe_secret_key.lwe_dimension(), lwe_dimension);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_lwe_secret_key_ternary(
+        &mut self,
+        lwe_dimension: LweDimension,
+    ) -> Result<LweSecretKeyTernary32, LweSecretKeyTernaryGenerationError<Self::EngineError>> {
+        LweSecretKeyTernaryGenerationError::perform_generic_checks(lwe_dimension)?;
+        Ok(unsafe { self.generate_new_lwe_secret_key_ternary_unchecked(lwe_dimension) })
+    }
+
+    unsafe fn generate_new_lwe_secret_key_ternary_unchecked(
+        &mut self,
+        lwe_dimension: LweDimension,
+    ) -> LweSecretKeyTernary32 {
+        LweSecretKeyTernary32(ImplLweSecretKey::generate_ternary(
+            lwe_dimension,
+            &mut self.secret_generator,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweSecretKeyTernaryGenerationEngine`] for [`DefaultEngine`] that operates on
+/// 64 bits integers.
+impl LweSecretKeyTernaryGenerationEngine<LweSecretKeyTernary64> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweSecretKeyTernary64 =
+    ///     engine.generate_new_lwe_secret_key_ternary(lwe_dimension)?;
+    /// #
+    /// assert_eq!(lwe_secret_key.lwe_dimension(), lwe_dimension);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_lwe_secret_key_ternary(
+        &mut self,
+        lwe_dimension: LweDimension,
+    ) -> Result<LweSecretKeyTernary64, LweSecretKeyTernaryGenerationError<Self::EngineError>> {
+        LweSecretKeyTernaryGenerationError::perform_generic_checks(lwe_dimension)?;
+        Ok(unsafe { self.generate_new_lwe_secret_key_ternary_unchecked(lwe_dimension) })
+    }
+
+    unsafe fn generate_new_lwe_secret_key_ternary_unchecked(
+        &mut self,
+        lwe_dimension: LweDimension,
+    ) -> LweSecretKeyTernary64 {
+        LweSecretKeyTernary64(ImplLweSecretKey::generate_ternary(
+            lwe_dimension,
+            &mut self.secret_generator,
+        ))
+    }
+}
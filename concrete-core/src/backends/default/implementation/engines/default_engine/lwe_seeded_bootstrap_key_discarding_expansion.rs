@@ -0,0 +1,140 @@
+use crate::backends::default::engines::DefaultEngine;
+use crate::backends::default::entities::{
+    LweBootstrapKey32, LweBootstrapKey64, LweSeededBootstrapKey32, LweSeededBootstrapKey64,
+};
+use crate::commons::math::random::ByteRandomGenerator;
+use crate::specification::engines::{
+    LweSeededBootstrapKeyDiscardingExpansionEngine, LweSeededBootstrapKeyDiscardingExpansionError,
+};
+
+/// # Description:
+/// Implementation of [`LweSeededBootstrapKeyDiscardingExpansionEngine`] for [`DefaultEngine`] that operates on 32 bits integers.
+impl LweSeededBootstrapKeyDiscardingExpansionEngine<LweSeededBootstrapKey32, LweBootstrapKey32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let seeded_bsk: LweSeededBootstrapKey32 =
+    ///     engine.generate_new_lwe_seeded_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// // The output key was allocated beforehand, for instance by a previous expansion.
+    /// let mut bsk = engine.transform_lwe_seeded_bootstrap_key_to_lwe_bootstrap_key(seeded_bsk.clone())?;
+    ///
+    /// // The masks are regenerated with a generator chosen by the caller.
+    /// engine.discard_expand_lwe_seeded_bootstrap_key::<SoftwareRandomGenerator>(&mut bsk, &seeded_bsk)?;
+    /// #
+    /// assert_eq!(bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(bsk.polynomial_size(), poly_size);
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_expand_lwe_seeded_bootstrap_key<Generator: ByteRandomGenerator>(
+        &mut self,
+        output: &mut LweBootstrapKey32,
+        input: &LweSeededBootstrapKey32,
+    ) -> Result<(), LweSeededBootstrapKeyDiscardingExpansionError<Self::EngineError>> {
+        LweSeededBootstrapKeyDiscardingExpansionError::perform_generic_checks(output, input)?;
+        unsafe {
+            self.discard_expand_lwe_seeded_bootstrap_key_unchecked::<Generator>(output, input)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_expand_lwe_seeded_bootstrap_key_unchecked<Generator: ByteRandomGenerator>(
+        &mut self,
+        output: &mut LweBootstrapKey32,
+        input: &LweSeededBootstrapKey32,
+    ) {
+        input.0.expand_into::<_, _, Generator>(&mut output.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweSeededBootstrapKeyDiscardingExpansionEngine`] for [`DefaultEngine`] that operates on 64 bits integers.
+impl LweSeededBootstrapKeyDiscardingExpansionEngine<LweSeededBootstrapKey64, LweBootstrapKey64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let seeded_bsk: LweSeededBootstrapKey64 =
+    ///     engine.generate_new_lwe_seeded_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// // The output key was allocated beforehand, for instance by a previous expansion.
+    /// let mut bsk = engine.transform_lwe_seeded_bootstrap_key_to_lwe_bootstrap_key(seeded_bsk.clone())?;
+    ///
+    /// // The masks are regenerated with a generator chosen by the caller.
+    /// engine.discard_expand_lwe_seeded_bootstrap_key::<SoftwareRandomGenerator>(&mut bsk, &seeded_bsk)?;
+    /// #
+    /// assert_eq!(bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(bsk.polynomial_size(), poly_size);
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_expand_lwe_seeded_bootstrap_key<Generator: ByteRandomGenerator>(
+        &mut self,
+        output: &mut LweBootstrapKey64,
+        input: &LweSeededBootstrapKey64,
+    ) -> Result<(), LweSeededBootstrapKeyDiscardingExpansionError<Self::EngineError>> {
+        LweSeededBootstrapKeyDiscardingExpansionError::perform_generic_checks(output, input)?;
+        unsafe {
+            self.discard_expand_lwe_seeded_bootstrap_key_unchecked::<Generator>(output, input)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_expand_lwe_seeded_bootstrap_key_unchecked<Generator: ByteRandomGenerator>(
+        &mut self,
+        output: &mut LweBootstrapKey64,
+        input: &LweSeededBootstrapKey64,
+    ) {
+        input.0.expand_into::<_, _, Generator>(&mut output.0);
+    }
+}
@@ -0,0 +1,196 @@
+use super::ActivatedRandomGenerator;
+use crate::backends::default::engines::DefaultEngine;
+use crate::backends::default::entities::{
+    LweSecretKey32, LweSecretKey64, LweSeededCiphertext32, LweSeededCiphertext64,
+    PlaintextVector32, PlaintextVector64,
+};
+use crate::commons::crypto::lwe::LweSeededList as ImplLweSeededList;
+use crate::commons::math::random::{CompressionSeed, Seeder};
+use crate::specification::engines::{
+    LweSeededCiphertextBatchEncryptionEngine, LweSeededCiphertextBatchEncryptionError,
+};
+use crate::specification::entities::{LweSecretKeyEntity, PlaintextVectorEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::CiphertextCount;
+
+/// # Description:
+/// Implementation of [`LweSeededCiphertextBatchEncryptionEngine`] for [`DefaultEngine`] that
+/// operates on 32 bits integers.
+impl
+    LweSeededCiphertextBatchEncryptionEngine<
+        LweSecretKey32,
+        PlaintextVector32,
+        LweSeededCiphertext32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let ciphertexts: Vec<LweSeededCiphertext32> =
+    ///     engine.encrypt_lwe_seeded_ciphertext_batch(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(ciphertexts.len(), 3);
+    /// // The ciphertexts share the same seed, and use consecutive parts of the generator stream.
+    /// assert_eq!(ciphertexts[0].compression_seed(), ciphertexts[2].compression_seed());
+    /// assert_eq!(ciphertexts[0].generator_byte_index(), 0);
+    /// assert_eq!(
+    ///     ciphertexts[2].generator_byte_index(),
+    ///     2 * lwe_dimension.0 * std::mem::size_of::<u32>()
+    /// );
+    ///
+    /// // Each ciphertext can be expanded on its own.
+    /// let ciphertext: LweCiphertext32 =
+    ///     engine.transform_lwe_seeded_ciphertext_to_lwe_ciphertext(ciphertexts[2].clone())?;
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_seeded_ciphertext_batch(
+        &mut self,
+        key: &LweSecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> Result<
+        Vec<LweSeededCiphertext32>,
+        LweSeededCiphertextBatchEncryptionError<Self::EngineError>,
+    > {
+        Ok(unsafe { self.encrypt_lwe_seeded_ciphertext_batch_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_lwe_seeded_ciphertext_batch_unchecked(
+        &mut self,
+        key: &LweSecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> Vec<LweSeededCiphertext32> {
+        let mut vector = ImplLweSeededList::allocate(
+            key.lwe_dimension(),
+            CiphertextCount(input.plaintext_count().0),
+            CompressionSeed {
+                seed: self.seeder.seed(),
+            },
+        );
+        key.0
+            .encrypt_seeded_lwe_list::<_, _, _, _, _, ActivatedRandomGenerator>(
+                &mut vector,
+                &input.0,
+                noise,
+                &mut self.seeder,
+            );
+        vector
+            .seeded_ciphertext_iter()
+            .map(LweSeededCiphertext32)
+            .collect()
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweSeededCiphertextBatchEncryptionEngine`] for [`DefaultEngine`] that
+/// operates on 64 bits integers.
+impl
+    LweSeededCiphertextBatchEncryptionEngine<
+        LweSecretKey64,
+        PlaintextVector64,
+        LweSeededCiphertext64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let ciphertexts: Vec<LweSeededCiphertext64> =
+    ///     engine.encrypt_lwe_seeded_ciphertext_batch(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(ciphertexts.len(), 3);
+    /// // The ciphertexts share the same seed, and use consecutive parts of the generator stream.
+    /// assert_eq!(ciphertexts[0].compression_seed(), ciphertexts[2].compression_seed());
+    /// assert_eq!(ciphertexts[0].generator_byte_index(), 0);
+    /// assert_eq!(
+    ///     ciphertexts[2].generator_byte_index(),
+    ///     2 * lwe_dimension.0 * std::mem::size_of::<u64>()
+    /// );
+    ///
+    /// // Each ciphertext can be expanded on its own.
+    /// let ciphertext: LweCiphertext64 =
+    ///     engine.transform_lwe_seeded_ciphertext_to_lwe_ciphertext(ciphertexts[2].clone())?;
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_seeded_ciphertext_batch(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> Result<
+        Vec<LweSeededCiphertext64>,
+        LweSeededCiphertextBatchEncryptionError<Self::EngineError>,
+    > {
+        Ok(unsafe { self.encrypt_lwe_seeded_ciphertext_batch_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_lwe_seeded_ciphertext_batch_unchecked(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> Vec<LweSeededCiphertext64> {
+        let mut vector = ImplLweSeededList::allocate(
+            key.lwe_dimension(),
+            CiphertextCount(input.plaintext_count().0),
+            CompressionSeed {
+                seed: self.seeder.seed(),
+            },
+        );
+        key.0
+            .encrypt_seeded_lwe_list::<_, _, _, _, _, ActivatedRandomGenerator>(
+                &mut vector,
+                &input.0,
+                noise,
+                &mut self.seeder,
+            );
+        vector
+            .seeded_ciphertext_iter()
+            .map(LweSeededCiphertext64)
+            .collect()
+    }
+}
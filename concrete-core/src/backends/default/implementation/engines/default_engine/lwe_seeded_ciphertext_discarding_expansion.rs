@@ -0,0 +1,132 @@
+use crate::backends::default::engines::DefaultEngine;
+use crate::backends::default::entities::{
+    LweCiphertext32, LweCiphertext64, LweSeededCiphertext32, LweSeededCiphertext64,
+};
+use crate::commons::math::random::ByteRandomGenerator;
+use crate::specification::engines::{
+    LweSeededCiphertextDiscardingExpansionEngine, LweSeededCiphertextDiscardingExpansionError,
+};
+
+/// # Description:
+/// Implementation of [`LweSeededCiphertextDiscardingExpansionEngine`] for [`DefaultEngine`] that operates on 32 bits integers.
+impl LweSeededCiphertextDiscardingExpansionEngine<LweSeededCiphertext32, LweCiphertext32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let seeded_ciphertext = engine.encrypt_lwe_seeded_ciphertext(&key, &plaintext, noise)?;
+    /// // The output ciphertext was allocated beforehand, for instance by a previous expansion.
+    /// let mut ciphertext =
+    ///     engine.transform_lwe_seeded_ciphertext_to_lwe_ciphertext(seeded_ciphertext.clone())?;
+    ///
+    /// // The masks are regenerated with a generator chosen by the caller.
+    /// engine.discard_expand_lwe_seeded_ciphertext::<SoftwareRandomGenerator>(
+    ///     &mut ciphertext,
+    ///     &seeded_ciphertext,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_expand_lwe_seeded_ciphertext<Generator: ByteRandomGenerator>(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &LweSeededCiphertext32,
+    ) -> Result<(), LweSeededCiphertextDiscardingExpansionError<Self::EngineError>> {
+        LweSeededCiphertextDiscardingExpansionError::perform_generic_checks(output, input)?;
+        unsafe { self.discard_expand_lwe_seeded_ciphertext_unchecked::<Generator>(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_expand_lwe_seeded_ciphertext_unchecked<Generator: ByteRandomGenerator>(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &LweSeededCiphertext32,
+    ) {
+        input.0.expand_into::<_, Generator>(&mut output.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweSeededCiphertextDiscardingExpansionEngine`] for [`DefaultEngine`] that operates on 64 bits integers.
+impl LweSeededCiphertextDiscardingExpansionEngine<LweSeededCiphertext64, LweCiphertext64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let seeded_ciphertext = engine.encrypt_lwe_seeded_ciphertext(&key, &plaintext, noise)?;
+    /// // The output ciphertext was allocated beforehand, for instance by a previous expansion.
+    /// let mut ciphertext =
+    ///     engine.transform_lwe_seeded_ciphertext_to_lwe_ciphertext(seeded_ciphertext.clone())?;
+    ///
+    /// // The masks are regenerated with a generator chosen by the caller.
+    /// engine.discard_expand_lwe_seeded_ciphertext::<SoftwareRandomGenerator>(
+    ///     &mut ciphertext,
+    ///     &seeded_ciphertext,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_expand_lwe_seeded_ciphertext<Generator: ByteRandomGenerator>(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &LweSeededCiphertext64,
+    ) -> Result<(), LweSeededCiphertextDiscardingExpansionError<Self::EngineError>> {
+        LweSeededCiphertextDiscardingExpansionError::perform_generic_checks(output, input)?;
+        unsafe { self.discard_expand_lwe_seeded_ciphertext_unchecked::<Generator>(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_expand_lwe_seeded_ciphertext_unchecked<Generator: ByteRandomGenerator>(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &LweSeededCiphertext64,
+    ) {
+        input.0.expand_into::<_, Generator>(&mut output.0);
+    }
+}
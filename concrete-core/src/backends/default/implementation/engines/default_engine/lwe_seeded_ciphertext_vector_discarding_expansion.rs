@@ -0,0 +1,156 @@
+use crate::backends::default::engines::DefaultEngine;
+use crate::backends::default::entities::{
+    LweCiphertextVector32, LweCiphertextVector64, LweSeededCiphertextVector32,
+    LweSeededCiphertextVector64,
+};
+use crate::commons::math::random::ByteRandomGenerator;
+use crate::specification::engines::{
+    LweSeededCiphertextVectorDiscardingExpansionEngine,
+    LweSeededCiphertextVectorDiscardingExpansionError,
+};
+
+/// # Description:
+/// Implementation of [`LweSeededCiphertextVectorDiscardingExpansionEngine`] for [`DefaultEngine`] that operates on 32 bits integers.
+impl
+    LweSeededCiphertextVectorDiscardingExpansionEngine<
+        LweSeededCiphertextVector32,
+        LweCiphertextVector32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let seeded_ciphertext_vector: LweSeededCiphertextVector32 =
+    ///     engine.encrypt_lwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// // The output vector was allocated beforehand, for instance by a previous expansion.
+    /// let mut ciphertext_vector = engine
+    ///     .transform_lwe_seeded_ciphertext_vector_to_lwe_ciphertext_vector(
+    ///         seeded_ciphertext_vector.clone(),
+    ///     )?;
+    ///
+    /// // The masks are regenerated with a generator chosen by the caller.
+    /// engine.discard_expand_lwe_seeded_ciphertext_vector::<SoftwareRandomGenerator>(
+    ///     &mut ciphertext_vector,
+    ///     &seeded_ciphertext_vector,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(ciphertext_vector.lwe_ciphertext_count(), LweCiphertextCount(3));
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_expand_lwe_seeded_ciphertext_vector<Generator: ByteRandomGenerator>(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &LweSeededCiphertextVector32,
+    ) -> Result<(), LweSeededCiphertextVectorDiscardingExpansionError<Self::EngineError>> {
+        LweSeededCiphertextVectorDiscardingExpansionError::perform_generic_checks(output, input)?;
+        unsafe {
+            self.discard_expand_lwe_seeded_ciphertext_vector_unchecked::<Generator>(output, input)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_expand_lwe_seeded_ciphertext_vector_unchecked<
+        Generator: ByteRandomGenerator,
+    >(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &LweSeededCiphertextVector32,
+    ) {
+        input.0.expand_into::<_, _, Generator>(&mut output.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweSeededCiphertextVectorDiscardingExpansionEngine`] for [`DefaultEngine`] that operates on 64 bits integers.
+impl
+    LweSeededCiphertextVectorDiscardingExpansionEngine<
+        LweSeededCiphertextVector64,
+        LweCiphertextVector64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let seeded_ciphertext_vector: LweSeededCiphertextVector64 =
+    ///     engine.encrypt_lwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// // The output vector was allocated beforehand, for instance by a previous expansion.
+    /// let mut ciphertext_vector = engine
+    ///     .transform_lwe_seeded_ciphertext_vector_to_lwe_ciphertext_vector(
+    ///         seeded_ciphertext_vector.clone(),
+    ///     )?;
+    ///
+    /// // The masks are regenerated with a generator chosen by the caller.
+    /// engine.discard_expand_lwe_seeded_ciphertext_vector::<SoftwareRandomGenerator>(
+    ///     &mut ciphertext_vector,
+    ///     &seeded_ciphertext_vector,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(ciphertext_vector.lwe_ciphertext_count(), LweCiphertextCount(3));
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_expand_lwe_seeded_ciphertext_vector<Generator: ByteRandomGenerator>(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &LweSeededCiphertextVector64,
+    ) -> Result<(), LweSeededCiphertextVectorDiscardingExpansionError<Self::EngineError>> {
+        LweSeededCiphertextVectorDiscardingExpansionError::perform_generic_checks(output, input)?;
+        unsafe {
+            self.discard_expand_lwe_seeded_ciphertext_vector_unchecked::<Generator>(output, input)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_expand_lwe_seeded_ciphertext_vector_unchecked<
+        Generator: ByteRandomGenerator,
+    >(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &LweSeededCiphertextVector64,
+    ) {
+        input.0.expand_into::<_, _, Generator>(&mut output.0);
+    }
+}
@@ -0,0 +1,156 @@
+use crate::backends::default::engines::DefaultEngine;
+use crate::backends::default::entities::{
+    LweKeyswitchKey32, LweKeyswitchKey64, LweSeededKeyswitchKey32, LweSeededKeyswitchKey64,
+};
+use crate::commons::math::random::ByteRandomGenerator;
+use crate::specification::engines::{
+    LweSeededKeyswitchKeyDiscardingExpansionEngine, LweSeededKeyswitchKeyDiscardingExpansionError,
+};
+
+/// # Description:
+/// Implementation of [`LweSeededKeyswitchKeyDiscardingExpansionEngine`] for [`DefaultEngine`] that operates on 32 bits integers.
+impl LweSeededKeyswitchKeyDiscardingExpansionEngine<LweSeededKeyswitchKey32, LweKeyswitchKey32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let seeded_keyswitch_key = engine.generate_new_lwe_seeded_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// // The output key was allocated beforehand, for instance by a previous expansion.
+    /// let mut keyswitch_key = engine
+    ///     .transform_lwe_seeded_keyswitch_key_to_lwe_keyswitch_key(seeded_keyswitch_key.clone())?;
+    ///
+    /// // The masks are regenerated with a generator chosen by the caller.
+    /// engine.discard_expand_lwe_seeded_keyswitch_key::<SoftwareRandomGenerator>(
+    ///     &mut keyswitch_key,
+    ///     &seeded_keyswitch_key,
+    /// )?;
+    /// #
+    /// assert_eq!(keyswitch_key.decomposition_level_count(), decomposition_level_count);
+    /// assert_eq!(keyswitch_key.decomposition_base_log(), decomposition_base_log);
+    /// assert_eq!(keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_expand_lwe_seeded_keyswitch_key<Generator: ByteRandomGenerator>(
+        &mut self,
+        output: &mut LweKeyswitchKey32,
+        input: &LweSeededKeyswitchKey32,
+    ) -> Result<(), LweSeededKeyswitchKeyDiscardingExpansionError<Self::EngineError>> {
+        LweSeededKeyswitchKeyDiscardingExpansionError::perform_generic_checks(output, input)?;
+        unsafe {
+            self.discard_expand_lwe_seeded_keyswitch_key_unchecked::<Generator>(output, input)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_expand_lwe_seeded_keyswitch_key_unchecked<Generator: ByteRandomGenerator>(
+        &mut self,
+        output: &mut LweKeyswitchKey32,
+        input: &LweSeededKeyswitchKey32,
+    ) {
+        input.0.expand_into::<_, _, Generator>(&mut output.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweSeededKeyswitchKeyDiscardingExpansionEngine`] for [`DefaultEngine`] that operates on 64 bits integers.
+impl LweSeededKeyswitchKeyDiscardingExpansionEngine<LweSeededKeyswitchKey64, LweKeyswitchKey64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let seeded_keyswitch_key = engine.generate_new_lwe_seeded_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// // The output key was allocated beforehand, for instance by a previous expansion.
+    /// let mut keyswitch_key = engine
+    ///     .transform_lwe_seeded_keyswitch_key_to_lwe_keyswitch_key(seeded_keyswitch_key.clone())?;
+    ///
+    /// // The masks are regenerated with a generator chosen by the caller.
+    /// engine.discard_expand_lwe_seeded_keyswitch_key::<SoftwareRandomGenerator>(
+    ///     &mut keyswitch_key,
+    ///     &seeded_keyswitch_key,
+    /// )?;
+    /// #
+    /// assert_eq!(keyswitch_key.decomposition_level_count(), decomposition_level_count);
+    /// assert_eq!(keyswitch_key.decomposition_base_log(), decomposition_base_log);
+    /// assert_eq!(keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_expand_lwe_seeded_keyswitch_key<Generator: ByteRandomGenerator>(
+        &mut self,
+        output: &mut LweKeyswitchKey64,
+        input: &LweSeededKeyswitchKey64,
+    ) -> Result<(), LweSeededKeyswitchKeyDiscardingExpansionError<Self::EngineError>> {
+        LweSeededKeyswitchKeyDiscardingExpansionError::perform_generic_checks(output, input)?;
+        unsafe {
+            self.discard_expand_lwe_seeded_keyswitch_key_unchecked::<Generator>(output, input)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_expand_lwe_seeded_keyswitch_key_unchecked<Generator: ByteRandomGenerator>(
+        &mut self,
+        output: &mut LweKeyswitchKey64,
+        input: &LweSeededKeyswitchKey64,
+    ) {
+        input.0.expand_into::<_, _, Generator>(&mut output.0);
+    }
+}
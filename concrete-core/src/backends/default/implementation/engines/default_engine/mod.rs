@@ -1,10 +1,17 @@
+use crate::commons::crypto::encoding::{
+    Cleartext as ImplCleartext, Encoder, FloatEncoder as ImplFloatEncoder,
+    Plaintext as ImplPlaintext,
+};
 use crate::commons::crypto::secret::generators::{
     DeterministicSeeder as ImplDeterministicSeeder,
     EncryptionRandomGenerator as ImplEncryptionRandomGenerator,
     SecretRandomGenerator as ImplSecretRandomGenerator,
 };
+use crate::commons::math::random::RandomGenerator as ImplRandomGenerator;
+use crate::commons::math::torus::UnsignedTorus;
 use crate::specification::engines::sealed::AbstractEngineSeal;
 use crate::specification::engines::AbstractEngine;
+use concrete_commons::parameters::RoundingMode;
 #[cfg(feature = "backend_default_generator_x86_64_aesni")]
 use concrete_csprng::generators::AesniRandomGenerator;
 #[cfg(not(feature = "backend_default_generator_x86_64_aesni"))]
@@ -25,6 +32,17 @@ pub enum DefaultError {
     FloatEncoderNullPrecision,
     FloatEncoderMinMaxOrder,
     FloatEncoderNullRadius,
+    ModularEncoderInvalidModulus,
+    ModularEncoderModulusTooLarge,
+    ModularEncoderMessageOutsideModulus,
+    ModularEncoderNullPadding,
+    ModularEncoderPolynomialSizeTooSmall,
+    ModularEncoderTableSizeMismatch,
+    ModularEncoderInvalidByteModulus,
+    ModularEncoderNoiseTooLarge,
+    ModularEncoderByteCountMismatch,
+    RawDumpNonBinarySecretKey,
+    KeyFingerprintMismatch,
 }
 
 impl Display for DefaultError {
@@ -45,6 +63,53 @@ impl Display for DefaultError {
             DefaultError::FloatEncoderNullRadius => {
                 write!(f, "Tried to create a float encoder with null radius.")
             }
+            DefaultError::ModularEncoderInvalidModulus => {
+                write!(
+                    f,
+                    "Tried to create a modular encoder with a modulus below two."
+                )
+            }
+            DefaultError::ModularEncoderModulusTooLarge => write!(
+                f,
+                "The modular encoder modulus and padding do not fit in the plaintext precision."
+            ),
+            DefaultError::ModularEncoderMessageOutsideModulus => write!(
+                f,
+                "Tried to encode a message larger than the modular encoder modulus."
+            ),
+            DefaultError::ModularEncoderNullPadding => write!(
+                f,
+                "The modular encoder of a lookup table input must have one bit of padding at least."
+            ),
+            DefaultError::ModularEncoderPolynomialSizeTooSmall => write!(
+                f,
+                "The polynomial size is too small to hold one coefficient per message of the \
+                 lookup table input."
+            ),
+            DefaultError::ModularEncoderTableSizeMismatch => write!(
+                f,
+                "The lookup table size must be the modulus of the input modular encoder."
+            ),
+            DefaultError::ModularEncoderInvalidByteModulus => write!(
+                f,
+                "The modulus of a modular encoder used on bytes must be 2, 4, 16 or 256."
+            ),
+            DefaultError::ModularEncoderNoiseTooLarge => write!(
+                f,
+                "The noise is too large to guarantee the exact decryption of the encoded messages."
+            ),
+            DefaultError::ModularEncoderByteCountMismatch => write!(
+                f,
+                "The ciphertext count must be a multiple of the number of messages per byte."
+            ),
+            DefaultError::RawDumpNonBinarySecretKey => write!(
+                f,
+                "The raw dump of a binary secret key must only contain zeros and ones."
+            ),
+            DefaultError::KeyFingerprintMismatch => write!(
+                f,
+                "The input ciphertext is not encrypted under the input key of the evaluation key."
+            ),
         }
     }
 }
@@ -56,6 +121,11 @@ type ActivatedRandomGenerator = AesniRandomGenerator;
 #[cfg(not(feature = "backend_default_generator_x86_64_aesni"))]
 type ActivatedRandomGenerator = SoftwareRandomGenerator;
 
+/// The main engine exposed by the default backend.
+///
+/// When the `backend_default_debug` feature is enabled, the keyswitch and bootstrap operations of
+/// the engine check that their input ciphertexts are encrypted under the input key of the
+/// evaluation key, and return a [`DefaultError::KeyFingerprintMismatch`] error otherwise.
 pub struct DefaultEngine {
     /// A structure containing a single CSPRNG to generate secret key coefficients.
     secret_generator: ImplSecretRandomGenerator<ActivatedRandomGenerator>,
@@ -69,7 +139,72 @@ pub struct DefaultEngine {
     /// A seeder that can be called to generate 128 bits seeds, useful to create new
     /// [`ImplEncryptionRandomGenerator`] to encrypt seeded types.
     seeder: ImplDeterministicSeeder<ActivatedRandomGenerator>,
+    /// The rounding mode used when decoding decrypted plaintexts and decomposing ciphertexts.
+    rounding_mode: Option<RoundingMode>,
+    /// A CSPRNG used for stochastic rounding, only created when this rounding mode is first set.
+    rounding_generator: Option<ImplRandomGenerator<ActivatedRandomGenerator>>,
 }
+
+impl DefaultEngine {
+    /// Sets the rounding mode used by this engine when decoding decrypted plaintexts with a float
+    /// encoder, and when decomposing the mask of ciphertexts during a keyswitch.
+    ///
+    /// By default, no rounding mode is set: the plaintexts are decoded without being rounded, and
+    /// the decompositions round to the nearest representable value, ties being rounded up. When a
+    /// rounding mode is set, the plaintexts are rounded to the precision of the encoder before
+    /// being decoded.
+    ///
+    /// # Example
+    /// ```
+    /// use concrete_commons::parameters::RoundingMode;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// assert_eq!(engine.rounding_mode(), None);
+    ///
+    /// engine.set_rounding_mode(Some(RoundingMode::Stochastic));
+    /// assert_eq!(engine.rounding_mode(), Some(RoundingMode::Stochastic));
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_rounding_mode(&mut self, rounding_mode: Option<RoundingMode>) {
+        // The generator is only seeded when needed, to leave the seeder untouched otherwise.
+        if rounding_mode == Some(RoundingMode::Stochastic) && self.rounding_generator.is_none() {
+            self.rounding_generator = Some(ImplRandomGenerator::new(self.seeder.seed()));
+        }
+        self.rounding_mode = rounding_mode;
+    }
+
+    /// Returns the rounding mode used by this engine, if any.
+    pub fn rounding_mode(&self) -> Option<RoundingMode> {
+        self.rounding_mode
+    }
+
+    /// Decodes a plaintext with a float encoder, rounding it with the rounding mode of the engine
+    /// if one is set.
+    pub(crate) fn decode_with_rounding_mode<Scalar>(
+        &mut self,
+        encoder: &ImplFloatEncoder,
+        plaintext: ImplPlaintext<Scalar>,
+    ) -> ImplCleartext<f64>
+    where
+        Scalar: UnsignedTorus,
+    {
+        match self.rounding_mode {
+            None => encoder.decode(plaintext),
+            Some(rounding) => {
+                encoder.decode_with_rounding(plaintext, rounding, self.rounding_generator.as_mut())
+            }
+        }
+    }
+}
+
 impl AbstractEngineSeal for DefaultEngine {}
 
 impl AbstractEngine for DefaultEngine {
@@ -92,10 +227,31 @@ impl AbstractEngine for DefaultEngine {
                 &mut deterministic_seeder,
             ),
             seeder: deterministic_seeder,
+            rounding_mode: None,
+            rounding_generator: None,
         })
     }
 }
 
+// When the `backend_default_debug` feature is enabled, checks that the fingerprint of the key the
+// input ciphertext is encrypted under matches the fingerprint of the input key of the evaluation
+// key. Using an evaluation key generated for another secret key would otherwise go unnoticed, and
+// silently produce garbage.
+macro_rules! check_key_fingerprint {
+    ($error: ident, $evaluation_key: expr, $input: expr) => {
+        #[cfg(feature = "backend_default_debug")]
+        {
+            use crate::backends::default::implementation::engines::DefaultError;
+            if !$evaluation_key
+                .input_key_fingerprint()
+                .is_compatible_with(&$input.key_fingerprint())
+            {
+                return Err($error::Engine(DefaultError::KeyFingerprintMismatch));
+            }
+        }
+    };
+}
+
 mod cleartext_creation;
 mod cleartext_discarding_retrieval;
 mod cleartext_encoding;
@@ -106,47 +262,85 @@ mod cleartext_vector_encoding;
 mod cleartext_vector_retrieval;
 mod encoder_creation;
 mod encoder_vector_creation;
+mod entity_raw_export;
+mod entity_raw_import;
+mod entity_validation;
+mod ggsw_ciphertext_conversion;
 mod ggsw_ciphertext_scalar_discarding_encryption;
 mod ggsw_ciphertext_scalar_encryption;
+mod ggsw_ciphertext_scalar_seeded_encryption;
 mod ggsw_ciphertext_scalar_trivial_encryption;
+mod ggsw_seeded_ciphertext_to_ggsw_ciphertext_transformation;
+mod glev_ciphertext_decryption;
+mod glev_ciphertext_encryption;
+mod glwe_automorphism_keys_generation;
 mod glwe_ciphertext_consuming_retrieval;
 mod glwe_ciphertext_creation;
 mod glwe_ciphertext_decryption;
 mod glwe_ciphertext_discarding_decryption;
 mod glwe_ciphertext_discarding_encryption;
+mod glwe_ciphertext_discarding_ring_reduction;
+mod glwe_ciphertext_discarding_ring_switching;
 mod glwe_ciphertext_discarding_trivial_encryption;
 mod glwe_ciphertext_encryption;
+mod glwe_ciphertext_fusing_partial_blind_rotation;
+mod glwe_ciphertext_ggsw_ciphertext_discarding_external_product;
+mod glwe_ciphertext_ggsw_ciphertext_discarding_external_product_add;
+mod glwe_ciphertext_ring_splitting;
+mod glwe_ciphertext_sparse_cleartext_polynomial_discarding_multiplication;
 mod glwe_ciphertext_trivial_decryption;
 mod glwe_ciphertext_trivial_encryption;
 mod glwe_ciphertext_vector_decryption;
 mod glwe_ciphertext_vector_discarding_decryption;
 mod glwe_ciphertext_vector_discarding_encryption;
+mod glwe_ciphertext_vector_discarding_variable_noise_encryption;
 mod glwe_ciphertext_vector_encryption;
 mod glwe_ciphertext_vector_trivial_decryption;
 mod glwe_ciphertext_vector_trivial_encryption;
+mod glwe_ciphertext_vector_variable_noise_encryption;
 mod glwe_ciphertext_vector_zero_encryption;
 mod glwe_ciphertext_zero_encryption;
+mod glwe_ciphertexts_ggsw_ciphertext_fusing_cmux;
+mod glwe_relinearization_key_generation;
+mod glwe_ring_reduction_key_generation;
+mod glwe_ring_switching_key_generation;
+mod glwe_secret_key_gaussian_generation;
 mod glwe_secret_key_generation;
+mod glwe_secret_key_ring_splitting;
+mod glwe_secret_key_tensor_product_same_key;
+mod glwe_secret_key_ternary_generation;
+mod glwe_seeded_ciphertext_discarding_expansion;
 mod glwe_seeded_ciphertext_encryption;
 mod glwe_seeded_ciphertext_to_glwe_ciphertext_transformation;
+mod glwe_seeded_ciphertext_vector_discarding_expansion;
 mod glwe_seeded_ciphertext_vector_encryption;
 mod glwe_seeded_vector_to_glwe_ciphertext_vector_transformation;
 mod glwe_to_lwe_secret_key_transformation;
+mod lev_ciphertext_decryption;
+mod lev_ciphertext_encryption;
 mod lwe_bootstrap_key_consuming_retrieval;
 mod lwe_bootstrap_key_creation;
 mod lwe_bootstrap_key_discarding_conversion;
 mod lwe_bootstrap_key_generation;
+mod lwe_bootstrap_key_ggsw_ciphertext_discarding_insertion;
+mod lwe_bootstrap_key_ggsw_ciphertext_extraction;
+mod lwe_bootstrap_key_shard_extraction;
+mod lwe_bootstrap_lookup_table_creation;
 mod lwe_ciphertext_cleartext_discarding_multiplication;
 mod lwe_ciphertext_cleartext_fusing_multiplication;
 mod lwe_ciphertext_consuming_retrieval;
 mod lwe_ciphertext_creation;
 mod lwe_ciphertext_decryption;
+mod lwe_ciphertext_decryption_decoding;
 mod lwe_ciphertext_discarding_addition;
 mod lwe_ciphertext_discarding_decryption;
 mod lwe_ciphertext_discarding_encryption;
 mod lwe_ciphertext_discarding_extraction;
 mod lwe_ciphertext_discarding_keyswitch;
+mod lwe_ciphertext_discarding_keyswitch_modulus_switch;
 mod lwe_ciphertext_discarding_opposite;
+mod lwe_ciphertext_discarding_partial_keyswitch;
+mod lwe_ciphertext_discarding_polynomial_evaluation;
 mod lwe_ciphertext_discarding_public_key_encryption;
 mod lwe_ciphertext_discarding_subtraction;
 mod lwe_ciphertext_encryption;
@@ -157,38 +351,65 @@ mod lwe_ciphertext_plaintext_discarding_addition;
 mod lwe_ciphertext_plaintext_discarding_subtraction;
 mod lwe_ciphertext_plaintext_fusing_addition;
 mod lwe_ciphertext_plaintext_fusing_subtraction;
+mod lwe_ciphertext_public_key_encryption;
+#[cfg(feature = "verifiable_decryption")]
+mod lwe_ciphertext_transcript_decryption;
 mod lwe_ciphertext_trivial_decryption;
 mod lwe_ciphertext_trivial_encryption;
+mod lwe_ciphertext_vector_bytes_decryption;
+mod lwe_ciphertext_vector_bytes_encryption;
 mod lwe_ciphertext_vector_consuming_retrieval;
 mod lwe_ciphertext_vector_creation;
 mod lwe_ciphertext_vector_decryption;
+mod lwe_ciphertext_vector_decryption_decoding;
 mod lwe_ciphertext_vector_discarding_addition;
 mod lwe_ciphertext_vector_discarding_affine_transformation;
+mod lwe_ciphertext_vector_discarding_affine_transformation_keyswitch;
+mod lwe_ciphertext_vector_discarding_bootstrap;
 mod lwe_ciphertext_vector_discarding_decryption;
 mod lwe_ciphertext_vector_discarding_encryption;
+mod lwe_ciphertext_vector_discarding_gathering;
+mod lwe_ciphertext_vector_discarding_keyswitch;
+mod lwe_ciphertext_vector_discarding_permutation;
+mod lwe_ciphertext_vector_discarding_scattering;
 mod lwe_ciphertext_vector_discarding_subtraction;
 mod lwe_ciphertext_vector_encryption;
 mod lwe_ciphertext_vector_fusing_addition;
 mod lwe_ciphertext_vector_fusing_subtraction;
 mod lwe_ciphertext_vector_glwe_ciphertext_discarding_packing_keyswitch;
 mod lwe_ciphertext_vector_glwe_ciphertext_discarding_private_functional_packing_keyswitch;
+mod lwe_ciphertext_vector_glwe_ciphertext_discarding_tree_packing;
+mod lwe_ciphertext_vector_streaming_decryption;
+mod lwe_ciphertext_vector_streaming_encryption;
 mod lwe_ciphertext_vector_trivial_decryption;
 mod lwe_ciphertext_vector_trivial_encryption;
 mod lwe_ciphertext_vector_zero_encryption;
 mod lwe_ciphertext_zero_encryption;
 mod lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys_generation;
+mod lwe_keyswitch_key_conversion;
 mod lwe_keyswitch_key_generation;
 mod lwe_packing_keyswitch_key_generation;
+mod lwe_partial_keyswitch_key_generation;
 mod lwe_private_functional_packing_keyswitch_key_generation;
 mod lwe_public_key_generation;
+mod lwe_secret_key_gaussian_generation;
 mod lwe_secret_key_generation;
+mod lwe_secret_key_partial_keyswitch;
+mod lwe_secret_key_ternary_generation;
+mod lwe_seeded_bootstrap_key_discarding_expansion;
 mod lwe_seeded_bootstrap_key_generation;
 mod lwe_seeded_bootstrap_key_to_lwe_bootstrap_key_transformation;
+mod lwe_seeded_ciphertext_batch_encryption;
+mod lwe_seeded_ciphertext_discarding_expansion;
 mod lwe_seeded_ciphertext_encryption;
+mod lwe_seeded_ciphertext_vector_discarding_expansion;
 mod lwe_seeded_ciphertext_vector_encryption;
 mod lwe_seeded_ciphertext_vector_to_lwe_ciphertext_vector_transformation;
+mod lwe_seeded_keyswitch_key_discarding_expansion;
 mod lwe_seeded_keyswitch_key_generation;
 mod lwe_seeded_keyswitch_key_to_lwe_keyswitch_key_transformation;
+mod lwe_seeded_packing_keyswitch_key_generation;
+mod lwe_seeded_packing_keyswitch_key_to_lwe_packing_keyswitch_key_transformation;
 mod lwe_seeded_to_lwe_ciphertext_transformation;
 mod lwe_to_glwe_secret_key_transformation;
 mod plaintext_creation;
@@ -198,4 +419,12 @@ mod plaintext_retrieval;
 mod plaintext_vector_creation;
 mod plaintext_vector_decoding;
 mod plaintext_vector_discarding_retrieval;
+mod plaintext_vector_iterator_creation;
+mod plaintext_vector_lookup_table_creation;
 mod plaintext_vector_retrieval;
+mod plaintext_vector_view_creation;
+mod sparse_cleartext_polynomial_creation;
+mod trivial_glwe_ciphertext_creation;
+mod trivial_glwe_ciphertext_to_glwe_ciphertext_transformation;
+mod trivial_lwe_ciphertext_creation;
+mod trivial_lwe_ciphertext_to_lwe_ciphertext_transformation;
@@ -1,8 +1,42 @@
 use crate::backends::default::implementation::engines::DefaultEngine;
-use crate::backends::default::implementation::entities::{Plaintext32, Plaintext64};
+use crate::backends::default::implementation::entities::{Plaintext16, Plaintext32, Plaintext64};
 use crate::commons::crypto::encoding::Plaintext as ImplPlaintext;
 use crate::specification::engines::{PlaintextCreationEngine, PlaintextCreationError};
 
+/// # Description:
+/// Implementation of [`PlaintextCreationEngine`] for [`DefaultEngine`] that operates on
+/// 16 bits integers.
+impl PlaintextCreationEngine<u16, Plaintext16> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 12 bits)
+    /// let input = 3_u16 << 12;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let plaintext: Plaintext16 = engine.create_plaintext_from(&input)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_plaintext_from(
+        &mut self,
+        input: &u16,
+    ) -> Result<Plaintext16, PlaintextCreationError<Self::EngineError>> {
+        Ok(unsafe { self.create_plaintext_from_unchecked(input) })
+    }
+
+    unsafe fn create_plaintext_from_unchecked(&mut self, input: &u16) -> Plaintext16 {
+        Plaintext16(ImplPlaintext(*input))
+    }
+}
+
 /// # Description:
 /// Implementation of [`PlaintextCreationEngine`] for [`DefaultEngine`] that operates on
 /// 32 bits integers.
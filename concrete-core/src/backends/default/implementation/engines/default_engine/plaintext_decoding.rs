@@ -1,7 +1,7 @@
 use crate::commons::crypto::encoding::Encoder;
 use crate::prelude::{
-    CleartextF64, DefaultEngine, FloatEncoder, Plaintext32, Plaintext64, PlaintextDecodingEngine,
-    PlaintextDecodingError,
+    Cleartext32, Cleartext64, CleartextF64, DefaultEngine, DefaultError, FloatEncoder,
+    ModularEncoder, Plaintext32, Plaintext64, PlaintextDecodingEngine, PlaintextDecodingError,
 };
 
 /// # Description:
@@ -89,3 +89,97 @@ impl PlaintextDecodingEngine<FloatEncoder, Plaintext64, CleartextF64> for Defaul
         CleartextF64(encoder.0.decode(input.0))
     }
 }
+
+/// # Description:
+/// Implementation of [`PlaintextDecodingEngine`] for [`DefaultEngine`] that decodes 32 bits
+/// integers to 32 bits integers modulo an arbitrary modulus, by rounding to the closest message.
+impl PlaintextDecodingEngine<ModularEncoder, Plaintext32, Cleartext32> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let encoder = engine.create_encoder_from(&ModularEncoderConfig {
+    ///     modulus: 5,
+    ///     nb_bit_padding: 1,
+    /// })?;
+    /// let cleartext: Cleartext32 = engine.create_cleartext_from(&3_u32)?;
+    /// let plaintext: Plaintext32 = engine.encode_cleartext(&encoder, &cleartext)?;
+    /// let recovered_cleartext: Cleartext32 = engine.decode_plaintext(&encoder, &plaintext)?;
+    /// assert_eq!(engine.retrieve_cleartext(&recovered_cleartext)?, 3_u32);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decode_plaintext(
+        &mut self,
+        encoder: &ModularEncoder,
+        input: &Plaintext32,
+    ) -> Result<Cleartext32, PlaintextDecodingError<Self::EngineError>> {
+        if !encoder.0.fits_in_precision(32) {
+            return Err(PlaintextDecodingError::Engine(
+                DefaultError::ModularEncoderModulusTooLarge,
+            ));
+        }
+        Ok(unsafe { self.decode_plaintext_unchecked(input, encoder) })
+    }
+
+    unsafe fn decode_plaintext_unchecked(
+        &mut self,
+        input: &Plaintext32,
+        encoder: &ModularEncoder,
+    ) -> Cleartext32 {
+        Cleartext32(encoder.0.decode(input.0))
+    }
+}
+
+/// # Description:
+/// Implementation of [`PlaintextDecodingEngine`] for [`DefaultEngine`] that decodes 64 bits
+/// integers to 64 bits integers modulo an arbitrary modulus, by rounding to the closest message.
+impl PlaintextDecodingEngine<ModularEncoder, Plaintext64, Cleartext64> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let encoder = engine.create_encoder_from(&ModularEncoderConfig {
+    ///     modulus: 5,
+    ///     nb_bit_padding: 1,
+    /// })?;
+    /// let cleartext: Cleartext64 = engine.create_cleartext_from(&3_u64)?;
+    /// let plaintext: Plaintext64 = engine.encode_cleartext(&encoder, &cleartext)?;
+    /// let recovered_cleartext: Cleartext64 = engine.decode_plaintext(&encoder, &plaintext)?;
+    /// assert_eq!(engine.retrieve_cleartext(&recovered_cleartext)?, 3_u64);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decode_plaintext(
+        &mut self,
+        encoder: &ModularEncoder,
+        input: &Plaintext64,
+    ) -> Result<Cleartext64, PlaintextDecodingError<Self::EngineError>> {
+        if !encoder.0.fits_in_precision(64) {
+            return Err(PlaintextDecodingError::Engine(
+                DefaultError::ModularEncoderModulusTooLarge,
+            ));
+        }
+        Ok(unsafe { self.decode_plaintext_unchecked(input, encoder) })
+    }
+
+    unsafe fn decode_plaintext_unchecked(
+        &mut self,
+        input: &Plaintext64,
+        encoder: &ModularEncoder,
+    ) -> Cleartext64 {
+        Cleartext64(encoder.0.decode(input.0))
+    }
+}
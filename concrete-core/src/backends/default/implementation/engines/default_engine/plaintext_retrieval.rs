@@ -1,7 +1,44 @@
 use crate::backends::default::implementation::engines::DefaultEngine;
-use crate::backends::default::implementation::entities::{Plaintext32, Plaintext64};
+use crate::backends::default::implementation::entities::{Plaintext16, Plaintext32, Plaintext64};
 use crate::specification::engines::{PlaintextRetrievalEngine, PlaintextRetrievalError};
 
+/// # Description:
+/// Implementation of [`PlaintextRetrievalEngine`] for [`DefaultEngine`] that operates on 16 bits
+/// integers.
+impl PlaintextRetrievalEngine<Plaintext16, u16> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 12 bits)
+    /// let input = 3_u16 << 12;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let plaintext: Plaintext16 = engine.create_plaintext_from(&input)?;
+    /// let output: u16 = engine.retrieve_plaintext(&plaintext)?;
+    ///
+    /// assert_eq!(output, 3_u16 << 12);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn retrieve_plaintext(
+        &mut self,
+        plaintext: &Plaintext16,
+    ) -> Result<u16, PlaintextRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.retrieve_plaintext_unchecked(plaintext) })
+    }
+
+    unsafe fn retrieve_plaintext_unchecked(&mut self, plaintext: &Plaintext16) -> u16 {
+        plaintext.0 .0
+    }
+}
+
 /// # Description:
 /// Implementation of [`PlaintextRetrievalEngine`] for [`DefaultEngine`] that operates on 64 bits
 /// integers.
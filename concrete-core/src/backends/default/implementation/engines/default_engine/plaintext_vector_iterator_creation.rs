@@ -0,0 +1,111 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{PlaintextVector32, PlaintextVector64};
+use crate::commons::crypto::encoding::PlaintextList as ImplPlaintextList;
+use crate::specification::engines::{
+    PlaintextVectorIteratorCreationEngine, PlaintextVectorIteratorCreationError,
+};
+use crate::specification::entities::PlaintextVectorEntity;
+
+/// # Description:
+/// Implementation of [`PlaintextVectorIteratorCreationEngine`] for [`DefaultEngine`] that operates
+/// on 32 bits integers.
+impl PlaintextVectorIteratorCreationEngine<u32, PlaintextVector32> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::PlaintextCount;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = (0..3_u32).map(|message| message << 20);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from_iter(input)?;
+    /// #
+    /// assert_eq!(plaintext_vector.plaintext_count(), PlaintextCount(3));
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_plaintext_vector_from_iter<Values>(
+        &mut self,
+        values: Values,
+    ) -> Result<PlaintextVector32, PlaintextVectorIteratorCreationError<Self::EngineError>>
+    where
+        Values: IntoIterator<Item = u32>,
+    {
+        let plaintext_vector = unsafe { self.create_plaintext_vector_from_iter_unchecked(values) };
+        PlaintextVectorIteratorCreationError::perform_generic_checks(
+            plaintext_vector.plaintext_count(),
+        )?;
+        Ok(plaintext_vector)
+    }
+
+    unsafe fn create_plaintext_vector_from_iter_unchecked<Values>(
+        &mut self,
+        values: Values,
+    ) -> PlaintextVector32
+    where
+        Values: IntoIterator<Item = u32>,
+    {
+        PlaintextVector32(ImplPlaintextList::from_container(
+            values.into_iter().collect::<Vec<_>>(),
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`PlaintextVectorIteratorCreationEngine`] for [`DefaultEngine`] that operates
+/// on 64 bits integers.
+impl PlaintextVectorIteratorCreationEngine<u64, PlaintextVector64> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::PlaintextCount;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = (0..3_u64).map(|message| message << 50);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from_iter(input)?;
+    /// #
+    /// assert_eq!(plaintext_vector.plaintext_count(), PlaintextCount(3));
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_plaintext_vector_from_iter<Values>(
+        &mut self,
+        values: Values,
+    ) -> Result<PlaintextVector64, PlaintextVectorIteratorCreationError<Self::EngineError>>
+    where
+        Values: IntoIterator<Item = u64>,
+    {
+        let plaintext_vector = unsafe { self.create_plaintext_vector_from_iter_unchecked(values) };
+        PlaintextVectorIteratorCreationError::perform_generic_checks(
+            plaintext_vector.plaintext_count(),
+        )?;
+        Ok(plaintext_vector)
+    }
+
+    unsafe fn create_plaintext_vector_from_iter_unchecked<Values>(
+        &mut self,
+        values: Values,
+    ) -> PlaintextVector64
+    where
+        Values: IntoIterator<Item = u64>,
+    {
+        PlaintextVector64(ImplPlaintextList::from_container(
+            values.into_iter().collect::<Vec<_>>(),
+        ))
+    }
+}
@@ -0,0 +1,200 @@
+use crate::commons::crypto::encoding::{
+    ModularEncoder as ImplModularEncoder, PlaintextList as ImplPlaintextList,
+};
+use crate::commons::math::tensor::AsRefTensor;
+use crate::prelude::{
+    CleartextVector32, CleartextVector64, DefaultEngine, DefaultError, ModularEncoder,
+    PlaintextVector32, PlaintextVector64, PlaintextVectorLookupTableCreationEngine,
+    PlaintextVectorLookupTableCreationError,
+};
+use concrete_commons::parameters::{PlaintextCount, PolynomialSize};
+
+/// # Description:
+/// Implementation of [`PlaintextVectorLookupTableCreationEngine`] for [`DefaultEngine`] that
+/// creates a lookup table of 32 bits integers, mapping messages encoded with a modular encoder.
+impl PlaintextVectorLookupTableCreationEngine<ModularEncoder, CleartextVector32, PlaintextVector32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweSize, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let polynomial_size = PolynomialSize(512);
+    /// // The images of x -> x^2 + 1 modulo 5
+    /// let table = vec![1_u32, 2, 0, 0, 2];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let encoder = engine.create_encoder_from(&ModularEncoderConfig {
+    ///     modulus: 5,
+    ///     nb_bit_padding: 1,
+    /// })?;
+    /// let table: CleartextVector32 = engine.create_cleartext_vector_from(&table)?;
+    /// let lookup_table: PlaintextVector32 =
+    ///     engine.create_lookup_table_plaintext_vector(&encoder, &encoder, &table, polynomial_size)?;
+    /// assert_eq!(lookup_table.plaintext_count().0, polynomial_size.0);
+    ///
+    /// // The lookup table is trivially encrypted into the accumulator of a bootstrap.
+    /// let accumulator: GlweCiphertext32 =
+    ///     engine.trivially_encrypt_glwe_ciphertext(GlweSize(2), &lookup_table)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lookup_table_plaintext_vector(
+        &mut self,
+        input_encoder: &ModularEncoder,
+        output_encoder: &ModularEncoder,
+        table: &CleartextVector32,
+        polynomial_size: PolynomialSize,
+    ) -> Result<PlaintextVector32, PlaintextVectorLookupTableCreationError<Self::EngineError>> {
+        PlaintextVectorLookupTableCreationError::perform_generic_checks(table, polynomial_size)?;
+        check_lookup_table_inputs(
+            &input_encoder.0,
+            &output_encoder.0,
+            table.0.as_tensor().iter().map(|value| u64::from(*value)),
+            polynomial_size,
+            32,
+        )
+        .map_err(PlaintextVectorLookupTableCreationError::Engine)?;
+        Ok(unsafe {
+            self.create_lookup_table_plaintext_vector_unchecked(
+                input_encoder,
+                output_encoder,
+                table,
+                polynomial_size,
+            )
+        })
+    }
+
+    unsafe fn create_lookup_table_plaintext_vector_unchecked(
+        &mut self,
+        input_encoder: &ModularEncoder,
+        output_encoder: &ModularEncoder,
+        table: &CleartextVector32,
+        polynomial_size: PolynomialSize,
+    ) -> PlaintextVector32 {
+        let mut lookup_table =
+            ImplPlaintextList::allocate(0_u32, PlaintextCount(polynomial_size.0));
+        input_encoder
+            .0
+            .fill_lookup_table(&mut lookup_table, &output_encoder.0, |message| {
+                u64::from(*table.0.as_tensor().get_element(message as usize))
+            });
+        PlaintextVector32(lookup_table)
+    }
+}
+
+/// # Description:
+/// Implementation of [`PlaintextVectorLookupTableCreationEngine`] for [`DefaultEngine`] that
+/// creates a lookup table of 64 bits integers, mapping messages encoded with a modular encoder.
+impl PlaintextVectorLookupTableCreationEngine<ModularEncoder, CleartextVector64, PlaintextVector64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweSize, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let polynomial_size = PolynomialSize(512);
+    /// // The images of x -> x^2 + 1 modulo 5
+    /// let table = vec![1_u64, 2, 0, 0, 2];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let encoder = engine.create_encoder_from(&ModularEncoderConfig {
+    ///     modulus: 5,
+    ///     nb_bit_padding: 1,
+    /// })?;
+    /// let table: CleartextVector64 = engine.create_cleartext_vector_from(&table)?;
+    /// let lookup_table: PlaintextVector64 =
+    ///     engine.create_lookup_table_plaintext_vector(&encoder, &encoder, &table, polynomial_size)?;
+    /// assert_eq!(lookup_table.plaintext_count().0, polynomial_size.0);
+    ///
+    /// // The lookup table is trivially encrypted into the accumulator of a bootstrap.
+    /// let accumulator: GlweCiphertext64 =
+    ///     engine.trivially_encrypt_glwe_ciphertext(GlweSize(2), &lookup_table)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lookup_table_plaintext_vector(
+        &mut self,
+        input_encoder: &ModularEncoder,
+        output_encoder: &ModularEncoder,
+        table: &CleartextVector64,
+        polynomial_size: PolynomialSize,
+    ) -> Result<PlaintextVector64, PlaintextVectorLookupTableCreationError<Self::EngineError>> {
+        PlaintextVectorLookupTableCreationError::perform_generic_checks(table, polynomial_size)?;
+        check_lookup_table_inputs(
+            &input_encoder.0,
+            &output_encoder.0,
+            table.0.as_tensor().iter().copied(),
+            polynomial_size,
+            64,
+        )
+        .map_err(PlaintextVectorLookupTableCreationError::Engine)?;
+        Ok(unsafe {
+            self.create_lookup_table_plaintext_vector_unchecked(
+                input_encoder,
+                output_encoder,
+                table,
+                polynomial_size,
+            )
+        })
+    }
+
+    unsafe fn create_lookup_table_plaintext_vector_unchecked(
+        &mut self,
+        input_encoder: &ModularEncoder,
+        output_encoder: &ModularEncoder,
+        table: &CleartextVector64,
+        polynomial_size: PolynomialSize,
+    ) -> PlaintextVector64 {
+        let mut lookup_table =
+            ImplPlaintextList::allocate(0_u64, PlaintextCount(polynomial_size.0));
+        input_encoder
+            .0
+            .fill_lookup_table(&mut lookup_table, &output_encoder.0, |message| {
+                *table.0.as_tensor().get_element(message as usize)
+            });
+        PlaintextVector64(lookup_table)
+    }
+}
+
+// Checks the inputs of the lookup table creation which are specific to the modular encoders.
+fn check_lookup_table_inputs(
+    input_encoder: &ImplModularEncoder,
+    output_encoder: &ImplModularEncoder,
+    mut table: impl ExactSizeIterator<Item = u64>,
+    polynomial_size: PolynomialSize,
+    precision: usize,
+) -> Result<(), DefaultError> {
+    if !input_encoder.fits_in_precision(precision) || !output_encoder.fits_in_precision(precision) {
+        return Err(DefaultError::ModularEncoderModulusTooLarge);
+    }
+    if input_encoder.nb_bit_padding == 0 {
+        return Err(DefaultError::ModularEncoderNullPadding);
+    }
+    if table.len() as u64 != input_encoder.modulus {
+        return Err(DefaultError::ModularEncoderTableSizeMismatch);
+    }
+    if (u128::from(input_encoder.modulus) << input_encoder.nb_bit_padding)
+        > 2 * polynomial_size.0 as u128
+    {
+        return Err(DefaultError::ModularEncoderPolynomialSizeTooSmall);
+    }
+    if table.any(|value| value >= output_encoder.modulus) {
+        return Err(DefaultError::ModularEncoderMessageOutsideModulus);
+    }
+    Ok(())
+}
@@ -0,0 +1,102 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    PlaintextVectorView32, PlaintextVectorView64,
+};
+use crate::commons::crypto::encoding::PlaintextList as ImplPlaintextList;
+use crate::specification::engines::{
+    PlaintextVectorViewCreationEngine, PlaintextVectorViewCreationError,
+};
+
+/// # Description:
+/// Implementation of [`PlaintextVectorViewCreationEngine`] for [`DefaultEngine`] which returns an
+/// immutable [`PlaintextVectorView32`] that does not own its memory.
+impl<'data> PlaintextVectorViewCreationEngine<&'data [u32], PlaintextVectorView32<'data>>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::PlaintextCount;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let plaintext_vector: PlaintextVectorView32 =
+    ///     engine.create_plaintext_vector_view_from(&input[..])?;
+    /// #
+    /// assert_eq!(plaintext_vector.plaintext_count(), PlaintextCount(3));
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_plaintext_vector_view_from(
+        &mut self,
+        container: &'data [u32],
+    ) -> Result<PlaintextVectorView32<'data>, PlaintextVectorViewCreationError<Self::EngineError>>
+    {
+        PlaintextVectorViewCreationError::<Self::EngineError>::perform_generic_checks(
+            container.len(),
+        )?;
+        Ok(unsafe { self.create_plaintext_vector_view_from_unchecked(container) })
+    }
+
+    unsafe fn create_plaintext_vector_view_from_unchecked(
+        &mut self,
+        container: &'data [u32],
+    ) -> PlaintextVectorView32<'data> {
+        PlaintextVectorView32(ImplPlaintextList::from_container(container))
+    }
+}
+
+/// # Description:
+/// Implementation of [`PlaintextVectorViewCreationEngine`] for [`DefaultEngine`] which returns an
+/// immutable [`PlaintextVectorView64`] that does not own its memory.
+impl<'data> PlaintextVectorViewCreationEngine<&'data [u64], PlaintextVectorView64<'data>>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::PlaintextCount;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let plaintext_vector: PlaintextVectorView64 =
+    ///     engine.create_plaintext_vector_view_from(&input[..])?;
+    /// #
+    /// assert_eq!(plaintext_vector.plaintext_count(), PlaintextCount(3));
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_plaintext_vector_view_from(
+        &mut self,
+        container: &'data [u64],
+    ) -> Result<PlaintextVectorView64<'data>, PlaintextVectorViewCreationError<Self::EngineError>>
+    {
+        PlaintextVectorViewCreationError::<Self::EngineError>::perform_generic_checks(
+            container.len(),
+        )?;
+        Ok(unsafe { self.create_plaintext_vector_view_from_unchecked(container) })
+    }
+
+    unsafe fn create_plaintext_vector_view_from_unchecked(
+        &mut self,
+        container: &'data [u64],
+    ) -> PlaintextVectorView64<'data> {
+        PlaintextVectorView64(ImplPlaintextList::from_container(container))
+    }
+}
@@ -0,0 +1,140 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    SparseCleartextPolynomial32, SparseCleartextPolynomial64,
+};
+use crate::commons::math::polynomial::SparsePolynomial as ImplSparsePolynomial;
+use crate::specification::engines::{
+    SparseCleartextPolynomialCreationEngine, SparseCleartextPolynomialCreationError,
+};
+#[allow(deprecated)]
+use concrete_commons::parameters::{MonomialDegree, MonomialIndex, PolynomialSize};
+
+/// # Description:
+/// Implementation of [`SparseCleartextPolynomialCreationEngine`] for [`DefaultEngine`] that
+/// operates on 32 bits integers.
+impl SparseCleartextPolynomialCreationEngine<u32, SparseCleartextPolynomial32> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{MonomialCount, MonomialIndex, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // The polynomial 3 + 5.X^7
+    /// let values = vec![3_u32, 5];
+    /// let indices = vec![MonomialIndex(0), MonomialIndex(7)];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let sparse_polynomial: SparseCleartextPolynomial32 =
+    ///     engine.create_sparse_cleartext_polynomial_from(&values, &indices, PolynomialSize(256))?;
+    /// #
+    /// assert_eq!(sparse_polynomial.polynomial_size(), PolynomialSize(256));
+    /// assert_eq!(sparse_polynomial.monomial_count(), MonomialCount(2));
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_sparse_cleartext_polynomial_from(
+        &mut self,
+        values: &[u32],
+        indices: &[MonomialIndex],
+        polynomial_size: PolynomialSize,
+    ) -> Result<
+        SparseCleartextPolynomial32,
+        SparseCleartextPolynomialCreationError<Self::EngineError>,
+    > {
+        SparseCleartextPolynomialCreationError::perform_generic_checks(
+            values,
+            indices,
+            polynomial_size,
+        )?;
+        Ok(unsafe {
+            self.create_sparse_cleartext_polynomial_from_unchecked(values, indices, polynomial_size)
+        })
+    }
+
+    unsafe fn create_sparse_cleartext_polynomial_from_unchecked(
+        &mut self,
+        values: &[u32],
+        indices: &[MonomialIndex],
+        polynomial_size: PolynomialSize,
+    ) -> SparseCleartextPolynomial32 {
+        #[allow(deprecated)]
+        SparseCleartextPolynomial32(ImplSparsePolynomial::from_container(
+            values.to_vec(),
+            indices
+                .iter()
+                .map(|index| MonomialDegree(index.0))
+                .collect(),
+            polynomial_size,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`SparseCleartextPolynomialCreationEngine`] for [`DefaultEngine`] that
+/// operates on 64 bits integers.
+impl SparseCleartextPolynomialCreationEngine<u64, SparseCleartextPolynomial64> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{MonomialCount, MonomialIndex, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // The polynomial 3 + 5.X^7
+    /// let values = vec![3_u64, 5];
+    /// let indices = vec![MonomialIndex(0), MonomialIndex(7)];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let sparse_polynomial: SparseCleartextPolynomial64 =
+    ///     engine.create_sparse_cleartext_polynomial_from(&values, &indices, PolynomialSize(256))?;
+    /// #
+    /// assert_eq!(sparse_polynomial.polynomial_size(), PolynomialSize(256));
+    /// assert_eq!(sparse_polynomial.monomial_count(), MonomialCount(2));
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_sparse_cleartext_polynomial_from(
+        &mut self,
+        values: &[u64],
+        indices: &[MonomialIndex],
+        polynomial_size: PolynomialSize,
+    ) -> Result<
+        SparseCleartextPolynomial64,
+        SparseCleartextPolynomialCreationError<Self::EngineError>,
+    > {
+        SparseCleartextPolynomialCreationError::perform_generic_checks(
+            values,
+            indices,
+            polynomial_size,
+        )?;
+        Ok(unsafe {
+            self.create_sparse_cleartext_polynomial_from_unchecked(values, indices, polynomial_size)
+        })
+    }
+
+    unsafe fn create_sparse_cleartext_polynomial_from_unchecked(
+        &mut self,
+        values: &[u64],
+        indices: &[MonomialIndex],
+        polynomial_size: PolynomialSize,
+    ) -> SparseCleartextPolynomial64 {
+        #[allow(deprecated)]
+        SparseCleartextPolynomial64(ImplSparsePolynomial::from_container(
+            values.to_vec(),
+            indices
+                .iter()
+                .map(|index| MonomialDegree(index.0))
+                .collect(),
+            polynomial_size,
+        ))
+    }
+}
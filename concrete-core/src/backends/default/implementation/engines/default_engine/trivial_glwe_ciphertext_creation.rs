@@ -0,0 +1,120 @@
+use concrete_commons::parameters::GlweSize;
+
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    PlaintextVector32, PlaintextVector64, TrivialGlweCiphertext32, TrivialGlweCiphertext64,
+};
+use crate::commons::crypto::glwe::GlweCiphertext as ImplGlweCiphertext;
+use crate::specification::engines::{
+    TrivialGlweCiphertextCreationEngine, TrivialGlweCiphertextCreationError,
+};
+
+/// # Description:
+/// Implementation of [`TrivialGlweCiphertextCreationEngine`] for [`DefaultEngine`] that operates
+/// on 32 bits integers.
+impl TrivialGlweCiphertextCreationEngine<PlaintextVector32, TrivialGlweCiphertext32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input)?;
+    /// // DISCLAIMER: trivial encryption is NOT secure, and DOES NOT hide the message at all.
+    /// let ciphertext: TrivialGlweCiphertext32 = engine
+    ///     .create_trivial_glwe_ciphertext(glwe_dimension.to_glwe_size(), &plaintext_vector)?;
+    ///
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_trivial_glwe_ciphertext(
+        &mut self,
+        glwe_size: GlweSize,
+        input: &PlaintextVector32,
+    ) -> Result<TrivialGlweCiphertext32, TrivialGlweCiphertextCreationError<Self::EngineError>>
+    {
+        unsafe { Ok(self.create_trivial_glwe_ciphertext_unchecked(glwe_size, input)) }
+    }
+
+    unsafe fn create_trivial_glwe_ciphertext_unchecked(
+        &mut self,
+        glwe_size: GlweSize,
+        input: &PlaintextVector32,
+    ) -> TrivialGlweCiphertext32 {
+        let ciphertext: ImplGlweCiphertext<Vec<u32>> =
+            ImplGlweCiphertext::new_trivial_encryption(glwe_size, &input.0);
+        TrivialGlweCiphertext32(ciphertext)
+    }
+}
+
+/// # Description:
+/// Implementation of [`TrivialGlweCiphertextCreationEngine`] for [`DefaultEngine`] that operates
+/// on 64 bits integers.
+impl TrivialGlweCiphertextCreationEngine<PlaintextVector64, TrivialGlweCiphertext64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u64 << 20; polynomial_size.0];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input)?;
+    /// // DISCLAIMER: trivial encryption is NOT secure, and DOES NOT hide the message at all.
+    /// let ciphertext: TrivialGlweCiphertext64 = engine
+    ///     .create_trivial_glwe_ciphertext(glwe_dimension.to_glwe_size(), &plaintext_vector)?;
+    ///
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_trivial_glwe_ciphertext(
+        &mut self,
+        glwe_size: GlweSize,
+        input: &PlaintextVector64,
+    ) -> Result<TrivialGlweCiphertext64, TrivialGlweCiphertextCreationError<Self::EngineError>>
+    {
+        unsafe { Ok(self.create_trivial_glwe_ciphertext_unchecked(glwe_size, input)) }
+    }
+
+    unsafe fn create_trivial_glwe_ciphertext_unchecked(
+        &mut self,
+        glwe_size: GlweSize,
+        input: &PlaintextVector64,
+    ) -> TrivialGlweCiphertext64 {
+        let ciphertext: ImplGlweCiphertext<Vec<u64>> =
+            ImplGlweCiphertext::new_trivial_encryption(glwe_size, &input.0);
+        TrivialGlweCiphertext64(ciphertext)
+    }
+}
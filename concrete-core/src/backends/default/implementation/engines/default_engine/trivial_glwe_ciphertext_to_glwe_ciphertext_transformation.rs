@@ -0,0 +1,132 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GlweCiphertext32, GlweCiphertext64, TrivialGlweCiphertext32, TrivialGlweCiphertext64,
+};
+use crate::specification::engines::{
+    TrivialGlweCiphertextToGlweCiphertextTransformationEngine,
+    TrivialGlweCiphertextToGlweCiphertextTransformationError,
+};
+
+/// # Description:
+/// Implementation of [`TrivialGlweCiphertextToGlweCiphertextTransformationEngine`] for
+/// [`DefaultEngine`] that operates on 32 bits integers.
+impl
+    TrivialGlweCiphertextToGlweCiphertextTransformationEngine<
+        TrivialGlweCiphertext32,
+        GlweCiphertext32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input)?;
+    /// let trivial_ciphertext: TrivialGlweCiphertext32 = engine
+    ///     .create_trivial_glwe_ciphertext(glwe_dimension.to_glwe_size(), &plaintext_vector)?;
+    ///
+    /// let ciphertext: GlweCiphertext32 =
+    ///     engine.transform_trivial_glwe_ciphertext_to_glwe_ciphertext(trivial_ciphertext)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn transform_trivial_glwe_ciphertext_to_glwe_ciphertext(
+        &mut self,
+        trivial_glwe_ciphertext: TrivialGlweCiphertext32,
+    ) -> Result<
+        GlweCiphertext32,
+        TrivialGlweCiphertextToGlweCiphertextTransformationError<Self::EngineError>,
+    > {
+        Ok(unsafe {
+            self.transform_trivial_glwe_ciphertext_to_glwe_ciphertext_unchecked(
+                trivial_glwe_ciphertext,
+            )
+        })
+    }
+
+    unsafe fn transform_trivial_glwe_ciphertext_to_glwe_ciphertext_unchecked(
+        &mut self,
+        trivial_glwe_ciphertext: TrivialGlweCiphertext32,
+    ) -> GlweCiphertext32 {
+        GlweCiphertext32(trivial_glwe_ciphertext.0)
+    }
+}
+
+/// # Description:
+/// Implementation of [`TrivialGlweCiphertextToGlweCiphertextTransformationEngine`] for
+/// [`DefaultEngine`] that operates on 64 bits integers.
+impl
+    TrivialGlweCiphertextToGlweCiphertextTransformationEngine<
+        TrivialGlweCiphertext64,
+        GlweCiphertext64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u64 << 20; polynomial_size.0];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input)?;
+    /// let trivial_ciphertext: TrivialGlweCiphertext64 = engine
+    ///     .create_trivial_glwe_ciphertext(glwe_dimension.to_glwe_size(), &plaintext_vector)?;
+    ///
+    /// let ciphertext: GlweCiphertext64 =
+    ///     engine.transform_trivial_glwe_ciphertext_to_glwe_ciphertext(trivial_ciphertext)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn transform_trivial_glwe_ciphertext_to_glwe_ciphertext(
+        &mut self,
+        trivial_glwe_ciphertext: TrivialGlweCiphertext64,
+    ) -> Result<
+        GlweCiphertext64,
+        TrivialGlweCiphertextToGlweCiphertextTransformationError<Self::EngineError>,
+    > {
+        Ok(unsafe {
+            self.transform_trivial_glwe_ciphertext_to_glwe_ciphertext_unchecked(
+                trivial_glwe_ciphertext,
+            )
+        })
+    }
+
+    unsafe fn transform_trivial_glwe_ciphertext_to_glwe_ciphertext_unchecked(
+        &mut self,
+        trivial_glwe_ciphertext: TrivialGlweCiphertext64,
+    ) -> GlweCiphertext64 {
+        GlweCiphertext64(trivial_glwe_ciphertext.0)
+    }
+}
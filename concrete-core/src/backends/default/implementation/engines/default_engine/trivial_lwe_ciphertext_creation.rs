@@ -0,0 +1,108 @@
+use concrete_commons::parameters::LweSize;
+
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    Plaintext32, Plaintext64, TrivialLweCiphertext32, TrivialLweCiphertext64,
+};
+use crate::commons::crypto::lwe::LweCiphertext as ImplLweCiphertext;
+use crate::specification::engines::{
+    TrivialLweCiphertextCreationEngine, TrivialLweCiphertextCreationError,
+};
+
+/// # Description:
+/// Implementation of [`TrivialLweCiphertextCreationEngine`] for [`DefaultEngine`] that operates on
+/// 32 bits integers.
+impl TrivialLweCiphertextCreationEngine<Plaintext32, TrivialLweCiphertext32> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweSize;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_size = LweSize(10);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let plaintext: Plaintext32 = engine.create_plaintext_from(&input)?;
+    /// // DISCLAIMER: trivial encryption is NOT secure, and DOES NOT hide the message at all.
+    /// let ciphertext: TrivialLweCiphertext32 =
+    ///     engine.create_trivial_lwe_ciphertext(lwe_size, &plaintext)?;
+    ///
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_size.to_lwe_dimension());
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_trivial_lwe_ciphertext(
+        &mut self,
+        lwe_size: LweSize,
+        input: &Plaintext32,
+    ) -> Result<TrivialLweCiphertext32, TrivialLweCiphertextCreationError<Self::EngineError>> {
+        unsafe { Ok(self.create_trivial_lwe_ciphertext_unchecked(lwe_size, input)) }
+    }
+
+    unsafe fn create_trivial_lwe_ciphertext_unchecked(
+        &mut self,
+        lwe_size: LweSize,
+        input: &Plaintext32,
+    ) -> TrivialLweCiphertext32 {
+        let ciphertext = ImplLweCiphertext::new_trivial_encryption(lwe_size, &input.0);
+        TrivialLweCiphertext32(ciphertext)
+    }
+}
+
+/// # Description:
+/// Implementation of [`TrivialLweCiphertextCreationEngine`] for [`DefaultEngine`] that operates on
+/// 64 bits integers.
+impl TrivialLweCiphertextCreationEngine<Plaintext64, TrivialLweCiphertext64> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweSize;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_size = LweSize(10);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u64 << 20;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let plaintext: Plaintext64 = engine.create_plaintext_from(&input)?;
+    /// // DISCLAIMER: trivial encryption is NOT secure, and DOES NOT hide the message at all.
+    /// let ciphertext: TrivialLweCiphertext64 =
+    ///     engine.create_trivial_lwe_ciphertext(lwe_size, &plaintext)?;
+    ///
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_size.to_lwe_dimension());
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_trivial_lwe_ciphertext(
+        &mut self,
+        lwe_size: LweSize,
+        input: &Plaintext64,
+    ) -> Result<TrivialLweCiphertext64, TrivialLweCiphertextCreationError<Self::EngineError>> {
+        unsafe { Ok(self.create_trivial_lwe_ciphertext_unchecked(lwe_size, input)) }
+    }
+
+    unsafe fn create_trivial_lwe_ciphertext_unchecked(
+        &mut self,
+        lwe_size: LweSize,
+        input: &Plaintext64,
+    ) -> TrivialLweCiphertext64 {
+        let ciphertext = ImplLweCiphertext::new_trivial_encryption(lwe_size, &input.0);
+        TrivialLweCiphertext64(ciphertext)
+    }
+}
@@ -0,0 +1,134 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    LweCiphertext32, LweCiphertext64, TrivialLweCiphertext32, TrivialLweCiphertext64,
+};
+use crate::specification::engines::{
+    TrivialLweCiphertextToLweCiphertextTransformationEngine,
+    TrivialLweCiphertextToLweCiphertextTransformationError,
+};
+
+/// # Description:
+/// Implementation of [`TrivialLweCiphertextToLweCiphertextTransformationEngine`] for
+/// [`DefaultEngine`] that operates on 32 bits integers.
+impl
+    TrivialLweCiphertextToLweCiphertextTransformationEngine<TrivialLweCiphertext32, LweCiphertext32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext: Plaintext32 = engine.create_plaintext_from(&input)?;
+    /// let trivial_ciphertext: TrivialLweCiphertext32 =
+    ///     engine.create_trivial_lwe_ciphertext(lwe_dimension.to_lwe_size(), &plaintext)?;
+    ///
+    /// let mut ciphertext: LweCiphertext32 =
+    ///     engine.transform_trivial_lwe_ciphertext_to_lwe_ciphertext(trivial_ciphertext)?;
+    /// let other_ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// engine.fuse_add_lwe_ciphertext(&mut ciphertext, &other_ciphertext)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn transform_trivial_lwe_ciphertext_to_lwe_ciphertext(
+        &mut self,
+        trivial_lwe_ciphertext: TrivialLweCiphertext32,
+    ) -> Result<
+        LweCiphertext32,
+        TrivialLweCiphertextToLweCiphertextTransformationError<Self::EngineError>,
+    > {
+        Ok(unsafe {
+            self.transform_trivial_lwe_ciphertext_to_lwe_ciphertext_unchecked(
+                trivial_lwe_ciphertext,
+            )
+        })
+    }
+
+    unsafe fn transform_trivial_lwe_ciphertext_to_lwe_ciphertext_unchecked(
+        &mut self,
+        trivial_lwe_ciphertext: TrivialLweCiphertext32,
+    ) -> LweCiphertext32 {
+        LweCiphertext32(trivial_lwe_ciphertext.0)
+    }
+}
+
+/// # Description:
+/// Implementation of [`TrivialLweCiphertextToLweCiphertextTransformationEngine`] for
+/// [`DefaultEngine`] that operates on 64 bits integers.
+impl
+    TrivialLweCiphertextToLweCiphertextTransformationEngine<TrivialLweCiphertext64, LweCiphertext64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u64 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext: Plaintext64 = engine.create_plaintext_from(&input)?;
+    /// let trivial_ciphertext: TrivialLweCiphertext64 =
+    ///     engine.create_trivial_lwe_ciphertext(lwe_dimension.to_lwe_size(), &plaintext)?;
+    ///
+    /// let mut ciphertext: LweCiphertext64 =
+    ///     engine.transform_trivial_lwe_ciphertext_to_lwe_ciphertext(trivial_ciphertext)?;
+    /// let other_ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// engine.fuse_add_lwe_ciphertext(&mut ciphertext, &other_ciphertext)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn transform_trivial_lwe_ciphertext_to_lwe_ciphertext(
+        &mut self,
+        trivial_lwe_ciphertext: TrivialLweCiphertext64,
+    ) -> Result<
+        LweCiphertext64,
+        TrivialLweCiphertextToLweCiphertextTransformationError<Self::EngineError>,
+    > {
+        Ok(unsafe {
+            self.transform_trivial_lwe_ciphertext_to_lwe_ciphertext_unchecked(
+                trivial_lwe_ciphertext,
+            )
+        })
+    }
+
+    unsafe fn transform_trivial_lwe_ciphertext_to_lwe_ciphertext_unchecked(
+        &mut self,
+        trivial_lwe_ciphertext: TrivialLweCiphertext64,
+    ) -> LweCiphertext64 {
+        LweCiphertext64(trivial_lwe_ciphertext.0)
+    }
+}
@@ -70,4 +70,7 @@ impl AbstractEngine for DefaultParallelEngine {
 }
 
 mod lwe_bootstrap_key_generation;
+mod lwe_ciphertext_vector_discarding_bootstrap;
+mod lwe_ciphertext_vector_discarding_keyswitch;
+mod lwe_ciphertext_vector_encryption;
 mod lwe_seeded_bootstrap_key_generation;
@@ -5,59 +5,159 @@ use crate::commons::crypto::bootstrap::{
 };
 use crate::commons::crypto::encoding::{
     Cleartext as ImplCleartext, CleartextList as ImplCleartextList,
-    FloatEncoder as ImplFloatEncoder, Plaintext as ImplPlaintext,
-    PlaintextList as ImplPlaintextList,
+    FloatEncoder as ImplFloatEncoder, ModularEncoder as ImplModularEncoder,
+    Plaintext as ImplPlaintext, PlaintextList as ImplPlaintextList,
 };
 use crate::commons::crypto::ggsw::{
     StandardGgswCiphertext as ImplStandardGgswCiphertext,
     StandardGgswSeededCiphertext as ImplStandardGgswSeededCiphertext,
 };
 use crate::commons::crypto::glwe::{
+    GlevCiphertext as ImplGlevCiphertext, GlweAutomorphismKeys as ImplGlweAutomorphismKeys,
     GlweCiphertext as ImplGlweCiphertext, GlweList as ImplGlweList,
+    GlweRelinearizationKey as ImplGlweRelinearizationKey,
+    GlweRingReductionKey as ImplGlweRingReductionKey,
+    GlweRingSwitchingKey as ImplGlweRingSwitchingKey,
     GlweSeededCiphertext as ImplGlweSeededCiphertext, GlweSeededList as ImplGlweSeededList,
     LwePackingKeyswitchKey as ImplLwePackingKeyswitchKey,
+    LweSeededPackingKeyswitchKey as ImplLweSeededPackingKeyswitchKey,
 };
 use crate::commons::crypto::lwe::{
-    LweCiphertext as ImplLweCiphertext, LweKeyswitchKey as ImplLweKeyswitchKey,
-    LweList as ImplLweList, LweSeededCiphertext as ImplLweSeededCiphertext,
+    LevCiphertext as ImplLevCiphertext, LweCiphertext as ImplLweCiphertext,
+    LweKeyswitchKey as ImplLweKeyswitchKey, LweList as ImplLweList,
+    LwePartialKeyswitchKey as ImplLwePartialKeyswitchKey,
+    LweSeededCiphertext as ImplLweSeededCiphertext,
     LweSeededKeyswitchKey as ImplLweSeededKeyswitchKey, LweSeededList as ImplLweSeededList,
 };
 use crate::commons::crypto::secret::{
-    GlweSecretKey as ImplGlweSecretKey, LweSecretKey as ImplLweSecretKey,
+    GlweSecretKey as ImplGlweSecretKey,
+    GlweTensorProductSecretKey as ImplGlweTensorProductSecretKey, LweSecretKey as ImplLweSecretKey,
 };
+use crate::commons::math::polynomial::SparsePolynomial as ImplSparsePolynomial;
 use crate::prelude::{
-    Cleartext32, Cleartext32Version, Cleartext64, Cleartext64Version, CleartextF64,
-    CleartextF64Version, CleartextVector32, CleartextVector32Version, CleartextVector64,
-    CleartextVector64Version, CleartextVectorF64, CleartextVectorF64Version,
-    DefaultSerializationEngine, DefaultSerializationError, EntityDeserializationEngine,
-    EntityDeserializationError, FloatEncoder, FloatEncoderVector, FloatEncoderVectorVersion,
-    FloatEncoderVersion, GgswCiphertext32, GgswCiphertext32Version, GgswCiphertext64,
-    GgswCiphertext64Version, GgswSeededCiphertext32, GgswSeededCiphertext32Version,
-    GgswSeededCiphertext64, GgswSeededCiphertext64Version, GlweCiphertext32,
-    GlweCiphertext32Version, GlweCiphertext64, GlweCiphertext64Version, GlweCiphertextVector32,
-    GlweCiphertextVector32Version, GlweCiphertextVector64, GlweCiphertextVector64Version,
-    GlweSecretKey32, GlweSecretKey32Version, GlweSecretKey64, GlweSecretKey64Version,
-    GlweSeededCiphertext32, GlweSeededCiphertext32Version, GlweSeededCiphertext64,
-    GlweSeededCiphertext64Version, GlweSeededCiphertextVector32,
+    Cleartext16, Cleartext16Version, Cleartext32, Cleartext32Version, Cleartext64,
+    Cleartext64Version, CleartextF64, CleartextF64Version, CleartextVector32,
+    CleartextVector32Version, CleartextVector64, CleartextVector64Version, CleartextVectorF64,
+    CleartextVectorF64Version, DefaultSerializationEngine, DefaultSerializationError,
+    EntityDeserializationEngine, EntityDeserializationError, FloatEncoder, FloatEncoderVector,
+    FloatEncoderVectorVersion, FloatEncoderVersion, GgswCiphertext32, GgswCiphertext32Version,
+    GgswCiphertext64, GgswCiphertext64Version, GgswSeededCiphertext32,
+    GgswSeededCiphertext32Version, GgswSeededCiphertext64, GgswSeededCiphertext64Version,
+    GlevCiphertext32, GlevCiphertext32Version, GlevCiphertext64, GlevCiphertext64Version,
+    GlweAutomorphismKeys32, GlweAutomorphismKeys32Version, GlweAutomorphismKeys64,
+    GlweAutomorphismKeys64Version, GlweCiphertext32, GlweCiphertext32Version, GlweCiphertext64,
+    GlweCiphertext64Version, GlweCiphertextVector32, GlweCiphertextVector32Version,
+    GlweCiphertextVector64, GlweCiphertextVector64Version, GlweRelinearizationKey32,
+    GlweRelinearizationKey32Version, GlweRelinearizationKey64, GlweRelinearizationKey64Version,
+    GlweRingReductionKey32, GlweRingReductionKey32Version, GlweRingReductionKey64,
+    GlweRingReductionKey64Version, GlweRingSwitchingKey32, GlweRingSwitchingKey32Version,
+    GlweRingSwitchingKey64, GlweRingSwitchingKey64Version, GlweSecretKey32, GlweSecretKey32Version,
+    GlweSecretKey64, GlweSecretKey64Version, GlweSecretKeyGaussian32,
+    GlweSecretKeyGaussian32Version, GlweSecretKeyGaussian64, GlweSecretKeyGaussian64Version,
+    GlweSecretKeyTernary32, GlweSecretKeyTernary32Version, GlweSecretKeyTernary64,
+    GlweSecretKeyTernary64Version, GlweSeededCiphertext32, GlweSeededCiphertext32Version,
+    GlweSeededCiphertext64, GlweSeededCiphertext64Version, GlweSeededCiphertextVector32,
     GlweSeededCiphertextVector32Version, GlweSeededCiphertextVector64,
-    GlweSeededCiphertextVector64Version, LweBootstrapKey32, LweBootstrapKey32Version,
-    LweBootstrapKey64, LweBootstrapKey64Version, LweCiphertext32, LweCiphertext32Version,
-    LweCiphertext64, LweCiphertext64Version, LweCiphertextVector32, LweCiphertextVector32Version,
-    LweCiphertextVector64, LweCiphertextVector64Version, LweKeyswitchKey32,
-    LweKeyswitchKey32Version, LweKeyswitchKey64, LweKeyswitchKey64Version,
-    LwePackingKeyswitchKey32, LwePackingKeyswitchKey32Version, LwePackingKeyswitchKey64,
-    LwePackingKeyswitchKey64Version, LweSecretKey32, LweSecretKey32Version, LweSecretKey64,
-    LweSecretKey64Version, LweSeededBootstrapKey32, LweSeededBootstrapKey32Version,
+    GlweSeededCiphertextVector64Version, GlweTensorProductSecretKey32,
+    GlweTensorProductSecretKey32Version, GlweTensorProductSecretKey64,
+    GlweTensorProductSecretKey64Version, LevCiphertext32, LevCiphertext32Version, LevCiphertext64,
+    LevCiphertext64Version, LweBootstrapKey32, LweBootstrapKey32Version, LweBootstrapKey64,
+    LweBootstrapKey64Version, LweCiphertext16, LweCiphertext16Version, LweCiphertext32,
+    LweCiphertext32Version, LweCiphertext64, LweCiphertext64Version, LweCiphertextVector32,
+    LweCiphertextVector32Version, LweCiphertextVector64, LweCiphertextVector64Version,
+    LweKeyswitchKey16, LweKeyswitchKey16Version, LweKeyswitchKey32, LweKeyswitchKey32Version,
+    LweKeyswitchKey64, LweKeyswitchKey64Version, LwePackingKeyswitchKey32,
+    LwePackingKeyswitchKey32Version, LwePackingKeyswitchKey64, LwePackingKeyswitchKey64Version,
+    LwePartialKeyswitchKey32, LwePartialKeyswitchKey32Version, LwePartialKeyswitchKey64,
+    LwePartialKeyswitchKey64Version, LweSecretKey16, LweSecretKey16Version, LweSecretKey32,
+    LweSecretKey32Version, LweSecretKey64, LweSecretKey64Version, LweSecretKeyGaussian32,
+    LweSecretKeyGaussian32Version, LweSecretKeyGaussian64, LweSecretKeyGaussian64Version,
+    LweSecretKeyTernary32, LweSecretKeyTernary32Version, LweSecretKeyTernary64,
+    LweSecretKeyTernary64Version, LweSeededBootstrapKey32, LweSeededBootstrapKey32Version,
     LweSeededBootstrapKey64, LweSeededBootstrapKey64Version, LweSeededCiphertext32,
     LweSeededCiphertext32Version, LweSeededCiphertext64, LweSeededCiphertext64Version,
     LweSeededCiphertextVector32, LweSeededCiphertextVector32Version, LweSeededCiphertextVector64,
     LweSeededCiphertextVector64Version, LweSeededKeyswitchKey32, LweSeededKeyswitchKey32Version,
-    LweSeededKeyswitchKey64, LweSeededKeyswitchKey64Version, Plaintext32, Plaintext32Version,
-    Plaintext64, Plaintext64Version, PlaintextVector32, PlaintextVector32Version,
-    PlaintextVector64, PlaintextVector64Version,
+    LweSeededKeyswitchKey64, LweSeededKeyswitchKey64Version, LweSeededPackingKeyswitchKey32,
+    LweSeededPackingKeyswitchKey32Version, LweSeededPackingKeyswitchKey64,
+    LweSeededPackingKeyswitchKey64Version, ModularEncoder, ModularEncoderVersion, Plaintext16,
+    Plaintext16Version, Plaintext32, Plaintext32Version, Plaintext64, Plaintext64Version,
+    PlaintextVector32, PlaintextVector32Version, PlaintextVector64, PlaintextVector64Version,
+    SparseCleartextPolynomial32, SparseCleartextPolynomial32Version, SparseCleartextPolynomial64,
+    SparseCleartextPolynomial64Version, TrivialGlweCiphertext32, TrivialGlweCiphertext32Version,
+    TrivialGlweCiphertext64, TrivialGlweCiphertext64Version, TrivialLweCiphertext32,
+    TrivialLweCiphertext32Version, TrivialLweCiphertext64, TrivialLweCiphertext64Version,
 };
-use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_commons::key_kinds::{BinaryKeyKind, GaussianKeyKind, TernaryKeyKind};
 use serde::Deserialize;
+use std::io::Read;
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 16 bits integers. It deserializes a cleartext entity.
+impl EntityDeserializationEngine<&[u8], Cleartext16> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input: u16 = 3;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let cleartext: Cleartext16 = engine.create_cleartext_from(&input)?;
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&cleartext)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(cleartext, recovered);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<Cleartext16, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> Cleartext16 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<Cleartext16, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableCleartext16 {
+            version: Cleartext16Version,
+            inner: ImplCleartext<u16>,
+        }
+        let deserialized: DeserializableCleartext16 = bincode::deserialize_from(reader)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableCleartext16 {
+                version: Cleartext16Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableCleartext16 {
+                version: Cleartext16Version::V0,
+                inner,
+            } => Ok(Cleartext16(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(&mut self, reader: R) -> Cleartext16 {
+        self.deserialize_from_reader(reader).unwrap()
+    }
+}
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
@@ -87,13 +187,24 @@ impl EntityDeserializationEngine<&[u8], Cleartext32> for DefaultSerializationEng
     fn deserialize(
         &mut self,
         serialized: &[u8],
+    ) -> Result<Cleartext32, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> Cleartext32 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
     ) -> Result<Cleartext32, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
         struct DeserializableCleartext32 {
             version: Cleartext32Version,
             inner: ImplCleartext<u32>,
         }
-        let deserialized: DeserializableCleartext32 = bincode::deserialize(serialized)
+        let deserialized: DeserializableCleartext32 = bincode::deserialize_from(reader)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
@@ -110,8 +221,8 @@ impl EntityDeserializationEngine<&[u8], Cleartext32> for DefaultSerializationEng
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> Cleartext32 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(&mut self, reader: R) -> Cleartext32 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
 
@@ -143,13 +254,24 @@ impl EntityDeserializationEngine<&[u8], Cleartext64> for DefaultSerializationEng
     fn deserialize(
         &mut self,
         serialized: &[u8],
+    ) -> Result<Cleartext64, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> Cleartext64 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
     ) -> Result<Cleartext64, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
         struct DeserializableCleartext64 {
             version: Cleartext64Version,
             inner: ImplCleartext<u64>,
         }
-        let deserialized: DeserializableCleartext64 = bincode::deserialize(serialized)
+        let deserialized: DeserializableCleartext64 = bincode::deserialize_from(reader)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
@@ -166,8 +288,8 @@ impl EntityDeserializationEngine<&[u8], Cleartext64> for DefaultSerializationEng
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> Cleartext64 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(&mut self, reader: R) -> Cleartext64 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
 
@@ -199,13 +321,24 @@ impl EntityDeserializationEngine<&[u8], CleartextF64> for DefaultSerializationEn
     fn deserialize(
         &mut self,
         serialized: &[u8],
+    ) -> Result<CleartextF64, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> CleartextF64 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
     ) -> Result<CleartextF64, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
         struct DeserializableCleartextF64 {
             version: CleartextF64Version,
             inner: ImplCleartext<f64>,
         }
-        let deserialized: DeserializableCleartextF64 = bincode::deserialize(serialized)
+        let deserialized: DeserializableCleartextF64 = bincode::deserialize_from(reader)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
@@ -222,8 +355,8 @@ impl EntityDeserializationEngine<&[u8], CleartextF64> for DefaultSerializationEn
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> CleartextF64 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(&mut self, reader: R) -> CleartextF64 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
 
@@ -256,13 +389,24 @@ impl EntityDeserializationEngine<&[u8], CleartextVector32> for DefaultSerializat
     fn deserialize(
         &mut self,
         serialized: &[u8],
+    ) -> Result<CleartextVector32, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> CleartextVector32 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
     ) -> Result<CleartextVector32, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
         struct DeserializableCleartextVector32 {
             version: CleartextVector32Version,
             inner: ImplCleartextList<Vec<u32>>,
         }
-        let deserialized: DeserializableCleartextVector32 = bincode::deserialize(serialized)
+        let deserialized: DeserializableCleartextVector32 = bincode::deserialize_from(reader)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
@@ -279,8 +423,11 @@ impl EntityDeserializationEngine<&[u8], CleartextVector32> for DefaultSerializat
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> CleartextVector32 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> CleartextVector32 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
 
@@ -313,13 +460,24 @@ impl EntityDeserializationEngine<&[u8], CleartextVector64> for DefaultSerializat
     fn deserialize(
         &mut self,
         serialized: &[u8],
+    ) -> Result<CleartextVector64, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> CleartextVector64 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
     ) -> Result<CleartextVector64, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
         struct DeserializableCleartextVector64 {
             version: CleartextVector64Version,
             inner: ImplCleartextList<Vec<u64>>,
         }
-        let deserialized: DeserializableCleartextVector64 = bincode::deserialize(serialized)
+        let deserialized: DeserializableCleartextVector64 = bincode::deserialize_from(reader)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
@@ -336,8 +494,11 @@ impl EntityDeserializationEngine<&[u8], CleartextVector64> for DefaultSerializat
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> CleartextVector64 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> CleartextVector64 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
 
@@ -370,13 +531,24 @@ impl EntityDeserializationEngine<&[u8], CleartextVectorF64> for DefaultSerializa
     fn deserialize(
         &mut self,
         serialized: &[u8],
+    ) -> Result<CleartextVectorF64, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> CleartextVectorF64 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
     ) -> Result<CleartextVectorF64, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
         struct DeserializableCleartextVectorF64 {
             version: CleartextVectorF64Version,
             inner: ImplCleartextList<Vec<f64>>,
         }
-        let deserialized: DeserializableCleartextVectorF64 = bincode::deserialize(serialized)
+        let deserialized: DeserializableCleartextVectorF64 = bincode::deserialize_from(reader)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
@@ -393,8 +565,11 @@ impl EntityDeserializationEngine<&[u8], CleartextVectorF64> for DefaultSerializa
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> CleartextVectorF64 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> CleartextVectorF64 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
 
@@ -444,13 +619,24 @@ impl EntityDeserializationEngine<&[u8], GgswCiphertext32> for DefaultSerializati
     fn deserialize(
         &mut self,
         serialized: &[u8],
+    ) -> Result<GgswCiphertext32, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GgswCiphertext32 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
     ) -> Result<GgswCiphertext32, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
         struct DeserializableGgswCiphertext32 {
             version: GgswCiphertext32Version,
             inner: ImplStandardGgswCiphertext<Vec<u32>>,
         }
-        let deserialized: DeserializableGgswCiphertext32 = bincode::deserialize(serialized)
+        let deserialized: DeserializableGgswCiphertext32 = bincode::deserialize_from(reader)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
@@ -467,8 +653,8 @@ impl EntityDeserializationEngine<&[u8], GgswCiphertext32> for DefaultSerializati
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GgswCiphertext32 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(&mut self, reader: R) -> GgswCiphertext32 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
 
@@ -518,13 +704,24 @@ impl EntityDeserializationEngine<&[u8], GgswCiphertext64> for DefaultSerializati
     fn deserialize(
         &mut self,
         serialized: &[u8],
+    ) -> Result<GgswCiphertext64, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GgswCiphertext64 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
     ) -> Result<GgswCiphertext64, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
         struct DeserializableGgswCiphertext64 {
             version: GgswCiphertext64Version,
             inner: ImplStandardGgswCiphertext<Vec<u64>>,
         }
-        let deserialized: DeserializableGgswCiphertext64 = bincode::deserialize(serialized)
+        let deserialized: DeserializableGgswCiphertext64 = bincode::deserialize_from(reader)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
@@ -541,91 +738,21 @@ impl EntityDeserializationEngine<&[u8], GgswCiphertext64> for DefaultSerializati
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GgswCiphertext64 {
-        self.deserialize(serialized).unwrap()
-    }
-}
-
-/// # Description:
-/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 32 bits integers. It deserializes a seeded GGSW ciphertext entity.
-impl EntityDeserializationEngine<&[u8], GgswSeededCiphertext32> for DefaultSerializationEngine {
-    /// TODO
-    fn deserialize(
-        &mut self,
-        serialized: &[u8],
-    ) -> Result<GgswSeededCiphertext32, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializableGgswSeededCiphertext32 {
-            version: GgswSeededCiphertext32Version,
-            inner: ImplStandardGgswSeededCiphertext<Vec<u32>>,
-        }
-        let deserialized: DeserializableGgswSeededCiphertext32 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializableGgswSeededCiphertext32 {
-                version: GgswSeededCiphertext32Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializableGgswSeededCiphertext32 {
-                version: GgswSeededCiphertext32Version::V0,
-                inner,
-            } => Ok(GgswSeededCiphertext32(inner)),
-        }
-    }
-
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GgswSeededCiphertext32 {
-        self.deserialize(serialized).unwrap()
-    }
-}
-
-/// # Description:
-/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 64 bits integers. It deserializes a seeded GGSW ciphertext entity.
-impl EntityDeserializationEngine<&[u8], GgswSeededCiphertext64> for DefaultSerializationEngine {
-    /// TODO
-    fn deserialize(
-        &mut self,
-        serialized: &[u8],
-    ) -> Result<GgswSeededCiphertext64, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializableGgswSeededCiphertext64 {
-            version: GgswSeededCiphertext64Version,
-            inner: ImplStandardGgswSeededCiphertext<Vec<u64>>,
-        }
-        let deserialized: DeserializableGgswSeededCiphertext64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
-        match deserialized {
-            DeserializableGgswSeededCiphertext64 {
-                version: GgswSeededCiphertext64Version::Unsupported,
-                ..
-            } => Err(EntityDeserializationError::Engine(
-                DefaultSerializationError::UnsupportedVersion,
-            )),
-            DeserializableGgswSeededCiphertext64 {
-                version: GgswSeededCiphertext64Version::V0,
-                inner,
-            } => Ok(GgswSeededCiphertext64(inner)),
-        }
-    }
-
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GgswSeededCiphertext64 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(&mut self, reader: R) -> GgswCiphertext64 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 32 bits integers. It deserializes a GLWE ciphertext entity.
-impl EntityDeserializationEngine<&[u8], GlweCiphertext32> for DefaultSerializationEngine {
+/// operates on 32 bits integers. It deserializes a GLev ciphertext entity.
+impl EntityDeserializationEngine<&[u8], GlevCiphertext32> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
@@ -633,9 +760,9 @@ impl EntityDeserializationEngine<&[u8], GlweCiphertext32> for DefaultSerializati
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
     /// let glwe_dimension = GlweDimension(2);
     /// let polynomial_size = PolynomialSize(4);
-    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
-    /// // Here a hard-set encoding is applied (shift by 20 bits)
-    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// let input = vec![3_u32; polynomial_size.0];
     /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
@@ -646,7 +773,8 @@ impl EntityDeserializationEngine<&[u8], GlweCiphertext32> for DefaultSerializati
     ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
     /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
     ///
-    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// let ciphertext =
+    ///     engine.encrypt_glev_ciphertext(&key, &plaintext_vector, noise, level, base_log)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
     /// let serialized = serialization_engine.serialize(&ciphertext)?;
@@ -660,42 +788,55 @@ impl EntityDeserializationEngine<&[u8], GlweCiphertext32> for DefaultSerializati
     fn deserialize(
         &mut self,
         serialized: &[u8],
-    ) -> Result<GlweCiphertext32, EntityDeserializationError<Self::EngineError>> {
+    ) -> Result<GlevCiphertext32, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlevCiphertext32 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<GlevCiphertext32, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
-        struct DeserializableGlweCiphertext32 {
-            version: GlweCiphertext32Version,
-            inner: ImplGlweCiphertext<Vec<u32>>,
+        struct DeserializableGlevCiphertext32 {
+            version: GlevCiphertext32Version,
+            inner: ImplGlevCiphertext<Vec<u32>>,
         }
-        let deserialized: DeserializableGlweCiphertext32 = bincode::deserialize(serialized)
+        let deserialized: DeserializableGlevCiphertext32 = bincode::deserialize_from(reader)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
-            DeserializableGlweCiphertext32 {
-                version: GlweCiphertext32Version::Unsupported,
+            DeserializableGlevCiphertext32 {
+                version: GlevCiphertext32Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
-            DeserializableGlweCiphertext32 {
-                version: GlweCiphertext32Version::V0,
+            DeserializableGlevCiphertext32 {
+                version: GlevCiphertext32Version::V0,
                 inner,
-            } => Ok(GlweCiphertext32(inner)),
+            } => Ok(GlevCiphertext32(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweCiphertext32 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(&mut self, reader: R) -> GlevCiphertext32 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 64 bits integers. It deserializes a GLWE ciphertext entity.
-impl EntityDeserializationEngine<&[u8], GlweCiphertext64> for DefaultSerializationEngine {
+/// operates on 64 bits integers. It deserializes a GLev ciphertext entity.
+impl EntityDeserializationEngine<&[u8], GlevCiphertext64> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
@@ -703,9 +844,9 @@ impl EntityDeserializationEngine<&[u8], GlweCiphertext64> for DefaultSerializati
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
     /// let glwe_dimension = GlweDimension(2);
     /// let polynomial_size = PolynomialSize(4);
-    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
-    /// // Here a hard-set encoding is applied (shift by 50 bits)
-    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// let input = vec![3_u64; polynomial_size.0];
     /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
@@ -716,7 +857,8 @@ impl EntityDeserializationEngine<&[u8], GlweCiphertext64> for DefaultSerializati
     ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
     /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
     ///
-    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// let ciphertext =
+    ///     engine.encrypt_glev_ciphertext(&key, &plaintext_vector, noise, level, base_log)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
     /// let serialized = serialization_engine.serialize(&ciphertext)?;
@@ -730,42 +872,153 @@ impl EntityDeserializationEngine<&[u8], GlweCiphertext64> for DefaultSerializati
     fn deserialize(
         &mut self,
         serialized: &[u8],
-    ) -> Result<GlweCiphertext64, EntityDeserializationError<Self::EngineError>> {
+    ) -> Result<GlevCiphertext64, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlevCiphertext64 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<GlevCiphertext64, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
-        struct DeserializableGlweCiphertext64 {
-            version: GlweCiphertext64Version,
-            inner: ImplGlweCiphertext<Vec<u64>>,
+        struct DeserializableGlevCiphertext64 {
+            version: GlevCiphertext64Version,
+            inner: ImplGlevCiphertext<Vec<u64>>,
         }
-        let deserialized: DeserializableGlweCiphertext64 = bincode::deserialize(serialized)
+        let deserialized: DeserializableGlevCiphertext64 = bincode::deserialize_from(reader)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
-            DeserializableGlweCiphertext64 {
-                version: GlweCiphertext64Version::Unsupported,
+            DeserializableGlevCiphertext64 {
+                version: GlevCiphertext64Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
-            DeserializableGlweCiphertext64 {
-                version: GlweCiphertext64Version::V0,
+            DeserializableGlevCiphertext64 {
+                version: GlevCiphertext64Version::V0,
                 inner,
-            } => Ok(GlweCiphertext64(inner)),
+            } => Ok(GlevCiphertext64(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweCiphertext64 {
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(&mut self, reader: R) -> GlevCiphertext64 {
+        self.deserialize_from_reader(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a seeded GGSW ciphertext entity.
+impl EntityDeserializationEngine<&[u8], GgswSeededCiphertext32> for DefaultSerializationEngine {
+    /// TODO
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GgswSeededCiphertext32, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GgswSeededCiphertext32 {
         self.deserialize(serialized).unwrap()
     }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<GgswSeededCiphertext32, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableGgswSeededCiphertext32 {
+            version: GgswSeededCiphertext32Version,
+            inner: ImplStandardGgswSeededCiphertext<Vec<u32>>,
+        }
+        let deserialized: DeserializableGgswSeededCiphertext32 = bincode::deserialize_from(reader)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableGgswSeededCiphertext32 {
+                version: GgswSeededCiphertext32Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableGgswSeededCiphertext32 {
+                version: GgswSeededCiphertext32Version::V0,
+                inner,
+            } => Ok(GgswSeededCiphertext32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> GgswSeededCiphertext32 {
+        self.deserialize_from_reader(reader).unwrap()
+    }
 }
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 32 bits integers. It deserializes a GLWE ciphertext vector entity.
-impl EntityDeserializationEngine<&[u8], GlweCiphertextVector32> for DefaultSerializationEngine {
+/// operates on 64 bits integers. It deserializes a seeded GGSW ciphertext entity.
+impl EntityDeserializationEngine<&[u8], GgswSeededCiphertext64> for DefaultSerializationEngine {
+    /// TODO
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GgswSeededCiphertext64, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GgswSeededCiphertext64 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<GgswSeededCiphertext64, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableGgswSeededCiphertext64 {
+            version: GgswSeededCiphertext64Version,
+            inner: ImplStandardGgswSeededCiphertext<Vec<u64>>,
+        }
+        let deserialized: DeserializableGgswSeededCiphertext64 = bincode::deserialize_from(reader)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableGgswSeededCiphertext64 {
+                version: GgswSeededCiphertext64Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableGgswSeededCiphertext64 {
+                version: GgswSeededCiphertext64Version::V0,
+                inner,
+            } => Ok(GgswSeededCiphertext64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> GgswSeededCiphertext64 {
+        self.deserialize_from_reader(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a GLWE ciphertext entity.
+impl EntityDeserializationEngine<&[u8], GlweCiphertext32> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
@@ -773,8 +1026,9 @@ impl EntityDeserializationEngine<&[u8], GlweCiphertextVector32> for DefaultSeria
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
     /// let glwe_dimension = GlweDimension(2);
     /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
     /// // Here a hard-set encoding is applied (shift by 20 bits)
-    /// let input = vec![3_u32 << 20; 8];
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
     /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
@@ -785,13 +1039,12 @@ impl EntityDeserializationEngine<&[u8], GlweCiphertextVector32> for DefaultSeria
     ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
     /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
     ///
-    /// let ciphertext_vector =
-    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&ciphertext_vector)?;
+    /// let serialized = serialization_engine.serialize(&ciphertext)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(ciphertext_vector, recovered);
+    /// assert_eq!(ciphertext, recovered);
     ///
     /// #
     /// # Ok(())
@@ -800,42 +1053,53 @@ impl EntityDeserializationEngine<&[u8], GlweCiphertextVector32> for DefaultSeria
     fn deserialize(
         &mut self,
         serialized: &[u8],
-    ) -> Result<GlweCiphertextVector32, EntityDeserializationError<Self::EngineError>> {
+    ) -> Result<GlweCiphertext32, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweCiphertext32 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<GlweCiphertext32, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
-        struct DeserializableGlweCiphertextVector32 {
-            version: GlweCiphertextVector32Version,
-            inner: ImplGlweList<Vec<u32>>,
+        struct DeserializableGlweCiphertext32 {
+            version: GlweCiphertext32Version,
+            inner: ImplGlweCiphertext<Vec<u32>>,
         }
-        let deserialized: DeserializableGlweCiphertextVector32 = bincode::deserialize(serialized)
+        let deserialized: DeserializableGlweCiphertext32 = bincode::deserialize_from(reader)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
-            DeserializableGlweCiphertextVector32 {
-                version: GlweCiphertextVector32Version::Unsupported,
+            DeserializableGlweCiphertext32 {
+                version: GlweCiphertext32Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
-            DeserializableGlweCiphertextVector32 {
-                version: GlweCiphertextVector32Version::V0,
+            DeserializableGlweCiphertext32 {
+                version: GlweCiphertext32Version::V0,
                 inner,
-            } => Ok(GlweCiphertextVector32(inner)),
+            } => Ok(GlweCiphertext32(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweCiphertextVector32 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(&mut self, reader: R) -> GlweCiphertext32 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 64 bits integers. It deserializes a GLWE ciphertext vector entity.
-impl EntityDeserializationEngine<&[u8], GlweCiphertextVector64> for DefaultSerializationEngine {
+/// operates on 64 bits integers. It deserializes a GLWE ciphertext entity.
+impl EntityDeserializationEngine<&[u8], GlweCiphertext64> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
@@ -843,8 +1107,9 @@ impl EntityDeserializationEngine<&[u8], GlweCiphertextVector64> for DefaultSeria
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
     /// let glwe_dimension = GlweDimension(2);
     /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
     /// // Here a hard-set encoding is applied (shift by 50 bits)
-    /// let input = vec![3_u64 << 50; 8];
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
     /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
@@ -855,13 +1120,12 @@ impl EntityDeserializationEngine<&[u8], GlweCiphertextVector64> for DefaultSeria
     ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
     /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
     ///
-    /// let ciphertext_vector =
-    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&ciphertext_vector)?;
+    /// let serialized = serialization_engine.serialize(&ciphertext)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(ciphertext_vector, recovered);
+    /// assert_eq!(ciphertext, recovered);
     ///
     /// #
     /// # Ok(())
@@ -870,38 +1134,49 @@ impl EntityDeserializationEngine<&[u8], GlweCiphertextVector64> for DefaultSeria
     fn deserialize(
         &mut self,
         serialized: &[u8],
-    ) -> Result<GlweCiphertextVector64, EntityDeserializationError<Self::EngineError>> {
+    ) -> Result<GlweCiphertext64, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweCiphertext64 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<GlweCiphertext64, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
-        struct DeserializableGlweCiphertextVector64 {
-            version: GlweCiphertextVector64Version,
-            inner: ImplGlweList<Vec<u64>>,
+        struct DeserializableGlweCiphertext64 {
+            version: GlweCiphertext64Version,
+            inner: ImplGlweCiphertext<Vec<u64>>,
         }
-        let deserialized: DeserializableGlweCiphertextVector64 = bincode::deserialize(serialized)
+        let deserialized: DeserializableGlweCiphertext64 = bincode::deserialize_from(reader)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
-            DeserializableGlweCiphertextVector64 {
-                version: GlweCiphertextVector64Version::Unsupported,
+            DeserializableGlweCiphertext64 {
+                version: GlweCiphertext64Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
-            DeserializableGlweCiphertextVector64 {
-                version: GlweCiphertextVector64Version::V0,
+            DeserializableGlweCiphertext64 {
+                version: GlweCiphertext64Version::V0,
                 inner,
-            } => Ok(GlweCiphertextVector64(inner)),
+            } => Ok(GlweCiphertext64(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweCiphertextVector64 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(&mut self, reader: R) -> GlweCiphertext64 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 32 bits integers. It deserializes a GLWE secret key entity.
-impl EntityDeserializationEngine<&[u8], GlweSecretKey32> for DefaultSerializationEngine {
+/// operates on 32 bits integers. It deserializes a trivial GLWE ciphertext entity.
+impl EntityDeserializationEngine<&[u8], TrivialGlweCiphertext32> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
@@ -912,18 +1187,21 @@ impl EntityDeserializationEngine<&[u8], GlweSecretKey32> for DefaultSerializatio
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
     /// let glwe_dimension = GlweDimension(2);
     /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let glwe_secret_key: GlweSecretKey32 =
-    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext: TrivialGlweCiphertext32 = engine
+    ///     .create_trivial_glwe_ciphertext(glwe_dimension.to_glwe_size(), &plaintext_vector)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&glwe_secret_key)?;
+    /// let serialized = serialization_engine.serialize(&ciphertext)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(glwe_secret_key, recovered);
+    /// assert_eq!(ciphertext, recovered);
     ///
     /// #
     /// # Ok(())
@@ -932,38 +1210,52 @@ impl EntityDeserializationEngine<&[u8], GlweSecretKey32> for DefaultSerializatio
     fn deserialize(
         &mut self,
         serialized: &[u8],
-    ) -> Result<GlweSecretKey32, EntityDeserializationError<Self::EngineError>> {
+    ) -> Result<TrivialGlweCiphertext32, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> TrivialGlweCiphertext32 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<TrivialGlweCiphertext32, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
-        struct DeserializableGlweSecretKey32 {
-            version: GlweSecretKey32Version,
-            inner: ImplGlweSecretKey<BinaryKeyKind, Vec<u32>>,
+        struct DeserializableTrivialGlweCiphertext32 {
+            version: TrivialGlweCiphertext32Version,
+            inner: ImplGlweCiphertext<Vec<u32>>,
         }
-        let deserialized: DeserializableGlweSecretKey32 = bincode::deserialize(serialized)
+        let deserialized: DeserializableTrivialGlweCiphertext32 = bincode::deserialize_from(reader)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
-            DeserializableGlweSecretKey32 {
-                version: GlweSecretKey32Version::Unsupported,
+            DeserializableTrivialGlweCiphertext32 {
+                version: TrivialGlweCiphertext32Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
-            DeserializableGlweSecretKey32 {
-                version: GlweSecretKey32Version::V0,
+            DeserializableTrivialGlweCiphertext32 {
+                version: TrivialGlweCiphertext32Version::V0,
                 inner,
-            } => Ok(GlweSecretKey32(inner)),
+            } => Ok(TrivialGlweCiphertext32(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweSecretKey32 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> TrivialGlweCiphertext32 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 64 bits integers. It deserializes a GLWE secret key entity.
-impl EntityDeserializationEngine<&[u8], GlweSecretKey64> for DefaultSerializationEngine {
+/// operates on 64 bits integers. It deserializes a trivial GLWE ciphertext entity.
+impl EntityDeserializationEngine<&[u8], TrivialGlweCiphertext64> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
@@ -974,18 +1266,21 @@ impl EntityDeserializationEngine<&[u8], GlweSecretKey64> for DefaultSerializatio
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
     /// let glwe_dimension = GlweDimension(2);
     /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u64 << 20; polynomial_size.0];
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let glwe_secret_key: GlweSecretKey64 =
-    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext: TrivialGlweCiphertext64 = engine
+    ///     .create_trivial_glwe_ciphertext(glwe_dimension.to_glwe_size(), &plaintext_vector)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&glwe_secret_key)?;
+    /// let serialized = serialization_engine.serialize(&ciphertext)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(glwe_secret_key, recovered);
+    /// assert_eq!(ciphertext, recovered);
     ///
     /// #
     /// # Ok(())
@@ -994,42 +1289,56 @@ impl EntityDeserializationEngine<&[u8], GlweSecretKey64> for DefaultSerializatio
     fn deserialize(
         &mut self,
         serialized: &[u8],
-    ) -> Result<GlweSecretKey64, EntityDeserializationError<Self::EngineError>> {
+    ) -> Result<TrivialGlweCiphertext64, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> TrivialGlweCiphertext64 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<TrivialGlweCiphertext64, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
-        struct DeserializableGlweSecretKey64 {
-            version: GlweSecretKey64Version,
-            inner: ImplGlweSecretKey<BinaryKeyKind, Vec<u64>>,
+        struct DeserializableTrivialGlweCiphertext64 {
+            version: TrivialGlweCiphertext64Version,
+            inner: ImplGlweCiphertext<Vec<u64>>,
         }
-        let deserialized: DeserializableGlweSecretKey64 = bincode::deserialize(serialized)
+        let deserialized: DeserializableTrivialGlweCiphertext64 = bincode::deserialize_from(reader)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
-            DeserializableGlweSecretKey64 {
-                version: GlweSecretKey64Version::Unsupported,
+            DeserializableTrivialGlweCiphertext64 {
+                version: TrivialGlweCiphertext64Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
-            DeserializableGlweSecretKey64 {
-                version: GlweSecretKey64Version::V0,
+            DeserializableTrivialGlweCiphertext64 {
+                version: TrivialGlweCiphertext64Version::V0,
                 inner,
-            } => Ok(GlweSecretKey64(inner)),
+            } => Ok(TrivialGlweCiphertext64(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweSecretKey64 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> TrivialGlweCiphertext64 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 32 bits integers. It deserializes a GLWE seeded ciphertext entity.
-impl EntityDeserializationEngine<&[u8], GlweSeededCiphertext32> for DefaultSerializationEngine {
+/// operates on 32 bits integers. It deserializes a GLWE ciphertext vector entity.
+impl EntityDeserializationEngine<&[u8], GlweCiphertextVector32> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
@@ -1037,9 +1346,8 @@ impl EntityDeserializationEngine<&[u8], GlweSeededCiphertext32> for DefaultSeria
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
     /// let glwe_dimension = GlweDimension(2);
     /// let polynomial_size = PolynomialSize(4);
-    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
     /// // Here a hard-set encoding is applied (shift by 20 bits)
-    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let input = vec![3_u32 << 20; 8];
     /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
@@ -1050,13 +1358,13 @@ impl EntityDeserializationEngine<&[u8], GlweSeededCiphertext32> for DefaultSeria
     ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
     /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
     ///
-    /// let seeded_ciphertext =
-    ///     engine.encrypt_glwe_seeded_ciphertext(&key, &plaintext_vector, noise)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&seeded_ciphertext)?;
+    /// let serialized = serialization_engine.serialize(&ciphertext_vector)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(seeded_ciphertext, recovered);
+    /// assert_eq!(ciphertext_vector, recovered);
     ///
     /// #
     /// # Ok(())
@@ -1065,42 +1373,56 @@ impl EntityDeserializationEngine<&[u8], GlweSeededCiphertext32> for DefaultSeria
     fn deserialize(
         &mut self,
         serialized: &[u8],
-    ) -> Result<GlweSeededCiphertext32, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializableGlweSeededCiphertext32 {
-            version: GlweSeededCiphertext32Version,
-            inner: ImplGlweSeededCiphertext<Vec<u32>>,
-        }
-        let deserialized: DeserializableGlweSeededCiphertext32 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
+    ) -> Result<GlweCiphertextVector32, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweCiphertextVector32 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<GlweCiphertextVector32, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableGlweCiphertextVector32 {
+            version: GlweCiphertextVector32Version,
+            inner: ImplGlweList<Vec<u32>>,
+        }
+        let deserialized: DeserializableGlweCiphertextVector32 = bincode::deserialize_from(reader)
+            .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
-            DeserializableGlweSeededCiphertext32 {
-                version: GlweSeededCiphertext32Version::Unsupported,
+            DeserializableGlweCiphertextVector32 {
+                version: GlweCiphertextVector32Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
-            DeserializableGlweSeededCiphertext32 {
-                version: GlweSeededCiphertext32Version::V0,
+            DeserializableGlweCiphertextVector32 {
+                version: GlweCiphertextVector32Version::V0,
                 inner,
-            } => Ok(GlweSeededCiphertext32(inner)),
+            } => Ok(GlweCiphertextVector32(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweSeededCiphertext32 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> GlweCiphertextVector32 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 64 bits integers. It deserializes a GLWE seeded ciphertext entity.
-impl EntityDeserializationEngine<&[u8], GlweSeededCiphertext64> for DefaultSerializationEngine {
+/// operates on 64 bits integers. It deserializes a GLWE ciphertext vector entity.
+impl EntityDeserializationEngine<&[u8], GlweCiphertextVector64> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
@@ -1108,9 +1430,8 @@ impl EntityDeserializationEngine<&[u8], GlweSeededCiphertext64> for DefaultSeria
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
     /// let glwe_dimension = GlweDimension(2);
     /// let polynomial_size = PolynomialSize(4);
-    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
     /// // Here a hard-set encoding is applied (shift by 50 bits)
-    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let input = vec![3_u64 << 50; 8];
     /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
@@ -1121,13 +1442,13 @@ impl EntityDeserializationEngine<&[u8], GlweSeededCiphertext64> for DefaultSeria
     ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
     /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
     ///
-    /// let seeded_ciphertext =
-    ///     engine.encrypt_glwe_seeded_ciphertext(&key, &plaintext_vector, noise)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&seeded_ciphertext)?;
+    /// let serialized = serialization_engine.serialize(&ciphertext_vector)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(seeded_ciphertext, recovered);
+    /// assert_eq!(ciphertext_vector, recovered);
     ///
     /// #
     /// # Ok(())
@@ -1136,44 +1457,55 @@ impl EntityDeserializationEngine<&[u8], GlweSeededCiphertext64> for DefaultSeria
     fn deserialize(
         &mut self,
         serialized: &[u8],
-    ) -> Result<GlweSeededCiphertext64, EntityDeserializationError<Self::EngineError>> {
+    ) -> Result<GlweCiphertextVector64, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweCiphertextVector64 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<GlweCiphertextVector64, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
-        struct DeserializableGlweSeededCiphertext64 {
-            version: GlweSeededCiphertext64Version,
-            inner: ImplGlweSeededCiphertext<Vec<u64>>,
+        struct DeserializableGlweCiphertextVector64 {
+            version: GlweCiphertextVector64Version,
+            inner: ImplGlweList<Vec<u64>>,
         }
-        let deserialized: DeserializableGlweSeededCiphertext64 = bincode::deserialize(serialized)
+        let deserialized: DeserializableGlweCiphertextVector64 = bincode::deserialize_from(reader)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
-            DeserializableGlweSeededCiphertext64 {
-                version: GlweSeededCiphertext64Version::Unsupported,
+            DeserializableGlweCiphertextVector64 {
+                version: GlweCiphertextVector64Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
-            DeserializableGlweSeededCiphertext64 {
-                version: GlweSeededCiphertext64Version::V0,
+            DeserializableGlweCiphertextVector64 {
+                version: GlweCiphertextVector64Version::V0,
                 inner,
-            } => Ok(GlweSeededCiphertext64(inner)),
+            } => Ok(GlweCiphertextVector64(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweSeededCiphertext64 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> GlweCiphertextVector64 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 32 bits integers. It deserializes a GLWE seeded ciphertext vector entity.
-impl EntityDeserializationEngine<&[u8], GlweSeededCiphertextVector32>
-    for DefaultSerializationEngine
-{
+/// operates on 32 bits integers. It deserializes a GLWE secret key entity.
+impl EntityDeserializationEngine<&[u8], GlweSecretKey32> for DefaultSerializationEngine {
     /// # Example:
     /// ```
-    /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
@@ -1181,25 +1513,91 @@ impl EntityDeserializationEngine<&[u8], GlweSeededCiphertextVector32>
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
     /// let glwe_dimension = GlweDimension(2);
     /// let polynomial_size = PolynomialSize(4);
-    /// // Here a hard-set encoding is applied (shift by 20 bits)
-    /// let input = vec![3_u32 << 20; 8];
-    /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: GlweSecretKey32 =
+    /// let glwe_secret_key: GlweSecretKey32 =
     ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
-    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
     ///
-    /// let seeded_ciphertext_vector =
-    ///     engine.encrypt_glwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&glwe_secret_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(glwe_secret_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GlweSecretKey32, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweSecretKey32 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<GlweSecretKey32, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableGlweSecretKey32 {
+            version: GlweSecretKey32Version,
+            inner: ImplGlweSecretKey<BinaryKeyKind, Vec<u32>>,
+        }
+        let deserialized: DeserializableGlweSecretKey32 = bincode::deserialize_from(reader)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableGlweSecretKey32 {
+                version: GlweSecretKey32Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableGlweSecretKey32 {
+                version: GlweSecretKey32Version::V0,
+                inner,
+            } => Ok(GlweSecretKey32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(&mut self, reader: R) -> GlweSecretKey32 {
+        self.deserialize_from_reader(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a GLWE secret key entity.
+impl EntityDeserializationEngine<&[u8], GlweSecretKey64> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let glwe_secret_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&seeded_ciphertext_vector)?;
+    /// let serialized = serialization_engine.serialize(&glwe_secret_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(seeded_ciphertext_vector, recovered);
+    /// assert_eq!(glwe_secret_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -1208,45 +1606,128 @@ impl EntityDeserializationEngine<&[u8], GlweSeededCiphertextVector32>
     fn deserialize(
         &mut self,
         serialized: &[u8],
-    ) -> Result<GlweSeededCiphertextVector32, EntityDeserializationError<Self::EngineError>> {
+    ) -> Result<GlweSecretKey64, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweSecretKey64 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<GlweSecretKey64, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
-        struct DeserializableGlweSeededCiphertextVector32 {
-            version: GlweSeededCiphertextVector32Version,
-            inner: ImplGlweSeededList<Vec<u32>>,
+        struct DeserializableGlweSecretKey64 {
+            version: GlweSecretKey64Version,
+            inner: ImplGlweSecretKey<BinaryKeyKind, Vec<u64>>,
         }
-        let deserialized: DeserializableGlweSeededCiphertextVector32 =
-            bincode::deserialize(serialized)
-                .map_err(DefaultSerializationError::Deserialization)
-                .map_err(EntityDeserializationError::Engine)?;
+        let deserialized: DeserializableGlweSecretKey64 = bincode::deserialize_from(reader)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
-            DeserializableGlweSeededCiphertextVector32 {
-                version: GlweSeededCiphertextVector32Version::Unsupported,
+            DeserializableGlweSecretKey64 {
+                version: GlweSecretKey64Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
-            DeserializableGlweSeededCiphertextVector32 {
-                version: GlweSeededCiphertextVector32Version::V0,
+            DeserializableGlweSecretKey64 {
+                version: GlweSecretKey64Version::V0,
                 inner,
-            } => Ok(GlweSeededCiphertextVector32(inner)),
+            } => Ok(GlweSecretKey64(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweSeededCiphertextVector32 {
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(&mut self, reader: R) -> GlweSecretKey64 {
+        self.deserialize_from_reader(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a ternary GLWE secret key entity.
+impl EntityDeserializationEngine<&[u8], GlweSecretKeyTernary32> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let glwe_secret_key: GlweSecretKeyTernary32 =
+    ///     engine.generate_new_glwe_secret_key_ternary(glwe_dimension, polynomial_size)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&glwe_secret_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(glwe_secret_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GlweSecretKeyTernary32, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweSecretKeyTernary32 {
         self.deserialize(serialized).unwrap()
     }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<GlweSecretKeyTernary32, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableGlweSecretKey32 {
+            version: GlweSecretKeyTernary32Version,
+            inner: ImplGlweSecretKey<TernaryKeyKind, Vec<u32>>,
+        }
+        let deserialized: DeserializableGlweSecretKey32 = bincode::deserialize_from(reader)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableGlweSecretKey32 {
+                version: GlweSecretKeyTernary32Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableGlweSecretKey32 {
+                version: GlweSecretKeyTernary32Version::V0,
+                inner,
+            } => Ok(GlweSecretKeyTernary32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> GlweSecretKeyTernary32 {
+        self.deserialize_from_reader(reader).unwrap()
+    }
 }
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 64 bits integers. It deserializes a GLWE seeded ciphertext vector entity.
-impl EntityDeserializationEngine<&[u8], GlweSeededCiphertextVector64>
-    for DefaultSerializationEngine
-{
+/// operates on 64 bits integers. It deserializes a ternary GLWE secret key entity.
+impl EntityDeserializationEngine<&[u8], GlweSecretKeyTernary64> for DefaultSerializationEngine {
     /// # Example:
     /// ```
-    /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
@@ -1254,7 +1735,1379 @@ impl EntityDeserializationEngine<&[u8], GlweSeededCiphertextVector64>
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
     /// let glwe_dimension = GlweDimension(2);
     /// let polynomial_size = PolynomialSize(4);
-    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let glwe_secret_key: GlweSecretKeyTernary64 =
+    ///     engine.generate_new_glwe_secret_key_ternary(glwe_dimension, polynomial_size)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&glwe_secret_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(glwe_secret_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GlweSecretKeyTernary64, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweSecretKeyTernary64 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<GlweSecretKeyTernary64, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableGlweSecretKey64 {
+            version: GlweSecretKeyTernary64Version,
+            inner: ImplGlweSecretKey<TernaryKeyKind, Vec<u64>>,
+        }
+        let deserialized: DeserializableGlweSecretKey64 = bincode::deserialize_from(reader)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableGlweSecretKey64 {
+                version: GlweSecretKeyTernary64Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableGlweSecretKey64 {
+                version: GlweSecretKeyTernary64Version::V0,
+                inner,
+            } => Ok(GlweSecretKeyTernary64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> GlweSecretKeyTernary64 {
+        self.deserialize_from_reader(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a gaussian GLWE secret key entity.
+impl EntityDeserializationEngine<&[u8], GlweSecretKeyGaussian32> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let glwe_secret_key: GlweSecretKeyGaussian32 =
+    ///     engine.generate_new_glwe_secret_key_gaussian(glwe_dimension, polynomial_size)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&glwe_secret_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(glwe_secret_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GlweSecretKeyGaussian32, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweSecretKeyGaussian32 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<GlweSecretKeyGaussian32, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableGlweSecretKey32 {
+            version: GlweSecretKeyGaussian32Version,
+            inner: ImplGlweSecretKey<GaussianKeyKind, Vec<u32>>,
+        }
+        let deserialized: DeserializableGlweSecretKey32 = bincode::deserialize_from(reader)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableGlweSecretKey32 {
+                version: GlweSecretKeyGaussian32Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableGlweSecretKey32 {
+                version: GlweSecretKeyGaussian32Version::V0,
+                inner,
+            } => Ok(GlweSecretKeyGaussian32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> GlweSecretKeyGaussian32 {
+        self.deserialize_from_reader(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a gaussian GLWE secret key entity.
+impl EntityDeserializationEngine<&[u8], GlweSecretKeyGaussian64> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let glwe_secret_key: GlweSecretKeyGaussian64 =
+    ///     engine.generate_new_glwe_secret_key_gaussian(glwe_dimension, polynomial_size)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&glwe_secret_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(glwe_secret_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GlweSecretKeyGaussian64, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweSecretKeyGaussian64 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<GlweSecretKeyGaussian64, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableGlweSecretKey64 {
+            version: GlweSecretKeyGaussian64Version,
+            inner: ImplGlweSecretKey<GaussianKeyKind, Vec<u64>>,
+        }
+        let deserialized: DeserializableGlweSecretKey64 = bincode::deserialize_from(reader)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableGlweSecretKey64 {
+                version: GlweSecretKeyGaussian64Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableGlweSecretKey64 {
+                version: GlweSecretKeyGaussian64Version::V0,
+                inner,
+            } => Ok(GlweSecretKeyGaussian64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> GlweSecretKeyGaussian64 {
+        self.deserialize_from_reader(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a GLWE tensor product secret key entity.
+impl EntityDeserializationEngine<&[u8], GlweTensorProductSecretKey32>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let glwe_secret_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let tensor_product_key: GlweTensorProductSecretKey32 =
+    ///     engine.create_tensor_product_secret_key_same_key(&glwe_secret_key)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&tensor_product_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(tensor_product_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GlweTensorProductSecretKey32, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweTensorProductSecretKey32 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<GlweTensorProductSecretKey32, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableGlweTensorProductSecretKey32 {
+            version: GlweTensorProductSecretKey32Version,
+            inner: ImplGlweTensorProductSecretKey<BinaryKeyKind, Vec<u32>>,
+        }
+        let deserialized: DeserializableGlweTensorProductSecretKey32 =
+            bincode::deserialize_from(reader)
+                .map_err(DefaultSerializationError::Deserialization)
+                .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableGlweTensorProductSecretKey32 {
+                version: GlweTensorProductSecretKey32Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableGlweTensorProductSecretKey32 {
+                version: GlweTensorProductSecretKey32Version::V0,
+                inner,
+            } => Ok(GlweTensorProductSecretKey32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> GlweTensorProductSecretKey32 {
+        self.deserialize_from_reader(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a GLWE tensor product secret key entity.
+impl EntityDeserializationEngine<&[u8], GlweTensorProductSecretKey64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let glwe_secret_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let tensor_product_key: GlweTensorProductSecretKey64 =
+    ///     engine.create_tensor_product_secret_key_same_key(&glwe_secret_key)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&tensor_product_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(tensor_product_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GlweTensorProductSecretKey64, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweTensorProductSecretKey64 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<GlweTensorProductSecretKey64, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableGlweTensorProductSecretKey64 {
+            version: GlweTensorProductSecretKey64Version,
+            inner: ImplGlweTensorProductSecretKey<BinaryKeyKind, Vec<u64>>,
+        }
+        let deserialized: DeserializableGlweTensorProductSecretKey64 =
+            bincode::deserialize_from(reader)
+                .map_err(DefaultSerializationError::Deserialization)
+                .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableGlweTensorProductSecretKey64 {
+                version: GlweTensorProductSecretKey64Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableGlweTensorProductSecretKey64 {
+                version: GlweTensorProductSecretKey64Version::V0,
+                inner,
+            } => Ok(GlweTensorProductSecretKey64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> GlweTensorProductSecretKey64 {
+        self.deserialize_from_reader(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a GLWE ring reduction key entity.
+impl EntityDeserializationEngine<&[u8], GlweRingReductionKey32> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_glwe_dimension = GlweDimension(1);
+    /// let input_polynomial_size = PolynomialSize(1024);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let output_polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(5);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(input_glwe_dimension, input_polynomial_size)?;
+    /// let output_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, output_polynomial_size)?;
+    /// let ring_reduction_key: GlweRingReductionKey32 = engine.generate_new_glwe_ring_reduction_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&ring_reduction_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(ring_reduction_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GlweRingReductionKey32, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweRingReductionKey32 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<GlweRingReductionKey32, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableGlweRingReductionKey32 {
+            version: GlweRingReductionKey32Version,
+            inner: ImplGlweRingReductionKey<Vec<u32>>,
+        }
+        let deserialized: DeserializableGlweRingReductionKey32 = bincode::deserialize_from(reader)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableGlweRingReductionKey32 {
+                version: GlweRingReductionKey32Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableGlweRingReductionKey32 {
+                version: GlweRingReductionKey32Version::V0,
+                inner,
+            } => Ok(GlweRingReductionKey32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> GlweRingReductionKey32 {
+        self.deserialize_from_reader(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a GLWE ring reduction key entity.
+impl EntityDeserializationEngine<&[u8], GlweRingReductionKey64> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_glwe_dimension = GlweDimension(1);
+    /// let input_polynomial_size = PolynomialSize(1024);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let output_polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(5);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(input_glwe_dimension, input_polynomial_size)?;
+    /// let output_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, output_polynomial_size)?;
+    /// let ring_reduction_key: GlweRingReductionKey64 = engine.generate_new_glwe_ring_reduction_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&ring_reduction_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(ring_reduction_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GlweRingReductionKey64, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweRingReductionKey64 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<GlweRingReductionKey64, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableGlweRingReductionKey64 {
+            version: GlweRingReductionKey64Version,
+            inner: ImplGlweRingReductionKey<Vec<u64>>,
+        }
+        let deserialized: DeserializableGlweRingReductionKey64 = bincode::deserialize_from(reader)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableGlweRingReductionKey64 {
+                version: GlweRingReductionKey64Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableGlweRingReductionKey64 {
+                version: GlweRingReductionKey64Version::V0,
+                inner,
+            } => Ok(GlweRingReductionKey64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> GlweRingReductionKey64 {
+        self.deserialize_from_reader(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a GLWE ring switching key entity.
+impl EntityDeserializationEngine<&[u8], GlweRingSwitchingKey32> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(5);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let output_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let input_key: GlweSecretKey32 = engine.split_glwe_secret_key_ring(&output_key)?;
+    /// let ring_switching_key: GlweRingSwitchingKey32 = engine.generate_new_glwe_ring_switching_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&ring_switching_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(ring_switching_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GlweRingSwitchingKey32, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweRingSwitchingKey32 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<GlweRingSwitchingKey32, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableGlweRingSwitchingKey32 {
+            version: GlweRingSwitchingKey32Version,
+            inner: ImplGlweRingSwitchingKey<Vec<u32>>,
+        }
+        let deserialized: DeserializableGlweRingSwitchingKey32 = bincode::deserialize_from(reader)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableGlweRingSwitchingKey32 {
+                version: GlweRingSwitchingKey32Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableGlweRingSwitchingKey32 {
+                version: GlweRingSwitchingKey32Version::V0,
+                inner,
+            } => Ok(GlweRingSwitchingKey32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> GlweRingSwitchingKey32 {
+        self.deserialize_from_reader(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a GLWE ring switching key entity.
+impl EntityDeserializationEngine<&[u8], GlweRingSwitchingKey64> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(5);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let output_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let input_key: GlweSecretKey64 = engine.split_glwe_secret_key_ring(&output_key)?;
+    /// let ring_switching_key: GlweRingSwitchingKey64 = engine.generate_new_glwe_ring_switching_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&ring_switching_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(ring_switching_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GlweRingSwitchingKey64, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweRingSwitchingKey64 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<GlweRingSwitchingKey64, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableGlweRingSwitchingKey64 {
+            version: GlweRingSwitchingKey64Version,
+            inner: ImplGlweRingSwitchingKey<Vec<u64>>,
+        }
+        let deserialized: DeserializableGlweRingSwitchingKey64 = bincode::deserialize_from(reader)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableGlweRingSwitchingKey64 {
+                version: GlweRingSwitchingKey64Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableGlweRingSwitchingKey64 {
+                version: GlweRingSwitchingKey64Version::V0,
+                inner,
+            } => Ok(GlweRingSwitchingKey64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> GlweRingSwitchingKey64 {
+        self.deserialize_from_reader(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a GLWE relinearization key entity.
+impl EntityDeserializationEngine<&[u8], GlweRelinearizationKey32> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(5);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let relinearization_key: GlweRelinearizationKey32 = engine
+    ///     .generate_new_glwe_relinearization_key(
+    ///         &key,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         noise,
+    ///     )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&relinearization_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(relinearization_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GlweRelinearizationKey32, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweRelinearizationKey32 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<GlweRelinearizationKey32, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableGlweRelinearizationKey32 {
+            version: GlweRelinearizationKey32Version,
+            inner: ImplGlweRelinearizationKey<Vec<u32>>,
+        }
+        let deserialized: DeserializableGlweRelinearizationKey32 =
+            bincode::deserialize_from(reader)
+                .map_err(DefaultSerializationError::Deserialization)
+                .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableGlweRelinearizationKey32 {
+                version: GlweRelinearizationKey32Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableGlweRelinearizationKey32 {
+                version: GlweRelinearizationKey32Version::V0,
+                inner,
+            } => Ok(GlweRelinearizationKey32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> GlweRelinearizationKey32 {
+        self.deserialize_from_reader(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a GLWE relinearization key entity.
+impl EntityDeserializationEngine<&[u8], GlweRelinearizationKey64> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(5);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let relinearization_key: GlweRelinearizationKey64 = engine
+    ///     .generate_new_glwe_relinearization_key(
+    ///         &key,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         noise,
+    ///     )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&relinearization_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(relinearization_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GlweRelinearizationKey64, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweRelinearizationKey64 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<GlweRelinearizationKey64, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableGlweRelinearizationKey64 {
+            version: GlweRelinearizationKey64Version,
+            inner: ImplGlweRelinearizationKey<Vec<u64>>,
+        }
+        let deserialized: DeserializableGlweRelinearizationKey64 =
+            bincode::deserialize_from(reader)
+                .map_err(DefaultSerializationError::Deserialization)
+                .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableGlweRelinearizationKey64 {
+                version: GlweRelinearizationKey64Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableGlweRelinearizationKey64 {
+                version: GlweRelinearizationKey64Version::V0,
+                inner,
+            } => Ok(GlweRelinearizationKey64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> GlweRelinearizationKey64 {
+        self.deserialize_from_reader(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a GLWE automorphism keys entity.
+impl EntityDeserializationEngine<&[u8], GlweAutomorphismKeys32> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(5);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let automorphism_keys: GlweAutomorphismKeys32 = engine.generate_new_glwe_automorphism_keys(
+    ///     &key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&automorphism_keys)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(automorphism_keys, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GlweAutomorphismKeys32, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweAutomorphismKeys32 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<GlweAutomorphismKeys32, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableGlweAutomorphismKeys32 {
+            version: GlweAutomorphismKeys32Version,
+            inner: ImplGlweAutomorphismKeys<Vec<u32>>,
+        }
+        let deserialized: DeserializableGlweAutomorphismKeys32 = bincode::deserialize_from(reader)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableGlweAutomorphismKeys32 {
+                version: GlweAutomorphismKeys32Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableGlweAutomorphismKeys32 {
+                version: GlweAutomorphismKeys32Version::V0,
+                inner,
+            } => Ok(GlweAutomorphismKeys32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> GlweAutomorphismKeys32 {
+        self.deserialize_from_reader(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a GLWE automorphism keys entity.
+impl EntityDeserializationEngine<&[u8], GlweAutomorphismKeys64> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(5);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let automorphism_keys: GlweAutomorphismKeys64 = engine.generate_new_glwe_automorphism_keys(
+    ///     &key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&automorphism_keys)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(automorphism_keys, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GlweAutomorphismKeys64, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweAutomorphismKeys64 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<GlweAutomorphismKeys64, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableGlweAutomorphismKeys64 {
+            version: GlweAutomorphismKeys64Version,
+            inner: ImplGlweAutomorphismKeys<Vec<u64>>,
+        }
+        let deserialized: DeserializableGlweAutomorphismKeys64 = bincode::deserialize_from(reader)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableGlweAutomorphismKeys64 {
+                version: GlweAutomorphismKeys64Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableGlweAutomorphismKeys64 {
+                version: GlweAutomorphismKeys64Version::V0,
+                inner,
+            } => Ok(GlweAutomorphismKeys64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> GlweAutomorphismKeys64 {
+        self.deserialize_from_reader(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a GLWE seeded ciphertext entity.
+impl EntityDeserializationEngine<&[u8], GlweSeededCiphertext32> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let seeded_ciphertext =
+    ///     engine.encrypt_glwe_seeded_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&seeded_ciphertext)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(seeded_ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GlweSeededCiphertext32, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweSeededCiphertext32 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<GlweSeededCiphertext32, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableGlweSeededCiphertext32 {
+            version: GlweSeededCiphertext32Version,
+            inner: ImplGlweSeededCiphertext<Vec<u32>>,
+        }
+        let deserialized: DeserializableGlweSeededCiphertext32 = bincode::deserialize_from(reader)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableGlweSeededCiphertext32 {
+                version: GlweSeededCiphertext32Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableGlweSeededCiphertext32 {
+                version: GlweSeededCiphertext32Version::V0,
+                inner,
+            } => Ok(GlweSeededCiphertext32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> GlweSeededCiphertext32 {
+        self.deserialize_from_reader(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a GLWE seeded ciphertext entity.
+impl EntityDeserializationEngine<&[u8], GlweSeededCiphertext64> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let seeded_ciphertext =
+    ///     engine.encrypt_glwe_seeded_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&seeded_ciphertext)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(seeded_ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GlweSeededCiphertext64, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweSeededCiphertext64 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<GlweSeededCiphertext64, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableGlweSeededCiphertext64 {
+            version: GlweSeededCiphertext64Version,
+            inner: ImplGlweSeededCiphertext<Vec<u64>>,
+        }
+        let deserialized: DeserializableGlweSeededCiphertext64 = bincode::deserialize_from(reader)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableGlweSeededCiphertext64 {
+                version: GlweSeededCiphertext64Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableGlweSeededCiphertext64 {
+                version: GlweSeededCiphertext64Version::V0,
+                inner,
+            } => Ok(GlweSeededCiphertext64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> GlweSeededCiphertext64 {
+        self.deserialize_from_reader(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a GLWE seeded ciphertext vector entity.
+impl EntityDeserializationEngine<&[u8], GlweSeededCiphertextVector32>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let seeded_ciphertext_vector =
+    ///     engine.encrypt_glwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&seeded_ciphertext_vector)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(seeded_ciphertext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GlweSeededCiphertextVector32, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweSeededCiphertextVector32 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<GlweSeededCiphertextVector32, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableGlweSeededCiphertextVector32 {
+            version: GlweSeededCiphertextVector32Version,
+            inner: ImplGlweSeededList<Vec<u32>>,
+        }
+        let deserialized: DeserializableGlweSeededCiphertextVector32 =
+            bincode::deserialize_from(reader)
+                .map_err(DefaultSerializationError::Deserialization)
+                .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableGlweSeededCiphertextVector32 {
+                version: GlweSeededCiphertextVector32Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableGlweSeededCiphertextVector32 {
+                version: GlweSeededCiphertextVector32Version::V0,
+                inner,
+            } => Ok(GlweSeededCiphertextVector32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> GlweSeededCiphertextVector32 {
+        self.deserialize_from_reader(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a GLWE seeded ciphertext vector entity.
+impl EntityDeserializationEngine<&[u8], GlweSeededCiphertextVector64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
     /// let input = vec![3_u64 << 50; 8];
     /// let noise = Variance(2_f64.powf(-25.));
     ///
@@ -1262,17 +3115,1079 @@ impl EntityDeserializationEngine<&[u8], GlweSeededCiphertextVector64>
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: GlweSecretKey64 =
-    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
-    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let seeded_ciphertext_vector =
+    ///     engine.encrypt_glwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&seeded_ciphertext_vector)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(seeded_ciphertext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GlweSeededCiphertextVector64, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweSeededCiphertextVector64 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<GlweSeededCiphertextVector64, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableGlweSeededCiphertextVector64 {
+            version: GlweSeededCiphertextVector64Version,
+            inner: ImplGlweSeededList<Vec<u64>>,
+        }
+        let deserialized: DeserializableGlweSeededCiphertextVector64 =
+            bincode::deserialize_from(reader)
+                .map_err(DefaultSerializationError::Deserialization)
+                .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableGlweSeededCiphertextVector64 {
+                version: GlweSeededCiphertextVector64Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableGlweSeededCiphertextVector64 {
+                version: GlweSeededCiphertextVector64Version::V0,
+                inner,
+            } => Ok(GlweSeededCiphertextVector64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> GlweSeededCiphertextVector64 {
+        self.deserialize_from_reader(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a Lev ciphertext entity.
+impl EntityDeserializationEngine<&[u8], LevCiphertext32> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// let input = 3_u32;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lev_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&ciphertext)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LevCiphertext32, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LevCiphertext32 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<LevCiphertext32, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLevCiphertext32 {
+            version: LevCiphertext32Version,
+            inner: ImplLevCiphertext<Vec<u32>>,
+        }
+        let deserialized: DeserializableLevCiphertext32 = bincode::deserialize_from(reader)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableLevCiphertext32 {
+                version: LevCiphertext32Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLevCiphertext32 {
+                version: LevCiphertext32Version::V0,
+                inner,
+            } => Ok(LevCiphertext32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(&mut self, reader: R) -> LevCiphertext32 {
+        self.deserialize_from_reader(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a Lev ciphertext entity.
+impl EntityDeserializationEngine<&[u8], LevCiphertext64> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// let input = 3_u64;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lev_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&ciphertext)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LevCiphertext64, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LevCiphertext64 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<LevCiphertext64, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLevCiphertext64 {
+            version: LevCiphertext64Version,
+            inner: ImplLevCiphertext<Vec<u64>>,
+        }
+        let deserialized: DeserializableLevCiphertext64 = bincode::deserialize_from(reader)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableLevCiphertext64 {
+                version: LevCiphertext64Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLevCiphertext64 {
+                version: LevCiphertext64Version::V0,
+                inner,
+            } => Ok(LevCiphertext64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(&mut self, reader: R) -> LevCiphertext64 {
+        self.deserialize_from_reader(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a LWE bootstrap key entity.
+impl EntityDeserializationEngine<&[u8], LweBootstrapKey32> for DefaultSerializationEngine {
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: LweBootstrapKey32 =
+    ///     engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&bsk)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(bsk, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweBootstrapKey32, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweBootstrapKey32 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<LweBootstrapKey32, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLweBootstrapKey32 {
+            version: LweBootstrapKey32Version,
+            inner: ImplStandardBootstrapKey<Vec<u32>>,
+        }
+        let deserialized: DeserializableLweBootstrapKey32 = bincode::deserialize_from(reader)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableLweBootstrapKey32 {
+                version: LweBootstrapKey32Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweBootstrapKey32 {
+                version: LweBootstrapKey32Version::V0,
+                inner,
+            } => Ok(LweBootstrapKey32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> LweBootstrapKey32 {
+        self.deserialize_from_reader(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a LWE bootstrap key entity.
+impl EntityDeserializationEngine<&[u8], LweBootstrapKey64> for DefaultSerializationEngine {
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: LweBootstrapKey64 =
+    ///     engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&bsk)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(bsk, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweBootstrapKey64, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweBootstrapKey64 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<LweBootstrapKey64, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLweBootstrapKey64 {
+            version: LweBootstrapKey64Version,
+            inner: ImplStandardBootstrapKey<Vec<u64>>,
+        }
+        let deserialized: DeserializableLweBootstrapKey64 = bincode::deserialize_from(reader)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableLweBootstrapKey64 {
+                version: LweBootstrapKey64Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweBootstrapKey64 {
+                version: LweBootstrapKey64Version::V0,
+                inner,
+            } => Ok(LweBootstrapKey64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> LweBootstrapKey64 {
+        self.deserialize_from_reader(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 16 bits integers. It deserializes a LWE ciphertext entity.
+impl EntityDeserializationEngine<&[u8], LweCiphertext16> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 12 bits)
+    /// let input = 3_u16 << 12;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey16 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&ciphertext)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweCiphertext16, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweCiphertext16 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<LweCiphertext16, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLweCiphertext16 {
+            version: LweCiphertext16Version,
+            inner: ImplLweCiphertext<Vec<u16>>,
+        }
+        let deserialized: DeserializableLweCiphertext16 = bincode::deserialize_from(reader)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableLweCiphertext16 {
+                version: LweCiphertext16Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweCiphertext16 {
+                version: LweCiphertext16Version::V0,
+                inner,
+            } => Ok(LweCiphertext16(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(&mut self, reader: R) -> LweCiphertext16 {
+        self.deserialize_from_reader(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a LWE ciphertext entity.
+impl EntityDeserializationEngine<&[u8], LweCiphertext32> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&ciphertext)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweCiphertext32, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweCiphertext32 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<LweCiphertext32, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLweCiphertext32 {
+            version: LweCiphertext32Version,
+            inner: ImplLweCiphertext<Vec<u32>>,
+        }
+        let deserialized: DeserializableLweCiphertext32 = bincode::deserialize_from(reader)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableLweCiphertext32 {
+                version: LweCiphertext32Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweCiphertext32 {
+                version: LweCiphertext32Version::V0,
+                inner,
+            } => Ok(LweCiphertext32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(&mut self, reader: R) -> LweCiphertext32 {
+        self.deserialize_from_reader(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a LWE ciphertext entity.
+impl EntityDeserializationEngine<&[u8], LweCiphertext64> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&ciphertext)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweCiphertext64, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweCiphertext64 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<LweCiphertext64, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLweCiphertext64 {
+            version: LweCiphertext64Version,
+            inner: ImplLweCiphertext<Vec<u64>>,
+        }
+        let deserialized: DeserializableLweCiphertext64 = bincode::deserialize_from(reader)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableLweCiphertext64 {
+                version: LweCiphertext64Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweCiphertext64 {
+                version: LweCiphertext64Version::V0,
+                inner,
+            } => Ok(LweCiphertext64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(&mut self, reader: R) -> LweCiphertext64 {
+        self.deserialize_from_reader(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a trivial LWE ciphertext entity.
+impl EntityDeserializationEngine<&[u8], TrivialLweCiphertext32> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweSize;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_size = LweSize(10);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let plaintext: Plaintext32 = engine.create_plaintext_from(&input)?;
+    /// let ciphertext: TrivialLweCiphertext32 =
+    ///     engine.create_trivial_lwe_ciphertext(lwe_size, &plaintext)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&ciphertext)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<TrivialLweCiphertext32, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> TrivialLweCiphertext32 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<TrivialLweCiphertext32, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableTrivialLweCiphertext32 {
+            version: TrivialLweCiphertext32Version,
+            inner: ImplLweCiphertext<Vec<u32>>,
+        }
+        let deserialized: DeserializableTrivialLweCiphertext32 = bincode::deserialize_from(reader)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableTrivialLweCiphertext32 {
+                version: TrivialLweCiphertext32Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableTrivialLweCiphertext32 {
+                version: TrivialLweCiphertext32Version::V0,
+                inner,
+            } => Ok(TrivialLweCiphertext32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> TrivialLweCiphertext32 {
+        self.deserialize_from_reader(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a trivial LWE ciphertext entity.
+impl EntityDeserializationEngine<&[u8], TrivialLweCiphertext64> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweSize;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_size = LweSize(10);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u64 << 20;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let plaintext: Plaintext64 = engine.create_plaintext_from(&input)?;
+    /// let ciphertext: TrivialLweCiphertext64 =
+    ///     engine.create_trivial_lwe_ciphertext(lwe_size, &plaintext)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&ciphertext)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<TrivialLweCiphertext64, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> TrivialLweCiphertext64 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<TrivialLweCiphertext64, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableTrivialLweCiphertext64 {
+            version: TrivialLweCiphertext64Version,
+            inner: ImplLweCiphertext<Vec<u64>>,
+        }
+        let deserialized: DeserializableTrivialLweCiphertext64 = bincode::deserialize_from(reader)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableTrivialLweCiphertext64 {
+                version: TrivialLweCiphertext64Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableTrivialLweCiphertext64 {
+                version: TrivialLweCiphertext64Version::V0,
+                inner,
+            } => Ok(TrivialLweCiphertext64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> TrivialLweCiphertext64 {
+        self.deserialize_from_reader(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a LWE ciphertext vector entity.
+impl EntityDeserializationEngine<&[u8], LweCiphertextVector32> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let mut ciphertext_vector: LweCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&ciphertext_vector)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(ciphertext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweCiphertextVector32, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweCiphertextVector32 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<LweCiphertextVector32, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLweCiphertextVector32 {
+            version: LweCiphertextVector32Version,
+            inner: ImplLweList<Vec<u32>>,
+        }
+        let deserialized: DeserializableLweCiphertextVector32 = bincode::deserialize_from(reader)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableLweCiphertextVector32 {
+                version: LweCiphertextVector32Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweCiphertextVector32 {
+                version: LweCiphertextVector32Version::V0,
+                inner,
+            } => Ok(LweCiphertextVector32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> LweCiphertextVector32 {
+        self.deserialize_from_reader(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a LWE ciphertext vector entity.
+impl EntityDeserializationEngine<&[u8], LweCiphertextVector64> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let mut ciphertext_vector: LweCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&ciphertext_vector)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(ciphertext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweCiphertextVector64, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweCiphertextVector64 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<LweCiphertextVector64, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLweCiphertextVector64 {
+            version: LweCiphertextVector64Version,
+            inner: ImplLweList<Vec<u64>>,
+        }
+        let deserialized: DeserializableLweCiphertextVector64 = bincode::deserialize_from(reader)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableLweCiphertextVector64 {
+                version: LweCiphertextVector64Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweCiphertextVector64 {
+                version: LweCiphertextVector64Version::V0,
+                inner,
+            } => Ok(LweCiphertextVector64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> LweCiphertextVector64 {
+        self.deserialize_from_reader(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 16 bits integers. It deserializes a LWE keyswitch key entity.
+impl EntityDeserializationEngine<&[u8], LweKeyswitchKey16> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey16 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey16 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&keyswitch_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(keyswitch_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweKeyswitchKey16, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweKeyswitchKey16 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<LweKeyswitchKey16, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLweKeyswitchKey16 {
+            version: LweKeyswitchKey16Version,
+            inner: ImplLweKeyswitchKey<Vec<u16>>,
+        }
+        let deserialized: DeserializableLweKeyswitchKey16 = bincode::deserialize_from(reader)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableLweKeyswitchKey16 {
+                version: LweKeyswitchKey16Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweKeyswitchKey16 {
+                version: LweKeyswitchKey16Version::V0,
+                inner,
+            } => Ok(LweKeyswitchKey16(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> LweKeyswitchKey16 {
+        self.deserialize_from_reader(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a LWE keyswitch key entity.
+impl EntityDeserializationEngine<&[u8], LweKeyswitchKey32> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
     ///
-    /// let seeded_ciphertext_vector =
-    ///     engine.encrypt_glwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&seeded_ciphertext_vector)?;
+    /// let serialized = serialization_engine.serialize(&keyswitch_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(seeded_ciphertext_vector, recovered);
+    /// assert_eq!(keyswitch_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -1281,68 +4196,88 @@ impl EntityDeserializationEngine<&[u8], GlweSeededCiphertextVector64>
     fn deserialize(
         &mut self,
         serialized: &[u8],
-    ) -> Result<GlweSeededCiphertextVector64, EntityDeserializationError<Self::EngineError>> {
+    ) -> Result<LweKeyswitchKey32, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweKeyswitchKey32 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<LweKeyswitchKey32, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
-        struct DeserializableGlweSeededCiphertextVector64 {
-            version: GlweSeededCiphertextVector64Version,
-            inner: ImplGlweSeededList<Vec<u64>>,
+        struct DeserializableLweKeyswitchKey32 {
+            version: LweKeyswitchKey32Version,
+            inner: ImplLweKeyswitchKey<Vec<u32>>,
         }
-        let deserialized: DeserializableGlweSeededCiphertextVector64 =
-            bincode::deserialize(serialized)
-                .map_err(DefaultSerializationError::Deserialization)
-                .map_err(EntityDeserializationError::Engine)?;
+        let deserialized: DeserializableLweKeyswitchKey32 = bincode::deserialize_from(reader)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
-            DeserializableGlweSeededCiphertextVector64 {
-                version: GlweSeededCiphertextVector64Version::Unsupported,
+            DeserializableLweKeyswitchKey32 {
+                version: LweKeyswitchKey32Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
-            DeserializableGlweSeededCiphertextVector64 {
-                version: GlweSeededCiphertextVector64Version::V0,
+            DeserializableLweKeyswitchKey32 {
+                version: LweKeyswitchKey32Version::V0,
                 inner,
-            } => Ok(GlweSeededCiphertextVector64(inner)),
+            } => Ok(LweKeyswitchKey32(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweSeededCiphertextVector64 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> LweKeyswitchKey32 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 32 bits integers. It deserializes a LWE bootstrap key entity.
-impl EntityDeserializationEngine<&[u8], LweBootstrapKey32> for DefaultSerializationEngine {
-    /// # Example
+/// operates on 64 bits integers. It deserializes a LWE keyswitch key entity.
+impl EntityDeserializationEngine<&[u8], LweKeyswitchKey64> for DefaultSerializationEngine {
+    /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
     /// use concrete_commons::parameters::{
-    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
     /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
-    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
     /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let lwe_sk: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dim)?;
-    /// let glwe_sk: GlweSecretKey32 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
     ///
-    /// let bsk: LweBootstrapKey32 =
-    ///     engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&bsk)?;
+    /// let serialized = serialization_engine.serialize(&keyswitch_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(bsk, recovered);
+    /// assert_eq!(keyswitch_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -1351,67 +4286,92 @@ impl EntityDeserializationEngine<&[u8], LweBootstrapKey32> for DefaultSerializat
     fn deserialize(
         &mut self,
         serialized: &[u8],
-    ) -> Result<LweBootstrapKey32, EntityDeserializationError<Self::EngineError>> {
+    ) -> Result<LweKeyswitchKey64, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweKeyswitchKey64 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<LweKeyswitchKey64, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
-        struct DeserializableLweBootstrapKey32 {
-            version: LweBootstrapKey32Version,
-            inner: ImplStandardBootstrapKey<Vec<u32>>,
+        struct DeserializableLweKeyswitchKey64 {
+            version: LweKeyswitchKey64Version,
+            inner: ImplLweKeyswitchKey<Vec<u64>>,
         }
-        let deserialized: DeserializableLweBootstrapKey32 = bincode::deserialize(serialized)
+        let deserialized: DeserializableLweKeyswitchKey64 = bincode::deserialize_from(reader)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
-            DeserializableLweBootstrapKey32 {
-                version: LweBootstrapKey32Version::Unsupported,
+            DeserializableLweKeyswitchKey64 {
+                version: LweKeyswitchKey64Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
-            DeserializableLweBootstrapKey32 {
-                version: LweBootstrapKey32Version::V0,
+            DeserializableLweKeyswitchKey64 {
+                version: LweKeyswitchKey64Version::V0,
                 inner,
-            } => Ok(LweBootstrapKey32(inner)),
+            } => Ok(LweKeyswitchKey64(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweBootstrapKey32 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> LweKeyswitchKey64 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 64 bits integers. It deserializes a LWE bootstrap key entity.
-impl EntityDeserializationEngine<&[u8], LweBootstrapKey64> for DefaultSerializationEngine {
-    /// # Example
+/// operates on 32 bits integers. It deserializes a LWE partial keyswitch key entity.
+impl EntityDeserializationEngine<&[u8], LwePartialKeyswitchKey32> for DefaultSerializationEngine {
+    /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
     /// use concrete_commons::parameters::{
-    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
     /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
-    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let switched_lwe_dimension = LweDimension(4);
+    /// let replacement_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
     /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let lwe_sk: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dim)?;
-    /// let glwe_sk: GlweSecretKey64 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
-    ///
-    /// let bsk: LweBootstrapKey64 =
-    ///     engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let replacement_key: LweSecretKey32 =
+    ///     engine.generate_new_lwe_secret_key(replacement_lwe_dimension)?;
+    ///
+    /// let partial_keyswitch_key: LwePartialKeyswitchKey32 = engine
+    ///     .generate_new_lwe_partial_keyswitch_key(
+    ///         &input_key,
+    ///         &replacement_key,
+    ///         switched_lwe_dimension,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         noise,
+    ///     )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&bsk)?;
+    /// let serialized = serialization_engine.serialize(&partial_keyswitch_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(bsk, recovered);
+    /// assert_eq!(partial_keyswitch_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -1420,65 +4380,93 @@ impl EntityDeserializationEngine<&[u8], LweBootstrapKey64> for DefaultSerializat
     fn deserialize(
         &mut self,
         serialized: &[u8],
-    ) -> Result<LweBootstrapKey64, EntityDeserializationError<Self::EngineError>> {
+    ) -> Result<LwePartialKeyswitchKey32, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LwePartialKeyswitchKey32 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<LwePartialKeyswitchKey32, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
-        struct DeserializableLweBootstrapKey64 {
-            version: LweBootstrapKey64Version,
-            inner: ImplStandardBootstrapKey<Vec<u64>>,
+        struct DeserializableLwePartialKeyswitchKey32 {
+            version: LwePartialKeyswitchKey32Version,
+            inner: ImplLwePartialKeyswitchKey<Vec<u32>>,
         }
-        let deserialized: DeserializableLweBootstrapKey64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
+        let deserialized: DeserializableLwePartialKeyswitchKey32 =
+            bincode::deserialize_from(reader)
+                .map_err(DefaultSerializationError::Deserialization)
+                .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
-            DeserializableLweBootstrapKey64 {
-                version: LweBootstrapKey64Version::Unsupported,
+            DeserializableLwePartialKeyswitchKey32 {
+                version: LwePartialKeyswitchKey32Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
-            DeserializableLweBootstrapKey64 {
-                version: LweBootstrapKey64Version::V0,
+            DeserializableLwePartialKeyswitchKey32 {
+                version: LwePartialKeyswitchKey32Version::V0,
                 inner,
-            } => Ok(LweBootstrapKey64(inner)),
+            } => Ok(LwePartialKeyswitchKey32(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweBootstrapKey64 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> LwePartialKeyswitchKey32 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 32 bits integers. It deserializes a LWE ciphertext entity.
-impl EntityDeserializationEngine<&[u8], LweCiphertext32> for DefaultSerializationEngine {
+/// operates on 64 bits integers. It deserializes a LWE partial keyswitch key entity.
+impl EntityDeserializationEngine<&[u8], LwePartialKeyswitchKey64> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let lwe_dimension = LweDimension(2);
-    /// // Here a hard-set encoding is applied (shift by 20 bits)
-    /// let input = 3_u32 << 20;
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let switched_lwe_dimension = LweDimension(4);
+    /// let replacement_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
     /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
-    /// let plaintext = engine.create_plaintext_from(&input)?;
-    ///
-    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let replacement_key: LweSecretKey64 =
+    ///     engine.generate_new_lwe_secret_key(replacement_lwe_dimension)?;
+    ///
+    /// let partial_keyswitch_key: LwePartialKeyswitchKey64 = engine
+    ///     .generate_new_lwe_partial_keyswitch_key(
+    ///         &input_key,
+    ///         &replacement_key,
+    ///         switched_lwe_dimension,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         noise,
+    ///     )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&ciphertext)?;
+    /// let serialized = serialization_engine.serialize(&partial_keyswitch_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(ciphertext, recovered);
+    /// assert_eq!(partial_keyswitch_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -1487,132 +4475,144 @@ impl EntityDeserializationEngine<&[u8], LweCiphertext32> for DefaultSerializatio
     fn deserialize(
         &mut self,
         serialized: &[u8],
-    ) -> Result<LweCiphertext32, EntityDeserializationError<Self::EngineError>> {
+    ) -> Result<LwePartialKeyswitchKey64, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LwePartialKeyswitchKey64 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<LwePartialKeyswitchKey64, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
-        struct DeserializableLweCiphertext32 {
-            version: LweCiphertext32Version,
-            inner: ImplLweCiphertext<Vec<u32>>,
+        struct DeserializableLwePartialKeyswitchKey64 {
+            version: LwePartialKeyswitchKey64Version,
+            inner: ImplLwePartialKeyswitchKey<Vec<u64>>,
         }
-        let deserialized: DeserializableLweCiphertext32 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
+        let deserialized: DeserializableLwePartialKeyswitchKey64 =
+            bincode::deserialize_from(reader)
+                .map_err(DefaultSerializationError::Deserialization)
+                .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
-            DeserializableLweCiphertext32 {
-                version: LweCiphertext32Version::Unsupported,
+            DeserializableLwePartialKeyswitchKey64 {
+                version: LwePartialKeyswitchKey64Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
-            DeserializableLweCiphertext32 {
-                version: LweCiphertext32Version::V0,
+            DeserializableLwePartialKeyswitchKey64 {
+                version: LwePartialKeyswitchKey64Version::V0,
                 inner,
-            } => Ok(LweCiphertext32(inner)),
+            } => Ok(LwePartialKeyswitchKey64(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweCiphertext32 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> LwePartialKeyswitchKey64 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 64 bits integers. It deserializes a LWE ciphertext entity.
-impl EntityDeserializationEngine<&[u8], LweCiphertext64> for DefaultSerializationEngine {
+/// operates on 16 bits integers. It deserializes a LWE secret key entity.
+impl EntityDeserializationEngine<&[u8], LweSecretKey16> for DefaultSerializationEngine {
     /// # Example:
     /// ```
-    /// use concrete_commons::dispersion::Variance;
     /// use concrete_commons::parameters::LweDimension;
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let lwe_dimension = LweDimension(2);
-    /// // Here a hard-set encoding is applied (shift by 50 bits)
-    /// let input = 3_u64 << 50;
-    /// let noise = Variance(2_f64.powf(-25.));
+    /// let lwe_dimension = LweDimension(6);
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
-    /// let plaintext = engine.create_plaintext_from(&input)?;
-    ///
-    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let lwe_secret_key: LweSecretKey16 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&ciphertext)?;
+    /// let serialized = serialization_engine.serialize(&lwe_secret_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(ciphertext, recovered);
+    /// assert_eq!(lwe_secret_key, recovered);
     ///
     /// #
     /// # Ok(())
     /// # }
+    /// ```
     fn deserialize(
         &mut self,
         serialized: &[u8],
-    ) -> Result<LweCiphertext64, EntityDeserializationError<Self::EngineError>> {
+    ) -> Result<LweSecretKey16, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSecretKey16 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<LweSecretKey16, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
-        struct DeserializableLweCiphertext64 {
-            version: LweCiphertext64Version,
-            inner: ImplLweCiphertext<Vec<u64>>,
+        struct DeserializableLweSecretKey16 {
+            version: LweSecretKey16Version,
+            inner: ImplLweSecretKey<BinaryKeyKind, Vec<u16>>,
         }
-        let deserialized: DeserializableLweCiphertext64 = bincode::deserialize(serialized)
+        let deserialized: DeserializableLweSecretKey16 = bincode::deserialize_from(reader)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
-            DeserializableLweCiphertext64 {
-                version: LweCiphertext64Version::Unsupported,
+            DeserializableLweSecretKey16 {
+                version: LweSecretKey16Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
-            DeserializableLweCiphertext64 {
-                version: LweCiphertext64Version::V0,
+            DeserializableLweSecretKey16 {
+                version: LweSecretKey16Version::V0,
                 inner,
-            } => Ok(LweCiphertext64(inner)),
+            } => Ok(LweSecretKey16(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweCiphertext64 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(&mut self, reader: R) -> LweSecretKey16 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 32 bits integers. It deserializes a LWE ciphertext vector entity.
-impl EntityDeserializationEngine<&[u8], LweCiphertextVector32> for DefaultSerializationEngine {
+/// operates on 32 bits integers. It deserializes a LWE secret key entity.
+impl EntityDeserializationEngine<&[u8], LweSecretKey32> for DefaultSerializationEngine {
     /// # Example:
     /// ```
-    /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_commons::parameters::LweDimension;
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
     /// let lwe_dimension = LweDimension(6);
-    /// // Here a hard-set encoding is applied (shift by 20 bits)
-    /// let input = vec![3_u32 << 20; 3];
-    /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
-    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input)?;
-    ///
-    /// let mut ciphertext_vector: LweCiphertextVector32 =
-    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let lwe_secret_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&ciphertext_vector)?;
+    /// let serialized = serialization_engine.serialize(&lwe_secret_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(ciphertext_vector, recovered);
+    /// assert_eq!(lwe_secret_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -1620,67 +4620,70 @@ impl EntityDeserializationEngine<&[u8], LweCiphertextVector32> for DefaultSerial
     /// ```
     fn deserialize(
         &mut self,
-        serialized: &[u8],
-    ) -> Result<LweCiphertextVector32, EntityDeserializationError<Self::EngineError>> {
+        serialized: &[u8],
+    ) -> Result<LweSecretKey32, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSecretKey32 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<LweSecretKey32, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
-        struct DeserializableLweCiphertextVector32 {
-            version: LweCiphertextVector32Version,
-            inner: ImplLweList<Vec<u32>>,
+        struct DeserializableLweSecretKey32 {
+            version: LweSecretKey32Version,
+            inner: ImplLweSecretKey<BinaryKeyKind, Vec<u32>>,
         }
-        let deserialized: DeserializableLweCiphertextVector32 = bincode::deserialize(serialized)
+        let deserialized: DeserializableLweSecretKey32 = bincode::deserialize_from(reader)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
-            DeserializableLweCiphertextVector32 {
-                version: LweCiphertextVector32Version::Unsupported,
+            DeserializableLweSecretKey32 {
+                version: LweSecretKey32Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
-            DeserializableLweCiphertextVector32 {
-                version: LweCiphertextVector32Version::V0,
+            DeserializableLweSecretKey32 {
+                version: LweSecretKey32Version::V0,
                 inner,
-            } => Ok(LweCiphertextVector32(inner)),
+            } => Ok(LweSecretKey32(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweCiphertextVector32 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(&mut self, reader: R) -> LweSecretKey32 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 64 bits integers. It deserializes a LWE ciphertext vector entity.
-impl EntityDeserializationEngine<&[u8], LweCiphertextVector64> for DefaultSerializationEngine {
+/// operates on 64 bits integers. It deserializes a LWE secret key entity.
+impl EntityDeserializationEngine<&[u8], LweSecretKey64> for DefaultSerializationEngine {
     /// # Example:
     /// ```
-    /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_commons::parameters::LweDimension;
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
     /// let lwe_dimension = LweDimension(6);
-    /// // Here a hard-set encoding is applied (shift by 50 bits)
-    /// let input = vec![3_u64 << 50; 3];
-    /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
-    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input)?;
-    ///
-    /// let mut ciphertext_vector: LweCiphertextVector64 =
-    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let lwe_secret_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&ciphertext_vector)?;
+    /// let serialized = serialization_engine.serialize(&lwe_secret_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(ciphertext_vector, recovered);
+    /// assert_eq!(lwe_secret_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -1689,74 +4692,70 @@ impl EntityDeserializationEngine<&[u8], LweCiphertextVector64> for DefaultSerial
     fn deserialize(
         &mut self,
         serialized: &[u8],
-    ) -> Result<LweCiphertextVector64, EntityDeserializationError<Self::EngineError>> {
+    ) -> Result<LweSecretKey64, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSecretKey64 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<LweSecretKey64, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
-        struct DeserializableLweCiphertextVector64 {
-            version: LweCiphertextVector64Version,
-            inner: ImplLweList<Vec<u64>>,
+        struct DeserializableLweSecretKey64 {
+            version: LweSecretKey64Version,
+            inner: ImplLweSecretKey<BinaryKeyKind, Vec<u64>>,
         }
-        let deserialized: DeserializableLweCiphertextVector64 = bincode::deserialize(serialized)
+        let deserialized: DeserializableLweSecretKey64 = bincode::deserialize_from(reader)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
-            DeserializableLweCiphertextVector64 {
-                version: LweCiphertextVector64Version::Unsupported,
+            DeserializableLweSecretKey64 {
+                version: LweSecretKey64Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
-            DeserializableLweCiphertextVector64 {
-                version: LweCiphertextVector64Version::V0,
+            DeserializableLweSecretKey64 {
+                version: LweSecretKey64Version::V0,
                 inner,
-            } => Ok(LweCiphertextVector64(inner)),
+            } => Ok(LweSecretKey64(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweCiphertextVector64 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(&mut self, reader: R) -> LweSecretKey64 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 32 bits integers. It deserializes a LWE keyswitch key entity.
-impl EntityDeserializationEngine<&[u8], LweKeyswitchKey32> for DefaultSerializationEngine {
+/// operates on 32 bits integers. It deserializes a ternary LWE secret key entity.
+impl EntityDeserializationEngine<&[u8], LweSecretKeyTernary32> for DefaultSerializationEngine {
     /// # Example:
     /// ```
-    /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{
-    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
-    /// };
+    /// use concrete_commons::parameters::LweDimension;
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let input_lwe_dimension = LweDimension(6);
-    /// let output_lwe_dimension = LweDimension(3);
-    /// let decomposition_level_count = DecompositionLevelCount(2);
-    /// let decomposition_base_log = DecompositionBaseLog(8);
-    /// let noise = Variance(2_f64.powf(-25.));
+    /// let lwe_dimension = LweDimension(6);
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
-    /// let output_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
-    ///
-    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
-    ///     &input_key,
-    ///     &output_key,
-    ///     decomposition_level_count,
-    ///     decomposition_base_log,
-    ///     noise,
-    /// )?;
+    /// let lwe_secret_key: LweSecretKeyTernary32 =
+    ///     engine.generate_new_lwe_secret_key_ternary(lwe_dimension)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&keyswitch_key)?;
+    /// let serialized = serialization_engine.serialize(&lwe_secret_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(keyswitch_key, recovered);
+    /// assert_eq!(lwe_secret_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -1765,74 +4764,73 @@ impl EntityDeserializationEngine<&[u8], LweKeyswitchKey32> for DefaultSerializat
     fn deserialize(
         &mut self,
         serialized: &[u8],
-    ) -> Result<LweKeyswitchKey32, EntityDeserializationError<Self::EngineError>> {
+    ) -> Result<LweSecretKeyTernary32, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSecretKeyTernary32 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<LweSecretKeyTernary32, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
-        struct DeserializableLweKeyswitchKey32 {
-            version: LweKeyswitchKey32Version,
-            inner: ImplLweKeyswitchKey<Vec<u32>>,
+        struct DeserializableLweSecretKey32 {
+            version: LweSecretKeyTernary32Version,
+            inner: ImplLweSecretKey<TernaryKeyKind, Vec<u32>>,
         }
-        let deserialized: DeserializableLweKeyswitchKey32 = bincode::deserialize(serialized)
+        let deserialized: DeserializableLweSecretKey32 = bincode::deserialize_from(reader)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
-            DeserializableLweKeyswitchKey32 {
-                version: LweKeyswitchKey32Version::Unsupported,
+            DeserializableLweSecretKey32 {
+                version: LweSecretKeyTernary32Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
-            DeserializableLweKeyswitchKey32 {
-                version: LweKeyswitchKey32Version::V0,
+            DeserializableLweSecretKey32 {
+                version: LweSecretKeyTernary32Version::V0,
                 inner,
-            } => Ok(LweKeyswitchKey32(inner)),
+            } => Ok(LweSecretKeyTernary32(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweKeyswitchKey32 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> LweSecretKeyTernary32 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 64 bits integers. It deserializes a LWE keyswitch key entity.
-impl EntityDeserializationEngine<&[u8], LweKeyswitchKey64> for DefaultSerializationEngine {
+/// operates on 64 bits integers. It deserializes a ternary LWE secret key entity.
+impl EntityDeserializationEngine<&[u8], LweSecretKeyTernary64> for DefaultSerializationEngine {
     /// # Example:
     /// ```
-    /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{
-    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
-    /// };
+    /// use concrete_commons::parameters::LweDimension;
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let input_lwe_dimension = LweDimension(6);
-    /// let output_lwe_dimension = LweDimension(3);
-    /// let decomposition_level_count = DecompositionLevelCount(2);
-    /// let decomposition_base_log = DecompositionBaseLog(8);
-    /// let noise = Variance(2_f64.powf(-25.));
+    /// let lwe_dimension = LweDimension(6);
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
-    /// let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
-    ///
-    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
-    ///     &input_key,
-    ///     &output_key,
-    ///     decomposition_level_count,
-    ///     decomposition_base_log,
-    ///     noise,
-    /// )?;
+    /// let lwe_secret_key: LweSecretKeyTernary64 =
+    ///     engine.generate_new_lwe_secret_key_ternary(lwe_dimension)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&keyswitch_key)?;
+    /// let serialized = serialization_engine.serialize(&lwe_secret_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(keyswitch_key, recovered);
+    /// assert_eq!(lwe_secret_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -1841,38 +4839,52 @@ impl EntityDeserializationEngine<&[u8], LweKeyswitchKey64> for DefaultSerializat
     fn deserialize(
         &mut self,
         serialized: &[u8],
-    ) -> Result<LweKeyswitchKey64, EntityDeserializationError<Self::EngineError>> {
+    ) -> Result<LweSecretKeyTernary64, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSecretKeyTernary64 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<LweSecretKeyTernary64, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
-        struct DeserializableLweKeyswitchKey64 {
-            version: LweKeyswitchKey64Version,
-            inner: ImplLweKeyswitchKey<Vec<u64>>,
+        struct DeserializableLweSecretKey64 {
+            version: LweSecretKeyTernary64Version,
+            inner: ImplLweSecretKey<TernaryKeyKind, Vec<u64>>,
         }
-        let deserialized: DeserializableLweKeyswitchKey64 = bincode::deserialize(serialized)
+        let deserialized: DeserializableLweSecretKey64 = bincode::deserialize_from(reader)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
-            DeserializableLweKeyswitchKey64 {
-                version: LweKeyswitchKey64Version::Unsupported,
+            DeserializableLweSecretKey64 {
+                version: LweSecretKeyTernary64Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
-            DeserializableLweKeyswitchKey64 {
-                version: LweKeyswitchKey64Version::V0,
+            DeserializableLweSecretKey64 {
+                version: LweSecretKeyTernary64Version::V0,
                 inner,
-            } => Ok(LweKeyswitchKey64(inner)),
+            } => Ok(LweSecretKeyTernary64(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweKeyswitchKey64 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> LweSecretKeyTernary64 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 32 bits integers. It deserializes a LWE secret key entity.
-impl EntityDeserializationEngine<&[u8], LweSecretKey32> for DefaultSerializationEngine {
+/// operates on 32 bits integers. It deserializes a gaussian LWE secret key entity.
+impl EntityDeserializationEngine<&[u8], LweSecretKeyGaussian32> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::parameters::LweDimension;
@@ -1887,7 +4899,8 @@ impl EntityDeserializationEngine<&[u8], LweSecretKey32> for DefaultSerialization
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let lwe_secret_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let lwe_secret_key: LweSecretKeyGaussian32 =
+    ///     engine.generate_new_lwe_secret_key_gaussian(lwe_dimension)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
     /// let serialized = serialization_engine.serialize(&lwe_secret_key)?;
@@ -1901,38 +4914,52 @@ impl EntityDeserializationEngine<&[u8], LweSecretKey32> for DefaultSerialization
     fn deserialize(
         &mut self,
         serialized: &[u8],
-    ) -> Result<LweSecretKey32, EntityDeserializationError<Self::EngineError>> {
+    ) -> Result<LweSecretKeyGaussian32, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSecretKeyGaussian32 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<LweSecretKeyGaussian32, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
         struct DeserializableLweSecretKey32 {
-            version: LweSecretKey32Version,
-            inner: ImplLweSecretKey<BinaryKeyKind, Vec<u32>>,
+            version: LweSecretKeyGaussian32Version,
+            inner: ImplLweSecretKey<GaussianKeyKind, Vec<u32>>,
         }
-        let deserialized: DeserializableLweSecretKey32 = bincode::deserialize(serialized)
+        let deserialized: DeserializableLweSecretKey32 = bincode::deserialize_from(reader)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializableLweSecretKey32 {
-                version: LweSecretKey32Version::Unsupported,
+                version: LweSecretKeyGaussian32Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
             DeserializableLweSecretKey32 {
-                version: LweSecretKey32Version::V0,
+                version: LweSecretKeyGaussian32Version::V0,
                 inner,
-            } => Ok(LweSecretKey32(inner)),
+            } => Ok(LweSecretKeyGaussian32(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSecretKey32 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> LweSecretKeyGaussian32 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 64 bits integers. It deserializes a LWE secret key entity.
-impl EntityDeserializationEngine<&[u8], LweSecretKey64> for DefaultSerializationEngine {
+/// operates on 64 bits integers. It deserializes a gaussian LWE secret key entity.
+impl EntityDeserializationEngine<&[u8], LweSecretKeyGaussian64> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::parameters::LweDimension;
@@ -1947,7 +4974,8 @@ impl EntityDeserializationEngine<&[u8], LweSecretKey64> for DefaultSerialization
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let lwe_secret_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let lwe_secret_key: LweSecretKeyGaussian64 =
+    ///     engine.generate_new_lwe_secret_key_gaussian(lwe_dimension)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
     /// let serialized = serialization_engine.serialize(&lwe_secret_key)?;
@@ -1961,31 +4989,45 @@ impl EntityDeserializationEngine<&[u8], LweSecretKey64> for DefaultSerialization
     fn deserialize(
         &mut self,
         serialized: &[u8],
-    ) -> Result<LweSecretKey64, EntityDeserializationError<Self::EngineError>> {
+    ) -> Result<LweSecretKeyGaussian64, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSecretKeyGaussian64 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<LweSecretKeyGaussian64, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
         struct DeserializableLweSecretKey64 {
-            version: LweSecretKey64Version,
-            inner: ImplLweSecretKey<BinaryKeyKind, Vec<u64>>,
+            version: LweSecretKeyGaussian64Version,
+            inner: ImplLweSecretKey<GaussianKeyKind, Vec<u64>>,
         }
-        let deserialized: DeserializableLweSecretKey64 = bincode::deserialize(serialized)
+        let deserialized: DeserializableLweSecretKey64 = bincode::deserialize_from(reader)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializableLweSecretKey64 {
-                version: LweSecretKey64Version::Unsupported,
+                version: LweSecretKeyGaussian64Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
             DeserializableLweSecretKey64 {
-                version: LweSecretKey64Version::V0,
+                version: LweSecretKeyGaussian64Version::V0,
                 inner,
-            } => Ok(LweSecretKey64(inner)),
+            } => Ok(LweSecretKeyGaussian64(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSecretKey64 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> LweSecretKeyGaussian64 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
 
@@ -2031,13 +5073,24 @@ impl EntityDeserializationEngine<&[u8], LweSeededBootstrapKey32> for DefaultSeri
     fn deserialize(
         &mut self,
         serialized: &[u8],
+    ) -> Result<LweSeededBootstrapKey32, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSeededBootstrapKey32 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
     ) -> Result<LweSeededBootstrapKey32, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
         struct DeserializableLweSeededBootstrapKey32 {
             version: LweSeededBootstrapKey32Version,
             inner: ImplStandardSeededBootstrapKey<Vec<u32>>,
         }
-        let deserialized: DeserializableLweSeededBootstrapKey32 = bincode::deserialize(serialized)
+        let deserialized: DeserializableLweSeededBootstrapKey32 = bincode::deserialize_from(reader)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
@@ -2054,8 +5107,11 @@ impl EntityDeserializationEngine<&[u8], LweSeededBootstrapKey32> for DefaultSeri
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSeededBootstrapKey32 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> LweSeededBootstrapKey32 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
 
@@ -2101,13 +5157,24 @@ impl EntityDeserializationEngine<&[u8], LweSeededBootstrapKey64> for DefaultSeri
     fn deserialize(
         &mut self,
         serialized: &[u8],
+    ) -> Result<LweSeededBootstrapKey64, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSeededBootstrapKey64 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
     ) -> Result<LweSeededBootstrapKey64, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
         struct DeserializableLweSeededBootstrapKey64 {
             version: LweSeededBootstrapKey64Version,
             inner: ImplStandardSeededBootstrapKey<Vec<u64>>,
         }
-        let deserialized: DeserializableLweSeededBootstrapKey64 = bincode::deserialize(serialized)
+        let deserialized: DeserializableLweSeededBootstrapKey64 = bincode::deserialize_from(reader)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
@@ -2124,8 +5191,11 @@ impl EntityDeserializationEngine<&[u8], LweSeededBootstrapKey64> for DefaultSeri
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSeededBootstrapKey64 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> LweSeededBootstrapKey64 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
 
@@ -2169,13 +5239,24 @@ impl EntityDeserializationEngine<&[u8], LweSeededCiphertext32> for DefaultSerial
     fn deserialize(
         &mut self,
         serialized: &[u8],
+    ) -> Result<LweSeededCiphertext32, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSeededCiphertext32 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
     ) -> Result<LweSeededCiphertext32, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
         struct DeserializableLweSeededCiphertext32 {
             version: LweSeededCiphertext32Version,
             inner: ImplLweSeededCiphertext<u32>,
         }
-        let deserialized: DeserializableLweSeededCiphertext32 = bincode::deserialize(serialized)
+        let deserialized: DeserializableLweSeededCiphertext32 = bincode::deserialize_from(reader)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
@@ -2192,8 +5273,11 @@ impl EntityDeserializationEngine<&[u8], LweSeededCiphertext32> for DefaultSerial
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSeededCiphertext32 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> LweSeededCiphertext32 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
 
@@ -2237,13 +5321,24 @@ impl EntityDeserializationEngine<&[u8], LweSeededCiphertext64> for DefaultSerial
     fn deserialize(
         &mut self,
         serialized: &[u8],
+    ) -> Result<LweSeededCiphertext64, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSeededCiphertext64 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
     ) -> Result<LweSeededCiphertext64, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
         struct DeserializableLweSeededCiphertext64 {
             version: LweSeededCiphertext64Version,
             inner: ImplLweSeededCiphertext<u64>,
         }
-        let deserialized: DeserializableLweSeededCiphertext64 = bincode::deserialize(serialized)
+        let deserialized: DeserializableLweSeededCiphertext64 = bincode::deserialize_from(reader)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
@@ -2260,8 +5355,11 @@ impl EntityDeserializationEngine<&[u8], LweSeededCiphertext64> for DefaultSerial
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSeededCiphertext64 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> LweSeededCiphertext64 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
 
@@ -2307,6 +5405,17 @@ impl EntityDeserializationEngine<&[u8], LweSeededCiphertextVector32>
     fn deserialize(
         &mut self,
         serialized: &[u8],
+    ) -> Result<LweSeededCiphertextVector32, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSeededCiphertextVector32 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
     ) -> Result<LweSeededCiphertextVector32, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
         struct DeserializableLweSeededCiphertextVector32 {
@@ -2314,7 +5423,7 @@ impl EntityDeserializationEngine<&[u8], LweSeededCiphertextVector32>
             inner: ImplLweSeededList<Vec<u32>>,
         }
         let deserialized: DeserializableLweSeededCiphertextVector32 =
-            bincode::deserialize(serialized)
+            bincode::deserialize_from(reader)
                 .map_err(DefaultSerializationError::Deserialization)
                 .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
@@ -2331,8 +5440,11 @@ impl EntityDeserializationEngine<&[u8], LweSeededCiphertextVector32>
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSeededCiphertextVector32 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> LweSeededCiphertextVector32 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
 
@@ -2378,6 +5490,17 @@ impl EntityDeserializationEngine<&[u8], LweSeededCiphertextVector64>
     fn deserialize(
         &mut self,
         serialized: &[u8],
+    ) -> Result<LweSeededCiphertextVector64, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSeededCiphertextVector64 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
     ) -> Result<LweSeededCiphertextVector64, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
         struct DeserializableLweSeededCiphertextVector64 {
@@ -2385,7 +5508,7 @@ impl EntityDeserializationEngine<&[u8], LweSeededCiphertextVector64>
             inner: ImplLweSeededList<Vec<u64>>,
         }
         let deserialized: DeserializableLweSeededCiphertextVector64 =
-            bincode::deserialize(serialized)
+            bincode::deserialize_from(reader)
                 .map_err(DefaultSerializationError::Deserialization)
                 .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
@@ -2402,8 +5525,11 @@ impl EntityDeserializationEngine<&[u8], LweSeededCiphertextVector64>
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSeededCiphertextVector64 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> LweSeededCiphertextVector64 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
 
@@ -2455,13 +5581,24 @@ impl EntityDeserializationEngine<&[u8], LweSeededKeyswitchKey32> for DefaultSeri
     fn deserialize(
         &mut self,
         serialized: &[u8],
+    ) -> Result<LweSeededKeyswitchKey32, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSeededKeyswitchKey32 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
     ) -> Result<LweSeededKeyswitchKey32, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
         struct DeserializableLweSeededKeyswitchKey32 {
             version: LweSeededKeyswitchKey32Version,
             inner: ImplLweSeededKeyswitchKey<Vec<u32>>,
         }
-        let deserialized: DeserializableLweSeededKeyswitchKey32 = bincode::deserialize(serialized)
+        let deserialized: DeserializableLweSeededKeyswitchKey32 = bincode::deserialize_from(reader)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
@@ -2478,8 +5615,11 @@ impl EntityDeserializationEngine<&[u8], LweSeededKeyswitchKey32> for DefaultSeri
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSeededKeyswitchKey32 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> LweSeededKeyswitchKey32 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
 
@@ -2531,13 +5671,24 @@ impl EntityDeserializationEngine<&[u8], LweSeededKeyswitchKey64> for DefaultSeri
     fn deserialize(
         &mut self,
         serialized: &[u8],
+    ) -> Result<LweSeededKeyswitchKey64, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSeededKeyswitchKey64 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
     ) -> Result<LweSeededKeyswitchKey64, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
         struct DeserializableLweSeededKeyswitchKey64 {
             version: LweSeededKeyswitchKey64Version,
             inner: ImplLweSeededKeyswitchKey<Vec<u64>>,
         }
-        let deserialized: DeserializableLweSeededKeyswitchKey64 = bincode::deserialize(serialized)
+        let deserialized: DeserializableLweSeededKeyswitchKey64 = bincode::deserialize_from(reader)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
@@ -2554,9 +5705,210 @@ impl EntityDeserializationEngine<&[u8], LweSeededKeyswitchKey64> for DefaultSeri
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSeededKeyswitchKey64 {
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> LweSeededKeyswitchKey64 {
+        self.deserialize_from_reader(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a seeded LWE packing keyswitch key entity.
+impl EntityDeserializationEngine<&[u8], LweSeededPackingKeyswitchKey32>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let seeded_packing_keyswitch_key = engine.generate_new_lwe_seeded_packing_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&seeded_packing_keyswitch_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(seeded_packing_keyswitch_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweSeededPackingKeyswitchKey32, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> LweSeededPackingKeyswitchKey32 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<LweSeededPackingKeyswitchKey32, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLweSeededPackingKeyswitchKey32 {
+            version: LweSeededPackingKeyswitchKey32Version,
+            inner: ImplLweSeededPackingKeyswitchKey<Vec<u32>>,
+        }
+        let deserialized: DeserializableLweSeededPackingKeyswitchKey32 =
+            bincode::deserialize_from(reader)
+                .map_err(DefaultSerializationError::Deserialization)
+                .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableLweSeededPackingKeyswitchKey32 {
+                version: LweSeededPackingKeyswitchKey32Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweSeededPackingKeyswitchKey32 {
+                version: LweSeededPackingKeyswitchKey32Version::V0,
+                inner,
+            } => Ok(LweSeededPackingKeyswitchKey32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> LweSeededPackingKeyswitchKey32 {
+        self.deserialize_from_reader(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a seeded LWE packing keyswitch key entity.
+impl EntityDeserializationEngine<&[u8], LweSeededPackingKeyswitchKey64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let seeded_packing_keyswitch_key = engine.generate_new_lwe_seeded_packing_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&seeded_packing_keyswitch_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(seeded_packing_keyswitch_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweSeededPackingKeyswitchKey64, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> LweSeededPackingKeyswitchKey64 {
         self.deserialize(serialized).unwrap()
     }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<LweSeededPackingKeyswitchKey64, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLweSeededPackingKeyswitchKey64 {
+            version: LweSeededPackingKeyswitchKey64Version,
+            inner: ImplLweSeededPackingKeyswitchKey<Vec<u64>>,
+        }
+        let deserialized: DeserializableLweSeededPackingKeyswitchKey64 =
+            bincode::deserialize_from(reader)
+                .map_err(DefaultSerializationError::Deserialization)
+                .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableLweSeededPackingKeyswitchKey64 {
+                version: LweSeededPackingKeyswitchKey64Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweSeededPackingKeyswitchKey64 {
+                version: LweSeededPackingKeyswitchKey64Version::V0,
+                inner,
+            } => Ok(LweSeededPackingKeyswitchKey64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> LweSeededPackingKeyswitchKey64 {
+        self.deserialize_from_reader(reader).unwrap()
+    }
 }
 
 /// # Description:
@@ -2607,13 +5959,24 @@ impl EntityDeserializationEngine<&[u8], LwePackingKeyswitchKey32> for DefaultSer
     fn deserialize(
         &mut self,
         serialized: &[u8],
+    ) -> Result<LwePackingKeyswitchKey32, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LwePackingKeyswitchKey32 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
     ) -> Result<LwePackingKeyswitchKey32, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
         struct DeserializablePackingKeyswitchKey32 {
             version: LwePackingKeyswitchKey32Version,
             inner: ImplLwePackingKeyswitchKey<Vec<u32>>,
         }
-        let deserialized: DeserializablePackingKeyswitchKey32 = bincode::deserialize(serialized)
+        let deserialized: DeserializablePackingKeyswitchKey32 = bincode::deserialize_from(reader)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
@@ -2626,55 +5989,131 @@ impl EntityDeserializationEngine<&[u8], LwePackingKeyswitchKey32> for DefaultSer
             DeserializablePackingKeyswitchKey32 {
                 version: LwePackingKeyswitchKey32Version::V0,
                 inner,
-            } => Ok(LwePackingKeyswitchKey32(inner)),
+            } => Ok(LwePackingKeyswitchKey32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> LwePackingKeyswitchKey32 {
+        self.deserialize_from_reader(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a packing keyswitch key entity.
+impl EntityDeserializationEngine<&[u8], LwePackingKeyswitchKey64> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let packing_keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&packing_keyswitch_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(packing_keyswitch_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LwePackingKeyswitchKey64, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LwePackingKeyswitchKey64 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<LwePackingKeyswitchKey64, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializablePackingKeyswitchKey64 {
+            version: LwePackingKeyswitchKey64Version,
+            inner: ImplLwePackingKeyswitchKey<Vec<u64>>,
+        }
+        let deserialized: DeserializablePackingKeyswitchKey64 = bincode::deserialize_from(reader)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializablePackingKeyswitchKey64 {
+                version: LwePackingKeyswitchKey64Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializablePackingKeyswitchKey64 {
+                version: LwePackingKeyswitchKey64Version::V0,
+                inner,
+            } => Ok(LwePackingKeyswitchKey64(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LwePackingKeyswitchKey32 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> LwePackingKeyswitchKey64 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 64 bits integers. It deserializes a packing keyswitch key entity.
-impl EntityDeserializationEngine<&[u8], LwePackingKeyswitchKey64> for DefaultSerializationEngine {
+/// operates on 16 bits integers. It deserializes a plaintext entity.
+impl EntityDeserializationEngine<&[u8], Plaintext16> for DefaultSerializationEngine {
     /// # Example:
     /// ```
-    /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{
-    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
-    /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
-    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let input_lwe_dimension = LweDimension(6);
-    /// let output_lwe_dimension = LweDimension(3);
-    /// let decomposition_level_count = DecompositionLevelCount(2);
-    /// let decomposition_base_log = DecompositionBaseLog(8);
-    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 12 bits)
+    /// let input = 3_u16 << 12;
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
-    /// let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
-    ///
-    /// let packing_keyswitch_key = engine.generate_new_lwe_keyswitch_key(
-    ///     &input_key,
-    ///     &output_key,
-    ///     decomposition_level_count,
-    ///     decomposition_base_log,
-    ///     noise,
-    /// )?;
+    /// let plaintext: Plaintext16 = engine.create_plaintext_from(&input)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&packing_keyswitch_key)?;
+    /// let serialized = serialization_engine.serialize(&plaintext)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(packing_keyswitch_key, recovered);
+    /// assert_eq!(plaintext, recovered);
     ///
     /// #
     /// # Ok(())
@@ -2683,31 +6122,42 @@ impl EntityDeserializationEngine<&[u8], LwePackingKeyswitchKey64> for DefaultSer
     fn deserialize(
         &mut self,
         serialized: &[u8],
-    ) -> Result<LwePackingKeyswitchKey64, EntityDeserializationError<Self::EngineError>> {
+    ) -> Result<Plaintext16, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> Plaintext16 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<Plaintext16, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
-        struct DeserializablePackingKeyswitchKey64 {
-            version: LwePackingKeyswitchKey64Version,
-            inner: ImplLwePackingKeyswitchKey<Vec<u64>>,
+        struct DeserializablePlaintext16 {
+            version: Plaintext16Version,
+            inner: ImplPlaintext<u16>,
         }
-        let deserialized: DeserializablePackingKeyswitchKey64 = bincode::deserialize(serialized)
+        let deserialized: DeserializablePlaintext16 = bincode::deserialize_from(reader)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
-            DeserializablePackingKeyswitchKey64 {
-                version: LwePackingKeyswitchKey64Version::Unsupported,
+            DeserializablePlaintext16 {
+                version: Plaintext16Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
-            DeserializablePackingKeyswitchKey64 {
-                version: LwePackingKeyswitchKey64Version::V0,
+            DeserializablePlaintext16 {
+                version: Plaintext16Version::V0,
                 inner,
-            } => Ok(LwePackingKeyswitchKey64(inner)),
+            } => Ok(Plaintext16(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LwePackingKeyswitchKey64 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(&mut self, reader: R) -> Plaintext16 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
 
@@ -2742,13 +6192,24 @@ impl EntityDeserializationEngine<&[u8], Plaintext32> for DefaultSerializationEng
     fn deserialize(
         &mut self,
         serialized: &[u8],
+    ) -> Result<Plaintext32, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> Plaintext32 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
     ) -> Result<Plaintext32, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
         struct DeserializablePlaintext32 {
             version: Plaintext32Version,
             inner: ImplPlaintext<u32>,
         }
-        let deserialized: DeserializablePlaintext32 = bincode::deserialize(serialized)
+        let deserialized: DeserializablePlaintext32 = bincode::deserialize_from(reader)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
@@ -2765,8 +6226,8 @@ impl EntityDeserializationEngine<&[u8], Plaintext32> for DefaultSerializationEng
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> Plaintext32 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(&mut self, reader: R) -> Plaintext32 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
 
@@ -2800,13 +6261,24 @@ impl EntityDeserializationEngine<&[u8], Plaintext64> for DefaultSerializationEng
     fn deserialize(
         &mut self,
         serialized: &[u8],
+    ) -> Result<Plaintext64, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> Plaintext64 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
     ) -> Result<Plaintext64, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
         struct DeserializablePlaintext64 {
             version: Plaintext64Version,
             inner: ImplPlaintext<u64>,
         }
-        let deserialized: DeserializablePlaintext64 = bincode::deserialize(serialized)
+        let deserialized: DeserializablePlaintext64 = bincode::deserialize_from(reader)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
@@ -2823,8 +6295,8 @@ impl EntityDeserializationEngine<&[u8], Plaintext64> for DefaultSerializationEng
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> Plaintext64 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(&mut self, reader: R) -> Plaintext64 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
 
@@ -2860,13 +6332,24 @@ impl EntityDeserializationEngine<&[u8], PlaintextVector32> for DefaultSerializat
     fn deserialize(
         &mut self,
         serialized: &[u8],
+    ) -> Result<PlaintextVector32, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> PlaintextVector32 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
     ) -> Result<PlaintextVector32, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
         struct DeserializablePlaintextVector32 {
             version: PlaintextVector32Version,
             inner: ImplPlaintextList<Vec<u32>>,
         }
-        let deserialized: DeserializablePlaintextVector32 = bincode::deserialize(serialized)
+        let deserialized: DeserializablePlaintextVector32 = bincode::deserialize_from(reader)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
@@ -2883,8 +6366,11 @@ impl EntityDeserializationEngine<&[u8], PlaintextVector32> for DefaultSerializat
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> PlaintextVector32 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> PlaintextVector32 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
 
@@ -2920,13 +6406,24 @@ impl EntityDeserializationEngine<&[u8], PlaintextVector64> for DefaultSerializat
     fn deserialize(
         &mut self,
         serialized: &[u8],
+    ) -> Result<PlaintextVector64, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> PlaintextVector64 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
     ) -> Result<PlaintextVector64, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
         struct DeserializablePlaintextVector64 {
             version: PlaintextVector64Version,
             inner: ImplPlaintextList<Vec<u64>>,
         }
-        let deserialized: DeserializablePlaintextVector64 = bincode::deserialize(serialized)
+        let deserialized: DeserializablePlaintextVector64 = bincode::deserialize_from(reader)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
@@ -2943,9 +6440,168 @@ impl EntityDeserializationEngine<&[u8], PlaintextVector64> for DefaultSerializat
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> PlaintextVector64 {
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> PlaintextVector64 {
+        self.deserialize_from_reader(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a sparse cleartext polynomial entity.
+impl EntityDeserializationEngine<&[u8], SparseCleartextPolynomial32>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{MonomialIndex, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let values = vec![3_u32, 5];
+    /// let indices = vec![MonomialIndex(0), MonomialIndex(7)];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let sparse_polynomial: SparseCleartextPolynomial32 =
+    ///     engine.create_sparse_cleartext_polynomial_from(&values, &indices, PolynomialSize(256))?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&sparse_polynomial)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(sparse_polynomial, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<SparseCleartextPolynomial32, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> SparseCleartextPolynomial32 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<SparseCleartextPolynomial32, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableSparseCleartextPolynomial32 {
+            version: SparseCleartextPolynomial32Version,
+            inner: ImplSparsePolynomial<Vec<u32>>,
+        }
+        let deserialized: DeserializableSparseCleartextPolynomial32 =
+            bincode::deserialize_from(reader)
+                .map_err(DefaultSerializationError::Deserialization)
+                .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableSparseCleartextPolynomial32 {
+                version: SparseCleartextPolynomial32Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableSparseCleartextPolynomial32 {
+                version: SparseCleartextPolynomial32Version::V0,
+                inner,
+            } => Ok(SparseCleartextPolynomial32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> SparseCleartextPolynomial32 {
+        self.deserialize_from_reader(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a sparse cleartext polynomial entity.
+impl EntityDeserializationEngine<&[u8], SparseCleartextPolynomial64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{MonomialIndex, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let values = vec![3_u64, 5];
+    /// let indices = vec![MonomialIndex(0), MonomialIndex(7)];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let sparse_polynomial: SparseCleartextPolynomial64 =
+    ///     engine.create_sparse_cleartext_polynomial_from(&values, &indices, PolynomialSize(256))?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&sparse_polynomial)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(sparse_polynomial, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<SparseCleartextPolynomial64, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> SparseCleartextPolynomial64 {
         self.deserialize(serialized).unwrap()
     }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<SparseCleartextPolynomial64, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableSparseCleartextPolynomial64 {
+            version: SparseCleartextPolynomial64Version,
+            inner: ImplSparsePolynomial<Vec<u64>>,
+        }
+        let deserialized: DeserializableSparseCleartextPolynomial64 =
+            bincode::deserialize_from(reader)
+                .map_err(DefaultSerializationError::Deserialization)
+                .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableSparseCleartextPolynomial64 {
+                version: SparseCleartextPolynomial64Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableSparseCleartextPolynomial64 {
+                version: SparseCleartextPolynomial64Version::V0,
+                inner,
+            } => Ok(SparseCleartextPolynomial64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> SparseCleartextPolynomial64 {
+        self.deserialize_from_reader(reader).unwrap()
+    }
 }
 
 /// # Description:
@@ -2983,13 +6639,24 @@ impl EntityDeserializationEngine<&[u8], FloatEncoder> for DefaultSerializationEn
     fn deserialize(
         &mut self,
         serialized: &[u8],
+    ) -> Result<FloatEncoder, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> FloatEncoder {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
     ) -> Result<FloatEncoder, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
         struct DeserializableFloatEncoder {
             version: FloatEncoderVersion,
             inner: ImplFloatEncoder,
         }
-        let deserialized: DeserializableFloatEncoder = bincode::deserialize(serialized)
+        let deserialized: DeserializableFloatEncoder = bincode::deserialize_from(reader)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
@@ -3006,9 +6673,81 @@ impl EntityDeserializationEngine<&[u8], FloatEncoder> for DefaultSerializationEn
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> FloatEncoder {
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(&mut self, reader: R) -> FloatEncoder {
+        self.deserialize_from_reader(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a modular encoder entity.
+impl EntityDeserializationEngine<&[u8], ModularEncoder> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::PlaintextCount;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let encoder = engine.create_encoder_from(&ModularEncoderConfig {
+    ///     modulus: 5,
+    ///     nb_bit_padding: 1,
+    /// })?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&encoder)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(encoder, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<ModularEncoder, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> ModularEncoder {
         self.deserialize(serialized).unwrap()
     }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<ModularEncoder, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableModularEncoder {
+            version: ModularEncoderVersion,
+            inner: ImplModularEncoder,
+        }
+        let deserialized: DeserializableModularEncoder = bincode::deserialize_from(reader)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableModularEncoder {
+                version: ModularEncoderVersion::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableModularEncoder {
+                version: ModularEncoderVersion::V0,
+                inner,
+            } => Ok(ModularEncoder(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(&mut self, reader: R) -> ModularEncoder {
+        self.deserialize_from_reader(reader).unwrap()
+    }
 }
 
 /// # Description:
@@ -3049,13 +6788,24 @@ impl EntityDeserializationEngine<&[u8], FloatEncoderVector> for DefaultSerializa
     fn deserialize(
         &mut self,
         serialized: &[u8],
+    ) -> Result<FloatEncoderVector, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> FloatEncoderVector {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
     ) -> Result<FloatEncoderVector, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
         struct DeserializableFloatEncoderVector {
             version: FloatEncoderVectorVersion,
             inner: Vec<ImplFloatEncoder>,
         }
-        let deserialized: DeserializableFloatEncoderVector = bincode::deserialize(serialized)
+        let deserialized: DeserializableFloatEncoderVector = bincode::deserialize_from(reader)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
@@ -3072,7 +6822,10 @@ impl EntityDeserializationEngine<&[u8], FloatEncoderVector> for DefaultSerializa
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> FloatEncoderVector {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> FloatEncoderVector {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
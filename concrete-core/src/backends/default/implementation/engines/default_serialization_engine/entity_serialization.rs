@@ -6,62 +6,158 @@ use crate::commons::crypto::bootstrap::{
 };
 use crate::commons::crypto::encoding::{
     Cleartext as ImplCleartext, CleartextList as ImplCleartextList,
-    FloatEncoder as ImplFloatEncoder, Plaintext as ImplPlaintext,
-    PlaintextList as ImplPlaintextList,
+    FloatEncoder as ImplFloatEncoder, ModularEncoder as ImplModularEncoder,
+    Plaintext as ImplPlaintext, PlaintextList as ImplPlaintextList,
 };
 use crate::commons::crypto::ggsw::{
     StandardGgswCiphertext as ImplStandardGgswCiphertext,
     StandardGgswSeededCiphertext as ImplStandardGgswSeededCiphertext,
 };
 use crate::commons::crypto::glwe::{
+    GlevCiphertext as ImplGlevCiphertext, GlweAutomorphismKeys as ImplGlweAutomorphismKeys,
     GlweCiphertext as ImplGlweCiphertext, GlweList as ImplGlweList,
+    GlweRelinearizationKey as ImplGlweRelinearizationKey,
+    GlweRingReductionKey as ImplGlweRingReductionKey,
+    GlweRingSwitchingKey as ImplGlweRingSwitchingKey,
     GlweSeededCiphertext as ImplGlweSeededCiphertext, GlweSeededList as ImplGlweSeededList,
     LwePackingKeyswitchKey as ImplLwePackingKeyswitchKey,
+    LweSeededPackingKeyswitchKey as ImplLweSeededPackingKeyswitchKey,
 };
 use crate::commons::crypto::lwe::{
-    LweCiphertext as ImplLweCiphertext, LweKeyswitchKey as ImplLweKeyswitchKey,
-    LweList as ImplLweList, LweSeededCiphertext as ImplLweSeededCiphertext,
+    LevCiphertext as ImplLevCiphertext, LweCiphertext as ImplLweCiphertext,
+    LweKeyswitchKey as ImplLweKeyswitchKey, LweList as ImplLweList,
+    LwePartialKeyswitchKey as ImplLwePartialKeyswitchKey,
+    LweSeededCiphertext as ImplLweSeededCiphertext,
     LweSeededKeyswitchKey as ImplLweSeededKeyswitchKey, LweSeededList as ImplLweSeededList,
 };
 use crate::commons::crypto::secret::{
-    GlweSecretKey as ImplGlweSecretKey, LweSecretKey as ImplLweSecretKey,
+    GlweSecretKey as ImplGlweSecretKey,
+    GlweTensorProductSecretKey as ImplGlweTensorProductSecretKey, LweSecretKey as ImplLweSecretKey,
 };
+use crate::commons::math::polynomial::SparsePolynomial as ImplSparsePolynomial;
 use crate::prelude::{
-    Cleartext32, Cleartext32Version, Cleartext64, Cleartext64Version, CleartextF64,
-    CleartextF64Version, CleartextVector32, CleartextVector32Version, CleartextVector64,
-    CleartextVector64Version, CleartextVectorF64, CleartextVectorF64Version,
-    DefaultSerializationEngine, DefaultSerializationError, EntitySerializationEngine,
-    EntitySerializationError, FloatEncoder, FloatEncoderVector, FloatEncoderVectorVersion,
-    FloatEncoderVersion, GgswCiphertext32, GgswCiphertext32Version, GgswCiphertext64,
-    GgswCiphertext64Version, GgswSeededCiphertext32, GgswSeededCiphertext32Version,
-    GgswSeededCiphertext64, GgswSeededCiphertext64Version, GlweCiphertext32,
-    GlweCiphertext32Version, GlweCiphertext64, GlweCiphertext64Version, GlweCiphertextMutView32,
-    GlweCiphertextMutView64, GlweCiphertextVector32, GlweCiphertextVector32Version,
-    GlweCiphertextVector64, GlweCiphertextVector64Version, GlweCiphertextView32,
-    GlweCiphertextView64, GlweSecretKey32, GlweSecretKey32Version, GlweSecretKey64,
-    GlweSecretKey64Version, GlweSeededCiphertext32, GlweSeededCiphertext32Version,
-    GlweSeededCiphertext64, GlweSeededCiphertext64Version, GlweSeededCiphertextVector32,
-    GlweSeededCiphertextVector32Version, GlweSeededCiphertextVector64,
-    GlweSeededCiphertextVector64Version, LweBootstrapKey32, LweBootstrapKey32Version,
-    LweBootstrapKey64, LweBootstrapKey64Version, LweCiphertext32, LweCiphertext32Version,
-    LweCiphertext64, LweCiphertext64Version, LweCiphertextMutView32, LweCiphertextMutView64,
-    LweCiphertextVector32, LweCiphertextVector32Version, LweCiphertextVector64,
-    LweCiphertextVector64Version, LweCiphertextVectorMutView32, LweCiphertextVectorMutView64,
-    LweCiphertextVectorView32, LweCiphertextVectorView64, LweCiphertextView32, LweCiphertextView64,
-    LweKeyswitchKey32, LweKeyswitchKey32Version, LweKeyswitchKey64, LweKeyswitchKey64Version,
-    LwePackingKeyswitchKey32, LwePackingKeyswitchKey32Version, LwePackingKeyswitchKey64,
-    LwePackingKeyswitchKey64Version, LweSecretKey32, LweSecretKey32Version, LweSecretKey64,
-    LweSecretKey64Version, LweSeededBootstrapKey32, LweSeededBootstrapKey32Version,
-    LweSeededBootstrapKey64, LweSeededBootstrapKey64Version, LweSeededCiphertext32,
-    LweSeededCiphertext32Version, LweSeededCiphertext64, LweSeededCiphertext64Version,
-    LweSeededCiphertextVector32, LweSeededCiphertextVector32Version, LweSeededCiphertextVector64,
+    Cleartext16, Cleartext16Version, Cleartext32, Cleartext32Version, Cleartext64,
+    Cleartext64Version, CleartextF64, CleartextF64Version, CleartextVector32,
+    CleartextVector32Version, CleartextVector64, CleartextVector64Version, CleartextVectorF64,
+    CleartextVectorF64Version, DefaultSerializationEngine, DefaultSerializationError,
+    EntitySerializationEngine, EntitySerializationError, FloatEncoder, FloatEncoderVector,
+    FloatEncoderVectorVersion, FloatEncoderVersion, GgswCiphertext32, GgswCiphertext32Version,
+    GgswCiphertext64, GgswCiphertext64Version, GgswSeededCiphertext32,
+    GgswSeededCiphertext32Version, GgswSeededCiphertext64, GgswSeededCiphertext64Version,
+    GlevCiphertext32, GlevCiphertext32Version, GlevCiphertext64, GlevCiphertext64Version,
+    GlweAutomorphismKeys32, GlweAutomorphismKeys32Version, GlweAutomorphismKeys64,
+    GlweAutomorphismKeys64Version, GlweCiphertext32, GlweCiphertext32Version, GlweCiphertext64,
+    GlweCiphertext64Version, GlweCiphertextMutView32, GlweCiphertextMutView64,
+    GlweCiphertextVector32, GlweCiphertextVector32Version, GlweCiphertextVector64,
+    GlweCiphertextVector64Version, GlweCiphertextView32, GlweCiphertextView64,
+    GlweRelinearizationKey32, GlweRelinearizationKey32Version, GlweRelinearizationKey64,
+    GlweRelinearizationKey64Version, GlweRingReductionKey32, GlweRingReductionKey32Version,
+    GlweRingReductionKey64, GlweRingReductionKey64Version, GlweRingSwitchingKey32,
+    GlweRingSwitchingKey32Version, GlweRingSwitchingKey64, GlweRingSwitchingKey64Version,
+    GlweSecretKey32, GlweSecretKey32Version, GlweSecretKey64, GlweSecretKey64Version,
+    GlweSecretKeyGaussian32, GlweSecretKeyGaussian32Version, GlweSecretKeyGaussian64,
+    GlweSecretKeyGaussian64Version, GlweSecretKeyTernary32, GlweSecretKeyTernary32Version,
+    GlweSecretKeyTernary64, GlweSecretKeyTernary64Version, GlweSeededCiphertext32,
+    GlweSeededCiphertext32Version, GlweSeededCiphertext64, GlweSeededCiphertext64Version,
+    GlweSeededCiphertextVector32, GlweSeededCiphertextVector32Version,
+    GlweSeededCiphertextVector64, GlweSeededCiphertextVector64Version,
+    GlweTensorProductSecretKey32, GlweTensorProductSecretKey32Version,
+    GlweTensorProductSecretKey64, GlweTensorProductSecretKey64Version, LevCiphertext32,
+    LevCiphertext32Version, LevCiphertext64, LevCiphertext64Version, LweBootstrapKey32,
+    LweBootstrapKey32Version, LweBootstrapKey64, LweBootstrapKey64Version, LweCiphertext16,
+    LweCiphertext16Version, LweCiphertext32, LweCiphertext32Version, LweCiphertext64,
+    LweCiphertext64Version, LweCiphertextMutView32, LweCiphertextMutView64, LweCiphertextVector32,
+    LweCiphertextVector32Version, LweCiphertextVector64, LweCiphertextVector64Version,
+    LweCiphertextVectorMutView32, LweCiphertextVectorMutView64, LweCiphertextVectorView32,
+    LweCiphertextVectorView64, LweCiphertextView32, LweCiphertextView64, LweKeyswitchKey16,
+    LweKeyswitchKey16Version, LweKeyswitchKey32, LweKeyswitchKey32Version, LweKeyswitchKey64,
+    LweKeyswitchKey64Version, LwePackingKeyswitchKey32, LwePackingKeyswitchKey32Version,
+    LwePackingKeyswitchKey64, LwePackingKeyswitchKey64Version, LwePartialKeyswitchKey32,
+    LwePartialKeyswitchKey32Version, LwePartialKeyswitchKey64, LwePartialKeyswitchKey64Version,
+    LweSecretKey16, LweSecretKey16Version, LweSecretKey32, LweSecretKey32Version, LweSecretKey64,
+    LweSecretKey64Version, LweSecretKeyGaussian32, LweSecretKeyGaussian32Version,
+    LweSecretKeyGaussian64, LweSecretKeyGaussian64Version, LweSecretKeyTernary32,
+    LweSecretKeyTernary32Version, LweSecretKeyTernary64, LweSecretKeyTernary64Version,
+    LweSeededBootstrapKey32, LweSeededBootstrapKey32Version, LweSeededBootstrapKey64,
+    LweSeededBootstrapKey64Version, LweSeededCiphertext32, LweSeededCiphertext32Version,
+    LweSeededCiphertext64, LweSeededCiphertext64Version, LweSeededCiphertextVector32,
+    LweSeededCiphertextVector32Version, LweSeededCiphertextVector64,
     LweSeededCiphertextVector64Version, LweSeededKeyswitchKey32, LweSeededKeyswitchKey32Version,
-    LweSeededKeyswitchKey64, LweSeededKeyswitchKey64Version, Plaintext32, Plaintext32Version,
-    Plaintext64, Plaintext64Version, PlaintextVector32, PlaintextVector32Version,
-    PlaintextVector64, PlaintextVector64Version,
+    LweSeededKeyswitchKey64, LweSeededKeyswitchKey64Version, LweSeededPackingKeyswitchKey32,
+    LweSeededPackingKeyswitchKey32Version, LweSeededPackingKeyswitchKey64,
+    LweSeededPackingKeyswitchKey64Version, ModularEncoder, ModularEncoderVersion, Plaintext16,
+    Plaintext16Version, Plaintext32, Plaintext32Version, Plaintext64, Plaintext64Version,
+    PlaintextVector32, PlaintextVector32Version, PlaintextVector64, PlaintextVector64Version,
+    SparseCleartextPolynomial32, SparseCleartextPolynomial32Version, SparseCleartextPolynomial64,
+    SparseCleartextPolynomial64Version, TrivialGlweCiphertext32, TrivialGlweCiphertext32Version,
+    TrivialGlweCiphertext64, TrivialGlweCiphertext64Version, TrivialLweCiphertext32,
+    TrivialLweCiphertext32Version, TrivialLweCiphertext64, TrivialLweCiphertext64Version,
 };
-use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_commons::key_kinds::{BinaryKeyKind, GaussianKeyKind, TernaryKeyKind};
 use serde::Serialize;
+use std::io::Write;
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 16 bits integers. It serializes a cleartext entity.
+impl EntitySerializationEngine<Cleartext16, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input: u16 = 3;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let cleartext: Cleartext16 = engine.create_cleartext_from(&input)?;
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&cleartext)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(cleartext, recovered);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &Cleartext16,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &Cleartext16) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &Cleartext16,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableCleartext16<'a> {
+            version: Cleartext16Version,
+            inner: &'a ImplCleartext<u16>,
+        }
+        let serializable = SerializableCleartext16 {
+            version: Cleartext16Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize_into(writer, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_to_writer_unchecked<W: Write>(&mut self, entity: &Cleartext16, writer: W) {
+        self.serialize_to_writer(entity, writer).unwrap()
+    }
+}
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
@@ -92,6 +188,20 @@ impl EntitySerializationEngine<Cleartext32, Vec<u8>> for DefaultSerializationEng
         &mut self,
         entity: &Cleartext32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &Cleartext32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &Cleartext32,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
         struct SerializableCleartext32<'a> {
             version: Cleartext32Version,
@@ -101,13 +211,13 @@ impl EntitySerializationEngine<Cleartext32, Vec<u8>> for DefaultSerializationEng
             version: Cleartext32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &Cleartext32) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(&mut self, entity: &Cleartext32, writer: W) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
@@ -140,6 +250,20 @@ impl EntitySerializationEngine<Cleartext64, Vec<u8>> for DefaultSerializationEng
         &mut self,
         entity: &Cleartext64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &Cleartext64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &Cleartext64,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
         struct SerializableCleartext64<'a> {
             version: Cleartext64Version,
@@ -149,13 +273,13 @@ impl EntitySerializationEngine<Cleartext64, Vec<u8>> for DefaultSerializationEng
             version: Cleartext64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &Cleartext64) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(&mut self, entity: &Cleartext64, writer: W) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
@@ -188,6 +312,20 @@ impl EntitySerializationEngine<CleartextF64, Vec<u8>> for DefaultSerializationEn
         &mut self,
         entity: &CleartextF64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &CleartextF64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &CleartextF64,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
         struct SerializableCleartextF64<'a> {
             version: CleartextF64Version,
@@ -197,13 +335,13 @@ impl EntitySerializationEngine<CleartextF64, Vec<u8>> for DefaultSerializationEn
             version: CleartextF64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &CleartextF64) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(&mut self, entity: &CleartextF64, writer: W) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
@@ -237,6 +375,20 @@ impl EntitySerializationEngine<CleartextVector32, Vec<u8>> for DefaultSerializat
         &mut self,
         entity: &CleartextVector32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &CleartextVector32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &CleartextVector32,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
         struct SerializableCleartextVector32<'a> {
             version: CleartextVector32Version,
@@ -246,13 +398,17 @@ impl EntitySerializationEngine<CleartextVector32, Vec<u8>> for DefaultSerializat
             version: CleartextVector32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &CleartextVector32) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &CleartextVector32,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
@@ -286,6 +442,20 @@ impl EntitySerializationEngine<CleartextVector64, Vec<u8>> for DefaultSerializat
         &mut self,
         entity: &CleartextVector64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &CleartextVector64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &CleartextVector64,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
         struct SerializableCleartextVector64<'a> {
             version: CleartextVector64Version,
@@ -295,13 +465,17 @@ impl EntitySerializationEngine<CleartextVector64, Vec<u8>> for DefaultSerializat
             version: CleartextVector64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &CleartextVector64) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &CleartextVector64,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
@@ -335,6 +509,20 @@ impl EntitySerializationEngine<CleartextVectorF64, Vec<u8>> for DefaultSerializa
         &mut self,
         entity: &CleartextVectorF64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &CleartextVectorF64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &CleartextVectorF64,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
         struct SerializableCleartextVectorF64<'a> {
             version: CleartextVectorF64Version,
@@ -344,13 +532,17 @@ impl EntitySerializationEngine<CleartextVectorF64, Vec<u8>> for DefaultSerializa
             version: CleartextVectorF64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &CleartextVectorF64) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &CleartextVectorF64,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
@@ -401,6 +593,20 @@ impl EntitySerializationEngine<GgswCiphertext32, Vec<u8>> for DefaultSerializati
         &mut self,
         entity: &GgswCiphertext32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &GgswCiphertext32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &GgswCiphertext32,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
         struct SerializableGgswCiphertext32<'a> {
             version: GgswCiphertext32Version,
@@ -410,13 +616,17 @@ impl EntitySerializationEngine<GgswCiphertext32, Vec<u8>> for DefaultSerializati
             version: GgswCiphertext32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &GgswCiphertext32) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &GgswCiphertext32,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
@@ -467,89 +677,53 @@ impl EntitySerializationEngine<GgswCiphertext64, Vec<u8>> for DefaultSerializati
         &mut self,
         entity: &GgswCiphertext64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableGgswCiphertext64<'a> {
-            version: GgswCiphertext64Version,
-            inner: &'a ImplStandardGgswCiphertext<Vec<u64>>,
-        }
-        let serializable = SerializableGgswCiphertext64 {
-            version: GgswCiphertext64Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &GgswCiphertext64) -> Vec<u8> {
         self.serialize(entity).unwrap()
     }
-}
 
-/// # Description:
-/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 32 bits integers. It serializes a seeded GGSW ciphertext entity.
-impl EntitySerializationEngine<GgswSeededCiphertext32, Vec<u8>> for DefaultSerializationEngine {
-    /// TODO
-    fn serialize(
+    fn serialize_to_writer<W: Write>(
         &mut self,
-        entity: &GgswSeededCiphertext32,
-    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        entity: &GgswCiphertext64,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableGgswSeededCiphertext32<'a> {
-            version: GgswSeededCiphertext32Version,
-            inner: &'a ImplStandardGgswSeededCiphertext<Vec<u32>>,
+        struct SerializableGgswCiphertext64<'a> {
+            version: GgswCiphertext64Version,
+            inner: &'a ImplStandardGgswCiphertext<Vec<u64>>,
         }
-        let serializable = SerializableGgswSeededCiphertext32 {
-            version: GgswSeededCiphertext32Version::V0,
+        let serializable = SerializableGgswCiphertext64 {
+            version: GgswCiphertext64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &GgswSeededCiphertext32) -> Vec<u8> {
-        self.serialize(entity).unwrap()
-    }
-}
-
-/// # Description:
-/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 64 bits integers. It serializes a seeded GGSW ciphertext entity.
-impl EntitySerializationEngine<GgswSeededCiphertext64, Vec<u8>> for DefaultSerializationEngine {
-    /// TODO
-    fn serialize(
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
         &mut self,
-        entity: &GgswSeededCiphertext64,
-    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableGgswSeededCiphertext64<'a> {
-            version: GgswSeededCiphertext64Version,
-            inner: &'a ImplStandardGgswSeededCiphertext<Vec<u64>>,
-        }
-        let serializable = SerializableGgswSeededCiphertext64 {
-            version: GgswSeededCiphertext64Version::V0,
-            inner: &entity.0,
-        };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
-    }
-
-    unsafe fn serialize_unchecked(&mut self, entity: &GgswSeededCiphertext64) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+        entity: &GgswCiphertext64,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 32 bits integers. It serializes a GLWE ciphertext entity.
-impl EntitySerializationEngine<GlweCiphertext32, Vec<u8>> for DefaultSerializationEngine {
+/// on 32 bits integers. It serializes a GLev ciphertext entity.
+impl EntitySerializationEngine<GlevCiphertext32, Vec<u8>> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
@@ -557,9 +731,9 @@ impl EntitySerializationEngine<GlweCiphertext32, Vec<u8>> for DefaultSerializati
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
     /// let glwe_dimension = GlweDimension(2);
     /// let polynomial_size = PolynomialSize(4);
-    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
-    /// // Here a hard-set encoding is applied (shift by 20 bits)
-    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// let input = vec![3_u32; polynomial_size.0];
     /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
@@ -570,7 +744,8 @@ impl EntitySerializationEngine<GlweCiphertext32, Vec<u8>> for DefaultSerializati
     ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
     /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
     ///
-    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// let ciphertext =
+    ///     engine.encrypt_glev_ciphertext(&key, &plaintext_vector, noise, level, base_log)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
     /// let serialized = serialization_engine.serialize(&ciphertext)?;
@@ -583,35 +758,55 @@ impl EntitySerializationEngine<GlweCiphertext32, Vec<u8>> for DefaultSerializati
     /// ```
     fn serialize(
         &mut self,
-        entity: &GlweCiphertext32,
+        entity: &GlevCiphertext32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &GlevCiphertext32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &GlevCiphertext32,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableGlweCiphertext32<'a> {
-            version: GlweCiphertext32Version,
-            inner: &'a ImplGlweCiphertext<Vec<u32>>,
+        struct SerializableGlevCiphertext32<'a> {
+            version: GlevCiphertext32Version,
+            inner: &'a ImplGlevCiphertext<Vec<u32>>,
         }
-        let serializable = SerializableGlweCiphertext32 {
-            version: GlweCiphertext32Version::V0,
+        let serializable = SerializableGlevCiphertext32 {
+            version: GlevCiphertext32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &GlweCiphertext32) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &GlevCiphertext32,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 64 bits integers. It serializes a GLWE ciphertext entity.
-impl EntitySerializationEngine<GlweCiphertext64, Vec<u8>> for DefaultSerializationEngine {
+/// on 64 bits integers. It serializes a GLev ciphertext entity.
+impl EntitySerializationEngine<GlevCiphertext64, Vec<u8>> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
@@ -619,9 +814,9 @@ impl EntitySerializationEngine<GlweCiphertext64, Vec<u8>> for DefaultSerializati
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
     /// let glwe_dimension = GlweDimension(2);
     /// let polynomial_size = PolynomialSize(4);
-    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
-    /// // Here a hard-set encoding is applied (shift by 50 bits)
-    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// let input = vec![3_u64; polynomial_size.0];
     /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
@@ -632,7 +827,8 @@ impl EntitySerializationEngine<GlweCiphertext64, Vec<u8>> for DefaultSerializati
     ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
     /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
     ///
-    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// let ciphertext =
+    ///     engine.encrypt_glev_ciphertext(&key, &plaintext_vector, noise, level, base_log)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
     /// let serialized = serialization_engine.serialize(&ciphertext)?;
@@ -645,169 +841,141 @@ impl EntitySerializationEngine<GlweCiphertext64, Vec<u8>> for DefaultSerializati
     /// ```
     fn serialize(
         &mut self,
-        entity: &GlweCiphertext64,
+        entity: &GlevCiphertext64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &GlevCiphertext64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &GlevCiphertext64,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableGlweCiphertext64<'a> {
-            version: GlweCiphertext64Version,
-            inner: &'a ImplGlweCiphertext<Vec<u64>>,
+        struct SerializableGlevCiphertext64<'a> {
+            version: GlevCiphertext64Version,
+            inner: &'a ImplGlevCiphertext<Vec<u64>>,
         }
-        let serializable = SerializableGlweCiphertext64 {
-            version: GlweCiphertext64Version::V0,
+        let serializable = SerializableGlevCiphertext64 {
+            version: GlevCiphertext64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &GlweCiphertext64) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &GlevCiphertext64,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 32 bits integers. It serializes a GLWE ciphertext view entity.
-impl<'b> EntitySerializationEngine<GlweCiphertextView32<'b>, Vec<u8>>
-    for DefaultSerializationEngine
-{
-    /// # Example:
-    /// ```
-    /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
-    /// use concrete_core::prelude::*;
-    /// # use std::error::Error;
-    ///
-    /// # fn main() -> Result<(), Box<dyn Error>> {
-    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let glwe_dimension = GlweDimension(2);
-    /// let polynomial_size = PolynomialSize(4);
-    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
-    /// // Here a hard-set encoding is applied (shift by 20 bits)
-    /// let input = vec![3_u32 << 20; polynomial_size.0];
-    /// let noise = Variance(2_f64.powf(-25.));
-    ///
-    /// // Unix seeder must be given a secret input.
-    /// // Here we just give it 0, which is totally unsafe.
-    /// const UNSAFE_SECRET: u128 = 0;
-    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: GlweSecretKey32 =
-    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
-    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
-    ///
-    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
-    /// let raw_buffer = engine.consume_retrieve_glwe_ciphertext(ciphertext)?;
-    /// let view: GlweCiphertextView32 =
-    ///     engine.create_glwe_ciphertext_from(raw_buffer.as_slice(), polynomial_size)?;
-    ///
-    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&view)?;
-    /// let recovered: GlweCiphertext32 = serialization_engine.deserialize(serialized.as_slice())?;
-    /// let recovered_buffer = engine.consume_retrieve_glwe_ciphertext(recovered)?;
-    /// assert_eq!(raw_buffer, recovered_buffer);
-    ///
-    /// #
-    /// # Ok(())
-    /// # }
-    /// ```
+/// on 32 bits integers. It serializes a seeded GGSW ciphertext entity.
+impl EntitySerializationEngine<GgswSeededCiphertext32, Vec<u8>> for DefaultSerializationEngine {
+    /// TODO
     fn serialize(
         &mut self,
-        entity: &GlweCiphertextView32<'b>,
+        entity: &GgswSeededCiphertext32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &GgswSeededCiphertext32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &GgswSeededCiphertext32,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableGlweCiphertextView32<'a, 'b> {
-            version: GlweCiphertext32Version,
-            inner: &'a ImplGlweCiphertext<&'b [u32]>,
+        struct SerializableGgswSeededCiphertext32<'a> {
+            version: GgswSeededCiphertext32Version,
+            inner: &'a ImplStandardGgswSeededCiphertext<Vec<u32>>,
         }
-        let serializable = SerializableGlweCiphertextView32 {
-            version: GlweCiphertext32Version::V0,
+        let serializable = SerializableGgswSeededCiphertext32 {
+            version: GgswSeededCiphertext32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &GlweCiphertextView32<'b>) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &GgswSeededCiphertext32,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 64 bits integers. It serializes a GLWE ciphertext view entity.
-impl<'b> EntitySerializationEngine<GlweCiphertextView64<'b>, Vec<u8>>
-    for DefaultSerializationEngine
-{
-    /// # Example:
-    /// ```
-    /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
-    /// use concrete_core::prelude::*;
-    /// # use std::error::Error;
-    ///
-    /// # fn main() -> Result<(), Box<dyn Error>> {
-    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let glwe_dimension = GlweDimension(2);
-    /// let polynomial_size = PolynomialSize(4);
-    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
-    /// // Here a hard-set encoding is applied (shift by 50 bits)
-    /// let input = vec![3_u64 << 50; polynomial_size.0];
-    /// let noise = Variance(2_f64.powf(-25.));
-    ///
-    /// // Unix seeder must be given a secret input.
-    /// // Here we just give it 0, which is totally unsafe.
-    /// const UNSAFE_SECRET: u128 = 0;
-    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: GlweSecretKey64 =
-    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
-    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
-    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
-    ///
-    /// let raw_buffer = engine.consume_retrieve_glwe_ciphertext(ciphertext)?;
-    /// let view: GlweCiphertextView64 =
-    ///     engine.create_glwe_ciphertext_from(raw_buffer.as_slice(), polynomial_size)?;
-    ///
-    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&view)?;
-    /// let recovered: GlweCiphertext64 = serialization_engine.deserialize(serialized.as_slice())?;
-    /// let recovered_buffer = engine.consume_retrieve_glwe_ciphertext(recovered)?;
-    /// assert_eq!(raw_buffer, recovered_buffer);
-    ///
-    /// #
-    /// # Ok(())
-    /// # }
-    /// ```
+/// on 64 bits integers. It serializes a seeded GGSW ciphertext entity.
+impl EntitySerializationEngine<GgswSeededCiphertext64, Vec<u8>> for DefaultSerializationEngine {
+    /// TODO
     fn serialize(
         &mut self,
-        entity: &GlweCiphertextView64<'b>,
+        entity: &GgswSeededCiphertext64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &GgswSeededCiphertext64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &GgswSeededCiphertext64,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableGlweCiphertextView64<'a, 'b> {
-            version: GlweCiphertext64Version,
-            inner: &'a ImplGlweCiphertext<&'b [u64]>,
+        struct SerializableGgswSeededCiphertext64<'a> {
+            version: GgswSeededCiphertext64Version,
+            inner: &'a ImplStandardGgswSeededCiphertext<Vec<u64>>,
         }
-        let serializable = SerializableGlweCiphertextView64 {
-            version: GlweCiphertext64Version::V0,
+        let serializable = SerializableGgswSeededCiphertext64 {
+            version: GgswSeededCiphertext64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &GlweCiphertextView64<'b>) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &GgswSeededCiphertext64,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 32 bits integers. It serializes a GLWE ciphertext mut view entity.
-impl<'b> EntitySerializationEngine<GlweCiphertextMutView32<'b>, Vec<u8>>
-    for DefaultSerializationEngine
-{
+/// on 32 bits integers. It serializes a GLWE ciphertext entity.
+impl EntitySerializationEngine<GlweCiphertext32, Vec<u8>> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
@@ -833,15 +1001,11 @@ impl<'b> EntitySerializationEngine<GlweCiphertextMutView32<'b>, Vec<u8>>
     /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
     ///
     /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
-    /// let mut raw_buffer = engine.consume_retrieve_glwe_ciphertext(ciphertext)?;
-    /// let view: GlweCiphertextMutView32 =
-    ///     engine.create_glwe_ciphertext_from(raw_buffer.as_mut_slice(), polynomial_size)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&view)?;
-    /// let recovered: GlweCiphertext32 = serialization_engine.deserialize(serialized.as_slice())?;
-    /// let recovered_buffer = engine.consume_retrieve_glwe_ciphertext(recovered)?;
-    /// assert_eq!(raw_buffer, recovered_buffer);
+    /// let serialized = serialization_engine.serialize(&ciphertext)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
     ///
     /// #
     /// # Ok(())
@@ -849,33 +1013,49 @@ impl<'b> EntitySerializationEngine<GlweCiphertextMutView32<'b>, Vec<u8>>
     /// ```
     fn serialize(
         &mut self,
-        entity: &GlweCiphertextMutView32<'b>,
+        entity: &GlweCiphertext32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &GlweCiphertext32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &GlweCiphertext32,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableGlweCiphertextMutView32<'a, 'b> {
+        struct SerializableGlweCiphertext32<'a> {
             version: GlweCiphertext32Version,
-            inner: &'a ImplGlweCiphertext<&'b mut [u32]>,
+            inner: &'a ImplGlweCiphertext<Vec<u32>>,
         }
-        let serializable = SerializableGlweCiphertextMutView32 {
+        let serializable = SerializableGlweCiphertext32 {
             version: GlweCiphertext32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &GlweCiphertextMutView32<'b>) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &GlweCiphertext32,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 64 bits integers. It serializes a GLWE ciphertext mut view entity.
-impl<'b> EntitySerializationEngine<GlweCiphertextMutView64<'b>, Vec<u8>>
-    for DefaultSerializationEngine
-{
+/// on 64 bits integers. It serializes a GLWE ciphertext entity.
+impl EntitySerializationEngine<GlweCiphertext64, Vec<u8>> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
@@ -899,17 +1079,13 @@ impl<'b> EntitySerializationEngine<GlweCiphertextMutView64<'b>, Vec<u8>>
     /// let key: GlweSecretKey64 =
     ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
     /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
-    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
     ///
-    /// let mut raw_buffer = engine.consume_retrieve_glwe_ciphertext(ciphertext)?;
-    /// let view: GlweCiphertextMutView64 =
-    ///     engine.create_glwe_ciphertext_from(raw_buffer.as_mut_slice(), polynomial_size)?;
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&view)?;
-    /// let recovered: GlweCiphertext64 = serialization_engine.deserialize(serialized.as_slice())?;
-    /// let recovered_buffer = engine.consume_retrieve_glwe_ciphertext(recovered)?;
-    /// assert_eq!(raw_buffer, recovered_buffer);
+    /// let serialized = serialization_engine.serialize(&ciphertext)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
     ///
     /// #
     /// # Ok(())
@@ -917,35 +1093,52 @@ impl<'b> EntitySerializationEngine<GlweCiphertextMutView64<'b>, Vec<u8>>
     /// ```
     fn serialize(
         &mut self,
-        entity: &GlweCiphertextMutView64<'b>,
+        entity: &GlweCiphertext64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &GlweCiphertext64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &GlweCiphertext64,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableGlweCiphertextMutView64<'a, 'b> {
+        struct SerializableGlweCiphertext64<'a> {
             version: GlweCiphertext64Version,
-            inner: &'a ImplGlweCiphertext<&'b mut [u64]>,
+            inner: &'a ImplGlweCiphertext<Vec<u64>>,
         }
-        let serializable = SerializableGlweCiphertextMutView64 {
+        let serializable = SerializableGlweCiphertext64 {
             version: GlweCiphertext64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &GlweCiphertextMutView64<'b>) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &GlweCiphertext64,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 32 bits integers. It serializes a GLWE ciphertext vector entity.
-impl EntitySerializationEngine<GlweCiphertextVector32, Vec<u8>> for DefaultSerializationEngine {
+/// on 32 bits integers. It serializes a trivial GLWE ciphertext entity.
+impl EntitySerializationEngine<TrivialGlweCiphertext32, Vec<u8>> for DefaultSerializationEngine {
     /// # Example:
     /// ```
-    /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
@@ -954,24 +1147,20 @@ impl EntitySerializationEngine<GlweCiphertextVector32, Vec<u8>> for DefaultSeria
     /// let glwe_dimension = GlweDimension(2);
     /// let polynomial_size = PolynomialSize(4);
     /// // Here a hard-set encoding is applied (shift by 20 bits)
-    /// let input = vec![3_u32 << 20; 8];
-    /// let noise = Variance(2_f64.powf(-25.));
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: GlweSecretKey32 =
-    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
-    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
-    ///
-    /// let ciphertext_vector =
-    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext: TrivialGlweCiphertext32 = engine
+    ///     .create_trivial_glwe_ciphertext(glwe_dimension.to_glwe_size(), &plaintext_vector)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&ciphertext_vector)?;
+    /// let serialized = serialization_engine.serialize(&ciphertext)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(ciphertext_vector, recovered);
+    /// assert_eq!(ciphertext, recovered);
     ///
     /// #
     /// # Ok(())
@@ -979,35 +1168,52 @@ impl EntitySerializationEngine<GlweCiphertextVector32, Vec<u8>> for DefaultSeria
     /// ```
     fn serialize(
         &mut self,
-        entity: &GlweCiphertextVector32,
+        entity: &TrivialGlweCiphertext32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &TrivialGlweCiphertext32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &TrivialGlweCiphertext32,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableGlweCiphertextVector32<'a> {
-            version: GlweCiphertextVector32Version,
-            inner: &'a ImplGlweList<Vec<u32>>,
+        struct SerializableTrivialGlweCiphertext32<'a> {
+            version: TrivialGlweCiphertext32Version,
+            inner: &'a ImplGlweCiphertext<Vec<u32>>,
         }
-        let serializable = SerializableGlweCiphertextVector32 {
-            version: GlweCiphertextVector32Version::V0,
+        let serializable = SerializableTrivialGlweCiphertext32 {
+            version: TrivialGlweCiphertext32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &GlweCiphertextVector32) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &TrivialGlweCiphertext32,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 64 bits integers. It serializes a GLWE ciphertext vector entity.
-impl EntitySerializationEngine<GlweCiphertextVector64, Vec<u8>> for DefaultSerializationEngine {
+/// on 64 bits integers. It serializes a trivial GLWE ciphertext entity.
+impl EntitySerializationEngine<TrivialGlweCiphertext64, Vec<u8>> for DefaultSerializationEngine {
     /// # Example:
     /// ```
-    /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
@@ -1015,25 +1221,21 @@ impl EntitySerializationEngine<GlweCiphertextVector64, Vec<u8>> for DefaultSeria
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
     /// let glwe_dimension = GlweDimension(2);
     /// let polynomial_size = PolynomialSize(4);
-    /// // Here a hard-set encoding is applied (shift by 50 bits)
-    /// let input = vec![3_u64 << 50; 8];
-    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u64 << 20; polynomial_size.0];
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: GlweSecretKey64 =
-    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
-    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
-    ///
-    /// let ciphertext_vector =
-    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext: TrivialGlweCiphertext64 = engine
+    ///     .create_trivial_glwe_ciphertext(glwe_dimension.to_glwe_size(), &plaintext_vector)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&ciphertext_vector)?;
+    /// let serialized = serialization_engine.serialize(&ciphertext)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(ciphertext_vector, recovered);
+    /// assert_eq!(ciphertext, recovered);
     ///
     /// #
     /// # Ok(())
@@ -1041,33 +1243,54 @@ impl EntitySerializationEngine<GlweCiphertextVector64, Vec<u8>> for DefaultSeria
     /// ```
     fn serialize(
         &mut self,
-        entity: &GlweCiphertextVector64,
+        entity: &TrivialGlweCiphertext64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &TrivialGlweCiphertext64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &TrivialGlweCiphertext64,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableGlweCiphertextVector64<'a> {
-            version: GlweCiphertextVector64Version,
-            inner: &'a ImplGlweList<Vec<u64>>,
+        struct SerializableTrivialGlweCiphertext64<'a> {
+            version: TrivialGlweCiphertext64Version,
+            inner: &'a ImplGlweCiphertext<Vec<u64>>,
         }
-        let serializable = SerializableGlweCiphertextVector64 {
-            version: GlweCiphertextVector64Version::V0,
+        let serializable = SerializableTrivialGlweCiphertext64 {
+            version: TrivialGlweCiphertext64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &GlweCiphertextVector64) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &TrivialGlweCiphertext64,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 32 bits integers. It serializes a GLWE secret key entity.
-impl EntitySerializationEngine<GlweSecretKey32, Vec<u8>> for DefaultSerializationEngine {
+/// on 32 bits integers. It serializes a GLWE ciphertext view entity.
+impl<'b> EntitySerializationEngine<GlweCiphertextView32<'b>, Vec<u8>>
+    for DefaultSerializationEngine
+{
     /// # Example:
     /// ```
+    /// use concrete_commons::dispersion::Variance;
     /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
@@ -1076,18 +1299,29 @@ impl EntitySerializationEngine<GlweSecretKey32, Vec<u8>> for DefaultSerializatio
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
     /// let glwe_dimension = GlweDimension(2);
     /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let glwe_secret_key: GlweSecretKey32 =
+    /// let key: GlweSecretKey32 =
     ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// let raw_buffer = engine.consume_retrieve_glwe_ciphertext(ciphertext)?;
+    /// let view: GlweCiphertextView32 =
+    ///     engine.create_glwe_ciphertext_from(raw_buffer.as_slice(), polynomial_size)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&glwe_secret_key)?;
-    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(glwe_secret_key, recovered);
+    /// let serialized = serialization_engine.serialize(&view)?;
+    /// let recovered: GlweCiphertext32 = serialization_engine.deserialize(serialized.as_slice())?;
+    /// let recovered_buffer = engine.consume_retrieve_glwe_ciphertext(recovered)?;
+    /// assert_eq!(raw_buffer, recovered_buffer);
     ///
     /// #
     /// # Ok(())
@@ -1095,33 +1329,54 @@ impl EntitySerializationEngine<GlweSecretKey32, Vec<u8>> for DefaultSerializatio
     /// ```
     fn serialize(
         &mut self,
-        entity: &GlweSecretKey32,
+        entity: &GlweCiphertextView32<'b>,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &GlweCiphertextView32<'b>) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &GlweCiphertextView32<'b>,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableGlweSecretKey32<'a> {
-            version: GlweSecretKey32Version,
-            inner: &'a ImplGlweSecretKey<BinaryKeyKind, Vec<u32>>,
+        struct SerializableGlweCiphertextView32<'a, 'b> {
+            version: GlweCiphertext32Version,
+            inner: &'a ImplGlweCiphertext<&'b [u32]>,
         }
-        let serializable = SerializableGlweSecretKey32 {
-            version: GlweSecretKey32Version::V0,
+        let serializable = SerializableGlweCiphertextView32 {
+            version: GlweCiphertext32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &GlweSecretKey32) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &GlweCiphertextView32<'b>,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 64 bits integers. It serializes a GLWE secret key entity.
-impl EntitySerializationEngine<GlweSecretKey64, Vec<u8>> for DefaultSerializationEngine {
+/// on 64 bits integers. It serializes a GLWE ciphertext view entity.
+impl<'b> EntitySerializationEngine<GlweCiphertextView64<'b>, Vec<u8>>
+    for DefaultSerializationEngine
+{
     /// # Example:
     /// ```
+    /// use concrete_commons::dispersion::Variance;
     /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
@@ -1130,18 +1385,29 @@ impl EntitySerializationEngine<GlweSecretKey64, Vec<u8>> for DefaultSerializatio
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
     /// let glwe_dimension = GlweDimension(2);
     /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let glwe_secret_key: GlweSecretKey64 =
+    /// let key: GlweSecretKey64 =
     ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let raw_buffer = engine.consume_retrieve_glwe_ciphertext(ciphertext)?;
+    /// let view: GlweCiphertextView64 =
+    ///     engine.create_glwe_ciphertext_from(raw_buffer.as_slice(), polynomial_size)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&glwe_secret_key)?;
-    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(glwe_secret_key, recovered);
+    /// let serialized = serialization_engine.serialize(&view)?;
+    /// let recovered: GlweCiphertext64 = serialization_engine.deserialize(serialized.as_slice())?;
+    /// let recovered_buffer = engine.consume_retrieve_glwe_ciphertext(recovered)?;
+    /// assert_eq!(raw_buffer, recovered_buffer);
     ///
     /// #
     /// # Ok(())
@@ -1149,31 +1415,51 @@ impl EntitySerializationEngine<GlweSecretKey64, Vec<u8>> for DefaultSerializatio
     /// ```
     fn serialize(
         &mut self,
-        entity: &GlweSecretKey64,
+        entity: &GlweCiphertextView64<'b>,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &GlweCiphertextView64<'b>) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &GlweCiphertextView64<'b>,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableGlweSecretKey64<'a> {
-            version: GlweSecretKey64Version,
-            inner: &'a ImplGlweSecretKey<BinaryKeyKind, Vec<u64>>,
+        struct SerializableGlweCiphertextView64<'a, 'b> {
+            version: GlweCiphertext64Version,
+            inner: &'a ImplGlweCiphertext<&'b [u64]>,
         }
-        let serializable = SerializableGlweSecretKey64 {
-            version: GlweSecretKey64Version::V0,
+        let serializable = SerializableGlweCiphertextView64 {
+            version: GlweCiphertext64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &GlweSecretKey64) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &GlweCiphertextView64<'b>,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 32 bits integers. It serializes a seeded GLWE ciphertext entity.
-impl EntitySerializationEngine<GlweSeededCiphertext32, Vec<u8>> for DefaultSerializationEngine {
+/// on 32 bits integers. It serializes a GLWE ciphertext mut view entity.
+impl<'b> EntitySerializationEngine<GlweCiphertextMutView32<'b>, Vec<u8>>
+    for DefaultSerializationEngine
+{
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
@@ -1198,13 +1484,16 @@ impl EntitySerializationEngine<GlweSeededCiphertext32, Vec<u8>> for DefaultSeria
     ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
     /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
     ///
-    /// let seeded_ciphertext =
-    ///     engine.encrypt_glwe_seeded_ciphertext(&key, &plaintext_vector, noise)?;
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// let mut raw_buffer = engine.consume_retrieve_glwe_ciphertext(ciphertext)?;
+    /// let view: GlweCiphertextMutView32 =
+    ///     engine.create_glwe_ciphertext_from(raw_buffer.as_mut_slice(), polynomial_size)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&seeded_ciphertext)?;
-    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(seeded_ciphertext, recovered);
+    /// let serialized = serialization_engine.serialize(&view)?;
+    /// let recovered: GlweCiphertext32 = serialization_engine.deserialize(serialized.as_slice())?;
+    /// let recovered_buffer = engine.consume_retrieve_glwe_ciphertext(recovered)?;
+    /// assert_eq!(raw_buffer, recovered_buffer);
     ///
     /// #
     /// # Ok(())
@@ -1212,31 +1501,51 @@ impl EntitySerializationEngine<GlweSeededCiphertext32, Vec<u8>> for DefaultSeria
     /// ```
     fn serialize(
         &mut self,
-        entity: &GlweSeededCiphertext32,
+        entity: &GlweCiphertextMutView32<'b>,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &GlweCiphertextMutView32<'b>) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &GlweCiphertextMutView32<'b>,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableGlweSeededCiphertext32<'a> {
-            version: GlweSeededCiphertext32Version,
-            inner: &'a ImplGlweSeededCiphertext<Vec<u32>>,
+        struct SerializableGlweCiphertextMutView32<'a, 'b> {
+            version: GlweCiphertext32Version,
+            inner: &'a ImplGlweCiphertext<&'b mut [u32]>,
         }
-        let serializable = SerializableGlweSeededCiphertext32 {
-            version: GlweSeededCiphertext32Version::V0,
+        let serializable = SerializableGlweCiphertextMutView32 {
+            version: GlweCiphertext32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &GlweSeededCiphertext32) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &GlweCiphertextMutView32<'b>,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 64 bits integers. It serializes a seeded GLWE ciphertext entity.
-impl EntitySerializationEngine<GlweSeededCiphertext64, Vec<u8>> for DefaultSerializationEngine {
+/// on 64 bits integers. It serializes a GLWE ciphertext mut view entity.
+impl<'b> EntitySerializationEngine<GlweCiphertextMutView64<'b>, Vec<u8>>
+    for DefaultSerializationEngine
+{
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
@@ -1260,14 +1569,17 @@ impl EntitySerializationEngine<GlweSeededCiphertext64, Vec<u8>> for DefaultSeria
     /// let key: GlweSecretKey64 =
     ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
     /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
     ///
-    /// let seeded_ciphertext =
-    ///     engine.encrypt_glwe_seeded_ciphertext(&key, &plaintext_vector, noise)?;
+    /// let mut raw_buffer = engine.consume_retrieve_glwe_ciphertext(ciphertext)?;
+    /// let view: GlweCiphertextMutView64 =
+    ///     engine.create_glwe_ciphertext_from(raw_buffer.as_mut_slice(), polynomial_size)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&seeded_ciphertext)?;
-    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(seeded_ciphertext, recovered);
+    /// let serialized = serialization_engine.serialize(&view)?;
+    /// let recovered: GlweCiphertext64 = serialization_engine.deserialize(serialized.as_slice())?;
+    /// let recovered_buffer = engine.consume_retrieve_glwe_ciphertext(recovered)?;
+    /// assert_eq!(raw_buffer, recovered_buffer);
     ///
     /// #
     /// # Ok(())
@@ -1275,33 +1587,49 @@ impl EntitySerializationEngine<GlweSeededCiphertext64, Vec<u8>> for DefaultSeria
     /// ```
     fn serialize(
         &mut self,
-        entity: &GlweSeededCiphertext64,
+        entity: &GlweCiphertextMutView64<'b>,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &GlweCiphertextMutView64<'b>) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &GlweCiphertextMutView64<'b>,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableGlweSeededCiphertext64<'a> {
-            version: GlweSeededCiphertext64Version,
-            inner: &'a ImplGlweSeededCiphertext<Vec<u64>>,
+        struct SerializableGlweCiphertextMutView64<'a, 'b> {
+            version: GlweCiphertext64Version,
+            inner: &'a ImplGlweCiphertext<&'b mut [u64]>,
         }
-        let serializable = SerializableGlweSeededCiphertext64 {
-            version: GlweSeededCiphertext64Version::V0,
+        let serializable = SerializableGlweCiphertextMutView64 {
+            version: GlweCiphertext64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &GlweSeededCiphertext64) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &GlweCiphertextMutView64<'b>,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 32 bits integers. It serializes a seeded GLWE ciphertext vector entity.
-impl EntitySerializationEngine<GlweSeededCiphertextVector32, Vec<u8>>
-    for DefaultSerializationEngine
-{
+/// on 32 bits integers. It serializes a GLWE ciphertext vector entity.
+impl EntitySerializationEngine<GlweCiphertextVector32, Vec<u8>> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
@@ -1325,13 +1653,13 @@ impl EntitySerializationEngine<GlweSeededCiphertextVector32, Vec<u8>>
     ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
     /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
     ///
-    /// let seeded_ciphertext_vector =
-    ///     engine.encrypt_glwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&seeded_ciphertext_vector)?;
+    /// let serialized = serialization_engine.serialize(&ciphertext_vector)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(seeded_ciphertext_vector, recovered);
+    /// assert_eq!(ciphertext_vector, recovered);
     ///
     /// #
     /// # Ok(())
@@ -1339,33 +1667,49 @@ impl EntitySerializationEngine<GlweSeededCiphertextVector32, Vec<u8>>
     /// ```
     fn serialize(
         &mut self,
-        entity: &GlweSeededCiphertextVector32,
+        entity: &GlweCiphertextVector32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &GlweCiphertextVector32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &GlweCiphertextVector32,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableGlweSeededCiphertextVector32<'a> {
-            version: GlweSeededCiphertextVector32Version,
-            inner: &'a ImplGlweSeededList<Vec<u32>>,
+        struct SerializableGlweCiphertextVector32<'a> {
+            version: GlweCiphertextVector32Version,
+            inner: &'a ImplGlweList<Vec<u32>>,
         }
-        let serializable = SerializableGlweSeededCiphertextVector32 {
-            version: GlweSeededCiphertextVector32Version::V0,
+        let serializable = SerializableGlweCiphertextVector32 {
+            version: GlweCiphertextVector32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &GlweSeededCiphertextVector32) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &GlweCiphertextVector32,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 64 bits integers. It serializes a seeded GLWE ciphertext vector entity.
-impl EntitySerializationEngine<GlweSeededCiphertextVector64, Vec<u8>>
-    for DefaultSerializationEngine
-{
+/// on 64 bits integers. It serializes a GLWE ciphertext vector entity.
+impl EntitySerializationEngine<GlweCiphertextVector64, Vec<u8>> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
@@ -1389,13 +1733,13 @@ impl EntitySerializationEngine<GlweSeededCiphertextVector64, Vec<u8>>
     ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
     /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
     ///
-    /// let seeded_ciphertext_vector =
-    ///     engine.encrypt_glwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&seeded_ciphertext_vector)?;
+    /// let serialized = serialization_engine.serialize(&ciphertext_vector)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(seeded_ciphertext_vector, recovered);
+    /// assert_eq!(ciphertext_vector, recovered);
     ///
     /// #
     /// # Ok(())
@@ -1403,60 +1747,71 @@ impl EntitySerializationEngine<GlweSeededCiphertextVector64, Vec<u8>>
     /// ```
     fn serialize(
         &mut self,
-        entity: &GlweSeededCiphertextVector64,
+        entity: &GlweCiphertextVector64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &GlweCiphertextVector64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &GlweCiphertextVector64,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableGlweSeededCiphertextVector64<'a> {
-            version: GlweSeededCiphertextVector64Version,
-            inner: &'a ImplGlweSeededList<Vec<u64>>,
+        struct SerializableGlweCiphertextVector64<'a> {
+            version: GlweCiphertextVector64Version,
+            inner: &'a ImplGlweList<Vec<u64>>,
         }
-        let serializable = SerializableGlweSeededCiphertextVector64 {
-            version: GlweSeededCiphertextVector64Version::V0,
+        let serializable = SerializableGlweCiphertextVector64 {
+            version: GlweCiphertextVector64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &GlweSeededCiphertextVector64) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &GlweCiphertextVector64,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 32 bits integers. It serializes a LWE bootstrap key entity.
-impl EntitySerializationEngine<LweBootstrapKey32, Vec<u8>> for DefaultSerializationEngine {
-    /// # Example
+/// on 32 bits integers. It serializes a GLWE secret key entity.
+impl EntitySerializationEngine<GlweSecretKey32, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
     /// ```
-    /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{
-    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
-    /// };
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
-    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
-    /// let noise = Variance(2_f64.powf(-25.));
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let lwe_sk: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dim)?;
-    /// let glwe_sk: GlweSecretKey32 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
-    ///
-    /// let bsk: LweBootstrapKey32 =
-    ///     engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let glwe_secret_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&bsk)?;
+    /// let serialized = serialization_engine.serialize(&glwe_secret_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(bsk, recovered);
+    /// assert_eq!(glwe_secret_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -1464,60 +1819,71 @@ impl EntitySerializationEngine<LweBootstrapKey32, Vec<u8>> for DefaultSerializat
     /// ```
     fn serialize(
         &mut self,
-        entity: &LweBootstrapKey32,
+        entity: &GlweSecretKey32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &GlweSecretKey32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &GlweSecretKey32,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableLweBootstrapKey32<'a> {
-            version: LweBootstrapKey32Version,
-            inner: &'a ImplStandardBootstrapKey<Vec<u32>>,
+        struct SerializableGlweSecretKey32<'a> {
+            version: GlweSecretKey32Version,
+            inner: &'a ImplGlweSecretKey<BinaryKeyKind, Vec<u32>>,
         }
-        let serializable = SerializableLweBootstrapKey32 {
-            version: LweBootstrapKey32Version::V0,
+        let serializable = SerializableGlweSecretKey32 {
+            version: GlweSecretKey32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweBootstrapKey32) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &GlweSecretKey32,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 64 bits integers. It serializes a LWE bootstrap key entity.
-impl EntitySerializationEngine<LweBootstrapKey64, Vec<u8>> for DefaultSerializationEngine {
-    /// # Example
+/// on 64 bits integers. It serializes a GLWE secret key entity.
+impl EntitySerializationEngine<GlweSecretKey64, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
     /// ```
-    /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{
-    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
-    /// };
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
-    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
-    /// let noise = Variance(2_f64.powf(-25.));
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let lwe_sk: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dim)?;
-    /// let glwe_sk: GlweSecretKey64 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
-    ///
-    /// let bsk: LweBootstrapKey64 =
-    ///     engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let glwe_secret_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&bsk)?;
+    /// let serialized = serialization_engine.serialize(&glwe_secret_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(bsk, recovered);
+    /// assert_eq!(glwe_secret_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -1525,58 +1891,71 @@ impl EntitySerializationEngine<LweBootstrapKey64, Vec<u8>> for DefaultSerializat
     /// ```
     fn serialize(
         &mut self,
-        entity: &LweBootstrapKey64,
+        entity: &GlweSecretKey64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &GlweSecretKey64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &GlweSecretKey64,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableLweBootstrapKey64<'a> {
-            version: LweBootstrapKey64Version,
-            inner: &'a ImplStandardBootstrapKey<Vec<u64>>,
+        struct SerializableGlweSecretKey64<'a> {
+            version: GlweSecretKey64Version,
+            inner: &'a ImplGlweSecretKey<BinaryKeyKind, Vec<u64>>,
         }
-        let serializable = SerializableLweBootstrapKey64 {
-            version: LweBootstrapKey64Version::V0,
+        let serializable = SerializableGlweSecretKey64 {
+            version: GlweSecretKey64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweBootstrapKey64) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &GlweSecretKey64,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 32 bits integers. It serializes a LWE ciphertext entity.
-impl EntitySerializationEngine<LweCiphertext32, Vec<u8>> for DefaultSerializationEngine {
+/// on 32 bits integers. It serializes a ternary GLWE secret key entity.
+impl EntitySerializationEngine<GlweSecretKeyTernary32, Vec<u8>> for DefaultSerializationEngine {
     /// # Example:
     /// ```
-    /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let lwe_dimension = LweDimension(2);
-    /// // Here a hard-set encoding is applied (shift by 20 bits)
-    /// let input = 3_u32 << 20;
-    /// let noise = Variance(2_f64.powf(-25.));
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
-    /// let plaintext = engine.create_plaintext_from(&input)?;
-    ///
-    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let glwe_secret_key: GlweSecretKeyTernary32 =
+    ///     engine.generate_new_glwe_secret_key_ternary(glwe_dimension, polynomial_size)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&ciphertext)?;
+    /// let serialized = serialization_engine.serialize(&glwe_secret_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(ciphertext, recovered);
+    /// assert_eq!(glwe_secret_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -1584,121 +1963,143 @@ impl EntitySerializationEngine<LweCiphertext32, Vec<u8>> for DefaultSerializatio
     /// ```
     fn serialize(
         &mut self,
-        entity: &LweCiphertext32,
+        entity: &GlweSecretKeyTernary32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &GlweSecretKeyTernary32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &GlweSecretKeyTernary32,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableLweCiphertext32<'a> {
-            version: LweCiphertext32Version,
-            inner: &'a ImplLweCiphertext<Vec<u32>>,
+        struct SerializableGlweSecretKey32<'a> {
+            version: GlweSecretKeyTernary32Version,
+            inner: &'a ImplGlweSecretKey<TernaryKeyKind, Vec<u32>>,
         }
-        let serializable = SerializableLweCiphertext32 {
-            version: LweCiphertext32Version::V0,
+        let serializable = SerializableGlweSecretKey32 {
+            version: GlweSecretKeyTernary32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertext32) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &GlweSecretKeyTernary32,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 64 bits integers. It serializes a LWE ciphertext entity.
-impl EntitySerializationEngine<LweCiphertext64, Vec<u8>> for DefaultSerializationEngine {
+/// on 64 bits integers. It serializes a ternary GLWE secret key entity.
+impl EntitySerializationEngine<GlweSecretKeyTernary64, Vec<u8>> for DefaultSerializationEngine {
     /// # Example:
     /// ```
-    /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let lwe_dimension = LweDimension(2);
-    /// // Here a hard-set encoding is applied (shift by 50 bits)
-    /// let input = 3_u64 << 50;
-    /// let noise = Variance(2_f64.powf(-25.));
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
-    /// let plaintext = engine.create_plaintext_from(&input)?;
-    ///
-    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let glwe_secret_key: GlweSecretKeyTernary64 =
+    ///     engine.generate_new_glwe_secret_key_ternary(glwe_dimension, polynomial_size)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&ciphertext)?;
+    /// let serialized = serialization_engine.serialize(&glwe_secret_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(ciphertext, recovered);
+    /// assert_eq!(glwe_secret_key, recovered);
     ///
     /// #
     /// # Ok(())
     /// # }
+    /// ```
     fn serialize(
         &mut self,
-        entity: &LweCiphertext64,
+        entity: &GlweSecretKeyTernary64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &GlweSecretKeyTernary64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &GlweSecretKeyTernary64,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableLweCiphertext64<'a> {
-            version: LweCiphertext64Version,
-            inner: &'a ImplLweCiphertext<Vec<u64>>,
+        struct SerializableGlweSecretKey64<'a> {
+            version: GlweSecretKeyTernary64Version,
+            inner: &'a ImplGlweSecretKey<TernaryKeyKind, Vec<u64>>,
         }
-        let serializable = SerializableLweCiphertext64 {
-            version: LweCiphertext64Version::V0,
+        let serializable = SerializableGlweSecretKey64 {
+            version: GlweSecretKeyTernary64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertext64) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &GlweSecretKeyTernary64,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 32 bits integers. It serializes a LWE ciphertext view entity.
-impl<'b> EntitySerializationEngine<LweCiphertextView32<'b>, Vec<u8>>
-    for DefaultSerializationEngine
-{
+/// on 32 bits integers. It serializes a gaussian GLWE secret key entity.
+impl EntitySerializationEngine<GlweSecretKeyGaussian32, Vec<u8>> for DefaultSerializationEngine {
     /// # Example:
     /// ```
-    /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let lwe_dimension = LweDimension(2);
-    /// // Here a hard-set encoding is applied (shift by 20 bits)
-    /// let input = 3_u32 << 20;
-    /// let noise = Variance(2_f64.powf(-25.));
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
-    /// let plaintext = engine.create_plaintext_from(&input)?;
-    ///
-    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
-    /// let raw_buffer = engine.consume_retrieve_lwe_ciphertext(ciphertext)?;
-    /// let view: LweCiphertextView32 = engine.create_lwe_ciphertext_from(raw_buffer.as_slice())?;
+    /// let glwe_secret_key: GlweSecretKeyGaussian32 =
+    ///     engine.generate_new_glwe_secret_key_gaussian(glwe_dimension, polynomial_size)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&view)?;
-    /// let recovered: LweCiphertext32 = serialization_engine.deserialize(serialized.as_slice())?;
-    /// let recovered_buffer = engine.consume_retrieve_lwe_ciphertext(recovered)?;
-    /// assert_eq!(raw_buffer, recovered_buffer);
+    /// let serialized = serialization_engine.serialize(&glwe_secret_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(glwe_secret_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -1706,128 +2107,147 @@ impl<'b> EntitySerializationEngine<LweCiphertextView32<'b>, Vec<u8>>
     /// ```
     fn serialize(
         &mut self,
-        entity: &LweCiphertextView32<'b>,
+        entity: &GlweSecretKeyGaussian32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &GlweSecretKeyGaussian32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &GlweSecretKeyGaussian32,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableLweCiphertextView32<'a, 'b> {
-            version: LweCiphertext32Version,
-            inner: &'a ImplLweCiphertext<&'b [u32]>,
+        struct SerializableGlweSecretKey32<'a> {
+            version: GlweSecretKeyGaussian32Version,
+            inner: &'a ImplGlweSecretKey<GaussianKeyKind, Vec<u32>>,
         }
-        let serializable = SerializableLweCiphertextView32 {
-            version: LweCiphertext32Version::V0,
+        let serializable = SerializableGlweSecretKey32 {
+            version: GlweSecretKeyGaussian32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextView32<'b>) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &GlweSecretKeyGaussian32,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 64 bits integers. It serializes a LWE ciphertext view entity.
-impl<'b> EntitySerializationEngine<LweCiphertextView64<'b>, Vec<u8>>
-    for DefaultSerializationEngine
-{
+/// on 64 bits integers. It serializes a gaussian GLWE secret key entity.
+impl EntitySerializationEngine<GlweSecretKeyGaussian64, Vec<u8>> for DefaultSerializationEngine {
     /// # Example:
     /// ```
-    /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let lwe_dimension = LweDimension(2);
-    /// // Here a hard-set encoding is applied (shift by 50 bits)
-    /// let input = 3_u64 << 50;
-    /// let noise = Variance(2_f64.powf(-25.));
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
-    /// let plaintext = engine.create_plaintext_from(&input)?;
-    ///
-    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
-    ///
-    /// let raw_buffer = engine.consume_retrieve_lwe_ciphertext(ciphertext)?;
-    /// let view: LweCiphertextView64 = engine.create_lwe_ciphertext_from(raw_buffer.as_slice())?;
+    /// let glwe_secret_key: GlweSecretKeyGaussian64 =
+    ///     engine.generate_new_glwe_secret_key_gaussian(glwe_dimension, polynomial_size)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&view)?;
-    /// let recovered: LweCiphertext64 = serialization_engine.deserialize(serialized.as_slice())?;
-    /// let recovered_buffer = engine.consume_retrieve_lwe_ciphertext(recovered)?;
-    /// assert_eq!(raw_buffer, recovered_buffer);
+    /// let serialized = serialization_engine.serialize(&glwe_secret_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(glwe_secret_key, recovered);
     ///
     /// #
     /// # Ok(())
     /// # }
+    /// ```
     fn serialize(
         &mut self,
-        entity: &LweCiphertextView64<'b>,
+        entity: &GlweSecretKeyGaussian64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &GlweSecretKeyGaussian64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &GlweSecretKeyGaussian64,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableLweCiphertextView64<'a, 'b> {
-            version: LweCiphertext64Version,
-            inner: &'a ImplLweCiphertext<&'b [u64]>,
+        struct SerializableGlweSecretKey64<'a> {
+            version: GlweSecretKeyGaussian64Version,
+            inner: &'a ImplGlweSecretKey<GaussianKeyKind, Vec<u64>>,
         }
-        let serializable = SerializableLweCiphertextView64 {
-            version: LweCiphertext64Version::V0,
+        let serializable = SerializableGlweSecretKey64 {
+            version: GlweSecretKeyGaussian64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextView64<'b>) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &GlweSecretKeyGaussian64,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 32 bits integers. It serializes a LWE ciphertext mut view entity.
-impl<'b> EntitySerializationEngine<LweCiphertextMutView32<'b>, Vec<u8>>
+/// on 32 bits integers. It serializes a GLWE tensor product secret key entity.
+impl EntitySerializationEngine<GlweTensorProductSecretKey32, Vec<u8>>
     for DefaultSerializationEngine
 {
     /// # Example:
     /// ```
-    /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let lwe_dimension = LweDimension(2);
-    /// // Here a hard-set encoding is applied (shift by 20 bits)
-    /// let input = 3_u32 << 20;
-    /// let noise = Variance(2_f64.powf(-25.));
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
-    /// let plaintext = engine.create_plaintext_from(&input)?;
-    ///
-    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
-    /// let mut raw_buffer = engine.consume_retrieve_lwe_ciphertext(ciphertext)?;
-    /// let view: LweCiphertextMutView32 =
-    ///     engine.create_lwe_ciphertext_from(raw_buffer.as_mut_slice())?;
+    /// let glwe_secret_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let tensor_product_key: GlweTensorProductSecretKey32 =
+    ///     engine.create_tensor_product_secret_key_same_key(&glwe_secret_key)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&view)?;
-    /// let recovered: LweCiphertext32 = serialization_engine.deserialize(serialized.as_slice())?;
-    /// let recovered_buffer = engine.consume_retrieve_lwe_ciphertext(recovered)?;
-    /// assert_eq!(raw_buffer, recovered_buffer);
+    /// let serialized = serialization_engine.serialize(&tensor_product_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(tensor_product_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -1835,123 +2255,164 @@ impl<'b> EntitySerializationEngine<LweCiphertextMutView32<'b>, Vec<u8>>
     /// ```
     fn serialize(
         &mut self,
-        entity: &LweCiphertextMutView32<'b>,
+        entity: &GlweTensorProductSecretKey32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &GlweTensorProductSecretKey32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &GlweTensorProductSecretKey32,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableLweCiphertextMutView32<'a, 'b> {
-            version: LweCiphertext32Version,
-            inner: &'a ImplLweCiphertext<&'b mut [u32]>,
+        struct SerializableGlweTensorProductSecretKey32<'a> {
+            version: GlweTensorProductSecretKey32Version,
+            inner: &'a ImplGlweTensorProductSecretKey<BinaryKeyKind, Vec<u32>>,
         }
-        let serializable = SerializableLweCiphertextMutView32 {
-            version: LweCiphertext32Version::V0,
+        let serializable = SerializableGlweTensorProductSecretKey32 {
+            version: GlweTensorProductSecretKey32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextMutView32<'b>) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &GlweTensorProductSecretKey32,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 64 bits integers. It serializes a LWE ciphertext mut view entity.
-impl<'b> EntitySerializationEngine<LweCiphertextMutView64<'b>, Vec<u8>>
+/// on 64 bits integers. It serializes a GLWE tensor product secret key entity.
+impl EntitySerializationEngine<GlweTensorProductSecretKey64, Vec<u8>>
     for DefaultSerializationEngine
 {
     /// # Example:
     /// ```
-    /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let lwe_dimension = LweDimension(2);
-    /// // Here a hard-set encoding is applied (shift by 50 bits)
-    /// let input = 3_u64 << 50;
-    /// let noise = Variance(2_f64.powf(-25.));
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
-    /// let plaintext = engine.create_plaintext_from(&input)?;
-    ///
-    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
-    ///
-    /// let mut raw_buffer = engine.consume_retrieve_lwe_ciphertext(ciphertext)?;
-    /// let view: LweCiphertextMutView64 = engine.create_lwe_ciphertext_from(raw_buffer.as_mut_slice())?;
+    /// let glwe_secret_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let tensor_product_key: GlweTensorProductSecretKey64 =
+    ///     engine.create_tensor_product_secret_key_same_key(&glwe_secret_key)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&view)?;
-    /// let recovered: LweCiphertext64 = serialization_engine.deserialize(serialized.as_slice())?;
-    /// let recovered_buffer = engine.consume_retrieve_lwe_ciphertext(recovered)?;
-    /// assert_eq!(raw_buffer, recovered_buffer);
+    /// let serialized = serialization_engine.serialize(&tensor_product_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(tensor_product_key, recovered);
     ///
     /// #
     /// # Ok(())
     /// # }
+    /// ```
     fn serialize(
         &mut self,
-        entity: &LweCiphertextMutView64<'b>,
+        entity: &GlweTensorProductSecretKey64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &GlweTensorProductSecretKey64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &GlweTensorProductSecretKey64,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableLweCiphertextMutView64<'a, 'b> {
-            version: LweCiphertext64Version,
-            inner: &'a ImplLweCiphertext<&'b mut [u64]>,
+        struct SerializableGlweTensorProductSecretKey64<'a> {
+            version: GlweTensorProductSecretKey64Version,
+            inner: &'a ImplGlweTensorProductSecretKey<BinaryKeyKind, Vec<u64>>,
         }
-        let serializable = SerializableLweCiphertextMutView64 {
-            version: LweCiphertext64Version::V0,
+        let serializable = SerializableGlweTensorProductSecretKey64 {
+            version: GlweTensorProductSecretKey64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextMutView64<'b>) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &GlweTensorProductSecretKey64,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 32 bits integers. It serializes a LWE ciphertext vector entity.
-impl EntitySerializationEngine<LweCiphertextVector32, Vec<u8>> for DefaultSerializationEngine {
+/// on 32 bits integers. It serializes a GLWE ring reduction key entity.
+impl EntitySerializationEngine<GlweRingReductionKey32, Vec<u8>> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let lwe_dimension = LweDimension(6);
-    /// // Here a hard-set encoding is applied (shift by 20 bits)
-    /// let input = vec![3_u32 << 20; 3];
+    /// let input_glwe_dimension = GlweDimension(1);
+    /// let input_polynomial_size = PolynomialSize(1024);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let output_polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(5);
     /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
-    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input)?;
-    ///
-    /// let mut ciphertext_vector: LweCiphertextVector32 =
-    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let input_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(input_glwe_dimension, input_polynomial_size)?;
+    /// let output_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, output_polynomial_size)?;
+    /// let ring_reduction_key: GlweRingReductionKey32 = engine.generate_new_glwe_ring_reduction_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&ciphertext_vector)?;
+    /// let serialized = serialization_engine.serialize(&ring_reduction_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(ciphertext_vector, recovered);
+    /// assert_eq!(ring_reduction_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -1959,59 +2420,88 @@ impl EntitySerializationEngine<LweCiphertextVector32, Vec<u8>> for DefaultSerial
     /// ```
     fn serialize(
         &mut self,
-        entity: &LweCiphertextVector32,
+        entity: &GlweRingReductionKey32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &GlweRingReductionKey32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &GlweRingReductionKey32,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableLweCiphertextVector32<'a> {
-            version: LweCiphertextVector32Version,
-            inner: &'a ImplLweList<Vec<u32>>,
+        struct SerializableGlweRingReductionKey32<'a> {
+            version: GlweRingReductionKey32Version,
+            inner: &'a ImplGlweRingReductionKey<Vec<u32>>,
         }
-        let serializable = SerializableLweCiphertextVector32 {
-            version: LweCiphertextVector32Version::V0,
+        let serializable = SerializableGlweRingReductionKey32 {
+            version: GlweRingReductionKey32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextVector32) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &GlweRingReductionKey32,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 64 bits integers. It serializes a LWE ciphertext vector entity.
-impl EntitySerializationEngine<LweCiphertextVector64, Vec<u8>> for DefaultSerializationEngine {
+/// on 64 bits integers. It serializes a GLWE ring reduction key entity.
+impl EntitySerializationEngine<GlweRingReductionKey64, Vec<u8>> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let lwe_dimension = LweDimension(6);
-    /// // Here a hard-set encoding is applied (shift by 50 bits)
-    /// let input = vec![3_u64 << 50; 3];
+    /// let input_glwe_dimension = GlweDimension(1);
+    /// let input_polynomial_size = PolynomialSize(1024);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let output_polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(5);
     /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
-    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input)?;
-    ///
-    /// let mut ciphertext_vector: LweCiphertextVector64 =
-    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let input_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(input_glwe_dimension, input_polynomial_size)?;
+    /// let output_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, output_polynomial_size)?;
+    /// let ring_reduction_key: GlweRingReductionKey64 = engine.generate_new_glwe_ring_reduction_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&ciphertext_vector)?;
+    /// let serialized = serialization_engine.serialize(&ring_reduction_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(ciphertext_vector, recovered);
+    /// assert_eq!(ring_reduction_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -2019,67 +2509,85 @@ impl EntitySerializationEngine<LweCiphertextVector64, Vec<u8>> for DefaultSerial
     /// ```
     fn serialize(
         &mut self,
-        entity: &LweCiphertextVector64,
+        entity: &GlweRingReductionKey64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &GlweRingReductionKey64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &GlweRingReductionKey64,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableLweCiphertextVector64<'a> {
-            version: LweCiphertextVector64Version,
-            inner: &'a ImplLweList<Vec<u64>>,
+        struct SerializableGlweRingReductionKey64<'a> {
+            version: GlweRingReductionKey64Version,
+            inner: &'a ImplGlweRingReductionKey<Vec<u64>>,
         }
-        let serializable = SerializableLweCiphertextVector64 {
-            version: LweCiphertextVector64Version::V0,
+        let serializable = SerializableGlweRingReductionKey64 {
+            version: GlweRingReductionKey64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextVector64) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &GlweRingReductionKey64,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 32 bits integers. It serializes a LWE ciphertext vector view entity. Immutable variant.
-impl<'b> EntitySerializationEngine<LweCiphertextVectorView32<'b>, Vec<u8>>
-    for DefaultSerializationEngine
-{
+/// on 32 bits integers. It serializes a GLWE ring switching key entity.
+impl EntitySerializationEngine<GlweRingSwitchingKey32, Vec<u8>> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let lwe_dimension = LweDimension(6);
-    /// let lwe_count = LweCiphertextCount(3);
-    /// // Here a hard-set encoding is applied (shift by 20 bits)
-    /// let input = vec![3_u32 << 20; lwe_count.0];
-    /// let noise = Variance(2_f64.powf(-50.));
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(5);
+    /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
-    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input)?;
-    ///
-    /// let mut ciphertext_vector: LweCiphertextVector32 =
-    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
-    /// let raw_buffer = engine.consume_retrieve_lwe_ciphertext_vector(ciphertext_vector)?;
-    /// let view: LweCiphertextVectorView32 = engine
-    ///     .create_lwe_ciphertext_vector_from(raw_buffer.as_slice(), lwe_dimension.to_lwe_size())?;
+    /// let output_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let input_key: GlweSecretKey32 = engine.split_glwe_secret_key_ring(&output_key)?;
+    /// let ring_switching_key: GlweRingSwitchingKey32 = engine.generate_new_glwe_ring_switching_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&view)?;
-    /// let recovered: LweCiphertextVector32 =
-    ///     serialization_engine.deserialize(serialized.as_slice())?;
-    /// let recovered_buffer = engine.consume_retrieve_lwe_ciphertext_vector(recovered)?;
-    /// assert_eq!(raw_buffer, recovered_buffer);
+    /// let serialized = serialization_engine.serialize(&ring_switching_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(ring_switching_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -2087,68 +2595,85 @@ impl<'b> EntitySerializationEngine<LweCiphertextVectorView32<'b>, Vec<u8>>
     /// ```
     fn serialize(
         &mut self,
-        entity: &LweCiphertextVectorView32<'b>,
+        entity: &GlweRingSwitchingKey32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &GlweRingSwitchingKey32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &GlweRingSwitchingKey32,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableLweCiphertextVectorView32<'a, 'b> {
-            version: LweCiphertextVector32Version,
-            inner: &'a ImplLweList<&'b [u32]>,
+        struct SerializableGlweRingSwitchingKey32<'a> {
+            version: GlweRingSwitchingKey32Version,
+            inner: &'a ImplGlweRingSwitchingKey<Vec<u32>>,
         }
-
-        let serializable = SerializableLweCiphertextVectorView32 {
-            version: LweCiphertextVector32Version::V0,
+        let serializable = SerializableGlweRingSwitchingKey32 {
+            version: GlweRingSwitchingKey32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextVectorView32<'b>) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &GlweRingSwitchingKey32,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 64 bits integers. It serializes a LWE ciphertext vector view entity. Immutable variant.
-impl<'b> EntitySerializationEngine<LweCiphertextVectorView64<'b>, Vec<u8>>
-    for DefaultSerializationEngine
-{
+/// on 64 bits integers. It serializes a GLWE ring switching key entity.
+impl EntitySerializationEngine<GlweRingSwitchingKey64, Vec<u8>> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let lwe_dimension = LweDimension(6);
-    /// let lwe_count = LweCiphertextCount(3);
-    /// // Here a hard-set encoding is applied (shift by 50 bits)
-    /// let input = vec![3_u64 << 50; lwe_count.0];
-    /// let noise = Variance(2_f64.powf(-50.));
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(5);
+    /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
-    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input)?;
-    ///
-    /// let mut ciphertext_vector: LweCiphertextVector64 =
-    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
-    /// let raw_buffer = engine.consume_retrieve_lwe_ciphertext_vector(ciphertext_vector)?;
-    /// let view: LweCiphertextVectorView64 = engine
-    ///     .create_lwe_ciphertext_vector_from(raw_buffer.as_slice(), lwe_dimension.to_lwe_size())?;
+    /// let output_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let input_key: GlweSecretKey64 = engine.split_glwe_secret_key_ring(&output_key)?;
+    /// let ring_switching_key: GlweRingSwitchingKey64 = engine.generate_new_glwe_ring_switching_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&view)?;
-    /// let recovered: LweCiphertextVector64 =
-    ///     serialization_engine.deserialize(serialized.as_slice())?;
-    /// let recovered_buffer = engine.consume_retrieve_lwe_ciphertext_vector(recovered)?;
-    /// assert_eq!(raw_buffer, recovered_buffer);
+    /// let serialized = serialization_engine.serialize(&ring_switching_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(ring_switching_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -2156,70 +2681,84 @@ impl<'b> EntitySerializationEngine<LweCiphertextVectorView64<'b>, Vec<u8>>
     /// ```
     fn serialize(
         &mut self,
-        entity: &LweCiphertextVectorView64<'b>,
+        entity: &GlweRingSwitchingKey64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &GlweRingSwitchingKey64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &GlweRingSwitchingKey64,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableLweCiphertextVectorView64<'a, 'b> {
-            version: LweCiphertextVector64Version,
-            inner: &'a ImplLweList<&'b [u64]>,
+        struct SerializableGlweRingSwitchingKey64<'a> {
+            version: GlweRingSwitchingKey64Version,
+            inner: &'a ImplGlweRingSwitchingKey<Vec<u64>>,
         }
-
-        let serializable = SerializableLweCiphertextVectorView64 {
-            version: LweCiphertextVector64Version::V0,
+        let serializable = SerializableGlweRingSwitchingKey64 {
+            version: GlweRingSwitchingKey64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextVectorView64<'b>) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &GlweRingSwitchingKey64,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 32 bits integers. It serializes a LWE ciphertext vector view entity. Mutable variant.
-impl<'b> EntitySerializationEngine<LweCiphertextVectorMutView32<'b>, Vec<u8>>
-    for DefaultSerializationEngine
-{
+/// on 32 bits integers. It serializes a GLWE relinearization key entity.
+impl EntitySerializationEngine<GlweRelinearizationKey32, Vec<u8>> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let lwe_dimension = LweDimension(6);
-    /// let lwe_count = LweCiphertextCount(3);
-    /// // Here a hard-set encoding is applied (shift by 20 bits)
-    /// let input = vec![3_u32 << 20; lwe_count.0];
-    /// let noise = Variance(2_f64.powf(-50.));
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(5);
+    /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
-    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input)?;
-    ///
-    /// let mut ciphertext_vector: LweCiphertextVector32 =
-    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
-    /// let mut raw_buffer = engine.consume_retrieve_lwe_ciphertext_vector(ciphertext_vector)?;
-    /// let view: LweCiphertextVectorMutView32 = engine.create_lwe_ciphertext_vector_from(
-    ///     raw_buffer.as_mut_slice(),
-    ///     lwe_dimension.to_lwe_size(),
-    /// )?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let relinearization_key: GlweRelinearizationKey32 = engine
+    ///     .generate_new_glwe_relinearization_key(
+    ///         &key,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         noise,
+    ///     )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&view)?;
-    /// let recovered: LweCiphertextVector32 =
-    ///     serialization_engine.deserialize(serialized.as_slice())?;
-    /// let recovered_buffer = engine.consume_retrieve_lwe_ciphertext_vector(recovered)?;
-    /// assert_eq!(raw_buffer, recovered_buffer);
+    /// let serialized = serialization_engine.serialize(&relinearization_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(relinearization_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -2227,70 +2766,84 @@ impl<'b> EntitySerializationEngine<LweCiphertextVectorMutView32<'b>, Vec<u8>>
     /// ```
     fn serialize(
         &mut self,
-        entity: &LweCiphertextVectorMutView32<'b>,
+        entity: &GlweRelinearizationKey32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &GlweRelinearizationKey32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &GlweRelinearizationKey32,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableLweCiphertextVectorMutView32<'a, 'b> {
-            version: LweCiphertextVector32Version,
-            inner: &'a ImplLweList<&'b mut [u32]>,
+        struct SerializableGlweRelinearizationKey32<'a> {
+            version: GlweRelinearizationKey32Version,
+            inner: &'a ImplGlweRelinearizationKey<Vec<u32>>,
         }
-
-        let serializable = SerializableLweCiphertextVectorMutView32 {
-            version: LweCiphertextVector32Version::V0,
+        let serializable = SerializableGlweRelinearizationKey32 {
+            version: GlweRelinearizationKey32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextVectorMutView32<'b>) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &GlweRelinearizationKey32,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 64 bits integers. It serializes a LWE ciphertext vector view entity. Mutable variant.
-impl<'b> EntitySerializationEngine<LweCiphertextVectorMutView64<'b>, Vec<u8>>
-    for DefaultSerializationEngine
-{
+/// on 64 bits integers. It serializes a GLWE relinearization key entity.
+impl EntitySerializationEngine<GlweRelinearizationKey64, Vec<u8>> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let lwe_dimension = LweDimension(6);
-    /// let lwe_count = LweCiphertextCount(3);
-    /// // Here a hard-set encoding is applied (shift by 50 bits)
-    /// let input = vec![3_u64 << 50; lwe_count.0];
-    /// let noise = Variance(2_f64.powf(-50.));
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(5);
+    /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
-    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input)?;
-    ///
-    /// let mut ciphertext_vector: LweCiphertextVector64 =
-    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
-    /// let mut raw_buffer = engine.consume_retrieve_lwe_ciphertext_vector(ciphertext_vector)?;
-    /// let view: LweCiphertextVectorMutView64 = engine.create_lwe_ciphertext_vector_from(
-    ///     raw_buffer.as_mut_slice(),
-    ///     lwe_dimension.to_lwe_size(),
-    /// )?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let relinearization_key: GlweRelinearizationKey64 = engine
+    ///     .generate_new_glwe_relinearization_key(
+    ///         &key,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         noise,
+    ///     )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&view)?;
-    /// let recovered: LweCiphertextVector64 =
-    ///     serialization_engine.deserialize(serialized.as_slice())?;
-    /// let recovered_buffer = engine.consume_retrieve_lwe_ciphertext_vector(recovered)?;
-    /// assert_eq!(raw_buffer, recovered_buffer);
+    /// let serialized = serialization_engine.serialize(&relinearization_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(relinearization_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -2298,68 +2851,83 @@ impl<'b> EntitySerializationEngine<LweCiphertextVectorMutView64<'b>, Vec<u8>>
     /// ```
     fn serialize(
         &mut self,
-        entity: &LweCiphertextVectorMutView64<'b>,
+        entity: &GlweRelinearizationKey64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &GlweRelinearizationKey64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &GlweRelinearizationKey64,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableLweCiphertextVectorMutView64<'a, 'b> {
-            version: LweCiphertextVector64Version,
-            inner: &'a ImplLweList<&'b mut [u64]>,
+        struct SerializableGlweRelinearizationKey64<'a> {
+            version: GlweRelinearizationKey64Version,
+            inner: &'a ImplGlweRelinearizationKey<Vec<u64>>,
         }
-
-        let serializable = SerializableLweCiphertextVectorMutView64 {
-            version: LweCiphertextVector64Version::V0,
+        let serializable = SerializableGlweRelinearizationKey64 {
+            version: GlweRelinearizationKey64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextVectorMutView64<'b>) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &GlweRelinearizationKey64,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 32 bits integers. It serializes a LWE keyswitch key entity.
-impl EntitySerializationEngine<LweKeyswitchKey32, Vec<u8>> for DefaultSerializationEngine {
+/// on 32 bits integers. It serializes a GLWE automorphism keys entity.
+impl EntitySerializationEngine<GlweAutomorphismKeys32, Vec<u8>> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
     /// use concrete_commons::parameters::{
-    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
     /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let input_lwe_dimension = LweDimension(6);
-    /// let output_lwe_dimension = LweDimension(3);
-    /// let decomposition_level_count = DecompositionLevelCount(2);
-    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(5);
     /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
-    /// let output_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
-    ///
-    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
-    ///     &input_key,
-    ///     &output_key,
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let automorphism_keys: GlweAutomorphismKeys32 = engine.generate_new_glwe_automorphism_keys(
+    ///     &key,
     ///     decomposition_level_count,
     ///     decomposition_base_log,
     ///     noise,
     /// )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&keyswitch_key)?;
+    /// let serialized = serialization_engine.serialize(&automorphism_keys)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(keyswitch_key, recovered);
+    /// assert_eq!(automorphism_keys, recovered);
     ///
     /// #
     /// # Ok(())
@@ -2367,67 +2935,83 @@ impl EntitySerializationEngine<LweKeyswitchKey32, Vec<u8>> for DefaultSerializat
     /// ```
     fn serialize(
         &mut self,
-        entity: &LweKeyswitchKey32,
+        entity: &GlweAutomorphismKeys32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &GlweAutomorphismKeys32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &GlweAutomorphismKeys32,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableLweKeyswitchKey32<'a> {
-            version: LweKeyswitchKey32Version,
-            inner: &'a ImplLweKeyswitchKey<Vec<u32>>,
+        struct SerializableGlweAutomorphismKeys32<'a> {
+            version: GlweAutomorphismKeys32Version,
+            inner: &'a ImplGlweAutomorphismKeys<Vec<u32>>,
         }
-        let serializable = SerializableLweKeyswitchKey32 {
-            version: LweKeyswitchKey32Version::V0,
+        let serializable = SerializableGlweAutomorphismKeys32 {
+            version: GlweAutomorphismKeys32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweKeyswitchKey32) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &GlweAutomorphismKeys32,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 64 bits integers. It serializes a LWE keyswitch key entity.
-impl EntitySerializationEngine<LweKeyswitchKey64, Vec<u8>> for DefaultSerializationEngine {
+/// on 64 bits integers. It serializes a GLWE automorphism keys entity.
+impl EntitySerializationEngine<GlweAutomorphismKeys64, Vec<u8>> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
     /// use concrete_commons::parameters::{
-    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
     /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let input_lwe_dimension = LweDimension(6);
-    /// let output_lwe_dimension = LweDimension(3);
-    /// let decomposition_level_count = DecompositionLevelCount(2);
-    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(5);
     /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
-    /// let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
-    ///
-    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
-    ///     &input_key,
-    ///     &output_key,
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let automorphism_keys: GlweAutomorphismKeys64 = engine.generate_new_glwe_automorphism_keys(
+    ///     &key,
     ///     decomposition_level_count,
     ///     decomposition_base_log,
     ///     noise,
     /// )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&keyswitch_key)?;
+    /// let serialized = serialization_engine.serialize(&automorphism_keys)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(keyswitch_key, recovered);
+    /// assert_eq!(automorphism_keys, recovered);
     ///
     /// #
     /// # Ok(())
@@ -2435,31 +3019,2698 @@ impl EntitySerializationEngine<LweKeyswitchKey64, Vec<u8>> for DefaultSerializat
     /// ```
     fn serialize(
         &mut self,
-        entity: &LweKeyswitchKey64,
+        entity: &GlweAutomorphismKeys64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &GlweAutomorphismKeys64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &GlweAutomorphismKeys64,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableLweKeyswitchKey64<'a> {
-            version: LweKeyswitchKey64Version,
-            inner: &'a ImplLweKeyswitchKey<Vec<u64>>,
+        struct SerializableGlweAutomorphismKeys64<'a> {
+            version: GlweAutomorphismKeys64Version,
+            inner: &'a ImplGlweAutomorphismKeys<Vec<u64>>,
         }
-        let serializable = SerializableLweKeyswitchKey64 {
-            version: LweKeyswitchKey64Version::V0,
+        let serializable = SerializableGlweAutomorphismKeys64 {
+            version: GlweAutomorphismKeys64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweKeyswitchKey64) -> Vec<u8> {
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &GlweAutomorphismKeys64,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 32 bits integers. It serializes a seeded GLWE ciphertext entity.
+impl EntitySerializationEngine<GlweSeededCiphertext32, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let seeded_ciphertext =
+    ///     engine.encrypt_glwe_seeded_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&seeded_ciphertext)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(seeded_ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &GlweSeededCiphertext32,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &GlweSeededCiphertext32) -> Vec<u8> {
         self.serialize(entity).unwrap()
     }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &GlweSeededCiphertext32,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableGlweSeededCiphertext32<'a> {
+            version: GlweSeededCiphertext32Version,
+            inner: &'a ImplGlweSeededCiphertext<Vec<u32>>,
+        }
+        let serializable = SerializableGlweSeededCiphertext32 {
+            version: GlweSeededCiphertext32Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize_into(writer, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &GlweSeededCiphertext32,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 64 bits integers. It serializes a seeded GLWE ciphertext entity.
+impl EntitySerializationEngine<GlweSeededCiphertext64, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let seeded_ciphertext =
+    ///     engine.encrypt_glwe_seeded_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&seeded_ciphertext)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(seeded_ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &GlweSeededCiphertext64,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &GlweSeededCiphertext64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &GlweSeededCiphertext64,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableGlweSeededCiphertext64<'a> {
+            version: GlweSeededCiphertext64Version,
+            inner: &'a ImplGlweSeededCiphertext<Vec<u64>>,
+        }
+        let serializable = SerializableGlweSeededCiphertext64 {
+            version: GlweSeededCiphertext64Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize_into(writer, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &GlweSeededCiphertext64,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 32 bits integers. It serializes a seeded GLWE ciphertext vector entity.
+impl EntitySerializationEngine<GlweSeededCiphertextVector32, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let seeded_ciphertext_vector =
+    ///     engine.encrypt_glwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&seeded_ciphertext_vector)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(seeded_ciphertext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &GlweSeededCiphertextVector32,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &GlweSeededCiphertextVector32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &GlweSeededCiphertextVector32,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableGlweSeededCiphertextVector32<'a> {
+            version: GlweSeededCiphertextVector32Version,
+            inner: &'a ImplGlweSeededList<Vec<u32>>,
+        }
+        let serializable = SerializableGlweSeededCiphertextVector32 {
+            version: GlweSeededCiphertextVector32Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize_into(writer, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &GlweSeededCiphertextVector32,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 64 bits integers. It serializes a seeded GLWE ciphertext vector entity.
+impl EntitySerializationEngine<GlweSeededCiphertextVector64, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let seeded_ciphertext_vector =
+    ///     engine.encrypt_glwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&seeded_ciphertext_vector)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(seeded_ciphertext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &GlweSeededCiphertextVector64,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &GlweSeededCiphertextVector64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &GlweSeededCiphertextVector64,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableGlweSeededCiphertextVector64<'a> {
+            version: GlweSeededCiphertextVector64Version,
+            inner: &'a ImplGlweSeededList<Vec<u64>>,
+        }
+        let serializable = SerializableGlweSeededCiphertextVector64 {
+            version: GlweSeededCiphertextVector64Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize_into(writer, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &GlweSeededCiphertextVector64,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 32 bits integers. It serializes a Lev ciphertext entity.
+impl EntitySerializationEngine<LevCiphertext32, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// let input = 3_u32;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lev_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&ciphertext)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LevCiphertext32,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LevCiphertext32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &LevCiphertext32,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLevCiphertext32<'a> {
+            version: LevCiphertext32Version,
+            inner: &'a ImplLevCiphertext<Vec<u32>>,
+        }
+        let serializable = SerializableLevCiphertext32 {
+            version: LevCiphertext32Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize_into(writer, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &LevCiphertext32,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 64 bits integers. It serializes a Lev ciphertext entity.
+impl EntitySerializationEngine<LevCiphertext64, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// let input = 3_u64;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lev_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&ciphertext)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LevCiphertext64,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LevCiphertext64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &LevCiphertext64,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLevCiphertext64<'a> {
+            version: LevCiphertext64Version,
+            inner: &'a ImplLevCiphertext<Vec<u64>>,
+        }
+        let serializable = SerializableLevCiphertext64 {
+            version: LevCiphertext64Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize_into(writer, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &LevCiphertext64,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 32 bits integers. It serializes a LWE bootstrap key entity.
+impl EntitySerializationEngine<LweBootstrapKey32, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: LweBootstrapKey32 =
+    ///     engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&bsk)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(bsk, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweBootstrapKey32,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweBootstrapKey32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &LweBootstrapKey32,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweBootstrapKey32<'a> {
+            version: LweBootstrapKey32Version,
+            inner: &'a ImplStandardBootstrapKey<Vec<u32>>,
+        }
+        let serializable = SerializableLweBootstrapKey32 {
+            version: LweBootstrapKey32Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize_into(writer, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &LweBootstrapKey32,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 64 bits integers. It serializes a LWE bootstrap key entity.
+impl EntitySerializationEngine<LweBootstrapKey64, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: LweBootstrapKey64 =
+    ///     engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&bsk)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(bsk, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweBootstrapKey64,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweBootstrapKey64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &LweBootstrapKey64,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweBootstrapKey64<'a> {
+            version: LweBootstrapKey64Version,
+            inner: &'a ImplStandardBootstrapKey<Vec<u64>>,
+        }
+        let serializable = SerializableLweBootstrapKey64 {
+            version: LweBootstrapKey64Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize_into(writer, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &LweBootstrapKey64,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 16 bits integers. It serializes a LWE ciphertext entity.
+impl EntitySerializationEngine<LweCiphertext16, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 12 bits)
+    /// let input = 3_u16 << 12;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey16 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&ciphertext)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweCiphertext16,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertext16) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &LweCiphertext16,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweCiphertext16<'a> {
+            version: LweCiphertext16Version,
+            inner: &'a ImplLweCiphertext<Vec<u16>>,
+        }
+        let serializable = SerializableLweCiphertext16 {
+            version: LweCiphertext16Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize_into(writer, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &LweCiphertext16,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 32 bits integers. It serializes a LWE ciphertext entity.
+impl EntitySerializationEngine<LweCiphertext32, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&ciphertext)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweCiphertext32,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertext32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &LweCiphertext32,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweCiphertext32<'a> {
+            version: LweCiphertext32Version,
+            inner: &'a ImplLweCiphertext<Vec<u32>>,
+        }
+        let serializable = SerializableLweCiphertext32 {
+            version: LweCiphertext32Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize_into(writer, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &LweCiphertext32,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 64 bits integers. It serializes a LWE ciphertext entity.
+impl EntitySerializationEngine<LweCiphertext64, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&ciphertext)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    fn serialize(
+        &mut self,
+        entity: &LweCiphertext64,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertext64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &LweCiphertext64,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweCiphertext64<'a> {
+            version: LweCiphertext64Version,
+            inner: &'a ImplLweCiphertext<Vec<u64>>,
+        }
+        let serializable = SerializableLweCiphertext64 {
+            version: LweCiphertext64Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize_into(writer, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &LweCiphertext64,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 32 bits integers. It serializes a trivial LWE ciphertext entity.
+impl EntitySerializationEngine<TrivialLweCiphertext32, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweSize;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_size = LweSize(10);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let plaintext: Plaintext32 = engine.create_plaintext_from(&input)?;
+    /// let ciphertext: TrivialLweCiphertext32 =
+    ///     engine.create_trivial_lwe_ciphertext(lwe_size, &plaintext)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&ciphertext)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &TrivialLweCiphertext32,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &TrivialLweCiphertext32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &TrivialLweCiphertext32,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableTrivialLweCiphertext32<'a> {
+            version: TrivialLweCiphertext32Version,
+            inner: &'a ImplLweCiphertext<Vec<u32>>,
+        }
+        let serializable = SerializableTrivialLweCiphertext32 {
+            version: TrivialLweCiphertext32Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize_into(writer, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &TrivialLweCiphertext32,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 64 bits integers. It serializes a trivial LWE ciphertext entity.
+impl EntitySerializationEngine<TrivialLweCiphertext64, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweSize;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_size = LweSize(10);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u64 << 20;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let plaintext: Plaintext64 = engine.create_plaintext_from(&input)?;
+    /// let ciphertext: TrivialLweCiphertext64 =
+    ///     engine.create_trivial_lwe_ciphertext(lwe_size, &plaintext)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&ciphertext)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &TrivialLweCiphertext64,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &TrivialLweCiphertext64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &TrivialLweCiphertext64,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableTrivialLweCiphertext64<'a> {
+            version: TrivialLweCiphertext64Version,
+            inner: &'a ImplLweCiphertext<Vec<u64>>,
+        }
+        let serializable = SerializableTrivialLweCiphertext64 {
+            version: TrivialLweCiphertext64Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize_into(writer, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &TrivialLweCiphertext64,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 32 bits integers. It serializes a LWE ciphertext view entity.
+impl<'b> EntitySerializationEngine<LweCiphertextView32<'b>, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let raw_buffer = engine.consume_retrieve_lwe_ciphertext(ciphertext)?;
+    /// let view: LweCiphertextView32 = engine.create_lwe_ciphertext_from(raw_buffer.as_slice())?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&view)?;
+    /// let recovered: LweCiphertext32 = serialization_engine.deserialize(serialized.as_slice())?;
+    /// let recovered_buffer = engine.consume_retrieve_lwe_ciphertext(recovered)?;
+    /// assert_eq!(raw_buffer, recovered_buffer);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweCiphertextView32<'b>,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextView32<'b>) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &LweCiphertextView32<'b>,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweCiphertextView32<'a, 'b> {
+            version: LweCiphertext32Version,
+            inner: &'a ImplLweCiphertext<&'b [u32]>,
+        }
+        let serializable = SerializableLweCiphertextView32 {
+            version: LweCiphertext32Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize_into(writer, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &LweCiphertextView32<'b>,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 64 bits integers. It serializes a LWE ciphertext view entity.
+impl<'b> EntitySerializationEngine<LweCiphertextView64<'b>, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let raw_buffer = engine.consume_retrieve_lwe_ciphertext(ciphertext)?;
+    /// let view: LweCiphertextView64 = engine.create_lwe_ciphertext_from(raw_buffer.as_slice())?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&view)?;
+    /// let recovered: LweCiphertext64 = serialization_engine.deserialize(serialized.as_slice())?;
+    /// let recovered_buffer = engine.consume_retrieve_lwe_ciphertext(recovered)?;
+    /// assert_eq!(raw_buffer, recovered_buffer);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    fn serialize(
+        &mut self,
+        entity: &LweCiphertextView64<'b>,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextView64<'b>) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &LweCiphertextView64<'b>,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweCiphertextView64<'a, 'b> {
+            version: LweCiphertext64Version,
+            inner: &'a ImplLweCiphertext<&'b [u64]>,
+        }
+        let serializable = SerializableLweCiphertextView64 {
+            version: LweCiphertext64Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize_into(writer, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &LweCiphertextView64<'b>,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 32 bits integers. It serializes a LWE ciphertext mut view entity.
+impl<'b> EntitySerializationEngine<LweCiphertextMutView32<'b>, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let mut raw_buffer = engine.consume_retrieve_lwe_ciphertext(ciphertext)?;
+    /// let view: LweCiphertextMutView32 =
+    ///     engine.create_lwe_ciphertext_from(raw_buffer.as_mut_slice())?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&view)?;
+    /// let recovered: LweCiphertext32 = serialization_engine.deserialize(serialized.as_slice())?;
+    /// let recovered_buffer = engine.consume_retrieve_lwe_ciphertext(recovered)?;
+    /// assert_eq!(raw_buffer, recovered_buffer);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweCiphertextMutView32<'b>,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextMutView32<'b>) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &LweCiphertextMutView32<'b>,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweCiphertextMutView32<'a, 'b> {
+            version: LweCiphertext32Version,
+            inner: &'a ImplLweCiphertext<&'b mut [u32]>,
+        }
+        let serializable = SerializableLweCiphertextMutView32 {
+            version: LweCiphertext32Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize_into(writer, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &LweCiphertextMutView32<'b>,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 64 bits integers. It serializes a LWE ciphertext mut view entity.
+impl<'b> EntitySerializationEngine<LweCiphertextMutView64<'b>, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let mut raw_buffer = engine.consume_retrieve_lwe_ciphertext(ciphertext)?;
+    /// let view: LweCiphertextMutView64 = engine.create_lwe_ciphertext_from(raw_buffer.as_mut_slice())?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&view)?;
+    /// let recovered: LweCiphertext64 = serialization_engine.deserialize(serialized.as_slice())?;
+    /// let recovered_buffer = engine.consume_retrieve_lwe_ciphertext(recovered)?;
+    /// assert_eq!(raw_buffer, recovered_buffer);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    fn serialize(
+        &mut self,
+        entity: &LweCiphertextMutView64<'b>,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextMutView64<'b>) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &LweCiphertextMutView64<'b>,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweCiphertextMutView64<'a, 'b> {
+            version: LweCiphertext64Version,
+            inner: &'a ImplLweCiphertext<&'b mut [u64]>,
+        }
+        let serializable = SerializableLweCiphertextMutView64 {
+            version: LweCiphertext64Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize_into(writer, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &LweCiphertextMutView64<'b>,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 32 bits integers. It serializes a LWE ciphertext vector entity.
+impl EntitySerializationEngine<LweCiphertextVector32, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let mut ciphertext_vector: LweCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&ciphertext_vector)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(ciphertext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweCiphertextVector32,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextVector32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &LweCiphertextVector32,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweCiphertextVector32<'a> {
+            version: LweCiphertextVector32Version,
+            inner: &'a ImplLweList<Vec<u32>>,
+        }
+        let serializable = SerializableLweCiphertextVector32 {
+            version: LweCiphertextVector32Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize_into(writer, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &LweCiphertextVector32,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 64 bits integers. It serializes a LWE ciphertext vector entity.
+impl EntitySerializationEngine<LweCiphertextVector64, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let mut ciphertext_vector: LweCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&ciphertext_vector)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(ciphertext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweCiphertextVector64,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextVector64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &LweCiphertextVector64,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweCiphertextVector64<'a> {
+            version: LweCiphertextVector64Version,
+            inner: &'a ImplLweList<Vec<u64>>,
+        }
+        let serializable = SerializableLweCiphertextVector64 {
+            version: LweCiphertextVector64Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize_into(writer, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &LweCiphertextVector64,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 32 bits integers. It serializes a LWE ciphertext vector view entity. Immutable variant.
+impl<'b> EntitySerializationEngine<LweCiphertextVectorView32<'b>, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// let lwe_count = LweCiphertextCount(3);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; lwe_count.0];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let mut ciphertext_vector: LweCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let raw_buffer = engine.consume_retrieve_lwe_ciphertext_vector(ciphertext_vector)?;
+    /// let view: LweCiphertextVectorView32 = engine
+    ///     .create_lwe_ciphertext_vector_from(raw_buffer.as_slice(), lwe_dimension.to_lwe_size())?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&view)?;
+    /// let recovered: LweCiphertextVector32 =
+    ///     serialization_engine.deserialize(serialized.as_slice())?;
+    /// let recovered_buffer = engine.consume_retrieve_lwe_ciphertext_vector(recovered)?;
+    /// assert_eq!(raw_buffer, recovered_buffer);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweCiphertextVectorView32<'b>,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextVectorView32<'b>) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &LweCiphertextVectorView32<'b>,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweCiphertextVectorView32<'a, 'b> {
+            version: LweCiphertextVector32Version,
+            inner: &'a ImplLweList<&'b [u32]>,
+        }
+
+        let serializable = SerializableLweCiphertextVectorView32 {
+            version: LweCiphertextVector32Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize_into(writer, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &LweCiphertextVectorView32<'b>,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 64 bits integers. It serializes a LWE ciphertext vector view entity. Immutable variant.
+impl<'b> EntitySerializationEngine<LweCiphertextVectorView64<'b>, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// let lwe_count = LweCiphertextCount(3);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; lwe_count.0];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let mut ciphertext_vector: LweCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let raw_buffer = engine.consume_retrieve_lwe_ciphertext_vector(ciphertext_vector)?;
+    /// let view: LweCiphertextVectorView64 = engine
+    ///     .create_lwe_ciphertext_vector_from(raw_buffer.as_slice(), lwe_dimension.to_lwe_size())?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&view)?;
+    /// let recovered: LweCiphertextVector64 =
+    ///     serialization_engine.deserialize(serialized.as_slice())?;
+    /// let recovered_buffer = engine.consume_retrieve_lwe_ciphertext_vector(recovered)?;
+    /// assert_eq!(raw_buffer, recovered_buffer);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweCiphertextVectorView64<'b>,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextVectorView64<'b>) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &LweCiphertextVectorView64<'b>,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweCiphertextVectorView64<'a, 'b> {
+            version: LweCiphertextVector64Version,
+            inner: &'a ImplLweList<&'b [u64]>,
+        }
+
+        let serializable = SerializableLweCiphertextVectorView64 {
+            version: LweCiphertextVector64Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize_into(writer, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &LweCiphertextVectorView64<'b>,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 32 bits integers. It serializes a LWE ciphertext vector view entity. Mutable variant.
+impl<'b> EntitySerializationEngine<LweCiphertextVectorMutView32<'b>, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// let lwe_count = LweCiphertextCount(3);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; lwe_count.0];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let mut ciphertext_vector: LweCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let mut raw_buffer = engine.consume_retrieve_lwe_ciphertext_vector(ciphertext_vector)?;
+    /// let view: LweCiphertextVectorMutView32 = engine.create_lwe_ciphertext_vector_from(
+    ///     raw_buffer.as_mut_slice(),
+    ///     lwe_dimension.to_lwe_size(),
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&view)?;
+    /// let recovered: LweCiphertextVector32 =
+    ///     serialization_engine.deserialize(serialized.as_slice())?;
+    /// let recovered_buffer = engine.consume_retrieve_lwe_ciphertext_vector(recovered)?;
+    /// assert_eq!(raw_buffer, recovered_buffer);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweCiphertextVectorMutView32<'b>,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextVectorMutView32<'b>) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &LweCiphertextVectorMutView32<'b>,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweCiphertextVectorMutView32<'a, 'b> {
+            version: LweCiphertextVector32Version,
+            inner: &'a ImplLweList<&'b mut [u32]>,
+        }
+
+        let serializable = SerializableLweCiphertextVectorMutView32 {
+            version: LweCiphertextVector32Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize_into(writer, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &LweCiphertextVectorMutView32<'b>,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 64 bits integers. It serializes a LWE ciphertext vector view entity. Mutable variant.
+impl<'b> EntitySerializationEngine<LweCiphertextVectorMutView64<'b>, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// let lwe_count = LweCiphertextCount(3);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; lwe_count.0];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let mut ciphertext_vector: LweCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let mut raw_buffer = engine.consume_retrieve_lwe_ciphertext_vector(ciphertext_vector)?;
+    /// let view: LweCiphertextVectorMutView64 = engine.create_lwe_ciphertext_vector_from(
+    ///     raw_buffer.as_mut_slice(),
+    ///     lwe_dimension.to_lwe_size(),
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&view)?;
+    /// let recovered: LweCiphertextVector64 =
+    ///     serialization_engine.deserialize(serialized.as_slice())?;
+    /// let recovered_buffer = engine.consume_retrieve_lwe_ciphertext_vector(recovered)?;
+    /// assert_eq!(raw_buffer, recovered_buffer);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweCiphertextVectorMutView64<'b>,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextVectorMutView64<'b>) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &LweCiphertextVectorMutView64<'b>,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweCiphertextVectorMutView64<'a, 'b> {
+            version: LweCiphertextVector64Version,
+            inner: &'a ImplLweList<&'b mut [u64]>,
+        }
+
+        let serializable = SerializableLweCiphertextVectorMutView64 {
+            version: LweCiphertextVector64Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize_into(writer, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &LweCiphertextVectorMutView64<'b>,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 16 bits integers. It serializes a LWE keyswitch key entity.
+impl EntitySerializationEngine<LweKeyswitchKey16, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey16 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey16 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&keyswitch_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(keyswitch_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweKeyswitchKey16,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweKeyswitchKey16) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &LweKeyswitchKey16,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweKeyswitchKey16<'a> {
+            version: LweKeyswitchKey16Version,
+            inner: &'a ImplLweKeyswitchKey<Vec<u16>>,
+        }
+        let serializable = SerializableLweKeyswitchKey16 {
+            version: LweKeyswitchKey16Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize_into(writer, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &LweKeyswitchKey16,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 32 bits integers. It serializes a LWE keyswitch key entity.
+impl EntitySerializationEngine<LweKeyswitchKey32, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&keyswitch_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(keyswitch_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweKeyswitchKey32,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweKeyswitchKey32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &LweKeyswitchKey32,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweKeyswitchKey32<'a> {
+            version: LweKeyswitchKey32Version,
+            inner: &'a ImplLweKeyswitchKey<Vec<u32>>,
+        }
+        let serializable = SerializableLweKeyswitchKey32 {
+            version: LweKeyswitchKey32Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize_into(writer, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &LweKeyswitchKey32,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 64 bits integers. It serializes a LWE keyswitch key entity.
+impl EntitySerializationEngine<LweKeyswitchKey64, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&keyswitch_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(keyswitch_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweKeyswitchKey64,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweKeyswitchKey64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &LweKeyswitchKey64,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweKeyswitchKey64<'a> {
+            version: LweKeyswitchKey64Version,
+            inner: &'a ImplLweKeyswitchKey<Vec<u64>>,
+        }
+        let serializable = SerializableLweKeyswitchKey64 {
+            version: LweKeyswitchKey64Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize_into(writer, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &LweKeyswitchKey64,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 32 bits integers. It serializes a LWE partial keyswitch key entity.
+impl EntitySerializationEngine<LwePartialKeyswitchKey32, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let switched_lwe_dimension = LweDimension(4);
+    /// let replacement_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let replacement_key: LweSecretKey32 =
+    ///     engine.generate_new_lwe_secret_key(replacement_lwe_dimension)?;
+    ///
+    /// let partial_keyswitch_key: LwePartialKeyswitchKey32 = engine
+    ///     .generate_new_lwe_partial_keyswitch_key(
+    ///         &input_key,
+    ///         &replacement_key,
+    ///         switched_lwe_dimension,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         noise,
+    ///     )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&partial_keyswitch_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(partial_keyswitch_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LwePartialKeyswitchKey32,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LwePartialKeyswitchKey32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &LwePartialKeyswitchKey32,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLwePartialKeyswitchKey32<'a> {
+            version: LwePartialKeyswitchKey32Version,
+            inner: &'a ImplLwePartialKeyswitchKey<Vec<u32>>,
+        }
+        let serializable = SerializableLwePartialKeyswitchKey32 {
+            version: LwePartialKeyswitchKey32Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize_into(writer, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &LwePartialKeyswitchKey32,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 64 bits integers. It serializes a LWE partial keyswitch key entity.
+impl EntitySerializationEngine<LwePartialKeyswitchKey64, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let switched_lwe_dimension = LweDimension(4);
+    /// let replacement_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let replacement_key: LweSecretKey64 =
+    ///     engine.generate_new_lwe_secret_key(replacement_lwe_dimension)?;
+    ///
+    /// let partial_keyswitch_key: LwePartialKeyswitchKey64 = engine
+    ///     .generate_new_lwe_partial_keyswitch_key(
+    ///         &input_key,
+    ///         &replacement_key,
+    ///         switched_lwe_dimension,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         noise,
+    ///     )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&partial_keyswitch_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(partial_keyswitch_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LwePartialKeyswitchKey64,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LwePartialKeyswitchKey64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &LwePartialKeyswitchKey64,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLwePartialKeyswitchKey64<'a> {
+            version: LwePartialKeyswitchKey64Version,
+            inner: &'a ImplLwePartialKeyswitchKey<Vec<u64>>,
+        }
+        let serializable = SerializableLwePartialKeyswitchKey64 {
+            version: LwePartialKeyswitchKey64Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize_into(writer, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &LwePartialKeyswitchKey64,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 16 bits integers. It serializes a LWE secret key entity.
+impl EntitySerializationEngine<LweSecretKey16, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweSecretKey16 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&lwe_secret_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(lwe_secret_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweSecretKey16,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweSecretKey16) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &LweSecretKey16,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweSecretKey16<'a> {
+            version: LweSecretKey16Version,
+            inner: &'a ImplLweSecretKey<BinaryKeyKind, Vec<u16>>,
+        }
+        let serializable = SerializableLweSecretKey16 {
+            version: LweSecretKey16Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize_into(writer, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &LweSecretKey16,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 32 bits integers. It serializes a LWE secret key entity.
+impl EntitySerializationEngine<LweSecretKey32, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&lwe_secret_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(lwe_secret_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweSecretKey32,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweSecretKey32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &LweSecretKey32,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweSecretKey32<'a> {
+            version: LweSecretKey32Version,
+            inner: &'a ImplLweSecretKey<BinaryKeyKind, Vec<u32>>,
+        }
+        let serializable = SerializableLweSecretKey32 {
+            version: LweSecretKey32Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize_into(writer, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &LweSecretKey32,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 64 bits integers. It serializes a LWE secret key entity.
+impl EntitySerializationEngine<LweSecretKey64, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&lwe_secret_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(lwe_secret_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweSecretKey64,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweSecretKey64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &LweSecretKey64,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweSecretKey64<'a> {
+            version: LweSecretKey64Version,
+            inner: &'a ImplLweSecretKey<BinaryKeyKind, Vec<u64>>,
+        }
+        let serializable = SerializableLweSecretKey64 {
+            version: LweSecretKey64Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize_into(writer, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &LweSecretKey64,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 32 bits integers. It serializes a ternary LWE secret key entity.
+impl EntitySerializationEngine<LweSecretKeyTernary32, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweSecretKeyTernary32 =
+    ///     engine.generate_new_lwe_secret_key_ternary(lwe_dimension)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&lwe_secret_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(lwe_secret_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweSecretKeyTernary32,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweSecretKeyTernary32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &LweSecretKeyTernary32,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweSecretKey32<'a> {
+            version: LweSecretKeyTernary32Version,
+            inner: &'a ImplLweSecretKey<TernaryKeyKind, Vec<u32>>,
+        }
+        let serializable = SerializableLweSecretKey32 {
+            version: LweSecretKeyTernary32Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize_into(writer, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &LweSecretKeyTernary32,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 64 bits integers. It serializes a ternary LWE secret key entity.
+impl EntitySerializationEngine<LweSecretKeyTernary64, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweSecretKeyTernary64 =
+    ///     engine.generate_new_lwe_secret_key_ternary(lwe_dimension)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&lwe_secret_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(lwe_secret_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweSecretKeyTernary64,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweSecretKeyTernary64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &LweSecretKeyTernary64,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweSecretKey64<'a> {
+            version: LweSecretKeyTernary64Version,
+            inner: &'a ImplLweSecretKey<TernaryKeyKind, Vec<u64>>,
+        }
+        let serializable = SerializableLweSecretKey64 {
+            version: LweSecretKeyTernary64Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize_into(writer, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &LweSecretKeyTernary64,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
+    }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 32 bits integers. It serializes a LWE secret key entity.
-impl EntitySerializationEngine<LweSecretKey32, Vec<u8>> for DefaultSerializationEngine {
+/// on 32 bits integers. It serializes a gaussian LWE secret key entity.
+impl EntitySerializationEngine<LweSecretKeyGaussian32, Vec<u8>> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::parameters::LweDimension;
@@ -2474,7 +5725,8 @@ impl EntitySerializationEngine<LweSecretKey32, Vec<u8>> for DefaultSerialization
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let lwe_secret_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let lwe_secret_key: LweSecretKeyGaussian32 =
+    ///     engine.generate_new_lwe_secret_key_gaussian(lwe_dimension)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
     /// let serialized = serialization_engine.serialize(&lwe_secret_key)?;
@@ -2487,31 +5739,49 @@ impl EntitySerializationEngine<LweSecretKey32, Vec<u8>> for DefaultSerialization
     /// ```
     fn serialize(
         &mut self,
-        entity: &LweSecretKey32,
+        entity: &LweSecretKeyGaussian32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweSecretKeyGaussian32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &LweSecretKeyGaussian32,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
         struct SerializableLweSecretKey32<'a> {
-            version: LweSecretKey32Version,
-            inner: &'a ImplLweSecretKey<BinaryKeyKind, Vec<u32>>,
+            version: LweSecretKeyGaussian32Version,
+            inner: &'a ImplLweSecretKey<GaussianKeyKind, Vec<u32>>,
         }
         let serializable = SerializableLweSecretKey32 {
-            version: LweSecretKey32Version::V0,
+            version: LweSecretKeyGaussian32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweSecretKey32) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &LweSecretKeyGaussian32,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 64 bits integers. It serializes a LWE secret key entity.
-impl EntitySerializationEngine<LweSecretKey64, Vec<u8>> for DefaultSerializationEngine {
+/// on 64 bits integers. It serializes a gaussian LWE secret key entity.
+impl EntitySerializationEngine<LweSecretKeyGaussian64, Vec<u8>> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::parameters::LweDimension;
@@ -2526,7 +5796,8 @@ impl EntitySerializationEngine<LweSecretKey64, Vec<u8>> for DefaultSerialization
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let lwe_secret_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let lwe_secret_key: LweSecretKeyGaussian64 =
+    ///     engine.generate_new_lwe_secret_key_gaussian(lwe_dimension)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
     /// let serialized = serialization_engine.serialize(&lwe_secret_key)?;
@@ -2539,24 +5810,42 @@ impl EntitySerializationEngine<LweSecretKey64, Vec<u8>> for DefaultSerialization
     /// ```
     fn serialize(
         &mut self,
-        entity: &LweSecretKey64,
+        entity: &LweSecretKeyGaussian64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweSecretKeyGaussian64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &LweSecretKeyGaussian64,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
         struct SerializableLweSecretKey64<'a> {
-            version: LweSecretKey64Version,
-            inner: &'a ImplLweSecretKey<BinaryKeyKind, Vec<u64>>,
+            version: LweSecretKeyGaussian64Version,
+            inner: &'a ImplLweSecretKey<GaussianKeyKind, Vec<u64>>,
         }
         let serializable = SerializableLweSecretKey64 {
-            version: LweSecretKey64Version::V0,
+            version: LweSecretKeyGaussian64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweSecretKey64) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &LweSecretKeyGaussian64,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
@@ -2603,6 +5892,20 @@ impl EntitySerializationEngine<LweSeededBootstrapKey32, Vec<u8>> for DefaultSeri
         &mut self,
         entity: &LweSeededBootstrapKey32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweSeededBootstrapKey32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &LweSeededBootstrapKey32,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
         struct SerializableLweSeededBootstrapKey32<'a> {
             version: LweSeededBootstrapKey32Version,
@@ -2612,13 +5915,17 @@ impl EntitySerializationEngine<LweSeededBootstrapKey32, Vec<u8>> for DefaultSeri
             version: LweSeededBootstrapKey32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweSeededBootstrapKey32) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &LweSeededBootstrapKey32,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
@@ -2666,6 +5973,20 @@ impl EntitySerializationEngine<LweSeededBootstrapKey64, Vec<u8>> for DefaultSeri
         &mut self,
         entity: &LweSeededBootstrapKey64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweSeededBootstrapKey64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &LweSeededBootstrapKey64,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
         struct SerializableLweSeededBootstrapKey64<'a> {
             version: LweSeededBootstrapKey64Version,
@@ -2675,13 +5996,17 @@ impl EntitySerializationEngine<LweSeededBootstrapKey64, Vec<u8>> for DefaultSeri
             version: LweSeededBootstrapKey64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweSeededBootstrapKey64) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &LweSeededBootstrapKey64,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
@@ -2726,6 +6051,20 @@ impl EntitySerializationEngine<LweSeededCiphertext32, Vec<u8>> for DefaultSerial
         &mut self,
         entity: &LweSeededCiphertext32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweSeededCiphertext32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &LweSeededCiphertext32,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
         struct SerializableLweSeededCiphertext32<'a> {
             version: LweSeededCiphertext32Version,
@@ -2735,13 +6074,17 @@ impl EntitySerializationEngine<LweSeededCiphertext32, Vec<u8>> for DefaultSerial
             version: LweSeededCiphertext32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweSeededCiphertext32) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &LweSeededCiphertext32,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
@@ -2786,6 +6129,20 @@ impl EntitySerializationEngine<LweSeededCiphertext64, Vec<u8>> for DefaultSerial
         &mut self,
         entity: &LweSeededCiphertext64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweSeededCiphertext64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &LweSeededCiphertext64,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
         struct SerializableLweSeededCiphertext64<'a> {
             version: LweSeededCiphertext64Version,
@@ -2795,13 +6152,17 @@ impl EntitySerializationEngine<LweSeededCiphertext64, Vec<u8>> for DefaultSerial
             version: LweSeededCiphertext64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweSeededCiphertext64) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &LweSeededCiphertext64,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
@@ -2848,6 +6209,20 @@ impl EntitySerializationEngine<LweSeededCiphertextVector32, Vec<u8>>
         &mut self,
         entity: &LweSeededCiphertextVector32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweSeededCiphertextVector32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &LweSeededCiphertextVector32,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
         struct SerializableLweSeededCiphertextVector32<'a> {
             version: LweSeededCiphertextVector32Version,
@@ -2857,13 +6232,17 @@ impl EntitySerializationEngine<LweSeededCiphertextVector32, Vec<u8>>
             version: LweSeededCiphertextVector32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweSeededCiphertextVector32) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &LweSeededCiphertextVector32,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
@@ -2910,6 +6289,20 @@ impl EntitySerializationEngine<LweSeededCiphertextVector64, Vec<u8>>
         &mut self,
         entity: &LweSeededCiphertextVector64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweSeededCiphertextVector64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &LweSeededCiphertextVector64,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
         struct SerializableLweSeededCiphertextVector64<'a> {
             version: LweSeededCiphertextVector64Version,
@@ -2919,13 +6312,17 @@ impl EntitySerializationEngine<LweSeededCiphertextVector64, Vec<u8>>
             version: LweSeededCiphertextVector64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweSeededCiphertextVector64) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &LweSeededCiphertextVector64,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
@@ -2978,6 +6375,20 @@ impl EntitySerializationEngine<LweSeededKeyswitchKey32, Vec<u8>> for DefaultSeri
         &mut self,
         entity: &LweSeededKeyswitchKey32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweSeededKeyswitchKey32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &LweSeededKeyswitchKey32,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
         struct LweSeededKeyswitchKey32<'a> {
             version: LweSeededKeyswitchKey32Version,
@@ -2987,13 +6398,17 @@ impl EntitySerializationEngine<LweSeededKeyswitchKey32, Vec<u8>> for DefaultSeri
             version: LweSeededKeyswitchKey32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweSeededKeyswitchKey32) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &LweSeededKeyswitchKey32,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
@@ -3046,6 +6461,20 @@ impl EntitySerializationEngine<LweSeededKeyswitchKey64, Vec<u8>> for DefaultSeri
         &mut self,
         entity: &LweSeededKeyswitchKey64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweSeededKeyswitchKey64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &LweSeededKeyswitchKey64,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
         struct LweSeededKeyswitchKey64<'a> {
             version: LweSeededKeyswitchKey64Version,
@@ -3055,14 +6484,200 @@ impl EntitySerializationEngine<LweSeededKeyswitchKey64, Vec<u8>> for DefaultSeri
             version: LweSeededKeyswitchKey64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweSeededKeyswitchKey64) -> Vec<u8> {
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &LweSeededKeyswitchKey64,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 32 bits integers. It serializes a seeded LWE packing keyswitch key entity.
+impl EntitySerializationEngine<LweSeededPackingKeyswitchKey32, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let seeded_packing_keyswitch_key = engine.generate_new_lwe_seeded_packing_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&seeded_packing_keyswitch_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(seeded_packing_keyswitch_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweSeededPackingKeyswitchKey32,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweSeededPackingKeyswitchKey32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &LweSeededPackingKeyswitchKey32,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct LweSeededPackingKeyswitchKey32<'a> {
+            version: LweSeededPackingKeyswitchKey32Version,
+            inner: &'a ImplLweSeededPackingKeyswitchKey<Vec<u32>>,
+        }
+        let serializable = LweSeededPackingKeyswitchKey32 {
+            version: LweSeededPackingKeyswitchKey32Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize_into(writer, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &LweSeededPackingKeyswitchKey32,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 64 bits integers. It serializes a seeded LWE packing keyswitch key entity.
+impl EntitySerializationEngine<LweSeededPackingKeyswitchKey64, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let seeded_packing_keyswitch_key = engine.generate_new_lwe_seeded_packing_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&seeded_packing_keyswitch_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(seeded_packing_keyswitch_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweSeededPackingKeyswitchKey64,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweSeededPackingKeyswitchKey64) -> Vec<u8> {
         self.serialize(entity).unwrap()
     }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &LweSeededPackingKeyswitchKey64,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct LweSeededPackingKeyswitchKey64<'a> {
+            version: LweSeededPackingKeyswitchKey64Version,
+            inner: &'a ImplLweSeededPackingKeyswitchKey<Vec<u64>>,
+        }
+        let serializable = LweSeededPackingKeyswitchKey64 {
+            version: LweSeededPackingKeyswitchKey64Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize_into(writer, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &LweSeededPackingKeyswitchKey64,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
+    }
 }
 
 /// # Description:
@@ -3113,7 +6728,21 @@ impl EntitySerializationEngine<LwePackingKeyswitchKey32, Vec<u8>> for DefaultSer
     fn serialize(
         &mut self,
         entity: &LwePackingKeyswitchKey32,
-    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LwePackingKeyswitchKey32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &LwePackingKeyswitchKey32,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
         struct SerializablePackingKeyswitchKey32<'a> {
             version: LwePackingKeyswitchKey32Version,
@@ -3123,13 +6752,17 @@ impl EntitySerializationEngine<LwePackingKeyswitchKey32, Vec<u8>> for DefaultSer
             version: LwePackingKeyswitchKey32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LwePackingKeyswitchKey32) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &LwePackingKeyswitchKey32,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
@@ -3182,6 +6815,20 @@ impl EntitySerializationEngine<LwePackingKeyswitchKey64, Vec<u8>> for DefaultSer
         &mut self,
         entity: &LwePackingKeyswitchKey64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LwePackingKeyswitchKey64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &LwePackingKeyswitchKey64,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
         struct SerializablePackingKeyswitchKey64<'a> {
             version: LwePackingKeyswitchKey64Version,
@@ -3191,14 +6838,83 @@ impl EntitySerializationEngine<LwePackingKeyswitchKey64, Vec<u8>> for DefaultSer
             version: LwePackingKeyswitchKey64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LwePackingKeyswitchKey64) -> Vec<u8> {
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &LwePackingKeyswitchKey64,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 16 bits integers. It serializes a plaintext entity.
+impl EntitySerializationEngine<Plaintext16, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 12 bits)
+    /// let input = 3_u16 << 12;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let plaintext: Plaintext16 = engine.create_plaintext_from(&input)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&plaintext)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(plaintext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &Plaintext16,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &Plaintext16) -> Vec<u8> {
         self.serialize(entity).unwrap()
     }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &Plaintext16,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializablePlaintext16<'a> {
+            version: Plaintext16Version,
+            inner: &'a ImplPlaintext<u16>,
+        }
+        let serializable = SerializablePlaintext16 {
+            version: Plaintext16Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize_into(writer, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_to_writer_unchecked<W: Write>(&mut self, entity: &Plaintext16, writer: W) {
+        self.serialize_to_writer(entity, writer).unwrap()
+    }
 }
 
 /// # Description:
@@ -3233,6 +6949,20 @@ impl EntitySerializationEngine<Plaintext32, Vec<u8>> for DefaultSerializationEng
         &mut self,
         entity: &Plaintext32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &Plaintext32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &Plaintext32,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
         struct SerializablePlaintext32<'a> {
             version: Plaintext32Version,
@@ -3242,13 +6972,13 @@ impl EntitySerializationEngine<Plaintext32, Vec<u8>> for DefaultSerializationEng
             version: Plaintext32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &Plaintext32) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(&mut self, entity: &Plaintext32, writer: W) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
@@ -3283,6 +7013,20 @@ impl EntitySerializationEngine<Plaintext64, Vec<u8>> for DefaultSerializationEng
         &mut self,
         entity: &Plaintext64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &Plaintext64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &Plaintext64,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
         struct SerializablePlaintext64<'a> {
             version: Plaintext64Version,
@@ -3292,13 +7036,13 @@ impl EntitySerializationEngine<Plaintext64, Vec<u8>> for DefaultSerializationEng
             version: Plaintext64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &Plaintext64) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(&mut self, entity: &Plaintext64, writer: W) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
@@ -3335,6 +7079,20 @@ impl EntitySerializationEngine<PlaintextVector32, Vec<u8>> for DefaultSerializat
         &mut self,
         entity: &PlaintextVector32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &PlaintextVector32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &PlaintextVector32,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
         struct SerializablePlaintextVector32<'a> {
             version: PlaintextVector32Version,
@@ -3344,13 +7102,17 @@ impl EntitySerializationEngine<PlaintextVector32, Vec<u8>> for DefaultSerializat
             version: PlaintextVector32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &PlaintextVector32) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &PlaintextVector32,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
@@ -3387,6 +7149,20 @@ impl EntitySerializationEngine<PlaintextVector64, Vec<u8>> for DefaultSerializat
         &mut self,
         entity: &PlaintextVector64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &PlaintextVector64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &PlaintextVector64,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
         struct SerializablePlaintextVector64<'a> {
             version: PlaintextVector64Version,
@@ -3396,14 +7172,164 @@ impl EntitySerializationEngine<PlaintextVector64, Vec<u8>> for DefaultSerializat
             version: PlaintextVector64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &PlaintextVector64) -> Vec<u8> {
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &PlaintextVector64,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 32 bits integers. It serializes a sparse cleartext polynomial entity.
+impl EntitySerializationEngine<SparseCleartextPolynomial32, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{MonomialIndex, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let values = vec![3_u32, 5];
+    /// let indices = vec![MonomialIndex(0), MonomialIndex(7)];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let sparse_polynomial: SparseCleartextPolynomial32 =
+    ///     engine.create_sparse_cleartext_polynomial_from(&values, &indices, PolynomialSize(256))?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&sparse_polynomial)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(sparse_polynomial, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &SparseCleartextPolynomial32,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &SparseCleartextPolynomial32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &SparseCleartextPolynomial32,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableSparseCleartextPolynomial32<'a> {
+            version: SparseCleartextPolynomial32Version,
+            inner: &'a ImplSparsePolynomial<Vec<u32>>,
+        }
+        let serializable = SerializableSparseCleartextPolynomial32 {
+            version: SparseCleartextPolynomial32Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize_into(writer, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &SparseCleartextPolynomial32,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 64 bits integers. It serializes a sparse cleartext polynomial entity.
+impl EntitySerializationEngine<SparseCleartextPolynomial64, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{MonomialIndex, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let values = vec![3_u64, 5];
+    /// let indices = vec![MonomialIndex(0), MonomialIndex(7)];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let sparse_polynomial: SparseCleartextPolynomial64 =
+    ///     engine.create_sparse_cleartext_polynomial_from(&values, &indices, PolynomialSize(256))?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&sparse_polynomial)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(sparse_polynomial, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &SparseCleartextPolynomial64,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &SparseCleartextPolynomial64) -> Vec<u8> {
         self.serialize(entity).unwrap()
     }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &SparseCleartextPolynomial64,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableSparseCleartextPolynomial64<'a> {
+            version: SparseCleartextPolynomial64Version,
+            inner: &'a ImplSparsePolynomial<Vec<u64>>,
+        }
+        let serializable = SerializableSparseCleartextPolynomial64 {
+            version: SparseCleartextPolynomial64Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize_into(writer, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &SparseCleartextPolynomial64,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
+    }
 }
 
 /// # Description:
@@ -3442,6 +7368,20 @@ impl EntitySerializationEngine<FloatEncoder, Vec<u8>> for DefaultSerializationEn
         &mut self,
         entity: &FloatEncoder,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &FloatEncoder) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &FloatEncoder,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
         struct SerializableFloatEncoder<'a> {
             version: FloatEncoderVersion,
@@ -3451,14 +7391,85 @@ impl EntitySerializationEngine<FloatEncoder, Vec<u8>> for DefaultSerializationEn
             version: FloatEncoderVersion::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &FloatEncoder) -> Vec<u8> {
+    unsafe fn serialize_to_writer_unchecked<W: Write>(&mut self, entity: &FloatEncoder, writer: W) {
+        self.serialize_to_writer(entity, writer).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 64 bits integers. It serializes a modular encoder entity.
+impl EntitySerializationEngine<ModularEncoder, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::PlaintextCount;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let encoder = engine.create_encoder_from(&ModularEncoderConfig {
+    ///     modulus: 5,
+    ///     nb_bit_padding: 1,
+    /// })?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&encoder)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(encoder, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &ModularEncoder,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &ModularEncoder) -> Vec<u8> {
         self.serialize(entity).unwrap()
     }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &ModularEncoder,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableModularEncoder<'a> {
+            version: ModularEncoderVersion,
+            inner: &'a ImplModularEncoder,
+        }
+        let serializable = SerializableModularEncoder {
+            version: ModularEncoderVersion::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize_into(writer, &serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &ModularEncoder,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
+    }
 }
 
 /// # Description:
@@ -3500,6 +7511,20 @@ impl EntitySerializationEngine<FloatEncoderVector, Vec<u8>> for DefaultSerializa
         &mut self,
         entity: &FloatEncoderVector,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &FloatEncoderVector) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &FloatEncoderVector,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
         struct SerializableFloatEncoderVector<'a> {
             version: FloatEncoderVectorVersion,
@@ -3509,12 +7534,16 @@ impl EntitySerializationEngine<FloatEncoderVector, Vec<u8>> for DefaultSerializa
             version: FloatEncoderVectorVersion::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &FloatEncoderVector) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &FloatEncoderVector,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
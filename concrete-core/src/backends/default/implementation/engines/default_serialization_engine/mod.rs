@@ -1,5 +1,10 @@
 use crate::prelude::sealed::AbstractEngineSeal;
-use crate::prelude::AbstractEngine;
+use crate::prelude::{
+    AbstractEngine, GlweCiphertext32, GlweCiphertext64, LweBootstrapKey32, LweBootstrapKey64,
+    LweCiphertext32, LweCiphertext64, LweKeyswitchKey32, LweKeyswitchKey64, LweSecretKey32,
+    LweSecretKey64, Plaintext32, Plaintext64,
+};
+use crate::specification::engines::recording::impl_recordable_entity;
 use std::error::Error;
 use std::fmt::{Display, Formatter};
 
@@ -50,5 +55,20 @@ impl AbstractEngine for DefaultSerializationEngine {
     }
 }
 
+impl_recordable_entity!(DefaultSerializationEngine =>
+    LweSecretKey32,
+    LweSecretKey64,
+    Plaintext32,
+    Plaintext64,
+    LweCiphertext32,
+    LweCiphertext64,
+    LweKeyswitchKey32,
+    LweKeyswitchKey64,
+    GlweCiphertext32,
+    GlweCiphertext64,
+    LweBootstrapKey32,
+    LweBootstrapKey64,
+);
+
 mod entity_deserialization;
 mod entity_serialization;
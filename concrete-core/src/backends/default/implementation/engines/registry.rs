@@ -0,0 +1,1455 @@
+//! The declaration of the engine traits implemented by the engines of the default backend.
+use crate::backends::support::declare_implementations;
+use crate::prelude::*;
+
+declare_implementations! {
+    "default",
+    DefaultEngine => [
+        CleartextCreationEngine<u16, Cleartext16>,
+        CleartextCreationEngine<u32, Cleartext32>,
+        CleartextCreationEngine<u64, Cleartext64>,
+        CleartextCreationEngine<f64, CleartextF64>,
+        CleartextDiscardingRetrievalEngine<Cleartext32, u32>,
+        CleartextDiscardingRetrievalEngine<Cleartext64, u64>,
+        CleartextDiscardingRetrievalEngine<CleartextF64, f64>,
+        CleartextEncodingEngine<FloatEncoder, CleartextF64, Plaintext32>,
+        CleartextEncodingEngine<FloatEncoder, CleartextF64, Plaintext64>,
+        CleartextEncodingEngine<ModularEncoder, Cleartext32, Plaintext32>,
+        CleartextEncodingEngine<ModularEncoder, Cleartext64, Plaintext64>,
+        CleartextRetrievalEngine<Cleartext16, u16>,
+        CleartextRetrievalEngine<Cleartext32, u32>,
+        CleartextRetrievalEngine<Cleartext64, u64>,
+        CleartextRetrievalEngine<CleartextF64, f64>,
+        CleartextVectorCreationEngine<u32, CleartextVector32>,
+        CleartextVectorCreationEngine<u64, CleartextVector64>,
+        CleartextVectorCreationEngine<f64, CleartextVectorF64>,
+        CleartextVectorDiscardingRetrievalEngine<CleartextVector32, u32>,
+        CleartextVectorDiscardingRetrievalEngine<CleartextVector64, u64>,
+        CleartextVectorDiscardingRetrievalEngine<CleartextVectorF64, f64>,
+        CleartextVectorEncodingEngine<FloatEncoderVector, CleartextVectorF64, PlaintextVector32>,
+        CleartextVectorEncodingEngine<FloatEncoderVector, CleartextVectorF64, PlaintextVector64>,
+        CleartextVectorRetrievalEngine<CleartextVector32, u32>,
+        CleartextVectorRetrievalEngine<CleartextVector64, u64>,
+        CleartextVectorRetrievalEngine<CleartextVectorF64, f64>,
+        EncoderCreationEngine<FloatEncoderMinMaxConfig, FloatEncoder>,
+        EncoderCreationEngine<FloatEncoderCenterRadiusConfig, FloatEncoder>,
+        EncoderCreationEngine<ModularEncoderConfig, ModularEncoder>,
+        EncoderVectorCreationEngine<FloatEncoderMinMaxConfig, FloatEncoderVector>,
+        EncoderVectorCreationEngine<FloatEncoderCenterRadiusConfig, FloatEncoderVector>,
+        EntityRawExportEngine<LweCiphertext32>,
+        EntityRawExportEngine<LweCiphertext64>,
+        EntityRawExportEngine<GlweCiphertext32>,
+        EntityRawExportEngine<GlweCiphertext64>,
+        EntityRawExportEngine<LweSecretKey32>,
+        EntityRawExportEngine<LweSecretKey64>,
+        EntityRawExportEngine<GlweSecretKey32>,
+        EntityRawExportEngine<GlweSecretKey64>,
+        EntityRawImportEngine<LweCiphertext32>,
+        EntityRawImportEngine<LweCiphertext64>,
+        EntityRawImportEngine<GlweCiphertext32>,
+        EntityRawImportEngine<GlweCiphertext64>,
+        EntityRawImportEngine<LweSecretKey32>,
+        EntityRawImportEngine<LweSecretKey64>,
+        EntityRawImportEngine<GlweSecretKey32>,
+        EntityRawImportEngine<GlweSecretKey64>,
+        EntityValidationEngine<LweCiphertext16>,
+        EntityValidationEngine<LweCiphertext32>,
+        EntityValidationEngine<LweCiphertext64>,
+        EntityValidationEngine<LweCiphertextVector32>,
+        EntityValidationEngine<LweCiphertextVector64>,
+        EntityValidationEngine<GlweCiphertext32>,
+        EntityValidationEngine<GlweCiphertext64>,
+        EntityValidationEngine<GlweCiphertextVector32>,
+        EntityValidationEngine<GlweCiphertextVector64>,
+        EntityValidationEngine<LweKeyswitchKey16>,
+        EntityValidationEngine<LweKeyswitchKey32>,
+        EntityValidationEngine<LweKeyswitchKey64>,
+        EntityValidationEngine<LweBootstrapKey32>,
+        EntityValidationEngine<LweBootstrapKey64>,
+        EntityValidationEngine<GgswCiphertext32>,
+        EntityValidationEngine<GgswCiphertext64>,
+        GgswCiphertextConversionEngine<GgswCiphertext32, PreparedGgswCiphertext32>,
+        GgswCiphertextDiscardingConversionEngine<GgswCiphertext32, PreparedGgswCiphertext32>,
+        GgswCiphertextConversionEngine<GgswCiphertext64, PreparedGgswCiphertext64>,
+        GgswCiphertextDiscardingConversionEngine<GgswCiphertext64, PreparedGgswCiphertext64>,
+        GgswCiphertextScalarDiscardingEncryptionEngine<
+            GlweSecretKey32,
+            Plaintext32,
+            GgswCiphertext32,
+        >,
+        GgswCiphertextScalarDiscardingEncryptionEngine<
+            GlweSecretKey64,
+            Plaintext64,
+            GgswCiphertext64,
+        >,
+        GgswCiphertextScalarEncryptionEngine<GlweSecretKey32, Plaintext32, GgswCiphertext32>,
+        GgswCiphertextScalarEncryptionEngine<GlweSecretKey64, Plaintext64, GgswCiphertext64>,
+        GgswCiphertextScalarSeededEncryptionEngine<
+            GlweSecretKey32,
+            Plaintext32,
+            GgswSeededCiphertext32,
+        >,
+        GgswCiphertextScalarSeededEncryptionEngine<
+            GlweSecretKey64,
+            Plaintext64,
+            GgswSeededCiphertext64,
+        >,
+        GgswCiphertextScalarTrivialEncryptionEngine<Plaintext32, GgswCiphertext32>,
+        GgswCiphertextScalarTrivialEncryptionEngine<Plaintext64, GgswCiphertext64>,
+        GgswSeededCiphertextToGgswCiphertextTransformationEngine<
+            GgswSeededCiphertext32,
+            GgswCiphertext32,
+        >,
+        GgswSeededCiphertextToGgswCiphertextTransformationEngine<
+            GgswSeededCiphertext64,
+            GgswCiphertext64,
+        >,
+        GlevCiphertextDecryptionEngine<GlweSecretKey32, GlevCiphertext32, PlaintextVector32>,
+        GlevCiphertextDecryptionEngine<GlweSecretKey64, GlevCiphertext64, PlaintextVector64>,
+        GlevCiphertextEncryptionEngine<GlweSecretKey32, PlaintextVector32, GlevCiphertext32>,
+        GlevCiphertextEncryptionEngine<GlweSecretKey64, PlaintextVector64, GlevCiphertext64>,
+        GlweAutomorphismKeysGenerationEngine<GlweSecretKey32, GlweAutomorphismKeys32>,
+        GlweAutomorphismKeysGenerationEngine<GlweSecretKey64, GlweAutomorphismKeys64>,
+        GlweCiphertextConsumingRetrievalEngine<GlweCiphertext32, Vec<u32>>,
+        GlweCiphertextConsumingRetrievalEngine<GlweCiphertext64, Vec<u64>>,
+        GlweCiphertextConsumingRetrievalEngine<GlweCiphertextView32<'data>, &'data [u32]>,
+        GlweCiphertextConsumingRetrievalEngine<GlweCiphertextMutView32<'data>, &'data mut [u32]>,
+        GlweCiphertextConsumingRetrievalEngine<GlweCiphertextView64<'data>, &'data [u64]>,
+        GlweCiphertextConsumingRetrievalEngine<GlweCiphertextMutView64<'data>, &'data mut [u64]>,
+        GlweCiphertextCreationEngine<Vec<u32>, GlweCiphertext32>,
+        GlweCiphertextCreationEngine<Vec<u64>, GlweCiphertext64>,
+        GlweCiphertextCreationEngine<&'data [u32], GlweCiphertextView32<'data>>,
+        GlweCiphertextCreationEngine<&'data mut [u32], GlweCiphertextMutView32<'data>>,
+        GlweCiphertextCreationEngine<&'data [u64], GlweCiphertextView64<'data>>,
+        GlweCiphertextCreationEngine<&'data mut [u64], GlweCiphertextMutView64<'data>>,
+        GlweCiphertextDecryptionEngine<GlweSecretKey32, GlweCiphertext32, PlaintextVector32>,
+        GlweCiphertextDecryptionEngine<GlweSecretKey64, GlweCiphertext64, PlaintextVector64>,
+        GlweCiphertextDecryptionEngine<GlweSecretKeyTernary32, GlweCiphertext32, PlaintextVector32>,
+        GlweCiphertextDecryptionEngine<GlweSecretKeyTernary64, GlweCiphertext64, PlaintextVector64>,
+        GlweCiphertextDecryptionEngine<
+            GlweSecretKeyGaussian32,
+            GlweCiphertext32,
+            PlaintextVector32,
+        >,
+        GlweCiphertextDecryptionEngine<
+            GlweSecretKeyGaussian64,
+            GlweCiphertext64,
+            PlaintextVector64,
+        >,
+        GlweCiphertextDiscardingDecryptionEngine<
+            GlweSecretKey32,
+            GlweCiphertext32,
+            PlaintextVector32,
+        >,
+        GlweCiphertextDiscardingDecryptionEngine<
+            GlweSecretKey64,
+            GlweCiphertext64,
+            PlaintextVector64,
+        >,
+        GlweCiphertextDiscardingEncryptionEngine<
+            GlweSecretKey32,
+            PlaintextVector32,
+            GlweCiphertext32,
+        >,
+        GlweCiphertextDiscardingEncryptionEngine<
+            GlweSecretKey64,
+            PlaintextVector64,
+            GlweCiphertext64,
+        >,
+        GlweCiphertextDiscardingRingReductionEngine<
+            GlweRingReductionKey32,
+            GlweCiphertext32,
+            GlweCiphertext32,
+        >,
+        GlweCiphertextDiscardingRingReductionEngine<
+            GlweRingReductionKey64,
+            GlweCiphertext64,
+            GlweCiphertext64,
+        >,
+        GlweCiphertextDiscardingRingSwitchingEngine<
+            GlweRingSwitchingKey32,
+            GlweCiphertext32,
+            GlweCiphertext32,
+        >,
+        GlweCiphertextDiscardingRingSwitchingEngine<
+            GlweRingSwitchingKey64,
+            GlweCiphertext64,
+            GlweCiphertext64,
+        >,
+        GlweCiphertextDiscardingTrivialEncryptionEngine<PlaintextVector32, GlweCiphertext32>,
+        GlweCiphertextDiscardingTrivialEncryptionEngine<PlaintextVector64, GlweCiphertext64>,
+        GlweCiphertextDiscardingTrivialEncryptionEngine<
+            PlaintextVector32,
+            GlweCiphertextMutView32<'data>,
+        >,
+        GlweCiphertextDiscardingTrivialEncryptionEngine<
+            PlaintextVector64,
+            GlweCiphertextMutView64<'data>,
+        >,
+        GlweCiphertextEncryptionEngine<GlweSecretKey32, PlaintextVector32, GlweCiphertext32>,
+        GlweCiphertextEncryptionEngine<GlweSecretKey64, PlaintextVector64, GlweCiphertext64>,
+        GlweCiphertextEncryptionEngine<GlweSecretKeyTernary32, PlaintextVector32, GlweCiphertext32>,
+        GlweCiphertextEncryptionEngine<GlweSecretKeyTernary64, PlaintextVector64, GlweCiphertext64>,
+        GlweCiphertextEncryptionEngine<
+            GlweSecretKeyGaussian32,
+            PlaintextVector32,
+            GlweCiphertext32,
+        >,
+        GlweCiphertextEncryptionEngine<
+            GlweSecretKeyGaussian64,
+            PlaintextVector64,
+            GlweCiphertext64,
+        >,
+        GlweCiphertextFusingPartialBlindRotationEngine<
+            LweCiphertext32,
+            LweBootstrapKey32,
+            GlweCiphertext32,
+        >,
+        GlweCiphertextFusingPartialBlindRotationEngine<
+            LweCiphertext64,
+            LweBootstrapKey64,
+            GlweCiphertext64,
+        >,
+        GlweCiphertextGgswCiphertextDiscardingExternalProductEngine<
+            GlweCiphertext32,
+            PreparedGgswCiphertext32,
+            GlweCiphertext32,
+        >,
+        GlweCiphertextGgswCiphertextDiscardingExternalProductEngine<
+            GlweCiphertext64,
+            PreparedGgswCiphertext64,
+            GlweCiphertext64,
+        >,
+        GlweCiphertextGgswCiphertextDiscardingExternalProductAddEngine<
+            GlweCiphertext32,
+            PreparedGgswCiphertext32,
+            GlweCiphertext32,
+        >,
+        GlweCiphertextGgswCiphertextDiscardingExternalProductAddEngine<
+            GlweCiphertext64,
+            PreparedGgswCiphertext64,
+            GlweCiphertext64,
+        >,
+        GlweCiphertextRingSplittingEngine<GlweCiphertext32, GlweCiphertextVector32>,
+        GlweCiphertextRingSplittingEngine<GlweCiphertext64, GlweCiphertextVector64>,
+        GlweCiphertextSparseCleartextPolynomialDiscardingMultiplicationEngine<
+            GlweCiphertext32,
+            SparseCleartextPolynomial32,
+            GlweCiphertext32,
+        >,
+        GlweCiphertextSparseCleartextPolynomialDiscardingMultiplicationEngine<
+            GlweCiphertext64,
+            SparseCleartextPolynomial64,
+            GlweCiphertext64,
+        >,
+        GlweCiphertextTrivialDecryptionEngine<GlweCiphertext32, PlaintextVector32>,
+        GlweCiphertextTrivialDecryptionEngine<GlweCiphertext64, PlaintextVector64>,
+        GlweCiphertextTrivialEncryptionEngine<PlaintextVector32, GlweCiphertext32>,
+        GlweCiphertextTrivialEncryptionEngine<PlaintextVector64, GlweCiphertext64>,
+        GlweCiphertextVectorDecryptionEngine<
+            GlweSecretKey32,
+            GlweCiphertextVector32,
+            PlaintextVector32,
+        >,
+        GlweCiphertextVectorDecryptionEngine<
+            GlweSecretKey64,
+            GlweCiphertextVector64,
+            PlaintextVector64,
+        >,
+        GlweCiphertextVectorDiscardingDecryptionEngine<
+            GlweSecretKey32,
+            GlweCiphertextVector32,
+            PlaintextVector32,
+        >,
+        GlweCiphertextVectorDiscardingDecryptionEngine<
+            GlweSecretKey64,
+            GlweCiphertextVector64,
+            PlaintextVector64,
+        >,
+        GlweCiphertextVectorDiscardingEncryptionEngine<
+            GlweSecretKey32,
+            PlaintextVector32,
+            GlweCiphertextVector32,
+        >,
+        GlweCiphertextVectorDiscardingEncryptionEngine<
+            GlweSecretKey64,
+            PlaintextVector64,
+            GlweCiphertextVector64,
+        >,
+        GlweCiphertextVectorDiscardingVariableNoiseEncryptionEngine<
+            GlweSecretKey32,
+            PlaintextVector32,
+            GlweCiphertextVector32,
+        >,
+        GlweCiphertextVectorDiscardingVariableNoiseEncryptionEngine<
+            GlweSecretKey64,
+            PlaintextVector64,
+            GlweCiphertextVector64,
+        >,
+        GlweCiphertextVectorEncryptionEngine<
+            GlweSecretKey32,
+            PlaintextVector32,
+            GlweCiphertextVector32,
+        >,
+        GlweCiphertextVectorEncryptionEngine<
+            GlweSecretKey64,
+            PlaintextVector64,
+            GlweCiphertextVector64,
+        >,
+        GlweCiphertextVectorTrivialDecryptionEngine<GlweCiphertextVector32, PlaintextVector32>,
+        GlweCiphertextVectorTrivialDecryptionEngine<GlweCiphertextVector64, PlaintextVector64>,
+        GlweCiphertextVectorTrivialEncryptionEngine<PlaintextVector32, GlweCiphertextVector32>,
+        GlweCiphertextVectorTrivialEncryptionEngine<PlaintextVector64, GlweCiphertextVector64>,
+        GlweCiphertextVectorVariableNoiseEncryptionEngine<
+            GlweSecretKey32,
+            PlaintextVector32,
+            GlweCiphertextVector32,
+        >,
+        GlweCiphertextVectorVariableNoiseEncryptionEngine<
+            GlweSecretKey64,
+            PlaintextVector64,
+            GlweCiphertextVector64,
+        >,
+        GlweCiphertextVectorZeroEncryptionEngine<GlweSecretKey32, GlweCiphertextVector32>,
+        GlweCiphertextVectorZeroEncryptionEngine<GlweSecretKey64, GlweCiphertextVector64>,
+        GlweCiphertextZeroEncryptionEngine<GlweSecretKey32, GlweCiphertext32>,
+        GlweCiphertextZeroEncryptionEngine<GlweSecretKey64, GlweCiphertext64>,
+        GlweCiphertextsGgswCiphertextFusingCmuxEngine<
+            GlweCiphertext32,
+            GlweCiphertext32,
+            PreparedGgswCiphertext32,
+        >,
+        GlweCiphertextsGgswCiphertextFusingCmuxEngine<
+            GlweCiphertext64,
+            GlweCiphertext64,
+            PreparedGgswCiphertext64,
+        >,
+        GlweRelinearizationKeyGenerationEngine<GlweSecretKey32, GlweRelinearizationKey32>,
+        GlweRelinearizationKeyGenerationEngine<GlweSecretKey64, GlweRelinearizationKey64>,
+        GlweRingReductionKeyGenerationEngine<
+            GlweSecretKey32,
+            GlweSecretKey32,
+            GlweRingReductionKey32,
+        >,
+        GlweRingReductionKeyGenerationEngine<
+            GlweSecretKey64,
+            GlweSecretKey64,
+            GlweRingReductionKey64,
+        >,
+        GlweRingSwitchingKeyGenerationEngine<
+            GlweSecretKey32,
+            GlweSecretKey32,
+            GlweRingSwitchingKey32,
+        >,
+        GlweRingSwitchingKeyGenerationEngine<
+            GlweSecretKey64,
+            GlweSecretKey64,
+            GlweRingSwitchingKey64,
+        >,
+        GlweSecretKeyGaussianGenerationEngine<GlweSecretKeyGaussian32>,
+        GlweSecretKeyGaussianGenerationEngine<GlweSecretKeyGaussian64>,
+        GlweSecretKeyGenerationEngine<GlweSecretKey32>,
+        GlweSecretKeyGenerationEngine<GlweSecretKey64>,
+        GlweSecretKeyRingSplittingEngine<GlweSecretKey32, GlweSecretKey32>,
+        GlweSecretKeyRingSplittingEngine<GlweSecretKey64, GlweSecretKey64>,
+        GlweSecretKeyTensorProductSameKeyEngine<GlweSecretKey32, GlweTensorProductSecretKey32>,
+        GlweSecretKeyTensorProductSameKeyEngine<GlweSecretKey64, GlweTensorProductSecretKey64>,
+        GlweSecretKeyTernaryGenerationEngine<GlweSecretKeyTernary32>,
+        GlweSecretKeyTernaryGenerationEngine<GlweSecretKeyTernary64>,
+        GlweSeededCiphertextDiscardingExpansionEngine<GlweSeededCiphertext32, GlweCiphertext32>,
+        GlweSeededCiphertextDiscardingExpansionEngine<GlweSeededCiphertext64, GlweCiphertext64>,
+        GlweSeededCiphertextEncryptionEngine<
+            GlweSecretKey32,
+            PlaintextVector32,
+            GlweSeededCiphertext32,
+        >,
+        GlweSeededCiphertextEncryptionEngine<
+            GlweSecretKey64,
+            PlaintextVector64,
+            GlweSeededCiphertext64,
+        >,
+        GlweSeededCiphertextToGlweCiphertextTransformationEngine<
+            GlweSeededCiphertext32,
+            GlweCiphertext32,
+        >,
+        GlweSeededCiphertextToGlweCiphertextTransformationEngine<
+            GlweSeededCiphertext64,
+            GlweCiphertext64,
+        >,
+        GlweSeededCiphertextVectorDiscardingExpansionEngine<
+            GlweSeededCiphertextVector32,
+            GlweCiphertextVector32,
+        >,
+        GlweSeededCiphertextVectorDiscardingExpansionEngine<
+            GlweSeededCiphertextVector64,
+            GlweCiphertextVector64,
+        >,
+        GlweSeededCiphertextVectorEncryptionEngine<
+            GlweSecretKey32,
+            PlaintextVector32,
+            GlweSeededCiphertextVector32,
+        >,
+        GlweSeededCiphertextVectorEncryptionEngine<
+            GlweSecretKey64,
+            PlaintextVector64,
+            GlweSeededCiphertextVector64,
+        >,
+        GlweSeededCiphertextVectorToGlweCiphertextVectorTransformationEngine<
+            GlweSeededCiphertextVector32,
+            GlweCiphertextVector32,
+        >,
+        GlweSeededCiphertextVectorToGlweCiphertextVectorTransformationEngine<
+            GlweSeededCiphertextVector64,
+            GlweCiphertextVector64,
+        >,
+        GlweToLweSecretKeyTransformationEngine<GlweSecretKey32, LweSecretKey32>,
+        GlweToLweSecretKeyTransformationEngine<GlweSecretKey64, LweSecretKey64>,
+        GlweToLweSecretKeyTransformationEngine<GlweSecretKeyTernary32, LweSecretKeyTernary32>,
+        GlweToLweSecretKeyTransformationEngine<GlweSecretKeyTernary64, LweSecretKeyTernary64>,
+        GlweToLweSecretKeyTransformationEngine<GlweSecretKeyGaussian32, LweSecretKeyGaussian32>,
+        GlweToLweSecretKeyTransformationEngine<GlweSecretKeyGaussian64, LweSecretKeyGaussian64>,
+        LevCiphertextDecryptionEngine<LweSecretKey32, LevCiphertext32, Plaintext32>,
+        LevCiphertextDecryptionEngine<LweSecretKey64, LevCiphertext64, Plaintext64>,
+        LevCiphertextEncryptionEngine<LweSecretKey32, Plaintext32, LevCiphertext32>,
+        LevCiphertextEncryptionEngine<LweSecretKey64, Plaintext64, LevCiphertext64>,
+        LweBootstrapKeyConsumingRetrievalEngine<LweBootstrapKey32, Vec<u32>>,
+        LweBootstrapKeyConsumingRetrievalEngine<LweBootstrapKey64, Vec<u64>>,
+        LweBootstrapKeyConsumingRetrievalEngine<LweBootstrapKeyMutView32<'data>, &'data mut [u32]>,
+        LweBootstrapKeyConsumingRetrievalEngine<LweBootstrapKeyMutView64<'data>, &'data mut [u64]>,
+        LweBootstrapKeyConsumingRetrievalEngine<LweBootstrapKeyView32<'data>, &'data [u32]>,
+        LweBootstrapKeyConsumingRetrievalEngine<LweBootstrapKeyView64<'data>, &'data [u64]>,
+        LweBootstrapKeyCreationEngine<Vec<u32>, LweBootstrapKey32>,
+        LweBootstrapKeyCreationEngine<Vec<u64>, LweBootstrapKey64>,
+        LweBootstrapKeyCreationEngine<&'data mut [u32], LweBootstrapKeyMutView32<'data>>,
+        LweBootstrapKeyCreationEngine<&'data mut [u64], LweBootstrapKeyMutView64<'data>>,
+        LweBootstrapKeyCreationEngine<&'data [u32], LweBootstrapKeyView32<'data>>,
+        LweBootstrapKeyCreationEngine<&'data [u64], LweBootstrapKeyView64<'data>>,
+        LweBootstrapKeyDiscardingConversionEngine<
+            LweBootstrapKey32,
+            LweBootstrapKeyMutView32<'data>,
+        >,
+        LweBootstrapKeyDiscardingConversionEngine<
+            LweBootstrapKey64,
+            LweBootstrapKeyMutView64<'data>,
+        >,
+        LweBootstrapKeyGenerationEngine<LweSecretKey32, GlweSecretKey32, LweBootstrapKey32>,
+        LweBootstrapKeyGenerationEngine<LweSecretKey64, GlweSecretKey64, LweBootstrapKey64>,
+        LweBootstrapKeyGenerationEngine<LweSecretKey32, GlweSecretKeyTernary32, LweBootstrapKey32>,
+        LweBootstrapKeyGenerationEngine<LweSecretKey64, GlweSecretKeyTernary64, LweBootstrapKey64>,
+        LweBootstrapKeyGgswCiphertextDiscardingInsertionEngine<GgswCiphertext32, LweBootstrapKey32>,
+        LweBootstrapKeyGgswCiphertextDiscardingInsertionEngine<GgswCiphertext64, LweBootstrapKey64>,
+        LweBootstrapKeyGgswCiphertextExtractionEngine<LweBootstrapKey32, GgswCiphertext32>,
+        LweBootstrapKeyGgswCiphertextExtractionEngine<LweBootstrapKey64, GgswCiphertext64>,
+        LweBootstrapKeyShardExtractionEngine<LweBootstrapKey32, LweBootstrapKey32>,
+        LweBootstrapKeyShardExtractionEngine<LweBootstrapKey64, LweBootstrapKey64>,
+        LweBootstrapLookupTableCreationEngine<LweBootstrapLookupTable32>,
+        LweBootstrapLookupTableCreationEngine<LweBootstrapLookupTable64>,
+        LweCiphertextCleartextDiscardingMultiplicationEngine<
+            LweCiphertext16,
+            Cleartext16,
+            LweCiphertext16,
+        >,
+        LweCiphertextCleartextDiscardingMultiplicationEngine<
+            LweCiphertext32,
+            Cleartext32,
+            LweCiphertext32,
+        >,
+        LweCiphertextCleartextDiscardingMultiplicationEngine<
+            LweCiphertext64,
+            Cleartext64,
+            LweCiphertext64,
+        >,
+        LweCiphertextCleartextDiscardingMultiplicationEngine<
+            LweCiphertextView32<'data>,
+            Cleartext32,
+            LweCiphertextMutView32<'data>,
+        >,
+        LweCiphertextCleartextDiscardingMultiplicationEngine<
+            LweCiphertextView64<'data>,
+            Cleartext64,
+            LweCiphertextMutView64<'data>,
+        >,
+        LweCiphertextCleartextFusingMultiplicationEngine<LweCiphertext16, Cleartext16>,
+        LweCiphertextCleartextFusingMultiplicationEngine<LweCiphertext32, Cleartext32>,
+        LweCiphertextCleartextFusingMultiplicationEngine<LweCiphertext64, Cleartext64>,
+        LweCiphertextConsumingRetrievalEngine<LweCiphertext32, Vec<u32>>,
+        LweCiphertextConsumingRetrievalEngine<LweCiphertext64, Vec<u64>>,
+        LweCiphertextConsumingRetrievalEngine<LweCiphertextView32<'data>, &'data [u32]>,
+        LweCiphertextConsumingRetrievalEngine<LweCiphertextMutView32<'data>, &'data mut [u32]>,
+        LweCiphertextConsumingRetrievalEngine<LweCiphertextView64<'data>, &'data [u64]>,
+        LweCiphertextConsumingRetrievalEngine<LweCiphertextMutView64<'data>, &'data mut [u64]>,
+        LweCiphertextCreationEngine<Vec<u32>, LweCiphertext32>,
+        LweCiphertextCreationEngine<Vec<u64>, LweCiphertext64>,
+        LweCiphertextCreationEngine<&'data [u32], LweCiphertextView32<'data>>,
+        LweCiphertextCreationEngine<&'data mut [u32], LweCiphertextMutView32<'data>>,
+        LweCiphertextCreationEngine<&'data [u64], LweCiphertextView64<'data>>,
+        LweCiphertextCreationEngine<&'data mut [u64], LweCiphertextMutView64<'data>>,
+        LweCiphertextDecryptionEngine<LweSecretKey16, LweCiphertext16, Plaintext16>,
+        LweCiphertextDecryptionEngine<LweSecretKey32, LweCiphertext32, Plaintext32>,
+        LweCiphertextDecryptionEngine<LweSecretKey64, LweCiphertext64, Plaintext64>,
+        LweCiphertextDecryptionEngine<LweSecretKey32, LweCiphertextView32<'data>, Plaintext32>,
+        LweCiphertextDecryptionEngine<LweSecretKey64, LweCiphertextView64<'data>, Plaintext64>,
+        LweCiphertextDecryptionEngine<LweSecretKeyTernary32, LweCiphertext32, Plaintext32>,
+        LweCiphertextDecryptionEngine<LweSecretKeyTernary64, LweCiphertext64, Plaintext64>,
+        LweCiphertextDecryptionEngine<LweSecretKeyGaussian32, LweCiphertext32, Plaintext32>,
+        LweCiphertextDecryptionEngine<LweSecretKeyGaussian64, LweCiphertext64, Plaintext64>,
+        LweCiphertextDecryptionEngine<SolinasLweSecretKey64, SolinasLweCiphertext64, Plaintext64>,
+        LweCiphertextDecryptionDecodingEngine<
+            LweSecretKey32,
+            FloatEncoder,
+            LweCiphertext32,
+            CleartextF64,
+        >,
+        LweCiphertextDecryptionDecodingEngine<
+            LweSecretKey64,
+            FloatEncoder,
+            LweCiphertext64,
+            CleartextF64,
+        >,
+        LweCiphertextDiscardingAdditionEngine<LweCiphertext16, LweCiphertext16>,
+        LweCiphertextDiscardingAdditionEngine<LweCiphertext32, LweCiphertext32>,
+        LweCiphertextDiscardingAdditionEngine<LweCiphertext64, LweCiphertext64>,
+        LweCiphertextDiscardingAdditionEngine<
+            LweCiphertextView32<'data>,
+            LweCiphertextMutView32<'data>,
+        >,
+        LweCiphertextDiscardingAdditionEngine<
+            LweCiphertextView64<'data>,
+            LweCiphertextMutView64<'data>,
+        >,
+        LweCiphertextDiscardingAdditionEngine<SolinasLweCiphertext64, SolinasLweCiphertext64>,
+        LweCiphertextDiscardingDecryptionEngine<LweSecretKey32, LweCiphertext32, Plaintext32>,
+        LweCiphertextDiscardingDecryptionEngine<LweSecretKey64, LweCiphertext64, Plaintext64>,
+        LweCiphertextDiscardingEncryptionEngine<LweSecretKey32, Plaintext32, LweCiphertext32>,
+        LweCiphertextDiscardingEncryptionEngine<LweSecretKey64, Plaintext64, LweCiphertext64>,
+        LweCiphertextDiscardingEncryptionEngine<
+            LweSecretKey32,
+            Plaintext32,
+            LweCiphertextMutView32<'data>,
+        >,
+        LweCiphertextDiscardingEncryptionEngine<
+            LweSecretKey64,
+            Plaintext64,
+            LweCiphertextMutView64<'data>,
+        >,
+        LweCiphertextDiscardingExtractionEngine<GlweCiphertext32, LweCiphertext32>,
+        LweCiphertextDiscardingExtractionEngine<GlweCiphertext64, LweCiphertext64>,
+        LweCiphertextDiscardingKeyswitchEngine<LweKeyswitchKey16, LweCiphertext16, LweCiphertext16>,
+        LweCiphertextDiscardingKeyswitchEngine<LweKeyswitchKey32, LweCiphertext32, LweCiphertext32>,
+        LweCiphertextDiscardingKeyswitchEngine<LweKeyswitchKey64, LweCiphertext64, LweCiphertext64>,
+        LweCiphertextDiscardingKeyswitchEngine<
+            LweKeyswitchKey32,
+            LweCiphertextView32<'data>,
+            LweCiphertextMutView32<'data>,
+        >,
+        LweCiphertextDiscardingKeyswitchEngine<
+            LweKeyswitchKey64,
+            LweCiphertextView64<'data>,
+            LweCiphertextMutView64<'data>,
+        >,
+        LweCiphertextDiscardingKeyswitchEngine<
+            LweLevelMajorKeyswitchKey32,
+            LweCiphertext32,
+            LweCiphertext32,
+        >,
+        LweCiphertextDiscardingKeyswitchEngine<
+            LweLevelMajorKeyswitchKey64,
+            LweCiphertext64,
+            LweCiphertext64,
+        >,
+        LweCiphertextDiscardingKeyswitchEngine<
+            SolinasLweKeyswitchKey64,
+            SolinasLweCiphertext64,
+            SolinasLweCiphertext64,
+        >,
+        LweCiphertextDiscardingKeyswitchModulusSwitchEngine<
+            LweKeyswitchKey32,
+            LweCiphertext32,
+            LweCiphertext32,
+        >,
+        LweCiphertextDiscardingKeyswitchModulusSwitchEngine<
+            LweKeyswitchKey64,
+            LweCiphertext64,
+            LweCiphertext64,
+        >,
+        LweCiphertextDiscardingOppositeEngine<LweCiphertext16, LweCiphertext16>,
+        LweCiphertextDiscardingOppositeEngine<LweCiphertext32, LweCiphertext32>,
+        LweCiphertextDiscardingOppositeEngine<LweCiphertext64, LweCiphertext64>,
+        LweCiphertextDiscardingOppositeEngine<
+            LweCiphertextView32<'data>,
+            LweCiphertextMutView32<'data>,
+        >,
+        LweCiphertextDiscardingOppositeEngine<
+            LweCiphertextView64<'data>,
+            LweCiphertextMutView64<'data>,
+        >,
+        LweCiphertextDiscardingPartialKeyswitchEngine<
+            LwePartialKeyswitchKey32,
+            LweCiphertext32,
+            LweCiphertext32,
+        >,
+        LweCiphertextDiscardingPartialKeyswitchEngine<
+            LwePartialKeyswitchKey64,
+            LweCiphertext64,
+            LweCiphertext64,
+        >,
+        LweCiphertextDiscardingPolynomialEvaluationEngine<
+            GlweCiphertext32,
+            Cleartext32,
+            LweCiphertext32,
+        >,
+        LweCiphertextDiscardingPolynomialEvaluationEngine<
+            GlweCiphertext64,
+            Cleartext64,
+            LweCiphertext64,
+        >,
+        LweCiphertextDiscardingPublicKeyEncryptionEngine<
+            LwePublicKey32,
+            Plaintext32,
+            LweCiphertext32,
+        >,
+        LweCiphertextDiscardingPublicKeyEncryptionEngine<
+            LwePublicKey64,
+            Plaintext64,
+            LweCiphertext64,
+        >,
+        LweCiphertextDiscardingSubtractionEngine<LweCiphertext16, LweCiphertext16>,
+        LweCiphertextDiscardingSubtractionEngine<LweCiphertext32, LweCiphertext32>,
+        LweCiphertextDiscardingSubtractionEngine<LweCiphertext64, LweCiphertext64>,
+        LweCiphertextEncryptionEngine<LweSecretKey16, Plaintext16, LweCiphertext16>,
+        LweCiphertextEncryptionEngine<LweSecretKey32, Plaintext32, LweCiphertext32>,
+        LweCiphertextEncryptionEngine<LweSecretKey64, Plaintext64, LweCiphertext64>,
+        LweCiphertextEncryptionEngine<LweSecretKeyTernary32, Plaintext32, LweCiphertext32>,
+        LweCiphertextEncryptionEngine<LweSecretKeyTernary64, Plaintext64, LweCiphertext64>,
+        LweCiphertextEncryptionEngine<LweSecretKeyGaussian32, Plaintext32, LweCiphertext32>,
+        LweCiphertextEncryptionEngine<LweSecretKeyGaussian64, Plaintext64, LweCiphertext64>,
+        LweCiphertextEncryptionEngine<SolinasLweSecretKey64, Plaintext64, SolinasLweCiphertext64>,
+        LweCiphertextFusingAdditionEngine<LweCiphertext16, LweCiphertext16>,
+        LweCiphertextFusingAdditionEngine<LweCiphertext32, LweCiphertext32>,
+        LweCiphertextFusingAdditionEngine<LweCiphertext64, LweCiphertext64>,
+        LweCiphertextFusingAdditionEngine<SolinasLweCiphertext64, SolinasLweCiphertext64>,
+        LweCiphertextFusingOppositeEngine<LweCiphertext16>,
+        LweCiphertextFusingOppositeEngine<LweCiphertext32>,
+        LweCiphertextFusingOppositeEngine<LweCiphertext64>,
+        LweCiphertextFusingSubtractionEngine<LweCiphertext16, LweCiphertext16>,
+        LweCiphertextFusingSubtractionEngine<LweCiphertext32, LweCiphertext32>,
+        LweCiphertextFusingSubtractionEngine<LweCiphertext64, LweCiphertext64>,
+        LweCiphertextPlaintextDiscardingAdditionEngine<
+            LweCiphertext16,
+            Plaintext16,
+            LweCiphertext16,
+        >,
+        LweCiphertextPlaintextDiscardingAdditionEngine<
+            LweCiphertext32,
+            Plaintext32,
+            LweCiphertext32,
+        >,
+        LweCiphertextPlaintextDiscardingAdditionEngine<
+            LweCiphertext64,
+            Plaintext64,
+            LweCiphertext64,
+        >,
+        LweCiphertextPlaintextDiscardingAdditionEngine<
+            LweCiphertextView32<'data>,
+            Plaintext32,
+            LweCiphertextMutView32<'data>,
+        >,
+        LweCiphertextPlaintextDiscardingAdditionEngine<
+            LweCiphertextView64<'data>,
+            Plaintext64,
+            LweCiphertextMutView64<'data>,
+        >,
+        LweCiphertextPlaintextDiscardingSubtractionEngine<
+            LweCiphertext16,
+            Plaintext16,
+            LweCiphertext16,
+        >,
+        LweCiphertextPlaintextDiscardingSubtractionEngine<
+            LweCiphertext32,
+            Plaintext32,
+            LweCiphertext32,
+        >,
+        LweCiphertextPlaintextDiscardingSubtractionEngine<
+            LweCiphertext64,
+            Plaintext64,
+            LweCiphertext64,
+        >,
+        LweCiphertextPlaintextFusingAdditionEngine<LweCiphertext16, Plaintext16>,
+        LweCiphertextPlaintextFusingAdditionEngine<LweCiphertext32, Plaintext32>,
+        LweCiphertextPlaintextFusingAdditionEngine<LweCiphertext64, Plaintext64>,
+        LweCiphertextPlaintextFusingSubtractionEngine<LweCiphertext16, Plaintext16>,
+        LweCiphertextPlaintextFusingSubtractionEngine<LweCiphertext32, Plaintext32>,
+        LweCiphertextPlaintextFusingSubtractionEngine<LweCiphertext64, Plaintext64>,
+        LweCiphertextPublicKeyEncryptionEngine<LwePublicKey32, Plaintext32, LweCiphertext32>,
+        LweCiphertextPublicKeyEncryptionEngine<LwePublicKey64, Plaintext64, LweCiphertext64>,
+        LweCiphertextTrivialDecryptionEngine<LweCiphertext32, Plaintext32>,
+        LweCiphertextTrivialDecryptionEngine<LweCiphertext64, Plaintext64>,
+        LweCiphertextTrivialEncryptionEngine<Plaintext32, LweCiphertext32>,
+        LweCiphertextTrivialEncryptionEngine<Plaintext64, LweCiphertext64>,
+        LweCiphertextVectorBytesDecryptionEngine<
+            LweSecretKey32,
+            ModularEncoder,
+            LweCiphertextVector32,
+        >,
+        LweCiphertextVectorBytesDecryptionEngine<
+            LweSecretKey64,
+            ModularEncoder,
+            LweCiphertextVector64,
+        >,
+        LweCiphertextVectorBytesEncryptionEngine<
+            LweSecretKey32,
+            ModularEncoder,
+            LweCiphertextVector32,
+        >,
+        LweCiphertextVectorBytesEncryptionEngine<
+            LweSecretKey64,
+            ModularEncoder,
+            LweCiphertextVector64,
+        >,
+        LweCiphertextVectorConsumingRetrievalEngine<LweCiphertextVector32, Vec<u32>>,
+        LweCiphertextVectorConsumingRetrievalEngine<LweCiphertextVector64, Vec<u64>>,
+        LweCiphertextVectorConsumingRetrievalEngine<LweCiphertextVectorView32<'data>, &'data [u32]>,
+        LweCiphertextVectorConsumingRetrievalEngine<LweCiphertextVectorView64<'data>, &'data [u64]>,
+        LweCiphertextVectorConsumingRetrievalEngine<
+            LweCiphertextVectorMutView32<'data>,
+            &'data mut [u32],
+        >,
+        LweCiphertextVectorConsumingRetrievalEngine<
+            LweCiphertextVectorMutView64<'data>,
+            &'data mut [u64],
+        >,
+        LweCiphertextVectorCreationEngine<Vec<u32>, LweCiphertextVector32>,
+        LweCiphertextVectorCreationEngine<Vec<u64>, LweCiphertextVector64>,
+        LweCiphertextVectorCreationEngine<&'data [u32], LweCiphertextVectorView32<'data>>,
+        LweCiphertextVectorCreationEngine<&'data mut [u32], LweCiphertextVectorMutView32<'data>>,
+        LweCiphertextVectorCreationEngine<&'data [u64], LweCiphertextVectorView64<'data>>,
+        LweCiphertextVectorCreationEngine<&'data mut [u64], LweCiphertextVectorMutView64<'data>>,
+        LweCiphertextVectorDecryptionEngine<
+            LweSecretKey32,
+            LweCiphertextVector32,
+            PlaintextVector32,
+        >,
+        LweCiphertextVectorDecryptionEngine<
+            LweSecretKey64,
+            LweCiphertextVector64,
+            PlaintextVector64,
+        >,
+        LweCiphertextVectorDecryptionEngine<
+            LweSecretKeyTernary32,
+            LweCiphertextVector32,
+            PlaintextVector32,
+        >,
+        LweCiphertextVectorDecryptionEngine<
+            LweSecretKeyTernary64,
+            LweCiphertextVector64,
+            PlaintextVector64,
+        >,
+        LweCiphertextVectorDecryptionEngine<
+            LweSecretKeyGaussian32,
+            LweCiphertextVector32,
+            PlaintextVector32,
+        >,
+        LweCiphertextVectorDecryptionEngine<
+            LweSecretKeyGaussian64,
+            LweCiphertextVector64,
+            PlaintextVector64,
+        >,
+        LweCiphertextVectorDecryptionDecodingEngine<
+            LweSecretKey32,
+            FloatEncoderVector,
+            LweCiphertextVector32,
+            CleartextVectorF64,
+        >,
+        LweCiphertextVectorDecryptionDecodingEngine<
+            LweSecretKey64,
+            FloatEncoderVector,
+            LweCiphertextVector64,
+            CleartextVectorF64,
+        >,
+        LweCiphertextVectorDiscardingAdditionEngine<LweCiphertextVector32, LweCiphertextVector32>,
+        LweCiphertextVectorDiscardingAdditionEngine<LweCiphertextVector64, LweCiphertextVector64>,
+        LweCiphertextVectorDiscardingAffineTransformationEngine<
+            LweCiphertextVector32,
+            CleartextVector32,
+            Plaintext32,
+            LweCiphertext32,
+        >,
+        LweCiphertextVectorDiscardingAffineTransformationEngine<
+            LweCiphertextVector64,
+            CleartextVector64,
+            Plaintext64,
+            LweCiphertext64,
+        >,
+        LweCiphertextVectorDiscardingAffineTransformationKeyswitchEngine<
+            LweCiphertextVector32,
+            CleartextVector32,
+            Plaintext32,
+            LweKeyswitchKey32,
+            LweCiphertext32,
+        >,
+        LweCiphertextVectorDiscardingAffineTransformationKeyswitchEngine<
+            LweCiphertextVector64,
+            CleartextVector64,
+            Plaintext64,
+            LweKeyswitchKey64,
+            LweCiphertext64,
+        >,
+        LweCiphertextVectorDiscardingBootstrapEngine<
+            LweBootstrapKey32,
+            GlweCiphertextVector32,
+            LweCiphertextVector32,
+            LweCiphertextVector32,
+        >,
+        LweCiphertextVectorDiscardingBootstrapEngine<
+            LweBootstrapKey64,
+            GlweCiphertextVector64,
+            LweCiphertextVector64,
+            LweCiphertextVector64,
+        >,
+        LweCiphertextVectorDiscardingDecryptionEngine<
+            LweSecretKey32,
+            LweCiphertextVector32,
+            PlaintextVector32,
+        >,
+        LweCiphertextVectorDiscardingDecryptionEngine<
+            LweSecretKey64,
+            LweCiphertextVector64,
+            PlaintextVector64,
+        >,
+        LweCiphertextVectorDiscardingEncryptionEngine<
+            LweSecretKey32,
+            PlaintextVector32,
+            LweCiphertextVector32,
+        >,
+        LweCiphertextVectorDiscardingEncryptionEngine<
+            LweSecretKey64,
+            PlaintextVector64,
+            LweCiphertextVector64,
+        >,
+        LweCiphertextVectorDiscardingGatheringEngine<LweCiphertextVector32, LweCiphertextVector32>,
+        LweCiphertextVectorDiscardingGatheringEngine<LweCiphertextVector64, LweCiphertextVector64>,
+        LweCiphertextVectorDiscardingKeyswitchEngine<
+            LweKeyswitchKey32,
+            LweCiphertextVector32,
+            LweCiphertextVector32,
+        >,
+        LweCiphertextVectorDiscardingKeyswitchEngine<
+            LweKeyswitchKey64,
+            LweCiphertextVector64,
+            LweCiphertextVector64,
+        >,
+        LweCiphertextVectorDiscardingPermutationEngine<
+            LweCiphertextVector32,
+            LweCiphertextVector32,
+        >,
+        LweCiphertextVectorDiscardingPermutationEngine<
+            LweCiphertextVector64,
+            LweCiphertextVector64,
+        >,
+        LweCiphertextVectorDiscardingScatteringEngine<LweCiphertextVector32, LweCiphertextVector32>,
+        LweCiphertextVectorDiscardingScatteringEngine<LweCiphertextVector64, LweCiphertextVector64>,
+        LweCiphertextVectorDiscardingSubtractionEngine<
+            LweCiphertextVector32,
+            LweCiphertextVector32,
+        >,
+        LweCiphertextVectorDiscardingSubtractionEngine<
+            LweCiphertextVector64,
+            LweCiphertextVector64,
+        >,
+        LweCiphertextVectorEncryptionEngine<
+            LweSecretKey32,
+            PlaintextVector32,
+            LweCiphertextVector32,
+        >,
+        LweCiphertextVectorEncryptionEngine<
+            LweSecretKey64,
+            PlaintextVector64,
+            LweCiphertextVector64,
+        >,
+        LweCiphertextVectorEncryptionEngine<
+            LweSecretKey32,
+            PlaintextVectorView32<'data>,
+            LweCiphertextVector32,
+        >,
+        LweCiphertextVectorEncryptionEngine<
+            LweSecretKey64,
+            PlaintextVectorView64<'data>,
+            LweCiphertextVector64,
+        >,
+        LweCiphertextVectorEncryptionEngine<
+            LweSecretKeyTernary32,
+            PlaintextVector32,
+            LweCiphertextVector32,
+        >,
+        LweCiphertextVectorEncryptionEngine<
+            LweSecretKeyTernary64,
+            PlaintextVector64,
+            LweCiphertextVector64,
+        >,
+        LweCiphertextVectorEncryptionEngine<
+            LweSecretKeyGaussian32,
+            PlaintextVector32,
+            LweCiphertextVector32,
+        >,
+        LweCiphertextVectorEncryptionEngine<
+            LweSecretKeyGaussian64,
+            PlaintextVector64,
+            LweCiphertextVector64,
+        >,
+        LweCiphertextVectorFusingAdditionEngine<LweCiphertextVector32, LweCiphertextVector32>,
+        LweCiphertextVectorFusingAdditionEngine<LweCiphertextVector64, LweCiphertextVector64>,
+        LweCiphertextVectorFusingSubtractionEngine<LweCiphertextVector32, LweCiphertextVector32>,
+        LweCiphertextVectorFusingSubtractionEngine<LweCiphertextVector64, LweCiphertextVector64>,
+        LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchEngine<
+            LwePackingKeyswitchKey32,
+            LweCiphertextVector32,
+            GlweCiphertext32,
+        >,
+        LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchEngine<
+            LwePackingKeyswitchKey64,
+            LweCiphertextVector64,
+            GlweCiphertext64,
+        >,
+        LweCiphertextVectorGlweCiphertextDiscardingPrivateFunctionalPackingKeyswitchEngine<
+            LwePrivateFunctionalPackingKeyswitchKey32,
+            LweCiphertextVector32,
+            GlweCiphertext32,
+        >,
+        LweCiphertextVectorGlweCiphertextDiscardingPrivateFunctionalPackingKeyswitchEngine<
+            LwePrivateFunctionalPackingKeyswitchKey64,
+            LweCiphertextVector64,
+            GlweCiphertext64,
+        >,
+        LweCiphertextVectorGlweCiphertextDiscardingTreePackingEngine<
+            GlweAutomorphismKeys32,
+            LweCiphertextVector32,
+            GlweCiphertext32,
+        >,
+        LweCiphertextVectorGlweCiphertextDiscardingTreePackingEngine<
+            GlweAutomorphismKeys64,
+            LweCiphertextVector64,
+            GlweCiphertext64,
+        >,
+        LweCiphertextVectorStreamingDecryptionEngine<
+            LweSecretKey32,
+            LweCiphertextVector32,
+            PlaintextVector32,
+        >,
+        LweCiphertextVectorStreamingDecryptionEngine<
+            LweSecretKey64,
+            LweCiphertextVector64,
+            PlaintextVector64,
+        >,
+        LweCiphertextVectorStreamingEncryptionEngine<
+            LweSecretKey32,
+            PlaintextVector32,
+            LweCiphertextVector32,
+        >,
+        LweCiphertextVectorStreamingEncryptionEngine<
+            LweSecretKey64,
+            PlaintextVector64,
+            LweCiphertextVector64,
+        >,
+        LweCiphertextVectorTrivialDecryptionEngine<LweCiphertextVector32, PlaintextVector32>,
+        LweCiphertextVectorTrivialDecryptionEngine<LweCiphertextVector64, PlaintextVector64>,
+        LweCiphertextVectorTrivialEncryptionEngine<PlaintextVector32, LweCiphertextVector32>,
+        LweCiphertextVectorTrivialEncryptionEngine<PlaintextVector64, LweCiphertextVector64>,
+        LweCiphertextVectorZeroEncryptionEngine<LweSecretKey32, LweCiphertextVector32>,
+        LweCiphertextVectorZeroEncryptionEngine<LweSecretKey64, LweCiphertextVector64>,
+        LweCiphertextZeroEncryptionEngine<LweSecretKey16, LweCiphertext16>,
+        LweCiphertextZeroEncryptionEngine<LweSecretKey32, LweCiphertext32>,
+        LweCiphertextZeroEncryptionEngine<LweSecretKey64, LweCiphertext64>,
+        LweCiphertextZeroEncryptionEngine<SolinasLweSecretKey64, SolinasLweCiphertext64>,
+        LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysGenerationEngine<
+            LweSecretKey32,
+            GlweSecretKey32,
+            LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+        >,
+        LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysGenerationEngine<
+            LweSecretKey64,
+            GlweSecretKey64,
+            LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+        >,
+        LweKeyswitchKeyConversionEngine<LweKeyswitchKey32, LweLevelMajorKeyswitchKey32>,
+        LweKeyswitchKeyConversionEngine<LweKeyswitchKey64, LweLevelMajorKeyswitchKey64>,
+        LweKeyswitchKeyConversionEngine<LweLevelMajorKeyswitchKey32, LweKeyswitchKey32>,
+        LweKeyswitchKeyConversionEngine<LweLevelMajorKeyswitchKey64, LweKeyswitchKey64>,
+        LweKeyswitchKeyGenerationEngine<LweSecretKey16, LweSecretKey16, LweKeyswitchKey16>,
+        LweKeyswitchKeyGenerationEngine<LweSecretKey32, LweSecretKey32, LweKeyswitchKey32>,
+        LweKeyswitchKeyGenerationEngine<LweSecretKey64, LweSecretKey64, LweKeyswitchKey64>,
+        LweKeyswitchKeyGenerationEngine<
+            LweSecretKeyTernary32,
+            LweSecretKeyTernary32,
+            LweKeyswitchKey32,
+        >,
+        LweKeyswitchKeyGenerationEngine<
+            LweSecretKeyTernary64,
+            LweSecretKeyTernary64,
+            LweKeyswitchKey64,
+        >,
+        LweKeyswitchKeyGenerationEngine<LweSecretKey32, LweSecretKeyTernary32, LweKeyswitchKey32>,
+        LweKeyswitchKeyGenerationEngine<LweSecretKey64, LweSecretKeyTernary64, LweKeyswitchKey64>,
+        LweKeyswitchKeyGenerationEngine<LweSecretKeyTernary32, LweSecretKey32, LweKeyswitchKey32>,
+        LweKeyswitchKeyGenerationEngine<LweSecretKeyTernary64, LweSecretKey64, LweKeyswitchKey64>,
+        LweKeyswitchKeyGenerationEngine<
+            SolinasLweSecretKey64,
+            SolinasLweSecretKey64,
+            SolinasLweKeyswitchKey64,
+        >,
+        LwePackingKeyswitchKeyGenerationEngine<
+            LweSecretKey32,
+            GlweSecretKey32,
+            LwePackingKeyswitchKey32,
+        >,
+        LwePackingKeyswitchKeyGenerationEngine<
+            LweSecretKey64,
+            GlweSecretKey64,
+            LwePackingKeyswitchKey64,
+        >,
+        LwePartialKeyswitchKeyGenerationEngine<
+            LweSecretKey32,
+            LweSecretKey32,
+            LwePartialKeyswitchKey32,
+        >,
+        LwePartialKeyswitchKeyGenerationEngine<
+            LweSecretKey64,
+            LweSecretKey64,
+            LwePartialKeyswitchKey64,
+        >,
+        LwePrivateFunctionalLwePackingKeyswitchKeyGenerationEngine<
+            LweSecretKey32,
+            GlweSecretKey32,
+            LwePrivateFunctionalPackingKeyswitchKey32,
+            CleartextVector32,
+            u32,
+        >,
+        LwePrivateFunctionalLwePackingKeyswitchKeyGenerationEngine<
+            LweSecretKey64,
+            GlweSecretKey64,
+            LwePrivateFunctionalPackingKeyswitchKey64,
+            CleartextVector64,
+            u64,
+        >,
+        LwePublicKeyGenerationEngine<LweSecretKey32, LwePublicKey32>,
+        LwePublicKeyGenerationEngine<LweSecretKey64, LwePublicKey64>,
+        LweSecretKeyGaussianGenerationEngine<LweSecretKeyGaussian32>,
+        LweSecretKeyGaussianGenerationEngine<LweSecretKeyGaussian64>,
+        LweSecretKeyGenerationEngine<LweSecretKey16>,
+        LweSecretKeyGenerationEngine<LweSecretKey32>,
+        LweSecretKeyGenerationEngine<LweSecretKey64>,
+        LweSecretKeyGenerationEngine<SolinasLweSecretKey64>,
+        LweSecretKeyPartialKeyswitchEngine<
+            LweSecretKey32,
+            LweSecretKey32,
+            LwePartialKeyswitchKey32,
+            LweSecretKey32,
+        >,
+        LweSecretKeyPartialKeyswitchEngine<
+            LweSecretKey64,
+            LweSecretKey64,
+            LwePartialKeyswitchKey64,
+            LweSecretKey64,
+        >,
+        LweSecretKeyTernaryGenerationEngine<LweSecretKeyTernary32>,
+        LweSecretKeyTernaryGenerationEngine<LweSecretKeyTernary64>,
+        LweSeededBootstrapKeyDiscardingExpansionEngine<LweSeededBootstrapKey32, LweBootstrapKey32>,
+        LweSeededBootstrapKeyDiscardingExpansionEngine<LweSeededBootstrapKey64, LweBootstrapKey64>,
+        LweSeededBootstrapKeyGenerationEngine<
+            LweSecretKey32,
+            GlweSecretKey32,
+            LweSeededBootstrapKey32,
+        >,
+        LweSeededBootstrapKeyGenerationEngine<
+            LweSecretKey64,
+            GlweSecretKey64,
+            LweSeededBootstrapKey64,
+        >,
+        LweSeededBootstrapKeyToLweBootstrapKeyTransformationEngine<
+            LweSeededBootstrapKey32,
+            LweBootstrapKey32,
+        >,
+        LweSeededBootstrapKeyToLweBootstrapKeyTransformationEngine<
+            LweSeededBootstrapKey64,
+            LweBootstrapKey64,
+        >,
+        LweSeededCiphertextBatchEncryptionEngine<
+            LweSecretKey32,
+            PlaintextVector32,
+            LweSeededCiphertext32,
+        >,
+        LweSeededCiphertextBatchEncryptionEngine<
+            LweSecretKey64,
+            PlaintextVector64,
+            LweSeededCiphertext64,
+        >,
+        LweSeededCiphertextDiscardingExpansionEngine<LweSeededCiphertext32, LweCiphertext32>,
+        LweSeededCiphertextDiscardingExpansionEngine<LweSeededCiphertext64, LweCiphertext64>,
+        LweSeededCiphertextEncryptionEngine<LweSecretKey32, Plaintext32, LweSeededCiphertext32>,
+        LweSeededCiphertextEncryptionEngine<LweSecretKey64, Plaintext64, LweSeededCiphertext64>,
+        LweSeededCiphertextVectorDiscardingExpansionEngine<
+            LweSeededCiphertextVector32,
+            LweCiphertextVector32,
+        >,
+        LweSeededCiphertextVectorDiscardingExpansionEngine<
+            LweSeededCiphertextVector64,
+            LweCiphertextVector64,
+        >,
+        LweSeededCiphertextVectorEncryptionEngine<
+            LweSecretKey32,
+            PlaintextVector32,
+            LweSeededCiphertextVector32,
+        >,
+        LweSeededCiphertextVectorEncryptionEngine<
+            LweSecretKey64,
+            PlaintextVector64,
+            LweSeededCiphertextVector64,
+        >,
+        LweSeededCiphertextVectorToLweCiphertextVectorTransformationEngine<
+            LweSeededCiphertextVector32,
+            LweCiphertextVector32,
+        >,
+        LweSeededCiphertextVectorToLweCiphertextVectorTransformationEngine<
+            LweSeededCiphertextVector64,
+            LweCiphertextVector64,
+        >,
+        LweSeededKeyswitchKeyDiscardingExpansionEngine<LweSeededKeyswitchKey32, LweKeyswitchKey32>,
+        LweSeededKeyswitchKeyDiscardingExpansionEngine<LweSeededKeyswitchKey64, LweKeyswitchKey64>,
+        LweSeededKeyswitchKeyGenerationEngine<
+            LweSecretKey32,
+            LweSecretKey32,
+            LweSeededKeyswitchKey32,
+        >,
+        LweSeededKeyswitchKeyGenerationEngine<
+            LweSecretKey64,
+            LweSecretKey64,
+            LweSeededKeyswitchKey64,
+        >,
+        LweSeededKeyswitchKeyToLweKeyswitchKeyTransformationEngine<
+            LweSeededKeyswitchKey32,
+            LweKeyswitchKey32,
+        >,
+        LweSeededKeyswitchKeyToLweKeyswitchKeyTransformationEngine<
+            LweSeededKeyswitchKey64,
+            LweKeyswitchKey64,
+        >,
+        LweSeededPackingKeyswitchKeyGenerationEngine<
+            LweSecretKey32,
+            GlweSecretKey32,
+            LweSeededPackingKeyswitchKey32,
+        >,
+        LweSeededPackingKeyswitchKeyGenerationEngine<
+            LweSecretKey64,
+            GlweSecretKey64,
+            LweSeededPackingKeyswitchKey64,
+        >,
+        LweSeededPackingKeyswitchKeyToLwePackingKeyswitchKeyTransformationEngine<
+            LweSeededPackingKeyswitchKey32,
+            LwePackingKeyswitchKey32,
+        >,
+        LweSeededPackingKeyswitchKeyToLwePackingKeyswitchKeyTransformationEngine<
+            LweSeededPackingKeyswitchKey64,
+            LwePackingKeyswitchKey64,
+        >,
+        LweSeededCiphertextToLweCiphertextTransformationEngine<
+            LweSeededCiphertext32,
+            LweCiphertext32,
+        >,
+        LweSeededCiphertextToLweCiphertextTransformationEngine<
+            LweSeededCiphertext64,
+            LweCiphertext64,
+        >,
+        LweToGlweSecretKeyTransformationEngine<LweSecretKey32, GlweSecretKey32>,
+        LweToGlweSecretKeyTransformationEngine<LweSecretKey64, GlweSecretKey64>,
+        PlaintextCreationEngine<u16, Plaintext16>,
+        PlaintextCreationEngine<u32, Plaintext32>,
+        PlaintextCreationEngine<u64, Plaintext64>,
+        PlaintextDecodingEngine<FloatEncoder, Plaintext32, CleartextF64>,
+        PlaintextDecodingEngine<FloatEncoder, Plaintext64, CleartextF64>,
+        PlaintextDecodingEngine<ModularEncoder, Plaintext32, Cleartext32>,
+        PlaintextDecodingEngine<ModularEncoder, Plaintext64, Cleartext64>,
+        PlaintextDiscardingRetrievalEngine<Plaintext32, u32>,
+        PlaintextDiscardingRetrievalEngine<Plaintext64, u64>,
+        PlaintextRetrievalEngine<Plaintext16, u16>,
+        PlaintextRetrievalEngine<Plaintext32, u32>,
+        PlaintextRetrievalEngine<Plaintext64, u64>,
+        PlaintextVectorCreationEngine<u32, PlaintextVector32>,
+        PlaintextVectorCreationEngine<u64, PlaintextVector64>,
+        PlaintextVectorDecodingEngine<FloatEncoderVector, PlaintextVector32, CleartextVectorF64>,
+        PlaintextVectorDecodingEngine<FloatEncoderVector, PlaintextVector64, CleartextVectorF64>,
+        PlaintextVectorDiscardingRetrievalEngine<PlaintextVector32, u32>,
+        PlaintextVectorDiscardingRetrievalEngine<PlaintextVector64, u64>,
+        PlaintextVectorIteratorCreationEngine<u32, PlaintextVector32>,
+        PlaintextVectorIteratorCreationEngine<u64, PlaintextVector64>,
+        PlaintextVectorLookupTableCreationEngine<
+            ModularEncoder,
+            CleartextVector32,
+            PlaintextVector32,
+        >,
+        PlaintextVectorLookupTableCreationEngine<
+            ModularEncoder,
+            CleartextVector64,
+            PlaintextVector64,
+        >,
+        PlaintextVectorRetrievalEngine<PlaintextVector32, u32>,
+        PlaintextVectorRetrievalEngine<PlaintextVector64, u64>,
+        PlaintextVectorViewCreationEngine<&'data [u32], PlaintextVectorView32<'data>>,
+        PlaintextVectorViewCreationEngine<&'data [u64], PlaintextVectorView64<'data>>,
+        SparseCleartextPolynomialCreationEngine<u32, SparseCleartextPolynomial32>,
+        SparseCleartextPolynomialCreationEngine<u64, SparseCleartextPolynomial64>,
+        TrivialGlweCiphertextCreationEngine<PlaintextVector32, TrivialGlweCiphertext32>,
+        TrivialGlweCiphertextCreationEngine<PlaintextVector64, TrivialGlweCiphertext64>,
+        TrivialGlweCiphertextToGlweCiphertextTransformationEngine<
+            TrivialGlweCiphertext32,
+            GlweCiphertext32,
+        >,
+        TrivialGlweCiphertextToGlweCiphertextTransformationEngine<
+            TrivialGlweCiphertext64,
+            GlweCiphertext64,
+        >,
+        TrivialLweCiphertextCreationEngine<Plaintext32, TrivialLweCiphertext32>,
+        TrivialLweCiphertextCreationEngine<Plaintext64, TrivialLweCiphertext64>,
+        TrivialLweCiphertextToLweCiphertextTransformationEngine<
+            TrivialLweCiphertext32,
+            LweCiphertext32,
+        >,
+        TrivialLweCiphertextToLweCiphertextTransformationEngine<
+            TrivialLweCiphertext64,
+            LweCiphertext64,
+        >,
+    ],
+    #[cfg(feature = "backend_default_parallel")]
+    DefaultParallelEngine => [
+        LweBootstrapKeyGenerationEngine<LweSecretKey32, GlweSecretKey32, LweBootstrapKey32>,
+        LweBootstrapKeyGenerationEngine<LweSecretKey64, GlweSecretKey64, LweBootstrapKey64>,
+        LweCiphertextVectorDiscardingBootstrapEngine<
+            LweBootstrapKey32,
+            GlweCiphertextVector32,
+            LweCiphertextVector32,
+            LweCiphertextVector32,
+        >,
+        LweCiphertextVectorDiscardingBootstrapEngine<
+            LweBootstrapKey64,
+            GlweCiphertextVector64,
+            LweCiphertextVector64,
+            LweCiphertextVector64,
+        >,
+        LweCiphertextVectorDiscardingKeyswitchEngine<
+            LweKeyswitchKey32,
+            LweCiphertextVector32,
+            LweCiphertextVector32,
+        >,
+        LweCiphertextVectorDiscardingKeyswitchEngine<
+            LweKeyswitchKey64,
+            LweCiphertextVector64,
+            LweCiphertextVector64,
+        >,
+        LweCiphertextVectorEncryptionEngine<
+            LweSecretKey32,
+            PlaintextVector32,
+            LweCiphertextVector32,
+        >,
+        LweCiphertextVectorEncryptionEngine<
+            LweSecretKey64,
+            PlaintextVector64,
+            LweCiphertextVector64,
+        >,
+        LweSeededBootstrapKeyGenerationEngine<
+            LweSecretKey32,
+            GlweSecretKey32,
+            LweSeededBootstrapKey32,
+        >,
+        LweSeededBootstrapKeyGenerationEngine<
+            LweSecretKey64,
+            GlweSecretKey64,
+            LweSeededBootstrapKey64,
+        >,
+    ],
+    #[cfg(feature = "verifiable_decryption")]
+    DefaultEngine => [
+        LweCiphertextTranscriptDecryptionEngine<LweSecretKey32, LweCiphertext32, u32>,
+        LweCiphertextTranscriptDecryptionEngine<LweSecretKey64, LweCiphertext64, u64>,
+    ],
+    #[cfg(feature = "backend_default_serialization")]
+    DefaultSerializationEngine => [
+        EntityDeserializationEngine<&'data [u8], Cleartext16>,
+        EntityDeserializationEngine<&'data [u8], Cleartext32>,
+        EntityDeserializationEngine<&'data [u8], Cleartext64>,
+        EntityDeserializationEngine<&'data [u8], CleartextF64>,
+        EntityDeserializationEngine<&'data [u8], CleartextVector32>,
+        EntityDeserializationEngine<&'data [u8], CleartextVector64>,
+        EntityDeserializationEngine<&'data [u8], CleartextVectorF64>,
+        EntityDeserializationEngine<&'data [u8], GgswCiphertext32>,
+        EntityDeserializationEngine<&'data [u8], GgswCiphertext64>,
+        EntityDeserializationEngine<&'data [u8], GgswSeededCiphertext32>,
+        EntityDeserializationEngine<&'data [u8], GgswSeededCiphertext64>,
+        EntityDeserializationEngine<&'data [u8], GlevCiphertext32>,
+        EntityDeserializationEngine<&'data [u8], GlevCiphertext64>,
+        EntityDeserializationEngine<&'data [u8], GlweCiphertext32>,
+        EntityDeserializationEngine<&'data [u8], GlweCiphertext64>,
+        EntityDeserializationEngine<&'data [u8], TrivialGlweCiphertext32>,
+        EntityDeserializationEngine<&'data [u8], TrivialGlweCiphertext64>,
+        EntityDeserializationEngine<&'data [u8], GlweCiphertextVector32>,
+        EntityDeserializationEngine<&'data [u8], GlweCiphertextVector64>,
+        EntityDeserializationEngine<&'data [u8], GlweSecretKey32>,
+        EntityDeserializationEngine<&'data [u8], GlweSecretKey64>,
+        EntityDeserializationEngine<&'data [u8], GlweSecretKeyTernary32>,
+        EntityDeserializationEngine<&'data [u8], GlweSecretKeyTernary64>,
+        EntityDeserializationEngine<&'data [u8], GlweSecretKeyGaussian32>,
+        EntityDeserializationEngine<&'data [u8], GlweSecretKeyGaussian64>,
+        EntityDeserializationEngine<&'data [u8], GlweTensorProductSecretKey32>,
+        EntityDeserializationEngine<&'data [u8], GlweTensorProductSecretKey64>,
+        EntityDeserializationEngine<&'data [u8], GlweRingReductionKey32>,
+        EntityDeserializationEngine<&'data [u8], GlweRingReductionKey64>,
+        EntityDeserializationEngine<&'data [u8], GlweRingSwitchingKey32>,
+        EntityDeserializationEngine<&'data [u8], GlweRingSwitchingKey64>,
+        EntityDeserializationEngine<&'data [u8], GlweRelinearizationKey32>,
+        EntityDeserializationEngine<&'data [u8], GlweRelinearizationKey64>,
+        EntityDeserializationEngine<&'data [u8], GlweAutomorphismKeys32>,
+        EntityDeserializationEngine<&'data [u8], GlweAutomorphismKeys64>,
+        EntityDeserializationEngine<&'data [u8], GlweSeededCiphertext32>,
+        EntityDeserializationEngine<&'data [u8], GlweSeededCiphertext64>,
+        EntityDeserializationEngine<&'data [u8], GlweSeededCiphertextVector32>,
+        EntityDeserializationEngine<&'data [u8], GlweSeededCiphertextVector64>,
+        EntityDeserializationEngine<&'data [u8], LevCiphertext32>,
+        EntityDeserializationEngine<&'data [u8], LevCiphertext64>,
+        EntityDeserializationEngine<&'data [u8], LweBootstrapKey32>,
+        EntityDeserializationEngine<&'data [u8], LweBootstrapKey64>,
+        EntityDeserializationEngine<&'data [u8], LweCiphertext16>,
+        EntityDeserializationEngine<&'data [u8], LweCiphertext32>,
+        EntityDeserializationEngine<&'data [u8], LweCiphertext64>,
+        EntityDeserializationEngine<&'data [u8], TrivialLweCiphertext32>,
+        EntityDeserializationEngine<&'data [u8], TrivialLweCiphertext64>,
+        EntityDeserializationEngine<&'data [u8], LweCiphertextVector32>,
+        EntityDeserializationEngine<&'data [u8], LweCiphertextVector64>,
+        EntityDeserializationEngine<&'data [u8], LweKeyswitchKey16>,
+        EntityDeserializationEngine<&'data [u8], LweKeyswitchKey32>,
+        EntityDeserializationEngine<&'data [u8], LweKeyswitchKey64>,
+        EntityDeserializationEngine<&'data [u8], LwePartialKeyswitchKey32>,
+        EntityDeserializationEngine<&'data [u8], LwePartialKeyswitchKey64>,
+        EntityDeserializationEngine<&'data [u8], LweSecretKey16>,
+        EntityDeserializationEngine<&'data [u8], LweSecretKey32>,
+        EntityDeserializationEngine<&'data [u8], LweSecretKey64>,
+        EntityDeserializationEngine<&'data [u8], LweSecretKeyTernary32>,
+        EntityDeserializationEngine<&'data [u8], LweSecretKeyTernary64>,
+        EntityDeserializationEngine<&'data [u8], LweSecretKeyGaussian32>,
+        EntityDeserializationEngine<&'data [u8], LweSecretKeyGaussian64>,
+        EntityDeserializationEngine<&'data [u8], LweSeededBootstrapKey32>,
+        EntityDeserializationEngine<&'data [u8], LweSeededBootstrapKey64>,
+        EntityDeserializationEngine<&'data [u8], LweSeededCiphertext32>,
+        EntityDeserializationEngine<&'data [u8], LweSeededCiphertext64>,
+        EntityDeserializationEngine<&'data [u8], LweSeededCiphertextVector32>,
+        EntityDeserializationEngine<&'data [u8], LweSeededCiphertextVector64>,
+        EntityDeserializationEngine<&'data [u8], LweSeededKeyswitchKey32>,
+        EntityDeserializationEngine<&'data [u8], LweSeededKeyswitchKey64>,
+        EntityDeserializationEngine<&'data [u8], LweSeededPackingKeyswitchKey32>,
+        EntityDeserializationEngine<&'data [u8], LweSeededPackingKeyswitchKey64>,
+        EntityDeserializationEngine<&'data [u8], LwePackingKeyswitchKey32>,
+        EntityDeserializationEngine<&'data [u8], LwePackingKeyswitchKey64>,
+        EntityDeserializationEngine<&'data [u8], Plaintext16>,
+        EntityDeserializationEngine<&'data [u8], Plaintext32>,
+        EntityDeserializationEngine<&'data [u8], Plaintext64>,
+        EntityDeserializationEngine<&'data [u8], PlaintextVector32>,
+        EntityDeserializationEngine<&'data [u8], PlaintextVector64>,
+        EntityDeserializationEngine<&'data [u8], SparseCleartextPolynomial32>,
+        EntityDeserializationEngine<&'data [u8], SparseCleartextPolynomial64>,
+        EntityDeserializationEngine<&'data [u8], FloatEncoder>,
+        EntityDeserializationEngine<&'data [u8], ModularEncoder>,
+        EntityDeserializationEngine<&'data [u8], FloatEncoderVector>,
+        EntitySerializationEngine<Cleartext16, Vec<u8>>,
+        EntitySerializationEngine<Cleartext32, Vec<u8>>,
+        EntitySerializationEngine<Cleartext64, Vec<u8>>,
+        EntitySerializationEngine<CleartextF64, Vec<u8>>,
+        EntitySerializationEngine<CleartextVector32, Vec<u8>>,
+        EntitySerializationEngine<CleartextVector64, Vec<u8>>,
+        EntitySerializationEngine<CleartextVectorF64, Vec<u8>>,
+        EntitySerializationEngine<GgswCiphertext32, Vec<u8>>,
+        EntitySerializationEngine<GgswCiphertext64, Vec<u8>>,
+        EntitySerializationEngine<GgswSeededCiphertext32, Vec<u8>>,
+        EntitySerializationEngine<GgswSeededCiphertext64, Vec<u8>>,
+        EntitySerializationEngine<GlevCiphertext32, Vec<u8>>,
+        EntitySerializationEngine<GlevCiphertext64, Vec<u8>>,
+        EntitySerializationEngine<GlweCiphertext32, Vec<u8>>,
+        EntitySerializationEngine<GlweCiphertext64, Vec<u8>>,
+        EntitySerializationEngine<TrivialGlweCiphertext32, Vec<u8>>,
+        EntitySerializationEngine<TrivialGlweCiphertext64, Vec<u8>>,
+        EntitySerializationEngine<GlweCiphertextView32<'data>, Vec<u8>>,
+        EntitySerializationEngine<GlweCiphertextView64<'data>, Vec<u8>>,
+        EntitySerializationEngine<GlweCiphertextMutView32<'data>, Vec<u8>>,
+        EntitySerializationEngine<GlweCiphertextMutView64<'data>, Vec<u8>>,
+        EntitySerializationEngine<GlweCiphertextVector32, Vec<u8>>,
+        EntitySerializationEngine<GlweCiphertextVector64, Vec<u8>>,
+        EntitySerializationEngine<GlweSecretKey32, Vec<u8>>,
+        EntitySerializationEngine<GlweSecretKey64, Vec<u8>>,
+        EntitySerializationEngine<GlweSecretKeyTernary32, Vec<u8>>,
+        EntitySerializationEngine<GlweSecretKeyTernary64, Vec<u8>>,
+        EntitySerializationEngine<GlweSecretKeyGaussian32, Vec<u8>>,
+        EntitySerializationEngine<GlweSecretKeyGaussian64, Vec<u8>>,
+        EntitySerializationEngine<GlweTensorProductSecretKey32, Vec<u8>>,
+        EntitySerializationEngine<GlweTensorProductSecretKey64, Vec<u8>>,
+        EntitySerializationEngine<GlweRingReductionKey32, Vec<u8>>,
+        EntitySerializationEngine<GlweRingReductionKey64, Vec<u8>>,
+        EntitySerializationEngine<GlweRingSwitchingKey32, Vec<u8>>,
+        EntitySerializationEngine<GlweRingSwitchingKey64, Vec<u8>>,
+        EntitySerializationEngine<GlweRelinearizationKey32, Vec<u8>>,
+        EntitySerializationEngine<GlweRelinearizationKey64, Vec<u8>>,
+        EntitySerializationEngine<GlweAutomorphismKeys32, Vec<u8>>,
+        EntitySerializationEngine<GlweAutomorphismKeys64, Vec<u8>>,
+        EntitySerializationEngine<GlweSeededCiphertext32, Vec<u8>>,
+        EntitySerializationEngine<GlweSeededCiphertext64, Vec<u8>>,
+        EntitySerializationEngine<GlweSeededCiphertextVector32, Vec<u8>>,
+        EntitySerializationEngine<GlweSeededCiphertextVector64, Vec<u8>>,
+        EntitySerializationEngine<LevCiphertext32, Vec<u8>>,
+        EntitySerializationEngine<LevCiphertext64, Vec<u8>>,
+        EntitySerializationEngine<LweBootstrapKey32, Vec<u8>>,
+        EntitySerializationEngine<LweBootstrapKey64, Vec<u8>>,
+        EntitySerializationEngine<LweCiphertext16, Vec<u8>>,
+        EntitySerializationEngine<LweCiphertext32, Vec<u8>>,
+        EntitySerializationEngine<LweCiphertext64, Vec<u8>>,
+        EntitySerializationEngine<TrivialLweCiphertext32, Vec<u8>>,
+        EntitySerializationEngine<TrivialLweCiphertext64, Vec<u8>>,
+        EntitySerializationEngine<LweCiphertextView32<'data>, Vec<u8>>,
+        EntitySerializationEngine<LweCiphertextView64<'data>, Vec<u8>>,
+        EntitySerializationEngine<LweCiphertextMutView32<'data>, Vec<u8>>,
+        EntitySerializationEngine<LweCiphertextMutView64<'data>, Vec<u8>>,
+        EntitySerializationEngine<LweCiphertextVector32, Vec<u8>>,
+        EntitySerializationEngine<LweCiphertextVector64, Vec<u8>>,
+        EntitySerializationEngine<LweCiphertextVectorView32<'data>, Vec<u8>>,
+        EntitySerializationEngine<LweCiphertextVectorView64<'data>, Vec<u8>>,
+        EntitySerializationEngine<LweCiphertextVectorMutView32<'data>, Vec<u8>>,
+        EntitySerializationEngine<LweCiphertextVectorMutView64<'data>, Vec<u8>>,
+        EntitySerializationEngine<LweKeyswitchKey16, Vec<u8>>,
+        EntitySerializationEngine<LweKeyswitchKey32, Vec<u8>>,
+        EntitySerializationEngine<LweKeyswitchKey64, Vec<u8>>,
+        EntitySerializationEngine<LwePartialKeyswitchKey32, Vec<u8>>,
+        EntitySerializationEngine<LwePartialKeyswitchKey64, Vec<u8>>,
+        EntitySerializationEngine<LweSecretKey16, Vec<u8>>,
+        EntitySerializationEngine<LweSecretKey32, Vec<u8>>,
+        EntitySerializationEngine<LweSecretKey64, Vec<u8>>,
+        EntitySerializationEngine<LweSecretKeyTernary32, Vec<u8>>,
+        EntitySerializationEngine<LweSecretKeyTernary64, Vec<u8>>,
+        EntitySerializationEngine<LweSecretKeyGaussian32, Vec<u8>>,
+        EntitySerializationEngine<LweSecretKeyGaussian64, Vec<u8>>,
+        EntitySerializationEngine<LweSeededBootstrapKey32, Vec<u8>>,
+        EntitySerializationEngine<LweSeededBootstrapKey64, Vec<u8>>,
+        EntitySerializationEngine<LweSeededCiphertext32, Vec<u8>>,
+        EntitySerializationEngine<LweSeededCiphertext64, Vec<u8>>,
+        EntitySerializationEngine<LweSeededCiphertextVector32, Vec<u8>>,
+        EntitySerializationEngine<LweSeededCiphertextVector64, Vec<u8>>,
+        EntitySerializationEngine<LweSeededKeyswitchKey32, Vec<u8>>,
+        EntitySerializationEngine<LweSeededKeyswitchKey64, Vec<u8>>,
+        EntitySerializationEngine<LweSeededPackingKeyswitchKey32, Vec<u8>>,
+        EntitySerializationEngine<LweSeededPackingKeyswitchKey64, Vec<u8>>,
+        EntitySerializationEngine<LwePackingKeyswitchKey32, Vec<u8>>,
+        EntitySerializationEngine<LwePackingKeyswitchKey64, Vec<u8>>,
+        EntitySerializationEngine<Plaintext16, Vec<u8>>,
+        EntitySerializationEngine<Plaintext32, Vec<u8>>,
+        EntitySerializationEngine<Plaintext64, Vec<u8>>,
+        EntitySerializationEngine<PlaintextVector32, Vec<u8>>,
+        EntitySerializationEngine<PlaintextVector64, Vec<u8>>,
+        EntitySerializationEngine<SparseCleartextPolynomial32, Vec<u8>>,
+        EntitySerializationEngine<SparseCleartextPolynomial64, Vec<u8>>,
+        EntitySerializationEngine<FloatEncoder, Vec<u8>>,
+        EntitySerializationEngine<ModularEncoder, Vec<u8>>,
+        EntitySerializationEngine<FloatEncoderVector, Vec<u8>>,
+    ],
+}
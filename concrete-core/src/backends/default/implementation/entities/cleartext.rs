@@ -4,6 +4,22 @@ use crate::specification::entities::{AbstractEntity, CleartextEntity};
 #[cfg(feature = "backend_default_serialization")]
 use serde::{Deserialize, Serialize};
 
+/// A structure representing a cleartext with 16 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cleartext16(pub(crate) ImplCleartext<u16>);
+impl AbstractEntity for Cleartext16 {
+    type Kind = CleartextKind;
+}
+impl CleartextEntity for Cleartext16 {}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum Cleartext16Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
+
 /// A structure representing a cleartext with 32 bits of precision.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Cleartext32(pub(crate) ImplCleartext<u32>);
@@ -1,4 +1,6 @@
-use crate::commons::crypto::encoding::FloatEncoder as ImplFloatEncoder;
+use crate::commons::crypto::encoding::{
+    FloatEncoder as ImplFloatEncoder, ModularEncoder as ImplModularEncoder,
+};
 use crate::prelude::markers::EncoderKind;
 use crate::prelude::{AbstractEntity, EncoderEntity};
 #[cfg(feature = "backend_default_serialization")]
@@ -79,3 +81,41 @@ impl FloatEncoderCenterRadiusConfig {
         .to_commons()
     }
 }
+
+/// An encoder for integers modulo an arbitrary modulus, such as the prime moduli of CRT
+/// decompositions.
+#[derive(Debug, PartialEq)]
+pub struct ModularEncoder(pub(crate) ImplModularEncoder);
+
+impl AbstractEntity for ModularEncoder {
+    type Kind = EncoderKind;
+}
+impl EncoderEntity for ModularEncoder {}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum ModularEncoderVersion {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
+
+/// Parameters allowing to construct a `ModularEncoder` from the modulus of the messages.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ModularEncoderConfig {
+    pub modulus: u64,
+    pub nb_bit_padding: usize,
+}
+
+impl ModularEncoderConfig {
+    pub(crate) fn to_commons(&self) -> ImplModularEncoder {
+        assert!(
+            self.modulus >= 2,
+            "The modulus must be greater than or equal to two."
+        );
+        ImplModularEncoder {
+            modulus: self.modulus,
+            nb_bit_padding: self.nb_bit_padding,
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use crate::commons::crypto::glwe::GlevCiphertext as ImplGlevCiphertext;
+use crate::specification::entities::markers::GlevCiphertextKind;
+use crate::specification::entities::{AbstractEntity, GlevCiphertextEntity};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+#[cfg(feature = "backend_default_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A structure representing a GLev ciphertext with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlevCiphertext32(pub(crate) ImplGlevCiphertext<Vec<u32>>);
+impl AbstractEntity for GlevCiphertext32 {
+    type Kind = GlevCiphertextKind;
+}
+impl GlevCiphertextEntity for GlevCiphertext32 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum GlevCiphertext32Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
+
+/// A structure representing a GLev ciphertext with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlevCiphertext64(pub(crate) ImplGlevCiphertext<Vec<u64>>);
+impl AbstractEntity for GlevCiphertext64 {
+    type Kind = GlevCiphertextKind;
+}
+impl GlevCiphertextEntity for GlevCiphertext64 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum GlevCiphertext64Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
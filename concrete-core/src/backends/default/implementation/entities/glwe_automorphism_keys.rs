@@ -0,0 +1,72 @@
+use crate::commons::crypto::glwe::GlweAutomorphismKeys as ImplGlweAutomorphismKeys;
+use crate::specification::entities::markers::GlweAutomorphismKeysKind;
+use crate::specification::entities::{AbstractEntity, GlweAutomorphismKeysEntity};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+#[cfg(feature = "backend_default_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A structure representing a set of GLWE automorphism keys with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweAutomorphismKeys32(pub(crate) ImplGlweAutomorphismKeys<Vec<u32>>);
+impl AbstractEntity for GlweAutomorphismKeys32 {
+    type Kind = GlweAutomorphismKeysKind;
+}
+impl GlweAutomorphismKeysEntity for GlweAutomorphismKeys32 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum GlweAutomorphismKeys32Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
+
+/// A structure representing a set of GLWE automorphism keys with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweAutomorphismKeys64(pub(crate) ImplGlweAutomorphismKeys<Vec<u64>>);
+impl AbstractEntity for GlweAutomorphismKeys64 {
+    type Kind = GlweAutomorphismKeysKind;
+}
+impl GlweAutomorphismKeysEntity for GlweAutomorphismKeys64 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum GlweAutomorphismKeys64Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
@@ -0,0 +1,88 @@
+use crate::commons::crypto::glwe::GlweRingReductionKey as ImplGlweRingReductionKey;
+use crate::specification::entities::markers::GlweRingReductionKeyKind;
+use crate::specification::entities::{AbstractEntity, GlweRingReductionKeyEntity};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+#[cfg(feature = "backend_default_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A structure representing a GLWE ring reduction key with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweRingReductionKey32(pub(crate) ImplGlweRingReductionKey<Vec<u32>>);
+impl AbstractEntity for GlweRingReductionKey32 {
+    type Kind = GlweRingReductionKeyKind;
+}
+impl GlweRingReductionKeyEntity for GlweRingReductionKey32 {
+    fn input_glwe_dimension(&self) -> GlweDimension {
+        self.0.input_glwe_key_dimension()
+    }
+
+    fn input_polynomial_size(&self) -> PolynomialSize {
+        self.0.input_polynomial_size()
+    }
+
+    fn output_glwe_dimension(&self) -> GlweDimension {
+        self.0.output_glwe_key_dimension()
+    }
+
+    fn output_polynomial_size(&self) -> PolynomialSize {
+        self.0.output_polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum GlweRingReductionKey32Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
+
+/// A structure representing a GLWE ring reduction key with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweRingReductionKey64(pub(crate) ImplGlweRingReductionKey<Vec<u64>>);
+impl AbstractEntity for GlweRingReductionKey64 {
+    type Kind = GlweRingReductionKeyKind;
+}
+impl GlweRingReductionKeyEntity for GlweRingReductionKey64 {
+    fn input_glwe_dimension(&self) -> GlweDimension {
+        self.0.input_glwe_key_dimension()
+    }
+
+    fn input_polynomial_size(&self) -> PolynomialSize {
+        self.0.input_polynomial_size()
+    }
+
+    fn output_glwe_dimension(&self) -> GlweDimension {
+        self.0.output_glwe_key_dimension()
+    }
+
+    fn output_polynomial_size(&self) -> PolynomialSize {
+        self.0.output_polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum GlweRingReductionKey64Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
@@ -0,0 +1,88 @@
+use crate::commons::crypto::glwe::GlweRingSwitchingKey as ImplGlweRingSwitchingKey;
+use crate::specification::entities::markers::GlweRingSwitchingKeyKind;
+use crate::specification::entities::{AbstractEntity, GlweRingSwitchingKeyEntity};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+#[cfg(feature = "backend_default_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A structure representing a GLWE ring switching key with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweRingSwitchingKey32(pub(crate) ImplGlweRingSwitchingKey<Vec<u32>>);
+impl AbstractEntity for GlweRingSwitchingKey32 {
+    type Kind = GlweRingSwitchingKeyKind;
+}
+impl GlweRingSwitchingKeyEntity for GlweRingSwitchingKey32 {
+    fn input_glwe_dimension(&self) -> GlweDimension {
+        self.0.input_glwe_key_dimension()
+    }
+
+    fn input_polynomial_size(&self) -> PolynomialSize {
+        self.0.input_polynomial_size()
+    }
+
+    fn output_glwe_dimension(&self) -> GlweDimension {
+        self.0.output_glwe_key_dimension()
+    }
+
+    fn output_polynomial_size(&self) -> PolynomialSize {
+        self.0.output_polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum GlweRingSwitchingKey32Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
+
+/// A structure representing a GLWE ring switching key with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweRingSwitchingKey64(pub(crate) ImplGlweRingSwitchingKey<Vec<u64>>);
+impl AbstractEntity for GlweRingSwitchingKey64 {
+    type Kind = GlweRingSwitchingKeyKind;
+}
+impl GlweRingSwitchingKeyEntity for GlweRingSwitchingKey64 {
+    fn input_glwe_dimension(&self) -> GlweDimension {
+        self.0.input_glwe_key_dimension()
+    }
+
+    fn input_polynomial_size(&self) -> PolynomialSize {
+        self.0.input_polynomial_size()
+    }
+
+    fn output_glwe_dimension(&self) -> GlweDimension {
+        self.0.output_glwe_key_dimension()
+    }
+
+    fn output_polynomial_size(&self) -> PolynomialSize {
+        self.0.output_polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum GlweRingSwitchingKey64Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
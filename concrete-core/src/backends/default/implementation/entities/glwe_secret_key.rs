@@ -1,7 +1,7 @@
 use crate::commons::crypto::secret::GlweSecretKey as ImpGlweSecretKey;
 use crate::specification::entities::markers::GlweSecretKeyKind;
 use crate::specification::entities::{AbstractEntity, GlweSecretKeyEntity};
-use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_commons::key_kinds::{BinaryKeyKind, GaussianKeyKind, TernaryKeyKind};
 use concrete_commons::parameters::{GlweDimension, PolynomialSize};
 #[cfg(feature = "backend_default_serialization")]
 use serde::{Deserialize, Serialize};
@@ -53,3 +53,99 @@ pub(crate) enum GlweSecretKey64Version {
     #[serde(other)]
     Unsupported,
 }
+
+/// A structure representing a ternary GLWE secret key with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweSecretKeyTernary32(pub(crate) ImpGlweSecretKey<TernaryKeyKind, Vec<u32>>);
+impl AbstractEntity for GlweSecretKeyTernary32 {
+    type Kind = GlweSecretKeyKind;
+}
+impl GlweSecretKeyEntity for GlweSecretKeyTernary32 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.key_size()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum GlweSecretKeyTernary32Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
+
+/// A structure representing a ternary GLWE secret key with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweSecretKeyTernary64(pub(crate) ImpGlweSecretKey<TernaryKeyKind, Vec<u64>>);
+impl AbstractEntity for GlweSecretKeyTernary64 {
+    type Kind = GlweSecretKeyKind;
+}
+impl GlweSecretKeyEntity for GlweSecretKeyTernary64 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.key_size()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum GlweSecretKeyTernary64Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
+
+/// A structure representing a gaussian GLWE secret key with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweSecretKeyGaussian32(pub(crate) ImpGlweSecretKey<GaussianKeyKind, Vec<u32>>);
+impl AbstractEntity for GlweSecretKeyGaussian32 {
+    type Kind = GlweSecretKeyKind;
+}
+impl GlweSecretKeyEntity for GlweSecretKeyGaussian32 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.key_size()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum GlweSecretKeyGaussian32Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
+
+/// A structure representing a gaussian GLWE secret key with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweSecretKeyGaussian64(pub(crate) ImpGlweSecretKey<GaussianKeyKind, Vec<u64>>);
+impl AbstractEntity for GlweSecretKeyGaussian64 {
+    type Kind = GlweSecretKeyKind;
+}
+impl GlweSecretKeyEntity for GlweSecretKeyGaussian64 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.key_size()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum GlweSecretKeyGaussian64Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
@@ -0,0 +1,36 @@
+use crate::commons::crypto::glwe::GlweCiphertext as ImplGlweCiphertext;
+use crate::specification::entities::markers::GlweTensorProductCiphertextKind;
+use crate::specification::entities::{AbstractEntity, GlweTensorProductCiphertextEntity};
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+
+/// A structure representing a GLWE tensor product ciphertext with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweTensorProductCiphertext32(pub(crate) ImplGlweCiphertext<Vec<u32>>);
+impl AbstractEntity for GlweTensorProductCiphertext32 {
+    type Kind = GlweTensorProductCiphertextKind;
+}
+impl GlweTensorProductCiphertextEntity for GlweTensorProductCiphertext32 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+}
+
+/// A structure representing a GLWE tensor product ciphertext with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweTensorProductCiphertext64(pub(crate) ImplGlweCiphertext<Vec<u64>>);
+impl AbstractEntity for GlweTensorProductCiphertext64 {
+    type Kind = GlweTensorProductCiphertextKind;
+}
+impl GlweTensorProductCiphertextEntity for GlweTensorProductCiphertext64 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+}
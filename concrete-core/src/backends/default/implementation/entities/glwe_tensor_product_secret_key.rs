@@ -0,0 +1,59 @@
+use crate::commons::crypto::secret::GlweTensorProductSecretKey as ImpGlweTensorProductSecretKey;
+use crate::specification::entities::markers::GlweTensorProductSecretKeyKind;
+use crate::specification::entities::{AbstractEntity, GlweTensorProductSecretKeyEntity};
+use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+#[cfg(feature = "backend_default_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A structure representing a GLWE tensor product secret key with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweTensorProductSecretKey32(
+    pub(crate) ImpGlweTensorProductSecretKey<BinaryKeyKind, Vec<u32>>,
+);
+impl AbstractEntity for GlweTensorProductSecretKey32 {
+    type Kind = GlweTensorProductSecretKeyKind;
+}
+impl GlweTensorProductSecretKeyEntity for GlweTensorProductSecretKey32 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.key_size()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum GlweTensorProductSecretKey32Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
+
+/// A structure representing a GLWE tensor product secret key with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweTensorProductSecretKey64(
+    pub(crate) ImpGlweTensorProductSecretKey<BinaryKeyKind, Vec<u64>>,
+);
+impl AbstractEntity for GlweTensorProductSecretKey64 {
+    type Kind = GlweTensorProductSecretKeyKind;
+}
+impl GlweTensorProductSecretKeyEntity for GlweTensorProductSecretKey64 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.key_size()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum GlweTensorProductSecretKey64Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
@@ -0,0 +1,62 @@
+use crate::commons::crypto::lwe::LevCiphertext as ImplLevCiphertext;
+use crate::specification::entities::markers::LevCiphertextKind;
+use crate::specification::entities::{AbstractEntity, LevCiphertextEntity};
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+#[cfg(feature = "backend_default_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A structure representing a Lev ciphertext with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LevCiphertext32(pub(crate) ImplLevCiphertext<Vec<u32>>);
+impl AbstractEntity for LevCiphertext32 {
+    type Kind = LevCiphertextKind;
+}
+impl LevCiphertextEntity for LevCiphertext32 {
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_size().to_lwe_dimension()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum LevCiphertext32Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
+
+/// A structure representing a Lev ciphertext with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LevCiphertext64(pub(crate) ImplLevCiphertext<Vec<u64>>);
+impl AbstractEntity for LevCiphertext64 {
+    type Kind = LevCiphertextKind;
+}
+impl LevCiphertextEntity for LevCiphertext64 {
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_size().to_lwe_dimension()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum LevCiphertext64Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
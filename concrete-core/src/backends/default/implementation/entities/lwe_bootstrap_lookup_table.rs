@@ -0,0 +1,48 @@
+use crate::commons::crypto::glwe::GlweCiphertext as ImplGlweCiphertext;
+use crate::specification::entities::markers::GlweCiphertextKind;
+use crate::specification::entities::{AbstractEntity, GlweCiphertextEntity};
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+
+/// A structure representing a lookup table for the programmable bootstrap, with 32 bits of
+/// precision.
+///
+/// The lookup table is a trivially encrypted GLWE ciphertext, which can be used as the accumulator
+/// of a bootstrap.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweBootstrapLookupTable32(pub(crate) ImplGlweCiphertext<Vec<u32>>);
+
+impl AbstractEntity for LweBootstrapLookupTable32 {
+    type Kind = GlweCiphertextKind;
+}
+
+impl GlweCiphertextEntity for LweBootstrapLookupTable32 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+}
+
+/// A structure representing a lookup table for the programmable bootstrap, with 64 bits of
+/// precision.
+///
+/// The lookup table is a trivially encrypted GLWE ciphertext, which can be used as the accumulator
+/// of a bootstrap.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweBootstrapLookupTable64(pub(crate) ImplGlweCiphertext<Vec<u64>>);
+
+impl AbstractEntity for LweBootstrapLookupTable64 {
+    type Kind = GlweCiphertextKind;
+}
+
+impl GlweCiphertextEntity for LweBootstrapLookupTable64 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+}
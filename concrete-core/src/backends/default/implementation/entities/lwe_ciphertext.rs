@@ -5,6 +5,26 @@ use concrete_commons::parameters::LweDimension;
 #[cfg(feature = "backend_default_serialization")]
 use serde::{Deserialize, Serialize};
 
+/// A structure representing an LWE ciphertext with 16 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweCiphertext16(pub(crate) ImplLweCiphertext<Vec<u16>>);
+impl AbstractEntity for LweCiphertext16 {
+    type Kind = LweCiphertextKind;
+}
+impl LweCiphertextEntity for LweCiphertext16 {
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_size().to_lwe_dimension()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum LweCiphertext16Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
+
 /// A structure representing an LWE ciphertext with 32 bits of precision.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct LweCiphertext32(pub(crate) ImplLweCiphertext<Vec<u32>>);
@@ -45,6 +65,13 @@ pub(crate) enum LweCiphertext64Version {
     Unsupported,
 }
 
+#[cfg(feature = "fault_injection")]
+crate::specification::engines::fault_injection::impl_fault_injectable_entity!(
+    LweCiphertext16 => u16,
+    LweCiphertext32 => u32,
+    LweCiphertext64 => u64,
+);
+
 // LweCiphertextViews are just LweCiphertext entities that do not own their memory, they use a slice
 // as a container as opposed to Vec for the standard LweCiphertext
 
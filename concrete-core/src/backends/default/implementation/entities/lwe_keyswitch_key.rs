@@ -1,3 +1,6 @@
+use crate::backends::default::implementation::entities::{
+    LweCiphertextVectorView32, LweCiphertextVectorView64,
+};
 use crate::commons::crypto::lwe::LweKeyswitchKey as ImplLweKeyswitchKey;
 use crate::specification::entities::markers::LweKeyswitchKeyKind;
 use crate::specification::entities::{AbstractEntity, LweKeyswitchKeyEntity};
@@ -5,6 +8,38 @@ use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount
 #[cfg(feature = "backend_default_serialization")]
 use serde::{Deserialize, Serialize};
 
+/// A structure representing an LWE keyswitch key with 16 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweKeyswitchKey16(pub(crate) ImplLweKeyswitchKey<Vec<u16>>);
+impl AbstractEntity for LweKeyswitchKey16 {
+    type Kind = LweKeyswitchKeyKind;
+}
+impl LweKeyswitchKeyEntity for LweKeyswitchKey16 {
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.0.before_key_size()
+    }
+
+    fn output_lwe_dimension(&self) -> LweDimension {
+        self.0.after_key_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_levels_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum LweKeyswitchKey16Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
+
 /// A structure representing an LWE keyswitch key with 32 bits of precision.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct LweKeyswitchKey32(pub(crate) ImplLweKeyswitchKey<Vec<u32>>);
@@ -29,6 +64,62 @@ impl LweKeyswitchKeyEntity for LweKeyswitchKey32 {
     }
 }
 
+impl LweKeyswitchKey32 {
+    /// Returns an iterator over the blocks of the key.
+    ///
+    /// The key contains one block per coefficient of the input LWE secret key. Each block is a
+    /// vector of [`decomposition_level_count`](LweKeyswitchKeyEntity::decomposition_level_count)
+    /// LWE ciphertexts under the output key, which encrypt the input key coefficient multiplied by
+    /// the successive levels of the decomposition, starting from the most significant one.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweCiphertextCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key: LweKeyswitchKey32 = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// assert_eq!(keyswitch_key.block_iter().count(), input_lwe_dimension.0);
+    /// for block in keyswitch_key.block_iter() {
+    ///     assert_eq!(block.lwe_dimension(), output_lwe_dimension);
+    ///     assert_eq!(block.lwe_ciphertext_count(), LweCiphertextCount(2));
+    /// }
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn block_iter(&self) -> impl Iterator<Item = LweCiphertextVectorView32<'_>> {
+        self.0
+            .bit_decomp_iter()
+            .map(|block| LweCiphertextVectorView32(block.into_lwe_list()))
+    }
+}
+
 #[cfg(feature = "backend_default_serialization")]
 #[derive(Serialize, Deserialize)]
 pub(crate) enum LweKeyswitchKey32Version {
@@ -61,6 +152,62 @@ impl LweKeyswitchKeyEntity for LweKeyswitchKey64 {
     }
 }
 
+impl LweKeyswitchKey64 {
+    /// Returns an iterator over the blocks of the key.
+    ///
+    /// The key contains one block per coefficient of the input LWE secret key. Each block is a
+    /// vector of [`decomposition_level_count`](LweKeyswitchKeyEntity::decomposition_level_count)
+    /// LWE ciphertexts under the output key, which encrypt the input key coefficient multiplied by
+    /// the successive levels of the decomposition, starting from the most significant one.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweCiphertextCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key: LweKeyswitchKey64 = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// assert_eq!(keyswitch_key.block_iter().count(), input_lwe_dimension.0);
+    /// for block in keyswitch_key.block_iter() {
+    ///     assert_eq!(block.lwe_dimension(), output_lwe_dimension);
+    ///     assert_eq!(block.lwe_ciphertext_count(), LweCiphertextCount(2));
+    /// }
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn block_iter(&self) -> impl Iterator<Item = LweCiphertextVectorView64<'_>> {
+        self.0
+            .bit_decomp_iter()
+            .map(|block| LweCiphertextVectorView64(block.into_lwe_list()))
+    }
+}
+
 #[cfg(feature = "backend_default_serialization")]
 #[derive(Serialize, Deserialize)]
 pub(crate) enum LweKeyswitchKey64Version {
@@ -0,0 +1,54 @@
+use crate::commons::crypto::lwe::LweLevelMajorKeyswitchKey as ImplLweLevelMajorKeyswitchKey;
+use crate::specification::entities::markers::LweKeyswitchKeyKind;
+use crate::specification::entities::{AbstractEntity, LweKeyswitchKeyEntity};
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+
+/// A structure representing an LWE keyswitch key with 32 bits of precision, stored in
+/// level-major order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweLevelMajorKeyswitchKey32(pub(crate) ImplLweLevelMajorKeyswitchKey<Vec<u32>>);
+impl AbstractEntity for LweLevelMajorKeyswitchKey32 {
+    type Kind = LweKeyswitchKeyKind;
+}
+impl LweKeyswitchKeyEntity for LweLevelMajorKeyswitchKey32 {
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.0.before_key_size()
+    }
+
+    fn output_lwe_dimension(&self) -> LweDimension {
+        self.0.after_key_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_levels_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
+
+/// A structure representing an LWE keyswitch key with 64 bits of precision, stored in
+/// level-major order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweLevelMajorKeyswitchKey64(pub(crate) ImplLweLevelMajorKeyswitchKey<Vec<u64>>);
+impl AbstractEntity for LweLevelMajorKeyswitchKey64 {
+    type Kind = LweKeyswitchKeyKind;
+}
+impl LweKeyswitchKeyEntity for LweLevelMajorKeyswitchKey64 {
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.0.before_key_size()
+    }
+
+    fn output_lwe_dimension(&self) -> LweDimension {
+        self.0.after_key_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_levels_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
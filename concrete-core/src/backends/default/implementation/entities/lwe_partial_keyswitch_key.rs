@@ -0,0 +1,86 @@
+use crate::commons::crypto::lwe::LwePartialKeyswitchKey as ImplLwePartialKeyswitchKey;
+use crate::specification::entities::markers::LwePartialKeyswitchKeyKind;
+use crate::specification::entities::{AbstractEntity, LwePartialKeyswitchKeyEntity};
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+#[cfg(feature = "backend_default_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A structure representing an LWE partial keyswitch key with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LwePartialKeyswitchKey32(pub(crate) ImplLwePartialKeyswitchKey<Vec<u32>>);
+impl AbstractEntity for LwePartialKeyswitchKey32 {
+    type Kind = LwePartialKeyswitchKeyKind;
+}
+impl LwePartialKeyswitchKeyEntity for LwePartialKeyswitchKey32 {
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.0.input_lwe_dimension()
+    }
+
+    fn switched_lwe_dimension(&self) -> LweDimension {
+        self.0.switched_lwe_dimension()
+    }
+
+    fn replacement_lwe_dimension(&self) -> LweDimension {
+        self.0.replacement_lwe_dimension()
+    }
+
+    fn output_lwe_dimension(&self) -> LweDimension {
+        self.0.output_lwe_dimension()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum LwePartialKeyswitchKey32Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
+
+/// A structure representing an LWE partial keyswitch key with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LwePartialKeyswitchKey64(pub(crate) ImplLwePartialKeyswitchKey<Vec<u64>>);
+impl AbstractEntity for LwePartialKeyswitchKey64 {
+    type Kind = LwePartialKeyswitchKeyKind;
+}
+impl LwePartialKeyswitchKeyEntity for LwePartialKeyswitchKey64 {
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.0.input_lwe_dimension()
+    }
+
+    fn switched_lwe_dimension(&self) -> LweDimension {
+        self.0.switched_lwe_dimension()
+    }
+
+    fn replacement_lwe_dimension(&self) -> LweDimension {
+        self.0.replacement_lwe_dimension()
+    }
+
+    fn output_lwe_dimension(&self) -> LweDimension {
+        self.0.output_lwe_dimension()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum LwePartialKeyswitchKey64Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
@@ -1,11 +1,31 @@
 use crate::commons::crypto::secret::LweSecretKey as ImpLweSecretKey;
 use crate::specification::entities::markers::LweSecretKeyKind;
 use crate::specification::entities::{AbstractEntity, LweSecretKeyEntity};
-use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_commons::key_kinds::{BinaryKeyKind, GaussianKeyKind, TernaryKeyKind};
 use concrete_commons::parameters::LweDimension;
 #[cfg(feature = "backend_default_serialization")]
 use serde::{Deserialize, Serialize};
 
+/// A structure representing an LWE secret key with 16 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweSecretKey16(pub(crate) ImpLweSecretKey<BinaryKeyKind, Vec<u16>>);
+impl AbstractEntity for LweSecretKey16 {
+    type Kind = LweSecretKeyKind;
+}
+impl LweSecretKeyEntity for LweSecretKey16 {
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.key_size()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum LweSecretKey16Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
+
 /// A structure representing an LWE secret key with 32 bits of precision.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct LweSecretKey32(pub(crate) ImpLweSecretKey<BinaryKeyKind, Vec<u32>>);
@@ -45,3 +65,83 @@ pub(crate) enum LweSecretKey64Version {
     #[serde(other)]
     Unsupported,
 }
+
+/// A structure representing a ternary LWE secret key with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweSecretKeyTernary32(pub(crate) ImpLweSecretKey<TernaryKeyKind, Vec<u32>>);
+impl AbstractEntity for LweSecretKeyTernary32 {
+    type Kind = LweSecretKeyKind;
+}
+impl LweSecretKeyEntity for LweSecretKeyTernary32 {
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.key_size()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum LweSecretKeyTernary32Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
+
+/// A structure representing a ternary LWE secret key with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweSecretKeyTernary64(pub(crate) ImpLweSecretKey<TernaryKeyKind, Vec<u64>>);
+impl AbstractEntity for LweSecretKeyTernary64 {
+    type Kind = LweSecretKeyKind;
+}
+impl LweSecretKeyEntity for LweSecretKeyTernary64 {
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.key_size()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum LweSecretKeyTernary64Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
+
+/// A structure representing a gaussian LWE secret key with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweSecretKeyGaussian32(pub(crate) ImpLweSecretKey<GaussianKeyKind, Vec<u32>>);
+impl AbstractEntity for LweSecretKeyGaussian32 {
+    type Kind = LweSecretKeyKind;
+}
+impl LweSecretKeyEntity for LweSecretKeyGaussian32 {
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.key_size()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum LweSecretKeyGaussian32Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
+
+/// A structure representing a gaussian LWE secret key with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweSecretKeyGaussian64(pub(crate) ImpLweSecretKey<GaussianKeyKind, Vec<u64>>);
+impl AbstractEntity for LweSecretKeyGaussian64 {
+    type Kind = LweSecretKeyKind;
+}
+impl LweSecretKeyEntity for LweSecretKeyGaussian64 {
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.key_size()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum LweSecretKeyGaussian64Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
@@ -20,6 +20,10 @@ impl LweSeededCiphertextEntity for LweSeededCiphertext32 {
     fn compression_seed(&self) -> CompressionSeed {
         self.0.compression_seed()
     }
+
+    fn generator_byte_index(&self) -> usize {
+        self.0.generator_byte_index()
+    }
 }
 
 #[cfg(feature = "backend_default_serialization")]
@@ -44,6 +48,10 @@ impl LweSeededCiphertextEntity for LweSeededCiphertext64 {
     fn compression_seed(&self) -> CompressionSeed {
         self.0.compression_seed()
     }
+
+    fn generator_byte_index(&self) -> usize {
+        self.0.generator_byte_index()
+    }
 }
 
 #[cfg(feature = "backend_default_serialization")]
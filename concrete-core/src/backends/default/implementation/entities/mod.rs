@@ -7,18 +7,29 @@ mod encoder;
 mod encoder_vector;
 mod ggsw_ciphertext;
 mod ggsw_seeded_ciphertext;
+mod glev_ciphertext;
+mod glwe_automorphism_keys;
 mod glwe_ciphertext;
 mod glwe_ciphertext_vector;
+mod glwe_relinearization_key;
+mod glwe_ring_reduction_key;
+mod glwe_ring_switching_key;
 mod glwe_secret_key;
 mod glwe_seeded_ciphertext;
 mod glwe_seeded_ciphertext_vector;
+mod glwe_tensor_product_ciphertext;
+mod glwe_tensor_product_secret_key;
 mod gsw_ciphertext;
+mod lev_ciphertext;
 mod lwe_bootstrap_key;
+mod lwe_bootstrap_lookup_table;
 mod lwe_ciphertext;
 mod lwe_ciphertext_vector;
 mod lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys;
 mod lwe_keyswitch_key;
+mod lwe_level_major_keyswitch_key;
 mod lwe_packing_keyswitch_key;
+mod lwe_partial_keyswitch_key;
 mod lwe_private_functional_packing_keyswitch_key;
 mod lwe_public_key;
 mod lwe_secret_key;
@@ -26,8 +37,16 @@ mod lwe_seeded_bootstrap_key;
 mod lwe_seeded_ciphertext;
 mod lwe_seeded_ciphertext_vector;
 mod lwe_seeded_keyswitch_key;
+mod lwe_seeded_packing_keyswitch_key;
 mod plaintext;
 mod plaintext_vector;
+mod prepared_ggsw_ciphertext;
+mod solinas_lwe_ciphertext;
+mod solinas_lwe_keyswitch_key;
+mod solinas_lwe_secret_key;
+mod sparse_cleartext_polynomial;
+mod trivial_glwe_ciphertext;
+mod trivial_lwe_ciphertext;
 
 pub use cleartext::*;
 pub use cleartext_vector::*;
@@ -35,18 +54,29 @@ pub use encoder::*;
 pub use encoder_vector::*;
 pub use ggsw_ciphertext::*;
 pub use ggsw_seeded_ciphertext::*;
+pub use glev_ciphertext::*;
+pub use glwe_automorphism_keys::*;
 pub use glwe_ciphertext::*;
 pub use glwe_ciphertext_vector::*;
+pub use glwe_relinearization_key::*;
+pub use glwe_ring_reduction_key::*;
+pub use glwe_ring_switching_key::*;
 pub use glwe_secret_key::*;
 pub use glwe_seeded_ciphertext::*;
 pub use glwe_seeded_ciphertext_vector::*;
+pub use glwe_tensor_product_ciphertext::*;
+pub use glwe_tensor_product_secret_key::*;
 pub use gsw_ciphertext::*;
+pub use lev_ciphertext::*;
 pub use lwe_bootstrap_key::*;
+pub use lwe_bootstrap_lookup_table::*;
 pub use lwe_ciphertext::*;
 pub use lwe_ciphertext_vector::*;
 pub use lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys::*;
 pub use lwe_keyswitch_key::*;
+pub use lwe_level_major_keyswitch_key::*;
 pub use lwe_packing_keyswitch_key::*;
+pub use lwe_partial_keyswitch_key::*;
 pub use lwe_private_functional_packing_keyswitch_key::*;
 pub use lwe_public_key::*;
 pub use lwe_secret_key::*;
@@ -54,5 +84,13 @@ pub use lwe_seeded_bootstrap_key::*;
 pub use lwe_seeded_ciphertext::*;
 pub use lwe_seeded_ciphertext_vector::*;
 pub use lwe_seeded_keyswitch_key::*;
+pub use lwe_seeded_packing_keyswitch_key::*;
 pub use plaintext::*;
 pub use plaintext_vector::*;
+pub use prepared_ggsw_ciphertext::*;
+pub use solinas_lwe_ciphertext::*;
+pub use solinas_lwe_keyswitch_key::*;
+pub use solinas_lwe_secret_key::*;
+pub use sparse_cleartext_polynomial::*;
+pub use trivial_glwe_ciphertext::*;
+pub use trivial_lwe_ciphertext::*;
@@ -4,6 +4,22 @@ use crate::specification::entities::{AbstractEntity, PlaintextEntity};
 #[cfg(feature = "backend_default_serialization")]
 use serde::{Deserialize, Serialize};
 
+/// A structure representing a plaintext with 16 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plaintext16(pub(crate) ImplPlaintext<u16>);
+impl AbstractEntity for Plaintext16 {
+    type Kind = PlaintextKind;
+}
+impl PlaintextEntity for Plaintext16 {}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum Plaintext16Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
+
 /// A structure representing a plaintext with 32 bits of precision.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Plaintext32(pub(crate) ImplPlaintext<u32>);
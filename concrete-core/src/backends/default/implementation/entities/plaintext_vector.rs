@@ -44,3 +44,44 @@ pub(crate) enum PlaintextVector64Version {
     #[serde(other)]
     Unsupported,
 }
+
+// PlaintextVectorViews are just PlaintextVector entities that do not own their memory, they use a
+// slice as a container as opposed to Vec for the standard PlaintextVector
+
+/// A structure representing a vector of plaintexts view, with 32 bits of precision.
+///
+/// By _view_ here, we mean that the entity does not own the data, but immutably borrows it.
+///
+/// Notes:
+/// ------
+/// This view is not Clone as Clone for a slice is not defined. It is not Deserialize either,
+/// as Deserialize of a slice is not defined. Immutable variant.
+#[derive(Debug, PartialEq, Eq)]
+pub struct PlaintextVectorView32<'a>(pub(crate) ImplPlaintextList<&'a [u32]>);
+impl AbstractEntity for PlaintextVectorView32<'_> {
+    type Kind = PlaintextVectorKind;
+}
+impl PlaintextVectorEntity for PlaintextVectorView32<'_> {
+    fn plaintext_count(&self) -> PlaintextCount {
+        self.0.count()
+    }
+}
+
+/// A structure representing a vector of plaintexts view, with 64 bits of precision.
+///
+/// By _view_ here, we mean that the entity does not own the data, but immutably borrows it.
+///
+/// Notes:
+/// ------
+/// This view is not Clone as Clone for a slice is not defined. It is not Deserialize either,
+/// as Deserialize of a slice is not defined. Immutable variant.
+#[derive(Debug, PartialEq, Eq)]
+pub struct PlaintextVectorView64<'a>(pub(crate) ImplPlaintextList<&'a [u64]>);
+impl AbstractEntity for PlaintextVectorView64<'_> {
+    type Kind = PlaintextVectorKind;
+}
+impl PlaintextVectorEntity for PlaintextVectorView64<'_> {
+    fn plaintext_count(&self) -> PlaintextCount {
+        self.0.count()
+    }
+}
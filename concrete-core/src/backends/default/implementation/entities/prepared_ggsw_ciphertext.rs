@@ -0,0 +1,56 @@
+use crate::commons::crypto::ggsw::PreparedGgswCiphertext as ImplPreparedGgswCiphertext;
+use crate::specification::entities::markers::GgswCiphertextKind;
+use crate::specification::entities::{AbstractEntity, GgswCiphertextEntity};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+
+/// A structure representing a GGSW ciphertext with 32 bits of precision, prepared for repeated
+/// external products.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PreparedGgswCiphertext32(pub(crate) ImplPreparedGgswCiphertext<Vec<u32>>);
+impl AbstractEntity for PreparedGgswCiphertext32 {
+    type Kind = GgswCiphertextKind;
+}
+impl GgswCiphertextEntity for PreparedGgswCiphertext32 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
+
+/// A structure representing a GGSW ciphertext with 64 bits of precision, prepared for repeated
+/// external products.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PreparedGgswCiphertext64(pub(crate) ImplPreparedGgswCiphertext<Vec<u64>>);
+impl AbstractEntity for PreparedGgswCiphertext64 {
+    type Kind = GgswCiphertextKind;
+}
+impl GgswCiphertextEntity for PreparedGgswCiphertext64 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
@@ -0,0 +1,25 @@
+use crate::commons::crypto::lwe::LweCiphertext as ImplLweCiphertext;
+use crate::commons::math::modular::{NonNativeModulus, SolinasPrime64};
+use crate::specification::entities::markers::LweCiphertextKind;
+use crate::specification::entities::{AbstractEntity, LweCiphertextEntity};
+use concrete_commons::parameters::{CiphertextModulus, LweDimension};
+
+/// A structure representing an LWE ciphertext modulo the Solinas prime $q = 2^{64} - 2^{32} + 1$.
+///
+/// The coefficients of the ciphertext are stored in `u64` integers in $[0, q)$. Since its modulus
+/// differs from the native one, such a ciphertext can not be mixed with an [`LweCiphertext64`](
+/// super::LweCiphertext64).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolinasLweCiphertext64(pub(crate) ImplLweCiphertext<Vec<u64>>);
+impl AbstractEntity for SolinasLweCiphertext64 {
+    type Kind = LweCiphertextKind;
+}
+impl LweCiphertextEntity for SolinasLweCiphertext64 {
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_size().to_lwe_dimension()
+    }
+
+    fn ciphertext_modulus(&self) -> CiphertextModulus {
+        CiphertextModulus::Custom(SolinasPrime64.value() as u128)
+    }
+}
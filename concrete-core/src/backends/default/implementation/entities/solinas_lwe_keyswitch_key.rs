@@ -0,0 +1,30 @@
+use crate::commons::crypto::lwe::LweKeyswitchKey as ImplLweKeyswitchKey;
+use crate::specification::entities::markers::LweKeyswitchKeyKind;
+use crate::specification::entities::{AbstractEntity, LweKeyswitchKeyEntity};
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+
+/// A structure representing an LWE keyswitch key modulo the Solinas prime
+/// $q = 2^{64} - 2^{32} + 1$, which switches the key of [`SolinasLweCiphertext64`](
+/// super::SolinasLweCiphertext64) ciphertexts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolinasLweKeyswitchKey64(pub(crate) ImplLweKeyswitchKey<Vec<u64>>);
+impl AbstractEntity for SolinasLweKeyswitchKey64 {
+    type Kind = LweKeyswitchKeyKind;
+}
+impl LweKeyswitchKeyEntity for SolinasLweKeyswitchKey64 {
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.0.before_key_size()
+    }
+
+    fn output_lwe_dimension(&self) -> LweDimension {
+        self.0.after_key_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_levels_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
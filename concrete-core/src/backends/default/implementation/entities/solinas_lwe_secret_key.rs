@@ -0,0 +1,19 @@
+use crate::commons::crypto::secret::LweSecretKey as ImpLweSecretKey;
+use crate::specification::entities::markers::LweSecretKeyKind;
+use crate::specification::entities::{AbstractEntity, LweSecretKeyEntity};
+use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_commons::parameters::LweDimension;
+
+/// A structure representing a binary LWE secret key used to encrypt
+/// [`SolinasLweCiphertext64`](super::SolinasLweCiphertext64) ciphertexts, modulo the Solinas prime
+/// $q = 2^{64} - 2^{32} + 1$.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolinasLweSecretKey64(pub(crate) ImpLweSecretKey<BinaryKeyKind, Vec<u64>>);
+impl AbstractEntity for SolinasLweSecretKey64 {
+    type Kind = LweSecretKeyKind;
+}
+impl LweSecretKeyEntity for SolinasLweSecretKey64 {
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.key_size()
+    }
+}
@@ -0,0 +1,54 @@
+use crate::commons::math::polynomial::SparsePolynomial as ImplSparsePolynomial;
+use crate::specification::entities::markers::SparseCleartextPolynomialKind;
+use crate::specification::entities::{AbstractEntity, SparseCleartextPolynomialEntity};
+use concrete_commons::parameters::{MonomialCount, PolynomialSize};
+#[cfg(feature = "backend_default_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A structure representing a sparse cleartext polynomial with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseCleartextPolynomial32(pub(crate) ImplSparsePolynomial<Vec<u32>>);
+impl AbstractEntity for SparseCleartextPolynomial32 {
+    type Kind = SparseCleartextPolynomialKind;
+}
+impl SparseCleartextPolynomialEntity for SparseCleartextPolynomial32 {
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn monomial_count(&self) -> MonomialCount {
+        self.0.monomial_count()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum SparseCleartextPolynomial32Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
+
+/// A structure representing a sparse cleartext polynomial with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseCleartextPolynomial64(pub(crate) ImplSparsePolynomial<Vec<u64>>);
+impl AbstractEntity for SparseCleartextPolynomial64 {
+    type Kind = SparseCleartextPolynomialKind;
+}
+impl SparseCleartextPolynomialEntity for SparseCleartextPolynomial64 {
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn monomial_count(&self) -> MonomialCount {
+        self.0.monomial_count()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum SparseCleartextPolynomial64Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
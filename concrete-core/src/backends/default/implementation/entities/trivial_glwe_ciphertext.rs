@@ -0,0 +1,58 @@
+use crate::commons::crypto::glwe::GlweCiphertext as ImplGlweCiphertext;
+use crate::specification::entities::markers::TrivialGlweCiphertextKind;
+use crate::specification::entities::{AbstractEntity, TrivialGlweCiphertextEntity};
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+#[cfg(feature = "backend_default_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A structure representing a trivial GLWE ciphertext with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrivialGlweCiphertext32(pub(crate) ImplGlweCiphertext<Vec<u32>>);
+
+impl AbstractEntity for TrivialGlweCiphertext32 {
+    type Kind = TrivialGlweCiphertextKind;
+}
+
+impl TrivialGlweCiphertextEntity for TrivialGlweCiphertext32 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum TrivialGlweCiphertext32Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
+
+/// A structure representing a trivial GLWE ciphertext with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrivialGlweCiphertext64(pub(crate) ImplGlweCiphertext<Vec<u64>>);
+
+impl AbstractEntity for TrivialGlweCiphertext64 {
+    type Kind = TrivialGlweCiphertextKind;
+}
+
+impl TrivialGlweCiphertextEntity for TrivialGlweCiphertext64 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum TrivialGlweCiphertext64Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
@@ -0,0 +1,46 @@
+use crate::commons::crypto::lwe::LweCiphertext as ImplLweCiphertext;
+use crate::specification::entities::markers::TrivialLweCiphertextKind;
+use crate::specification::entities::{AbstractEntity, TrivialLweCiphertextEntity};
+use concrete_commons::parameters::LweDimension;
+#[cfg(feature = "backend_default_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A structure representing a trivial LWE ciphertext with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrivialLweCiphertext32(pub(crate) ImplLweCiphertext<Vec<u32>>);
+impl AbstractEntity for TrivialLweCiphertext32 {
+    type Kind = TrivialLweCiphertextKind;
+}
+impl TrivialLweCiphertextEntity for TrivialLweCiphertext32 {
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_size().to_lwe_dimension()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum TrivialLweCiphertext32Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
+
+/// A structure representing a trivial LWE ciphertext with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrivialLweCiphertext64(pub(crate) ImplLweCiphertext<Vec<u64>>);
+impl AbstractEntity for TrivialLweCiphertext64 {
+    type Kind = TrivialLweCiphertextKind;
+}
+impl TrivialLweCiphertextEntity for TrivialLweCiphertext64 {
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_size().to_lwe_dimension()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum TrivialLweCiphertext64Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
@@ -0,0 +1,359 @@
+//! Conversions between the entities of the default backend and the layouts used by `tfhe-rs`.
+//!
+//! Both libraries store their keys and ciphertexts as flat containers of unsigned integers, so that
+//! sharing an entity between a deployment using this crate and one using `tfhe-rs` only requires
+//! to agree on the order of the coefficients in the container:
+//!
+//! + LWE and GLWE secret keys are stored the same way, the GLWE keys being a list of polynomials.
+//! + LWE ciphertexts are stored as the mask followed by the body, and GLWE ciphertexts as the
+//!   mask polynomials followed by the body polynomial, in both libraries.
+//! + GGSW ciphertexts and LWE bootstrap keys (which are lists of GGSW ciphertexts) are stored the
+//!   same way, as lists of level matrices starting with the first (most significant) level, each
+//!   matrix being a list of $k+1$ GLWE ciphertexts.
+//! + LWE keyswitch keys are lists of blocks, one per coefficient of the input key, each block
+//!   being a list of LWE ciphertexts, one per level. This crate stores the levels of a block
+//!   starting with the first (most significant) one, whereas `tfhe-rs` stores them starting with
+//!   the last one. The conversion of keyswitch keys hence reverses the order of the levels within
+//!   each block.
+//!
+//! The Fourier transforms used by the two libraries differ, and so do the layouts of the entities
+//! in the Fourier domain. Bootstrap keys and GGSW ciphertexts must hence be exchanged in the
+//! standard domain, and converted to the Fourier domain by each library.
+//!
+//! Both libraries use the native modulus $2^{32}$ or $2^{64}$ by default. The entities of
+//! `tfhe-rs` using another ciphertext modulus can not be converted.
+//!
+//! # Example
+//!
+//! ```rust
+//! use concrete_commons::dispersion::Variance;
+//! use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+//! use concrete_core::backends::default::interop::TfheRsInterop;
+//! use concrete_core::prelude::*;
+//! # use std::error::Error;
+//!
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! // Unix seeder must be given a secret input.
+//! // Here we just give it 0, which is totally unsafe.
+//! const UNSAFE_SECRET: u128 = 0;
+//! let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+//! let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(LweDimension(10))?;
+//! let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(LweDimension(4))?;
+//! let ksk: LweKeyswitchKey64 = engine.generate_new_lwe_keyswitch_key(
+//!     &input_key,
+//!     &output_key,
+//!     DecompositionLevelCount(3),
+//!     DecompositionBaseLog(4),
+//!     Variance(2_f64.powf(-50.)),
+//! )?;
+//!
+//! // The container can be given to `tfhe_rs::core_crypto::entities::LweKeyswitchKey`.
+//! let container = ksk.to_tfhe_rs_container();
+//! let imported = LweKeyswitchKey64::from_tfhe_rs_container(
+//!     container,
+//!     (
+//!         DecompositionBaseLog(4),
+//!         DecompositionLevelCount(3),
+//!         LweDimension(4).to_lwe_size(),
+//!     ),
+//! )?;
+//! assert_eq!(imported, ksk);
+//! #
+//! # Ok(())
+//! # }
+//! ```
+use crate::backends::default::implementation::entities::{
+    GgswCiphertext32, GgswCiphertext64, GlweCiphertext32, GlweCiphertext64, GlweSecretKey32,
+    GlweSecretKey64, LweBootstrapKey32, LweBootstrapKey64, LweCiphertext32, LweCiphertext64,
+    LweKeyswitchKey32, LweKeyswitchKey64, LweSecretKey32, LweSecretKey64,
+};
+use crate::commons::crypto::bootstrap::StandardBootstrapKey as ImplStandardBootstrapKey;
+use crate::commons::crypto::ggsw::StandardGgswCiphertext as ImplStandardGgswCiphertext;
+use crate::commons::crypto::glwe::GlweCiphertext as ImplGlweCiphertext;
+use crate::commons::crypto::lwe::{
+    LweCiphertext as ImplLweCiphertext, LweKeyswitchKey as ImplLweKeyswitchKey,
+};
+use crate::commons::crypto::secret::{
+    GlweSecretKey as ImplGlweSecretKey, LweSecretKey as ImplLweSecretKey,
+};
+use crate::commons::math::tensor::{AsRefSlice, AsRefTensor};
+use concrete_commons::numeric::UnsignedInteger;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweSize, PolynomialSize,
+};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// The error which can occur when importing an entity from a `tfhe-rs` container.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TfheRsInteropError {
+    /// The length of the container does not match the parameters of the entity.
+    InvalidLength,
+    /// The container of a secret key has coefficients which are neither zero nor one.
+    NonBinarySecretKey,
+}
+
+impl Display for TfheRsInteropError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TfheRsInteropError::InvalidLength => write!(
+                f,
+                "The length of the container does not match the parameters of the entity."
+            ),
+            TfheRsInteropError::NonBinarySecretKey => {
+                write!(f, "The coefficients of the secret key must be binary.")
+            }
+        }
+    }
+}
+
+impl Error for TfheRsInteropError {}
+
+/// A trait for the entities which can be exchanged with `tfhe-rs`.
+pub trait TfheRsInterop: Sized {
+    /// The type of the coefficients of the entity.
+    type Scalar: UnsignedInteger;
+
+    /// The parameters needed to interpret a container, given in the order of the arguments of the
+    /// `from_container` constructor of the matching `tfhe-rs` entity.
+    type Parameters;
+
+    /// Returns the coefficients of the entity, in the order used by `tfhe-rs`.
+    fn to_tfhe_rs_container(&self) -> Vec<Self::Scalar>;
+
+    /// Creates an entity from the coefficients of a `tfhe-rs` entity.
+    fn from_tfhe_rs_container(
+        container: Vec<Self::Scalar>,
+        parameters: Self::Parameters,
+    ) -> Result<Self, TfheRsInteropError>;
+}
+
+// Checks that a non empty container can be split in chunks of `chunk_size` coefficients.
+fn check_length<Scalar>(container: &[Scalar], chunk_size: usize) -> Result<(), TfheRsInteropError> {
+    if chunk_size == 0 || container.is_empty() || !container.len().is_multiple_of(chunk_size) {
+        return Err(TfheRsInteropError::InvalidLength);
+    }
+    Ok(())
+}
+
+fn check_binary<Scalar: UnsignedInteger>(container: &[Scalar]) -> Result<(), TfheRsInteropError> {
+    if container
+        .iter()
+        .any(|c| *c != Scalar::ZERO && *c != Scalar::ONE)
+    {
+        return Err(TfheRsInteropError::NonBinarySecretKey);
+    }
+    Ok(())
+}
+
+// Reverses the order of the levels in each block of a keyswitch key container. The operation is
+// its own inverse, and converts containers in both directions.
+fn reverse_keyswitch_key_levels<Scalar: Copy>(
+    container: &[Scalar],
+    level_count: DecompositionLevelCount,
+    lwe_size: LweSize,
+) -> Vec<Scalar> {
+    let mut output = Vec::with_capacity(container.len());
+    for block in container.chunks_exact(level_count.0 * lwe_size.0) {
+        for level in block.chunks_exact(lwe_size.0).rev() {
+            output.extend_from_slice(level);
+        }
+    }
+    output
+}
+
+macro_rules! implement {
+    ($scalar:ty,
+        $lwe_secret_key:ident,
+        $glwe_secret_key:ident,
+        $lwe_ciphertext:ident,
+        $glwe_ciphertext:ident,
+        $ggsw_ciphertext:ident,
+        $lwe_keyswitch_key:ident,
+        $lwe_bootstrap_key:ident
+    ) => {
+        impl TfheRsInterop for $lwe_secret_key {
+            type Scalar = $scalar;
+            type Parameters = ();
+
+            fn to_tfhe_rs_container(&self) -> Vec<$scalar> {
+                self.0.as_tensor().as_slice().to_vec()
+            }
+
+            fn from_tfhe_rs_container(
+                container: Vec<$scalar>,
+                _parameters: (),
+            ) -> Result<Self, TfheRsInteropError> {
+                check_length(&container, 1)?;
+                check_binary(&container)?;
+                Ok($lwe_secret_key(ImplLweSecretKey::binary_from_container(
+                    container,
+                )))
+            }
+        }
+
+        impl TfheRsInterop for $glwe_secret_key {
+            type Scalar = $scalar;
+            type Parameters = PolynomialSize;
+
+            fn to_tfhe_rs_container(&self) -> Vec<$scalar> {
+                self.0.as_tensor().as_slice().to_vec()
+            }
+
+            fn from_tfhe_rs_container(
+                container: Vec<$scalar>,
+                polynomial_size: PolynomialSize,
+            ) -> Result<Self, TfheRsInteropError> {
+                check_length(&container, polynomial_size.0)?;
+                check_binary(&container)?;
+                Ok($glwe_secret_key(ImplGlweSecretKey::binary_from_container(
+                    container,
+                    polynomial_size,
+                )))
+            }
+        }
+
+        impl TfheRsInterop for $lwe_ciphertext {
+            type Scalar = $scalar;
+            type Parameters = ();
+
+            fn to_tfhe_rs_container(&self) -> Vec<$scalar> {
+                self.0.as_tensor().as_slice().to_vec()
+            }
+
+            fn from_tfhe_rs_container(
+                container: Vec<$scalar>,
+                _parameters: (),
+            ) -> Result<Self, TfheRsInteropError> {
+                check_length(&container, 1)?;
+                Ok($lwe_ciphertext(ImplLweCiphertext::from_container(
+                    container,
+                )))
+            }
+        }
+
+        impl TfheRsInterop for $glwe_ciphertext {
+            type Scalar = $scalar;
+            type Parameters = PolynomialSize;
+
+            fn to_tfhe_rs_container(&self) -> Vec<$scalar> {
+                self.0.as_tensor().as_slice().to_vec()
+            }
+
+            fn from_tfhe_rs_container(
+                container: Vec<$scalar>,
+                polynomial_size: PolynomialSize,
+            ) -> Result<Self, TfheRsInteropError> {
+                check_length(&container, polynomial_size.0)?;
+                Ok($glwe_ciphertext(ImplGlweCiphertext::from_container(
+                    container,
+                    polynomial_size,
+                )))
+            }
+        }
+
+        impl TfheRsInterop for $ggsw_ciphertext {
+            type Scalar = $scalar;
+            type Parameters = (GlweSize, PolynomialSize, DecompositionBaseLog);
+
+            fn to_tfhe_rs_container(&self) -> Vec<$scalar> {
+                self.0.as_tensor().as_slice().to_vec()
+            }
+
+            fn from_tfhe_rs_container(
+                container: Vec<$scalar>,
+                (glwe_size, polynomial_size, base_log): Self::Parameters,
+            ) -> Result<Self, TfheRsInteropError> {
+                check_length(&container, glwe_size.0 * glwe_size.0 * polynomial_size.0)?;
+                Ok($ggsw_ciphertext(
+                    ImplStandardGgswCiphertext::from_container(
+                        container,
+                        glwe_size,
+                        polynomial_size,
+                        base_log,
+                    ),
+                ))
+            }
+        }
+
+        impl TfheRsInterop for $lwe_keyswitch_key {
+            type Scalar = $scalar;
+            type Parameters = (DecompositionBaseLog, DecompositionLevelCount, LweSize);
+
+            fn to_tfhe_rs_container(&self) -> Vec<$scalar> {
+                reverse_keyswitch_key_levels(
+                    self.0.as_tensor().as_slice(),
+                    self.0.decomposition_levels_count(),
+                    self.0.lwe_size(),
+                )
+            }
+
+            fn from_tfhe_rs_container(
+                container: Vec<$scalar>,
+                (base_log, level_count, output_lwe_size): Self::Parameters,
+            ) -> Result<Self, TfheRsInteropError> {
+                check_length(&container, level_count.0 * output_lwe_size.0)?;
+                Ok($lwe_keyswitch_key(ImplLweKeyswitchKey::from_container(
+                    reverse_keyswitch_key_levels(&container, level_count, output_lwe_size),
+                    base_log,
+                    level_count,
+                    output_lwe_size.to_lwe_dimension(),
+                )))
+            }
+        }
+
+        impl TfheRsInterop for $lwe_bootstrap_key {
+            type Scalar = $scalar;
+            type Parameters = (
+                GlweSize,
+                PolynomialSize,
+                DecompositionBaseLog,
+                DecompositionLevelCount,
+            );
+
+            fn to_tfhe_rs_container(&self) -> Vec<$scalar> {
+                self.0.as_tensor().as_slice().to_vec()
+            }
+
+            fn from_tfhe_rs_container(
+                container: Vec<$scalar>,
+                (glwe_size, polynomial_size, base_log, level_count): Self::Parameters,
+            ) -> Result<Self, TfheRsInteropError> {
+                check_length(
+                    &container,
+                    glwe_size.0 * glwe_size.0 * polynomial_size.0 * level_count.0,
+                )?;
+                Ok($lwe_bootstrap_key(
+                    ImplStandardBootstrapKey::from_container(
+                        container,
+                        glwe_size,
+                        polynomial_size,
+                        level_count,
+                        base_log,
+                    ),
+                ))
+            }
+        }
+    };
+}
+
+implement!(
+    u32,
+    LweSecretKey32,
+    GlweSecretKey32,
+    LweCiphertext32,
+    GlweCiphertext32,
+    GgswCiphertext32,
+    LweKeyswitchKey32,
+    LweBootstrapKey32
+);
+implement!(
+    u64,
+    LweSecretKey64,
+    GlweSecretKey64,
+    LweCiphertext64,
+    GlweCiphertext64,
+    GgswCiphertext64,
+    LweKeyswitchKey64,
+    LweBootstrapKey64
+);
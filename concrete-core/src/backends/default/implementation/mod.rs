@@ -1,2 +1,4 @@
 pub mod engines;
 pub mod entities;
+#[cfg(feature = "interop")]
+pub mod interop;
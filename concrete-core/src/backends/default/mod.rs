@@ -3,3 +3,6 @@
 mod implementation;
 
 pub use implementation::{engines, entities};
+
+#[cfg(feature = "interop")]
+pub use implementation::interop;
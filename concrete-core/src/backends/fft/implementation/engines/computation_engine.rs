@@ -1,6 +1,8 @@
 use concrete_commons::parameters::PolynomialSize;
 use dyn_stack::DynStack;
 
+use super::super::super::private::crypto::bootstrap::FourierLweBootstrapKeyLayout;
+
 use crate::specification::engines::sealed::AbstractEngineSeal;
 use crate::specification::engines::AbstractEngine;
 use core::mem::MaybeUninit;
@@ -10,6 +12,7 @@ use core::mem::MaybeUninit;
 #[non_exhaustive]
 pub enum FftError {
     UnsupportedPolynomialSize,
+    NullSampleCount,
 }
 
 impl core::fmt::Display for FftError {
@@ -19,6 +22,9 @@ impl core::fmt::Display for FftError {
                 "The Concrete-FFT backend only supports polynomials of sizes that are powers of two \
                     and greater than or equal to 32.",
             ),
+            FftError::NullSampleCount => {
+                f.write_str("The FFT error can not be measured on an empty sample.")
+            }
         }
     }
 }
@@ -28,9 +34,70 @@ impl std::error::Error for FftError {}
 /// The main engine exposed by the Concrete-FFT backend.
 pub struct FftEngine {
     memory: Vec<MaybeUninit<u8>>,
+    bootstrap_key_layout: FourierLweBootstrapKeyLayout,
 }
 
 impl FftEngine {
+    /// Sets the memory layout of the bootstrap keys converted to the Fourier domain by this
+    /// engine.
+    ///
+    /// The keys already converted keep their layout, and can be used by any engine. The default
+    /// layout is [`FourierLweBootstrapKeyLayout::GgswMajor`].
+    ///
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(1), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey64 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// fft_engine.set_bootstrap_key_layout(FourierLweBootstrapKeyLayout::LevelMajor);
+    /// let fourier_bsk: FftFourierLweBootstrapKey64 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// #
+    /// assert_eq!(fourier_bsk.layout(), FourierLweBootstrapKeyLayout::LevelMajor);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_bootstrap_key_layout(&mut self, layout: FourierLweBootstrapKeyLayout) {
+        self.bootstrap_key_layout = layout;
+    }
+
+    /// Returns the memory layout of the bootstrap keys converted to the Fourier domain by this
+    /// engine.
+    pub fn bootstrap_key_layout(&self) -> FourierLweBootstrapKeyLayout {
+        self.bootstrap_key_layout
+    }
+
+    /// Returns the size of the scratch memory held by this engine, in bytes.
+    ///
+    /// The scratch memory grows to fit the largest operation executed so far, and is never shrunk.
+    /// This can be used as the scratch memory probe of a
+    /// [`StatisticsEngine`](crate::specification::engines::statistics::StatisticsEngine).
+    pub fn scratch_memory_size(&self) -> usize {
+        self.memory.len()
+    }
+
     pub(crate) fn resize(&mut self, capacity: usize) {
         self.memory.resize_with(capacity, MaybeUninit::uninit);
     }
@@ -54,6 +121,9 @@ impl AbstractEngine for FftEngine {
     type Parameters = ();
 
     fn new(_parameter: Self::Parameters) -> Result<Self, Self::EngineError> {
-        Ok(FftEngine { memory: Vec::new() })
+        Ok(FftEngine {
+            memory: Vec::new(),
+            bootstrap_key_layout: FourierLweBootstrapKeyLayout::default(),
+        })
     }
 }
@@ -15,6 +15,7 @@ use concrete_commons::parameters::{
 };
 use concrete_fft::c64;
 use serde::Deserialize;
+use std::io::Read;
 
 impl EntityDeserializationEngine<&[u8], FftFourierGgswCiphertext32> for FftSerializationEngine {
     /// # Example
@@ -64,15 +65,27 @@ impl EntityDeserializationEngine<&[u8], FftFourierGgswCiphertext32> for FftSeria
     fn deserialize(
         &mut self,
         serialized: &[u8],
+    ) -> Result<FftFourierGgswCiphertext32, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> FftFourierGgswCiphertext32 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
     ) -> Result<FftFourierGgswCiphertext32, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
         struct SerializableFftFourierGgswCiphertext32 {
             version: FftFourierGgswCiphertext32Version,
             inner: FourierGgswCiphertext,
         }
-        let deserialized: SerializableFftFourierGgswCiphertext32 = bincode::deserialize(serialized)
-            .map_err(FftSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
+        let deserialized: SerializableFftFourierGgswCiphertext32 =
+            bincode::deserialize_from(reader)
+                .map_err(FftSerializationError::Deserialization)
+                .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             SerializableFftFourierGgswCiphertext32 {
                 version: FftFourierGgswCiphertext32Version::Unsupported,
@@ -87,8 +100,11 @@ impl EntityDeserializationEngine<&[u8], FftFourierGgswCiphertext32> for FftSeria
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> FftFourierGgswCiphertext32 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> FftFourierGgswCiphertext32 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
 
@@ -141,15 +157,27 @@ impl EntityDeserializationEngine<&[u8], FftFourierGgswCiphertext64> for FftSeria
     fn deserialize(
         &mut self,
         serialized: &[u8],
+    ) -> Result<FftFourierGgswCiphertext64, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> FftFourierGgswCiphertext64 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
     ) -> Result<FftFourierGgswCiphertext64, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
         struct SerializableFftFourierGgswCiphertext64 {
             version: FftFourierGgswCiphertext64Version,
             inner: FourierGgswCiphertext,
         }
-        let deserialized: SerializableFftFourierGgswCiphertext64 = bincode::deserialize(serialized)
-            .map_err(FftSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
+        let deserialized: SerializableFftFourierGgswCiphertext64 =
+            bincode::deserialize_from(reader)
+                .map_err(FftSerializationError::Deserialization)
+                .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             SerializableFftFourierGgswCiphertext64 {
                 version: FftFourierGgswCiphertext64Version::Unsupported,
@@ -164,8 +192,11 @@ impl EntityDeserializationEngine<&[u8], FftFourierGgswCiphertext64> for FftSeria
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> FftFourierGgswCiphertext64 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> FftFourierGgswCiphertext64 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
 
@@ -210,6 +241,17 @@ impl EntityDeserializationEngine<&[u8], FftFourierLweBootstrapKey32> for FftSeri
     fn deserialize(
         &mut self,
         serialized: &[u8],
+    ) -> Result<FftFourierLweBootstrapKey32, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> FftFourierLweBootstrapKey32 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
     ) -> Result<FftFourierLweBootstrapKey32, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
         struct SerializableFftFourierLweBootstrapKey32 {
@@ -217,7 +259,7 @@ impl EntityDeserializationEngine<&[u8], FftFourierLweBootstrapKey32> for FftSeri
             inner: FourierLweBootstrapKey,
         }
         let deserialized: SerializableFftFourierLweBootstrapKey32 =
-            bincode::deserialize(serialized)
+            bincode::deserialize_from(reader)
                 .map_err(FftSerializationError::Deserialization)
                 .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
@@ -234,8 +276,11 @@ impl EntityDeserializationEngine<&[u8], FftFourierLweBootstrapKey32> for FftSeri
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> FftFourierLweBootstrapKey32 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> FftFourierLweBootstrapKey32 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
 
@@ -280,6 +325,17 @@ impl EntityDeserializationEngine<&[u8], FftFourierLweBootstrapKey64> for FftSeri
     fn deserialize(
         &mut self,
         serialized: &[u8],
+    ) -> Result<FftFourierLweBootstrapKey64, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> FftFourierLweBootstrapKey64 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
     ) -> Result<FftFourierLweBootstrapKey64, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
         struct SerializableFftFourierLweBootstrapKey64 {
@@ -287,7 +343,7 @@ impl EntityDeserializationEngine<&[u8], FftFourierLweBootstrapKey64> for FftSeri
             inner: FourierLweBootstrapKey,
         }
         let deserialized: SerializableFftFourierLweBootstrapKey64 =
-            bincode::deserialize(serialized)
+            bincode::deserialize_from(reader)
                 .map_err(FftSerializationError::Deserialization)
                 .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
@@ -304,7 +360,10 @@ impl EntityDeserializationEngine<&[u8], FftFourierLweBootstrapKey64> for FftSeri
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> FftFourierLweBootstrapKey64 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> FftFourierLweBootstrapKey64 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
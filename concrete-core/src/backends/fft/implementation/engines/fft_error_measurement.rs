@@ -0,0 +1,136 @@
+use super::super::super::private::math::fft::Fft;
+use super::super::super::private::math::polynomial::{FourierPolynomial, Polynomial};
+use super::{FftEngine, FftError};
+use crate::commons::math::torus::UnsignedTorus;
+use aligned_vec::avec;
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::PolynomialSize;
+use concrete_fft::c64;
+use dyn_stack::{ReborrowMut, StackReq};
+
+/// The error introduced by a forward and backward Fourier transform, as measured by
+/// [`FftEngine::measure_fft_round_trip_error`].
+///
+/// The errors are expressed on the torus, i.e. as a fraction of the ciphertext modulus, so that
+/// the [`variance`](`Self::variance`) can be combined with the outputs of the noise formulas of
+/// `concrete-npe`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FftRoundTripError {
+    /// The size of the transformed polynomials.
+    pub polynomial_size: PolynomialSize,
+    /// The number of bits of the integers holding the polynomial coefficients.
+    pub precision: usize,
+    /// The largest error measured on a coefficient.
+    pub max_error: f64,
+    /// The variance of the error on a coefficient.
+    pub variance: Variance,
+}
+
+impl FftEngine {
+    /// Measures the error introduced by a forward and backward Fourier transform of polynomials
+    /// with coefficients of type `Scalar`, on the current machine.
+    ///
+    /// The transforms are applied to `sample_count` polynomials with uniformly random
+    /// coefficients, generated from a fixed seed so that the measurements are reproducible. The
+    /// error depends on the polynomial size and on the precision of the coefficients, and is
+    /// meant to be measured once for each set of parameters used with this engine.
+    ///
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::DispersionParameter;
+    /// use concrete_commons::parameters::PolynomialSize;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = FftEngine::new(())?;
+    /// let error = engine.measure_fft_round_trip_error::<u64>(PolynomialSize(1024), 10)?;
+    ///
+    /// assert_eq!(error.precision, 64);
+    /// assert!(error.max_error < 2_f64.powi(-30));
+    /// assert!(error.variance.get_variance() <= error.max_error * error.max_error);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn measure_fft_round_trip_error<Scalar: UnsignedTorus>(
+        &mut self,
+        polynomial_size: PolynomialSize,
+        sample_count: usize,
+    ) -> Result<FftRoundTripError, FftError> {
+        FftEngine::check_supported_size(polynomial_size)?;
+        if sample_count == 0 {
+            return Err(FftError::NullSampleCount);
+        }
+
+        let fft = Fft::new(polynomial_size);
+        let fft = fft.as_view();
+        self.resize(
+            StackReq::try_any_of([
+                fft.forward_scratch().unwrap(),
+                fft.backward_scratch().unwrap(),
+            ])
+            .unwrap()
+            .unaligned_bytes_required(),
+        );
+        let mut stack = self.stack();
+
+        let mut standard = Polynomial {
+            data: avec![Scalar::ZERO; polynomial_size.0].into_boxed_slice(),
+        };
+        let mut round_trip = Polynomial {
+            data: avec![Scalar::ZERO; polynomial_size.0].into_boxed_slice(),
+        };
+        let mut fourier = FourierPolynomial {
+            data: avec![c64::default(); polynomial_size.0 / 2].into_boxed_slice(),
+        };
+
+        let mut state = 0_u64;
+        let mut max_error = 0_f64;
+        let mut squared_error_sum = 0_f64;
+        for _ in 0..sample_count {
+            for coefficient in standard.data.iter_mut() {
+                *coefficient = Scalar::from_torus(next_uniform_torus(&mut state));
+            }
+
+            // SAFETY: forward_as_torus doesn't write any uninitialized values into its output
+            fft.forward_as_torus(
+                unsafe { fourier.as_mut_view().into_uninit() },
+                standard.as_view(),
+                stack.rb_mut(),
+            );
+            // SAFETY: backward_as_torus doesn't write any uninitialized values into its output
+            fft.backward_as_torus(
+                unsafe { round_trip.as_mut_view().into_uninit() },
+                fourier.as_view(),
+                stack.rb_mut(),
+            );
+
+            for (expected, actual) in standard.data.iter().zip(round_trip.data.iter()) {
+                // The difference is centered around zero before being measured.
+                let difference: f64 = actual.wrapping_sub(*expected).into_torus();
+                let error = difference - difference.round();
+                max_error = max_error.max(error.abs());
+                squared_error_sum += error * error;
+            }
+        }
+
+        Ok(FftRoundTripError {
+            polynomial_size,
+            precision: Scalar::BITS,
+            max_error,
+            variance: Variance(squared_error_sum / (sample_count * polynomial_size.0) as f64),
+        })
+    }
+}
+
+// Returns the next value of a splitmix64 sequence, as a uniform element of the torus. This is
+// not a cryptographic generator, which is not needed to sample the inputs of the transforms.
+fn next_uniform_torus(state: &mut u64) -> f64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^= z >> 31;
+    (z >> 11) as f64 / (1_u64 << 53) as f64
+}
@@ -120,6 +120,8 @@ impl
                 .unaligned_bytes_required(),
         );
         let stack = self.stack();
+        // The external product accumulates into its output, which is hence reset first.
+        output.0.tensor.as_mut_slice().fill(0);
         let out =
             GlweCiphertextMutView::new(output.0.tensor.as_mut_slice(), polynomial_size, glwe_size);
         let ggsw = ggsw_input.0.as_view();
@@ -233,6 +235,8 @@ impl
                 .unaligned_bytes_required(),
         );
         let stack = self.stack();
+        // The external product accumulates into its output, which is hence reset first.
+        output.0.tensor.as_mut_slice().fill(0);
         let out =
             GlweCiphertextMutView::new(output.0.tensor.as_mut_slice(), polynomial_size, glwe_size);
         let ggsw = ggsw_input.0.as_view();
@@ -0,0 +1,251 @@
+use super::super::super::entities::{FftFourierGgswCiphertext32, FftFourierGgswCiphertext64};
+use super::super::super::private::crypto::ggsw::{external_product, external_product_scratch};
+use super::super::super::private::crypto::glwe::{GlweCiphertextMutView, GlweCiphertextView};
+use super::super::super::private::math::fft::Fft;
+use super::{FftEngine, FftError};
+use crate::commons::math::tensor::{AsMutSlice, AsRefSlice};
+use crate::prelude::{
+    GlweCiphertext32, GlweCiphertext64,
+    GlweCiphertextGgswCiphertextDiscardingExternalProductAddEngine,
+    GlweCiphertextGgswCiphertextDiscardingExternalProductAddError,
+};
+
+impl From<FftError> for GlweCiphertextGgswCiphertextDiscardingExternalProductAddError<FftError> {
+    fn from(err: FftError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`GlweCiphertextGgswCiphertextDiscardingExternalProductAddEngine`] for
+/// [`FftEngine`] that operates on 32 bit integers.
+impl
+    GlweCiphertextGgswCiphertextDiscardingExternalProductAddEngine<
+        GlweCiphertext32,
+        FftFourierGgswCiphertext32,
+        GlweCiphertext32,
+    > for FftEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input_ggsw = 3_u32 << 20;
+    /// let input_glwe = vec![3_u32 << 20; polynomial_size.0];
+    /// let accumulator_glwe = vec![1_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_ggsw = default_engine.create_plaintext_from(&input_ggsw)?;
+    /// let plaintext_glwe = default_engine.create_plaintext_vector_from(&input_glwe)?;
+    /// let plaintext_accumulator = default_engine.create_plaintext_vector_from(&accumulator_glwe)?;
+    ///
+    /// let ggsw = default_engine.encrypt_scalar_ggsw_ciphertext(
+    ///     &key,
+    ///     &plaintext_ggsw,
+    ///     noise,
+    ///     level,
+    ///     base_log,
+    /// )?;
+    /// let complex_ggsw: FftFourierGgswCiphertext32 = fft_engine.convert_ggsw_ciphertext(&ggsw)?;
+    /// let glwe = default_engine.encrypt_glwe_ciphertext(&key, &plaintext_glwe, noise)?;
+    ///
+    /// // The external product is added to the content of the accumulator.
+    /// let mut accumulator =
+    ///     default_engine.encrypt_glwe_ciphertext(&key, &plaintext_accumulator, noise)?;
+    /// fft_engine.discard_add_external_product_glwe_ciphertext_ggsw_ciphertext(
+    ///     &glwe,
+    ///     &complex_ggsw,
+    ///     &mut accumulator,
+    /// )?;
+    /// #
+    /// # assert_eq!(
+    /// #     accumulator.polynomial_size(),
+    /// #     glwe.polynomial_size(),
+    /// # );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_add_external_product_glwe_ciphertext_ggsw_ciphertext(
+        &mut self,
+        glwe_input: &GlweCiphertext32,
+        ggsw_input: &FftFourierGgswCiphertext32,
+        output: &mut GlweCiphertext32,
+    ) -> Result<
+        (),
+        GlweCiphertextGgswCiphertextDiscardingExternalProductAddError<Self::EngineError>,
+    > {
+        FftEngine::check_supported_size(glwe_input.0.polynomial_size())?;
+        GlweCiphertextGgswCiphertextDiscardingExternalProductAddError::perform_generic_checks(
+            glwe_input, ggsw_input, output,
+        )?;
+        unsafe {
+            self.discard_add_external_product_glwe_ciphertext_ggsw_ciphertext_unchecked(
+                glwe_input, ggsw_input, output,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_add_external_product_glwe_ciphertext_ggsw_ciphertext_unchecked(
+        &mut self,
+        glwe_input: &GlweCiphertext32,
+        ggsw_input: &FftFourierGgswCiphertext32,
+        output: &mut GlweCiphertext32,
+    ) {
+        let glwe_size = glwe_input.0.size();
+        let polynomial_size = glwe_input.0.polynomial_size();
+        let fft = Fft::new(polynomial_size);
+        let fft = fft.as_view();
+        self.resize(
+            external_product_scratch::<u32>(glwe_size, polynomial_size, fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+        let stack = self.stack();
+        let out =
+            GlweCiphertextMutView::new(output.0.tensor.as_mut_slice(), polynomial_size, glwe_size);
+        let ggsw = ggsw_input.0.as_view();
+        let glwe =
+            GlweCiphertextView::new(glwe_input.0.tensor.as_slice(), polynomial_size, glwe_size);
+        external_product(out, ggsw, glwe, fft, stack)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`GlweCiphertextGgswCiphertextDiscardingExternalProductAddEngine`] for
+/// [`FftEngine`] that operates on 64 bit integers.
+impl
+    GlweCiphertextGgswCiphertextDiscardingExternalProductAddEngine<
+        GlweCiphertext64,
+        FftFourierGgswCiphertext64,
+        GlweCiphertext64,
+    > for FftEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input_ggsw = 3_u64 << 50;
+    /// let input_glwe = vec![3_u64 << 50; polynomial_size.0];
+    /// let accumulator_glwe = vec![1_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_ggsw = default_engine.create_plaintext_from(&input_ggsw)?;
+    /// let plaintext_glwe = default_engine.create_plaintext_vector_from(&input_glwe)?;
+    /// let plaintext_accumulator = default_engine.create_plaintext_vector_from(&accumulator_glwe)?;
+    ///
+    /// let ggsw = default_engine.encrypt_scalar_ggsw_ciphertext(
+    ///     &key,
+    ///     &plaintext_ggsw,
+    ///     noise,
+    ///     level,
+    ///     base_log,
+    /// )?;
+    /// let complex_ggsw: FftFourierGgswCiphertext64 = fft_engine.convert_ggsw_ciphertext(&ggsw)?;
+    /// let glwe = default_engine.encrypt_glwe_ciphertext(&key, &plaintext_glwe, noise)?;
+    ///
+    /// // The external product is added to the content of the accumulator.
+    /// let mut accumulator =
+    ///     default_engine.encrypt_glwe_ciphertext(&key, &plaintext_accumulator, noise)?;
+    /// fft_engine.discard_add_external_product_glwe_ciphertext_ggsw_ciphertext(
+    ///     &glwe,
+    ///     &complex_ggsw,
+    ///     &mut accumulator,
+    /// )?;
+    /// #
+    /// # assert_eq!(
+    /// #     accumulator.polynomial_size(),
+    /// #     glwe.polynomial_size(),
+    /// # );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_add_external_product_glwe_ciphertext_ggsw_ciphertext(
+        &mut self,
+        glwe_input: &GlweCiphertext64,
+        ggsw_input: &FftFourierGgswCiphertext64,
+        output: &mut GlweCiphertext64,
+    ) -> Result<
+        (),
+        GlweCiphertextGgswCiphertextDiscardingExternalProductAddError<Self::EngineError>,
+    > {
+        FftEngine::check_supported_size(glwe_input.0.polynomial_size())?;
+        GlweCiphertextGgswCiphertextDiscardingExternalProductAddError::perform_generic_checks(
+            glwe_input, ggsw_input, output,
+        )?;
+        unsafe {
+            self.discard_add_external_product_glwe_ciphertext_ggsw_ciphertext_unchecked(
+                glwe_input, ggsw_input, output,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_add_external_product_glwe_ciphertext_ggsw_ciphertext_unchecked(
+        &mut self,
+        glwe_input: &GlweCiphertext64,
+        ggsw_input: &FftFourierGgswCiphertext64,
+        output: &mut GlweCiphertext64,
+    ) {
+        let glwe_size = glwe_input.0.size();
+        let polynomial_size = glwe_input.0.polynomial_size();
+        let fft = Fft::new(polynomial_size);
+        let fft = fft.as_view();
+        self.resize(
+            external_product_scratch::<u64>(glwe_size, polynomial_size, fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+        let stack = self.stack();
+        let out =
+            GlweCiphertextMutView::new(output.0.tensor.as_mut_slice(), polynomial_size, glwe_size);
+        let ggsw = ggsw_input.0.as_view();
+        let glwe =
+            GlweCiphertextView::new(glwe_input.0.tensor.as_slice(), polynomial_size, glwe_size);
+        external_product(out, ggsw, glwe, fft, stack)
+    }
+}
@@ -20,7 +20,8 @@ impl From<FftError> for LweBootstrapKeyConversionError<FftError> {
 /// # Description
 ///
 /// Implementation of [`LweBootstrapKeyConversionEngine`] for [`FftEngine`] that operates on
-/// 32 bit integers. It converts a bootstrap key from the standard to the Fourier domain.
+/// 32 bit integers. It converts a bootstrap key from the standard to the Fourier domain, with the
+/// layout set by [`FftEngine::set_bootstrap_key_layout`].
 impl LweBootstrapKeyConversionEngine<LweBootstrapKey32, FftFourierLweBootstrapKey32> for FftEngine {
     /// # Example
     /// ```
@@ -92,6 +93,7 @@ impl LweBootstrapKeyConversionEngine<LweBootstrapKey32, FftFourierLweBootstrapKe
                 .unwrap()
                 .unaligned_bytes_required(),
         );
+        let layout = self.bootstrap_key_layout();
         let stack = self.stack();
 
         let mut output = FourierLweBootstrapKey::new(
@@ -101,6 +103,7 @@ impl LweBootstrapKeyConversionEngine<LweBootstrapKey32, FftFourierLweBootstrapKe
             input.0.glwe_size(),
             input.0.base_log(),
             input.0.level_count(),
+            layout,
         );
         let input = StandardLweBootstrapKeyView::new(
             input.0.tensor.as_slice(),
@@ -120,7 +123,8 @@ impl LweBootstrapKeyConversionEngine<LweBootstrapKey32, FftFourierLweBootstrapKe
 /// # Description
 ///
 /// Implementation of [`LweBootstrapKeyConversionEngine`] for [`FftEngine`] that operates on
-/// 64 bit integers. It converts a bootstrap key from the standard to the Fourier domain.
+/// 64 bit integers. It converts a bootstrap key from the standard to the Fourier domain, with the
+/// layout set by [`FftEngine::set_bootstrap_key_layout`].
 impl LweBootstrapKeyConversionEngine<LweBootstrapKey64, FftFourierLweBootstrapKey64> for FftEngine {
     /// # Example
     /// ```
@@ -193,6 +197,7 @@ impl LweBootstrapKeyConversionEngine<LweBootstrapKey64, FftFourierLweBootstrapKe
                 .unwrap()
                 .unaligned_bytes_required(),
         );
+        let layout = self.bootstrap_key_layout();
         let stack = self.stack();
 
         let mut output = FourierLweBootstrapKey::new(
@@ -202,6 +207,7 @@ impl LweBootstrapKeyConversionEngine<LweBootstrapKey64, FftFourierLweBootstrapKe
             input.0.glwe_size(),
             input.0.base_log(),
             input.0.level_count(),
+            layout,
         );
         let input = StandardLweBootstrapKeyView::new(
             input.0.tensor.as_slice(),
@@ -0,0 +1,291 @@
+use super::super::super::private::crypto::bootstrap::{
+    batch_bootstrap_scratch, BOOTSTRAP_BATCH_SIZE,
+};
+use super::super::super::private::math::fft::Fft;
+use super::{FftEngine, FftError};
+use crate::commons::math::tensor::{AsMutSlice, AsRefSlice};
+use crate::prelude::{
+    FftFourierLweBootstrapKey32, FftFourierLweBootstrapKey64, GlweCiphertextVector32,
+    GlweCiphertextVector64, LweCiphertextVector32, LweCiphertextVector64,
+    LweCiphertextVectorDiscardingBootstrapEngine, LweCiphertextVectorDiscardingBootstrapError,
+};
+
+impl From<FftError> for LweCiphertextVectorDiscardingBootstrapError<FftError> {
+    fn from(err: FftError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`LweCiphertextVectorDiscardingBootstrapEngine`] for [`FftEngine`] that
+/// operates on 32 bit integers.
+///
+/// The ciphertexts are bootstrapped by batches of [`BOOTSTRAP_BATCH_SIZE`], whose blind rotations
+/// are interleaved: each GGSW ciphertext of the bootstrap key is applied to all the accumulators of
+/// the batch before moving on to the next one, which improves the reuse of the key in the caches.
+impl
+    LweCiphertextVectorDiscardingBootstrapEngine<
+        FftFourierLweBootstrapKey32,
+        GlweCiphertextVector32,
+        LweCiphertextVector32,
+        LweCiphertextVector32,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweCiphertextCount,
+    ///     LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 10];
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![8_u32 << 20; poly_size.0 * 10];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey32 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey32 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let bsk: FftFourierLweBootstrapKey32 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// let lwe_sk_output: LweSecretKey32 =
+    ///     default_engine.generate_new_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&input)?;
+    /// let lut_plaintext_vector = default_engine.create_plaintext_vector_from(&lut)?;
+    /// let acc = default_engine.trivially_encrypt_glwe_ciphertext_vector(
+    ///     glwe_dim.to_glwe_size(),
+    ///     GlweCiphertextCount(10),
+    ///     &lut_plaintext_vector,
+    /// )?;
+    /// let input = default_engine.encrypt_lwe_ciphertext_vector(&lwe_sk, &plaintext_vector, noise)?;
+    /// let mut output = default_engine.zero_encrypt_lwe_ciphertext_vector(
+    ///     &lwe_sk_output,
+    ///     noise,
+    ///     LweCiphertextCount(10),
+    /// )?;
+    ///
+    /// fft_engine.discard_bootstrap_lwe_ciphertext_vector(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    /// assert_eq!(output.lwe_ciphertext_count(), LweCiphertextCount(10));
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &LweCiphertextVector32,
+        acc: &GlweCiphertextVector32,
+        bsk: &FftFourierLweBootstrapKey32,
+    ) -> Result<(), LweCiphertextVectorDiscardingBootstrapError<Self::EngineError>> {
+        FftEngine::check_supported_size(acc.0.polynomial_size())?;
+        LweCiphertextVectorDiscardingBootstrapError::perform_generic_checks(
+            output, input, acc, bsk,
+        )?;
+        unsafe { self.discard_bootstrap_lwe_ciphertext_vector_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &LweCiphertextVector32,
+        acc: &GlweCiphertextVector32,
+        bsk: &FftFourierLweBootstrapKey32,
+    ) {
+        let polynomial_size = acc.0.polynomial_size();
+        let glwe_size = acc.0.glwe_size();
+        let fft = Fft::new(polynomial_size);
+        let fft = fft.as_view();
+        let batch_count = BOOTSTRAP_BATCH_SIZE.min(input.0.count().0);
+        self.resize(
+            batch_bootstrap_scratch::<u32>(glwe_size, polynomial_size, batch_count, fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+        let output_batch_len = BOOTSTRAP_BATCH_SIZE * output.0.lwe_size().0;
+        let input_batch_len = BOOTSTRAP_BATCH_SIZE * input.0.lwe_size().0;
+        let acc_batch_len = BOOTSTRAP_BATCH_SIZE * glwe_size.0 * polynomial_size.0;
+        for (output_batch, (input_batch, acc_batch)) in output
+            .0
+            .tensor
+            .as_mut_slice()
+            .chunks_mut(output_batch_len)
+            .zip(
+                input
+                    .0
+                    .tensor
+                    .as_slice()
+                    .chunks(input_batch_len)
+                    .zip(acc.0.tensor.as_slice().chunks(acc_batch_len)),
+            )
+        {
+            bsk.0.as_view().batch_bootstrap(
+                output_batch,
+                input_batch,
+                acc_batch,
+                fft,
+                self.stack(),
+            );
+        }
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`LweCiphertextVectorDiscardingBootstrapEngine`] for [`FftEngine`] that
+/// operates on 64 bit integers.
+///
+/// The ciphertexts are bootstrapped by batches of [`BOOTSTRAP_BATCH_SIZE`], whose blind rotations
+/// are interleaved: each GGSW ciphertext of the bootstrap key is applied to all the accumulators of
+/// the batch before moving on to the next one, which improves the reuse of the key in the caches.
+impl
+    LweCiphertextVectorDiscardingBootstrapEngine<
+        FftFourierLweBootstrapKey64,
+        GlweCiphertextVector64,
+        LweCiphertextVector64,
+        LweCiphertextVector64,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweCiphertextCount,
+    ///     LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 10];
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![8_u64 << 50; poly_size.0 * 10];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey64 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let bsk: FftFourierLweBootstrapKey64 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// let lwe_sk_output: LweSecretKey64 =
+    ///     default_engine.generate_new_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&input)?;
+    /// let lut_plaintext_vector = default_engine.create_plaintext_vector_from(&lut)?;
+    /// let acc = default_engine.trivially_encrypt_glwe_ciphertext_vector(
+    ///     glwe_dim.to_glwe_size(),
+    ///     GlweCiphertextCount(10),
+    ///     &lut_plaintext_vector,
+    /// )?;
+    /// let input = default_engine.encrypt_lwe_ciphertext_vector(&lwe_sk, &plaintext_vector, noise)?;
+    /// let mut output = default_engine.zero_encrypt_lwe_ciphertext_vector(
+    ///     &lwe_sk_output,
+    ///     noise,
+    ///     LweCiphertextCount(10),
+    /// )?;
+    ///
+    /// fft_engine.discard_bootstrap_lwe_ciphertext_vector(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    /// assert_eq!(output.lwe_ciphertext_count(), LweCiphertextCount(10));
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &LweCiphertextVector64,
+        acc: &GlweCiphertextVector64,
+        bsk: &FftFourierLweBootstrapKey64,
+    ) -> Result<(), LweCiphertextVectorDiscardingBootstrapError<Self::EngineError>> {
+        FftEngine::check_supported_size(acc.0.polynomial_size())?;
+        LweCiphertextVectorDiscardingBootstrapError::perform_generic_checks(
+            output, input, acc, bsk,
+        )?;
+        unsafe { self.discard_bootstrap_lwe_ciphertext_vector_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &LweCiphertextVector64,
+        acc: &GlweCiphertextVector64,
+        bsk: &FftFourierLweBootstrapKey64,
+    ) {
+        let polynomial_size = acc.0.polynomial_size();
+        let glwe_size = acc.0.glwe_size();
+        let fft = Fft::new(polynomial_size);
+        let fft = fft.as_view();
+        let batch_count = BOOTSTRAP_BATCH_SIZE.min(input.0.count().0);
+        self.resize(
+            batch_bootstrap_scratch::<u64>(glwe_size, polynomial_size, batch_count, fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+        let output_batch_len = BOOTSTRAP_BATCH_SIZE * output.0.lwe_size().0;
+        let input_batch_len = BOOTSTRAP_BATCH_SIZE * input.0.lwe_size().0;
+        let acc_batch_len = BOOTSTRAP_BATCH_SIZE * glwe_size.0 * polynomial_size.0;
+        for (output_batch, (input_batch, acc_batch)) in output
+            .0
+            .tensor
+            .as_mut_slice()
+            .chunks_mut(output_batch_len)
+            .zip(
+                input
+                    .0
+                    .tensor
+                    .as_slice()
+                    .chunks(input_batch_len)
+                    .zip(acc.0.tensor.as_slice().chunks(acc_batch_len)),
+            )
+        {
+            bsk.0.as_view().batch_bootstrap(
+                output_batch,
+                input_batch,
+                acc_batch,
+                fft,
+                self.stack(),
+            );
+        }
+    }
+}
@@ -2,11 +2,18 @@
 //! backend.
 
 mod computation_engine;
+mod fft_error_measurement;
 
 pub use computation_engine::{FftEngine, FftError};
+pub use fft_error_measurement::FftRoundTripError;
 
 mod ggsw_ciphertext_conversion;
 mod glwe_ciphertext_ggsw_ciphertext_discarding_external_product;
+mod glwe_ciphertext_ggsw_ciphertext_discarding_external_product_add;
 mod glwe_ciphertexts_ggsw_ciphertext_fusing_cmux;
 mod lwe_bootstrap_key_conversion;
 mod lwe_ciphertext_discarding_bootstrap;
+mod lwe_ciphertext_vector_discarding_bootstrap;
+
+mod registry;
+pub(crate) use registry::implementations;
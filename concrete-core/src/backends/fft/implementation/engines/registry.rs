@@ -0,0 +1,81 @@
+//! The declaration of the engine traits implemented by the engines of the `Concrete-FFT` backend.
+use crate::backends::support::declare_implementations;
+use crate::prelude::*;
+
+declare_implementations! {
+    "fft",
+    FftEngine => [
+        GgswCiphertextDiscardingConversionEngine<GgswCiphertext32, FftFourierGgswCiphertext32>,
+        GgswCiphertextDiscardingConversionEngine<GgswCiphertext64, FftFourierGgswCiphertext64>,
+        GgswCiphertextConversionEngine<GgswCiphertext32, FftFourierGgswCiphertext32>,
+        GgswCiphertextConversionEngine<GgswCiphertext64, FftFourierGgswCiphertext64>,
+        GlweCiphertextGgswCiphertextDiscardingExternalProductEngine<
+            GlweCiphertext32,
+            FftFourierGgswCiphertext32,
+            GlweCiphertext32,
+        >,
+        GlweCiphertextGgswCiphertextDiscardingExternalProductEngine<
+            GlweCiphertext64,
+            FftFourierGgswCiphertext64,
+            GlweCiphertext64,
+        >,
+        GlweCiphertextGgswCiphertextDiscardingExternalProductAddEngine<
+            GlweCiphertext32,
+            FftFourierGgswCiphertext32,
+            GlweCiphertext32,
+        >,
+        GlweCiphertextGgswCiphertextDiscardingExternalProductAddEngine<
+            GlweCiphertext64,
+            FftFourierGgswCiphertext64,
+            GlweCiphertext64,
+        >,
+        GlweCiphertextsGgswCiphertextFusingCmuxEngine<
+            GlweCiphertext32,
+            GlweCiphertext32,
+            FftFourierGgswCiphertext32,
+        >,
+        GlweCiphertextsGgswCiphertextFusingCmuxEngine<
+            GlweCiphertext64,
+            GlweCiphertext64,
+            FftFourierGgswCiphertext64,
+        >,
+        LweBootstrapKeyConversionEngine<LweBootstrapKey32, FftFourierLweBootstrapKey32>,
+        LweBootstrapKeyConversionEngine<LweBootstrapKey64, FftFourierLweBootstrapKey64>,
+        LweCiphertextDiscardingBootstrapEngine<
+            FftFourierLweBootstrapKey32,
+            GlweCiphertext32,
+            LweCiphertext32,
+            LweCiphertext32,
+        >,
+        LweCiphertextDiscardingBootstrapEngine<
+            FftFourierLweBootstrapKey64,
+            GlweCiphertext64,
+            LweCiphertext64,
+            LweCiphertext64,
+        >,
+        LweCiphertextDiscardingBootstrapEngine<
+            FftFourierLweBootstrapKey32,
+            GlweCiphertextView32<'data>,
+            LweCiphertextView32<'data>,
+            LweCiphertextMutView32<'data>,
+        >,
+        LweCiphertextDiscardingBootstrapEngine<
+            FftFourierLweBootstrapKey64,
+            GlweCiphertextView64<'data>,
+            LweCiphertextView64<'data>,
+            LweCiphertextMutView64<'data>,
+        >,
+        LweCiphertextVectorDiscardingBootstrapEngine<
+            FftFourierLweBootstrapKey32,
+            GlweCiphertextVector32,
+            LweCiphertextVector32,
+            LweCiphertextVector32,
+        >,
+        LweCiphertextVectorDiscardingBootstrapEngine<
+            FftFourierLweBootstrapKey64,
+            GlweCiphertextVector64,
+            LweCiphertextVector64,
+            LweCiphertextVector64,
+        >,
+    ],
+}
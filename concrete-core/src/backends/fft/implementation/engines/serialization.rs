@@ -9,6 +9,7 @@ use crate::prelude::{
     FftFourierLweBootstrapKey64Version,
 };
 use serde::Serialize;
+use std::io::Write;
 
 impl EntitySerializationEngine<FftFourierGgswCiphertext32, Vec<u8>> for FftSerializationEngine {
     /// # Example
@@ -60,6 +61,20 @@ impl EntitySerializationEngine<FftFourierGgswCiphertext32, Vec<u8>> for FftSeria
         &mut self,
         entity: &FftFourierGgswCiphertext32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &FftFourierGgswCiphertext32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &FftFourierGgswCiphertext32,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         let entity = entity.0.as_ref();
         #[derive(Serialize)]
         struct SerializableFftFourierGgswCiphertext32<'a> {
@@ -70,13 +85,17 @@ impl EntitySerializationEngine<FftFourierGgswCiphertext32, Vec<u8>> for FftSeria
             version: FftFourierGgswCiphertext32Version::V0,
             inner: entity,
         };
-        bincode::serialize(&value)
+        bincode::serialize_into(writer, &value)
             .map_err(FftSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &FftFourierGgswCiphertext32) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &FftFourierGgswCiphertext32,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
@@ -130,6 +149,20 @@ impl EntitySerializationEngine<FftFourierGgswCiphertext64, Vec<u8>> for FftSeria
         &mut self,
         entity: &FftFourierGgswCiphertext64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &FftFourierGgswCiphertext64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &FftFourierGgswCiphertext64,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         let entity = entity.0.as_ref();
         #[derive(Serialize)]
         struct SerializableFftFourierGgswCiphertext64<'a> {
@@ -140,13 +173,17 @@ impl EntitySerializationEngine<FftFourierGgswCiphertext64, Vec<u8>> for FftSeria
             version: FftFourierGgswCiphertext64Version::V0,
             inner: entity,
         };
-        bincode::serialize(&value)
+        bincode::serialize_into(writer, &value)
             .map_err(FftSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &FftFourierGgswCiphertext64) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &FftFourierGgswCiphertext64,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
@@ -191,6 +228,20 @@ impl EntitySerializationEngine<FftFourierLweBootstrapKey32, Vec<u8>> for FftSeri
         &mut self,
         entity: &FftFourierLweBootstrapKey32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &FftFourierLweBootstrapKey32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &FftFourierLweBootstrapKey32,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         let entity = entity.0.as_ref();
         #[derive(Serialize)]
         struct SerializableFftFourierLweBootstrapKey32<'a> {
@@ -201,13 +252,17 @@ impl EntitySerializationEngine<FftFourierLweBootstrapKey32, Vec<u8>> for FftSeri
             version: FftFourierLweBootstrapKey32Version::V0,
             inner: entity,
         };
-        bincode::serialize(&value)
+        bincode::serialize_into(writer, &value)
             .map_err(FftSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &FftFourierLweBootstrapKey32) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &FftFourierLweBootstrapKey32,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
@@ -252,6 +307,20 @@ impl EntitySerializationEngine<FftFourierLweBootstrapKey64, Vec<u8>> for FftSeri
         &mut self,
         entity: &FftFourierLweBootstrapKey64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &FftFourierLweBootstrapKey64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &FftFourierLweBootstrapKey64,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         let entity = entity.0.as_ref();
         #[derive(Serialize)]
         struct SerializableFftFourierLweBootstrapKey64<'a> {
@@ -262,12 +331,16 @@ impl EntitySerializationEngine<FftFourierLweBootstrapKey64, Vec<u8>> for FftSeri
             version: FftFourierLweBootstrapKey64Version::V0,
             inner: entity,
         };
-        bincode::serialize(&value)
+        bincode::serialize_into(writer, &value)
             .map_err(FftSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &FftFourierLweBootstrapKey64) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &FftFourierLweBootstrapKey64,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
@@ -1,4 +1,5 @@
 use super::super::super::private::crypto::bootstrap::FourierLweBootstrapKey;
+pub use super::super::super::private::crypto::bootstrap::FourierLweBootstrapKeyLayout;
 use crate::specification::entities::markers::LweBootstrapKeyKind;
 use crate::specification::entities::{AbstractEntity, LweBootstrapKeyEntity};
 use aligned_vec::ABox;
@@ -14,6 +15,22 @@ pub struct FftFourierLweBootstrapKey32(pub(crate) FourierLweBootstrapKey<ABox<[c
 #[derive(Debug, Clone, PartialEq)]
 pub struct FftFourierLweBootstrapKey64(pub(crate) FourierLweBootstrapKey<ABox<[c64]>>);
 
+impl FftFourierLweBootstrapKey32 {
+    /// Returns the memory layout of the key, which is chosen when converting it to the Fourier
+    /// domain.
+    pub fn layout(&self) -> FourierLweBootstrapKeyLayout {
+        self.0.layout()
+    }
+}
+
+impl FftFourierLweBootstrapKey64 {
+    /// Returns the memory layout of the key, which is chosen when converting it to the Fourier
+    /// domain.
+    pub fn layout(&self) -> FourierLweBootstrapKeyLayout {
+        self.0.layout()
+    }
+}
+
 impl AbstractEntity for FftFourierLweBootstrapKey32 {
     type Kind = LweBootstrapKeyKind;
 }
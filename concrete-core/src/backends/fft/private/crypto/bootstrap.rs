@@ -25,6 +25,23 @@ pub struct StandardLweBootstrapKey<C> {
     decomposition_level_count: DecompositionLevelCount,
 }
 
+/// The memory layout of the level matrices of a bootstrap key in the Fourier domain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum FourierLweBootstrapKeyLayout {
+    /// The GGSW ciphertexts of the key are stored one after the other, each of them storing its
+    /// level matrices contiguously.
+    #[default]
+    GgswMajor,
+    /// The level matrices are grouped by decomposition level: the matrices of the first level of
+    /// all the GGSW ciphertexts are stored first, followed by the matrices of the second level,
+    /// and so on.
+    ///
+    /// During a blind rotation, the external product of each GGSW ciphertext processes its levels
+    /// in order, so that consecutive GGSW ciphertexts read their matrices of a given level from
+    /// neighbouring memory locations.
+    LevelMajor,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct FourierLweBootstrapKey<C> {
     data: C,
@@ -33,6 +50,7 @@ pub struct FourierLweBootstrapKey<C> {
     glwe_size: GlweSize,
     decomposition_base_log: DecompositionBaseLog,
     decomposition_level_count: DecompositionLevelCount,
+    layout: FourierLweBootstrapKeyLayout,
 }
 
 pub type StandardLweBootstrapKeyView<'a, Scalar> = StandardLweBootstrapKey<&'a [Scalar]>;
@@ -147,6 +165,7 @@ impl<C> FourierLweBootstrapKey<C> {
         glwe_size: GlweSize,
         decomposition_base_log: DecompositionBaseLog,
         decomposition_level_count: DecompositionLevelCount,
+        layout: FourierLweBootstrapKeyLayout,
     ) -> Self
     where
         C: Container,
@@ -166,25 +185,10 @@ impl<C> FourierLweBootstrapKey<C> {
             glwe_size,
             decomposition_base_log,
             decomposition_level_count,
+            layout,
         }
     }
 
-    /// Returns an iterator over the GGSW ciphertexts composing the key.
-    pub fn into_ggsw_iter(self) -> impl DoubleEndedIterator<Item = FourierGgswCiphertext<C>>
-    where
-        C: IntoChunks + Container,
-    {
-        self.data.split_into(self.key_size.0).map(move |slice| {
-            FourierGgswCiphertext::new(
-                slice,
-                self.polynomial_size,
-                self.glwe_size,
-                self.decomposition_base_log,
-                self.decomposition_level_count,
-            )
-        })
-    }
-
     pub fn key_size(&self) -> LweDimension {
         self.key_size
     }
@@ -205,6 +209,10 @@ impl<C> FourierLweBootstrapKey<C> {
         self.decomposition_level_count
     }
 
+    pub fn layout(&self) -> FourierLweBootstrapKeyLayout {
+        self.layout
+    }
+
     pub fn data(self) -> C {
         self.data
     }
@@ -220,6 +228,7 @@ impl<C> FourierLweBootstrapKey<C> {
             glwe_size: self.glwe_size,
             decomposition_base_log: self.decomposition_base_log,
             decomposition_level_count: self.decomposition_level_count,
+            layout: self.layout,
         }
     }
 
@@ -234,6 +243,7 @@ impl<C> FourierLweBootstrapKey<C> {
             glwe_size: self.glwe_size,
             decomposition_base_log: self.decomposition_base_log,
             decomposition_level_count: self.decomposition_level_count,
+            layout: self.layout,
         }
     }
 }
@@ -247,16 +257,44 @@ impl<'a> FourierLweBootstrapKeyMutView<'a> {
     /// Fills a bootstrapping key with the Fourier transform of a bootstrapping key in the standard
     /// domain.
     pub fn fill_with_forward_fourier<Scalar: UnsignedTorus + CastInto<usize>>(
-        mut self,
+        self,
         coef_bsk: StandardLweBootstrapKeyView<Scalar>,
         fft: FftView<'_>,
         mut stack: DynStack<'_>,
     ) {
-        for (fourier_ggsw, standard_ggsw) in izip!(
-            self.as_mut_view().into_ggsw_iter(),
-            coef_bsk.into_ggsw_iter()
-        ) {
-            fourier_ggsw.fill_with_forward_fourier(standard_ggsw, fft, stack.rb_mut());
+        let key_size = self.key_size.0;
+        let level_count = self.decomposition_level_count.0;
+        let standard_matrix_len = self.polynomial_size.0 * self.glwe_size.0 * self.glwe_size.0;
+        let standard_data = coef_bsk.data();
+
+        // Each level matrix is transformed independently, and written at its place in the
+        // layout of the key.
+        for (index, fourier_matrix) in self.data.into_chunks(standard_matrix_len / 2).enumerate() {
+            let (ggsw_index, level_index) = match self.layout {
+                FourierLweBootstrapKeyLayout::GgswMajor => {
+                    (index / level_count, index % level_count)
+                }
+                FourierLweBootstrapKeyLayout::LevelMajor => (index % key_size, index / key_size),
+            };
+            let start = (ggsw_index * level_count + level_index) * standard_matrix_len;
+            FourierGgswCiphertext::new(
+                fourier_matrix,
+                self.polynomial_size,
+                self.glwe_size,
+                self.decomposition_base_log,
+                DecompositionLevelCount(1),
+            )
+            .fill_with_forward_fourier(
+                StandardGgswCiphertext::new(
+                    &standard_data[start..start + standard_matrix_len],
+                    self.polynomial_size,
+                    self.glwe_size,
+                    self.decomposition_base_log,
+                    DecompositionLevelCount(1),
+                ),
+                fft,
+                stack.rb_mut(),
+            );
         }
     }
 }
@@ -282,7 +320,62 @@ pub fn bootstrap_scratch<Scalar>(
     )
 }
 
+/// The maximal number of ciphertexts whose blind rotations are interleaved by
+/// [`FourierLweBootstrapKeyView::batch_bootstrap`].
+///
+/// The accumulators of a batch are all kept in memory while the key is traversed, so this value
+/// trades the reuse of the bootstrap key against the cache pressure of the accumulators.
+pub const BOOTSTRAP_BATCH_SIZE: usize = 8;
+
+/// Returns the required memory for [`FourierLweBootstrapKeyView::batch_blind_rotate`].
+pub fn batch_blind_rotate_scratch<Scalar>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    blind_rotate_scratch::<Scalar>(glwe_size, polynomial_size, fft)
+}
+
+/// Returns the required memory for [`FourierLweBootstrapKeyView::batch_bootstrap`].
+pub fn batch_bootstrap_scratch<Scalar>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    batch_count: usize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    batch_blind_rotate_scratch::<Scalar>(glwe_size, polynomial_size, fft)?.try_and(
+        StackReq::try_new_aligned::<Scalar>(
+            batch_count
+                .checked_mul(glwe_size.0 * polynomial_size.0)
+                .ok_or(SizeOverflow)?,
+            CACHELINE_ALIGN,
+        )?,
+    )
+}
+
 impl<'a> FourierLweBootstrapKeyView<'a> {
+    /// Returns an iterator over the GGSW ciphertexts composing the key.
+    pub fn into_ggsw_iter(self) -> impl DoubleEndedIterator<Item = FourierGgswCiphertextView<'a>> {
+        let data = self.data;
+        let matrix_len = self.polynomial_size.0 / 2 * self.glwe_size.0 * self.glwe_size.0;
+        let level_count = self.decomposition_level_count.0;
+        let (ggsw_stride, level_stride) = match self.layout {
+            FourierLweBootstrapKeyLayout::GgswMajor => (level_count * matrix_len, matrix_len),
+            FourierLweBootstrapKeyLayout::LevelMajor => (matrix_len, self.key_size.0 * matrix_len),
+        };
+        (0..self.key_size.0).map(move |i| {
+            let start = i * ggsw_stride;
+            FourierGgswCiphertext::new_with_level_stride(
+                &data[start..start + (level_count - 1) * level_stride + matrix_len],
+                self.polynomial_size,
+                self.glwe_size,
+                self.decomposition_base_log,
+                self.decomposition_level_count,
+                level_stride,
+            )
+        })
+    }
+
     pub fn blind_rotate<Scalar: UnsignedTorus + CastInto<usize>>(
         self,
         mut lut: GlweCiphertextMutView<'_, Scalar>,
@@ -332,6 +425,71 @@ impl<'a> FourierLweBootstrapKeyView<'a> {
         }
     }
 
+    /// Performs the blind rotations of several accumulators at once.
+    ///
+    /// The accumulators are stored contiguously in `luts`, and the input LWE ciphertexts are
+    /// stored contiguously in `lwes`. Instead of blind rotating the accumulators one after the
+    /// other, the cmuxes of the different blind rotations are interleaved: each GGSW ciphertext of
+    /// the key is applied to all the accumulators before moving to the next one. This way, the
+    /// key is only streamed once from memory for the whole batch, and the successive external
+    /// products share the same operand.
+    pub fn batch_blind_rotate<Scalar: UnsignedTorus + CastInto<usize>>(
+        self,
+        luts: &mut [Scalar],
+        lwes: &[Scalar],
+        fft: FftView<'_>,
+        mut stack: DynStack<'_>,
+    ) {
+        let polynomial_size = self.polynomial_size;
+        let glwe_size = self.glwe_size;
+        let glwe_len = polynomial_size.0 * glwe_size.0;
+        let lwe_size = self.key_size.0 + 1;
+        debug_assert_eq!(luts.len() % glwe_len, 0);
+        debug_assert_eq!(lwes.len() % lwe_size, 0);
+        debug_assert_eq!(luts.len() / glwe_len, lwes.len() / lwe_size);
+
+        for (lut, lwe) in izip!(luts.chunks_exact_mut(glwe_len), lwes.chunks_exact(lwe_size)) {
+            let monomial_degree = pbs_modulus_switch(
+                *lwe.last().unwrap(),
+                polynomial_size,
+                ModulusSwitchOffset(0),
+                LutCountLog(0),
+            );
+            GlweCiphertextMutView::new(lut, polynomial_size, glwe_size)
+                .into_polynomials()
+                .for_each(|poly| {
+                    poly.update_with_wrapping_unit_monomial_div(monomial_degree);
+                });
+        }
+
+        for (mask_index, bootstrap_key_ggsw) in self.into_ggsw_iter().enumerate() {
+            for (lut, lwe) in izip!(luts.chunks_exact_mut(glwe_len), lwes.chunks_exact(lwe_size)) {
+                let lwe_mask_element = lwe[mask_index];
+                if lwe_mask_element != Scalar::ZERO {
+                    let stack = stack.rb_mut();
+                    // We copy ct_0 to ct_1
+                    let (mut ct1, stack) =
+                        stack.collect_aligned(CACHELINE_ALIGN, lut.iter().copied());
+
+                    let ct0 = GlweCiphertextMutView::new(lut, polynomial_size, glwe_size);
+                    let mut ct1 = GlweCiphertextMutView::new(&mut ct1, polynomial_size, glwe_size);
+
+                    // We rotate ct_1 by performing ct_1 <- ct_1 * X^{a_hat}
+                    for poly in ct1.as_mut_view().into_polynomials() {
+                        poly.update_with_wrapping_unit_monomial_mul(pbs_modulus_switch(
+                            lwe_mask_element,
+                            polynomial_size,
+                            ModulusSwitchOffset(0),
+                            LutCountLog(0),
+                        ));
+                    }
+
+                    cmux(ct0, ct1, bootstrap_key_ggsw, fft, stack);
+                }
+            }
+        }
+    }
+
     pub fn bootstrap<'out, Scalar: UnsignedTorus + CastInto<usize>>(
         self,
         lwe_out: &'out mut [Scalar],
@@ -352,6 +510,36 @@ impl<'a> FourierLweBootstrapKeyView<'a> {
             .as_view()
             .fill_lwe_with_sample_extraction(lwe_out, 0);
     }
+
+    /// Bootstraps several LWE ciphertexts at once, interleaving their blind rotations as described
+    /// in [`FourierLweBootstrapKeyView::batch_blind_rotate`].
+    ///
+    /// The input ciphertexts, the accumulators and the output ciphertexts are all stored
+    /// contiguously, and must have the same count.
+    pub fn batch_bootstrap<Scalar: UnsignedTorus + CastInto<usize>>(
+        self,
+        lwes_out: &mut [Scalar],
+        lwes_in: &[Scalar],
+        accumulators: &[Scalar],
+        fft: FftView<'_>,
+        stack: DynStack<'_>,
+    ) {
+        let polynomial_size = self.polynomial_size;
+        let glwe_size = self.glwe_size;
+        let lwe_out_size = polynomial_size.0 * (glwe_size.0 - 1) + 1;
+        debug_assert_eq!(lwes_out.len() % lwe_out_size, 0);
+
+        let (mut local_accumulators, stack) =
+            stack.collect_aligned(CACHELINE_ALIGN, accumulators.iter().copied());
+        self.batch_blind_rotate(&mut local_accumulators, lwes_in, fft, stack);
+        for (lwe_out, local_accumulator) in izip!(
+            lwes_out.chunks_exact_mut(lwe_out_size),
+            local_accumulators.chunks_exact(polynomial_size.0 * glwe_size.0)
+        ) {
+            GlweCiphertextView::new(local_accumulator, polynomial_size, glwe_size)
+                .fill_lwe_with_sample_extraction(lwe_out, 0);
+        }
+    }
 }
 
 /// This function switches modulus for a single coefficient of a ciphertext,
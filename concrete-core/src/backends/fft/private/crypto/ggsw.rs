@@ -34,6 +34,11 @@ pub struct StandardGgswCiphertext<C> {
 }
 
 /// A GGSW ciphertext in the Fourier domain.
+///
+/// The level matrices are not necessarily contiguous: two consecutive level matrices start
+/// `level_stride` elements apart, which allows viewing the GGSW ciphertexts of a bootstrap key
+/// stored with the [`LevelMajor`](super::bootstrap::FourierLweBootstrapKeyLayout::LevelMajor)
+/// layout.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct FourierGgswCiphertext<C> {
     data: C,
@@ -41,6 +46,7 @@ pub struct FourierGgswCiphertext<C> {
     glwe_size: GlweSize,
     decomposition_base_log: DecompositionBaseLog,
     decomposition_level_count: DecompositionLevelCount,
+    level_stride: usize,
 }
 
 /// A matrix containing a single level of gadget decomposition, in the Fourier domain.
@@ -151,13 +157,39 @@ impl<C> FourierGgswCiphertext<C> {
         decomposition_base_log: DecompositionBaseLog,
         decomposition_level_count: DecompositionLevelCount,
     ) -> Self
+    where
+        C: Container,
+    {
+        Self::new_with_level_stride(
+            data,
+            polynomial_size,
+            glwe_size,
+            decomposition_base_log,
+            decomposition_level_count,
+            polynomial_size.0 / 2 * glwe_size.0 * glwe_size.0,
+        )
+    }
+
+    /// Creates a GGSW ciphertext whose consecutive level matrices start `level_stride` elements
+    /// apart in `data`.
+    pub fn new_with_level_stride(
+        data: C,
+        polynomial_size: PolynomialSize,
+        glwe_size: GlweSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        level_stride: usize,
+    ) -> Self
     where
         C: Container,
     {
         assert_eq!(polynomial_size.0 % 2, 0);
+        let matrix_len = polynomial_size.0 / 2 * glwe_size.0 * glwe_size.0;
+        assert!(level_stride >= matrix_len);
+        assert!(decomposition_level_count.0 > 0);
         assert_eq!(
             data.container_len(),
-            polynomial_size.0 / 2 * glwe_size.0 * glwe_size.0 * decomposition_level_count.0
+            (decomposition_level_count.0 - 1) * level_stride + matrix_len
         );
 
         Self {
@@ -166,6 +198,7 @@ impl<C> FourierGgswCiphertext<C> {
             glwe_size,
             decomposition_base_log,
             decomposition_level_count,
+            level_stride,
         }
     }
 
@@ -199,6 +232,7 @@ impl<C> FourierGgswCiphertext<C> {
             glwe_size: self.glwe_size,
             decomposition_base_log: self.decomposition_base_log,
             decomposition_level_count: self.decomposition_level_count,
+            level_stride: self.level_stride,
         }
     }
 
@@ -212,6 +246,7 @@ impl<C> FourierGgswCiphertext<C> {
             glwe_size: self.glwe_size,
             decomposition_base_log: self.decomposition_base_log,
             decomposition_level_count: self.decomposition_level_count,
+            level_stride: self.level_stride,
         }
     }
 }
@@ -317,18 +352,18 @@ impl<C> FourierGgswLevelRow<C> {
 impl<'a> FourierGgswCiphertextView<'a> {
     /// Returns an iterator over the level matrices.
     pub fn into_levels(self) -> impl DoubleEndedIterator<Item = FourierGgswLevelMatrixView<'a>> {
-        self.data
-            .split_into(self.decomposition_level_count.0)
-            .enumerate()
-            .map(move |(i, slice)| {
-                FourierGgswLevelMatrixView::new(
-                    slice,
-                    self.polynomial_size,
-                    self.glwe_size,
-                    self.glwe_size.0,
-                    DecompositionLevel(i + 1),
-                )
-            })
+        let data = self.data;
+        let matrix_len = self.polynomial_size.0 / 2 * self.glwe_size.0 * self.glwe_size.0;
+        (0..self.decomposition_level_count.0).map(move |i| {
+            let start = i * self.level_stride;
+            FourierGgswLevelMatrixView::new(
+                &data[start..start + matrix_len],
+                self.polynomial_size,
+                self.glwe_size,
+                self.glwe_size.0,
+                DecompositionLevel(i + 1),
+            )
+        })
     }
 }
 
@@ -347,6 +382,10 @@ impl<'a> FourierGgswCiphertextMutView<'a> {
         mut stack: DynStack<'_>,
     ) {
         debug_assert_eq!(coef_ggsw.polynomial_size(), self.polynomial_size());
+        debug_assert_eq!(
+            self.level_stride,
+            self.polynomial_size.0 / 2 * self.glwe_size.0 * self.glwe_size.0
+        );
         let poly_size = coef_ggsw.polynomial_size().0;
 
         for (fourier_poly, coef_poly) in izip!(
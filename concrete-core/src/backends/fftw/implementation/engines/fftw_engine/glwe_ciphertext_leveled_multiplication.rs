@@ -0,0 +1,315 @@
+use super::glwe_ciphertext_tensor_product_same_key::tensor_product_glwe_ciphertexts;
+use crate::backends::fftw::private::crypto::bootstrap::FourierBuffers;
+use crate::commons::crypto::glwe::{
+    GlweCiphertext as ImplGlweCiphertext, GlweRelinearizationKey as ImplGlweRelinearizationKey,
+};
+use crate::commons::math::torus::UnsignedTorus;
+use crate::prelude::{
+    FftwEngine, FftwError, GlweCiphertext32, GlweCiphertext64,
+    GlweCiphertextLeveledMultiplicationEngine, GlweCiphertextLeveledMultiplicationError,
+    GlweRelinearizationKey32, GlweRelinearizationKey64, GlweRelinearizationKeyEntity,
+};
+use concrete_commons::parameters::ScalingFactor;
+
+impl From<FftwError> for GlweCiphertextLeveledMultiplicationError<FftwError> {
+    fn from(err: FftwError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextLeveledMultiplicationEngine`] for [`FftwEngine`] that operates
+/// on 32 bits integers.
+impl
+    GlweCiphertextLeveledMultiplicationEngine<
+        GlweCiphertext32,
+        GlweCiphertext32,
+        GlweRelinearizationKey32,
+        GlweCiphertext32,
+    > for FftwEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, ScalingFactor,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (glwe_dimension, polynomial_size) = (GlweDimension(1), PolynomialSize(256));
+    /// let (rlk_level_count, rlk_base_log) = (DecompositionLevelCount(7), DecompositionBaseLog(4));
+    /// let noise = Variance(2_f64.powf(-60.));
+    /// // Here a hard-set encoding is applied (shift by 27 bits)
+    /// let scaling_factor = ScalingFactor(1 << 27);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fftw_engine = FftwEngine::new(())?;
+    /// let key: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let rlk: GlweRelinearizationKey32 = default_engine.generate_new_glwe_relinearization_key(
+    ///     &key,
+    ///     rlk_level_count,
+    ///     rlk_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// // The first message is 3 + X, the second one is 2
+    /// let mut input_1 = vec![0_u32; polynomial_size.0];
+    /// input_1[0] = 3 << 27;
+    /// input_1[1] = 1 << 27;
+    /// let mut input_2 = vec![0_u32; polynomial_size.0];
+    /// input_2[0] = 2 << 27;
+    /// let plaintext_vector_1 = default_engine.create_plaintext_vector_from(&input_1)?;
+    /// let plaintext_vector_2 = default_engine.create_plaintext_vector_from(&input_2)?;
+    /// let ciphertext_1 = default_engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    /// let ciphertext_2 = default_engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_2, noise)?;
+    ///
+    /// let product = fftw_engine.mul_glwe_ciphertext_leveled(
+    ///     &ciphertext_1,
+    ///     &ciphertext_2,
+    ///     &rlk,
+    ///     scaling_factor,
+    ///     scaling_factor,
+    ///     scaling_factor,
+    /// )?;
+    /// #
+    /// assert_eq!(product.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(product.polynomial_size(), polynomial_size);
+    ///
+    /// let decrypted = default_engine.decrypt_glwe_ciphertext(&key, &product)?;
+    /// let decrypted = default_engine.retrieve_plaintext_vector(&decrypted)?;
+    /// let decoded: Vec<u32> = decrypted
+    ///     .iter()
+    ///     .map(|coefficient| coefficient.wrapping_add(1 << 26) >> 27)
+    ///     .collect();
+    /// assert_eq!(&decoded[..3], &[6, 2, 0]);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn mul_glwe_ciphertext_leveled(
+        &mut self,
+        input_1: &GlweCiphertext32,
+        input_2: &GlweCiphertext32,
+        rlk: &GlweRelinearizationKey32,
+        input_1_scaling_factor: ScalingFactor,
+        input_2_scaling_factor: ScalingFactor,
+        output_scaling_factor: ScalingFactor,
+    ) -> Result<GlweCiphertext32, GlweCiphertextLeveledMultiplicationError<Self::EngineError>> {
+        FftwError::perform_fftw_checks(rlk.polynomial_size())?;
+        GlweCiphertextLeveledMultiplicationError::perform_generic_checks(
+            input_1,
+            input_2,
+            rlk,
+            input_1_scaling_factor,
+            input_2_scaling_factor,
+            output_scaling_factor,
+            32,
+        )?;
+        Ok(unsafe {
+            self.mul_glwe_ciphertext_leveled_unchecked(
+                input_1,
+                input_2,
+                rlk,
+                input_1_scaling_factor,
+                input_2_scaling_factor,
+                output_scaling_factor,
+            )
+        })
+    }
+
+    unsafe fn mul_glwe_ciphertext_leveled_unchecked(
+        &mut self,
+        input_1: &GlweCiphertext32,
+        input_2: &GlweCiphertext32,
+        rlk: &GlweRelinearizationKey32,
+        input_1_scaling_factor: ScalingFactor,
+        input_2_scaling_factor: ScalingFactor,
+        output_scaling_factor: ScalingFactor,
+    ) -> GlweCiphertext32 {
+        let buffers =
+            self.get_fourier_u32_buffer(rlk.polynomial_size(), rlk.glwe_dimension().to_glwe_size());
+        GlweCiphertext32(multiply_glwe_ciphertexts(
+            &input_1.0,
+            &input_2.0,
+            &rlk.0,
+            compute_divisor(
+                input_1_scaling_factor,
+                input_2_scaling_factor,
+                output_scaling_factor,
+            ),
+            buffers,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextLeveledMultiplicationEngine`] for [`FftwEngine`] that operates
+/// on 64 bits integers.
+impl
+    GlweCiphertextLeveledMultiplicationEngine<
+        GlweCiphertext64,
+        GlweCiphertext64,
+        GlweRelinearizationKey64,
+        GlweCiphertext64,
+    > for FftwEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, ScalingFactor,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (glwe_dimension, polynomial_size) = (GlweDimension(1), PolynomialSize(256));
+    /// let (rlk_level_count, rlk_base_log) = (DecompositionLevelCount(5), DecompositionBaseLog(10));
+    /// let noise = Variance(2_f64.powf(-120.));
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let scaling_factor = ScalingFactor(1 << 50);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fftw_engine = FftwEngine::new(())?;
+    /// let key: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let rlk: GlweRelinearizationKey64 = default_engine.generate_new_glwe_relinearization_key(
+    ///     &key,
+    ///     rlk_level_count,
+    ///     rlk_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// // The first message is 3 + X, the second one is 2
+    /// let mut input_1 = vec![0_u64; polynomial_size.0];
+    /// input_1[0] = 3 << 50;
+    /// input_1[1] = 1 << 50;
+    /// let mut input_2 = vec![0_u64; polynomial_size.0];
+    /// input_2[0] = 2 << 50;
+    /// let plaintext_vector_1 = default_engine.create_plaintext_vector_from(&input_1)?;
+    /// let plaintext_vector_2 = default_engine.create_plaintext_vector_from(&input_2)?;
+    /// let ciphertext_1 = default_engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    /// let ciphertext_2 = default_engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_2, noise)?;
+    ///
+    /// let product = fftw_engine.mul_glwe_ciphertext_leveled(
+    ///     &ciphertext_1,
+    ///     &ciphertext_2,
+    ///     &rlk,
+    ///     scaling_factor,
+    ///     scaling_factor,
+    ///     scaling_factor,
+    /// )?;
+    /// #
+    /// assert_eq!(product.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(product.polynomial_size(), polynomial_size);
+    ///
+    /// let decrypted = default_engine.decrypt_glwe_ciphertext(&key, &product)?;
+    /// let decrypted = default_engine.retrieve_plaintext_vector(&decrypted)?;
+    /// let decoded: Vec<u64> = decrypted
+    ///     .iter()
+    ///     .map(|coefficient| coefficient.wrapping_add(1 << 49) >> 50)
+    ///     .collect();
+    /// assert_eq!(&decoded[..3], &[6, 2, 0]);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn mul_glwe_ciphertext_leveled(
+        &mut self,
+        input_1: &GlweCiphertext64,
+        input_2: &GlweCiphertext64,
+        rlk: &GlweRelinearizationKey64,
+        input_1_scaling_factor: ScalingFactor,
+        input_2_scaling_factor: ScalingFactor,
+        output_scaling_factor: ScalingFactor,
+    ) -> Result<GlweCiphertext64, GlweCiphertextLeveledMultiplicationError<Self::EngineError>> {
+        FftwError::perform_fftw_checks(rlk.polynomial_size())?;
+        GlweCiphertextLeveledMultiplicationError::perform_generic_checks(
+            input_1,
+            input_2,
+            rlk,
+            input_1_scaling_factor,
+            input_2_scaling_factor,
+            output_scaling_factor,
+            64,
+        )?;
+        Ok(unsafe {
+            self.mul_glwe_ciphertext_leveled_unchecked(
+                input_1,
+                input_2,
+                rlk,
+                input_1_scaling_factor,
+                input_2_scaling_factor,
+                output_scaling_factor,
+            )
+        })
+    }
+
+    unsafe fn mul_glwe_ciphertext_leveled_unchecked(
+        &mut self,
+        input_1: &GlweCiphertext64,
+        input_2: &GlweCiphertext64,
+        rlk: &GlweRelinearizationKey64,
+        input_1_scaling_factor: ScalingFactor,
+        input_2_scaling_factor: ScalingFactor,
+        output_scaling_factor: ScalingFactor,
+    ) -> GlweCiphertext64 {
+        let buffers =
+            self.get_fourier_u64_buffer(rlk.polynomial_size(), rlk.glwe_dimension().to_glwe_size());
+        GlweCiphertext64(multiply_glwe_ciphertexts(
+            &input_1.0,
+            &input_2.0,
+            &rlk.0,
+            compute_divisor(
+                input_1_scaling_factor,
+                input_2_scaling_factor,
+                output_scaling_factor,
+            ),
+            buffers,
+        ))
+    }
+}
+
+// The tensor product of the inputs is divided by this value, so that the message of the output is
+// encoded with the output scaling factor.
+pub(super) fn compute_divisor(
+    input_1_scaling_factor: ScalingFactor,
+    input_2_scaling_factor: ScalingFactor,
+    output_scaling_factor: ScalingFactor,
+) -> f64 {
+    (input_1_scaling_factor.0 as u128 * input_2_scaling_factor.0 as u128
+        / output_scaling_factor.0 as u128) as f64
+}
+
+fn multiply_glwe_ciphertexts<Scalar>(
+    input_1: &ImplGlweCiphertext<Vec<Scalar>>,
+    input_2: &ImplGlweCiphertext<Vec<Scalar>>,
+    rlk: &ImplGlweRelinearizationKey<Vec<Scalar>>,
+    divisor: f64,
+    buffers: &mut FourierBuffers<Scalar>,
+) -> ImplGlweCiphertext<Vec<Scalar>>
+where
+    Scalar: UnsignedTorus,
+{
+    let product = tensor_product_glwe_ciphertexts(input_1, input_2, divisor, buffers);
+
+    // We relinearize the rescaled tensor product back to the input key
+    let mut output = ImplGlweCiphertext::allocate(
+        Scalar::ZERO,
+        rlk.polynomial_size(),
+        rlk.glwe_dimension().to_glwe_size(),
+    );
+    rlk.relinearize(&mut output, &product);
+    output
+}
@@ -0,0 +1,187 @@
+use crate::backends::fftw::engines::{FftwEngine, FftwError};
+use crate::commons::crypto::glwe::GlweCiphertext as ImplGlweCiphertext;
+use crate::prelude::{
+    GlweCiphertext32, GlweCiphertext64, GlweCiphertextRelinearizationEngine,
+    GlweCiphertextRelinearizationError, GlweRelinearizationKey32, GlweRelinearizationKey64,
+    GlweRelinearizationKeyEntity, GlweTensorProductCiphertext32, GlweTensorProductCiphertext64,
+};
+
+impl From<FftwError> for GlweCiphertextRelinearizationError<FftwError> {
+    fn from(err: FftwError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextRelinearizationEngine`] for [`FftwEngine`] that operates on
+/// 32 bits integers.
+impl
+    GlweCiphertextRelinearizationEngine<
+        GlweTensorProductCiphertext32,
+        GlweRelinearizationKey32,
+        GlweCiphertext32,
+    > for FftwEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, ScalingFactor,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (glwe_dimension, polynomial_size) = (GlweDimension(1), PolynomialSize(256));
+    /// let (rlk_level_count, rlk_base_log) = (DecompositionLevelCount(7), DecompositionBaseLog(4));
+    /// let noise = Variance(2_f64.powf(-60.));
+    /// // Here a hard-set encoding is applied (shift by 27 bits)
+    /// let scaling_factor = ScalingFactor(1 << 27);
+    /// let mut input_1 = vec![0_u32; polynomial_size.0];
+    /// input_1[0] = 3 << 27;
+    /// let mut input_2 = vec![0_u32; polynomial_size.0];
+    /// input_2[0] = 2 << 27;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fftw_engine = FftwEngine::new(())?;
+    /// let key: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let rlk: GlweRelinearizationKey32 = default_engine.generate_new_glwe_relinearization_key(
+    ///     &key,
+    ///     rlk_level_count,
+    ///     rlk_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector_1 = default_engine.create_plaintext_vector_from(&input_1)?;
+    /// let plaintext_vector_2 = default_engine.create_plaintext_vector_from(&input_2)?;
+    /// let ciphertext_1 = default_engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    /// let ciphertext_2 = default_engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_2, noise)?;
+    /// let product: GlweTensorProductCiphertext32 = fftw_engine
+    ///     .tensor_product_glwe_ciphertext_same_key(&ciphertext_1, &ciphertext_2, scaling_factor)?;
+    ///
+    /// let relinearized: GlweCiphertext32 = fftw_engine.relinearize_glwe_ciphertext(&product, &rlk)?;
+    /// #
+    /// assert_eq!(relinearized.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(relinearized.polynomial_size(), polynomial_size);
+    ///
+    /// let decrypted = default_engine.decrypt_glwe_ciphertext(&key, &relinearized)?;
+    /// let decrypted = default_engine.retrieve_plaintext_vector(&decrypted)?;
+    /// assert_eq!(decrypted[0].wrapping_add(1 << 26) >> 27, 6);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn relinearize_glwe_ciphertext(
+        &mut self,
+        input: &GlweTensorProductCiphertext32,
+        rlk: &GlweRelinearizationKey32,
+    ) -> Result<GlweCiphertext32, GlweCiphertextRelinearizationError<Self::EngineError>> {
+        GlweCiphertextRelinearizationError::perform_generic_checks(input, rlk)?;
+        Ok(unsafe { self.relinearize_glwe_ciphertext_unchecked(input, rlk) })
+    }
+
+    unsafe fn relinearize_glwe_ciphertext_unchecked(
+        &mut self,
+        input: &GlweTensorProductCiphertext32,
+        rlk: &GlweRelinearizationKey32,
+    ) -> GlweCiphertext32 {
+        let mut output = ImplGlweCiphertext::allocate(
+            0_u32,
+            rlk.polynomial_size(),
+            rlk.glwe_dimension().to_glwe_size(),
+        );
+        rlk.0.relinearize(&mut output, &input.0);
+        GlweCiphertext32(output)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextRelinearizationEngine`] for [`FftwEngine`] that operates on
+/// 64 bits integers.
+impl
+    GlweCiphertextRelinearizationEngine<
+        GlweTensorProductCiphertext64,
+        GlweRelinearizationKey64,
+        GlweCiphertext64,
+    > for FftwEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, ScalingFactor,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (glwe_dimension, polynomial_size) = (GlweDimension(1), PolynomialSize(256));
+    /// let (rlk_level_count, rlk_base_log) = (DecompositionLevelCount(5), DecompositionBaseLog(10));
+    /// let noise = Variance(2_f64.powf(-120.));
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let scaling_factor = ScalingFactor(1 << 50);
+    /// let mut input_1 = vec![0_u64; polynomial_size.0];
+    /// input_1[0] = 3 << 50;
+    /// let mut input_2 = vec![0_u64; polynomial_size.0];
+    /// input_2[0] = 2 << 50;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fftw_engine = FftwEngine::new(())?;
+    /// let key: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let rlk: GlweRelinearizationKey64 = default_engine.generate_new_glwe_relinearization_key(
+    ///     &key,
+    ///     rlk_level_count,
+    ///     rlk_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector_1 = default_engine.create_plaintext_vector_from(&input_1)?;
+    /// let plaintext_vector_2 = default_engine.create_plaintext_vector_from(&input_2)?;
+    /// let ciphertext_1 = default_engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    /// let ciphertext_2 = default_engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_2, noise)?;
+    /// let product: GlweTensorProductCiphertext64 = fftw_engine
+    ///     .tensor_product_glwe_ciphertext_same_key(&ciphertext_1, &ciphertext_2, scaling_factor)?;
+    ///
+    /// let relinearized: GlweCiphertext64 = fftw_engine.relinearize_glwe_ciphertext(&product, &rlk)?;
+    /// #
+    /// assert_eq!(relinearized.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(relinearized.polynomial_size(), polynomial_size);
+    ///
+    /// let decrypted = default_engine.decrypt_glwe_ciphertext(&key, &relinearized)?;
+    /// let decrypted = default_engine.retrieve_plaintext_vector(&decrypted)?;
+    /// assert_eq!(decrypted[0].wrapping_add(1 << 49) >> 50, 6);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn relinearize_glwe_ciphertext(
+        &mut self,
+        input: &GlweTensorProductCiphertext64,
+        rlk: &GlweRelinearizationKey64,
+    ) -> Result<GlweCiphertext64, GlweCiphertextRelinearizationError<Self::EngineError>> {
+        GlweCiphertextRelinearizationError::perform_generic_checks(input, rlk)?;
+        Ok(unsafe { self.relinearize_glwe_ciphertext_unchecked(input, rlk) })
+    }
+
+    unsafe fn relinearize_glwe_ciphertext_unchecked(
+        &mut self,
+        input: &GlweTensorProductCiphertext64,
+        rlk: &GlweRelinearizationKey64,
+    ) -> GlweCiphertext64 {
+        let mut output = ImplGlweCiphertext::allocate(
+            0_u64,
+            rlk.polynomial_size(),
+            rlk.glwe_dimension().to_glwe_size(),
+        );
+        rlk.0.relinearize(&mut output, &input.0);
+        GlweCiphertext64(output)
+    }
+}
@@ -0,0 +1,225 @@
+use crate::backends::fftw::private::crypto::bootstrap::FourierBuffers;
+use crate::backends::fftw::private::crypto::glwe::FourierGlweCiphertext;
+use crate::backends::fftw::private::math::fft::Complex64;
+use crate::commons::crypto::glwe::GlweCiphertext as ImplGlweCiphertext;
+use crate::commons::math::torus::UnsignedTorus;
+use crate::prelude::{
+    FftwEngine, FftwError, GlweCiphertext32, GlweCiphertext64, GlweCiphertextEntity,
+    GlweCiphertextTensorProductSameKeyEngine, GlweCiphertextTensorProductSameKeyError,
+    GlweTensorProductCiphertext32, GlweTensorProductCiphertext64,
+};
+use concrete_commons::parameters::ScalingFactor;
+
+impl From<FftwError> for GlweCiphertextTensorProductSameKeyError<FftwError> {
+    fn from(err: FftwError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextTensorProductSameKeyEngine`] for [`FftwEngine`] that operates
+/// on 32 bits integers.
+impl
+    GlweCiphertextTensorProductSameKeyEngine<
+        GlweCiphertext32,
+        GlweCiphertext32,
+        GlweTensorProductCiphertext32,
+    > for FftwEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize, ScalingFactor};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (glwe_dimension, polynomial_size) = (GlweDimension(2), PolynomialSize(256));
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input_1 = vec![3_u32 << 20; polynomial_size.0];
+    /// let input_2 = vec![2_u32 << 20; polynomial_size.0];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fftw_engine = FftwEngine::new(())?;
+    /// let key: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector_1 = default_engine.create_plaintext_vector_from(&input_1)?;
+    /// let plaintext_vector_2 = default_engine.create_plaintext_vector_from(&input_2)?;
+    /// let ciphertext_1 = default_engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    /// let ciphertext_2 = default_engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_2, noise)?;
+    ///
+    /// let product: GlweTensorProductCiphertext32 = fftw_engine
+    ///     .tensor_product_glwe_ciphertext_same_key(
+    ///         &ciphertext_1,
+    ///         &ciphertext_2,
+    ///         ScalingFactor(1 << 20),
+    ///     )?;
+    /// #
+    /// assert_eq!(product.glwe_dimension(), GlweDimension(5));
+    /// assert_eq!(product.polynomial_size(), polynomial_size);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn tensor_product_glwe_ciphertext_same_key(
+        &mut self,
+        input_1: &GlweCiphertext32,
+        input_2: &GlweCiphertext32,
+        scaling_factor: ScalingFactor,
+    ) -> Result<
+        GlweTensorProductCiphertext32,
+        GlweCiphertextTensorProductSameKeyError<Self::EngineError>,
+    > {
+        FftwError::perform_fftw_checks(input_1.polynomial_size())?;
+        GlweCiphertextTensorProductSameKeyError::perform_generic_checks(
+            input_1,
+            input_2,
+            scaling_factor,
+            32,
+        )?;
+        Ok(unsafe {
+            self.tensor_product_glwe_ciphertext_same_key_unchecked(input_1, input_2, scaling_factor)
+        })
+    }
+
+    unsafe fn tensor_product_glwe_ciphertext_same_key_unchecked(
+        &mut self,
+        input_1: &GlweCiphertext32,
+        input_2: &GlweCiphertext32,
+        scaling_factor: ScalingFactor,
+    ) -> GlweTensorProductCiphertext32 {
+        let buffers = self.get_fourier_u32_buffer(
+            input_1.polynomial_size(),
+            input_1.glwe_dimension().to_glwe_size(),
+        );
+        GlweTensorProductCiphertext32(tensor_product_glwe_ciphertexts(
+            &input_1.0,
+            &input_2.0,
+            scaling_factor.0 as f64,
+            buffers,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextTensorProductSameKeyEngine`] for [`FftwEngine`] that operates
+/// on 64 bits integers.
+impl
+    GlweCiphertextTensorProductSameKeyEngine<
+        GlweCiphertext64,
+        GlweCiphertext64,
+        GlweTensorProductCiphertext64,
+    > for FftwEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize, ScalingFactor};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (glwe_dimension, polynomial_size) = (GlweDimension(2), PolynomialSize(256));
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // Here a hard-set encoding is applied (shift by 40 bits)
+    /// let input_1 = vec![3_u64 << 40; polynomial_size.0];
+    /// let input_2 = vec![2_u64 << 40; polynomial_size.0];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fftw_engine = FftwEngine::new(())?;
+    /// let key: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector_1 = default_engine.create_plaintext_vector_from(&input_1)?;
+    /// let plaintext_vector_2 = default_engine.create_plaintext_vector_from(&input_2)?;
+    /// let ciphertext_1 = default_engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    /// let ciphertext_2 = default_engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_2, noise)?;
+    ///
+    /// let product: GlweTensorProductCiphertext64 = fftw_engine
+    ///     .tensor_product_glwe_ciphertext_same_key(
+    ///         &ciphertext_1,
+    ///         &ciphertext_2,
+    ///         ScalingFactor(1 << 40),
+    ///     )?;
+    /// #
+    /// assert_eq!(product.glwe_dimension(), GlweDimension(5));
+    /// assert_eq!(product.polynomial_size(), polynomial_size);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn tensor_product_glwe_ciphertext_same_key(
+        &mut self,
+        input_1: &GlweCiphertext64,
+        input_2: &GlweCiphertext64,
+        scaling_factor: ScalingFactor,
+    ) -> Result<
+        GlweTensorProductCiphertext64,
+        GlweCiphertextTensorProductSameKeyError<Self::EngineError>,
+    > {
+        FftwError::perform_fftw_checks(input_1.polynomial_size())?;
+        GlweCiphertextTensorProductSameKeyError::perform_generic_checks(
+            input_1,
+            input_2,
+            scaling_factor,
+            64,
+        )?;
+        Ok(unsafe {
+            self.tensor_product_glwe_ciphertext_same_key_unchecked(input_1, input_2, scaling_factor)
+        })
+    }
+
+    unsafe fn tensor_product_glwe_ciphertext_same_key_unchecked(
+        &mut self,
+        input_1: &GlweCiphertext64,
+        input_2: &GlweCiphertext64,
+        scaling_factor: ScalingFactor,
+    ) -> GlweTensorProductCiphertext64 {
+        let buffers = self.get_fourier_u64_buffer(
+            input_1.polynomial_size(),
+            input_1.glwe_dimension().to_glwe_size(),
+        );
+        GlweTensorProductCiphertext64(tensor_product_glwe_ciphertexts(
+            &input_1.0,
+            &input_2.0,
+            scaling_factor.0 as f64,
+            buffers,
+        ))
+    }
+}
+
+pub(super) fn tensor_product_glwe_ciphertexts<Scalar>(
+    input_1: &ImplGlweCiphertext<Vec<Scalar>>,
+    input_2: &ImplGlweCiphertext<Vec<Scalar>>,
+    divisor: f64,
+    buffers: &mut FourierBuffers<Scalar>,
+) -> ImplGlweCiphertext<Vec<Scalar>>
+where
+    Scalar: UnsignedTorus,
+{
+    let polynomial_size = input_1.polynomial_size();
+    let glwe_size = input_1.size();
+
+    let mut fourier_1 =
+        FourierGlweCiphertext::allocate(Complex64::new(0., 0.), polynomial_size, glwe_size);
+    let mut fourier_2 =
+        FourierGlweCiphertext::allocate(Complex64::new(0., 0.), polynomial_size, glwe_size);
+    fourier_1.fill_with_forward_fourier(input_1, buffers);
+    fourier_2.fill_with_forward_fourier(input_2, buffers);
+
+    let mut product = ImplGlweCiphertext::allocate(
+        Scalar::ZERO,
+        polynomial_size,
+        glwe_size.tensor_product_size(),
+    );
+    fourier_1.fill_glwe_with_tensor_product_same_key(&fourier_2, &mut product, divisor, buffers);
+    product
+}
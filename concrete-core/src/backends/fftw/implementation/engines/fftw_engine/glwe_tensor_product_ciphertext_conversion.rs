@@ -0,0 +1,352 @@
+use crate::backends::fftw::engines::{FftwEngine, FftwError};
+use crate::backends::fftw::entities::{
+    FftwFourierGlweTensorProductCiphertext32, FftwFourierGlweTensorProductCiphertext64,
+};
+use crate::backends::fftw::private::crypto::glwe::FourierGlweCiphertext;
+use crate::backends::fftw::private::math::fft::Complex64;
+use crate::commons::crypto::glwe::GlweCiphertext;
+use crate::prelude::{GlweTensorProductCiphertext32, GlweTensorProductCiphertext64};
+use crate::specification::engines::{
+    GlweTensorProductCiphertextConversionEngine, GlweTensorProductCiphertextConversionError,
+};
+use crate::specification::entities::GlweTensorProductCiphertextEntity;
+
+impl From<FftwError> for GlweTensorProductCiphertextConversionError<FftwError> {
+    fn from(err: FftwError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweTensorProductCiphertextConversionEngine`] for [`FftwEngine`] that
+/// operates on 32 bits integers. It converts a GLWE tensor product ciphertext from the standard to
+/// the Fourier domain.
+impl
+    GlweTensorProductCiphertextConversionEngine<
+        GlweTensorProductCiphertext32,
+        FftwFourierGlweTensorProductCiphertext32,
+    > for FftwEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize, ScalingFactor};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (glwe_dimension, polynomial_size) = (GlweDimension(2), PolynomialSize(256));
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fftw_engine = FftwEngine::new(())?;
+    /// let key: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext = default_engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// // We compute a tensor product in the standard domain
+    /// let product: GlweTensorProductCiphertext32 = fftw_engine
+    ///     .tensor_product_glwe_ciphertext_same_key(
+    ///         &ciphertext,
+    ///         &ciphertext,
+    ///         ScalingFactor(1 << 20),
+    ///     )?;
+    ///
+    /// // Then we convert it to the Fourier domain.
+    /// let fourier_product: FftwFourierGlweTensorProductCiphertext32 =
+    ///     fftw_engine.convert_glwe_tensor_product_ciphertext(&product)?;
+    /// #
+    /// assert_eq!(fourier_product.glwe_dimension(), GlweDimension(5));
+    /// assert_eq!(fourier_product.polynomial_size(), polynomial_size);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_glwe_tensor_product_ciphertext(
+        &mut self,
+        input: &GlweTensorProductCiphertext32,
+    ) -> Result<
+        FftwFourierGlweTensorProductCiphertext32,
+        GlweTensorProductCiphertextConversionError<Self::EngineError>,
+    > {
+        FftwError::perform_fftw_checks(input.polynomial_size())?;
+        Ok(unsafe { self.convert_glwe_tensor_product_ciphertext_unchecked(input) })
+    }
+
+    unsafe fn convert_glwe_tensor_product_ciphertext_unchecked(
+        &mut self,
+        input: &GlweTensorProductCiphertext32,
+    ) -> FftwFourierGlweTensorProductCiphertext32 {
+        let mut output = FourierGlweCiphertext::allocate(
+            Complex64::new(0., 0.),
+            input.polynomial_size(),
+            input.glwe_dimension().to_glwe_size(),
+        );
+        let buffers = self.get_fourier_u32_buffer(
+            input.polynomial_size(),
+            input.glwe_dimension().to_glwe_size(),
+        );
+        output.fill_with_forward_fourier(&input.0, buffers);
+        FftwFourierGlweTensorProductCiphertext32(output)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweTensorProductCiphertextConversionEngine`] for [`FftwEngine`] that
+/// operates on 64 bits integers. It converts a GLWE tensor product ciphertext from the standard to
+/// the Fourier domain.
+impl
+    GlweTensorProductCiphertextConversionEngine<
+        GlweTensorProductCiphertext64,
+        FftwFourierGlweTensorProductCiphertext64,
+    > for FftwEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize, ScalingFactor};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (glwe_dimension, polynomial_size) = (GlweDimension(2), PolynomialSize(256));
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // Here a hard-set encoding is applied (shift by 40 bits)
+    /// let input = vec![3_u64 << 40; polynomial_size.0];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fftw_engine = FftwEngine::new(())?;
+    /// let key: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext = default_engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// // We compute a tensor product in the standard domain
+    /// let product: GlweTensorProductCiphertext64 = fftw_engine
+    ///     .tensor_product_glwe_ciphertext_same_key(
+    ///         &ciphertext,
+    ///         &ciphertext,
+    ///         ScalingFactor(1 << 40),
+    ///     )?;
+    ///
+    /// // Then we convert it to the Fourier domain.
+    /// let fourier_product: FftwFourierGlweTensorProductCiphertext64 =
+    ///     fftw_engine.convert_glwe_tensor_product_ciphertext(&product)?;
+    /// #
+    /// assert_eq!(fourier_product.glwe_dimension(), GlweDimension(5));
+    /// assert_eq!(fourier_product.polynomial_size(), polynomial_size);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_glwe_tensor_product_ciphertext(
+        &mut self,
+        input: &GlweTensorProductCiphertext64,
+    ) -> Result<
+        FftwFourierGlweTensorProductCiphertext64,
+        GlweTensorProductCiphertextConversionError<Self::EngineError>,
+    > {
+        FftwError::perform_fftw_checks(input.polynomial_size())?;
+        Ok(unsafe { self.convert_glwe_tensor_product_ciphertext_unchecked(input) })
+    }
+
+    unsafe fn convert_glwe_tensor_product_ciphertext_unchecked(
+        &mut self,
+        input: &GlweTensorProductCiphertext64,
+    ) -> FftwFourierGlweTensorProductCiphertext64 {
+        let mut output = FourierGlweCiphertext::allocate(
+            Complex64::new(0., 0.),
+            input.polynomial_size(),
+            input.glwe_dimension().to_glwe_size(),
+        );
+        let buffers = self.get_fourier_u64_buffer(
+            input.polynomial_size(),
+            input.glwe_dimension().to_glwe_size(),
+        );
+        output.fill_with_forward_fourier(&input.0, buffers);
+        FftwFourierGlweTensorProductCiphertext64(output)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweTensorProductCiphertextConversionEngine`] for [`FftwEngine`] that
+/// operates on 32 bits integers. It converts a GLWE tensor product ciphertext from the Fourier to
+/// the standard domain.
+impl
+    GlweTensorProductCiphertextConversionEngine<
+        FftwFourierGlweTensorProductCiphertext32,
+        GlweTensorProductCiphertext32,
+    > for FftwEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize, ScalingFactor};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (glwe_dimension, polynomial_size) = (GlweDimension(2), PolynomialSize(256));
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fftw_engine = FftwEngine::new(())?;
+    /// let key: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext = default_engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// // We compute a tensor product in the standard domain
+    /// let product: GlweTensorProductCiphertext32 = fftw_engine
+    ///     .tensor_product_glwe_ciphertext_same_key(
+    ///         &ciphertext,
+    ///         &ciphertext,
+    ///         ScalingFactor(1 << 20),
+    ///     )?;
+    ///
+    /// // Then we convert it to the Fourier domain.
+    /// let fourier_product: FftwFourierGlweTensorProductCiphertext32 =
+    ///     fftw_engine.convert_glwe_tensor_product_ciphertext(&product)?;
+    /// // Then we convert it back to the standard domain.
+    /// let product_out: GlweTensorProductCiphertext32 =
+    ///     fftw_engine.convert_glwe_tensor_product_ciphertext(&fourier_product)?;
+    /// #
+    /// assert_eq!(product_out.glwe_dimension(), GlweDimension(5));
+    /// assert_eq!(product_out.polynomial_size(), polynomial_size);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_glwe_tensor_product_ciphertext(
+        &mut self,
+        input: &FftwFourierGlweTensorProductCiphertext32,
+    ) -> Result<
+        GlweTensorProductCiphertext32,
+        GlweTensorProductCiphertextConversionError<Self::EngineError>,
+    > {
+        FftwError::perform_fftw_checks(input.polynomial_size())?;
+        Ok(unsafe { self.convert_glwe_tensor_product_ciphertext_unchecked(input) })
+    }
+
+    unsafe fn convert_glwe_tensor_product_ciphertext_unchecked(
+        &mut self,
+        input: &FftwFourierGlweTensorProductCiphertext32,
+    ) -> GlweTensorProductCiphertext32 {
+        let mut output = GlweCiphertext::allocate(
+            0_u32,
+            input.polynomial_size(),
+            input.glwe_dimension().to_glwe_size(),
+        );
+        let buffers = self.get_fourier_u32_buffer(
+            input.polynomial_size(),
+            input.glwe_dimension().to_glwe_size(),
+        );
+        input
+            .0
+            .fill_glwe_with_backward_fourier(&mut output, buffers);
+        GlweTensorProductCiphertext32(output)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweTensorProductCiphertextConversionEngine`] for [`FftwEngine`] that
+/// operates on 64 bits integers. It converts a GLWE tensor product ciphertext from the Fourier to
+/// the standard domain.
+impl
+    GlweTensorProductCiphertextConversionEngine<
+        FftwFourierGlweTensorProductCiphertext64,
+        GlweTensorProductCiphertext64,
+    > for FftwEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize, ScalingFactor};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (glwe_dimension, polynomial_size) = (GlweDimension(2), PolynomialSize(256));
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // Here a hard-set encoding is applied (shift by 40 bits)
+    /// let input = vec![3_u64 << 40; polynomial_size.0];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fftw_engine = FftwEngine::new(())?;
+    /// let key: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext = default_engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// // We compute a tensor product in the standard domain
+    /// let product: GlweTensorProductCiphertext64 = fftw_engine
+    ///     .tensor_product_glwe_ciphertext_same_key(
+    ///         &ciphertext,
+    ///         &ciphertext,
+    ///         ScalingFactor(1 << 40),
+    ///     )?;
+    ///
+    /// // Then we convert it to the Fourier domain.
+    /// let fourier_product: FftwFourierGlweTensorProductCiphertext64 =
+    ///     fftw_engine.convert_glwe_tensor_product_ciphertext(&product)?;
+    /// // Then we convert it back to the standard domain.
+    /// let product_out: GlweTensorProductCiphertext64 =
+    ///     fftw_engine.convert_glwe_tensor_product_ciphertext(&fourier_product)?;
+    /// #
+    /// assert_eq!(product_out.glwe_dimension(), GlweDimension(5));
+    /// assert_eq!(product_out.polynomial_size(), polynomial_size);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_glwe_tensor_product_ciphertext(
+        &mut self,
+        input: &FftwFourierGlweTensorProductCiphertext64,
+    ) -> Result<
+        GlweTensorProductCiphertext64,
+        GlweTensorProductCiphertextConversionError<Self::EngineError>,
+    > {
+        FftwError::perform_fftw_checks(input.polynomial_size())?;
+        Ok(unsafe { self.convert_glwe_tensor_product_ciphertext_unchecked(input) })
+    }
+
+    unsafe fn convert_glwe_tensor_product_ciphertext_unchecked(
+        &mut self,
+        input: &FftwFourierGlweTensorProductCiphertext64,
+    ) -> GlweTensorProductCiphertext64 {
+        let mut output = GlweCiphertext::allocate(
+            0_u64,
+            input.polynomial_size(),
+            input.glwe_dimension().to_glwe_size(),
+        );
+        let buffers = self.get_fourier_u64_buffer(
+            input.polynomial_size(),
+            input.glwe_dimension().to_glwe_size(),
+        );
+        input
+            .0
+            .fill_glwe_with_backward_fourier(&mut output, buffers);
+        GlweTensorProductCiphertext64(output)
+    }
+}
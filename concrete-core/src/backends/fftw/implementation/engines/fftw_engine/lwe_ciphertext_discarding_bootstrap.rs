@@ -2,8 +2,9 @@ use crate::backends::fftw::engines::{FftwEngine, FftwError};
 use crate::backends::fftw::entities::{FftwFourierLweBootstrapKey32, FftwFourierLweBootstrapKey64};
 use crate::prelude::{
     GlweCiphertext32, GlweCiphertext64, GlweCiphertextEntity, GlweCiphertextView32,
-    GlweCiphertextView64, LweBootstrapKeyEntity, LweCiphertext32, LweCiphertext64,
-    LweCiphertextMutView32, LweCiphertextMutView64, LweCiphertextView32, LweCiphertextView64,
+    GlweCiphertextView64, LweBootstrapKeyEntity, LweBootstrapLookupTable32,
+    LweBootstrapLookupTable64, LweCiphertext32, LweCiphertext64, LweCiphertextMutView32,
+    LweCiphertextMutView64, LweCiphertextView32, LweCiphertextView64,
 };
 use crate::specification::engines::{
     LweCiphertextDiscardingBootstrapEngine, LweCiphertextDiscardingBootstrapError,
@@ -407,3 +408,193 @@ impl
         bsk.0.bootstrap(&mut output.0, &input.0, &acc.0, buffers);
     }
 }
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingBootstrapEngine`] for [`FftwEngine`] that operates on
+/// 32 bits integers, with a lookup table created from a function.
+impl
+    LweCiphertextDiscardingBootstrapEngine<
+        FftwFourierLweBootstrapKey32,
+        LweBootstrapLookupTable32,
+        LweCiphertext32,
+        LweCiphertext32,
+    > for FftwEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, DeltaLog, GlweDimension, LweDimension,
+    ///     PolynomialSize, ScalingFactor,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // The messages are encoded on 3 bits, with a padding bit (shift by 28 bits)
+    /// let delta = ScalingFactor::for_precision32(DeltaLog(28)).unwrap();
+    /// let input = 3_u32 << 28;
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fftw_engine = FftwEngine::new(())?;
+    /// let lwe_sk: LweSecretKey32 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey32 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let bsk: FftwFourierLweBootstrapKey32 = fftw_engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// let lwe_sk_output: LweSecretKey32 =
+    ///     default_engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_sk)?;
+    /// // The square of the message is computed during the bootstrap
+    /// let lut: LweBootstrapLookupTable32 = default_engine.create_lwe_bootstrap_lookup_table(
+    ///     &|m| (m * m) % 8,
+    ///     glwe_dim,
+    ///     poly_size,
+    ///     delta,
+    ///     delta,
+    /// )?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    /// let input = default_engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output = default_engine.zero_encrypt_lwe_ciphertext(&lwe_sk_output, noise)?;
+    ///
+    /// fftw_engine.discard_bootstrap_lwe_ciphertext(&mut output, &input, &lut, &bsk)?;
+    /// #
+    /// let decrypted = default_engine.decrypt_lwe_ciphertext(&lwe_sk_output, &output)?;
+    /// let decoded = default_engine.retrieve_plaintext(&decrypted)?;
+    /// assert_eq!(decoded.wrapping_add(1 << 27) >> 28, 1);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &LweCiphertext32,
+        acc: &LweBootstrapLookupTable32,
+        bsk: &FftwFourierLweBootstrapKey32,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapError<Self::EngineError>> {
+        FftwError::perform_fftw_checks(acc.polynomial_size())?;
+        LweCiphertextDiscardingBootstrapError::perform_generic_checks(output, input, acc, bsk)?;
+        unsafe { self.discard_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &LweCiphertext32,
+        acc: &LweBootstrapLookupTable32,
+        bsk: &FftwFourierLweBootstrapKey32,
+    ) {
+        let buffers =
+            self.get_fourier_u32_buffer(bsk.polynomial_size(), bsk.glwe_dimension().to_glwe_size());
+        bsk.0.bootstrap(&mut output.0, &input.0, &acc.0, buffers);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingBootstrapEngine`] for [`FftwEngine`] that operates on
+/// 64 bits integers, with a lookup table created from a function.
+impl
+    LweCiphertextDiscardingBootstrapEngine<
+        FftwFourierLweBootstrapKey64,
+        LweBootstrapLookupTable64,
+        LweCiphertext64,
+        LweCiphertext64,
+    > for FftwEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, DeltaLog, GlweDimension, LweDimension,
+    ///     PolynomialSize, ScalingFactor,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // The messages are encoded on 3 bits, with a padding bit (shift by 60 bits)
+    /// let delta = ScalingFactor::for_precision64(DeltaLog(60)).unwrap();
+    /// let input = 3_u64 << 60;
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fftw_engine = FftwEngine::new(())?;
+    /// let lwe_sk: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey64 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let bsk: FftwFourierLweBootstrapKey64 = fftw_engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// let lwe_sk_output: LweSecretKey64 =
+    ///     default_engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_sk)?;
+    /// // The square of the message is computed during the bootstrap
+    /// let lut: LweBootstrapLookupTable64 = default_engine.create_lwe_bootstrap_lookup_table(
+    ///     &|m| (m * m) % 8,
+    ///     glwe_dim,
+    ///     poly_size,
+    ///     delta,
+    ///     delta,
+    /// )?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    /// let input = default_engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output = default_engine.zero_encrypt_lwe_ciphertext(&lwe_sk_output, noise)?;
+    ///
+    /// fftw_engine.discard_bootstrap_lwe_ciphertext(&mut output, &input, &lut, &bsk)?;
+    /// #
+    /// let decrypted = default_engine.decrypt_lwe_ciphertext(&lwe_sk_output, &output)?;
+    /// let decoded = default_engine.retrieve_plaintext(&decrypted)?;
+    /// assert_eq!(decoded.wrapping_add(1 << 59) >> 60, 1);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &LweCiphertext64,
+        acc: &LweBootstrapLookupTable64,
+        bsk: &FftwFourierLweBootstrapKey64,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapError<Self::EngineError>> {
+        FftwError::perform_fftw_checks(acc.polynomial_size())?;
+        LweCiphertextDiscardingBootstrapError::perform_generic_checks(output, input, acc, bsk)?;
+        unsafe { self.discard_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &LweCiphertext64,
+        acc: &LweBootstrapLookupTable64,
+        bsk: &FftwFourierLweBootstrapKey64,
+    ) {
+        let buffers =
+            self.get_fourier_u64_buffer(bsk.polynomial_size(), bsk.glwe_dimension().to_glwe_size());
+        bsk.0.bootstrap(&mut output.0, &input.0, &acc.0, buffers);
+    }
+}
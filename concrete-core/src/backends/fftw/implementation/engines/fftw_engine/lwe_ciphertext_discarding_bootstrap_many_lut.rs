@@ -0,0 +1,247 @@
+use crate::backends::fftw::engines::{FftwEngine, FftwError};
+use crate::backends::fftw::entities::{FftwFourierLweBootstrapKey32, FftwFourierLweBootstrapKey64};
+use crate::prelude::{
+    GlweCiphertext32, GlweCiphertext64, GlweCiphertextEntity, LweBootstrapKeyEntity,
+    LweCiphertext32, LweCiphertext64, LweCiphertextVector32, LweCiphertextVector64,
+};
+use crate::specification::engines::{
+    LweCiphertextDiscardingBootstrapManyLutEngine, LweCiphertextDiscardingBootstrapManyLutError,
+};
+
+impl From<FftwError> for LweCiphertextDiscardingBootstrapManyLutError<FftwError> {
+    fn from(err: FftwError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingBootstrapManyLutEngine`] for [`FftwEngine`] that
+/// operates on 32 bits integers.
+impl
+    LweCiphertextDiscardingBootstrapManyLutEngine<
+        FftwFourierLweBootstrapKey32,
+        GlweCiphertext32,
+        LweCiphertext32,
+        LweCiphertextVector32,
+    > for FftwEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweCiphertextCount,
+    ///     LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // The messages are encoded on 3 bits, with a padding bit (shift by 28 bits)
+    /// let input = 3_u32 << 28;
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // The square and the successor of the message are computed by the same bootstrap
+    /// let functions: [fn(u32) -> u32; 2] = [|m| (m * m) % 8, |m| (m + 1) % 8];
+    /// // The coefficient i of each block of two coefficients holds the i-th function of the
+    /// // message closest to the phase of the block, the last half box holding the opposite of
+    /// // the image of zero.
+    /// let lut: Vec<u32> = (0..poly_size.0)
+    ///     .map(|index| {
+    ///         let function = functions[index % 2];
+    ///         match (index - index % 2 + 64) / 128 {
+    ///             8 => function(0).wrapping_neg() << 28,
+    ///             message => function(message as u32) << 28,
+    ///         }
+    ///     })
+    ///     .collect();
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fftw_engine = FftwEngine::new(())?;
+    /// let lwe_sk: LweSecretKey32 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey32 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let bsk: FftwFourierLweBootstrapKey32 = fftw_engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// let lwe_sk_output: LweSecretKey32 =
+    ///     default_engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_sk)?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&lut)?;
+    /// let acc = default_engine
+    ///     .trivially_encrypt_glwe_ciphertext(glwe_dim.to_glwe_size(), &plaintext_vector)?;
+    /// let input = default_engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output = default_engine.zero_encrypt_lwe_ciphertext_vector(
+    ///     &lwe_sk_output,
+    ///     noise,
+    ///     LweCiphertextCount(2),
+    /// )?;
+    ///
+    /// fftw_engine.discard_bootstrap_many_lut_lwe_ciphertext(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// let decrypted = default_engine.decrypt_lwe_ciphertext_vector(&lwe_sk_output, &output)?;
+    /// let decoded = default_engine.retrieve_plaintext_vector(&decrypted)?;
+    /// let decoded: Vec<u32> = decoded
+    ///     .iter()
+    ///     .map(|d| d.wrapping_add(1 << (28 - 1)) >> 28)
+    ///     .collect();
+    /// assert_eq!(decoded, vec![1, 4]);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_many_lut_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &LweCiphertext32,
+        acc: &GlweCiphertext32,
+        bsk: &FftwFourierLweBootstrapKey32,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapManyLutError<Self::EngineError>> {
+        FftwError::perform_fftw_checks(acc.polynomial_size())?;
+        LweCiphertextDiscardingBootstrapManyLutError::perform_generic_checks(
+            output, input, acc, bsk,
+        )?;
+        unsafe {
+            self.discard_bootstrap_many_lut_lwe_ciphertext_unchecked(output, input, acc, bsk)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_many_lut_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &LweCiphertext32,
+        acc: &GlweCiphertext32,
+        bsk: &FftwFourierLweBootstrapKey32,
+    ) {
+        let buffers =
+            self.get_fourier_u32_buffer(bsk.polynomial_size(), bsk.glwe_dimension().to_glwe_size());
+        bsk.0
+            .bootstrap_many_lut(&mut output.0, &input.0, &acc.0, buffers);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingBootstrapManyLutEngine`] for [`FftwEngine`] that
+/// operates on 64 bits integers.
+impl
+    LweCiphertextDiscardingBootstrapManyLutEngine<
+        FftwFourierLweBootstrapKey64,
+        GlweCiphertext64,
+        LweCiphertext64,
+        LweCiphertextVector64,
+    > for FftwEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweCiphertextCount,
+    ///     LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // The messages are encoded on 3 bits, with a padding bit (shift by 60 bits)
+    /// let input = 3_u64 << 60;
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // The square and the successor of the message are computed by the same bootstrap
+    /// let functions: [fn(u64) -> u64; 2] = [|m| (m * m) % 8, |m| (m + 1) % 8];
+    /// // The coefficient i of each block of two coefficients holds the i-th function of the
+    /// // message closest to the phase of the block, the last half box holding the opposite of
+    /// // the image of zero.
+    /// let lut: Vec<u64> = (0..poly_size.0)
+    ///     .map(|index| {
+    ///         let function = functions[index % 2];
+    ///         match (index - index % 2 + 64) / 128 {
+    ///             8 => function(0).wrapping_neg() << 60,
+    ///             message => function(message as u64) << 60,
+    ///         }
+    ///     })
+    ///     .collect();
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fftw_engine = FftwEngine::new(())?;
+    /// let lwe_sk: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey64 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let bsk: FftwFourierLweBootstrapKey64 = fftw_engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// let lwe_sk_output: LweSecretKey64 =
+    ///     default_engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_sk)?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&lut)?;
+    /// let acc = default_engine
+    ///     .trivially_encrypt_glwe_ciphertext(glwe_dim.to_glwe_size(), &plaintext_vector)?;
+    /// let input = default_engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output = default_engine.zero_encrypt_lwe_ciphertext_vector(
+    ///     &lwe_sk_output,
+    ///     noise,
+    ///     LweCiphertextCount(2),
+    /// )?;
+    ///
+    /// fftw_engine.discard_bootstrap_many_lut_lwe_ciphertext(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// let decrypted = default_engine.decrypt_lwe_ciphertext_vector(&lwe_sk_output, &output)?;
+    /// let decoded = default_engine.retrieve_plaintext_vector(&decrypted)?;
+    /// let decoded: Vec<u64> = decoded
+    ///     .iter()
+    ///     .map(|d| d.wrapping_add(1 << (60 - 1)) >> 60)
+    ///     .collect();
+    /// assert_eq!(decoded, vec![1, 4]);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_many_lut_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &LweCiphertext64,
+        acc: &GlweCiphertext64,
+        bsk: &FftwFourierLweBootstrapKey64,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapManyLutError<Self::EngineError>> {
+        FftwError::perform_fftw_checks(acc.polynomial_size())?;
+        LweCiphertextDiscardingBootstrapManyLutError::perform_generic_checks(
+            output, input, acc, bsk,
+        )?;
+        unsafe {
+            self.discard_bootstrap_many_lut_lwe_ciphertext_unchecked(output, input, acc, bsk)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_many_lut_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &LweCiphertext64,
+        acc: &GlweCiphertext64,
+        bsk: &FftwFourierLweBootstrapKey64,
+    ) {
+        let buffers =
+            self.get_fourier_u64_buffer(bsk.polynomial_size(), bsk.glwe_dimension().to_glwe_size());
+        bsk.0
+            .bootstrap_many_lut(&mut output.0, &input.0, &acc.0, buffers);
+    }
+}
@@ -0,0 +1,363 @@
+use super::glwe_ciphertext_leveled_multiplication::compute_divisor;
+use crate::backends::fftw::private::crypto::bootstrap::FourierBuffers;
+use crate::backends::fftw::private::crypto::glwe::FourierGlweCiphertext;
+use crate::backends::fftw::private::math::fft::Complex64;
+use crate::commons::crypto::glwe::{
+    GlweCiphertext as ImplGlweCiphertext, GlweRelinearizationKey as ImplGlweRelinearizationKey,
+    LwePackingKeyswitchKey as ImplLwePackingKeyswitchKey,
+};
+use crate::commons::crypto::lwe::LweCiphertext as ImplLweCiphertext;
+use crate::commons::math::torus::UnsignedTorus;
+use crate::prelude::{
+    FftwEngine, FftwError, GlweRelinearizationKey32, GlweRelinearizationKey64,
+    GlweRelinearizationKeyEntity, LweCiphertext32, LweCiphertext64,
+    LweCiphertextDiscardingMultiplicationEngine, LweCiphertextDiscardingMultiplicationError,
+    LwePackingKeyswitchKey32, LwePackingKeyswitchKey64,
+};
+#[allow(deprecated)]
+use concrete_commons::parameters::{MonomialDegree, ScalingFactor};
+
+impl From<FftwError> for LweCiphertextDiscardingMultiplicationError<FftwError> {
+    fn from(err: FftwError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingMultiplicationEngine`] for [`FftwEngine`] that
+/// operates on 32 bits integers.
+impl
+    LweCiphertextDiscardingMultiplicationEngine<
+        LweCiphertext32,
+        LweCiphertext32,
+        LwePackingKeyswitchKey32,
+        GlweRelinearizationKey32,
+        LweCiphertext32,
+    > for FftwEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PolynomialSize, ScalingFactor,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dimension, glwe_dimension, polynomial_size) =
+    ///     (LweDimension(256), GlweDimension(1), PolynomialSize(256));
+    /// let (pksk_level_count, pksk_base_log) = (DecompositionLevelCount(7), DecompositionBaseLog(4));
+    /// let (rlk_level_count, rlk_base_log) = (DecompositionLevelCount(7), DecompositionBaseLog(4));
+    /// let noise = Variance(2_f64.powf(-60.));
+    /// // Here a hard-set encoding is applied (shift by 27 bits)
+    /// let scaling_factor = ScalingFactor(1 << 27);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fftw_engine = FftwEngine::new(())?;
+    /// let lwe_key: LweSecretKey32 = default_engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let glwe_key: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let pksk: LwePackingKeyswitchKey32 = default_engine.generate_new_lwe_packing_keyswitch_key(
+    ///     &lwe_key,
+    ///     &glwe_key,
+    ///     pksk_level_count,
+    ///     pksk_base_log,
+    ///     noise,
+    /// )?;
+    /// let rlk: GlweRelinearizationKey32 = default_engine.generate_new_glwe_relinearization_key(
+    ///     &glwe_key,
+    ///     rlk_level_count,
+    ///     rlk_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let plaintext_1 = default_engine.create_plaintext_from(&(3_u32 << 27))?;
+    /// let plaintext_2 = default_engine.create_plaintext_from(&(2_u32 << 27))?;
+    /// let input_1 = default_engine.encrypt_lwe_ciphertext(&lwe_key, &plaintext_1, noise)?;
+    /// let input_2 = default_engine.encrypt_lwe_ciphertext(&lwe_key, &plaintext_2, noise)?;
+    ///
+    /// let output_key: LweSecretKey32 =
+    ///     default_engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_key)?;
+    /// let mut output = default_engine.zero_encrypt_lwe_ciphertext(&output_key, noise)?;
+    ///
+    /// fftw_engine.discard_mul_lwe_ciphertext(
+    ///     &mut output,
+    ///     &input_1,
+    ///     &input_2,
+    ///     &pksk,
+    ///     &rlk,
+    ///     scaling_factor,
+    ///     scaling_factor,
+    ///     scaling_factor,
+    /// )?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), LweDimension(256));
+    ///
+    /// let decrypted = default_engine.decrypt_lwe_ciphertext(&output_key, &output)?;
+    /// let decrypted = default_engine.retrieve_plaintext(&decrypted)?;
+    /// assert_eq!(decrypted.wrapping_add(1 << 26) >> 27, 6);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_mul_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input_1: &LweCiphertext32,
+        input_2: &LweCiphertext32,
+        pksk: &LwePackingKeyswitchKey32,
+        rlk: &GlweRelinearizationKey32,
+        input_1_scaling_factor: ScalingFactor,
+        input_2_scaling_factor: ScalingFactor,
+        output_scaling_factor: ScalingFactor,
+    ) -> Result<(), LweCiphertextDiscardingMultiplicationError<Self::EngineError>> {
+        FftwError::perform_fftw_checks(rlk.polynomial_size())?;
+        LweCiphertextDiscardingMultiplicationError::perform_generic_checks(
+            output,
+            input_1,
+            input_2,
+            pksk,
+            rlk,
+            input_1_scaling_factor,
+            input_2_scaling_factor,
+            output_scaling_factor,
+            32,
+        )?;
+        unsafe {
+            self.discard_mul_lwe_ciphertext_unchecked(
+                output,
+                input_1,
+                input_2,
+                pksk,
+                rlk,
+                input_1_scaling_factor,
+                input_2_scaling_factor,
+                output_scaling_factor,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_mul_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input_1: &LweCiphertext32,
+        input_2: &LweCiphertext32,
+        pksk: &LwePackingKeyswitchKey32,
+        rlk: &GlweRelinearizationKey32,
+        input_1_scaling_factor: ScalingFactor,
+        input_2_scaling_factor: ScalingFactor,
+        output_scaling_factor: ScalingFactor,
+    ) {
+        let buffers =
+            self.get_fourier_u32_buffer(rlk.polynomial_size(), rlk.glwe_dimension().to_glwe_size());
+        multiply_lwe_ciphertexts(
+            &mut output.0,
+            &input_1.0,
+            &input_2.0,
+            &pksk.0,
+            &rlk.0,
+            compute_divisor(
+                input_1_scaling_factor,
+                input_2_scaling_factor,
+                output_scaling_factor,
+            ),
+            buffers,
+        );
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingMultiplicationEngine`] for [`FftwEngine`] that
+/// operates on 64 bits integers.
+impl
+    LweCiphertextDiscardingMultiplicationEngine<
+        LweCiphertext64,
+        LweCiphertext64,
+        LwePackingKeyswitchKey64,
+        GlweRelinearizationKey64,
+        LweCiphertext64,
+    > for FftwEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PolynomialSize, ScalingFactor,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dimension, glwe_dimension, polynomial_size) =
+    ///     (LweDimension(256), GlweDimension(1), PolynomialSize(256));
+    /// let (pksk_level_count, pksk_base_log) = (DecompositionLevelCount(5), DecompositionBaseLog(10));
+    /// let (rlk_level_count, rlk_base_log) = (DecompositionLevelCount(5), DecompositionBaseLog(10));
+    /// let noise = Variance(2_f64.powf(-120.));
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let scaling_factor = ScalingFactor(1 << 50);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fftw_engine = FftwEngine::new(())?;
+    /// let lwe_key: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let glwe_key: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let pksk: LwePackingKeyswitchKey64 = default_engine.generate_new_lwe_packing_keyswitch_key(
+    ///     &lwe_key,
+    ///     &glwe_key,
+    ///     pksk_level_count,
+    ///     pksk_base_log,
+    ///     noise,
+    /// )?;
+    /// let rlk: GlweRelinearizationKey64 = default_engine.generate_new_glwe_relinearization_key(
+    ///     &glwe_key,
+    ///     rlk_level_count,
+    ///     rlk_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let plaintext_1 = default_engine.create_plaintext_from(&(3_u64 << 50))?;
+    /// let plaintext_2 = default_engine.create_plaintext_from(&(2_u64 << 50))?;
+    /// let input_1 = default_engine.encrypt_lwe_ciphertext(&lwe_key, &plaintext_1, noise)?;
+    /// let input_2 = default_engine.encrypt_lwe_ciphertext(&lwe_key, &plaintext_2, noise)?;
+    ///
+    /// let output_key: LweSecretKey64 =
+    ///     default_engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_key)?;
+    /// let mut output = default_engine.zero_encrypt_lwe_ciphertext(&output_key, noise)?;
+    ///
+    /// fftw_engine.discard_mul_lwe_ciphertext(
+    ///     &mut output,
+    ///     &input_1,
+    ///     &input_2,
+    ///     &pksk,
+    ///     &rlk,
+    ///     scaling_factor,
+    ///     scaling_factor,
+    ///     scaling_factor,
+    /// )?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), LweDimension(256));
+    ///
+    /// let decrypted = default_engine.decrypt_lwe_ciphertext(&output_key, &output)?;
+    /// let decrypted = default_engine.retrieve_plaintext(&decrypted)?;
+    /// assert_eq!(decrypted.wrapping_add(1 << 49) >> 50, 6);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_mul_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input_1: &LweCiphertext64,
+        input_2: &LweCiphertext64,
+        pksk: &LwePackingKeyswitchKey64,
+        rlk: &GlweRelinearizationKey64,
+        input_1_scaling_factor: ScalingFactor,
+        input_2_scaling_factor: ScalingFactor,
+        output_scaling_factor: ScalingFactor,
+    ) -> Result<(), LweCiphertextDiscardingMultiplicationError<Self::EngineError>> {
+        FftwError::perform_fftw_checks(rlk.polynomial_size())?;
+        LweCiphertextDiscardingMultiplicationError::perform_generic_checks(
+            output,
+            input_1,
+            input_2,
+            pksk,
+            rlk,
+            input_1_scaling_factor,
+            input_2_scaling_factor,
+            output_scaling_factor,
+            64,
+        )?;
+        unsafe {
+            self.discard_mul_lwe_ciphertext_unchecked(
+                output,
+                input_1,
+                input_2,
+                pksk,
+                rlk,
+                input_1_scaling_factor,
+                input_2_scaling_factor,
+                output_scaling_factor,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_mul_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input_1: &LweCiphertext64,
+        input_2: &LweCiphertext64,
+        pksk: &LwePackingKeyswitchKey64,
+        rlk: &GlweRelinearizationKey64,
+        input_1_scaling_factor: ScalingFactor,
+        input_2_scaling_factor: ScalingFactor,
+        output_scaling_factor: ScalingFactor,
+    ) {
+        let buffers =
+            self.get_fourier_u64_buffer(rlk.polynomial_size(), rlk.glwe_dimension().to_glwe_size());
+        multiply_lwe_ciphertexts(
+            &mut output.0,
+            &input_1.0,
+            &input_2.0,
+            &pksk.0,
+            &rlk.0,
+            compute_divisor(
+                input_1_scaling_factor,
+                input_2_scaling_factor,
+                output_scaling_factor,
+            ),
+            buffers,
+        );
+    }
+}
+
+fn multiply_lwe_ciphertexts<Scalar>(
+    output: &mut ImplLweCiphertext<Vec<Scalar>>,
+    input_1: &ImplLweCiphertext<Vec<Scalar>>,
+    input_2: &ImplLweCiphertext<Vec<Scalar>>,
+    pksk: &ImplLwePackingKeyswitchKey<Vec<Scalar>>,
+    rlk: &ImplGlweRelinearizationKey<Vec<Scalar>>,
+    divisor: f64,
+    buffers: &mut FourierBuffers<Scalar>,
+) where
+    Scalar: UnsignedTorus,
+{
+    let polynomial_size = rlk.polynomial_size();
+    let glwe_size = rlk.glwe_dimension().to_glwe_size();
+
+    // We pack the inputs in GLWE ciphertexts, and move them to the Fourier domain
+    let mut packed = ImplGlweCiphertext::allocate(Scalar::ZERO, polynomial_size, glwe_size);
+    let mut fourier_1 =
+        FourierGlweCiphertext::allocate(Complex64::new(0., 0.), polynomial_size, glwe_size);
+    let mut fourier_2 =
+        FourierGlweCiphertext::allocate(Complex64::new(0., 0.), polynomial_size, glwe_size);
+    pksk.keyswitch_ciphertext(&mut packed, input_1);
+    fourier_1.fill_with_forward_fourier(&packed, buffers);
+    pksk.keyswitch_ciphertext(&mut packed, input_2);
+    fourier_2.fill_with_forward_fourier(&packed, buffers);
+
+    // We compute the tensor product, and relinearize it back to the GLWE key
+    let mut product = ImplGlweCiphertext::allocate(
+        Scalar::ZERO,
+        polynomial_size,
+        glwe_size.tensor_product_size(),
+    );
+    fourier_1.fill_glwe_with_tensor_product_same_key(&fourier_2, &mut product, divisor, buffers);
+    rlk.relinearize(&mut packed, &product);
+
+    // The product of the messages lies in the constant coefficient
+    #[allow(deprecated)]
+    packed.fill_lwe_with_sample_extraction(output, MonomialDegree(0));
+}
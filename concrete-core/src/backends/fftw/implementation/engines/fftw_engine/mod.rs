@@ -13,6 +13,7 @@ use crate::specification::engines::AbstractEngine;
 #[derive(Debug)]
 pub enum FftwError {
     UnsupportedPolynomialSize,
+    NullSampleCount,
 }
 
 impl Display for FftwError {
@@ -25,6 +26,12 @@ impl Display for FftwError {
                 1024, 2048, 4096, 8192, 16384."
                 )
             }
+            FftwError::NullSampleCount => {
+                write!(
+                    f,
+                    "The polynomial multiplications can not be timed on an empty sample."
+                )
+            }
         }
     }
 }
@@ -98,7 +105,13 @@ mod ggsw_ciphertext_discarding_conversion;
 mod glwe_ciphertext_conversion;
 mod glwe_ciphertext_ggsw_ciphertext_discarding_external_product;
 mod glwe_ciphertext_ggsw_ciphertext_external_product;
+mod glwe_ciphertext_leveled_multiplication;
+mod glwe_ciphertext_relinearization;
+mod glwe_ciphertext_tensor_product_same_key;
 mod glwe_ciphertexts_ggsw_ciphertext_fusing_cmux;
+mod glwe_tensor_product_ciphertext_conversion;
 mod lwe_bootstrap_key_conversion;
 mod lwe_ciphertext_discarding_bit_extraction;
 mod lwe_ciphertext_discarding_bootstrap;
+mod lwe_ciphertext_discarding_bootstrap_many_lut;
+mod lwe_ciphertext_discarding_multiplication;
@@ -14,6 +14,7 @@ use crate::prelude::{
 };
 use concrete_fftw::array::AlignedVec;
 use serde::Deserialize;
+use std::io::Read;
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`FftwSerializationEngine`] that operates
@@ -67,6 +68,17 @@ impl EntityDeserializationEngine<&[u8], FftwFourierGgswCiphertext32> for FftwSer
     fn deserialize(
         &mut self,
         serialized: &[u8],
+    ) -> Result<FftwFourierGgswCiphertext32, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> FftwFourierGgswCiphertext32 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
     ) -> Result<FftwFourierGgswCiphertext32, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
         struct DeserializableFftwFourierGgswCiphertext32 {
@@ -74,7 +86,7 @@ impl EntityDeserializationEngine<&[u8], FftwFourierGgswCiphertext32> for FftwSer
             inner: ImplFourierGgswCiphertext<AlignedVec<Complex64>, u32>,
         }
         let deserialized: DeserializableFftwFourierGgswCiphertext32 =
-            bincode::deserialize(serialized)
+            bincode::deserialize_from(reader)
                 .map_err(FftwSerializationError::Deserialization)
                 .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
@@ -91,8 +103,11 @@ impl EntityDeserializationEngine<&[u8], FftwFourierGgswCiphertext32> for FftwSer
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> FftwFourierGgswCiphertext32 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> FftwFourierGgswCiphertext32 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
 
@@ -148,6 +163,17 @@ impl EntityDeserializationEngine<&[u8], FftwFourierGgswCiphertext64> for FftwSer
     fn deserialize(
         &mut self,
         serialized: &[u8],
+    ) -> Result<FftwFourierGgswCiphertext64, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> FftwFourierGgswCiphertext64 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
     ) -> Result<FftwFourierGgswCiphertext64, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
         struct DeserializableFftwFourierGgswCiphertext64 {
@@ -155,7 +181,7 @@ impl EntityDeserializationEngine<&[u8], FftwFourierGgswCiphertext64> for FftwSer
             inner: ImplFourierGgswCiphertext<AlignedVec<Complex64>, u64>,
         }
         let deserialized: DeserializableFftwFourierGgswCiphertext64 =
-            bincode::deserialize(serialized)
+            bincode::deserialize_from(reader)
                 .map_err(FftwSerializationError::Deserialization)
                 .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
@@ -172,8 +198,11 @@ impl EntityDeserializationEngine<&[u8], FftwFourierGgswCiphertext64> for FftwSer
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> FftwFourierGgswCiphertext64 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> FftwFourierGgswCiphertext64 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
 
@@ -226,6 +255,17 @@ impl EntityDeserializationEngine<&[u8], FftwFourierGlweCiphertext32> for FftwSer
     fn deserialize(
         &mut self,
         serialized: &[u8],
+    ) -> Result<FftwFourierGlweCiphertext32, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> FftwFourierGlweCiphertext32 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
     ) -> Result<FftwFourierGlweCiphertext32, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
         struct DeserializableFftwFourierGlweCiphertext32 {
@@ -233,7 +273,7 @@ impl EntityDeserializationEngine<&[u8], FftwFourierGlweCiphertext32> for FftwSer
             inner: ImplFourierGlweCiphertext<AlignedVec<Complex64>, u32>,
         }
         let deserialized: DeserializableFftwFourierGlweCiphertext32 =
-            bincode::deserialize(serialized)
+            bincode::deserialize_from(reader)
                 .map_err(FftwSerializationError::Deserialization)
                 .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
@@ -250,8 +290,11 @@ impl EntityDeserializationEngine<&[u8], FftwFourierGlweCiphertext32> for FftwSer
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> FftwFourierGlweCiphertext32 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> FftwFourierGlweCiphertext32 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
 
@@ -304,6 +347,17 @@ impl EntityDeserializationEngine<&[u8], FftwFourierGlweCiphertext64> for FftwSer
     fn deserialize(
         &mut self,
         serialized: &[u8],
+    ) -> Result<FftwFourierGlweCiphertext64, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> FftwFourierGlweCiphertext64 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
     ) -> Result<FftwFourierGlweCiphertext64, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
         struct DeserializableFftwFourierGlweCiphertext64 {
@@ -311,7 +365,7 @@ impl EntityDeserializationEngine<&[u8], FftwFourierGlweCiphertext64> for FftwSer
             inner: ImplFourierGlweCiphertext<AlignedVec<Complex64>, u64>,
         }
         let deserialized: DeserializableFftwFourierGlweCiphertext64 =
-            bincode::deserialize(serialized)
+            bincode::deserialize_from(reader)
                 .map_err(FftwSerializationError::Deserialization)
                 .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
@@ -328,8 +382,11 @@ impl EntityDeserializationEngine<&[u8], FftwFourierGlweCiphertext64> for FftwSer
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> FftwFourierGlweCiphertext64 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> FftwFourierGlweCiphertext64 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
 
@@ -377,6 +434,17 @@ impl EntityDeserializationEngine<&[u8], FftwFourierLweBootstrapKey32> for FftwSe
     fn deserialize(
         &mut self,
         serialized: &[u8],
+    ) -> Result<FftwFourierLweBootstrapKey32, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> FftwFourierLweBootstrapKey32 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
     ) -> Result<FftwFourierLweBootstrapKey32, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
         struct DeserializableFftwFourierLweBootstrapKey32 {
@@ -384,7 +452,7 @@ impl EntityDeserializationEngine<&[u8], FftwFourierLweBootstrapKey32> for FftwSe
             inner: ImplFourierBootstrapKey<AlignedVec<Complex64>, u32>,
         }
         let deserialized: DeserializableFftwFourierLweBootstrapKey32 =
-            bincode::deserialize(serialized)
+            bincode::deserialize_from(reader)
                 .map_err(FftwSerializationError::Deserialization)
                 .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
@@ -401,8 +469,11 @@ impl EntityDeserializationEngine<&[u8], FftwFourierLweBootstrapKey32> for FftwSe
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> FftwFourierLweBootstrapKey32 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> FftwFourierLweBootstrapKey32 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
 
@@ -450,6 +521,17 @@ impl EntityDeserializationEngine<&[u8], FftwFourierLweBootstrapKey64> for FftwSe
     fn deserialize(
         &mut self,
         serialized: &[u8],
+    ) -> Result<FftwFourierLweBootstrapKey64, EntityDeserializationError<Self::EngineError>> {
+        self.deserialize_from_reader(serialized)
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> FftwFourierLweBootstrapKey64 {
+        self.deserialize(serialized).unwrap()
+    }
+
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
     ) -> Result<FftwFourierLweBootstrapKey64, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
         struct DeserializableFftwFourierLweBootstrapKey64 {
@@ -457,7 +539,7 @@ impl EntityDeserializationEngine<&[u8], FftwFourierLweBootstrapKey64> for FftwSe
             inner: ImplFourierBootstrapKey<AlignedVec<Complex64>, u64>,
         }
         let deserialized: DeserializableFftwFourierLweBootstrapKey64 =
-            bincode::deserialize(serialized)
+            bincode::deserialize_from(reader)
                 .map_err(FftwSerializationError::Deserialization)
                 .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
@@ -474,7 +556,10 @@ impl EntityDeserializationEngine<&[u8], FftwFourierLweBootstrapKey64> for FftwSe
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> FftwFourierLweBootstrapKey64 {
-        self.deserialize(serialized).unwrap()
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> FftwFourierLweBootstrapKey64 {
+        self.deserialize_from_reader(reader).unwrap()
     }
 }
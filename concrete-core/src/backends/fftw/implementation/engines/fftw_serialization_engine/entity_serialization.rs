@@ -14,6 +14,7 @@ use crate::prelude::{
 };
 use concrete_fftw::array::AlignedVec;
 use serde::Serialize;
+use std::io::Write;
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`FftwSerializationEngine`] that operates on
@@ -68,6 +69,20 @@ impl EntitySerializationEngine<FftwFourierGgswCiphertext32, Vec<u8>> for FftwSer
         &mut self,
         entity: &FftwFourierGgswCiphertext32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &FftwFourierGgswCiphertext32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &FftwFourierGgswCiphertext32,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
         struct SerializableFftwFourierGgswCiphertext32<'a> {
             version: FftwFourierGgswCiphertext32Version,
@@ -77,13 +92,17 @@ impl EntitySerializationEngine<FftwFourierGgswCiphertext32, Vec<u8>> for FftwSer
             version: FftwFourierGgswCiphertext32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(FftwSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &FftwFourierGgswCiphertext32) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &FftwFourierGgswCiphertext32,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
@@ -140,6 +159,20 @@ impl EntitySerializationEngine<FftwFourierGgswCiphertext64, Vec<u8>> for FftwSer
         &mut self,
         entity: &FftwFourierGgswCiphertext64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &FftwFourierGgswCiphertext64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &FftwFourierGgswCiphertext64,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
         struct SerializableFftwFourierGgswCiphertext64<'a> {
             version: FftwFourierGgswCiphertext64Version,
@@ -149,13 +182,17 @@ impl EntitySerializationEngine<FftwFourierGgswCiphertext64, Vec<u8>> for FftwSer
             version: FftwFourierGgswCiphertext64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(FftwSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &FftwFourierGgswCiphertext64) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &FftwFourierGgswCiphertext64,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
@@ -209,6 +246,20 @@ impl EntitySerializationEngine<FftwFourierGlweCiphertext32, Vec<u8>> for FftwSer
         &mut self,
         entity: &FftwFourierGlweCiphertext32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &FftwFourierGlweCiphertext32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &FftwFourierGlweCiphertext32,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
         struct SerializableFftwFourierGlweCiphertext32<'a> {
             version: FftwFourierGlweCiphertext32Version,
@@ -218,13 +269,17 @@ impl EntitySerializationEngine<FftwFourierGlweCiphertext32, Vec<u8>> for FftwSer
             version: FftwFourierGlweCiphertext32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(FftwSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &FftwFourierGlweCiphertext32) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &FftwFourierGlweCiphertext32,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
@@ -278,6 +333,20 @@ impl EntitySerializationEngine<FftwFourierGlweCiphertext64, Vec<u8>> for FftwSer
         &mut self,
         entity: &FftwFourierGlweCiphertext64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &FftwFourierGlweCiphertext64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &FftwFourierGlweCiphertext64,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
         struct SerializableFftwFourierGlweCiphertext64<'a> {
             version: FftwFourierGlweCiphertext64Version,
@@ -287,13 +356,17 @@ impl EntitySerializationEngine<FftwFourierGlweCiphertext64, Vec<u8>> for FftwSer
             version: FftwFourierGlweCiphertext64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(FftwSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &FftwFourierGlweCiphertext64) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &FftwFourierGlweCiphertext64,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
@@ -342,6 +415,20 @@ impl EntitySerializationEngine<FftwFourierLweBootstrapKey32, Vec<u8>> for FftwSe
         &mut self,
         entity: &FftwFourierLweBootstrapKey32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &FftwFourierLweBootstrapKey32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &FftwFourierLweBootstrapKey32,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
         struct SerializableFftwFourierLweBootstrapKey32<'a> {
             version: FftwFourierLweBootstrapKey32Version,
@@ -351,13 +438,17 @@ impl EntitySerializationEngine<FftwFourierLweBootstrapKey32, Vec<u8>> for FftwSe
             version: FftwFourierLweBootstrapKey32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(FftwSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &FftwFourierLweBootstrapKey32) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &FftwFourierLweBootstrapKey32,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
 
@@ -406,6 +497,20 @@ impl EntitySerializationEngine<FftwFourierLweBootstrapKey64, Vec<u8>> for FftwSe
         &mut self,
         entity: &FftwFourierLweBootstrapKey64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let mut serialized = Vec::new();
+        self.serialize_to_writer(entity, &mut serialized)?;
+        Ok(serialized)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &FftwFourierLweBootstrapKey64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &FftwFourierLweBootstrapKey64,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
         struct SerializableFftwFourierLweBootstrapKey64<'a> {
             version: FftwFourierLweBootstrapKey64Version,
@@ -415,12 +520,16 @@ impl EntitySerializationEngine<FftwFourierLweBootstrapKey64, Vec<u8>> for FftwSe
             version: FftwFourierLweBootstrapKey64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
+        bincode::serialize_into(writer, &serializable)
             .map_err(FftwSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &FftwFourierLweBootstrapKey64) -> Vec<u8> {
-        self.serialize(entity).unwrap()
+    unsafe fn serialize_to_writer_unchecked<W: Write>(
+        &mut self,
+        entity: &FftwFourierLweBootstrapKey64,
+        writer: W,
+    ) {
+        self.serialize_to_writer(entity, writer).unwrap()
     }
 }
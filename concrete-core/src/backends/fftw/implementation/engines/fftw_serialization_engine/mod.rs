@@ -1,4 +1,6 @@
 use crate::specification::engines::sealed::AbstractEngineSeal;
+use crate::prelude::{FftwFourierLweBootstrapKey32, FftwFourierLweBootstrapKey64};
+use crate::specification::engines::recording::impl_recordable_entity;
 use crate::specification::engines::AbstractEngine;
 use std::error::Error;
 use std::fmt::{Display, Formatter};
@@ -46,5 +48,10 @@ impl AbstractEngine for FftwSerializationEngine {
     }
 }
 
+impl_recordable_entity!(FftwSerializationEngine =>
+    FftwFourierLweBootstrapKey32,
+    FftwFourierLweBootstrapKey64,
+);
+
 mod entity_deserialization;
 mod entity_serialization;
@@ -3,7 +3,13 @@
 mod fftw_engine;
 pub use fftw_engine::*;
 
+mod polynomial_multiplication_calibration;
+pub use polynomial_multiplication_calibration::*;
+
 #[cfg(feature = "backend_fftw_serialization")]
 mod fftw_serialization_engine;
 #[cfg(feature = "backend_fftw_serialization")]
 pub use fftw_serialization_engine::*;
+
+mod registry;
+pub(crate) use registry::implementations;
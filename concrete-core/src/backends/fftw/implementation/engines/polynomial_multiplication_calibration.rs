@@ -0,0 +1,188 @@
+use std::time::{Duration, Instant};
+
+use crate::backends::fftw::private::math::fft::{Complex64, Fft, FourierPolynomial};
+use crate::commons::math::polynomial::Polynomial;
+use crate::commons::math::tensor::AsMutTensor;
+use crate::commons::math::torus::UnsignedTorus;
+use concrete_commons::parameters::PolynomialSize;
+
+use super::{FftwEngine, FftwError};
+
+/// The mean time taken by a polynomial multiplication, with and without the Fourier transform, as
+/// measured by [`FftwEngine::calibrate_polynomial_multiplication`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PolynomialMultiplicationTiming {
+    /// The size of the multiplied polynomials.
+    pub polynomial_size: PolynomialSize,
+    /// The mean time of a multiplication with the Karatsuba algorithm of the default backend.
+    pub karatsuba: Duration,
+    /// The mean time of a multiplication in the Fourier domain, including the transforms.
+    pub fourier: Duration,
+}
+
+impl PolynomialMultiplicationTiming {
+    /// Returns whether the multiplication in the Fourier domain was the fastest.
+    pub fn is_fourier_faster(&self) -> bool {
+        self.fourier < self.karatsuba
+    }
+}
+
+/// A table of polynomial multiplication timings, sorted by polynomial size, which tells from which
+/// polynomial size the multiplications are faster in the Fourier domain.
+///
+/// It is meant to be computed once at startup with
+/// [`FftwEngine::calibrate_polynomial_multiplication`], for the polynomial sizes of the user
+/// parameters, so that each operation can be dispatched to the fastest backend.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PolynomialMultiplicationCrossover {
+    timings: Vec<PolynomialMultiplicationTiming>,
+}
+
+impl PolynomialMultiplicationCrossover {
+    /// Returns the measured timings, sorted by polynomial size.
+    pub fn timings(&self) -> &[PolynomialMultiplicationTiming] {
+        &self.timings
+    }
+
+    /// Returns the smallest measured polynomial size from which the multiplication in the Fourier
+    /// domain was the fastest for all the larger measured sizes, if any.
+    pub fn crossover_polynomial_size(&self) -> Option<PolynomialSize> {
+        self.timings
+            .iter()
+            .rev()
+            .take_while(|timing| timing.is_fourier_faster())
+            .last()
+            .map(|timing| timing.polynomial_size)
+    }
+
+    /// Returns whether the multiplications of polynomials of size `polynomial_size` should be
+    /// computed in the Fourier domain.
+    ///
+    /// This is the case if the size is supported by the fftw backend, and not smaller than the
+    /// [crossover size](`Self::crossover_polynomial_size`).
+    pub fn prefers_fourier(&self, polynomial_size: PolynomialSize) -> bool {
+        FftwError::perform_fftw_checks(polynomial_size).is_ok()
+            && matches!(
+                self.crossover_polynomial_size(),
+                Some(crossover) if polynomial_size >= crossover
+            )
+    }
+}
+
+impl FftwEngine {
+    /// Times the multiplication of polynomials with coefficients of type `Scalar`, with the
+    /// Karatsuba algorithm of the default backend and in the Fourier domain, for each of the
+    /// `polynomial_sizes`.
+    ///
+    /// Each multiplication is run `sample_count` times on fixed inputs, and the mean times are
+    /// recorded in a [`PolynomialMultiplicationCrossover`] table. For small polynomial sizes, the
+    /// cost of the transforms often outweighs their gain, in which case the table tells to keep
+    /// the default backend.
+    ///
+    /// # Example
+    /// ```
+    /// use concrete_commons::parameters::PolynomialSize;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = FftwEngine::new(())?;
+    /// let crossover = engine.calibrate_polynomial_multiplication::<u64>(
+    ///     &[PolynomialSize(1024), PolynomialSize(128)],
+    ///     10,
+    /// )?;
+    ///
+    /// assert_eq!(crossover.timings().len(), 2);
+    /// assert_eq!(crossover.timings()[0].polynomial_size, PolynomialSize(128));
+    /// // Sizes which are not supported by the fftw backend always use the default backend
+    /// assert!(!crossover.prefers_fourier(PolynomialSize(100)));
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn calibrate_polynomial_multiplication<Scalar: UnsignedTorus>(
+        &mut self,
+        polynomial_sizes: &[PolynomialSize],
+        sample_count: usize,
+    ) -> Result<PolynomialMultiplicationCrossover, FftwError> {
+        for polynomial_size in polynomial_sizes.iter() {
+            FftwError::perform_fftw_checks(*polynomial_size)?;
+        }
+        if sample_count == 0 {
+            return Err(FftwError::NullSampleCount);
+        }
+
+        let mut timings: Vec<_> = polynomial_sizes
+            .iter()
+            .map(|polynomial_size| time_multiplications::<Scalar>(*polynomial_size, sample_count))
+            .collect();
+        timings.sort_by_key(|timing| timing.polynomial_size);
+        timings.dedup_by_key(|timing| timing.polynomial_size);
+
+        Ok(PolynomialMultiplicationCrossover { timings })
+    }
+}
+
+// Times the two multiplication paths for a given polynomial size.
+fn time_multiplications<Scalar: UnsignedTorus>(
+    polynomial_size: PolynomialSize,
+    sample_count: usize,
+) -> PolynomialMultiplicationTiming {
+    let mut state = 0_u64;
+    let mut lhs = Polynomial::allocate(Scalar::ZERO, polynomial_size);
+    let mut rhs = Polynomial::allocate(Scalar::ZERO, polynomial_size);
+    for (lhs_coefficient, rhs_coefficient) in lhs
+        .as_mut_tensor()
+        .iter_mut()
+        .zip(rhs.as_mut_tensor().iter_mut())
+    {
+        *lhs_coefficient = Scalar::from_torus(next_uniform_torus(&mut state));
+        *rhs_coefficient = Scalar::from_torus(next_uniform_torus(&mut state));
+    }
+    let mut output = Polynomial::allocate(Scalar::ZERO, polynomial_size);
+
+    // The first multiplications warm up the caches and are not timed.
+    output.fill_with_karatsuba_mul(&lhs, &rhs);
+    let start = Instant::now();
+    for _ in 0..sample_count {
+        output.fill_with_karatsuba_mul(&lhs, &rhs);
+    }
+    let karatsuba = start.elapsed() / sample_count as u32;
+
+    let fft = Fft::new(polynomial_size);
+    let mut fourier_lhs = FourierPolynomial::allocate(Complex64::new(0., 0.), polynomial_size);
+    let mut fourier_rhs = FourierPolynomial::allocate(Complex64::new(0., 0.), polynomial_size);
+    let mut fourier_output = FourierPolynomial::allocate(Complex64::new(0., 0.), polynomial_size);
+    let mut fourier_multiplication = || {
+        fft.forward_as_torus(&mut fourier_lhs, &lhs);
+        fft.forward_as_integer(&mut fourier_rhs, &rhs);
+        fourier_output
+            .as_mut_tensor()
+            .fill_with_element(Complex64::new(0., 0.));
+        fourier_output.update_with_multiply_accumulate(&fourier_lhs, &fourier_rhs);
+        fft.backward_as_torus(&mut output, &mut fourier_output);
+    };
+    fourier_multiplication();
+    let start = Instant::now();
+    for _ in 0..sample_count {
+        fourier_multiplication();
+    }
+    let fourier = start.elapsed() / sample_count as u32;
+
+    PolynomialMultiplicationTiming {
+        polynomial_size,
+        karatsuba,
+        fourier,
+    }
+}
+
+// Returns the next value of a splitmix64 sequence, as a uniform element of the torus. This is
+// not a cryptographic generator, which is not needed to sample the inputs of the multiplications.
+fn next_uniform_torus(state: &mut u64) -> f64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^= z >> 31;
+    (z >> 11) as f64 / (1_u64 << 53) as f64
+}
@@ -0,0 +1,198 @@
+//! The declaration of the engine traits implemented by the engines of the fftw backend.
+use crate::backends::support::declare_implementations;
+use crate::prelude::*;
+
+declare_implementations! {
+    "fftw",
+    FftwEngine => [
+        GgswCiphertextConversionEngine<GgswCiphertext32, FftwFourierGgswCiphertext32>,
+        GgswCiphertextConversionEngine<GgswCiphertext64, FftwFourierGgswCiphertext64>,
+        GgswCiphertextDiscardingConversionEngine<GgswCiphertext32, FftwFourierGgswCiphertext32>,
+        GgswCiphertextDiscardingConversionEngine<GgswCiphertext64, FftwFourierGgswCiphertext64>,
+        GlweCiphertextConversionEngine<GlweCiphertext32, FftwFourierGlweCiphertext32>,
+        GlweCiphertextConversionEngine<GlweCiphertext64, FftwFourierGlweCiphertext64>,
+        GlweCiphertextConversionEngine<FftwFourierGlweCiphertext32, GlweCiphertext32>,
+        GlweCiphertextConversionEngine<FftwFourierGlweCiphertext64, GlweCiphertext64>,
+        GlweCiphertextGgswCiphertextDiscardingExternalProductEngine<
+            GlweCiphertext32,
+            FftwFourierGgswCiphertext32,
+            GlweCiphertext32,
+        >,
+        GlweCiphertextGgswCiphertextDiscardingExternalProductEngine<
+            GlweCiphertext64,
+            FftwFourierGgswCiphertext64,
+            GlweCiphertext64,
+        >,
+        GlweCiphertextGgswCiphertextExternalProductEngine<
+            GlweCiphertext32,
+            FftwFourierGgswCiphertext32,
+            GlweCiphertext32,
+        >,
+        GlweCiphertextGgswCiphertextExternalProductEngine<
+            GlweCiphertext64,
+            FftwFourierGgswCiphertext64,
+            GlweCiphertext64,
+        >,
+        GlweCiphertextLeveledMultiplicationEngine<
+            GlweCiphertext32,
+            GlweCiphertext32,
+            GlweRelinearizationKey32,
+            GlweCiphertext32,
+        >,
+        GlweCiphertextLeveledMultiplicationEngine<
+            GlweCiphertext64,
+            GlweCiphertext64,
+            GlweRelinearizationKey64,
+            GlweCiphertext64,
+        >,
+        GlweCiphertextRelinearizationEngine<
+            GlweTensorProductCiphertext32,
+            GlweRelinearizationKey32,
+            GlweCiphertext32,
+        >,
+        GlweCiphertextRelinearizationEngine<
+            GlweTensorProductCiphertext64,
+            GlweRelinearizationKey64,
+            GlweCiphertext64,
+        >,
+        GlweCiphertextTensorProductSameKeyEngine<
+            GlweCiphertext32,
+            GlweCiphertext32,
+            GlweTensorProductCiphertext32,
+        >,
+        GlweCiphertextTensorProductSameKeyEngine<
+            GlweCiphertext64,
+            GlweCiphertext64,
+            GlweTensorProductCiphertext64,
+        >,
+        GlweCiphertextsGgswCiphertextFusingCmuxEngine<
+            GlweCiphertext32,
+            GlweCiphertext32,
+            FftwFourierGgswCiphertext32,
+        >,
+        GlweCiphertextsGgswCiphertextFusingCmuxEngine<
+            GlweCiphertext64,
+            GlweCiphertext64,
+            FftwFourierGgswCiphertext64,
+        >,
+        GlweTensorProductCiphertextConversionEngine<
+            GlweTensorProductCiphertext32,
+            FftwFourierGlweTensorProductCiphertext32,
+        >,
+        GlweTensorProductCiphertextConversionEngine<
+            GlweTensorProductCiphertext64,
+            FftwFourierGlweTensorProductCiphertext64,
+        >,
+        GlweTensorProductCiphertextConversionEngine<
+            FftwFourierGlweTensorProductCiphertext32,
+            GlweTensorProductCiphertext32,
+        >,
+        GlweTensorProductCiphertextConversionEngine<
+            FftwFourierGlweTensorProductCiphertext64,
+            GlweTensorProductCiphertext64,
+        >,
+        LweBootstrapKeyConversionEngine<LweBootstrapKey32, FftwFourierLweBootstrapKey32>,
+        LweBootstrapKeyConversionEngine<LweBootstrapKey64, FftwFourierLweBootstrapKey64>,
+        LweCiphertextDiscardingBitExtractEngine<
+            FftwFourierLweBootstrapKey32,
+            LweKeyswitchKey32,
+            LweCiphertext32,
+            LweCiphertextVector32,
+        >,
+        LweCiphertextDiscardingBitExtractEngine<
+            FftwFourierLweBootstrapKey64,
+            LweKeyswitchKey64,
+            LweCiphertext64,
+            LweCiphertextVector64,
+        >,
+        LweCiphertextDiscardingBitExtractEngine<
+            FftwFourierLweBootstrapKey32,
+            LweKeyswitchKey32,
+            LweCiphertextView32<'data>,
+            LweCiphertextVectorMutView32<'data>,
+        >,
+        LweCiphertextDiscardingBitExtractEngine<
+            FftwFourierLweBootstrapKey64,
+            LweKeyswitchKey64,
+            LweCiphertextView64<'data>,
+            LweCiphertextVectorMutView64<'data>,
+        >,
+        LweCiphertextDiscardingBootstrapEngine<
+            FftwFourierLweBootstrapKey32,
+            GlweCiphertext32,
+            LweCiphertext32,
+            LweCiphertext32,
+        >,
+        LweCiphertextDiscardingBootstrapEngine<
+            FftwFourierLweBootstrapKey64,
+            GlweCiphertext64,
+            LweCiphertext64,
+            LweCiphertext64,
+        >,
+        LweCiphertextDiscardingBootstrapEngine<
+            FftwFourierLweBootstrapKey32,
+            GlweCiphertextView32<'data>,
+            LweCiphertextView32<'data>,
+            LweCiphertextMutView32<'data>,
+        >,
+        LweCiphertextDiscardingBootstrapEngine<
+            FftwFourierLweBootstrapKey64,
+            GlweCiphertextView64<'data>,
+            LweCiphertextView64<'data>,
+            LweCiphertextMutView64<'data>,
+        >,
+        LweCiphertextDiscardingBootstrapEngine<
+            FftwFourierLweBootstrapKey32,
+            LweBootstrapLookupTable32,
+            LweCiphertext32,
+            LweCiphertext32,
+        >,
+        LweCiphertextDiscardingBootstrapEngine<
+            FftwFourierLweBootstrapKey64,
+            LweBootstrapLookupTable64,
+            LweCiphertext64,
+            LweCiphertext64,
+        >,
+        LweCiphertextDiscardingBootstrapManyLutEngine<
+            FftwFourierLweBootstrapKey32,
+            GlweCiphertext32,
+            LweCiphertext32,
+            LweCiphertextVector32,
+        >,
+        LweCiphertextDiscardingBootstrapManyLutEngine<
+            FftwFourierLweBootstrapKey64,
+            GlweCiphertext64,
+            LweCiphertext64,
+            LweCiphertextVector64,
+        >,
+        LweCiphertextDiscardingMultiplicationEngine<
+            LweCiphertext32,
+            LweCiphertext32,
+            LwePackingKeyswitchKey32,
+            GlweRelinearizationKey32,
+            LweCiphertext32,
+        >,
+        LweCiphertextDiscardingMultiplicationEngine<
+            LweCiphertext64,
+            LweCiphertext64,
+            LwePackingKeyswitchKey64,
+            GlweRelinearizationKey64,
+            LweCiphertext64,
+        >,
+    ],
+    #[cfg(feature = "backend_fftw_serialization")]
+    FftwSerializationEngine => [
+        EntityDeserializationEngine<&'data [u8], FftwFourierGgswCiphertext32>,
+        EntityDeserializationEngine<&'data [u8], FftwFourierGgswCiphertext64>,
+        EntityDeserializationEngine<&'data [u8], FftwFourierGlweCiphertext32>,
+        EntityDeserializationEngine<&'data [u8], FftwFourierGlweCiphertext64>,
+        EntityDeserializationEngine<&'data [u8], FftwFourierLweBootstrapKey32>,
+        EntityDeserializationEngine<&'data [u8], FftwFourierLweBootstrapKey64>,
+        EntitySerializationEngine<FftwFourierGgswCiphertext32, Vec<u8>>,
+        EntitySerializationEngine<FftwFourierGgswCiphertext64, Vec<u8>>,
+        EntitySerializationEngine<FftwFourierGlweCiphertext32, Vec<u8>>,
+        EntitySerializationEngine<FftwFourierGlweCiphertext64, Vec<u8>>,
+        EntitySerializationEngine<FftwFourierLweBootstrapKey32, Vec<u8>>,
+        EntitySerializationEngine<FftwFourierLweBootstrapKey64, Vec<u8>>,
+    ],
+}
@@ -0,0 +1,42 @@
+use crate::backends::fftw::private::crypto::glwe::FourierGlweCiphertext;
+use crate::backends::fftw::private::math::fft::Complex64;
+use crate::specification::entities::markers::GlweTensorProductCiphertextKind;
+use crate::specification::entities::{AbstractEntity, GlweTensorProductCiphertextEntity};
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+use concrete_fftw::array::AlignedVec;
+
+/// A structure representing a Fourier GLWE tensor product ciphertext with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FftwFourierGlweTensorProductCiphertext32(
+    pub(crate) FourierGlweCiphertext<AlignedVec<Complex64>, u32>,
+);
+impl AbstractEntity for FftwFourierGlweTensorProductCiphertext32 {
+    type Kind = GlweTensorProductCiphertextKind;
+}
+impl GlweTensorProductCiphertextEntity for FftwFourierGlweTensorProductCiphertext32 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+}
+
+/// A structure representing a Fourier GLWE tensor product ciphertext with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FftwFourierGlweTensorProductCiphertext64(
+    pub(crate) FourierGlweCiphertext<AlignedVec<Complex64>, u64>,
+);
+impl AbstractEntity for FftwFourierGlweTensorProductCiphertext64 {
+    type Kind = GlweTensorProductCiphertextKind;
+}
+impl GlweTensorProductCiphertextEntity for FftwFourierGlweTensorProductCiphertext64 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+}
@@ -3,8 +3,10 @@
 
 mod ggsw_ciphertext;
 mod glwe_ciphertext;
+mod glwe_tensor_product_ciphertext;
 mod lwe_bootstrap_key;
 
 pub use ggsw_ciphertext::*;
 pub use glwe_ciphertext::*;
+pub use glwe_tensor_product_ciphertext::*;
 pub use lwe_bootstrap_key::*;
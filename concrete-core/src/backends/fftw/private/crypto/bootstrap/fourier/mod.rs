@@ -15,7 +15,7 @@ use crate::backends::fftw::private::crypto::ggsw::FourierGgswCiphertext;
 use crate::backends::fftw::private::math::fft::Complex64;
 use crate::commons::crypto::bootstrap::StandardBootstrapKey;
 use crate::commons::crypto::glwe::GlweCiphertext;
-use crate::commons::crypto::lwe::LweCiphertext;
+use crate::commons::crypto::lwe::{LweCiphertext, LweList};
 use crate::commons::math::tensor::{
     ck_dim_div, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, IntoTensor, Tensor,
 };
@@ -445,8 +445,13 @@ where
             })
     }
 
-    fn blind_rotate<C2>(&self, buffers: &mut FourierBuffers<Scalar>, lwe: &LweCiphertext<C2>)
-    where
+    // The rotations are rounded to multiples of 2^lut_count_log.
+    fn blind_rotate<C2>(
+        &self,
+        buffers: &mut FourierBuffers<Scalar>,
+        lwe: &LweCiphertext<C2>,
+        lut_count_log: LutCountLog,
+    ) where
         LweCiphertext<C2>: AsRefTensor<Element = Scalar>,
         GlweCiphertext<Vec<Scalar>>: AsMutTensor<Element = Scalar>,
         Self: AsRefTensor<Element = Complex64>,
@@ -463,7 +468,7 @@ where
                 lwe_body.0,
                 lut_poly_size,
                 ModulusSwitchOffset(0),
-                LutCountLog(0),
+                lut_count_log,
             ));
 
         // We initialize the ct_0 and ct_1 used for the successive cmuxes
@@ -487,7 +492,7 @@ where
                         *lwe_mask_element,
                         lut_poly_size,
                         ModulusSwitchOffset(0),
-                        LutCountLog(0),
+                        lut_count_log,
                     ));
                 // We perform the cmux.
                 bootstrap_key_ggsw.cmux(
@@ -645,12 +650,52 @@ where
         }
 
         // We perform the blind rotate
-        self.blind_rotate(buffers, lwe_in);
+        self.blind_rotate(buffers, lwe_in, LutCountLog(0));
 
         // We perform the extraction of the first sample.
         let local_accumulator = &mut buffers.lut_buffer;
         local_accumulator.fill_lwe_with_sample_extraction(lwe_out, MonomialDegree(0));
     }
+
+    /// Performs a bootstrap of an lwe ciphertext evaluating several lookup tables at once, with a
+    /// given accumulator.
+    ///
+    /// The number of lookup tables $t$ is the number of ciphertexts of the output list, and must
+    /// be a power of two. The rotations of the blind rotation are rounded to multiples of $t$, so
+    /// the coefficient $i < t$ of the rotated accumulator contains the $i$-th lookup table, as laid
+    /// out by [`GlweCiphertext::fill_with_many_lookup_tables`]. The $i$-th output ciphertext is
+    /// the sample extracted from this coefficient. Since the rotation loses $\log\_2(t)$ bits of
+    /// precision, the input messages must leave this many bits free below their encoding.
+    pub fn bootstrap_many_lut<C1, C2, C3>(
+        &self,
+        lwe_out: &mut LweList<C1>,
+        lwe_in: &LweCiphertext<C2>,
+        accumulator: &GlweCiphertext<C3>,
+        buffers: &mut FourierBuffers<Scalar>,
+    ) where
+        LweList<C1>: AsMutTensor<Element = Scalar>,
+        LweCiphertext<C2>: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<C3>: AsRefTensor<Element = Scalar>,
+    {
+        debug_assert!(lwe_out.count().0.is_power_of_two());
+        let lut_count_log = LutCountLog(lwe_out.count().0.trailing_zeros() as usize);
+
+        // We retrieve the accumulator buffer, and fill it with the input accumulator values.
+        buffers
+            .lut_buffer
+            .as_mut_tensor()
+            .as_mut_slice()
+            .copy_from_slice(accumulator.as_tensor().as_slice());
+
+        // We perform the blind rotate
+        self.blind_rotate(buffers, lwe_in, lut_count_log);
+
+        // We extract one sample per lookup table.
+        let local_accumulator = &buffers.lut_buffer;
+        for (index, mut lwe) in lwe_out.ciphertext_iter_mut().enumerate() {
+            local_accumulator.fill_lwe_with_sample_extraction(&mut lwe, MonomialDegree(index));
+        }
+    }
 }
 
 impl<Element, Cont, Scalar> AsRefTensor for FourierBootstrapKey<Cont, Scalar>
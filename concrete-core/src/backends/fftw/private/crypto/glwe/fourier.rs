@@ -2,7 +2,7 @@ use crate::backends::fftw::private::crypto::bootstrap::FourierBuffers;
 use crate::backends::fftw::private::math::fft::{Complex64, FourierPolynomial};
 use crate::commons::crypto::glwe::GlweCiphertext;
 use crate::commons::math::tensor::{
-    ck_dim_div, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, IntoTensor, Tensor,
+    ck_dim_div, ck_dim_eq, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, IntoTensor, Tensor,
 };
 use crate::commons::math::torus::UnsignedTorus;
 use concrete_commons::parameters::{GlweSize, PolynomialSize};
@@ -144,7 +144,7 @@ impl<Cont, Scalar: UnsignedTorus> FourierGlweCiphertext<Cont, Scalar> {
     {
         // We retrieve a buffer for the fft.
         let fft_buffer = &mut buffers.fft_buffers.first_buffer;
-        let fft = &mut buffers.fft_buffers.fft;
+        let fft = &buffers.fft_buffers.fft;
 
         // We move every polynomial to the fourier domain.
         let poly_list = glwe.as_polynomial_list();
@@ -188,7 +188,7 @@ impl<Cont, Scalar: UnsignedTorus> FourierGlweCiphertext<Cont, Scalar> {
         GlweCiphertext<InputCont>: AsMutTensor<Element = Scalar>,
     {
         // We get the fft to use from the passed buffers
-        let fft = &mut buffers.fft_buffers.fft;
+        let fft = &buffers.fft_buffers.fft;
 
         // Output buffer is large enough to hold self which is a FourierGlweCiphertext
         let input_fourier_polynomials_buffer = &mut buffers.fft_buffers.output_buffer;
@@ -215,6 +215,158 @@ impl<Cont, Scalar: UnsignedTorus> FourierGlweCiphertext<Cont, Scalar> {
         }
     }
 
+    /// Fills a GLWE ciphertext with the tensor product of two Fourier GLWE ciphertexts encrypted
+    /// under the same key, divided by `scaling_factor`.
+    ///
+    /// For two ciphertexts $(A\_1, \dots, A\_k, B)$ and $(A'\_1, \dots, A'\_k, B')$, the output
+    /// is encrypted under the
+    /// [tensor product key](`crate::commons::crypto::secret::GlweTensorProductSecretKey`) of their
+    /// key, and contains the polynomials $B A'\_i + B' A\_i$, then $-(A\_i A'\_j + A\_j A'\_i)$
+    /// for $i < j$, then $-A\_i A'\_i$, and finally the body $B B'$, all computed over the
+    /// integers and divided by `scaling_factor` before being reduced modulo $q$.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{GlweDimension, GlweSize, PolynomialSize};
+    /// use concrete_core::backends::fftw::private::crypto::bootstrap::FourierBuffers;
+    /// use concrete_core::backends::fftw::private::crypto::glwe::FourierGlweCiphertext;
+    /// use concrete_core::backends::fftw::private::math::fft::Complex64;
+    /// use concrete_core::commons::crypto::encoding::PlaintextList;
+    /// use concrete_core::commons::crypto::glwe::GlweCiphertext;
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::GlweSecretKey;
+    /// use concrete_core::commons::math::tensor::{AsRefSlice, AsRefTensor};
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// let noise = LogStandardDev::from_log_standard_dev(-40.);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let glwe_size = GlweSize(3);
+    ///
+    /// let key: GlweSecretKey<_, Vec<u64>> = GlweSecretKey::generate_binary(
+    ///     GlweDimension(2),
+    ///     polynomial_size,
+    ///     &mut secret_generator,
+    /// );
+    /// let tensor_product_key = key.create_tensor_product_key();
+    ///
+    /// // The messages are 3 and 2, with a scaling factor of 2^50
+    /// let mut messages_1 = vec![0_u64; 256];
+    /// messages_1[0] = 3 << 50;
+    /// let mut messages_2 = vec![0_u64; 256];
+    /// messages_2[0] = 2 << 50;
+    /// let mut ciphertext_1 = GlweCiphertext::allocate(0_u64, polynomial_size, glwe_size);
+    /// let mut ciphertext_2 = GlweCiphertext::allocate(0_u64, polynomial_size, glwe_size);
+    /// key.encrypt_glwe(
+    ///     &mut ciphertext_1,
+    ///     &PlaintextList::from_container(messages_1),
+    ///     noise,
+    ///     &mut encryption_generator,
+    /// );
+    /// key.encrypt_glwe(
+    ///     &mut ciphertext_2,
+    ///     &PlaintextList::from_container(messages_2),
+    ///     noise,
+    ///     &mut encryption_generator,
+    /// );
+    ///
+    /// let mut buffers = FourierBuffers::new(polynomial_size, glwe_size);
+    /// let mut fourier_1: FourierGlweCiphertext<_, u64> =
+    ///     FourierGlweCiphertext::allocate(Complex64::new(0., 0.), polynomial_size, glwe_size);
+    /// let mut fourier_2: FourierGlweCiphertext<_, u64> =
+    ///     FourierGlweCiphertext::allocate(Complex64::new(0., 0.), polynomial_size, glwe_size);
+    /// fourier_1.fill_with_forward_fourier(&ciphertext_1, &mut buffers);
+    /// fourier_2.fill_with_forward_fourier(&ciphertext_2, &mut buffers);
+    ///
+    /// let mut product =
+    ///     GlweCiphertext::allocate(0_u64, polynomial_size, glwe_size.tensor_product_size());
+    /// fourier_1.fill_glwe_with_tensor_product_same_key(
+    ///     &fourier_2,
+    ///     &mut product,
+    ///     2_f64.powi(50),
+    ///     &mut buffers,
+    /// );
+    ///
+    /// // The product is decrypted with the tensor product key
+    /// let mut decrypted = PlaintextList::from_container(vec![0_u64; 256]);
+    /// GlweSecretKey::binary_from_container(tensor_product_key.as_tensor().as_slice(), polynomial_size)
+    ///     .decrypt_glwe(&mut decrypted, &product);
+    /// let decoded = (decrypted.as_tensor().first() + (1 << 49)) >> 50;
+    /// assert_eq!(decoded, 6);
+    /// ```
+    pub fn fill_glwe_with_tensor_product_same_key<OutputCont, OtherCont>(
+        &self,
+        other: &FourierGlweCiphertext<OtherCont, Scalar>,
+        output: &mut GlweCiphertext<OutputCont>,
+        scaling_factor: f64,
+        buffers: &mut FourierBuffers<Scalar>,
+    ) where
+        Self: AsRefTensor<Element = Complex64>,
+        FourierGlweCiphertext<OtherCont, Scalar>: AsRefTensor<Element = Complex64>,
+        GlweCiphertext<OutputCont>: AsMutTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.glwe_size.0 => other.glwe_size.0);
+        ck_dim_eq!(self.glwe_size.tensor_product_size().0 => output.size().0);
+        ck_dim_eq!(self.poly_size.0 => other.poly_size.0, output.polynomial_size().0);
+
+        // We get the fft and the accumulator to use from the passed buffers
+        let fft = &buffers.fft_buffers.fft;
+        let accumulator = &mut buffers.fft_buffers.first_buffer;
+
+        // The coefficients of the polynomials are converted to the torus by the forward fft, so
+        // that the products are divided by q^2 when they come back. We multiply them by
+        // q / scaling_factor to get the rescaled products, reduced modulo q.
+        let rescaling = 2_f64.powi(Scalar::BITS as i32) / scaling_factor;
+
+        let polynomials_1: Vec<_> = self.polynomial_iter().collect();
+        let polynomials_2: Vec<_> = other.polynomial_iter().collect();
+        let (body_1, mask_1) = polynomials_1.split_last().unwrap();
+        let (body_2, mask_2) = polynomials_2.split_last().unwrap();
+
+        // We list the products to accumulate for each output polynomial, in the order of the
+        // tensor product key, along with the sign of the output polynomial.
+        let mut terms = Vec::with_capacity(self.glwe_size.tensor_product_size().0);
+        for (a_1, a_2) in mask_1.iter().zip(mask_2.iter()) {
+            terms.push((vec![(body_1, a_2), (a_1, body_2)], 1.));
+        }
+        for i in 0..mask_1.len() {
+            for j in i + 1..mask_1.len() {
+                terms.push((
+                    vec![(&mask_1[i], &mask_2[j]), (&mask_1[j], &mask_2[i])],
+                    -1.,
+                ));
+            }
+        }
+        for (a_1, a_2) in mask_1.iter().zip(mask_2.iter()) {
+            terms.push((vec![(a_1, a_2)], -1.));
+        }
+        terms.push((vec![(body_1, body_2)], 1.));
+
+        for ((products, sign), mut output_polynomial) in terms
+            .iter()
+            .zip(output.as_mut_polynomial_list().polynomial_iter_mut())
+        {
+            accumulator
+                .as_mut_tensor()
+                .fill_with_element(Complex64::new(0., 0.));
+            for (poly_1, poly_2) in products.iter() {
+                accumulator.update_with_multiply_accumulate(poly_1, poly_2);
+            }
+            for coefficient in accumulator.coefficient_iter_mut() {
+                *coefficient *= sign * rescaling;
+            }
+            fft.backward_as_torus(&mut output_polynomial, accumulator);
+        }
+    }
+
     /// Returns an iterator over references to the polynomials contained in the GLWE.
     ///
     /// # Example
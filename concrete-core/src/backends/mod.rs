@@ -1,5 +1,7 @@
 //! A module containing various backends implementing the `concrete` FHE scheme.
 
+pub mod support;
+
 #[cfg(feature = "backend_default")]
 pub mod default;
 
@@ -9,5 +11,8 @@ pub mod fftw;
 #[cfg(feature = "backend_fft")]
 pub mod fft;
 
+#[cfg(feature = "backend_ntt")]
+pub mod ntt;
+
 #[cfg(all(feature = "backend_cuda", not(feature = "_ci_do_not_compile")))]
 pub mod cuda;
@@ -0,0 +1,215 @@
+use crate::backends::ntt::engines::{AutoNttEngine, AutoNttError};
+use crate::backends::ntt::entities::{AutoGgswCiphertext32, AutoGgswCiphertext64};
+use crate::backends::ntt::private::provider::ProviderEntity;
+use crate::prelude::{
+    GgswCiphertext32, GgswCiphertext64, GgswCiphertextConversionEngine,
+    GgswCiphertextConversionError,
+};
+use crate::specification::engines::polynomial_multiplication::{
+    PolynomialMultiplicationProvider, PolynomialMultiplicationProviderSelector,
+};
+use crate::specification::entities::GgswCiphertextEntity;
+
+impl From<AutoNttError> for GgswCiphertextConversionError<AutoNttError> {
+    fn from(err: AutoNttError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GgswCiphertextConversionEngine`] for [`AutoNttEngine`] that operates on
+/// 32 bits integers. It converts a GGSW ciphertext from the standard domain to the domain of the
+/// polynomial multiplication provider selected for its parameters.
+impl GgswCiphertextConversionEngine<GgswCiphertext32, AutoGgswCiphertext32> for AutoNttEngine {
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// use concrete_core::specification::engines::polynomial_multiplication::PolynomialMultiplicationProvider;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut auto_ntt_engine = AutoNttEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// // The FFT rounding noise can be avoided whenever the NTT supports the parameters.
+    /// auto_ntt_engine
+    ///     .set_preferred_polynomial_multiplication_provider(PolynomialMultiplicationProvider::Ntt);
+    /// let key: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    ///
+    /// // We encrypt a GGSW ciphertext in the standard domain
+    /// let ciphertext =
+    ///     default_engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    ///
+    /// // Then we convert it to the domain of the selected provider.
+    /// let auto_ciphertext: AutoGgswCiphertext32 =
+    ///     auto_ntt_engine.convert_ggsw_ciphertext(&ciphertext)?;
+    /// assert_eq!(
+    ///     auto_ciphertext.polynomial_multiplication_provider(),
+    ///     PolynomialMultiplicationProvider::Ntt
+    /// );
+    /// #
+    /// assert_eq!(auto_ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(auto_ciphertext.polynomial_size(), polynomial_size);
+    /// assert_eq!(auto_ciphertext.decomposition_base_log(), base_log);
+    /// assert_eq!(auto_ciphertext.decomposition_level_count(), level);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_ggsw_ciphertext(
+        &mut self,
+        input: &GgswCiphertext32,
+    ) -> Result<AutoGgswCiphertext32, GgswCiphertextConversionError<Self::EngineError>> {
+        if self
+            .select_polynomial_multiplication_provider(
+                input.polynomial_size(),
+                input.glwe_dimension(),
+                input.decomposition_level_count(),
+                input.decomposition_base_log(),
+            )
+            .is_none()
+        {
+            return Err(AutoNttError::NoProviderSupportsParameters.into());
+        }
+        Ok(unsafe { self.convert_ggsw_ciphertext_unchecked(input) })
+    }
+
+    unsafe fn convert_ggsw_ciphertext_unchecked(
+        &mut self,
+        input: &GgswCiphertext32,
+    ) -> AutoGgswCiphertext32 {
+        let provider = self
+            .select_polynomial_multiplication_provider(
+                input.polynomial_size(),
+                input.glwe_dimension(),
+                input.decomposition_level_count(),
+                input.decomposition_base_log(),
+            )
+            .unwrap_or(self.preferred_provider);
+        match provider {
+            PolynomialMultiplicationProvider::Fft => AutoGgswCiphertext32(ProviderEntity::Fft(
+                self.fft_engine.convert_ggsw_ciphertext_unchecked(input),
+            )),
+            PolynomialMultiplicationProvider::Ntt => AutoGgswCiphertext32(ProviderEntity::Ntt(
+                self.ntt_engine.convert_ggsw_ciphertext_unchecked(input),
+            )),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GgswCiphertextConversionEngine`] for [`AutoNttEngine`] that operates on
+/// 64 bits integers. It converts a GGSW ciphertext from the standard domain to the domain of the
+/// polynomial multiplication provider selected for its parameters.
+impl GgswCiphertextConversionEngine<GgswCiphertext64, AutoGgswCiphertext64> for AutoNttEngine {
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// use concrete_core::specification::engines::polynomial_multiplication::PolynomialMultiplicationProvider;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 52 bits)
+    /// let input = 3_u64 << 52;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut auto_ntt_engine = AutoNttEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// // The FFT rounding noise can be avoided whenever the NTT supports the parameters.
+    /// auto_ntt_engine
+    ///     .set_preferred_polynomial_multiplication_provider(PolynomialMultiplicationProvider::Ntt);
+    /// let key: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    ///
+    /// // We encrypt a GGSW ciphertext in the standard domain
+    /// let ciphertext =
+    ///     default_engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    ///
+    /// // Then we convert it to the domain of the selected provider.
+    /// let auto_ciphertext: AutoGgswCiphertext64 =
+    ///     auto_ntt_engine.convert_ggsw_ciphertext(&ciphertext)?;
+    /// assert_eq!(
+    ///     auto_ciphertext.polynomial_multiplication_provider(),
+    ///     PolynomialMultiplicationProvider::Ntt
+    /// );
+    /// #
+    /// assert_eq!(auto_ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(auto_ciphertext.polynomial_size(), polynomial_size);
+    /// assert_eq!(auto_ciphertext.decomposition_base_log(), base_log);
+    /// assert_eq!(auto_ciphertext.decomposition_level_count(), level);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_ggsw_ciphertext(
+        &mut self,
+        input: &GgswCiphertext64,
+    ) -> Result<AutoGgswCiphertext64, GgswCiphertextConversionError<Self::EngineError>> {
+        if self
+            .select_polynomial_multiplication_provider(
+                input.polynomial_size(),
+                input.glwe_dimension(),
+                input.decomposition_level_count(),
+                input.decomposition_base_log(),
+            )
+            .is_none()
+        {
+            return Err(AutoNttError::NoProviderSupportsParameters.into());
+        }
+        Ok(unsafe { self.convert_ggsw_ciphertext_unchecked(input) })
+    }
+
+    unsafe fn convert_ggsw_ciphertext_unchecked(
+        &mut self,
+        input: &GgswCiphertext64,
+    ) -> AutoGgswCiphertext64 {
+        let provider = self
+            .select_polynomial_multiplication_provider(
+                input.polynomial_size(),
+                input.glwe_dimension(),
+                input.decomposition_level_count(),
+                input.decomposition_base_log(),
+            )
+            .unwrap_or(self.preferred_provider);
+        match provider {
+            PolynomialMultiplicationProvider::Fft => AutoGgswCiphertext64(ProviderEntity::Fft(
+                self.fft_engine.convert_ggsw_ciphertext_unchecked(input),
+            )),
+            PolynomialMultiplicationProvider::Ntt => AutoGgswCiphertext64(ProviderEntity::Ntt(
+                self.ntt_engine.convert_ggsw_ciphertext_unchecked(input),
+            )),
+        }
+    }
+}
@@ -0,0 +1,224 @@
+use crate::backends::ntt::engines::AutoNttEngine;
+use crate::backends::ntt::entities::{AutoGgswCiphertext32, AutoGgswCiphertext64};
+use crate::backends::ntt::private::provider::ProviderEntity;
+use crate::prelude::{
+    GlweCiphertext32, GlweCiphertext64,
+    GlweCiphertextGgswCiphertextDiscardingExternalProductEngine,
+    GlweCiphertextGgswCiphertextDiscardingExternalProductError,
+};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextGgswCiphertextDiscardingExternalProductEngine`] for
+/// [`AutoNttEngine`] that operates on 32 bits integers. The polynomial products are computed by
+/// the provider the GGSW ciphertext was converted with.
+impl
+    GlweCiphertextGgswCiphertextDiscardingExternalProductEngine<
+        GlweCiphertext32,
+        AutoGgswCiphertext32,
+        GlweCiphertext32,
+    > for AutoNttEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(240);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input_ggsw = 3_u32 << 20;
+    /// let input_glwe = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut auto_ntt_engine = AutoNttEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_ggsw = default_engine.create_plaintext_from(&input_ggsw)?;
+    /// let plaintext_glwe = default_engine.create_plaintext_vector_from(&input_glwe)?;
+    ///
+    /// let ggsw = default_engine.encrypt_scalar_ggsw_ciphertext(
+    ///     &key,
+    ///     &plaintext_ggsw,
+    ///     noise,
+    ///     level,
+    ///     base_log,
+    /// )?;
+    /// let auto_ggsw: AutoGgswCiphertext32 = auto_ntt_engine.convert_ggsw_ciphertext(&ggsw)?;
+    /// let glwe = default_engine.encrypt_glwe_ciphertext(&key, &plaintext_glwe, noise)?;
+    ///
+    /// // We allocate an output ciphertext simply by cloning the input.
+    /// // The content of this output ciphertext will by wiped by the external product.
+    /// let mut product = glwe.clone();
+    /// auto_ntt_engine.discard_compute_external_product_glwe_ciphertext_ggsw_ciphertext(
+    ///     &glwe,
+    ///     &auto_ggsw,
+    ///     &mut product,
+    /// )?;
+    /// #
+    /// # assert_eq!(
+    /// #     product.polynomial_size(),
+    /// #     glwe.polynomial_size(),
+    /// # );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_compute_external_product_glwe_ciphertext_ggsw_ciphertext(
+        &mut self,
+        glwe_input: &GlweCiphertext32,
+        ggsw_input: &AutoGgswCiphertext32,
+        output: &mut GlweCiphertext32,
+    ) -> Result<(), GlweCiphertextGgswCiphertextDiscardingExternalProductError<Self::EngineError>>
+    {
+        GlweCiphertextGgswCiphertextDiscardingExternalProductError::perform_generic_checks(
+            glwe_input, ggsw_input, output,
+        )?;
+        unsafe {
+            self.discard_compute_external_product_glwe_ciphertext_ggsw_ciphertext_unchecked(
+                glwe_input, ggsw_input, output,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_compute_external_product_glwe_ciphertext_ggsw_ciphertext_unchecked(
+        &mut self,
+        glwe_input: &GlweCiphertext32,
+        ggsw_input: &AutoGgswCiphertext32,
+        output: &mut GlweCiphertext32,
+    ) {
+        match &ggsw_input.0 {
+            ProviderEntity::Fft(ggsw_input) => self
+                .fft_engine
+                .discard_compute_external_product_glwe_ciphertext_ggsw_ciphertext_unchecked(
+                    glwe_input, ggsw_input, output,
+                ),
+            ProviderEntity::Ntt(ggsw_input) => self
+                .ntt_engine
+                .discard_compute_external_product_glwe_ciphertext_ggsw_ciphertext_unchecked(
+                    glwe_input, ggsw_input, output,
+                ),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextGgswCiphertextDiscardingExternalProductEngine`] for
+/// [`AutoNttEngine`] that operates on 64 bits integers. The polynomial products are computed by
+/// the provider the GGSW ciphertext was converted with.
+impl
+    GlweCiphertextGgswCiphertextDiscardingExternalProductEngine<
+        GlweCiphertext64,
+        AutoGgswCiphertext64,
+        GlweCiphertext64,
+    > for AutoNttEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(240);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 52 bits)
+    /// let input_ggsw = 3_u64 << 52;
+    /// let input_glwe = vec![3_u64 << 52; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut auto_ntt_engine = AutoNttEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_ggsw = default_engine.create_plaintext_from(&input_ggsw)?;
+    /// let plaintext_glwe = default_engine.create_plaintext_vector_from(&input_glwe)?;
+    ///
+    /// let ggsw = default_engine.encrypt_scalar_ggsw_ciphertext(
+    ///     &key,
+    ///     &plaintext_ggsw,
+    ///     noise,
+    ///     level,
+    ///     base_log,
+    /// )?;
+    /// let auto_ggsw: AutoGgswCiphertext64 = auto_ntt_engine.convert_ggsw_ciphertext(&ggsw)?;
+    /// let glwe = default_engine.encrypt_glwe_ciphertext(&key, &plaintext_glwe, noise)?;
+    ///
+    /// // We allocate an output ciphertext simply by cloning the input.
+    /// // The content of this output ciphertext will by wiped by the external product.
+    /// let mut product = glwe.clone();
+    /// auto_ntt_engine.discard_compute_external_product_glwe_ciphertext_ggsw_ciphertext(
+    ///     &glwe,
+    ///     &auto_ggsw,
+    ///     &mut product,
+    /// )?;
+    /// #
+    /// # assert_eq!(
+    /// #     product.polynomial_size(),
+    /// #     glwe.polynomial_size(),
+    /// # );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_compute_external_product_glwe_ciphertext_ggsw_ciphertext(
+        &mut self,
+        glwe_input: &GlweCiphertext64,
+        ggsw_input: &AutoGgswCiphertext64,
+        output: &mut GlweCiphertext64,
+    ) -> Result<(), GlweCiphertextGgswCiphertextDiscardingExternalProductError<Self::EngineError>>
+    {
+        GlweCiphertextGgswCiphertextDiscardingExternalProductError::perform_generic_checks(
+            glwe_input, ggsw_input, output,
+        )?;
+        unsafe {
+            self.discard_compute_external_product_glwe_ciphertext_ggsw_ciphertext_unchecked(
+                glwe_input, ggsw_input, output,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_compute_external_product_glwe_ciphertext_ggsw_ciphertext_unchecked(
+        &mut self,
+        glwe_input: &GlweCiphertext64,
+        ggsw_input: &AutoGgswCiphertext64,
+        output: &mut GlweCiphertext64,
+    ) {
+        match &ggsw_input.0 {
+            ProviderEntity::Fft(ggsw_input) => self
+                .fft_engine
+                .discard_compute_external_product_glwe_ciphertext_ggsw_ciphertext_unchecked(
+                    glwe_input, ggsw_input, output,
+                ),
+            ProviderEntity::Ntt(ggsw_input) => self
+                .ntt_engine
+                .discard_compute_external_product_glwe_ciphertext_ggsw_ciphertext_unchecked(
+                    glwe_input, ggsw_input, output,
+                ),
+        }
+    }
+}
@@ -0,0 +1,223 @@
+use crate::backends::ntt::engines::{AutoNttEngine, AutoNttError};
+use crate::backends::ntt::entities::{AutoLweBootstrapKey32, AutoLweBootstrapKey64};
+use crate::backends::ntt::private::provider::ProviderEntity;
+use crate::prelude::{LweBootstrapKey32, LweBootstrapKey64};
+use crate::specification::engines::polynomial_multiplication::{
+    PolynomialMultiplicationProvider, PolynomialMultiplicationProviderSelector,
+};
+use crate::specification::engines::{
+    LweBootstrapKeyConversionEngine, LweBootstrapKeyConversionError,
+};
+use crate::specification::entities::LweBootstrapKeyEntity;
+
+impl From<AutoNttError> for LweBootstrapKeyConversionError<AutoNttError> {
+    fn from(err: AutoNttError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyConversionEngine`] for [`AutoNttEngine`] that operates on
+/// 32 bits integers. It converts a bootstrap key from the standard domain to the domain of the
+/// polynomial multiplication provider selected for its parameters.
+impl LweBootstrapKeyConversionEngine<LweBootstrapKey32, AutoLweBootstrapKey32> for AutoNttEngine {
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// use concrete_core::specification::engines::polynomial_multiplication::PolynomialMultiplicationProvider;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim) = (LweDimension(4), GlweDimension(2));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut auto_ntt_engine = AutoNttEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey32 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    ///
+    /// // The FFT is selected for polynomial sizes which are powers of two...
+    /// let poly_size = PolynomialSize(256);
+    /// let glwe_sk: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey32 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let auto_bsk: AutoLweBootstrapKey32 = auto_ntt_engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// assert_eq!(
+    ///     auto_bsk.polynomial_multiplication_provider(),
+    ///     PolynomialMultiplicationProvider::Fft
+    /// );
+    ///
+    /// // ... and the NTT for the other ones.
+    /// let poly_size = PolynomialSize(240);
+    /// let glwe_sk: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey32 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let auto_bsk: AutoLweBootstrapKey32 = auto_ntt_engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// assert_eq!(
+    ///     auto_bsk.polynomial_multiplication_provider(),
+    ///     PolynomialMultiplicationProvider::Ntt
+    /// );
+    /// #
+    /// assert_eq!(auto_bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(auto_bsk.polynomial_size(), poly_size);
+    /// assert_eq!(auto_bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(auto_bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(auto_bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_bootstrap_key(
+        &mut self,
+        input: &LweBootstrapKey32,
+    ) -> Result<AutoLweBootstrapKey32, LweBootstrapKeyConversionError<Self::EngineError>> {
+        if self
+            .select_polynomial_multiplication_provider(
+                input.polynomial_size(),
+                input.glwe_dimension(),
+                input.decomposition_level_count(),
+                input.decomposition_base_log(),
+            )
+            .is_none()
+        {
+            return Err(AutoNttError::NoProviderSupportsParameters.into());
+        }
+        Ok(unsafe { self.convert_lwe_bootstrap_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input: &LweBootstrapKey32,
+    ) -> AutoLweBootstrapKey32 {
+        let provider = self
+            .select_polynomial_multiplication_provider(
+                input.polynomial_size(),
+                input.glwe_dimension(),
+                input.decomposition_level_count(),
+                input.decomposition_base_log(),
+            )
+            .unwrap_or(self.preferred_provider);
+        match provider {
+            PolynomialMultiplicationProvider::Fft => AutoLweBootstrapKey32(ProviderEntity::Fft(
+                self.fft_engine.convert_lwe_bootstrap_key_unchecked(input),
+            )),
+            PolynomialMultiplicationProvider::Ntt => AutoLweBootstrapKey32(ProviderEntity::Ntt(
+                self.ntt_engine.convert_lwe_bootstrap_key_unchecked(input),
+            )),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyConversionEngine`] for [`AutoNttEngine`] that operates on
+/// 64 bits integers. It converts a bootstrap key from the standard domain to the domain of the
+/// polynomial multiplication provider selected for its parameters.
+impl LweBootstrapKeyConversionEngine<LweBootstrapKey64, AutoLweBootstrapKey64> for AutoNttEngine {
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// use concrete_core::specification::engines::polynomial_multiplication::PolynomialMultiplicationProvider;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim) = (LweDimension(4), GlweDimension(2));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut auto_ntt_engine = AutoNttEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    ///
+    /// // The FFT is selected for polynomial sizes which are powers of two...
+    /// let poly_size = PolynomialSize(256);
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey64 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let auto_bsk: AutoLweBootstrapKey64 = auto_ntt_engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// assert_eq!(
+    ///     auto_bsk.polynomial_multiplication_provider(),
+    ///     PolynomialMultiplicationProvider::Fft
+    /// );
+    ///
+    /// // ... and the NTT for the other ones.
+    /// let poly_size = PolynomialSize(240);
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey64 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let auto_bsk: AutoLweBootstrapKey64 = auto_ntt_engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// assert_eq!(
+    ///     auto_bsk.polynomial_multiplication_provider(),
+    ///     PolynomialMultiplicationProvider::Ntt
+    /// );
+    /// #
+    /// assert_eq!(auto_bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(auto_bsk.polynomial_size(), poly_size);
+    /// assert_eq!(auto_bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(auto_bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(auto_bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_bootstrap_key(
+        &mut self,
+        input: &LweBootstrapKey64,
+    ) -> Result<AutoLweBootstrapKey64, LweBootstrapKeyConversionError<Self::EngineError>> {
+        if self
+            .select_polynomial_multiplication_provider(
+                input.polynomial_size(),
+                input.glwe_dimension(),
+                input.decomposition_level_count(),
+                input.decomposition_base_log(),
+            )
+            .is_none()
+        {
+            return Err(AutoNttError::NoProviderSupportsParameters.into());
+        }
+        Ok(unsafe { self.convert_lwe_bootstrap_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input: &LweBootstrapKey64,
+    ) -> AutoLweBootstrapKey64 {
+        let provider = self
+            .select_polynomial_multiplication_provider(
+                input.polynomial_size(),
+                input.glwe_dimension(),
+                input.decomposition_level_count(),
+                input.decomposition_base_log(),
+            )
+            .unwrap_or(self.preferred_provider);
+        match provider {
+            PolynomialMultiplicationProvider::Fft => AutoLweBootstrapKey64(ProviderEntity::Fft(
+                self.fft_engine.convert_lwe_bootstrap_key_unchecked(input),
+            )),
+            PolynomialMultiplicationProvider::Ntt => AutoLweBootstrapKey64(ProviderEntity::Ntt(
+                self.ntt_engine.convert_lwe_bootstrap_key_unchecked(input),
+            )),
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use crate::backends::fft::engines::{FftEngine, FftError};
+use crate::backends::ntt::engines::{NttEngine, NttError};
+use crate::specification::engines::polynomial_multiplication::{
+    PolynomialMultiplicationProvider, PolynomialMultiplicationProviderSelector,
+};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+use concrete_csprng::seeders::Seeder;
+
+use crate::specification::engines::sealed::AbstractEngineSeal;
+use crate::specification::engines::AbstractEngine;
+
+/// The error which can occur in the execution of FHE operations, due to the auto ntt
+/// implementation.
+#[derive(Debug)]
+pub enum AutoNttError {
+    NoProviderSupportsParameters,
+    Fft(FftError),
+    Ntt(NttError),
+}
+
+impl Display for AutoNttError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AutoNttError::NoProviderSupportsParameters => {
+                write!(
+                    f,
+                    "Neither the FFT nor the NTT polynomial multiplication supports the given \
+                    polynomial size and decomposition parameters."
+                )
+            }
+            AutoNttError::Fft(error) => write!(f, "{}", error),
+            AutoNttError::Ntt(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for AutoNttError {}
+
+/// A variant of NttEngine exposed by the ntt backend.
+///
+/// This engine computes the polynomial products of the external products and bootstraps either
+/// with the fast Fourier transforms of the [`FftEngine`], or with the number-theoretic transforms
+/// of the [`NttEngine`]. The provider is selected for each parameter set when the bootstrap keys
+/// and GGSW ciphertexts are converted, see
+/// [`PolynomialMultiplicationProviderSelector::select_polynomial_multiplication_provider`], and the
+/// operations then use the provider of the converted entity.
+///
+/// The preferred provider, [`PolynomialMultiplicationProvider::Fft`] by default, is selected
+/// whenever it supports the parameters, and the other one otherwise. It can be changed with
+/// [`AutoNttEngine::set_preferred_polynomial_multiplication_provider`], for instance to avoid the
+/// rounding noise of the FFT whenever the parameters allow for exact products.
+pub struct AutoNttEngine {
+    fft_engine: FftEngine,
+    ntt_engine: NttEngine,
+    preferred_provider: PolynomialMultiplicationProvider,
+}
+
+impl AutoNttEngine {
+    /// Sets the provider selected whenever it supports the parameters.
+    pub fn set_preferred_polynomial_multiplication_provider(
+        &mut self,
+        provider: PolynomialMultiplicationProvider,
+    ) {
+        self.preferred_provider = provider;
+    }
+
+    /// Returns the provider selected whenever it supports the parameters.
+    pub fn preferred_polynomial_multiplication_provider(&self) -> PolynomialMultiplicationProvider {
+        self.preferred_provider
+    }
+}
+
+fn provider_supports_parameters(
+    provider: PolynomialMultiplicationProvider,
+    polynomial_size: PolynomialSize,
+    glwe_dimension: GlweDimension,
+    decomposition_level_count: DecompositionLevelCount,
+    decomposition_base_log: DecompositionBaseLog,
+) -> bool {
+    match provider {
+        PolynomialMultiplicationProvider::Fft => {
+            FftEngine::check_supported_size(polynomial_size).is_ok()
+        }
+        PolynomialMultiplicationProvider::Ntt => NttError::perform_decomposition_checks(
+            polynomial_size,
+            glwe_dimension.to_glwe_size(),
+            decomposition_level_count,
+            decomposition_base_log,
+        )
+        .is_ok(),
+    }
+}
+
+impl PolynomialMultiplicationProviderSelector for AutoNttEngine {
+    fn select_polynomial_multiplication_provider(
+        &self,
+        polynomial_size: PolynomialSize,
+        glwe_dimension: GlweDimension,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Option<PolynomialMultiplicationProvider> {
+        let other_provider = match self.preferred_provider {
+            PolynomialMultiplicationProvider::Fft => PolynomialMultiplicationProvider::Ntt,
+            PolynomialMultiplicationProvider::Ntt => PolynomialMultiplicationProvider::Fft,
+        };
+        [self.preferred_provider, other_provider]
+            .into_iter()
+            .find(|provider| {
+                provider_supports_parameters(
+                    *provider,
+                    polynomial_size,
+                    glwe_dimension,
+                    decomposition_level_count,
+                    decomposition_base_log,
+                )
+            })
+    }
+}
+
+impl AbstractEngineSeal for AutoNttEngine {}
+
+impl AbstractEngine for AutoNttEngine {
+    type EngineError = AutoNttError;
+
+    type Parameters = Box<dyn Seeder>;
+
+    fn new(parameters: Self::Parameters) -> Result<Self, Self::EngineError> {
+        Ok(AutoNttEngine {
+            fft_engine: FftEngine::new(()).map_err(AutoNttError::Fft)?,
+            ntt_engine: NttEngine::new(parameters).map_err(AutoNttError::Ntt)?,
+            preferred_provider: PolynomialMultiplicationProvider::Fft,
+        })
+    }
+}
+
+mod ggsw_ciphertext_conversion;
+mod glwe_ciphertext_ggsw_ciphertext_discarding_external_product;
+mod lwe_bootstrap_key_conversion;
+mod lwe_ciphertext_discarding_bootstrap;
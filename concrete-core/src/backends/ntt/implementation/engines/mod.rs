@@ -0,0 +1,12 @@
+//! A module containing the [engines](crate::specification::engines) exposed by the ntt backend.
+
+mod ntt_engine;
+pub use ntt_engine::*;
+
+#[cfg(feature = "backend_fft")]
+mod auto_ntt_engine;
+#[cfg(feature = "backend_fft")]
+pub use auto_ntt_engine::*;
+
+mod registry;
+pub(crate) use registry::implementations;
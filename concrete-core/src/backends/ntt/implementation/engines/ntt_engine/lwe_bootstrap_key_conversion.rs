@@ -0,0 +1,164 @@
+use crate::backends::ntt::engines::{NttEngine, NttError};
+use crate::backends::ntt::entities::{NttLweBootstrapKey32, NttLweBootstrapKey64};
+use crate::backends::ntt::private::crypto::bootstrap::NttBootstrapKey;
+use crate::prelude::{LweBootstrapKey32, LweBootstrapKey64};
+use crate::specification::engines::{
+    LweBootstrapKeyConversionEngine, LweBootstrapKeyConversionError,
+};
+use crate::specification::entities::LweBootstrapKeyEntity;
+
+impl From<NttError> for LweBootstrapKeyConversionError<NttError> {
+    fn from(err: NttError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyConversionEngine`] for [`NttEngine`] that operates on
+/// 32 bits integers. It converts a bootstrap key from the standard to the NTT domain.
+impl LweBootstrapKeyConversionEngine<LweBootstrapKey32, NttLweBootstrapKey32> for NttEngine {
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(2), PolynomialSize(240));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut ntt_engine = NttEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey32 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey32 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let ntt_bsk: NttLweBootstrapKey32 = ntt_engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// #
+    /// assert_eq!(ntt_bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(ntt_bsk.polynomial_size(), poly_size);
+    /// assert_eq!(ntt_bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(ntt_bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(ntt_bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_bootstrap_key(
+        &mut self,
+        input: &LweBootstrapKey32,
+    ) -> Result<NttLweBootstrapKey32, LweBootstrapKeyConversionError<Self::EngineError>> {
+        NttError::perform_decomposition_checks(
+            input.polynomial_size(),
+            input.glwe_dimension().to_glwe_size(),
+            input.decomposition_level_count(),
+            input.decomposition_base_log(),
+        )?;
+        Ok(unsafe { self.convert_lwe_bootstrap_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input: &LweBootstrapKey32,
+    ) -> NttLweBootstrapKey32 {
+        let mut output = NttBootstrapKey::allocate(
+            input.glwe_dimension().to_glwe_size(),
+            input.polynomial_size(),
+            input.decomposition_level_count(),
+            input.decomposition_base_log(),
+            input.input_lwe_dimension(),
+        );
+        let buffers = self.get_ntt_u32_buffer(
+            input.polynomial_size(),
+            input.glwe_dimension().to_glwe_size(),
+        );
+        output.fill_with_forward_ntt(&input.0, buffers);
+        NttLweBootstrapKey32(output)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyConversionEngine`] for [`NttEngine`] that operates on
+/// 64 bits integers. It converts a bootstrap key from the standard to the NTT domain.
+impl LweBootstrapKeyConversionEngine<LweBootstrapKey64, NttLweBootstrapKey64> for NttEngine {
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(2), PolynomialSize(240));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut ntt_engine = NttEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey64 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let ntt_bsk: NttLweBootstrapKey64 = ntt_engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// #
+    /// assert_eq!(ntt_bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(ntt_bsk.polynomial_size(), poly_size);
+    /// assert_eq!(ntt_bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(ntt_bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(ntt_bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_bootstrap_key(
+        &mut self,
+        input: &LweBootstrapKey64,
+    ) -> Result<NttLweBootstrapKey64, LweBootstrapKeyConversionError<Self::EngineError>> {
+        NttError::perform_decomposition_checks(
+            input.polynomial_size(),
+            input.glwe_dimension().to_glwe_size(),
+            input.decomposition_level_count(),
+            input.decomposition_base_log(),
+        )?;
+        Ok(unsafe { self.convert_lwe_bootstrap_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input: &LweBootstrapKey64,
+    ) -> NttLweBootstrapKey64 {
+        let mut output = NttBootstrapKey::allocate(
+            input.glwe_dimension().to_glwe_size(),
+            input.polynomial_size(),
+            input.decomposition_level_count(),
+            input.decomposition_base_log(),
+            input.input_lwe_dimension(),
+        );
+        let buffers = self.get_ntt_u64_buffer(
+            input.polynomial_size(),
+            input.glwe_dimension().to_glwe_size(),
+        );
+        output.fill_with_forward_ntt(&input.0, buffers);
+        NttLweBootstrapKey64(output)
+    }
+}
@@ -0,0 +1,310 @@
+use super::NttBufferKey;
+use crate::backends::ntt::engines::NttEngine;
+use crate::backends::ntt::entities::{NttLweBootstrapKey32, NttLweBootstrapKey64};
+use crate::backends::ntt::private::crypto::bootstrap::NttBuffers;
+use crate::prelude::{
+    GlweCiphertext32, GlweCiphertext64, LweBootstrapKeyEntity, LweCiphertext32, LweCiphertext64,
+    LwePublicKey32, LwePublicKey64,
+};
+use crate::specification::engines::{
+    LweCiphertextDiscardingSanitizationEngine, LweCiphertextDiscardingSanitizationError,
+};
+use concrete_commons::dispersion::Variance;
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingSanitizationEngine`] for [`NttEngine`] that operates
+/// on 32 bits integers.
+impl
+    LweCiphertextDiscardingSanitizationEngine<
+        NttLweBootstrapKey32,
+        GlweCiphertext32,
+        LwePublicKey32,
+        LweCiphertext32,
+        LweCiphertext32,
+    > for NttEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     LwePublicKeyZeroEncryptionCount, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(768),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(768),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![8_u32 << 20; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// let public_key_size = LwePublicKeyZeroEncryptionCount(10);
+    /// let (accumulator_noise, flooding_noise) = (Variance(2_f64.powf(-30.)), Variance(2_f64.powf(-20.)));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut ntt_engine = NttEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey32 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let lwe_sk_output: LweSecretKey32 =
+    ///     default_engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_sk.clone())?;
+    /// let bsk: LweBootstrapKey32 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let bsk: NttLweBootstrapKey32 = ntt_engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// let input_pk: LwePublicKey32 =
+    ///     default_engine.generate_new_lwe_public_key(&lwe_sk, noise, public_key_size)?;
+    /// let output_pk: LwePublicKey32 =
+    ///     default_engine.generate_new_lwe_public_key(&lwe_sk_output, noise, public_key_size)?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&lut)?;
+    /// let acc = default_engine
+    ///     .trivially_encrypt_glwe_ciphertext(glwe_dim.to_glwe_size(), &plaintext_vector)?;
+    /// let input = default_engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output = default_engine.zero_encrypt_lwe_ciphertext(&lwe_sk_output, noise)?;
+    ///
+    /// ntt_engine.discard_sanitize_lwe_ciphertext(
+    ///     &mut output,
+    ///     &input,
+    ///     &acc,
+    ///     &bsk,
+    ///     &input_pk,
+    ///     &output_pk,
+    ///     accumulator_noise,
+    ///     flooding_noise,
+    /// )?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_sanitize_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &LweCiphertext32,
+        acc: &GlweCiphertext32,
+        bsk: &NttLweBootstrapKey32,
+        input_public_key: &LwePublicKey32,
+        output_public_key: &LwePublicKey32,
+        accumulator_noise: Variance,
+        flooding_noise: Variance,
+    ) -> Result<(), LweCiphertextDiscardingSanitizationError<Self::EngineError>> {
+        LweCiphertextDiscardingSanitizationError::perform_generic_checks(
+            output,
+            input,
+            acc,
+            bsk,
+            input_public_key,
+            output_public_key,
+        )?;
+        unsafe {
+            self.discard_sanitize_lwe_ciphertext_unchecked(
+                output,
+                input,
+                acc,
+                bsk,
+                input_public_key,
+                output_public_key,
+                accumulator_noise,
+                flooding_noise,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_sanitize_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &LweCiphertext32,
+        acc: &GlweCiphertext32,
+        bsk: &NttLweBootstrapKey32,
+        input_public_key: &LwePublicKey32,
+        output_public_key: &LwePublicKey32,
+        accumulator_noise: Variance,
+        flooding_noise: Variance,
+    ) {
+        let buffers = self
+            .ntt_buffers_u32
+            .entry(NttBufferKey(
+                bsk.polynomial_size(),
+                bsk.glwe_dimension().to_glwe_size(),
+            ))
+            .or_insert_with(|| {
+                NttBuffers::for_params(bsk.polynomial_size(), bsk.glwe_dimension().to_glwe_size())
+            });
+        bsk.0.sanitize(
+            &mut output.0,
+            &input.0,
+            &acc.0,
+            &input_public_key.0,
+            &output_public_key.0,
+            accumulator_noise,
+            flooding_noise,
+            buffers,
+            &mut self.secret_generator,
+            &mut self.encryption_generator,
+        );
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingSanitizationEngine`] for [`NttEngine`] that operates
+/// on 64 bits integers.
+impl
+    LweCiphertextDiscardingSanitizationEngine<
+        NttLweBootstrapKey64,
+        GlweCiphertext64,
+        LwePublicKey64,
+        LweCiphertext64,
+        LweCiphertext64,
+    > for NttEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     LwePublicKeyZeroEncryptionCount, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(768),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(768),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![8_u64 << 50; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// let public_key_size = LwePublicKeyZeroEncryptionCount(10);
+    /// let (accumulator_noise, flooding_noise) = (Variance(2_f64.powf(-30.)), Variance(2_f64.powf(-20.)));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut ntt_engine = NttEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let lwe_sk_output: LweSecretKey64 =
+    ///     default_engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_sk.clone())?;
+    /// let bsk: LweBootstrapKey64 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let bsk: NttLweBootstrapKey64 = ntt_engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// let input_pk: LwePublicKey64 =
+    ///     default_engine.generate_new_lwe_public_key(&lwe_sk, noise, public_key_size)?;
+    /// let output_pk: LwePublicKey64 =
+    ///     default_engine.generate_new_lwe_public_key(&lwe_sk_output, noise, public_key_size)?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&lut)?;
+    /// let acc = default_engine
+    ///     .trivially_encrypt_glwe_ciphertext(glwe_dim.to_glwe_size(), &plaintext_vector)?;
+    /// let input = default_engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output = default_engine.zero_encrypt_lwe_ciphertext(&lwe_sk_output, noise)?;
+    ///
+    /// ntt_engine.discard_sanitize_lwe_ciphertext(
+    ///     &mut output,
+    ///     &input,
+    ///     &acc,
+    ///     &bsk,
+    ///     &input_pk,
+    ///     &output_pk,
+    ///     accumulator_noise,
+    ///     flooding_noise,
+    /// )?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_sanitize_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &LweCiphertext64,
+        acc: &GlweCiphertext64,
+        bsk: &NttLweBootstrapKey64,
+        input_public_key: &LwePublicKey64,
+        output_public_key: &LwePublicKey64,
+        accumulator_noise: Variance,
+        flooding_noise: Variance,
+    ) -> Result<(), LweCiphertextDiscardingSanitizationError<Self::EngineError>> {
+        LweCiphertextDiscardingSanitizationError::perform_generic_checks(
+            output,
+            input,
+            acc,
+            bsk,
+            input_public_key,
+            output_public_key,
+        )?;
+        unsafe {
+            self.discard_sanitize_lwe_ciphertext_unchecked(
+                output,
+                input,
+                acc,
+                bsk,
+                input_public_key,
+                output_public_key,
+                accumulator_noise,
+                flooding_noise,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_sanitize_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &LweCiphertext64,
+        acc: &GlweCiphertext64,
+        bsk: &NttLweBootstrapKey64,
+        input_public_key: &LwePublicKey64,
+        output_public_key: &LwePublicKey64,
+        accumulator_noise: Variance,
+        flooding_noise: Variance,
+    ) {
+        let buffers = self
+            .ntt_buffers_u64
+            .entry(NttBufferKey(
+                bsk.polynomial_size(),
+                bsk.glwe_dimension().to_glwe_size(),
+            ))
+            .or_insert_with(|| {
+                NttBuffers::for_params(bsk.polynomial_size(), bsk.glwe_dimension().to_glwe_size())
+            });
+        bsk.0.sanitize(
+            &mut output.0,
+            &input.0,
+            &acc.0,
+            &input_public_key.0,
+            &output_public_key.0,
+            accumulator_noise,
+            flooding_noise,
+            buffers,
+            &mut self.secret_generator,
+            &mut self.encryption_generator,
+        );
+    }
+}
@@ -0,0 +1,221 @@
+use super::NttBufferKey;
+use crate::backends::ntt::engines::NttEngine;
+use crate::backends::ntt::entities::{NttLweBootstrapKey32, NttLweBootstrapKey64};
+use crate::backends::ntt::private::crypto::bootstrap::NttBuffers;
+use crate::prelude::{
+    GlweCiphertext32, GlweCiphertext64, LweBootstrapKeyEntity, LweCiphertext32, LweCiphertext64,
+};
+use crate::specification::engines::{
+    LweCiphertextDiscardingStochasticBootstrapEngine,
+    LweCiphertextDiscardingStochasticBootstrapError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingStochasticBootstrapEngine`] for [`NttEngine`] that operates on
+/// 32 bits integers.
+impl
+    LweCiphertextDiscardingStochasticBootstrapEngine<
+        NttLweBootstrapKey32,
+        GlweCiphertext32,
+        LweCiphertext32,
+        LweCiphertext32,
+    > for NttEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(768),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(768),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![8_u32 << 20; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut ntt_engine = NttEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey32 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey32 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let bsk: NttLweBootstrapKey32 = ntt_engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// let lwe_sk_output: LweSecretKey32 =
+    ///     default_engine.generate_new_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&lut)?;
+    /// let acc = default_engine
+    ///     .trivially_encrypt_glwe_ciphertext(glwe_dim.to_glwe_size(), &plaintext_vector)?;
+    /// let input = default_engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output = default_engine.zero_encrypt_lwe_ciphertext(&lwe_sk_output, noise)?;
+    ///
+    /// ntt_engine.discard_stochastic_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_stochastic_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &LweCiphertext32,
+        acc: &GlweCiphertext32,
+        bsk: &NttLweBootstrapKey32,
+    ) -> Result<(), LweCiphertextDiscardingStochasticBootstrapError<Self::EngineError>> {
+        LweCiphertextDiscardingStochasticBootstrapError::perform_generic_checks(
+            output, input, acc, bsk,
+        )?;
+        unsafe {
+            self.discard_stochastic_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_stochastic_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &LweCiphertext32,
+        acc: &GlweCiphertext32,
+        bsk: &NttLweBootstrapKey32,
+    ) {
+        let buffers = self
+            .ntt_buffers_u32
+            .entry(NttBufferKey(
+                bsk.polynomial_size(),
+                bsk.glwe_dimension().to_glwe_size(),
+            ))
+            .or_insert_with(|| {
+                NttBuffers::for_params(bsk.polynomial_size(), bsk.glwe_dimension().to_glwe_size())
+            });
+        bsk.0.stochastic_bootstrap(
+            &mut output.0,
+            &input.0,
+            &acc.0,
+            buffers,
+            &mut self.rounding_generator,
+        );
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingStochasticBootstrapEngine`] for [`NttEngine`] that operates on
+/// 64 bits integers.
+impl
+    LweCiphertextDiscardingStochasticBootstrapEngine<
+        NttLweBootstrapKey64,
+        GlweCiphertext64,
+        LweCiphertext64,
+        LweCiphertext64,
+    > for NttEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(768),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(768),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![8_u64 << 50; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut ntt_engine = NttEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey64 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let bsk: NttLweBootstrapKey64 = ntt_engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// let lwe_sk_output: LweSecretKey64 =
+    ///     default_engine.generate_new_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&lut)?;
+    /// let acc = default_engine
+    ///     .trivially_encrypt_glwe_ciphertext(glwe_dim.to_glwe_size(), &plaintext_vector)?;
+    /// let input = default_engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output = default_engine.zero_encrypt_lwe_ciphertext(&lwe_sk_output, noise)?;
+    ///
+    /// ntt_engine.discard_stochastic_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_stochastic_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &LweCiphertext64,
+        acc: &GlweCiphertext64,
+        bsk: &NttLweBootstrapKey64,
+    ) -> Result<(), LweCiphertextDiscardingStochasticBootstrapError<Self::EngineError>> {
+        LweCiphertextDiscardingStochasticBootstrapError::perform_generic_checks(
+            output, input, acc, bsk,
+        )?;
+        unsafe {
+            self.discard_stochastic_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_stochastic_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &LweCiphertext64,
+        acc: &GlweCiphertext64,
+        bsk: &NttLweBootstrapKey64,
+    ) {
+        let buffers = self
+            .ntt_buffers_u64
+            .entry(NttBufferKey(
+                bsk.polynomial_size(),
+                bsk.glwe_dimension().to_glwe_size(),
+            ))
+            .or_insert_with(|| {
+                NttBuffers::for_params(bsk.polynomial_size(), bsk.glwe_dimension().to_glwe_size())
+            });
+        bsk.0.stochastic_bootstrap(
+            &mut output.0,
+            &input.0,
+            &acc.0,
+            buffers,
+            &mut self.rounding_generator,
+        );
+    }
+}
@@ -0,0 +1,153 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use crate::backends::ntt::private::crypto::bootstrap::NttBuffers;
+use crate::backends::ntt::private::math::ntt::{is_supported_size, products_are_exact};
+use crate::commons::crypto::secret::generators::{
+    EncryptionRandomGenerator, SecretRandomGenerator,
+};
+use crate::commons::math::random::RandomGenerator;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+};
+#[cfg(feature = "backend_default_generator_x86_64_aesni")]
+use concrete_csprng::generators::AesniRandomGenerator;
+#[cfg(not(feature = "backend_default_generator_x86_64_aesni"))]
+use concrete_csprng::generators::SoftwareRandomGenerator;
+use concrete_csprng::seeders::Seeder;
+
+use crate::specification::engines::sealed::AbstractEngineSeal;
+use crate::specification::engines::AbstractEngine;
+
+/// The error which can occur in the execution of FHE operations, due to the ntt implementation.
+#[derive(Debug)]
+pub enum NttError {
+    UnsupportedPolynomialSize,
+    UnsupportedDecomposition,
+}
+
+impl Display for NttError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NttError::UnsupportedPolynomialSize => {
+                write!(
+                    f,
+                    "The Ntt Backend only supports polynomials whose transform size is at most \
+                    2^31."
+                )
+            }
+            NttError::UnsupportedDecomposition => {
+                write!(
+                    f,
+                    "The decomposition parameters are too large for the polynomial products to be \
+                    computed exactly."
+                )
+            }
+        }
+    }
+}
+
+impl Error for NttError {}
+
+impl NttError {
+    pub fn perform_ntt_checks(polynomial_size: PolynomialSize) -> Result<(), Self> {
+        if !is_supported_size(polynomial_size) {
+            return Err(NttError::UnsupportedPolynomialSize);
+        }
+        Ok(())
+    }
+
+    pub fn perform_decomposition_checks(
+        polynomial_size: PolynomialSize,
+        glwe_size: GlweSize,
+        level_count: DecompositionLevelCount,
+        base_log: DecompositionBaseLog,
+    ) -> Result<(), Self> {
+        Self::perform_ntt_checks(polynomial_size)?;
+        // The decomposition terms are bounded by 2^(base_log - 1) in absolute value.
+        if !products_are_exact(
+            polynomial_size,
+            level_count.0 * glwe_size.0,
+            base_log.0.saturating_sub(1),
+        ) {
+            return Err(NttError::UnsupportedDecomposition);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "backend_default_generator_x86_64_aesni")]
+type ActivatedRandomGenerator = AesniRandomGenerator;
+#[cfg(not(feature = "backend_default_generator_x86_64_aesni"))]
+type ActivatedRandomGenerator = SoftwareRandomGenerator;
+
+#[derive(Ord, PartialOrd, Eq, PartialEq)]
+pub(crate) struct NttBufferKey(pub PolynomialSize, pub GlweSize);
+
+/// The main engine exposed by the ntt backend.
+pub struct NttEngine {
+    /// A structure containing two CSPRNGs to generate material for encryption like public masks
+    /// and secret errors.
+    encryption_generator: EncryptionRandomGenerator<ActivatedRandomGenerator>,
+    /// A CSPRNG used to draw the random bits of the stochastic rounding of the modulus switch.
+    rounding_generator: RandomGenerator<ActivatedRandomGenerator>,
+    /// A CSPRNG used to draw the secret subsets of public key encryptions of zero which
+    /// rerandomize the sanitized ciphertexts.
+    secret_generator: SecretRandomGenerator<ActivatedRandomGenerator>,
+    // As in the fftw backend, the plans and buffers are allocated once for every polynomial and
+    // GLWE sizes, and reused by all the subsequent operations.
+    ntt_buffers_u32: BTreeMap<NttBufferKey, NttBuffers<u32>>,
+    ntt_buffers_u64: BTreeMap<NttBufferKey, NttBuffers<u64>>,
+}
+
+impl NttEngine {
+    pub(crate) fn get_ntt_u32_buffer(
+        &mut self,
+        poly_size: PolynomialSize,
+        glwe_size: GlweSize,
+    ) -> &mut NttBuffers<u32> {
+        self.ntt_buffers_u32
+            .entry(NttBufferKey(poly_size, glwe_size))
+            .or_insert_with(|| NttBuffers::for_params(poly_size, glwe_size))
+    }
+
+    pub(crate) fn get_ntt_u64_buffer(
+        &mut self,
+        poly_size: PolynomialSize,
+        glwe_size: GlweSize,
+    ) -> &mut NttBuffers<u64> {
+        self.ntt_buffers_u64
+            .entry(NttBufferKey(poly_size, glwe_size))
+            .or_insert_with(|| NttBuffers::for_params(poly_size, glwe_size))
+    }
+}
+
+impl AbstractEngineSeal for NttEngine {}
+
+impl AbstractEngine for NttEngine {
+    type EngineError = NttError;
+
+    type Parameters = Box<dyn Seeder>;
+
+    fn new(mut parameters: Self::Parameters) -> Result<Self, Self::EngineError> {
+        Ok(NttEngine {
+            encryption_generator: EncryptionRandomGenerator::new(
+                parameters.seed(),
+                parameters.as_mut(),
+            ),
+            rounding_generator: RandomGenerator::new(parameters.seed()),
+            secret_generator: SecretRandomGenerator::new(parameters.seed()),
+            ntt_buffers_u32: Default::default(),
+            ntt_buffers_u64: Default::default(),
+        })
+    }
+}
+
+mod ggsw_ciphertext_conversion;
+mod glwe_ciphertext_encryption;
+mod glwe_ciphertext_ggsw_ciphertext_discarding_external_product;
+mod lwe_bootstrap_key_conversion;
+mod lwe_ciphertext_discarding_bootstrap;
+mod lwe_ciphertext_discarding_sanitization;
+mod lwe_ciphertext_discarding_stochastic_bootstrap;
@@ -0,0 +1,92 @@
+//! The declaration of the engine traits implemented by the engines of the ntt backend.
+use crate::backends::support::declare_implementations;
+use crate::prelude::*;
+
+declare_implementations! {
+    "ntt",
+    NttEngine => [
+        GgswCiphertextConversionEngine<GgswCiphertext32, NttGgswCiphertext32>,
+        GgswCiphertextConversionEngine<GgswCiphertext64, NttGgswCiphertext64>,
+        GlweCiphertextEncryptionEngine<GlweSecretKey32, PlaintextVector32, GlweCiphertext32>,
+        GlweCiphertextEncryptionEngine<GlweSecretKey64, PlaintextVector64, GlweCiphertext64>,
+        GlweCiphertextGgswCiphertextDiscardingExternalProductEngine<
+            GlweCiphertext32,
+            NttGgswCiphertext32,
+            GlweCiphertext32,
+        >,
+        GlweCiphertextGgswCiphertextDiscardingExternalProductEngine<
+            GlweCiphertext64,
+            NttGgswCiphertext64,
+            GlweCiphertext64,
+        >,
+        LweBootstrapKeyConversionEngine<LweBootstrapKey32, NttLweBootstrapKey32>,
+        LweBootstrapKeyConversionEngine<LweBootstrapKey64, NttLweBootstrapKey64>,
+        LweCiphertextDiscardingBootstrapEngine<
+            NttLweBootstrapKey32,
+            GlweCiphertext32,
+            LweCiphertext32,
+            LweCiphertext32,
+        >,
+        LweCiphertextDiscardingBootstrapEngine<
+            NttLweBootstrapKey64,
+            GlweCiphertext64,
+            LweCiphertext64,
+            LweCiphertext64,
+        >,
+        LweCiphertextDiscardingSanitizationEngine<
+            NttLweBootstrapKey32,
+            GlweCiphertext32,
+            LwePublicKey32,
+            LweCiphertext32,
+            LweCiphertext32,
+        >,
+        LweCiphertextDiscardingSanitizationEngine<
+            NttLweBootstrapKey64,
+            GlweCiphertext64,
+            LwePublicKey64,
+            LweCiphertext64,
+            LweCiphertext64,
+        >,
+        LweCiphertextDiscardingStochasticBootstrapEngine<
+            NttLweBootstrapKey32,
+            GlweCiphertext32,
+            LweCiphertext32,
+            LweCiphertext32,
+        >,
+        LweCiphertextDiscardingStochasticBootstrapEngine<
+            NttLweBootstrapKey64,
+            GlweCiphertext64,
+            LweCiphertext64,
+            LweCiphertext64,
+        >,
+    ],
+    #[cfg(feature = "backend_fft")]
+    AutoNttEngine => [
+        GgswCiphertextConversionEngine<GgswCiphertext32, AutoGgswCiphertext32>,
+        GgswCiphertextConversionEngine<GgswCiphertext64, AutoGgswCiphertext64>,
+        GlweCiphertextGgswCiphertextDiscardingExternalProductEngine<
+            GlweCiphertext32,
+            AutoGgswCiphertext32,
+            GlweCiphertext32,
+        >,
+        GlweCiphertextGgswCiphertextDiscardingExternalProductEngine<
+            GlweCiphertext64,
+            AutoGgswCiphertext64,
+            GlweCiphertext64,
+        >,
+        LweBootstrapKeyConversionEngine<LweBootstrapKey32, AutoLweBootstrapKey32>,
+        LweBootstrapKeyConversionEngine<LweBootstrapKey64, AutoLweBootstrapKey64>,
+        LweCiphertextDiscardingBootstrapEngine<
+            AutoLweBootstrapKey32,
+            GlweCiphertext32,
+            LweCiphertext32,
+            LweCiphertext32,
+        >,
+        LweCiphertextDiscardingBootstrapEngine<
+            AutoLweBootstrapKey64,
+            GlweCiphertext64,
+            LweCiphertext64,
+            LweCiphertext64,
+        >,
+    ],
+}
@@ -0,0 +1,79 @@
+use crate::backends::fft::entities::{FftFourierGgswCiphertext32, FftFourierGgswCiphertext64};
+use crate::backends::ntt::entities::{NttGgswCiphertext32, NttGgswCiphertext64};
+use crate::backends::ntt::private::provider::{dispatch, ProviderEntity};
+use crate::specification::engines::polynomial_multiplication::PolynomialMultiplicationProvider;
+use crate::specification::entities::markers::GgswCiphertextKind;
+use crate::specification::entities::{AbstractEntity, GgswCiphertextEntity};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+
+/// A structure representing a GGSW ciphertext with 32 bits of precision, converted to the
+/// domain of the polynomial multiplication provider selected by an
+/// [`AutoNttEngine`](crate::backends::ntt::engines::AutoNttEngine).
+#[derive(Debug, Clone, PartialEq)]
+pub struct AutoGgswCiphertext32(
+    pub(crate) ProviderEntity<FftFourierGgswCiphertext32, NttGgswCiphertext32>,
+);
+impl AbstractEntity for AutoGgswCiphertext32 {
+    type Kind = GgswCiphertextKind;
+}
+impl GgswCiphertextEntity for AutoGgswCiphertext32 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        dispatch!(&self.0, ggsw => ggsw.glwe_dimension())
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        dispatch!(&self.0, ggsw => ggsw.polynomial_size())
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        dispatch!(&self.0, ggsw => ggsw.decomposition_level_count())
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        dispatch!(&self.0, ggsw => ggsw.decomposition_base_log())
+    }
+}
+impl AutoGgswCiphertext32 {
+    /// Returns the provider computing the polynomial products of the external products using this
+    /// ciphertext.
+    pub fn polynomial_multiplication_provider(&self) -> PolynomialMultiplicationProvider {
+        self.0.provider()
+    }
+}
+
+/// A structure representing a GGSW ciphertext with 64 bits of precision, converted to the
+/// domain of the polynomial multiplication provider selected by an
+/// [`AutoNttEngine`](crate::backends::ntt::engines::AutoNttEngine).
+#[derive(Debug, Clone, PartialEq)]
+pub struct AutoGgswCiphertext64(
+    pub(crate) ProviderEntity<FftFourierGgswCiphertext64, NttGgswCiphertext64>,
+);
+impl AbstractEntity for AutoGgswCiphertext64 {
+    type Kind = GgswCiphertextKind;
+}
+impl GgswCiphertextEntity for AutoGgswCiphertext64 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        dispatch!(&self.0, ggsw => ggsw.glwe_dimension())
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        dispatch!(&self.0, ggsw => ggsw.polynomial_size())
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        dispatch!(&self.0, ggsw => ggsw.decomposition_level_count())
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        dispatch!(&self.0, ggsw => ggsw.decomposition_base_log())
+    }
+}
+impl AutoGgswCiphertext64 {
+    /// Returns the provider computing the polynomial products of the external products using this
+    /// ciphertext.
+    pub fn polynomial_multiplication_provider(&self) -> PolynomialMultiplicationProvider {
+        self.0.provider()
+    }
+}
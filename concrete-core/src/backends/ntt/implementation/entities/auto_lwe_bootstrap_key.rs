@@ -0,0 +1,85 @@
+use crate::backends::fft::entities::{FftFourierLweBootstrapKey32, FftFourierLweBootstrapKey64};
+use crate::backends::ntt::entities::{NttLweBootstrapKey32, NttLweBootstrapKey64};
+use crate::backends::ntt::private::provider::{dispatch, ProviderEntity};
+use crate::specification::engines::polynomial_multiplication::PolynomialMultiplicationProvider;
+use crate::specification::entities::markers::LweBootstrapKeyKind;
+use crate::specification::entities::{AbstractEntity, LweBootstrapKeyEntity};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+
+/// A structure representing an LWE bootstrap key with 32 bits of precision, converted to the
+/// domain of the polynomial multiplication provider selected by an
+/// [`AutoNttEngine`](crate::backends::ntt::engines::AutoNttEngine).
+#[derive(Debug, Clone, PartialEq)]
+pub struct AutoLweBootstrapKey32(
+    pub(crate) ProviderEntity<FftFourierLweBootstrapKey32, NttLweBootstrapKey32>,
+);
+impl AbstractEntity for AutoLweBootstrapKey32 {
+    type Kind = LweBootstrapKeyKind;
+}
+impl LweBootstrapKeyEntity for AutoLweBootstrapKey32 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        dispatch!(&self.0, key => key.glwe_dimension())
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        dispatch!(&self.0, key => key.polynomial_size())
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        dispatch!(&self.0, key => key.input_lwe_dimension())
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        dispatch!(&self.0, key => key.decomposition_base_log())
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        dispatch!(&self.0, key => key.decomposition_level_count())
+    }
+}
+impl AutoLweBootstrapKey32 {
+    /// Returns the provider computing the polynomial products of the bootstraps using this key.
+    pub fn polynomial_multiplication_provider(&self) -> PolynomialMultiplicationProvider {
+        self.0.provider()
+    }
+}
+
+/// A structure representing an LWE bootstrap key with 64 bits of precision, converted to the
+/// domain of the polynomial multiplication provider selected by an
+/// [`AutoNttEngine`](crate::backends::ntt::engines::AutoNttEngine).
+#[derive(Debug, Clone, PartialEq)]
+pub struct AutoLweBootstrapKey64(
+    pub(crate) ProviderEntity<FftFourierLweBootstrapKey64, NttLweBootstrapKey64>,
+);
+impl AbstractEntity for AutoLweBootstrapKey64 {
+    type Kind = LweBootstrapKeyKind;
+}
+impl LweBootstrapKeyEntity for AutoLweBootstrapKey64 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        dispatch!(&self.0, key => key.glwe_dimension())
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        dispatch!(&self.0, key => key.polynomial_size())
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        dispatch!(&self.0, key => key.input_lwe_dimension())
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        dispatch!(&self.0, key => key.decomposition_base_log())
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        dispatch!(&self.0, key => key.decomposition_level_count())
+    }
+}
+impl AutoLweBootstrapKey64 {
+    /// Returns the provider computing the polynomial products of the bootstraps using this key.
+    pub fn polynomial_multiplication_provider(&self) -> PolynomialMultiplicationProvider {
+        self.0.provider()
+    }
+}
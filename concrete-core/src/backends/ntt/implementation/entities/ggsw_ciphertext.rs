@@ -0,0 +1,58 @@
+use crate::backends::ntt::private::crypto::ggsw::NttGgswCiphertext;
+use crate::specification::entities::markers::GgswCiphertextKind;
+use crate::specification::entities::{AbstractEntity, GgswCiphertextEntity};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+
+/// A structure representing a GGSW ciphertext with 32 bits of precision in the NTT domain.
+/// Note: The name `NttGgswCiphertext32` refers to the bit size of the coefficients in the
+/// standard domain. Coefficients in the NTT domain are always represented on 64 bits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NttGgswCiphertext32(pub(crate) NttGgswCiphertext<Vec<u64>, u32>);
+impl AbstractEntity for NttGgswCiphertext32 {
+    type Kind = GgswCiphertextKind;
+}
+impl GgswCiphertextEntity for NttGgswCiphertext32 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
+
+/// A structure representing a GGSW ciphertext with 64 bits of precision in the NTT domain.
+/// Note: The name `NttGgswCiphertext64` refers to the bit size of the coefficients in the
+/// standard domain. Coefficients in the NTT domain are always represented on 64 bits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NttGgswCiphertext64(pub(crate) NttGgswCiphertext<Vec<u64>, u64>);
+impl AbstractEntity for NttGgswCiphertext64 {
+    type Kind = GgswCiphertextKind;
+}
+impl GgswCiphertextEntity for NttGgswCiphertext64 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
@@ -0,0 +1,16 @@
+//! A module containing all the [entities](crate::specification::entities) exposed by the ntt
+//! backend.
+
+#[cfg(feature = "backend_fft")]
+mod auto_ggsw_ciphertext;
+#[cfg(feature = "backend_fft")]
+mod auto_lwe_bootstrap_key;
+mod ggsw_ciphertext;
+mod lwe_bootstrap_key;
+
+#[cfg(feature = "backend_fft")]
+pub use auto_ggsw_ciphertext::*;
+#[cfg(feature = "backend_fft")]
+pub use auto_lwe_bootstrap_key::*;
+pub use ggsw_ciphertext::*;
+pub use lwe_bootstrap_key::*;
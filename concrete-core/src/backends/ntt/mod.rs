@@ -0,0 +1,11 @@
+//! A pure-rust backend, computing the polynomial products with number-theoretic transforms.
+//!
+//! The products are computed exactly over a 64 bits prime field, which removes the rounding noise
+//! of the floating-point transforms, and supports polynomial sizes that are not powers of two.
+
+#[doc(hidden)]
+pub mod private;
+
+mod implementation;
+
+pub use implementation::{engines, entities};
@@ -0,0 +1,466 @@
+use crate::backends::ntt::private::crypto::ggsw::NttGgswCiphertext;
+use crate::backends::ntt::private::math::ntt::{limb_count, transform_size, NttPlan};
+use crate::commons::crypto::bootstrap::{
+    pbs_modulus_switch, pbs_stochastic_modulus_switch, StandardBootstrapKey,
+};
+use crate::commons::crypto::glwe::GlweCiphertext;
+use crate::commons::crypto::lwe::{LweCiphertext, LweList};
+use crate::commons::crypto::secret::generators::{
+    EncryptionRandomGenerator, SecretRandomGenerator,
+};
+use crate::commons::math::polynomial::MonomialDegree;
+use crate::commons::math::random::{ByteRandomGenerator, RandomGenerator};
+use crate::commons::math::tensor::{
+    ck_dim_div, ck_dim_eq, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, IntoTensor, Tensor,
+};
+use crate::commons::math::torus::UnsignedTorus;
+use concrete_commons::dispersion::DispersionParameter;
+use concrete_commons::numeric::{CastFrom, CastInto, Numeric};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweDimension, PolynomialSize,
+};
+use std::marker::PhantomData;
+
+/// The buffers used by the operations in the NTT domain, for a given polynomial size and GLWE
+/// size.
+pub struct NttBuffers<Scalar> {
+    /// The plan used to compute the transforms.
+    pub plan: NttPlan,
+    /// A buffer holding the transform of a polynomial with small coefficients.
+    pub term: Vec<u64>,
+    /// A buffer holding the transforms of the limbs of a torus polynomial.
+    pub polynomial: Vec<u64>,
+    /// A buffer holding the transforms of the limbs of a GLWE ciphertext.
+    pub accumulator: Vec<u64>,
+    _scalar: PhantomData<Scalar>,
+}
+
+impl<Scalar: Numeric> NttBuffers<Scalar> {
+    /// Allocates the buffers and the plan needed for the given polynomial size and GLWE size.
+    pub fn for_params(poly_size: PolynomialSize, glwe_size: GlweSize) -> Self {
+        let plan = NttPlan::new(poly_size);
+        let poly_len = limb_count::<Scalar>() * plan.transform_size();
+        NttBuffers {
+            term: vec![0; plan.transform_size()],
+            polynomial: vec![0; poly_len],
+            accumulator: vec![0; glwe_size.0 * poly_len],
+            plan,
+            _scalar: PhantomData,
+        }
+    }
+}
+
+/// A bootstrapping key in the NTT domain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NttBootstrapKey<Cont, Scalar> {
+    tensor: Tensor<Cont>,
+    poly_size: PolynomialSize,
+    glwe_size: GlweSize,
+    decomp_level: DecompositionLevelCount,
+    decomp_base_log: DecompositionBaseLog,
+    _scalar: PhantomData<Scalar>,
+}
+
+impl<Scalar> NttBootstrapKey<Vec<u64>, Scalar>
+where
+    Scalar: UnsignedTorus,
+{
+    /// Allocates a new bootstrapping key in the NTT domain whose coefficients are all zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::ntt::private::crypto::bootstrap::NttBootstrapKey;
+    /// let bsk: NttBootstrapKey<_, u32> = NttBootstrapKey::allocate(
+    ///     GlweSize(7),
+    ///     PolynomialSize(9),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(5),
+    ///     LweDimension(4),
+    /// );
+    /// assert_eq!(bsk.polynomial_size(), PolynomialSize(9));
+    /// assert_eq!(bsk.glwe_size(), GlweSize(7));
+    /// assert_eq!(bsk.level_count(), DecompositionLevelCount(3));
+    /// assert_eq!(bsk.base_log(), DecompositionBaseLog(5));
+    /// assert_eq!(bsk.key_size(), LweDimension(4));
+    /// ```
+    pub fn allocate(
+        glwe_size: GlweSize,
+        poly_size: PolynomialSize,
+        decomp_level: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+        key_size: LweDimension,
+    ) -> Self {
+        let poly_len = limb_count::<Scalar>() * transform_size(poly_size);
+        NttBootstrapKey {
+            tensor: Tensor::from_container(vec![
+                0;
+                key_size.0
+                    * decomp_level.0
+                    * glwe_size.0
+                    * glwe_size.0
+                    * poly_len
+            ]),
+            poly_size,
+            glwe_size,
+            decomp_level,
+            decomp_base_log,
+            _scalar: PhantomData,
+        }
+    }
+}
+
+impl<Cont, Scalar> NttBootstrapKey<Cont, Scalar>
+where
+    Scalar: UnsignedTorus + CastInto<u64> + CastFrom<u64>,
+{
+    /// Returns the size of the polynomials used in the bootstrapping key.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.poly_size
+    }
+
+    /// Returns the size of the GLWE ciphertexts used in the bootstrapping key.
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_size
+    }
+
+    /// Returns the number of levels used to decompose the key bits.
+    pub fn level_count(&self) -> DecompositionLevelCount {
+        self.decomp_level
+    }
+
+    /// Returns the logarithm of the base used to decompose the key bits.
+    pub fn base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Returns the size of the LWE encrypted key.
+    pub fn key_size(&self) -> LweDimension
+    where
+        Self: AsRefTensor,
+    {
+        let ggsw_size = self.ggsw_size();
+        ck_dim_div!(self.as_tensor().len() => ggsw_size);
+        LweDimension(self.as_tensor().len() / ggsw_size)
+    }
+
+    fn ggsw_size(&self) -> usize {
+        self.decomp_level.0
+            * self.glwe_size.0
+            * self.glwe_size.0
+            * limb_count::<Scalar>()
+            * transform_size(self.poly_size)
+    }
+
+    /// Returns an iterator over the borrowed GGSW ciphertexts composing the key.
+    pub fn ggsw_iter(&self) -> impl Iterator<Item = NttGgswCiphertext<&[u64], Scalar>>
+    where
+        Self: AsRefTensor<Element = u64>,
+    {
+        let glwe_size = self.glwe_size;
+        let poly_size = self.poly_size;
+        let base_log = self.decomp_base_log;
+        self.as_tensor()
+            .as_slice()
+            .chunks_exact(self.ggsw_size())
+            .map(move |slice| {
+                NttGgswCiphertext::from_container(slice, glwe_size, poly_size, base_log)
+            })
+    }
+
+    /// Fills the current key with the NTT transform of a standard bootstrapping key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::ntt::private::crypto::bootstrap::{
+    ///     NttBootstrapKey, NttBuffers,
+    /// };
+    /// use concrete_core::commons::crypto::bootstrap::StandardBootstrapKey;
+    ///
+    /// let bsk = StandardBootstrapKey::allocate(
+    ///     9u32,
+    ///     GlweSize(2),
+    ///     PolynomialSize(12),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(5),
+    ///     LweDimension(4),
+    /// );
+    /// let mut ntt_bsk = NttBootstrapKey::allocate(
+    ///     GlweSize(2),
+    ///     PolynomialSize(12),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(5),
+    ///     LweDimension(4),
+    /// );
+    /// let mut buffers = NttBuffers::for_params(PolynomialSize(12), GlweSize(2));
+    /// ntt_bsk.fill_with_forward_ntt(&bsk, &mut buffers);
+    /// ```
+    pub fn fill_with_forward_ntt<InputCont>(
+        &mut self,
+        bsk: &StandardBootstrapKey<InputCont>,
+        buffers: &mut NttBuffers<Scalar>,
+    ) where
+        Self: AsMutTensor<Element = u64>,
+        StandardBootstrapKey<InputCont>: AsRefTensor<Element = Scalar>,
+    {
+        ck_dim_eq!(self.key_size() => bsk.key_size());
+        let glwe_size = self.glwe_size;
+        let poly_size = self.poly_size;
+        let base_log = self.decomp_base_log;
+        let ggsw_size = self.ggsw_size();
+        for (ntt_ggsw, ggsw) in self
+            .as_mut_tensor()
+            .as_mut_slice()
+            .chunks_exact_mut(ggsw_size)
+            .zip(bsk.ggsw_iter())
+        {
+            NttGgswCiphertext::from_container(ntt_ggsw, glwe_size, poly_size, base_log)
+                .fill_with_forward_ntt(&ggsw, buffers);
+        }
+    }
+
+    /// Performs a bootstrap of an lwe ciphertext, with a given accumulator.
+    ///
+    /// The polynomial products are computed exactly, and the result is the same as the one of
+    /// [`StandardBootstrapKey::bootstrap`] with the standard key this one was transformed from.
+    pub fn bootstrap<C1, C2, C3>(
+        &self,
+        lwe_out: &mut LweCiphertext<C1>,
+        lwe_in: &LweCiphertext<C2>,
+        accumulator: &GlweCiphertext<C3>,
+        buffers: &mut NttBuffers<Scalar>,
+    ) where
+        Self: AsRefTensor<Element = u64>,
+        LweCiphertext<C1>: AsMutTensor<Element = Scalar>,
+        LweCiphertext<C2>: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<C3>: AsRefTensor<Element = Scalar>,
+    {
+        // We copy the accumulator, which is rotated in place by the blind rotation.
+        let mut local_accumulator =
+            GlweCiphertext::allocate(Scalar::ZERO, self.poly_size, self.glwe_size);
+        local_accumulator
+            .as_mut_tensor()
+            .fill_with_copy(accumulator.as_tensor());
+
+        let poly_size = self.poly_size;
+        self.blind_rotate(&mut local_accumulator, lwe_in, buffers, |coefficient| {
+            pbs_modulus_switch(coefficient, poly_size)
+        });
+
+        // We perform the extraction of the first sample.
+        local_accumulator.fill_lwe_with_sample_extraction(lwe_out, MonomialDegree(0));
+    }
+
+    /// Performs a bootstrap of an lwe ciphertext, with a given accumulator, using a stochastic
+    /// rounding in the modulus switch of the input ciphertext.
+    ///
+    /// Every coefficient of the input ciphertext is rounded up to the next multiple of q/2N with a
+    /// probability equal to its distance to the previous multiple, divided by q/2N, the
+    /// randomness being drawn from `generator`. The rounding errors then have a zero mean, and a
+    /// variance twice as large as the one of the rounding to the nearest used in
+    /// [`NttBootstrapKey::bootstrap`].
+    pub fn stochastic_bootstrap<C1, C2, C3, G>(
+        &self,
+        lwe_out: &mut LweCiphertext<C1>,
+        lwe_in: &LweCiphertext<C2>,
+        accumulator: &GlweCiphertext<C3>,
+        buffers: &mut NttBuffers<Scalar>,
+        generator: &mut RandomGenerator<G>,
+    ) where
+        Self: AsRefTensor<Element = u64>,
+        LweCiphertext<C1>: AsMutTensor<Element = Scalar>,
+        LweCiphertext<C2>: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<C3>: AsRefTensor<Element = Scalar>,
+        G: ByteRandomGenerator,
+    {
+        // We copy the accumulator, which is rotated in place by the blind rotation.
+        let mut local_accumulator =
+            GlweCiphertext::allocate(Scalar::ZERO, self.poly_size, self.glwe_size);
+        local_accumulator
+            .as_mut_tensor()
+            .fill_with_copy(accumulator.as_tensor());
+
+        let poly_size = self.poly_size;
+        self.blind_rotate(&mut local_accumulator, lwe_in, buffers, |coefficient| {
+            pbs_stochastic_modulus_switch(coefficient, poly_size, generator)
+        });
+
+        // We perform the extraction of the first sample.
+        local_accumulator.fill_lwe_with_sample_extraction(lwe_out, MonomialDegree(0));
+    }
+
+    /// Performs the sanitization of an lwe ciphertext, with a given accumulator.
+    ///
+    /// The input ciphertext is first rerandomized, by adding to it a random subset of the
+    /// encryptions of zero of `input_public_key`. It is then bootstrapped with a copy of the
+    /// accumulator whose body is noised with `accumulator_noise`. The output ciphertext is finally
+    /// rerandomized with a random subset of the encryptions of zero of `output_public_key`, and
+    /// its body is flooded with `flooding_noise`.
+    ///
+    /// The subsets are drawn from `secret_generator`, and the noises from
+    /// `encryption_generator`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn sanitize<C1, C2, C3, C4, C5, G>(
+        &self,
+        lwe_out: &mut LweCiphertext<C1>,
+        lwe_in: &LweCiphertext<C2>,
+        accumulator: &GlweCiphertext<C3>,
+        input_public_key: &LweList<C4>,
+        output_public_key: &LweList<C5>,
+        accumulator_noise: impl DispersionParameter,
+        flooding_noise: impl DispersionParameter,
+        buffers: &mut NttBuffers<Scalar>,
+        secret_generator: &mut SecretRandomGenerator<G>,
+        encryption_generator: &mut EncryptionRandomGenerator<G>,
+    ) where
+        Self: AsRefTensor<Element = u64>,
+        LweCiphertext<C1>: AsMutTensor<Element = Scalar>,
+        LweCiphertext<C2>: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<C3>: AsRefTensor<Element = Scalar>,
+        LweList<C4>: AsRefTensor<Element = Scalar>,
+        LweList<C5>: AsRefTensor<Element = Scalar>,
+        G: ByteRandomGenerator,
+    {
+        // We rerandomize a copy of the input ciphertext.
+        let mut local_lwe_in = LweCiphertext::allocate(Scalar::ZERO, lwe_in.lwe_size());
+        local_lwe_in
+            .as_mut_tensor()
+            .fill_with_copy(lwe_in.as_tensor());
+        add_random_subset_of_encryptions_of_zero(
+            &mut local_lwe_in,
+            input_public_key,
+            secret_generator,
+        );
+
+        // We copy the accumulator, and add fresh noise to its body.
+        let mut local_accumulator =
+            GlweCiphertext::allocate(Scalar::ZERO, self.poly_size, self.glwe_size);
+        local_accumulator
+            .as_mut_tensor()
+            .fill_with_copy(accumulator.as_tensor());
+        for coefficient in local_accumulator.get_mut_body().as_mut_tensor().iter_mut() {
+            let noise: Scalar = encryption_generator.random_noise(accumulator_noise);
+            *coefficient = coefficient.wrapping_add(noise);
+        }
+
+        let poly_size = self.poly_size;
+        self.blind_rotate(
+            &mut local_accumulator,
+            &local_lwe_in,
+            buffers,
+            |coefficient| pbs_modulus_switch(coefficient, poly_size),
+        );
+        local_accumulator.fill_lwe_with_sample_extraction(lwe_out, MonomialDegree(0));
+
+        // We rerandomize the output ciphertext, and flood its noise.
+        add_random_subset_of_encryptions_of_zero(lwe_out, output_public_key, secret_generator);
+        let flooding: Scalar = encryption_generator.random_noise(flooding_noise);
+        let body = lwe_out.get_mut_body();
+        body.0 = body.0.wrapping_add(flooding);
+    }
+
+    // Performs the blind rotation of `lut` by `lwe`, the coefficients of `lwe` being switched to
+    // the modulus 2N with `modulus_switch`.
+    fn blind_rotate<C, F>(
+        &self,
+        lut: &mut GlweCiphertext<Vec<Scalar>>,
+        lwe: &LweCiphertext<C>,
+        buffers: &mut NttBuffers<Scalar>,
+        mut modulus_switch: F,
+    ) where
+        Self: AsRefTensor<Element = u64>,
+        LweCiphertext<C>: AsRefTensor<Element = Scalar>,
+        F: FnMut(Scalar) -> MonomialDegree,
+    {
+        // We unpack the lwe ciphertext.
+        let (lwe_body, lwe_mask) = lwe.get_body_and_mask();
+
+        // We perform the initial clear rotation by performing lut <- lut * X^{-body_hat}
+        lut.as_mut_polynomial_list()
+            .update_with_wrapping_monic_monomial_div(modulus_switch(lwe_body.0));
+
+        // We initialize the ct_0 and ct_1 used for the successive cmuxes
+        let ct_0 = lut;
+        let mut ct_1 = GlweCiphertext::allocate(Scalar::ZERO, self.poly_size, self.glwe_size);
+
+        // We iterate over the bootstrap key elements and perform the blind rotation.
+        for (lwe_mask_element, bootstrap_key_ggsw) in
+            lwe_mask.mask_element_iter().zip(self.ggsw_iter())
+        {
+            // If the mask is zero, the cmux would leave ct_0 unchanged.
+            if *lwe_mask_element == Scalar::ZERO {
+                continue;
+            }
+            // We copy ct_0 to ct_1, and rotate ct_1 by performing ct_1 <- ct_1 * X^{a_hat}
+            ct_1.as_mut_tensor().fill_with_copy(ct_0.as_tensor());
+            ct_1.as_mut_polynomial_list()
+                .update_with_wrapping_monic_monomial_mul(modulus_switch(*lwe_mask_element));
+            // We perform the cmux.
+            bootstrap_key_ggsw.cmux(ct_0, &mut ct_1, buffers);
+        }
+    }
+}
+
+impl<Element, Cont, Scalar> AsRefTensor for NttBootstrapKey<Cont, Scalar>
+where
+    Cont: AsRefSlice<Element = Element>,
+    Scalar: UnsignedTorus,
+{
+    type Element = Element;
+    type Container = Cont;
+    fn as_tensor(&self) -> &Tensor<Self::Container> {
+        &self.tensor
+    }
+}
+
+impl<Element, Cont, Scalar> AsMutTensor for NttBootstrapKey<Cont, Scalar>
+where
+    Cont: AsMutSlice<Element = Element>,
+    Scalar: UnsignedTorus,
+{
+    type Element = Element;
+    type Container = Cont;
+    fn as_mut_tensor(&mut self) -> &mut Tensor<<Self as AsMutTensor>::Container> {
+        &mut self.tensor
+    }
+}
+
+impl<Cont, Scalar> IntoTensor for NttBootstrapKey<Cont, Scalar>
+where
+    Cont: AsRefSlice,
+    Scalar: UnsignedTorus,
+{
+    type Element = <Cont as AsRefSlice>::Element;
+    type Container = Cont;
+    fn into_tensor(self) -> Tensor<Self::Container> {
+        self.tensor
+    }
+}
+
+// Adds to `lwe` a subset of the encryptions of zero of `public_key`, each one of them being
+// selected with probability 1/2.
+fn add_random_subset_of_encryptions_of_zero<Lwe, C, Scalar, G>(
+    lwe: &mut Lwe,
+    public_key: &LweList<C>,
+    secret_generator: &mut SecretRandomGenerator<G>,
+) where
+    Lwe: AsMutTensor<Element = Scalar>,
+    LweList<C>: AsRefTensor<Element = Scalar>,
+    Scalar: UnsignedTorus,
+    G: ByteRandomGenerator,
+{
+    let choices = secret_generator.random_binary_tensor::<Scalar>(public_key.count().0);
+    for (&chosen, encryption_of_zero) in choices.iter().zip(public_key.ciphertext_iter()) {
+        if chosen == Scalar::ONE {
+            lwe.as_mut_tensor()
+                .update_with_wrapping_add(encryption_of_zero.as_tensor());
+        }
+    }
+}
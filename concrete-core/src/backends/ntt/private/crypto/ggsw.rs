@@ -0,0 +1,339 @@
+use crate::backends::ntt::private::crypto::bootstrap::NttBuffers;
+use crate::backends::ntt::private::math::ntt::{
+    limb_count, transform_size, update_with_multiply_add,
+};
+use crate::commons::crypto::ggsw::StandardGgswCiphertext;
+use crate::commons::crypto::glwe::GlweCiphertext;
+use crate::commons::math::decomposition::SignedDecomposer;
+use crate::commons::math::tensor::{
+    ck_dim_div, ck_dim_eq, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, IntoTensor, Tensor,
+};
+use crate::commons::math::torus::UnsignedTorus;
+use concrete_commons::numeric::{CastFrom, CastInto};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+};
+use std::marker::PhantomData;
+
+/// A GGSW ciphertext in the NTT domain.
+///
+/// Every polynomial of the GGSW ciphertext is split into limbs, whose forward transforms are
+/// stored one after the other (see [`NttPlan::forward_torus`]). As for the
+/// [`PreparedGgswCiphertext`], the level matrices are stored in the order in which the
+/// decomposition of a GLWE ciphertext produces its terms (from the highest level to the lowest
+/// one).
+///
+/// [`NttPlan::forward_torus`]: crate::backends::ntt::private::math::ntt::NttPlan::forward_torus
+/// [`PreparedGgswCiphertext`]: crate::commons::crypto::ggsw::PreparedGgswCiphertext
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NttGgswCiphertext<Cont, Scalar> {
+    tensor: Tensor<Cont>,
+    poly_size: PolynomialSize,
+    glwe_size: GlweSize,
+    decomp_base_log: DecompositionBaseLog,
+    _scalar: PhantomData<Scalar>,
+}
+
+impl<Scalar> NttGgswCiphertext<Vec<u64>, Scalar>
+where
+    Scalar: UnsignedTorus,
+{
+    /// Allocates a new GGSW ciphertext in the NTT domain whose coefficients are all zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::ntt::private::crypto::ggsw::NttGgswCiphertext;
+    /// let ggsw: NttGgswCiphertext<_, u32> = NttGgswCiphertext::allocate(
+    ///     PolynomialSize(10),
+    ///     GlweSize(7),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(4),
+    /// );
+    /// assert_eq!(ggsw.glwe_size(), GlweSize(7));
+    /// assert_eq!(ggsw.decomposition_level_count(), DecompositionLevelCount(3));
+    /// assert_eq!(ggsw.decomposition_base_log(), DecompositionBaseLog(4));
+    /// assert_eq!(ggsw.polynomial_size(), PolynomialSize(10));
+    /// ```
+    pub fn allocate(
+        poly_size: PolynomialSize,
+        glwe_size: GlweSize,
+        decomp_level: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+    ) -> Self {
+        let poly_len = limb_count::<Scalar>() * transform_size(poly_size);
+        NttGgswCiphertext {
+            tensor: Tensor::from_container(vec![
+                0;
+                decomp_level.0
+                    * glwe_size.0
+                    * glwe_size.0
+                    * poly_len
+            ]),
+            poly_size,
+            glwe_size,
+            decomp_base_log,
+            _scalar: PhantomData,
+        }
+    }
+}
+
+impl<Cont, Scalar> NttGgswCiphertext<Cont, Scalar>
+where
+    Scalar: UnsignedTorus + CastInto<u64> + CastFrom<u64>,
+{
+    /// Creates a GGSW ciphertext in the NTT domain from an existing container.
+    pub fn from_container(
+        cont: Cont,
+        glwe_size: GlweSize,
+        poly_size: PolynomialSize,
+        decomp_base_log: DecompositionBaseLog,
+    ) -> Self
+    where
+        Cont: AsRefSlice<Element = u64>,
+    {
+        let tensor = Tensor::from_container(cont);
+        ck_dim_div!(
+            tensor.len() =>
+            glwe_size.0 * glwe_size.0 * limb_count::<Scalar>() * transform_size(poly_size)
+        );
+        NttGgswCiphertext {
+            tensor,
+            poly_size,
+            glwe_size,
+            decomp_base_log,
+            _scalar: PhantomData,
+        }
+    }
+
+    /// Returns the size of the GLWE ciphertexts composing the GGSW ciphertext.
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_size
+    }
+
+    /// Returns the size of the polynomials used in the ciphertext.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.poly_size
+    }
+
+    /// Returns the logarithm of the base used for the gadget decomposition.
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Returns the number of decomposition levels used in the ciphertext.
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount
+    where
+        Self: AsRefTensor,
+    {
+        let level_size = self.level_size();
+        ck_dim_div!(self.as_tensor().len() => level_size);
+        DecompositionLevelCount(self.as_tensor().len() / level_size)
+    }
+
+    fn poly_len(&self) -> usize {
+        limb_count::<Scalar>() * transform_size(self.poly_size)
+    }
+
+    fn level_size(&self) -> usize {
+        self.glwe_size.0 * self.glwe_size.0 * self.poly_len()
+    }
+
+    /// Fills the current GGSW ciphertext with the NTT transform of a standard GGSW ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::ntt::private::crypto::bootstrap::NttBuffers;
+    /// use concrete_core::backends::ntt::private::crypto::ggsw::NttGgswCiphertext;
+    /// use concrete_core::commons::crypto::encoding::Plaintext;
+    /// use concrete_core::commons::crypto::ggsw::StandardGgswCiphertext;
+    ///
+    /// let ggsw = StandardGgswCiphertext::new_trivial_encryption(
+    ///     PolynomialSize(12),
+    ///     GlweSize(2),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(8),
+    ///     &Plaintext(1_u32),
+    /// );
+    /// let mut ntt_ggsw = NttGgswCiphertext::allocate(
+    ///     PolynomialSize(12),
+    ///     GlweSize(2),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(8),
+    /// );
+    /// let mut buffers = NttBuffers::for_params(PolynomialSize(12), GlweSize(2));
+    /// ntt_ggsw.fill_with_forward_ntt(&ggsw, &mut buffers);
+    /// ```
+    pub fn fill_with_forward_ntt<InputCont>(
+        &mut self,
+        ggsw: &StandardGgswCiphertext<InputCont>,
+        buffers: &mut NttBuffers<Scalar>,
+    ) where
+        Self: AsMutTensor<Element = u64>,
+        StandardGgswCiphertext<InputCont>: AsRefTensor<Element = Scalar>,
+    {
+        ck_dim_eq!(self.poly_size => ggsw.polynomial_size(), buffers.plan.polynomial_size());
+        ck_dim_eq!(self.glwe_size => ggsw.glwe_size());
+        ck_dim_eq!(self.decomposition_level_count() => ggsw.decomposition_level_count());
+        ck_dim_eq!(self.decomp_base_log => ggsw.decomposition_base_log());
+
+        let poly_size = self.poly_size.0;
+        let poly_len = self.poly_len();
+        let level_size = self.level_size();
+        let standard_level_size = self.glwe_size.0 * self.glwe_size.0 * poly_size;
+        // The levels are reversed to match the order of the decomposition iterator.
+        for (ntt_level, level) in self
+            .as_mut_tensor()
+            .as_mut_slice()
+            .chunks_exact_mut(level_size)
+            .zip(
+                ggsw.as_tensor()
+                    .as_slice()
+                    .chunks_exact(standard_level_size)
+                    .rev(),
+            )
+        {
+            for (ntt_poly, poly) in ntt_level
+                .chunks_exact_mut(poly_len)
+                .zip(level.chunks_exact(poly_size))
+            {
+                buffers.plan.forward_torus(ntt_poly, poly);
+            }
+        }
+    }
+
+    /// Computes the external product between the current GGSW ciphertext and a GLWE ciphertext,
+    /// and adds the result to the `output` GLWE ciphertext.
+    ///
+    /// The products are computed exactly, and the result is the same as the one of
+    /// [`StandardGgswCiphertext::external_product`] with the standard GGSW ciphertext this one was
+    /// transformed from.
+    pub fn external_product<C1, C2>(
+        &self,
+        output: &mut GlweCiphertext<C1>,
+        glwe: &GlweCiphertext<C2>,
+        buffers: &mut NttBuffers<Scalar>,
+    ) where
+        Self: AsRefTensor<Element = u64>,
+        GlweCiphertext<C1>: AsMutTensor<Element = Scalar>,
+        GlweCiphertext<C2>: AsRefTensor<Element = Scalar>,
+    {
+        ck_dim_eq!(
+            self.poly_size =>
+            glwe.polynomial_size(),
+            output.polynomial_size(),
+            buffers.plan.polynomial_size()
+        );
+        ck_dim_eq!(
+            self.glwe_size =>
+            glwe.size(),
+            output.size()
+        );
+
+        let NttBuffers {
+            plan,
+            term,
+            accumulator,
+            ..
+        } = buffers;
+        let poly_size = self.poly_size.0;
+        let poly_len = self.poly_len();
+        let ntt_size = plan.transform_size();
+        let accumulator = &mut accumulator[..self.glwe_size.0 * poly_len];
+        accumulator.fill(0);
+
+        let decomposer =
+            SignedDecomposer::new(self.decomp_base_log, self.decomposition_level_count());
+        let mut decomposition = decomposer.decompose_tensor(glwe);
+        for ntt_level in self.as_tensor().as_slice().chunks_exact(self.level_size()) {
+            let glwe_decomp_term = decomposition.next_term().unwrap();
+            // Every row of the level matrix is multiplied by the corresponding polynomial of the
+            // glwe decomposition, and the result is accumulated in the NTT domain.
+            for (ntt_row, glwe_poly) in ntt_level.chunks_exact(self.glwe_size.0 * poly_len).zip(
+                glwe_decomp_term
+                    .as_tensor()
+                    .as_slice()
+                    .chunks_exact(poly_size),
+            ) {
+                plan.forward_signed(term, glwe_poly);
+                for (ntt_limb, accumulator_limb) in ntt_row
+                    .chunks_exact(ntt_size)
+                    .zip(accumulator.chunks_exact_mut(ntt_size))
+                {
+                    update_with_multiply_add(accumulator_limb, term, ntt_limb);
+                }
+            }
+        }
+
+        for (output_poly, accumulator_poly) in output
+            .as_mut_tensor()
+            .as_mut_slice()
+            .chunks_exact_mut(poly_size)
+            .zip(accumulator.chunks_exact_mut(poly_len))
+        {
+            plan.add_backward_torus(output_poly, accumulator_poly);
+        }
+    }
+
+    /// Computes a CMUX between two GLWE ciphertexts, using the current GGSW ciphertext as
+    /// selector.
+    ///
+    /// The result is written in `ct0`, and `ct1` is left with the difference `ct1 - ct0`.
+    pub fn cmux<C0, C1>(
+        &self,
+        ct0: &mut GlweCiphertext<C0>,
+        ct1: &mut GlweCiphertext<C1>,
+        buffers: &mut NttBuffers<Scalar>,
+    ) where
+        Self: AsRefTensor<Element = u64>,
+        GlweCiphertext<C0>: AsMutTensor<Element = Scalar>,
+        GlweCiphertext<C1>: AsMutTensor<Element = Scalar>,
+    {
+        ct1.as_mut_tensor()
+            .update_with_wrapping_sub(ct0.as_tensor());
+        self.external_product(ct0, ct1, buffers);
+    }
+}
+
+impl<Element, Cont, Scalar> AsRefTensor for NttGgswCiphertext<Cont, Scalar>
+where
+    Cont: AsRefSlice<Element = Element>,
+    Scalar: UnsignedTorus,
+{
+    type Element = Element;
+    type Container = Cont;
+    fn as_tensor(&self) -> &Tensor<Self::Container> {
+        &self.tensor
+    }
+}
+
+impl<Element, Cont, Scalar> AsMutTensor for NttGgswCiphertext<Cont, Scalar>
+where
+    Cont: AsMutSlice<Element = Element>,
+    Scalar: UnsignedTorus,
+{
+    type Element = Element;
+    type Container = Cont;
+    fn as_mut_tensor(&mut self) -> &mut Tensor<<Self as AsMutTensor>::Container> {
+        &mut self.tensor
+    }
+}
+
+impl<Cont, Scalar> IntoTensor for NttGgswCiphertext<Cont, Scalar>
+where
+    Cont: AsRefSlice,
+    Scalar: UnsignedTorus,
+{
+    type Element = <Cont as AsRefSlice>::Element;
+    type Container = Cont;
+    fn into_tensor(self) -> Tensor<Self::Container> {
+        self.tensor
+    }
+}
@@ -0,0 +1,74 @@
+use crate::backends::ntt::private::crypto::bootstrap::NttBuffers;
+use crate::backends::ntt::private::math::ntt::update_with_multiply_add;
+use crate::commons::crypto::encoding::PlaintextList;
+use crate::commons::crypto::glwe::GlweCiphertext;
+use crate::commons::crypto::secret::generators::EncryptionRandomGenerator;
+use crate::commons::crypto::secret::GlweSecretKey;
+use crate::commons::math::random::ByteRandomGenerator;
+use crate::commons::math::tensor::{ck_dim_eq, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor};
+use crate::commons::math::torus::UnsignedTorus;
+use concrete_commons::dispersion::DispersionParameter;
+use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_commons::numeric::{CastFrom, CastInto};
+
+/// Encrypts a single GLWE ciphertext, computing the products between the mask and the secret key
+/// in the NTT domain.
+///
+/// The random mask and noise are drawn in the same order as in [`GlweSecretKey::encrypt_glwe`],
+/// so that the two functions output the same ciphertext when given generators in the same state.
+pub fn encrypt_glwe<KeyCont, OutputCont, InputCont, Scalar, Gen>(
+    key: &GlweSecretKey<BinaryKeyKind, KeyCont>,
+    encrypted: &mut GlweCiphertext<OutputCont>,
+    encoded: &PlaintextList<InputCont>,
+    noise_parameter: impl DispersionParameter,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+    buffers: &mut NttBuffers<Scalar>,
+) where
+    GlweSecretKey<BinaryKeyKind, KeyCont>: AsRefTensor<Element = Scalar>,
+    GlweCiphertext<OutputCont>: AsMutTensor<Element = Scalar>,
+    PlaintextList<InputCont>: AsRefTensor<Element = Scalar>,
+    Scalar: UnsignedTorus + CastInto<u64> + CastFrom<u64>,
+    Gen: ByteRandomGenerator,
+{
+    ck_dim_eq!(encoded.count().0 => encrypted.polynomial_size().0);
+    ck_dim_eq!(encrypted.mask_size().0 => key.key_size().0);
+    ck_dim_eq!(key.polynomial_size() => buffers.plan.polynomial_size());
+
+    let NttBuffers {
+        plan,
+        term,
+        polynomial,
+        accumulator,
+        ..
+    } = buffers;
+    let poly_size = key.polynomial_size().0;
+    let ntt_size = plan.transform_size();
+    let accumulator = &mut accumulator[..polynomial.len()];
+    accumulator.fill(0);
+
+    let (mut body, mut mask) = encrypted.get_mut_body_and_mask();
+    generator.fill_tensor_with_random_noise(&mut body, noise_parameter);
+    generator.fill_tensor_with_random_mask(&mut mask);
+
+    for (mask_poly, key_poly) in mask
+        .as_tensor()
+        .as_slice()
+        .chunks_exact(poly_size)
+        .zip(key.as_tensor().as_slice().chunks_exact(poly_size))
+    {
+        plan.forward_signed(term, key_poly);
+        plan.forward_torus(polynomial, mask_poly);
+        for (mask_limb, accumulator_limb) in polynomial
+            .chunks_exact(ntt_size)
+            .zip(accumulator.chunks_exact_mut(ntt_size))
+        {
+            update_with_multiply_add(accumulator_limb, term, mask_limb);
+        }
+    }
+
+    let body = body.as_mut_tensor().as_mut_slice();
+    plan.add_backward_torus(body, accumulator);
+    for (body_coef, encoded_coef) in body.iter_mut().zip(encoded.as_tensor().iter()) {
+        *body_coef = body_coef.wrapping_add(*encoded_coef);
+    }
+}
@@ -0,0 +1,8 @@
+//! Homomorphic primitives computed in the NTT domain.
+
+pub mod bootstrap;
+pub mod ggsw;
+pub mod glwe;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,296 @@
+use crate::backends::ntt::private::crypto::bootstrap::{NttBootstrapKey, NttBuffers};
+use crate::backends::ntt::private::crypto::ggsw::NttGgswCiphertext;
+use crate::backends::ntt::private::crypto::glwe::encrypt_glwe;
+use crate::commons::crypto::bootstrap::{pbs_stochastic_modulus_switch, StandardBootstrapKey};
+use crate::commons::crypto::encoding::PlaintextList;
+use crate::commons::crypto::ggsw::StandardGgswCiphertext;
+use crate::commons::crypto::glwe::GlweCiphertext;
+use crate::commons::crypto::lwe::{LweCiphertext, LweList};
+use crate::commons::crypto::secret::generators::{DeterministicSeeder, EncryptionRandomGenerator};
+use crate::commons::crypto::secret::GlweSecretKey;
+use crate::commons::math::random::{RandomGenerable, Seeder, Uniform};
+use crate::commons::math::tensor::AsRefTensor;
+use crate::commons::math::torus::UnsignedTorus;
+use crate::commons::test_tools;
+use concrete_commons::dispersion::{LogStandardDev, Variance};
+use concrete_commons::numeric::{CastFrom, CastInto};
+use concrete_commons::parameters::{
+    CiphertextCount, DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    LweSize, PolynomialSize,
+};
+use concrete_csprng::generators::SoftwareRandomGenerator;
+
+// A power-of-two size, and sizes which need to be zero-padded.
+const SIZES: [usize; 3] = [16, 12, 240];
+
+fn test_external_product<T>()
+where
+    T: UnsignedTorus + RandomGenerable<Uniform> + CastInto<u64> + CastFrom<u64>,
+{
+    let mut generator = test_tools::new_random_generator();
+    for size in SIZES {
+        let poly_size = PolynomialSize(size);
+        let glwe_size = test_tools::random_glwe_dimension(3).to_glwe_size();
+        let level = DecompositionLevelCount(3);
+        let base_log = DecompositionBaseLog(7);
+
+        let mut ggsw =
+            StandardGgswCiphertext::allocate(T::ZERO, poly_size, glwe_size, level, base_log);
+        generator.fill_tensor_with_random_uniform(&mut ggsw);
+        let mut glwe = GlweCiphertext::allocate(T::ZERO, poly_size, glwe_size);
+        generator.fill_tensor_with_random_uniform(&mut glwe);
+
+        let mut expected = GlweCiphertext::allocate(T::ZERO, poly_size, glwe_size);
+        generator.fill_tensor_with_random_uniform(&mut expected);
+        let mut output = expected.clone();
+        ggsw.external_product(&mut expected, &glwe);
+
+        let mut buffers = NttBuffers::for_params(poly_size, glwe_size);
+        let mut ntt_ggsw = NttGgswCiphertext::allocate(poly_size, glwe_size, level, base_log);
+        ntt_ggsw.fill_with_forward_ntt(&ggsw, &mut buffers);
+        ntt_ggsw.external_product(&mut output, &glwe, &mut buffers);
+
+        assert_eq!(output, expected);
+    }
+}
+
+#[test]
+fn test_external_product_u32() {
+    test_external_product::<u32>()
+}
+
+#[test]
+fn test_external_product_u64() {
+    test_external_product::<u64>()
+}
+
+fn test_bootstrap<T>()
+where
+    T: UnsignedTorus + RandomGenerable<Uniform> + CastInto<u64> + CastFrom<u64>,
+{
+    let mut generator = test_tools::new_random_generator();
+    for size in SIZES {
+        let poly_size = PolynomialSize(size);
+        let glwe_dimension = GlweDimension(1);
+        let lwe_dimension = LweDimension(10);
+        let level = DecompositionLevelCount(3);
+        let base_log = DecompositionBaseLog(7);
+
+        let mut bsk = StandardBootstrapKey::allocate(
+            T::ZERO,
+            glwe_dimension.to_glwe_size(),
+            poly_size,
+            level,
+            base_log,
+            lwe_dimension,
+        );
+        generator.fill_tensor_with_random_uniform(&mut bsk);
+        let mut lwe_in = LweCiphertext::allocate(T::ZERO, lwe_dimension.to_lwe_size());
+        generator.fill_tensor_with_random_uniform(&mut lwe_in);
+        let mut accumulator =
+            GlweCiphertext::allocate(T::ZERO, poly_size, glwe_dimension.to_glwe_size());
+        generator.fill_tensor_with_random_uniform(&mut accumulator);
+
+        let output_size = LweSize(glwe_dimension.0 * poly_size.0 + 1);
+        let mut expected = LweCiphertext::allocate(T::ZERO, output_size);
+        bsk.bootstrap(&mut expected, &lwe_in, &accumulator);
+
+        let mut buffers = NttBuffers::for_params(poly_size, glwe_dimension.to_glwe_size());
+        let mut ntt_bsk = NttBootstrapKey::allocate(
+            glwe_dimension.to_glwe_size(),
+            poly_size,
+            level,
+            base_log,
+            lwe_dimension,
+        );
+        ntt_bsk.fill_with_forward_ntt(&bsk, &mut buffers);
+        let mut output = LweCiphertext::allocate(T::ZERO, output_size);
+        ntt_bsk.bootstrap(&mut output, &lwe_in, &accumulator, &mut buffers);
+
+        assert_eq!(output.as_tensor(), expected.as_tensor());
+    }
+}
+
+#[test]
+fn test_bootstrap_u32() {
+    test_bootstrap::<u32>()
+}
+
+#[test]
+fn test_bootstrap_u64() {
+    test_bootstrap::<u64>()
+}
+
+// With public keys made of trivial encryptions of zero and null noises, the sanitization is a
+// plain bootstrap.
+fn test_sanitize_without_randomness<T>()
+where
+    T: UnsignedTorus + RandomGenerable<Uniform> + CastInto<u64> + CastFrom<u64>,
+{
+    let mut generator = test_tools::new_random_generator();
+    let mut secret_generator = test_tools::new_secret_random_generator();
+    for size in SIZES {
+        let poly_size = PolynomialSize(size);
+        let glwe_dimension = GlweDimension(1);
+        let lwe_dimension = LweDimension(10);
+        let level = DecompositionLevelCount(3);
+        let base_log = DecompositionBaseLog(7);
+
+        let mut bsk = StandardBootstrapKey::allocate(
+            T::ZERO,
+            glwe_dimension.to_glwe_size(),
+            poly_size,
+            level,
+            base_log,
+            lwe_dimension,
+        );
+        generator.fill_tensor_with_random_uniform(&mut bsk);
+        let mut lwe_in = LweCiphertext::allocate(T::ZERO, lwe_dimension.to_lwe_size());
+        generator.fill_tensor_with_random_uniform(&mut lwe_in);
+        let mut accumulator =
+            GlweCiphertext::allocate(T::ZERO, poly_size, glwe_dimension.to_glwe_size());
+        generator.fill_tensor_with_random_uniform(&mut accumulator);
+
+        let output_size = LweSize(glwe_dimension.0 * poly_size.0 + 1);
+        let mut expected = LweCiphertext::allocate(T::ZERO, output_size);
+        bsk.bootstrap(&mut expected, &lwe_in, &accumulator);
+
+        let input_public_key =
+            LweList::allocate(T::ZERO, lwe_dimension.to_lwe_size(), CiphertextCount(8));
+        let output_public_key = LweList::allocate(T::ZERO, output_size, CiphertextCount(8));
+        let mut encryption_generator = test_tools::new_encryption_random_generator();
+
+        let mut buffers = NttBuffers::for_params(poly_size, glwe_dimension.to_glwe_size());
+        let mut ntt_bsk = NttBootstrapKey::allocate(
+            glwe_dimension.to_glwe_size(),
+            poly_size,
+            level,
+            base_log,
+            lwe_dimension,
+        );
+        ntt_bsk.fill_with_forward_ntt(&bsk, &mut buffers);
+        let mut output = LweCiphertext::allocate(T::ZERO, output_size);
+        ntt_bsk.sanitize(
+            &mut output,
+            &lwe_in,
+            &accumulator,
+            &input_public_key,
+            &output_public_key,
+            Variance(0.),
+            Variance(0.),
+            &mut buffers,
+            &mut secret_generator,
+            &mut encryption_generator,
+        );
+
+        assert_eq!(output.as_tensor(), expected.as_tensor());
+    }
+}
+
+#[test]
+fn test_sanitize_without_randomness_u32() {
+    test_sanitize_without_randomness::<u32>()
+}
+
+#[test]
+fn test_sanitize_without_randomness_u64() {
+    test_sanitize_without_randomness::<u64>()
+}
+
+fn test_encrypt_glwe<T>()
+where
+    T: UnsignedTorus + RandomGenerable<Uniform> + CastInto<u64> + CastFrom<u64>,
+{
+    let mut secret_generator = test_tools::new_secret_random_generator();
+    for size in SIZES {
+        let poly_size = PolynomialSize(size);
+        let glwe_dimension = test_tools::random_glwe_dimension(3);
+        let noise = LogStandardDev::from_log_standard_dev(-25.);
+        let key = GlweSecretKey::generate_binary(glwe_dimension, poly_size, &mut secret_generator);
+        let plaintexts = PlaintextList::from_tensor(secret_generator.random_uniform_tensor(size));
+
+        // Both encryptions use generators in the same state.
+        let seed = test_tools::random_seed();
+        let new_generator = || {
+            let mut seeder = DeterministicSeeder::<SoftwareRandomGenerator>::new(seed);
+            EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(seeder.seed(), &mut seeder)
+        };
+
+        let mut expected =
+            GlweCiphertext::allocate(T::ZERO, poly_size, glwe_dimension.to_glwe_size());
+        key.encrypt_glwe(&mut expected, &plaintexts, noise, &mut new_generator());
+
+        let mut buffers = NttBuffers::for_params(poly_size, glwe_dimension.to_glwe_size());
+        let mut output =
+            GlweCiphertext::allocate(T::ZERO, poly_size, glwe_dimension.to_glwe_size());
+        encrypt_glwe(
+            &key,
+            &mut output,
+            &plaintexts,
+            noise,
+            &mut new_generator(),
+            &mut buffers,
+        );
+
+        assert_eq!(output, expected);
+    }
+}
+
+#[test]
+fn test_encrypt_glwe_u32() {
+    test_encrypt_glwe::<u32>()
+}
+
+#[test]
+fn test_encrypt_glwe_u64() {
+    test_encrypt_glwe::<u64>()
+}
+
+fn test_stochastic_modulus_switch<T>()
+where
+    T: UnsignedTorus + RandomGenerable<Uniform>,
+{
+    let mut generator = test_tools::new_random_generator();
+    for size in SIZES {
+        let poly_size = PolynomialSize(size);
+        let modulus = 2 * size;
+        let input: T = generator.random_uniform();
+        let scaled = input.into_torus() * modulus as f64;
+        let floor = scaled.floor() as usize;
+
+        // Every switched value is one of the two closest integers, the upper one being drawn with
+        // a probability equal to the fractional part.
+        let draws = 10_000;
+        let mut rounded_up = 0;
+        for _ in 0..draws {
+            let switched = pbs_stochastic_modulus_switch(input, poly_size, &mut generator);
+            match (switched.0 + modulus - floor) % modulus {
+                0 => {}
+                1 => rounded_up += 1,
+                _ => panic!("The switched value is not one of the two closest integers."),
+            }
+        }
+        let frequency = rounded_up as f64 / draws as f64;
+        assert!((frequency - (scaled - scaled.floor())).abs() < 0.05);
+
+        // When 2N divides q, the multiples of q / 2N are never rounded.
+        if !size.is_power_of_two() {
+            continue;
+        }
+        let exact = T::from_torus(3. / modulus as f64);
+        for _ in 0..100 {
+            let switched = pbs_stochastic_modulus_switch(exact, poly_size, &mut generator);
+            assert_eq!(switched.0, 3);
+        }
+    }
+}
+
+#[test]
+fn test_stochastic_modulus_switch_u32() {
+    test_stochastic_modulus_switch::<u32>()
+}
+
+#[test]
+fn test_stochastic_modulus_switch_u64() {
+    test_stochastic_modulus_switch::<u64>()
+}
@@ -0,0 +1,3 @@
+//! A module containing general mathematical tools.
+
+pub mod ntt;
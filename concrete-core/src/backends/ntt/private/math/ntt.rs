@@ -0,0 +1,289 @@
+//! Negacyclic number-theoretic transforms over the Solinas prime.
+//!
+//! The polynomial products of the backend are computed exactly, in $\mathbb{Z}\_q$ for the
+//! [`SolinasPrime64`] modulus $q = 2^{64} - 2^{32} + 1$. Since $2^{32}$ divides $q - 1$, the field
+//! contains the $2M$-th roots of unity needed by a negacyclic transform of size $M$, for any power
+//! of two $M \leq 2^{31}$.
+//!
+//! + When the polynomial size $N$ is a power of two, the products in $\mathbb{Z}\_q\[X\] / (X^N +
+//!   1)$ are computed with a negacyclic transform of size $M = N$.
+//! + Otherwise, the polynomials are zero-padded to the smallest power of two $M \geq 2N$. The
+//!   negacyclic product of size $M$ is then the plain product of the polynomials, which is reduced
+//!   modulo $X^N + 1$ after the backward transform.
+//!
+//! The torus coefficients can not be multiplied exactly in $\mathbb{Z}\_q$, as the products of a
+//! 64 bits coefficient by a decomposition term would overflow the modulus. They are split into
+//! limbs of [`LIMB_BITS`] bits, which are transformed and multiplied separately, and recombined
+//! modulo $2^w$ after the backward transform. The products are exact as long as their coefficients
+//! stay below $q / 2$ in absolute value, which is checked by [`products_are_exact`].
+use crate::commons::math::modular::{NonNativeModulus, SolinasPrime64};
+use crate::commons::math::torus::UnsignedTorus;
+use concrete_commons::numeric::{CastFrom, CastInto, Numeric};
+use concrete_commons::parameters::PolynomialSize;
+
+#[cfg(test)]
+mod tests;
+
+/// The number of bits of the limbs the torus coefficients are split into.
+pub const LIMB_BITS: usize = 16;
+
+/// A generator of the multiplicative group of $\mathbb{Z}\_q$.
+const GENERATOR: u64 = 7;
+
+/// The largest transform size supported by the modulus.
+const MAX_TRANSFORM_SIZE: usize = 1 << 31;
+
+/// Returns the number of limbs a torus coefficient of type `Scalar` is split into.
+pub fn limb_count<Scalar: Numeric>() -> usize {
+    Scalar::BITS / LIMB_BITS
+}
+
+/// Returns the size of the transform used for polynomials of a given size.
+pub fn transform_size(polynomial_size: PolynomialSize) -> usize {
+    if polynomial_size.0.is_power_of_two() {
+        polynomial_size.0
+    } else {
+        (2 * polynomial_size.0).next_power_of_two()
+    }
+}
+
+/// Returns whether polynomials of a given size can be multiplied by the backend.
+pub fn is_supported_size(polynomial_size: PolynomialSize) -> bool {
+    polynomial_size.0 > 0 && transform_size(polynomial_size) <= MAX_TRANSFORM_SIZE
+}
+
+/// Returns whether the sum of `product_count` products between polynomials of size
+/// `polynomial_size`, whose left coefficients are bounded by $2^{\mathsf{small\\_bits}}$ in
+/// absolute value, and whose right coefficients are torus limbs, is computed exactly.
+pub fn products_are_exact(
+    polynomial_size: PolynomialSize,
+    product_count: usize,
+    small_bits: usize,
+) -> bool {
+    let log2_ceil = |value: usize| value.next_power_of_two().trailing_zeros() as usize;
+    // Every coefficient of the products is a sum of at most N terms, each of them being smaller
+    // than 2^(small_bits + LIMB_BITS). This must stay below q / 2 > 2^62.
+    log2_ceil(product_count) + log2_ceil(polynomial_size.0) + small_bits + LIMB_BITS <= 62
+}
+
+fn pow(base: u64, mut exponent: u64) -> u64 {
+    let modulus = SolinasPrime64;
+    let mut output = 1;
+    let mut power = base;
+    while exponent != 0 {
+        if exponent & 1 == 1 {
+            output = modulus.mul(output, power);
+        }
+        power = modulus.mul(power, power);
+        exponent >>= 1;
+    }
+    output
+}
+
+fn bit_reverse(value: usize, bit_count: usize) -> usize {
+    if bit_count == 0 {
+        0
+    } else {
+        value.reverse_bits() >> (usize::BITS as usize - bit_count)
+    }
+}
+
+/// A plan to compute the negacyclic number-theoretic transforms of the polynomials of a given size.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NttPlan {
+    polynomial_size: PolynomialSize,
+    // The powers psi^bitrev(i) of a primitive 2M-th root of unity psi.
+    twiddles: Vec<u64>,
+    // The powers psi^(-bitrev(i)).
+    inverse_twiddles: Vec<u64>,
+    // The inverse of M modulo q.
+    size_inverse: u64,
+}
+
+impl NttPlan {
+    /// Creates a plan for polynomials of the given size.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the polynomial size is not supported, see [`is_supported_size`].
+    pub fn new(polynomial_size: PolynomialSize) -> Self {
+        assert!(is_supported_size(polynomial_size));
+        let modulus = SolinasPrime64;
+        let size = transform_size(polynomial_size);
+        let log_size = size.trailing_zeros() as usize;
+
+        let psi = pow(GENERATOR, (modulus.value() - 1) / (2 * size as u64));
+        let psi_inverse = pow(psi, modulus.value() - 2);
+        let powers = |root: u64| {
+            let mut powers = Vec::with_capacity(size);
+            let mut power = 1;
+            for _ in 0..size {
+                powers.push(power);
+                power = modulus.mul(power, root);
+            }
+            (0..size)
+                .map(|i| powers[bit_reverse(i, log_size)])
+                .collect::<Vec<_>>()
+        };
+
+        NttPlan {
+            polynomial_size,
+            twiddles: powers(psi),
+            inverse_twiddles: powers(psi_inverse),
+            size_inverse: pow(size as u64, modulus.value() - 2),
+        }
+    }
+
+    /// Returns the size of the polynomials the plan applies to.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    /// Returns the number of coefficients of the transformed polynomials.
+    pub fn transform_size(&self) -> usize {
+        self.twiddles.len()
+    }
+
+    /// Computes the forward transform of a polynomial in place.
+    ///
+    /// The input coefficients must be reduced modulo $q$, and are given in the natural order. The
+    /// output coefficients are in the bit-reversed order.
+    pub fn forward_in_place(&self, data: &mut [u64]) {
+        debug_assert_eq!(data.len(), self.transform_size());
+        let modulus = SolinasPrime64;
+        let size = data.len();
+        let mut t = size;
+        let mut m = 1;
+        while m < size {
+            t /= 2;
+            for i in 0..m {
+                let twiddle = self.twiddles[m + i];
+                let (lo, hi) = data[2 * i * t..2 * (i + 1) * t].split_at_mut(t);
+                for (u, v) in lo.iter_mut().zip(hi.iter_mut()) {
+                    let w = modulus.mul(*v, twiddle);
+                    *v = modulus.sub(*u, w);
+                    *u = modulus.add(*u, w);
+                }
+            }
+            m *= 2;
+        }
+    }
+
+    /// Computes the backward transform of a polynomial in place.
+    ///
+    /// The input coefficients are given in the bit-reversed order, and the output coefficients are
+    /// in the natural order.
+    pub fn backward_in_place(&self, data: &mut [u64]) {
+        debug_assert_eq!(data.len(), self.transform_size());
+        let modulus = SolinasPrime64;
+        let size = data.len();
+        let mut t = 1;
+        let mut m = size;
+        while m > 1 {
+            let h = m / 2;
+            for i in 0..h {
+                let twiddle = self.inverse_twiddles[h + i];
+                let (lo, hi) = data[2 * i * t..2 * (i + 1) * t].split_at_mut(t);
+                for (u, v) in lo.iter_mut().zip(hi.iter_mut()) {
+                    let (a, b) = (*u, *v);
+                    *u = modulus.add(a, b);
+                    *v = modulus.mul(modulus.sub(a, b), twiddle);
+                }
+            }
+            t *= 2;
+            m = h;
+        }
+        for coefficient in data.iter_mut() {
+            *coefficient = modulus.mul(*coefficient, self.size_inverse);
+        }
+    }
+
+    /// Computes the forward transforms of the limbs of a torus polynomial.
+    ///
+    /// The output contains the transforms of the limbs one after the other, from the least
+    /// significant one to the most significant one.
+    pub fn forward_torus<Scalar>(&self, output: &mut [u64], input: &[Scalar])
+    where
+        Scalar: UnsignedTorus + CastInto<u64>,
+    {
+        debug_assert_eq!(input.len(), self.polynomial_size.0);
+        debug_assert_eq!(output.len(), limb_count::<Scalar>() * self.transform_size());
+        let mask = (1 << LIMB_BITS) - 1;
+        for (limb, transformed) in output.chunks_exact_mut(self.transform_size()).enumerate() {
+            let (coefficients, padding) = transformed.split_at_mut(input.len());
+            for (output_coef, input_coef) in coefficients.iter_mut().zip(input.iter()) {
+                let input_coef: u64 = (*input_coef).cast_into();
+                *output_coef = (input_coef >> (limb * LIMB_BITS)) & mask;
+            }
+            padding.fill(0);
+            self.forward_in_place(transformed);
+        }
+    }
+
+    /// Computes the forward transform of a polynomial with small signed coefficients, stored as
+    /// their two's complement representation in `Scalar`.
+    pub fn forward_signed<Scalar>(&self, output: &mut [u64], input: &[Scalar])
+    where
+        Scalar: UnsignedTorus + CastInto<u64>,
+    {
+        debug_assert_eq!(input.len(), self.polynomial_size.0);
+        debug_assert_eq!(output.len(), self.transform_size());
+        let modulus = SolinasPrime64;
+        let (coefficients, padding) = output.split_at_mut(input.len());
+        for (output_coef, input_coef) in coefficients.iter_mut().zip(input.iter()) {
+            *output_coef = if *input_coef >> (Scalar::BITS - 1) == Scalar::ONE {
+                modulus.neg(input_coef.wrapping_neg().cast_into())
+            } else {
+                (*input_coef).cast_into()
+            };
+        }
+        padding.fill(0);
+        self.forward_in_place(output);
+    }
+
+    /// Computes the backward transforms of the limbs of a torus polynomial, and adds the
+    /// recombined polynomial to the output.
+    ///
+    /// The input is modified in place, and must contain the transforms of the limbs in the order
+    /// produced by [`NttPlan::forward_torus`].
+    pub fn add_backward_torus<Scalar>(&self, output: &mut [Scalar], input: &mut [u64])
+    where
+        Scalar: UnsignedTorus + CastFrom<u64>,
+    {
+        debug_assert_eq!(output.len(), self.polynomial_size.0);
+        debug_assert_eq!(input.len(), limb_count::<Scalar>() * self.transform_size());
+        let modulus = SolinasPrime64;
+        let size = self.polynomial_size.0;
+        let half_modulus = modulus.value() / 2;
+        for (limb, transformed) in input.chunks_exact_mut(self.transform_size()).enumerate() {
+            self.backward_in_place(transformed);
+            let (low, high) = transformed.split_at(size);
+            for (i, (output_coef, low_coef)) in output.iter_mut().zip(low.iter()).enumerate() {
+                // When the polynomials were zero-padded, the product is reduced modulo X^N + 1.
+                let coef = match high.get(i) {
+                    Some(high_coef) => modulus.sub(*low_coef, *high_coef),
+                    None => *low_coef,
+                };
+                // We lift the coefficient to a signed integer, which is exact as long as its
+                // absolute value is smaller than q / 2.
+                let lifted = if coef > half_modulus {
+                    coef.wrapping_sub(modulus.value())
+                } else {
+                    coef
+                };
+                *output_coef =
+                    output_coef.wrapping_add(Scalar::cast_from(lifted) << (limb * LIMB_BITS));
+            }
+        }
+    }
+}
+
+/// Adds the pointwise product of two transformed polynomials to an accumulator.
+pub fn update_with_multiply_add(accumulator: &mut [u64], lhs: &[u64], rhs: &[u64]) {
+    debug_assert_eq!(accumulator.len(), lhs.len());
+    debug_assert_eq!(accumulator.len(), rhs.len());
+    let modulus = SolinasPrime64;
+    for ((acc, l), r) in accumulator.iter_mut().zip(lhs.iter()).zip(rhs.iter()) {
+        *acc = modulus.add(*acc, modulus.mul(*l, *r));
+    }
+}
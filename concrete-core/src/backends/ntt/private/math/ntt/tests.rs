@@ -0,0 +1,95 @@
+use crate::backends::ntt::private::math::ntt::{
+    limb_count, products_are_exact, transform_size, update_with_multiply_add, NttPlan,
+};
+use crate::commons::math::modular::{NonNativeModulus, SolinasPrime64};
+use crate::commons::math::polynomial::Polynomial;
+use crate::commons::math::random::{RandomGenerable, Uniform};
+use crate::commons::math::tensor::{AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor};
+use crate::commons::math::torus::UnsignedTorus;
+use crate::commons::test_tools::{any_uint, random_i32_between};
+use concrete_commons::numeric::{CastFrom, CastInto};
+use concrete_commons::parameters::PolynomialSize;
+
+// Power-of-two sizes, and sizes which need to be zero-padded.
+const SIZES: [usize; 7] = [1, 2, 16, 256, 3, 12, 240];
+
+#[test]
+fn test_transform_size() {
+    assert_eq!(transform_size(PolynomialSize(1024)), 1024);
+    assert_eq!(transform_size(PolynomialSize(768)), 2048);
+    assert_eq!(transform_size(PolynomialSize(3)), 8);
+}
+
+#[test]
+fn test_forward_backward_is_identity() {
+    let modulus = SolinasPrime64;
+    for size in SIZES {
+        let plan = NttPlan::new(PolynomialSize(size));
+        let input: Vec<u64> = (0..plan.transform_size())
+            .map(|_| modulus.reduce(any_uint::<u64>() as u128))
+            .collect();
+        let mut data = input.clone();
+        plan.forward_in_place(&mut data);
+        plan.backward_in_place(&mut data);
+        assert_eq!(data, input);
+    }
+}
+
+fn test_product<T>()
+where
+    T: UnsignedTorus + RandomGenerable<Uniform> + CastInto<u64> + CastFrom<u64>,
+{
+    let base_log = 12;
+    for size in SIZES {
+        let poly_size = PolynomialSize(size);
+        let plan = NttPlan::new(poly_size);
+        let ntt_size = plan.transform_size();
+        assert!(products_are_exact(poly_size, 1, base_log - 1));
+
+        let mut torus = Polynomial::allocate(T::ZERO, poly_size);
+        torus
+            .as_mut_tensor()
+            .iter_mut()
+            .for_each(|a| *a = any_uint::<T>());
+        let mut small = Polynomial::allocate(T::ZERO, poly_size);
+        let bound = 1 << (base_log - 1);
+        small.as_mut_tensor().iter_mut().for_each(|a| {
+            *a = T::cast_from(random_i32_between(-bound..bound + 1) as i64 as u64);
+        });
+
+        let mut expected = Polynomial::allocate(T::ZERO, poly_size);
+        expected.fill_with_wrapping_mul(&torus, &small);
+
+        let mut ntt_torus = vec![0; limb_count::<T>() * ntt_size];
+        let mut ntt_small = vec![0; ntt_size];
+        let mut accumulator = vec![0; limb_count::<T>() * ntt_size];
+        plan.forward_torus(&mut ntt_torus, torus.as_tensor().as_slice());
+        plan.forward_signed(&mut ntt_small, small.as_tensor().as_slice());
+        for (acc, limb) in accumulator
+            .chunks_exact_mut(ntt_size)
+            .zip(ntt_torus.chunks_exact(ntt_size))
+        {
+            update_with_multiply_add(acc, &ntt_small, limb);
+        }
+        let mut output = Polynomial::allocate(T::ZERO, poly_size);
+        plan.add_backward_torus(output.as_mut_tensor().as_mut_slice(), &mut accumulator);
+        assert_eq!(output, expected);
+    }
+}
+
+#[test]
+fn test_product_u32() {
+    test_product::<u32>()
+}
+
+#[test]
+fn test_product_u64() {
+    test_product::<u64>()
+}
+
+#[test]
+fn test_products_are_exact() {
+    assert!(products_are_exact(PolynomialSize(2048), 6, 32));
+    assert!(!products_are_exact(PolynomialSize(2048), 6, 33));
+    assert!(!products_are_exact(PolynomialSize(1024), 1, 63));
+}
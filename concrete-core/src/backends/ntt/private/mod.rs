@@ -0,0 +1,6 @@
+#![allow(deprecated)] // For the time being
+
+pub mod crypto;
+pub mod math;
+#[cfg(feature = "backend_fft")]
+pub(crate) mod provider;
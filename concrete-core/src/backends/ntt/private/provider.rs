@@ -0,0 +1,32 @@
+//! The entities converted by one of the polynomial multiplication providers of the
+//! [`AutoNttEngine`](crate::backends::ntt::engines::AutoNttEngine).
+use crate::specification::engines::polynomial_multiplication::PolynomialMultiplicationProvider;
+
+/// An entity converted to the domain of one of the polynomial multiplication providers.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum ProviderEntity<FftEntity, NttEntity> {
+    Fft(FftEntity),
+    Ntt(NttEntity),
+}
+
+impl<FftEntity, NttEntity> ProviderEntity<FftEntity, NttEntity> {
+    /// Returns the provider the entity was converted with.
+    pub(crate) fn provider(&self) -> PolynomialMultiplicationProvider {
+        match self {
+            ProviderEntity::Fft(_) => PolynomialMultiplicationProvider::Fft,
+            ProviderEntity::Ntt(_) => PolynomialMultiplicationProvider::Ntt,
+        }
+    }
+}
+
+// Evaluates the same expression on the entity wrapped by a `ProviderEntity`, whatever the
+// provider it was converted with.
+macro_rules! dispatch {
+    ($entity: expr, $inner: ident => $body: expr) => {
+        match $entity {
+            $crate::backends::ntt::private::provider::ProviderEntity::Fft($inner) => $body,
+            $crate::backends::ntt::private::provider::ProviderEntity::Ntt($inner) => $body,
+        }
+    };
+}
+pub(crate) use dispatch;
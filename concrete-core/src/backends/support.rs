@@ -0,0 +1,155 @@
+//! A module describing which engine traits are implemented by the engines of the backends.
+//!
+//! Every backend declares the engine traits implemented by its engines, along with the entity
+//! types they are implemented for. These declarations are checked at compile time, and can be
+//! retrieved with [`implementations`], for the backends activated by the feature flags.
+//!
+//! The [`support_matrix`] function renders these declarations as a markdown table, with one row
+//! per engine trait and one column per engine:
+//! ```
+//! use concrete_core::backends::support::support_matrix;
+//!
+//! let matrix = support_matrix();
+//! # #[cfg(feature = "backend_default")]
+//! assert!(matrix.contains("| LweCiphertextEncryptionEngine |"));
+//! println!("{}", matrix);
+//! ```
+//!
+//! # Note:
+//!
+//! The implementations generic over the entity types, such as the conversions of an entity to
+//! its own type, are not declared.
+use std::collections::BTreeSet;
+use std::fmt::{Display, Formatter};
+
+/// The declaration of an engine trait implemented by an engine, for a given set of entity types.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EngineImplementation {
+    /// The name of the backend.
+    pub backend: &'static str,
+    /// The name of the engine type.
+    pub engine: &'static str,
+    /// The name of the engine trait.
+    pub engine_trait: &'static str,
+    /// The names of the entity types the engine trait is implemented for, in the order of the
+    /// generic parameters of the trait.
+    pub entities: &'static [&'static str],
+}
+
+impl Display for EngineImplementation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: {}<{}>",
+            self.engine,
+            self.engine_trait,
+            self.entities.join(", ")
+        )
+    }
+}
+
+/// Returns the engine implementations declared by the activated backends.
+pub fn implementations() -> Vec<EngineImplementation> {
+    #[allow(unused_mut)]
+    let mut implementations = Vec::new();
+    #[cfg(feature = "backend_default")]
+    implementations.extend(crate::backends::default::engines::implementations());
+    #[cfg(feature = "backend_fftw")]
+    implementations.extend(crate::backends::fftw::engines::implementations());
+    #[cfg(feature = "backend_fft")]
+    implementations.extend(crate::backends::fft::engines::implementations());
+    #[cfg(feature = "backend_ntt")]
+    implementations.extend(crate::backends::ntt::engines::implementations());
+    #[cfg(all(feature = "backend_cuda", not(feature = "_ci_do_not_compile")))]
+    implementations.extend(crate::backends::cuda::engines::implementations());
+    implementations
+}
+
+/// Returns the support matrix of the activated backends, as a markdown table.
+///
+/// The table contains one row per engine trait and one column per engine. A cell is checked when
+/// the engine implements the engine trait for at least one set of entity types.
+pub fn support_matrix() -> String {
+    let implementations = implementations();
+    let mut engines: Vec<&str> = Vec::new();
+    for implementation in implementations.iter() {
+        if !engines.contains(&implementation.engine) {
+            engines.push(implementation.engine);
+        }
+    }
+    let engine_traits: BTreeSet<&str> = implementations
+        .iter()
+        .map(|implementation| implementation.engine_trait)
+        .collect();
+
+    let mut matrix = format!("| Engine trait | {} |\n", engines.join(" | "));
+    matrix.push_str(&format!("|---|{}\n", "---|".repeat(engines.len())));
+    for engine_trait in engine_traits {
+        let cells: Vec<&str> = engines
+            .iter()
+            .map(|engine| {
+                if implementations.iter().any(|implementation| {
+                    implementation.engine == *engine && implementation.engine_trait == engine_trait
+                }) {
+                    "✓"
+                } else {
+                    ""
+                }
+            })
+            .collect();
+        matrix.push_str(&format!("| {} | {} |\n", engine_trait, cells.join(" | ")));
+    }
+    matrix
+}
+
+// Declares the engine traits implemented by the engines of a backend, by defining an
+// `implementations` function returning the declarations.
+//
+// The declarations are checked at compile time: the generated function does not compile if an
+// engine does not implement one of the traits declared for it. Lifetime parameters of the entity
+// types must be named `'data`.
+macro_rules! declare_implementations {
+    (
+        $backend: literal,
+        $(
+            $(#[$attr: meta])*
+            $engine: ident => [
+                $($engine_trait: ident<$($entity: ty),+ $(,)?>),+ $(,)?
+            ]
+        ),+ $(,)?
+    ) => {
+        /// Returns the engine implementations declared by the backend.
+        pub(crate) fn implementations() -> Vec<$crate::backends::support::EngineImplementation> {
+            use $crate::backends::support::EngineImplementation;
+            let mut implementations = Vec::new();
+            $(
+                $(#[$attr])*
+                {
+                    $(
+                        {
+                            fn check_implementation<Engine>()
+                            where
+                                for<'data> Engine: $engine_trait<$($entity),+>,
+                            {
+                            }
+                            check_implementation::<$engine>();
+                        }
+                    )+
+                    implementations.extend_from_slice(&[
+                        $(
+                            EngineImplementation {
+                                backend: $backend,
+                                engine: stringify!($engine),
+                                engine_trait: stringify!($engine_trait),
+                                entities: &[$(stringify!($entity)),+],
+                            },
+                        )+
+                    ]);
+                }
+            )+
+            implementations
+        }
+    };
+}
+#[allow(unused_imports)]
+pub(crate) use declare_implementations;
@@ -7,6 +7,8 @@ mod seeded_standard;
 mod standard;
 
 pub use seeded_standard::StandardSeededBootstrapKey;
+#[cfg(feature = "backend_ntt")]
+pub(crate) use standard::{pbs_modulus_switch, pbs_stochastic_modulus_switch};
 pub use standard::StandardBootstrapKey;
 
 #[cfg(test)]
@@ -702,7 +702,7 @@ impl<Cont> StandardSeededBootstrapKey<Cont> {
     /// );
     /// seeded_bsk.expand_into::<_, _, SoftwareRandomGenerator>(&mut coef_bsk_expanded);
     /// ```
-    pub fn expand_into<Scalar, OutCont, Gen>(self, output: &mut StandardBootstrapKey<OutCont>)
+    pub fn expand_into<Scalar, OutCont, Gen>(&self, output: &mut StandardBootstrapKey<OutCont>)
     where
         Scalar: Copy + RandomGenerable<Uniform> + Numeric,
         StandardBootstrapKey<OutCont>: AsMutTensor<Element = Scalar>,
@@ -1,21 +1,26 @@
 use crate::commons::crypto::encoding::Plaintext;
 use crate::commons::crypto::ggsw::StandardGgswCiphertext;
+use crate::commons::crypto::glwe::GlweCiphertext;
+use crate::commons::crypto::lwe::LweCiphertext;
 use crate::commons::crypto::secret::generators::EncryptionRandomGenerator;
-use crate::commons::crypto::secret::{GlweSecretKey, LweSecretKey};
-use crate::commons::math::polynomial::Polynomial;
+use crate::commons::crypto::secret::{GlweSecretKey, KeyFingerprint, LweSecretKey};
+use crate::commons::math::polynomial::{MonomialDegree, Polynomial};
 use crate::commons::math::random::ByteRandomGenerator;
 #[cfg(feature = "__commons_parallel")]
 use crate::commons::math::random::ParallelByteRandomGenerator;
+#[cfg(feature = "backend_ntt")]
+use crate::commons::math::random::RandomGenerator;
 use crate::commons::math::tensor::{
     ck_dim_div, ck_dim_eq, tensor_traits, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
 };
 use crate::commons::math::torus::UnsignedTorus;
-use crate::commons::utils::{zip, zip_args};
+use crate::commons::utils::{validate_container_length, zip, zip_args, ContainerValidationError};
 use concrete_commons::dispersion::DispersionParameter;
-use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_commons::key_kinds::{BinaryKeyKind, KeyKind};
 use concrete_commons::numeric::Numeric;
 use concrete_commons::parameters::{
-    DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweDimension, PolynomialSize,
+    DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextIndex, GlweSize, LweDimension,
+    PolynomialSize,
 };
 #[cfg(feature = "__commons_parallel")]
 use rayon::{iter::IndexedParallelIterator, prelude::*};
@@ -23,6 +28,9 @@ use rayon::{iter::IndexedParallelIterator, prelude::*};
 use serde::{Deserialize, Serialize};
 
 /// A bootstrapping key represented in the standard domain.
+///
+/// The bootstrapping key carries the [`KeyFingerprint`]s of its input LWE key and of its output
+/// key, which are set by [`StandardBootstrapKey::fill_with_new_key`].
 #[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct StandardBootstrapKey<Cont> {
@@ -31,6 +39,10 @@ pub struct StandardBootstrapKey<Cont> {
     rlwe_size: GlweSize,
     decomp_level: DecompositionLevelCount,
     decomp_base_log: DecompositionBaseLog,
+    #[cfg_attr(feature = "__commons_serialization", serde(skip))]
+    input_key_fingerprint: KeyFingerprint,
+    #[cfg_attr(feature = "__commons_serialization", serde(skip))]
+    output_key_fingerprint: KeyFingerprint,
 }
 
 tensor_traits!(StandardBootstrapKey);
@@ -84,6 +96,8 @@ impl<Scalar> StandardBootstrapKey<Vec<Scalar>> {
             decomp_base_log,
             rlwe_size,
             poly_size,
+            input_key_fingerprint: KeyFingerprint::unknown(),
+            output_key_fingerprint: KeyFingerprint::unknown(),
         }
     }
 }
@@ -135,6 +149,8 @@ impl<Cont> StandardBootstrapKey<Cont> {
             poly_size,
             decomp_level,
             decomp_base_log,
+            input_key_fingerprint: KeyFingerprint::unknown(),
+            output_key_fingerprint: KeyFingerprint::unknown(),
         }
     }
 
@@ -178,16 +194,17 @@ impl<Cont> StandardBootstrapKey<Cont> {
     ///     &mut encryption_generator,
     /// );
     /// ```
-    pub fn fill_with_new_key<LweCont, RlweCont, Scalar, Gen>(
+    pub fn fill_with_new_key<GlweKind, LweCont, RlweCont, Scalar, Gen>(
         &mut self,
         lwe_secret_key: &LweSecretKey<BinaryKeyKind, LweCont>,
-        glwe_secret_key: &GlweSecretKey<BinaryKeyKind, RlweCont>,
+        glwe_secret_key: &GlweSecretKey<GlweKind, RlweCont>,
         noise_parameters: impl DispersionParameter,
         generator: &mut EncryptionRandomGenerator<Gen>,
     ) where
         Self: AsMutTensor<Element = Scalar>,
+        GlweKind: KeyKind,
         LweSecretKey<BinaryKeyKind, LweCont>: AsRefTensor<Element = Scalar>,
-        GlweSecretKey<BinaryKeyKind, RlweCont>: AsRefTensor<Element = Scalar>,
+        GlweSecretKey<GlweKind, RlweCont>: AsRefTensor<Element = Scalar>,
         Scalar: UnsignedTorus,
         Gen: ByteRandomGenerator,
     {
@@ -217,6 +234,8 @@ impl<Cont> StandardBootstrapKey<Cont> {
                 &mut generator,
             );
         }
+        self.input_key_fingerprint = lwe_secret_key.key_fingerprint();
+        self.output_key_fingerprint = glwe_secret_key.key_fingerprint();
     }
 
     /// Generate a new bootstrap key from the input parameters, and fills the current container
@@ -267,16 +286,17 @@ impl<Cont> StandardBootstrapKey<Cont> {
     /// );
     /// ```
     #[cfg(feature = "__commons_parallel")]
-    pub fn par_fill_with_new_key<LweCont, RlweCont, Scalar, Gen>(
+    pub fn par_fill_with_new_key<GlweKind, LweCont, RlweCont, Scalar, Gen>(
         &mut self,
         lwe_secret_key: &LweSecretKey<BinaryKeyKind, LweCont>,
-        glwe_secret_key: &GlweSecretKey<BinaryKeyKind, RlweCont>,
+        glwe_secret_key: &GlweSecretKey<GlweKind, RlweCont>,
         noise_parameters: impl DispersionParameter + Sync + Send,
         generator: &mut EncryptionRandomGenerator<Gen>,
     ) where
         Self: AsMutTensor<Element = Scalar>,
+        GlweKind: KeyKind,
         LweSecretKey<BinaryKeyKind, LweCont>: AsRefTensor<Element = Scalar>,
-        GlweSecretKey<BinaryKeyKind, RlweCont>: AsRefTensor<Element = Scalar>,
+        GlweSecretKey<GlweKind, RlweCont>: AsRefTensor<Element = Scalar>,
         Scalar: UnsignedTorus + Sync + Send,
         RlweCont: Sync,
         Gen: ParallelByteRandomGenerator,
@@ -304,6 +324,8 @@ impl<Cont> StandardBootstrapKey<Cont> {
                     &mut generator,
                 );
             });
+        self.input_key_fingerprint = lwe_secret_key.key_fingerprint();
+        self.output_key_fingerprint = glwe_secret_key.key_fingerprint();
     }
 
     /// Generate a new bootstrap key from the input parameters, and fills the current container
@@ -417,6 +439,23 @@ impl<Cont> StandardBootstrapKey<Cont> {
         self.rlwe_size
     }
 
+    /// Checks that the length of the container is consistent with the decomposition level count,
+    /// the GLWE size and the polynomial size of the key.
+    pub(crate) fn validate(&self) -> Result<(), ContainerValidationError>
+    where
+        Self: AsRefTensor,
+    {
+        validate_container_length(
+            self.as_tensor().len(),
+            &[
+                self.decomp_level.0,
+                self.rlwe_size.0,
+                self.rlwe_size.0,
+                self.poly_size.0,
+            ],
+        )
+    }
+
     /// Returns the number of levels used to decompose the key bits.
     ///
     /// # Example
@@ -497,6 +536,19 @@ impl<Cont> StandardBootstrapKey<Cont> {
         )
     }
 
+    /// Returns the fingerprint of the input LWE key of the bootstrapping key.
+    pub fn input_key_fingerprint(&self) -> KeyFingerprint {
+        self.input_key_fingerprint
+    }
+
+    /// Returns the fingerprint of the output key of the bootstrapping key.
+    ///
+    /// This is the fingerprint of the GLWE key used to generate the bootstrapping key, and of the
+    /// LWE key it can be turned into.
+    pub fn output_key_fingerprint(&self) -> KeyFingerprint {
+        self.output_key_fingerprint
+    }
+
     /// Returns an iterator over the borrowed GGSW ciphertext composing the key.
     ///
     /// # Example
@@ -718,3 +770,325 @@ impl<Cont> StandardBootstrapKey<Cont> {
             .map(|chunk| Polynomial::from_container(chunk.into_container()))
     }
 }
+
+impl<Cont> StandardBootstrapKey<Cont> {
+    /// Performs a bootstrap of an lwe ciphertext, with a given accumulator, in the standard
+    /// domain.
+    ///
+    /// This is a reference implementation, relying on the naive polynomial multiplication. It is
+    /// much slower than the bootstrap with a Fourier bootstrap key, and is mostly meant to check
+    /// the correctness of the other implementations.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::numeric::CastInto;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, LweSize,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::bootstrap::StandardBootstrapKey;
+    /// use concrete_core::commons::crypto::encoding::Plaintext;
+    /// use concrete_core::commons::crypto::glwe::GlweCiphertext;
+    /// use concrete_core::commons::crypto::lwe::LweCiphertext;
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::{GlweSecretKey, LweSecretKey};
+    /// use concrete_core::commons::math::tensor::AsMutTensor;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    ///
+    /// // define settings
+    /// let polynomial_size = PolynomialSize(256);
+    /// let rlwe_dimension = GlweDimension(1);
+    /// let lwe_dimension = LweDimension(10);
+    ///
+    /// let level = DecompositionLevelCount(3);
+    /// let base_log = DecompositionBaseLog(7);
+    /// let std = LogStandardDev::from_log_standard_dev(-29.);
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    ///
+    /// let rlwe_sk =
+    ///     GlweSecretKey::generate_binary(rlwe_dimension, polynomial_size, &mut secret_generator);
+    /// let lwe_sk = LweSecretKey::generate_binary(lwe_dimension, &mut secret_generator);
+    ///
+    /// let mut bsk = StandardBootstrapKey::allocate(
+    ///     0 as u32,
+    ///     rlwe_dimension.to_glwe_size(),
+    ///     polynomial_size,
+    ///     level,
+    ///     base_log,
+    ///     lwe_dimension,
+    /// );
+    /// bsk.fill_with_new_key(&lwe_sk, &rlwe_sk, std, &mut encryption_generator);
+    ///
+    /// let message = Plaintext(2u32.pow(30));
+    ///
+    /// let mut lwe_in = LweCiphertext::allocate(0u32, lwe_dimension.to_lwe_size());
+    /// let mut lwe_out =
+    ///     LweCiphertext::allocate(0u32, LweSize(rlwe_dimension.0 * polynomial_size.0 + 1));
+    /// lwe_sk.encrypt_lwe(&mut lwe_in, &message, std, &mut encryption_generator);
+    ///
+    /// // accumulator is a trivial encryption of [0, 1/2N, 2/2N, ...]
+    /// let mut accumulator =
+    ///     GlweCiphertext::allocate(0u32, polynomial_size, rlwe_dimension.to_glwe_size());
+    /// accumulator
+    ///     .get_mut_body()
+    ///     .as_mut_tensor()
+    ///     .iter_mut()
+    ///     .enumerate()
+    ///     .for_each(|(i, a)| {
+    ///         *a = (i as f64 * 2_f64.powi(32_i32 - 8 - 1)).cast_into();
+    ///     });
+    ///
+    /// // bootstrap
+    /// bsk.bootstrap(&mut lwe_out, &lwe_in, &accumulator);
+    /// ```
+    pub fn bootstrap<C1, C2, C3, Scalar>(
+        &self,
+        lwe_out: &mut LweCiphertext<C1>,
+        lwe_in: &LweCiphertext<C2>,
+        accumulator: &GlweCiphertext<C3>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        LweCiphertext<C1>: AsMutTensor<Element = Scalar>,
+        LweCiphertext<C2>: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<C3>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        // We copy the accumulator, which is rotated in place by the blind rotation.
+        let mut local_accumulator =
+            GlweCiphertext::allocate(Scalar::ZERO, self.poly_size, self.rlwe_size);
+        local_accumulator
+            .as_mut_tensor()
+            .fill_with_copy(accumulator.as_tensor());
+
+        // We perform the blind rotate
+        self.partial_blind_rotate(&mut local_accumulator, lwe_in, GgswCiphertextIndex(0));
+
+        // We perform the extraction of the first sample.
+        local_accumulator.fill_lwe_with_sample_extraction(lwe_out, MonomialDegree(0));
+        lwe_out.set_key_fingerprint(self.output_key_fingerprint);
+    }
+
+    /// Performs the part of the blind rotation of `lut` by the input LWE ciphertext `lwe`,
+    /// which uses the GGSW ciphertexts of this key.
+    ///
+    /// This key is expected to hold the GGSW ciphertexts encrypting the coefficients of the input
+    /// LWE secret key starting at index `first`, for instance a shard of a larger key, split by
+    /// key-bit range. The shard starting at index 0 also performs the initial rotation by the body
+    /// of `lwe`. Performing the partial blind rotations of all the shards of a key on the same
+    /// `lut`, in any order, is equivalent to the blind rotation by the whole key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::numeric::CastInto;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextIndex, GlweDimension,
+    ///     LweDimension, LweSize, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::bootstrap::StandardBootstrapKey;
+    /// use concrete_core::commons::crypto::encoding::Plaintext;
+    /// use concrete_core::commons::crypto::glwe::GlweCiphertext;
+    /// use concrete_core::commons::crypto::lwe::LweCiphertext;
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::{GlweSecretKey, LweSecretKey};
+    /// use concrete_core::commons::math::polynomial::MonomialDegree;
+    /// use concrete_core::commons::math::tensor::{AsMutTensor, AsRefSlice, AsRefTensor};
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    ///
+    /// // define settings
+    /// let polynomial_size = PolynomialSize(256);
+    /// let rlwe_dimension = GlweDimension(1);
+    /// let lwe_dimension = LweDimension(10);
+    ///
+    /// let level = DecompositionLevelCount(3);
+    /// let base_log = DecompositionBaseLog(7);
+    /// let std = LogStandardDev::from_log_standard_dev(-29.);
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    ///
+    /// let rlwe_sk =
+    ///     GlweSecretKey::generate_binary(rlwe_dimension, polynomial_size, &mut secret_generator);
+    /// let lwe_sk = LweSecretKey::generate_binary(lwe_dimension, &mut secret_generator);
+    ///
+    /// let mut bsk = StandardBootstrapKey::allocate(
+    ///     0 as u32,
+    ///     rlwe_dimension.to_glwe_size(),
+    ///     polynomial_size,
+    ///     level,
+    ///     base_log,
+    ///     lwe_dimension,
+    /// );
+    /// bsk.fill_with_new_key(&lwe_sk, &rlwe_sk, std, &mut encryption_generator);
+    ///
+    /// let message = Plaintext(2u32.pow(30));
+    ///
+    /// let mut lwe_in = LweCiphertext::allocate(0u32, lwe_dimension.to_lwe_size());
+    /// let mut lwe_out =
+    ///     LweCiphertext::allocate(0u32, LweSize(rlwe_dimension.0 * polynomial_size.0 + 1));
+    /// lwe_sk.encrypt_lwe(&mut lwe_in, &message, std, &mut encryption_generator);
+    ///
+    /// // accumulator is a trivial encryption of [0, 1/2N, 2/2N, ...]
+    /// let mut accumulator =
+    ///     GlweCiphertext::allocate(0u32, polynomial_size, rlwe_dimension.to_glwe_size());
+    /// accumulator
+    ///     .get_mut_body()
+    ///     .as_mut_tensor()
+    ///     .iter_mut()
+    ///     .enumerate()
+    ///     .for_each(|(i, a)| {
+    ///         *a = (i as f64 * 2_f64.powi(32_i32 - 8 - 1)).cast_into();
+    ///     });
+    ///
+    /// // blind rotation with the key split in two shards of 5 GGSW ciphertexts
+    /// let ggsw_size = rlwe_dimension.to_glwe_size().0
+    ///     * rlwe_dimension.to_glwe_size().0
+    ///     * polynomial_size.0
+    ///     * level.0;
+    /// let mut sharded_accumulator = accumulator.clone();
+    /// for (shard_index, shard) in bsk
+    ///     .as_tensor()
+    ///     .as_slice()
+    ///     .chunks(5 * ggsw_size)
+    ///     .enumerate()
+    /// {
+    ///     let shard = StandardBootstrapKey::from_container(
+    ///         shard,
+    ///         rlwe_dimension.to_glwe_size(),
+    ///         polynomial_size,
+    ///         level,
+    ///         base_log,
+    ///     );
+    ///     shard.partial_blind_rotate(
+    ///         &mut sharded_accumulator,
+    ///         &lwe_in,
+    ///         GgswCiphertextIndex(5 * shard_index),
+    ///     );
+    /// }
+    /// let mut sharded_lwe_out =
+    ///     LweCiphertext::allocate(0u32, LweSize(rlwe_dimension.0 * polynomial_size.0 + 1));
+    /// sharded_accumulator.fill_lwe_with_sample_extraction(&mut sharded_lwe_out, MonomialDegree(0));
+    ///
+    /// // bootstrap with the whole key
+    /// bsk.bootstrap(&mut lwe_out, &lwe_in, &accumulator);
+    /// assert_eq!(sharded_lwe_out, lwe_out);
+    /// ```
+    pub fn partial_blind_rotate<C, Scalar>(
+        &self,
+        lut: &mut GlweCiphertext<Vec<Scalar>>,
+        lwe: &LweCiphertext<C>,
+        first: GgswCiphertextIndex,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        LweCiphertext<C>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        // We unpack the lwe ciphertext.
+        let (lwe_body, lwe_mask) = lwe.get_body_and_mask();
+
+        // We perform the initial clear rotation by performing lut <- lut * X^{-body_hat}
+        if first.0 == 0 {
+            lut.as_mut_polynomial_list()
+                .update_with_wrapping_monic_monomial_div(pbs_modulus_switch(
+                    lwe_body.0,
+                    self.poly_size,
+                ));
+        }
+
+        // We initialize the ct_0 and ct_1 used for the successive cmuxes
+        let ct_0 = lut;
+        let mut ct_1 = GlweCiphertext::allocate(Scalar::ZERO, self.poly_size, self.rlwe_size);
+
+        // We iterate over the bootstrap key elements and perform the blind rotation.
+        for (lwe_mask_element, bootstrap_key_ggsw) in lwe_mask
+            .mask_element_iter()
+            .skip(first.0)
+            .zip(self.ggsw_iter())
+        {
+            // If the mask is zero, the cmux would leave ct_0 unchanged.
+            if *lwe_mask_element == Scalar::ZERO {
+                continue;
+            }
+            // We copy ct_0 to ct_1, and rotate ct_1 by performing ct_1 <- ct_1 * X^{a_hat}
+            ct_1.as_mut_tensor().fill_with_copy(ct_0.as_tensor());
+            ct_1.as_mut_polynomial_list()
+                .update_with_wrapping_monic_monomial_mul(pbs_modulus_switch(
+                    *lwe_mask_element,
+                    self.poly_size,
+                ));
+            // We perform the cmux.
+            bootstrap_key_ggsw.cmux(ct_0, &mut ct_1);
+        }
+    }
+}
+
+// This function switches the modulus of a single coefficient of a ciphertext from q to 2N, in
+// the context of a PBS.
+pub(crate) fn pbs_modulus_switch<Scalar>(input: Scalar, poly_size: PolynomialSize) -> MonomialDegree
+where
+    Scalar: UnsignedTorus,
+{
+    // When N is not a power of two, 2N does not divide q, and the switch can not be done with
+    // shifts. The coefficient is rounded from its torus representation instead.
+    if !poly_size.0.is_power_of_two() {
+        let modulus = 2 * poly_size.0;
+        let output = (input.into_torus() * modulus as f64).round() as usize;
+        return MonomialDegree(output % modulus);
+    }
+    // Start doing the right shift
+    let mut output = input >> (Scalar::BITS - poly_size.log2().0 - 2);
+    // Do the rounding
+    output += output & Scalar::ONE;
+    // Finish the right shift
+    output >>= 1;
+    MonomialDegree(output.cast_into() as usize)
+}
+
+// This function switches the modulus of a single coefficient of a ciphertext from q to 2N, in
+// the context of a PBS, with a stochastic rounding: the switched value is rounded up with a
+// probability equal to its fractional part, and rounded down otherwise. Contrary to the rounding
+// to the nearest performed by `pbs_modulus_switch`, the resulting rounding error has a zero mean
+// whatever the distribution of the input, at the price of a variance twice as large.
+#[cfg(feature = "backend_ntt")]
+pub(crate) fn pbs_stochastic_modulus_switch<Scalar, G>(
+    input: Scalar,
+    poly_size: PolynomialSize,
+    generator: &mut RandomGenerator<G>,
+) -> MonomialDegree
+where
+    Scalar: UnsignedTorus,
+    G: ByteRandomGenerator,
+{
+    let modulus = 2 * poly_size.0;
+    // When N is not a power of two, the fractional part is computed from the torus representation
+    // of the coefficient.
+    if !poly_size.0.is_power_of_two() {
+        let scaled = input.into_torus() * modulus as f64;
+        let floor = scaled.floor();
+        let draw: Scalar = generator.random_uniform();
+        let output = floor as usize + usize::from(draw.into_torus() < scaled - floor);
+        return MonomialDegree(output % modulus);
+    }
+    // The bits dropped by the right shift are compared against as many random bits.
+    let shift = Scalar::BITS - poly_size.log2().0 - 1;
+    let rem_mask = (Scalar::ONE << shift) - Scalar::ONE;
+    let draw: Scalar = generator.random_uniform();
+    let mut output = input >> shift;
+    if draw & rem_mask < input & rem_mask {
+        output = output.wrapping_add(Scalar::ONE);
+    }
+    MonomialDegree(output.cast_into() as usize % modulus)
+}
@@ -1,12 +1,22 @@
 use crate::commons::crypto::bootstrap::{StandardBootstrapKey, StandardSeededBootstrapKey};
+use crate::commons::crypto::encoding::{
+    Cleartext, Encoder, ModularEncoder, Plaintext, PlaintextList,
+};
+use crate::commons::crypto::glwe::GlweCiphertext;
+use crate::commons::crypto::lwe::LweCiphertext;
 use crate::commons::crypto::secret::generators::{DeterministicSeeder, EncryptionRandomGenerator};
 use crate::commons::crypto::secret::{GlweSecretKey, LweSecretKey};
 use crate::commons::math::random::CompressionSeed;
+use crate::commons::math::tensor::{AsMutTensor, AsRefTensor};
 use crate::commons::math::torus::UnsignedTorus;
-use crate::commons::test_tools::new_secret_random_generator;
-use concrete_commons::dispersion::StandardDev;
+use crate::commons::test_tools::{
+    new_encryption_random_generator, new_secret_random_generator, random_usize_between,
+};
+use concrete_commons::dispersion::{LogStandardDev, StandardDev};
+use concrete_commons::numeric::{CastFrom, CastInto};
 use concrete_commons::parameters::{
-    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, LweSize,
+    PlaintextCount, PolynomialSize,
 };
 use concrete_csprng::generators::SoftwareRandomGenerator;
 use concrete_csprng::seeders::Seed;
@@ -89,6 +99,159 @@ fn test_bsk_seeded_gen_equivalence_u64() {
     test_bsk_seeded_gen_equivalence::<u64>()
 }
 
+fn test_bootstrap_identity<T: UnsignedTorus + CastFrom<usize> + CastInto<usize>>(
+    poly_size: PolynomialSize,
+) {
+    // We use 4 bits of message and one bit of padding.
+    let message_modulus = 16;
+    let delta = T::ONE << (T::BITS - 5);
+
+    let lwe_dim = LweDimension(10);
+    let glwe_dim = GlweDimension(1);
+    let level = DecompositionLevelCount(3);
+    let base_log = DecompositionBaseLog(7);
+    let std = LogStandardDev::from_log_standard_dev(-25.);
+
+    let mut secret_generator = new_secret_random_generator();
+    let mut encryption_generator = new_encryption_random_generator();
+    let lwe_sk = LweSecretKey::generate_binary(lwe_dim, &mut secret_generator);
+    let glwe_sk = GlweSecretKey::generate_binary(glwe_dim, poly_size, &mut secret_generator);
+
+    let mut bsk = StandardBootstrapKey::allocate(
+        T::ZERO,
+        glwe_dim.to_glwe_size(),
+        poly_size,
+        level,
+        base_log,
+        lwe_dim,
+    );
+    bsk.fill_with_new_key(&lwe_sk, &glwe_sk, std, &mut encryption_generator);
+
+    // The accumulator maps every coefficient to the closest encoded message.
+    let box_size = poly_size.0 / message_modulus;
+    let mut accumulator = GlweCiphertext::allocate(T::ZERO, poly_size, glwe_dim.to_glwe_size());
+    accumulator
+        .get_mut_body()
+        .as_mut_tensor()
+        .iter_mut()
+        .enumerate()
+        .for_each(|(i, a)| *a = T::cast_from((i + box_size / 2) / box_size) * delta);
+
+    let output_lwe_sk = glwe_sk.into_lwe_secret_key();
+    for _ in 0..10 {
+        let message = random_usize_between(0..message_modulus / 2);
+        let mut lwe_in = LweCiphertext::allocate(T::ZERO, lwe_dim.to_lwe_size());
+        lwe_sk.encrypt_lwe(
+            &mut lwe_in,
+            &Plaintext(T::cast_from(message) * delta),
+            std,
+            &mut encryption_generator,
+        );
+
+        let mut lwe_out = LweCiphertext::allocate(T::ZERO, LweSize(glwe_dim.0 * poly_size.0 + 1));
+        bsk.bootstrap(&mut lwe_out, &lwe_in, &accumulator);
+
+        let mut decrypted = Plaintext(T::ZERO);
+        output_lwe_sk.decrypt_lwe(&mut decrypted, &lwe_out);
+        let rounded = decrypted.0.wrapping_add(delta >> 1) / delta;
+        let decoded: usize = rounded.cast_into();
+        assert_eq!(decoded % message_modulus, message);
+    }
+}
+
+#[test]
+fn test_bootstrap_identity_u32() {
+    test_bootstrap_identity::<u32>(PolynomialSize(256))
+}
+
+#[test]
+fn test_bootstrap_identity_u64() {
+    test_bootstrap_identity::<u64>(PolynomialSize(256))
+}
+
+#[test]
+fn test_bootstrap_identity_non_power_of_two_u32() {
+    test_bootstrap_identity::<u32>(PolynomialSize(240))
+}
+
+#[test]
+fn test_bootstrap_identity_non_power_of_two_u64() {
+    test_bootstrap_identity::<u64>(PolynomialSize(240))
+}
+
+fn test_bootstrap_modular_lookup_table<
+    T: UnsignedTorus + CastFrom<usize> + CastInto<usize> + CastFrom<u128> + CastInto<u128>,
+>(
+    modulus: u64,
+) {
+    // We evaluate x -> x^2 + 1 on messages modulo a prime, with one bit of padding.
+    let encoder = ModularEncoder {
+        modulus,
+        nb_bit_padding: 1,
+    };
+
+    let poly_size = PolynomialSize(512);
+    let lwe_dim = LweDimension(10);
+    let glwe_dim = GlweDimension(1);
+    let level = DecompositionLevelCount(3);
+    let base_log = DecompositionBaseLog(7);
+    let std = LogStandardDev::from_log_standard_dev(-25.);
+
+    let mut secret_generator = new_secret_random_generator();
+    let mut encryption_generator = new_encryption_random_generator();
+    let lwe_sk = LweSecretKey::generate_binary(lwe_dim, &mut secret_generator);
+    let glwe_sk = GlweSecretKey::generate_binary(glwe_dim, poly_size, &mut secret_generator);
+
+    let mut bsk = StandardBootstrapKey::allocate(
+        T::ZERO,
+        glwe_dim.to_glwe_size(),
+        poly_size,
+        level,
+        base_log,
+        lwe_dim,
+    );
+    bsk.fill_with_new_key(&lwe_sk, &glwe_sk, std, &mut encryption_generator);
+
+    let mut lookup_table = PlaintextList::allocate(T::ZERO, PlaintextCount(poly_size.0));
+    encoder.fill_lookup_table(&mut lookup_table, &encoder, |m| m * m + 1);
+    let mut accumulator = GlweCiphertext::allocate(T::ZERO, poly_size, glwe_dim.to_glwe_size());
+    accumulator
+        .get_mut_body()
+        .as_mut_tensor()
+        .fill_with_copy(lookup_table.as_tensor());
+
+    let output_lwe_sk = glwe_sk.into_lwe_secret_key();
+    for message in 0..modulus {
+        let mut lwe_in = LweCiphertext::allocate(T::ZERO, lwe_dim.to_lwe_size());
+        lwe_sk.encrypt_lwe(
+            &mut lwe_in,
+            &encoder.encode(Cleartext(T::cast_from(message as usize))),
+            std,
+            &mut encryption_generator,
+        );
+
+        let mut lwe_out = LweCiphertext::allocate(T::ZERO, LweSize(glwe_dim.0 * poly_size.0 + 1));
+        bsk.bootstrap(&mut lwe_out, &lwe_in, &accumulator);
+
+        let mut decrypted = Plaintext(T::ZERO);
+        output_lwe_sk.decrypt_lwe(&mut decrypted, &lwe_out);
+        let decoded: usize = encoder.decode(decrypted).0.cast_into();
+        assert_eq!(decoded as u64, (message * message + 1) % modulus);
+    }
+}
+
+#[test]
+fn test_bootstrap_modular_lookup_table_u32() {
+    test_bootstrap_modular_lookup_table::<u32>(5);
+    test_bootstrap_modular_lookup_table::<u32>(7);
+}
+
+#[test]
+fn test_bootstrap_modular_lookup_table_u64() {
+    test_bootstrap_modular_lookup_table::<u64>(5);
+    test_bootstrap_modular_lookup_table::<u64>(7);
+}
+
 #[cfg(all(test, feature = "__commons_parallel"))]
 mod parallel {
     use crate::commons::crypto::bootstrap::{StandardBootstrapKey, StandardSeededBootstrapKey};
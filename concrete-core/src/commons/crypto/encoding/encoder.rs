@@ -1,9 +1,11 @@
 use super::{Cleartext, CleartextList, Plaintext, PlaintextList};
 use crate::commons::math::decomposition::SignedDecomposer;
+use crate::commons::math::random::{ByteRandomGenerator, RandomGenerator};
 use crate::commons::math::tensor::{AsMutTensor, AsRefTensor};
 use crate::commons::math::torus::{FromTorus, IntoTorus, UnsignedTorus};
-use concrete_commons::numeric::{FloatingPoint, Numeric};
-use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+use concrete_commons::dispersion::DispersionParameter;
+use concrete_commons::numeric::{CastFrom, CastInto, FloatingPoint, Numeric};
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, RoundingMode};
 #[cfg(feature = "__commons_serialization")]
 use serde::{Deserialize, Serialize};
 
@@ -217,6 +219,32 @@ impl FloatEncoder {
     pub(crate) fn is_message_out_of_range(&self, message: f64) -> bool {
         message < self.o || message > self.o + self.delta
     }
+
+    /// Decodes a plaintext, after rounding it to the precision of the encoder with the given
+    /// rounding mode.
+    ///
+    /// Contrary to [`Encoder::decode`], the plaintext is always rounded, whether the encoder was
+    /// created in a rounding context or not. A generator must be provided when the rounding mode
+    /// is [`RoundingMode::Stochastic`].
+    pub(crate) fn decode_with_rounding<EncScalar, G>(
+        &self,
+        encoded: Plaintext<EncScalar>,
+        rounding: RoundingMode,
+        generator: Option<&mut RandomGenerator<G>>,
+    ) -> Cleartext<f64>
+    where
+        EncScalar: UnsignedTorus + FromTorus<f64> + IntoTorus<f64>,
+        G: ByteRandomGenerator,
+    {
+        let decomposer = SignedDecomposer::<EncScalar>::new(
+            DecompositionBaseLog(self.nb_bit_precision + self.nb_bit_padding),
+            DecompositionLevelCount(1),
+        );
+        let rounded =
+            decomposer.closest_representable_with_rounding(encoded.0, rounding, generator);
+        // The rounding performed by the decoding of a rounding encoder leaves the value unchanged.
+        self.decode(Plaintext(rounded))
+    }
 }
 
 impl<EncScalar> Encoder<EncScalar> for FloatEncoder
@@ -292,6 +320,176 @@ where
     }
 }
 
+/// The number of standard deviations of the noise below which the decoding of a
+/// [`ModularEncoder`] is guaranteed to be exact. The probability for a gaussian noise to exceed it
+/// is below $2^{-75}$.
+const EXACT_DECODING_STD_DEV_COUNT: f64 = 10.;
+
+/// An encoder for integers modulo an arbitrary modulus.
+///
+/// The message $m \in \mathbb{Z}\_p$ is encoded as the closest integer to $m \cdot q / (p \cdot
+/// 2^\pi)$, where $q$ is the modulus of the plaintexts and $\pi$ the number of bits of padding.
+/// Contrarily to a power-of-two scaling, this supports the prime moduli used by CRT
+/// decompositions, and the decoding rounds to the closest message.
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ModularEncoder {
+    /// The modulus of the messages, which must be at least two.
+    pub modulus: u64,
+    /// The number of bits of padding, left free above the messages.
+    pub nb_bit_padding: usize,
+}
+
+impl ModularEncoder {
+    /// Returns whether the messages of the encoder can be distinguished in plaintexts with `bits`
+    /// bits of precision.
+    pub(crate) fn fits_in_precision(&self, bits: usize) -> bool {
+        self.nb_bit_padding < bits
+            && u128::from(self.modulus) <= 1_u128 << (bits - self.nb_bit_padding)
+    }
+
+    /// Returns the number of messages needed to encode a byte, when the modulus is a power of two
+    /// whose logarithm divides eight, and `None` otherwise.
+    pub(crate) fn byte_digit_count(&self) -> Option<usize> {
+        match self.modulus {
+            2 => Some(8),
+            4 => Some(4),
+            16 => Some(2),
+            256 => Some(1),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the messages encoded with `self` in plaintexts with `bits` bits of
+    /// precision are decoded exactly after the addition of a gaussian noise of dispersion `noise`,
+    /// up to `EXACT_DECODING_STD_DEV_COUNT` standard deviations.
+    ///
+    /// The decoding is exact as long as the noise, added to the rounding error of the encoding,
+    /// stays below half of the distance between two consecutive encoded messages.
+    pub(crate) fn guarantees_exact_decoding<D: DispersionParameter>(
+        &self,
+        bits: usize,
+        noise: D,
+    ) -> bool {
+        let half_distance = 2_f64.powi(-(self.nb_bit_padding as i32)) / (2. * self.modulus as f64);
+        let rounding_error = 2_f64.powi(-(bits as i32 + 1));
+        EXACT_DECODING_STD_DEV_COUNT * noise.get_standard_dev() + rounding_error < half_distance
+    }
+
+    /// Fills `lookup_table` with the body of a bootstrap accumulator, which maps the messages
+    /// encoded with `self` to the encoding of their image by `function`, with `output_encoder`.
+    ///
+    /// The input messages must have at least one bit of padding, and the size $N$ of the lookup
+    /// table must be large enough for each message to cover one coefficient at least, that is
+    /// $N \geq p \cdot 2^{\pi - 1}$. The coefficients which are not closer to a message than to
+    /// the half of the torus encode the opposite of $f(0)$, so that the negacyclic wrapping of the
+    /// bootstrap maps the small negative phases to $f(0)$, even for moduli which do not divide $N$.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::commons::crypto::encoding::*;
+    /// use concrete_core::commons::math::tensor::AsRefTensor;
+    /// use concrete_commons::parameters::PlaintextCount;
+    /// let encoder = ModularEncoder {
+    ///     modulus: 5,
+    ///     nb_bit_padding: 1,
+    /// };
+    /// let mut lookup_table = PlaintextList::allocate(0_u64, PlaintextCount(20));
+    /// encoder.fill_lookup_table(&mut lookup_table, &encoder, |m| m * m);
+    /// // The coefficient 16 holds the message 4, whose square is 1 modulo 5.
+    /// let image: Plaintext<u64> = encoder.encode(Cleartext(1));
+    /// assert_eq!(*lookup_table.as_tensor().get_element(16), image.0);
+    /// ```
+    pub fn fill_lookup_table<Scalar, Cont, F>(
+        &self,
+        lookup_table: &mut PlaintextList<Cont>,
+        output_encoder: &ModularEncoder,
+        function: F,
+    ) where
+        PlaintextList<Cont>: AsMutTensor<Element = Scalar>,
+        Scalar: UnsignedTorus + CastFrom<u128> + CastInto<u128>,
+        F: Fn(u64) -> u64,
+    {
+        assert!(
+            self.nb_bit_padding > 0,
+            "The lookup table inputs must have at least one bit of padding."
+        );
+        let polynomial_size = lookup_table.count().0;
+        let modulus = u128::from(self.modulus);
+        let padded_modulus = modulus << self.nb_bit_padding;
+        assert!(
+            padded_modulus <= 2 * polynomial_size as u128,
+            "The lookup table has {} coefficients, which is not enough for a modulus of {}.",
+            polynomial_size,
+            self.modulus,
+        );
+        let encode_image = |message: u64| -> Scalar {
+            output_encoder
+                .encode(Cleartext(Scalar::cast_from(u128::from(
+                    function(message) % output_encoder.modulus,
+                ))))
+                .0
+        };
+        let opposite_of_zero_image = encode_image(0).wrapping_neg();
+
+        // The coefficient of index j lies on the phase j / 2N, which scaled by the padded modulus
+        // is j.p.2^pi / 2N. We compare the numerators, over a common denominator of 2N.
+        let denominator = 2 * polynomial_size as u128;
+        for (index, coefficient) in lookup_table.as_mut_tensor().iter_mut().enumerate() {
+            let numerator = index as u128 * padded_modulus;
+            let message = (numerator + denominator / 2) / denominator;
+            *coefficient = if message < modulus {
+                encode_image(message as u64)
+            } else {
+                let distance_to_last = numerator - (modulus - 1) * denominator;
+                let distance_to_half = (padded_modulus / 2) * denominator - numerator;
+                if distance_to_last < distance_to_half {
+                    encode_image(self.modulus - 1)
+                } else {
+                    opposite_of_zero_image
+                }
+            };
+        }
+    }
+}
+
+impl<EncScalar> Encoder<EncScalar> for ModularEncoder
+where
+    EncScalar: UnsignedTorus + CastFrom<u128> + CastInto<u128>,
+{
+    type Raw = EncScalar;
+
+    fn encode(&self, raw: Cleartext<Self::Raw>) -> Plaintext<EncScalar> {
+        if !self.fits_in_precision(EncScalar::BITS) {
+            panic!(
+                "Tried to encode a message with a modulus ({}) too large for the precision.",
+                self.modulus
+            );
+        }
+        let modulus = u128::from(self.modulus);
+        let message = <EncScalar as CastInto<u128>>::cast_into(raw.0) % modulus;
+        let scaling_log = EncScalar::BITS - self.nb_bit_padding;
+        Plaintext(EncScalar::cast_from(
+            ((message << scaling_log) + modulus / 2) / modulus,
+        ))
+    }
+
+    fn decode(&self, encoded: Plaintext<EncScalar>) -> Cleartext<Self::Raw> {
+        if !self.fits_in_precision(EncScalar::BITS) {
+            panic!(
+                "Tried to decode a message with a modulus ({}) too large for the precision.",
+                self.modulus
+            );
+        }
+        let modulus = u128::from(self.modulus);
+        let encoded: u128 = encoded.0.cast_into();
+        let scaling_log = EncScalar::BITS - self.nb_bit_padding;
+        let rounded = (encoded * modulus + (1 << (scaling_log - 1))) >> scaling_log;
+        Cleartext(EncScalar::cast_from(rounded % modulus))
+    }
+}
+
 #[cfg(all(test))]
 mod test {
     #![allow(clippy::float_cmp)]
@@ -301,7 +499,7 @@ mod test {
     };
     use crate::commons::math::random::RandomGenerator;
     use crate::commons::math::tensor::{AsMutTensor, AsRefTensor, Tensor};
-    use concrete_commons::parameters::{CleartextCount, PlaintextCount};
+    use concrete_commons::parameters::{CleartextCount, PlaintextCount, RoundingMode};
     use concrete_csprng::generators::SoftwareRandomGenerator;
     use concrete_csprng::seeders::{Seeder, UnixSeeder};
 
@@ -576,6 +774,49 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_new_x_decode_with_rounding() {
+        // create an encoder with a granularity = 1, which does not round when decoding
+        let (min, _) = generate_random_interval!();
+        let (precision, padding) = generate_precision_padding!(8, 8);
+        let max = min + f64::powi(2., precision as i32) - 1.;
+        let encoder = FloatEncoder::new(min, max, precision, padding);
+        let mut generator =
+            RandomGenerator::<SoftwareRandomGenerator>::new(UnixSeeder::new(0).seed());
+
+        for _ in 0..100 {
+            // generates a random message
+            let m: f64 = random_index!((f64::powi(2., precision as i32)) as usize) as f64;
+            let m1 = m + min;
+
+            // message with error in [-0.25,0.25], which does not wrap around the interval
+            let m2: f64 = m1
+                + if m == 0. {
+                    random_message!(0., 0.25)
+                } else if m == f64::powi(2., precision as i32) - 1. {
+                    random_message!(-0.25, 0.)
+                } else {
+                    random_message!(-0.25, 0.25)
+                };
+            let plaintext: Plaintext<u64> = encoder.encode(Cleartext(m2));
+
+            // all the rounding modes recover the message
+            for rounding in [
+                RoundingMode::NearestUp,
+                RoundingMode::NearestEven,
+                RoundingMode::Stochastic,
+            ] {
+                let decoding =
+                    encoder.decode_with_rounding(plaintext, rounding, Some(&mut generator));
+                if rounding == RoundingMode::Stochastic {
+                    assert!((m1 - decoding.0).abs() <= 1.);
+                } else {
+                    assert_eq!(m1, decoding.0);
+                }
+            }
+        }
+    }
+
     #[test]
     fn margins_with_integers() {
         let power: usize = random_index!(5) + 2;
@@ -1,6 +1,10 @@
-use crate::commons::crypto::encoding::{Cleartext, Encoder, Plaintext, RealEncoder};
+use crate::commons::crypto::encoding::{
+    Cleartext, Encoder, ModularEncoder, Plaintext, RealEncoder,
+};
 use crate::commons::math::torus::UnsignedTorus;
-use crate::commons::test_tools::{any_uint, random_uint_between};
+use crate::commons::test_tools::{any_uint, random_uint_between, random_usize_between};
+use concrete_commons::dispersion::StandardDev;
+use concrete_commons::numeric::{CastFrom, CastInto};
 
 fn test_encoding_decoding<T: UnsignedTorus>() {
     //! Encodes and decodes random messages
@@ -46,3 +50,85 @@ fn test_encoding_decoding_u32() {
 fn test_encoding_decoding_u64() {
     test_encoding_decoding::<u64>()
 }
+
+fn test_modular_encoding_decoding<T: UnsignedTorus + CastFrom<u128> + CastInto<u128>>() {
+    //! Encodes and decodes random messages modulo random moduli, with some noise
+    let n_tests = 1000;
+    for _i in 0..n_tests {
+        let modulus = random_usize_between(2..100) as u64;
+        let nb_bit_padding = random_usize_between(0..3);
+        let encoder = ModularEncoder {
+            modulus,
+            nb_bit_padding,
+        };
+
+        let message = T::cast_from(random_usize_between(0..modulus as usize) as u128);
+        let encoding: Plaintext<T> = encoder.encode(Cleartext(message));
+
+        // the noise stays below a quarter of the distance between two encodings
+        let noise_bound =
+            (T::ONE << (T::BITS - nb_bit_padding - 2)) / T::cast_from(modulus as u128);
+        let noise: T = random_uint_between(T::ZERO..noise_bound);
+        let noisy = if any_uint::<T>() % T::TWO == T::ZERO {
+            encoding.0.wrapping_add(noise)
+        } else {
+            encoding.0.wrapping_sub(noise)
+        };
+
+        let decoding = encoder.decode(Plaintext(noisy));
+        assert_eq!(message, decoding.0);
+    }
+}
+
+#[test]
+fn test_modular_encoding_decoding_u32() {
+    test_modular_encoding_decoding::<u32>()
+}
+
+#[test]
+fn test_modular_encoding_decoding_u64() {
+    test_modular_encoding_decoding::<u64>()
+}
+
+fn test_modular_encoding_exact_decoding_guarantee<T>()
+where
+    T: UnsignedTorus + CastFrom<u128> + CastInto<u128>,
+{
+    //! Checks that the messages are decoded exactly up to the guaranteed noise, and that larger
+    //! noises are refused
+    let n_tests = 1000;
+    for _i in 0..n_tests {
+        let modulus = random_usize_between(2..100) as u64;
+        let nb_bit_padding = random_usize_between(0..3);
+        let encoder = ModularEncoder {
+            modulus,
+            nb_bit_padding,
+        };
+        let half_distance = 2_f64.powi(-(nb_bit_padding as i32)) / (2. * modulus as f64);
+
+        // ten standard deviations fill most of the half distance between two encodings
+        let std_dev = StandardDev(0.09 * half_distance);
+        assert!(encoder.guarantees_exact_decoding(T::BITS, std_dev));
+        assert!(!encoder.guarantees_exact_decoding(T::BITS, StandardDev(0.11 * half_distance)));
+
+        let message = T::cast_from(random_usize_between(0..modulus as usize) as u128);
+        let encoding: Plaintext<T> = encoder.encode(Cleartext(message));
+        let noise = T::cast_from((10. * std_dev.0 * 2_f64.powi(T::BITS as i32)) as u128);
+        let noisy = if any_uint::<T>() % T::TWO == T::ZERO {
+            encoding.0.wrapping_add(noise)
+        } else {
+            encoding.0.wrapping_sub(noise)
+        };
+        assert_eq!(message, encoder.decode(Plaintext(noisy)).0);
+    }
+}
+
+#[test]
+fn test_modular_encoding_exact_decoding_guarantee_u32() {
+    test_modular_encoding_exact_decoding_guarantee::<u32>()
+}
+
+#[test]
+fn test_modular_encoding_exact_decoding_guarantee_u64() {
+    test_modular_encoding_exact_decoding_guarantee::<u64>()
+}
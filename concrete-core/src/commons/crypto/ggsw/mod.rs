@@ -1,11 +1,13 @@
 //! GGSW encryption scheme.
 
 mod levels;
+mod prepared;
 mod seeded_levels;
 mod seeded_standard;
 mod standard;
 
 pub use levels::*;
+pub use prepared::*;
 pub use seeded_levels::*;
 pub use seeded_standard::*;
 pub use standard::*;
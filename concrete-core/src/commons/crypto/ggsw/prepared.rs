@@ -0,0 +1,309 @@
+use crate::commons::crypto::glwe::GlweCiphertext;
+use crate::commons::math::decomposition::SignedDecomposer;
+use crate::commons::math::tensor::{
+    ck_dim_div, ck_dim_eq, tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+};
+use crate::commons::math::torus::UnsignedTorus;
+
+use super::StandardGgswCiphertext;
+
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+};
+#[cfg(feature = "__commons_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A GGSW ciphertext prepared for repeated external products.
+///
+/// A prepared GGSW ciphertext holds the same information as a [`StandardGgswCiphertext`], laid out
+/// so that the external product can be computed without any per-call bookkeeping:
+///
+/// + The level matrices are stored in the order in which the decomposition of a GLWE ciphertext
+///   produces its terms (from the highest level to the lowest one).
+/// + Every polynomial $P$ of the GGSW ciphertext is stored along with its negation, as the $2N$
+///   coefficients of $(-P, P)$. The product of $P$ by a monomial $X^j$ in $\mathbb{Z}\_q\[X\] /
+///   (X^N + 1)$ is then the contiguous window of $N$ coefficients starting at index $N - j$, which
+///   turns the polynomial products into branch-free multiply-accumulate loops.
+///
+/// The preparation is done once (see [`PreparedGgswCiphertext::fill_with_standard`]), and pays off
+/// when the same GGSW ciphertext is used in several external products, e.g. as the selector of
+/// all the CMUXes of a level of a CMUX tree.
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PreparedGgswCiphertext<Cont> {
+    tensor: Tensor<Cont>,
+    poly_size: PolynomialSize,
+    glwe_size: GlweSize,
+    decomp_base_log: DecompositionBaseLog,
+}
+
+tensor_traits!(PreparedGgswCiphertext);
+
+impl<Scalar> PreparedGgswCiphertext<Vec<Scalar>>
+where
+    Scalar: Copy,
+{
+    /// Allocates a new prepared GGSW ciphertext whose coefficients are all `value`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::ggsw::PreparedGgswCiphertext;
+    ///
+    /// let ggsw = PreparedGgswCiphertext::allocate(
+    ///     9 as u8,
+    ///     PolynomialSize(10),
+    ///     GlweSize(7),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(4),
+    /// );
+    /// assert_eq!(ggsw.glwe_size(), GlweSize(7));
+    /// assert_eq!(ggsw.polynomial_size(), PolynomialSize(10));
+    /// assert_eq!(ggsw.decomposition_level_count(), DecompositionLevelCount(3));
+    /// assert_eq!(ggsw.decomposition_base_log(), DecompositionBaseLog(4));
+    /// ```
+    pub fn allocate(
+        value: Scalar,
+        poly_size: PolynomialSize,
+        glwe_size: GlweSize,
+        decomp_level: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+    ) -> Self {
+        PreparedGgswCiphertext {
+            tensor: Tensor::from_container(vec![
+                value;
+                decomp_level.0
+                    * glwe_size.0
+                    * glwe_size.0
+                    * 2
+                    * poly_size.0
+            ]),
+            poly_size,
+            glwe_size,
+            decomp_base_log,
+        }
+    }
+}
+
+impl<Cont> PreparedGgswCiphertext<Cont> {
+    /// Returns the size of the GLWE ciphertexts composing the GGSW ciphertext.
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_size
+    }
+
+    /// Returns the size of the polynomials used in the ciphertext.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.poly_size
+    }
+
+    /// Returns the logarithm of the base used for the gadget decomposition.
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Returns the number of decomposition levels used in the ciphertext.
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount
+    where
+        Self: AsRefTensor,
+    {
+        let level_size = self.glwe_size.0 * self.glwe_size.0 * 2 * self.poly_size.0;
+        ck_dim_div!(self.as_tensor().len() => level_size);
+        DecompositionLevelCount(self.as_tensor().len() / level_size)
+    }
+
+    /// Fills the current prepared GGSW ciphertext with the preparation of a standard GGSW
+    /// ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::encoding::Plaintext;
+    /// use concrete_core::commons::crypto::ggsw::{PreparedGgswCiphertext, StandardGgswCiphertext};
+    ///
+    /// let ggsw = StandardGgswCiphertext::new_trivial_encryption(
+    ///     PolynomialSize(8),
+    ///     GlweSize(2),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(8),
+    ///     &Plaintext(1_u32),
+    /// );
+    /// let mut prepared = PreparedGgswCiphertext::allocate(
+    ///     0_u32,
+    ///     PolynomialSize(8),
+    ///     GlweSize(2),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(8),
+    /// );
+    /// prepared.fill_with_standard(&ggsw);
+    /// ```
+    pub fn fill_with_standard<InputCont, Scalar>(
+        &mut self,
+        ggsw: &StandardGgswCiphertext<InputCont>,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        StandardGgswCiphertext<InputCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.poly_size => ggsw.polynomial_size());
+        ck_dim_eq!(self.glwe_size => ggsw.glwe_size());
+        ck_dim_eq!(self.decomposition_level_count() => ggsw.decomposition_level_count());
+        ck_dim_eq!(self.decomp_base_log => ggsw.decomposition_base_log());
+
+        let poly_size = self.poly_size.0;
+        let level_size = self.glwe_size.0 * self.glwe_size.0 * poly_size;
+        // The levels are reversed to match the order of the decomposition iterator.
+        for (prepared_level, level) in self
+            .as_mut_tensor()
+            .subtensor_iter_mut(2 * level_size)
+            .zip(ggsw.as_tensor().subtensor_iter(level_size).rev())
+        {
+            for (prepared_poly, poly) in prepared_level
+                .into_container()
+                .chunks_exact_mut(2 * poly_size)
+                .zip(level.as_slice().chunks_exact(poly_size))
+            {
+                let (negated, copied) = prepared_poly.split_at_mut(poly_size);
+                for (neg, coef) in negated.iter_mut().zip(poly.iter()) {
+                    *neg = coef.wrapping_neg();
+                }
+                copied.copy_from_slice(poly);
+            }
+        }
+    }
+
+    /// Computes the external product between the current prepared GGSW ciphertext and a GLWE
+    /// ciphertext, and adds the result to the `output` GLWE ciphertext.
+    ///
+    /// The result is the same as the one of [`StandardGgswCiphertext::external_product`] with the
+    /// GGSW ciphertext this one was prepared from.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, GlweSize, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::encoding::Plaintext;
+    /// use concrete_core::commons::crypto::ggsw::{PreparedGgswCiphertext, StandardGgswCiphertext};
+    /// use concrete_core::commons::crypto::glwe::GlweCiphertext;
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::GlweSecretKey;
+    /// use concrete_core::commons::math::random::RandomGenerator;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    ///
+    /// let poly_size = PolynomialSize(16);
+    /// let glwe_size = GlweSize(3);
+    /// let level = DecompositionLevelCount(3);
+    /// let base_log = DecompositionBaseLog(7);
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// let key =
+    ///     GlweSecretKey::generate_binary(GlweDimension(2), poly_size, &mut secret_generator);
+    /// let mut ggsw =
+    ///     StandardGgswCiphertext::allocate(0_u64, poly_size, glwe_size, level, base_log);
+    /// key.encrypt_constant_ggsw(
+    ///     &mut ggsw,
+    ///     &Plaintext(1_u64),
+    ///     LogStandardDev::from_log_standard_dev(-40.),
+    ///     &mut encryption_generator,
+    /// );
+    /// let mut prepared =
+    ///     PreparedGgswCiphertext::allocate(0_u64, poly_size, glwe_size, level, base_log);
+    /// prepared.fill_with_standard(&ggsw);
+    ///
+    /// let mut glwe = GlweCiphertext::allocate(0_u64, poly_size, glwe_size);
+    /// RandomGenerator::<SoftwareRandomGenerator>::new(Seed(1))
+    ///     .fill_tensor_with_random_uniform(&mut glwe);
+    /// let mut expected = GlweCiphertext::allocate(0_u64, poly_size, glwe_size);
+    /// ggsw.external_product(&mut expected, &glwe);
+    /// let mut output = GlweCiphertext::allocate(0_u64, poly_size, glwe_size);
+    /// prepared.external_product(&mut output, &glwe);
+    /// assert_eq!(output, expected);
+    /// ```
+    pub fn external_product<C1, C2, Scalar>(
+        &self,
+        output: &mut GlweCiphertext<C1>,
+        glwe: &GlweCiphertext<C2>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<C1>: AsMutTensor<Element = Scalar>,
+        GlweCiphertext<C2>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(
+            self.poly_size =>
+            glwe.polynomial_size(),
+            output.polynomial_size()
+        );
+        ck_dim_eq!(
+            self.glwe_size =>
+            glwe.size(),
+            output.size()
+        );
+
+        let poly_size = self.poly_size.0;
+        let level_size = self.glwe_size.0 * self.glwe_size.0 * 2 * poly_size;
+        let decomposer =
+            SignedDecomposer::new(self.decomp_base_log, self.decomposition_level_count());
+        let mut decomposition = decomposer.decompose_tensor(glwe);
+        let output = output.as_mut_tensor().as_mut_slice();
+        for prepared_level in self.as_tensor().as_slice().chunks_exact(level_size) {
+            let glwe_decomp_term = decomposition.next_term().unwrap();
+            // Every row of the level matrix is multiplied by the corresponding polynomial of the
+            // glwe decomposition, and the result is added to the output.
+            for (prepared_row, glwe_poly) in prepared_level
+                .chunks_exact(self.glwe_size.0 * 2 * poly_size)
+                .zip(
+                    glwe_decomp_term
+                        .as_tensor()
+                        .as_slice()
+                        .chunks_exact(poly_size),
+                )
+            {
+                for (prepared_poly, output_poly) in prepared_row
+                    .chunks_exact(2 * poly_size)
+                    .zip(output.chunks_exact_mut(poly_size))
+                {
+                    // The decomposed coefficients are small, and many of them are zero.
+                    for (j, coef) in glwe_poly
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, c)| **c != Scalar::ZERO)
+                    {
+                        let window = &prepared_poly[poly_size - j..2 * poly_size - j];
+                        for (out, ggsw_coef) in output_poly.iter_mut().zip(window.iter()) {
+                            *out = out.wrapping_add(ggsw_coef.wrapping_mul(*coef));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Computes a CMUX between two GLWE ciphertexts, using the current prepared GGSW ciphertext
+    /// as selector.
+    ///
+    /// The result is written in `ct0`, and `ct1` is left with the difference `ct1 - ct0`.
+    pub fn cmux<C0, C1, Scalar>(&self, ct0: &mut GlweCiphertext<C0>, ct1: &mut GlweCiphertext<C1>)
+    where
+        Self: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<C0>: AsMutTensor<Element = Scalar>,
+        GlweCiphertext<C1>: AsMutTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ct1.as_mut_tensor()
+            .update_with_wrapping_sub(ct0.as_tensor());
+        self.external_product(ct0, ct1);
+    }
+}
@@ -507,7 +507,7 @@ impl<Cont> StandardGgswSeededCiphertext<Cont> {
     }
 
     pub fn expand_into_with_existing_generator<Scalar, OutCont, Gen>(
-        self,
+        &self,
         output: &mut StandardGgswCiphertext<OutCont>,
         generator: &mut RandomGenerator<Gen>,
     ) where
@@ -574,7 +574,7 @@ impl<Cont> StandardGgswSeededCiphertext<Cont> {
     /// assert_eq!(ggsw.decomposition_base_log(), decomp_base_log);
     /// assert_eq!(ggsw.decomposition_level_count(), decomp_level);
     /// ```
-    pub fn expand_into<Scalar, OutCont, Gen>(self, output: &mut StandardGgswCiphertext<OutCont>)
+    pub fn expand_into<Scalar, OutCont, Gen>(&self, output: &mut StandardGgswCiphertext<OutCont>)
     where
         Scalar: Copy + RandomGenerable<Uniform> + Numeric,
         StandardGgswCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
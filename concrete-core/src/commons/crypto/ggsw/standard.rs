@@ -1,11 +1,13 @@
 use crate::commons::crypto::encoding::Plaintext;
 
-use crate::commons::crypto::glwe::GlweList;
-use crate::commons::math::decomposition::DecompositionLevel;
+use crate::commons::crypto::glwe::{GlweCiphertext, GlweList};
+use crate::commons::math::decomposition::{DecompositionLevel, SignedDecomposer};
+use crate::commons::math::polynomial::Polynomial;
 use crate::commons::math::tensor::{
-    ck_dim_div, tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+    ck_dim_div, ck_dim_eq, tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
 };
 use crate::commons::math::torus::UnsignedTorus;
+use crate::commons::utils::{validate_container_length, ContainerValidationError};
 
 use super::GgswLevelMatrix;
 
@@ -219,6 +221,18 @@ impl<Cont> StandardGgswCiphertext<Cont> {
         self.poly_size
     }
 
+    /// Checks that the length of the container is consistent with the GLWE size and the polynomial
+    /// size of the ciphertext.
+    pub(crate) fn validate(&self) -> Result<(), ContainerValidationError>
+    where
+        Self: AsRefTensor,
+    {
+        validate_container_length(
+            self.as_tensor().len(),
+            &[self.rlwe_size.0, self.rlwe_size.0, self.poly_size.0],
+        )
+    }
+
     /// Returns a borrowed list composed of all the GLWE ciphertext composing current ciphertext.
     ///
     /// # Example
@@ -504,4 +518,112 @@ impl<Cont> StandardGgswCiphertext<Cont> {
             }
         }
     }
+
+    /// Computes the external product between the current GGSW ciphertext and a GLWE ciphertext,
+    /// and adds the result to the `output` GLWE ciphertext.
+    ///
+    /// The polynomial products are computed with the naive (quadratic) multiplication. This is
+    /// much slower than the external product in the Fourier domain, but it does not rely on any
+    /// floating point arithmetic.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::encoding::Plaintext;
+    /// use concrete_core::commons::crypto::ggsw::StandardGgswCiphertext;
+    /// use concrete_core::commons::crypto::glwe::GlweCiphertext;
+    /// use concrete_core::commons::math::tensor::{AsMutTensor, AsRefTensor};
+    ///
+    /// // A trivial encryption of 1 in the GGSW leaves the GLWE unchanged.
+    /// let ggsw = StandardGgswCiphertext::new_trivial_encryption(
+    ///     PolynomialSize(8),
+    ///     GlweSize(2),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(8),
+    ///     &Plaintext(1_u32),
+    /// );
+    /// let mut glwe = GlweCiphertext::allocate(0_u32, PolynomialSize(8), GlweSize(2));
+    /// glwe.get_mut_body()
+    ///     .as_mut_tensor()
+    ///     .iter_mut()
+    ///     .enumerate()
+    ///     .for_each(|(i, a)| *a = (i as u32) << 24);
+    /// let mut output = GlweCiphertext::allocate(0_u32, PolynomialSize(8), GlweSize(2));
+    /// ggsw.external_product(&mut output, &glwe);
+    /// assert_eq!(output, glwe);
+    /// ```
+    pub fn external_product<C1, C2, Scalar>(
+        &self,
+        output: &mut GlweCiphertext<C1>,
+        glwe: &GlweCiphertext<C2>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<C1>: AsMutTensor<Element = Scalar>,
+        GlweCiphertext<C2>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        // We check that the polynomial sizes match
+        ck_dim_eq!(
+            self.poly_size =>
+            glwe.polynomial_size(),
+            output.polynomial_size()
+        );
+        // We check that the glwe sizes match
+        ck_dim_eq!(
+            self.glwe_size() =>
+            glwe.size(),
+            output.size()
+        );
+
+        let mut product = Polynomial::allocate(Scalar::ZERO, self.poly_size);
+        let decomposer =
+            SignedDecomposer::new(self.decomp_base_log, self.decomposition_level_count());
+        let mut decomposition = decomposer.decompose_tensor(glwe);
+        // We loop through the levels (we reverse to match the order of the decomposition iterator.)
+        for ggsw_decomp_matrix in self.level_matrix_iter().rev() {
+            // We retrieve the decomposition of this level.
+            let glwe_decomp_term = decomposition.next_term().unwrap();
+            debug_assert_eq!(
+                ggsw_decomp_matrix.decomposition_level(),
+                glwe_decomp_term.level()
+            );
+            // Every row of the level matrix is multiplied by the corresponding polynomial of the
+            // glwe decomposition, and the result is added to the output.
+            for (ggsw_row, glwe_poly) in ggsw_decomp_matrix.row_iter().zip(
+                glwe_decomp_term
+                    .as_tensor()
+                    .subtensor_iter(self.poly_size.0)
+                    .map(Polynomial::from_tensor),
+            ) {
+                let ggsw_glwe = ggsw_row.into_glwe();
+                for (ggsw_poly, mut output_poly) in ggsw_glwe
+                    .as_polynomial_list()
+                    .polynomial_iter()
+                    .zip(output.as_mut_polynomial_list().polynomial_iter_mut())
+                {
+                    product.fill_with_wrapping_mul(&ggsw_poly, &glwe_poly);
+                    output_poly.update_with_wrapping_add(&product);
+                }
+            }
+        }
+    }
+
+    /// Computes a CMUX between two GLWE ciphertexts, using the current GGSW ciphertext as
+    /// selector.
+    ///
+    /// The result is written in `ct0`, and `ct1` is left with the difference `ct1 - ct0`.
+    pub fn cmux<C0, C1, Scalar>(&self, ct0: &mut GlweCiphertext<C0>, ct1: &mut GlweCiphertext<C1>)
+    where
+        Self: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<C0>: AsMutTensor<Element = Scalar>,
+        GlweCiphertext<C1>: AsMutTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ct1.as_mut_tensor()
+            .update_with_wrapping_sub(ct0.as_tensor());
+        self.external_product(ct0, ct1);
+    }
 }
@@ -0,0 +1,739 @@
+use super::{GlweCiphertext, GlweList};
+use crate::commons::crypto::encoding::PlaintextList;
+use crate::commons::crypto::lwe::LweList;
+use crate::commons::crypto::secret::generators::EncryptionRandomGenerator;
+use crate::commons::crypto::secret::GlweSecretKey;
+use crate::commons::math::decomposition::{
+    DecompositionLevel, DecompositionTerm, SignedDecomposer,
+};
+use crate::commons::math::polynomial::Polynomial;
+use crate::commons::math::random::ByteRandomGenerator;
+use crate::commons::math::tensor::{
+    ck_dim_div, ck_dim_eq, tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+};
+use crate::commons::math::torus::UnsignedTorus;
+use concrete_commons::dispersion::DispersionParameter;
+use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, GlweSize, MonomialDegree,
+    PlaintextCount, PolynomialSize,
+};
+#[cfg(feature = "__commons_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A set of GLWE automorphism keys.
+///
+/// An automorphism $\tau\_t$ maps a polynomial $P(X)$ to $P(X^t)$, for an odd integer $t$.
+/// Applied to a GLWE ciphertext encrypting $M(X)$ under the key $S(X)$, it gives a ciphertext
+/// encrypting $M(X^t)$ under the key $S(X^t)$, which an automorphism key switches back to the key
+/// $S(X)$.
+///
+/// The set contains one key for each $t = 2^l + 1$, with $1 \leq l \leq \log\_2(N)$, which are
+/// the automorphisms needed to pack LWE ciphertexts with [`GlweAutomorphismKeys::tree_pack`]. For
+/// each $l$, and for each polynomial $S\_j(X)$ of the key, the set contains one GLWE encryption
+/// of $S\_j(X^t)$ per decomposition level.
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweAutomorphismKeys<Cont> {
+    tensor: Tensor<Cont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+}
+
+tensor_traits!(GlweAutomorphismKeys);
+
+impl<Scalar> GlweAutomorphismKeys<Vec<Scalar>>
+where
+    Scalar: Copy,
+{
+    /// Allocates a set of automorphism keys whose masks and bodies are all `value`.
+    ///
+    /// # Note
+    ///
+    /// This function does *not* generate automorphism keys, but merely allocates a container of
+    /// the right size. See [`GlweAutomorphismKeys::fill_with_automorphism_keys`] to fill the
+    /// container with proper automorphism keys.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the polynomial size is not a power of two.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweAutomorphismKeys;
+    /// let keys = GlweAutomorphismKeys::allocate(
+    ///     0 as u8,
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(16),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    /// );
+    /// assert_eq!(
+    ///     keys.decomposition_level_count(),
+    ///     DecompositionLevelCount(10)
+    /// );
+    /// assert_eq!(keys.decomposition_base_log(), DecompositionBaseLog(16));
+    /// assert_eq!(keys.glwe_dimension(), GlweDimension(2));
+    /// assert_eq!(keys.polynomial_size(), PolynomialSize(256));
+    /// assert_eq!(keys.automorphism_count(), 8);
+    /// ```
+    pub fn allocate(
+        value: Scalar,
+        decomp_size: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Self {
+        assert!(
+            polynomial_size.0.is_power_of_two(),
+            "The polynomial size must be a power of two, got {}.",
+            polynomial_size.0
+        );
+        let automorphism_count = polynomial_size.0.trailing_zeros() as usize;
+        GlweAutomorphismKeys {
+            tensor: Tensor::from_container(vec![
+                value;
+                automorphism_count
+                    * decomp_size.0
+                    * glwe_dimension.0
+                    * glwe_dimension.to_glwe_size().0
+                    * polynomial_size.0
+            ]),
+            decomp_base_log,
+            decomp_level_count: decomp_size,
+            glwe_size: glwe_dimension.to_glwe_size(),
+            polynomial_size,
+        }
+    }
+}
+
+impl<Cont> GlweAutomorphismKeys<Cont> {
+    /// Creates a set of automorphism keys from a container.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweAutomorphismKeys;
+    /// let keys = GlweAutomorphismKeys::from_container(
+    ///     vec![0 as u8; 8 * 10 * 2 * 3 * 256],
+    ///     DecompositionBaseLog(16),
+    ///     DecompositionLevelCount(10),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    /// );
+    /// assert_eq!(
+    ///     keys.decomposition_level_count(),
+    ///     DecompositionLevelCount(10)
+    /// );
+    /// assert_eq!(keys.decomposition_base_log(), DecompositionBaseLog(16));
+    /// assert_eq!(keys.glwe_dimension(), GlweDimension(2));
+    /// assert_eq!(keys.polynomial_size(), PolynomialSize(256));
+    /// ```
+    pub fn from_container(
+        cont: Cont,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_size: DecompositionLevelCount,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> GlweAutomorphismKeys<Cont>
+    where
+        Cont: AsRefSlice,
+    {
+        let tensor = Tensor::from_container(cont);
+        ck_dim_div!(tensor.len() =>
+            decomp_size.0,
+            glwe_dimension.0,
+            glwe_dimension.to_glwe_size().0,
+            polynomial_size.0
+        );
+        GlweAutomorphismKeys {
+            tensor,
+            decomp_base_log,
+            decomp_level_count: decomp_size,
+            glwe_size: glwe_dimension.to_glwe_size(),
+            polynomial_size,
+        }
+    }
+
+    /// Returns the dimension of the GLWE key the automorphism keys operate under.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweAutomorphismKeys;
+    /// let keys = GlweAutomorphismKeys::allocate(
+    ///     0 as u8,
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(16),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    /// );
+    /// assert_eq!(keys.glwe_dimension(), GlweDimension(2));
+    /// ```
+    pub fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_size.to_glwe_dimension()
+    }
+
+    /// Returns the size of the polynomials of the GLWE key the automorphism keys operate under.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweAutomorphismKeys;
+    /// let keys = GlweAutomorphismKeys::allocate(
+    ///     0 as u8,
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(16),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    /// );
+    /// assert_eq!(keys.polynomial_size(), PolynomialSize(256));
+    /// ```
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    /// Returns the number of automorphisms in the set, which is the logarithm of the polynomial
+    /// size.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweAutomorphismKeys;
+    /// let keys = GlweAutomorphismKeys::allocate(
+    ///     0 as u8,
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(16),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    /// );
+    /// assert_eq!(keys.automorphism_count(), 8);
+    /// ```
+    pub fn automorphism_count(&self) -> usize
+    where
+        Self: AsRefTensor,
+    {
+        self.as_tensor().len() / self.automorphism_key_size()
+    }
+
+    /// Returns the number of levels used in the decomposition.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweAutomorphismKeys;
+    /// let keys = GlweAutomorphismKeys::allocate(
+    ///     0 as u8,
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(16),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    /// );
+    /// assert_eq!(
+    ///     keys.decomposition_level_count(),
+    ///     DecompositionLevelCount(10)
+    /// );
+    /// ```
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomp_level_count
+    }
+
+    /// Returns the logarithm of the base used in the decomposition.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweAutomorphismKeys;
+    /// let keys = GlweAutomorphismKeys::allocate(
+    ///     0 as u8,
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(16),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    /// );
+    /// assert_eq!(keys.decomposition_base_log(), DecompositionBaseLog(16));
+    /// ```
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    // Returns the number of elements of the key of a single automorphism.
+    fn automorphism_key_size(&self) -> usize {
+        self.decomp_level_count.0
+            * self.glwe_dimension().0
+            * self.glwe_size.0
+            * self.polynomial_size.0
+    }
+
+    /// Fills the current container with actual automorphism keys for a GLWE secret key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweAutomorphismKeys;
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::GlweSecretKey;
+    /// use concrete_core::commons::math::tensor::AsRefTensor;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// let noise = LogStandardDev::from_log_standard_dev(-15.);
+    ///
+    /// let glwe_key = GlweSecretKey::generate_binary(
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     &mut secret_generator,
+    /// );
+    ///
+    /// let mut keys = GlweAutomorphismKeys::allocate(
+    ///     0 as u32,
+    ///     DecompositionLevelCount(5),
+    ///     DecompositionBaseLog(3),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    /// );
+    /// keys.fill_with_automorphism_keys(&glwe_key, noise, &mut encryption_generator);
+    ///
+    /// assert!(!keys.as_tensor().iter().all(|a| *a == 0));
+    /// ```
+    pub fn fill_with_automorphism_keys<KeyCont, Scalar, Gen>(
+        &mut self,
+        glwe_key: &GlweSecretKey<BinaryKeyKind, KeyCont>,
+        noise_parameters: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator<Gen>,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        GlweSecretKey<BinaryKeyKind, KeyCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+        Gen: ByteRandomGenerator,
+    {
+        ck_dim_eq!(self.glwe_dimension().0 => glwe_key.key_size().0);
+        ck_dim_eq!(self.polynomial_size.0 => glwe_key.polynomial_size().0);
+
+        // We instantiate a buffer
+        let mut messages = PlaintextList::from_container(vec![
+            Scalar::ZERO;
+            self.decomp_level_count.0
+                * self.polynomial_size.0
+        ]);
+
+        // We retrieve decomposition arguments
+        let decomp_level_count = self.decomp_level_count;
+        let decomp_base_log = self.decomp_base_log;
+        let glwe_dimension = self.glwe_dimension();
+        let polynomial_size = self.polynomial_size;
+        let automorphism_key_size = self.automorphism_key_size();
+        let block_size = decomp_level_count.0 * self.glwe_size.0 * polynomial_size.0;
+
+        let mut key_image = Polynomial::allocate(Scalar::ZERO, polynomial_size);
+
+        // loop over the automorphisms X -> X^(2^l + 1)
+        for (automorphism_log, mut automorphism_key) in (1..).zip(
+            self.as_mut_tensor()
+                .subtensor_iter_mut(automorphism_key_size),
+        ) {
+            // loop over the key polynomials
+            for (key_polynomial, keyswitch_key_block) in glwe_key
+                .as_polynomial_list()
+                .polynomial_iter()
+                .zip(automorphism_key.subtensor_iter_mut(block_size))
+            {
+                // We compute the image of the key polynomial by the automorphism
+                fill_with_automorphism(
+                    key_image.as_mut_tensor().as_mut_slice(),
+                    key_polynomial.as_tensor().as_slice(),
+                    (1 << automorphism_log) + 1,
+                );
+
+                // We fill the buffer with the powers of the image of the key polynomial
+                for (level, mut message) in (1..=decomp_level_count.0)
+                    .map(DecompositionLevel)
+                    .zip(messages.sublist_iter_mut(PlaintextCount(polynomial_size.0)))
+                {
+                    for (message_coefficient, key_coefficient) in message
+                        .as_mut_tensor()
+                        .iter_mut()
+                        .zip(key_image.coefficient_iter())
+                    {
+                        *message_coefficient =
+                            DecompositionTerm::new(level, decomp_base_log, *key_coefficient)
+                                .to_recomposition_summand();
+                    }
+                }
+
+                // We encrypt the buffer
+                glwe_key.encrypt_glwe_list(
+                    &mut GlweList::from_container(
+                        keyswitch_key_block.into_container(),
+                        glwe_dimension,
+                        polynomial_size,
+                    ),
+                    &messages,
+                    noise_parameters,
+                    generator,
+                );
+            }
+        }
+    }
+
+    /// Applies the automorphism $X \mapsto X^{2^l + 1}$ to a GLWE ciphertext, where $l$ is the
+    /// `automorphism_log`, and switches the result back to the original key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, GlweSize, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::encoding::PlaintextList;
+    /// use concrete_core::commons::crypto::glwe::{GlweAutomorphismKeys, GlweCiphertext};
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::GlweSecretKey;
+    /// use concrete_core::commons::math::tensor::{AsMutTensor, AsRefTensor};
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// let noise = LogStandardDev::from_log_standard_dev(-25.);
+    ///
+    /// let glwe_key = GlweSecretKey::generate_binary(
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     &mut secret_generator,
+    /// );
+    /// let mut keys = GlweAutomorphismKeys::allocate(
+    ///     0 as u32,
+    ///     DecompositionLevelCount(6),
+    ///     DecompositionBaseLog(4),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    /// );
+    /// keys.fill_with_automorphism_keys(&glwe_key, noise, &mut encryption_generator);
+    ///
+    /// // We encrypt the message X
+    /// let mut messages = PlaintextList::from_container(vec![0_u32; 256]);
+    /// *messages.as_mut_tensor().get_element_mut(1) = 1 << 28;
+    /// let mut input = GlweCiphertext::allocate(0_u32, PolynomialSize(256), GlweSize(3));
+    /// glwe_key.encrypt_glwe(&mut input, &messages, noise, &mut encryption_generator);
+    ///
+    /// let mut output = GlweCiphertext::allocate(0_u32, PolynomialSize(256), GlweSize(3));
+    /// keys.apply_automorphism(&mut output, &input, 1);
+    ///
+    /// let mut decrypted = PlaintextList::from_container(vec![0_u32; 256]);
+    /// glwe_key.decrypt_glwe(&mut decrypted, &output);
+    /// for (i, decrypted) in decrypted.as_tensor().iter().enumerate() {
+    ///     // The message is now X^3
+    ///     let expected = if i == 3 { 1_u32 << 28 } else { 0 };
+    ///     let distance = decrypted.wrapping_sub(expected).min(expected.wrapping_sub(*decrypted));
+    ///     assert!(distance < 1 << 24);
+    /// }
+    /// ```
+    pub fn apply_automorphism<InCont, OutCont, Scalar>(
+        &self,
+        output: &mut GlweCiphertext<OutCont>,
+        input: &GlweCiphertext<InCont>,
+        automorphism_log: usize,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        GlweCiphertext<InCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.glwe_size.0 => input.size().0);
+        ck_dim_eq!(self.polynomial_size.0 => input.polynomial_size().0);
+        ck_dim_eq!(self.glwe_size.0 => output.size().0);
+        ck_dim_eq!(self.polynomial_size.0 => output.polynomial_size().0);
+        assert!(
+            1 <= automorphism_log && automorphism_log <= self.automorphism_count(),
+            "The automorphism logarithm must be between 1 and {}, got {}.",
+            self.automorphism_count(),
+            automorphism_log
+        );
+
+        let polynomial_size = self.polynomial_size;
+        let exponent = (1 << automorphism_log) + 1;
+
+        // We reset the output, and set its body to the image of the input body
+        output.as_mut_tensor().fill_with_element(Scalar::ZERO);
+        let (input_body, input_mask) = input.get_body_and_mask();
+        fill_with_automorphism(
+            output.get_mut_body().as_mut_tensor().as_mut_slice(),
+            input_body.as_tensor().as_slice(),
+            exponent,
+        );
+
+        // We compute the image of the input mask polynomials
+        let mut mask_image =
+            Tensor::allocate(Scalar::ZERO, input.mask_size().0 * polynomial_size.0);
+        for (mut image_polynomial, input_polynomial) in mask_image
+            .subtensor_iter_mut(polynomial_size.0)
+            .zip(input_mask.as_tensor().subtensor_iter(polynomial_size.0))
+        {
+            fill_with_automorphism(
+                image_polynomial.as_mut_slice(),
+                input_polynomial.as_slice(),
+                exponent,
+            );
+        }
+
+        // We instantiate a decomposer
+        let decomposer = SignedDecomposer::new(self.decomp_base_log, self.decomp_level_count);
+        let mut decomposition = decomposer.decompose_tensor(&mask_image);
+
+        let mut product = Polynomial::allocate(Scalar::ZERO, polynomial_size);
+        let level_size = self.glwe_size.0 * polynomial_size.0;
+        let block_size = self.decomp_level_count.0 * level_size;
+        let automorphism_key = self
+            .as_tensor()
+            .get_sub((automorphism_log - 1) * self.automorphism_key_size()..)
+            .into_container();
+
+        // Loop over the levels of the decomposition:
+        // We compute the multiplication of a ciphertext from the automorphism key with a
+        // polynomial of the decomposition and subtract it to the output
+        while let Some(term) = decomposition.next_term() {
+            let level = term.level().0;
+            for (mask_polynomial, block) in term
+                .as_tensor()
+                .subtensor_iter(polynomial_size.0)
+                .map(Polynomial::from_tensor)
+                .zip(automorphism_key.chunks(block_size))
+            {
+                let key_ciphertext = GlweCiphertext::from_container(
+                    &block[(level - 1) * level_size..level * level_size],
+                    polynomial_size,
+                );
+                for (key_polynomial, mut output_polynomial) in key_ciphertext
+                    .as_polynomial_list()
+                    .polynomial_iter()
+                    .zip(output.as_mut_polynomial_list().polynomial_iter_mut())
+                {
+                    product.fill_with_karatsuba_mul(&key_polynomial, &mask_polynomial);
+                    output_polynomial.update_with_wrapping_sub(&product);
+                }
+            }
+        }
+    }
+
+    /// Packs a list of LWE ciphertexts into a single GLWE ciphertext, by recursively merging
+    /// pairs of GLWE ciphertexts with automorphisms.
+    ///
+    /// The input ciphertexts must be encrypted under the LWE key obtained by flattening the GLWE
+    /// key of the automorphism keys, and their number $n$ must be a power of two smaller than the
+    /// polynomial size $N$. The message of the $i$-th input ciphertext is placed on the
+    /// coefficient of degree $i \cdot N / n$ of the output, and the other coefficients encrypt
+    /// zero. Compared to a packing keyswitch, the noise of the output grows with $\log\_2(N)$
+    /// instead of $n$.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     CiphertextCount, DecompositionBaseLog, DecompositionLevelCount, GlweDimension, GlweSize,
+    ///     LweSize, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::encoding::PlaintextList;
+    /// use concrete_core::commons::crypto::glwe::{GlweAutomorphismKeys, GlweCiphertext};
+    /// use concrete_core::commons::crypto::lwe::LweList;
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::GlweSecretKey;
+    /// use concrete_core::commons::math::tensor::AsRefTensor;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// let noise = LogStandardDev::from_log_standard_dev(-50.);
+    ///
+    /// let glwe_key = GlweSecretKey::generate_binary(
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     &mut secret_generator,
+    /// );
+    /// let mut keys = GlweAutomorphismKeys::allocate(
+    ///     0 as u64,
+    ///     DecompositionLevelCount(6),
+    ///     DecompositionBaseLog(8),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    /// );
+    /// keys.fill_with_automorphism_keys(&glwe_key, noise, &mut encryption_generator);
+    ///
+    /// let lwe_key = glwe_key.clone().into_lwe_secret_key();
+    /// let messages = PlaintextList::from_container((1..=4).map(|i| i << 60).collect::<Vec<u64>>());
+    /// let mut input = LweList::allocate(0_u64, LweSize(513), CiphertextCount(4));
+    /// lwe_key.encrypt_lwe_list(&mut input, &messages, noise, &mut encryption_generator);
+    ///
+    /// let mut output = GlweCiphertext::allocate(0_u64, PolynomialSize(256), GlweSize(3));
+    /// keys.tree_pack(&mut output, &input);
+    ///
+    /// let mut decrypted = PlaintextList::from_container(vec![0_u64; 256]);
+    /// glwe_key.decrypt_glwe(&mut decrypted, &output);
+    /// for (i, decrypted) in decrypted.as_tensor().iter().enumerate() {
+    ///     // The messages are spread over the coefficients of degree 0, 64, 128 and 192
+    ///     let expected = if i % 64 == 0 { (i as u64 / 64 + 1) << 60 } else { 0 };
+    ///     let distance = decrypted.wrapping_sub(expected).min(expected.wrapping_sub(*decrypted));
+    ///     assert!(distance < 1 << 50);
+    /// }
+    /// ```
+    pub fn tree_pack<InCont, OutCont, Scalar>(
+        &self,
+        output: &mut GlweCiphertext<OutCont>,
+        input: &LweList<InCont>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        LweList<InCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.glwe_dimension().0 * self.polynomial_size.0 => input.mask_size().0);
+        ck_dim_eq!(self.glwe_size.0 => output.size().0);
+        ck_dim_eq!(self.polynomial_size.0 => output.polynomial_size().0);
+        let count = input.count().0;
+        assert!(
+            count.is_power_of_two() && count <= self.polynomial_size.0,
+            "The number of input ciphertexts must be a power of two smaller than the polynomial \
+            size, got {}.",
+            count
+        );
+
+        let polynomial_size = self.polynomial_size;
+        let count_log = count.trailing_zeros() as usize;
+        let polynomial_size_log = self.automorphism_count();
+
+        // We convert the input ciphertexts to GLWE ciphertexts whose constant coefficient encrypts
+        // the message. The coefficients are divided by the polynomial size with rounding, to
+        // compensate the factor accumulated by the merges.
+        let mut ciphertexts: Vec<_> = input
+            .ciphertext_iter()
+            .map(|lwe| {
+                let mut glwe =
+                    GlweCiphertext::allocate(Scalar::ZERO, polynomial_size, self.glwe_size);
+                let (lwe_body, lwe_mask) = lwe.get_body_and_mask();
+                let (mut glwe_body, mut glwe_mask) = glwe.get_mut_body_and_mask();
+                *glwe_body.as_mut_tensor().first_mut() =
+                    divide_with_rounding(lwe_body.0, polynomial_size_log);
+                for (mut glwe_polynomial, lwe_polynomial) in glwe_mask
+                    .as_mut_tensor()
+                    .subtensor_iter_mut(polynomial_size.0)
+                    .zip(lwe_mask.as_tensor().subtensor_iter(polynomial_size.0))
+                {
+                    let glwe_polynomial = glwe_polynomial.as_mut_slice();
+                    let lwe_polynomial = lwe_polynomial.as_slice();
+                    glwe_polynomial[0] =
+                        divide_with_rounding(lwe_polynomial[0], polynomial_size_log);
+                    for i in 1..polynomial_size.0 {
+                        glwe_polynomial[polynomial_size.0 - i] =
+                            divide_with_rounding(lwe_polynomial[i], polynomial_size_log)
+                                .wrapping_neg();
+                    }
+                }
+                glwe
+            })
+            .collect();
+        let mut buffer = GlweCiphertext::allocate(Scalar::ZERO, polynomial_size, self.glwe_size);
+
+        // We merge the ciphertexts bottom-up: at level l, the ciphertexts r and r + n / 2^l
+        // are merged into (even + X^(N / 2^l) odd) + tau(even - X^(N / 2^l) odd)
+        for level in 1..=count_log {
+            let stride = count >> level;
+            let (evens, odds) = ciphertexts.split_at_mut(stride);
+            for (even, odd) in evens.iter_mut().zip(odds.iter_mut()) {
+                odd.as_mut_polynomial_list()
+                    .update_with_wrapping_monic_monomial_mul(MonomialDegree(
+                        polynomial_size.0 >> level,
+                    ));
+                buffer.as_mut_tensor().fill_with_copy(even.as_tensor());
+                buffer
+                    .as_mut_tensor()
+                    .update_with_wrapping_sub(odd.as_tensor());
+                even.as_mut_tensor()
+                    .update_with_wrapping_add(odd.as_tensor());
+                self.apply_automorphism::<Vec<Scalar>, Vec<Scalar>, Scalar>(odd, &buffer, level);
+                even.as_mut_tensor()
+                    .update_with_wrapping_add(odd.as_tensor());
+            }
+        }
+
+        // We clear the coefficients which do not hold a message, with the remaining automorphisms
+        let packed = &mut ciphertexts[0];
+        for level in count_log + 1..=polynomial_size_log {
+            self.apply_automorphism::<Vec<Scalar>, Vec<Scalar>, Scalar>(&mut buffer, packed, level);
+            packed
+                .as_mut_tensor()
+                .update_with_wrapping_add(buffer.as_tensor());
+        }
+
+        output.as_mut_tensor().fill_with_copy(packed.as_tensor());
+    }
+}
+
+// Fills the coefficients of `output` with those of the image of `input` by the automorphism
+// X -> X^exponent, in the ring of polynomials modulo X^N + 1.
+fn fill_with_automorphism<Scalar>(output: &mut [Scalar], input: &[Scalar], exponent: usize)
+where
+    Scalar: UnsignedTorus,
+{
+    let polynomial_size = input.len();
+    for (degree, coefficient) in input.iter().enumerate() {
+        let image_degree = (degree * exponent) % (2 * polynomial_size);
+        if image_degree < polynomial_size {
+            output[image_degree] = *coefficient;
+        } else {
+            output[image_degree - polynomial_size] = coefficient.wrapping_neg();
+        }
+    }
+}
+
+// Divides a torus element by 2^log, rounding to the closest integer.
+fn divide_with_rounding<Scalar>(value: Scalar, log: usize) -> Scalar
+where
+    Scalar: UnsignedTorus,
+{
+    if log == 0 {
+        return value;
+    }
+    (value >> log).wrapping_add((value >> (log - 1)) & Scalar::ONE)
+}
@@ -1,12 +1,13 @@
-use super::{GlweBody, GlweMask};
+use super::{GlweBody, GlweList, GlweMask};
 use crate::commons::crypto::encoding::{Plaintext, PlaintextList};
 use crate::commons::crypto::lwe::LweCiphertext;
-use crate::commons::math::polynomial::PolynomialList;
+use crate::commons::math::polynomial::{PolynomialList, SparsePolynomial};
 use crate::commons::math::tensor::{
-    tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+    ck_dim_eq, tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
 };
 use crate::commons::math::torus::UnsignedTorus;
-use concrete_commons::numeric::Numeric;
+use crate::commons::utils::{validate_container_length, ContainerValidationError};
+use concrete_commons::numeric::{CastFrom, CastInto, Numeric};
 use concrete_commons::parameters::{GlweDimension, GlweSize, MonomialDegree, PolynomialSize};
 #[cfg(feature = "__commons_serialization")]
 use serde::{Deserialize, Serialize};
@@ -107,6 +108,15 @@ impl<Cont> GlweCiphertext<Cont> {
         GlweSize(self.as_tensor().len() / self.poly_size.0)
     }
 
+    /// Checks that the length of the container is consistent with the polynomial size of the
+    /// ciphertext.
+    pub(crate) fn validate(&self) -> Result<(), ContainerValidationError>
+    where
+        Self: AsRefTensor,
+    {
+        validate_container_length(self.as_tensor().len(), &[self.poly_size.0])
+    }
+
     /// Returns the number of masks of the ciphertext, e.g. its size - 1.
     ///
     /// # Example
@@ -479,6 +489,403 @@ impl<Cont> GlweCiphertext<Cont> {
         }
     }
 
+    /// Fills an LWE ciphertext with the evaluation of the message of the current GLWE ciphertext
+    /// at a public point.
+    ///
+    /// The message $M$ is evaluated as its representative of degree smaller than $N$, that is
+    /// $\sum\_{i=0}^{N-1} m\_i x^i$ for a point $x$, and the output LWE ciphertext is encrypted
+    /// under the LWE key obtained from the GLWE key, like the sample extraction. The noise of the
+    /// input is evaluated the same way, which means that it is amplified by the magnitude of the
+    /// powers of the point.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{GlweDimension, LweDimension, PolynomialSize};
+    /// use concrete_core::commons::crypto::encoding::{Plaintext, PlaintextList};
+    /// use concrete_core::commons::crypto::glwe::GlweCiphertext;
+    /// use concrete_core::commons::crypto::lwe::LweCiphertext;
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::GlweSecretKey;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// let poly_size = PolynomialSize(4);
+    /// let glwe_dim = GlweDimension(2);
+    /// let glwe_secret_key =
+    ///     GlweSecretKey::generate_binary(glwe_dim, poly_size, &mut secret_generator);
+    /// let plaintext_list =
+    ///     PlaintextList::from_container(vec![100000 as u32, 200000, 300000, 400000]);
+    /// let mut glwe_ct = GlweCiphertext::allocate(0u32, poly_size, glwe_dim.to_glwe_size());
+    /// let mut lwe_ct =
+    ///     LweCiphertext::allocate(0u32, LweDimension(poly_size.0 * glwe_dim.0).to_lwe_size());
+    /// glwe_secret_key.encrypt_glwe(
+    ///     &mut glwe_ct,
+    ///     &plaintext_list,
+    ///     LogStandardDev(-25.),
+    ///     &mut encryption_generator,
+    /// );
+    /// let lwe_secret_key = glwe_secret_key.into_lwe_secret_key();
+    ///
+    /// // We evaluate the message at 2
+    /// glwe_ct.fill_lwe_with_polynomial_evaluation(&mut lwe_ct, 2);
+    /// // We decrypt
+    /// let mut output = Plaintext(0u32);
+    /// lwe_secret_key.decrypt_lwe(&mut output, &lwe_ct);
+    /// // We check that the decryption is correct
+    /// let expected = 100000 + 200000 * 2 + 300000 * 4 + 400000 * 8;
+    /// let d0 = output.0.wrapping_sub(expected);
+    /// let d1 = expected.wrapping_sub(output.0);
+    /// let dist = std::cmp::min(d0, d1);
+    /// assert!(dist < 10000);
+    /// ```
+    pub fn fill_lwe_with_polynomial_evaluation<OutputCont, Element>(
+        &self,
+        lwe: &mut LweCiphertext<OutputCont>,
+        point: Element,
+    ) where
+        Self: AsRefTensor<Element = Element>,
+        LweCiphertext<OutputCont>: AsMutTensor<Element = Element>,
+        Element: UnsignedTorus,
+    {
+        ck_dim_eq!(lwe.lwe_size().to_lwe_dimension().0 => self.mask_size().0 * self.poly_size.0);
+
+        // We retrieve the bodies and masks of the two ciphertexts.
+        let (lwe_body, mut lwe_mask) = lwe.get_mut_body_and_mask();
+        let (glwe_body, glwe_mask) = self.get_body_and_mask();
+
+        // We evaluate the body
+        lwe_body.0 = glwe_body.as_polynomial().wrapping_evaluate(point);
+
+        // The coefficient of the mask multiplying the k-th coefficient of a key polynomial is the
+        // evaluation of the product of the mask polynomial by X^k. Multiplying a polynomial P by
+        // X subtracts p_{N-1}.(x^N + 1) from the product of its evaluation by x.
+        let point_power =
+            (0..self.poly_size.0).fold(Element::ONE, |acc, _| acc.wrapping_mul(point));
+        let wrap_factor = point_power.wrapping_add(Element::ONE);
+        for (mut lwe_mask_poly, glwe_mask_poly) in lwe_mask
+            .as_mut_tensor()
+            .subtensor_iter_mut(self.poly_size.0)
+            .zip(glwe_mask.as_polynomial_list().polynomial_iter())
+        {
+            let mut evaluation = glwe_mask_poly.wrapping_evaluate(point);
+            for (lwe_mask_coefficient, glwe_mask_coefficient) in lwe_mask_poly
+                .iter_mut()
+                .zip(glwe_mask_poly.coefficient_iter().rev())
+            {
+                *lwe_mask_coefficient = evaluation;
+                evaluation = evaluation
+                    .wrapping_mul(point)
+                    .wrapping_sub(glwe_mask_coefficient.wrapping_mul(wrap_factor));
+            }
+        }
+    }
+
+    /// Fills a GLWE list of two ciphertexts with the ring split of the current GLWE ciphertext.
+    ///
+    /// The current ciphertext, with polynomials of size $N$ and a GLWE dimension $k$, is split
+    /// into two ciphertexts with polynomials of size $N/2$ and a GLWE dimension $2k$. If the
+    /// current ciphertext encrypts the message $M(X) = M\_e(X^2) + X \cdot M\_o(X^2)$, the first
+    /// output ciphertext encrypts $M\_e$ and the second one encrypts $M\_o$, under the key returned
+    /// by [`GlweSecretKey::to_ring_split_key`](`crate::commons::crypto::secret::GlweSecretKey::to_ring_split_key`).
+    ///
+    /// This operation only rearranges the coefficients of the ciphertext, and does not add any
+    /// noise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{CiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::commons::crypto::encoding::PlaintextList;
+    /// use concrete_core::commons::crypto::glwe::{GlweCiphertext, GlweList};
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::GlweSecretKey;
+    /// use concrete_core::commons::math::tensor::AsRefTensor;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// let poly_size = PolynomialSize(4);
+    /// let glwe_dim = GlweDimension(2);
+    /// let glwe_secret_key =
+    ///     GlweSecretKey::generate_binary(glwe_dim, poly_size, &mut secret_generator);
+    /// let plaintext_list =
+    ///     PlaintextList::from_container(vec![100000 as u32, 200000, 300000, 400000]);
+    /// let mut glwe_ct = GlweCiphertext::allocate(0u32, poly_size, glwe_dim.to_glwe_size());
+    /// glwe_secret_key.encrypt_glwe(
+    ///     &mut glwe_ct,
+    ///     &plaintext_list,
+    ///     LogStandardDev(-25.),
+    ///     &mut encryption_generator,
+    /// );
+    ///
+    /// let mut split_list =
+    ///     GlweList::allocate(0u32, PolynomialSize(2), GlweDimension(4), CiphertextCount(2));
+    /// glwe_ct.fill_glwe_list_with_ring_split(&mut split_list);
+    ///
+    /// let split_key = glwe_secret_key.to_ring_split_key();
+    /// let mut decrypted = PlaintextList::from_container(vec![0u32; 4]);
+    /// split_key.decrypt_glwe_list(&mut decrypted, &split_list);
+    /// // The even coefficients are decrypted first, then the odd ones
+    /// for (decrypted, expected) in decrypted
+    ///     .as_tensor()
+    ///     .iter()
+    ///     .zip([100000u32, 300000, 200000, 400000].iter())
+    /// {
+    ///     let distance = decrypted.wrapping_sub(*expected).min(expected.wrapping_sub(*decrypted));
+    ///     assert!(distance < 400);
+    /// }
+    /// ```
+    pub fn fill_glwe_list_with_ring_split<OutputCont, Element>(
+        &self,
+        output: &mut GlweList<OutputCont>,
+    ) where
+        Self: AsRefTensor<Element = Element>,
+        GlweList<OutputCont>: AsMutTensor<Element = Element>,
+        Element: UnsignedTorus,
+    {
+        ck_dim_eq!(output.ciphertext_count().0 => 2);
+        ck_dim_eq!(output.polynomial_size().0 * 2 => self.poly_size.0);
+        ck_dim_eq!(output.glwe_dimension().0 => 2 * self.mask_size().0);
+
+        let mask_size = self.mask_size().0;
+        let half_size = self.poly_size.0 / 2;
+        let mut ciphertexts = output.ciphertext_iter_mut();
+        let mut even = ciphertexts.next().unwrap().into_polynomial_list();
+        let mut odd = ciphertexts.next().unwrap().into_polynomial_list();
+
+        // The i-th polynomial P(X) = P_e(X^2) + X.P_o(X^2) of the input ciphertext is written at
+        // the position 2i of the output ciphertexts. For the mask polynomials, the polynomials
+        // multiplying the odd polynomials of the key are also written at the position 2i+1.
+        {
+            let even_slice = even.as_mut_tensor().as_mut_slice();
+            let odd_slice = odd.as_mut_tensor().as_mut_slice();
+            for (i, polynomial) in self.as_polynomial_list().polynomial_iter().enumerate() {
+                for (j, pair) in polynomial
+                    .as_tensor()
+                    .as_slice()
+                    .chunks_exact(2)
+                    .enumerate()
+                {
+                    even_slice[2 * i * half_size + j] = pair[0];
+                    odd_slice[2 * i * half_size + j] = pair[1];
+                    if i < mask_size {
+                        even_slice[(2 * i + 1) * half_size + j] = pair[1];
+                        odd_slice[(2 * i + 1) * half_size + j] = pair[0];
+                    }
+                }
+            }
+        }
+
+        // The odd mask polynomials of the even ciphertext are multiplied by X
+        for i in 0..mask_size {
+            even.get_mut_polynomial(2 * i + 1)
+                .update_with_wrapping_monic_monomial_mul(MonomialDegree(1));
+        }
+    }
+
+    /// Fills the current ciphertext with the product of an input ciphertext by a sparse cleartext
+    /// polynomial.
+    ///
+    /// Each polynomial of the input ciphertext is multiplied by the sparse polynomial, as a sum of
+    /// negacyclic rotations scaled by its coefficients. If the input ciphertext encrypts $M$, the
+    /// output ciphertext encrypts $M \cdot P$, where $P$ is the sparse polynomial.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::commons::crypto::encoding::PlaintextList;
+    /// use concrete_core::commons::crypto::glwe::GlweCiphertext;
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::GlweSecretKey;
+    /// use concrete_core::commons::math::polynomial::{MonomialDegree, SparsePolynomial};
+    /// use concrete_core::commons::math::tensor::AsRefTensor;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// let poly_size = PolynomialSize(4);
+    /// let glwe_dim = GlweDimension(2);
+    /// let glwe_secret_key =
+    ///     GlweSecretKey::generate_binary(glwe_dim, poly_size, &mut secret_generator);
+    /// let plaintext_list =
+    ///     PlaintextList::from_container(vec![100000 as u32, 200000, 300000, 400000]);
+    /// let mut glwe_ct = GlweCiphertext::allocate(0u32, poly_size, glwe_dim.to_glwe_size());
+    /// glwe_secret_key.encrypt_glwe(
+    ///     &mut glwe_ct,
+    ///     &plaintext_list,
+    ///     LogStandardDev(-25.),
+    ///     &mut encryption_generator,
+    /// );
+    ///
+    /// // We multiply the message by 2X
+    /// let sparse = SparsePolynomial::from_container(vec![2u32], vec![MonomialDegree(1)], poly_size);
+    /// let mut output = GlweCiphertext::allocate(0u32, poly_size, glwe_dim.to_glwe_size());
+    /// output.fill_with_sparse_polynomial_mul(&glwe_ct, &sparse);
+    ///
+    /// let mut decrypted = PlaintextList::from_container(vec![0u32; 4]);
+    /// glwe_secret_key.decrypt_glwe(&mut decrypted, &output);
+    /// for (decrypted, expected) in decrypted
+    ///     .as_tensor()
+    ///     .iter()
+    ///     .zip([800000u32.wrapping_neg(), 200000, 400000, 600000].iter())
+    /// {
+    ///     let distance = decrypted.wrapping_sub(*expected).min(expected.wrapping_sub(*decrypted));
+    ///     assert!(distance < 1000);
+    /// }
+    /// ```
+    pub fn fill_with_sparse_polynomial_mul<InputCont, SparseCont, Element>(
+        &mut self,
+        input: &GlweCiphertext<InputCont>,
+        sparse_polynomial: &SparsePolynomial<SparseCont>,
+    ) where
+        Self: AsMutTensor<Element = Element>,
+        GlweCiphertext<InputCont>: AsRefTensor<Element = Element>,
+        SparsePolynomial<SparseCont>: AsRefTensor<Element = Element>,
+        Element: UnsignedTorus,
+    {
+        ck_dim_eq!(self.size().0 => input.size().0);
+        ck_dim_eq!(
+            self.poly_size.0 =>
+            input.poly_size.0,
+            sparse_polynomial.polynomial_size().0
+        );
+        for (mut output_polynomial, input_polynomial) in self
+            .as_mut_polynomial_list()
+            .polynomial_iter_mut()
+            .zip(input.as_polynomial_list().polynomial_iter())
+        {
+            output_polynomial.fill_with_wrapping_sparse_mul(&input_polynomial, sparse_polynomial);
+        }
+    }
+
+    /// Fills the current ciphertext with a trivial encryption of the lookup table evaluating a
+    /// function during a bootstrap.
+    ///
+    /// The input messages $m \in [0, p)$ of the bootstrap are expected to be encoded as
+    /// $m\cdot\Delta\_{\mathsf{in}}$, with a padding bit, so that
+    /// $p = q / (2\Delta\_{\mathsf{in}})$. The coefficient $j$ of the body polynomial is set to
+    /// $f(m)\cdot\Delta\_{\mathsf{out}}$, where $m$ is the message closest to the phase
+    /// $j\cdot q / (2N)$. The coefficients closest to $q/2$ are set to
+    /// $-f(0)\cdot\Delta\_{\mathsf{out}}$, so that the small negative phases are also mapped to
+    /// $f(0)$ by the negacyclic rotation of the bootstrap.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{GlweSize, PolynomialSize};
+    /// use concrete_core::commons::crypto::glwe::GlweCiphertext;
+    /// use concrete_core::commons::math::tensor::{AsRefSlice, AsRefTensor};
+    /// let mut lut = GlweCiphertext::allocate(0 as u32, PolynomialSize(16), GlweSize(2));
+    /// // Four messages, the output is encoded with the same scaling factor
+    /// lut.fill_with_lookup_table(&|m| (m + 1) % 4, 1 << 29, 1 << 29);
+    ///
+    /// assert!(lut.get_mask().as_tensor().iter().all(|a| *a == 0));
+    /// let body = lut.get_body();
+    /// let coefficients = body.as_tensor().as_slice();
+    /// assert_eq!(coefficients[0], 1 << 29);
+    /// assert_eq!(coefficients[4], 2 << 29);
+    /// assert_eq!(coefficients[8], 3 << 29);
+    /// assert_eq!(coefficients[12], 0);
+    /// assert_eq!(coefficients[15], (1_u32 << 29).wrapping_neg());
+    /// ```
+    pub fn fill_with_lookup_table<Scalar>(
+        &mut self,
+        function: &dyn Fn(u64) -> u64,
+        input_scaling_factor: Scalar,
+        output_scaling_factor: Scalar,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        Scalar: UnsignedTorus + CastInto<u128> + CastFrom<u64>,
+    {
+        self.fill_with_many_lookup_tables(&[function], input_scaling_factor, output_scaling_factor);
+    }
+
+    /// Fills the current ciphertext with a trivial encryption of several lookup tables, which are
+    /// evaluated by a single bootstrap.
+    ///
+    /// The number of functions $t$ must be a power of two. The coefficients are grouped in blocks
+    /// of $t$ consecutive coefficients, and the coefficient $i$ of a block is filled as the
+    /// coefficient of the first index of the block would be by
+    /// [`GlweCiphertext::fill_with_lookup_table`] for the $i$-th function. A bootstrap which rounds
+    /// the rotation to a multiple of $t$ then evaluates the $i$-th function in its coefficient $i$.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{GlweSize, PolynomialSize};
+    /// use concrete_core::commons::crypto::glwe::GlweCiphertext;
+    /// use concrete_core::commons::math::tensor::{AsRefSlice, AsRefTensor};
+    /// let mut lut = GlweCiphertext::allocate(0 as u32, PolynomialSize(16), GlweSize(2));
+    /// // Four messages, the outputs are encoded with the same scaling factor
+    /// lut.fill_with_many_lookup_tables(&[&|m| (m + 1) % 4, &|m| 3 - m], 1 << 29, 1 << 29);
+    ///
+    /// assert!(lut.get_mask().as_tensor().iter().all(|a| *a == 0));
+    /// let body = lut.get_body();
+    /// let coefficients = body.as_tensor().as_slice();
+    /// assert_eq!(coefficients[0], 1 << 29);
+    /// assert_eq!(coefficients[1], 3 << 29);
+    /// assert_eq!(coefficients[4], 2 << 29);
+    /// assert_eq!(coefficients[5], 2 << 29);
+    /// assert_eq!(coefficients[14], (1_u32 << 29).wrapping_neg());
+    /// assert_eq!(coefficients[15], (3_u32 << 29).wrapping_neg());
+    /// ```
+    pub fn fill_with_many_lookup_tables<Scalar>(
+        &mut self,
+        functions: &[&dyn Fn(u64) -> u64],
+        input_scaling_factor: Scalar,
+        output_scaling_factor: Scalar,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        Scalar: UnsignedTorus + CastInto<u128> + CastFrom<u64>,
+    {
+        debug_assert!(functions.len().is_power_of_two());
+        debug_assert!(functions.len() <= self.poly_size.0);
+        let poly_size = self.poly_size.0 as u128;
+        let half_modulus = 1_u128 << (Scalar::BITS - 1);
+        let input_scaling_factor: u128 = input_scaling_factor.cast_into();
+        let message_count = half_modulus / input_scaling_factor;
+        let encode = |function: &dyn Fn(u64) -> u64, message: u64| {
+            Scalar::cast_from(function(message)).wrapping_mul(output_scaling_factor)
+        };
+
+        let (mut body, mut mask) = self.get_mut_body_and_mask();
+        mask.as_mut_tensor().fill_with_element(Scalar::ZERO);
+        for (block_index, block) in body
+            .as_mut_tensor()
+            .subtensor_iter_mut(functions.len())
+            .enumerate()
+        {
+            // The phase associated with the block, rounded to the closest message
+            let phase = (block_index * functions.len()) as u128 * half_modulus / poly_size;
+            let message = (phase + input_scaling_factor / 2) / input_scaling_factor;
+            for (coefficient, function) in block.into_container().iter_mut().zip(functions) {
+                *coefficient = if message < message_count {
+                    encode(*function, message as u64)
+                } else {
+                    encode(*function, 0).wrapping_neg()
+                };
+            }
+        }
+    }
+
     pub fn fill_with_trivial_encryption<PlaintextContainer, Scalar>(
         &mut self,
         plaintexts: &PlaintextList<PlaintextContainer>,
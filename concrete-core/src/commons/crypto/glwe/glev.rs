@@ -0,0 +1,232 @@
+use super::GlweCiphertext;
+use crate::commons::math::decomposition::DecompositionLevel;
+use crate::commons::math::tensor::{
+    ck_dim_div, tensor_traits, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, GlweSize, PolynomialSize,
+};
+#[cfg(feature = "__commons_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A GLev ciphertext, e.g. a list of GLWE ciphertexts encrypting the same plaintext scaled by the
+/// successive powers of the inverse of a decomposition base.
+///
+/// The ciphertext of level $j$, for $1 \le j \le \ell$, encrypts the plaintext multiplied by
+/// $q / \beta^j$, where $\beta$ is the decomposition base. The levels are stored in increasing
+/// order.
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlevCiphertext<Cont> {
+    pub(crate) tensor: Tensor<Cont>,
+    pub(crate) rlwe_size: GlweSize,
+    pub(crate) poly_size: PolynomialSize,
+    pub(crate) decomp_base_log: DecompositionBaseLog,
+}
+
+tensor_traits!(GlevCiphertext);
+
+impl<Scalar> GlevCiphertext<Vec<Scalar>>
+where
+    Scalar: Copy,
+{
+    /// Allocates storage for an owned [`GlevCiphertext`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, GlweSize, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlevCiphertext;
+    /// let glev = GlevCiphertext::allocate(
+    ///     0 as u8,
+    ///     PolynomialSize(10),
+    ///     GlweDimension(2),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(4),
+    /// );
+    /// assert_eq!(glev.polynomial_size(), PolynomialSize(10));
+    /// assert_eq!(glev.glwe_size(), GlweSize(3));
+    /// assert_eq!(glev.decomposition_level_count(), DecompositionLevelCount(3));
+    /// assert_eq!(glev.decomposition_base_log(), DecompositionBaseLog(4));
+    /// ```
+    pub fn allocate(
+        value: Scalar,
+        poly_size: PolynomialSize,
+        glwe_dimension: GlweDimension,
+        decomp_level: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+    ) -> Self {
+        GlevCiphertext {
+            tensor: Tensor::from_container(vec![
+                value;
+                poly_size.0
+                    * glwe_dimension.to_glwe_size().0
+                    * decomp_level.0
+            ]),
+            rlwe_size: glwe_dimension.to_glwe_size(),
+            poly_size,
+            decomp_base_log,
+        }
+    }
+}
+
+impl<Cont> GlevCiphertext<Cont> {
+    /// Creates a GLev ciphertext from an existing container.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, GlweSize, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlevCiphertext;
+    /// let glev = GlevCiphertext::from_container(
+    ///     vec![0 as u8; 10 * 3 * 3],
+    ///     GlweDimension(2),
+    ///     PolynomialSize(10),
+    ///     DecompositionBaseLog(4),
+    /// );
+    /// assert_eq!(glev.glwe_size(), GlweSize(3));
+    /// assert_eq!(glev.decomposition_level_count(), DecompositionLevelCount(3));
+    /// ```
+    pub fn from_container(
+        cont: Cont,
+        glwe_dimension: GlweDimension,
+        poly_size: PolynomialSize,
+        decomp_base_log: DecompositionBaseLog,
+    ) -> Self
+    where
+        Cont: AsRefSlice,
+    {
+        let tensor = Tensor::from_container(cont);
+        ck_dim_div!(tensor.len() => glwe_dimension.to_glwe_size().0, poly_size.0);
+        GlevCiphertext {
+            tensor,
+            rlwe_size: glwe_dimension.to_glwe_size(),
+            poly_size,
+            decomp_base_log,
+        }
+    }
+
+    /// Returns the size of the GLWE ciphertexts composing the GLev ciphertext.
+    pub fn glwe_size(&self) -> GlweSize {
+        self.rlwe_size
+    }
+
+    /// Returns the size of the polynomials used in the ciphertext.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.poly_size
+    }
+
+    /// Returns the number of decomposition levels of the ciphertext.
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount
+    where
+        Self: AsRefTensor,
+    {
+        ck_dim_div!(self.as_tensor().len() => self.rlwe_size.0, self.poly_size.0);
+        DecompositionLevelCount(self.as_tensor().len() / (self.rlwe_size.0 * self.poly_size.0))
+    }
+
+    /// Returns the logarithm of the base used for the decomposition.
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Returns an iterator over the levels of the ciphertext, with the GLWE ciphertext of each
+    /// level.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlevCiphertext;
+    /// let glev = GlevCiphertext::allocate(
+    ///     0 as u8,
+    ///     PolynomialSize(10),
+    ///     GlweDimension(2),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(4),
+    /// );
+    /// for (level, glwe) in glev.level_iter() {
+    ///     assert!(level.0 >= 1 && level.0 <= 3);
+    ///     assert_eq!(glwe.polynomial_size(), PolynomialSize(10));
+    /// }
+    /// assert_eq!(glev.level_iter().count(), 3);
+    /// ```
+    pub fn level_iter(
+        &self,
+    ) -> impl DoubleEndedIterator<
+        Item = (
+            DecompositionLevel,
+            GlweCiphertext<&[<Self as AsRefTensor>::Element]>,
+        ),
+    >
+    where
+        Self: AsRefTensor,
+    {
+        let poly_size = self.poly_size;
+        let chunks_size = self.rlwe_size.0 * self.poly_size.0;
+        self.as_tensor()
+            .subtensor_iter(chunks_size)
+            .enumerate()
+            .map(move |(index, sub)| {
+                (
+                    DecompositionLevel(index + 1),
+                    GlweCiphertext::from_container(sub.into_container(), poly_size),
+                )
+            })
+    }
+
+    /// Returns an iterator over the levels of the ciphertext, with the mutably borrowed GLWE
+    /// ciphertext of each level.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlevCiphertext;
+    /// use concrete_core::commons::math::tensor::{AsMutTensor, AsRefTensor};
+    /// let mut glev = GlevCiphertext::allocate(
+    ///     0 as u8,
+    ///     PolynomialSize(10),
+    ///     GlweDimension(2),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(4),
+    /// );
+    /// for (level, mut glwe) in glev.level_iter_mut() {
+    ///     glwe.as_mut_tensor().fill_with_element(level.0 as u8);
+    /// }
+    /// for (level, glwe) in glev.level_iter() {
+    ///     assert!(glwe.as_tensor().iter().all(|a| *a == level.0 as u8));
+    /// }
+    /// ```
+    pub fn level_iter_mut(
+        &mut self,
+    ) -> impl DoubleEndedIterator<
+        Item = (
+            DecompositionLevel,
+            GlweCiphertext<&mut [<Self as AsMutTensor>::Element]>,
+        ),
+    >
+    where
+        Self: AsMutTensor,
+    {
+        let poly_size = self.poly_size;
+        let chunks_size = self.rlwe_size.0 * self.poly_size.0;
+        self.as_mut_tensor()
+            .subtensor_iter_mut(chunks_size)
+            .enumerate()
+            .map(move |(index, sub)| {
+                (
+                    DecompositionLevel(index + 1),
+                    GlweCiphertext::from_container(sub.into_container(), poly_size),
+                )
+            })
+    }
+}
@@ -3,10 +3,13 @@ use crate::commons::crypto::encoding::PlaintextList;
 use crate::commons::math::tensor::{
     ck_dim_div, tensor_traits, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
 };
+use crate::commons::utils::{validate_container_length, ContainerValidationError};
 use concrete_commons::numeric::Numeric;
 use concrete_commons::parameters::{
     CiphertextCount, GlweDimension, GlweSize, PlaintextCount, PolynomialSize,
 };
+#[cfg(feature = "__commons_parallel")]
+use rayon::{iter::IndexedParallelIterator, prelude::*};
 #[cfg(feature = "__commons_serialization")]
 use serde::{Deserialize, Serialize};
 
@@ -159,6 +162,18 @@ impl<Cont> GlweList<Cont> {
         self.poly_size
     }
 
+    /// Checks that the length of the container is consistent with the GLWE size and the polynomial
+    /// size of the ciphertexts of the list.
+    pub(crate) fn validate(&self) -> Result<(), ContainerValidationError>
+    where
+        Self: AsRefTensor,
+    {
+        validate_container_length(
+            self.as_tensor().len(),
+            &[self.rlwe_size.0, self.poly_size.0],
+        )
+    }
+
     /// Returns the number of masks of the ciphertexts in the list.
     ///
     /// # Example
@@ -215,6 +230,45 @@ impl<Cont> GlweList<Cont> {
             .map(move |sub| GlweCiphertext::from_container(sub.into_container(), poly_size))
     }
 
+    /// Returns a parallel iterator over ciphertexts borrowed from the list.
+    ///
+    /// # Note:
+    ///
+    /// This iterator is hidden behind the "parallel" feature gate.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{CiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::commons::crypto::glwe::GlweList;
+    /// use rayon::iter::ParallelIterator;
+    /// let list = GlweList::allocate(
+    ///     0 as u8,
+    ///     PolynomialSize(10),
+    ///     GlweDimension(20),
+    ///     CiphertextCount(30),
+    /// );
+    /// list.par_ciphertext_iter().for_each(|glwe| {
+    ///     assert_eq!(glwe.polynomial_size(), PolynomialSize(10));
+    /// });
+    /// assert_eq!(list.par_ciphertext_iter().count(), 30);
+    /// ```
+    #[cfg(feature = "__commons_parallel")]
+    pub fn par_ciphertext_iter(
+        &self,
+    ) -> impl IndexedParallelIterator<Item = GlweCiphertext<&[<Self as AsRefTensor>::Element]>>
+    where
+        Self: AsRefTensor,
+        <Self as AsRefTensor>::Element: Sync,
+    {
+        ck_dim_div!(self.as_tensor().len() => self.rlwe_size.0, self.poly_size.0);
+        let poly_size = self.poly_size;
+        let size = self.rlwe_size.0 * self.polynomial_size().0;
+        self.as_tensor()
+            .par_subtensor_iter(size)
+            .map(move |sub| GlweCiphertext::from_container(sub.into_container(), poly_size))
+    }
+
     /// Returns an iterator over ciphertexts borrowed from the list.
     ///
     /// # Example
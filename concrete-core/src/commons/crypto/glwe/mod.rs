@@ -1,17 +1,32 @@
 //! GLWE encryption scheme
 
+mod automorphism;
 mod body;
 mod ciphertext;
+mod glev;
 mod keyswitch;
 mod list;
 mod mask;
+mod relinearization;
+mod ring_reduction;
+mod ring_switching;
 mod seeded_ciphertext;
+mod seeded_keyswitch;
 mod seeded_list;
 
+pub use automorphism::*;
 pub use body::*;
 pub use ciphertext::*;
+pub use glev::*;
 pub use keyswitch::*;
 pub use list::*;
 pub use mask::*;
+pub use relinearization::*;
+pub use ring_reduction::*;
+pub use ring_switching::*;
 pub use seeded_ciphertext::*;
+pub use seeded_keyswitch::*;
 pub use seeded_list::*;
+
+#[cfg(test)]
+mod tests;
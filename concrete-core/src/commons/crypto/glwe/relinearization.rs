@@ -0,0 +1,501 @@
+use super::{GlweCiphertext, GlweList};
+use crate::commons::crypto::encoding::PlaintextList;
+use crate::commons::crypto::secret::generators::EncryptionRandomGenerator;
+use crate::commons::crypto::secret::GlweSecretKey;
+use crate::commons::math::decomposition::{
+    DecompositionLevel, DecompositionTerm, SignedDecomposer,
+};
+use crate::commons::math::polynomial::Polynomial;
+use crate::commons::math::random::ByteRandomGenerator;
+use crate::commons::math::tensor::{
+    ck_dim_eq, tensor_traits, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+};
+use crate::commons::math::torus::UnsignedTorus;
+use concrete_commons::dispersion::DispersionParameter;
+use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, GlweSize, PlaintextCount,
+    PolynomialSize,
+};
+#[cfg(feature = "__commons_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A relinearization key.
+///
+/// A relinearization key allows to switch the tensor product of two GLWE ciphertexts encrypted
+/// under the same GLWE secret key $\vec{S}$, which is encrypted under the associated
+/// [tensor product key](`crate::commons::crypto::secret::GlweTensorProductSecretKey`), back to a
+/// GLWE ciphertext encrypted under $\vec{S}$.
+///
+/// The key contains, for each of the $k(k+1)/2$ quadratic polynomials $S\_i \cdot S\_j$ ($i \le j$)
+/// of the tensor product key, taken in the order of the tensor product key, and for each
+/// decomposition level, a GLWE encryption under $\vec{S}$ of this polynomial scaled by the level.
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweRelinearizationKey<Cont> {
+    tensor: Tensor<Cont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    glwe_size: GlweSize,
+    poly_size: PolynomialSize,
+}
+
+tensor_traits!(GlweRelinearizationKey);
+
+/// Returns the number of quadratic polynomials in the tensor product key of a GLWE key.
+fn quadratic_polynomial_count(glwe_dimension: GlweDimension) -> usize {
+    glwe_dimension.0 * (glwe_dimension.0 + 1) / 2
+}
+
+impl<Scalar> GlweRelinearizationKey<Vec<Scalar>>
+where
+    Scalar: Copy,
+{
+    /// Allocates a relinearization key whose masks and bodies are all `value`.
+    ///
+    /// # Note
+    ///
+    /// This function does *not* generate a relinearization key, but merely allocates a container
+    /// of the right size. See [`GlweRelinearizationKey::fill_with_relinearization_key`] to fill
+    /// the container with a proper relinearization key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweRelinearizationKey;
+    /// let rlk = GlweRelinearizationKey::allocate(
+    ///     0 as u8,
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(16),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    /// );
+    /// assert_eq!(
+    ///     rlk.decomposition_level_count(),
+    ///     DecompositionLevelCount(10)
+    /// );
+    /// assert_eq!(rlk.decomposition_base_log(), DecompositionBaseLog(16));
+    /// assert_eq!(rlk.glwe_dimension(), GlweDimension(2));
+    /// assert_eq!(rlk.polynomial_size(), PolynomialSize(256));
+    /// ```
+    pub fn allocate(
+        value: Scalar,
+        decomp_size: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Self {
+        GlweRelinearizationKey {
+            tensor: Tensor::from_container(vec![
+                value;
+                decomp_size.0
+                    * glwe_dimension.to_glwe_size().0
+                    * polynomial_size.0
+                    * quadratic_polynomial_count(glwe_dimension)
+            ]),
+            decomp_base_log,
+            decomp_level_count: decomp_size,
+            glwe_size: glwe_dimension.to_glwe_size(),
+            poly_size: polynomial_size,
+        }
+    }
+}
+
+impl<Cont> GlweRelinearizationKey<Cont> {
+    /// Creates a relinearization key from a container.
+    ///
+    /// # Notes
+    ///
+    /// This method does not create a relinearization key, but merely wraps the container in the
+    /// proper type. It assumes that either the container already contains a proper
+    /// relinearization key, or that [`GlweRelinearizationKey::fill_with_relinearization_key`] will
+    /// be called right after.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweRelinearizationKey;
+    /// let rlk = GlweRelinearizationKey::from_container(
+    ///     vec![0 as u8; 3 * 10 * 3 * 256],
+    ///     DecompositionBaseLog(16),
+    ///     DecompositionLevelCount(10),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    /// );
+    /// assert_eq!(rlk.glwe_dimension(), GlweDimension(2));
+    /// ```
+    pub fn from_container(
+        cont: Cont,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_size: DecompositionLevelCount,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> GlweRelinearizationKey<Cont>
+    where
+        Cont: AsRefSlice,
+    {
+        let tensor = Tensor::from_container(cont);
+        ck_dim_eq!(
+            tensor.len() =>
+            decomp_size.0
+                * glwe_dimension.to_glwe_size().0
+                * polynomial_size.0
+                * quadratic_polynomial_count(glwe_dimension)
+        );
+        GlweRelinearizationKey {
+            tensor,
+            decomp_base_log,
+            decomp_level_count: decomp_size,
+            glwe_size: glwe_dimension.to_glwe_size(),
+            poly_size: polynomial_size,
+        }
+    }
+
+    /// Returns the dimension of the GLWE key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweRelinearizationKey;
+    /// let rlk = GlweRelinearizationKey::allocate(
+    ///     0 as u8,
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(16),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    /// );
+    /// assert_eq!(rlk.glwe_dimension(), GlweDimension(2));
+    /// ```
+    pub fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_size.to_glwe_dimension()
+    }
+
+    /// Returns the size of the polynomials of the GLWE key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweRelinearizationKey;
+    /// let rlk = GlweRelinearizationKey::allocate(
+    ///     0 as u8,
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(16),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    /// );
+    /// assert_eq!(rlk.polynomial_size(), PolynomialSize(256));
+    /// ```
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.poly_size
+    }
+
+    /// Returns the number of levels used for the decomposition of the quadratic masks.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweRelinearizationKey;
+    /// let rlk = GlweRelinearizationKey::allocate(
+    ///     0 as u8,
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(16),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    /// );
+    /// assert_eq!(
+    ///     rlk.decomposition_level_count(),
+    ///     DecompositionLevelCount(10)
+    /// );
+    /// ```
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomp_level_count
+    }
+
+    /// Returns the logarithm of the base used for the decomposition of the quadratic masks.
+    ///
+    /// Indeed, the basis used is always of the form $2^b$. This function returns $b$.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweRelinearizationKey;
+    /// let rlk = GlweRelinearizationKey::allocate(
+    ///     0 as u8,
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(16),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    /// );
+    /// assert_eq!(rlk.decomposition_base_log(), DecompositionBaseLog(16));
+    /// ```
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Fills the current relinearization key container with an actual relinearization key
+    /// constructed from a GLWE secret key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweRelinearizationKey;
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::GlweSecretKey;
+    /// use concrete_core::commons::math::tensor::AsRefTensor;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// let noise = LogStandardDev::from_log_standard_dev(-15.);
+    ///
+    /// let key = GlweSecretKey::generate_binary(
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     &mut secret_generator,
+    /// );
+    ///
+    /// let mut rlk = GlweRelinearizationKey::allocate(
+    ///     0 as u32,
+    ///     DecompositionLevelCount(5),
+    ///     DecompositionBaseLog(3),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    /// );
+    /// rlk.fill_with_relinearization_key(&key, noise, &mut encryption_generator);
+    ///
+    /// assert!(!rlk.as_tensor().iter().all(|a| *a == 0));
+    /// ```
+    pub fn fill_with_relinearization_key<KeyCont, Scalar, Gen>(
+        &mut self,
+        glwe_key: &GlweSecretKey<BinaryKeyKind, KeyCont>,
+        noise_parameters: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator<Gen>,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        GlweSecretKey<BinaryKeyKind, KeyCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+        Gen: ByteRandomGenerator,
+    {
+        ck_dim_eq!(self.glwe_dimension().0 => glwe_key.key_size().0);
+        ck_dim_eq!(self.poly_size.0 => glwe_key.polynomial_size().0);
+
+        // We instantiate a buffer
+        let mut messages = PlaintextList::from_container(vec![
+            Scalar::ZERO;
+            self.decomp_level_count.0
+                * self.poly_size.0
+        ]);
+
+        // We retrieve decomposition arguments
+        let decomp_level_count = self.decomp_level_count;
+        let decomp_base_log = self.decomp_base_log;
+        let glwe_dimension = self.glwe_dimension();
+        let polynomial_size = self.poly_size;
+        let block_size = decomp_level_count.0 * self.glwe_size.0 * polynomial_size.0;
+
+        // loop over the quadratic polynomials of the tensor product key, which come after the
+        // linear ones
+        let tensor_product_key = glwe_key.create_tensor_product_key();
+        for (key_polynomial, relinearization_key_block) in tensor_product_key
+            .as_polynomial_list()
+            .polynomial_iter()
+            .skip(glwe_dimension.0)
+            .zip(self.as_mut_tensor().subtensor_iter_mut(block_size))
+        {
+            // We fill the buffer with the powers of the key polynomial
+            for (level, mut message) in (1..=decomp_level_count.0)
+                .map(DecompositionLevel)
+                .zip(messages.sublist_iter_mut(PlaintextCount(polynomial_size.0)))
+            {
+                for (message_coefficient, key_coefficient) in message
+                    .as_mut_tensor()
+                    .iter_mut()
+                    .zip(key_polynomial.coefficient_iter())
+                {
+                    *message_coefficient =
+                        DecompositionTerm::new(level, decomp_base_log, *key_coefficient)
+                            .to_recomposition_summand();
+                }
+            }
+
+            // We encrypt the buffer
+            glwe_key.encrypt_glwe_list(
+                &mut GlweList::from_container(
+                    relinearization_key_block.into_container(),
+                    glwe_dimension,
+                    polynomial_size,
+                ),
+                &messages,
+                noise_parameters,
+                generator,
+            );
+        }
+    }
+
+    /// Relinearizes the tensor product of two GLWE ciphertexts.
+    ///
+    /// The `input` ciphertext is encrypted under the tensor product key of the GLWE key of the
+    /// relinearization key, and the `output` ciphertext is filled with an encryption of the same
+    /// message under the GLWE key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, GlweSize, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::encoding::PlaintextList;
+    /// use concrete_core::commons::crypto::glwe::{GlweCiphertext, GlweRelinearizationKey};
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::GlweSecretKey;
+    /// use concrete_core::commons::math::tensor::{AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor};
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// let noise = LogStandardDev::from_log_standard_dev(-25.);
+    ///
+    /// let key = GlweSecretKey::generate_binary(
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     &mut secret_generator,
+    /// );
+    /// let mut rlk = GlweRelinearizationKey::allocate(
+    ///     0 as u32,
+    ///     DecompositionLevelCount(7),
+    ///     DecompositionBaseLog(4),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    /// );
+    /// rlk.fill_with_relinearization_key(&key, noise, &mut encryption_generator);
+    ///
+    /// // We build a ciphertext under the tensor product key, whose quadratic masks are all zeros
+    /// // but one.
+    /// let messages = PlaintextList::from_container(vec![1_u32 << 28; 256]);
+    /// let mut ciphertext = GlweCiphertext::allocate(0_u32, PolynomialSize(256), GlweSize(3));
+    /// key.encrypt_glwe(&mut ciphertext, &messages, noise, &mut encryption_generator);
+    /// let mut input = GlweCiphertext::allocate(0_u32, PolynomialSize(256), GlweSize(6));
+    /// input.as_mut_tensor().as_mut_slice()[..2 * 256]
+    ///     .copy_from_slice(&ciphertext.as_tensor().as_slice()[..2 * 256]);
+    /// input.as_mut_tensor().as_mut_slice()[5 * 256..]
+    ///     .copy_from_slice(&ciphertext.as_tensor().as_slice()[2 * 256..]);
+    /// input.as_mut_tensor().as_mut_slice()[2 * 256] = 1_u32;
+    /// let tensor_product_key = key.create_tensor_product_key();
+    /// for (body, key) in input.as_mut_tensor().as_mut_slice()[5 * 256..]
+    ///     .iter_mut()
+    ///     .zip(tensor_product_key.as_tensor().as_slice()[2 * 256..3 * 256].iter())
+    /// {
+    ///     *body = body.wrapping_add(*key);
+    /// }
+    ///
+    /// let mut output = GlweCiphertext::allocate(0_u32, PolynomialSize(256), GlweSize(3));
+    /// rlk.relinearize(&mut output, &input);
+    ///
+    /// let mut decrypted = PlaintextList::from_container(vec![0_u32; 256]);
+    /// key.decrypt_glwe(&mut decrypted, &output);
+    /// for decrypted in decrypted.as_tensor().iter() {
+    ///     let expected = 1_u32 << 28;
+    ///     let distance = decrypted.wrapping_sub(expected).min(expected.wrapping_sub(*decrypted));
+    ///     assert!(distance < 1 << 24);
+    /// }
+    /// ```
+    pub fn relinearize<InCont, OutCont, Scalar>(
+        &self,
+        output: &mut GlweCiphertext<OutCont>,
+        input: &GlweCiphertext<InCont>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        GlweCiphertext<InCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        let glwe_dimension = self.glwe_dimension();
+        ck_dim_eq!(glwe_dimension.tensor_product_dimension().0 => input.mask_size().0);
+        ck_dim_eq!(self.poly_size.0 => input.polynomial_size().0, output.polynomial_size().0);
+        ck_dim_eq!(self.glwe_size.0 => output.size().0);
+
+        let polynomial_size = self.poly_size;
+
+        // We copy the input body and the linear part of the input mask to the output
+        let (input_body, input_mask) = input.get_body_and_mask();
+        let (linear_mask, quadratic_mask) = input_mask
+            .as_tensor()
+            .as_slice()
+            .split_at(glwe_dimension.0 * polynomial_size.0);
+        {
+            let (mut output_body, mut output_mask) = output.get_mut_body_and_mask();
+            output_body
+                .as_mut_tensor()
+                .fill_with_copy(input_body.as_tensor());
+            output_mask
+                .as_mut_tensor()
+                .fill_with_copy(&Tensor::from_container(linear_mask));
+        }
+
+        // We instantiate a decomposer
+        let decomposer = SignedDecomposer::new(self.decomp_base_log, self.decomp_level_count);
+        let mut decomposition =
+            decomposer.decompose_tensor(&Tensor::from_container(quadratic_mask));
+
+        let mut product = Polynomial::allocate(Scalar::ZERO, polynomial_size);
+        let level_size = self.glwe_size.0 * polynomial_size.0;
+        let block_size = self.decomp_level_count.0 * level_size;
+
+        // Loop over the levels of the decomposition:
+        // We compute the multiplication of a ciphertext from the relinearization key with a
+        // polynomial of the decomposition and subtract it to the output
+        while let Some(term) = decomposition.next_term() {
+            let level = term.level().0;
+            for (mask_polynomial, block) in term
+                .as_tensor()
+                .subtensor_iter(polynomial_size.0)
+                .map(Polynomial::from_tensor)
+                .zip(self.as_tensor().subtensor_iter(block_size))
+            {
+                let key_ciphertext = GlweCiphertext::from_container(
+                    &block.as_slice()[(level - 1) * level_size..level * level_size],
+                    polynomial_size,
+                );
+                for (key_polynomial, mut output_polynomial) in key_ciphertext
+                    .as_polynomial_list()
+                    .polynomial_iter()
+                    .zip(output.as_mut_polynomial_list().polynomial_iter_mut())
+                {
+                    product.fill_with_karatsuba_mul(&key_polynomial, &mask_polynomial);
+                    output_polynomial.update_with_wrapping_sub(&product);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,615 @@
+use super::{GlweCiphertext, GlweList};
+use crate::commons::crypto::encoding::PlaintextList;
+use crate::commons::crypto::secret::generators::EncryptionRandomGenerator;
+use crate::commons::crypto::secret::GlweSecretKey;
+use crate::commons::math::decomposition::{
+    DecompositionLevel, DecompositionTerm, SignedDecomposer,
+};
+use crate::commons::math::polynomial::Polynomial;
+use crate::commons::math::random::ByteRandomGenerator;
+use crate::commons::math::tensor::{
+    ck_dim_div, ck_dim_eq, tensor_traits, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+};
+use crate::commons::math::torus::UnsignedTorus;
+use concrete_commons::dispersion::DispersionParameter;
+use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, GlweSize, MonomialDegree,
+    PlaintextCount, PolynomialSize,
+};
+#[cfg(feature = "__commons_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A ring reduction key.
+///
+/// A ring reduction key is a keyswitch key between two GLWE keys of different polynomial sizes.
+/// It allows to switch a GLWE ciphertext whose polynomials have $N\_1$ coefficients, to a GLWE
+/// ciphertext whose polynomials have $N\_2$ coefficients, where $N\_2$ divides $N\_1$. With
+/// $r = N\_1 / N\_2$, the message $M(X) = \sum\_{u=0}^{r-1} X^u M\_u(X^r)$ of the input
+/// ciphertext becomes the message $M\_0$ of the output ciphertext, which contains the
+/// coefficients of $M$ whose degrees are multiples of $r$. This is for instance the case of all
+/// the messages of a ciphertext packed by
+/// [`GlweAutomorphismKeys::tree_pack`](`super::GlweAutomorphismKeys::tree_pack`).
+///
+/// Every polynomial $S\_j(X) = \sum\_{u=0}^{r-1} X^u S\_{j,u}(X^r)$ of the input key is split in
+/// the same way. With $Y = X^r$, the key contains, for each $t\in[0, r)$ and for each
+/// decomposition level, a GLWE encryption under the output key of $S\_{j,0}$ if $t = 0$, and of
+/// $Y\cdot S\_{j,r-t}$ otherwise, scaled by the level.
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweRingReductionKey<Cont> {
+    tensor: Tensor<Cont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    input_polynomial_size: PolynomialSize,
+    output_glwe_size: GlweSize,
+    output_polynomial_size: PolynomialSize,
+}
+
+tensor_traits!(GlweRingReductionKey);
+
+impl<Scalar> GlweRingReductionKey<Vec<Scalar>>
+where
+    Scalar: Copy,
+{
+    /// Allocates a ring reduction key whose masks and bodies are all `value`.
+    ///
+    /// # Note
+    ///
+    /// This function does *not* generate a ring reduction key, but merely allocates a container
+    /// of the right size. See [`GlweRingReductionKey::fill_with_ring_reduction_key`] to fill the
+    /// container with a proper ring reduction key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweRingReductionKey;
+    /// let rrk = GlweRingReductionKey::allocate(
+    ///     0 as u8,
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(16),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(1024),
+    ///     GlweDimension(4),
+    ///     PolynomialSize(256),
+    /// );
+    /// assert_eq!(
+    ///     rrk.decomposition_level_count(),
+    ///     DecompositionLevelCount(10)
+    /// );
+    /// assert_eq!(rrk.decomposition_base_log(), DecompositionBaseLog(16));
+    /// assert_eq!(rrk.input_glwe_key_dimension(), GlweDimension(2));
+    /// assert_eq!(rrk.input_polynomial_size(), PolynomialSize(1024));
+    /// assert_eq!(rrk.output_glwe_key_dimension(), GlweDimension(4));
+    /// assert_eq!(rrk.output_polynomial_size(), PolynomialSize(256));
+    /// ```
+    pub fn allocate(
+        value: Scalar,
+        decomp_size: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+        input_dimension: GlweDimension,
+        input_polynomial_size: PolynomialSize,
+        output_dimension: GlweDimension,
+        output_polynomial_size: PolynomialSize,
+    ) -> Self {
+        ck_dim_div!(input_polynomial_size.0 => output_polynomial_size.0);
+        GlweRingReductionKey {
+            tensor: Tensor::from_container(vec![
+                value;
+                decomp_size.0
+                    * output_dimension.to_glwe_size().0
+                    * input_polynomial_size.0
+                    * input_dimension.0
+            ]),
+            decomp_base_log,
+            decomp_level_count: decomp_size,
+            input_polynomial_size,
+            output_glwe_size: output_dimension.to_glwe_size(),
+            output_polynomial_size,
+        }
+    }
+}
+
+impl<Cont> GlweRingReductionKey<Cont> {
+    /// Creates a ring reduction key from a container.
+    ///
+    /// # Notes
+    ///
+    /// This method does not create a ring reduction key, but merely wraps the container in the
+    /// proper type. It assumes that either the container already contains a proper ring
+    /// reduction key, or that [`GlweRingReductionKey::fill_with_ring_reduction_key`] will be
+    /// called right after.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweRingReductionKey;
+    /// let rrk = GlweRingReductionKey::from_container(
+    ///     vec![0 as u8; 10 * 5 * 1024 * 2],
+    ///     DecompositionBaseLog(16),
+    ///     DecompositionLevelCount(10),
+    ///     PolynomialSize(1024),
+    ///     GlweDimension(4),
+    ///     PolynomialSize(256),
+    /// );
+    /// assert_eq!(rrk.input_glwe_key_dimension(), GlweDimension(2));
+    /// assert_eq!(rrk.output_glwe_key_dimension(), GlweDimension(4));
+    /// ```
+    pub fn from_container(
+        cont: Cont,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_size: DecompositionLevelCount,
+        input_polynomial_size: PolynomialSize,
+        output_glwe_dimension: GlweDimension,
+        output_polynomial_size: PolynomialSize,
+    ) -> GlweRingReductionKey<Cont>
+    where
+        Cont: AsRefSlice,
+    {
+        let tensor = Tensor::from_container(cont);
+        ck_dim_div!(input_polynomial_size.0 => output_polynomial_size.0);
+        ck_dim_div!(tensor.len() => output_glwe_dimension.to_glwe_size().0 * input_polynomial_size.0, decomp_size.0);
+        GlweRingReductionKey {
+            tensor,
+            decomp_base_log,
+            decomp_level_count: decomp_size,
+            input_polynomial_size,
+            output_glwe_size: output_glwe_dimension.to_glwe_size(),
+            output_polynomial_size,
+        }
+    }
+
+    /// Returns the dimension of the input GLWE key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweRingReductionKey;
+    /// let rrk = GlweRingReductionKey::allocate(
+    ///     0 as u8,
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(16),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(1024),
+    ///     GlweDimension(4),
+    ///     PolynomialSize(256),
+    /// );
+    /// assert_eq!(rrk.input_glwe_key_dimension(), GlweDimension(2));
+    /// ```
+    pub fn input_glwe_key_dimension(&self) -> GlweDimension
+    where
+        Self: AsRefTensor,
+    {
+        GlweDimension(
+            self.as_tensor().len()
+                / (self.output_glwe_size.0
+                    * self.input_polynomial_size.0
+                    * self.decomp_level_count.0),
+        )
+    }
+
+    /// Returns the size of the polynomials of the input GLWE key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweRingReductionKey;
+    /// let rrk = GlweRingReductionKey::allocate(
+    ///     0 as u8,
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(16),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(1024),
+    ///     GlweDimension(4),
+    ///     PolynomialSize(256),
+    /// );
+    /// assert_eq!(rrk.input_polynomial_size(), PolynomialSize(1024));
+    /// ```
+    pub fn input_polynomial_size(&self) -> PolynomialSize {
+        self.input_polynomial_size
+    }
+
+    /// Returns the dimension of the output GLWE key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweRingReductionKey;
+    /// let rrk = GlweRingReductionKey::allocate(
+    ///     0 as u8,
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(16),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(1024),
+    ///     GlweDimension(4),
+    ///     PolynomialSize(256),
+    /// );
+    /// assert_eq!(rrk.output_glwe_key_dimension(), GlweDimension(4));
+    /// ```
+    pub fn output_glwe_key_dimension(&self) -> GlweDimension {
+        self.output_glwe_size.to_glwe_dimension()
+    }
+
+    /// Returns the size of the polynomials of the output GLWE key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweRingReductionKey;
+    /// let rrk = GlweRingReductionKey::allocate(
+    ///     0 as u8,
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(16),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(1024),
+    ///     GlweDimension(4),
+    ///     PolynomialSize(256),
+    /// );
+    /// assert_eq!(rrk.output_polynomial_size(), PolynomialSize(256));
+    /// ```
+    pub fn output_polynomial_size(&self) -> PolynomialSize {
+        self.output_polynomial_size
+    }
+
+    /// Returns the number of levels used for the decomposition of the input masks.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweRingReductionKey;
+    /// let rrk = GlweRingReductionKey::allocate(
+    ///     0 as u8,
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(16),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(1024),
+    ///     GlweDimension(4),
+    ///     PolynomialSize(256),
+    /// );
+    /// assert_eq!(
+    ///     rrk.decomposition_level_count(),
+    ///     DecompositionLevelCount(10)
+    /// );
+    /// ```
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomp_level_count
+    }
+
+    /// Returns the logarithm of the base used for the decomposition of the input masks.
+    ///
+    /// Indeed, the basis used is always of the form $2^b$. This function returns $b$.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweRingReductionKey;
+    /// let rrk = GlweRingReductionKey::allocate(
+    ///     0 as u8,
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(16),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(1024),
+    ///     GlweDimension(4),
+    ///     PolynomialSize(256),
+    /// );
+    /// assert_eq!(rrk.decomposition_base_log(), DecompositionBaseLog(16));
+    /// ```
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Fills the current ring reduction key container with an actual ring reduction key
+    /// constructed from an input and an output key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweRingReductionKey;
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::GlweSecretKey;
+    /// use concrete_core::commons::math::tensor::AsRefTensor;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// let noise = LogStandardDev::from_log_standard_dev(-15.);
+    ///
+    /// let input_key = GlweSecretKey::generate_binary(
+    ///     GlweDimension(1),
+    ///     PolynomialSize(512),
+    ///     &mut secret_generator,
+    /// );
+    /// let output_key = GlweSecretKey::generate_binary(
+    ///     GlweDimension(2),
+    ///     PolynomialSize(128),
+    ///     &mut secret_generator,
+    /// );
+    ///
+    /// let mut rrk = GlweRingReductionKey::allocate(
+    ///     0 as u32,
+    ///     DecompositionLevelCount(5),
+    ///     DecompositionBaseLog(3),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(512),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(128),
+    /// );
+    /// rrk.fill_with_ring_reduction_key(&input_key, &output_key, noise, &mut encryption_generator);
+    ///
+    /// assert!(!rrk.as_tensor().iter().all(|a| *a == 0));
+    /// ```
+    pub fn fill_with_ring_reduction_key<InKeyCont, OutKeyCont, Scalar, Gen>(
+        &mut self,
+        input_glwe_key: &GlweSecretKey<BinaryKeyKind, InKeyCont>,
+        output_glwe_key: &GlweSecretKey<BinaryKeyKind, OutKeyCont>,
+        noise_parameters: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator<Gen>,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        GlweSecretKey<BinaryKeyKind, InKeyCont>: AsRefTensor<Element = Scalar>,
+        GlweSecretKey<BinaryKeyKind, OutKeyCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+        Gen: ByteRandomGenerator,
+    {
+        ck_dim_eq!(self.input_glwe_key_dimension().0 => input_glwe_key.key_size().0);
+        ck_dim_eq!(self.input_polynomial_size.0 => input_glwe_key.polynomial_size().0);
+        ck_dim_eq!(self.output_glwe_key_dimension().0 => output_glwe_key.key_size().0);
+        ck_dim_eq!(self.output_polynomial_size.0 => output_glwe_key.polynomial_size().0);
+
+        // We retrieve decomposition arguments
+        let decomp_level_count = self.decomp_level_count;
+        let decomp_base_log = self.decomp_base_log;
+        let output_glwe_dimension = self.output_glwe_key_dimension();
+        let polynomial_size = self.output_polynomial_size;
+        let ratio = self.input_polynomial_size.0 / polynomial_size.0;
+        let block_size = decomp_level_count.0 * self.output_glwe_size.0 * polynomial_size.0;
+
+        // We instantiate the buffers
+        let mut split_key_polynomial = Polynomial::allocate(Scalar::ZERO, polynomial_size);
+        let mut messages = PlaintextList::from_container(vec![
+            Scalar::ZERO;
+            decomp_level_count.0
+                * polynomial_size.0
+        ]);
+
+        // loop over the input key polynomials
+        let mut keyswitch_key_blocks = self.as_mut_tensor().subtensor_iter_mut(block_size);
+        for input_key_polynomial in input_glwe_key.as_polynomial_list().polynomial_iter() {
+            for t in 0..ratio {
+                let keyswitch_key_block = keyswitch_key_blocks.next().unwrap();
+
+                // We extract S_{j,0} if t = 0, and Y.S_{j,r-t} otherwise
+                let u = (ratio - t) % ratio;
+                for (split_coefficient, key_coefficient) in
+                    split_key_polynomial.coefficient_iter_mut().zip(
+                        input_key_polynomial
+                            .coefficient_iter()
+                            .skip(u)
+                            .step_by(ratio),
+                    )
+                {
+                    *split_coefficient = *key_coefficient;
+                }
+                if t != 0 {
+                    split_key_polynomial.update_with_wrapping_monic_monomial_mul(MonomialDegree(1));
+                }
+
+                // We fill the buffer with the powers of the split key polynomial
+                for (level, mut message) in (1..=decomp_level_count.0)
+                    .map(DecompositionLevel)
+                    .zip(messages.sublist_iter_mut(PlaintextCount(polynomial_size.0)))
+                {
+                    for (message_coefficient, key_coefficient) in message
+                        .as_mut_tensor()
+                        .iter_mut()
+                        .zip(split_key_polynomial.coefficient_iter())
+                    {
+                        *message_coefficient =
+                            DecompositionTerm::new(level, decomp_base_log, *key_coefficient)
+                                .to_recomposition_summand();
+                    }
+                }
+
+                // We encrypt the buffer
+                output_glwe_key.encrypt_glwe_list(
+                    &mut GlweList::from_container(
+                        keyswitch_key_block.into_container(),
+                        output_glwe_dimension,
+                        polynomial_size,
+                    ),
+                    &messages,
+                    noise_parameters,
+                    generator,
+                );
+            }
+        }
+    }
+
+    /// Switches a GLWE ciphertext to a GLWE ciphertext with smaller polynomials, keeping the
+    /// coefficients of the input message whose degrees are multiples of $r = N\_1 / N\_2$.
+    ///
+    /// The other coefficients of the message can be kept instead by multiplying the input
+    /// ciphertext by a monomial $X^{-u}$ beforehand.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, GlweSize, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::encoding::PlaintextList;
+    /// use concrete_core::commons::crypto::glwe::{GlweCiphertext, GlweRingReductionKey};
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::GlweSecretKey;
+    /// use concrete_core::commons::math::tensor::AsRefTensor;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// let noise = LogStandardDev::from_log_standard_dev(-25.);
+    ///
+    /// let input_key = GlweSecretKey::generate_binary(
+    ///     GlweDimension(1),
+    ///     PolynomialSize(512),
+    ///     &mut secret_generator,
+    /// );
+    /// let output_key = GlweSecretKey::generate_binary(
+    ///     GlweDimension(2),
+    ///     PolynomialSize(128),
+    ///     &mut secret_generator,
+    /// );
+    /// let mut rrk = GlweRingReductionKey::allocate(
+    ///     0 as u32,
+    ///     DecompositionLevelCount(6),
+    ///     DecompositionBaseLog(4),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(512),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(128),
+    /// );
+    /// rrk.fill_with_ring_reduction_key(&input_key, &output_key, noise, &mut encryption_generator);
+    ///
+    /// let messages = PlaintextList::from_container(
+    ///     (0..512_u32).map(|i| (i % 16) << 28).collect::<Vec<_>>(),
+    /// );
+    /// let mut input = GlweCiphertext::allocate(0_u32, PolynomialSize(512), GlweSize(2));
+    /// input_key.encrypt_glwe(&mut input, &messages, noise, &mut encryption_generator);
+    ///
+    /// let mut output = GlweCiphertext::allocate(0_u32, PolynomialSize(128), GlweSize(3));
+    /// rrk.ring_reduce(&mut output, &input);
+    ///
+    /// let mut decrypted = PlaintextList::from_container(vec![0_u32; 128]);
+    /// output_key.decrypt_glwe(&mut decrypted, &output);
+    /// for (i, decrypted) in decrypted.as_tensor().iter().enumerate() {
+    ///     // The output message contains the coefficients of degree 4i of the input message
+    ///     let expected = ((4 * i as u32) % 16) << 28;
+    ///     let distance = decrypted.wrapping_sub(expected).min(expected.wrapping_sub(*decrypted));
+    ///     assert!(distance < 1 << 24);
+    /// }
+    /// ```
+    pub fn ring_reduce<InCont, OutCont, Scalar>(
+        &self,
+        output: &mut GlweCiphertext<OutCont>,
+        input: &GlweCiphertext<InCont>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        GlweCiphertext<InCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.input_glwe_key_dimension().0 => input.mask_size().0);
+        ck_dim_eq!(self.input_polynomial_size.0 => input.polynomial_size().0);
+        ck_dim_eq!(self.output_glwe_size.0 => output.size().0);
+        ck_dim_eq!(self.output_polynomial_size.0 => output.polynomial_size().0);
+
+        let polynomial_size = self.output_polynomial_size;
+        let ratio = self.input_polynomial_size.0 / polynomial_size.0;
+
+        // We reset the output, and copy the coefficients of the input body whose degrees are
+        // multiples of r on its body
+        output.as_mut_tensor().fill_with_element(Scalar::ZERO);
+        let (input_body, input_mask) = input.get_body_and_mask();
+        for (output_coefficient, input_coefficient) in output
+            .get_mut_body()
+            .as_mut_tensor()
+            .iter_mut()
+            .zip(input_body.as_tensor().iter().step_by(ratio))
+        {
+            *output_coefficient = *input_coefficient;
+        }
+
+        // We split the input mask polynomials A_j(X) = sum_t X^t A_{j,t}(X^r), the polynomial
+        // A_{j,t} multiplying the t-th polynomial of the split key
+        let mut split_mask = Tensor::allocate(
+            Scalar::ZERO,
+            input.mask_size().0 * ratio * polynomial_size.0,
+        );
+        for (mut split_polynomials, input_polynomial) in split_mask
+            .subtensor_iter_mut(ratio * polynomial_size.0)
+            .zip(input_mask.as_polynomial_list().polynomial_iter())
+        {
+            for (t, mut split_polynomial) in split_polynomials
+                .subtensor_iter_mut(polynomial_size.0)
+                .enumerate()
+            {
+                for (split_coefficient, input_coefficient) in split_polynomial
+                    .iter_mut()
+                    .zip(input_polynomial.coefficient_iter().skip(t).step_by(ratio))
+                {
+                    *split_coefficient = *input_coefficient;
+                }
+            }
+        }
+
+        // We instantiate a decomposer
+        let decomposer = SignedDecomposer::new(self.decomp_base_log, self.decomp_level_count);
+        let mut decomposition = decomposer.decompose_tensor(&split_mask);
+
+        let mut product = Polynomial::allocate(Scalar::ZERO, polynomial_size);
+        let level_size = self.output_glwe_size.0 * polynomial_size.0;
+        let block_size = self.decomp_level_count.0 * level_size;
+
+        // Loop over the levels of the decomposition:
+        // We compute the multiplication of a ciphertext from the ring reduction key with a
+        // polynomial of the decomposition and subtract it to the output
+        while let Some(term) = decomposition.next_term() {
+            let level = term.level().0;
+            for (mask_polynomial, block) in term
+                .as_tensor()
+                .subtensor_iter(polynomial_size.0)
+                .map(Polynomial::from_tensor)
+                .zip(self.as_tensor().subtensor_iter(block_size))
+            {
+                let key_ciphertext = GlweCiphertext::from_container(
+                    &block.as_slice()[(level - 1) * level_size..level * level_size],
+                    polynomial_size,
+                );
+                for (key_polynomial, mut output_polynomial) in key_ciphertext
+                    .as_polynomial_list()
+                    .polynomial_iter()
+                    .zip(output.as_mut_polynomial_list().polynomial_iter_mut())
+                {
+                    product.fill_with_karatsuba_mul(&key_polynomial, &mask_polynomial);
+                    output_polynomial.update_with_wrapping_sub(&product);
+                }
+            }
+        }
+    }
+}
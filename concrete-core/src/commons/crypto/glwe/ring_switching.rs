@@ -0,0 +1,561 @@
+use super::{GlweCiphertext, GlweList};
+use crate::commons::crypto::encoding::PlaintextList;
+use crate::commons::crypto::secret::generators::EncryptionRandomGenerator;
+use crate::commons::crypto::secret::GlweSecretKey;
+use crate::commons::math::decomposition::{
+    DecompositionLevel, DecompositionTerm, SignedDecomposer,
+};
+use crate::commons::math::polynomial::Polynomial;
+use crate::commons::math::random::ByteRandomGenerator;
+use crate::commons::math::tensor::{
+    ck_dim_div, ck_dim_eq, tensor_traits, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+};
+use crate::commons::math::torus::UnsignedTorus;
+use concrete_commons::dispersion::DispersionParameter;
+use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, GlweSize, PlaintextCount,
+    PolynomialSize,
+};
+#[cfg(feature = "__commons_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A ring switching key.
+///
+/// A ring switching key allows to switch a GLWE ciphertext whose polynomials have $N/2$
+/// coefficients, to a GLWE ciphertext whose polynomials have $N$ coefficients. The message
+/// $M(X)$ of the input ciphertext becomes the message $M(X^2)$ of the output ciphertext, which
+/// makes this operation the inverse of
+/// [`GlweCiphertext::fill_glwe_list_with_ring_split`](`super::GlweCiphertext::fill_glwe_list_with_ring_split`).
+///
+/// The key contains, for each polynomial $S'\_j(X)$ of the input key, and for each decomposition
+/// level, a GLWE encryption under the output key of $S'\_j(X^2)$ scaled by the level.
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweRingSwitchingKey<Cont> {
+    tensor: Tensor<Cont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    output_glwe_size: GlweSize,
+    output_polynomial_size: PolynomialSize,
+}
+
+tensor_traits!(GlweRingSwitchingKey);
+
+impl<Scalar> GlweRingSwitchingKey<Vec<Scalar>>
+where
+    Scalar: Copy,
+{
+    /// Allocates a ring switching key whose masks and bodies are all `value`.
+    ///
+    /// The polynomials of the input key are half the size of the `output_polynomial_size`.
+    ///
+    /// # Note
+    ///
+    /// This function does *not* generate a ring switching key, but merely allocates a container
+    /// of the right size. See [`GlweRingSwitchingKey::fill_with_ring_switching_key`] to fill the
+    /// container with a proper ring switching key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweRingSwitchingKey;
+    /// let rsk = GlweRingSwitchingKey::allocate(
+    ///     0 as u8,
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(16),
+    ///     GlweDimension(4),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    /// );
+    /// assert_eq!(
+    ///     rsk.decomposition_level_count(),
+    ///     DecompositionLevelCount(10)
+    /// );
+    /// assert_eq!(rsk.decomposition_base_log(), DecompositionBaseLog(16));
+    /// assert_eq!(rsk.input_glwe_key_dimension(), GlweDimension(4));
+    /// assert_eq!(rsk.input_polynomial_size(), PolynomialSize(128));
+    /// assert_eq!(rsk.output_glwe_key_dimension(), GlweDimension(2));
+    /// assert_eq!(rsk.output_polynomial_size(), PolynomialSize(256));
+    /// ```
+    pub fn allocate(
+        value: Scalar,
+        decomp_size: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+        input_dimension: GlweDimension,
+        output_dimension: GlweDimension,
+        output_polynomial_size: PolynomialSize,
+    ) -> Self {
+        GlweRingSwitchingKey {
+            tensor: Tensor::from_container(vec![
+                value;
+                decomp_size.0
+                    * output_dimension.to_glwe_size().0
+                    * output_polynomial_size.0
+                    * input_dimension.0
+            ]),
+            decomp_base_log,
+            decomp_level_count: decomp_size,
+            output_glwe_size: output_dimension.to_glwe_size(),
+            output_polynomial_size,
+        }
+    }
+}
+
+impl<Cont> GlweRingSwitchingKey<Cont> {
+    /// Creates a ring switching key from a container.
+    ///
+    /// # Notes
+    ///
+    /// This method does not create a ring switching key, but merely wraps the container in the
+    /// proper type. It assumes that either the container already contains a proper ring switching
+    /// key, or that [`GlweRingSwitchingKey::fill_with_ring_switching_key`] will be called right
+    /// after.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweRingSwitchingKey;
+    /// let rsk = GlweRingSwitchingKey::from_container(
+    ///     vec![0 as u8; 10 * 3 * 256 * 4],
+    ///     DecompositionBaseLog(16),
+    ///     DecompositionLevelCount(10),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    /// );
+    /// assert_eq!(rsk.input_glwe_key_dimension(), GlweDimension(4));
+    /// assert_eq!(rsk.output_glwe_key_dimension(), GlweDimension(2));
+    /// ```
+    pub fn from_container(
+        cont: Cont,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_size: DecompositionLevelCount,
+        output_glwe_dimension: GlweDimension,
+        output_polynomial_size: PolynomialSize,
+    ) -> GlweRingSwitchingKey<Cont>
+    where
+        Cont: AsRefSlice,
+    {
+        let tensor = Tensor::from_container(cont);
+        ck_dim_div!(tensor.len() => output_glwe_dimension.to_glwe_size().0 * output_polynomial_size.0, decomp_size.0);
+        GlweRingSwitchingKey {
+            tensor,
+            decomp_base_log,
+            decomp_level_count: decomp_size,
+            output_glwe_size: output_glwe_dimension.to_glwe_size(),
+            output_polynomial_size,
+        }
+    }
+
+    /// Returns the dimension of the input GLWE key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweRingSwitchingKey;
+    /// let rsk = GlweRingSwitchingKey::allocate(
+    ///     0 as u8,
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(16),
+    ///     GlweDimension(4),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    /// );
+    /// assert_eq!(rsk.input_glwe_key_dimension(), GlweDimension(4));
+    /// ```
+    pub fn input_glwe_key_dimension(&self) -> GlweDimension
+    where
+        Self: AsRefTensor,
+    {
+        GlweDimension(
+            self.as_tensor().len()
+                / (self.output_glwe_size.0
+                    * self.output_polynomial_size.0
+                    * self.decomp_level_count.0),
+        )
+    }
+
+    /// Returns the size of the polynomials of the input GLWE key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweRingSwitchingKey;
+    /// let rsk = GlweRingSwitchingKey::allocate(
+    ///     0 as u8,
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(16),
+    ///     GlweDimension(4),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    /// );
+    /// assert_eq!(rsk.input_polynomial_size(), PolynomialSize(128));
+    /// ```
+    pub fn input_polynomial_size(&self) -> PolynomialSize {
+        PolynomialSize(self.output_polynomial_size.0 / 2)
+    }
+
+    /// Returns the dimension of the output GLWE key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweRingSwitchingKey;
+    /// let rsk = GlweRingSwitchingKey::allocate(
+    ///     0 as u8,
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(16),
+    ///     GlweDimension(4),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    /// );
+    /// assert_eq!(rsk.output_glwe_key_dimension(), GlweDimension(2));
+    /// ```
+    pub fn output_glwe_key_dimension(&self) -> GlweDimension {
+        self.output_glwe_size.to_glwe_dimension()
+    }
+
+    /// Returns the size of the polynomials of the output GLWE key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweRingSwitchingKey;
+    /// let rsk = GlweRingSwitchingKey::allocate(
+    ///     0 as u8,
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(16),
+    ///     GlweDimension(4),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    /// );
+    /// assert_eq!(rsk.output_polynomial_size(), PolynomialSize(256));
+    /// ```
+    pub fn output_polynomial_size(&self) -> PolynomialSize {
+        self.output_polynomial_size
+    }
+
+    /// Returns the number of levels used for the decomposition of the input masks.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweRingSwitchingKey;
+    /// let rsk = GlweRingSwitchingKey::allocate(
+    ///     0 as u8,
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(16),
+    ///     GlweDimension(4),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    /// );
+    /// assert_eq!(
+    ///     rsk.decomposition_level_count(),
+    ///     DecompositionLevelCount(10)
+    /// );
+    /// ```
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomp_level_count
+    }
+
+    /// Returns the logarithm of the base used for the decomposition of the input masks.
+    ///
+    /// Indeed, the basis used is always of the form $2^b$. This function returns $b$.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweRingSwitchingKey;
+    /// let rsk = GlweRingSwitchingKey::allocate(
+    ///     0 as u8,
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(16),
+    ///     GlweDimension(4),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    /// );
+    /// assert_eq!(rsk.decomposition_base_log(), DecompositionBaseLog(16));
+    /// ```
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Fills the current ring switching key container with an actual ring switching key
+    /// constructed from an input and an output key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweRingSwitchingKey;
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::GlweSecretKey;
+    /// use concrete_core::commons::math::tensor::AsRefTensor;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// let noise = LogStandardDev::from_log_standard_dev(-15.);
+    ///
+    /// let input_key = GlweSecretKey::generate_binary(
+    ///     GlweDimension(4),
+    ///     PolynomialSize(128),
+    ///     &mut secret_generator,
+    /// );
+    /// let output_key = GlweSecretKey::generate_binary(
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     &mut secret_generator,
+    /// );
+    ///
+    /// let mut rsk = GlweRingSwitchingKey::allocate(
+    ///     0 as u32,
+    ///     DecompositionLevelCount(5),
+    ///     DecompositionBaseLog(3),
+    ///     GlweDimension(4),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    /// );
+    /// rsk.fill_with_ring_switching_key(&input_key, &output_key, noise, &mut encryption_generator);
+    ///
+    /// assert!(!rsk.as_tensor().iter().all(|a| *a == 0));
+    /// ```
+    pub fn fill_with_ring_switching_key<InKeyCont, OutKeyCont, Scalar, Gen>(
+        &mut self,
+        input_glwe_key: &GlweSecretKey<BinaryKeyKind, InKeyCont>,
+        output_glwe_key: &GlweSecretKey<BinaryKeyKind, OutKeyCont>,
+        noise_parameters: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator<Gen>,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        GlweSecretKey<BinaryKeyKind, InKeyCont>: AsRefTensor<Element = Scalar>,
+        GlweSecretKey<BinaryKeyKind, OutKeyCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+        Gen: ByteRandomGenerator,
+    {
+        ck_dim_eq!(self.input_glwe_key_dimension().0 => input_glwe_key.key_size().0);
+        ck_dim_eq!(self.input_polynomial_size().0 => input_glwe_key.polynomial_size().0);
+        ck_dim_eq!(self.output_glwe_key_dimension().0 => output_glwe_key.key_size().0);
+        ck_dim_eq!(self.output_polynomial_size.0 => output_glwe_key.polynomial_size().0);
+
+        // We instantiate a buffer
+        let mut messages = PlaintextList::from_container(vec![
+            Scalar::ZERO;
+            self.decomp_level_count.0
+                * self.output_polynomial_size.0
+        ]);
+
+        // We retrieve decomposition arguments
+        let decomp_level_count = self.decomp_level_count;
+        let decomp_base_log = self.decomp_base_log;
+        let output_glwe_dimension = self.output_glwe_key_dimension();
+        let polynomial_size = self.output_polynomial_size;
+        let block_size = decomp_level_count.0 * self.output_glwe_size.0 * polynomial_size.0;
+
+        // loop over the input key polynomials
+        for (input_key_polynomial, keyswitch_key_block) in input_glwe_key
+            .as_polynomial_list()
+            .polynomial_iter()
+            .zip(self.as_mut_tensor().subtensor_iter_mut(block_size))
+        {
+            // We reset the buffer
+            messages.as_mut_tensor().fill_with_element(Scalar::ZERO);
+
+            // We fill the buffer with the powers of the input key polynomial, evaluated in X^2
+            for (level, mut message) in (1..=decomp_level_count.0)
+                .map(DecompositionLevel)
+                .zip(messages.sublist_iter_mut(PlaintextCount(polynomial_size.0)))
+            {
+                for (message_coefficient, key_coefficient) in message
+                    .as_mut_tensor()
+                    .iter_mut()
+                    .step_by(2)
+                    .zip(input_key_polynomial.coefficient_iter())
+                {
+                    *message_coefficient =
+                        DecompositionTerm::new(level, decomp_base_log, *key_coefficient)
+                            .to_recomposition_summand();
+                }
+            }
+
+            // We encrypt the buffer
+            output_glwe_key.encrypt_glwe_list(
+                &mut GlweList::from_container(
+                    keyswitch_key_block.into_container(),
+                    output_glwe_dimension,
+                    polynomial_size,
+                ),
+                &messages,
+                noise_parameters,
+                generator,
+            );
+        }
+    }
+
+    /// Switches a GLWE ciphertext to a GLWE ciphertext with polynomials twice as large.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, GlweSize, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::encoding::PlaintextList;
+    /// use concrete_core::commons::crypto::glwe::{GlweCiphertext, GlweRingSwitchingKey};
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::GlweSecretKey;
+    /// use concrete_core::commons::math::tensor::AsRefTensor;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// let noise = LogStandardDev::from_log_standard_dev(-25.);
+    ///
+    /// let input_key = GlweSecretKey::generate_binary(
+    ///     GlweDimension(4),
+    ///     PolynomialSize(128),
+    ///     &mut secret_generator,
+    /// );
+    /// let output_key = GlweSecretKey::generate_binary(
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     &mut secret_generator,
+    /// );
+    /// let mut rsk = GlweRingSwitchingKey::allocate(
+    ///     0 as u32,
+    ///     DecompositionLevelCount(6),
+    ///     DecompositionBaseLog(4),
+    ///     GlweDimension(4),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    /// );
+    /// rsk.fill_with_ring_switching_key(&input_key, &output_key, noise, &mut encryption_generator);
+    ///
+    /// let messages = PlaintextList::from_container(vec![1_u32 << 28; 128]);
+    /// let mut input = GlweCiphertext::allocate(0_u32, PolynomialSize(128), GlweSize(5));
+    /// input_key.encrypt_glwe(&mut input, &messages, noise, &mut encryption_generator);
+    ///
+    /// let mut output = GlweCiphertext::allocate(0_u32, PolynomialSize(256), GlweSize(3));
+    /// rsk.ring_switch(&mut output, &input);
+    ///
+    /// let mut decrypted = PlaintextList::from_container(vec![0_u32; 256]);
+    /// output_key.decrypt_glwe(&mut decrypted, &output);
+    /// for (i, decrypted) in decrypted.as_tensor().iter().enumerate() {
+    ///     // The message is moved to the even coefficients
+    ///     let expected = if i % 2 == 0 { 1_u32 << 28 } else { 0 };
+    ///     let distance = decrypted.wrapping_sub(expected).min(expected.wrapping_sub(*decrypted));
+    ///     assert!(distance < 1 << 24);
+    /// }
+    /// ```
+    pub fn ring_switch<InCont, OutCont, Scalar>(
+        &self,
+        output: &mut GlweCiphertext<OutCont>,
+        input: &GlweCiphertext<InCont>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        GlweCiphertext<InCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.input_glwe_key_dimension().0 => input.mask_size().0);
+        ck_dim_eq!(self.input_polynomial_size().0 => input.polynomial_size().0);
+        ck_dim_eq!(self.output_glwe_size.0 => output.size().0);
+        ck_dim_eq!(self.output_polynomial_size.0 => output.polynomial_size().0);
+
+        let polynomial_size = self.output_polynomial_size;
+
+        // We reset the output, and copy the input body on the even coefficients of its body
+        output.as_mut_tensor().fill_with_element(Scalar::ZERO);
+        let (input_body, input_mask) = input.get_body_and_mask();
+        for (output_coefficient, input_coefficient) in output
+            .get_mut_body()
+            .as_mut_tensor()
+            .iter_mut()
+            .step_by(2)
+            .zip(input_body.as_tensor().iter())
+        {
+            *output_coefficient = *input_coefficient;
+        }
+
+        // We evaluate the input mask polynomials in X^2
+        let mut embedded_mask =
+            Tensor::allocate(Scalar::ZERO, input.mask_size().0 * polynomial_size.0);
+        for (embedded_coefficient, input_coefficient) in embedded_mask
+            .iter_mut()
+            .step_by(2)
+            .zip(input_mask.as_tensor().iter())
+        {
+            *embedded_coefficient = *input_coefficient;
+        }
+
+        // We instantiate a decomposer
+        let decomposer = SignedDecomposer::new(self.decomp_base_log, self.decomp_level_count);
+        let mut decomposition = decomposer.decompose_tensor(&embedded_mask);
+
+        let mut product = Polynomial::allocate(Scalar::ZERO, polynomial_size);
+        let level_size = self.output_glwe_size.0 * polynomial_size.0;
+        let block_size = self.decomp_level_count.0 * level_size;
+
+        // Loop over the levels of the decomposition:
+        // We compute the multiplication of a ciphertext from the ring switching key with a
+        // polynomial of the decomposition and subtract it to the output
+        while let Some(term) = decomposition.next_term() {
+            let level = term.level().0;
+            for (mask_polynomial, block) in term
+                .as_tensor()
+                .subtensor_iter(polynomial_size.0)
+                .map(Polynomial::from_tensor)
+                .zip(self.as_tensor().subtensor_iter(block_size))
+            {
+                let key_ciphertext = GlweCiphertext::from_container(
+                    &block.as_slice()[(level - 1) * level_size..level * level_size],
+                    polynomial_size,
+                );
+                for (key_polynomial, mut output_polynomial) in key_ciphertext
+                    .as_polynomial_list()
+                    .polynomial_iter()
+                    .zip(output.as_mut_polynomial_list().polynomial_iter_mut())
+                {
+                    product.fill_with_karatsuba_mul(&key_polynomial, &mask_polynomial);
+                    output_polynomial.update_with_wrapping_sub(&product);
+                }
+            }
+        }
+    }
+}
@@ -7,7 +7,7 @@ use crate::commons::math::random::{
     ByteRandomGenerator, CompressionSeed, RandomGenerable, RandomGenerator, Uniform,
 };
 use crate::commons::math::tensor::{
-    tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, IntoTensor, Tensor,
+    tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
 };
 
 use super::{GlweBody, GlweCiphertext};
@@ -299,13 +299,13 @@ impl<Cont> GlweSeededCiphertext<Cont> {
     }
 
     pub fn expand_into_with_existing_generator<Scalar, OutputCont, Gen>(
-        self,
+        &self,
         output: &mut GlweCiphertext<OutputCont>,
         generator: &mut RandomGenerator<Gen>,
     ) where
         Scalar: Copy + RandomGenerable<Uniform> + Numeric,
         GlweCiphertext<OutputCont>: AsMutTensor<Element = Scalar>,
-        Self: IntoTensor<Element = Scalar> + AsRefTensor,
+        Self: AsRefTensor<Element = Scalar>,
         Gen: ByteRandomGenerator,
     {
         let (mut output_body, mut output_mask) = output.get_mut_body_and_mask();
@@ -316,7 +316,7 @@ impl<Cont> GlweSeededCiphertext<Cont> {
         output_body
             .as_mut_tensor()
             .as_mut_slice()
-            .clone_from_slice(self.into_tensor().as_slice());
+            .clone_from_slice(self.as_tensor().as_slice());
     }
 
     /// Returns the ciphertext as a full fledged GlweCiphertext
@@ -353,11 +353,11 @@ impl<Cont> GlweSeededCiphertext<Cont> {
     /// assert_eq!(ciphertext.mask_size(), glwe_dimension);
     /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
     /// ```
-    pub fn expand_into<Scalar, OutCont, Gen>(self, output: &mut GlweCiphertext<OutCont>)
+    pub fn expand_into<Scalar, OutCont, Gen>(&self, output: &mut GlweCiphertext<OutCont>)
     where
         Scalar: Copy + RandomGenerable<Uniform> + Numeric,
         GlweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
-        Self: IntoTensor<Element = Scalar> + AsRefTensor,
+        Self: AsRefTensor<Element = Scalar>,
         Gen: ByteRandomGenerator,
     {
         let mut generator = RandomGenerator::<Gen>::new(self.compression_seed().seed);
@@ -0,0 +1,373 @@
+#[cfg(feature = "__commons_serialization")]
+use serde::{Deserialize, Serialize};
+
+use concrete_commons::dispersion::DispersionParameter;
+use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_commons::numeric::Numeric;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PlaintextCount,
+    PolynomialSize,
+};
+
+use crate::commons::crypto::encoding::PlaintextList;
+use crate::commons::crypto::secret::generators::EncryptionRandomGenerator;
+use crate::commons::crypto::secret::{GlweSecretKey, LweSecretKey};
+use crate::commons::math::decomposition::{DecompositionLevel, DecompositionTerm};
+use crate::commons::math::random::{
+    ByteRandomGenerator, CompressionSeed, RandomGenerable, RandomGenerator, Seeder, Uniform,
+};
+use crate::commons::math::tensor::{
+    ck_dim_div, ck_dim_eq, tensor_traits, AsMutTensor, AsRefTensor, Tensor,
+};
+use crate::commons::math::torus::UnsignedTorus;
+
+use super::{GlweSeededCiphertext, LwePackingKeyswitchKey};
+
+/// A seeded packing keyswitching key.
+///
+/// This seeded version of the packing keyswitch key only stores the bodies of the GLWE ciphertexts
+/// encrypting the levels of decomposition of the input key bits, the masks being regenerated from
+/// the compression seed. See [`LwePackingKeyswitchKey`] for more details on packing keyswitching
+/// keys.
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweSeededPackingKeyswitchKey<Cont> {
+    tensor: Tensor<Cont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    output_glwe_dimension: GlweDimension,
+    output_polynomial_size: PolynomialSize,
+    compression_seed: CompressionSeed,
+}
+
+tensor_traits!(LweSeededPackingKeyswitchKey);
+
+impl<Scalar> LweSeededPackingKeyswitchKey<Vec<Scalar>>
+where
+    Scalar: Copy + Numeric,
+{
+    /// Allocates a seeded packing keyswitching key, the underlying container has a size of
+    /// `level_decomp * input_dimension * output_polynomial_size`.
+    ///
+    /// # Note
+    ///
+    /// This function does *not* generate a seeded packing keyswitch key, but merely allocates a
+    /// container of the right size. See
+    /// [`LweSeededPackingKeyswitchKey::fill_with_seeded_packing_keyswitch_key`] to fill the
+    /// container with a proper seeded packing keyswitching key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::LweSeededPackingKeyswitchKey;
+    /// use concrete_core::commons::math::random::{CompressionSeed, Seed};
+    ///
+    /// let compression_seed = CompressionSeed { seed: Seed(42) };
+    /// let pksk: LweSeededPackingKeyswitchKey<Vec<u64>> = LweSeededPackingKeyswitchKey::allocate(
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(16),
+    ///     LweDimension(10),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     compression_seed,
+    /// );
+    ///
+    /// assert_eq!(
+    ///     pksk.decomposition_level_count(),
+    ///     DecompositionLevelCount(10)
+    /// );
+    /// assert_eq!(pksk.decomposition_base_log(), DecompositionBaseLog(16));
+    /// assert_eq!(pksk.input_lwe_key_dimension(), LweDimension(10));
+    /// assert_eq!(pksk.output_glwe_key_dimension(), GlweDimension(2));
+    /// assert_eq!(pksk.output_polynomial_size(), PolynomialSize(256));
+    /// assert_eq!(pksk.compression_seed(), compression_seed);
+    /// ```
+    pub fn allocate(
+        decomp_level_count: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+        input_dimension: LweDimension,
+        output_glwe_dimension: GlweDimension,
+        output_polynomial_size: PolynomialSize,
+        compression_seed: CompressionSeed,
+    ) -> Self {
+        LweSeededPackingKeyswitchKey {
+            tensor: Tensor::from_container(vec![
+                Scalar::ZERO;
+                decomp_level_count.0
+                    * output_polynomial_size.0
+                    * input_dimension.0
+            ]),
+            decomp_base_log,
+            decomp_level_count,
+            output_glwe_dimension,
+            output_polynomial_size,
+            compression_seed,
+        }
+    }
+}
+
+impl<Cont> LweSeededPackingKeyswitchKey<Cont> {
+    /// Returns the dimension of the output GLWE key.
+    pub fn output_glwe_key_dimension(&self) -> GlweDimension {
+        self.output_glwe_dimension
+    }
+
+    /// Returns the size of the polynomials of the output GLWE key.
+    pub fn output_polynomial_size(&self) -> PolynomialSize {
+        self.output_polynomial_size
+    }
+
+    /// Returns the dimension of the input LWE key.
+    pub fn input_lwe_key_dimension(&self) -> LweDimension
+    where
+        Self: AsRefTensor,
+    {
+        LweDimension(
+            self.as_tensor().len() / (self.decomp_level_count.0 * self.output_polynomial_size.0),
+        )
+    }
+
+    /// Returns the number of levels used for the decomposition of the input key bits.
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomp_level_count
+    }
+
+    /// Returns the logarithm of the base used for the decomposition of the input key bits.
+    ///
+    /// Indeed, the basis used is always of the form $2^N$. This function returns $N$.
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Returns the seed used to generate the masks of the key.
+    pub fn compression_seed(&self) -> CompressionSeed {
+        self.compression_seed
+    }
+
+    /// Fills the current seeded packing keyswitch key container with an actual seeded packing
+    /// keyswitching key constructed from an input LWE key and an output GLWE key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::LweSeededPackingKeyswitchKey;
+    /// use concrete_core::commons::crypto::secret::generators::SecretRandomGenerator;
+    /// use concrete_core::commons::crypto::secret::{GlweSecretKey, LweSecretKey};
+    /// use concrete_core::commons::math::random::{CompressionSeed, Seed};
+    /// use concrete_core::commons::math::tensor::AsRefTensor;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::UnixSeeder;
+    ///
+    /// let input_size = LweDimension(10);
+    /// let output_size = GlweDimension(3);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut seeder = UnixSeeder::new(0);
+    /// let noise = LogStandardDev::from_log_standard_dev(-15.);
+    ///
+    /// let input_key = LweSecretKey::generate_binary(input_size, &mut secret_generator);
+    /// let output_key =
+    ///     GlweSecretKey::generate_binary(output_size, polynomial_size, &mut secret_generator);
+    ///
+    /// let mut pksk: LweSeededPackingKeyswitchKey<Vec<u32>> = LweSeededPackingKeyswitchKey::allocate(
+    ///     DecompositionLevelCount(5),
+    ///     DecompositionBaseLog(3),
+    ///     input_size,
+    ///     output_size,
+    ///     polynomial_size,
+    ///     CompressionSeed { seed: Seed(42) },
+    /// );
+    /// pksk.fill_with_seeded_packing_keyswitch_key::<_, _, _, _, _, SoftwareRandomGenerator>(
+    ///     &input_key,
+    ///     &output_key,
+    ///     noise,
+    ///     &mut seeder,
+    /// );
+    ///
+    /// assert!(!pksk.as_tensor().iter().all(|a| *a == 0));
+    /// ```
+    pub fn fill_with_seeded_packing_keyswitch_key<
+        InKeyCont,
+        OutKeyCont,
+        Scalar,
+        NoiseParameter,
+        NoiseSeeder,
+        Gen,
+    >(
+        &mut self,
+        input_lwe_key: &LweSecretKey<BinaryKeyKind, InKeyCont>,
+        output_glwe_key: &GlweSecretKey<BinaryKeyKind, OutKeyCont>,
+        noise_parameters: NoiseParameter,
+        noise_seeder: &mut NoiseSeeder,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        LweSecretKey<BinaryKeyKind, InKeyCont>: AsRefTensor<Element = Scalar>,
+        GlweSecretKey<BinaryKeyKind, OutKeyCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+        NoiseParameter: DispersionParameter,
+        NoiseSeeder: Seeder,
+        Gen: ByteRandomGenerator,
+    {
+        ck_dim_eq!(self.input_lwe_key_dimension().0 => input_lwe_key.key_size().0);
+        ck_dim_eq!(self.output_glwe_dimension.0 => output_glwe_key.key_size().0);
+
+        // We instantiate a buffer
+        let mut message = PlaintextList::allocate(
+            <Self as AsMutTensor>::Element::ZERO,
+            PlaintextCount(self.output_polynomial_size.0),
+        );
+
+        // We retrieve decomposition arguments
+        let decomp_level_count = self.decomp_level_count;
+        let decomp_base_log = self.decomp_base_log;
+        let polynomial_size = self.output_polynomial_size;
+        let glwe_dimension = self.output_glwe_dimension;
+        let compression_seed = self.compression_seed;
+
+        let mut generator =
+            EncryptionRandomGenerator::<Gen>::new(compression_seed.seed, noise_seeder);
+
+        // loop over the before key blocks
+        for (input_key_bit, mut keyswitch_key_block) in input_lwe_key.as_tensor().iter().zip(
+            self.as_mut_tensor()
+                .subtensor_iter_mut(decomp_level_count.0 * polynomial_size.0),
+        ) {
+            // We encrypt the powers of the key bit, one level at a time
+            for (level, body) in (1..=decomp_level_count.0)
+                .map(DecompositionLevel)
+                .zip(keyswitch_key_block.subtensor_iter_mut(polynomial_size.0))
+            {
+                *message.as_mut_tensor().first_mut() =
+                    DecompositionTerm::new(level, decomp_base_log, *input_key_bit)
+                        .to_recomposition_summand();
+                let mut seeded_glwe = GlweSeededCiphertext::from_container(
+                    body.into_container(),
+                    glwe_dimension,
+                    compression_seed,
+                );
+                output_glwe_key.encrypt_seeded_glwe_with_existing_generator(
+                    &mut seeded_glwe,
+                    &message,
+                    noise_parameters,
+                    &mut generator,
+                );
+            }
+        }
+    }
+
+    /// Expands the current seeded packing keyswitch key into a packing keyswitch key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PlaintextCount, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::encoding::{Plaintext, PlaintextList};
+    /// use concrete_core::commons::crypto::glwe::{
+    ///     GlweCiphertext, LwePackingKeyswitchKey, LweSeededPackingKeyswitchKey,
+    /// };
+    /// use concrete_core::commons::crypto::lwe::LweCiphertext;
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::{GlweSecretKey, LweSecretKey};
+    /// use concrete_core::commons::math::random::{CompressionSeed, Seed};
+    /// use concrete_core::commons::math::tensor::AsRefTensor;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::UnixSeeder;
+    ///
+    /// let input_size = LweDimension(10);
+    /// let output_size = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut seeder = UnixSeeder::new(0);
+    /// let noise = LogStandardDev::from_log_standard_dev(-40.);
+    ///
+    /// let input_key = LweSecretKey::generate_binary(input_size, &mut secret_generator);
+    /// let output_key =
+    ///     GlweSecretKey::generate_binary(output_size, polynomial_size, &mut secret_generator);
+    ///
+    /// let mut seeded_pksk: LweSeededPackingKeyswitchKey<Vec<u64>> =
+    ///     LweSeededPackingKeyswitchKey::allocate(
+    ///         DecompositionLevelCount(4),
+    ///         DecompositionBaseLog(8),
+    ///         input_size,
+    ///         output_size,
+    ///         polynomial_size,
+    ///         CompressionSeed { seed: Seed(42) },
+    ///     );
+    /// seeded_pksk.fill_with_seeded_packing_keyswitch_key::<_, _, _, _, _, SoftwareRandomGenerator>(
+    ///     &input_key,
+    ///     &output_key,
+    ///     noise,
+    ///     &mut seeder,
+    /// );
+    ///
+    /// let mut pksk = LwePackingKeyswitchKey::allocate(
+    ///     0,
+    ///     seeded_pksk.decomposition_level_count(),
+    ///     seeded_pksk.decomposition_base_log(),
+    ///     seeded_pksk.input_lwe_key_dimension(),
+    ///     seeded_pksk.output_glwe_key_dimension(),
+    ///     seeded_pksk.output_polynomial_size(),
+    /// );
+    /// seeded_pksk.expand_into::<_, _, SoftwareRandomGenerator>(&mut pksk);
+    ///
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut seeder);
+    /// let mut ciphertext = LweCiphertext::allocate(0_u64, input_size.to_lwe_size());
+    /// input_key.encrypt_lwe(
+    ///     &mut ciphertext,
+    ///     &Plaintext(3_u64 << 60),
+    ///     noise,
+    ///     &mut encryption_generator,
+    /// );
+    /// let mut packed =
+    ///     GlweCiphertext::allocate(0_u64, polynomial_size, output_size.to_glwe_size());
+    /// pksk.keyswitch_ciphertext(&mut packed, &ciphertext);
+    ///
+    /// let mut decrypted = PlaintextList::allocate(0_u64, PlaintextCount(polynomial_size.0));
+    /// output_key.decrypt_glwe(&mut decrypted, &packed);
+    /// let first = *decrypted.as_tensor().first();
+    /// assert_eq!(first.wrapping_add(1 << 59) >> 60, 3);
+    /// ```
+    pub fn expand_into<OutCont, Scalar, Gen>(&self, output: &mut LwePackingKeyswitchKey<OutCont>)
+    where
+        LwePackingKeyswitchKey<OutCont>: AsMutTensor<Element = Scalar>,
+        Self: AsRefTensor<Element = Scalar>,
+        Scalar: Copy + RandomGenerable<Uniform> + Numeric,
+        Gen: ByteRandomGenerator,
+    {
+        ck_dim_div!(self.as_tensor().len() => self.output_polynomial_size.0);
+        let mut generator = RandomGenerator::<Gen>::new(self.compression_seed.seed);
+
+        for (mut output_block, seeded_block) in output.bit_decomp_iter_mut().zip(
+            self.as_tensor()
+                .subtensor_iter(self.decomp_level_count.0 * self.output_polynomial_size.0),
+        ) {
+            for (mut glwe, body) in output_block
+                .ciphertext_iter_mut()
+                .zip(seeded_block.subtensor_iter(self.output_polynomial_size.0))
+            {
+                GlweSeededCiphertext::from_container(
+                    body.into_container(),
+                    self.output_glwe_dimension,
+                    self.compression_seed,
+                )
+                .expand_into_with_existing_generator::<_, _, Gen>(&mut glwe, &mut generator);
+            }
+        }
+    }
+}
@@ -365,7 +365,7 @@ impl<Cont> GlweSeededList<Cont> {
     /// assert_eq!(ciphertexts.glwe_dimension(), glwe_dimension);
     /// assert_eq!(ciphertexts.ciphertext_count(), ciphertext_count);
     /// ```
-    pub fn expand_into<OutCont, Scalar, Gen>(self, output: &mut GlweList<OutCont>)
+    pub fn expand_into<OutCont, Scalar, Gen>(&self, output: &mut GlweList<OutCont>)
     where
         Self: AsRefTensor<Element = Scalar>,
         GlweList<OutCont>: AsMutTensor<Element = Scalar>,
@@ -0,0 +1,429 @@
+use crate::commons::crypto::encoding::{Plaintext, PlaintextList};
+use crate::commons::crypto::glwe::{
+    GlweAutomorphismKeys, GlweCiphertext, GlweList, GlweRingReductionKey, GlweRingSwitchingKey,
+};
+use crate::commons::crypto::lwe::{LweCiphertext, LweList};
+use crate::commons::crypto::secret::GlweSecretKey;
+use crate::commons::math::polynomial::{Polynomial, SparsePolynomial};
+use crate::commons::math::tensor::{AsMutTensor, AsRefSlice, AsRefTensor};
+use crate::commons::math::torus::UnsignedTorus;
+use crate::commons::test_tools;
+use concrete_commons::dispersion::LogStandardDev;
+use concrete_commons::parameters::{
+    CiphertextCount, DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweSize,
+    MonomialDegree, PlaintextCount, PolynomialSize,
+};
+
+fn test_ring_split<T: UnsignedTorus>() {
+    // random settings
+    let glwe_dimension = test_tools::random_glwe_dimension(5);
+    let polynomial_size = PolynomialSize(2 * test_tools::random_polynomial_size(200).0);
+    let half_size = PolynomialSize(polynomial_size.0 / 2);
+    let noise_parameters = LogStandardDev::from_log_standard_dev(-25.);
+    let mut secret_generator = test_tools::new_secret_random_generator();
+    let mut encryption_generator = test_tools::new_encryption_random_generator();
+
+    let secret_key: GlweSecretKey<_, Vec<T>> =
+        GlweSecretKey::generate_binary(glwe_dimension, polynomial_size, &mut secret_generator);
+    let messages =
+        PlaintextList::from_tensor(secret_generator.random_uniform_tensor(polynomial_size.0));
+    let mut ciphertext =
+        GlweCiphertext::allocate(T::ZERO, polynomial_size, glwe_dimension.to_glwe_size());
+    secret_key.encrypt_glwe(
+        &mut ciphertext,
+        &messages,
+        noise_parameters,
+        &mut encryption_generator,
+    );
+
+    let mut split = GlweList::allocate(
+        T::ZERO,
+        half_size,
+        GlweDimension(2 * glwe_dimension.0),
+        CiphertextCount(2),
+    );
+    ciphertext.fill_glwe_list_with_ring_split(&mut split);
+
+    let split_key = secret_key.to_ring_split_key();
+    assert_eq!(split_key.key_size(), GlweDimension(2 * glwe_dimension.0));
+    assert_eq!(split_key.polynomial_size(), half_size);
+
+    // The first ciphertext encrypts the even coefficients, and the second one the odd ones.
+    let mut expected = PlaintextList::allocate(T::ZERO, PlaintextCount(polynomial_size.0));
+    for (i, message) in messages.as_tensor().iter().enumerate() {
+        *expected
+            .as_mut_tensor()
+            .get_element_mut((i % 2) * half_size.0 + i / 2) = *message;
+    }
+    let mut decrypted = PlaintextList::allocate(T::ZERO, PlaintextCount(polynomial_size.0));
+    split_key.decrypt_glwe_list(&mut decrypted, &split);
+    test_tools::assert_delta_std_dev(&expected, &decrypted, noise_parameters);
+}
+
+#[test]
+fn test_ring_split_u32() {
+    test_ring_split::<u32>()
+}
+
+#[test]
+fn test_ring_split_u64() {
+    test_ring_split::<u64>()
+}
+
+fn test_ring_switch_inverts_ring_split<T: UnsignedTorus>() {
+    // random settings
+    let glwe_dimension = test_tools::random_glwe_dimension(3);
+    let polynomial_size = PolynomialSize(2 * test_tools::random_polynomial_size(100).0);
+    let half_size = PolynomialSize(polynomial_size.0 / 2);
+    let noise_parameters = LogStandardDev::from_log_standard_dev(-25.);
+    let decomp_level_count = DecompositionLevelCount(6);
+    let decomp_base_log = DecompositionBaseLog(4);
+    let mut secret_generator = test_tools::new_secret_random_generator();
+    let mut encryption_generator = test_tools::new_encryption_random_generator();
+
+    let secret_key: GlweSecretKey<_, Vec<T>> =
+        GlweSecretKey::generate_binary(glwe_dimension, polynomial_size, &mut secret_generator);
+    let split_key = secret_key.to_ring_split_key();
+
+    let mut ring_switching_key = GlweRingSwitchingKey::allocate(
+        T::ZERO,
+        decomp_level_count,
+        decomp_base_log,
+        split_key.key_size(),
+        glwe_dimension,
+        polynomial_size,
+    );
+    ring_switching_key.fill_with_ring_switching_key(
+        &split_key,
+        &secret_key,
+        noise_parameters,
+        &mut encryption_generator,
+    );
+
+    // We only keep the 4 most significant bits of the messages, to decode them after the switch.
+    let mut messages =
+        PlaintextList::from_tensor(secret_generator.random_uniform_tensor(polynomial_size.0));
+    for message in messages.as_mut_tensor().iter_mut() {
+        *message = (*message >> (T::BITS - 4)) << (T::BITS - 4);
+    }
+    let mut ciphertext =
+        GlweCiphertext::allocate(T::ZERO, polynomial_size, glwe_dimension.to_glwe_size());
+    secret_key.encrypt_glwe(
+        &mut ciphertext,
+        &messages,
+        noise_parameters,
+        &mut encryption_generator,
+    );
+
+    let mut split =
+        GlweList::allocate(T::ZERO, half_size, split_key.key_size(), CiphertextCount(2));
+    ciphertext.fill_glwe_list_with_ring_split(&mut split);
+
+    // We switch both halves back, and recombine them as M_e(X^2) + X.M_o(X^2)
+    let mut switched =
+        GlweList::allocate(T::ZERO, polynomial_size, glwe_dimension, CiphertextCount(2));
+    for (mut output, input) in switched.ciphertext_iter_mut().zip(split.ciphertext_iter()) {
+        ring_switching_key.ring_switch(&mut output, &input);
+    }
+    let mut switched_ciphertexts = switched.ciphertext_iter_mut();
+    let mut even = switched_ciphertexts.next().unwrap();
+    let mut odd = switched_ciphertexts.next().unwrap();
+    odd.as_mut_polynomial_list()
+        .update_with_wrapping_monic_monomial_mul(MonomialDegree(1));
+    even.as_mut_tensor()
+        .update_with_wrapping_add(odd.as_tensor());
+
+    let mut decrypted = PlaintextList::allocate(T::ZERO, PlaintextCount(polynomial_size.0));
+    secret_key.decrypt_glwe(&mut decrypted, &even);
+    for (decrypted, expected) in decrypted
+        .as_tensor()
+        .iter()
+        .zip(messages.as_tensor().iter())
+    {
+        let distance = decrypted
+            .wrapping_sub(*expected)
+            .min(expected.wrapping_sub(*decrypted));
+        assert!(distance < T::ONE << (T::BITS - 8));
+    }
+}
+
+#[test]
+fn test_ring_switch_inverts_ring_split_u32() {
+    test_ring_switch_inverts_ring_split::<u32>()
+}
+
+#[test]
+fn test_ring_switch_inverts_ring_split_u64() {
+    test_ring_switch_inverts_ring_split::<u64>()
+}
+
+fn test_tree_pack<T: UnsignedTorus>() {
+    // random settings
+    let glwe_dimension = test_tools::random_glwe_dimension(3);
+    let polynomial_size = PolynomialSize(1 << test_tools::random_usize_between(1..10));
+    let count =
+        1 << test_tools::random_usize_between(0..polynomial_size.0.trailing_zeros() as usize + 1);
+    let noise_parameters = LogStandardDev::from_log_standard_dev(-25.);
+    let decomp_level_count = DecompositionLevelCount(6);
+    let decomp_base_log = DecompositionBaseLog(4);
+    let mut secret_generator = test_tools::new_secret_random_generator();
+    let mut encryption_generator = test_tools::new_encryption_random_generator();
+
+    let glwe_key: GlweSecretKey<_, Vec<T>> =
+        GlweSecretKey::generate_binary(glwe_dimension, polynomial_size, &mut secret_generator);
+    let mut automorphism_keys = GlweAutomorphismKeys::allocate(
+        T::ZERO,
+        decomp_level_count,
+        decomp_base_log,
+        glwe_dimension,
+        polynomial_size,
+    );
+    automorphism_keys.fill_with_automorphism_keys(
+        &glwe_key,
+        noise_parameters,
+        &mut encryption_generator,
+    );
+
+    // We only keep the 4 most significant bits of the messages, to decode them after the packing.
+    let mut messages = PlaintextList::from_tensor(secret_generator.random_uniform_tensor(count));
+    for message in messages.as_mut_tensor().iter_mut() {
+        *message = (*message >> (T::BITS - 4)) << (T::BITS - 4);
+    }
+    let lwe_key = glwe_key.clone().into_lwe_secret_key();
+    let mut ciphertexts = LweList::allocate(
+        T::ZERO,
+        LweSize(glwe_dimension.0 * polynomial_size.0 + 1),
+        CiphertextCount(count),
+    );
+    lwe_key.encrypt_lwe_list(
+        &mut ciphertexts,
+        &messages,
+        noise_parameters,
+        &mut encryption_generator,
+    );
+
+    let mut packed =
+        GlweCiphertext::allocate(T::ZERO, polynomial_size, glwe_dimension.to_glwe_size());
+    automorphism_keys.tree_pack(&mut packed, &ciphertexts);
+
+    // The i-th message is on the coefficient of degree i * N / n, and the others are zero
+    let mut expected = PlaintextList::allocate(T::ZERO, PlaintextCount(polynomial_size.0));
+    let spacing = polynomial_size.0 / count;
+    for (i, message) in messages.as_tensor().iter().enumerate() {
+        *expected.as_mut_tensor().get_element_mut(i * spacing) = *message;
+    }
+    let mut decrypted = PlaintextList::allocate(T::ZERO, PlaintextCount(polynomial_size.0));
+    glwe_key.decrypt_glwe(&mut decrypted, &packed);
+    for (decrypted, expected) in decrypted
+        .as_tensor()
+        .iter()
+        .zip(expected.as_tensor().iter())
+    {
+        let distance = decrypted
+            .wrapping_sub(*expected)
+            .min(expected.wrapping_sub(*decrypted));
+        assert!(distance < T::ONE << (T::BITS - 8));
+    }
+}
+
+#[test]
+fn test_tree_pack_u32() {
+    test_tree_pack::<u32>()
+}
+
+#[test]
+fn test_tree_pack_u64() {
+    test_tree_pack::<u64>()
+}
+
+fn test_ring_reduce<T: UnsignedTorus>() {
+    // random settings
+    let glwe_dimension = test_tools::random_glwe_dimension(3);
+    let output_glwe_dimension = test_tools::random_glwe_dimension(3);
+    let polynomial_size = PolynomialSize(1 << test_tools::random_usize_between(1..10));
+    let output_polynomial_size = PolynomialSize(
+        1 << test_tools::random_usize_between(0..polynomial_size.0.trailing_zeros() as usize + 1),
+    );
+    let ratio = polynomial_size.0 / output_polynomial_size.0;
+    let noise_parameters = LogStandardDev::from_log_standard_dev(-25.);
+    let decomp_level_count = DecompositionLevelCount(6);
+    let decomp_base_log = DecompositionBaseLog(4);
+    let mut secret_generator = test_tools::new_secret_random_generator();
+    let mut encryption_generator = test_tools::new_encryption_random_generator();
+
+    let input_key: GlweSecretKey<_, Vec<T>> =
+        GlweSecretKey::generate_binary(glwe_dimension, polynomial_size, &mut secret_generator);
+    let output_key: GlweSecretKey<_, Vec<T>> = GlweSecretKey::generate_binary(
+        output_glwe_dimension,
+        output_polynomial_size,
+        &mut secret_generator,
+    );
+    let mut ring_reduction_key = GlweRingReductionKey::allocate(
+        T::ZERO,
+        decomp_level_count,
+        decomp_base_log,
+        glwe_dimension,
+        polynomial_size,
+        output_glwe_dimension,
+        output_polynomial_size,
+    );
+    ring_reduction_key.fill_with_ring_reduction_key(
+        &input_key,
+        &output_key,
+        noise_parameters,
+        &mut encryption_generator,
+    );
+
+    // We only keep the 4 most significant bits of the messages, to decode them after the
+    // reduction.
+    let mut messages =
+        PlaintextList::from_tensor(secret_generator.random_uniform_tensor(polynomial_size.0));
+    for message in messages.as_mut_tensor().iter_mut() {
+        *message = (*message >> (T::BITS - 4)) << (T::BITS - 4);
+    }
+    let mut ciphertext =
+        GlweCiphertext::allocate(T::ZERO, polynomial_size, glwe_dimension.to_glwe_size());
+    input_key.encrypt_glwe(
+        &mut ciphertext,
+        &messages,
+        noise_parameters,
+        &mut encryption_generator,
+    );
+
+    let mut reduced = GlweCiphertext::allocate(
+        T::ZERO,
+        output_polynomial_size,
+        output_glwe_dimension.to_glwe_size(),
+    );
+    ring_reduction_key.ring_reduce(&mut reduced, &ciphertext);
+
+    // Only the coefficients whose degrees are multiples of N_1 / N_2 are kept
+    let mut decrypted = PlaintextList::allocate(T::ZERO, PlaintextCount(output_polynomial_size.0));
+    output_key.decrypt_glwe(&mut decrypted, &reduced);
+    for (decrypted, expected) in decrypted
+        .as_tensor()
+        .iter()
+        .zip(messages.as_tensor().iter().step_by(ratio))
+    {
+        let distance = decrypted
+            .wrapping_sub(*expected)
+            .min(expected.wrapping_sub(*decrypted));
+        assert!(distance < T::ONE << (T::BITS - 8));
+    }
+}
+
+#[test]
+fn test_ring_reduce_u32() {
+    test_ring_reduce::<u32>()
+}
+
+#[test]
+fn test_ring_reduce_u64() {
+    test_ring_reduce::<u64>()
+}
+
+fn test_polynomial_evaluation<T: UnsignedTorus>() {
+    // random settings
+    let glwe_dimension = test_tools::random_glwe_dimension(5);
+    let polynomial_size = test_tools::random_polynomial_size(200);
+    let point = test_tools::any_uint::<T>();
+    let noise_parameters = LogStandardDev::from_log_standard_dev(-25.);
+    let mut secret_generator = test_tools::new_secret_random_generator();
+    let mut encryption_generator = test_tools::new_encryption_random_generator();
+
+    let glwe_key: GlweSecretKey<_, Vec<T>> =
+        GlweSecretKey::generate_binary(glwe_dimension, polynomial_size, &mut secret_generator);
+    let messages =
+        PlaintextList::from_tensor(secret_generator.random_uniform_tensor(polynomial_size.0));
+    let mut ciphertext =
+        GlweCiphertext::allocate(T::ZERO, polynomial_size, glwe_dimension.to_glwe_size());
+    glwe_key.encrypt_glwe(
+        &mut ciphertext,
+        &messages,
+        noise_parameters,
+        &mut encryption_generator,
+    );
+
+    let mut evaluation =
+        LweCiphertext::allocate(T::ZERO, LweSize(glwe_dimension.0 * polynomial_size.0 + 1));
+    ciphertext.fill_lwe_with_polynomial_evaluation(&mut evaluation, point);
+
+    // The evaluation is linear, so the phase of the output is the evaluation of the phase of the
+    // input, noise included.
+    let mut phase = PlaintextList::allocate(T::ZERO, PlaintextCount(polynomial_size.0));
+    glwe_key.decrypt_glwe(&mut phase, &ciphertext);
+    let mut decrypted = Plaintext(T::ZERO);
+    glwe_key
+        .into_lwe_secret_key()
+        .decrypt_lwe(&mut decrypted, &evaluation);
+    assert_eq!(decrypted.0, phase.as_polynomial().wrapping_evaluate(point));
+}
+
+#[test]
+fn test_polynomial_evaluation_u32() {
+    test_polynomial_evaluation::<u32>()
+}
+
+#[test]
+fn test_polynomial_evaluation_u64() {
+    test_polynomial_evaluation::<u64>()
+}
+
+fn test_sparse_polynomial_mul<T: UnsignedTorus>() {
+    // random settings
+    let glwe_dimension = test_tools::random_glwe_dimension(5);
+    let polynomial_size = test_tools::random_polynomial_size(200);
+    let monomial_count = test_tools::random_usize_between(1..8);
+    let noise_parameters = LogStandardDev::from_log_standard_dev(-25.);
+    let mut secret_generator = test_tools::new_secret_random_generator();
+    let mut encryption_generator = test_tools::new_encryption_random_generator();
+
+    let glwe_key: GlweSecretKey<_, Vec<T>> =
+        GlweSecretKey::generate_binary(glwe_dimension, polynomial_size, &mut secret_generator);
+    let messages =
+        PlaintextList::from_tensor(secret_generator.random_uniform_tensor(polynomial_size.0));
+    let mut ciphertext =
+        GlweCiphertext::allocate(T::ZERO, polynomial_size, glwe_dimension.to_glwe_size());
+    glwe_key.encrypt_glwe(
+        &mut ciphertext,
+        &messages,
+        noise_parameters,
+        &mut encryption_generator,
+    );
+
+    let sparse_polynomial = SparsePolynomial::from_container(
+        (0..monomial_count)
+            .map(|_| test_tools::any_uint::<T>())
+            .collect::<Vec<_>>(),
+        (0..monomial_count)
+            .map(|_| MonomialDegree(test_tools::random_usize_between(0..polynomial_size.0)))
+            .collect(),
+        polynomial_size,
+    );
+    let mut product =
+        GlweCiphertext::allocate(T::ZERO, polynomial_size, glwe_dimension.to_glwe_size());
+    product.fill_with_sparse_polynomial_mul(&ciphertext, &sparse_polynomial);
+
+    // The product is linear, so the phase of the output is the product of the phase of the input,
+    // noise included, with the dense polynomial.
+    let mut phase = PlaintextList::allocate(T::ZERO, PlaintextCount(polynomial_size.0));
+    glwe_key.decrypt_glwe(&mut phase, &ciphertext);
+    let mut expected = Polynomial::allocate(T::ZERO, polynomial_size);
+    expected.fill_with_wrapping_mul(&phase.as_polynomial(), &sparse_polynomial.to_polynomial());
+    let mut decrypted = PlaintextList::allocate(T::ZERO, PlaintextCount(polynomial_size.0));
+    glwe_key.decrypt_glwe(&mut decrypted, &product);
+    assert_eq!(
+        decrypted.as_tensor().as_slice(),
+        expected.as_tensor().as_slice()
+    );
+}
+
+#[test]
+fn test_sparse_polynomial_mul_u32() {
+    test_sparse_polynomial_mul::<u32>()
+}
+
+#[test]
+fn test_sparse_polynomial_mul_u64() {
+    test_sparse_polynomial_mul::<u64>()
+}
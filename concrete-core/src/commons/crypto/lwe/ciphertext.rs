@@ -1,23 +1,65 @@
 use super::LweList;
 use crate::commons::crypto::encoding::{Cleartext, CleartextList, Plaintext};
 use crate::commons::crypto::glwe::GlweCiphertext;
-use crate::commons::crypto::secret::LweSecretKey;
-use crate::commons::math::tensor::{tensor_traits, AsMutTensor, AsRefTensor, Tensor};
+use crate::commons::crypto::secret::{KeyFingerprint, LweSecretKey};
+use crate::commons::math::modular::NonNativeModulus;
+use crate::commons::math::tensor::{
+    tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, IntoTensor, Tensor,
+};
 use crate::commons::math::torus::UnsignedTorus;
+use crate::commons::utils::{validate_container_length, ContainerValidationError};
 use concrete_commons::key_kinds::KeyKind;
 use concrete_commons::numeric::{Numeric, UnsignedInteger};
-use concrete_commons::parameters::{LweDimension, LweSize, MonomialDegree};
+use concrete_commons::parameters::{CiphertextModulusLog, LweDimension, LweSize, MonomialDegree};
 #[cfg(feature = "__commons_serialization")]
 use serde::{Deserialize, Serialize};
 
 /// A ciphertext encrypted using the LWE scheme.
+///
+/// The ciphertext carries the [`KeyFingerprint`] of the key it is encrypted under. Any mutable
+/// access to the mask of the ciphertext resets this fingerprint, which is then restored by the
+/// operations whose result is known to be encrypted under a given key.
 #[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct LweCiphertext<Cont> {
     pub(crate) tensor: Tensor<Cont>,
+    #[cfg_attr(feature = "__commons_serialization", serde(skip))]
+    pub(crate) key_fingerprint: KeyFingerprint,
 }
 
-tensor_traits!(LweCiphertext);
+impl<Element, Cont> AsRefTensor for LweCiphertext<Cont>
+where
+    Cont: AsRefSlice<Element = Element>,
+{
+    type Element = Element;
+    type Container = Cont;
+    fn as_tensor(&self) -> &Tensor<Self::Container> {
+        &self.tensor
+    }
+}
+
+impl<Element, Cont> AsMutTensor for LweCiphertext<Cont>
+where
+    Cont: AsMutSlice<Element = Element>,
+{
+    type Element = Element;
+    type Container = Cont;
+    fn as_mut_tensor(&mut self) -> &mut Tensor<<Self as AsMutTensor>::Container> {
+        self.key_fingerprint = KeyFingerprint::unknown();
+        &mut self.tensor
+    }
+}
+
+impl<Cont> IntoTensor for LweCiphertext<Cont>
+where
+    Cont: AsRefSlice,
+{
+    type Element = <Cont as AsRefSlice>::Element;
+    type Container = Cont;
+    fn into_tensor(self) -> Tensor<Self::Container> {
+        self.tensor
+    }
+}
 
 impl<Scalar> LweCiphertext<Vec<Scalar>>
 where
@@ -37,6 +79,7 @@ where
     pub fn allocate(value: Scalar, size: LweSize) -> Self {
         LweCiphertext {
             tensor: Tensor::from_container(vec![value; size.0]),
+            key_fingerprint: KeyFingerprint::unknown(),
         }
     }
 }
@@ -105,7 +148,28 @@ impl<Cont> LweCiphertext<Cont> {
     /// ```
     pub fn from_container(cont: Cont) -> LweCiphertext<Cont> {
         let tensor = Tensor::from_container(cont);
-        LweCiphertext { tensor }
+        LweCiphertext {
+            tensor,
+            key_fingerprint: KeyFingerprint::unknown(),
+        }
+    }
+
+    /// Returns the fingerprint of the key the ciphertext is encrypted under.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::lwe::LweCiphertext;
+    /// let ct = LweCiphertext::from_container(vec![0 as u8; 10]);
+    /// assert!(!ct.key_fingerprint().is_known());
+    /// ```
+    pub fn key_fingerprint(&self) -> KeyFingerprint {
+        self.key_fingerprint
+    }
+
+    /// Sets the fingerprint of the key the ciphertext is encrypted under.
+    pub fn set_key_fingerprint(&mut self, key_fingerprint: KeyFingerprint) {
+        self.key_fingerprint = key_fingerprint;
     }
 
     /// Returns the size of the cipher, e.g. the size of the mask + 1 for the body.
@@ -125,6 +189,14 @@ impl<Cont> LweCiphertext<Cont> {
         LweSize(self.as_tensor().len())
     }
 
+    /// Checks that the container of the ciphertext is not empty.
+    pub(crate) fn validate(&self) -> Result<(), ContainerValidationError>
+    where
+        Self: AsRefTensor,
+    {
+        validate_container_length(self.as_tensor().len(), &[1])
+    }
+
     /// Returns the body of the ciphertext.
     ///
     /// # Example
@@ -198,7 +270,11 @@ impl<Cont> LweCiphertext<Cont> {
     where
         Self: AsMutTensor<Element = Scalar>,
     {
-        unsafe { &mut *{ self.as_mut_tensor().last_mut() as *mut Scalar as *mut LweBody<Scalar> } }
+        // The body does not depend on the key, so its modification keeps the fingerprint.
+        let key_fingerprint = self.key_fingerprint;
+        let body = self.as_mut_tensor().last_mut() as *mut Scalar as *mut LweBody<Scalar>;
+        self.key_fingerprint = key_fingerprint;
+        unsafe { &mut *body }
     }
 
     /// Returns the mutable mask of the ciphertext.
@@ -309,6 +385,7 @@ impl<Cont> LweCiphertext<Cont> {
     {
         self.as_mut_tensor()
             .fill_with_one(input.as_tensor(), |o| o.wrapping_mul(scalar.0));
+        self.key_fingerprint = input.key_fingerprint;
     }
 
     /// Fills the ciphertext with the result of the multisum of the `input_list` with the
@@ -392,6 +469,45 @@ impl<Cont> LweCiphertext<Cont> {
         *self.get_mut_body() = LweBody(new_body);
     }
 
+    /// Rounds every element of the ciphertext to the closest multiple of $q/2^{k}$, where $q$ is
+    /// the modulus of the ciphertext and $k$ the given modulus log.
+    ///
+    /// Up to a scaling by $2^{k}/q$, the output is the modulus switch of the ciphertext to the
+    /// modulus $2^{k}$, kept in the native representation so that it can be fed to the usual
+    /// operations. A bootstrap using polynomials of size $N$ with $2N = 2^{k}$ performs its own
+    /// modulus switch on such a ciphertext without any additional rounding error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::CiphertextModulusLog;
+    /// use concrete_core::commons::crypto::lwe::*;
+    /// use concrete_core::commons::math::tensor::{AsRefSlice, AsRefTensor};
+    ///
+    /// let container = vec![(1 << 20) + 1, (1 << 19) + 5, (1 << 19) - 1_u32];
+    /// let mut cipher = LweCiphertext::from_container(container);
+    /// cipher.round_to_modulus(CiphertextModulusLog(12));
+    /// assert_eq!(cipher.as_tensor().as_slice(), &[1 << 20, 1 << 20, 0]);
+    /// ```
+    pub fn round_to_modulus<Scalar>(&mut self, modulus_log: CiphertextModulusLog)
+    where
+        Self: AsMutTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        debug_assert!(modulus_log.0 > 0 && modulus_log.0 <= <Scalar as Numeric>::BITS);
+        let non_rep_bit_count = <Scalar as Numeric>::BITS - modulus_log.0;
+        if non_rep_bit_count == 0 {
+            return;
+        }
+        let key_fingerprint = self.key_fingerprint;
+        self.as_mut_tensor().update_with(|element| {
+            let non_rep_msb = (*element >> (non_rep_bit_count - 1)) & Scalar::ONE;
+            *element =
+                ((*element >> non_rep_bit_count).wrapping_add(non_rep_msb)) << non_rep_bit_count;
+        });
+        self.key_fingerprint = key_fingerprint;
+    }
+
     /// Adds the `other` ciphertext to the current one.
     ///
     /// # Example
@@ -444,8 +560,10 @@ impl<Cont> LweCiphertext<Cont> {
         LweCiphertext<OtherCont>: AsRefTensor<Element = Scalar>,
         Scalar: UnsignedTorus,
     {
+        let key_fingerprint = self.key_fingerprint.merge(&other.key_fingerprint);
         self.as_mut_tensor()
-            .update_with_wrapping_add(other.as_tensor())
+            .update_with_wrapping_add(other.as_tensor());
+        self.key_fingerprint = key_fingerprint;
     }
 
     /// Subtracts the `other` ciphertext from the current one.
@@ -500,8 +618,94 @@ impl<Cont> LweCiphertext<Cont> {
         LweCiphertext<OtherCont>: AsRefTensor<Element = Scalar>,
         Scalar: UnsignedTorus,
     {
+        let key_fingerprint = self.key_fingerprint.merge(&other.key_fingerprint);
+        self.as_mut_tensor()
+            .update_with_wrapping_sub(other.as_tensor());
+        self.key_fingerprint = key_fingerprint;
+    }
+
+    /// Adds the `other` ciphertext to the current one, modulo a non-native modulus.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{LweDimension, LweSize};
+    /// use concrete_core::commons::crypto::encoding::*;
+    /// use concrete_core::commons::crypto::lwe::*;
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::LweSecretKey;
+    /// use concrete_core::commons::math::modular::{NonNativeModulus, SolinasPrime64};
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    ///
+    /// let secret_key = LweSecretKey::generate_binary(LweDimension(256), &mut secret_generator);
+    /// let noise = LogStandardDev::from_log_standard_dev(-25.);
+    /// let modulus = SolinasPrime64;
+    /// let delta = modulus.value() / 16;
+    ///
+    /// let mut cipher_1 = LweCiphertext::allocate(0u64, LweSize(257));
+    /// let plain_1 = Plaintext(13 * delta);
+    /// secret_key.encrypt_lwe_modular(
+    ///     &mut cipher_1,
+    ///     &plain_1,
+    ///     noise,
+    ///     &mut encryption_generator,
+    ///     &modulus,
+    /// );
+    /// let mut cipher_2 = LweCiphertext::allocate(0u64, LweSize(257));
+    /// let plain_2 = Plaintext(5 * delta);
+    /// secret_key.encrypt_lwe_modular(
+    ///     &mut cipher_2,
+    ///     &plain_2,
+    ///     noise,
+    ///     &mut encryption_generator,
+    ///     &modulus,
+    /// );
+    ///
+    /// cipher_1.update_with_add_modular(&cipher_2, &modulus);
+    ///
+    /// let mut decrypted = Plaintext(0u64);
+    /// secret_key.decrypt_lwe_modular(&mut decrypted, &cipher_1, &modulus);
+    /// assert_eq!(((decrypted.0 + delta / 2) / delta) % 16, 2);
+    /// ```
+    pub fn update_with_add_modular<OtherCont, Modulus>(
+        &mut self,
+        other: &LweCiphertext<OtherCont>,
+        modulus: &Modulus,
+    ) where
+        Self: AsMutTensor<Element = u64>,
+        LweCiphertext<OtherCont>: AsRefTensor<Element = u64>,
+        Modulus: NonNativeModulus,
+    {
+        let key_fingerprint = self.key_fingerprint.merge(&other.key_fingerprint);
+        self.as_mut_tensor()
+            .update_with_one(other.as_tensor(), |a, b| *a = modulus.add(*a, *b));
+        self.key_fingerprint = key_fingerprint;
+    }
+
+    /// Subtracts the `other` ciphertext from the current one, modulo a non-native modulus.
+    ///
+    /// See [`LweCiphertext::update_with_add_modular`] for an example.
+    pub fn update_with_sub_modular<OtherCont, Modulus>(
+        &mut self,
+        other: &LweCiphertext<OtherCont>,
+        modulus: &Modulus,
+    ) where
+        Self: AsMutTensor<Element = u64>,
+        LweCiphertext<OtherCont>: AsRefTensor<Element = u64>,
+        Modulus: NonNativeModulus,
+    {
+        let key_fingerprint = self.key_fingerprint.merge(&other.key_fingerprint);
         self.as_mut_tensor()
-            .update_with_wrapping_sub(other.as_tensor())
+            .update_with_one(other.as_tensor(), |a, b| *a = modulus.sub(*a, *b));
+        self.key_fingerprint = key_fingerprint;
     }
 
     /// Computes the opposite of the ciphertext.
@@ -550,7 +754,9 @@ impl<Cont> LweCiphertext<Cont> {
         Self: AsMutTensor<Element = Scalar>,
         Scalar: UnsignedTorus,
     {
-        self.as_mut_tensor().update_with_wrapping_neg()
+        let key_fingerprint = self.key_fingerprint;
+        self.as_mut_tensor().update_with_wrapping_neg();
+        self.key_fingerprint = key_fingerprint;
     }
 
     /// Multiplies the current ciphertext with a scalar value inplace.
@@ -599,8 +805,10 @@ impl<Cont> LweCiphertext<Cont> {
         Self: AsMutTensor<Element = Scalar>,
         Scalar: UnsignedTorus,
     {
+        let key_fingerprint = self.key_fingerprint;
         self.as_mut_tensor()
-            .update_with_wrapping_scalar_mul(&scalar.0)
+            .update_with_wrapping_scalar_mul(&scalar.0);
+        self.key_fingerprint = key_fingerprint;
     }
 
     /// Fills an LWE ciphertext with the sample extraction of one of the coefficients of a GLWE
@@ -798,7 +1006,7 @@ impl<Cont> LweMask<Cont> {
         self.as_tensor().fold_with_one(
             key.as_tensor(),
             <Scalar as Numeric>::ZERO,
-            |ac, s_i, o_i| ac.wrapping_add(*s_i * *o_i),
+            |ac, s_i, o_i| ac.wrapping_add(s_i.wrapping_mul(*o_i)),
         )
     }
 }
@@ -1,20 +1,25 @@
-use super::{LweCiphertext, LweList};
-use crate::commons::crypto::encoding::{Plaintext, PlaintextList};
+use super::{LweBody, LweCiphertext, LweLevelMajorKeyswitchKey, LweList};
+use crate::commons::crypto::encoding::{CleartextList, Plaintext, PlaintextList};
 use crate::commons::crypto::secret::generators::EncryptionRandomGenerator;
-use crate::commons::crypto::secret::LweSecretKey;
+use crate::commons::crypto::secret::{KeyFingerprint, LweSecretKey};
 use crate::commons::math::decomposition::{
     DecompositionLevel, DecompositionTerm, SignedDecomposer,
 };
-use crate::commons::math::random::ByteRandomGenerator;
+use crate::commons::math::modular::{lazy_reduction_period, NonNativeModulus};
+use crate::commons::math::random::{ByteRandomGenerator, RandomGenerator};
 use crate::commons::math::tensor::{
     ck_dim_div, ck_dim_eq, tensor_traits, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
 };
 use crate::commons::math::torus::UnsignedTorus;
+use crate::commons::utils::{validate_container_length, ContainerValidationError};
 use concrete_commons::dispersion::DispersionParameter;
-use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_commons::key_kinds::KeyKind;
 use concrete_commons::parameters::{
     CiphertextCount, DecompositionBaseLog, DecompositionLevelCount, LweDimension, LweSize,
+    RoundingMode,
 };
+#[cfg(feature = "__commons_parallel")]
+use rayon::prelude::*;
 #[cfg(feature = "__commons_serialization")]
 use serde::{Deserialize, Serialize};
 
@@ -29,6 +34,9 @@ use serde::{Deserialize, Serialize};
 /// The keyswitch key will be composed of $m$ encryptions of each bits of the $s\_{out}$ key, under
 /// the key $s\_{in}$; encryptions which will be stored as their decomposition over a given basis
 /// $B\_{ks}\in\mathbb{N}$, up to a level $l\_{ks}\in\mathbb{N}$.
+///
+/// The keyswitching key carries the [`KeyFingerprint`]s of its input and output keys, which are
+/// set by [`LweKeyswitchKey::fill_with_keyswitch_key`].
 #[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct LweKeyswitchKey<Cont> {
@@ -36,6 +44,10 @@ pub struct LweKeyswitchKey<Cont> {
     decomp_base_log: DecompositionBaseLog,
     decomp_level_count: DecompositionLevelCount,
     lwe_size: LweSize,
+    #[cfg_attr(feature = "__commons_serialization", serde(skip))]
+    input_key_fingerprint: KeyFingerprint,
+    #[cfg_attr(feature = "__commons_serialization", serde(skip))]
+    output_key_fingerprint: KeyFingerprint,
 }
 
 tensor_traits!(LweKeyswitchKey);
@@ -91,6 +103,8 @@ where
             decomp_base_log,
             decomp_level_count: decomp_size,
             lwe_size: LweSize(output_size.0 + 1),
+            input_key_fingerprint: KeyFingerprint::unknown(),
+            output_key_fingerprint: KeyFingerprint::unknown(),
         }
     }
 }
@@ -146,6 +160,8 @@ impl<Cont> LweKeyswitchKey<Cont> {
             decomp_base_log,
             decomp_level_count: decomp_size,
             lwe_size: LweSize(output_size.0 + 1),
+            input_key_fingerprint: KeyFingerprint::unknown(),
+            output_key_fingerprint: KeyFingerprint::unknown(),
         }
     }
 
@@ -202,6 +218,18 @@ impl<Cont> LweKeyswitchKey<Cont> {
         self.lwe_size
     }
 
+    /// Checks that the length of the container is consistent with the output LWE size and the
+    /// decomposition level count of the key.
+    pub(crate) fn validate(&self) -> Result<(), ContainerValidationError>
+    where
+        Self: AsRefTensor,
+    {
+        validate_container_length(
+            self.as_tensor().len(),
+            &[self.lwe_size.0, self.decomp_level_count.0],
+        )
+    }
+
     /// Returns the size of the input key.
     ///
     /// # Example
@@ -329,16 +357,18 @@ impl<Cont> LweKeyswitchKey<Cont> {
     ///
     /// assert!(!ksk.as_tensor().iter().all(|a| *a == 0));
     /// ```
-    pub fn fill_with_keyswitch_key<InKeyCont, OutKeyCont, Scalar, Gen>(
+    pub fn fill_with_keyswitch_key<InKind, OutKind, InKeyCont, OutKeyCont, Scalar, Gen>(
         &mut self,
-        before_key: &LweSecretKey<BinaryKeyKind, InKeyCont>,
-        after_key: &LweSecretKey<BinaryKeyKind, OutKeyCont>,
+        before_key: &LweSecretKey<InKind, InKeyCont>,
+        after_key: &LweSecretKey<OutKind, OutKeyCont>,
         noise_parameters: impl DispersionParameter,
         generator: &mut EncryptionRandomGenerator<Gen>,
     ) where
         Self: AsMutTensor<Element = Scalar>,
-        LweSecretKey<BinaryKeyKind, InKeyCont>: AsRefTensor<Element = Scalar>,
-        LweSecretKey<BinaryKeyKind, OutKeyCont>: AsRefTensor<Element = Scalar>,
+        InKind: KeyKind,
+        OutKind: KeyKind,
+        LweSecretKey<InKind, InKeyCont>: AsRefTensor<Element = Scalar>,
+        LweSecretKey<OutKind, OutKeyCont>: AsRefTensor<Element = Scalar>,
         Scalar: UnsignedTorus,
         Gen: ByteRandomGenerator,
     {
@@ -382,6 +412,54 @@ impl<Cont> LweKeyswitchKey<Cont> {
                 generator,
             );
         }
+
+        self.input_key_fingerprint = before_key.key_fingerprint();
+        self.output_key_fingerprint = after_key.key_fingerprint();
+    }
+
+    /// Returns the fingerprint of the input key of the keyswitching key.
+    pub fn input_key_fingerprint(&self) -> KeyFingerprint {
+        self.input_key_fingerprint
+    }
+
+    /// Returns the fingerprint of the output key of the keyswitching key.
+    pub fn output_key_fingerprint(&self) -> KeyFingerprint {
+        self.output_key_fingerprint
+    }
+
+    /// Fills the current key with the ciphertexts of a keyswitching key stored in level-major
+    /// order.
+    ///
+    /// See [`LweLevelMajorKeyswitchKey::fill_with_standard_keyswitch_key`] for the converse
+    /// operation.
+    pub fn fill_with_level_major_keyswitch_key<InCont, Scalar>(
+        &mut self,
+        input: &LweLevelMajorKeyswitchKey<InCont>,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        LweLevelMajorKeyswitchKey<InCont>: AsRefTensor<Element = Scalar>,
+        Scalar: Copy,
+    {
+        ck_dim_eq!(self.as_tensor().len() => input.as_tensor().len());
+        ck_dim_eq!(self.lwe_size.0 => input.lwe_size().0);
+        ck_dim_eq!(self.decomp_level_count.0 => input.decomposition_levels_count().0);
+        let lwe_size = self.lwe_size.0;
+        let block_size = self.decomp_level_count.0 * lwe_size;
+        for (level, level_key_ciphers) in input.level_iter() {
+            for (input_index, ciphertext) in level_key_ciphers
+                .as_tensor()
+                .subtensor_iter(lwe_size)
+                .enumerate()
+            {
+                let start = input_index * block_size + (level.0 - 1) * lwe_size;
+                self.as_mut_tensor()
+                    .get_sub_mut(start..start + lwe_size)
+                    .fill_with_copy(&ciphertext);
+            }
+        }
+        self.decomp_base_log = input.decomposition_base_log();
+        self.input_key_fingerprint = input.input_key_fingerprint();
+        self.output_key_fingerprint = input.output_key_fingerprint();
     }
 
     /// Iterates over borrowed `LweKeyBitDecomposition` elements.
@@ -525,23 +603,180 @@ impl<Cont> LweKeyswitchKey<Cont> {
         LweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
         LweCiphertext<InCont>: AsRefTensor<Element = Scalar>,
         Scalar: UnsignedTorus,
+    {
+        self.keyswitch_ciphertext_with(after, before, |decomposer, mask| {
+            decomposer.closest_representable(mask)
+        });
+    }
+
+    /// Keyswitches a single LWE ciphertext, rounding the mask elements before their decomposition
+    /// with the given rounding mode.
+    ///
+    /// A generator must be provided when the rounding mode is [`RoundingMode::Stochastic`].
+    pub fn keyswitch_ciphertext_with_rounding<InCont, OutCont, Scalar, Gen>(
+        &self,
+        after: &mut LweCiphertext<OutCont>,
+        before: &LweCiphertext<InCont>,
+        rounding: RoundingMode,
+        mut generator: Option<&mut RandomGenerator<Gen>>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        LweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        LweCiphertext<InCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+        Gen: ByteRandomGenerator,
+    {
+        self.keyswitch_ciphertext_with(after, before, |decomposer, mask| {
+            decomposer.closest_representable_with_rounding(mask, rounding, generator.as_deref_mut())
+        });
+    }
+
+    fn keyswitch_ciphertext_with<InCont, OutCont, Scalar, Round>(
+        &self,
+        after: &mut LweCiphertext<OutCont>,
+        before: &LweCiphertext<InCont>,
+        round: Round,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        LweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        LweCiphertext<InCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+        Round: FnMut(&SignedDecomposer<Scalar>, Scalar) -> Scalar,
     {
         ck_dim_eq!(self.before_key_size().0 => before.get_mask().mask_size().0);
+        self.keyswitch_elements_with(
+            after,
+            before.get_mask().mask_element_iter().copied(),
+            before.get_body().0,
+            round,
+        );
+    }
+
+    /// Keyswitches the affine combination of a list of ciphertexts, without computing the
+    /// combination itself.
+    ///
+    /// The output is the keyswitch of the ciphertext that
+    /// [`LweCiphertext::fill_with_multisum_with_bias`] would compute from the same inputs. Each
+    /// mask element of the combination is computed right before its decomposition, which spares
+    /// the allocation and the traversal of the intermediate ciphertext.
+    pub fn keyswitch_multisum_with_bias<InCont, WeightCont, OutCont, Scalar>(
+        &self,
+        after: &mut LweCiphertext<OutCont>,
+        before_list: &LweList<InCont>,
+        weights: &CleartextList<WeightCont>,
+        bias: &Plaintext<Scalar>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        LweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        LweList<InCont>: AsRefTensor<Element = Scalar>,
+        CleartextList<WeightCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        self.keyswitch_multisum_with_bias_with(
+            after,
+            before_list,
+            weights,
+            bias,
+            |decomposer, mask| decomposer.closest_representable(mask),
+        );
+    }
+
+    /// Keyswitches the affine combination of a list of ciphertexts, rounding the mask elements
+    /// of the combination before their decomposition with the given rounding mode.
+    ///
+    /// A generator must be provided when the rounding mode is [`RoundingMode::Stochastic`].
+    pub fn keyswitch_multisum_with_bias_with_rounding<InCont, WeightCont, OutCont, Scalar, Gen>(
+        &self,
+        after: &mut LweCiphertext<OutCont>,
+        before_list: &LweList<InCont>,
+        weights: &CleartextList<WeightCont>,
+        bias: &Plaintext<Scalar>,
+        rounding: RoundingMode,
+        mut generator: Option<&mut RandomGenerator<Gen>>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        LweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        LweList<InCont>: AsRefTensor<Element = Scalar>,
+        CleartextList<WeightCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+        Gen: ByteRandomGenerator,
+    {
+        self.keyswitch_multisum_with_bias_with(
+            after,
+            before_list,
+            weights,
+            bias,
+            |decomposer, mask| {
+                decomposer.closest_representable_with_rounding(
+                    mask,
+                    rounding,
+                    generator.as_deref_mut(),
+                )
+            },
+        );
+    }
+
+    fn keyswitch_multisum_with_bias_with<InCont, WeightCont, OutCont, Scalar, Round>(
+        &self,
+        after: &mut LweCiphertext<OutCont>,
+        before_list: &LweList<InCont>,
+        weights: &CleartextList<WeightCont>,
+        bias: &Plaintext<Scalar>,
+        round: Round,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        LweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        LweList<InCont>: AsRefTensor<Element = Scalar>,
+        CleartextList<WeightCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+        Round: FnMut(&SignedDecomposer<Scalar>, Scalar) -> Scalar,
+    {
+        ck_dim_eq!(self.before_key_size().0 + 1 => before_list.lwe_size().0);
+        ck_dim_eq!(before_list.count().0 => weights.count().0);
+        let lwe_size = before_list.lwe_size().0;
+        let before = before_list.as_tensor().as_slice();
+        let weights = weights.as_tensor().as_slice();
+        // The element at the given index of the affine combination, without the bias.
+        let combined_element = |index: usize| {
+            before
+                .iter()
+                .skip(index)
+                .step_by(lwe_size)
+                .zip(weights.iter())
+                .fold(Scalar::ZERO, |acc, (element, weight)| {
+                    acc.wrapping_add(element.wrapping_mul(*weight))
+                })
+        };
+        let body = combined_element(lwe_size - 1).wrapping_add(bias.0);
+        self.keyswitch_elements_with(after, (0..lwe_size - 1).map(combined_element), body, round);
+    }
+
+    // Keyswitches the ciphertext made of the given mask elements and body.
+    fn keyswitch_elements_with<OutCont, Scalar, Mask, Round>(
+        &self,
+        after: &mut LweCiphertext<OutCont>,
+        before_mask: Mask,
+        before_body: Scalar,
+        mut round: Round,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        LweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+        Mask: Iterator<Item = Scalar>,
+        Round: FnMut(&SignedDecomposer<Scalar>, Scalar) -> Scalar,
+    {
         ck_dim_eq!(self.after_key_size().0 => after.get_mask().mask_size().0);
 
         // We reset the output
         after.as_mut_tensor().fill_with(|| Scalar::ZERO);
 
         // We copy the body
-        *after.get_mut_body() = *before.get_body();
+        *after.get_mut_body() = LweBody(before_body);
         // We instantiate a decomposer
         let decomposer = SignedDecomposer::new(self.decomp_base_log, self.decomp_level_count);
 
-        for (block, before_mask) in self
-            .bit_decomp_iter()
-            .zip(before.get_mask().mask_element_iter())
-        {
-            let mask_rounded = decomposer.closest_representable(*before_mask);
+        for (block, before_mask) in self.bit_decomp_iter().zip(before_mask) {
+            let mask_rounded = round(&decomposer, before_mask);
             let decomp = decomposer.decompose(mask_rounded);
             // loop over the number of levels
             for (level_key_cipher, decomposed) in block
@@ -555,6 +790,7 @@ impl<Cont> LweKeyswitchKey<Cont> {
                     .update_with_wrapping_sub_element_mul(&level_key_cipher, decomposed.value());
             }
         }
+        after.set_key_fingerprint(self.output_key_fingerprint);
     }
 
     pub fn keyswitch_list<InCont, OutCont, Scalar>(
@@ -574,6 +810,246 @@ impl<Cont> LweKeyswitchKey<Cont> {
         {
             self.keyswitch_ciphertext(&mut output_cipher, &input_cipher);
         }
+        output.set_key_fingerprint(self.output_key_fingerprint);
+    }
+
+    /// Keyswitches a list of ciphertexts, rounding the mask elements before their decomposition
+    /// with the given rounding mode.
+    ///
+    /// A generator must be provided when the rounding mode is [`RoundingMode::Stochastic`].
+    pub fn keyswitch_list_with_rounding<InCont, OutCont, Scalar, Gen>(
+        &self,
+        output: &mut LweList<OutCont>,
+        input: &LweList<InCont>,
+        rounding: RoundingMode,
+        mut generator: Option<&mut RandomGenerator<Gen>>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        LweList<InCont>: AsRefTensor<Element = Scalar>,
+        LweList<OutCont>: AsMutTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+        Gen: ByteRandomGenerator,
+    {
+        ck_dim_eq!(input.count().0 => output.count().0);
+        for (input_cipher, mut output_cipher) in
+            input.ciphertext_iter().zip(output.ciphertext_iter_mut())
+        {
+            self.keyswitch_ciphertext_with_rounding(
+                &mut output_cipher,
+                &input_cipher,
+                rounding,
+                generator.as_deref_mut(),
+            );
+        }
+        output.set_key_fingerprint(self.output_key_fingerprint);
+    }
+
+    /// Keyswitches a list of ciphertexts, using as many threads as possible.
+    ///
+    /// # Notes
+    /// This method is hidden behind the "parallel" feature gate.
+    #[cfg(feature = "__commons_parallel")]
+    pub fn par_keyswitch_list<InCont, OutCont, Scalar>(
+        &self,
+        output: &mut LweList<OutCont>,
+        input: &LweList<InCont>,
+    ) where
+        Self: AsRefTensor<Element = Scalar> + Sync,
+        LweList<InCont>: AsRefTensor<Element = Scalar>,
+        LweList<OutCont>: AsMutTensor<Element = Scalar>,
+        Scalar: UnsignedTorus + Send + Sync,
+    {
+        ck_dim_eq!(input.count().0 => output.count().0);
+        input
+            .par_ciphertext_iter()
+            .zip(output.par_ciphertext_iter_mut())
+            .for_each(|(input_cipher, mut output_cipher)| {
+                self.keyswitch_ciphertext(&mut output_cipher, &input_cipher);
+            });
+        output.set_key_fingerprint(self.output_key_fingerprint);
+    }
+
+    /// Fills the current keyswitch key container with an actual keyswitching key constructed
+    /// from an input and an output key, modulo a non-native modulus.
+    ///
+    /// The level $j$ of the decomposition of the coefficient $s\_i$ of the input key is an
+    /// encryption of $s\_i\cdot\lfloor q / B^j\rceil$ under the output key, where $B$ is the
+    /// decomposition base.
+    pub fn fill_with_keyswitch_key_modular<InKind, OutKind, InKeyCont, OutKeyCont, Gen, Modulus>(
+        &mut self,
+        before_key: &LweSecretKey<InKind, InKeyCont>,
+        after_key: &LweSecretKey<OutKind, OutKeyCont>,
+        noise_parameters: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator<Gen>,
+        modulus: &Modulus,
+    ) where
+        Self: AsMutTensor<Element = u64>,
+        InKind: KeyKind,
+        OutKind: KeyKind,
+        LweSecretKey<InKind, InKeyCont>: AsRefTensor<Element = u64>,
+        LweSecretKey<OutKind, OutKeyCont>: AsRefTensor<Element = u64>,
+        Gen: ByteRandomGenerator,
+        Modulus: NonNativeModulus,
+    {
+        let decomp_base_log = self.decomp_base_log;
+
+        // loop over the before key blocks
+        for (input_key_coef, keyswitch_key_block) in before_key
+            .as_tensor()
+            .iter()
+            .zip(self.bit_decomp_iter_mut())
+        {
+            let input_key_coef = modulus.reduce_signed(*input_key_coef as i64);
+            // We encrypt the multiples of the key coefficient for each level
+            for (level, mut level_key_cipher) in
+                (1..).zip(keyswitch_key_block.into_lwe_list().ciphertext_iter_mut())
+            {
+                let message = Plaintext(modulus.mul(
+                    input_key_coef,
+                    modulus.scaled_power_of_two(level * decomp_base_log.0),
+                ));
+                after_key.encrypt_lwe_modular(
+                    &mut level_key_cipher,
+                    &message,
+                    noise_parameters,
+                    generator,
+                    modulus,
+                );
+            }
+        }
+
+        self.input_key_fingerprint = before_key.key_fingerprint();
+        self.output_key_fingerprint = after_key.key_fingerprint();
+    }
+
+    /// Switches the key of a single Lwe ciphertext, modulo a non-native modulus.
+    ///
+    /// The mask coefficients of the input ciphertext are mapped to the native torus
+    /// representation before being decomposed, so the same decomposition parameters can be used
+    /// as for the native modulus.
+    ///
+    /// The products of the key coefficients by the decomposition terms are summed in `u128`
+    /// accumulators, which are only reduced once every [`lazy_reduction_period`] products. The
+    /// result is exactly the one obtained by reducing after every product, for any base log.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, LweSize,
+    /// };
+    /// use concrete_core::commons::crypto::encoding::*;
+    /// use concrete_core::commons::crypto::lwe::*;
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::LweSecretKey;
+    /// use concrete_core::commons::math::modular::{NonNativeModulus, SolinasPrime64};
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    ///
+    /// let input_size = LweDimension(600);
+    /// let output_size = LweDimension(400);
+    /// let noise = LogStandardDev::from_log_standard_dev(-25.);
+    /// let modulus = SolinasPrime64;
+    /// let delta = modulus.value() / 16;
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// let input_key = LweSecretKey::generate_binary(input_size, &mut secret_generator);
+    /// let output_key = LweSecretKey::generate_binary(output_size, &mut secret_generator);
+    ///
+    /// let mut ksk = LweKeyswitchKey::allocate(
+    ///     0 as u64,
+    ///     DecompositionLevelCount(5),
+    ///     DecompositionBaseLog(4),
+    ///     input_size,
+    ///     output_size,
+    /// );
+    /// ksk.fill_with_keyswitch_key_modular(
+    ///     &input_key,
+    ///     &output_key,
+    ///     noise,
+    ///     &mut encryption_generator,
+    ///     &modulus,
+    /// );
+    ///
+    /// let plaintext = Plaintext(7 * delta);
+    /// let mut ciphertext = LweCiphertext::allocate(0u64, input_size.to_lwe_size());
+    /// input_key.encrypt_lwe_modular(
+    ///     &mut ciphertext,
+    ///     &plaintext,
+    ///     noise,
+    ///     &mut encryption_generator,
+    ///     &modulus,
+    /// );
+    /// let mut switched_ciphertext = LweCiphertext::allocate(0u64, output_size.to_lwe_size());
+    /// ksk.keyswitch_ciphertext_modular(&mut switched_ciphertext, &ciphertext, &modulus);
+    ///
+    /// let mut decrypted = Plaintext(0u64);
+    /// output_key.decrypt_lwe_modular(&mut decrypted, &switched_ciphertext, &modulus);
+    /// assert_eq!((decrypted.0 + delta / 2) / delta, 7);
+    /// ```
+    pub fn keyswitch_ciphertext_modular<InCont, OutCont, Modulus>(
+        &self,
+        after: &mut LweCiphertext<OutCont>,
+        before: &LweCiphertext<InCont>,
+        modulus: &Modulus,
+    ) where
+        Self: AsRefTensor<Element = u64>,
+        LweCiphertext<OutCont>: AsMutTensor<Element = u64>,
+        LweCiphertext<InCont>: AsRefTensor<Element = u64>,
+        Modulus: NonNativeModulus,
+    {
+        ck_dim_eq!(self.before_key_size().0 => before.get_mask().mask_size().0);
+        ck_dim_eq!(self.after_key_size().0 => after.get_mask().mask_size().0);
+
+        // The decomposition terms are smaller than 2^(base_log - 1) in absolute value.
+        let period = lazy_reduction_period(self.decomp_base_log.0);
+        let mut pending_products = 0;
+        // We start the accumulation from the body of the input
+        let mut accumulators = vec![0u128; self.after_key_size().0 + 1];
+        *accumulators.last_mut().unwrap() = before.get_body().0 as u128;
+        // We instantiate a decomposer
+        let decomposer = SignedDecomposer::new(self.decomp_base_log, self.decomp_level_count);
+
+        for (block, before_mask) in self
+            .bit_decomp_iter()
+            .zip(before.get_mask().mask_element_iter())
+        {
+            let mask_rounded = decomposer.closest_representable(modulus.to_native(*before_mask));
+            let decomp = decomposer.decompose(mask_rounded);
+            // loop over the number of levels
+            for (level_key_cipher, decomposed) in block
+                .as_tensor()
+                .subtensor_iter(self.after_key_size().0 + 1)
+                .rev()
+                .zip(decomp)
+            {
+                if pending_products == period {
+                    accumulators
+                        .iter_mut()
+                        .for_each(|acc| *acc = modulus.reduce(*acc) as u128);
+                    pending_products = 0;
+                }
+                // Subtracting the product of the key by a positive term amounts to adding the
+                // product of the opposite of the key by this term.
+                let value = decomposed.value() as i64;
+                let factor = value.unsigned_abs() as u128;
+                for (acc, key) in accumulators.iter_mut().zip(level_key_cipher.iter()) {
+                    let key = if value > 0 { modulus.neg(*key) } else { *key };
+                    *acc += key as u128 * factor;
+                }
+                pending_products += 1;
+            }
+        }
+        after
+            .as_mut_tensor()
+            .iter_mut()
+            .zip(accumulators.iter())
+            .for_each(|(output, acc)| *output = modulus.reduce(*acc));
+        after.set_key_fingerprint(self.output_key_fingerprint);
     }
 }
 
@@ -714,6 +1190,7 @@ impl<Cont> LweKeyBitDecomposition<Cont> {
         LweList {
             tensor: self.tensor,
             lwe_size: self.lwe_size,
+            key_fingerprint: KeyFingerprint::unknown(),
         }
     }
 }
@@ -0,0 +1,209 @@
+use super::LweCiphertext;
+use crate::commons::math::decomposition::DecompositionLevel;
+use crate::commons::math::tensor::{
+    ck_dim_div, tensor_traits, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, LweDimension, LweSize,
+};
+#[cfg(feature = "__commons_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A Lev ciphertext, e.g. a list of LWE ciphertexts encrypting the same plaintext scaled by the
+/// successive powers of the inverse of a decomposition base.
+///
+/// The ciphertext of level $j$, for $1 \le j \le \ell$, encrypts the plaintext multiplied by
+/// $q / \beta^j$, where $\beta$ is the decomposition base. The levels are stored in increasing
+/// order.
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LevCiphertext<Cont> {
+    pub(crate) tensor: Tensor<Cont>,
+    pub(crate) lwe_size: LweSize,
+    pub(crate) decomp_base_log: DecompositionBaseLog,
+}
+
+tensor_traits!(LevCiphertext);
+
+impl<Scalar> LevCiphertext<Vec<Scalar>>
+where
+    Scalar: Copy,
+{
+    /// Allocates storage for an owned [`LevCiphertext`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, LweSize,
+    /// };
+    /// use concrete_core::commons::crypto::lwe::LevCiphertext;
+    /// let lev = LevCiphertext::allocate(
+    ///     0 as u8,
+    ///     LweDimension(10),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(4),
+    /// );
+    /// assert_eq!(lev.lwe_size(), LweSize(11));
+    /// assert_eq!(lev.decomposition_level_count(), DecompositionLevelCount(3));
+    /// assert_eq!(lev.decomposition_base_log(), DecompositionBaseLog(4));
+    /// ```
+    pub fn allocate(
+        value: Scalar,
+        lwe_dimension: LweDimension,
+        decomp_level: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+    ) -> Self {
+        LevCiphertext {
+            tensor: Tensor::from_container(vec![
+                value;
+                lwe_dimension.to_lwe_size().0 * decomp_level.0
+            ]),
+            lwe_size: lwe_dimension.to_lwe_size(),
+            decomp_base_log,
+        }
+    }
+}
+
+impl<Cont> LevCiphertext<Cont> {
+    /// Creates a Lev ciphertext from an existing container.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, LweSize,
+    /// };
+    /// use concrete_core::commons::crypto::lwe::LevCiphertext;
+    /// let lev =
+    ///     LevCiphertext::from_container(vec![0 as u8; 11 * 3], LweDimension(10), DecompositionBaseLog(4));
+    /// assert_eq!(lev.lwe_size(), LweSize(11));
+    /// assert_eq!(lev.decomposition_level_count(), DecompositionLevelCount(3));
+    /// ```
+    pub fn from_container(
+        cont: Cont,
+        lwe_dimension: LweDimension,
+        decomp_base_log: DecompositionBaseLog,
+    ) -> Self
+    where
+        Cont: AsRefSlice,
+    {
+        let tensor = Tensor::from_container(cont);
+        ck_dim_div!(tensor.len() => lwe_dimension.to_lwe_size().0);
+        LevCiphertext {
+            tensor,
+            lwe_size: lwe_dimension.to_lwe_size(),
+            decomp_base_log,
+        }
+    }
+
+    /// Returns the size of the LWE ciphertexts composing the Lev ciphertext.
+    pub fn lwe_size(&self) -> LweSize {
+        self.lwe_size
+    }
+
+    /// Returns the number of decomposition levels of the ciphertext.
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount
+    where
+        Self: AsRefTensor,
+    {
+        ck_dim_div!(self.as_tensor().len() => self.lwe_size.0);
+        DecompositionLevelCount(self.as_tensor().len() / self.lwe_size.0)
+    }
+
+    /// Returns the logarithm of the base used for the decomposition.
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Returns an iterator over the levels of the ciphertext, with the LWE ciphertext of each
+    /// level.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, LweSize,
+    /// };
+    /// use concrete_core::commons::crypto::lwe::LevCiphertext;
+    /// let lev = LevCiphertext::allocate(
+    ///     0 as u8,
+    ///     LweDimension(10),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(4),
+    /// );
+    /// for (level, lwe) in lev.level_iter() {
+    ///     assert!(level.0 >= 1 && level.0 <= 3);
+    ///     assert_eq!(lwe.lwe_size(), LweSize(11));
+    /// }
+    /// assert_eq!(lev.level_iter().count(), 3);
+    /// ```
+    pub fn level_iter(
+        &self,
+    ) -> impl DoubleEndedIterator<
+        Item = (
+            DecompositionLevel,
+            LweCiphertext<&[<Self as AsRefTensor>::Element]>,
+        ),
+    >
+    where
+        Self: AsRefTensor,
+    {
+        self.as_tensor()
+            .subtensor_iter(self.lwe_size.0)
+            .enumerate()
+            .map(|(index, sub)| {
+                (
+                    DecompositionLevel(index + 1),
+                    LweCiphertext::from_container(sub.into_container()),
+                )
+            })
+    }
+
+    /// Returns an iterator over the levels of the ciphertext, with the mutably borrowed LWE
+    /// ciphertext of each level.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::commons::crypto::lwe::LevCiphertext;
+    /// use concrete_core::commons::math::tensor::{AsMutTensor, AsRefTensor};
+    /// let mut lev = LevCiphertext::allocate(
+    ///     0 as u8,
+    ///     LweDimension(10),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(4),
+    /// );
+    /// for (level, mut lwe) in lev.level_iter_mut() {
+    ///     lwe.as_mut_tensor().fill_with_element(level.0 as u8);
+    /// }
+    /// for (level, lwe) in lev.level_iter() {
+    ///     assert!(lwe.as_tensor().iter().all(|a| *a == level.0 as u8));
+    /// }
+    /// ```
+    pub fn level_iter_mut(
+        &mut self,
+    ) -> impl DoubleEndedIterator<
+        Item = (
+            DecompositionLevel,
+            LweCiphertext<&mut [<Self as AsMutTensor>::Element]>,
+        ),
+    >
+    where
+        Self: AsMutTensor,
+    {
+        let chunks_size = self.lwe_size.0;
+        self.as_mut_tensor()
+            .subtensor_iter_mut(chunks_size)
+            .enumerate()
+            .map(|(index, sub)| {
+                (
+                    DecompositionLevel(index + 1),
+                    LweCiphertext::from_container(sub.into_container()),
+                )
+            })
+    }
+}
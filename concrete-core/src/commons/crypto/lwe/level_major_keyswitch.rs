@@ -0,0 +1,481 @@
+use super::{LweBody, LweCiphertext, LweKeyswitchKey, LweList};
+use crate::commons::crypto::secret::KeyFingerprint;
+use crate::commons::math::decomposition::{DecompositionLevel, SignedDecomposer};
+use crate::commons::math::random::{ByteRandomGenerator, RandomGenerator};
+use crate::commons::math::tensor::{
+    ck_dim_div, ck_dim_eq, tensor_traits, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+};
+use crate::commons::math::torus::UnsignedTorus;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, LweDimension, LweSize, RoundingMode,
+};
+#[cfg(feature = "__commons_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// An LWE keyswitching key stored in level-major order.
+///
+/// This key contains the same ciphertexts as an [`LweKeyswitchKey`], but instead of storing the
+/// $l$ levels of the decomposition of each coefficient of the input key next to each other, it
+/// stores next to each other the encryptions of all the coefficients of the input key for a
+/// given level. The levels are stored starting from the most significant one.
+///
+/// This layout allows the keyswitch to decompose the whole input mask level by level, and to
+/// traverse the key in a single pass for each level, which improves the cache behavior when the
+/// number of levels is large.
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweLevelMajorKeyswitchKey<Cont> {
+    tensor: Tensor<Cont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    lwe_size: LweSize,
+    #[cfg_attr(feature = "__commons_serialization", serde(skip))]
+    input_key_fingerprint: KeyFingerprint,
+    #[cfg_attr(feature = "__commons_serialization", serde(skip))]
+    output_key_fingerprint: KeyFingerprint,
+}
+
+tensor_traits!(LweLevelMajorKeyswitchKey);
+
+impl<Scalar> LweLevelMajorKeyswitchKey<Vec<Scalar>>
+where
+    Scalar: Copy,
+{
+    /// Allocates a level-major keyswitching key whose masks and bodies are all `value`.
+    ///
+    /// # Note
+    ///
+    /// This function does *not* generate a keyswitch key, but merely allocates a container of the
+    /// right size. See [`LweLevelMajorKeyswitchKey::fill_with_standard_keyswitch_key`] to fill the
+    /// container with a proper keyswitching key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, LweSize,
+    /// };
+    /// use concrete_core::commons::crypto::lwe::LweLevelMajorKeyswitchKey;
+    /// let ksk = LweLevelMajorKeyswitchKey::allocate(
+    ///     0 as u8,
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(16),
+    ///     LweDimension(10),
+    ///     LweDimension(20),
+    /// );
+    /// assert_eq!(
+    ///     ksk.decomposition_levels_count(),
+    ///     DecompositionLevelCount(10)
+    /// );
+    /// assert_eq!(ksk.decomposition_base_log(), DecompositionBaseLog(16));
+    /// assert_eq!(ksk.lwe_size(), LweSize(21));
+    /// assert_eq!(ksk.before_key_size(), LweDimension(10));
+    /// assert_eq!(ksk.after_key_size(), LweDimension(20));
+    /// ```
+    pub fn allocate(
+        value: Scalar,
+        decomp_size: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+        input_size: LweDimension,
+        output_size: LweDimension,
+    ) -> Self {
+        LweLevelMajorKeyswitchKey {
+            tensor: Tensor::from_container(vec![
+                value;
+                decomp_size.0 * (output_size.0 + 1) * input_size.0
+            ]),
+            decomp_base_log,
+            decomp_level_count: decomp_size,
+            lwe_size: LweSize(output_size.0 + 1),
+            input_key_fingerprint: KeyFingerprint::unknown(),
+            output_key_fingerprint: KeyFingerprint::unknown(),
+        }
+    }
+}
+
+impl<Cont> LweLevelMajorKeyswitchKey<Cont> {
+    /// Creates a level-major LWE keyswitching key from a container.
+    ///
+    /// # Notes
+    ///
+    /// This method does not create a keyswitching key, but merely wrap the container in the proper
+    /// type. It assumes that either the container already contains a proper keyswitching key, or
+    /// that [`LweLevelMajorKeyswitchKey::fill_with_standard_keyswitch_key`] will be called right
+    /// after.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, LweSize,
+    /// };
+    /// use concrete_core::commons::crypto::lwe::LweLevelMajorKeyswitchKey;
+    /// let input_size = LweDimension(256);
+    /// let output_size = LweDimension(35);
+    /// let decomp_log_base = DecompositionBaseLog(7);
+    /// let decomp_level_count = DecompositionLevelCount(4);
+    ///
+    /// let ksk = LweLevelMajorKeyswitchKey::from_container(
+    ///     vec![0 as u8; input_size.0 * (output_size.0 + 1) * decomp_level_count.0],
+    ///     decomp_log_base,
+    ///     decomp_level_count,
+    ///     output_size,
+    /// );
+    ///
+    /// assert_eq!(ksk.decomposition_levels_count(), DecompositionLevelCount(4));
+    /// assert_eq!(ksk.decomposition_base_log(), DecompositionBaseLog(7));
+    /// assert_eq!(ksk.lwe_size(), LweSize(36));
+    /// assert_eq!(ksk.before_key_size(), LweDimension(256));
+    /// assert_eq!(ksk.after_key_size(), LweDimension(35));
+    /// ```
+    pub fn from_container(
+        cont: Cont,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_size: DecompositionLevelCount,
+        output_size: LweDimension,
+    ) -> LweLevelMajorKeyswitchKey<Cont>
+    where
+        Cont: AsRefSlice,
+    {
+        let tensor = Tensor::from_container(cont);
+        ck_dim_div!(tensor.len() => output_size.0 + 1, decomp_size.0);
+        LweLevelMajorKeyswitchKey {
+            tensor,
+            decomp_base_log,
+            decomp_level_count: decomp_size,
+            lwe_size: LweSize(output_size.0 + 1),
+            input_key_fingerprint: KeyFingerprint::unknown(),
+            output_key_fingerprint: KeyFingerprint::unknown(),
+        }
+    }
+
+    /// Returns the size of the output key.
+    pub fn after_key_size(&self) -> LweDimension {
+        LweDimension(self.lwe_size.0 - 1)
+    }
+
+    /// Returns the size of the ciphertexts encoding each level of the decomposition of each
+    /// coefficient of the input key.
+    pub fn lwe_size(&self) -> LweSize {
+        self.lwe_size
+    }
+
+    /// Returns the size of the input key.
+    pub fn before_key_size(&self) -> LweDimension
+    where
+        Self: AsRefTensor,
+    {
+        LweDimension(self.as_tensor().len() / (self.lwe_size.0 * self.decomp_level_count.0))
+    }
+
+    /// Returns the number of levels used for the decomposition of the input key coefficients.
+    pub fn decomposition_levels_count(&self) -> DecompositionLevelCount {
+        self.decomp_level_count
+    }
+
+    /// Returns the logarithm of the base used for the decomposition of the input key
+    /// coefficients.
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Returns the fingerprint of the input key of the keyswitching key.
+    pub fn input_key_fingerprint(&self) -> KeyFingerprint {
+        self.input_key_fingerprint
+    }
+
+    /// Returns the fingerprint of the output key of the keyswitching key.
+    pub fn output_key_fingerprint(&self) -> KeyFingerprint {
+        self.output_key_fingerprint
+    }
+
+    /// Returns an iterator over the levels of the key, starting from the most significant one.
+    ///
+    /// Each level is a list of LWE ciphertexts encrypting, under the output key, the coefficients
+    /// of the input key multiplied by the recomposition factor of the level.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     CiphertextCount, DecompositionBaseLog, DecompositionLevelCount, LweDimension, LweSize,
+    /// };
+    /// use concrete_core::commons::crypto::lwe::LweLevelMajorKeyswitchKey;
+    /// let ksk = LweLevelMajorKeyswitchKey::allocate(
+    ///     0 as u8,
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(16),
+    ///     LweDimension(15),
+    ///     LweDimension(20),
+    /// );
+    /// for (level, list) in ksk.level_iter() {
+    ///     assert!(1 <= level.0 && level.0 <= 10);
+    ///     assert_eq!(list.lwe_size(), LweSize(21));
+    ///     assert_eq!(list.count(), CiphertextCount(15));
+    /// }
+    /// assert_eq!(ksk.level_iter().count(), 10);
+    /// ```
+    pub fn level_iter(
+        &self,
+    ) -> impl DoubleEndedIterator<
+        Item = (
+            DecompositionLevel,
+            LweList<&[<Self as AsRefTensor>::Element]>,
+        ),
+    >
+    where
+        Self: AsRefTensor,
+    {
+        ck_dim_div!(self.as_tensor().len() => self.lwe_size.0, self.decomp_level_count.0);
+        let chunks_size = self.as_tensor().len() / self.decomp_level_count.0;
+        let lwe_size = self.lwe_size;
+        self.as_tensor()
+            .subtensor_iter(chunks_size)
+            .enumerate()
+            .map(move |(index, sub)| {
+                (
+                    DecompositionLevel(index + 1),
+                    LweList::from_container(sub.into_container(), lwe_size),
+                )
+            })
+    }
+
+    /// Fills the current key with the ciphertexts of a keyswitching key stored in the standard,
+    /// coefficient-major, order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::commons::crypto::lwe::{LweKeyswitchKey, LweLevelMajorKeyswitchKey};
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::LweSecretKey;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    ///
+    /// let input_size = LweDimension(10);
+    /// let output_size = LweDimension(20);
+    /// let decomp_log_base = DecompositionBaseLog(3);
+    /// let decomp_level_count = DecompositionLevelCount(5);
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// let noise = LogStandardDev::from_log_standard_dev(-15.);
+    ///
+    /// let input_key = LweSecretKey::generate_binary(input_size, &mut secret_generator);
+    /// let output_key = LweSecretKey::generate_binary(output_size, &mut secret_generator);
+    ///
+    /// let mut ksk = LweKeyswitchKey::allocate(
+    ///     0 as u32,
+    ///     decomp_level_count,
+    ///     decomp_log_base,
+    ///     input_size,
+    ///     output_size,
+    /// );
+    /// ksk.fill_with_keyswitch_key(&input_key, &output_key, noise, &mut encryption_generator);
+    ///
+    /// let mut level_major_ksk = LweLevelMajorKeyswitchKey::allocate(
+    ///     0 as u32,
+    ///     decomp_level_count,
+    ///     decomp_log_base,
+    ///     input_size,
+    ///     output_size,
+    /// );
+    /// level_major_ksk.fill_with_standard_keyswitch_key(&ksk);
+    ///
+    /// let mut converted_ksk = LweKeyswitchKey::allocate(
+    ///     0 as u32,
+    ///     decomp_level_count,
+    ///     decomp_log_base,
+    ///     input_size,
+    ///     output_size,
+    /// );
+    /// converted_ksk.fill_with_level_major_keyswitch_key(&level_major_ksk);
+    /// assert_eq!(converted_ksk, ksk);
+    /// ```
+    pub fn fill_with_standard_keyswitch_key<InCont, Scalar>(
+        &mut self,
+        input: &LweKeyswitchKey<InCont>,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        LweKeyswitchKey<InCont>: AsRefTensor<Element = Scalar>,
+        Scalar: Copy,
+    {
+        ck_dim_eq!(self.as_tensor().len() => input.as_tensor().len());
+        ck_dim_eq!(self.lwe_size.0 => input.lwe_size().0);
+        ck_dim_eq!(self.decomp_level_count.0 => input.decomposition_levels_count().0);
+        let lwe_size = self.lwe_size.0;
+        let input_key_size = self.before_key_size().0;
+        for (input_index, block) in input.bit_decomp_iter().enumerate() {
+            for (level_index, ciphertext) in block.as_tensor().subtensor_iter(lwe_size).enumerate()
+            {
+                let start = (level_index * input_key_size + input_index) * lwe_size;
+                self.as_mut_tensor()
+                    .get_sub_mut(start..start + lwe_size)
+                    .fill_with_copy(&ciphertext);
+            }
+        }
+        self.decomp_base_log = input.decomposition_base_log();
+        self.input_key_fingerprint = input.input_key_fingerprint();
+        self.output_key_fingerprint = input.output_key_fingerprint();
+    }
+
+    /// Switches the key of a single LWE ciphertext.
+    ///
+    /// The output is the same as the one of [`LweKeyswitchKey::keyswitch_ciphertext`] with the
+    /// same key stored in the standard order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, LweSize,
+    /// };
+    /// use concrete_core::commons::crypto::encoding::*;
+    /// use concrete_core::commons::crypto::lwe::*;
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::LweSecretKey;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    ///
+    /// let input_size = LweDimension(1024);
+    /// let output_size = LweDimension(600);
+    /// let decomp_log_base = DecompositionBaseLog(3);
+    /// let decomp_level_count = DecompositionLevelCount(8);
+    /// let noise = LogStandardDev::from_log_standard_dev(-40.);
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// let input_key = LweSecretKey::generate_binary(input_size, &mut secret_generator);
+    /// let output_key = LweSecretKey::generate_binary(output_size, &mut secret_generator);
+    ///
+    /// let mut ksk = LweKeyswitchKey::allocate(
+    ///     0 as u64,
+    ///     decomp_level_count,
+    ///     decomp_log_base,
+    ///     input_size,
+    ///     output_size,
+    /// );
+    /// ksk.fill_with_keyswitch_key(&input_key, &output_key, noise, &mut encryption_generator);
+    /// let mut level_major_ksk = LweLevelMajorKeyswitchKey::allocate(
+    ///     0 as u64,
+    ///     decomp_level_count,
+    ///     decomp_log_base,
+    ///     input_size,
+    ///     output_size,
+    /// );
+    /// level_major_ksk.fill_with_standard_keyswitch_key(&ksk);
+    ///
+    /// let delta = 1_u64 << 60;
+    /// let plaintext = Plaintext(3 * delta);
+    /// let mut ciphertext = LweCiphertext::allocate(0 as u64, input_size.to_lwe_size());
+    /// let mut switched_ciphertext = LweCiphertext::allocate(0 as u64, output_size.to_lwe_size());
+    /// input_key.encrypt_lwe(
+    ///     &mut ciphertext,
+    ///     &plaintext,
+    ///     noise,
+    ///     &mut encryption_generator,
+    /// );
+    ///
+    /// level_major_ksk.keyswitch_ciphertext(&mut switched_ciphertext, &ciphertext);
+    ///
+    /// let mut decrypted = Plaintext(0 as u64);
+    /// output_key.decrypt_lwe(&mut decrypted, &switched_ciphertext);
+    /// assert_eq!(decrypted.0.wrapping_add(delta / 2) / delta, 3);
+    /// ```
+    pub fn keyswitch_ciphertext<InCont, OutCont, Scalar>(
+        &self,
+        after: &mut LweCiphertext<OutCont>,
+        before: &LweCiphertext<InCont>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        LweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        LweCiphertext<InCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        self.keyswitch_ciphertext_with(after, before, |decomposer, mask| {
+            decomposer.closest_representable(mask)
+        });
+    }
+
+    /// Keyswitches a single LWE ciphertext, rounding the mask elements before their decomposition
+    /// with the given rounding mode.
+    ///
+    /// A generator must be provided when the rounding mode is [`RoundingMode::Stochastic`].
+    pub fn keyswitch_ciphertext_with_rounding<InCont, OutCont, Scalar, Gen>(
+        &self,
+        after: &mut LweCiphertext<OutCont>,
+        before: &LweCiphertext<InCont>,
+        rounding: RoundingMode,
+        mut generator: Option<&mut RandomGenerator<Gen>>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        LweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        LweCiphertext<InCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+        Gen: ByteRandomGenerator,
+    {
+        self.keyswitch_ciphertext_with(after, before, |decomposer, mask| {
+            decomposer.closest_representable_with_rounding(mask, rounding, generator.as_deref_mut())
+        });
+    }
+
+    fn keyswitch_ciphertext_with<InCont, OutCont, Scalar, Round>(
+        &self,
+        after: &mut LweCiphertext<OutCont>,
+        before: &LweCiphertext<InCont>,
+        mut round: Round,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        LweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        LweCiphertext<InCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+        Round: FnMut(&SignedDecomposer<Scalar>, Scalar) -> Scalar,
+    {
+        ck_dim_eq!(self.before_key_size().0 => before.get_mask().mask_size().0);
+        ck_dim_eq!(self.after_key_size().0 => after.get_mask().mask_size().0);
+
+        // We reset the output
+        after.as_mut_tensor().fill_with(|| Scalar::ZERO);
+
+        // We copy the body
+        *after.get_mut_body() = LweBody(before.get_body().0);
+        // We instantiate a decomposer
+        let decomposer = SignedDecomposer::new(self.decomp_base_log, self.decomp_level_count);
+
+        // We round the whole mask before decomposing it level by level
+        let rounded = Tensor::from_container(
+            before
+                .get_mask()
+                .mask_element_iter()
+                .map(|mask| round(&decomposer, *mask))
+                .collect::<Vec<_>>(),
+        );
+        let mut decomposition = decomposer.decompose_tensor(&rounded);
+
+        // The decomposition yields the least significant level first
+        let mut levels = self.level_iter().rev();
+        while let Some(term) = decomposition.next_term() {
+            let (level, level_key_ciphers) = levels.next().unwrap();
+            debug_assert_eq!(level, term.level());
+            for (level_key_cipher, decomposed) in level_key_ciphers
+                .as_tensor()
+                .subtensor_iter(self.lwe_size.0)
+                .zip(term.as_tensor().iter())
+            {
+                after
+                    .as_mut_tensor()
+                    .update_with_wrapping_sub_element_mul(&level_key_cipher, *decomposed);
+            }
+        }
+        after.set_key_fingerprint(self.output_key_fingerprint);
+    }
+}
@@ -1,23 +1,63 @@
 use super::LweCiphertext;
 use crate::commons::crypto::encoding::{CleartextList, PlaintextList};
+use crate::commons::crypto::secret::KeyFingerprint;
 use crate::commons::math::tensor::{
-    ck_dim_div, tensor_traits, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+    ck_dim_div, ck_dim_eq, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, IntoTensor, Tensor,
 };
 use crate::commons::math::torus::UnsignedTorus;
-use crate::commons::utils::{zip, zip_args};
+use crate::commons::utils::{validate_container_length, zip, zip_args, ContainerValidationError};
 use concrete_commons::parameters::{CiphertextCount, CleartextCount, LweDimension, LweSize};
+#[cfg(feature = "__commons_parallel")]
+use rayon::{iter::IndexedParallelIterator, prelude::*};
 #[cfg(feature = "__commons_serialization")]
 use serde::{Deserialize, Serialize};
 
 /// A list of ciphertext encoded with the LWE scheme.
+///
+/// Like [`LweCiphertext`], the list carries the [`KeyFingerprint`] of the key its ciphertexts are
+/// encrypted under, which is reset by any mutable access to the list.
 #[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct LweList<Cont> {
     pub(crate) tensor: Tensor<Cont>,
     pub(crate) lwe_size: LweSize,
+    #[cfg_attr(feature = "__commons_serialization", serde(skip))]
+    pub(crate) key_fingerprint: KeyFingerprint,
 }
 
-tensor_traits!(LweList);
+impl<Element, Cont> AsRefTensor for LweList<Cont>
+where
+    Cont: AsRefSlice<Element = Element>,
+{
+    type Element = Element;
+    type Container = Cont;
+    fn as_tensor(&self) -> &Tensor<Self::Container> {
+        &self.tensor
+    }
+}
+
+impl<Element, Cont> AsMutTensor for LweList<Cont>
+where
+    Cont: AsMutSlice<Element = Element>,
+{
+    type Element = Element;
+    type Container = Cont;
+    fn as_mut_tensor(&mut self) -> &mut Tensor<<Self as AsMutTensor>::Container> {
+        self.key_fingerprint = KeyFingerprint::unknown();
+        &mut self.tensor
+    }
+}
+
+impl<Cont> IntoTensor for LweList<Cont>
+where
+    Cont: AsRefSlice,
+{
+    type Element = <Cont as AsRefSlice>::Element;
+    type Container = Cont;
+    fn into_tensor(self) -> Tensor<Self::Container> {
+        self.tensor
+    }
+}
 
 impl<Scalar> LweList<Vec<Scalar>>
 where
@@ -39,6 +79,7 @@ where
         LweList {
             tensor: Tensor::from_container(vec![value; lwe_size.0 * lwe_count.0]),
             lwe_size,
+            key_fingerprint: KeyFingerprint::unknown(),
         }
     }
 }
@@ -134,7 +175,21 @@ impl<Cont> LweList<Cont> {
     {
         ck_dim_div!(cont.as_slice().len() => lwe_size.0);
         let tensor = Tensor::from_container(cont);
-        LweList { tensor, lwe_size }
+        LweList {
+            tensor,
+            lwe_size,
+            key_fingerprint: KeyFingerprint::unknown(),
+        }
+    }
+
+    /// Returns the fingerprint of the key the ciphertexts of the list are encrypted under.
+    pub fn key_fingerprint(&self) -> KeyFingerprint {
+        self.key_fingerprint
+    }
+
+    /// Sets the fingerprint of the key the ciphertexts of the list are encrypted under.
+    pub fn set_key_fingerprint(&mut self, key_fingerprint: KeyFingerprint) {
+        self.key_fingerprint = key_fingerprint;
     }
 
     /// Returns the number of ciphertexts in the list.
@@ -174,6 +229,15 @@ impl<Cont> LweList<Cont> {
         self.lwe_size
     }
 
+    /// Checks that the length of the container is consistent with the size of the ciphertexts of
+    /// the list.
+    pub(crate) fn validate(&self) -> Result<(), ContainerValidationError>
+    where
+        Self: AsRefTensor,
+    {
+        validate_container_length(self.as_tensor().len(), &[self.lwe_size.0])
+    }
+
     /// Returns the number of masks of the ciphertexts in the list.
     ///
     /// # Example
@@ -218,9 +282,14 @@ impl<Cont> LweList<Cont> {
         Self: AsRefTensor,
     {
         ck_dim_div!(self.as_tensor().len() => self.lwe_size.0);
+        let key_fingerprint = self.key_fingerprint;
         self.as_tensor()
             .subtensor_iter(self.lwe_size.0)
-            .map(|sub| LweCiphertext::from_container(sub.into_container()))
+            .map(move |sub| {
+                let mut ciphertext = LweCiphertext::from_container(sub.into_container());
+                ciphertext.set_key_fingerprint(key_fingerprint);
+                ciphertext
+            })
     }
 
     /// Returns an iterator over ciphers mutably borrowed from the list.
@@ -255,6 +324,82 @@ impl<Cont> LweList<Cont> {
             .map(|sub| LweCiphertext::from_container(sub.into_container()))
     }
 
+    /// Returns a parallel iterator over ciphers borrowed from the list.
+    ///
+    /// # Note:
+    ///
+    /// This iterator is hidden behind the "parallel" feature gate.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::LweSize;
+    /// use concrete_core::commons::crypto::lwe::*;
+    /// use concrete_core::commons::crypto::*;
+    /// use rayon::iter::ParallelIterator;
+    /// let list = LweList::from_container(vec![0 as u8; 200], LweSize(10));
+    /// list.par_ciphertext_iter().for_each(|ciphertext| {
+    ///     assert_eq!(ciphertext.get_body(), &LweBody(0));
+    /// });
+    /// assert_eq!(list.par_ciphertext_iter().count(), 20);
+    /// ```
+    #[cfg(feature = "__commons_parallel")]
+    pub fn par_ciphertext_iter(
+        &self,
+    ) -> impl IndexedParallelIterator<Item = LweCiphertext<&[<Self as AsRefTensor>::Element]>>
+    where
+        Self: AsRefTensor,
+        <Self as AsRefTensor>::Element: Sync,
+    {
+        ck_dim_div!(self.as_tensor().len() => self.lwe_size.0);
+        let key_fingerprint = self.key_fingerprint;
+        self.as_tensor()
+            .par_subtensor_iter(self.lwe_size.0)
+            .map(move |sub| {
+                let mut ciphertext = LweCiphertext::from_container(sub.into_container());
+                ciphertext.set_key_fingerprint(key_fingerprint);
+                ciphertext
+            })
+    }
+
+    /// Returns a parallel iterator over ciphers mutably borrowed from the list.
+    ///
+    /// # Note:
+    ///
+    /// This iterator is hidden behind the "parallel" feature gate.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::LweSize;
+    /// use concrete_core::commons::crypto::lwe::*;
+    /// use concrete_core::commons::crypto::*;
+    /// use rayon::iter::ParallelIterator;
+    /// let mut list = LweList::from_container(vec![0 as u8; 200], LweSize(10));
+    /// list.par_ciphertext_iter_mut().for_each(|mut ciphertext| {
+    ///     let body = ciphertext.get_mut_body();
+    ///     *body = LweBody(2);
+    /// });
+    /// for ciphertext in list.ciphertext_iter() {
+    ///     let body = ciphertext.get_body();
+    ///     assert_eq!(body, &LweBody(2));
+    /// }
+    /// ```
+    #[cfg(feature = "__commons_parallel")]
+    pub fn par_ciphertext_iter_mut(
+        &mut self,
+    ) -> impl IndexedParallelIterator<Item = LweCiphertext<&mut [<Self as AsMutTensor>::Element]>>
+    where
+        Self: AsMutTensor,
+        <Self as AsMutTensor>::Element: Sync + Send,
+    {
+        ck_dim_div!(self.as_tensor().len() => self.lwe_size.0);
+        let lwe_size = self.lwe_size.0;
+        self.as_mut_tensor()
+            .par_subtensor_iter_mut(lwe_size)
+            .map(|sub| LweCiphertext::from_container(sub.into_container()))
+    }
+
     /// Returns an iterator over sub lists borrowed from the list.
     ///
     /// # Example
@@ -424,6 +569,113 @@ impl<Cont> LweList<Cont> {
         }
     }
 
+    /// Fills the current list with the ciphertexts of an input list, reordered by a permutation.
+    ///
+    /// The `i`-th ciphertext of the current list is a copy of the `permutation[i]`-th ciphertext
+    /// of the input list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::LweSize;
+    /// use concrete_core::commons::crypto::lwe::*;
+    /// use concrete_core::commons::crypto::*;
+    /// let input = LweList::from_container(vec![0u8, 1, 2, 3, 4, 5], LweSize(2));
+    /// let mut output = LweList::from_container(vec![0u8; 6], LweSize(2));
+    /// output.fill_with_permutation(&input, &[2, 0, 1]);
+    /// let bodies: Vec<_> = output.ciphertext_iter().map(|c| c.get_body().0).collect();
+    /// assert_eq!(bodies, vec![5, 1, 3]);
+    /// ```
+    pub fn fill_with_permutation<InputCont, Scalar>(
+        &mut self,
+        input_list: &LweList<InputCont>,
+        permutation: &[usize],
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        LweList<InputCont>: AsRefTensor<Element = Scalar>,
+        Scalar: Copy,
+    {
+        ck_dim_eq!(self.count().0 => input_list.count().0);
+        self.fill_with_gather(input_list, permutation);
+    }
+
+    /// Fills the current list with the ciphertexts of an input list at the given indices.
+    ///
+    /// The `i`-th ciphertext of the current list is a copy of the `indices[i]`-th ciphertext of
+    /// the input list. Runs of consecutive indices are copied with a single copy of the underlying
+    /// memory.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::LweSize;
+    /// use concrete_core::commons::crypto::lwe::*;
+    /// use concrete_core::commons::crypto::*;
+    /// let input = LweList::from_container(vec![0u8, 1, 2, 3, 4, 5, 6, 7], LweSize(2));
+    /// let mut output = LweList::from_container(vec![0u8; 6], LweSize(2));
+    /// output.fill_with_gather(&input, &[3, 1, 2]);
+    /// let bodies: Vec<_> = output.ciphertext_iter().map(|c| c.get_body().0).collect();
+    /// assert_eq!(bodies, vec![7, 3, 5]);
+    /// ```
+    pub fn fill_with_gather<InputCont, Scalar>(
+        &mut self,
+        input_list: &LweList<InputCont>,
+        indices: &[usize],
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        LweList<InputCont>: AsRefTensor<Element = Scalar>,
+        Scalar: Copy,
+    {
+        ck_dim_eq!(self.count().0 => indices.len());
+        ck_dim_eq!(self.lwe_size().0 => input_list.lwe_size().0);
+        let lwe_size = self.lwe_size.0;
+        let input = input_list.as_tensor().as_slice();
+        let output = self.as_mut_tensor().as_mut_slice();
+        for (position, start, length) in contiguous_runs(indices) {
+            output[position * lwe_size..(position + length) * lwe_size]
+                .copy_from_slice(&input[start * lwe_size..(start + length) * lwe_size]);
+        }
+    }
+
+    /// Copies the ciphertexts of an input list at the given indices of the current list.
+    ///
+    /// The `indices[i]`-th ciphertext of the current list is replaced by a copy of the `i`-th
+    /// ciphertext of the input list, the other ciphertexts of the current list being left
+    /// untouched. Runs of consecutive indices are copied with a single copy of the underlying
+    /// memory.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::LweSize;
+    /// use concrete_core::commons::crypto::lwe::*;
+    /// use concrete_core::commons::crypto::*;
+    /// let input = LweList::from_container(vec![1u8, 2, 3, 4], LweSize(2));
+    /// let mut output = LweList::from_container(vec![0u8; 8], LweSize(2));
+    /// output.fill_with_scatter(&input, &[3, 0]);
+    /// let bodies: Vec<_> = output.ciphertext_iter().map(|c| c.get_body().0).collect();
+    /// assert_eq!(bodies, vec![4, 0, 0, 2]);
+    /// ```
+    pub fn fill_with_scatter<InputCont, Scalar>(
+        &mut self,
+        input_list: &LweList<InputCont>,
+        indices: &[usize],
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        LweList<InputCont>: AsRefTensor<Element = Scalar>,
+        Scalar: Copy,
+    {
+        ck_dim_eq!(input_list.count().0 => indices.len());
+        ck_dim_eq!(self.lwe_size().0 => input_list.lwe_size().0);
+        let lwe_size = self.lwe_size.0;
+        let input = input_list.as_tensor().as_slice();
+        let output = self.as_mut_tensor().as_mut_slice();
+        for (position, start, length) in contiguous_runs(indices) {
+            output[start * lwe_size..(start + length) * lwe_size]
+                .copy_from_slice(&input[position * lwe_size..(position + length) * lwe_size]);
+        }
+    }
+
     pub fn fill_with_trivial_encryption<InputCont, Scalar>(
         &mut self,
         encoded: &PlaintextList<InputCont>,
@@ -441,3 +693,22 @@ impl<Cont> LweList<Cont> {
         }
     }
 }
+
+/// Splits a list of indices into runs of consecutive indices.
+///
+/// Returns an iterator over `(position, start, length)` triplets, meaning that
+/// `indices[position..position + length]` is equal to `start..start + length`.
+fn contiguous_runs(indices: &[usize]) -> impl Iterator<Item = (usize, usize, usize)> + '_ {
+    let mut position = 0;
+    std::iter::from_fn(move || {
+        let start = *indices.get(position)?;
+        let length = indices[position..]
+            .iter()
+            .enumerate()
+            .take_while(|(offset, &index)| index == start + offset)
+            .count();
+        let run = (position, start, length);
+        position += length;
+        Some(run)
+    })
+}
@@ -1,31 +1,44 @@
 //! LWE encryption scheme.
 mod ciphertext;
 mod keyswitch;
+mod lev;
+mod level_major_keyswitch;
 mod list;
+mod partial_keyswitch;
 mod seeded_ciphertext;
 mod seeded_keyswitch;
 mod seeded_list;
 
 pub use ciphertext::*;
 pub use keyswitch::*;
+pub use lev::*;
+pub use level_major_keyswitch::*;
 pub use list::*;
+pub use partial_keyswitch::*;
 pub use seeded_ciphertext::*;
 pub use seeded_keyswitch::*;
 pub use seeded_list::*;
 
 #[cfg(test)]
 mod test {
-    use crate::commons::crypto::lwe::{LweKeyswitchKey, LweSeededKeyswitchKey};
+    use crate::commons::crypto::encoding::PlaintextList;
+    use crate::commons::crypto::lwe::{
+        LweCiphertext, LweKeyswitchKey, LweLevelMajorKeyswitchKey, LweList,
+        LwePartialKeyswitchKey, LweSeededKeyswitchKey, LweSeededList,
+    };
     use crate::commons::crypto::secret::generators::{
         DeterministicSeeder, EncryptionRandomGenerator,
     };
     use crate::commons::crypto::secret::LweSecretKey;
+    use crate::commons::math::decomposition::SignedDecomposer;
+    use crate::commons::math::modular::{NonNativeModulus, SolinasPrime64};
     use crate::commons::math::random::CompressionSeed;
+    use crate::commons::math::tensor::{AsRefSlice, AsRefTensor};
     use crate::commons::math::torus::UnsignedTorus;
     use crate::commons::test_tools::new_secret_random_generator;
     use concrete_commons::dispersion::StandardDev;
     use concrete_commons::parameters::{
-        DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+        CiphertextCount, DecompositionBaseLog, DecompositionLevelCount, LweDimension,
     };
     use concrete_csprng::generators::SoftwareRandomGenerator;
     use concrete_csprng::seeders::Seed;
@@ -101,4 +114,238 @@ mod test {
     fn test_ksk_seeded_gen_equivalence_u64() {
         test_ksk_seeded_gen_equivalence::<u64>()
     }
+
+    fn test_seeded_list_split_equivalence<T: UnsignedTorus>() {
+        for _ in 0..10 {
+            let lwe_dim = LweDimension(crate::commons::test_tools::random_usize_between(5..10));
+            let count = CiphertextCount(crate::commons::test_tools::random_usize_between(2..10));
+            let mask_seed = Seed(crate::commons::test_tools::any_usize() as u128);
+            let compression_seed = CompressionSeed { seed: mask_seed };
+
+            let mut secret_generator = new_secret_random_generator();
+            let key = LweSecretKey::generate_binary(lwe_dim, &mut secret_generator);
+            let plaintexts =
+                PlaintextList::from_tensor(secret_generator.random_uniform_tensor::<T>(count.0));
+
+            let mut seeded_list = LweSeededList::allocate(lwe_dim, count, compression_seed);
+            key.encrypt_seeded_lwe_list::<_, _, _, _, _, SoftwareRandomGenerator>(
+                &mut seeded_list,
+                &plaintexts,
+                StandardDev::from_standard_dev(10.),
+                &mut DeterministicSeeder::<SoftwareRandomGenerator>::new(mask_seed),
+            );
+
+            // Each seeded ciphertext of the list can be expanded on its own.
+            let mut expanded_list = LweList::allocate(T::ZERO, lwe_dim.to_lwe_size(), count);
+            seeded_list
+                .clone()
+                .expand_into::<_, _, SoftwareRandomGenerator>(&mut expanded_list);
+            for (seeded_ciphertext, expected) in seeded_list
+                .seeded_ciphertext_iter()
+                .zip(expanded_list.ciphertext_iter())
+            {
+                let mut expanded = LweCiphertext::allocate(T::ZERO, lwe_dim.to_lwe_size());
+                seeded_ciphertext.expand_into::<_, SoftwareRandomGenerator>(&mut expanded);
+                assert!(expanded.as_tensor().iter().eq(expected.as_tensor().iter()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_seeded_list_split_equivalence_u16() {
+        test_seeded_list_split_equivalence::<u16>()
+    }
+
+    #[test]
+    fn test_seeded_list_split_equivalence_u32() {
+        test_seeded_list_split_equivalence::<u32>()
+    }
+
+    #[test]
+    fn test_seeded_list_split_equivalence_u64() {
+        test_seeded_list_split_equivalence::<u64>()
+    }
+
+    fn test_partial_keyswitch_full_equivalence<T: UnsignedTorus>() {
+        for _ in 0..10 {
+            let input_lwe_dim =
+                LweDimension(crate::commons::test_tools::random_usize_between(5..10));
+            let output_lwe_dim =
+                LweDimension(crate::commons::test_tools::random_usize_between(5..10));
+            let level =
+                DecompositionLevelCount(crate::commons::test_tools::random_usize_between(2..5));
+            let base_log =
+                DecompositionBaseLog(crate::commons::test_tools::random_usize_between(2..5));
+            let seed = Seed(crate::commons::test_tools::any_usize() as u128);
+
+            let mut secret_generator = new_secret_random_generator();
+            let input_key = LweSecretKey::generate_binary(input_lwe_dim, &mut secret_generator);
+            let output_key = LweSecretKey::generate_binary(output_lwe_dim, &mut secret_generator);
+
+            // Switching all the coefficients of the input key is a regular keyswitch.
+            let mut ksk =
+                LweKeyswitchKey::allocate(T::ZERO, level, base_log, input_lwe_dim, output_lwe_dim);
+            ksk.fill_with_keyswitch_key(
+                &input_key,
+                &output_key,
+                StandardDev::from_standard_dev(10.),
+                &mut EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(
+                    seed,
+                    &mut DeterministicSeeder::<SoftwareRandomGenerator>::new(seed),
+                ),
+            );
+            let mut partial_ksk = LwePartialKeyswitchKey::allocate(
+                T::ZERO,
+                level,
+                base_log,
+                input_lwe_dim,
+                input_lwe_dim,
+                output_lwe_dim,
+            );
+            partial_ksk.fill_with_partial_keyswitch_key(
+                &input_key,
+                &output_key,
+                StandardDev::from_standard_dev(10.),
+                &mut EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(
+                    seed,
+                    &mut DeterministicSeeder::<SoftwareRandomGenerator>::new(seed),
+                ),
+            );
+
+            let input = LweCiphertext::from_container(
+                secret_generator.random_uniform_tensor::<T>(input_lwe_dim.to_lwe_size().0),
+            );
+            let mut expected = LweCiphertext::allocate(T::ZERO, output_lwe_dim.to_lwe_size());
+            ksk.keyswitch_ciphertext(&mut expected, &input);
+            let mut output = LweCiphertext::allocate(T::ZERO, output_lwe_dim.to_lwe_size());
+            partial_ksk.partial_keyswitch_ciphertext(&mut output, &input);
+            assert_eq!(output, expected);
+        }
+    }
+
+    #[test]
+    fn test_partial_keyswitch_full_equivalence_u32() {
+        test_partial_keyswitch_full_equivalence::<u32>()
+    }
+
+    #[test]
+    fn test_partial_keyswitch_full_equivalence_u64() {
+        test_partial_keyswitch_full_equivalence::<u64>()
+    }
+
+    fn test_level_major_keyswitch_equivalence<T: UnsignedTorus>() {
+        for _ in 0..10 {
+            let input_lwe_dim =
+                LweDimension(crate::commons::test_tools::random_usize_between(5..10));
+            let output_lwe_dim =
+                LweDimension(crate::commons::test_tools::random_usize_between(5..10));
+            let level =
+                DecompositionLevelCount(crate::commons::test_tools::random_usize_between(2..5));
+            let base_log =
+                DecompositionBaseLog(crate::commons::test_tools::random_usize_between(2..5));
+            let seed = Seed(crate::commons::test_tools::any_usize() as u128);
+
+            let mut secret_generator = new_secret_random_generator();
+            let input_key = LweSecretKey::generate_binary(input_lwe_dim, &mut secret_generator);
+            let output_key = LweSecretKey::generate_binary(output_lwe_dim, &mut secret_generator);
+
+            let mut ksk =
+                LweKeyswitchKey::allocate(T::ZERO, level, base_log, input_lwe_dim, output_lwe_dim);
+            ksk.fill_with_keyswitch_key(
+                &input_key,
+                &output_key,
+                StandardDev::from_standard_dev(10.),
+                &mut EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(
+                    seed,
+                    &mut DeterministicSeeder::<SoftwareRandomGenerator>::new(seed),
+                ),
+            );
+            let mut level_major_ksk = LweLevelMajorKeyswitchKey::allocate(
+                T::ZERO,
+                level,
+                base_log,
+                input_lwe_dim,
+                output_lwe_dim,
+            );
+            level_major_ksk.fill_with_standard_keyswitch_key(&ksk);
+
+            // The conversion can be reverted.
+            let mut converted_ksk =
+                LweKeyswitchKey::allocate(T::ZERO, level, base_log, input_lwe_dim, output_lwe_dim);
+            converted_ksk.fill_with_level_major_keyswitch_key(&level_major_ksk);
+            assert_eq!(converted_ksk, ksk);
+
+            // Both layouts compute the same keyswitch.
+            let input = LweCiphertext::from_container(
+                secret_generator.random_uniform_tensor::<T>(input_lwe_dim.to_lwe_size().0),
+            );
+            let mut expected = LweCiphertext::allocate(T::ZERO, output_lwe_dim.to_lwe_size());
+            ksk.keyswitch_ciphertext(&mut expected, &input);
+            let mut output = LweCiphertext::allocate(T::ZERO, output_lwe_dim.to_lwe_size());
+            level_major_ksk.keyswitch_ciphertext(&mut output, &input);
+            assert_eq!(output, expected);
+        }
+    }
+
+    #[test]
+    fn test_level_major_keyswitch_equivalence_u32() {
+        test_level_major_keyswitch_equivalence::<u32>()
+    }
+
+    #[test]
+    fn test_level_major_keyswitch_equivalence_u64() {
+        test_level_major_keyswitch_equivalence::<u64>()
+    }
+
+    #[test]
+    fn test_modular_keyswitch_lazy_reduction() {
+        let modulus = SolinasPrime64;
+        // The large base logs force several reductions of the accumulators.
+        for (base_log, level) in [(4, 5), (31, 2), (60, 1), (63, 1)] {
+            let base_log = DecompositionBaseLog(base_log);
+            let level = DecompositionLevelCount(level);
+            let input_lwe_dim = LweDimension(40);
+            let output_lwe_dim = LweDimension(8);
+            let mut secret_generator = new_secret_random_generator();
+            let mut reduced_vec = |len| {
+                secret_generator
+                    .random_uniform_tensor::<u64>(len)
+                    .iter()
+                    .map(|a| modulus.reduce(*a as u128))
+                    .collect::<Vec<_>>()
+            };
+            let ksk = LweKeyswitchKey::from_container(
+                reduced_vec(input_lwe_dim.0 * level.0 * output_lwe_dim.to_lwe_size().0),
+                base_log,
+                level,
+                output_lwe_dim,
+            );
+            let input = LweCiphertext::from_container(reduced_vec(input_lwe_dim.to_lwe_size().0));
+            let mut output = LweCiphertext::allocate(0u64, output_lwe_dim.to_lwe_size());
+            ksk.keyswitch_ciphertext_modular(&mut output, &input, &modulus);
+
+            // We compute the keyswitch with a reduction after every product.
+            let mut expected = vec![0u64; output_lwe_dim.to_lwe_size().0];
+            *expected.last_mut().unwrap() = input.get_body().0;
+            let decomposer = SignedDecomposer::new(base_log, level);
+            let key_levels = ksk
+                .as_tensor()
+                .as_slice()
+                .chunks(output_lwe_dim.to_lwe_size().0)
+                .collect::<Vec<_>>();
+            for (block, mask) in key_levels
+                .chunks(level.0)
+                .zip(input.get_mask().mask_element_iter())
+            {
+                let rounded = decomposer.closest_representable(modulus.to_native(*mask));
+                for (key, term) in block.iter().rev().zip(decomposer.decompose(rounded)) {
+                    let value = modulus.reduce_signed(term.value() as i64);
+                    for (output, key) in expected.iter_mut().zip(key.iter()) {
+                        *output = modulus.sub(*output, modulus.mul(*key, value));
+                    }
+                }
+            }
+            assert_eq!(output.as_tensor().as_slice(), expected.as_slice());
+        }
+    }
 }
@@ -0,0 +1,270 @@
+#[cfg(feature = "__commons_serialization")]
+use serde::{Deserialize, Serialize};
+
+use concrete_commons::dispersion::DispersionParameter;
+use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+
+use crate::commons::crypto::secret::generators::EncryptionRandomGenerator;
+use crate::commons::crypto::secret::LweSecretKey;
+use crate::commons::math::decomposition::SignedDecomposer;
+use crate::commons::math::random::ByteRandomGenerator;
+use crate::commons::math::tensor::{ck_dim_eq, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor};
+use crate::commons::math::torus::UnsignedTorus;
+
+use super::{LweCiphertext, LweKeyswitchKey};
+
+/// A partial Lwe keyswitching key.
+///
+/// A partial keyswitching key only switches the first $d$ coefficients of the input key
+/// $s\_{in} = (s\_0, \cdots, s\_{n-1})$ to a replacement key $s\_{rep}$ of $m$ coefficients, and
+/// keeps the $n - d$ remaining coefficients as they are. The ciphertexts it outputs are hence
+/// encrypted under the key $(s\_{rep}, s\_d, \cdots, s\_{n-1})$ of $m + n - d$ coefficients.
+///
+/// It is made of a regular [`LweKeyswitchKey`] from $(s\_0, \cdots, s\_{d-1})$ to $s\_{rep}$,
+/// along with the input dimension $n$.
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LwePartialKeyswitchKey<Cont> {
+    key: LweKeyswitchKey<Cont>,
+    input_dimension: LweDimension,
+}
+
+impl<Scalar> LwePartialKeyswitchKey<Vec<Scalar>>
+where
+    Scalar: Copy,
+{
+    /// Allocates a partial keyswitching key whose masks and bodies are all `value`.
+    ///
+    /// # Note
+    ///
+    /// This function does *not* generate a partial keyswitch key, but merely allocates a container
+    /// of the right size. See [`LwePartialKeyswitchKey::fill_with_partial_keyswitch_key`] to fill
+    /// the container with a proper partial keyswitching key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::commons::crypto::lwe::LwePartialKeyswitchKey;
+    /// let ksk = LwePartialKeyswitchKey::allocate(
+    ///     0 as u8,
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(16),
+    ///     LweDimension(10),
+    ///     LweDimension(4),
+    ///     LweDimension(20),
+    /// );
+    /// assert_eq!(ksk.input_lwe_dimension(), LweDimension(10));
+    /// assert_eq!(ksk.switched_lwe_dimension(), LweDimension(4));
+    /// assert_eq!(ksk.replacement_lwe_dimension(), LweDimension(20));
+    /// assert_eq!(ksk.output_lwe_dimension(), LweDimension(26));
+    /// ```
+    pub fn allocate(
+        value: Scalar,
+        decomp_size: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+        input_dimension: LweDimension,
+        switched_dimension: LweDimension,
+        replacement_dimension: LweDimension,
+    ) -> Self {
+        debug_assert!(switched_dimension.0 <= input_dimension.0);
+        LwePartialKeyswitchKey {
+            key: LweKeyswitchKey::allocate(
+                value,
+                decomp_size,
+                decomp_base_log,
+                switched_dimension,
+                replacement_dimension,
+            ),
+            input_dimension,
+        }
+    }
+}
+
+impl<Cont> LwePartialKeyswitchKey<Cont>
+where
+    LweKeyswitchKey<Cont>: AsRefTensor,
+{
+    /// Returns the dimension of the input keys.
+    pub fn input_lwe_dimension(&self) -> LweDimension {
+        self.input_dimension
+    }
+
+    /// Returns the number of input key coefficients which are switched.
+    pub fn switched_lwe_dimension(&self) -> LweDimension {
+        self.key.before_key_size()
+    }
+
+    /// Returns the dimension of the key replacing the switched coefficients.
+    pub fn replacement_lwe_dimension(&self) -> LweDimension {
+        self.key.after_key_size()
+    }
+
+    /// Returns the dimension of the output keys.
+    pub fn output_lwe_dimension(&self) -> LweDimension {
+        LweDimension(
+            self.replacement_lwe_dimension().0 + self.input_dimension.0
+                - self.switched_lwe_dimension().0,
+        )
+    }
+
+    /// Returns the number of levels used for the decomposition of the input key bits.
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.key.decomposition_levels_count()
+    }
+
+    /// Returns the logarithm of the base used for the decomposition of the input key bits.
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.key.decomposition_base_log()
+    }
+
+    /// Fills the current partial keyswitch key container with an actual partial keyswitching key
+    /// constructed from an input and a replacement key.
+    ///
+    /// Only the first [`switched_lwe_dimension`](Self::switched_lwe_dimension) coefficients of
+    /// the input key are encrypted under the replacement key.
+    pub fn fill_with_partial_keyswitch_key<InKeyCont, RepKeyCont, Scalar, Gen>(
+        &mut self,
+        input_key: &LweSecretKey<BinaryKeyKind, InKeyCont>,
+        replacement_key: &LweSecretKey<BinaryKeyKind, RepKeyCont>,
+        noise_parameters: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator<Gen>,
+    ) where
+        LweKeyswitchKey<Cont>: AsMutTensor<Element = Scalar>,
+        LweSecretKey<BinaryKeyKind, InKeyCont>: AsRefTensor<Element = Scalar>,
+        LweSecretKey<BinaryKeyKind, RepKeyCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+        Gen: ByteRandomGenerator,
+    {
+        ck_dim_eq!(self.input_dimension.0 => input_key.key_size().0);
+        let switched_key = LweSecretKey::binary_from_container(
+            &input_key.as_tensor().as_slice()[..self.key.before_key_size().0],
+        );
+        self.key.fill_with_keyswitch_key(
+            &switched_key,
+            replacement_key,
+            noise_parameters,
+            generator,
+        );
+    }
+
+    /// Switches the key of the first coefficients of a single Lwe ciphertext, and copies the
+    /// remaining ones.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::commons::crypto::encoding::*;
+    /// use concrete_core::commons::crypto::lwe::*;
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::LweSecretKey;
+    /// use concrete_core::commons::math::tensor::AsRefTensor;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    ///
+    /// let input_size = LweDimension(600);
+    /// let switched_size = LweDimension(400);
+    /// let replacement_size = LweDimension(200);
+    /// let noise = LogStandardDev::from_log_standard_dev(-25.);
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// let input_key = LweSecretKey::generate_binary(input_size, &mut secret_generator);
+    /// let replacement_key = LweSecretKey::generate_binary(replacement_size, &mut secret_generator);
+    ///
+    /// let mut ksk = LwePartialKeyswitchKey::allocate(
+    ///     0 as u64,
+    ///     DecompositionLevelCount(5),
+    ///     DecompositionBaseLog(4),
+    ///     input_size,
+    ///     switched_size,
+    ///     replacement_size,
+    /// );
+    /// ksk.fill_with_partial_keyswitch_key(
+    ///     &input_key,
+    ///     &replacement_key,
+    ///     noise,
+    ///     &mut encryption_generator,
+    /// );
+    ///
+    /// let plaintext = Plaintext(3_u64 << 60);
+    /// let mut ciphertext = LweCiphertext::allocate(0 as u64, input_size.to_lwe_size());
+    /// input_key.encrypt_lwe(&mut ciphertext, &plaintext, noise, &mut encryption_generator);
+    /// let mut switched_ciphertext =
+    ///     LweCiphertext::allocate(0 as u64, ksk.output_lwe_dimension().to_lwe_size());
+    /// ksk.partial_keyswitch_ciphertext(&mut switched_ciphertext, &ciphertext);
+    ///
+    /// let output_key = LweSecretKey::binary_from_container(
+    ///     replacement_key
+    ///         .as_tensor()
+    ///         .iter()
+    ///         .chain(input_key.as_tensor().iter().skip(switched_size.0))
+    ///         .copied()
+    ///         .collect::<Vec<_>>(),
+    /// );
+    /// let mut decrypted = Plaintext(0_u64);
+    /// output_key.decrypt_lwe(&mut decrypted, &switched_ciphertext);
+    /// assert_eq!((decrypted.0.wrapping_add(1 << 59)) >> 60, 3);
+    /// ```
+    pub fn partial_keyswitch_ciphertext<InCont, OutCont, Scalar>(
+        &self,
+        after: &mut LweCiphertext<OutCont>,
+        before: &LweCiphertext<InCont>,
+    ) where
+        LweKeyswitchKey<Cont>: AsRefTensor<Element = Scalar>,
+        LweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        LweCiphertext<InCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.input_dimension.0 => before.lwe_size().to_lwe_dimension().0);
+        ck_dim_eq!(self.output_lwe_dimension().0 => after.lwe_size().to_lwe_dimension().0);
+        let switched_dimension = self.switched_lwe_dimension().0;
+        let replacement_dimension = self.replacement_lwe_dimension().0;
+        let input = before.as_tensor().as_slice();
+        let (output_mask, output_body) = after
+            .as_mut_tensor()
+            .as_mut_slice()
+            .split_at_mut(replacement_dimension + self.input_dimension.0 - switched_dimension);
+
+        // We copy the body and the coefficients which are not switched
+        output_mask[..replacement_dimension].fill(Scalar::ZERO);
+        output_mask[replacement_dimension..]
+            .copy_from_slice(&input[switched_dimension..self.input_dimension.0]);
+        output_body[0] = input[self.input_dimension.0];
+
+        // We keyswitch the first coefficients into the beginning of the output mask
+        let decomposer = SignedDecomposer::new(
+            self.key.decomposition_base_log(),
+            self.key.decomposition_levels_count(),
+        );
+        for (block, input_mask) in self.key.bit_decomp_iter().zip(&input[..switched_dimension]) {
+            let decomp = decomposer.decompose(decomposer.closest_representable(*input_mask));
+            for (level_key_cipher, decomposed) in block
+                .as_tensor()
+                .subtensor_iter(replacement_dimension + 1)
+                .rev()
+                .zip(decomp)
+            {
+                let level_key_cipher = level_key_cipher.as_slice();
+                let value = decomposed.value();
+                for (output, key) in output_mask[..replacement_dimension]
+                    .iter_mut()
+                    .zip(level_key_cipher)
+                {
+                    *output = output.wrapping_sub(key.wrapping_mul(value));
+                }
+                output_body[0] = output_body[0]
+                    .wrapping_sub(level_key_cipher[replacement_dimension].wrapping_mul(value));
+            }
+        }
+    }
+}
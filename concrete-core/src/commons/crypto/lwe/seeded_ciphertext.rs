@@ -18,6 +18,7 @@ pub struct LweSeededCiphertext<Scalar> {
     pub(crate) body: LweBody<Scalar>,
     pub(crate) lwe_dimension: LweDimension,
     pub(crate) compression_seed: CompressionSeed,
+    pub(crate) generator_byte_index: usize,
 }
 
 impl<Scalar: Numeric> LweSeededCiphertext<Scalar> {
@@ -58,10 +59,44 @@ impl<Scalar: Numeric> LweSeededCiphertext<Scalar> {
     /// assert_eq!(ciphertext.compression_seed(), compression_seed);
     /// ```
     pub fn from_scalar(value: Scalar, lwe_dimension: LweDimension, seed: CompressionSeed) -> Self {
+        Self::from_scalar_at_byte_index(value, lwe_dimension, seed, 0)
+    }
+
+    /// Allocates a new seeded ciphertext from elementary components, whose mask starts at the
+    /// byte of index `generator_byte_index` in the stream of the generator seeded with `seed`.
+    ///
+    /// This allows several seeded ciphertexts to share the same seed, by using disjoint parts of
+    /// the generator stream.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::commons::crypto::lwe::LweSeededCiphertext;
+    /// use concrete_core::commons::math::random::{CompressionSeed, Seed};
+    ///
+    /// let compression_seed = CompressionSeed { seed: Seed(42) };
+    ///
+    /// let ciphertext = LweSeededCiphertext::from_scalar_at_byte_index(
+    ///     0_u8,
+    ///     LweDimension(3),
+    ///     compression_seed,
+    ///     3,
+    /// );
+    /// assert_eq!(ciphertext.compression_seed(), compression_seed);
+    /// assert_eq!(ciphertext.generator_byte_index(), 3);
+    /// ```
+    pub fn from_scalar_at_byte_index(
+        value: Scalar,
+        lwe_dimension: LweDimension,
+        seed: CompressionSeed,
+        generator_byte_index: usize,
+    ) -> Self {
         Self {
             body: LweBody(value),
             lwe_dimension,
             compression_seed: seed,
+            generator_byte_index,
         }
     }
 
@@ -142,6 +177,25 @@ impl<Scalar: Numeric> LweSeededCiphertext<Scalar> {
         self.compression_seed
     }
 
+    /// Returns the index of the first byte of the mask, in the stream of the generator seeded with
+    /// the compression seed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::commons::crypto::lwe::LweSeededCiphertext;
+    /// use concrete_core::commons::math::random::{CompressionSeed, Seed};
+    ///
+    /// let compression_seed = CompressionSeed { seed: Seed(42) };
+    ///
+    /// let ciphertext = LweSeededCiphertext::from_scalar(0_u8, LweDimension(3), compression_seed);
+    /// assert_eq!(ciphertext.generator_byte_index(), 0);
+    /// ```
+    pub fn generator_byte_index(&self) -> usize {
+        self.generator_byte_index
+    }
+
     /// Returns the ciphertext as a fully fledged LweCiphertext
     ///
     /// # Example
@@ -162,13 +216,16 @@ impl<Scalar: Numeric> LweSeededCiphertext<Scalar> {
     /// assert_eq!(body, &mut LweBody(0));
     /// assert_eq!(mask.mask_size(), LweDimension(9));
     /// ```
-    pub fn expand_into<Cont, Gen>(self, output: &mut LweCiphertext<Cont>)
+    pub fn expand_into<Cont, Gen>(&self, output: &mut LweCiphertext<Cont>)
     where
         LweCiphertext<Cont>: AsMutTensor<Element = Scalar>,
         Scalar: Copy + RandomGenerable<Uniform> + Numeric,
         Gen: ByteRandomGenerator,
     {
         let mut generator = RandomGenerator::<Gen>::new(self.compression_seed.seed);
+        generator
+            .skip_bytes(self.generator_byte_index)
+            .expect("Failed to reach the generator byte index of the seeded ciphertext.");
         let (output_body, mut output_mask) = output.get_mut_body_and_mask();
 
         // generate a uniformly random mask
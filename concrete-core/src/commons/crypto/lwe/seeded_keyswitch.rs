@@ -469,7 +469,7 @@ impl<Cont> LweSeededKeyswitchKey<Cont> {
     ///
     /// ksk.expand_into::<_, _, SoftwareRandomGenerator>(&mut output_ksk);
     /// ```
-    pub fn expand_into<OutCont, Scalar, Gen>(self, output: &mut LweKeyswitchKey<OutCont>)
+    pub fn expand_into<OutCont, Scalar, Gen>(&self, output: &mut LweKeyswitchKey<OutCont>)
     where
         LweKeyswitchKey<OutCont>: AsMutTensor<Element = Scalar>,
         Self: AsRefTensor<Element = Scalar>,
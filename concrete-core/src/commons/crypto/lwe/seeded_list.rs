@@ -1,10 +1,11 @@
 #[cfg(feature = "__commons_serialization")]
 use serde::{Deserialize, Serialize};
 
-use concrete_commons::numeric::Numeric;
+use concrete_commons::numeric::{Numeric, UnsignedInteger};
 use concrete_commons::parameters::{CiphertextCount, LweDimension, LweSize};
 
-use crate::commons::crypto::lwe::LweList;
+use crate::commons::crypto::lwe::{LweList, LweSeededCiphertext};
+use crate::commons::crypto::secret::generators::mask_bytes_per_lwe;
 use crate::commons::math::random::{
     ByteRandomGenerator, CompressionSeed, RandomGenerable, RandomGenerator, Uniform,
 };
@@ -240,8 +241,52 @@ impl<Cont> LweSeededList<Cont> {
             .map(|scalar| unsafe { std::mem::transmute(scalar) })
     }
 
+    /// Returns an iterator over the ciphertexts of the list, as independent seeded ciphertexts
+    /// sharing the seed of the list.
+    ///
+    /// The mask of each ciphertext starts at the generator byte index following the mask of the
+    /// previous one, so that each ciphertext can be expanded on its own.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{CiphertextCount, LweDimension};
+    /// use concrete_core::commons::crypto::lwe::LweSeededList;
+    /// use concrete_core::commons::math::random::{CompressionSeed, Seed};
+    ///
+    /// let compression_seed = CompressionSeed { seed: Seed(42) };
+    ///
+    /// let list =
+    ///     LweSeededList::<Vec<u32>>::allocate(LweDimension(9), CiphertextCount(20), compression_seed);
+    ///
+    /// let ciphertexts = list.seeded_ciphertext_iter().collect::<Vec<_>>();
+    /// assert_eq!(ciphertexts.len(), 20);
+    /// assert_eq!(ciphertexts[2].compression_seed(), compression_seed);
+    /// assert_eq!(ciphertexts[2].generator_byte_index(), 2 * 9 * 4);
+    /// ```
+    pub fn seeded_ciphertext_iter<Scalar>(
+        &self,
+    ) -> impl Iterator<Item = LweSeededCiphertext<Scalar>> + '_
+    where
+        Self: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedInteger,
+    {
+        let mask_bytes = mask_bytes_per_lwe::<Scalar>(self.lwe_dimension);
+        self.as_tensor()
+            .iter()
+            .enumerate()
+            .map(move |(index, body)| {
+                LweSeededCiphertext::from_scalar_at_byte_index(
+                    *body,
+                    self.lwe_dimension,
+                    self.compression_seed,
+                    index * mask_bytes,
+                )
+            })
+    }
+
     pub fn expand_into_with_existing_generator<OutCont, Scalar, Gen>(
-        self,
+        &self,
         output: &mut LweList<OutCont>,
         generator: &mut RandomGenerator<Gen>,
     ) where
@@ -278,7 +323,7 @@ impl<Cont> LweSeededList<Cont> {
     /// seeded_list.expand_into::<_, _, SoftwareRandomGenerator>(&mut list);
     /// assert_eq!(list.mask_size(), LweDimension(9));
     /// ```
-    pub fn expand_into<OutCont, Scalar, Gen>(self, output: &mut LweList<OutCont>)
+    pub fn expand_into<OutCont, Scalar, Gen>(&self, output: &mut LweList<OutCont>)
     where
         LweList<OutCont>: AsMutTensor<Element = Scalar>,
         Self: AsRefTensor<Element = Scalar>,
@@ -0,0 +1,192 @@
+#[cfg(feature = "__commons_key_fingerprint")]
+use once_cell::sync::Lazy;
+#[cfg(feature = "__commons_key_fingerprint")]
+use sha2::{Digest, Sha256};
+#[cfg(feature = "__commons_key_fingerprint")]
+use std::hash::Hasher;
+
+use crate::commons::math::torus::UnsignedTorus;
+
+// The domain separation tags of the hashes computing the commitment and the fingerprint.
+#[cfg(feature = "__commons_key_fingerprint")]
+const COMMITMENT_DOMAIN: &[u8] = b"concrete-core key commitment";
+#[cfg(feature = "__commons_key_fingerprint")]
+const FINGERPRINT_DOMAIN: &[u8] = b"concrete-core key fingerprint";
+
+// The secret keying the commitments to the key coefficients. It is drawn from the randomness of the
+// operating system the first time a fingerprint is computed, and never leaves the process.
+#[cfg(feature = "__commons_key_fingerprint")]
+static COMMITMENT_KEY: Lazy<[u8; 32]> = Lazy::new(|| {
+    let mut key = [0u8; 32];
+    getrandom::getrandom(&mut key).expect("Failed to draw the key fingerprint commitment key.");
+    key
+});
+
+// Feeds the coefficients of a key to a SHA-256 hash. The integers are written in little endian, so
+// that the fingerprint of a key does not depend on the platform.
+#[cfg(feature = "__commons_key_fingerprint")]
+struct Sha256Hasher(Sha256);
+
+#[cfg(feature = "__commons_key_fingerprint")]
+impl Hasher for Sha256Hasher {
+    fn finish(&self) -> u64 {
+        unreachable!("the digest is retrieved by finalizing the inner hash")
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.update(bytes);
+    }
+
+    fn write_u8(&mut self, i: u8) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u128(&mut self, i: u128) {
+        self.write(&i.to_le_bytes());
+    }
+}
+
+/// A lightweight fingerprint of a secret key.
+///
+/// Ciphertexts and evaluation keys carry the fingerprints of the secret keys they are related to,
+/// so that an operation can detect that it was given a ciphertext encrypted under another key than
+/// the one its evaluation key expects (e.g. a wrong bootstrap key).
+///
+/// The fingerprint is a SHA-256 hash of the parameters of the secret key (the bit width and the
+/// number of its coefficients), and of a commitment to its coefficients. The commitment is keyed
+/// with a random secret drawn once per process, so that the fingerprint can not be used to test
+/// guesses of the key coefficients outside of the process. As a consequence, fingerprints can only
+/// be compared within the process which computed them. Since the coefficients of a GLWE secret key
+/// are the ones of the LWE secret key it can be turned into, both keys share the same fingerprint.
+///
+/// The fingerprints are a debugging aid, meant to catch mixed up keys, and are not designed to
+/// protect the keys against an adversary having access to the process.
+///
+/// # Note
+///
+/// The fingerprint is only computed when the `__commons_key_fingerprint` feature is enabled, and
+/// is otherwise always unknown. A fingerprint is unknown as well when the related key is not known
+/// (e.g. for trivial encryptions, or after a deserialization). An unknown fingerprint is
+/// compatible with any other fingerprint.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct KeyFingerprint {
+    #[cfg(feature = "__commons_key_fingerprint")]
+    hash: Option<[u8; 32]>,
+}
+
+impl KeyFingerprint {
+    /// Returns an unknown fingerprint.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::commons::crypto::secret::KeyFingerprint;
+    /// let fingerprint = KeyFingerprint::unknown();
+    /// assert!(!fingerprint.is_known());
+    /// ```
+    pub fn unknown() -> Self {
+        Self::default()
+    }
+
+    /// Computes the fingerprint of the secret key whose coefficients are given.
+    pub(crate) fn of_key_coefficients<Scalar>(coefficients: &[Scalar]) -> Self
+    where
+        Scalar: UnsignedTorus,
+    {
+        #[cfg(feature = "__commons_key_fingerprint")]
+        {
+            let mut commitment = Sha256Hasher(Sha256::new());
+            commitment.0.update(COMMITMENT_DOMAIN);
+            commitment.0.update(*COMMITMENT_KEY);
+            for coefficient in coefficients {
+                coefficient.hash(&mut commitment);
+            }
+            let mut fingerprint = Sha256::new();
+            fingerprint.update(FINGERPRINT_DOMAIN);
+            fingerprint.update((Scalar::BITS as u64).to_le_bytes());
+            fingerprint.update((coefficients.len() as u64).to_le_bytes());
+            fingerprint.update(commitment.0.finalize());
+            KeyFingerprint {
+                hash: Some(fingerprint.finalize().into()),
+            }
+        }
+        #[cfg(not(feature = "__commons_key_fingerprint"))]
+        {
+            let _ = coefficients;
+            KeyFingerprint {}
+        }
+    }
+
+    /// Returns whether the fingerprint is known.
+    pub fn is_known(&self) -> bool {
+        #[cfg(feature = "__commons_key_fingerprint")]
+        {
+            self.hash.is_some()
+        }
+        #[cfg(not(feature = "__commons_key_fingerprint"))]
+        {
+            false
+        }
+    }
+
+    /// Returns whether two fingerprints may belong to the same key.
+    ///
+    /// This is the case if the two fingerprints are equal, or if one of them is unknown.
+    pub fn is_compatible_with(&self, other: &KeyFingerprint) -> bool {
+        #[cfg(feature = "__commons_key_fingerprint")]
+        {
+            match (self.hash, other.hash) {
+                (Some(hash), Some(other_hash)) => hash == other_hash,
+                _ => true,
+            }
+        }
+        #[cfg(not(feature = "__commons_key_fingerprint"))]
+        {
+            let _ = other;
+            true
+        }
+    }
+
+    /// Returns the fingerprint of the result of an operation mixing two ciphertexts.
+    ///
+    /// The result is known only if the two fingerprints are known and equal, or if one is known
+    /// and the other one is unknown.
+    pub(crate) fn merge(&self, other: &KeyFingerprint) -> KeyFingerprint {
+        #[cfg(feature = "__commons_key_fingerprint")]
+        {
+            match (self.hash, other.hash) {
+                (Some(hash), Some(other_hash)) if hash != other_hash => KeyFingerprint::unknown(),
+                (hash, other_hash) => KeyFingerprint {
+                    hash: hash.or(other_hash),
+                },
+            }
+        }
+        #[cfg(not(feature = "__commons_key_fingerprint"))]
+        {
+            let _ = other;
+            *self
+        }
+    }
+}
+
+// The fingerprint is metadata which is not part of the value of the entities carrying it, and
+// must not prevent two otherwise equal entities from comparing equal.
+impl PartialEq for KeyFingerprint {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for KeyFingerprint {}
@@ -1,3 +1,4 @@
+use crate::commons::math::modular::NonNativeModulus;
 #[cfg(feature = "__commons_parallel")]
 use crate::commons::math::random::ParallelByteRandomGenerator;
 use crate::commons::math::random::{
@@ -7,6 +8,8 @@ use crate::commons::math::tensor::AsMutTensor;
 
 use concrete_commons::dispersion::DispersionParameter;
 use concrete_commons::numeric::UnsignedInteger;
+#[cfg(feature = "__commons_parallel")]
+use concrete_commons::parameters::CiphertextCount;
 use concrete_commons::parameters::{
     DecompositionLevelCount, GlweDimension, GlweSize, LweDimension, LweSize, PolynomialSize,
 };
@@ -44,6 +47,12 @@ impl<G: ByteRandomGenerator> EncryptionRandomGenerator<G> {
         self.mask.remaining_bytes()
     }
 
+    // Skips the next bytes of the mask generator, when the mask of a seeded ciphertext does not
+    // start at the beginning of the stream.
+    pub(crate) fn skip_mask_bytes(&mut self, n_bytes: usize) -> Result<(), ForkError> {
+        self.mask.skip_bytes(n_bytes)
+    }
+
     // Forks the generator, when splitting a bootstrap key into ggsw ct.
     #[allow(dead_code)]
     pub(crate) fn fork_bsk_to_ggsw<T: UnsignedInteger>(
@@ -130,6 +139,21 @@ impl<G: ByteRandomGenerator> EncryptionRandomGenerator<G> {
         self.mask.fill_tensor_with_random_uniform(output)
     }
 
+    // Fills the tensor with random uniform values modulo a non-native modulus, using the mask
+    // generator.
+    pub(crate) fn fill_tensor_with_random_mask_modular<Modulus, Tensorable>(
+        &mut self,
+        output: &mut Tensorable,
+        modulus: &Modulus,
+    ) where
+        Modulus: NonNativeModulus,
+        Tensorable: AsMutTensor<Element = u64>,
+    {
+        for element in output.as_mut_tensor().iter_mut() {
+            *element = modulus.random_uniform(&mut self.mask);
+        }
+    }
+
     // Sample a noise value, using the noise generator.
     pub(crate) fn random_noise<Scalar>(&mut self, std: impl DispersionParameter) -> Scalar
     where
@@ -218,6 +242,17 @@ impl<G: ParallelByteRandomGenerator> EncryptionRandomGenerator<G> {
         self.par_try_fork(lwe_size.0, mask_bytes, noise_bytes)
     }
 
+    // Forks the generator into a parallel iterator, when splitting a lwe list into ciphertexts.
+    pub(crate) fn par_fork_lwe_list_to_lwe<T: UnsignedInteger>(
+        &mut self,
+        lwe_count: CiphertextCount,
+        lwe_size: LweSize,
+    ) -> Result<impl IndexedParallelIterator<Item = EncryptionRandomGenerator<G>>, ForkError> {
+        let mask_bytes = mask_bytes_per_lwe::<T>(lwe_size.to_lwe_dimension());
+        let noise_bytes = noise_bytes_per_lwe();
+        self.par_try_fork(lwe_count.0, mask_bytes, noise_bytes)
+    }
+
     // Forks both generators into a parallel iterator.
     fn par_try_fork(
         &mut self,
@@ -258,7 +293,7 @@ fn mask_bytes_per_ggsw_level<T: UnsignedInteger>(
     glwe_size.0 * mask_bytes_per_glwe::<T>(glwe_size.to_glwe_dimension(), poly_size)
 }
 
-fn mask_bytes_per_lwe<T: UnsignedInteger>(lwe_dimension: LweDimension) -> usize {
+pub(crate) fn mask_bytes_per_lwe<T: UnsignedInteger>(lwe_dimension: LweDimension) -> usize {
     lwe_dimension.0 * mask_bytes_per_coef::<T>()
 }
 
@@ -1,4 +1,5 @@
 mod encryption;
+pub(crate) use encryption::mask_bytes_per_lwe;
 pub use encryption::EncryptionRandomGenerator;
 
 mod secret;
@@ -1,12 +1,13 @@
 use crate::commons::crypto::encoding::{Plaintext, PlaintextList};
 use crate::commons::crypto::ggsw::{StandardGgswCiphertext, StandardGgswSeededCiphertext};
 use crate::commons::crypto::glwe::{
-    GlweBody, GlweCiphertext, GlweList, GlweMask, GlweSeededCiphertext, GlweSeededList,
+    GlevCiphertext, GlweBody, GlweCiphertext, GlweList, GlweMask, GlweSeededCiphertext,
+    GlweSeededList,
 };
 use crate::commons::crypto::secret::generators::{
     EncryptionRandomGenerator, SecretRandomGenerator,
 };
-use crate::commons::crypto::secret::LweSecretKey;
+use crate::commons::crypto::secret::{KeyFingerprint, LweSecretKey};
 use crate::commons::math::polynomial::PolynomialList;
 #[cfg(feature = "__commons_parallel")]
 use crate::commons::math::random::ParallelByteRandomGenerator;
@@ -382,6 +383,17 @@ where
         GlweDimension(self.as_tensor().len() / self.poly_size.0)
     }
 
+    /// Returns the fingerprint of the secret key.
+    ///
+    /// This is also the fingerprint of the LWE secret key the GLWE secret key can be turned into.
+    pub fn key_fingerprint<Scalar>(&self) -> KeyFingerprint
+    where
+        Self: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        KeyFingerprint::of_key_coefficients(self.as_tensor().as_slice())
+    }
+
     /// Returns the size of the secret key polynomials.
     ///
     /// # Example
@@ -465,6 +477,55 @@ where
         PolynomialList::from_container(self.as_mut_tensor().as_mut_slice(), poly_size)
     }
 
+    /// Returns the key of the ciphertexts obtained by splitting ciphertexts encrypted under the
+    /// current key, with [`GlweCiphertext::fill_glwe_list_with_ring_split`].
+    ///
+    /// Every polynomial $S(X)$ of the current key is split into two polynomials of half the size,
+    /// $S\_e(X)$ and $S\_o(X)$, such that $S(X) = S\_e(X^2) + X \cdot S\_o(X^2)$. The returned key
+    /// is made of the polynomials $S\_{0,e}, S\_{0,o}, S\_{1,e}, S\_{1,o}, \ldots$ in this order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::commons::crypto::secret::generators::SecretRandomGenerator;
+    /// use concrete_core::commons::crypto::secret::*;
+    /// use concrete_core::commons::math::tensor::AsRefTensor;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let secret_key: GlweSecretKey<_, Vec<u32>> =
+    ///     GlweSecretKey::generate_binary(GlweDimension(2), PolynomialSize(10), &mut secret_generator);
+    /// let split_key = secret_key.to_ring_split_key();
+    /// assert_eq!(split_key.key_size(), GlweDimension(4));
+    /// assert_eq!(split_key.polynomial_size(), PolynomialSize(5));
+    /// assert_eq!(
+    ///     split_key.as_tensor().get_element(1),
+    ///     secret_key.as_tensor().get_element(2)
+    /// );
+    /// assert_eq!(
+    ///     split_key.as_tensor().get_element(5),
+    ///     secret_key.as_tensor().get_element(1)
+    /// );
+    /// ```
+    pub fn to_ring_split_key<Scalar>(&self) -> GlweSecretKey<Kind, Vec<Scalar>>
+    where
+        Self: AsRefTensor<Element = Scalar>,
+        Scalar: Copy,
+    {
+        ck_dim_div!(self.poly_size.0 => 2);
+        let mut tensor = Vec::with_capacity(self.as_tensor().len());
+        for polynomial in self.as_tensor().subtensor_iter(self.poly_size.0) {
+            tensor.extend(polynomial.iter().step_by(2));
+            tensor.extend(polynomial.iter().skip(1).step_by(2));
+        }
+        GlweSecretKey {
+            tensor: Tensor::from_container(tensor),
+            poly_size: PolynomialSize(self.poly_size.0 / 2),
+            kind: PhantomData,
+        }
+    }
+
     fn fill_glwe_mask_and_body_for_encryption<InputCont, BodyCont, MaskCont, Scalar, Gen>(
         &self,
         mut output_body: GlweBody<BodyCont>,
@@ -810,6 +871,172 @@ where
         }
     }
 
+    /// Encrypts a list of GLWE ciphertexts, with a different noise for each ciphertext.
+    ///
+    /// The i-th ciphertext of the list is encrypted with the i-th noise of `noise_parameters`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{CiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::commons::crypto::encoding::PlaintextList;
+    /// use concrete_core::commons::crypto::glwe::GlweList;
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::*;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let secret_key = GlweSecretKey::generate_binary(
+    ///     GlweDimension(256),
+    ///     PolynomialSize(2),
+    ///     &mut secret_generator,
+    /// );
+    /// let noises = [
+    ///     LogStandardDev::from_log_standard_dev(-25.),
+    ///     LogStandardDev::from_log_standard_dev(-20.),
+    /// ];
+    /// let plaintexts = PlaintextList::from_container(vec![1000 as u32, 2000, 3000, 4000]);
+    /// let mut ciphertexts = GlweList::allocate(
+    ///     0 as u32,
+    ///     PolynomialSize(2),
+    ///     GlweDimension(256),
+    ///     CiphertextCount(2),
+    /// );
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// secret_key.encrypt_glwe_list_with_variable_noise(
+    ///     &mut ciphertexts,
+    ///     &plaintexts,
+    ///     &noises,
+    ///     &mut encryption_generator,
+    /// );
+    /// let mut decrypted = PlaintextList::from_container(vec![0 as u32, 0, 0, 0]);
+    /// secret_key.decrypt_glwe_list(&mut decrypted, &ciphertexts);
+    /// for (dec, plain) in decrypted.plaintext_iter().zip(plaintexts.plaintext_iter()) {
+    ///     let d0 = dec.0.wrapping_sub(plain.0);
+    ///     let d1 = plain.0.wrapping_sub(dec.0);
+    ///     let dist = std::cmp::min(d0, d1);
+    ///     assert!(dist < 10_000, "dist: {:?}", dist);
+    /// }
+    /// ```
+    pub fn encrypt_glwe_list_with_variable_noise<CiphCont, EncCont, Scalar, Gen, Noise>(
+        &self,
+        encrypt: &mut GlweList<CiphCont>,
+        encoded: &PlaintextList<EncCont>,
+        noise_parameters: &[Noise],
+        generator: &mut EncryptionRandomGenerator<Gen>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        GlweList<CiphCont>: AsMutTensor<Element = Scalar>,
+        PlaintextList<EncCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+        for<'a> PlaintextList<&'a [Scalar]>: AsRefTensor<Element = Scalar>,
+        Gen: ByteRandomGenerator,
+        Noise: DispersionParameter,
+    {
+        ck_dim_eq!(encrypt.ciphertext_count().0 * encrypt.polynomial_size().0 => encoded.count().0);
+        ck_dim_eq!(encrypt.glwe_dimension().0 => self.key_size().0);
+        ck_dim_eq!(encrypt.ciphertext_count().0 => noise_parameters.len());
+
+        let count = PlaintextCount(encrypt.polynomial_size().0);
+        for ((mut ciphertext, encoded), noise) in encrypt
+            .ciphertext_iter_mut()
+            .zip(encoded.sublist_iter(count))
+            .zip(noise_parameters.iter())
+        {
+            self.encrypt_glwe(&mut ciphertext, &encoded, *noise, generator);
+        }
+    }
+
+    /// Encrypts a GLev ciphertext.
+    ///
+    /// The GLWE ciphertext of each level $j$ of `encrypted` is filled with an encryption of the
+    /// `encoded` polynomial multiplied by $q / \beta^j$, where $\beta$ is the decomposition base
+    /// of `encrypted`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::encoding::PlaintextList;
+    /// use concrete_core::commons::crypto::glwe::GlevCiphertext;
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::*;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let secret_key = GlweSecretKey::generate_binary(
+    ///     GlweDimension(2),
+    ///     PolynomialSize(4),
+    ///     &mut secret_generator,
+    /// );
+    /// let noise = LogStandardDev::from_log_standard_dev(-25.);
+    /// let plaintexts = PlaintextList::from_container(vec![1 as u32, 7, 12, 3]);
+    /// let mut ciphertext = GlevCiphertext::allocate(
+    ///     0 as u32,
+    ///     PolynomialSize(4),
+    ///     GlweDimension(2),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(4),
+    /// );
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// secret_key.encrypt_glev(
+    ///     &mut ciphertext,
+    ///     &plaintexts,
+    ///     noise,
+    ///     &mut encryption_generator,
+    /// );
+    /// let mut decrypted = PlaintextList::from_container(vec![0 as u32; 4]);
+    /// secret_key.decrypt_glev(&mut decrypted, &ciphertext);
+    /// assert_eq!(decrypted, plaintexts);
+    /// ```
+    pub fn encrypt_glev<CiphCont, EncCont, Scalar, Gen>(
+        &self,
+        encrypted: &mut GlevCiphertext<CiphCont>,
+        encoded: &PlaintextList<EncCont>,
+        noise_parameters: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator<Gen>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        GlevCiphertext<CiphCont>: AsMutTensor<Element = Scalar>,
+        PlaintextList<EncCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+        for<'a> PlaintextList<&'a [Scalar]>: AsRefTensor<Element = Scalar>,
+        Gen: ByteRandomGenerator,
+    {
+        ck_dim_eq!(encoded.count().0 => encrypted.polynomial_size().0);
+        ck_dim_eq!(encrypted.glwe_size().to_glwe_dimension().0 => self.key_size().0);
+        debug_assert!(
+            encrypted.decomposition_base_log().0 * encrypted.decomposition_level_count().0
+                <= Scalar::BITS,
+            "The decomposition precision must not exceed the precision of the ciphertext."
+        );
+
+        let base_log = encrypted.decomposition_base_log();
+        let mut scaled = PlaintextList::allocate(Scalar::ZERO, encoded.count());
+        for (level, mut glwe) in encrypted.level_iter_mut() {
+            let factor = Scalar::ONE << (Scalar::BITS - (base_log.0 * level.0));
+            scaled
+                .as_mut_tensor()
+                .fill_with_one(encoded.as_tensor(), |a| a.wrapping_mul(factor));
+            self.encrypt_glwe(
+                &mut glwe,
+                &PlaintextList::from_container(scaled.as_tensor().as_slice()),
+                noise_parameters,
+                generator,
+            );
+        }
+    }
+
     /// Encrypts a list of seeded GLWE ciphertexts.
     ///
     /// # Example
@@ -1019,6 +1246,42 @@ where
         }
     }
 
+    /// Decrypts a GLev ciphertext.
+    ///
+    /// The GLWE ciphertext of the first level is decrypted, and its result is rounded to the
+    /// closest multiple of $q / \beta$, so that `encoded` is filled with the encrypted plaintext
+    /// modulo $\beta$.
+    ///
+    /// See [`GlweSecretKey::encrypt_glev`] for an example.
+    pub fn decrypt_glev<CiphCont, EncCont, Scalar>(
+        &self,
+        encoded: &mut PlaintextList<EncCont>,
+        encrypted: &GlevCiphertext<CiphCont>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        PlaintextList<EncCont>: AsMutTensor<Element = Scalar>,
+        GlevCiphertext<CiphCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus + Add,
+    {
+        ck_dim_eq!(encoded.count().0 => encrypted.polynomial_size().0);
+        ck_dim_eq!(encrypted.glwe_size().to_glwe_dimension().0 => self.key_size().0);
+
+        let (_, first_level) = encrypted
+            .level_iter()
+            .next()
+            .expect("A GLev ciphertext contains at least one level.");
+        self.decrypt_glwe(encoded, &first_level);
+
+        let shift = Scalar::BITS - encrypted.decomposition_base_log().0;
+        if shift == 0 {
+            return;
+        }
+        let rounding = Scalar::ONE << (shift - 1);
+        encoded
+            .as_mut_tensor()
+            .update_with(|a| *a = a.wrapping_add(rounding) >> shift);
+    }
+
     fn encrypt_constant_ggsw_row<Scalar, InputCont, OutputCont, Gen>(
         &self,
         (row_index, last_row_index): (usize, usize),
@@ -1,11 +1,13 @@
 use crate::commons::crypto::encoding::{Plaintext, PlaintextList};
 use crate::commons::crypto::gsw::GswCiphertext;
 use crate::commons::crypto::lwe::{
-    LweBody, LweCiphertext, LweList, LweMask, LweSeededCiphertext, LweSeededList,
+    LevCiphertext, LweBody, LweCiphertext, LweList, LweMask, LweSeededCiphertext, LweSeededList,
 };
 use crate::commons::crypto::secret::generators::{
     EncryptionRandomGenerator, SecretRandomGenerator,
 };
+use crate::commons::crypto::secret::KeyFingerprint;
+use crate::commons::math::modular::NonNativeModulus;
 #[cfg(feature = "__commons_parallel")]
 use crate::commons::math::random::ParallelByteRandomGenerator;
 use crate::commons::math::random::{ByteRandomGenerator, Gaussian, RandomGenerable, Seeder};
@@ -303,6 +305,27 @@ where
         LweDimension(self.as_tensor().len())
     }
 
+    /// Returns the fingerprint of the secret key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::commons::crypto::secret::*;
+    /// let secret_key = LweSecretKey::binary_from_container(vec![1u32; 256]);
+    /// let other_key = LweSecretKey::binary_from_container(vec![0u32; 256]);
+    /// let fingerprint = secret_key.key_fingerprint();
+    /// assert!(fingerprint.is_compatible_with(&secret_key.key_fingerprint()));
+    /// # #[cfg(feature = "__commons_key_fingerprint")]
+    /// assert!(!fingerprint.is_compatible_with(&other_key.key_fingerprint()));
+    /// ```
+    pub fn key_fingerprint<Scalar>(&self) -> KeyFingerprint
+    where
+        Self: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        KeyFingerprint::of_key_coefficients(self.as_tensor().as_slice())
+    }
+
     fn fill_lwe_mask_and_body_for_encryption<OutputCont, Scalar, Gen>(
         &self,
         output_body: &mut LweBody<Scalar>,
@@ -390,6 +413,7 @@ where
             noise_parameters,
             generator,
         );
+        output.set_key_fingerprint(self.key_fingerprint());
     }
 
     /// Encrypts a single seeded ciphertext.
@@ -465,6 +489,9 @@ where
         // that the noise generator is seeded with a private seed
         let mut generator =
             EncryptionRandomGenerator::<Gen>::new(output.compression_seed().seed, seeder);
+        generator
+            .skip_mask_bytes(output.generator_byte_index())
+            .expect("Failed to reach the generator byte index of the seeded ciphertext.");
 
         let mut output_mask = LweMask::from_container(vec![Scalar::ZERO; self.key_size().0]);
         let output_body = output.get_mut_body();
@@ -547,8 +574,112 @@ where
             "Lwe cipher list size and encoded list size are not compatible"
         );
         for (mut cipher, message) in output.ciphertext_iter_mut().zip(encoded.plaintext_iter()) {
-            self.encrypt_lwe(&mut cipher, message, noise_parameters, generator);
+            let (output_body, mut output_mask) = cipher.get_mut_body_and_mask();
+            self.fill_lwe_mask_and_body_for_encryption(
+                output_body,
+                &mut output_mask,
+                message,
+                noise_parameters,
+                generator,
+            );
         }
+        output.set_key_fingerprint(self.key_fingerprint());
+    }
+
+    /// Encrypts a list of ciphertexts, using as many threads as possible.
+    ///
+    /// # Notes
+    /// This method is hidden behind the "parallel" feature gate.
+    ///
+    /// The generator is forked once per ciphertext, so that the ciphertexts can be encrypted
+    /// independently, and in any order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     CiphertextCount, CleartextCount, LweDimension, LweSize, PlaintextCount,
+    /// };
+    /// use concrete_core::commons::crypto::encoding::*;
+    /// use concrete_core::commons::crypto::lwe::*;
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::*;
+    /// use concrete_core::commons::crypto::*;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let secret_key = LweSecretKey::generate_binary(LweDimension(256), &mut secret_generator);
+    /// let encoder = RealEncoder {
+    ///     offset: 0. as f32,
+    ///     delta: 10.,
+    /// };
+    /// let noise = LogStandardDev::from_log_standard_dev(-15.);
+    ///
+    /// let clear_values = CleartextList::allocate(2. as f32, CleartextCount(100));
+    /// let mut plain_values = PlaintextList::allocate(0u32, PlaintextCount(100));
+    /// encoder.encode_list(&mut plain_values, &clear_values);
+    /// let mut encrypted_values = LweList::allocate(0u32, LweSize(257), CiphertextCount(100));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// secret_key.par_encrypt_lwe_list(
+    ///     &mut encrypted_values,
+    ///     &plain_values,
+    ///     noise,
+    ///     &mut encryption_generator,
+    /// );
+    ///
+    /// let mut decrypted_values = PlaintextList::allocate(0u32, PlaintextCount(100));
+    /// secret_key.decrypt_lwe_list(&mut decrypted_values, &encrypted_values);
+    /// let mut decoded_values = CleartextList::allocate(0. as f32, CleartextCount(100));
+    /// encoder.decode_list(&mut decoded_values, &decrypted_values);
+    /// for (clear, decoded) in clear_values
+    ///     .cleartext_iter()
+    ///     .zip(decoded_values.cleartext_iter())
+    /// {
+    ///     assert!((clear.0 - decoded.0).abs() < 0.1);
+    /// }
+    /// ```
+    #[cfg(feature = "__commons_parallel")]
+    pub fn par_encrypt_lwe_list<OutputCont, InputCont, Scalar, Gen>(
+        &self,
+        output: &mut LweList<OutputCont>,
+        encoded: &PlaintextList<InputCont>,
+        noise_parameters: impl DispersionParameter + Send + Sync,
+        generator: &mut EncryptionRandomGenerator<Gen>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        LweList<OutputCont>: AsMutTensor<Element = Scalar>,
+        PlaintextList<InputCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus + Send + Sync,
+        Cont: Sync,
+        Gen: ParallelByteRandomGenerator,
+    {
+        debug_assert!(
+            output.count().0 == encoded.count().0,
+            "Lwe cipher list size and encoded list size are not compatible"
+        );
+        let generators = generator
+            .par_fork_lwe_list_to_lwe::<Scalar>(output.count(), output.lwe_size())
+            .expect("Failed to split generator into lwe");
+        output
+            .par_ciphertext_iter_mut()
+            .zip(encoded.as_tensor().par_iter())
+            .zip(generators)
+            .for_each(|((mut cipher, message), mut generator)| {
+                let (output_body, mut output_mask) = cipher.get_mut_body_and_mask();
+                self.fill_lwe_mask_and_body_for_encryption(
+                    output_body,
+                    &mut output_mask,
+                    &Plaintext(*message),
+                    noise_parameters,
+                    &mut generator,
+                );
+            });
+        output.set_key_fingerprint(self.key_fingerprint());
     }
 
     pub fn encrypt_seeded_lwe_list_with_existing_generator<
@@ -683,6 +814,116 @@ where
         output.0 = output.0.wrapping_sub(masks.compute_multisum(self));
     }
 
+    /// Encrypts a single ciphertext modulo a non-native modulus.
+    ///
+    /// The encoded message is expected to be reduced modulo $q$, and the noise parameters are
+    /// given relatively to $q$, as for the native modulus.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{LweDimension, LweSize};
+    /// use concrete_core::commons::crypto::encoding::*;
+    /// use concrete_core::commons::crypto::lwe::*;
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::*;
+    /// use concrete_core::commons::math::modular::{NonNativeModulus, SolinasPrime64};
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let secret_key = LweSecretKey::generate_binary(LweDimension(256), &mut secret_generator);
+    /// let noise = LogStandardDev::from_log_standard_dev(-25.);
+    /// let modulus = SolinasPrime64;
+    /// let delta = modulus.value() / 16;
+    ///
+    /// let plain = Plaintext(3 * delta);
+    /// let mut encrypted = LweCiphertext::allocate(0u64, LweSize(257));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// secret_key.encrypt_lwe_modular(
+    ///     &mut encrypted,
+    ///     &plain,
+    ///     noise,
+    ///     &mut encryption_generator,
+    ///     &modulus,
+    /// );
+    ///
+    /// let mut decrypted = Plaintext(0u64);
+    /// secret_key.decrypt_lwe_modular(&mut decrypted, &encrypted, &modulus);
+    /// assert_eq!((decrypted.0 + delta / 2) / delta, 3);
+    /// ```
+    pub fn encrypt_lwe_modular<OutputCont, Gen, Modulus>(
+        &self,
+        output: &mut LweCiphertext<OutputCont>,
+        encoded: &Plaintext<u64>,
+        noise_parameters: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator<Gen>,
+        modulus: &Modulus,
+    ) where
+        Self: AsRefTensor<Element = u64>,
+        LweCiphertext<OutputCont>: AsMutTensor<Element = u64>,
+        Gen: ByteRandomGenerator,
+        Modulus: NonNativeModulus,
+    {
+        let (output_body, mut output_mask) = output.get_mut_body_and_mask();
+
+        // generate a uniformly random mask in [0, q)
+        generator.fill_tensor_with_random_mask_modular(&mut output_mask, modulus);
+
+        // generate an error from the normal distribution described by std_dev, scaled to q
+        let noise = modulus.native_to_modular(generator.random_noise(noise_parameters));
+
+        // compute the multisum between the secret key and the mask, and add the error and the
+        // encoded message
+        output_body.0 = modulus.add(
+            modulus.add(self.compute_multisum_modular(&output_mask, modulus), noise),
+            encoded.0,
+        );
+        output.set_key_fingerprint(self.key_fingerprint());
+    }
+
+    /// Decrypts a single ciphertext encrypted modulo a non-native modulus.
+    ///
+    /// See [`LweSecretKey::encrypt_lwe_modular`] for an example.
+    pub fn decrypt_lwe_modular<CipherCont, Modulus>(
+        &self,
+        output: &mut Plaintext<u64>,
+        cipher: &LweCiphertext<CipherCont>,
+        modulus: &Modulus,
+    ) where
+        Self: AsRefTensor<Element = u64>,
+        LweCiphertext<CipherCont>: AsRefTensor<Element = u64>,
+        Modulus: NonNativeModulus,
+    {
+        let (body, masks) = cipher.get_body_and_mask();
+        output.0 = modulus.sub(body.0, self.compute_multisum_modular(&masks, modulus));
+    }
+
+    // Computes the multisum between the key and a mask modulo a non-native modulus. The key
+    // coefficients are interpreted as signed integers, so that ternary and gaussian keys are
+    // supported as well.
+    fn compute_multisum_modular<MaskCont, Modulus>(
+        &self,
+        mask: &LweMask<MaskCont>,
+        modulus: &Modulus,
+    ) -> u64
+    where
+        Self: AsRefTensor<Element = u64>,
+        LweMask<MaskCont>: AsRefTensor<Element = u64>,
+        Modulus: NonNativeModulus,
+    {
+        ck_dim_eq!(self.key_size().0 => mask.mask_size().0);
+        mask.mask_element_iter()
+            .zip(self.as_tensor().iter())
+            .fold(0, |acc, (mask, key)| {
+                modulus.add(acc, modulus.mul(*mask, modulus.reduce_signed(*key as i64)))
+            })
+    }
+
     /// Decrypts a list of ciphertexts.
     ///
     /// See ['encrypt_lwe_list'] for an example.
@@ -707,6 +948,111 @@ where
         }
     }
 
+    /// Encrypts a Lev ciphertext.
+    ///
+    /// The LWE ciphertext of each level $j$ of `encrypted` is filled with an encryption of the
+    /// `encoded` plaintext multiplied by $q / \beta^j$, where $\beta$ is the decomposition base of
+    /// `encrypted`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::commons::crypto::encoding::Plaintext;
+    /// use concrete_core::commons::crypto::lwe::LevCiphertext;
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::LweSecretKey;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let secret_key = LweSecretKey::generate_binary(LweDimension(256), &mut secret_generator);
+    /// let noise = LogStandardDev::from_log_standard_dev(-25.);
+    /// let mut ciphertext = LevCiphertext::allocate(
+    ///     0 as u32,
+    ///     LweDimension(256),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(4),
+    /// );
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// secret_key.encrypt_lev(
+    ///     &mut ciphertext,
+    ///     &Plaintext(7 as u32),
+    ///     noise,
+    ///     &mut encryption_generator,
+    /// );
+    /// let mut decrypted = Plaintext(0 as u32);
+    /// secret_key.decrypt_lev(&mut decrypted, &ciphertext);
+    /// assert_eq!(decrypted, Plaintext(7));
+    /// ```
+    pub fn encrypt_lev<OutputCont, Scalar, Gen>(
+        &self,
+        encrypted: &mut LevCiphertext<OutputCont>,
+        encoded: &Plaintext<Scalar>,
+        noise_parameters: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator<Gen>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        LevCiphertext<OutputCont>: AsMutTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+        Gen: ByteRandomGenerator,
+    {
+        ck_dim_eq!(encrypted.lwe_size().to_lwe_dimension().0 => self.key_size().0);
+        debug_assert!(
+            encrypted.decomposition_base_log().0 * encrypted.decomposition_level_count().0
+                <= Scalar::BITS,
+            "The decomposition precision must not exceed the precision of the ciphertext."
+        );
+
+        let base_log = encrypted.decomposition_base_log();
+        for (level, mut lwe) in encrypted.level_iter_mut() {
+            let factor = Scalar::ONE << (Scalar::BITS - (base_log.0 * level.0));
+            self.encrypt_lwe(
+                &mut lwe,
+                &Plaintext(encoded.0.wrapping_mul(factor)),
+                noise_parameters,
+                generator,
+            );
+        }
+    }
+
+    /// Decrypts a Lev ciphertext.
+    ///
+    /// The LWE ciphertext of the first level is decrypted, and its result is rounded to the
+    /// closest multiple of $q / \beta$, so that `output` is filled with the encrypted plaintext
+    /// modulo $\beta$.
+    ///
+    /// See [`LweSecretKey::encrypt_lev`] for an example.
+    pub fn decrypt_lev<Scalar, CipherCont>(
+        &self,
+        output: &mut Plaintext<Scalar>,
+        cipher: &LevCiphertext<CipherCont>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        LevCiphertext<CipherCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(cipher.lwe_size().to_lwe_dimension().0 => self.key_size().0);
+
+        let (_, first_level) = cipher
+            .level_iter()
+            .next()
+            .expect("A Lev ciphertext contains at least one level.");
+        self.decrypt_lwe(output, &first_level);
+
+        let shift = Scalar::BITS - cipher.decomposition_base_log().0;
+        if shift == 0 {
+            return;
+        }
+        let rounding = Scalar::ONE << (shift - 1);
+        output.0 = output.0.wrapping_add(rounding) >> shift;
+    }
+
     /// This function encrypts a message as a GSW ciphertext.
     ///
     /// # Examples
@@ -1,8 +1,15 @@
 //! Secret keys for the concrete schemes.
+pub use fingerprint::*;
 pub use glwe::*;
 pub use lwe::*;
+pub use tensor_product::*;
 
 pub mod generators;
 
+mod fingerprint;
 mod glwe;
 mod lwe;
+mod tensor_product;
+
+#[cfg(test)]
+mod tests;
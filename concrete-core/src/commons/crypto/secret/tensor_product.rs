@@ -0,0 +1,262 @@
+use crate::commons::crypto::secret::GlweSecretKey;
+use crate::commons::math::polynomial::PolynomialList;
+use crate::commons::math::tensor::{
+    ck_dim_div, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, IntoTensor, Tensor,
+};
+use crate::commons::math::torus::UnsignedTorus;
+use concrete_commons::key_kinds::KeyKind;
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+#[cfg(feature = "__commons_serialization")]
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+
+/// The secret key associated with the tensor product of two GLWE ciphertexts encrypted under the
+/// same GLWE secret key.
+///
+/// For a GLWE secret key $(S\_1, \dots, S\_k)$, the tensor product key contains the
+/// $k(k+3)/2$ following polynomials, in this order:
+///
+/// + the $k$ polynomials $S\_i$,
+/// + the $k(k-1)/2$ polynomials $S\_i \cdot S\_j$ for $i < j$, ordered by $i$ and then by $j$,
+/// + the $k$ polynomials $S\_i^2$.
+///
+/// All the products are computed modulo $X^N + 1$. The `Kind` parameter is the kind of the GLWE
+/// secret key the tensor product key was created from.
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweTensorProductSecretKey<Kind, Container>
+where
+    Kind: KeyKind,
+{
+    tensor: Tensor<Container>,
+    poly_size: PolynomialSize,
+    kind: PhantomData<Kind>,
+}
+
+impl<Kind, Cont> GlweTensorProductSecretKey<Kind, Cont>
+where
+    Kind: KeyKind,
+{
+    /// Creates a tensor product key from a container.
+    ///
+    /// # Notes
+    ///
+    /// This method does not compute the products. It merely wraps the container in the
+    /// appropriate type. For a method that computes the key from a GLWE secret key, see
+    /// [`GlweSecretKey::create_tensor_product_key`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::key_kinds::BinaryKeyKind;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::commons::crypto::secret::*;
+    /// let tensor_product_key = GlweTensorProductSecretKey::<BinaryKeyKind, _>::from_container(
+    ///     vec![0 as u8; 5 * 256],
+    ///     PolynomialSize(256),
+    /// );
+    /// assert_eq!(tensor_product_key.key_size(), GlweDimension(5));
+    /// assert_eq!(tensor_product_key.polynomial_size(), PolynomialSize(256));
+    /// ```
+    pub fn from_container(cont: Cont, poly_size: PolynomialSize) -> Self
+    where
+        Cont: AsRefSlice,
+    {
+        ck_dim_div!(cont.as_slice().len() => poly_size.0);
+        GlweTensorProductSecretKey {
+            tensor: Tensor::from_container(cont),
+            poly_size,
+            kind: PhantomData,
+        }
+    }
+
+    /// Returns the number of polynomials in the key.
+    ///
+    /// This is equivalent to the number of masks in the tensor product ciphertexts.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::commons::crypto::secret::generators::SecretRandomGenerator;
+    /// use concrete_core::commons::crypto::secret::*;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let secret_key: GlweSecretKey<_, Vec<u32>> =
+    ///     GlweSecretKey::generate_binary(GlweDimension(3), PolynomialSize(256), &mut secret_generator);
+    /// let tensor_product_key = secret_key.create_tensor_product_key();
+    /// assert_eq!(tensor_product_key.key_size(), GlweDimension(9));
+    /// ```
+    pub fn key_size(&self) -> GlweDimension
+    where
+        Self: AsRefTensor,
+    {
+        GlweDimension(self.as_tensor().len() / self.poly_size.0)
+    }
+
+    /// Returns the size of the key polynomials.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::commons::crypto::secret::generators::SecretRandomGenerator;
+    /// use concrete_core::commons::crypto::secret::*;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let secret_key: GlweSecretKey<_, Vec<u32>> =
+    ///     GlweSecretKey::generate_binary(GlweDimension(3), PolynomialSize(256), &mut secret_generator);
+    /// let tensor_product_key = secret_key.create_tensor_product_key();
+    /// assert_eq!(tensor_product_key.polynomial_size(), PolynomialSize(256));
+    /// ```
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.poly_size
+    }
+
+    /// Returns a borrowed polynomial list from the current key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialCount, PolynomialSize};
+    /// use concrete_core::commons::crypto::secret::generators::SecretRandomGenerator;
+    /// use concrete_core::commons::crypto::secret::*;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let secret_key: GlweSecretKey<_, Vec<u32>> =
+    ///     GlweSecretKey::generate_binary(GlweDimension(3), PolynomialSize(256), &mut secret_generator);
+    /// let tensor_product_key = secret_key.create_tensor_product_key();
+    /// let poly = tensor_product_key.as_polynomial_list();
+    /// assert_eq!(poly.polynomial_count(), PolynomialCount(9));
+    /// assert_eq!(poly.polynomial_size(), PolynomialSize(256));
+    /// ```
+    pub fn as_polynomial_list(&self) -> PolynomialList<&[<Self as AsRefTensor>::Element]>
+    where
+        Self: AsRefTensor,
+    {
+        PolynomialList::from_container(self.as_tensor().as_slice(), self.poly_size)
+    }
+}
+
+impl<Kind, Cont> GlweSecretKey<Kind, Cont>
+where
+    Kind: KeyKind,
+{
+    /// Computes the tensor product key associated with the current key.
+    ///
+    /// See [`GlweTensorProductSecretKey`] for the layout of the output key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::commons::crypto::secret::generators::SecretRandomGenerator;
+    /// use concrete_core::commons::crypto::secret::*;
+    /// use concrete_core::commons::math::polynomial::Polynomial;
+    /// use concrete_core::commons::math::tensor::{AsRefSlice, AsRefTensor};
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let secret_key: GlweSecretKey<_, Vec<u64>> =
+    ///     GlweSecretKey::generate_binary(GlweDimension(2), PolynomialSize(256), &mut secret_generator);
+    /// let tensor_product_key = secret_key.create_tensor_product_key();
+    ///
+    /// let key_polynomials = secret_key.as_polynomial_list();
+    /// let tensor_product_polynomials = tensor_product_key.as_polynomial_list();
+    /// let mut square = Polynomial::allocate(0_u64, PolynomialSize(256));
+    /// square.fill_with_wrapping_mul(
+    ///     &key_polynomials.get_polynomial(1),
+    ///     &key_polynomials.get_polynomial(1),
+    /// );
+    /// assert_eq!(
+    ///     tensor_product_polynomials.get_polynomial(4).as_tensor().as_slice(),
+    ///     square.as_tensor().as_slice()
+    /// );
+    /// ```
+    pub fn create_tensor_product_key<Scalar>(&self) -> GlweTensorProductSecretKey<Kind, Vec<Scalar>>
+    where
+        Self: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        let key_size = self.key_size();
+        let poly_size = self.polynomial_size();
+        let mut output = GlweTensorProductSecretKey::from_container(
+            vec![Scalar::ZERO; key_size.tensor_product_dimension().0 * poly_size.0],
+            poly_size,
+        );
+        {
+            let key_polynomials = self.as_polynomial_list();
+            let mut output_polynomials =
+                PolynomialList::from_container(output.as_mut_tensor().as_mut_slice(), poly_size);
+            let mut output_iter = output_polynomials.polynomial_iter_mut();
+
+            // The linear terms are copied from the input key.
+            for key_polynomial in key_polynomials.polynomial_iter() {
+                output_iter
+                    .next()
+                    .unwrap()
+                    .as_mut_tensor()
+                    .fill_with_copy(key_polynomial.as_tensor());
+            }
+
+            // The cross terms, for i < j.
+            for i in 0..key_size.0 {
+                for j in (i + 1)..key_size.0 {
+                    output_iter.next().unwrap().fill_with_karatsuba_mul(
+                        &key_polynomials.get_polynomial(i),
+                        &key_polynomials.get_polynomial(j),
+                    );
+                }
+            }
+
+            // The squared terms.
+            for key_polynomial in key_polynomials.polynomial_iter() {
+                output_iter
+                    .next()
+                    .unwrap()
+                    .fill_with_karatsuba_mul(&key_polynomial, &key_polynomial);
+            }
+        }
+
+        output
+    }
+}
+
+impl<Kind, Element, Cont> AsRefTensor for GlweTensorProductSecretKey<Kind, Cont>
+where
+    Kind: KeyKind,
+    Cont: AsRefSlice<Element = Element>,
+{
+    type Element = Element;
+    type Container = Cont;
+    fn as_tensor(&self) -> &Tensor<Self::Container> {
+        &self.tensor
+    }
+}
+
+impl<Kind, Element, Cont> AsMutTensor for GlweTensorProductSecretKey<Kind, Cont>
+where
+    Kind: KeyKind,
+    Cont: AsMutSlice<Element = Element>,
+{
+    type Element = Element;
+    type Container = Cont;
+    fn as_mut_tensor(&mut self) -> &mut Tensor<<Self as AsMutTensor>::Container> {
+        &mut self.tensor
+    }
+}
+
+impl<Kind, Cont> IntoTensor for GlweTensorProductSecretKey<Kind, Cont>
+where
+    Kind: KeyKind,
+    Cont: AsRefSlice,
+{
+    type Element = <Cont as AsRefSlice>::Element;
+    type Container = Cont;
+    fn into_tensor(self) -> Tensor<Self::Container> {
+        self.tensor
+    }
+}
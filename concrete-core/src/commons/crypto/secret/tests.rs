@@ -0,0 +1,168 @@
+use crate::commons::crypto::secret::GlweSecretKey;
+use crate::commons::math::polynomial::Polynomial;
+use crate::commons::math::tensor::{AsMutTensor, AsRefSlice, AsRefTensor};
+use crate::commons::math::torus::UnsignedTorus;
+use crate::commons::test_tools;
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+
+fn test_tensor_product_key<T: UnsignedTorus>(
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+) {
+    let mut secret_generator = test_tools::new_secret_random_generator();
+    let secret_key: GlweSecretKey<_, Vec<T>> =
+        GlweSecretKey::generate_binary(glwe_dimension, polynomial_size, &mut secret_generator);
+
+    let tensor_product_key = secret_key.create_tensor_product_key();
+    assert_eq!(
+        tensor_product_key.key_size(),
+        glwe_dimension.tensor_product_dimension()
+    );
+    assert_eq!(tensor_product_key.polynomial_size(), polynomial_size);
+
+    // We compute the expected polynomials with the naive multiplication, in the documented order.
+    let key_polynomials = secret_key.as_polynomial_list();
+    let mut expected = Vec::new();
+    for i in 0..glwe_dimension.0 {
+        let mut polynomial = Polynomial::allocate(T::ZERO, polynomial_size);
+        polynomial
+            .as_mut_tensor()
+            .fill_with_copy(key_polynomials.get_polynomial(i).as_tensor());
+        expected.push(polynomial);
+    }
+    for i in 0..glwe_dimension.0 {
+        for j in (i + 1)..glwe_dimension.0 {
+            let mut polynomial = Polynomial::allocate(T::ZERO, polynomial_size);
+            polynomial.fill_with_wrapping_mul(
+                &key_polynomials.get_polynomial(i),
+                &key_polynomials.get_polynomial(j),
+            );
+            expected.push(polynomial);
+        }
+    }
+    for i in 0..glwe_dimension.0 {
+        let mut polynomial = Polynomial::allocate(T::ZERO, polynomial_size);
+        polynomial.fill_with_wrapping_mul(
+            &key_polynomials.get_polynomial(i),
+            &key_polynomials.get_polynomial(i),
+        );
+        expected.push(polynomial);
+    }
+
+    let output_polynomials = tensor_product_key.as_polynomial_list();
+    assert_eq!(output_polynomials.polynomial_count().0, expected.len());
+    for (output, expected) in output_polynomials.polynomial_iter().zip(expected.iter()) {
+        assert_eq!(
+            output.as_tensor().as_slice(),
+            expected.as_tensor().as_slice()
+        );
+    }
+}
+
+#[test]
+fn test_tensor_product_key_u32() {
+    test_tensor_product_key::<u32>(GlweDimension(3), PolynomialSize(256));
+    test_tensor_product_key::<u32>(
+        test_tools::random_glwe_dimension(5),
+        test_tools::random_polynomial_size(200),
+    );
+}
+
+#[test]
+fn test_tensor_product_key_u64() {
+    test_tensor_product_key::<u64>(GlweDimension(3), PolynomialSize(256));
+    test_tensor_product_key::<u64>(
+        test_tools::random_glwe_dimension(5),
+        test_tools::random_polynomial_size(200),
+    );
+}
+
+#[cfg(feature = "__commons_key_fingerprint")]
+fn test_key_fingerprint<T: UnsignedTorus>() {
+    use crate::commons::crypto::encoding::Plaintext;
+    use crate::commons::crypto::lwe::{LweCiphertext, LweKeyswitchKey};
+    use crate::commons::crypto::secret::LweSecretKey;
+    use concrete_commons::dispersion::LogStandardDev;
+    use concrete_commons::parameters::{
+        DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    };
+
+    let mut secret_generator = test_tools::new_secret_random_generator();
+    let mut encryption_generator = test_tools::new_encryption_random_generator();
+    let noise = LogStandardDev::from_log_standard_dev(-25.);
+    let glwe_key: GlweSecretKey<_, Vec<T>> = GlweSecretKey::generate_binary(
+        GlweDimension(2),
+        PolynomialSize(256),
+        &mut secret_generator,
+    );
+    let input_key = glwe_key.clone().into_lwe_secret_key();
+    let other_key = LweSecretKey::generate_binary(input_key.key_size(), &mut secret_generator);
+    let output_key = LweSecretKey::generate_binary(LweDimension(100), &mut secret_generator);
+
+    // The GLWE key and the LWE key it is turned into share the same fingerprint.
+    assert!(input_key.key_fingerprint().is_known());
+    assert!(glwe_key
+        .key_fingerprint()
+        .is_compatible_with(&input_key.key_fingerprint()));
+    assert!(!other_key
+        .key_fingerprint()
+        .is_compatible_with(&input_key.key_fingerprint()));
+
+    let mut ksk = LweKeyswitchKey::allocate(
+        T::ZERO,
+        DecompositionLevelCount(3),
+        DecompositionBaseLog(4),
+        input_key.key_size(),
+        output_key.key_size(),
+    );
+    ksk.fill_with_keyswitch_key(&input_key, &output_key, noise, &mut encryption_generator);
+
+    let mut ciphertext = LweCiphertext::allocate(T::ZERO, input_key.key_size().to_lwe_size());
+    let mut other_ciphertext = ciphertext.clone();
+    input_key.encrypt_lwe(
+        &mut ciphertext,
+        &Plaintext(T::ZERO),
+        noise,
+        &mut encryption_generator,
+    );
+    other_key.encrypt_lwe(
+        &mut other_ciphertext,
+        &Plaintext(T::ZERO),
+        noise,
+        &mut encryption_generator,
+    );
+    assert!(ksk
+        .input_key_fingerprint()
+        .is_compatible_with(&ciphertext.key_fingerprint()));
+    assert!(!ksk
+        .input_key_fingerprint()
+        .is_compatible_with(&other_ciphertext.key_fingerprint()));
+
+    // The fingerprint is kept by the operations with ciphertexts encrypted under the same key, and
+    // reset by the ones mixing keys.
+    let mut sum = ciphertext.clone();
+    sum.update_with_add(&ciphertext);
+    assert!(sum.key_fingerprint().is_known());
+    sum.update_with_add(&other_ciphertext);
+    assert!(!sum.key_fingerprint().is_known());
+
+    // The output of the keyswitch carries the fingerprint of the output key.
+    let mut switched = LweCiphertext::allocate(T::ZERO, output_key.key_size().to_lwe_size());
+    ksk.keyswitch_ciphertext(&mut switched, &ciphertext);
+    assert!(output_key
+        .key_fingerprint()
+        .is_compatible_with(&switched.key_fingerprint()));
+    assert!(switched.key_fingerprint().is_known());
+}
+
+#[cfg(feature = "__commons_key_fingerprint")]
+#[test]
+fn test_key_fingerprint_u32() {
+    test_key_fingerprint::<u32>();
+}
+
+#[cfg(feature = "__commons_key_fingerprint")]
+#[test]
+fn test_key_fingerprint_u64() {
+    test_key_fingerprint::<u64>();
+}
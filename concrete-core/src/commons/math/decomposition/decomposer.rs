@@ -1,7 +1,13 @@
 use crate::commons::math::decomposition::{SignedDecompositionIter, TensorSignedDecompositionIter};
-use crate::commons::math::tensor::{AsMutTensor, AsRefTensor, Tensor};
+use crate::commons::math::random::{
+    ByteRandomGenerator, RandomGenerable, RandomGenerator, Uniform,
+};
+use crate::commons::math::simd;
+use crate::commons::math::tensor::{
+    ck_dim_eq, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+};
 use concrete_commons::numeric::{Numeric, UnsignedInteger};
-use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, RoundingMode};
 use std::marker::PhantomData;
 
 /// A structure which allows to decompose unsigned integers into a set of smaller terms.
@@ -136,9 +142,13 @@ where
         I: AsRefTensor<Element = Scalar>,
         O: AsMutTensor<Element = Scalar>,
     {
-        output
-            .as_mut_tensor()
-            .fill_with_one(input.as_tensor(), |elmt| self.closest_representable(*elmt))
+        let output = output.as_mut_tensor();
+        ck_dim_eq!(output.len() => input.as_tensor().len());
+        simd::fill_with_closest_representable(
+            self,
+            output.as_mut_slice(),
+            input.as_tensor().as_slice(),
+        );
     }
 
     /// Generates an iterator over the terms of the decomposition of the input.
@@ -175,6 +185,122 @@ where
         )
     }
 
+    /// Returns the value representable by the decomposition obtained by rounding the input with
+    /// the given rounding mode.
+    ///
+    /// A generator must be provided when the rounding mode is [`RoundingMode::Stochastic`], and is
+    /// ignored otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rounding mode is [`RoundingMode::Stochastic`] and no generator is provided.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, RoundingMode,
+    /// };
+    /// use concrete_core::commons::math::decomposition::SignedDecomposer;
+    /// use concrete_core::commons::math::random::RandomGenerator;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// let decomposer =
+    ///     SignedDecomposer::<u32>::new(DecompositionBaseLog(4), DecompositionLevelCount(3));
+    /// // The input lies exactly halfway between two representable values.
+    /// let input = 0b0101_0000_0000_1000_0000_0000_0000_0000_u32;
+    /// let up = decomposer.closest_representable_with_rounding::<SoftwareRandomGenerator>(
+    ///     input,
+    ///     RoundingMode::NearestUp,
+    ///     None,
+    /// );
+    /// assert_eq!(up, 0b0101_0000_0001_0000_0000_0000_0000_0000_u32);
+    /// let even = decomposer.closest_representable_with_rounding::<SoftwareRandomGenerator>(
+    ///     input,
+    ///     RoundingMode::NearestEven,
+    ///     None,
+    /// );
+    /// assert_eq!(even, 0b0101_0000_0000_0000_0000_0000_0000_0000_u32);
+    /// ```
+    pub fn closest_representable_with_rounding<G: ByteRandomGenerator>(
+        &self,
+        input: Scalar,
+        rounding: RoundingMode,
+        generator: Option<&mut RandomGenerator<G>>,
+    ) -> Scalar
+    where
+        Scalar: RandomGenerable<Uniform>,
+    {
+        let non_rep_bit_count: usize = <Scalar as Numeric>::BITS - self.level_count * self.base_log;
+        // We split the input in its representable part, and the remainder which is dropped.
+        let rem_mask = (Scalar::ONE << non_rep_bit_count).wrapping_sub(Scalar::ONE);
+        let rem = input & rem_mask;
+        let res = input >> non_rep_bit_count;
+        let half = Scalar::ONE << (non_rep_bit_count - 1);
+        let round_up = match rounding {
+            RoundingMode::NearestUp => rem >= half,
+            RoundingMode::NearestEven => {
+                rem > half || (rem == half && (res & Scalar::ONE) == Scalar::ONE)
+            }
+            RoundingMode::Stochastic => {
+                // We round up with probability rem / 2^non_rep_bit_count.
+                let generator = generator.expect("Stochastic rounding requires a generator.");
+                let draw = generator.random_uniform::<Scalar>() & rem_mask;
+                draw < rem
+            }
+        };
+        let res = if round_up {
+            res.wrapping_add(Scalar::ONE)
+        } else {
+            res
+        };
+        res << non_rep_bit_count
+    }
+
+    /// Generates an iterator over the terms of the decomposition of the input, rounded with the
+    /// given rounding mode.
+    ///
+    /// See [`SignedDecomposer::decompose`] for the description of the iterator, and
+    /// [`SignedDecomposer::closest_representable_with_rounding`] for the rounding.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, RoundingMode,
+    /// };
+    /// use concrete_core::commons::math::decomposition::SignedDecomposer;
+    /// use concrete_core::commons::math::random::RandomGenerator;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// let decomposer =
+    ///     SignedDecomposer::<u32>::new(DecompositionBaseLog(4), DecompositionLevelCount(3));
+    /// let mut generator = RandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let input = 1_340_987_234_u32;
+    /// let decomposition = decomposer.decompose_with_rounding(
+    ///     input,
+    ///     RoundingMode::Stochastic,
+    ///     Some(&mut generator),
+    /// );
+    /// let recomposed = decomposer.recompose(decomposition).unwrap();
+    /// // The stochastic rounding yields one of the two closest representable values.
+    /// assert!(recomposed == 1_340_080_128_u32 || recomposed == 1_341_128_704_u32);
+    /// ```
+    pub fn decompose_with_rounding<G: ByteRandomGenerator>(
+        &self,
+        input: Scalar,
+        rounding: RoundingMode,
+        generator: Option<&mut RandomGenerator<G>>,
+    ) -> SignedDecompositionIter<Scalar>
+    where
+        Scalar: RandomGenerable<Uniform>,
+    {
+        SignedDecompositionIter::new(
+            self.closest_representable_with_rounding(input, rounding, generator),
+            DecompositionBaseLog(self.base_log),
+            DecompositionLevelCount(self.level_count),
+        )
+    }
+
     /// Recomposes a decomposed value by summing all the terms.
     ///
     /// If the input iterator yields $\tilde{\theta}\_i$, this returns
@@ -1,8 +1,8 @@
 use crate::commons::math::decomposition::{
     DecompositionLevel, DecompositionTerm, DecompositionTermTensor,
 };
+use crate::commons::math::simd;
 use crate::commons::math::tensor::Tensor;
-use crate::commons::utils::{zip, zip_args};
 use concrete_commons::numeric::UnsignedInteger;
 use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
 
@@ -145,9 +145,12 @@ where
             return None;
         }
         // We iterate over the elements of the outputs and decompose
-        for zip_args!(output_i, state_i) in zip!(self.outputs.iter_mut(), self.states.iter_mut()) {
-            *output_i = decompose_one_level(self.base_log, state_i, self.mod_b_mask);
-        }
+        simd::decompose_one_level_in_place(
+            &mut self.outputs,
+            &mut self.states,
+            self.base_log,
+            self.mod_b_mask,
+        );
         self.current_level -= 1;
         // We return the term tensor.
         Some(DecompositionTermTensor::new(
@@ -273,7 +276,11 @@ where
     }
 }
 
-fn decompose_one_level<S: UnsignedInteger>(base_log: usize, state: &mut S, mod_b_mask: S) -> S {
+pub(crate) fn decompose_one_level<S: UnsignedInteger>(
+    base_log: usize,
+    state: &mut S,
+    mod_b_mask: S,
+) -> S {
     let res = *state & mod_b_mask;
     *state >>= base_log;
     let mut carry = (res.wrapping_sub(S::ONE) | *state) & res;
@@ -2,9 +2,10 @@ use crate::commons::math::decomposition::SignedDecomposer;
 use crate::commons::math::random::{RandomGenerable, Uniform};
 use crate::commons::math::tensor::Tensor;
 use crate::commons::math::torus::UnsignedTorus;
-use crate::commons::test_tools::{any_uint, any_usize, random_usize_between};
+use crate::commons::test_tools::{any_uint, any_usize, new_random_generator, random_usize_between};
 use concrete_commons::numeric::{Numeric, SignedInteger, UnsignedInteger};
-use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, RoundingMode};
+use concrete_csprng::generators::SoftwareRandomGenerator;
 use std::fmt::Debug;
 
 // Returns a random decomposition valid for the size of the T type.
@@ -178,3 +179,115 @@ fn test_round_tensor_to_closest_twice_u32() {
 fn test_round_tensor_to_closest_twice_u64() {
     test_round_tensor_to_closest_twice::<u64>();
 }
+
+fn test_round_with_rounding_mode<T: UnsignedTorus + Debug>() {
+    let mut generator = new_random_generator();
+    for _ in 0..10_000 {
+        let decomp = random_decomp::<T>();
+        let input: T = any_uint();
+        let non_rep_bit_count = T::BITS - decomp.level_count * decomp.base_log;
+        let floor = (input >> non_rep_bit_count) << non_rep_bit_count;
+        let ceil = floor.wrapping_add(T::ONE << non_rep_bit_count);
+        let half = T::ONE << (non_rep_bit_count - 1);
+        let is_tie = input.wrapping_sub(floor) == half;
+
+        let up = decomp.closest_representable_with_rounding(
+            input,
+            RoundingMode::NearestUp,
+            Some(&mut generator),
+        );
+        assert_eq!(up, decomp.closest_representable(input));
+
+        let even = decomp.closest_representable_with_rounding(
+            input,
+            RoundingMode::NearestEven,
+            Some(&mut generator),
+        );
+        if is_tie {
+            assert_eq!((even >> non_rep_bit_count) & T::ONE, T::ZERO);
+        } else {
+            assert_eq!(even, up);
+        }
+
+        let stochastic = decomp.closest_representable_with_rounding(
+            input,
+            RoundingMode::Stochastic,
+            Some(&mut generator),
+        );
+        assert!(stochastic == floor || stochastic == ceil);
+    }
+}
+
+#[test]
+fn test_round_with_rounding_mode_u32() {
+    test_round_with_rounding_mode::<u32>();
+}
+
+#[test]
+fn test_round_with_rounding_mode_u64() {
+    test_round_with_rounding_mode::<u64>();
+}
+
+fn test_round_ties_to_even<T: UnsignedTorus + Debug>() {
+    for _ in 0..1000 {
+        let decomp = random_decomp::<T>();
+        let non_rep_bit_count = T::BITS - decomp.level_count * decomp.base_log;
+        let value = (any_uint::<T>() >> non_rep_bit_count) << non_rep_bit_count;
+        let tie = value.wrapping_add(T::ONE << (non_rep_bit_count - 1));
+        let expected = if (value >> non_rep_bit_count) & T::ONE == T::ZERO {
+            value
+        } else {
+            value.wrapping_add(T::ONE << non_rep_bit_count)
+        };
+        assert_eq!(
+            decomp.closest_representable_with_rounding::<SoftwareRandomGenerator>(
+                tie,
+                RoundingMode::NearestEven,
+                None
+            ),
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_round_ties_to_even_u32() {
+    test_round_ties_to_even::<u32>();
+}
+
+#[test]
+fn test_round_ties_to_even_u64() {
+    test_round_ties_to_even::<u64>();
+}
+
+fn test_stochastic_rounding_is_unbiased<T: UnsignedTorus + Debug>() {
+    // We round a value located at a quarter of the interval between two representable values,
+    // which must be rounded up a quarter of the time.
+    let mut generator = new_random_generator();
+    let decomp = SignedDecomposer::<T>::new(DecompositionBaseLog(4), DecompositionLevelCount(2));
+    let non_rep_bit_count = T::BITS - 8;
+    let floor = (any_uint::<T>() >> non_rep_bit_count) << non_rep_bit_count;
+    let input = floor.wrapping_add(T::ONE << (non_rep_bit_count - 2));
+    let sample_count = 100_000;
+    let rounded_up_count = (0..sample_count)
+        .filter(|_| {
+            decomp.closest_representable_with_rounding(
+                input,
+                RoundingMode::Stochastic,
+                Some(&mut generator),
+            ) != floor
+        })
+        .count();
+    let ratio = rounded_up_count as f64 / sample_count as f64;
+    assert!((ratio - 0.25).abs() < 0.01);
+}
+
+#[test]
+fn test_stochastic_rounding_is_unbiased_u32() {
+    test_stochastic_rounding_is_unbiased::<u32>();
+}
+
+#[test]
+fn test_stochastic_rounding_is_unbiased_u64() {
+    test_stochastic_rounding_is_unbiased::<u64>();
+}
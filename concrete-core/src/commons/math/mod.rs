@@ -1,7 +1,9 @@
 //! A module containing general mathematical tools.
 
 pub mod decomposition;
+pub mod modular;
 pub mod polynomial;
 pub mod random;
+pub(crate) mod simd;
 pub mod tensor;
 pub mod torus;
@@ -0,0 +1,202 @@
+//! Arithmetic modulo non-native moduli.
+//!
+//! Most of the library represents torus elements with unsigned integers, and relies on the
+//! wrapping arithmetic of these integers, which computes modulo $2^w$ for $w$-bits integers. This
+//! module contains the tools needed to work with ciphertexts over $\mathbb{Z}/q\mathbb{Z}$ for a
+//! non-native modulus $q < 2^{64}$, stored in `u64` integers in $[0, q)$.
+//!
+//! # Description
+//!
+//! A torus element $t\in[0, 1)$ is represented by $\lfloor t\cdot q\rceil$ modulo $q$, which means
+//! that the same noise distributions and encodings can be used for both the native and the
+//! non-native moduli, once scaled by $q/2^{64}$.
+//!
+//! The [`SolinasPrime64`] modulus $q = 2^{64} - 2^{32} + 1$ supports a fast reduction which only
+//! uses additions and shifts, and is NTT-friendly, as $2^{32}$ divides $q-1$.
+use crate::commons::math::random::{ByteRandomGenerator, RandomGenerator};
+use std::fmt::Debug;
+
+#[cfg(test)]
+mod tests;
+
+/// A trait for non-native moduli $q < 2^{64}$.
+///
+/// Implementors only need to provide the value of the modulus and the reduction of a 128 bits
+/// integer, the other operations being derived from them. All the operations expect their inputs
+/// to be reduced, and return reduced values.
+pub trait NonNativeModulus: Copy + Debug + Send + Sync {
+    /// Returns the value $q$ of the modulus.
+    fn value(&self) -> u64;
+
+    /// Reduces a 128 bits integer modulo $q$.
+    fn reduce(&self, input: u128) -> u64;
+
+    /// Computes $a + b \mod q$.
+    #[inline]
+    fn add(&self, a: u64, b: u64) -> u64 {
+        let sum = a as u128 + b as u128;
+        if sum >= self.value() as u128 {
+            (sum - self.value() as u128) as u64
+        } else {
+            sum as u64
+        }
+    }
+
+    /// Computes $a - b \mod q$.
+    #[inline]
+    fn sub(&self, a: u64, b: u64) -> u64 {
+        if a >= b {
+            a - b
+        } else {
+            a + (self.value() - b)
+        }
+    }
+
+    /// Computes $-a \mod q$.
+    #[inline]
+    fn neg(&self, a: u64) -> u64 {
+        if a == 0 {
+            0
+        } else {
+            self.value() - a
+        }
+    }
+
+    /// Computes $a \cdot b \mod q$.
+    #[inline]
+    fn mul(&self, a: u64, b: u64) -> u64 {
+        self.reduce(a as u128 * b as u128)
+    }
+
+    /// Returns the representative modulo $q$ of a signed integer.
+    #[inline]
+    fn reduce_signed(&self, input: i64) -> u64 {
+        let reduced = self.reduce(input.unsigned_abs() as u128);
+        if input < 0 {
+            self.neg(reduced)
+        } else {
+            reduced
+        }
+    }
+
+    /// Converts a torus element in native representation, i.e. modulo $2^{64}$, into the closest
+    /// element modulo $q$.
+    #[inline]
+    fn native_to_modular(&self, input: u64) -> u64 {
+        let scaled = (input as u128 * self.value() as u128 + (1 << 63)) >> 64;
+        self.reduce(scaled)
+    }
+
+    /// Converts an element modulo $q$ into the closest torus element in native representation,
+    /// i.e. modulo $2^{64}$.
+    #[inline]
+    fn to_native(&self, input: u64) -> u64 {
+        let q = self.value() as u128;
+        ((((input as u128) << 64) + q / 2) / q) as u64
+    }
+
+    /// Returns $\lfloor q / 2^{n}\rceil$, the value used for the $n$-th bit of a decomposition.
+    #[inline]
+    fn scaled_power_of_two(&self, n: usize) -> u64 {
+        debug_assert!(n > 0 && n <= 64);
+        ((self.value() as u128 + (1 << (n - 1))) >> n) as u64
+    }
+
+    /// Samples a value uniformly at random in $[0, q)$, using rejection sampling.
+    fn random_uniform<G: ByteRandomGenerator>(&self, generator: &mut RandomGenerator<G>) -> u64 {
+        loop {
+            let candidate: u64 = generator.random_uniform();
+            if candidate < self.value() {
+                return candidate;
+            }
+        }
+    }
+}
+
+/// Returns the number of products which can be summed in a `u128` accumulator before it must be
+/// reduced.
+///
+/// The products are those of a reduced value, smaller than $2^{64}$, by a factor strictly smaller
+/// than $2^{\mathsf{factor\\_bits}}$. Starting from a reduced value, the sum of
+/// $2^{64 - \mathsf{factor\\_bits}}$ such products is smaller than
+/// $(2^{64} - 1)(2^{\mathsf{factor\\_bits}} - 1)2^{64 - \mathsf{factor\\_bits}} + 2^{64} - 1 <
+/// 2^{128}$, so the accumulation is exact, and a single reduction of the accumulator gives the
+/// same result as reducing after every product.
+///
+/// # Example
+///
+/// ```rust
+/// use concrete_core::commons::math::modular::{
+///     lazy_reduction_period, NonNativeModulus, SolinasPrime64,
+/// };
+/// let q = SolinasPrime64;
+/// let period = lazy_reduction_period(48);
+/// assert_eq!(period, 1 << 16);
+/// let value = q.value() - 1;
+/// let factor = (1 << 48) - 1;
+/// let accumulator = (0..period).fold(value as u128, |acc, _| acc + value as u128 * factor);
+/// let expected = (0..period).fold(value, |acc, _| q.add(acc, q.mul(value, factor as u64)));
+/// assert_eq!(q.reduce(accumulator), expected);
+/// ```
+pub fn lazy_reduction_period(factor_bits: usize) -> usize {
+    debug_assert!(factor_bits > 0 && factor_bits <= 64);
+    1 << (64 - factor_bits)
+}
+
+/// The Solinas prime $q = 2^{64} - 2^{32} + 1$.
+///
+/// # Example
+///
+/// ```rust
+/// use concrete_core::commons::math::modular::{NonNativeModulus, SolinasPrime64};
+/// let q = SolinasPrime64;
+/// assert_eq!(q.value(), 0xFFFF_FFFF_0000_0001);
+/// assert_eq!(q.add(q.value() - 1, 2), 1);
+/// assert_eq!(q.sub(1, 2), q.value() - 1);
+/// assert_eq!(q.mul(1 << 32, 1 << 32), (1 << 32) - 1);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SolinasPrime64;
+
+impl SolinasPrime64 {
+    /// The value of the modulus.
+    pub const MODULUS: u64 = 0xFFFF_FFFF_0000_0001;
+
+    // The value of 2^64 mod q.
+    const EPSILON: u64 = 0xFFFF_FFFF;
+}
+
+impl NonNativeModulus for SolinasPrime64 {
+    #[inline]
+    fn value(&self) -> u64 {
+        Self::MODULUS
+    }
+
+    #[inline]
+    fn reduce(&self, input: u128) -> u64 {
+        // We write the input as x_lo + 2^64 x_hi_lo + 2^96 x_hi_hi, and use the fact that
+        // 2^64 = 2^32 - 1 mod q and 2^96 = -1 mod q.
+        let x_lo = input as u64;
+        let x_hi = (input >> 64) as u64;
+        let x_hi_hi = x_hi >> 32;
+        let x_hi_lo = x_hi & Self::EPSILON;
+
+        // x_hi_hi < 2^32, so the second subtraction can not underflow.
+        let (mut t0, borrow) = x_lo.overflowing_sub(x_hi_hi);
+        if borrow {
+            t0 = t0.wrapping_sub(Self::EPSILON);
+        }
+        // x_hi_lo < 2^32, so the product can not overflow, and neither can the correction of the
+        // carry.
+        let t1 = x_hi_lo * Self::EPSILON;
+        let (mut output, carry) = t0.overflowing_add(t1);
+        if carry {
+            output = output.wrapping_add(Self::EPSILON);
+        }
+        if output >= Self::MODULUS {
+            output - Self::MODULUS
+        } else {
+            output
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use crate::commons::math::modular::{NonNativeModulus, SolinasPrime64};
+use crate::commons::test_tools::any_uint;
+
+const Q: u128 = SolinasPrime64::MODULUS as u128;
+
+fn any_reduced() -> u64 {
+    (any_uint::<u64>() as u128 % Q) as u64
+}
+
+#[test]
+fn test_solinas_reduce() {
+    let q = SolinasPrime64;
+    for _ in 0..10000 {
+        let input = any_uint::<u128>();
+        assert_eq!(q.reduce(input) as u128, input % Q);
+    }
+    assert_eq!(q.reduce(u128::MAX) as u128, u128::MAX % Q);
+    assert_eq!(q.reduce(Q), 0);
+    assert_eq!(q.reduce(Q - 1) as u128, Q - 1);
+}
+
+#[test]
+fn test_solinas_arithmetic() {
+    let q = SolinasPrime64;
+    for _ in 0..10000 {
+        let a = any_reduced();
+        let b = any_reduced();
+        assert_eq!(q.add(a, b) as u128, (a as u128 + b as u128) % Q);
+        assert_eq!(q.sub(a, b) as u128, (a as u128 + Q - b as u128) % Q);
+        assert_eq!(q.add(q.neg(a), a), 0);
+        assert_eq!(q.mul(a, b) as u128, (a as u128 * b as u128) % Q);
+    }
+    assert_eq!(q.reduce_signed(-1) as u128, Q - 1);
+    assert_eq!(q.reduce_signed(i64::MIN) as u128, Q - (1 << 63));
+}
+
+#[test]
+fn test_solinas_native_conversion() {
+    let q = SolinasPrime64;
+    for _ in 0..10000 {
+        let a = any_reduced();
+        assert_eq!(q.native_to_modular(q.to_native(a)), a);
+    }
+    assert_eq!(q.native_to_modular(1 << 63), q.scaled_power_of_two(1));
+    assert_eq!(q.native_to_modular(0), 0);
+}
@@ -1,16 +1,18 @@
 //! A module to manipulate polynomials.
 //!
-//! This module allows to manipulate modular polynomials In particular, we provide three generic
+//! This module allows to manipulate modular polynomials In particular, we provide four generic
 //! types to manipulate such objects:
 //!
 //! + [`Monomial`], which represents a free monomial term (not bound to a given modular degree)
 //! + [`Polynomial`], which represents a dense polynomial of a given degree.
 //! + [`PolynomialList`], which represent a set of polynomials with the same degree, on which
 //! operations can be performed.
+//! + [`SparsePolynomial`], which represents a polynomial with few nonzero coefficients.
 
 pub use list::*;
 pub use monomial::*;
 pub use polynomial::*;
+pub use sparse::*;
 
 #[cfg(test)]
 mod tests;
@@ -19,5 +21,6 @@ mod list;
 mod monomial;
 #[allow(clippy::module_inception)]
 mod polynomial;
+mod sparse;
 
 pub use concrete_commons::parameters::MonomialDegree;
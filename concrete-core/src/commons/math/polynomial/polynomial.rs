@@ -1,13 +1,14 @@
 use std::fmt::Debug;
 use std::iter::Iterator;
 
+use crate::commons::math::simd;
 use crate::commons::math::tensor::{
-    ck_dim_eq, tensor_traits, AsMutSlice, AsMutTensor, AsRefTensor, Tensor,
+    ck_dim_eq, tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
 };
 
 use super::*;
 use concrete_commons::numeric::UnsignedInteger;
-use concrete_commons::parameters::{MonomialDegree, PolynomialSize};
+use concrete_commons::parameters::{MonomialDegree, PolynomialSize, RingStructure};
 
 // stop the induction when polynomials have KARATUSBA_STOP elements
 const KARATUSBA_STOP: usize = 32;
@@ -243,6 +244,30 @@ impl<Cont> Polynomial<Cont> {
         )
     }
 
+    /// Evaluates the polynomial at a point, using the Horner method with wrapping arithmetic.
+    ///
+    /// The polynomial is evaluated as its representative of degree smaller than $N$.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::math::polynomial::Polynomial;
+    /// let poly = Polynomial::from_container(vec![1_u8, 2, 3]);
+    /// assert_eq!(poly.wrapping_evaluate(2), 17);
+    /// assert_eq!(poly.wrapping_evaluate(10), 65);
+    /// ```
+    pub fn wrapping_evaluate<Coef>(&self, point: Coef) -> Coef
+    where
+        Self: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        self.coefficient_iter()
+            .rev()
+            .fold(Coef::ZERO, |acc, coefficient| {
+                acc.wrapping_mul(point).wrapping_add(*coefficient)
+            })
+    }
+
     /// Fills the current polynomial, with the result of the (slow) product of
     /// two polynomials, reduced modulo $(X^N + 1)$.
     ///
@@ -277,6 +302,45 @@ impl<Cont> Polynomial<Cont> {
         Polynomial<LhsCont>: AsRefTensor<Element = Coef>,
         Polynomial<RhsCont>: AsRefTensor<Element = Coef>,
         Coef: UnsignedInteger,
+    {
+        self.fill_with_wrapping_mul_in_ring(lhs, rhs, RingStructure::Negacyclic);
+    }
+
+    /// Fills the current polynomial, with the result of the (slow) product of two polynomials,
+    /// reduced modulo $(X^N + 1)$ or $(X^N - 1)$ depending on the ring structure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{PolynomialSize, RingStructure};
+    /// use concrete_core::commons::math::polynomial::{MonomialDegree, Polynomial};
+    /// let lhs = Polynomial::from_container(vec![4_u8, 5, 0]);
+    /// let rhs = Polynomial::from_container(vec![7_u8, 9, 1]);
+    /// let mut res = Polynomial::allocate(0 as u8, PolynomialSize(3));
+    /// res.fill_with_wrapping_mul_in_ring(&lhs, &rhs, RingStructure::Cyclic);
+    /// assert_eq!(
+    ///     *res.get_monomial(MonomialDegree(0)).get_coefficient(),
+    ///     33 as u8
+    /// );
+    /// assert_eq!(
+    ///     *res.get_monomial(MonomialDegree(1)).get_coefficient(),
+    ///     71 as u8
+    /// );
+    /// assert_eq!(
+    ///     *res.get_monomial(MonomialDegree(2)).get_coefficient(),
+    ///     49 as u8
+    /// );
+    /// ```
+    pub fn fill_with_wrapping_mul_in_ring<Coef, LhsCont, RhsCont>(
+        &mut self,
+        lhs: &Polynomial<LhsCont>,
+        rhs: &Polynomial<RhsCont>,
+        ring: RingStructure,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        Polynomial<LhsCont>: AsRefTensor<Element = Coef>,
+        Polynomial<RhsCont>: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger,
     {
         ck_dim_eq!(self.polynomial_size() => lhs.polynomial_size(), rhs.polynomial_size());
         self.coefficient_iter_mut().for_each(|a| *a = Coef::ZERO);
@@ -293,7 +357,10 @@ impl<Cont> Polynomial<Cont> {
                         .as_mut_tensor()
                         .get_element_mut(target_degree % (degree + 1));
                     let new = lhsi.get_coefficient().wrapping_mul(*rhsi.get_coefficient());
-                    *element = element.wrapping_sub(new);
+                    *element = match ring {
+                        RingStructure::Negacyclic => element.wrapping_sub(new),
+                        RingStructure::Cyclic => element.wrapping_add(new),
+                    };
                 }
             }
         }
@@ -303,6 +370,9 @@ impl<Cont> Polynomial<Cont> {
     /// polynomials, reduced modulo $(X^N + 1)$ with the Karatsuba algorithm
     /// Complexity: N^{1.58}
     ///
+    /// When $N$ is not a power of two, the inputs are padded with zeros to the next power of two,
+    /// and their full product is reduced modulo $(X^N + 1)$.
+    ///
     /// # Example
     ///
     /// ```
@@ -325,20 +395,78 @@ impl<Cont> Polynomial<Cont> {
         Polynomial<LhsCont>: AsRefTensor<Element = Coef>,
         Polynomial<RhsCont>: AsRefTensor<Element = Coef>,
         Coef: UnsignedInteger,
+    {
+        self.fill_with_karatsuba_mul_in_ring(p, q, RingStructure::Negacyclic);
+    }
+
+    /// Fills the current polynomial, with the result of the product of two polynomials, reduced
+    /// modulo $(X^N + 1)$ or $(X^N - 1)$ depending on the ring structure, with the Karatsuba
+    /// algorithm.
+    ///
+    /// In the cyclic ring, the inputs are always padded, and their full product is reduced.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{PolynomialSize, RingStructure};
+    /// use concrete_core::commons::math::polynomial::{MonomialDegree, Polynomial};
+    /// let lhs = Polynomial::from_container(vec![1_u32; 128]);
+    /// let rhs = Polynomial::from_container(vec![2_u32; 128]);
+    /// let mut res_kara = Polynomial::allocate(0 as u32, PolynomialSize(128));
+    /// let mut res_mul = Polynomial::allocate(0 as u32, PolynomialSize(128));
+    /// res_kara.fill_with_karatsuba_mul_in_ring(&lhs, &rhs, RingStructure::Cyclic);
+    /// res_mul.fill_with_wrapping_mul_in_ring(&lhs, &rhs, RingStructure::Cyclic);
+    /// assert_eq!(res_kara, res_mul);
+    /// ```
+    pub fn fill_with_karatsuba_mul_in_ring<Coef, LhsCont, RhsCont>(
+        &mut self,
+        p: &Polynomial<LhsCont>,
+        q: &Polynomial<RhsCont>,
+        ring: RingStructure,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        Polynomial<LhsCont>: AsRefTensor<Element = Coef>,
+        Polynomial<RhsCont>: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger,
     {
         // check same dimensions
         ck_dim_eq!(self.polynomial_size() => p.polynomial_size(), q.polynomial_size());
 
-        // check dimensions are a power of 2
-        debug_assert!(
-            f64::abs(
-                f64::floor(f64::log2(p.polynomial_size().0 as f64))
-                    - f64::log2(p.polynomial_size().0 as f64)
-            ) < f64::EPSILON
-        );
-
         let poly_size = self.polynomial_size().0;
 
+        // the negacyclic splitting below needs a power of 2, whose halves are large enough to
+        // stop the induction
+        if !ring.is_negacyclic() || !poly_size.is_power_of_two() || poly_size < 2 * KARATUSBA_STOP {
+            let padded_size = poly_size.next_power_of_two().max(KARATUSBA_STOP);
+            let mut padded_p = Tensor::allocate(Coef::ZERO, padded_size);
+            let mut padded_q = Tensor::allocate(Coef::ZERO, padded_size);
+            padded_p
+                .get_sub_mut(0..poly_size)
+                .fill_with_copy(p.as_tensor());
+            padded_q
+                .get_sub_mut(0..poly_size)
+                .fill_with_copy(q.as_tensor());
+
+            // the full product has a degree lower than 2N - 1
+            let mut product = Tensor::allocate(Coef::ZERO, 2 * padded_size);
+            induction_karatsuba(
+                &mut product.get_sub_mut(..),
+                &padded_p.get_sub(..),
+                &padded_q.get_sub(..),
+            );
+            let (low, high) = (
+                product.get_sub(0..poly_size),
+                product.get_sub(poly_size..(2 * poly_size)),
+            );
+            match ring {
+                RingStructure::Negacyclic => {
+                    self.as_mut_tensor().fill_with_wrapping_sub(&low, &high)
+                }
+                RingStructure::Cyclic => self.as_mut_tensor().fill_with_wrapping_add(&low, &high),
+            }
+            return;
+        }
+
         // allocate slices for the rec
         let mut a0 = Tensor::allocate(Coef::ZERO, poly_size);
         let mut a1 = Tensor::allocate(Coef::ZERO, poly_size);
@@ -518,30 +646,62 @@ impl<Cont> Polynomial<Cont> {
         Polynomial<Cont1>: AsRefTensor<Element = Coef>,
         Polynomial<Cont2>: AsRefTensor<Element = Coef>,
         Coef: UnsignedInteger,
+    {
+        self.update_with_wrapping_add_mul_in_ring(
+            polynomial,
+            bin_polynomial,
+            RingStructure::Negacyclic,
+        );
+    }
+
+    /// Adds the result of the product between two integer polynomials, reduced modulo $(X^N+1)$
+    /// or $(X^N-1)$ depending on the ring structure, to the current polynomial.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::RingStructure;
+    /// use concrete_core::commons::math::polynomial::{MonomialDegree, Polynomial};
+    /// let poly_1 = Polynomial::from_container(vec![1_u8, 2, 3]);
+    /// let poly_2 = Polynomial::from_container(vec![0, 1, 1]);
+    /// let mut res = Polynomial::from_container(vec![1, 0, 253]);
+    /// res.update_with_wrapping_add_mul_in_ring(&poly_1, &poly_2, RingStructure::Cyclic);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(0)).get_coefficient(), 6);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(1)).get_coefficient(), 4);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(2)).get_coefficient(), 0);
+    /// ```
+    pub fn update_with_wrapping_add_mul_in_ring<Coef, Cont1, Cont2>(
+        &mut self,
+        polynomial: &Polynomial<Cont1>,
+        bin_polynomial: &Polynomial<Cont2>,
+        ring: RingStructure,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        Polynomial<Cont1>: AsRefTensor<Element = Coef>,
+        Polynomial<Cont2>: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger,
     {
         ck_dim_eq!(
             self.polynomial_size() =>
             polynomial.polynomial_size(),
             bin_polynomial.polynomial_size()
         );
-        let degree = polynomial.polynomial_size().0 - 1;
-        for lhsi in polynomial.monomial_iter() {
-            for rhsi in bin_polynomial.monomial_iter() {
-                let target_degree = lhsi.degree().0 + rhsi.degree().0;
-                if target_degree <= degree {
-                    let update = self
-                        .as_tensor()
-                        .get_element(target_degree)
-                        .wrapping_add(*lhsi.get_coefficient() * *rhsi.get_coefficient());
-                    *self.as_mut_tensor().get_element_mut(target_degree) = update;
-                } else {
-                    let update = self
-                        .as_tensor()
-                        .get_element(target_degree % (degree + 1))
-                        .wrapping_sub(*lhsi.get_coefficient() * *rhsi.get_coefficient());
-                    *self
-                        .as_mut_tensor()
-                        .get_element_mut(target_degree % (degree + 1)) = update;
+        // The product of the coefficient of degree i with the coefficients of degree j < N - i
+        // contributes to the coefficients of degree i + j, and the product with the coefficients of
+        // degree j >= N - i wraps around to the coefficients of degree i + j - N.
+        let poly_size = polynomial.polynomial_size().0;
+        let output = self.as_mut_tensor().as_mut_slice();
+        let rhs = bin_polynomial.as_tensor().as_slice();
+        for (degree, coefficient) in polynomial.as_tensor().iter().enumerate() {
+            let (wrapped_output, output) = output.split_at_mut(degree);
+            let (rhs, wrapped_rhs) = rhs.split_at(poly_size - degree);
+            simd::wrapping_add_element_mul_assign(output, rhs, *coefficient);
+            match ring {
+                RingStructure::Negacyclic => {
+                    simd::wrapping_sub_element_mul_assign(wrapped_output, wrapped_rhs, *coefficient)
+                }
+                RingStructure::Cyclic => {
+                    simd::wrapping_add_element_mul_assign(wrapped_output, wrapped_rhs, *coefficient)
                 }
             }
         }
@@ -571,32 +731,60 @@ impl<Cont> Polynomial<Cont> {
         Polynomial<PolyCont>: AsRefTensor<Element = Coef>,
         Polynomial<BinCont>: AsRefTensor<Element = Coef>,
         Coef: UnsignedInteger,
+    {
+        self.update_with_wrapping_sub_mul_in_ring(
+            polynomial,
+            bin_polynomial,
+            RingStructure::Negacyclic,
+        );
+    }
+
+    /// Subtracts the result of the product between two integer polynomials, reduced modulo
+    /// $(X^N+1)$ or $(X^N-1)$ depending on the ring structure, to the current polynomial.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::RingStructure;
+    /// use concrete_core::commons::math::polynomial::{MonomialDegree, Polynomial};
+    /// let poly = Polynomial::from_container(vec![1_u8, 2, 3]);
+    /// let bin_poly = Polynomial::from_container(vec![0, 1, 1]);
+    /// let mut res = Polynomial::from_container(vec![255, 255, 1]);
+    /// res.update_with_wrapping_sub_mul_in_ring(&poly, &bin_poly, RingStructure::Cyclic);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(0)).get_coefficient(), 250);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(1)).get_coefficient(), 251);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(2)).get_coefficient(), 254);
+    /// ```
+    pub fn update_with_wrapping_sub_mul_in_ring<Coef, PolyCont, BinCont>(
+        &mut self,
+        polynomial: &Polynomial<PolyCont>,
+        bin_polynomial: &Polynomial<BinCont>,
+        ring: RingStructure,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        Polynomial<PolyCont>: AsRefTensor<Element = Coef>,
+        Polynomial<BinCont>: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger,
     {
         ck_dim_eq!(
             self.polynomial_size() =>
             polynomial.polynomial_size(),
             bin_polynomial.polynomial_size()
         );
-        let degree = polynomial.polynomial_size().0 - 1;
-        for lhsi in polynomial.monomial_iter() {
-            for rhsi in bin_polynomial.monomial_iter() {
-                let target_degree = lhsi.degree().0 + rhsi.degree().0;
-                if target_degree <= degree {
-                    let update = self
-                        .as_tensor()
-                        .get_element(target_degree)
-                        .wrapping_sub(*lhsi.get_coefficient() * *rhsi.get_coefficient());
-                    *self.as_mut_tensor().get_element_mut(target_degree) = update;
-                } else {
-                    let update = self
-                        .as_tensor()
-                        .get_element(target_degree % (degree + 1))
-                        .wrapping_add(*lhsi.get_coefficient() * *rhsi.get_coefficient());
-                    *self
-                        .as_mut_tensor()
-                        .as_mut_slice()
-                        .get_mut(target_degree % (degree + 1))
-                        .unwrap() = update;
+        // See `update_with_wrapping_add_mul_in_ring` for the split of the products.
+        let poly_size = polynomial.polynomial_size().0;
+        let output = self.as_mut_tensor().as_mut_slice();
+        let rhs = bin_polynomial.as_tensor().as_slice();
+        for (degree, coefficient) in polynomial.as_tensor().iter().enumerate() {
+            let (wrapped_output, output) = output.split_at_mut(degree);
+            let (rhs, wrapped_rhs) = rhs.split_at(poly_size - degree);
+            simd::wrapping_sub_element_mul_assign(output, rhs, *coefficient);
+            match ring {
+                RingStructure::Negacyclic => {
+                    simd::wrapping_add_element_mul_assign(wrapped_output, wrapped_rhs, *coefficient)
+                }
+                RingStructure::Cyclic => {
+                    simd::wrapping_sub_element_mul_assign(wrapped_output, wrapped_rhs, *coefficient)
                 }
             }
         }
@@ -683,6 +871,45 @@ impl<Cont> Polynomial<Cont> {
         Self: AsMutTensor<Element = Coef>,
         Coef: UnsignedInteger,
     {
+        self.update_with_wrapping_monic_monomial_mul_in_ring(
+            monomial_degree,
+            RingStructure::Negacyclic,
+        );
+    }
+
+    /// Multiplies (mod $(X^N+1)$ or $(X^N-1)$ depending on the ring structure), the current
+    /// polynomial with a monomial of a given degree, and a coefficient of one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use concrete_commons::parameters::RingStructure;
+    /// use concrete_core::commons::math::polynomial::{MonomialDegree, Polynomial};
+    /// let mut poly = Polynomial::from_container(vec![1u8, 2, 3]);
+    /// poly.update_with_wrapping_monic_monomial_mul_in_ring(
+    ///     MonomialDegree(2),
+    ///     RingStructure::Cyclic,
+    /// );
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(0)).get_coefficient(), 2);
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(1)).get_coefficient(), 3);
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(2)).get_coefficient(), 1);
+    /// ```
+    pub fn update_with_wrapping_monic_monomial_mul_in_ring<Coef>(
+        &mut self,
+        monomial_degree: MonomialDegree,
+        ring: RingStructure,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        let remaining_degree = monomial_degree.0 % self.as_tensor().len();
+        self.as_mut_tensor()
+            .as_mut_slice()
+            .rotate_right(remaining_degree);
+        // In the cyclic ring, X^N = 1 and the monomial only rotates the coefficients.
+        if !ring.is_negacyclic() {
+            return;
+        }
         let full_cycles_count = monomial_degree.0 / self.as_tensor().len();
         if full_cycles_count % 2 != 0 {
             self.as_mut_tensor()
@@ -690,10 +917,6 @@ impl<Cont> Polynomial<Cont> {
                 .iter_mut()
                 .for_each(|a| *a = a.wrapping_neg());
         }
-        let remaining_degree = monomial_degree.0 % self.as_tensor().len();
-        self.as_mut_tensor()
-            .as_mut_slice()
-            .rotate_right(remaining_degree);
         self.as_mut_tensor()
             .as_mut_slice()
             .iter_mut()
@@ -719,6 +942,45 @@ impl<Cont> Polynomial<Cont> {
         Self: AsMutTensor<Element = Coef>,
         Coef: UnsignedInteger,
     {
+        self.update_with_wrapping_unit_monomial_div_in_ring(
+            monomial_degree,
+            RingStructure::Negacyclic,
+        );
+    }
+
+    /// Divides (mod $(X^N+1)$ or $(X^N-1)$ depending on the ring structure), the current
+    /// polynomial by a monomial of a given degree, and a coefficient of one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use concrete_commons::parameters::RingStructure;
+    /// use concrete_core::commons::math::polynomial::{MonomialDegree, Polynomial};
+    /// let mut poly = Polynomial::from_container(vec![1u8, 2, 3]);
+    /// poly.update_with_wrapping_unit_monomial_div_in_ring(
+    ///     MonomialDegree(2),
+    ///     RingStructure::Cyclic,
+    /// );
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(0)).get_coefficient(), 3);
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(1)).get_coefficient(), 1);
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(2)).get_coefficient(), 2);
+    /// ```
+    pub fn update_with_wrapping_unit_monomial_div_in_ring<Coef>(
+        &mut self,
+        monomial_degree: MonomialDegree,
+        ring: RingStructure,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        let remaining_degree = monomial_degree.0 % self.as_tensor().len();
+        self.as_mut_tensor()
+            .as_mut_slice()
+            .rotate_left(remaining_degree);
+        // In the cyclic ring, X^N = 1 and the monomial only rotates the coefficients.
+        if !ring.is_negacyclic() {
+            return;
+        }
         let full_cycles_count = monomial_degree.0 / self.as_tensor().len();
         if full_cycles_count % 2 != 0 {
             self.as_mut_tensor()
@@ -726,10 +988,6 @@ impl<Cont> Polynomial<Cont> {
                 .iter_mut()
                 .for_each(|a| *a = a.wrapping_neg());
         }
-        let remaining_degree = monomial_degree.0 % self.as_tensor().len();
-        self.as_mut_tensor()
-            .as_mut_slice()
-            .rotate_left(remaining_degree);
         self.as_mut_tensor()
             .as_mut_slice()
             .iter_mut()
@@ -0,0 +1,294 @@
+use crate::commons::math::simd;
+use crate::commons::math::tensor::{
+    ck_dim_eq, tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+};
+
+use super::*;
+use concrete_commons::numeric::UnsignedInteger;
+use concrete_commons::parameters::{MonomialCount, MonomialDegree, PolynomialSize};
+#[cfg(feature = "__commons_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A sparse polynomial.
+///
+/// This type represents a polynomial in $\mathbb{Z}\_{2^q}\[X\] / <X^N + 1>$ with only a few
+/// nonzero coefficients. It is stored as a list of coefficients, along with the degrees of the
+/// associated monomials.
+///
+/// # Example:
+///
+/// ```
+/// use concrete_commons::parameters::{MonomialCount, PolynomialSize};
+/// use concrete_core::commons::math::polynomial::{MonomialDegree, SparsePolynomial};
+/// let poly = SparsePolynomial::from_container(
+///     vec![3u32, 5],
+///     vec![MonomialDegree(0), MonomialDegree(7)],
+///     PolynomialSize(16),
+/// );
+/// assert_eq!(poly.polynomial_size(), PolynomialSize(16));
+/// assert_eq!(poly.monomial_count(), MonomialCount(2));
+/// ```
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct SparsePolynomial<Cont> {
+    tensor: Tensor<Cont>,
+    degrees: Vec<MonomialDegree>,
+    poly_size: PolynomialSize,
+}
+
+tensor_traits!(SparsePolynomial);
+
+impl<Coef> SparsePolynomial<Vec<Coef>> {
+    /// Creates a sparse polynomial holding the nonzero coefficients of a dense polynomial.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{MonomialCount, PolynomialSize};
+    /// use concrete_core::commons::math::polynomial::{Polynomial, SparsePolynomial};
+    /// let dense = Polynomial::from_container(vec![0u8, 2, 0, 0, 5, 0]);
+    /// let sparse = SparsePolynomial::from_polynomial(&dense);
+    /// assert_eq!(sparse.polynomial_size(), PolynomialSize(6));
+    /// assert_eq!(sparse.monomial_count(), MonomialCount(2));
+    /// ```
+    pub fn from_polynomial<Cont>(polynomial: &Polynomial<Cont>) -> Self
+    where
+        Polynomial<Cont>: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        let (degrees, coefficients): (Vec<_>, Vec<_>) = polynomial
+            .coefficient_iter()
+            .enumerate()
+            .filter(|(_, coefficient)| **coefficient != Coef::ZERO)
+            .map(|(degree, coefficient)| (MonomialDegree(degree), *coefficient))
+            .unzip();
+        SparsePolynomial {
+            tensor: Tensor::from_container(coefficients),
+            degrees,
+            poly_size: polynomial.polynomial_size(),
+        }
+    }
+}
+
+impl<Cont> SparsePolynomial<Cont> {
+    /// Creates a sparse polynomial from a container of coefficients, the degrees of the associated
+    /// monomials, and the size of the dense polynomials it operates on.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{MonomialCount, PolynomialSize};
+    /// use concrete_core::commons::math::polynomial::{MonomialDegree, SparsePolynomial};
+    /// let vec = vec![1u8, 1];
+    /// let poly = SparsePolynomial::from_container(
+    ///     vec.as_slice(),
+    ///     vec![MonomialDegree(1), MonomialDegree(3)],
+    ///     PolynomialSize(4),
+    /// );
+    /// assert_eq!(poly.monomial_count(), MonomialCount(2));
+    /// ```
+    pub fn from_container(
+        cont: Cont,
+        degrees: Vec<MonomialDegree>,
+        poly_size: PolynomialSize,
+    ) -> SparsePolynomial<Cont>
+    where
+        Cont: AsRefSlice,
+    {
+        debug_assert!(
+            cont.as_slice().len() == degrees.len(),
+            "Tried to create a sparse polynomial with a different number of coefficients and \
+            degrees"
+        );
+        debug_assert!(
+            degrees.iter().all(|degree| degree.0 < poly_size.0),
+            "Tried to create a sparse polynomial with a degree larger than the polynomial size"
+        );
+        SparsePolynomial {
+            tensor: Tensor::from_container(cont),
+            degrees,
+            poly_size,
+        }
+    }
+
+    /// Returns the size of the dense polynomials the sparse polynomial operates on.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::PolynomialSize;
+    /// use concrete_core::commons::math::polynomial::{MonomialDegree, SparsePolynomial};
+    /// let poly = SparsePolynomial::from_container(
+    ///     vec![1u8],
+    ///     vec![MonomialDegree(3)],
+    ///     PolynomialSize(4),
+    /// );
+    /// assert_eq!(poly.polynomial_size(), PolynomialSize(4));
+    /// ```
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.poly_size
+    }
+
+    /// Returns the number of monomials stored in the sparse polynomial.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{MonomialCount, PolynomialSize};
+    /// use concrete_core::commons::math::polynomial::{MonomialDegree, SparsePolynomial};
+    /// let poly = SparsePolynomial::from_container(
+    ///     vec![1u8],
+    ///     vec![MonomialDegree(3)],
+    ///     PolynomialSize(4),
+    /// );
+    /// assert_eq!(poly.monomial_count(), MonomialCount(1));
+    /// ```
+    pub fn monomial_count(&self) -> MonomialCount {
+        MonomialCount(self.degrees.len())
+    }
+
+    /// Returns an iterator over borrowed monomials.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::PolynomialSize;
+    /// use concrete_core::commons::math::polynomial::{MonomialDegree, SparsePolynomial};
+    /// let poly = SparsePolynomial::from_container(
+    ///     vec![1u8, 2],
+    ///     vec![MonomialDegree(1), MonomialDegree(3)],
+    ///     PolynomialSize(4),
+    /// );
+    /// for monomial in poly.monomial_iter() {
+    ///     assert!(monomial.degree() == MonomialDegree(1) || monomial.degree() == MonomialDegree(3));
+    /// }
+    /// assert_eq!(poly.monomial_iter().count(), 2);
+    /// ```
+    pub fn monomial_iter(&self) -> impl Iterator<Item = Monomial<&[<Self as AsRefTensor>::Element]>>
+    where
+        Self: AsRefTensor,
+    {
+        self.as_tensor()
+            .subtensor_iter(1)
+            .zip(self.degrees.iter())
+            .map(|(tensor, degree)| Monomial::from_container(tensor.into_container(), *degree))
+    }
+
+    /// Returns the dense polynomial equal to the sparse polynomial.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::PolynomialSize;
+    /// use concrete_core::commons::math::polynomial::{MonomialDegree, Polynomial, SparsePolynomial};
+    /// let poly = SparsePolynomial::from_container(
+    ///     vec![1u8, 2],
+    ///     vec![MonomialDegree(1), MonomialDegree(3)],
+    ///     PolynomialSize(4),
+    /// );
+    /// assert_eq!(poly.to_polynomial(), Polynomial::from_container(vec![0, 1, 0, 2]));
+    /// ```
+    pub fn to_polynomial<Coef>(&self) -> Polynomial<Vec<Coef>>
+    where
+        Self: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        let mut polynomial = Polynomial::allocate(Coef::ZERO, self.poly_size);
+        for monomial in self.monomial_iter() {
+            let mut dense_monomial = polynomial.get_mut_monomial(monomial.degree());
+            let coefficient = dense_monomial.get_mut_coefficient();
+            *coefficient = coefficient.wrapping_add(*monomial.get_coefficient());
+        }
+        polynomial
+    }
+}
+
+impl<Cont> Polynomial<Cont> {
+    /// Adds the product between an integer polynomial and a sparse polynomial, reduced modulo
+    /// $(X^N+1)$, to the current polynomial.
+    ///
+    /// The product is computed as a sum of negacyclic rotations of the dense polynomial, which is
+    /// much faster than a dense multiplication when the sparse polynomial has few monomials.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::PolynomialSize;
+    /// use concrete_core::commons::math::polynomial::{MonomialDegree, Polynomial, SparsePolynomial};
+    /// let poly = Polynomial::from_container(vec![1_u8, 2, 3]);
+    /// let sparse = SparsePolynomial::from_container(
+    ///     vec![1, 2],
+    ///     vec![MonomialDegree(0), MonomialDegree(2)],
+    ///     PolynomialSize(3),
+    /// );
+    /// let mut res = Polynomial::from_container(vec![1, 0, 0]);
+    /// res.update_with_wrapping_add_sparse_mul(&poly, &sparse);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(0)).get_coefficient(), 254);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(1)).get_coefficient(), 252);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(2)).get_coefficient(), 5);
+    /// ```
+    pub fn update_with_wrapping_add_sparse_mul<Coef, PolyCont, SparseCont>(
+        &mut self,
+        polynomial: &Polynomial<PolyCont>,
+        sparse_polynomial: &SparsePolynomial<SparseCont>,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        Polynomial<PolyCont>: AsRefTensor<Element = Coef>,
+        SparsePolynomial<SparseCont>: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        ck_dim_eq!(
+            self.polynomial_size() =>
+            polynomial.polynomial_size(),
+            sparse_polynomial.polynomial_size()
+        );
+        // The monomial of degree d sends the coefficients of degree j < N - d to the coefficients
+        // of degree j + d, and the coefficients of degree j >= N - d to the coefficients of
+        // degree j + d - N, with a sign change.
+        let poly_size = polynomial.polynomial_size().0;
+        let output = self.as_mut_tensor().as_mut_slice();
+        let input = polynomial.as_tensor().as_slice();
+        for monomial in sparse_polynomial.monomial_iter() {
+            let degree = monomial.degree().0;
+            let coefficient = *monomial.get_coefficient();
+            let (wrapped_output, output) = output.split_at_mut(degree);
+            let (input, wrapped_input) = input.split_at(poly_size - degree);
+            simd::wrapping_add_element_mul_assign(output, input, coefficient);
+            simd::wrapping_sub_element_mul_assign(wrapped_output, wrapped_input, coefficient);
+        }
+    }
+
+    /// Fills the current polynomial with the product between an integer polynomial and a sparse
+    /// polynomial, reduced modulo $(X^N+1)$.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::PolynomialSize;
+    /// use concrete_core::commons::math::polynomial::{MonomialDegree, Polynomial, SparsePolynomial};
+    /// let poly = Polynomial::from_container(vec![1_u8, 2, 3]);
+    /// let sparse = SparsePolynomial::from_container(
+    ///     vec![1],
+    ///     vec![MonomialDegree(1)],
+    ///     PolynomialSize(3),
+    /// );
+    /// let mut res = Polynomial::allocate(0, PolynomialSize(3));
+    /// res.fill_with_wrapping_sparse_mul(&poly, &sparse);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(0)).get_coefficient(), 253);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(1)).get_coefficient(), 1);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(2)).get_coefficient(), 2);
+    /// ```
+    pub fn fill_with_wrapping_sparse_mul<Coef, PolyCont, SparseCont>(
+        &mut self,
+        polynomial: &Polynomial<PolyCont>,
+        sparse_polynomial: &SparsePolynomial<SparseCont>,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        Polynomial<PolyCont>: AsRefTensor<Element = Coef>,
+        SparsePolynomial<SparseCont>: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        self.as_mut_tensor().fill_with_element(Coef::ZERO);
+        self.update_with_wrapping_add_sparse_mul(polynomial, sparse_polynomial);
+    }
+}
@@ -1,8 +1,8 @@
 use rand::Rng;
 
-use concrete_commons::parameters::{MonomialDegree, PolynomialSize};
+use concrete_commons::parameters::{MonomialDegree, PolynomialSize, RingStructure};
 
-use crate::commons::math::polynomial::Polynomial;
+use crate::commons::math::polynomial::{Polynomial, SparsePolynomial};
 use crate::commons::math::torus::UnsignedTorus;
 use crate::commons::test_tools::*;
 
@@ -113,3 +113,163 @@ pub fn test_multiply_karatsuba_u32() {
 pub fn test_multiply_karatsuba_u64() {
     test_multiply_karatsuba::<u64>()
 }
+
+/// test if we have the same result when using schoolbook or karatsuba
+/// for random polynomial multiplication, with sizes which are not powers of two
+fn test_multiply_karatsuba_any_size<T: UnsignedTorus>() {
+    // 50 times the test
+    for _i in 0..50 {
+        // random source
+        let mut rng = rand::thread_rng();
+
+        // random settings settings
+        let polynomial_size = PolynomialSize((rng.gen::<usize>() % 1000) + 1);
+        let mut generator = new_random_generator();
+
+        // generates two random Torus polynomials
+        let poly_1 = Polynomial::from_container(
+            generator
+                .random_uniform_tensor::<T>(polynomial_size.0)
+                .into_container(),
+        );
+        let poly_2 = Polynomial::from_container(
+            generator
+                .random_uniform_tensor::<T>(polynomial_size.0)
+                .into_container(),
+        );
+
+        let mut sb_mul = Polynomial::allocate(T::ZERO, polynomial_size);
+        let mut ka_mul = Polynomial::allocate(T::ZERO, polynomial_size);
+
+        // compute the schoolbook
+        sb_mul.fill_with_wrapping_mul(&poly_1, &poly_2);
+
+        // compute the karatsuba
+        ka_mul.fill_with_karatsuba_mul(&poly_1, &poly_2);
+
+        // test
+        assert_eq!(&sb_mul, &ka_mul);
+    }
+}
+
+#[test]
+pub fn test_multiply_karatsuba_any_size_u32() {
+    test_multiply_karatsuba_any_size::<u32>()
+}
+
+#[test]
+pub fn test_multiply_karatsuba_any_size_u64() {
+    test_multiply_karatsuba_any_size::<u64>()
+}
+
+/// test if we have the same result when using schoolbook or karatsuba for random polynomial
+/// multiplication in the cyclic ring, and if the monomial multiplication matches the product with
+/// a monomial polynomial
+fn test_multiply_cyclic<T: UnsignedTorus>() {
+    // 50 times the test
+    for _i in 0..50 {
+        // random source
+        let mut rng = rand::thread_rng();
+
+        // random settings settings
+        let polynomial_size = PolynomialSize((rng.gen::<usize>() % 1000) + 1);
+        let mut generator = new_random_generator();
+
+        // generates two random Torus polynomials
+        let poly_1 = Polynomial::from_container(
+            generator
+                .random_uniform_tensor::<T>(polynomial_size.0)
+                .into_container(),
+        );
+        let poly_2 = Polynomial::from_container(
+            generator
+                .random_uniform_tensor::<T>(polynomial_size.0)
+                .into_container(),
+        );
+
+        let mut sb_mul = Polynomial::allocate(T::ZERO, polynomial_size);
+        let mut ka_mul = Polynomial::allocate(T::ZERO, polynomial_size);
+        sb_mul.fill_with_wrapping_mul_in_ring(&poly_1, &poly_2, RingStructure::Cyclic);
+        ka_mul.fill_with_karatsuba_mul_in_ring(&poly_1, &poly_2, RingStructure::Cyclic);
+        assert_eq!(&sb_mul, &ka_mul);
+
+        // multiplies by X^r, either as a monomial or as a polynomial
+        let r = rng.gen::<usize>() % (4 * polynomial_size.0);
+        let mut monomial = Polynomial::allocate(T::ZERO, polynomial_size);
+        *monomial
+            .get_mut_monomial(MonomialDegree(r % polynomial_size.0))
+            .get_mut_coefficient() = T::ONE;
+        let mut poly_mul = Polynomial::allocate(T::ZERO, polynomial_size);
+        poly_mul.fill_with_wrapping_mul_in_ring(&poly_1, &monomial, RingStructure::Cyclic);
+        let mut monomial_mul = poly_1.clone();
+        monomial_mul.update_with_wrapping_monic_monomial_mul_in_ring(
+            MonomialDegree(r),
+            RingStructure::Cyclic,
+        );
+        assert_eq!(&poly_mul, &monomial_mul);
+
+        // divides by X^r
+        monomial_mul.update_with_wrapping_unit_monomial_div_in_ring(
+            MonomialDegree(r),
+            RingStructure::Cyclic,
+        );
+        assert_eq!(&poly_1, &monomial_mul);
+    }
+}
+
+#[test]
+pub fn test_multiply_cyclic_u32() {
+    test_multiply_cyclic::<u32>()
+}
+
+#[test]
+pub fn test_multiply_cyclic_u64() {
+    test_multiply_cyclic::<u64>()
+}
+
+/// test if we have the same result when multiplying a random polynomial by a random sparse
+/// polynomial, and by the equivalent dense polynomial
+fn test_multiply_sparse<T: UnsignedTorus>() {
+    // 50 times the test
+    for _i in 0..50 {
+        // random source
+        let mut rng = rand::thread_rng();
+
+        // random settings settings
+        let polynomial_size = PolynomialSize((rng.gen::<usize>() % 1000) + 1);
+        let monomial_count = rng.gen::<usize>() % 10;
+        let mut generator = new_random_generator();
+
+        // generates a random Torus polynomial and a random sparse polynomial
+        let poly = Polynomial::from_container(
+            generator
+                .random_uniform_tensor::<T>(polynomial_size.0)
+                .into_container(),
+        );
+        let sparse = SparsePolynomial::from_container(
+            generator
+                .random_uniform_tensor::<T>(monomial_count)
+                .into_container(),
+            (0..monomial_count)
+                .map(|_| MonomialDegree(rng.gen::<usize>() % polynomial_size.0))
+                .collect(),
+            polynomial_size,
+        );
+
+        let mut sb_mul = Polynomial::allocate(T::ZERO, polynomial_size);
+        let mut sparse_mul = Polynomial::allocate(T::ZERO, polynomial_size);
+        sb_mul.fill_with_wrapping_mul(&poly, &sparse.to_polynomial());
+        sparse_mul.fill_with_wrapping_sparse_mul(&poly, &sparse);
+        assert_eq!(&sb_mul, &sparse_mul);
+    }
+}
+
+#[test]
+pub fn test_multiply_sparse_u32() {
+    test_multiply_sparse::<u32>()
+}
+
+#[test]
+pub fn test_multiply_sparse_u64() {
+    test_multiply_sparse::<u64>()
+}
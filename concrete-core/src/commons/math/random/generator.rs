@@ -136,6 +136,28 @@ impl<G: ByteRandomGenerator> RandomGenerator<G> {
             .map(|iter| iter.map(Self))
     }
 
+    /// Skips the next `n_bytes` bytes of the stream, without generating them. This fails if the
+    /// generator is bounded and can not output `n_bytes` bytes anymore.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::math::random::RandomGenerator;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// let mut generator = RandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut skipping_generator = RandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// generator.generate_next();
+    /// skipping_generator.skip_bytes(1).unwrap();
+    /// assert_eq!(generator.generate_next(), skipping_generator.generate_next());
+    /// ```
+    pub fn skip_bytes(&mut self, n_bytes: usize) -> Result<(), ForkError> {
+        if n_bytes == 0 {
+            return Ok(());
+        }
+        self.try_fork(1, n_bytes).map(|_| ())
+    }
+
     /// Generates a random uniform unsigned integer.
     ///
     /// # Example
@@ -0,0 +1,346 @@
+//! AVX-512 implementations of the slice kernels.
+//!
+//! Each function of this module returns `true` if it processed its inputs, and `false` if the
+//! scalar type is not supported, or if the CPU lacks the needed target features, in which case
+//! the caller is expected to fall back to the portable implementation.
+use std::any::TypeId;
+use std::arch::x86_64::*;
+
+/// Returns whether the target features needed by the kernels are available.
+#[inline]
+fn is_available() -> bool {
+    is_x86_feature_detected!("avx512f") && is_x86_feature_detected!("avx512dq")
+}
+
+/// Returns the input slice cast to `To`, if `From` and `To` are the same type.
+#[inline]
+fn cast_slice<From: 'static, To: 'static>(slice: &[From]) -> Option<&[To]> {
+    (TypeId::of::<From>() == TypeId::of::<To>()).then(|| {
+        // SAFETY: `From` and `To` are the same type.
+        unsafe { std::slice::from_raw_parts(slice.as_ptr() as *const To, slice.len()) }
+    })
+}
+
+/// Returns the input mutable slice cast to `To`, if `From` and `To` are the same type.
+#[inline]
+fn cast_slice_mut<From: 'static, To: 'static>(slice: &mut [From]) -> Option<&mut [To]> {
+    (TypeId::of::<From>() == TypeId::of::<To>()).then(|| {
+        // SAFETY: `From` and `To` are the same type.
+        unsafe { std::slice::from_raw_parts_mut(slice.as_mut_ptr() as *mut To, slice.len()) }
+    })
+}
+
+/// Returns the input value cast to `To`, if `From` and `To` are the same type.
+#[inline]
+fn cast_value<From: Copy + 'static, To: Copy + 'static>(value: From) -> Option<To> {
+    cast_slice(std::slice::from_ref(&value)).map(|value| value[0])
+}
+
+// Dispatches a kernel to its `u32` or `u64` instance, casting the slice and value arguments.
+macro_rules! dispatch {
+    (
+        $kernel: ident,
+        [$($slice_mut: ident),*],
+        [$($slice: ident),*],
+        [$($value: ident),*],
+        [$($arg: ident),*] $(,)?
+    ) => {{
+        if !is_available() {
+            return false;
+        }
+        if let ($(Some($slice_mut),)* $(Some($slice),)* $(Some($value),)*) = (
+            $(cast_slice_mut::<_, u64>($slice_mut),)*
+            $(cast_slice::<_, u64>($slice),)*
+            $(cast_value::<_, u64>($value),)*
+        ) {
+            // SAFETY: the availability of the target features was checked above.
+            unsafe { lanes_u64::$kernel($($slice_mut,)* $($slice,)* $($value,)* $($arg,)*) };
+            return true;
+        }
+        if let ($(Some($slice_mut),)* $(Some($slice),)* $(Some($value),)*) = (
+            $(cast_slice_mut::<_, u32>($slice_mut),)*
+            $(cast_slice::<_, u32>($slice),)*
+            $(cast_value::<_, u32>($value),)*
+        ) {
+            // SAFETY: the availability of the target features was checked above.
+            unsafe { lanes_u32::$kernel($($slice_mut,)* $($slice,)* $($value,)* $($arg,)*) };
+            return true;
+        }
+        false
+    }};
+}
+
+pub(super) fn wrapping_add_assign<Scalar: 'static>(lhs: &mut [Scalar], rhs: &[Scalar]) -> bool {
+    dispatch!(wrapping_add_assign, [lhs], [rhs], [], [])
+}
+
+pub(super) fn wrapping_sub_assign<Scalar: 'static>(lhs: &mut [Scalar], rhs: &[Scalar]) -> bool {
+    dispatch!(wrapping_sub_assign, [lhs], [rhs], [], [])
+}
+
+pub(super) fn wrapping_add_element_mul_assign<Scalar: Copy + 'static>(
+    lhs: &mut [Scalar],
+    rhs: &[Scalar],
+    element: Scalar,
+) -> bool {
+    dispatch!(wrapping_add_element_mul_assign, [lhs], [rhs], [element], [])
+}
+
+pub(super) fn wrapping_sub_element_mul_assign<Scalar: Copy + 'static>(
+    lhs: &mut [Scalar],
+    rhs: &[Scalar],
+    element: Scalar,
+) -> bool {
+    dispatch!(wrapping_sub_element_mul_assign, [lhs], [rhs], [element], [])
+}
+
+pub(super) fn fill_with_closest_representable<Scalar: 'static>(
+    output: &mut [Scalar],
+    input: &[Scalar],
+    non_rep_bit_count: usize,
+) -> bool {
+    dispatch!(
+        fill_with_closest_representable,
+        [output],
+        [input],
+        [],
+        [non_rep_bit_count]
+    )
+}
+
+pub(super) fn decompose_one_level_in_place<Scalar: Copy + 'static>(
+    outputs: &mut [Scalar],
+    states: &mut [Scalar],
+    base_log: usize,
+    mod_b_mask: Scalar,
+) -> bool {
+    dispatch!(
+        decompose_one_level_in_place,
+        [outputs, states],
+        [],
+        [mod_b_mask],
+        [base_log]
+    )
+}
+
+// Implements the kernels for a given scalar type, from the intrinsics operating on its lanes.
+macro_rules! lanes {
+    (
+        $module: ident,
+        $Scalar: ty,
+        set1 = $set1: ident as $Signed: ty,
+        add = $add: ident,
+        sub = $sub: ident,
+        mullo = $mullo: ident,
+        srl = $srl: ident,
+        sll = $sll: ident $(,)?
+    ) => {
+        mod $module {
+            use super::*;
+
+            // The number of values in a 512 bits register.
+            const LANES: usize = 64 / std::mem::size_of::<$Scalar>();
+
+            #[inline]
+            #[target_feature(enable = "avx512f,avx512dq")]
+            unsafe fn load(values: &[$Scalar]) -> __m512i {
+                debug_assert_eq!(values.len(), LANES);
+                _mm512_loadu_si512(values.as_ptr().cast())
+            }
+
+            #[inline]
+            #[target_feature(enable = "avx512f,avx512dq")]
+            unsafe fn store(values: &mut [$Scalar], vector: __m512i) {
+                debug_assert_eq!(values.len(), LANES);
+                _mm512_storeu_si512(values.as_mut_ptr().cast(), vector)
+            }
+
+            /// # Safety
+            ///
+            ///  - `is_x86_feature_detected!("avx512f")` must be true.
+            ///  - `is_x86_feature_detected!("avx512dq")` must be true.
+            #[target_feature(enable = "avx512f,avx512dq")]
+            pub(super) unsafe fn wrapping_add_assign(lhs: &mut [$Scalar], rhs: &[$Scalar]) {
+                let mut lhs_chunks = lhs.chunks_exact_mut(LANES);
+                let mut rhs_chunks = rhs.chunks_exact(LANES);
+                for (lhs, rhs) in (&mut lhs_chunks).zip(&mut rhs_chunks) {
+                    store(lhs, $add(load(lhs), load(rhs)));
+                }
+                for (lhs, rhs) in lhs_chunks
+                    .into_remainder()
+                    .iter_mut()
+                    .zip(rhs_chunks.remainder())
+                {
+                    *lhs = lhs.wrapping_add(*rhs);
+                }
+            }
+
+            /// # Safety
+            ///
+            ///  - `is_x86_feature_detected!("avx512f")` must be true.
+            ///  - `is_x86_feature_detected!("avx512dq")` must be true.
+            #[target_feature(enable = "avx512f,avx512dq")]
+            pub(super) unsafe fn wrapping_sub_assign(lhs: &mut [$Scalar], rhs: &[$Scalar]) {
+                let mut lhs_chunks = lhs.chunks_exact_mut(LANES);
+                let mut rhs_chunks = rhs.chunks_exact(LANES);
+                for (lhs, rhs) in (&mut lhs_chunks).zip(&mut rhs_chunks) {
+                    store(lhs, $sub(load(lhs), load(rhs)));
+                }
+                for (lhs, rhs) in lhs_chunks
+                    .into_remainder()
+                    .iter_mut()
+                    .zip(rhs_chunks.remainder())
+                {
+                    *lhs = lhs.wrapping_sub(*rhs);
+                }
+            }
+
+            /// # Safety
+            ///
+            ///  - `is_x86_feature_detected!("avx512f")` must be true.
+            ///  - `is_x86_feature_detected!("avx512dq")` must be true.
+            #[target_feature(enable = "avx512f,avx512dq")]
+            pub(super) unsafe fn wrapping_add_element_mul_assign(
+                lhs: &mut [$Scalar],
+                rhs: &[$Scalar],
+                element: $Scalar,
+            ) {
+                let element_vector = $set1(element as $Signed);
+                let mut lhs_chunks = lhs.chunks_exact_mut(LANES);
+                let mut rhs_chunks = rhs.chunks_exact(LANES);
+                for (lhs, rhs) in (&mut lhs_chunks).zip(&mut rhs_chunks) {
+                    store(lhs, $add(load(lhs), $mullo(load(rhs), element_vector)));
+                }
+                for (lhs, rhs) in lhs_chunks
+                    .into_remainder()
+                    .iter_mut()
+                    .zip(rhs_chunks.remainder())
+                {
+                    *lhs = lhs.wrapping_add(rhs.wrapping_mul(element));
+                }
+            }
+
+            /// # Safety
+            ///
+            ///  - `is_x86_feature_detected!("avx512f")` must be true.
+            ///  - `is_x86_feature_detected!("avx512dq")` must be true.
+            #[target_feature(enable = "avx512f,avx512dq")]
+            pub(super) unsafe fn wrapping_sub_element_mul_assign(
+                lhs: &mut [$Scalar],
+                rhs: &[$Scalar],
+                element: $Scalar,
+            ) {
+                let element_vector = $set1(element as $Signed);
+                let mut lhs_chunks = lhs.chunks_exact_mut(LANES);
+                let mut rhs_chunks = rhs.chunks_exact(LANES);
+                for (lhs, rhs) in (&mut lhs_chunks).zip(&mut rhs_chunks) {
+                    store(lhs, $sub(load(lhs), $mullo(load(rhs), element_vector)));
+                }
+                for (lhs, rhs) in lhs_chunks
+                    .into_remainder()
+                    .iter_mut()
+                    .zip(rhs_chunks.remainder())
+                {
+                    *lhs = lhs.wrapping_sub(rhs.wrapping_mul(element));
+                }
+            }
+
+            /// # Safety
+            ///
+            ///  - `is_x86_feature_detected!("avx512f")` must be true.
+            ///  - `is_x86_feature_detected!("avx512dq")` must be true.
+            ///  - `non_rep_bit_count` must be in `1..<$Scalar>::BITS`.
+            #[target_feature(enable = "avx512f,avx512dq")]
+            pub(super) unsafe fn fill_with_closest_representable(
+                output: &mut [$Scalar],
+                input: &[$Scalar],
+                non_rep_bit_count: usize,
+            ) {
+                let round = |input: $Scalar| {
+                    let non_rep_msb = (input >> (non_rep_bit_count - 1)) & 1;
+                    ((input >> non_rep_bit_count) + non_rep_msb) << non_rep_bit_count
+                };
+                let shift = _mm_cvtsi64_si128(non_rep_bit_count as i64);
+                let msb_shift = _mm_cvtsi64_si128(non_rep_bit_count as i64 - 1);
+                let one = $set1(1);
+                let mut output_chunks = output.chunks_exact_mut(LANES);
+                let mut input_chunks = input.chunks_exact(LANES);
+                for (output, input) in (&mut output_chunks).zip(&mut input_chunks) {
+                    let input = load(input);
+                    let non_rep_msb = _mm512_and_si512($srl(input, msb_shift), one);
+                    let rounded = $add($srl(input, shift), non_rep_msb);
+                    store(output, $sll(rounded, shift));
+                }
+                for (output, input) in output_chunks
+                    .into_remainder()
+                    .iter_mut()
+                    .zip(input_chunks.remainder())
+                {
+                    *output = round(*input);
+                }
+            }
+
+            /// # Safety
+            ///
+            ///  - `is_x86_feature_detected!("avx512f")` must be true.
+            ///  - `is_x86_feature_detected!("avx512dq")` must be true.
+            ///  - `base_log` must be in `1..<$Scalar>::BITS`.
+            #[target_feature(enable = "avx512f,avx512dq")]
+            pub(super) unsafe fn decompose_one_level_in_place(
+                outputs: &mut [$Scalar],
+                states: &mut [$Scalar],
+                mod_b_mask: $Scalar,
+                base_log: usize,
+            ) {
+                let mod_b_mask_vector = $set1(mod_b_mask as $Signed);
+                let one = $set1(1);
+                let shift = _mm_cvtsi64_si128(base_log as i64);
+                let carry_shift = _mm_cvtsi64_si128(base_log as i64 - 1);
+                let mut output_chunks = outputs.chunks_exact_mut(LANES);
+                let mut state_chunks = states.chunks_exact_mut(LANES);
+                for (output, state) in (&mut output_chunks).zip(&mut state_chunks) {
+                    let res = _mm512_and_si512(load(state), mod_b_mask_vector);
+                    let shifted_state = $srl(load(state), shift);
+                    let carry =
+                        _mm512_and_si512(_mm512_or_si512($sub(res, one), shifted_state), res);
+                    let carry = $srl(carry, carry_shift);
+                    store(state, $add(shifted_state, carry));
+                    store(output, $sub(res, $sll(carry, shift)));
+                }
+                for (output, state) in output_chunks
+                    .into_remainder()
+                    .iter_mut()
+                    .zip(state_chunks.into_remainder())
+                {
+                    let res = *state & mod_b_mask;
+                    *state >>= base_log;
+                    let carry = ((res.wrapping_sub(1) | *state) & res) >> (base_log - 1);
+                    *state += carry;
+                    *output = res.wrapping_sub(carry << base_log);
+                }
+            }
+        }
+    };
+}
+
+lanes!(
+    lanes_u32,
+    u32,
+    set1 = _mm512_set1_epi32 as i32,
+    add = _mm512_add_epi32,
+    sub = _mm512_sub_epi32,
+    mullo = _mm512_mullo_epi32,
+    srl = _mm512_srl_epi32,
+    sll = _mm512_sll_epi32,
+);
+
+lanes!(
+    lanes_u64,
+    u64,
+    set1 = _mm512_set1_epi64 as i64,
+    add = _mm512_add_epi64,
+    sub = _mm512_sub_epi64,
+    mullo = _mm512_mullo_epi64,
+    srl = _mm512_srl_epi64,
+    sll = _mm512_sll_epi64,
+);
@@ -0,0 +1,115 @@
+//! Slice kernels used in the hot loops of the tensor, decomposition and polynomial operations.
+//!
+//! Every kernel comes with a portable implementation. When the `backend_default_x86_64_avx512`
+//! feature is activated, the kernels operating on `u32` or `u64` values are dispatched at runtime
+//! to an AVX-512 implementation, provided that the CPU supports the `avx512f` and `avx512dq`
+//! instruction sets. Both implementations compute the exact same values.
+use crate::commons::math::decomposition::{decompose_one_level, SignedDecomposer};
+use crate::commons::utils::{zip, zip_args};
+use concrete_commons::numeric::UnsignedInteger;
+
+#[cfg(all(feature = "backend_default_x86_64_avx512", target_arch = "x86_64"))]
+mod avx512;
+
+#[cfg(test)]
+mod tests;
+
+/// Wrap-adds the values of `rhs` to the values of `lhs`.
+#[inline]
+pub(crate) fn wrapping_add_assign<Scalar: UnsignedInteger>(lhs: &mut [Scalar], rhs: &[Scalar]) {
+    debug_assert_eq!(lhs.len(), rhs.len());
+    #[cfg(all(feature = "backend_default_x86_64_avx512", target_arch = "x86_64"))]
+    if avx512::wrapping_add_assign(lhs, rhs) {
+        return;
+    }
+    for (lhs_i, rhs_i) in lhs.iter_mut().zip(rhs.iter()) {
+        *lhs_i = lhs_i.wrapping_add(*rhs_i);
+    }
+}
+
+/// Wrap-subtracts the values of `rhs` to the values of `lhs`.
+#[inline]
+pub(crate) fn wrapping_sub_assign<Scalar: UnsignedInteger>(lhs: &mut [Scalar], rhs: &[Scalar]) {
+    debug_assert_eq!(lhs.len(), rhs.len());
+    #[cfg(all(feature = "backend_default_x86_64_avx512", target_arch = "x86_64"))]
+    if avx512::wrapping_sub_assign(lhs, rhs) {
+        return;
+    }
+    for (lhs_i, rhs_i) in lhs.iter_mut().zip(rhs.iter()) {
+        *lhs_i = lhs_i.wrapping_sub(*rhs_i);
+    }
+}
+
+/// Wrap-adds the wrapping products of the values of `rhs` with `element` to the values of `lhs`.
+#[inline]
+pub(crate) fn wrapping_add_element_mul_assign<Scalar: UnsignedInteger>(
+    lhs: &mut [Scalar],
+    rhs: &[Scalar],
+    element: Scalar,
+) {
+    debug_assert_eq!(lhs.len(), rhs.len());
+    #[cfg(all(feature = "backend_default_x86_64_avx512", target_arch = "x86_64"))]
+    if avx512::wrapping_add_element_mul_assign(lhs, rhs, element) {
+        return;
+    }
+    for (lhs_i, rhs_i) in lhs.iter_mut().zip(rhs.iter()) {
+        *lhs_i = lhs_i.wrapping_add(rhs_i.wrapping_mul(element));
+    }
+}
+
+/// Wrap-subtracts the wrapping products of the values of `rhs` with `element` to the values of
+/// `lhs`.
+#[inline]
+pub(crate) fn wrapping_sub_element_mul_assign<Scalar: UnsignedInteger>(
+    lhs: &mut [Scalar],
+    rhs: &[Scalar],
+    element: Scalar,
+) {
+    debug_assert_eq!(lhs.len(), rhs.len());
+    #[cfg(all(feature = "backend_default_x86_64_avx512", target_arch = "x86_64"))]
+    if avx512::wrapping_sub_element_mul_assign(lhs, rhs, element) {
+        return;
+    }
+    for (lhs_i, rhs_i) in lhs.iter_mut().zip(rhs.iter()) {
+        *lhs_i = lhs_i.wrapping_sub(rhs_i.wrapping_mul(element));
+    }
+}
+
+/// Fills `output` with the closest values of `input` representable by the decomposer.
+#[inline]
+pub(crate) fn fill_with_closest_representable<Scalar: UnsignedInteger>(
+    decomposer: &SignedDecomposer<Scalar>,
+    output: &mut [Scalar],
+    input: &[Scalar],
+) {
+    debug_assert_eq!(output.len(), input.len());
+    #[cfg(all(feature = "backend_default_x86_64_avx512", target_arch = "x86_64"))]
+    {
+        let non_rep_bit_count = Scalar::BITS - decomposer.level_count().0 * decomposer.base_log().0;
+        if avx512::fill_with_closest_representable(output, input, non_rep_bit_count) {
+            return;
+        }
+    }
+    for (output_i, input_i) in output.iter_mut().zip(input.iter()) {
+        *output_i = decomposer.closest_representable(*input_i);
+    }
+}
+
+/// Computes one level of the signed decompositions whose states are stored in `states`, and
+/// writes the resulting terms in `outputs`.
+#[inline]
+pub(crate) fn decompose_one_level_in_place<Scalar: UnsignedInteger>(
+    outputs: &mut [Scalar],
+    states: &mut [Scalar],
+    base_log: usize,
+    mod_b_mask: Scalar,
+) {
+    debug_assert_eq!(outputs.len(), states.len());
+    #[cfg(all(feature = "backend_default_x86_64_avx512", target_arch = "x86_64"))]
+    if avx512::decompose_one_level_in_place(outputs, states, base_log, mod_b_mask) {
+        return;
+    }
+    for zip_args!(output_i, state_i) in zip!(outputs.iter_mut(), states.iter_mut()) {
+        *output_i = decompose_one_level(base_log, state_i, mod_b_mask);
+    }
+}
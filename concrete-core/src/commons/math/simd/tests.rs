@@ -0,0 +1,114 @@
+use crate::commons::math::decomposition::{decompose_one_level, SignedDecomposer};
+use crate::commons::math::random::{RandomGenerable, Uniform};
+use crate::commons::math::simd;
+use crate::commons::test_tools::{any_uint, random_usize_between};
+use concrete_commons::numeric::UnsignedInteger;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+use std::fmt::Debug;
+
+// Lengths which are multiples of the number of lanes, and lengths which leave a remainder.
+const LENGTHS: [usize; 7] = [0, 1, 7, 16, 33, 64, 1000];
+
+fn random_values<T: UnsignedInteger + RandomGenerable<Uniform>>(len: usize) -> Vec<T> {
+    (0..len).map(|_| any_uint::<T>()).collect()
+}
+
+fn test_element_wise<T: UnsignedInteger + RandomGenerable<Uniform> + Debug>() {
+    for len in LENGTHS {
+        let lhs = random_values::<T>(len);
+        let rhs = random_values::<T>(len);
+        let element = any_uint::<T>();
+
+        let mut output = lhs.clone();
+        simd::wrapping_add_assign(&mut output, &rhs);
+        for ((out, l), r) in output.iter().zip(lhs.iter()).zip(rhs.iter()) {
+            assert_eq!(*out, l.wrapping_add(*r));
+        }
+
+        let mut output = lhs.clone();
+        simd::wrapping_sub_assign(&mut output, &rhs);
+        for ((out, l), r) in output.iter().zip(lhs.iter()).zip(rhs.iter()) {
+            assert_eq!(*out, l.wrapping_sub(*r));
+        }
+
+        let mut output = lhs.clone();
+        simd::wrapping_add_element_mul_assign(&mut output, &rhs, element);
+        for ((out, l), r) in output.iter().zip(lhs.iter()).zip(rhs.iter()) {
+            assert_eq!(*out, l.wrapping_add(r.wrapping_mul(element)));
+        }
+
+        let mut output = lhs.clone();
+        simd::wrapping_sub_element_mul_assign(&mut output, &rhs, element);
+        for ((out, l), r) in output.iter().zip(lhs.iter()).zip(rhs.iter()) {
+            assert_eq!(*out, l.wrapping_sub(r.wrapping_mul(element)));
+        }
+    }
+}
+
+#[test]
+fn test_element_wise_u8() {
+    test_element_wise::<u8>()
+}
+
+#[test]
+fn test_element_wise_u32() {
+    test_element_wise::<u32>()
+}
+
+#[test]
+fn test_element_wise_u64() {
+    test_element_wise::<u64>()
+}
+
+fn test_decomposition<T: UnsignedInteger + RandomGenerable<Uniform> + Debug>() {
+    for len in LENGTHS {
+        let (base_log, level_count) = loop {
+            let base_log = random_usize_between(1..T::BITS);
+            let level_count = random_usize_between(1..T::BITS);
+            if base_log * level_count < T::BITS {
+                break (base_log, level_count);
+            }
+        };
+        let decomposer = SignedDecomposer::<T>::new(
+            DecompositionBaseLog(base_log),
+            DecompositionLevelCount(level_count),
+        );
+        let input = random_values::<T>(len);
+
+        let mut rounded = vec![T::ZERO; len];
+        simd::fill_with_closest_representable(&decomposer, &mut rounded, &input);
+        for (rounded, input) in rounded.iter().zip(input.iter()) {
+            assert_eq!(*rounded, decomposer.closest_representable(*input));
+        }
+
+        let mod_b_mask = (T::ONE << base_log) - T::ONE;
+        let mut states: Vec<T> = rounded
+            .iter()
+            .map(|value| *value >> (T::BITS - base_log * level_count))
+            .collect();
+        let mut expected_states = states.clone();
+        let mut outputs = vec![T::ZERO; len];
+        for _ in 0..level_count {
+            simd::decompose_one_level_in_place(&mut outputs, &mut states, base_log, mod_b_mask);
+            for (output, state) in outputs.iter().zip(expected_states.iter_mut()) {
+                assert_eq!(*output, decompose_one_level(base_log, state, mod_b_mask));
+            }
+            assert_eq!(states, expected_states);
+        }
+    }
+}
+
+#[test]
+fn test_decomposition_u8() {
+    test_decomposition::<u8>()
+}
+
+#[test]
+fn test_decomposition_u32() {
+    test_decomposition::<u32>()
+}
+
+#[test]
+fn test_decomposition_u64() {
+    test_decomposition::<u64>()
+}
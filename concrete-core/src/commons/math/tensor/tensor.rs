@@ -12,6 +12,7 @@ use serde::{Deserialize, Serialize};
 
 use concrete_commons::numeric::{CastFrom, UnsignedInteger};
 
+use crate::commons::math::simd;
 use crate::commons::utils::zip;
 
 use super::{AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor};
@@ -454,6 +455,89 @@ impl<Container> Tensor<Container> {
             .map(Tensor::from_container)
     }
 
+    /// Returns a parallel iterator over at most `shard_count` shards of the tensor, each shard
+    /// being a `Tensor<&[Scalar]>` made of whole sub tensors of length `size`.
+    ///
+    /// The sub tensors are distributed evenly between the shards, so that every shard but the
+    /// last one contains the same number of sub tensors. This allows to split the work on the
+    /// sub tensors between a fixed number of tasks, for instance to share a buffer between the
+    /// sub tensors of a task.
+    ///
+    /// # Note:
+    /// The length of the sub-tensors must divide the size of the tensor.
+    /// This iterator is hidden behind the "parallel" feature gate.
+    ///
+    /// # Example:
+    /// ```
+    /// use concrete_core::commons::math::tensor::Tensor;
+    /// use rayon::iter::ParallelIterator;
+    /// let tensor = Tensor::allocate(9 as u8, 1000);
+    /// // The 100 sub tensors are split in 3 shards of 34, 34 and 32 sub tensors
+    /// let lengths: Vec<usize> = tensor
+    ///     .par_subtensor_shard_iter(10, 3)
+    ///     .map(|shard| shard.len())
+    ///     .collect();
+    /// assert_eq!(lengths, vec![340, 340, 320]);
+    /// ```
+    #[cfg(feature = "__commons_parallel")]
+    pub fn par_subtensor_shard_iter(
+        &self,
+        size: usize,
+        shard_count: usize,
+    ) -> impl IndexedParallelIterator<Item = Tensor<&[<Self as AsRefSlice>::Element]>>
+    where
+        Self: AsRefSlice,
+        <Self as AsRefSlice>::Element: Sync,
+    {
+        debug_assert!(self.as_slice().len().is_multiple_of(size), "Uneven chunks size");
+        let shard_len = shard_len(self.as_slice().len(), size, shard_count);
+        self.as_slice()
+            .par_chunks(shard_len)
+            .map(Tensor::from_container)
+    }
+
+    /// Returns a parallel iterator over at most `shard_count` mutable shards of the tensor, each
+    /// shard being a `Tensor<&mut [Scalar]>` made of whole sub tensors of length `size`.
+    ///
+    /// See [`Tensor::par_subtensor_shard_iter`] for the distribution of the sub tensors.
+    ///
+    /// # Note:
+    /// The length of the sub-tensors must divide the size of the tensor.
+    /// This iterator is hidden behind the "parallel" feature gate.
+    ///
+    /// # Example:
+    /// ```
+    /// use concrete_core::commons::math::tensor::Tensor;
+    /// use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+    /// let mut tensor = Tensor::allocate(9 as u8, 1000);
+    /// tensor
+    ///     .par_subtensor_shard_iter_mut(10, 4)
+    ///     .enumerate()
+    ///     .for_each(|(index, mut shard)| {
+    ///         assert_eq!(shard.len(), 250);
+    ///         shard.fill_with_element(index as u8);
+    ///     });
+    /// assert_eq!(*tensor.get_element(249), 0);
+    /// assert_eq!(*tensor.get_element(250), 1);
+    /// assert_eq!(*tensor.get_element(999), 3);
+    /// ```
+    #[cfg(feature = "__commons_parallel")]
+    pub fn par_subtensor_shard_iter_mut(
+        &mut self,
+        size: usize,
+        shard_count: usize,
+    ) -> impl IndexedParallelIterator<Item = Tensor<&mut [<Self as AsMutSlice>::Element]>>
+    where
+        Self: AsMutSlice,
+        <Self as AsMutSlice>::Element: Sync + Send,
+    {
+        debug_assert!(self.as_slice().len().is_multiple_of(size), "Uneven chunks size");
+        let shard_len = shard_len(self.as_slice().len(), size, shard_count);
+        self.as_mut_slice()
+            .par_chunks_mut(shard_len)
+            .map(Tensor::from_container)
+    }
+
     /// Returns a reference to the first element.
     ///
     /// # Note:
@@ -1120,8 +1204,50 @@ impl<Container> Tensor<Container> {
     update_with!(ShlAssign, update_with_shl, |s, a| *s <<= *a);
     update_with!(ShrAssign, update_with_shr, |s, a| *s >>= *a);
 
-    update_with_wrapping!(update_with_wrapping_add, |s, a| *s = s.wrapping_add(*a));
-    update_with_wrapping!(update_with_wrapping_sub, |s, a| *s = s.wrapping_sub(*a));
+    /// Updates the values of a mutable tensor by wrap-adding the elements of another tensor.
+    ///
+    /// # Example
+    /// ```
+    /// use concrete_core::commons::math::tensor::Tensor;
+    /// let mut t1 = Tensor::allocate(9 as u8, 1000);
+    /// let t2 = Tensor::allocate(250 as u8, 1000);
+    /// t1.update_with_wrapping_add(&t2);
+    /// for scalar in t1.iter() {
+    ///     assert_eq!(*scalar, 3);
+    /// }
+    /// ```
+    pub fn update_with_wrapping_add<Other, Element>(&mut self, other: &Tensor<Other>)
+    where
+        Self: AsMutSlice<Element = Element>,
+        Tensor<Other>: AsRefSlice<Element = Element>,
+        Element: UnsignedInteger,
+    {
+        ck_dim_eq!(self.len() => other.len());
+        simd::wrapping_add_assign(self.as_mut_slice(), other.as_slice());
+    }
+
+    /// Updates the values of a mutable tensor by wrap-subtracting the elements of another tensor.
+    ///
+    /// # Example
+    /// ```
+    /// use concrete_core::commons::math::tensor::Tensor;
+    /// let mut t1 = Tensor::allocate(9 as u8, 1000);
+    /// let t2 = Tensor::allocate(10 as u8, 1000);
+    /// t1.update_with_wrapping_sub(&t2);
+    /// for scalar in t1.iter() {
+    ///     assert_eq!(*scalar, 255);
+    /// }
+    /// ```
+    pub fn update_with_wrapping_sub<Other, Element>(&mut self, other: &Tensor<Other>)
+    where
+        Self: AsMutSlice<Element = Element>,
+        Tensor<Other>: AsRefSlice<Element = Element>,
+        Element: UnsignedInteger,
+    {
+        ck_dim_eq!(self.len() => other.len());
+        simd::wrapping_sub_assign(self.as_mut_slice(), other.as_slice());
+    }
+
     update_with_wrapping!(update_with_wrapping_mul, |s, a| *s = s.wrapping_mul(*a));
     update_with_wrapping!(update_with_wrapping_div, |s, a| *s = s.wrapping_div(*a));
 
@@ -1286,7 +1412,7 @@ impl<Container> Tensor<Container> {
         Element: UnsignedInteger,
     {
         ck_dim_eq!(self.len() => tensor.len());
-        self.update_with_one(tensor, |s, t| *s = s.wrapping_sub(t.wrapping_mul(scalar)));
+        simd::wrapping_sub_element_mul_assign(self.as_mut_slice(), tensor.as_slice(), scalar);
     }
 
     /// Updates the values of a mutable tensor by adding the product of the element of another
@@ -1341,7 +1467,7 @@ impl<Container> Tensor<Container> {
         Element: UnsignedInteger,
     {
         ck_dim_eq!(self.len() => tensor.len());
-        self.update_with_one(tensor, |s, t| *s = s.wrapping_add(t.wrapping_mul(element)));
+        simd::wrapping_add_element_mul_assign(self.as_mut_slice(), tensor.as_slice(), element);
     }
 
     /// Computes a value by folding a tensor with another.
@@ -1480,3 +1606,68 @@ where
         self
     }
 }
+
+/// Tensors can be used as parallel slices, which gives access to the parallel iterators of
+/// [`ParallelSlice`], like [`ParallelSlice::par_chunks`].
+///
+/// # Notes:
+/// This implementation is hidden behind the "parallel" feature gate.
+///
+/// # Example:
+/// ```
+/// use concrete_core::commons::math::tensor::Tensor;
+/// use rayon::prelude::*;
+/// let tensor = Tensor::from_container(vec![1 as u8, 2, 3, 4, 5, 6]);
+/// let sums: Vec<u8> = tensor
+///     .par_chunks_exact(2)
+///     .map(|chunk| chunk.iter().sum())
+///     .collect();
+/// assert_eq!(sums, vec![3, 7, 11]);
+/// ```
+#[cfg(feature = "__commons_parallel")]
+impl<Cont> ParallelSlice<<Cont as AsRefSlice>::Element> for Tensor<Cont>
+where
+    Cont: AsRefSlice,
+    <Cont as AsRefSlice>::Element: Sync,
+{
+    fn as_parallel_slice(&self) -> &[<Cont as AsRefSlice>::Element] {
+        self.as_slice()
+    }
+}
+
+/// Mutable tensors can be used as mutable parallel slices, which gives access to the parallel
+/// iterators of [`ParallelSliceMut`], like [`ParallelSliceMut::par_chunks_mut`].
+///
+/// # Notes:
+/// This implementation is hidden behind the "parallel" feature gate.
+///
+/// # Example:
+/// ```
+/// use concrete_core::commons::math::tensor::Tensor;
+/// use rayon::prelude::*;
+/// let mut tensor = Tensor::allocate(0 as u8, 6);
+/// tensor
+///     .par_chunks_exact_mut(2)
+///     .enumerate()
+///     .for_each(|(index, chunk)| chunk.fill(index as u8));
+/// assert_eq!(tensor.into_container(), vec![0, 0, 1, 1, 2, 2]);
+/// ```
+#[cfg(feature = "__commons_parallel")]
+impl<Cont> ParallelSliceMut<<Cont as AsMutSlice>::Element> for Tensor<Cont>
+where
+    Cont: AsMutSlice,
+    <Cont as AsMutSlice>::Element: Send,
+{
+    fn as_parallel_slice_mut(&mut self) -> &mut [<Cont as AsMutSlice>::Element] {
+        self.as_mut_slice()
+    }
+}
+
+// Returns the length of the shards of a tensor of length `len`, made of at most `shard_count`
+// shards of whole sub tensors of length `size`.
+#[cfg(feature = "__commons_parallel")]
+fn shard_len(len: usize, size: usize, shard_count: usize) -> usize {
+    debug_assert!(size > 0 && shard_count > 0);
+    // The length can not be null, even when the tensor is empty.
+    (len / size).div_ceil(shard_count).max(1) * size
+}
@@ -18,6 +18,7 @@ use crate::commons::math::random::{
 use concrete_commons::dispersion::LogStandardDev;
 use concrete_commons::numeric::{CastInto, FloatingPoint, Numeric, UnsignedInteger};
 use std::fmt::{Debug, Display};
+use std::hash::Hash;
 
 /// A trait that converts a torus element in unsigned integer representation to the closest
 /// torus element in floating point representation.
@@ -88,11 +89,16 @@ pub trait UnsignedTorus:
     + RandomGenerable<Uniform>
     + Display
     + Debug
+    + Hash
 {
     /// The log standard deviation used to sample gaussian keys in this precision.
     const GAUSSIAN_KEY_LOG_STD: LogStandardDev;
 }
 
+impl UnsignedTorus for u16 {
+    const GAUSSIAN_KEY_LOG_STD: LogStandardDev = LogStandardDev(-14.32192809488736);
+}
+
 impl UnsignedTorus for u32 {
     const GAUSSIAN_KEY_LOG_STD: LogStandardDev = LogStandardDev(-30.32192809488736);
 }
@@ -1,5 +1,7 @@
 //! Utilities for the library.
 
+use concrete_commons::numeric::{CastFrom, CastInto, UnsignedInteger};
+
 /// This macro is used in tandem with the [`zip_args`] macro, to allow to zip iterators and access
 /// them in an non-nested fashion. This makes large zip iterators easier to write, but also,
 /// makes the code faster, as zipped-flatten iterators are hard to optimize for the compiler.
@@ -30,6 +32,112 @@ macro_rules! zip_args {
 }
 pub(crate) use zip_args;
 
+/// The errors which can occur when reading a raw dump with [`read_raw_dump`].
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum RawDumpError {
+    /// The length of the dump does not match its header.
+    InvalidLength,
+    /// The coefficient width of the dump does not match the expected scalar type.
+    ScalarSizeMismatch,
+}
+
+/// Writes a raw dump made of the `header` words and of the coefficient width, stored as 64 bits
+/// little-endian words, followed by the `coefficients` stored little-endian on their own width.
+pub(crate) fn write_raw_dump<Scalar>(header: &[usize], coefficients: &[Scalar]) -> Vec<u8>
+where
+    Scalar: UnsignedInteger + CastInto<u128>,
+{
+    let scalar_bytes = Scalar::BITS / 8;
+    let mut raw = Vec::with_capacity(8 * (header.len() + 1) + scalar_bytes * coefficients.len());
+    for word in header.iter().map(|w| *w as u64).chain(Some(Scalar::BITS as u64)) {
+        raw.extend_from_slice(&word.to_le_bytes());
+    }
+    for coefficient in coefficients {
+        let coefficient: u128 = (*coefficient).cast_into();
+        raw.extend_from_slice(&coefficient.to_le_bytes()[..scalar_bytes]);
+    }
+    raw
+}
+
+/// Reads a raw dump written by [`write_raw_dump`] with `header_len` header words, and returns
+/// the header and the coefficients. The number of coefficients must be the one returned by
+/// `coefficient_count` on the header.
+pub(crate) fn read_raw_dump<Scalar, F>(
+    raw: &[u8],
+    header_len: usize,
+    coefficient_count: F,
+) -> Result<(Vec<usize>, Vec<Scalar>), RawDumpError>
+where
+    Scalar: UnsignedInteger + CastFrom<u128>,
+    F: Fn(&[usize]) -> Option<usize>,
+{
+    let header_bytes = 8 * (header_len + 1);
+    if raw.len() < header_bytes {
+        return Err(RawDumpError::InvalidLength);
+    }
+    let (header_raw, coefficients_raw) = raw.split_at(header_bytes);
+    let mut words = header_raw
+        .chunks_exact(8)
+        .map(|word| u64::from_le_bytes(word.try_into().unwrap()));
+    let header = words
+        .by_ref()
+        .take(header_len)
+        .map(usize::try_from)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| RawDumpError::InvalidLength)?;
+    if words.next() != Some(Scalar::BITS as u64) {
+        return Err(RawDumpError::ScalarSizeMismatch);
+    }
+    let scalar_bytes = Scalar::BITS / 8;
+    let expected_len = coefficient_count(&header).and_then(|count| count.checked_mul(scalar_bytes));
+    if expected_len != Some(coefficients_raw.len()) {
+        return Err(RawDumpError::InvalidLength);
+    }
+    let coefficients = coefficients_raw
+        .chunks_exact(scalar_bytes)
+        .map(|coefficient| {
+            let mut bytes = [0_u8; 16];
+            bytes[..scalar_bytes].copy_from_slice(coefficient);
+            Scalar::cast_from(u128::from_le_bytes(bytes))
+        })
+        .collect();
+    Ok((header, coefficients))
+}
+
+/// The errors which can occur when validating the container of an entity with
+/// [`validate_container_length`].
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum ContainerValidationError {
+    /// The container does not hold any coefficient.
+    EmptyContainer,
+    /// One of the dimensions declared by the entity is zero.
+    NullDimension,
+    /// The length of the container is not a multiple of the element size declared by the entity.
+    InvalidContainerLength,
+}
+
+/// Checks that a container of length `len` holds a whole, non-zero number of elements whose size
+/// is the product of the `dimensions` declared by an entity.
+pub(crate) fn validate_container_length(
+    len: usize,
+    dimensions: &[usize],
+) -> Result<(), ContainerValidationError> {
+    if dimensions.contains(&0) {
+        return Err(ContainerValidationError::NullDimension);
+    }
+    if len == 0 {
+        return Err(ContainerValidationError::EmptyContainer);
+    }
+    let element_size = dimensions
+        .iter()
+        .try_fold(1_usize, |size, dimension| size.checked_mul(*dimension))
+        .ok_or(ContainerValidationError::InvalidContainerLength)?;
+    if !len.is_multiple_of(element_size) {
+        return Err(ContainerValidationError::InvalidContainerLength);
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod test {
     #![allow(clippy::many_single_char_names)]
@@ -68,4 +176,50 @@ mod test {
             (3, (6, (9, (12, (15, (18, 21))))))
         );
     }
+
+    #[test]
+    fn test_raw_dump() {
+        use super::{read_raw_dump, write_raw_dump, RawDumpError};
+        let coefficients = vec![0_u32, 1, u32::MAX, 0xdead_beef, 42, 7];
+        let raw = write_raw_dump(&[2, 3], &coefficients);
+        assert_eq!(raw.len(), 3 * 8 + 6 * 4);
+        assert_eq!(raw[..8], 2_u64.to_le_bytes());
+        assert_eq!(raw[16..24], 32_u64.to_le_bytes());
+        assert_eq!(raw[24 + 12..24 + 16], 0xdead_beef_u32.to_le_bytes());
+
+        let count = |header: &[usize]| Some(header[0] * header[1]);
+        let (header, read) = read_raw_dump::<u32, _>(&raw, 2, count).unwrap();
+        assert_eq!(header, vec![2, 3]);
+        assert_eq!(read, coefficients);
+        assert_eq!(
+            read_raw_dump::<u64, _>(&raw, 2, count),
+            Err(RawDumpError::ScalarSizeMismatch)
+        );
+        assert_eq!(
+            read_raw_dump::<u32, _>(&raw[..raw.len() - 1], 2, count),
+            Err(RawDumpError::InvalidLength)
+        );
+    }
+
+    #[test]
+    fn test_validate_container_length() {
+        use super::{validate_container_length, ContainerValidationError};
+        assert_eq!(validate_container_length(12, &[2, 3]), Ok(()));
+        assert_eq!(
+            validate_container_length(0, &[2, 3]),
+            Err(ContainerValidationError::EmptyContainer)
+        );
+        assert_eq!(
+            validate_container_length(12, &[2, 0]),
+            Err(ContainerValidationError::NullDimension)
+        );
+        assert_eq!(
+            validate_container_length(14, &[2, 3]),
+            Err(ContainerValidationError::InvalidContainerLength)
+        );
+        assert_eq!(
+            validate_container_length(12, &[usize::MAX, 2]),
+            Err(ContainerValidationError::InvalidContainerLength)
+        );
+    }
 }
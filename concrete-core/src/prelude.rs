@@ -21,6 +21,12 @@ pub use super::backends::fft::engines::*;
 #[cfg(feature = "backend_fft")]
 pub use super::backends::fft::entities::*;
 
+// ------------------------------------------------------------------------------------- NTT BACKEND
+#[cfg(feature = "backend_ntt")]
+pub use super::backends::ntt::engines::*;
+#[cfg(feature = "backend_ntt")]
+pub use super::backends::ntt::entities::*;
+
 // ------------------------------------------------------------------------------------ CUDA BACKEND
 #[cfg(all(feature = "backend_cuda", not(feature = "_ci_do_not_compile")))]
 pub use super::backends::cuda::engines::*;
@@ -43,3 +49,12 @@ pub use concrete_csprng::seeders::RdseedSeeder;
 pub use concrete_csprng::seeders::Seeder;
 #[cfg(feature = "seeder_unix")]
 pub use concrete_csprng::seeders::UnixSeeder;
+
+// ------------------------------------------------------------------------------ GENERATOR REEXPORT
+// Re-export the different random generators of the `concrete-csprng` crate, which can be chosen to
+// expand seeded entities.
+#[cfg(feature = "backend_default_generator_x86_64_aesni")]
+pub use concrete_csprng::generators::AesniRandomGenerator;
+pub use concrete_csprng::generators::RandomGenerator as ByteRandomGenerator;
+#[cfg(feature = "backend_default")]
+pub use concrete_csprng::generators::SoftwareRandomGenerator;
@@ -0,0 +1,643 @@
+//! A module containing a static description of FHE circuits, and their execution.
+//!
+//! A [`Circuit`] is a list of operations over LWE ciphertexts, in which every operation reads
+//! some wires and writes a new one. The circuit only describes the computation: the keys, the
+//! accumulators and the engines are given when the circuit is executed, with [`Circuit::execute`].
+//! Since the circuit is known before the execution, the executor knows when a wire is read for
+//! the last time, and reuses its ciphertext as the output buffer of a later operation, instead of
+//! allocating a new one. Before the execution, the redundant operations of the circuit can be
+//! removed with [`Circuit::eliminate_common_subexpressions`].
+//!
+//! The supported operations are the ones exposed by the [`dynamic`](super::dynamic) module:
+//!
+//! + The (discarding) addition of two LWE ciphertexts.
+//! + The (discarding) keyswitch of an LWE ciphertext.
+//! + The (discarding) bootstrap of an LWE ciphertext.
+//!
+//! ```
+//! use concrete_commons::dispersion::Variance;
+//! use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+//! use concrete_core::prelude::*;
+//! use concrete_core::specification::engines::circuit::{Circuit, CircuitEngines, CircuitKeys};
+//! # use std::error::Error;
+//!
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+//! let (input_lwe_dimension, output_lwe_dimension) = (LweDimension(6), LweDimension(3));
+//! let (level, base_log) = (DecompositionLevelCount(5), DecompositionBaseLog(8));
+//! let noise = Variance(2_f64.powf(-50.));
+//!
+//! // The circuit computes `ks(a + b)` and `ks(a + b) + ks(a + b)`.
+//! let mut circuit = Circuit::new();
+//! let a = circuit.add_input();
+//! let b = circuit.add_input();
+//! let sum = circuit.add_addition(a, b);
+//! let switched = circuit.add_keyswitch(sum);
+//! let doubled = circuit.add_addition(switched, switched);
+//! circuit.add_output(switched);
+//! circuit.add_output(doubled);
+//!
+//! // Unix seeder must be given a secret input.
+//! // Here we just give it 0, which is totally unsafe.
+//! const UNSAFE_SECRET: u128 = 0;
+//! let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+//! let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+//! let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+//! let ksk = engine.generate_new_lwe_keyswitch_key(
+//!     &input_key,
+//!     &output_key,
+//!     level,
+//!     base_log,
+//!     noise,
+//! )?;
+//! let zero = engine.create_plaintext_from(&0_u64)?;
+//! let plaintext_a = engine.create_plaintext_from(&(1_u64 << 60))?;
+//! let plaintext_b = engine.create_plaintext_from(&(2_u64 << 60))?;
+//! let inputs = vec![
+//!     engine.encrypt_lwe_ciphertext(&input_key, &plaintext_a, noise)?,
+//!     engine.encrypt_lwe_ciphertext(&input_key, &plaintext_b, noise)?,
+//! ];
+//!
+//! let mut allocator = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+//! let mut adder = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+//! let outputs = circuit.execute::<(
+//!     Plaintext64,
+//!     LweCiphertext64,
+//!     LweKeyswitchKey64,
+//!     LweBootstrapKey64,
+//!     GlweCiphertext64,
+//! )>(
+//!     CircuitEngines {
+//!         allocator: &mut allocator,
+//!         adder: Some(&mut adder),
+//!         keyswitcher: Some(&mut engine),
+//!         bootstrapper: None,
+//!     },
+//!     CircuitKeys {
+//!         zero: &zero,
+//!         ksk: Some(&ksk),
+//!         bsk: None,
+//!         accumulators: &[],
+//!     },
+//!     inputs,
+//! )?;
+//! #
+//! # assert_eq!(outputs.len(), 2);
+//! # for (output, expected) in outputs.iter().zip([3_u64 << 60, 6_u64 << 60]) {
+//! #     assert_eq!(output.lwe_dimension(), output_lwe_dimension);
+//! #     let decrypted = engine.decrypt_lwe_ciphertext(&output_key, output)?;
+//! #     let mut raw = 0_u64;
+//! #     engine.discard_retrieve_plaintext(&mut raw, &decrypted)?;
+//! #     assert!(raw.wrapping_sub(expected).min(expected.wrapping_sub(raw)) < 1 << 50);
+//! # }
+//! #
+//! # Ok(())
+//! # }
+//! ```
+use super::dynamic::{DynBootstrapper, DynKeyswitcher, DynLweAdder, DynLweTrivialEncryptor};
+use crate::specification::entities::{
+    GlweCiphertextEntity, LweBootstrapKeyEntity, LweCiphertextEntity, LweKeyswitchKeyEntity,
+    PlaintextEntity,
+};
+use concrete_commons::parameters::LweDimension;
+#[cfg(feature = "__commons_serialization")]
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// A wire of a [`Circuit`], holding an LWE ciphertext.
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Wire(pub usize);
+
+/// An operation of a [`Circuit`].
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CircuitOperation {
+    /// Writes the sum of `lhs` and `rhs` in `output`.
+    Addition { lhs: Wire, rhs: Wire, output: Wire },
+    /// Writes the keyswitch of `input` in `output`.
+    Keyswitch { input: Wire, output: Wire },
+    /// Writes the bootstrap of `input` in `output`, using the accumulator with the given index.
+    Bootstrap {
+        input: Wire,
+        accumulator: usize,
+        output: Wire,
+    },
+}
+
+impl CircuitOperation {
+    fn inputs(&self) -> Vec<Wire> {
+        match self {
+            CircuitOperation::Addition { lhs, rhs, .. } => vec![*lhs, *rhs],
+            CircuitOperation::Keyswitch { input, .. } => vec![*input],
+            CircuitOperation::Bootstrap { input, .. } => vec![*input],
+        }
+    }
+
+    fn output(&self) -> Wire {
+        match self {
+            CircuitOperation::Addition { output, .. }
+            | CircuitOperation::Keyswitch { output, .. }
+            | CircuitOperation::Bootstrap { output, .. } => *output,
+        }
+    }
+
+    fn rename_inputs(&mut self, renames: &[Wire]) {
+        match self {
+            CircuitOperation::Addition { lhs, rhs, .. } => {
+                *lhs = renames[lhs.0];
+                *rhs = renames[rhs.0];
+            }
+            CircuitOperation::Keyswitch { input, .. }
+            | CircuitOperation::Bootstrap { input, .. } => *input = renames[input.0],
+        }
+    }
+
+    // Two operations with the same key compute the same value. The addition being commutative,
+    // its operands are sorted.
+    fn key(&self) -> OperationKey {
+        match self {
+            CircuitOperation::Addition { lhs, rhs, .. } => {
+                OperationKey::Addition(*lhs.min(rhs), *lhs.max(rhs))
+            }
+            CircuitOperation::Keyswitch { input, .. } => OperationKey::Keyswitch(*input),
+            CircuitOperation::Bootstrap {
+                input, accumulator, ..
+            } => OperationKey::Bootstrap(*input, *accumulator),
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Hash)]
+enum OperationKey {
+    Addition(Wire, Wire),
+    Keyswitch(Wire),
+    Bootstrap(Wire, usize),
+}
+
+/// A static description of an FHE circuit over LWE ciphertexts.
+///
+/// The wires are created by the circuit itself: the first ones are the inputs, and every
+/// operation creates a new wire for its output. A wire is thus always written before it is read.
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Circuit {
+    wire_count: usize,
+    inputs: Vec<Wire>,
+    operations: Vec<CircuitOperation>,
+    outputs: Vec<Wire>,
+}
+
+impl Circuit {
+    /// Creates an empty circuit.
+    pub fn new() -> Self {
+        Circuit::default()
+    }
+
+    /// Returns the input wires, in the order the inputs are given to [`Circuit::execute`].
+    pub fn inputs(&self) -> &[Wire] {
+        self.inputs.as_slice()
+    }
+
+    /// Returns the operations, in the order they are executed.
+    pub fn operations(&self) -> &[CircuitOperation] {
+        self.operations.as_slice()
+    }
+
+    /// Returns the output wires, in the order the outputs are returned by [`Circuit::execute`].
+    pub fn outputs(&self) -> &[Wire] {
+        self.outputs.as_slice()
+    }
+
+    /// Adds an input to the circuit, and returns its wire.
+    pub fn add_input(&mut self) -> Wire {
+        let wire = self.new_wire();
+        self.inputs.push(wire);
+        wire
+    }
+
+    /// Adds the addition of two wires to the circuit, and returns its output wire.
+    ///
+    /// # Panics
+    /// Panics if one of the wires does not belong to the circuit.
+    pub fn add_addition(&mut self, lhs: Wire, rhs: Wire) -> Wire {
+        self.check_wire(lhs);
+        self.check_wire(rhs);
+        let output = self.new_wire();
+        self.operations
+            .push(CircuitOperation::Addition { lhs, rhs, output });
+        output
+    }
+
+    /// Adds the keyswitch of a wire to the circuit, and returns its output wire.
+    ///
+    /// # Panics
+    /// Panics if the wire does not belong to the circuit.
+    pub fn add_keyswitch(&mut self, input: Wire) -> Wire {
+        self.check_wire(input);
+        let output = self.new_wire();
+        self.operations
+            .push(CircuitOperation::Keyswitch { input, output });
+        output
+    }
+
+    /// Adds the bootstrap of a wire to the circuit, and returns its output wire. The accumulator
+    /// is the one with the given index in the [`CircuitKeys`] used for the execution.
+    ///
+    /// # Panics
+    /// Panics if the wire does not belong to the circuit.
+    pub fn add_bootstrap(&mut self, input: Wire, accumulator: usize) -> Wire {
+        self.check_wire(input);
+        let output = self.new_wire();
+        self.operations.push(CircuitOperation::Bootstrap {
+            input,
+            accumulator,
+            output,
+        });
+        output
+    }
+
+    /// Marks a wire as an output of the circuit.
+    ///
+    /// # Panics
+    /// Panics if the wire does not belong to the circuit.
+    pub fn add_output(&mut self, wire: Wire) {
+        self.check_wire(wire);
+        self.outputs.push(wire);
+    }
+
+    /// Removes the operations computing the same value as a previous operation, and returns the
+    /// number of removed operations.
+    ///
+    /// Two operations compute the same value when they are of the same kind, and read the same
+    /// wires (in any order for an addition), with the same accumulator for a bootstrap. The wires
+    /// reading the output of a removed operation read the output of the previous one instead. The
+    /// merged operations are found across the whole circuit: in particular, a keyswitch repeated
+    /// on the same wire at every iteration of an unrolled loop is only executed once, at its first
+    /// occurrence.
+    ///
+    /// The numbering of the wires is kept, so that the wires returned while building the circuit
+    /// remain valid. The wires written by a removed operation are simply no longer written.
+    ///
+    /// ```
+    /// use concrete_core::specification::engines::circuit::Circuit;
+    ///
+    /// let mut circuit = Circuit::new();
+    /// let input = circuit.add_input();
+    /// let mut accumulated = circuit.add_bootstrap(input, 0);
+    /// for _ in 0..3 {
+    ///     let switched = circuit.add_keyswitch(input);
+    ///     let bootstrapped = circuit.add_bootstrap(switched, 0);
+    ///     accumulated = circuit.add_addition(accumulated, bootstrapped);
+    /// }
+    /// circuit.add_output(accumulated);
+    /// assert_eq!(circuit.operations().len(), 10);
+    ///
+    /// // The keyswitch and the bootstrap are only computed once.
+    /// assert_eq!(circuit.eliminate_common_subexpressions(), 4);
+    /// assert_eq!(circuit.operations().len(), 6);
+    /// ```
+    pub fn eliminate_common_subexpressions(&mut self) -> usize {
+        let mut renames: Vec<Wire> = (0..self.wire_count).map(Wire).collect();
+        let mut computed: HashMap<OperationKey, Wire> = HashMap::new();
+        let operation_count = self.operations.len();
+        let mut operations = Vec::with_capacity(operation_count);
+        for mut operation in self.operations.drain(..) {
+            operation.rename_inputs(&renames);
+            match computed.get(&operation.key()) {
+                Some(wire) => renames[operation.output().0] = *wire,
+                None => {
+                    computed.insert(operation.key(), operation.output());
+                    operations.push(operation);
+                }
+            }
+        }
+        self.operations = operations;
+        for output in self.outputs.iter_mut() {
+            *output = renames[output.0];
+        }
+        operation_count - self.operations.len()
+    }
+
+    /// Executes the circuit with the given engines and keys, and returns the outputs.
+    ///
+    /// The ciphertexts of the inputs, and of the wires which are not outputs, are reused as the
+    /// output buffers of the later operations once they are no longer read. A new ciphertext is
+    /// only allocated, as a trivial encryption of `keys.zero`, when no ciphertext of the right
+    /// dimension is available.
+    pub fn execute<Entities: CircuitEntities>(
+        &self,
+        mut engines: CircuitEngines<'_, Entities>,
+        keys: CircuitKeys<'_, Entities>,
+        inputs: Vec<Entities::LweCiphertext>,
+    ) -> Result<Vec<Entities::LweCiphertext>, CircuitError> {
+        if inputs.len() != self.inputs.len() {
+            return Err(CircuitError::InputCount {
+                expected: self.inputs.len(),
+                actual: inputs.len(),
+            });
+        }
+
+        // The index of the last operation reading every wire, if any. The output wires are never
+        // released.
+        let mut last_reads: Vec<Option<usize>> = vec![None; self.wire_count];
+        for (index, operation) in self.operations.iter().enumerate() {
+            for wire in operation.inputs() {
+                last_reads[wire.0] = Some(index);
+            }
+        }
+        let mut is_output = vec![false; self.wire_count];
+        for wire in self.outputs.iter() {
+            is_output[wire.0] = true;
+        }
+
+        let mut values: Vec<Option<Entities::LweCiphertext>> =
+            (0..self.wire_count).map(|_| None).collect();
+        let mut buffers = BufferPool::default();
+        for (wire, input) in self.inputs.iter().zip(inputs) {
+            if last_reads[wire.0].is_none() && !is_output[wire.0] {
+                buffers.release(input);
+            } else {
+                values[wire.0] = Some(input);
+            }
+        }
+
+        for (index, operation) in self.operations.iter().enumerate() {
+            let output_dimension = match operation {
+                CircuitOperation::Addition { lhs, .. } => {
+                    values[lhs.0].as_ref().unwrap().lwe_dimension()
+                }
+                CircuitOperation::Keyswitch { .. } => keys
+                    .ksk
+                    .ok_or(CircuitError::MissingKey(index))?
+                    .output_lwe_dimension(),
+                CircuitOperation::Bootstrap { .. } => keys
+                    .bsk
+                    .ok_or(CircuitError::MissingKey(index))?
+                    .output_lwe_dimension(),
+            };
+            let mut output =
+                buffers.acquire(output_dimension, keys.zero, engines.allocator, index)?;
+
+            match operation {
+                CircuitOperation::Addition { lhs, rhs, .. } => {
+                    let adder = engines
+                        .adder
+                        .as_mut()
+                        .ok_or(CircuitError::MissingEngine(index))?;
+                    adder
+                        .discard_add_lwe_ciphertext(
+                            &mut output,
+                            values[lhs.0].as_ref().unwrap(),
+                            values[rhs.0].as_ref().unwrap(),
+                        )
+                        .map_err(|error| CircuitError::operation(index, error))?;
+                }
+                CircuitOperation::Keyswitch { input, .. } => {
+                    let keyswitcher = engines
+                        .keyswitcher
+                        .as_mut()
+                        .ok_or(CircuitError::MissingEngine(index))?;
+                    let ksk = keys.ksk.ok_or(CircuitError::MissingKey(index))?;
+                    keyswitcher
+                        .discard_keyswitch_lwe_ciphertext(
+                            &mut output,
+                            values[input.0].as_ref().unwrap(),
+                            ksk,
+                        )
+                        .map_err(|error| CircuitError::operation(index, error))?;
+                }
+                CircuitOperation::Bootstrap {
+                    input, accumulator, ..
+                } => {
+                    let bootstrapper = engines
+                        .bootstrapper
+                        .as_mut()
+                        .ok_or(CircuitError::MissingEngine(index))?;
+                    let bsk = keys.bsk.ok_or(CircuitError::MissingKey(index))?;
+                    let acc = keys
+                        .accumulators
+                        .get(*accumulator)
+                        .ok_or(CircuitError::MissingAccumulator(index))?;
+                    bootstrapper
+                        .discard_bootstrap_lwe_ciphertext(
+                            &mut output,
+                            values[input.0].as_ref().unwrap(),
+                            acc,
+                            bsk,
+                        )
+                        .map_err(|error| CircuitError::operation(index, error))?;
+                }
+            }
+
+            // The output is released right away if it is never read, and the inputs are released
+            // after their last read.
+            let output_wire = operation.output();
+            if last_reads[output_wire.0].is_none() && !is_output[output_wire.0] {
+                buffers.release(output);
+            } else {
+                values[output_wire.0] = Some(output);
+            }
+            for wire in operation.inputs() {
+                if last_reads[wire.0] == Some(index) && !is_output[wire.0] {
+                    if let Some(value) = values[wire.0].take() {
+                        buffers.release(value);
+                    }
+                }
+            }
+        }
+
+        // A wire marked several times as an output is cloned for all but its last occurrence.
+        let outputs = self
+            .outputs
+            .iter()
+            .enumerate()
+            .map(|(position, wire)| {
+                if self.outputs[position + 1..].contains(wire) {
+                    values[wire.0].clone().unwrap()
+                } else {
+                    values[wire.0].take().unwrap()
+                }
+            })
+            .collect();
+        Ok(outputs)
+    }
+
+    fn new_wire(&mut self) -> Wire {
+        let wire = Wire(self.wire_count);
+        self.wire_count += 1;
+        wire
+    }
+
+    fn check_wire(&self, wire: Wire) {
+        assert!(
+            wire.0 < self.wire_count,
+            "The wire {} does not belong to the circuit.",
+            wire.0
+        );
+    }
+}
+
+// The released ciphertexts, sorted by dimension.
+struct BufferPool<Ciphertext> {
+    buffers: BTreeMap<LweDimension, Vec<Ciphertext>>,
+}
+
+impl<Ciphertext> Default for BufferPool<Ciphertext> {
+    fn default() -> Self {
+        BufferPool {
+            buffers: BTreeMap::new(),
+        }
+    }
+}
+
+impl<Ciphertext: LweCiphertextEntity> BufferPool<Ciphertext> {
+    fn release(&mut self, ciphertext: Ciphertext) {
+        self.buffers
+            .entry(ciphertext.lwe_dimension())
+            .or_default()
+            .push(ciphertext);
+    }
+
+    fn acquire<Plaintext: PlaintextEntity>(
+        &mut self,
+        lwe_dimension: LweDimension,
+        zero: &Plaintext,
+        allocator: &mut dyn DynLweTrivialEncryptor<Plaintext, Ciphertext>,
+        index: usize,
+    ) -> Result<Ciphertext, CircuitError> {
+        match self
+            .buffers
+            .get_mut(&lwe_dimension)
+            .and_then(|buffers| buffers.pop())
+        {
+            Some(ciphertext) => Ok(ciphertext),
+            None => allocator
+                .trivially_encrypt_lwe_ciphertext(lwe_dimension.to_lwe_size(), zero)
+                .map_err(|error| CircuitError::operation(index, error)),
+        }
+    }
+}
+
+/// The entity types a [`Circuit`] is executed with.
+///
+/// This trait is implemented for the tuples
+/// `(Plaintext, Ciphertext, KeyswitchKey, BootstrapKey, Accumulator)`.
+pub trait CircuitEntities {
+    type Plaintext: PlaintextEntity;
+    type LweCiphertext: LweCiphertextEntity + Clone;
+    type LweKeyswitchKey: LweKeyswitchKeyEntity;
+    type LweBootstrapKey: LweBootstrapKeyEntity;
+    type GlweCiphertext: GlweCiphertextEntity;
+}
+
+impl<Plaintext, Ciphertext, KeyswitchKey, BootstrapKey, Accumulator> CircuitEntities
+    for (
+        Plaintext,
+        Ciphertext,
+        KeyswitchKey,
+        BootstrapKey,
+        Accumulator,
+    )
+where
+    Plaintext: PlaintextEntity,
+    Ciphertext: LweCiphertextEntity + Clone,
+    KeyswitchKey: LweKeyswitchKeyEntity,
+    BootstrapKey: LweBootstrapKeyEntity,
+    Accumulator: GlweCiphertextEntity,
+{
+    type Plaintext = Plaintext;
+    type LweCiphertext = Ciphertext;
+    type LweKeyswitchKey = KeyswitchKey;
+    type LweBootstrapKey = BootstrapKey;
+    type GlweCiphertext = Accumulator;
+}
+
+/// The engines a [`Circuit`] is executed with.
+///
+/// The allocator is used to create the output buffers which can not be reused from previous
+/// operations. The other engines are only needed for the kinds of operations contained in the
+/// circuit, and the same engine can not be used for several roles at once.
+pub struct CircuitEngines<'engines, Entities: CircuitEntities> {
+    pub allocator:
+        &'engines mut dyn DynLweTrivialEncryptor<Entities::Plaintext, Entities::LweCiphertext>,
+    pub adder:
+        Option<&'engines mut dyn DynLweAdder<Entities::LweCiphertext, Entities::LweCiphertext>>,
+    pub keyswitcher: Option<
+        &'engines mut dyn DynKeyswitcher<
+            Entities::LweKeyswitchKey,
+            Entities::LweCiphertext,
+            Entities::LweCiphertext,
+        >,
+    >,
+    #[allow(clippy::type_complexity)]
+    pub bootstrapper: Option<
+        &'engines mut dyn DynBootstrapper<
+            Entities::LweBootstrapKey,
+            Entities::GlweCiphertext,
+            Entities::LweCiphertext,
+            Entities::LweCiphertext,
+        >,
+    >,
+}
+
+/// The keys a [`Circuit`] is executed with.
+///
+/// The `zero` plaintext is trivially encrypted to allocate new output buffers. The keys are only
+/// needed for the kinds of operations contained in the circuit.
+pub struct CircuitKeys<'keys, Entities: CircuitEntities> {
+    pub zero: &'keys Entities::Plaintext,
+    pub ksk: Option<&'keys Entities::LweKeyswitchKey>,
+    pub bsk: Option<&'keys Entities::LweBootstrapKey>,
+    pub accumulators: &'keys [Entities::GlweCiphertext],
+}
+
+/// The error which can occur while executing a [`Circuit`].
+#[derive(Debug)]
+pub enum CircuitError {
+    /// The number of inputs does not match the circuit.
+    InputCount { expected: usize, actual: usize },
+    /// No engine was given for the operation with the given index.
+    MissingEngine(usize),
+    /// No key was given for the operation with the given index.
+    MissingKey(usize),
+    /// The accumulator of the operation with the given index does not exist.
+    MissingAccumulator(usize),
+    /// The operation with the given index failed.
+    Operation { index: usize, error: Box<dyn Error> },
+}
+
+impl CircuitError {
+    fn operation<OperationError: Error + 'static>(index: usize, error: OperationError) -> Self {
+        CircuitError::Operation {
+            index,
+            error: Box::new(error),
+        }
+    }
+}
+
+impl Display for CircuitError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CircuitError::InputCount { expected, actual } => {
+                write!(f, "Expected {} inputs, got {}.", expected, actual)
+            }
+            CircuitError::MissingEngine(index) => {
+                write!(f, "No engine was given to execute the operation {}.", index)
+            }
+            CircuitError::MissingKey(index) => {
+                write!(f, "No key was given to execute the operation {}.", index)
+            }
+            CircuitError::MissingAccumulator(index) => {
+                write!(f, "The accumulator of the operation {} is missing.", index)
+            }
+            CircuitError::Operation { index, error } => {
+                write!(f, "The operation {} failed: {}", index, error)
+            }
+        }
+    }
+}
+
+impl Error for CircuitError {}
@@ -0,0 +1,318 @@
+//! A module containing object-safe subsets of the engine traits.
+//!
+//! The `*Engine` traits can not be used to build trait objects directly, since their
+//! [`AbstractEngine`] super-trait carries two associated types (the engine error and the
+//! constructor parameters) which differ from one backend to the other. This makes it cumbersome to
+//! select a backend at runtime, for instance from a configuration file.
+//!
+//! The `Dyn*` traits defined in this module expose a subset of the operations, in a way that is
+//! independent of the backend:
+//!
+//! + Only the safe entry points are exposed.
+//! + The _specific_ engine errors are erased into a [`DynEngineError`], while the _generic_ error
+//!   variants are kept as is.
+//!
+//! Every `Dyn*` trait is implemented for every engine implementing the matching `*Engine` trait,
+//! so that any engine can be turned into a trait object:
+//!
+//! ```
+//! use concrete_commons::dispersion::Variance;
+//! use concrete_commons::parameters::LweDimension;
+//! use concrete_core::prelude::*;
+//! use concrete_core::specification::engines::dynamic::DynLweEncryptor;
+//! # use std::error::Error;
+//!
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+//! let lwe_dimension = LweDimension(2);
+//! let noise = Variance(2_f64.powf(-25.));
+//!
+//! // Unix seeder must be given a secret input.
+//! // Here we just give it 0, which is totally unsafe.
+//! const UNSAFE_SECRET: u128 = 0;
+//! let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+//! let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+//! let plaintext = engine.create_plaintext_from(&(3_u64 << 50))?;
+//!
+//! // The backend is hidden behind the trait object.
+//! let mut encryptor: Box<dyn DynLweEncryptor<LweSecretKey64, Plaintext64, LweCiphertext64>> =
+//!     Box::new(engine);
+//! let ciphertext = encryptor.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+//! #
+//! assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+//! #
+//! # Ok(())
+//! # }
+//! ```
+use super::{
+    AbstractEngine, LweCiphertextDecryptionEngine, LweCiphertextDecryptionError,
+    LweCiphertextDiscardingAdditionEngine, LweCiphertextDiscardingAdditionError,
+    LweCiphertextDiscardingBootstrapEngine, LweCiphertextDiscardingBootstrapError,
+    LweCiphertextDiscardingKeyswitchEngine, LweCiphertextDiscardingKeyswitchError,
+    LweCiphertextEncryptionEngine, LweCiphertextEncryptionError,
+    LweCiphertextTrivialEncryptionEngine, LweCiphertextTrivialEncryptionError,
+};
+use crate::specification::entities::{
+    GlweCiphertextEntity, LweBootstrapKeyEntity, LweCiphertextEntity, LweKeyswitchKeyEntity,
+    LweSecretKeyEntity, PlaintextEntity,
+};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::LweSize;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+
+/// The engine error used by the `Dyn*` traits, in which the actual engine error is boxed.
+pub struct DynEngineError(Box<dyn Error>);
+
+impl DynEngineError {
+    fn erase<EngineError: Error + 'static>(error: EngineError) -> Self {
+        DynEngineError(Box::new(error))
+    }
+
+    /// Returns a reference to the underlying engine error.
+    pub fn inner(&self) -> &(dyn Error + 'static) {
+        self.0.as_ref()
+    }
+}
+
+impl Debug for DynEngineError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&self.0, f)
+    }
+}
+
+impl Display for DynEngineError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl Error for DynEngineError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(self.0.as_ref())
+    }
+}
+
+/// An object-safe subset of the [`LweCiphertextEncryptionEngine`] trait.
+pub trait DynLweEncryptor<SecretKey, Plaintext, Ciphertext>
+where
+    SecretKey: LweSecretKeyEntity,
+    Plaintext: PlaintextEntity,
+    Ciphertext: LweCiphertextEntity,
+{
+    /// Encrypts an LWE ciphertext.
+    fn encrypt_lwe_ciphertext(
+        &mut self,
+        key: &SecretKey,
+        input: &Plaintext,
+        noise: Variance,
+    ) -> Result<Ciphertext, LweCiphertextEncryptionError<DynEngineError>>;
+}
+
+impl<Engine, SecretKey, Plaintext, Ciphertext> DynLweEncryptor<SecretKey, Plaintext, Ciphertext>
+    for Engine
+where
+    Engine: LweCiphertextEncryptionEngine<SecretKey, Plaintext, Ciphertext>,
+    <Engine as AbstractEngine>::EngineError: 'static,
+    SecretKey: LweSecretKeyEntity,
+    Plaintext: PlaintextEntity,
+    Ciphertext: LweCiphertextEntity,
+{
+    fn encrypt_lwe_ciphertext(
+        &mut self,
+        key: &SecretKey,
+        input: &Plaintext,
+        noise: Variance,
+    ) -> Result<Ciphertext, LweCiphertextEncryptionError<DynEngineError>> {
+        LweCiphertextEncryptionEngine::encrypt_lwe_ciphertext(self, key, input, noise)
+            .map_err(|error| error.map_engine_error(DynEngineError::erase))
+    }
+}
+
+/// An object-safe subset of the [`LweCiphertextDecryptionEngine`] trait.
+pub trait DynLweDecryptor<SecretKey, Ciphertext, Plaintext>
+where
+    SecretKey: LweSecretKeyEntity,
+    Ciphertext: LweCiphertextEntity,
+    Plaintext: PlaintextEntity,
+{
+    /// Decrypts an LWE ciphertext.
+    fn decrypt_lwe_ciphertext(
+        &mut self,
+        key: &SecretKey,
+        input: &Ciphertext,
+    ) -> Result<Plaintext, LweCiphertextDecryptionError<DynEngineError>>;
+}
+
+impl<Engine, SecretKey, Ciphertext, Plaintext> DynLweDecryptor<SecretKey, Ciphertext, Plaintext>
+    for Engine
+where
+    Engine: LweCiphertextDecryptionEngine<SecretKey, Ciphertext, Plaintext>,
+    <Engine as AbstractEngine>::EngineError: 'static,
+    SecretKey: LweSecretKeyEntity,
+    Ciphertext: LweCiphertextEntity,
+    Plaintext: PlaintextEntity,
+{
+    fn decrypt_lwe_ciphertext(
+        &mut self,
+        key: &SecretKey,
+        input: &Ciphertext,
+    ) -> Result<Plaintext, LweCiphertextDecryptionError<DynEngineError>> {
+        LweCiphertextDecryptionEngine::decrypt_lwe_ciphertext(self, key, input)
+            .map_err(|error| error.map_engine_error(DynEngineError::erase))
+    }
+}
+
+/// An object-safe subset of the [`LweCiphertextTrivialEncryptionEngine`] trait.
+pub trait DynLweTrivialEncryptor<Plaintext, Ciphertext>
+where
+    Plaintext: PlaintextEntity,
+    Ciphertext: LweCiphertextEntity,
+{
+    /// Trivially encrypts an LWE ciphertext.
+    fn trivially_encrypt_lwe_ciphertext(
+        &mut self,
+        lwe_size: LweSize,
+        input: &Plaintext,
+    ) -> Result<Ciphertext, LweCiphertextTrivialEncryptionError<DynEngineError>>;
+}
+
+impl<Engine, Plaintext, Ciphertext> DynLweTrivialEncryptor<Plaintext, Ciphertext> for Engine
+where
+    Engine: LweCiphertextTrivialEncryptionEngine<Plaintext, Ciphertext>,
+    <Engine as AbstractEngine>::EngineError: 'static,
+    Plaintext: PlaintextEntity,
+    Ciphertext: LweCiphertextEntity,
+{
+    fn trivially_encrypt_lwe_ciphertext(
+        &mut self,
+        lwe_size: LweSize,
+        input: &Plaintext,
+    ) -> Result<Ciphertext, LweCiphertextTrivialEncryptionError<DynEngineError>> {
+        LweCiphertextTrivialEncryptionEngine::trivially_encrypt_lwe_ciphertext(
+            self, lwe_size, input,
+        )
+        .map_err(|error| error.map_engine_error(DynEngineError::erase))
+    }
+}
+
+/// An object-safe subset of the [`LweCiphertextDiscardingAdditionEngine`] trait.
+pub trait DynLweAdder<InputCiphertext, OutputCiphertext>
+where
+    InputCiphertext: LweCiphertextEntity,
+    OutputCiphertext: LweCiphertextEntity,
+{
+    /// Adds two LWE ciphertexts.
+    fn discard_add_lwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input_1: &InputCiphertext,
+        input_2: &InputCiphertext,
+    ) -> Result<(), LweCiphertextDiscardingAdditionError<DynEngineError>>;
+}
+
+impl<Engine, InputCiphertext, OutputCiphertext> DynLweAdder<InputCiphertext, OutputCiphertext>
+    for Engine
+where
+    Engine: LweCiphertextDiscardingAdditionEngine<InputCiphertext, OutputCiphertext>,
+    <Engine as AbstractEngine>::EngineError: 'static,
+    InputCiphertext: LweCiphertextEntity,
+    OutputCiphertext: LweCiphertextEntity,
+{
+    fn discard_add_lwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input_1: &InputCiphertext,
+        input_2: &InputCiphertext,
+    ) -> Result<(), LweCiphertextDiscardingAdditionError<DynEngineError>> {
+        LweCiphertextDiscardingAdditionEngine::discard_add_lwe_ciphertext(
+            self, output, input_1, input_2,
+        )
+        .map_err(|error| error.map_engine_error(DynEngineError::erase))
+    }
+}
+
+/// An object-safe subset of the [`LweCiphertextDiscardingKeyswitchEngine`] trait.
+pub trait DynKeyswitcher<KeyswitchKey, InputCiphertext, OutputCiphertext>
+where
+    KeyswitchKey: LweKeyswitchKeyEntity,
+    InputCiphertext: LweCiphertextEntity,
+    OutputCiphertext: LweCiphertextEntity,
+{
+    /// Keyswitch an LWE ciphertext.
+    fn discard_keyswitch_lwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        ksk: &KeyswitchKey,
+    ) -> Result<(), LweCiphertextDiscardingKeyswitchError<DynEngineError>>;
+}
+
+impl<Engine, KeyswitchKey, InputCiphertext, OutputCiphertext>
+    DynKeyswitcher<KeyswitchKey, InputCiphertext, OutputCiphertext> for Engine
+where
+    Engine: LweCiphertextDiscardingKeyswitchEngine<KeyswitchKey, InputCiphertext, OutputCiphertext>,
+    <Engine as AbstractEngine>::EngineError: 'static,
+    KeyswitchKey: LweKeyswitchKeyEntity,
+    InputCiphertext: LweCiphertextEntity,
+    OutputCiphertext: LweCiphertextEntity,
+{
+    fn discard_keyswitch_lwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        ksk: &KeyswitchKey,
+    ) -> Result<(), LweCiphertextDiscardingKeyswitchError<DynEngineError>> {
+        LweCiphertextDiscardingKeyswitchEngine::discard_keyswitch_lwe_ciphertext(
+            self, output, input, ksk,
+        )
+        .map_err(|error| error.map_engine_error(DynEngineError::erase))
+    }
+}
+
+/// An object-safe subset of the [`LweCiphertextDiscardingBootstrapEngine`] trait.
+pub trait DynBootstrapper<BootstrapKey, Accumulator, InputCiphertext, OutputCiphertext>
+where
+    BootstrapKey: LweBootstrapKeyEntity,
+    Accumulator: GlweCiphertextEntity,
+    InputCiphertext: LweCiphertextEntity,
+    OutputCiphertext: LweCiphertextEntity,
+{
+    /// Bootstrap an LWE ciphertext.
+    fn discard_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        acc: &Accumulator,
+        bsk: &BootstrapKey,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapError<DynEngineError>>;
+}
+
+impl<Engine, BootstrapKey, Accumulator, InputCiphertext, OutputCiphertext>
+    DynBootstrapper<BootstrapKey, Accumulator, InputCiphertext, OutputCiphertext> for Engine
+where
+    Engine: LweCiphertextDiscardingBootstrapEngine<
+        BootstrapKey,
+        Accumulator,
+        InputCiphertext,
+        OutputCiphertext,
+    >,
+    <Engine as AbstractEngine>::EngineError: 'static,
+    BootstrapKey: LweBootstrapKeyEntity,
+    Accumulator: GlweCiphertextEntity,
+    InputCiphertext: LweCiphertextEntity,
+    OutputCiphertext: LweCiphertextEntity,
+{
+    fn discard_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        acc: &Accumulator,
+        bsk: &BootstrapKey,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapError<DynEngineError>> {
+        LweCiphertextDiscardingBootstrapEngine::discard_bootstrap_lwe_ciphertext(
+            self, output, input, acc, bsk,
+        )
+        .map_err(|error| error.map_engine_error(DynEngineError::erase))
+    }
+}
@@ -1,6 +1,7 @@
 use super::engine_error;
 use crate::prelude::AbstractEntity;
 use crate::specification::engines::AbstractEngine;
+use std::io::Read;
 
 engine_error! {
     EntityDeserializationError for EntityDeserializationEngine @
@@ -12,6 +13,10 @@ engine_error! {
 ///
 /// This [pure](super#operation-semantics) operation generates an entity containing the
 /// deserialization of the `serialized` type.
+///
+/// The [`deserialize_from_reader`](Self::deserialize_from_reader) variant reads the serialized
+/// entity from a [`Read`] implementor instead, which allows large entities to be streamed from a
+/// file or a socket without being buffered in memory first.
 pub trait EntityDeserializationEngine<Serialized, Entity>: AbstractEngine
 where
     Entity: AbstractEntity,
@@ -29,4 +34,18 @@ where
     /// of [`EntityDeserializationError`]. For safety concerns _specific_ to an engine, refer to
     /// the implementer safety section.
     unsafe fn deserialize_unchecked(&mut self, serialized: Serialized) -> Entity;
+
+    /// Deserializes an entity from a reader.
+    fn deserialize_from_reader<R: Read>(
+        &mut self,
+        reader: R,
+    ) -> Result<Entity, EntityDeserializationError<Self::EngineError>>;
+
+    /// Unsafely deserializes an entity from a reader.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`EntityDeserializationError`]. For safety concerns _specific_ to an engine, refer to
+    /// the implementer safety section.
+    unsafe fn deserialize_from_reader_unchecked<R: Read>(&mut self, reader: R) -> Entity;
 }
@@ -0,0 +1,40 @@
+use super::engine_error;
+use crate::prelude::AbstractEntity;
+use crate::specification::engines::AbstractEngine;
+
+engine_error! {
+    EntityRawExportError for EntityRawExportEngine @
+}
+
+/// A trait for engines exporting entities to raw dumps.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a byte string containing the raw
+/// dump of `entity`. Contrarily to the serialization, the layout of the dump is simple enough to
+/// be read by external tools, such as verification scripts:
+///
+/// - a header made of 64 bits little-endian words, containing the dimensions of the entity ($n$
+///   for LWE entities, $k$ then $N$ for GLWE entities), followed by the bit width of the
+///   coefficients;
+/// - the coefficients, each stored little-endian on the bit width given in the header, in the
+///   order of the entity: the mask then the body for ciphertexts, and polynomial after polynomial
+///   by increasing degree for GLWE entities.
+pub trait EntityRawExportEngine<Entity>: AbstractEngine
+where
+    Entity: AbstractEntity,
+{
+    /// Exports an entity to a raw dump.
+    fn export_raw(
+        &mut self,
+        entity: &Entity,
+    ) -> Result<Vec<u8>, EntityRawExportError<Self::EngineError>>;
+
+    /// Unsafely exports an entity to a raw dump.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`EntityRawExportError`]. For safety concerns _specific_ to an engine, refer to
+    /// the implementer safety section.
+    unsafe fn export_raw_unchecked(&mut self, entity: &Entity) -> Vec<u8>;
+}
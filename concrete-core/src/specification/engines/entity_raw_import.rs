@@ -0,0 +1,32 @@
+use super::engine_error;
+use crate::prelude::AbstractEntity;
+use crate::specification::engines::AbstractEngine;
+
+engine_error! {
+    EntityRawImportError for EntityRawImportEngine @
+    InvalidLength => "The length of the raw dump does not match its header.",
+    ScalarSizeMismatch => "The coefficient width of the raw dump does not match the entity."
+}
+
+/// A trait for engines importing entities from raw dumps.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates an entity from a `raw` dump, with
+/// the layout described in [`EntityRawExportEngine`](`super::EntityRawExportEngine`).
+pub trait EntityRawImportEngine<Entity>: AbstractEngine
+where
+    Entity: AbstractEntity,
+{
+    /// Imports an entity from a raw dump.
+    fn import_raw(&mut self, raw: &[u8])
+        -> Result<Entity, EntityRawImportError<Self::EngineError>>;
+
+    /// Unsafely imports an entity from a raw dump.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`EntityRawImportError`]. For safety concerns _specific_ to an engine, refer to
+    /// the implementer safety section.
+    unsafe fn import_raw_unchecked(&mut self, raw: &[u8]) -> Entity;
+}
@@ -1,6 +1,7 @@
 use super::engine_error;
 use crate::prelude::AbstractEntity;
 use crate::specification::engines::AbstractEngine;
+use std::io::Write;
 
 engine_error! {
     EntitySerializationError for EntitySerializationEngine @
@@ -12,6 +13,10 @@ engine_error! {
 ///
 /// This [pure](super#operation-semantics) operation generates a value containing the serialization
 /// of `entity`.
+///
+/// The [`serialize_to_writer`](Self::serialize_to_writer) variant writes the serialization of
+/// `entity` to a [`Write`] implementor instead, which allows large entities to be streamed to a file
+/// or a socket without being buffered in memory first.
 pub trait EntitySerializationEngine<Entity, Serialized>: AbstractEngine
 where
     Entity: AbstractEntity,
@@ -29,4 +34,19 @@ where
     /// of [`EntitySerializationError`]. For safety concerns _specific_ to an engine, refer to
     /// the implementer safety section.
     unsafe fn serialize_unchecked(&mut self, entity: &Entity) -> Serialized;
+
+    /// Serializes an entity into a writer.
+    fn serialize_to_writer<W: Write>(
+        &mut self,
+        entity: &Entity,
+        writer: W,
+    ) -> Result<(), EntitySerializationError<Self::EngineError>>;
+
+    /// Unsafely serializes an entity into a writer.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`EntitySerializationError`]. For safety concerns _specific_ to an engine, refer to
+    /// the implementer safety section.
+    unsafe fn serialize_to_writer_unchecked<W: Write>(&mut self, entity: &Entity, writer: W);
 }
@@ -0,0 +1,30 @@
+use super::engine_error;
+use crate::prelude::AbstractEntity;
+use crate::specification::engines::AbstractEngine;
+
+engine_error! {
+    EntityValidationError for EntityValidationEngine @
+    EmptyContainer => "The container of the entity is empty.",
+    NullDimension => "One of the dimensions declared by the entity is zero.",
+    InvalidContainerLength => "The length of the container of the entity does not match its \
+                               declared dimensions."
+}
+
+/// A trait for engines validating entities.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation checks that the container of `entity` is
+/// consistent with the dimensions it declares. Most entity constructors only check this in debug
+/// builds, which leaves malformed entities undetected in release builds. This is particularly
+/// useful on entities obtained from a deserialization or from a raw container, before feeding
+/// them to other engines.
+///
+/// This operation has no unchecked counterpart, as its only purpose is to perform the checks.
+pub trait EntityValidationEngine<Entity>: AbstractEngine
+where
+    Entity: AbstractEntity,
+{
+    /// Validates an entity.
+    fn validate(&mut self, entity: &Entity) -> Result<(), EntityValidationError<Self::EngineError>>;
+}
@@ -0,0 +1,486 @@
+//! A module containing an engine injecting faults in the outputs of the operations it executes.
+//!
+//! The [`FaultInjectionEngine`] wraps an engine, and randomly corrupts the LWE ciphertexts
+//! produced by the operations executed through it, with a configurable probability. Two kinds of
+//! faults can be injected:
+//!
+//! + A [`Fault::BitFlip`] flips one of the low-order bits of one coefficient of the ciphertext.
+//!   Depending on the number of bits reserved for the noise, the ciphertext may still decrypt to
+//!   the right message.
+//! + A [`Fault::MaximumNoise`] adds a uniformly random value to the body of the ciphertext, which
+//!   then decrypts to a random message.
+//!
+//! This engine is only meant to test the error handling and verification layers of applications
+//! built on top of `concrete-core`, and is only available with the `fault_injection` feature.
+//!
+//! The operations covered are the ones exposed by the [`dynamic`](super::dynamic) module. The
+//! decryption of LWE ciphertexts is executed without faults.
+//!
+//! ```
+//! use concrete_commons::dispersion::Variance;
+//! use concrete_commons::parameters::LweDimension;
+//! use concrete_core::prelude::*;
+//! use concrete_core::specification::engines::fault_injection::{
+//!     Fault, FaultInjectionEngine, FaultInjectionParameters,
+//! };
+//! # use std::error::Error;
+//!
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+//! let lwe_dimension = LweDimension(2);
+//! let noise = Variance(2_f64.powf(-50.));
+//!
+//! // Unix seeder must be given a secret input.
+//! // Here we just give it 0, which is totally unsafe.
+//! const UNSAFE_SECRET: u128 = 0;
+//! let engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+//! // Every output gets the maximum noise.
+//! let parameters = FaultInjectionParameters::new().with_maximum_noise(1.);
+//! let mut engine = FaultInjectionEngine::from_engine(
+//!     engine,
+//!     parameters,
+//!     Box::new(UnixSeeder::new(UNSAFE_SECRET)),
+//! );
+//! let key: LweSecretKey64 = engine
+//!     .engine_mut()
+//!     .generate_new_lwe_secret_key(lwe_dimension)?;
+//! let plaintext = engine.engine_mut().create_plaintext_from(&(3_u64 << 50))?;
+//!
+//! let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+//! assert_eq!(engine.fault_count(Fault::MaximumNoise), 1);
+//! assert_eq!(engine.fault_count(Fault::BitFlip), 0);
+//! #
+//! # Ok(())
+//! # }
+//! ```
+use super::sealed::AbstractEngineSeal;
+use super::{
+    AbstractEngine, LweCiphertextDecryptionEngine, LweCiphertextDecryptionError,
+    LweCiphertextDiscardingAdditionEngine, LweCiphertextDiscardingAdditionError,
+    LweCiphertextDiscardingBootstrapEngine, LweCiphertextDiscardingBootstrapError,
+    LweCiphertextDiscardingKeyswitchEngine, LweCiphertextDiscardingKeyswitchError,
+    LweCiphertextEncryptionEngine, LweCiphertextEncryptionError,
+    LweCiphertextTrivialEncryptionEngine, LweCiphertextTrivialEncryptionError,
+};
+use crate::commons::math::random::RandomGenerator;
+use crate::specification::entities::{
+    GlweCiphertextEntity, LweBootstrapKeyEntity, LweCiphertextEntity, LweKeyswitchKeyEntity,
+    LweSecretKeyEntity, PlaintextEntity,
+};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::LweSize;
+use concrete_csprng::generators::SoftwareRandomGenerator;
+use concrete_csprng::seeders::Seeder;
+
+/// An LWE ciphertext in which faults can be injected by a [`FaultInjectionEngine`].
+pub trait FaultInjectableEntity: LweCiphertextEntity {
+    /// Returns the bit width of the coefficients of the ciphertext.
+    fn coefficient_bits(&self) -> usize;
+
+    /// Flips a bit of a coefficient of the ciphertext, the body being the last coefficient.
+    fn flip_coefficient_bit(&mut self, coefficient: usize, bit: usize);
+
+    /// Adds a value to the body of the ciphertext, truncated to the coefficient bit width.
+    fn wrapping_add_to_body(&mut self, value: u64);
+}
+
+// Implements `FaultInjectableEntity` for the LWE ciphertexts whose inner type is a
+// `crate::commons::crypto::lwe::LweCiphertext` of owned unsigned integers.
+macro_rules! impl_fault_injectable_entity {
+    ($($entity: ident => $scalar: ty),+ $(,)?) => {
+        $(
+            impl $crate::specification::engines::fault_injection::FaultInjectableEntity
+                for $entity
+            {
+                fn coefficient_bits(&self) -> usize {
+                    <$scalar>::BITS as usize
+                }
+
+                fn flip_coefficient_bit(&mut self, coefficient: usize, bit: usize) {
+                    use $crate::commons::math::tensor::{AsMutSlice, AsMutTensor};
+                    self.0.as_mut_tensor().as_mut_slice()[coefficient] ^= 1 << bit;
+                }
+
+                fn wrapping_add_to_body(&mut self, value: u64) {
+                    let body = self.0.get_mut_body();
+                    body.0 = body.0.wrapping_add(value as $scalar);
+                }
+            }
+        )+
+    };
+}
+pub(crate) use impl_fault_injectable_entity;
+
+/// The kinds of faults injected by a [`FaultInjectionEngine`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Fault {
+    /// A flip of one of the low-order bits of one coefficient.
+    BitFlip,
+    /// The addition of a uniformly random value to the body.
+    MaximumNoise,
+}
+
+/// The parameters of a [`FaultInjectionEngine`].
+///
+/// At most one fault is injected in every output: the maximum noise is drawn first, and the bit
+/// flip is only drawn when no maximum noise was injected.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FaultInjectionParameters {
+    bit_flip_probability: f64,
+    low_order_bits: usize,
+    maximum_noise_probability: f64,
+}
+
+impl FaultInjectionParameters {
+    /// Creates parameters injecting no fault.
+    pub fn new() -> Self {
+        FaultInjectionParameters {
+            bit_flip_probability: 0.,
+            low_order_bits: 0,
+            maximum_noise_probability: 0.,
+        }
+    }
+
+    /// Flips one of the `low_order_bits` least significant bits of one random coefficient of the
+    /// outputs, with the given probability.
+    ///
+    /// # Panics
+    /// Panics if the probability is not in `[0, 1]`, or if `low_order_bits` is zero.
+    pub fn with_bit_flips(mut self, probability: f64, low_order_bits: usize) -> Self {
+        assert!(
+            (0. ..=1.).contains(&probability),
+            "The bit flip probability must be in [0, 1], got {probability}."
+        );
+        assert!(
+            low_order_bits > 0,
+            "At least one low-order bit must be flipped."
+        );
+        self.bit_flip_probability = probability;
+        self.low_order_bits = low_order_bits;
+        self
+    }
+
+    /// Adds a uniformly random value to the body of the outputs, with the given probability.
+    ///
+    /// # Panics
+    /// Panics if the probability is not in `[0, 1]`.
+    pub fn with_maximum_noise(mut self, probability: f64) -> Self {
+        assert!(
+            (0. ..=1.).contains(&probability),
+            "The maximum noise probability must be in [0, 1], got {probability}."
+        );
+        self.maximum_noise_probability = probability;
+        self
+    }
+}
+
+impl Default for FaultInjectionParameters {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An engine injecting faults in the LWE ciphertexts produced by the engine it wraps.
+///
+/// The faults are only injected in the outputs of the operations which succeed, and the
+/// operations executed directly with the wrapped engine are not affected. The errors of the
+/// wrapped engine are returned unchanged.
+pub struct FaultInjectionEngine<Engine> {
+    engine: Engine,
+    parameters: FaultInjectionParameters,
+    generator: RandomGenerator<SoftwareRandomGenerator>,
+    bit_flip_count: usize,
+    maximum_noise_count: usize,
+}
+
+impl<Engine> FaultInjectionEngine<Engine> {
+    /// Wraps an engine, drawing the faults from a generator seeded with the given seeder.
+    pub fn from_engine(
+        engine: Engine,
+        parameters: FaultInjectionParameters,
+        mut seeder: Box<dyn Seeder>,
+    ) -> Self {
+        FaultInjectionEngine {
+            engine,
+            parameters,
+            generator: RandomGenerator::new(seeder.seed()),
+            bit_flip_count: 0,
+            maximum_noise_count: 0,
+        }
+    }
+
+    /// Returns the parameters of the faults.
+    pub fn parameters(&self) -> FaultInjectionParameters {
+        self.parameters
+    }
+
+    /// Replaces the parameters of the faults.
+    pub fn set_parameters(&mut self, parameters: FaultInjectionParameters) {
+        self.parameters = parameters;
+    }
+
+    /// Returns the number of faults of a given kind injected so far.
+    pub fn fault_count(&self, fault: Fault) -> usize {
+        match fault {
+            Fault::BitFlip => self.bit_flip_count,
+            Fault::MaximumNoise => self.maximum_noise_count,
+        }
+    }
+
+    /// Returns a mutable reference to the wrapped engine.
+    ///
+    /// No fault is injected in the operations executed with the returned engine.
+    pub fn engine_mut(&mut self) -> &mut Engine {
+        &mut self.engine
+    }
+
+    /// Returns the wrapped engine.
+    pub fn into_engine(self) -> Engine {
+        self.engine
+    }
+
+    // Returns true with the given probability.
+    fn draw(&mut self, probability: f64) -> bool {
+        // The 53 most significant bits give a uniform value in [0, 1).
+        let uniform = (self.generator.random_uniform::<u64>() >> 11) as f64 / (1u64 << 53) as f64;
+        uniform < probability
+    }
+
+    // Injects at most one fault in an output.
+    fn inject<Ciphertext: FaultInjectableEntity>(&mut self, output: &mut Ciphertext) {
+        if self.draw(self.parameters.maximum_noise_probability) {
+            let value = self.generator.random_uniform::<u64>();
+            output.wrapping_add_to_body(value);
+            self.maximum_noise_count += 1;
+        } else if self.draw(self.parameters.bit_flip_probability) {
+            let coefficient_count = output.lwe_dimension().to_lwe_size().0;
+            let coefficient =
+                (self.generator.random_uniform::<u64>() % coefficient_count as u64) as usize;
+            let low_order_bits = self
+                .parameters
+                .low_order_bits
+                .min(output.coefficient_bits());
+            let bit = (self.generator.random_uniform::<u64>() % low_order_bits as u64) as usize;
+            output.flip_coefficient_bit(coefficient, bit);
+            self.bit_flip_count += 1;
+        }
+    }
+}
+
+impl<Engine: AbstractEngine> AbstractEngineSeal for FaultInjectionEngine<Engine> {}
+
+impl<Engine: AbstractEngine> AbstractEngine for FaultInjectionEngine<Engine> {
+    type EngineError = Engine::EngineError;
+    /// The parameters of the wrapped engine, of the faults, and the seeder of the faults.
+    type Parameters = (
+        Engine::Parameters,
+        FaultInjectionParameters,
+        Box<dyn Seeder>,
+    );
+
+    fn new(parameters: Self::Parameters) -> Result<Self, Self::EngineError> {
+        let (engine_parameters, parameters, seeder) = parameters;
+        Engine::new(engine_parameters)
+            .map(|engine| FaultInjectionEngine::from_engine(engine, parameters, seeder))
+    }
+}
+
+impl<Engine, SecretKey, Plaintext, Ciphertext>
+    LweCiphertextEncryptionEngine<SecretKey, Plaintext, Ciphertext> for FaultInjectionEngine<Engine>
+where
+    Engine: LweCiphertextEncryptionEngine<SecretKey, Plaintext, Ciphertext>,
+    SecretKey: LweSecretKeyEntity,
+    Plaintext: PlaintextEntity,
+    Ciphertext: FaultInjectableEntity,
+{
+    fn encrypt_lwe_ciphertext(
+        &mut self,
+        key: &SecretKey,
+        input: &Plaintext,
+        noise: Variance,
+    ) -> Result<Ciphertext, LweCiphertextEncryptionError<Self::EngineError>> {
+        let mut output = self.engine.encrypt_lwe_ciphertext(key, input, noise)?;
+        self.inject(&mut output);
+        Ok(output)
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &SecretKey,
+        input: &Plaintext,
+        noise: Variance,
+    ) -> Ciphertext {
+        let mut output = self
+            .engine
+            .encrypt_lwe_ciphertext_unchecked(key, input, noise);
+        self.inject(&mut output);
+        output
+    }
+}
+
+impl<Engine, SecretKey, Ciphertext, Plaintext>
+    LweCiphertextDecryptionEngine<SecretKey, Ciphertext, Plaintext> for FaultInjectionEngine<Engine>
+where
+    Engine: LweCiphertextDecryptionEngine<SecretKey, Ciphertext, Plaintext>,
+    SecretKey: LweSecretKeyEntity,
+    Ciphertext: LweCiphertextEntity,
+    Plaintext: PlaintextEntity,
+{
+    fn decrypt_lwe_ciphertext(
+        &mut self,
+        key: &SecretKey,
+        input: &Ciphertext,
+    ) -> Result<Plaintext, LweCiphertextDecryptionError<Self::EngineError>> {
+        self.engine.decrypt_lwe_ciphertext(key, input)
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &SecretKey,
+        input: &Ciphertext,
+    ) -> Plaintext {
+        self.engine.decrypt_lwe_ciphertext_unchecked(key, input)
+    }
+}
+
+impl<Engine, Plaintext, Ciphertext> LweCiphertextTrivialEncryptionEngine<Plaintext, Ciphertext>
+    for FaultInjectionEngine<Engine>
+where
+    Engine: LweCiphertextTrivialEncryptionEngine<Plaintext, Ciphertext>,
+    Plaintext: PlaintextEntity,
+    Ciphertext: FaultInjectableEntity,
+{
+    fn trivially_encrypt_lwe_ciphertext(
+        &mut self,
+        lwe_size: LweSize,
+        input: &Plaintext,
+    ) -> Result<Ciphertext, LweCiphertextTrivialEncryptionError<Self::EngineError>> {
+        let mut output = self
+            .engine
+            .trivially_encrypt_lwe_ciphertext(lwe_size, input)?;
+        self.inject(&mut output);
+        Ok(output)
+    }
+
+    unsafe fn trivially_encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        lwe_size: LweSize,
+        input: &Plaintext,
+    ) -> Ciphertext {
+        let mut output = self
+            .engine
+            .trivially_encrypt_lwe_ciphertext_unchecked(lwe_size, input);
+        self.inject(&mut output);
+        output
+    }
+}
+
+impl<Engine, InputCiphertext, OutputCiphertext>
+    LweCiphertextDiscardingAdditionEngine<InputCiphertext, OutputCiphertext>
+    for FaultInjectionEngine<Engine>
+where
+    Engine: LweCiphertextDiscardingAdditionEngine<InputCiphertext, OutputCiphertext>,
+    InputCiphertext: LweCiphertextEntity,
+    OutputCiphertext: FaultInjectableEntity,
+{
+    fn discard_add_lwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input_1: &InputCiphertext,
+        input_2: &InputCiphertext,
+    ) -> Result<(), LweCiphertextDiscardingAdditionError<Self::EngineError>> {
+        self.engine
+            .discard_add_lwe_ciphertext(output, input_1, input_2)?;
+        self.inject(output);
+        Ok(())
+    }
+
+    unsafe fn discard_add_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input_1: &InputCiphertext,
+        input_2: &InputCiphertext,
+    ) {
+        self.engine
+            .discard_add_lwe_ciphertext_unchecked(output, input_1, input_2);
+        self.inject(output);
+    }
+}
+
+impl<Engine, KeyswitchKey, InputCiphertext, OutputCiphertext>
+    LweCiphertextDiscardingKeyswitchEngine<KeyswitchKey, InputCiphertext, OutputCiphertext>
+    for FaultInjectionEngine<Engine>
+where
+    Engine: LweCiphertextDiscardingKeyswitchEngine<KeyswitchKey, InputCiphertext, OutputCiphertext>,
+    KeyswitchKey: LweKeyswitchKeyEntity,
+    InputCiphertext: LweCiphertextEntity,
+    OutputCiphertext: FaultInjectableEntity,
+{
+    fn discard_keyswitch_lwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        ksk: &KeyswitchKey,
+    ) -> Result<(), LweCiphertextDiscardingKeyswitchError<Self::EngineError>> {
+        self.engine
+            .discard_keyswitch_lwe_ciphertext(output, input, ksk)?;
+        self.inject(output);
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        ksk: &KeyswitchKey,
+    ) {
+        self.engine
+            .discard_keyswitch_lwe_ciphertext_unchecked(output, input, ksk);
+        self.inject(output);
+    }
+}
+
+impl<Engine, BootstrapKey, Accumulator, InputCiphertext, OutputCiphertext>
+    LweCiphertextDiscardingBootstrapEngine<
+        BootstrapKey,
+        Accumulator,
+        InputCiphertext,
+        OutputCiphertext,
+    > for FaultInjectionEngine<Engine>
+where
+    Engine: LweCiphertextDiscardingBootstrapEngine<
+        BootstrapKey,
+        Accumulator,
+        InputCiphertext,
+        OutputCiphertext,
+    >,
+    BootstrapKey: LweBootstrapKeyEntity,
+    Accumulator: GlweCiphertextEntity,
+    InputCiphertext: LweCiphertextEntity,
+    OutputCiphertext: FaultInjectableEntity,
+{
+    fn discard_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        acc: &Accumulator,
+        bsk: &BootstrapKey,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapError<Self::EngineError>> {
+        self.engine
+            .discard_bootstrap_lwe_ciphertext(output, input, acc, bsk)?;
+        self.inject(output);
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        acc: &Accumulator,
+        bsk: &BootstrapKey,
+    ) {
+        self.engine
+            .discard_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk);
+        self.inject(output);
+    }
+}
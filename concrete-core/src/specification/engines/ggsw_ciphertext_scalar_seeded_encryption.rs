@@ -0,0 +1,57 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    GgswSeededCiphertextEntity, GlweSecretKeyEntity, PlaintextEntity,
+};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+engine_error! {
+    GgswCiphertextScalarSeededEncryptionError for GgswCiphertextScalarSeededEncryptionEngine @
+}
+
+/// A trait for engines encrypting seeded GGSW ciphertexts containing a single plaintext.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a seeded GGSW ciphertext containing
+/// the encryption of the `input` plaintext, under the `key` secret key.
+///
+/// # Formal Definition
+///
+/// The masks of the GLWE ciphertexts composing the GGSW ciphertext are all generated from a single
+/// seed, which is stored in the output in place of the masks. The output can then be expanded into
+/// a GGSW ciphertext, for instance with
+/// [`GgswSeededCiphertextToGgswCiphertextTransformationEngine`](super::GgswSeededCiphertextToGgswCiphertextTransformationEngine).
+pub trait GgswCiphertextScalarSeededEncryptionEngine<SecretKey, Plaintext, Ciphertext>:
+    AbstractEngine
+where
+    SecretKey: GlweSecretKeyEntity,
+    Plaintext: PlaintextEntity,
+    Ciphertext: GgswSeededCiphertextEntity,
+{
+    /// Encrypts a plaintext into a seeded GGSW ciphertext.
+    fn encrypt_scalar_ggsw_seeded_ciphertext(
+        &mut self,
+        key: &SecretKey,
+        input: &Plaintext,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Result<Ciphertext, GgswCiphertextScalarSeededEncryptionError<Self::EngineError>>;
+
+    /// Unsafely encrypts a plaintext into a seeded GGSW ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GgswCiphertextScalarSeededEncryptionError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn encrypt_scalar_ggsw_seeded_ciphertext_unchecked(
+        &mut self,
+        key: &SecretKey,
+        input: &Plaintext,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Ciphertext;
+}
@@ -0,0 +1,51 @@
+use super::engine_error;
+use crate::prelude::AbstractEngine;
+
+use crate::specification::entities::{GgswCiphertextEntity, GgswSeededCiphertextEntity};
+
+engine_error! {
+    GgswSeededCiphertextToGgswCiphertextTransformationError for GgswSeededCiphertextToGgswCiphertextTransformationEngine @
+}
+
+/// A trait for engines transforming GGSW seeded ciphertexts into GGSW ciphertexts.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation moves the existing GGSW seeded ciphertext into
+/// a GGSW ciphertext.
+///
+/// # Formal Definition
+///
+/// ## GGSW seeded ciphertext to GGSW ciphertext transformation
+///
+/// The masks of all the GLWE ciphertexts composing the GGSW ciphertext are regenerated from the
+/// compression seed of the input, in the order in which they were generated at encryption time,
+/// following the algorithm described for
+/// [`GlweSeededCiphertextToGlweCiphertextTransformationEngine`](super::GlweSeededCiphertextToGlweCiphertextTransformationEngine).
+pub trait GgswSeededCiphertextToGgswCiphertextTransformationEngine<
+    InputCiphertext,
+    OutputCiphertext,
+>: AbstractEngine where
+    InputCiphertext: GgswSeededCiphertextEntity,
+    OutputCiphertext: GgswCiphertextEntity,
+{
+    /// Does the transformation of the GGSW seeded ciphertext into a GGSW ciphertext
+    fn transform_ggsw_seeded_ciphertext_to_ggsw_ciphertext(
+        &mut self,
+        ggsw_seeded_ciphertext: InputCiphertext,
+    ) -> Result<
+        OutputCiphertext,
+        GgswSeededCiphertextToGgswCiphertextTransformationError<Self::EngineError>,
+    >;
+
+    /// Unsafely transforms a GGSW seeded ciphertext into a GGSW ciphertext
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GgswSeededCiphertextToGgswCiphertextTransformationError`].
+    /// For safety concerns _specific_ to an engine, refer to the implementer safety section.
+    unsafe fn transform_ggsw_seeded_ciphertext_to_ggsw_ciphertext_unchecked(
+        &mut self,
+        ggsw_seeded_ciphertext: InputCiphertext,
+    ) -> OutputCiphertext;
+}
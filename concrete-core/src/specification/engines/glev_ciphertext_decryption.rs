@@ -0,0 +1,89 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    GlevCiphertextEntity, GlweSecretKeyEntity, PlaintextVectorEntity,
+};
+
+engine_error! {
+    GlevCiphertextDecryptionError for GlevCiphertextDecryptionEngine @
+    GlweDimensionMismatch => "The ciphertext and secret key GLWE dimension must be the same.",
+    PolynomialSizeMismatch => "The ciphertext and secret key polynomial size must be the same."
+}
+
+impl<EngineError: std::error::Error> GlevCiphertextDecryptionError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<SecretKey, Ciphertext>(
+        key: &SecretKey,
+        input: &Ciphertext,
+    ) -> Result<(), Self>
+    where
+        SecretKey: GlweSecretKeyEntity,
+        Ciphertext: GlevCiphertextEntity,
+    {
+        if input.glwe_dimension() != key.glwe_dimension() {
+            return Err(Self::GlweDimensionMismatch);
+        }
+        if input.polynomial_size() != key.polynomial_size() {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines decrypting GLev ciphertexts.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a plaintext vector containing the
+/// decryption of the `input` ciphertext, under the `key` secret key.
+///
+/// # Formal Definition
+///
+/// ## GLev Decryption
+/// ###### inputs:
+/// - $\overline{\mathsf{CT}} = \left( \mathsf{CT}\_0 , \cdots , \mathsf{CT}\_{\ell-1} \right) \in
+///   \mathsf{GLev}\_{\vec{S}}^{\beta, \ell}\left(\mathsf{PT}\right)$: a GLev ciphertext
+/// - $\vec{S} \in\mathcal{R}\_q^k$: a secret key
+///
+/// ###### outputs:
+/// - $\mathsf{PT}\in\mathcal{R}\_\beta$: a plaintext
+///
+/// ###### algorithm:
+///
+/// 1. decrypt the first level $\mathsf{CT}\_0$ with the
+///    [`GLWE decryption algorithm`](`super::GlweCiphertextDecryptionEngine`),
+///    which gives $\mathsf{PT}\cdot \frac{q}{\beta} + E$
+/// 2. round each coefficient to the closest multiple of $\frac{q}{\beta}$, and divide it by
+///    $\frac{q}{\beta}$
+/// 3. output $\mathsf{PT}$
+///
+/// **Remark:** Unlike the GLWE decryption, the decoding is part of this operation, and the output
+/// plaintext vector contains the input of the
+/// [`GLev encryption`](`crate::specification::engines::GlevCiphertextEncryptionEngine`) modulo
+/// $\beta$.
+pub trait GlevCiphertextDecryptionEngine<SecretKey, Ciphertext, PlaintextVector>:
+    AbstractEngine
+where
+    SecretKey: GlweSecretKeyEntity,
+    Ciphertext: GlevCiphertextEntity,
+    PlaintextVector: PlaintextVectorEntity,
+{
+    /// Decrypts a GLev ciphertext into a plaintext vector.
+    fn decrypt_glev_ciphertext(
+        &mut self,
+        key: &SecretKey,
+        input: &Ciphertext,
+    ) -> Result<PlaintextVector, GlevCiphertextDecryptionError<Self::EngineError>>;
+
+    /// Unsafely decrypts a GLev ciphertext into a plaintext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlevCiphertextDecryptionError`]. For safety concerns _specific_ to an engine, refer to
+    /// the implementer safety section.
+    unsafe fn decrypt_glev_ciphertext_unchecked(
+        &mut self,
+        key: &SecretKey,
+        input: &Ciphertext,
+    ) -> PlaintextVector;
+}
@@ -0,0 +1,85 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GlweAutomorphismKeysEntity, GlweSecretKeyEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+engine_error! {
+    GlweAutomorphismKeysGenerationError for GlweAutomorphismKeysGenerationEngine @
+    NullDecompositionBaseLog => "The key decomposition base log must be greater than zero.",
+    NullDecompositionLevelCount => "The key decomposition level count must be greater than zero.",
+    DecompositionTooLarge => "The decomposition precision (base log * level count) must not exceed \
+                              the precision of the ciphertext.",
+    NonPowerOfTwoPolynomialSize => "The polynomial size of the key must be a power of two."
+}
+
+impl<EngineError: std::error::Error> GlweAutomorphismKeysGenerationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<SecretKey>(
+        key: &SecretKey,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        integer_precision: usize,
+    ) -> Result<(), Self>
+    where
+        SecretKey: GlweSecretKeyEntity,
+    {
+        if decomposition_base_log.0 == 0 {
+            return Err(Self::NullDecompositionBaseLog);
+        }
+
+        if decomposition_level_count.0 == 0 {
+            return Err(Self::NullDecompositionLevelCount);
+        }
+
+        if decomposition_level_count.0 * decomposition_base_log.0 > integer_precision {
+            return Err(Self::DecompositionTooLarge);
+        }
+
+        if !key.polynomial_size().0.is_power_of_two() {
+            return Err(Self::NonPowerOfTwoPolynomialSize);
+        }
+
+        Ok(())
+    }
+}
+
+/// A trait for engines generating new GLWE automorphism keys.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a new set of GLWE automorphism keys
+/// for the `key` GLWE secret key.
+///
+/// # Formal Definition
+///
+/// cf [`here`](`crate::specification::entities::GlweAutomorphismKeysEntity`)
+pub trait GlweAutomorphismKeysGenerationEngine<SecretKey, AutomorphismKeys>:
+    AbstractEngine
+where
+    SecretKey: GlweSecretKeyEntity,
+    AutomorphismKeys: GlweAutomorphismKeysEntity,
+{
+    /// Generates new GLWE automorphism keys.
+    fn generate_new_glwe_automorphism_keys(
+        &mut self,
+        key: &SecretKey,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<AutomorphismKeys, GlweAutomorphismKeysGenerationError<Self::EngineError>>;
+
+    /// Unsafely generates new GLWE automorphism keys.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweAutomorphismKeysGenerationError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn generate_new_glwe_automorphism_keys_unchecked(
+        &mut self,
+        key: &SecretKey,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> AutomorphismKeys;
+}
@@ -0,0 +1,119 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GlweCiphertextEntity, GlweRingReductionKeyEntity};
+
+engine_error! {
+    GlweCiphertextDiscardingRingReductionError for GlweCiphertextDiscardingRingReductionEngine @
+    InputGlweDimensionMismatch => "The input ciphertext GLWE dimension and ring reduction key \
+                                   input GLWE dimension must be the same.",
+    InputPolynomialSizeMismatch => "The input ciphertext polynomial size and ring reduction key \
+                                    input polynomial size must be the same.",
+    OutputGlweDimensionMismatch => "The output ciphertext GLWE dimension and ring reduction key \
+                                    output GLWE dimension must be the same.",
+    OutputPolynomialSizeMismatch => "The output ciphertext polynomial size and ring reduction key \
+                                     output polynomial size must be the same."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextDiscardingRingReductionError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<RingReductionKey, InputCiphertext, OutputCiphertext>(
+        output: &OutputCiphertext,
+        input: &InputCiphertext,
+        rrk: &RingReductionKey,
+    ) -> Result<(), Self>
+    where
+        RingReductionKey: GlweRingReductionKeyEntity,
+        InputCiphertext: GlweCiphertextEntity,
+        OutputCiphertext: GlweCiphertextEntity,
+    {
+        if input.glwe_dimension() != rrk.input_glwe_dimension() {
+            return Err(Self::InputGlweDimensionMismatch);
+        }
+        if input.polynomial_size() != rrk.input_polynomial_size() {
+            return Err(Self::InputPolynomialSizeMismatch);
+        }
+        if output.glwe_dimension() != rrk.output_glwe_dimension() {
+            return Err(Self::OutputGlweDimensionMismatch);
+        }
+        if output.polynomial_size() != rrk.output_polynomial_size() {
+            return Err(Self::OutputPolynomialSizeMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines ring reducing (discarding) GLWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` GLWE ciphertext
+/// with the ring reduction of the `input` GLWE ciphertext, using the `rrk` GLWE ring reduction
+/// key. The polynomial size of the output ciphertext divides the polynomial size of the input
+/// ciphertext, and the output message is made of the coefficients of the input message whose
+/// degrees are multiples of their ratio.
+///
+/// # Formal Definition
+///
+/// ## GLWE Ring Reduction
+///
+/// This homomorphic procedure transforms an input
+/// [`GLWE ciphertext`](`crate::specification::entities::GlweCiphertextEntity`)
+/// $\mathsf{ct}\_{\mathsf{in}} = \left( A\_0, \ldots, A\_{k-1}, B\right) \in
+/// \mathcal{R}\_{N\_1}^{k+1}$ encrypting $M(X) = \sum\_{u=0}^{r-1} X^u M\_u(X^r)$ under
+/// $\vec{S}$, with $r = N\_1 / N\_2$, into an output
+/// [`GLWE ciphertext`](`crate::specification::entities::GlweCiphertextEntity`)
+/// $\mathsf{ct}\_{\mathsf{out}} \in \mathcal{R}\_{N\_2}^{k'+1}$ encrypting $M\_0$ under
+/// $\vec{S'}$. It requires a
+/// [`ring reduction key`](`crate::specification::entities::GlweRingReductionKeyEntity`)
+/// $\mathsf{RRK}\_{\vec{S} \rightarrow \vec{S'}}$.
+///
+/// Used after a packing which places the messages on the coefficients whose degrees are
+/// multiples of $r$, it reduces the size of the ciphertext without decrypting it.
+///
+/// ## Algorithm
+/// ###### inputs:
+/// - $\mathsf{ct}\_{\mathsf{in}} = \left( A\_0, \ldots, A\_{k-1}, B\right)$: a GLWE
+///   ciphertext with polynomials of size $N\_1$
+/// - $\mathsf{RRK}\_{\vec{S} \rightarrow \vec{S'}}$: a ring reduction key
+///
+/// ###### outputs:
+/// - $\mathsf{ct}\_{\mathsf{out}}$: a GLWE ciphertext with polynomials of size $N\_2$
+///
+/// ###### algorithm:
+/// 1. split every polynomial of $\mathsf{ct}\_{\mathsf{in}}$ as
+///    $P(X) = \sum\_{t=0}^{r-1} X^t P\_t(X^r)$
+/// 2. set $\mathsf{ct}=\left( 0 , \cdots , 0 ,  B\_0 \right) \in \mathcal{R}\_{N\_2}^{k'+1}$
+/// 3. compute $\mathsf{ct}\_{\mathsf{out}} = \mathsf{ct} -
+///    \sum\_{j=0}^{k-1} \sum\_{t=0}^{r-1} \mathsf{decompProduct}\left( A\_{j,t} ,
+///    \overline{\mathsf{ct}\_{j,t}} \right)$
+/// 4. output $\mathsf{ct}\_{\mathsf{out}}$
+pub trait GlweCiphertextDiscardingRingReductionEngine<
+    RingReductionKey,
+    InputCiphertext,
+    OutputCiphertext,
+>: AbstractEngine where
+    RingReductionKey: GlweRingReductionKeyEntity,
+    InputCiphertext: GlweCiphertextEntity,
+    OutputCiphertext: GlweCiphertextEntity,
+{
+    /// Ring reduces a GLWE ciphertext.
+    fn discard_ring_reduce_glwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        rrk: &RingReductionKey,
+    ) -> Result<(), GlweCiphertextDiscardingRingReductionError<Self::EngineError>>;
+
+    /// Unsafely ring reduces a GLWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextDiscardingRingReductionError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_ring_reduce_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        rrk: &RingReductionKey,
+    );
+}
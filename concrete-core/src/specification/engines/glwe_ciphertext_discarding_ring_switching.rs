@@ -0,0 +1,115 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GlweCiphertextEntity, GlweRingSwitchingKeyEntity};
+
+engine_error! {
+    GlweCiphertextDiscardingRingSwitchingError for GlweCiphertextDiscardingRingSwitchingEngine @
+    InputGlweDimensionMismatch => "The input ciphertext GLWE dimension and ring switching key \
+                                   input GLWE dimension must be the same.",
+    InputPolynomialSizeMismatch => "The input ciphertext polynomial size and ring switching key \
+                                    input polynomial size must be the same.",
+    OutputGlweDimensionMismatch => "The output ciphertext GLWE dimension and ring switching key \
+                                    output GLWE dimension must be the same.",
+    OutputPolynomialSizeMismatch => "The output ciphertext polynomial size and ring switching key \
+                                     output polynomial size must be the same."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextDiscardingRingSwitchingError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<RingSwitchingKey, InputCiphertext, OutputCiphertext>(
+        output: &OutputCiphertext,
+        input: &InputCiphertext,
+        rsk: &RingSwitchingKey,
+    ) -> Result<(), Self>
+    where
+        RingSwitchingKey: GlweRingSwitchingKeyEntity,
+        InputCiphertext: GlweCiphertextEntity,
+        OutputCiphertext: GlweCiphertextEntity,
+    {
+        if input.glwe_dimension() != rsk.input_glwe_dimension() {
+            return Err(Self::InputGlweDimensionMismatch);
+        }
+        if input.polynomial_size() != rsk.input_polynomial_size() {
+            return Err(Self::InputPolynomialSizeMismatch);
+        }
+        if output.glwe_dimension() != rsk.output_glwe_dimension() {
+            return Err(Self::OutputGlweDimensionMismatch);
+        }
+        if output.polynomial_size() != rsk.output_polynomial_size() {
+            return Err(Self::OutputPolynomialSizeMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines ring switching (discarding) GLWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` GLWE ciphertext
+/// with the ring switch of the `input` GLWE ciphertext, using the `rsk` GLWE ring switching key.
+/// The output ciphertext has polynomials twice as large as the input ciphertext.
+///
+/// # Formal Definition
+///
+/// ## GLWE Ring Switch
+///
+/// This homomorphic procedure transforms an input
+/// [`GLWE ciphertext`](`crate::specification::entities::GlweCiphertextEntity`)
+/// $\mathsf{ct}\_{\mathsf{in}} = \left( A'\_0, \ldots, A'\_{k'-1}, B'\right) \in
+/// \mathcal{R}\_{N/2}^{k'+1}$ encrypting $M(X)$ under $\vec{S'}$, into an output
+/// [`GLWE ciphertext`](`crate::specification::entities::GlweCiphertextEntity`)
+/// $\mathsf{ct}\_{\mathsf{out}} \in \mathcal{R}\_{N}^{k+1}$ encrypting $M(X^2)$ under
+/// $\vec{S}$. It requires a
+/// [`ring switching key`](`crate::specification::entities::GlweRingSwitchingKeyEntity`)
+/// $\mathsf{RSK}\_{\vec{S'} \rightarrow \vec{S}}$.
+///
+/// Used on the two outputs of a
+/// [`GlweCiphertextRingSplittingEngine`](`super::GlweCiphertextRingSplittingEngine`), it
+/// inverts the split: the input message is recovered as $M\_e(X^2) + X \cdot M\_o(X^2)$.
+///
+/// ## Algorithm
+/// ###### inputs:
+/// - $\mathsf{ct}\_{\mathsf{in}} = \left( A'\_0, \ldots, A'\_{k'-1}, B'\right)$: a GLWE
+///   ciphertext with polynomials of size $N/2$
+/// - $\mathsf{RSK}\_{\vec{S'} \rightarrow \vec{S}}$: a ring switching key
+///
+/// ###### outputs:
+/// - $\mathsf{ct}\_{\mathsf{out}}$: a GLWE ciphertext with polynomials of size $N$
+///
+/// ###### algorithm:
+/// 1. set $\mathsf{ct}=\left( 0 , \cdots , 0 ,  B'(X^2) \right) \in \mathcal{R}\_N^{k+1}$
+/// 2. compute $\mathsf{ct}\_{\mathsf{out}} = \mathsf{ct} -
+///    \sum\_{j=0}^{k'-1} \mathsf{decompProduct}\left( A'\_j(X^2) , \overline{\mathsf{ct}\_j}
+///    \right)$
+/// 3. output $\mathsf{ct}\_{\mathsf{out}}$
+pub trait GlweCiphertextDiscardingRingSwitchingEngine<
+    RingSwitchingKey,
+    InputCiphertext,
+    OutputCiphertext,
+>: AbstractEngine where
+    RingSwitchingKey: GlweRingSwitchingKeyEntity,
+    InputCiphertext: GlweCiphertextEntity,
+    OutputCiphertext: GlweCiphertextEntity,
+{
+    /// Ring switches a GLWE ciphertext.
+    fn discard_ring_switch_glwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        rsk: &RingSwitchingKey,
+    ) -> Result<(), GlweCiphertextDiscardingRingSwitchingError<Self::EngineError>>;
+
+    /// Unsafely ring switches a GLWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextDiscardingRingSwitchingError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_ring_switch_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        rsk: &RingSwitchingKey,
+    );
+}
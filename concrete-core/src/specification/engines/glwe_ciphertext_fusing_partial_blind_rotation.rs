@@ -0,0 +1,98 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    GlweCiphertextEntity, LweBootstrapKeyEntity, LweCiphertextEntity,
+};
+use concrete_commons::parameters::GgswCiphertextIndex;
+
+engine_error! {
+    GlweCiphertextFusingPartialBlindRotationError for GlweCiphertextFusingPartialBlindRotationEngine @
+    GlweDimensionMismatch => "The GLWE dimensions of the accumulator and the key shard must be the \
+                              same.",
+    PolynomialSizeMismatch => "The polynomial sizes of the accumulator and the key shard must be \
+                               the same.",
+    ShardOutOfBounds => "The key shard must only cover coefficients of the input LWE ciphertext \
+                         mask."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextFusingPartialBlindRotationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<LweCiphertext, BootstrapKeyShard, GlweCiphertext>(
+        accumulator: &GlweCiphertext,
+        input: &LweCiphertext,
+        shard: &BootstrapKeyShard,
+        first: GgswCiphertextIndex,
+    ) -> Result<(), Self>
+    where
+        LweCiphertext: LweCiphertextEntity,
+        BootstrapKeyShard: LweBootstrapKeyEntity,
+        GlweCiphertext: GlweCiphertextEntity,
+    {
+        if accumulator.glwe_dimension() != shard.glwe_dimension() {
+            return Err(Self::GlweDimensionMismatch);
+        }
+        if accumulator.polynomial_size() != shard.polynomial_size() {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+        if first.0 + shard.input_lwe_dimension().0 > input.lwe_dimension().0 {
+            return Err(Self::ShardOutOfBounds);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines performing (fusing) partial blind rotations of GLWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [fusing](super#operation-semantics) operation performs the part of the blind rotation of
+/// the `accumulator` GLWE ciphertext by the `input` LWE ciphertext, which uses the bootstrap key
+/// `shard` holding the GGSW ciphertexts starting at index `first` of the whole key (see
+/// [`LweBootstrapKeyShardExtractionEngine`](super::LweBootstrapKeyShardExtractionEngine)). The
+/// shard starting at index 0 also performs the initial rotation by the body of the `input`.
+///
+/// Performing the partial blind rotations of all the shards of a key on the same accumulator is
+/// equivalent to the blind rotation by the whole key. A bootstrap can then be completed by
+/// extracting the first coefficient of the accumulator, for instance with the
+/// [`LweCiphertextDiscardingExtractionEngine`](super::LweCiphertextDiscardingExtractionEngine).
+///
+/// # Formal Definition
+///
+/// Let $\mathsf{ACC}$ be the accumulator, $(a\_0, \ldots, a\_{n-1}, b)$ the modulus-switched input
+/// LWE ciphertext, and $\mathsf{BSK}\_i$ the GGSW ciphertexts of the key. The shard holding
+/// $\mathsf{BSK}\_i$ for $i \in [f, f + s)$ performs
+/// $\mathsf{ACC} \leftarrow \mathsf{CMux}(\mathsf{BSK}\_i, \mathsf{ACC}, X^{a\_i} \cdot
+/// \mathsf{ACC})$ for each $i$, after $\mathsf{ACC} \leftarrow X^{-b} \cdot \mathsf{ACC}$ if
+/// $f = 0$.
+pub trait GlweCiphertextFusingPartialBlindRotationEngine<
+    LweCiphertext,
+    BootstrapKeyShard,
+    GlweCiphertext,
+>: AbstractEngine where
+    LweCiphertext: LweCiphertextEntity,
+    BootstrapKeyShard: LweBootstrapKeyEntity,
+    GlweCiphertext: GlweCiphertextEntity,
+{
+    /// Performs a partial blind rotation of a GLWE ciphertext.
+    fn fuse_partial_blind_rotate_glwe_ciphertext(
+        &mut self,
+        accumulator: &mut GlweCiphertext,
+        input: &LweCiphertext,
+        shard: &BootstrapKeyShard,
+        first: GgswCiphertextIndex,
+    ) -> Result<(), GlweCiphertextFusingPartialBlindRotationError<Self::EngineError>>;
+
+    /// Unsafely performs a partial blind rotation of a GLWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextFusingPartialBlindRotationError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn fuse_partial_blind_rotate_glwe_ciphertext_unchecked(
+        &mut self,
+        accumulator: &mut GlweCiphertext,
+        input: &LweCiphertext,
+        shard: &BootstrapKeyShard,
+        first: GgswCiphertextIndex,
+    );
+}
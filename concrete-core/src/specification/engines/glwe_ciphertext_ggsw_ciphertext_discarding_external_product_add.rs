@@ -0,0 +1,84 @@
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GgswCiphertextEntity, GlweCiphertextEntity};
+
+use super::engine_error;
+
+engine_error! {
+    GlweCiphertextGgswCiphertextDiscardingExternalProductAddError for
+    GlweCiphertextGgswCiphertextDiscardingExternalProductAddEngine @
+    PolynomialSizeMismatch => "All the GGSW and GLWE ciphertexts polynomial sizes must be the same.",
+    GlweDimensionMismatch => "All the GGSW and GLWE ciphertexts GLWE dimension must be the same."
+}
+
+impl<EngineError: std::error::Error>
+    GlweCiphertextGgswCiphertextDiscardingExternalProductAddError<EngineError>
+{
+    /// Validates the inputs
+    pub fn perform_generic_checks<GlweCiphertext, GgswCiphertext>(
+        glwe_input: &GlweCiphertext,
+        ggsw_input: &GgswCiphertext,
+        output: &GlweCiphertext,
+    ) -> Result<(), Self>
+    where
+        GlweCiphertext: GlweCiphertextEntity,
+        GgswCiphertext: GgswCiphertextEntity,
+    {
+        if glwe_input.polynomial_size().0 != ggsw_input.polynomial_size().0
+            || glwe_input.polynomial_size().0 != output.polynomial_size().0
+        {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+        if glwe_input.glwe_dimension().0 != ggsw_input.glwe_dimension().0
+            || glwe_input.glwe_dimension().0 != output.glwe_dimension().0
+        {
+            return Err(Self::GlweDimensionMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines computing the external product between a GLWE ciphertext and a GSW
+/// ciphertext, and adding the result to a GLWE ciphertext.
+///
+/// # Semantics
+///
+/// This operation adds to the `output` GLWE ciphertext the result of the external product between
+/// a `glwe_input` GLWE ciphertext and a `ggsw_input` GSW ciphertext. The previous content of
+/// `output` is kept, which saves a separate GLWE addition when external products are accumulated,
+/// e.g. in a blind rotation.
+///
+/// # Formal Definition
+pub trait GlweCiphertextGgswCiphertextDiscardingExternalProductAddEngine<
+    GlweInput,
+    GgswInput,
+    Output,
+>: AbstractEngine where
+    GlweInput: GlweCiphertextEntity,
+    GgswInput: GgswCiphertextEntity,
+    Output: GlweCiphertextEntity,
+{
+    /// Adds the external product between a GLWE and a GSW ciphertext to a GLWE ciphertext.
+    fn discard_add_external_product_glwe_ciphertext_ggsw_ciphertext(
+        &mut self,
+        glwe_input: &GlweInput,
+        ggsw_input: &GgswInput,
+        output: &mut Output,
+    ) -> Result<
+        (),
+        GlweCiphertextGgswCiphertextDiscardingExternalProductAddError<Self::EngineError>,
+    >;
+
+    /// Unsafely adds the external product between a GLWE and a GSW ciphertext to a GLWE
+    /// ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextGgswCiphertextDiscardingExternalProductAddError`]. For safety concerns
+    /// _specific_ to an engine, refer to the implementer safety section.
+    unsafe fn discard_add_external_product_glwe_ciphertext_ggsw_ciphertext_unchecked(
+        &mut self,
+        glwe_input: &GlweInput,
+        ggsw_input: &GgswInput,
+        output: &mut Output,
+    );
+}
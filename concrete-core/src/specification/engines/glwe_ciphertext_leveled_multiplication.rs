@@ -0,0 +1,124 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GlweCiphertextEntity, GlweRelinearizationKeyEntity};
+use concrete_commons::parameters::ScalingFactor;
+
+engine_error! {
+    GlweCiphertextLeveledMultiplicationError for GlweCiphertextLeveledMultiplicationEngine @
+    GlweDimensionMismatch => "The GLWE dimension of the input ciphertexts and of the \
+                              relinearization key must be the same.",
+    PolynomialSizeMismatch => "The polynomial size of the input ciphertexts and of the \
+                               relinearization key must be the same.",
+    NullScalingFactor => "The scaling factors must be greater than zero.",
+    ScalingFactorTooLarge => "The scaling factors must fit in the precision of the ciphertexts.",
+    ScalingFactorMismatch => "The product of the input scaling factors must be a multiple of the \
+                              output scaling factor."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextLeveledMultiplicationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<InputCiphertext1, InputCiphertext2, RelinearizationKey>(
+        input_1: &InputCiphertext1,
+        input_2: &InputCiphertext2,
+        rlk: &RelinearizationKey,
+        input_1_scaling_factor: ScalingFactor,
+        input_2_scaling_factor: ScalingFactor,
+        output_scaling_factor: ScalingFactor,
+        integer_precision: usize,
+    ) -> Result<(), Self>
+    where
+        InputCiphertext1: GlweCiphertextEntity,
+        InputCiphertext2: GlweCiphertextEntity,
+        RelinearizationKey: GlweRelinearizationKeyEntity,
+    {
+        if input_1.glwe_dimension() != input_2.glwe_dimension()
+            || input_1.glwe_dimension() != rlk.glwe_dimension()
+        {
+            return Err(Self::GlweDimensionMismatch);
+        }
+        if input_1.polynomial_size() != input_2.polynomial_size()
+            || input_1.polynomial_size() != rlk.polynomial_size()
+        {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+        let scaling_factors = [
+            input_1_scaling_factor,
+            input_2_scaling_factor,
+            output_scaling_factor,
+        ];
+        if scaling_factors.iter().any(|factor| factor.0 == 0) {
+            return Err(Self::NullScalingFactor);
+        }
+        if scaling_factors
+            .iter()
+            .any(|factor| (factor.0 as u128) >> integer_precision != 0)
+        {
+            return Err(Self::ScalingFactorTooLarge);
+        }
+        let product = input_1_scaling_factor.0 as u128 * input_2_scaling_factor.0 as u128;
+        if !product.is_multiple_of(output_scaling_factor.0 as u128) {
+            return Err(Self::ScalingFactorMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines multiplying GLWE ciphertexts in a leveled fashion.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a GLWE ciphertext containing the
+/// product of the `input_1` and `input_2` GLWE ciphertexts, encrypted under the same key. The
+/// tensor product of the inputs is computed, rescaled, and relinearized with the `rlk`
+/// relinearization key, so that the output is encrypted under the key of the inputs.
+///
+/// The messages of the inputs are assumed to be encoded with the `input_1_scaling_factor` and
+/// `input_2_scaling_factor` scaling factors, and the message of the output is encoded with the
+/// `output_scaling_factor` scaling factor. Using the same scaling factor $\Delta$ for the inputs
+/// and the output allows to chain multiplications, as long as the noise budget permits.
+///
+/// # Formal Definition
+///
+/// For two GLWE ciphertexts encrypting the polynomials $\Delta\_1 M\_1$ and $\Delta\_2 M\_2$ under
+/// the same key, the output is a GLWE ciphertext encrypting $\Delta\_{\mathsf{out}} M\_1 M\_2$
+/// modulo $X^N + 1$, where $\Delta\_{\mathsf{out}}$ divides $\Delta\_1 \Delta\_2$. The tensor
+/// product is divided by $\Delta\_1 \Delta\_2 / \Delta\_{\mathsf{out}}$ before being
+/// relinearized.
+pub trait GlweCiphertextLeveledMultiplicationEngine<
+    InputCiphertext1,
+    InputCiphertext2,
+    RelinearizationKey,
+    OutputCiphertext,
+>: AbstractEngine where
+    InputCiphertext1: GlweCiphertextEntity,
+    InputCiphertext2: GlweCiphertextEntity,
+    RelinearizationKey: GlweRelinearizationKeyEntity,
+    OutputCiphertext: GlweCiphertextEntity,
+{
+    /// Multiplies two GLWE ciphertexts.
+    fn mul_glwe_ciphertext_leveled(
+        &mut self,
+        input_1: &InputCiphertext1,
+        input_2: &InputCiphertext2,
+        rlk: &RelinearizationKey,
+        input_1_scaling_factor: ScalingFactor,
+        input_2_scaling_factor: ScalingFactor,
+        output_scaling_factor: ScalingFactor,
+    ) -> Result<OutputCiphertext, GlweCiphertextLeveledMultiplicationError<Self::EngineError>>;
+
+    /// Unsafely multiplies two GLWE ciphertexts.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextLeveledMultiplicationError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn mul_glwe_ciphertext_leveled_unchecked(
+        &mut self,
+        input_1: &InputCiphertext1,
+        input_2: &InputCiphertext2,
+        rlk: &RelinearizationKey,
+        input_1_scaling_factor: ScalingFactor,
+        input_2_scaling_factor: ScalingFactor,
+        output_scaling_factor: ScalingFactor,
+    ) -> OutputCiphertext;
+}
@@ -0,0 +1,71 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    GlweCiphertextEntity, GlweRelinearizationKeyEntity, GlweTensorProductCiphertextEntity,
+};
+
+engine_error! {
+    GlweCiphertextRelinearizationError for GlweCiphertextRelinearizationEngine @
+    GlweDimensionMismatch => "The GLWE dimension of the input ciphertext must be the tensor \
+                              product dimension of the relinearization key GLWE dimension.",
+    PolynomialSizeMismatch => "The polynomial size of the input ciphertext and of the \
+                               relinearization key must be the same."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextRelinearizationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<InputCiphertext, RelinearizationKey>(
+        input: &InputCiphertext,
+        rlk: &RelinearizationKey,
+    ) -> Result<(), Self>
+    where
+        InputCiphertext: GlweTensorProductCiphertextEntity,
+        RelinearizationKey: GlweRelinearizationKeyEntity,
+    {
+        if input.glwe_dimension() != rlk.glwe_dimension().tensor_product_dimension() {
+            return Err(Self::GlweDimensionMismatch);
+        }
+        if input.polynomial_size() != rlk.polynomial_size() {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines relinearizing GLWE tensor product ciphertexts.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a GLWE ciphertext encrypted under
+/// the original GLWE secret key, containing the same message as the `input` GLWE tensor product
+/// ciphertext, using the `rlk` relinearization key.
+///
+/// # Formal Definition
+///
+/// cf [`here`](`crate::specification::entities::GlweRelinearizationKeyEntity`)
+pub trait GlweCiphertextRelinearizationEngine<InputCiphertext, RelinearizationKey, OutputCiphertext>:
+    AbstractEngine
+where
+    InputCiphertext: GlweTensorProductCiphertextEntity,
+    RelinearizationKey: GlweRelinearizationKeyEntity,
+    OutputCiphertext: GlweCiphertextEntity,
+{
+    /// Relinearizes a GLWE tensor product ciphertext.
+    fn relinearize_glwe_ciphertext(
+        &mut self,
+        input: &InputCiphertext,
+        rlk: &RelinearizationKey,
+    ) -> Result<OutputCiphertext, GlweCiphertextRelinearizationError<Self::EngineError>>;
+
+    /// Unsafely relinearizes a GLWE tensor product ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextRelinearizationError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn relinearize_glwe_ciphertext_unchecked(
+        &mut self,
+        input: &InputCiphertext,
+        rlk: &RelinearizationKey,
+    ) -> OutputCiphertext;
+}
@@ -0,0 +1,65 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GlweCiphertextEntity, GlweCiphertextVectorEntity};
+
+engine_error! {
+    GlweCiphertextRingSplittingError for GlweCiphertextRingSplittingEngine @
+    OddPolynomialSize => "The polynomial size of the input ciphertext must be even."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextRingSplittingError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<Ciphertext>(input: &Ciphertext) -> Result<(), Self>
+    where
+        Ciphertext: GlweCiphertextEntity,
+    {
+        if !input.polynomial_size().0.is_multiple_of(2) {
+            return Err(Self::OddPolynomialSize);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines splitting GLWE ciphertexts into two GLWE ciphertexts over a smaller ring.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a GLWE ciphertext vector of two
+/// ciphertexts, with polynomials of half the size and twice the GLWE dimension of the `input`
+/// GLWE ciphertext. The first ciphertext encrypts the even coefficients of the input message,
+/// and the second one its odd coefficients, under the key returned by a
+/// [`GlweSecretKeyRingSplittingEngine`](`super::GlweSecretKeyRingSplittingEngine`).
+///
+/// # Formal Definition
+///
+/// The input ciphertext $\left( A\_0, \ldots, A\_{k-1}, B\right) \in \mathcal{R}\_N^{k+1}$
+/// encrypts $M$ under $\vec{S}$. Every polynomial $P(X)$ of the input is split into
+/// $P\_e, P\_o \in \mathcal{R}\_{N/2}$ such that $P(X) = P\_e(X^2) + X \cdot P\_o(X^2)$, and with
+/// $Y = X^2$, the output ciphertexts are:
+/// $$\left( A\_{0,e}, Y \cdot A\_{0,o}, \ldots, A\_{k-1,e}, Y \cdot A\_{k-1,o}, B\_e \right)$$
+/// $$\left( A\_{0,o}, A\_{0,e}, \ldots, A\_{k-1,o}, A\_{k-1,e}, B\_o \right)$$
+/// which respectively encrypt $M\_e$ and $M\_o$ under the key
+/// $\left( S\_{0,e}, S\_{0,o}, \ldots, S\_{k-1,e}, S\_{k-1,o}\right)$. The coefficients are only
+/// rearranged, so that the noise of the output ciphertexts is the noise of the input.
+pub trait GlweCiphertextRingSplittingEngine<Ciphertext, CiphertextVector>: AbstractEngine
+where
+    Ciphertext: GlweCiphertextEntity,
+    CiphertextVector: GlweCiphertextVectorEntity,
+{
+    /// Splits a GLWE ciphertext into two GLWE ciphertexts over a smaller ring.
+    fn split_glwe_ciphertext_ring(
+        &mut self,
+        input: &Ciphertext,
+    ) -> Result<CiphertextVector, GlweCiphertextRingSplittingError<Self::EngineError>>;
+
+    /// Unsafely splits a GLWE ciphertext into two GLWE ciphertexts over a smaller ring.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextRingSplittingError`]. For safety concerns _specific_ to an engine, refer
+    /// to the implementer safety section.
+    unsafe fn split_glwe_ciphertext_ring_unchecked(
+        &mut self,
+        input: &Ciphertext,
+    ) -> CiphertextVector;
+}
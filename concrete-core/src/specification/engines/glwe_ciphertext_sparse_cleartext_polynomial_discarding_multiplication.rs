@@ -0,0 +1,105 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GlweCiphertextEntity, SparseCleartextPolynomialEntity};
+
+engine_error! {
+    GlweCiphertextSparseCleartextPolynomialDiscardingMultiplicationError
+    for GlweCiphertextSparseCleartextPolynomialDiscardingMultiplicationEngine @
+    GlweDimensionMismatch => "The input and output ciphertext GLWE dimension must be the same.",
+    PolynomialSizeMismatch => "The input ciphertext, output ciphertext and sparse polynomial \
+                               polynomial sizes must be the same."
+}
+
+impl<EngineError: std::error::Error>
+    GlweCiphertextSparseCleartextPolynomialDiscardingMultiplicationError<EngineError>
+{
+    /// Validates the inputs
+    pub fn perform_generic_checks<InputCiphertext, SparseCleartextPolynomial, OutputCiphertext>(
+        output: &OutputCiphertext,
+        input_1: &InputCiphertext,
+        input_2: &SparseCleartextPolynomial,
+    ) -> Result<(), Self>
+    where
+        InputCiphertext: GlweCiphertextEntity,
+        SparseCleartextPolynomial: SparseCleartextPolynomialEntity,
+        OutputCiphertext: GlweCiphertextEntity,
+    {
+        if output.glwe_dimension() != input_1.glwe_dimension() {
+            return Err(Self::GlweDimensionMismatch);
+        }
+        if output.polynomial_size() != input_1.polynomial_size()
+            || input_2.polynomial_size() != input_1.polynomial_size()
+        {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines multiplying (discarding) GLWE ciphertexts by sparse cleartext polynomials.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` GLWE ciphertext with
+/// the product of the `input_1` GLWE ciphertext with the `input_2` sparse cleartext polynomial.
+///
+/// # Formal Definition
+///
+/// Let a GLWE ciphertext
+/// $$
+/// \mathsf{CT} = \left( \vec{A}, B\right) \in \mathsf{GLWE}\_{\vec{S}} \left( \mathsf{PT} \right)
+/// \subseteq \mathcal{R}\_q^{k+1}
+/// $$
+/// and a sparse cleartext polynomial $P = \sum\_{i=1}^{t} p\_i X^{d\_i} \in \mathcal{R}\_q$ with
+/// $t$ nonzero monomials.
+///
+/// ###### inputs:
+/// - $\mathsf{CT} = \left( \vec{A}, B\right) \in \mathsf{GLWE}\_{\vec{S}} \left( \mathsf{PT}
+///   \right) \subseteq \mathcal{R}\_q^{k+1}$: a GLWE ciphertext
+/// - $P \in \mathcal{R}\_q$: a sparse cleartext polynomial
+///
+/// ###### outputs:
+/// - $\mathsf{CT}' = \left( \vec{A}' , B' \right) \in \mathsf{GLWE}\_{\vec{S}}( P \cdot
+///   \mathsf{PT} )\subseteq \mathcal{R}\_q^{k+1}$: a GLWE ciphertext
+///
+/// ###### algorithm:
+/// 1. Compute $\vec{A}' = \sum\_{i=1}^{t} p\_i \cdot X^{d\_i} \cdot \vec{A} \in\mathcal{R}^k\_q$
+/// 2. Compute $B' = \sum\_{i=1}^{t} p\_i \cdot X^{d\_i} \cdot B \in\mathcal{R}\_q$
+/// 3. Output $\left( \vec{A}' , B' \right)$
+///
+/// Each product by $X^{d\_i}$ is a negacyclic rotation of the coefficients, so the whole
+/// operation costs $O(t \cdot (k+1) \cdot N)$ scalar operations instead of a full polynomial
+/// product. The variance of the noise is multiplied by $\left(\sum\_{i} |p\_i|\right)^2$ at most.
+pub trait GlweCiphertextSparseCleartextPolynomialDiscardingMultiplicationEngine<
+    InputCiphertext,
+    SparseCleartextPolynomial,
+    OutputCiphertext,
+>: AbstractEngine where
+    InputCiphertext: GlweCiphertextEntity,
+    SparseCleartextPolynomial: SparseCleartextPolynomialEntity,
+    OutputCiphertext: GlweCiphertextEntity,
+{
+    /// Multiply a GLWE ciphertext with a sparse cleartext polynomial.
+    fn discard_mul_glwe_ciphertext_sparse_cleartext_polynomial(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input_1: &InputCiphertext,
+        input_2: &SparseCleartextPolynomial,
+    ) -> Result<
+        (),
+        GlweCiphertextSparseCleartextPolynomialDiscardingMultiplicationError<Self::EngineError>,
+    >;
+
+    /// Unsafely multiply a GLWE ciphertext with a sparse cleartext polynomial.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextSparseCleartextPolynomialDiscardingMultiplicationError`]. For safety
+    /// concerns _specific_ to an engine, refer to the implementer safety section.
+    unsafe fn discard_mul_glwe_ciphertext_sparse_cleartext_polynomial_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input_1: &InputCiphertext,
+        input_2: &SparseCleartextPolynomial,
+    );
+}
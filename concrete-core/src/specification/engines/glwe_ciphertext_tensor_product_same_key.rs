@@ -0,0 +1,86 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GlweCiphertextEntity, GlweTensorProductCiphertextEntity};
+use concrete_commons::parameters::ScalingFactor;
+
+engine_error! {
+    GlweCiphertextTensorProductSameKeyError for GlweCiphertextTensorProductSameKeyEngine @
+    GlweDimensionMismatch => "The GLWE dimension of the input ciphertexts must be the same.",
+    PolynomialSizeMismatch => "The polynomial size of the input ciphertexts must be the same.",
+    NullScalingFactor => "The scaling factor must be greater than zero.",
+    ScalingFactorTooLarge => "The scaling factor must fit in the precision of the ciphertexts."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextTensorProductSameKeyError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<InputCiphertext1, InputCiphertext2>(
+        input_1: &InputCiphertext1,
+        input_2: &InputCiphertext2,
+        scaling_factor: ScalingFactor,
+        integer_precision: usize,
+    ) -> Result<(), Self>
+    where
+        InputCiphertext1: GlweCiphertextEntity,
+        InputCiphertext2: GlweCiphertextEntity,
+    {
+        if input_1.glwe_dimension() != input_2.glwe_dimension() {
+            return Err(Self::GlweDimensionMismatch);
+        }
+        if input_1.polynomial_size() != input_2.polynomial_size() {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+        if scaling_factor.0 == 0 {
+            return Err(Self::NullScalingFactor);
+        }
+        if (scaling_factor.0 as u128) >> integer_precision != 0 {
+            return Err(Self::ScalingFactorTooLarge);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines computing the tensor product of GLWE ciphertexts encrypted under the same
+/// key.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a GLWE tensor product ciphertext
+/// containing the tensor product of the `input_1` and `input_2` GLWE ciphertexts, divided by
+/// `scaling_factor`.
+///
+/// # Formal Definition
+///
+/// For two GLWE ciphertexts encrypting the polynomials $\Delta\_1 M\_1$ and $\Delta\_2 M\_2$ under
+/// the same key, the output encrypts $\Delta\_1 \Delta\_2 M\_1 M\_2 / \Delta$ under the tensor
+/// product key, where $\Delta$ is the scaling factor. The tensor product key is defined
+/// [`here`](`crate::specification::entities::GlweTensorProductSecretKeyEntity`).
+pub trait GlweCiphertextTensorProductSameKeyEngine<
+    InputCiphertext1,
+    InputCiphertext2,
+    OutputCiphertext,
+>: AbstractEngine where
+    InputCiphertext1: GlweCiphertextEntity,
+    InputCiphertext2: GlweCiphertextEntity,
+    OutputCiphertext: GlweTensorProductCiphertextEntity,
+{
+    /// Computes the tensor product of two GLWE ciphertexts.
+    fn tensor_product_glwe_ciphertext_same_key(
+        &mut self,
+        input_1: &InputCiphertext1,
+        input_2: &InputCiphertext2,
+        scaling_factor: ScalingFactor,
+    ) -> Result<OutputCiphertext, GlweCiphertextTensorProductSameKeyError<Self::EngineError>>;
+
+    /// Unsafely computes the tensor product of two GLWE ciphertexts.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextTensorProductSameKeyError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn tensor_product_glwe_ciphertext_same_key_unchecked(
+        &mut self,
+        input_1: &InputCiphertext1,
+        input_2: &InputCiphertext2,
+        scaling_factor: ScalingFactor,
+    ) -> OutputCiphertext;
+}
@@ -0,0 +1,97 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    GlweCiphertextVectorEntity, GlweSecretKeyEntity, PlaintextVectorEntity,
+};
+use concrete_commons::dispersion::Variance;
+
+engine_error! {
+    GlweCiphertextVectorDiscardingVariableNoiseEncryptionError for
+    GlweCiphertextVectorDiscardingVariableNoiseEncryptionEngine @
+    GlweDimensionMismatch => "The GLWE dimensions of the key and the output ciphertext vector must \
+                              be the same.",
+    PolynomialSizeMismatch => "The polynomial size of the key and the output ciphertext vector \
+                               must be the same.",
+    PlaintextCountMismatch => "The input plaintext vector length and output ciphertext vector \
+                               capacity (poly size * length) must be the same.",
+    NoiseCountMismatch => "The number of noises and the output ciphertext vector length must be \
+                           the same."
+}
+
+impl<EngineError: std::error::Error>
+    GlweCiphertextVectorDiscardingVariableNoiseEncryptionError<EngineError>
+{
+    /// Validates the inputs
+    pub fn perform_generic_checks<SecretKey, PlaintextVector, CiphertextVector>(
+        key: &SecretKey,
+        output: &CiphertextVector,
+        input: &PlaintextVector,
+        noises: &[Variance],
+    ) -> Result<(), Self>
+    where
+        SecretKey: GlweSecretKeyEntity,
+        PlaintextVector: PlaintextVectorEntity,
+        CiphertextVector: GlweCiphertextVectorEntity,
+    {
+        if key.glwe_dimension() != output.glwe_dimension() {
+            return Err(Self::GlweDimensionMismatch);
+        }
+        if key.polynomial_size() != output.polynomial_size() {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+        if output.polynomial_size().0 * output.glwe_ciphertext_count().0
+            != input.plaintext_count().0
+        {
+            return Err(Self::PlaintextCountMismatch);
+        }
+        if output.glwe_ciphertext_count().0 != noises.len() {
+            return Err(Self::NoiseCountMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines encrypting (discarding) GLWE ciphertext vectors with a different noise for
+/// each ciphertext.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` GLWE ciphertext vector
+/// with the piece-wise encryptions of the `input` plaintext vector, under the `key` secret key.
+/// The i-th ciphertext of the vector is encrypted with a noise of variance `noises[i]`.
+///
+/// # Formal Definition
+///
+/// cf [`here`](`crate::specification::engines::GlweCiphertextEncryptionEngine`)
+pub trait GlweCiphertextVectorDiscardingVariableNoiseEncryptionEngine<
+    SecretKey,
+    PlaintextVector,
+    CiphertextVector,
+>: AbstractEngine where
+    SecretKey: GlweSecretKeyEntity,
+    PlaintextVector: PlaintextVectorEntity,
+    CiphertextVector: GlweCiphertextVectorEntity,
+{
+    /// Encrypts a GLWE ciphertext vector with a different noise for each ciphertext.
+    fn discard_encrypt_glwe_ciphertext_vector_with_variable_noise(
+        &mut self,
+        key: &SecretKey,
+        output: &mut CiphertextVector,
+        input: &PlaintextVector,
+        noises: &[Variance],
+    ) -> Result<(), GlweCiphertextVectorDiscardingVariableNoiseEncryptionError<Self::EngineError>>;
+
+    /// Unsafely encrypts a GLWE ciphertext vector with a different noise for each ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextVectorDiscardingVariableNoiseEncryptionError`]. For safety concerns
+    /// _specific_ to an engine, refer to the implementer safety section.
+    unsafe fn discard_encrypt_glwe_ciphertext_vector_with_variable_noise_unchecked(
+        &mut self,
+        key: &SecretKey,
+        output: &mut CiphertextVector,
+        input: &PlaintextVector,
+        noises: &[Variance],
+    );
+}
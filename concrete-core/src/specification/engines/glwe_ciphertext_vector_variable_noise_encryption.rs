@@ -0,0 +1,83 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    GlweCiphertextVectorEntity, GlweSecretKeyEntity, PlaintextVectorEntity,
+};
+use concrete_commons::dispersion::Variance;
+
+engine_error! {
+    GlweCiphertextVectorVariableNoiseEncryptionError for
+    GlweCiphertextVectorVariableNoiseEncryptionEngine @
+    PlaintextCountMismatch => "The key polynomial size must divide the plaintext count of the input \
+                               vector.",
+    NoiseCountMismatch => "The number of noises must be equal to the number of ciphertexts \
+                           (plaintext count / polynomial size)."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextVectorVariableNoiseEncryptionError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<SecretKey, PlaintextVector>(
+        key: &SecretKey,
+        input: &PlaintextVector,
+        noises: &[Variance],
+    ) -> Result<(), Self>
+    where
+        SecretKey: GlweSecretKeyEntity,
+        PlaintextVector: PlaintextVectorEntity,
+    {
+        if !input
+            .plaintext_count()
+            .0
+            .is_multiple_of(key.polynomial_size().0)
+        {
+            return Err(Self::PlaintextCountMismatch);
+        }
+        if input.plaintext_count().0 / key.polynomial_size().0 != noises.len() {
+            return Err(Self::NoiseCountMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines encrypting GLWE ciphertext vectors with a different noise for each
+/// ciphertext.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a GLWE ciphertext vector containing
+/// the piece-wise encryptions of the `input` plaintext vector, under the `key` secret key. The
+/// i-th ciphertext of the vector is encrypted with a noise of variance `noises[i]`.
+///
+/// # Formal Definition
+///
+/// cf [`here`](`crate::specification::engines::GlweCiphertextEncryptionEngine`)
+pub trait GlweCiphertextVectorVariableNoiseEncryptionEngine<
+    SecretKey,
+    PlaintextVector,
+    CiphertextVector,
+>: AbstractEngine where
+    SecretKey: GlweSecretKeyEntity,
+    PlaintextVector: PlaintextVectorEntity,
+    CiphertextVector: GlweCiphertextVectorEntity,
+{
+    /// Encrypts a GLWE ciphertext vector with a different noise for each ciphertext.
+    fn encrypt_glwe_ciphertext_vector_with_variable_noise(
+        &mut self,
+        key: &SecretKey,
+        input: &PlaintextVector,
+        noises: &[Variance],
+    ) -> Result<CiphertextVector, GlweCiphertextVectorVariableNoiseEncryptionError<Self::EngineError>>;
+
+    /// Unsafely encrypts a GLWE ciphertext vector with a different noise for each ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextVectorVariableNoiseEncryptionError`]. For safety concerns _specific_ to
+    /// an engine, refer to the implementer safety section.
+    unsafe fn encrypt_glwe_ciphertext_vector_with_variable_noise_unchecked(
+        &mut self,
+        key: &SecretKey,
+        input: &PlaintextVector,
+        noises: &[Variance],
+    ) -> CiphertextVector;
+}
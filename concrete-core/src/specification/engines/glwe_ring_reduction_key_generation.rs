@@ -0,0 +1,96 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GlweRingReductionKeyEntity, GlweSecretKeyEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+engine_error! {
+    GlweRingReductionKeyGenerationError for GlweRingReductionKeyGenerationEngine @
+    NullDecompositionBaseLog => "The key decomposition base log must be greater than zero.",
+    NullDecompositionLevelCount => "The key decomposition level count must be greater than zero.",
+    DecompositionTooLarge => "The decomposition precision (base log * level count) must not exceed \
+                              the precision of the ciphertext.",
+    PolynomialSizeMismatch => "The polynomial size of the output key must divide the polynomial \
+                               size of the input key."
+}
+
+impl<EngineError: std::error::Error> GlweRingReductionKeyGenerationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<InputSecretKey, OutputSecretKey>(
+        input_key: &InputSecretKey,
+        output_key: &OutputSecretKey,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        integer_precision: usize,
+    ) -> Result<(), Self>
+    where
+        InputSecretKey: GlweSecretKeyEntity,
+        OutputSecretKey: GlweSecretKeyEntity,
+    {
+        if decomposition_base_log.0 == 0 {
+            return Err(Self::NullDecompositionBaseLog);
+        }
+
+        if decomposition_level_count.0 == 0 {
+            return Err(Self::NullDecompositionLevelCount);
+        }
+
+        if decomposition_level_count.0 * decomposition_base_log.0 > integer_precision {
+            return Err(Self::DecompositionTooLarge);
+        }
+
+        if !input_key
+            .polynomial_size()
+            .0
+            .is_multiple_of(output_key.polynomial_size().0)
+        {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+
+        Ok(())
+    }
+}
+
+/// A trait for engines generating new GLWE ring reduction keys.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a new GLWE ring reduction key
+/// allowing to switch from the `input_key` GLWE secret key, to the `output_key` GLWE secret key
+/// whose polynomial size divides the polynomial size of the `input_key`.
+///
+/// # Formal Definition
+///
+/// cf [`here`](`crate::specification::entities::GlweRingReductionKeyEntity`)
+pub trait GlweRingReductionKeyGenerationEngine<InputSecretKey, OutputSecretKey, RingReductionKey>:
+    AbstractEngine
+where
+    InputSecretKey: GlweSecretKeyEntity,
+    OutputSecretKey: GlweSecretKeyEntity,
+    RingReductionKey: GlweRingReductionKeyEntity,
+{
+    /// Generates a new GLWE ring reduction key.
+    fn generate_new_glwe_ring_reduction_key(
+        &mut self,
+        input_key: &InputSecretKey,
+        output_key: &OutputSecretKey,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<RingReductionKey, GlweRingReductionKeyGenerationError<Self::EngineError>>;
+
+    /// Unsafely generates a new GLWE ring reduction key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweRingReductionKeyGenerationError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn generate_new_glwe_ring_reduction_key_unchecked(
+        &mut self,
+        input_key: &InputSecretKey,
+        output_key: &OutputSecretKey,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> RingReductionKey;
+}
@@ -0,0 +1,92 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GlweRingSwitchingKeyEntity, GlweSecretKeyEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+engine_error! {
+    GlweRingSwitchingKeyGenerationError for GlweRingSwitchingKeyGenerationEngine @
+    NullDecompositionBaseLog => "The key decomposition base log must be greater than zero.",
+    NullDecompositionLevelCount => "The key decomposition level count must be greater than zero.",
+    DecompositionTooLarge => "The decomposition precision (base log * level count) must not exceed \
+                              the precision of the ciphertext.",
+    PolynomialSizeMismatch => "The polynomial size of the output key must be twice the polynomial \
+                               size of the input key."
+}
+
+impl<EngineError: std::error::Error> GlweRingSwitchingKeyGenerationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<InputSecretKey, OutputSecretKey>(
+        input_key: &InputSecretKey,
+        output_key: &OutputSecretKey,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        integer_precision: usize,
+    ) -> Result<(), Self>
+    where
+        InputSecretKey: GlweSecretKeyEntity,
+        OutputSecretKey: GlweSecretKeyEntity,
+    {
+        if decomposition_base_log.0 == 0 {
+            return Err(Self::NullDecompositionBaseLog);
+        }
+
+        if decomposition_level_count.0 == 0 {
+            return Err(Self::NullDecompositionLevelCount);
+        }
+
+        if decomposition_level_count.0 * decomposition_base_log.0 > integer_precision {
+            return Err(Self::DecompositionTooLarge);
+        }
+
+        if output_key.polynomial_size().0 != 2 * input_key.polynomial_size().0 {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+
+        Ok(())
+    }
+}
+
+/// A trait for engines generating new GLWE ring switching keys.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a new GLWE ring switching key
+/// allowing to switch from the `input_key` GLWE secret key, to the `output_key` GLWE secret key
+/// whose polynomials are twice as large.
+///
+/// # Formal Definition
+///
+/// cf [`here`](`crate::specification::entities::GlweRingSwitchingKeyEntity`)
+pub trait GlweRingSwitchingKeyGenerationEngine<InputSecretKey, OutputSecretKey, RingSwitchingKey>:
+    AbstractEngine
+where
+    InputSecretKey: GlweSecretKeyEntity,
+    OutputSecretKey: GlweSecretKeyEntity,
+    RingSwitchingKey: GlweRingSwitchingKeyEntity,
+{
+    /// Generates a new GLWE ring switching key.
+    fn generate_new_glwe_ring_switching_key(
+        &mut self,
+        input_key: &InputSecretKey,
+        output_key: &OutputSecretKey,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<RingSwitchingKey, GlweRingSwitchingKeyGenerationError<Self::EngineError>>;
+
+    /// Unsafely generates a new GLWE ring switching key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweRingSwitchingKeyGenerationError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn generate_new_glwe_ring_switching_key_unchecked(
+        &mut self,
+        input_key: &InputSecretKey,
+        output_key: &OutputSecretKey,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> RingSwitchingKey;
+}
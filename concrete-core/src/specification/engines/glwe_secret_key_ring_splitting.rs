@@ -0,0 +1,58 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::GlweSecretKeyEntity;
+
+engine_error! {
+    GlweSecretKeyRingSplittingError for GlweSecretKeyRingSplittingEngine @
+    OddPolynomialSize => "The polynomial size of the input key must be even."
+}
+
+impl<EngineError: std::error::Error> GlweSecretKeyRingSplittingError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<InputKey>(input: &InputKey) -> Result<(), Self>
+    where
+        InputKey: GlweSecretKeyEntity,
+    {
+        if !input.polynomial_size().0.is_multiple_of(2) {
+            return Err(Self::OddPolynomialSize);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines creating the secret key of ring split GLWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates the GLWE secret key of the
+/// ciphertexts obtained by splitting ciphertexts encrypted under the `input` GLWE secret key,
+/// with a [`GlweCiphertextRingSplittingEngine`](`super::GlweCiphertextRingSplittingEngine`).
+///
+/// # Formal Definition
+///
+/// Every polynomial $S\_i(X) \in \mathcal{R}\_N$ of the input key
+/// $\vec{S} = \left( S\_0, \ldots, S\_{k-1}\right)$ is split into two polynomials
+/// $S\_{i,e}, S\_{i,o} \in \mathcal{R}\_{N/2}$ such that
+/// $S\_i(X) = S\_{i,e}(X^2) + X \cdot S\_{i,o}(X^2)$. The output key is made of the $2k$
+/// polynomials:
+/// $$\left( S\_{0,e}, S\_{0,o}, \ldots, S\_{k-1,e}, S\_{k-1,o}\right) \in \mathcal{R}\_{N/2}^{2k}$$
+pub trait GlweSecretKeyRingSplittingEngine<InputKey, OutputKey>: AbstractEngine
+where
+    InputKey: GlweSecretKeyEntity,
+    OutputKey: GlweSecretKeyEntity,
+{
+    /// Creates the secret key of the ring split ciphertexts encrypted under a GLWE secret key.
+    fn split_glwe_secret_key_ring(
+        &mut self,
+        input: &InputKey,
+    ) -> Result<OutputKey, GlweSecretKeyRingSplittingError<Self::EngineError>>;
+
+    /// Unsafely creates the secret key of the ring split ciphertexts encrypted under a GLWE
+    /// secret key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweSecretKeyRingSplittingError`]. For safety concerns _specific_ to an engine, refer
+    /// to the implementer safety section.
+    unsafe fn split_glwe_secret_key_ring_unchecked(&mut self, input: &InputKey) -> OutputKey;
+}
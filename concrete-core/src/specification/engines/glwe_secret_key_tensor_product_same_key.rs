@@ -0,0 +1,41 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GlweSecretKeyEntity, GlweTensorProductSecretKeyEntity};
+
+engine_error! {
+    GlweSecretKeyTensorProductSameKeyError for GlweSecretKeyTensorProductSameKeyEngine @
+}
+
+/// A trait for engines creating the secret key of the tensor product of GLWE ciphertexts
+/// encrypted under the same key.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a GLWE tensor product secret key
+/// from the `input` GLWE secret key.
+///
+/// # Formal Definition
+///
+/// cf [`here`](`crate::specification::entities::GlweTensorProductSecretKeyEntity`)
+pub trait GlweSecretKeyTensorProductSameKeyEngine<InputKey, OutputKey>: AbstractEngine
+where
+    InputKey: GlweSecretKeyEntity,
+    OutputKey: GlweTensorProductSecretKeyEntity,
+{
+    /// Creates the tensor product secret key associated with a GLWE secret key.
+    fn create_tensor_product_secret_key_same_key(
+        &mut self,
+        input: &InputKey,
+    ) -> Result<OutputKey, GlweSecretKeyTensorProductSameKeyError<Self::EngineError>>;
+
+    /// Unsafely creates the tensor product secret key associated with a GLWE secret key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweSecretKeyTensorProductSameKeyError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn create_tensor_product_secret_key_same_key_unchecked(
+        &mut self,
+        input: &InputKey,
+    ) -> OutputKey;
+}
@@ -0,0 +1,60 @@
+use super::engine_error;
+use crate::commons::math::random::ByteRandomGenerator;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GlweCiphertextEntity, GlweSeededCiphertextEntity};
+
+engine_error! {
+    GlweSeededCiphertextDiscardingExpansionError for GlweSeededCiphertextDiscardingExpansionEngine @
+    GlweDimensionMismatch => "The input and output GLWE dimension must be the same.",
+    PolynomialSizeMismatch => "The input and output polynomial size must be the same."
+}
+
+impl<EngineError: std::error::Error> GlweSeededCiphertextDiscardingExpansionError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<Input, Output>(output: &Output, input: &Input) -> Result<(), Self>
+    where
+        Input: GlweSeededCiphertextEntity,
+        Output: GlweCiphertextEntity,
+    {
+        if input.glwe_dimension() != output.glwe_dimension() {
+            return Err(Self::GlweDimensionMismatch);
+        }
+
+        if input.polynomial_size() != output.polynomial_size() {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines expanding (discarding) GLWE seeded ciphertexts.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` GLWE ciphertext with
+/// the expansion of the `input` GLWE seeded ciphertext, using the `Generator` CSPRNG (see
+/// [the expansion of seeded entities](super#expansion-of-seeded-entities)).
+pub trait GlweSeededCiphertextDiscardingExpansionEngine<Input, Output>: AbstractEngine
+where
+    Input: GlweSeededCiphertextEntity,
+    Output: GlweCiphertextEntity,
+{
+    /// Expands a GLWE seeded ciphertext into a GLWE ciphertext.
+    fn discard_expand_glwe_seeded_ciphertext<Generator: ByteRandomGenerator>(
+        &mut self,
+        output: &mut Output,
+        input: &Input,
+    ) -> Result<(), GlweSeededCiphertextDiscardingExpansionError<Self::EngineError>>;
+
+    /// Unsafely expands a GLWE seeded ciphertext into a GLWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweSeededCiphertextDiscardingExpansionError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_expand_glwe_seeded_ciphertext_unchecked<Generator: ByteRandomGenerator>(
+        &mut self,
+        output: &mut Output,
+        input: &Input,
+    );
+}
@@ -0,0 +1,72 @@
+use super::engine_error;
+use crate::commons::math::random::ByteRandomGenerator;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    GlweCiphertextVectorEntity, GlweSeededCiphertextVectorEntity,
+};
+
+engine_error! {
+    GlweSeededCiphertextVectorDiscardingExpansionError for GlweSeededCiphertextVectorDiscardingExpansionEngine @
+    GlweDimensionMismatch => "The input and output GLWE dimension must be the same.",
+    PolynomialSizeMismatch => "The input and output polynomial size must be the same.",
+    CiphertextCountMismatch => "The input and output ciphertext count must be the same."
+}
+
+impl<EngineError: std::error::Error>
+    GlweSeededCiphertextVectorDiscardingExpansionError<EngineError>
+{
+    /// Validates the inputs
+    pub fn perform_generic_checks<Input, Output>(output: &Output, input: &Input) -> Result<(), Self>
+    where
+        Input: GlweSeededCiphertextVectorEntity,
+        Output: GlweCiphertextVectorEntity,
+    {
+        if input.glwe_dimension() != output.glwe_dimension() {
+            return Err(Self::GlweDimensionMismatch);
+        }
+
+        if input.polynomial_size() != output.polynomial_size() {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+
+        if input.glwe_ciphertext_count() != output.glwe_ciphertext_count() {
+            return Err(Self::CiphertextCountMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines expanding (discarding) GLWE seeded ciphertext vectors.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` GLWE ciphertext vector
+/// with the expansion of the `input` GLWE seeded ciphertext vector, using the `Generator` CSPRNG
+/// (see [the expansion of seeded entities](super#expansion-of-seeded-entities)).
+pub trait GlweSeededCiphertextVectorDiscardingExpansionEngine<Input, Output>:
+    AbstractEngine
+where
+    Input: GlweSeededCiphertextVectorEntity,
+    Output: GlweCiphertextVectorEntity,
+{
+    /// Expands a GLWE seeded ciphertext vector into a GLWE ciphertext vector.
+    fn discard_expand_glwe_seeded_ciphertext_vector<Generator: ByteRandomGenerator>(
+        &mut self,
+        output: &mut Output,
+        input: &Input,
+    ) -> Result<(), GlweSeededCiphertextVectorDiscardingExpansionError<Self::EngineError>>;
+
+    /// Unsafely expands a GLWE seeded ciphertext vector into a GLWE ciphertext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweSeededCiphertextVectorDiscardingExpansionError`]. For safety concerns _specific_ to
+    /// an engine, refer to the implementer safety section.
+    unsafe fn discard_expand_glwe_seeded_ciphertext_vector_unchecked<
+        Generator: ByteRandomGenerator,
+    >(
+        &mut self,
+        output: &mut Output,
+        input: &Input,
+    );
+}
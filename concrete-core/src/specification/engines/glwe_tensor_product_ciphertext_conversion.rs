@@ -0,0 +1,36 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::GlweTensorProductCiphertextEntity;
+
+engine_error! {
+    GlweTensorProductCiphertextConversionError for GlweTensorProductCiphertextConversionEngine @
+}
+
+/// A trait for engines converting GLWE tensor product ciphertexts.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a GLWE tensor product ciphertext
+/// containing the conversion of the `input` GLWE tensor product ciphertext to a type with a
+/// different representation (for instance from the standard to the Fourier domain).
+///
+/// # Formal Definition
+pub trait GlweTensorProductCiphertextConversionEngine<Input, Output>: AbstractEngine
+where
+    Input: GlweTensorProductCiphertextEntity,
+    Output: GlweTensorProductCiphertextEntity,
+{
+    /// Converts a GLWE tensor product ciphertext.
+    fn convert_glwe_tensor_product_ciphertext(
+        &mut self,
+        input: &Input,
+    ) -> Result<Output, GlweTensorProductCiphertextConversionError<Self::EngineError>>;
+
+    /// Unsafely converts a GLWE tensor product ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweTensorProductCiphertextConversionError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn convert_glwe_tensor_product_ciphertext_unchecked(&mut self, input: &Input) -> Output;
+}
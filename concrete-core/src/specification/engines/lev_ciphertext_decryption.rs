@@ -0,0 +1,81 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{LevCiphertextEntity, LweSecretKeyEntity, PlaintextEntity};
+
+engine_error! {
+    LevCiphertextDecryptionError for LevCiphertextDecryptionEngine @
+    LweDimensionMismatch => "The ciphertext and secret key LWE dimension must be the same."
+}
+
+impl<EngineError: std::error::Error> LevCiphertextDecryptionError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<SecretKey, Ciphertext>(
+        key: &SecretKey,
+        input: &Ciphertext,
+    ) -> Result<(), Self>
+    where
+        SecretKey: LweSecretKeyEntity,
+        Ciphertext: LevCiphertextEntity,
+    {
+        if input.lwe_dimension() != key.lwe_dimension() {
+            return Err(Self::LweDimensionMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines decrypting Lev ciphertexts.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a plaintext containing the
+/// decryption of the `input` ciphertext, under the `key` secret key.
+///
+/// # Formal Definition
+///
+/// ## Lev Decryption
+/// ###### inputs:
+/// - $\overline{\mathsf{ct}} = \left( \mathsf{ct}\_0 , \cdots , \mathsf{ct}\_{\ell-1} \right) \in
+///   \mathsf{Lev}\_{\vec{s}}^{\beta, \ell}\left(\mathsf{pt}\right)$: a Lev ciphertext
+/// - $\vec{s}\in\mathbb{Z}\_q^n$: a secret key
+///
+/// ###### outputs:
+/// - $\mathsf{pt}\in\mathbb{Z}\_\beta$: a plaintext
+///
+/// ###### algorithm:
+///
+/// 1. decrypt the first level $\mathsf{ct}\_0$ with the
+///    [`LWE decryption algorithm`](`crate::specification::engines::LweCiphertextDecryptionEngine`),
+///    which gives $\mathsf{pt}\cdot \frac{q}{\beta} + e$
+/// 2. round it to the closest multiple of $\frac{q}{\beta}$, and divide it by $\frac{q}{\beta}$
+/// 3. output $\mathsf{pt}$
+///
+/// **Remark:** Unlike the LWE decryption, the decoding is part of this operation, and the output
+/// plaintext contains the input of the
+/// [`Lev encryption`](`crate::specification::engines::LevCiphertextEncryptionEngine`) modulo
+/// $\beta$.
+pub trait LevCiphertextDecryptionEngine<SecretKey, Ciphertext, Plaintext>: AbstractEngine
+where
+    SecretKey: LweSecretKeyEntity,
+    Ciphertext: LevCiphertextEntity,
+    Plaintext: PlaintextEntity,
+{
+    /// Decrypts a Lev ciphertext into a plaintext.
+    fn decrypt_lev_ciphertext(
+        &mut self,
+        key: &SecretKey,
+        input: &Ciphertext,
+    ) -> Result<Plaintext, LevCiphertextDecryptionError<Self::EngineError>>;
+
+    /// Unsafely decrypts a Lev ciphertext into a plaintext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LevCiphertextDecryptionError`]. For safety concerns _specific_ to an engine, refer to
+    /// the implementer safety section.
+    unsafe fn decrypt_lev_ciphertext_unchecked(
+        &mut self,
+        key: &SecretKey,
+        input: &Ciphertext,
+    ) -> Plaintext;
+}
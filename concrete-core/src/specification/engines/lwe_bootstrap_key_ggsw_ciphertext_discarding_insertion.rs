@@ -0,0 +1,89 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GgswCiphertextEntity, LweBootstrapKeyEntity};
+use concrete_commons::parameters::GgswCiphertextIndex;
+
+engine_error! {
+    LweBootstrapKeyGgswCiphertextDiscardingInsertionError for
+    LweBootstrapKeyGgswCiphertextDiscardingInsertionEngine @
+    GlweDimensionMismatch => "The GLWE dimensions of the key and the GGSW ciphertext must be the \
+                              same.",
+    PolynomialSizeMismatch => "The polynomial sizes of the key and the GGSW ciphertext must be the \
+                               same.",
+    DecompositionBaseLogMismatch => "The decomposition base logs of the key and the GGSW \
+                                     ciphertext must be the same.",
+    DecompositionLevelCountMismatch => "The decomposition level counts of the key and the GGSW \
+                                        ciphertext must be the same.",
+    IndexTooLarge => "The GGSW ciphertext index must be smaller than the input LWE dimension of \
+                      the key."
+}
+
+impl<EngineError: std::error::Error>
+    LweBootstrapKeyGgswCiphertextDiscardingInsertionError<EngineError>
+{
+    /// Validates the inputs
+    pub fn perform_generic_checks<BootstrapKey, GgswCiphertext>(
+        bootstrap_key: &BootstrapKey,
+        input: &GgswCiphertext,
+        index: GgswCiphertextIndex,
+    ) -> Result<(), Self>
+    where
+        BootstrapKey: LweBootstrapKeyEntity,
+        GgswCiphertext: GgswCiphertextEntity,
+    {
+        if bootstrap_key.glwe_dimension() != input.glwe_dimension() {
+            return Err(Self::GlweDimensionMismatch);
+        }
+        if bootstrap_key.polynomial_size() != input.polynomial_size() {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+        if bootstrap_key.decomposition_base_log() != input.decomposition_base_log() {
+            return Err(Self::DecompositionBaseLogMismatch);
+        }
+        if bootstrap_key.decomposition_level_count() != input.decomposition_level_count() {
+            return Err(Self::DecompositionLevelCountMismatch);
+        }
+        if index.0 >= bootstrap_key.input_lwe_dimension().0 {
+            return Err(Self::IndexTooLarge);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines inserting (discarding) GGSW ciphertexts in LWE bootstrap keys.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `index`-th GGSW ciphertext of
+/// the `bootstrap_key` with the `input` GGSW ciphertext, leaving the other ones untouched.
+///
+/// Inserting the GGSW ciphertexts extracted with the
+/// [`LweBootstrapKeyGgswCiphertextExtractionEngine`](super::LweBootstrapKeyGgswCiphertextExtractionEngine)
+/// one by one makes it possible to upload a large key in chunks, instead of converting it at once.
+pub trait LweBootstrapKeyGgswCiphertextDiscardingInsertionEngine<GgswCiphertext, BootstrapKey>:
+    AbstractEngine
+where
+    GgswCiphertext: GgswCiphertextEntity,
+    BootstrapKey: LweBootstrapKeyEntity,
+{
+    /// Inserts a GGSW ciphertext in an LWE bootstrap key.
+    fn discard_insert_lwe_bootstrap_key_ggsw_ciphertext(
+        &mut self,
+        bootstrap_key: &mut BootstrapKey,
+        input: &GgswCiphertext,
+        index: GgswCiphertextIndex,
+    ) -> Result<(), LweBootstrapKeyGgswCiphertextDiscardingInsertionError<Self::EngineError>>;
+
+    /// Unsafely inserts a GGSW ciphertext in an LWE bootstrap key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweBootstrapKeyGgswCiphertextDiscardingInsertionError`]. For safety concerns
+    /// _specific_ to an engine, refer to the implementer safety section.
+    unsafe fn discard_insert_lwe_bootstrap_key_ggsw_ciphertext_unchecked(
+        &mut self,
+        bootstrap_key: &mut BootstrapKey,
+        input: &GgswCiphertext,
+        index: GgswCiphertextIndex,
+    );
+}
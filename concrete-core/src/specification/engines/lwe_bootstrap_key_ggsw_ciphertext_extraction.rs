@@ -0,0 +1,63 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GgswCiphertextEntity, LweBootstrapKeyEntity};
+use concrete_commons::parameters::GgswCiphertextIndex;
+
+engine_error! {
+    LweBootstrapKeyGgswCiphertextExtractionError for LweBootstrapKeyGgswCiphertextExtractionEngine @
+    IndexTooLarge => "The GGSW ciphertext index must be smaller than the input LWE dimension of \
+                      the key."
+}
+
+impl<EngineError: std::error::Error> LweBootstrapKeyGgswCiphertextExtractionError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<BootstrapKey>(
+        bootstrap_key: &BootstrapKey,
+        index: GgswCiphertextIndex,
+    ) -> Result<(), Self>
+    where
+        BootstrapKey: LweBootstrapKeyEntity,
+    {
+        if index.0 >= bootstrap_key.input_lwe_dimension().0 {
+            return Err(Self::IndexTooLarge);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines extracting GGSW ciphertexts from LWE bootstrap keys.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a GGSW ciphertext containing a copy
+/// of the `index`-th GGSW ciphertext of the `bootstrap_key`, that is the encryption of the
+/// `index`-th coefficient of the input LWE secret key.
+///
+/// Together with the
+/// [`LweBootstrapKeyGgswCiphertextDiscardingInsertionEngine`](super::LweBootstrapKeyGgswCiphertextDiscardingInsertionEngine),
+/// it makes it possible to serialize or transfer a bootstrap key in chunks.
+pub trait LweBootstrapKeyGgswCiphertextExtractionEngine<BootstrapKey, GgswCiphertext>:
+    AbstractEngine
+where
+    BootstrapKey: LweBootstrapKeyEntity,
+    GgswCiphertext: GgswCiphertextEntity,
+{
+    /// Extracts a GGSW ciphertext from an LWE bootstrap key.
+    fn extract_lwe_bootstrap_key_ggsw_ciphertext(
+        &mut self,
+        bootstrap_key: &BootstrapKey,
+        index: GgswCiphertextIndex,
+    ) -> Result<GgswCiphertext, LweBootstrapKeyGgswCiphertextExtractionError<Self::EngineError>>;
+
+    /// Unsafely extracts a GGSW ciphertext from an LWE bootstrap key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweBootstrapKeyGgswCiphertextExtractionError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn extract_lwe_bootstrap_key_ggsw_ciphertext_unchecked(
+        &mut self,
+        bootstrap_key: &BootstrapKey,
+        index: GgswCiphertextIndex,
+    ) -> GgswCiphertext;
+}
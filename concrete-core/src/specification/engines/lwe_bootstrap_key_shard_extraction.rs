@@ -0,0 +1,70 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::LweBootstrapKeyEntity;
+use concrete_commons::parameters::{GgswCiphertextIndex, LweDimension};
+
+engine_error! {
+    LweBootstrapKeyShardExtractionError for LweBootstrapKeyShardExtractionEngine @
+    NullShardSize => "The shard must contain at least one GGSW ciphertext.",
+    ShardOutOfBounds => "The shard must only contain GGSW ciphertexts of the key."
+}
+
+impl<EngineError: std::error::Error> LweBootstrapKeyShardExtractionError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<BootstrapKey>(
+        bootstrap_key: &BootstrapKey,
+        first: GgswCiphertextIndex,
+        shard_size: LweDimension,
+    ) -> Result<(), Self>
+    where
+        BootstrapKey: LweBootstrapKeyEntity,
+    {
+        if shard_size.0 == 0 {
+            return Err(Self::NullShardSize);
+        }
+        if first.0 + shard_size.0 > bootstrap_key.input_lwe_dimension().0 {
+            return Err(Self::ShardOutOfBounds);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines extracting shards of LWE bootstrap keys.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates an LWE bootstrap key shard
+/// containing a copy of the `shard_size` GGSW ciphertexts of the `bootstrap_key` starting at index
+/// `first`. The input LWE dimension of the shard is `shard_size`.
+///
+/// Splitting a key in shards by key-bit range makes it possible to distribute a bootstrap key
+/// which does not fit in the memory of a single device, and to run the blind rotation shard by
+/// shard with the
+/// [`GlweCiphertextFusingPartialBlindRotationEngine`](super::GlweCiphertextFusingPartialBlindRotationEngine).
+pub trait LweBootstrapKeyShardExtractionEngine<BootstrapKey, BootstrapKeyShard>:
+    AbstractEngine
+where
+    BootstrapKey: LweBootstrapKeyEntity,
+    BootstrapKeyShard: LweBootstrapKeyEntity,
+{
+    /// Extracts a shard of an LWE bootstrap key.
+    fn extract_lwe_bootstrap_key_shard(
+        &mut self,
+        bootstrap_key: &BootstrapKey,
+        first: GgswCiphertextIndex,
+        shard_size: LweDimension,
+    ) -> Result<BootstrapKeyShard, LweBootstrapKeyShardExtractionError<Self::EngineError>>;
+
+    /// Unsafely extracts a shard of an LWE bootstrap key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweBootstrapKeyShardExtractionError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn extract_lwe_bootstrap_key_shard_unchecked(
+        &mut self,
+        bootstrap_key: &BootstrapKey,
+        first: GgswCiphertextIndex,
+        shard_size: LweDimension,
+    ) -> BootstrapKeyShard;
+}
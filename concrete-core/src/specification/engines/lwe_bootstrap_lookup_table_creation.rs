@@ -0,0 +1,90 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::GlweCiphertextEntity;
+use concrete_commons::parameters::{GlweDimension, PolynomialSize, ScalingFactor};
+
+engine_error! {
+    LweBootstrapLookupTableCreationError for LweBootstrapLookupTableCreationEngine @
+    NullScalingFactor => "The scaling factors must be greater than zero.",
+    InputScalingFactorTooLarge => "The input scaling factor must leave the most significant bit of \
+                                   the ciphertexts as padding.",
+    OutputScalingFactorTooLarge => "The output scaling factor must fit in the precision of the \
+                                    ciphertexts.",
+    InsufficientPolynomialSize => "The polynomial size must be greater than or equal to the number \
+                                   of messages encoded with the input scaling factor."
+}
+
+impl<EngineError: std::error::Error> LweBootstrapLookupTableCreationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks(
+        polynomial_size: PolynomialSize,
+        input_scaling_factor: ScalingFactor,
+        output_scaling_factor: ScalingFactor,
+        integer_precision: usize,
+    ) -> Result<(), Self> {
+        if input_scaling_factor.0 == 0 || output_scaling_factor.0 == 0 {
+            return Err(Self::NullScalingFactor);
+        }
+        let half_modulus = 1_u128 << (integer_precision - 1);
+        if input_scaling_factor.0 as u128 > half_modulus {
+            return Err(Self::InputScalingFactorTooLarge);
+        }
+        if (output_scaling_factor.0 as u128) >> integer_precision != 0 {
+            return Err(Self::OutputScalingFactorTooLarge);
+        }
+        if half_modulus / input_scaling_factor.0 as u128 > polynomial_size.0 as u128 {
+            return Err(Self::InsufficientPolynomialSize);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines creating lookup tables for the programmable bootstrap.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation creates a lookup table evaluating `function`
+/// during a bootstrap, which can be used in place of the accumulator of the
+/// [`LweCiphertextDiscardingBootstrapEngine`](super::LweCiphertextDiscardingBootstrapEngine).
+///
+/// The input messages of the bootstrap are expected to be encoded with the
+/// `input_scaling_factor`, and the most significant bit of the ciphertexts must be kept as
+/// padding. The outputs of `function` are encoded with the `output_scaling_factor`.
+///
+/// # Formal Definition
+///
+/// The input messages $m \in [0, p)$ are encoded as $m\cdot\Delta\_{\mathsf{in}}$, where
+/// $p = q / (2\Delta\_{\mathsf{in}})$. The lookup table is a trivial GLWE encryption of the
+/// polynomial $\sum\_{j=0}^{N-1} f(m\_j)\cdot\Delta\_{\mathsf{out}} X^j$, where $m\_j$ is the message
+/// closest to the phase $j\cdot q / (2N)$. The coefficients for which $m\_j = p$ are set to
+/// $-f(0)\cdot\Delta\_{\mathsf{out}}$, so that the phases slightly below zero are mapped to $f(0)$
+/// as well.
+pub trait LweBootstrapLookupTableCreationEngine<LookupTable>: AbstractEngine
+where
+    LookupTable: GlweCiphertextEntity,
+{
+    /// Creates a lookup table evaluating a function during a bootstrap.
+    fn create_lwe_bootstrap_lookup_table(
+        &mut self,
+        function: &dyn Fn(u64) -> u64,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        input_scaling_factor: ScalingFactor,
+        output_scaling_factor: ScalingFactor,
+    ) -> Result<LookupTable, LweBootstrapLookupTableCreationError<Self::EngineError>>;
+
+    /// Unsafely creates a lookup table evaluating a function during a bootstrap.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweBootstrapLookupTableCreationError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn create_lwe_bootstrap_lookup_table_unchecked(
+        &mut self,
+        function: &dyn Fn(u64) -> u64,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        input_scaling_factor: ScalingFactor,
+        output_scaling_factor: ScalingFactor,
+    ) -> LookupTable;
+}
@@ -0,0 +1,69 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    CleartextEntity, EncoderEntity, LweCiphertextEntity, LweSecretKeyEntity,
+};
+
+engine_error! {
+    LweCiphertextDecryptionDecodingError for LweCiphertextDecryptionDecodingEngine @
+    LweDimensionMismatch => "The input and secret key LWE dimension must be the same."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextDecryptionDecodingError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<SecretKey, Ciphertext>(
+        key: &SecretKey,
+        input: &Ciphertext,
+    ) -> Result<(), Self>
+    where
+        SecretKey: LweSecretKeyEntity,
+        Ciphertext: LweCiphertextEntity,
+    {
+        if key.lwe_dimension() != input.lwe_dimension() {
+            return Err(Self::LweDimensionMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines decrypting and decoding LWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a cleartext containing the
+/// decoding, under the `encoder` encoder, of the decryption of the `input` LWE ciphertext under
+/// the `key` secret key.
+///
+/// # Formal Definition
+///
+/// cf [`here`](`crate::specification::engines::LweCiphertextDecryptionEngine`) for the
+/// decryption, which is followed by the decoding of the resulting plaintext.
+pub trait LweCiphertextDecryptionDecodingEngine<SecretKey, Encoder, Ciphertext, Cleartext>:
+    AbstractEngine
+where
+    SecretKey: LweSecretKeyEntity,
+    Encoder: EncoderEntity,
+    Ciphertext: LweCiphertextEntity,
+    Cleartext: CleartextEntity,
+{
+    /// Decrypts and decodes an LWE ciphertext.
+    fn decrypt_and_decode_lwe_ciphertext(
+        &mut self,
+        key: &SecretKey,
+        encoder: &Encoder,
+        input: &Ciphertext,
+    ) -> Result<Cleartext, LweCiphertextDecryptionDecodingError<Self::EngineError>>;
+
+    /// Unsafely decrypts and decodes an LWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextDecryptionDecodingError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn decrypt_and_decode_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &SecretKey,
+        encoder: &Encoder,
+        input: &Ciphertext,
+    ) -> Cleartext;
+}
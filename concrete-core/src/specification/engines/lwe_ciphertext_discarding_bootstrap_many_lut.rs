@@ -0,0 +1,117 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+
+use crate::specification::entities::{
+    GlweCiphertextEntity, LweBootstrapKeyEntity, LweCiphertextEntity, LweCiphertextVectorEntity,
+};
+
+engine_error! {
+    LweCiphertextDiscardingBootstrapManyLutError for LweCiphertextDiscardingBootstrapManyLutEngine @
+    InputLweDimensionMismatch => "The input ciphertext and key LWE dimension must be the same.",
+    OutputLweDimensionMismatch => "The output ciphertext vector dimension and key size (dimension \
+                                   * polynomial size) must be the same.",
+    AccumulatorPolynomialSizeMismatch => "The accumulator and key polynomial sizes must be the same.",
+    AccumulatorGlweDimensionMismatch => "The accumulator and key GLWE dimensions must be the same.",
+    LutCountNotPowerOfTwo => "The output ciphertext count must be a power of two.",
+    LutCountTooLarge => "The output ciphertext count must not be larger than the polynomial size."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextDiscardingBootstrapManyLutError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<
+        BootstrapKey,
+        Accumulator,
+        InputCiphertext,
+        OutputCiphertextVector,
+    >(
+        output: &OutputCiphertextVector,
+        input: &InputCiphertext,
+        acc: &Accumulator,
+        bsk: &BootstrapKey,
+    ) -> Result<(), Self>
+    where
+        BootstrapKey: LweBootstrapKeyEntity,
+        Accumulator: GlweCiphertextEntity,
+        InputCiphertext: LweCiphertextEntity,
+        OutputCiphertextVector: LweCiphertextVectorEntity,
+    {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(Self::InputLweDimensionMismatch);
+        }
+        if acc.polynomial_size() != bsk.polynomial_size() {
+            return Err(Self::AccumulatorPolynomialSizeMismatch);
+        }
+        if acc.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(Self::AccumulatorGlweDimensionMismatch);
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(Self::OutputLweDimensionMismatch);
+        }
+        if !output.lwe_ciphertext_count().0.is_power_of_two() {
+            return Err(Self::LutCountNotPowerOfTwo);
+        }
+        if output.lwe_ciphertext_count().0 > bsk.polynomial_size().0 {
+            return Err(Self::LutCountTooLarge);
+        }
+
+        Ok(())
+    }
+}
+
+/// A trait for engines bootstrapping (discarding) LWE ciphertexts with several lookup tables.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext vector
+/// with the bootstraps of the `input` LWE ciphertext by $t$ lookup tables packed in the `acc`
+/// accumulator, using the `bsk` bootstrap key. The number of lookup tables $t$ is the number of
+/// ciphertexts of `output`, and must be a power of two.
+///
+/// # Formal Definition
+///
+/// This operation is the "many LUT" variant of the
+/// [`programmable bootstrap`](super::LweCiphertextDiscardingBootstrapEngine): the $t$ lookup
+/// tables share a single blind rotation, which saves $t - 1$ blind rotations compared to $t$
+/// separate bootstraps.
+///
+/// The coefficients of the accumulator are grouped in blocks of $t$ consecutive coefficients, the
+/// coefficient $i$ of a block containing the value of the $i$-th lookup table for the phase of
+/// the block. The rotations $\tilde{a}\_i$ and $\tilde{b}\_{\mathsf{in}}$ of the blind rotation
+/// are rounded to multiples of $t$, so that the coefficient $i$ of the rotated accumulator
+/// contains the $i$-th lookup table evaluated on the input, and the $i$-th output ciphertext is
+/// extracted from this coefficient. The rounding loses $\log\_2(t)$ bits of precision on the
+/// phase of the input, which its encoding must leave free.
+pub trait LweCiphertextDiscardingBootstrapManyLutEngine<
+    BootstrapKey,
+    Accumulator,
+    InputCiphertext,
+    OutputCiphertextVector,
+>: AbstractEngine where
+    BootstrapKey: LweBootstrapKeyEntity,
+    Accumulator: GlweCiphertextEntity,
+    InputCiphertext: LweCiphertextEntity,
+    OutputCiphertextVector: LweCiphertextVectorEntity,
+{
+    /// Bootstraps an LWE ciphertext with several lookup tables.
+    fn discard_bootstrap_many_lut_lwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertextVector,
+        input: &InputCiphertext,
+        acc: &Accumulator,
+        bsk: &BootstrapKey,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapManyLutError<Self::EngineError>>;
+
+    /// Unsafely bootstraps an LWE ciphertext with several lookup tables.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextDiscardingBootstrapManyLutError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_bootstrap_many_lut_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertextVector,
+        input: &InputCiphertext,
+        acc: &Accumulator,
+        bsk: &BootstrapKey,
+    );
+}
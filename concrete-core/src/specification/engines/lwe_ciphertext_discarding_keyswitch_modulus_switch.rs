@@ -0,0 +1,92 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{LweCiphertextEntity, LweKeyswitchKeyEntity};
+use concrete_commons::parameters::CiphertextModulusLog;
+
+engine_error! {
+    LweCiphertextDiscardingKeyswitchModulusSwitchError for LweCiphertextDiscardingKeyswitchModulusSwitchEngine @
+    InputLweDimensionMismatch => "The input ciphertext LWE dimension and keyswitch key input LWE \
+                                  dimensions must be the same.",
+    OutputLweDimensionMismatch => "The output ciphertext LWE dimension and keyswitch output LWE \
+                                   dimensions must be the same.",
+    InvalidModulusLog => "The log of the output modulus must be non-zero, and must not exceed the \
+                          log of the ciphertext modulus."
+}
+
+impl<EngineError: std::error::Error>
+    LweCiphertextDiscardingKeyswitchModulusSwitchError<EngineError>
+{
+    /// Validates the inputs
+    pub fn perform_generic_checks<KeyswitchKey, InputCiphertext, OutputCiphertext>(
+        output: &OutputCiphertext,
+        input: &InputCiphertext,
+        ksk: &KeyswitchKey,
+        modulus_log: CiphertextModulusLog,
+        ciphertext_modulus_log: CiphertextModulusLog,
+    ) -> Result<(), Self>
+    where
+        KeyswitchKey: LweKeyswitchKeyEntity,
+        InputCiphertext: LweCiphertextEntity,
+        OutputCiphertext: LweCiphertextEntity,
+    {
+        if input.lwe_dimension() != ksk.input_lwe_dimension() {
+            return Err(Self::InputLweDimensionMismatch);
+        }
+        if output.lwe_dimension() != ksk.output_lwe_dimension() {
+            return Err(Self::OutputLweDimensionMismatch);
+        }
+        if modulus_log.0 == 0 || modulus_log.0 > ciphertext_modulus_log.0 {
+            return Err(Self::InvalidModulusLog);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines keyswitching (discarding) LWE ciphertexts, and switching the result to a
+/// smaller modulus.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext with
+/// the keyswitch of the `input` LWE ciphertext using the `ksk` LWE keyswitch key, switched to the
+/// modulus $2^{k}$ where $k$ is `modulus_log`.
+///
+/// The modulus-switched ciphertext is kept in the representation of the input ciphertext: each
+/// of its elements is rounded to the closest multiple of $q/2^{k}$, where $q$ is the ciphertext
+/// modulus. With $2^{k} = 2N$, a bootstrap using polynomials of size $N$ then performs its own
+/// modulus switch without any additional rounding. The implementers are expected to round the
+/// output of the keyswitch in place, rather than through an intermediate ciphertext.
+///
+/// # Formal Definition
+pub trait LweCiphertextDiscardingKeyswitchModulusSwitchEngine<
+    KeyswitchKey,
+    InputCiphertext,
+    OutputCiphertext,
+>: AbstractEngine where
+    KeyswitchKey: LweKeyswitchKeyEntity,
+    InputCiphertext: LweCiphertextEntity,
+    OutputCiphertext: LweCiphertextEntity,
+{
+    /// Keyswitches an LWE ciphertext and switches the result to a smaller modulus.
+    fn discard_keyswitch_modulus_switch_lwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        ksk: &KeyswitchKey,
+        modulus_log: CiphertextModulusLog,
+    ) -> Result<(), LweCiphertextDiscardingKeyswitchModulusSwitchError<Self::EngineError>>;
+
+    /// Unsafely keyswitches an LWE ciphertext and switches the result to a smaller modulus.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextDiscardingKeyswitchModulusSwitchError`]. For safety concerns _specific_ to
+    /// an engine, refer to the implementer safety section.
+    unsafe fn discard_keyswitch_modulus_switch_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        ksk: &KeyswitchKey,
+        modulus_log: CiphertextModulusLog,
+    );
+}
@@ -0,0 +1,154 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    GlweRelinearizationKeyEntity, LweCiphertextEntity, LwePackingKeyswitchKeyEntity,
+};
+use concrete_commons::parameters::ScalingFactor;
+
+engine_error! {
+    LweCiphertextDiscardingMultiplicationError for LweCiphertextDiscardingMultiplicationEngine @
+    InputLweDimensionMismatch => "The two input LWE ciphertexts must have the same LWE dimension.",
+    PackingKeyswitchKeyInputLweDimensionMismatch => "The input LWE dimension of the packing \
+                                                     keyswitch key must be the same as the LWE \
+                                                     dimension of the input ciphertexts.",
+    KeysMismatch => "The GLWE dimension and polynomial size of the relinearization key must be the \
+                     same as the output GLWE dimension and polynomial size of the packing \
+                     keyswitch key.",
+    OutputLweDimensionMismatch => "The output LWE dimension must be the product of the GLWE \
+                                   dimension and polynomial size of the relinearization key.",
+    NullScalingFactor => "The scaling factors must be greater than zero.",
+    ScalingFactorTooLarge => "The scaling factors must fit in the precision of the ciphertexts.",
+    ScalingFactorMismatch => "The product of the input scaling factors must be a multiple of the \
+                              output scaling factor."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextDiscardingMultiplicationError<EngineError> {
+    /// Validates the inputs
+    #[allow(clippy::too_many_arguments)]
+    pub fn perform_generic_checks<
+        InputCiphertext1,
+        InputCiphertext2,
+        PackingKeyswitchKey,
+        RelinearizationKey,
+        OutputCiphertext,
+    >(
+        output: &OutputCiphertext,
+        input_1: &InputCiphertext1,
+        input_2: &InputCiphertext2,
+        pksk: &PackingKeyswitchKey,
+        rlk: &RelinearizationKey,
+        input_1_scaling_factor: ScalingFactor,
+        input_2_scaling_factor: ScalingFactor,
+        output_scaling_factor: ScalingFactor,
+        integer_precision: usize,
+    ) -> Result<(), Self>
+    where
+        InputCiphertext1: LweCiphertextEntity,
+        InputCiphertext2: LweCiphertextEntity,
+        PackingKeyswitchKey: LwePackingKeyswitchKeyEntity,
+        RelinearizationKey: GlweRelinearizationKeyEntity,
+        OutputCiphertext: LweCiphertextEntity,
+    {
+        if input_1.lwe_dimension() != input_2.lwe_dimension() {
+            return Err(Self::InputLweDimensionMismatch);
+        }
+        if input_1.lwe_dimension() != pksk.input_lwe_dimension() {
+            return Err(Self::PackingKeyswitchKeyInputLweDimensionMismatch);
+        }
+        if pksk.output_glwe_dimension() != rlk.glwe_dimension()
+            || pksk.output_polynomial_size() != rlk.polynomial_size()
+        {
+            return Err(Self::KeysMismatch);
+        }
+        if output.lwe_dimension().0 != rlk.glwe_dimension().0 * rlk.polynomial_size().0 {
+            return Err(Self::OutputLweDimensionMismatch);
+        }
+        let scaling_factors = [
+            input_1_scaling_factor,
+            input_2_scaling_factor,
+            output_scaling_factor,
+        ];
+        if scaling_factors.iter().any(|factor| factor.0 == 0) {
+            return Err(Self::NullScalingFactor);
+        }
+        if scaling_factors
+            .iter()
+            .any(|factor| (factor.0 as u128) >> integer_precision != 0)
+        {
+            return Err(Self::ScalingFactorTooLarge);
+        }
+        let product = input_1_scaling_factor.0 as u128 * input_2_scaling_factor.0 as u128;
+        if !product.is_multiple_of(output_scaling_factor.0 as u128) {
+            return Err(Self::ScalingFactorMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines multiplying (discarding) LWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext with
+/// the product of the `input_1` and `input_2` LWE ciphertexts. The inputs are first packed into
+/// GLWE ciphertexts with the `pksk` packing keyswitch key, then multiplied with a tensor product
+/// and relinearized with the `rlk` relinearization key, before the constant coefficient of the
+/// result is extracted.
+///
+/// The messages of the inputs are assumed to be encoded with the `input_1_scaling_factor` and
+/// `input_2_scaling_factor` scaling factors, and the message of the output is encoded with the
+/// `output_scaling_factor` scaling factor.
+///
+/// # Formal Definition
+///
+/// For two LWE ciphertexts encrypting $\Delta\_1 m\_1$ and $\Delta\_2 m\_2$ under the same key,
+/// the output is an LWE ciphertext encrypting $\Delta\_{\mathsf{out}} m\_1 m\_2$ under the LWE key
+/// extracted from the GLWE key of `rlk`, where $\Delta\_{\mathsf{out}}$ divides
+/// $\Delta\_1 \Delta\_2$. The tensor product is divided by
+/// $\Delta\_1 \Delta\_2 / \Delta\_{\mathsf{out}}$ before being relinearized.
+pub trait LweCiphertextDiscardingMultiplicationEngine<
+    InputCiphertext1,
+    InputCiphertext2,
+    PackingKeyswitchKey,
+    RelinearizationKey,
+    OutputCiphertext,
+>: AbstractEngine where
+    InputCiphertext1: LweCiphertextEntity,
+    InputCiphertext2: LweCiphertextEntity,
+    PackingKeyswitchKey: LwePackingKeyswitchKeyEntity,
+    RelinearizationKey: GlweRelinearizationKeyEntity,
+    OutputCiphertext: LweCiphertextEntity,
+{
+    /// Multiplies two LWE ciphertexts.
+    #[allow(clippy::too_many_arguments)]
+    fn discard_mul_lwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input_1: &InputCiphertext1,
+        input_2: &InputCiphertext2,
+        pksk: &PackingKeyswitchKey,
+        rlk: &RelinearizationKey,
+        input_1_scaling_factor: ScalingFactor,
+        input_2_scaling_factor: ScalingFactor,
+        output_scaling_factor: ScalingFactor,
+    ) -> Result<(), LweCiphertextDiscardingMultiplicationError<Self::EngineError>>;
+
+    /// Unsafely multiplies two LWE ciphertexts.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextDiscardingMultiplicationError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn discard_mul_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input_1: &InputCiphertext1,
+        input_2: &InputCiphertext2,
+        pksk: &PackingKeyswitchKey,
+        rlk: &RelinearizationKey,
+        input_1_scaling_factor: ScalingFactor,
+        input_2_scaling_factor: ScalingFactor,
+        output_scaling_factor: ScalingFactor,
+    );
+}
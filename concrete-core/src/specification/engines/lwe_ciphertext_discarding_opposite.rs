@@ -4,7 +4,8 @@ use crate::specification::entities::LweCiphertextEntity;
 
 engine_error! {
     LweCiphertextDiscardingOppositeError for LweCiphertextDiscardingOppositeEngine @
-    LweDimensionMismatch => "The input and output LWE dimension must be the same."
+    LweDimensionMismatch => "The input and output LWE dimension must be the same.",
+    CiphertextModulusMismatch => "The input and output ciphertext moduli must be the same."
 }
 
 impl<EngineError: std::error::Error> LweCiphertextDiscardingOppositeError<EngineError> {
@@ -21,6 +22,9 @@ impl<EngineError: std::error::Error> LweCiphertextDiscardingOppositeError<Engine
             return Err(Self::LweDimensionMismatch);
         }
 
+        if output.ciphertext_modulus() != input.ciphertext_modulus() {
+            return Err(Self::CiphertextModulusMismatch);
+        }
         Ok(())
     }
 }
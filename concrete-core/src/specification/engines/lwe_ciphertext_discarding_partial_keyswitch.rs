@@ -0,0 +1,105 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+
+use crate::specification::entities::{LweCiphertextEntity, LwePartialKeyswitchKeyEntity};
+
+engine_error! {
+    LweCiphertextDiscardingPartialKeyswitchError for LweCiphertextDiscardingPartialKeyswitchEngine @
+    InputLweDimensionMismatch => "The input ciphertext LWE dimension and partial keyswitch key \
+                                  input LWE dimensions must be the same.",
+    OutputLweDimensionMismatch => "The output ciphertext LWE dimension and partial keyswitch key \
+                                   output LWE dimensions must be the same."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextDiscardingPartialKeyswitchError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<PartialKeyswitchKey, InputCiphertext, OutputCiphertext>(
+        output: &OutputCiphertext,
+        input: &InputCiphertext,
+        ksk: &PartialKeyswitchKey,
+    ) -> Result<(), Self>
+    where
+        PartialKeyswitchKey: LwePartialKeyswitchKeyEntity,
+        InputCiphertext: LweCiphertextEntity,
+        OutputCiphertext: LweCiphertextEntity,
+    {
+        if input.lwe_dimension() != ksk.input_lwe_dimension() {
+            return Err(Self::InputLweDimensionMismatch);
+        }
+        if output.lwe_dimension() != ksk.output_lwe_dimension() {
+            return Err(Self::OutputLweDimensionMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines partially keyswitching (discarding) LWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext with
+/// the partial keyswitch of the `input` LWE ciphertext, using the `ksk` LWE partial keyswitch key.
+///
+/// # Formal Definition
+///
+/// ## LWE Partial Keyswitch
+///
+/// This homomorphic procedure transforms an input
+/// [`LWE ciphertext`](`crate::specification::entities::LweCiphertextEntity`)
+/// $\mathsf{ct}\_{\mathsf{in}} = \left( a\_0, \cdots, a\_{n\_{\mathsf{in}}-1} , b\_{\mathsf{in}}
+/// \right)$ encrypted under $\vec{s}\_{\mathsf{in}}$ into an output
+/// [`LWE ciphertext`](`crate::specification::entities::LweCiphertextEntity`) encrypted under
+/// $\vec{s}\_{\mathsf{out}} = \left( \vec{s}\_{\mathsf{rep}}, s\_d, \cdots,
+/// s\_{n\_{\mathsf{in}}-1} \right)$. It requires a
+/// [`partial key switching key`](`crate::specification::entities::LwePartialKeyswitchKeyEntity`)
+/// $\mathsf{PKSK}\_{\vec{s}\_{\mathsf{in}}\rightarrow \vec{s}\_{\mathsf{rep}}}$ switching the
+/// first $d$ coefficients of the input key.
+///
+/// ## Algorithm
+/// ###### inputs:
+/// - $\mathsf{ct}\_{\mathsf{in}} = \left( a\_0, \cdots, a\_{n\_{\mathsf{in}}-1} , b\_{\mathsf{in}}
+///   \right)$: an [`LWE ciphertext`](`LweCiphertextEntity`)
+/// - $\mathsf{PKSK}\_{\vec{s}\_{\mathsf{in}}\rightarrow \vec{s}\_{\mathsf{rep}}}$: a
+///   [`partial key switching key`](`crate::specification::entities::LwePartialKeyswitchKeyEntity`)
+///
+/// ###### outputs:
+/// - $\mathsf{ct}\_{\mathsf{out}}$: an
+///   [`LWE ciphertext`](`crate::specification::entities::LweCiphertextEntity`)
+///
+/// ###### algorithm:
+/// 1. set $\mathsf{ct}=\left( 0 , \cdots , 0 , a\_d, \cdots, a\_{n\_{\mathsf{in}}-1},
+///    b\_{\mathsf{in}} \right) \in \mathbb{Z}\_q^{(n\_{\mathsf{rep}} + n\_{\mathsf{in}} - d+1)}$
+/// 2. compute $\mathsf{ct}\_{\mathsf{out}} = \mathsf{ct} -
+///    \sum\_{i=0}^{d-1} \mathsf{decompProduct}\left( a\_i , \overline{\mathsf{ct}\_i} \right)$,
+///    where the products only affect the first $n\_{\mathsf{rep}}$ mask coefficients and the body
+/// 3. output $\mathsf{ct}\_{\mathsf{out}}$
+pub trait LweCiphertextDiscardingPartialKeyswitchEngine<
+    PartialKeyswitchKey,
+    InputCiphertext,
+    OutputCiphertext,
+>: AbstractEngine where
+    PartialKeyswitchKey: LwePartialKeyswitchKeyEntity,
+    InputCiphertext: LweCiphertextEntity,
+    OutputCiphertext: LweCiphertextEntity,
+{
+    /// Partially keyswitch an LWE ciphertext.
+    fn discard_partial_keyswitch_lwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        ksk: &PartialKeyswitchKey,
+    ) -> Result<(), LweCiphertextDiscardingPartialKeyswitchError<Self::EngineError>>;
+
+    /// Unsafely partially keyswitch an LWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextDiscardingPartialKeyswitchError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_partial_keyswitch_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        ksk: &PartialKeyswitchKey,
+    );
+}
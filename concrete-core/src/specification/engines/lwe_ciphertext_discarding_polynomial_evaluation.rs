@@ -0,0 +1,86 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{CleartextEntity, GlweCiphertextEntity, LweCiphertextEntity};
+use concrete_commons::parameters::LweDimension;
+
+engine_error! {
+    LweCiphertextDiscardingPolynomialEvaluationError for
+    LweCiphertextDiscardingPolynomialEvaluationEngine @
+    SizeMismatch => "The sizes of the output LWE (LWE dimension) and the input GLWE (GLWE \
+                     dimension * poly size) must be compatible."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextDiscardingPolynomialEvaluationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<GlweCiphertext, LweCiphertext>(
+        output: &LweCiphertext,
+        input: &GlweCiphertext,
+    ) -> Result<(), Self>
+    where
+        GlweCiphertext: GlweCiphertextEntity,
+        LweCiphertext: LweCiphertextEntity,
+    {
+        if output.lwe_dimension()
+            != LweDimension(input.polynomial_size().0 * input.glwe_dimension().0)
+        {
+            return Err(Self::SizeMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines evaluating (discarding) the message of GLWE ciphertexts at a cleartext
+/// point.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext with
+/// the evaluation of the polynomial encrypted in the `input` GLWE ciphertext at the `point`
+/// cleartext. The output is encrypted under the LWE key obtained from the GLWE key of the input,
+/// as for the [`sample extraction`](super::LweCiphertextDiscardingExtractionEngine).
+///
+/// # Formal Definition
+///
+/// The input GLWE ciphertext $\left( A\_0, \ldots, A\_{k-1}, B\right) \in \mathcal{R}\_N^{k+1}$
+/// encrypts $M(X) = \sum\_{i=0}^{N-1} m\_i X^i$ under $\vec{S}$. For a cleartext $x$, the map
+/// $L: P \mapsto \sum\_{i=0}^{N-1} p\_i x^i$ is linear in the coefficients of $P$, so that
+/// $$L(B) - \sum\_{j=0}^{k-1}\sum\_{l=0}^{N-1} s\_{j,l} \cdot L\left(X^l \cdot A\_j\right) =
+/// L(M) + L(E)$$
+/// and the output LWE ciphertext is
+/// $\left( L(A\_0), \ldots, L(X^{N-1} \cdot A\_0), \ldots, L(X^{N-1} \cdot A\_{k-1}), L(B)
+/// \right)$. The evaluations are computed with the Horner method, each $L(X^{l+1} \cdot A\_j)$
+/// being obtained from $L(X^l \cdot A\_j)$ with a multiplication by $x$ and a correction of the
+/// coefficient wrapping around $X^N = -1$.
+///
+/// The noise $E$ of the input is evaluated the same way, which means that the point must be
+/// small enough for the noise $L(E)$ to remain below the precision of the encoding.
+pub trait LweCiphertextDiscardingPolynomialEvaluationEngine<
+    GlweCiphertext,
+    Cleartext,
+    LweCiphertext,
+>: AbstractEngine where
+    GlweCiphertext: GlweCiphertextEntity,
+    Cleartext: CleartextEntity,
+    LweCiphertext: LweCiphertextEntity,
+{
+    /// Evaluates the polynomial encrypted in a GLWE ciphertext at a cleartext point.
+    fn discard_evaluate_polynomial_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext,
+        input: &GlweCiphertext,
+        point: &Cleartext,
+    ) -> Result<(), LweCiphertextDiscardingPolynomialEvaluationError<Self::EngineError>>;
+
+    /// Unsafely evaluates the polynomial encrypted in a GLWE ciphertext at a cleartext point.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextDiscardingPolynomialEvaluationError`]. For safety concerns _specific_ to
+    /// an engine, refer to the implementer safety section.
+    unsafe fn discard_evaluate_polynomial_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext,
+        input: &GlweCiphertext,
+        point: &Cleartext,
+    );
+}
@@ -0,0 +1,158 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use concrete_commons::dispersion::Variance;
+
+use crate::specification::entities::{
+    GlweCiphertextEntity, LweBootstrapKeyEntity, LweCiphertextEntity, LwePublicKeyEntity,
+};
+
+engine_error! {
+    LweCiphertextDiscardingSanitizationError for LweCiphertextDiscardingSanitizationEngine @
+    InputLweDimensionMismatch => "The input ciphertext and key LWE dimension must be the same.",
+    OutputLweDimensionMismatch => "The output ciphertext dimension and key size (dimension * \
+                                   polynomial size) must be the same.",
+    AccumulatorPolynomialSizeMismatch => "The accumulator and key polynomial sizes must be the same.",
+    AccumulatorGlweDimensionMismatch => "The accumulator and key GLWE dimensions must be the same.",
+    InputPublicKeyLweDimensionMismatch => "The input public key and input ciphertext LWE \
+                                           dimensions must be the same.",
+    OutputPublicKeyLweDimensionMismatch => "The output public key and output ciphertext LWE \
+                                            dimensions must be the same."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextDiscardingSanitizationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<
+        BootstrapKey,
+        Accumulator,
+        PublicKey,
+        InputCiphertext,
+        OutputCiphertext,
+    >(
+        output: &OutputCiphertext,
+        input: &InputCiphertext,
+        acc: &Accumulator,
+        bsk: &BootstrapKey,
+        input_public_key: &PublicKey,
+        output_public_key: &PublicKey,
+    ) -> Result<(), Self>
+    where
+        BootstrapKey: LweBootstrapKeyEntity,
+        Accumulator: GlweCiphertextEntity,
+        PublicKey: LwePublicKeyEntity,
+        InputCiphertext: LweCiphertextEntity,
+        OutputCiphertext: LweCiphertextEntity,
+    {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(Self::InputLweDimensionMismatch);
+        }
+        if acc.polynomial_size() != bsk.polynomial_size() {
+            return Err(Self::AccumulatorPolynomialSizeMismatch);
+        }
+        if acc.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(Self::AccumulatorGlweDimensionMismatch);
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(Self::OutputLweDimensionMismatch);
+        }
+        if input_public_key.lwe_dimension() != input.lwe_dimension() {
+            return Err(Self::InputPublicKeyLweDimensionMismatch);
+        }
+        if output_public_key.lwe_dimension() != output.lwe_dimension() {
+            return Err(Self::OutputPublicKeyLweDimensionMismatch);
+        }
+
+        Ok(())
+    }
+}
+
+/// A trait for engines sanitizing (discarding) LWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext with
+/// the bootstrap of the `input` LWE ciphertext, using the `acc` accumulator as lookup-table, and
+/// the `bsk` bootstrap key. Unlike the output of the
+/// [`LweCiphertextDiscardingBootstrapEngine`](super::LweCiphertextDiscardingBootstrapEngine), the
+/// output of this operation is statistically independent of the `input` ciphertext, given the
+/// message it encrypts: it reveals nothing of the computations which produced the input
+/// ciphertext, even to the owner of the secret keys.
+///
+/// # Formal Definition
+///
+/// The sanitization is one wash cycle of the soak-spin-repeat strategy of
+/// [Ducas and Stehlé](https://eprint.iacr.org/2016/164), with an additional randomization of the
+/// accumulator:
+///
+/// 1. The input ciphertext is rerandomized, by adding to it a uniformly random subset of the
+///    encryptions of zero of `input_public_key`.
+/// 2. A fresh gaussian noise of variance `accumulator_noise` is added to every coefficient of the
+///    body of a copy of the accumulator.
+/// 3. The rerandomized input ciphertext is bootstrapped with this randomized accumulator, as
+///    described in
+///    [`LweCiphertextDiscardingBootstrapEngine`](super::LweCiphertextDiscardingBootstrapEngine).
+/// 4. The output ciphertext is rerandomized, by adding to it a uniformly random subset of the
+///    encryptions of zero of `output_public_key`, and a fresh gaussian noise of variance
+///    `flooding_noise` is added to its body.
+///
+/// # Security Parameters
+///
+/// The output ciphertext is only statistically independent of the input ciphertext when the
+/// parameters satisfy the following conditions, for a statistical security parameter $\lambda$
+/// (typically $\lambda = 40$ or more):
+///
+/// + The public keys must contain at least $(n+1) \cdot \log\_2(q) + 2\lambda$ encryptions of
+///   zero, where $n$ is the LWE dimension of the key, for the leftover hash lemma to make the
+///   rerandomized masks close to uniform.
+/// + The `flooding_noise` must be at least $2^{2\lambda}$ times larger than the variance of the
+///   noise of the output of the bootstrap, including the noise of the `output_public_key`
+///   encryptions of zero, so that the distribution of the output noise does not depend on the
+///   input ciphertext. The message of the output ciphertext must leave room for this noise.
+/// + The `accumulator_noise` hides the rotation applied to the accumulator by the blind rotation
+///   in the noise of the output. It must be small enough for the bootstrap to stay correct, and is
+///   typically chosen close to the variance of the noise added by the blind rotation.
+pub trait LweCiphertextDiscardingSanitizationEngine<
+    BootstrapKey,
+    Accumulator,
+    PublicKey,
+    InputCiphertext,
+    OutputCiphertext,
+>: AbstractEngine where
+    BootstrapKey: LweBootstrapKeyEntity,
+    Accumulator: GlweCiphertextEntity,
+    PublicKey: LwePublicKeyEntity,
+    InputCiphertext: LweCiphertextEntity,
+    OutputCiphertext: LweCiphertextEntity,
+{
+    /// Sanitizes an LWE ciphertext.
+    #[allow(clippy::too_many_arguments)]
+    fn discard_sanitize_lwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        acc: &Accumulator,
+        bsk: &BootstrapKey,
+        input_public_key: &PublicKey,
+        output_public_key: &PublicKey,
+        accumulator_noise: Variance,
+        flooding_noise: Variance,
+    ) -> Result<(), LweCiphertextDiscardingSanitizationError<Self::EngineError>>;
+
+    /// Unsafely sanitizes an LWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextDiscardingSanitizationError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn discard_sanitize_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        acc: &Accumulator,
+        bsk: &BootstrapKey,
+        input_public_key: &PublicKey,
+        output_public_key: &PublicKey,
+        accumulator_noise: Variance,
+        flooding_noise: Variance,
+    );
+}
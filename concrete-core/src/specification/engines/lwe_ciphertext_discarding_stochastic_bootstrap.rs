@@ -0,0 +1,106 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+
+use crate::specification::entities::{
+    GlweCiphertextEntity, LweBootstrapKeyEntity, LweCiphertextEntity,
+};
+
+engine_error! {
+    LweCiphertextDiscardingStochasticBootstrapError for
+    LweCiphertextDiscardingStochasticBootstrapEngine @
+    InputLweDimensionMismatch => "The input ciphertext and key LWE dimension must be the same.",
+    OutputLweDimensionMismatch => "The output ciphertext dimension and key size (dimension * \
+                                   polynomial size) must be the same.",
+    AccumulatorPolynomialSizeMismatch => "The accumulator and key polynomial sizes must be the same.",
+    AccumulatorGlweDimensionMismatch => "The accumulator and key GLWE dimensions must be the same."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextDiscardingStochasticBootstrapError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<BootstrapKey, Accumulator, InputCiphertext, OutputCiphertext>(
+        output: &OutputCiphertext,
+        input: &InputCiphertext,
+        acc: &Accumulator,
+        bsk: &BootstrapKey,
+    ) -> Result<(), Self>
+    where
+        BootstrapKey: LweBootstrapKeyEntity,
+        Accumulator: GlweCiphertextEntity,
+        InputCiphertext: LweCiphertextEntity,
+        OutputCiphertext: LweCiphertextEntity,
+    {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(Self::InputLweDimensionMismatch);
+        }
+        if acc.polynomial_size() != bsk.polynomial_size() {
+            return Err(Self::AccumulatorPolynomialSizeMismatch);
+        }
+        if acc.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(Self::AccumulatorGlweDimensionMismatch);
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(Self::OutputLweDimensionMismatch);
+        }
+
+        Ok(())
+    }
+}
+
+/// A trait for engines bootstrapping (discarding) LWE ciphertexts with a stochastic rounding in the
+/// modulus switch.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext with
+/// the bootstrap of the `input` LWE ciphertext, using the `acc` accumulator as lookup-table, and
+/// the `bsk` bootstrap key. It differs from the
+/// [`LweCiphertextDiscardingBootstrapEngine`](super::LweCiphertextDiscardingBootstrapEngine) only
+/// in the way the coefficients of the input ciphertext are switched to the modulus $2N$.
+///
+/// # Formal Definition
+///
+/// The algorithm is the one of the programmable bootstrapping described in
+/// [`LweCiphertextDiscardingBootstrapEngine`](super::LweCiphertextDiscardingBootstrapEngine),
+/// except for the first two steps. Instead of being rounded to the nearest, every value $x \in
+/// \\{a\_0, \cdots, a\_{n\_{\mathsf{in}}-1}, b\_{\mathsf{in}}\\}$ is switched to
+/// $\lfloor \frac{2N\_{\mathsf{out}} \cdot x}{q} \rfloor + 1$ with probability
+/// $\frac{2N\_{\mathsf{out}} \cdot x}{q} - \lfloor \frac{2N\_{\mathsf{out}} \cdot x}{q} \rfloor$,
+/// and to $\lfloor \frac{2N\_{\mathsf{out}} \cdot x}{q} \rfloor$ otherwise.
+///
+/// The rounding errors of this stochastic rounding have a zero mean whatever the input, which
+/// removes the bias the rounding to the nearest can introduce in the phase of the switched
+/// ciphertext. Their variance is twice as large as the one of the rounding to the nearest.
+pub trait LweCiphertextDiscardingStochasticBootstrapEngine<
+    BootstrapKey,
+    Accumulator,
+    InputCiphertext,
+    OutputCiphertext,
+>: AbstractEngine where
+    BootstrapKey: LweBootstrapKeyEntity,
+    Accumulator: GlweCiphertextEntity,
+    InputCiphertext: LweCiphertextEntity,
+    OutputCiphertext: LweCiphertextEntity,
+{
+    /// Bootstrap an LWE ciphertext with a stochastic modulus switch.
+    fn discard_stochastic_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        acc: &Accumulator,
+        bsk: &BootstrapKey,
+    ) -> Result<(), LweCiphertextDiscardingStochasticBootstrapError<Self::EngineError>>;
+
+    /// Unsafely bootstrap an LWE ciphertext with a stochastic modulus switch.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextDiscardingStochasticBootstrapError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_stochastic_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        acc: &Accumulator,
+        bsk: &BootstrapKey,
+    );
+}
@@ -4,7 +4,8 @@ use crate::specification::entities::LweCiphertextEntity;
 
 engine_error! {
     LweCiphertextDiscardingSubtractionError for LweCiphertextDiscardingSubtractionEngine @
-    LweDimensionMismatch => "All the ciphertext LWE dimensions must be the same."
+    LweDimensionMismatch => "All the ciphertext LWE dimensions must be the same.",
+    CiphertextModulusMismatch => "The input and output ciphertext moduli must be the same."
 }
 
 impl<EngineError: std::error::Error> LweCiphertextDiscardingSubtractionError<EngineError> {
@@ -23,6 +24,11 @@ impl<EngineError: std::error::Error> LweCiphertextDiscardingSubtractionError<Eng
         {
             return Err(Self::LweDimensionMismatch);
         }
+        if output.ciphertext_modulus() != input_1.ciphertext_modulus()
+            || output.ciphertext_modulus() != input_2.ciphertext_modulus()
+        {
+            return Err(Self::CiphertextModulusMismatch);
+        }
         Ok(())
     }
 }
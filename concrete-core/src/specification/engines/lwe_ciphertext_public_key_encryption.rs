@@ -0,0 +1,60 @@
+use super::engine_error;
+
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{LweCiphertextEntity, LwePublicKeyEntity, PlaintextEntity};
+
+engine_error! {
+    LweCiphertextPublicKeyEncryptionError for LweCiphertextPublicKeyEncryptionEngine @
+}
+
+/// A trait for engines encrypting LWE ciphertexts with a public key.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates an LWE ciphertext containing the
+/// encryption of the `input` plaintext, using the public `key`. The ciphertext can be decrypted by
+/// the secret key used to generate the public key.
+///
+/// # Formal Definition
+///
+/// ## LWE Public Key Encryption
+/// ###### inputs:
+/// - $\mathsf{pt}\in\mathbb{Z}\_q$: a plaintext
+/// - $\left(\mathsf{ct}\_1, \cdots, \mathsf{ct}\_m\right)$: a public key, made of $m$ LWE
+///   encryptions of zero under the secret key $\vec{s}\in\mathbb{Z}\_q^n$
+///
+/// ###### outputs:
+/// - $\mathsf{ct} \in \mathsf{LWE}^n\_{\vec{s}}( \mathsf{pt} )\subseteq \mathbb{Z}\_q^{(n+1)}$: an
+///   LWE ciphertext
+///
+/// ###### algorithm:
+/// 1. uniformly sample a binary vector $\vec{r}\in\\{0,1\\}^m$
+/// 2. compute $\mathsf{ct} = \left(\vec{0}, \mathsf{pt}\right) + \sum\_{i=1}^{m} r\_i
+///    \mathsf{ct}\_i$
+/// 3. output $\mathsf{ct}$
+pub trait LweCiphertextPublicKeyEncryptionEngine<PublicKey, Plaintext, Ciphertext>:
+    AbstractEngine
+where
+    PublicKey: LwePublicKeyEntity,
+    Plaintext: PlaintextEntity,
+    Ciphertext: LweCiphertextEntity,
+{
+    /// Encrypts an LWE ciphertext using a public key.
+    fn encrypt_lwe_ciphertext_with_public_key(
+        &mut self,
+        key: &PublicKey,
+        input: &Plaintext,
+    ) -> Result<Ciphertext, LweCiphertextPublicKeyEncryptionError<Self::EngineError>>;
+
+    /// Unsafely encrypts an LWE ciphertext using a public key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextPublicKeyEncryptionError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn encrypt_lwe_ciphertext_with_public_key_unchecked(
+        &mut self,
+        key: &PublicKey,
+        input: &Plaintext,
+    ) -> Ciphertext;
+}
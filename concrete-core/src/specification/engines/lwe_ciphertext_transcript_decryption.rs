@@ -0,0 +1,113 @@
+use super::engine_error;
+use crate::prelude::numeric::UnsignedInteger;
+use crate::prelude::{AbstractEngine, DeltaLog, LweCiphertextEntity, LweSecretKeyEntity};
+
+engine_error! {
+    LweCiphertextTranscriptDecryptionError for LweCiphertextTranscriptDecryptionEngine @
+    LweDimensionMismatch => "The input and secret key LWE dimension must be the same.",
+    InvalidDeltaLog => "The delta log must be non-zero, and lower than the bit size of the \
+                        ciphertext integers."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextTranscriptDecryptionError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<SecretKey, Ciphertext, Scalar>(
+        key: &SecretKey,
+        input: &Ciphertext,
+        delta_log: DeltaLog,
+    ) -> Result<(), Self>
+    where
+        SecretKey: LweSecretKeyEntity,
+        Ciphertext: LweCiphertextEntity,
+        Scalar: UnsignedInteger,
+    {
+        if key.lwe_dimension() != input.lwe_dimension() {
+            return Err(Self::LweDimensionMismatch);
+        }
+        if delta_log.0 == 0 || delta_log.0 >= Scalar::BITS {
+            return Err(Self::InvalidDeltaLog);
+        }
+        Ok(())
+    }
+}
+
+/// The intermediate values computed during the decryption of an LWE ciphertext.
+///
+/// All the values are integers modulo $q = 2^{\mathsf{BITS}}$, stored in `Scalar` integers. The
+/// transcript is meant to be fed to an external proof system, to prove that the decrypted
+/// message is the one carried by the ciphertext without revealing the secret key $\vec{s}$. The
+/// relations to prove are:
+/// - $\mathsf{mask\\_key\\_dot\\_product} = \left\langle \vec{a} , \vec{s} \right\rangle$
+/// - $\mathsf{phase} = b - \mathsf{mask\\_key\\_dot\\_product}$
+/// - $\mathsf{rounded\\_phase} = \mathsf{message} \cdot \Delta$
+/// - $\mathsf{phase} = \mathsf{rounded\\_phase} + \mathsf{rounding\\_error}$, with the rounding
+///   error, read as a signed integer, in $\left[-\Delta/2, \Delta/2\right[$
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweDecryptionTranscript<Scalar: UnsignedInteger> {
+    /// The mask $\vec{a}$ of the ciphertext.
+    pub mask: Vec<Scalar>,
+    /// The body $b$ of the ciphertext.
+    pub body: Scalar,
+    /// The dot product between the mask and the secret key.
+    pub mask_key_dot_product: Scalar,
+    /// The phase of the ciphertext, that is, its raw decryption.
+    pub phase: Scalar,
+    /// The base 2 logarithm of the scaling factor $\Delta$ used for the rounding.
+    pub delta_log: DeltaLog,
+    /// Whether the phase was rounded up to the next multiple of $\Delta$.
+    pub rounded_up: bool,
+    /// The multiple of $\Delta$ closest to the phase.
+    pub rounded_phase: Scalar,
+    /// The difference between the phase and the rounded phase.
+    pub rounding_error: Scalar,
+    /// The decrypted message, reduced modulo $q / \Delta$.
+    pub message: Scalar,
+}
+
+/// A trait for engines decrypting LWE ciphertexts, while recording a transcript of the
+/// intermediate values.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates an [`LweDecryptionTranscript`]
+/// containing the decryption of the `input` LWE ciphertext under the `key` secret key, rounded to
+/// the closest multiple of $\Delta = 2^{\mathsf{delta\\_log}}$, along with the values computed to
+/// obtain it.
+///
+/// # Formal Definition
+///
+/// cf [`here`](`crate::specification::engines::LweCiphertextDecryptionEngine`) for the
+/// decryption, which is followed by the rounding of the resulting plaintext. The rounding is
+/// decided by the bit of weight $\Delta/2$ of the plaintext, which is set when the plaintext is
+/// rounded up.
+pub trait LweCiphertextTranscriptDecryptionEngine<SecretKey, Ciphertext, Scalar>:
+    AbstractEngine
+where
+    SecretKey: LweSecretKeyEntity,
+    Ciphertext: LweCiphertextEntity,
+    Scalar: UnsignedInteger,
+{
+    /// Decrypts an LWE ciphertext, and records the transcript of the decryption.
+    fn decrypt_lwe_ciphertext_with_transcript(
+        &mut self,
+        key: &SecretKey,
+        input: &Ciphertext,
+        delta_log: DeltaLog,
+    ) -> Result<
+        LweDecryptionTranscript<Scalar>,
+        LweCiphertextTranscriptDecryptionError<Self::EngineError>,
+    >;
+
+    /// Unsafely decrypts an LWE ciphertext, and records the transcript of the decryption.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextTranscriptDecryptionError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn decrypt_lwe_ciphertext_with_transcript_unchecked(
+        &mut self,
+        key: &SecretKey,
+        input: &Ciphertext,
+        delta_log: DeltaLog,
+    ) -> LweDecryptionTranscript<Scalar>;
+}
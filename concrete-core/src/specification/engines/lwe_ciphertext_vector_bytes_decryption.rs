@@ -0,0 +1,64 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    EncoderEntity, LweCiphertextVectorEntity, LweSecretKeyEntity,
+};
+
+engine_error! {
+    LweCiphertextVectorBytesDecryptionError for LweCiphertextVectorBytesDecryptionEngine @
+    LweDimensionMismatch => "The input and secret key LWE dimension must be the same."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextVectorBytesDecryptionError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<SecretKey, CiphertextVector>(
+        key: &SecretKey,
+        input: &CiphertextVector,
+    ) -> Result<(), Self>
+    where
+        SecretKey: LweSecretKeyEntity,
+        CiphertextVector: LweCiphertextVectorEntity,
+    {
+        if key.lwe_dimension() != input.lwe_dimension() {
+            return Err(Self::LweDimensionMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines decrypting LWE ciphertext vectors into byte strings.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation decrypts the `input` LWE ciphertext vector
+/// under the `key` secret key, decodes the messages with the `encoder`, and gathers them back into
+/// the byte string sealed by a
+/// [`LweCiphertextVectorBytesEncryptionEngine`](`super::LweCiphertextVectorBytesEncryptionEngine`).
+pub trait LweCiphertextVectorBytesDecryptionEngine<SecretKey, Encoder, CiphertextVector>:
+    AbstractEngine
+where
+    SecretKey: LweSecretKeyEntity,
+    Encoder: EncoderEntity,
+    CiphertextVector: LweCiphertextVectorEntity,
+{
+    /// Decrypts an LWE ciphertext vector into a byte string.
+    fn decrypt_lwe_ciphertext_vector_to_bytes(
+        &mut self,
+        key: &SecretKey,
+        encoder: &Encoder,
+        input: &CiphertextVector,
+    ) -> Result<Vec<u8>, LweCiphertextVectorBytesDecryptionError<Self::EngineError>>;
+
+    /// Unsafely decrypts an LWE ciphertext vector into a byte string.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorBytesDecryptionError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn decrypt_lwe_ciphertext_vector_to_bytes_unchecked(
+        &mut self,
+        key: &SecretKey,
+        encoder: &Encoder,
+        input: &CiphertextVector,
+    ) -> Vec<u8>;
+}
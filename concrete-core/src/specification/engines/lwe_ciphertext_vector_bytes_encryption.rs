@@ -0,0 +1,68 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    EncoderEntity, LweCiphertextVectorEntity, LweSecretKeyEntity,
+};
+use concrete_commons::dispersion::Variance;
+
+engine_error! {
+    LweCiphertextVectorBytesEncryptionError for LweCiphertextVectorBytesEncryptionEngine @
+    EmptyInput => "The input byte string must not be empty."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextVectorBytesEncryptionError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks(input: &[u8]) -> Result<(), Self> {
+        if input.is_empty() {
+            return Err(Self::EmptyInput);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines encrypting byte strings as LWE ciphertext vectors.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates an LWE ciphertext vector containing
+/// the encryption of the `input` byte string, under the `key` secret key. Each byte is split into
+/// one or several messages, which are encoded with the `encoder` and encrypted separately.
+///
+/// This is meant to seal short byte strings such as symmetric keys, in hybrid schemes where the
+/// bulk of the data is encrypted with a symmetric cipher and only its key is encrypted under LWE.
+/// The engine must refuse the encryption if the `noise` does not guarantee the exact decryption of
+/// the bytes.
+///
+/// # Formal Definition
+///
+/// cf [`here`](`crate::specification::engines::LweCiphertextEncryptionEngine`)
+pub trait LweCiphertextVectorBytesEncryptionEngine<SecretKey, Encoder, CiphertextVector>:
+    AbstractEngine
+where
+    SecretKey: LweSecretKeyEntity,
+    Encoder: EncoderEntity,
+    CiphertextVector: LweCiphertextVectorEntity,
+{
+    /// Encrypts a byte string as an LWE ciphertext vector.
+    fn encrypt_lwe_ciphertext_vector_from_bytes(
+        &mut self,
+        key: &SecretKey,
+        encoder: &Encoder,
+        input: &[u8],
+        noise: Variance,
+    ) -> Result<CiphertextVector, LweCiphertextVectorBytesEncryptionError<Self::EngineError>>;
+
+    /// Unsafely encrypts a byte string as an LWE ciphertext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorBytesEncryptionError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn encrypt_lwe_ciphertext_vector_from_bytes_unchecked(
+        &mut self,
+        key: &SecretKey,
+        encoder: &Encoder,
+        input: &[u8],
+        noise: Variance,
+    ) -> CiphertextVector;
+}
@@ -0,0 +1,77 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    CleartextVectorEntity, EncoderVectorEntity, LweCiphertextVectorEntity, LweSecretKeyEntity,
+};
+
+engine_error! {
+    LweCiphertextVectorDecryptionDecodingError for LweCiphertextVectorDecryptionDecodingEngine @
+    LweDimensionMismatch => "The input and secret key LWE dimension must be the same.",
+    EncoderCountMismatch => "The encoder count and input ciphertext count must be the same."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextVectorDecryptionDecodingError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<SecretKey, EncoderVector, CiphertextVector>(
+        key: &SecretKey,
+        encoder: &EncoderVector,
+        input: &CiphertextVector,
+    ) -> Result<(), Self>
+    where
+        SecretKey: LweSecretKeyEntity,
+        EncoderVector: EncoderVectorEntity,
+        CiphertextVector: LweCiphertextVectorEntity,
+    {
+        if key.lwe_dimension() != input.lwe_dimension() {
+            return Err(Self::LweDimensionMismatch);
+        }
+        if encoder.encoder_count().0 != input.lwe_ciphertext_count().0 {
+            return Err(Self::EncoderCountMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines decrypting and decoding LWE ciphertext vectors.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a cleartext vector containing the
+/// element-wise decodings, under the `encoder` encoder vector, of the decryptions of the `input`
+/// LWE ciphertext vector under the `key` secret key.
+///
+/// # Formal Definition
+///
+/// cf [`here`](`crate::specification::engines::LweCiphertextDecryptionDecodingEngine`)
+pub trait LweCiphertextVectorDecryptionDecodingEngine<
+    SecretKey,
+    EncoderVector,
+    CiphertextVector,
+    CleartextVector,
+>: AbstractEngine where
+    SecretKey: LweSecretKeyEntity,
+    EncoderVector: EncoderVectorEntity,
+    CiphertextVector: LweCiphertextVectorEntity,
+    CleartextVector: CleartextVectorEntity,
+{
+    /// Decrypts and decodes an LWE ciphertext vector.
+    fn decrypt_and_decode_lwe_ciphertext_vector(
+        &mut self,
+        key: &SecretKey,
+        encoder: &EncoderVector,
+        input: &CiphertextVector,
+    ) -> Result<CleartextVector, LweCiphertextVectorDecryptionDecodingError<Self::EngineError>>;
+
+    /// Unsafely decrypts and decodes an LWE ciphertext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorDecryptionDecodingError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn decrypt_and_decode_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &SecretKey,
+        encoder: &EncoderVector,
+        input: &CiphertextVector,
+    ) -> CleartextVector;
+}
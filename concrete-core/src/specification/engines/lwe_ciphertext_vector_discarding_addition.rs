@@ -5,7 +5,8 @@ use crate::specification::entities::LweCiphertextVectorEntity;
 engine_error! {
     LweCiphertextVectorDiscardingAdditionError for LweCiphertextVectorDiscardingAdditionEngine @
     LweDimensionMismatch => "The input and output LWE dimensions must be the same.",
-    CiphertextCountMismatch => "The input and output ciphertext count must be the same."
+    CiphertextCountMismatch => "The input and output ciphertext count must be the same.",
+    CiphertextModulusMismatch => "The input and output ciphertext moduli must be the same."
 }
 
 impl<EngineError: std::error::Error> LweCiphertextVectorDiscardingAdditionError<EngineError> {
@@ -29,6 +30,11 @@ impl<EngineError: std::error::Error> LweCiphertextVectorDiscardingAdditionError<
         {
             return Err(Self::CiphertextCountMismatch);
         }
+        if output.ciphertext_modulus() != input_1.ciphertext_modulus()
+            || output.ciphertext_modulus() != input_2.ciphertext_modulus()
+        {
+            return Err(Self::CiphertextModulusMismatch);
+        }
         Ok(())
     }
 }
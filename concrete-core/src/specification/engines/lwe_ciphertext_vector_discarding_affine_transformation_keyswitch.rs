@@ -0,0 +1,109 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    CleartextVectorEntity, LweCiphertextEntity, LweCiphertextVectorEntity, LweKeyswitchKeyEntity,
+    PlaintextEntity,
+};
+
+engine_error! {
+    LweCiphertextVectorDiscardingAffineTransformationKeyswitchError for LweCiphertextVectorDiscardingAffineTransformationKeyswitchEngine @
+    InputLweDimensionMismatch => "The input ciphertext vector LWE dimension and keyswitch key input \
+                                  LWE dimensions must be the same.",
+    OutputLweDimensionMismatch => "The output ciphertext LWE dimension and keyswitch output LWE \
+                                   dimensions must be the same.",
+    CleartextCountMismatch => "The cleartext vector count and input vector count must be the same."
+}
+
+impl<EngineError: std::error::Error>
+    LweCiphertextVectorDiscardingAffineTransformationKeyswitchError<EngineError>
+{
+    /// Validates the inputs
+    pub fn perform_generic_checks<
+        CiphertextVector,
+        CleartextVector,
+        KeyswitchKey,
+        OutputCiphertext,
+    >(
+        output: &OutputCiphertext,
+        inputs: &CiphertextVector,
+        weights: &CleartextVector,
+        ksk: &KeyswitchKey,
+    ) -> Result<(), Self>
+    where
+        CiphertextVector: LweCiphertextVectorEntity,
+        CleartextVector: CleartextVectorEntity,
+        KeyswitchKey: LweKeyswitchKeyEntity,
+        OutputCiphertext: LweCiphertextEntity,
+    {
+        if inputs.lwe_dimension() != ksk.input_lwe_dimension() {
+            return Err(Self::InputLweDimensionMismatch);
+        }
+        if output.lwe_dimension() != ksk.output_lwe_dimension() {
+            return Err(Self::OutputLweDimensionMismatch);
+        }
+        if inputs.lwe_ciphertext_count().0 != weights.cleartext_count().0 {
+            return Err(Self::CleartextCountMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines performing a (discarding) affine transformation of LWE ciphertexts,
+/// followed by a keyswitch.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext with
+/// the keyswitch, using the `ksk` LWE keyswitch key, of the affine transform of the `inputs` LWE
+/// ciphertext vector with the `weights` cleartext vector and the `bias` plaintext.
+///
+/// The result is the same as the one of an
+/// [`LweCiphertextVectorDiscardingAffineTransformationEngine`](super::LweCiphertextVectorDiscardingAffineTransformationEngine)
+/// followed by an
+/// [`LweCiphertextDiscardingKeyswitchEngine`](super::LweCiphertextDiscardingKeyswitchEngine), but
+/// the implementers are expected to not materialize the affine transform in memory. This pair of
+/// operations is found at the input of most bootstraps of a circuit, so fusing them saves a
+/// large share of the memory traffic of deep circuits.
+///
+/// # Formal Definition
+pub trait LweCiphertextVectorDiscardingAffineTransformationKeyswitchEngine<
+    CiphertextVector,
+    CleartextVector,
+    Plaintext,
+    KeyswitchKey,
+    OutputCiphertext,
+>: AbstractEngine where
+    CiphertextVector: LweCiphertextVectorEntity,
+    CleartextVector: CleartextVectorEntity,
+    Plaintext: PlaintextEntity,
+    KeyswitchKey: LweKeyswitchKeyEntity,
+    OutputCiphertext: LweCiphertextEntity,
+{
+    /// Keyswitches the affine transform of an LWE ciphertext vector.
+    fn discard_affine_transform_keyswitch_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut OutputCiphertext,
+        inputs: &CiphertextVector,
+        weights: &CleartextVector,
+        bias: &Plaintext,
+        ksk: &KeyswitchKey,
+    ) -> Result<
+        (),
+        LweCiphertextVectorDiscardingAffineTransformationKeyswitchError<Self::EngineError>,
+    >;
+
+    /// Unsafely keyswitches the affine transform of an LWE ciphertext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorDiscardingAffineTransformationKeyswitchError`]. For safety concerns
+    /// _specific_ to an engine, refer to the implementer safety section.
+    unsafe fn discard_affine_transform_keyswitch_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        inputs: &CiphertextVector,
+        weights: &CleartextVector,
+        bias: &Plaintext,
+        ksk: &KeyswitchKey,
+    );
+}
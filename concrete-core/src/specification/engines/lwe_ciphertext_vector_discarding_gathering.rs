@@ -0,0 +1,83 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::LweCiphertextVectorEntity;
+
+engine_error! {
+    LweCiphertextVectorDiscardingGatheringError for LweCiphertextVectorDiscardingGatheringEngine @
+    LweDimensionMismatch => "The input and output LWE dimensions must be the same.",
+    IndicesCountMismatch => "The number of indices must be the same as the output ciphertext \
+                             count.",
+    IndexOutOfBounds => "The indices must be smaller than the input ciphertext count."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextVectorDiscardingGatheringError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<OutputCiphertextVector, InputCiphertextVector>(
+        output: &OutputCiphertextVector,
+        input: &InputCiphertextVector,
+        indices: &[usize],
+    ) -> Result<(), Self>
+    where
+        InputCiphertextVector: LweCiphertextVectorEntity,
+        OutputCiphertextVector: LweCiphertextVectorEntity,
+    {
+        if output.lwe_dimension() != input.lwe_dimension() {
+            return Err(Self::LweDimensionMismatch);
+        }
+        if indices.len() != output.lwe_ciphertext_count().0 {
+            return Err(Self::IndicesCountMismatch);
+        }
+        if indices
+            .iter()
+            .any(|&index| index >= input.lwe_ciphertext_count().0)
+        {
+            return Err(Self::IndexOutOfBounds);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines gathering (discarding) LWE ciphertext vectors.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext vector
+/// with the ciphertexts of the `input` LWE ciphertext vector at the cleartext `indices`: the
+/// `i`-th ciphertext of `output` is a copy of the `indices[i]`-th ciphertext of `input`. The same
+/// index may appear several times.
+///
+/// # Formal Definition
+///
+/// Given an input vector of LWE ciphertexts $\left(\mathsf{ct}\_0, \cdots,
+/// \mathsf{ct}\_{n-1}\right)$ and indices $\left(j\_0, \cdots, j\_{m-1}\right)$ in
+/// $\left\\{0, \cdots, n-1\right\\}$, the output vector is $\left(\mathsf{ct}\_{j\_0}, \cdots,
+/// \mathsf{ct}\_{j\_{m-1}}\right)$. The ciphertexts are copied as is, so the noise of each of them
+/// is left unchanged.
+pub trait LweCiphertextVectorDiscardingGatheringEngine<
+    InputCiphertextVector,
+    OutputCiphertextVector,
+>: AbstractEngine where
+    InputCiphertextVector: LweCiphertextVectorEntity,
+    OutputCiphertextVector: LweCiphertextVectorEntity,
+{
+    /// Gathers the ciphertexts of an LWE ciphertext vector.
+    fn discard_gather_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut OutputCiphertextVector,
+        input: &InputCiphertextVector,
+        indices: &[usize],
+    ) -> Result<(), LweCiphertextVectorDiscardingGatheringError<Self::EngineError>>;
+
+    /// Unsafely gathers the ciphertexts of an LWE ciphertext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorDiscardingGatheringError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_gather_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut OutputCiphertextVector,
+        input: &InputCiphertextVector,
+        indices: &[usize],
+    );
+}
@@ -0,0 +1,88 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::LweCiphertextVectorEntity;
+
+engine_error! {
+    LweCiphertextVectorDiscardingPermutationError for LweCiphertextVectorDiscardingPermutationEngine @
+    LweDimensionMismatch => "The input and output LWE dimensions must be the same.",
+    CiphertextCountMismatch => "The input and output ciphertext count must be the same.",
+    PermutationLengthMismatch => "The permutation length must be the same as the input \
+                                  ciphertext count.",
+    InvalidPermutation => "The permutation must contain every index of the input vector exactly \
+                           once."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextVectorDiscardingPermutationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<OutputCiphertextVector, InputCiphertextVector>(
+        output: &OutputCiphertextVector,
+        input: &InputCiphertextVector,
+        permutation: &[usize],
+    ) -> Result<(), Self>
+    where
+        InputCiphertextVector: LweCiphertextVectorEntity,
+        OutputCiphertextVector: LweCiphertextVectorEntity,
+    {
+        if output.lwe_dimension() != input.lwe_dimension() {
+            return Err(Self::LweDimensionMismatch);
+        }
+        if output.lwe_ciphertext_count() != input.lwe_ciphertext_count() {
+            return Err(Self::CiphertextCountMismatch);
+        }
+        if permutation.len() != input.lwe_ciphertext_count().0 {
+            return Err(Self::PermutationLengthMismatch);
+        }
+        let mut seen = vec![false; permutation.len()];
+        for &index in permutation {
+            if index >= seen.len() || seen[index] {
+                return Err(Self::InvalidPermutation);
+            }
+            seen[index] = true;
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines permuting (discarding) LWE ciphertext vectors.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext vector
+/// with the ciphertexts of the `input` LWE ciphertext vector, reordered by the cleartext
+/// `permutation`: the `i`-th ciphertext of `output` is a copy of the `permutation[i]`-th
+/// ciphertext of `input`.
+///
+/// # Formal Definition
+///
+/// Given an input vector of LWE ciphertexts $\left(\mathsf{ct}\_0, \cdots,
+/// \mathsf{ct}\_{n-1}\right)$ and a permutation $\sigma$ of $\left\\{0, \cdots, n-1\right\\}$, the
+/// output vector is $\left(\mathsf{ct}\_{\sigma(0)}, \cdots, \mathsf{ct}\_{\sigma(n-1)}\right)$.
+/// The ciphertexts are copied as is, so the noise of each of them is left unchanged.
+pub trait LweCiphertextVectorDiscardingPermutationEngine<
+    InputCiphertextVector,
+    OutputCiphertextVector,
+>: AbstractEngine where
+    InputCiphertextVector: LweCiphertextVectorEntity,
+    OutputCiphertextVector: LweCiphertextVectorEntity,
+{
+    /// Permutes an LWE ciphertext vector.
+    fn discard_permute_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut OutputCiphertextVector,
+        input: &InputCiphertextVector,
+        permutation: &[usize],
+    ) -> Result<(), LweCiphertextVectorDiscardingPermutationError<Self::EngineError>>;
+
+    /// Unsafely permutes an LWE ciphertext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorDiscardingPermutationError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_permute_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut OutputCiphertextVector,
+        input: &InputCiphertextVector,
+        permutation: &[usize],
+    );
+}
@@ -0,0 +1,90 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::LweCiphertextVectorEntity;
+
+engine_error! {
+    LweCiphertextVectorDiscardingScatteringError for LweCiphertextVectorDiscardingScatteringEngine @
+    LweDimensionMismatch => "The input and output LWE dimensions must be the same.",
+    IndicesCountMismatch => "The number of indices must be the same as the input ciphertext \
+                             count.",
+    IndexOutOfBounds => "The indices must be smaller than the output ciphertext count.",
+    DuplicateIndex => "The indices must all be different."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextVectorDiscardingScatteringError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<OutputCiphertextVector, InputCiphertextVector>(
+        output: &OutputCiphertextVector,
+        input: &InputCiphertextVector,
+        indices: &[usize],
+    ) -> Result<(), Self>
+    where
+        InputCiphertextVector: LweCiphertextVectorEntity,
+        OutputCiphertextVector: LweCiphertextVectorEntity,
+    {
+        if output.lwe_dimension() != input.lwe_dimension() {
+            return Err(Self::LweDimensionMismatch);
+        }
+        if indices.len() != input.lwe_ciphertext_count().0 {
+            return Err(Self::IndicesCountMismatch);
+        }
+        let mut seen = vec![false; output.lwe_ciphertext_count().0];
+        for &index in indices {
+            if index >= seen.len() {
+                return Err(Self::IndexOutOfBounds);
+            }
+            if seen[index] {
+                return Err(Self::DuplicateIndex);
+            }
+            seen[index] = true;
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines scattering (discarding) LWE ciphertext vectors.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation copies the ciphertexts of the `input`
+/// LWE ciphertext vector into the `output` LWE ciphertext vector at the cleartext `indices`: the
+/// `indices[i]`-th ciphertext of `output` is replaced by a copy of the `i`-th ciphertext of
+/// `input`. The ciphertexts of `output` whose index does not appear in `indices` are left
+/// untouched.
+///
+/// # Formal Definition
+///
+/// Given an input vector of LWE ciphertexts $\left(\mathsf{ct}\_0, \cdots,
+/// \mathsf{ct}\_{m-1}\right)$, an output vector of LWE ciphertexts $\left(\mathsf{ct}'\_0, \cdots,
+/// \mathsf{ct}'\_{n-1}\right)$ and distinct indices $\left(j\_0, \cdots, j\_{m-1}\right)$ in
+/// $\left\\{0, \cdots, n-1\right\\}$, the operation sets $\mathsf{ct}'\_{j\_i} = \mathsf{ct}\_i$
+/// for every $0 \le i < m$. The ciphertexts are copied as is, so the noise of each of them is left
+/// unchanged.
+pub trait LweCiphertextVectorDiscardingScatteringEngine<
+    InputCiphertextVector,
+    OutputCiphertextVector,
+>: AbstractEngine where
+    InputCiphertextVector: LweCiphertextVectorEntity,
+    OutputCiphertextVector: LweCiphertextVectorEntity,
+{
+    /// Scatters the ciphertexts of an LWE ciphertext vector.
+    fn discard_scatter_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut OutputCiphertextVector,
+        input: &InputCiphertextVector,
+        indices: &[usize],
+    ) -> Result<(), LweCiphertextVectorDiscardingScatteringError<Self::EngineError>>;
+
+    /// Unsafely scatters the ciphertexts of an LWE ciphertext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorDiscardingScatteringError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_scatter_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut OutputCiphertextVector,
+        input: &InputCiphertextVector,
+        indices: &[usize],
+    );
+}
@@ -5,7 +5,8 @@ use crate::specification::entities::LweCiphertextVectorEntity;
 engine_error! {
     LweCiphertextVectorFusingAdditionError for LweCiphertextVectorFusingAdditionEngine @
     LweDimensionMismatch => "The input and output LWE dimension must be the same.",
-    CiphertextCountMismatch => "The input and output vectors length must be the same."
+    CiphertextCountMismatch => "The input and output vectors length must be the same.",
+    CiphertextModulusMismatch => "The input and output ciphertext moduli must be the same."
 }
 
 impl<EngineError: std::error::Error> LweCiphertextVectorFusingAdditionError<EngineError> {
@@ -25,6 +26,9 @@ impl<EngineError: std::error::Error> LweCiphertextVectorFusingAdditionError<Engi
         if input.lwe_ciphertext_count() != output.lwe_ciphertext_count() {
             return Err(Self::CiphertextCountMismatch);
         }
+        if output.ciphertext_modulus() != input.ciphertext_modulus() {
+            return Err(Self::CiphertextModulusMismatch);
+        }
         Ok(())
     }
 }
@@ -0,0 +1,123 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    GlweAutomorphismKeysEntity, GlweCiphertextEntity, LweCiphertextVectorEntity,
+};
+
+engine_error! {
+    LweCiphertextVectorGlweCiphertextDiscardingTreePackingError for LweCiphertextVectorGlweCiphertextDiscardingTreePackingEngine @
+    InputLweDimensionMismatch => "The input ciphertext vector LWE dimension must be the product of \
+                                  the GLWE dimension and polynomial size of the automorphism keys.",
+    OutputGlweDimensionMismatch => "The output ciphertext and automorphism keys GLWE dimensions \
+                                    must be the same.",
+    OutputPolynomialSizeMismatch => "The output ciphertext and automorphism keys polynomial sizes \
+                                     must be the same.",
+    CiphertextCountMismatch => "The input ciphertext count is bigger than the output polynomial \
+                                size.",
+    NonPowerOfTwoCiphertextCount => "The input ciphertext count must be a power of two."
+}
+
+impl<EngineError: std::error::Error>
+    LweCiphertextVectorGlweCiphertextDiscardingTreePackingError<EngineError>
+{
+    /// Validates the inputs
+    pub fn perform_generic_checks<AutomorphismKeys, InputCiphertextVector, OutputCiphertext>(
+        output: &OutputCiphertext,
+        input: &InputCiphertextVector,
+        automorphism_keys: &AutomorphismKeys,
+    ) -> Result<(), Self>
+    where
+        AutomorphismKeys: GlweAutomorphismKeysEntity,
+        InputCiphertextVector: LweCiphertextVectorEntity,
+        OutputCiphertext: GlweCiphertextEntity,
+    {
+        if input.lwe_dimension().0
+            != automorphism_keys.glwe_dimension().0 * automorphism_keys.polynomial_size().0
+        {
+            return Err(Self::InputLweDimensionMismatch);
+        }
+
+        if output.glwe_dimension() != automorphism_keys.glwe_dimension() {
+            return Err(Self::OutputGlweDimensionMismatch);
+        }
+
+        if output.polynomial_size() != automorphism_keys.polynomial_size() {
+            return Err(Self::OutputPolynomialSizeMismatch);
+        }
+
+        if input.lwe_ciphertext_count().0 > output.polynomial_size().0 {
+            return Err(Self::CiphertextCountMismatch);
+        }
+
+        if !input.lwe_ciphertext_count().0.is_power_of_two() {
+            return Err(Self::NonPowerOfTwoCiphertextCount);
+        }
+
+        Ok(())
+    }
+}
+
+/// A trait for engines packing (discarding) LWE ciphertext vectors into a GLWE ciphertext, by
+/// recursively merging GLWE ciphertexts with automorphisms.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` GLWE ciphertext
+/// with the tree packing of the `input` LWE ciphertext vector, using the `automorphism_keys`.
+/// The input ciphertexts must be encrypted under the LWE key obtained by flattening the GLWE key
+/// of the automorphism keys, and their number $n$ must be a power of two. The message of the
+/// $i$-th input ciphertext is placed on the coefficient of degree $i \cdot N / n$ of the output,
+/// and the other coefficients encrypt zero.
+///
+/// Compared to a
+/// [`packing keyswitch`](`super::LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchEngine`),
+/// the noise of the output grows with $\log\_2(N)$ keyswitches instead of $n$, which makes it
+/// better suited to large batches of ciphertexts.
+///
+/// # Formal Definition
+///
+/// ## Tree Packing
+///
+/// Each input ciphertext $\mathsf{ct}\_i$ is first seen as a
+/// [`GLWE ciphertext`](`crate::specification::entities::GlweCiphertextEntity`) whose constant
+/// coefficient encrypts its message $m\_i$, and whose coefficients are divided by $N$. For
+/// $2^l$ ciphertexts, the packing is defined recursively from the packings $\mathsf{ct}\_{even}$
+/// and $\mathsf{ct}\_{odd}$ of the ciphertexts of even and odd indices:
+///
+/// $$\mathsf{ct} = \left(\mathsf{ct}\_{even} + X^{N/2^l} \cdot \mathsf{ct}\_{odd}\right) +
+/// \tau\_{2^l+1}\left(\mathsf{ct}\_{even} - X^{N/2^l} \cdot \mathsf{ct}\_{odd}\right)$$
+///
+/// where $\tau\_t$ is the automorphism $X \mapsto X^t$ followed by a keyswitch with the
+/// corresponding [`automorphism key`](`crate::specification::entities::GlweAutomorphismKeysEntity`).
+/// The coefficients which do not hold a message are then cleared by computing
+/// $\mathsf{ct} = \mathsf{ct} + \tau\_{2^l+1}(\mathsf{ct})$ for the remaining $l \leq \log\_2(N)$.
+pub trait LweCiphertextVectorGlweCiphertextDiscardingTreePackingEngine<
+    AutomorphismKeys,
+    InputCiphertextVector,
+    OutputCiphertext,
+>: AbstractEngine where
+    AutomorphismKeys: GlweAutomorphismKeysEntity,
+    InputCiphertextVector: LweCiphertextVectorEntity,
+    OutputCiphertext: GlweCiphertextEntity,
+{
+    /// Tree packs an LWE ciphertext vector.
+    fn discard_tree_pack_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertextVector,
+        automorphism_keys: &AutomorphismKeys,
+    ) -> Result<(), LweCiphertextVectorGlweCiphertextDiscardingTreePackingError<Self::EngineError>>;
+
+    /// Unsafely tree packs an LWE ciphertext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorGlweCiphertextDiscardingTreePackingError`]. For safety concerns
+    /// _specific_ to an engine, refer to the implementer safety section.
+    unsafe fn discard_tree_pack_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertextVector,
+        automorphism_keys: &AutomorphismKeys,
+    );
+}
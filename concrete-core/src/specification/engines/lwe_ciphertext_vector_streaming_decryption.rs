@@ -0,0 +1,85 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    LweCiphertextVectorEntity, LweSecretKeyEntity, PlaintextVectorEntity,
+};
+
+engine_error! {
+    LweCiphertextVectorStreamingDecryptionError for LweCiphertextVectorStreamingDecryptionEngine @
+    LweDimensionMismatch => "The input and secret key LWE dimension must be the same."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextVectorStreamingDecryptionError<EngineError> {
+    /// Validates the inputs
+    ///
+    /// This check applies to a single chunk of the stream.
+    pub fn perform_generic_checks<SecretKey, CiphertextVector>(
+        key: &SecretKey,
+        input: &CiphertextVector,
+    ) -> Result<(), Self>
+    where
+        SecretKey: LweSecretKeyEntity,
+        CiphertextVector: LweCiphertextVectorEntity,
+    {
+        if key.lwe_dimension() != input.lwe_dimension() {
+            return Err(Self::LweDimensionMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines decrypting streams of LWE ciphertext vectors.
+///
+/// # Semantics
+///
+/// This operation consumes the `input` iterator of LWE ciphertext vectors one chunk at a time.
+/// Each chunk is decrypted under the `key` secret key, as with a
+/// [`LweCiphertextVectorDecryptionEngine`](`super::LweCiphertextVectorDecryptionEngine`), and the
+/// resulting plaintext vector is handed to the `output` callback before the next chunk is pulled
+/// from the iterator.
+///
+/// At most one chunk of ciphertexts and one chunk of plaintexts are alive at any time, so the
+/// peak memory used by the operation is bounded by the size of the chunks chosen by the caller,
+/// independently of the total size of the data. Chunks are processed in the order of the
+/// iterator.
+///
+/// Since the chunks are only known when they are pulled from the iterator, the checks are
+/// performed chunk by chunk: when a chunk is invalid, the error is returned and the stream is
+/// stopped, but the chunks preceding it have already been handed to the `output` callback.
+///
+/// # Formal Definition
+///
+/// cf [`here`](`crate::specification::engines::LweCiphertextDecryptionEngine`)
+pub trait LweCiphertextVectorStreamingDecryptionEngine<SecretKey, CiphertextVector, PlaintextVector>:
+    AbstractEngine
+where
+    SecretKey: LweSecretKeyEntity,
+    CiphertextVector: LweCiphertextVectorEntity,
+    PlaintextVector: PlaintextVectorEntity,
+{
+    /// Decrypts a stream of LWE ciphertext vectors into plaintext vectors.
+    fn stream_decrypt_lwe_ciphertext_vector<Input, Output>(
+        &mut self,
+        key: &SecretKey,
+        input: Input,
+        output: Output,
+    ) -> Result<(), LweCiphertextVectorStreamingDecryptionError<Self::EngineError>>
+    where
+        Input: IntoIterator<Item = CiphertextVector>,
+        Output: FnMut(PlaintextVector);
+
+    /// Unsafely decrypts a stream of LWE ciphertext vectors into plaintext vectors.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorStreamingDecryptionError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn stream_decrypt_lwe_ciphertext_vector_unchecked<Input, Output>(
+        &mut self,
+        key: &SecretKey,
+        input: Input,
+        output: Output,
+    ) where
+        Input: IntoIterator<Item = CiphertextVector>,
+        Output: FnMut(PlaintextVector);
+}
@@ -0,0 +1,64 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    LweCiphertextVectorEntity, LweSecretKeyEntity, PlaintextVectorEntity,
+};
+use concrete_commons::dispersion::Variance;
+
+engine_error! {
+    LweCiphertextVectorStreamingEncryptionError for LweCiphertextVectorStreamingEncryptionEngine @
+}
+
+/// A trait for engines encrypting streams of LWE ciphertext vectors.
+///
+/// # Semantics
+///
+/// This operation consumes the `input` iterator of plaintext vectors one chunk at a time. Each
+/// chunk is encrypted under the `key` secret key, as with a
+/// [`LweCiphertextVectorEncryptionEngine`](`super::LweCiphertextVectorEncryptionEngine`), and the
+/// resulting LWE ciphertext vector is handed to the `output` callback before the next chunk is
+/// pulled from the iterator.
+///
+/// At most one chunk of plaintexts and one chunk of ciphertexts are alive at any time, so the
+/// peak memory used by the operation is bounded by the size of the chunks chosen by the caller,
+/// independently of the total size of the data. Chunks are processed in the order of the
+/// iterator.
+///
+/// # Formal Definition
+///
+/// cf [`here`](`crate::specification::engines::LweCiphertextEncryptionEngine`)
+pub trait LweCiphertextVectorStreamingEncryptionEngine<SecretKey, PlaintextVector, CiphertextVector>:
+    AbstractEngine
+where
+    SecretKey: LweSecretKeyEntity,
+    PlaintextVector: PlaintextVectorEntity,
+    CiphertextVector: LweCiphertextVectorEntity,
+{
+    /// Encrypts a stream of plaintext vectors into LWE ciphertext vectors.
+    fn stream_encrypt_lwe_ciphertext_vector<Input, Output>(
+        &mut self,
+        key: &SecretKey,
+        input: Input,
+        noise: Variance,
+        output: Output,
+    ) -> Result<(), LweCiphertextVectorStreamingEncryptionError<Self::EngineError>>
+    where
+        Input: IntoIterator<Item = PlaintextVector>,
+        Output: FnMut(CiphertextVector);
+
+    /// Unsafely encrypts a stream of plaintext vectors into LWE ciphertext vectors.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorStreamingEncryptionError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn stream_encrypt_lwe_ciphertext_vector_unchecked<Input, Output>(
+        &mut self,
+        key: &SecretKey,
+        input: Input,
+        noise: Variance,
+        output: Output,
+    ) where
+        Input: IntoIterator<Item = PlaintextVector>,
+        Output: FnMut(CiphertextVector);
+}
@@ -0,0 +1,101 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+
+use crate::specification::entities::{LwePartialKeyswitchKeyEntity, LweSecretKeyEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+
+engine_error! {
+    LwePartialKeyswitchKeyGenerationError for LwePartialKeyswitchKeyGenerationEngine @
+    NullDecompositionBaseLog => "The key decomposition base log must be greater than zero.",
+    NullDecompositionLevelCount => "The key decomposition level count must be greater than zero.",
+    DecompositionTooLarge => "The decomposition precision (base log * level count) must not exceed \
+                              the precision of the ciphertext.",
+    NullSwitchedLweDimension => "The number of switched coefficients must be greater than zero.",
+    SwitchedLweDimensionTooLarge => "The number of switched coefficients must not exceed the \
+                                     input key LWE dimension."
+}
+
+impl<EngineError: std::error::Error> LwePartialKeyswitchKeyGenerationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<InputSecretKey>(
+        input_key: &InputSecretKey,
+        switched_lwe_dimension: LweDimension,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        integer_precision: usize,
+    ) -> Result<(), Self>
+    where
+        InputSecretKey: LweSecretKeyEntity,
+    {
+        if decomposition_base_log.0 == 0 {
+            return Err(Self::NullDecompositionBaseLog);
+        }
+
+        if decomposition_level_count.0 == 0 {
+            return Err(Self::NullDecompositionLevelCount);
+        }
+
+        if decomposition_level_count.0 * decomposition_base_log.0 > integer_precision {
+            return Err(Self::DecompositionTooLarge);
+        }
+
+        if switched_lwe_dimension.0 == 0 {
+            return Err(Self::NullSwitchedLweDimension);
+        }
+
+        if switched_lwe_dimension.0 > input_key.lwe_dimension().0 {
+            return Err(Self::SwitchedLweDimensionTooLarge);
+        }
+
+        Ok(())
+    }
+}
+
+/// A trait for engines generating new LWE partial keyswitch keys.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a new LWE partial keyswitch key
+/// allowing to switch the first `switched_lwe_dimension` coefficients of the `input_key` LWE
+/// secret key to the `replacement_key` LWE secret key.
+///
+/// # Formal Definition
+///
+/// cf [`here`](`crate::specification::entities::LwePartialKeyswitchKeyEntity`)
+pub trait LwePartialKeyswitchKeyGenerationEngine<
+    InputSecretKey,
+    ReplacementSecretKey,
+    PartialKeyswitchKey,
+>: AbstractEngine where
+    InputSecretKey: LweSecretKeyEntity,
+    ReplacementSecretKey: LweSecretKeyEntity,
+    PartialKeyswitchKey: LwePartialKeyswitchKeyEntity,
+{
+    /// Generates a new LWE partial keyswitch key.
+    fn generate_new_lwe_partial_keyswitch_key(
+        &mut self,
+        input_key: &InputSecretKey,
+        replacement_key: &ReplacementSecretKey,
+        switched_lwe_dimension: LweDimension,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<PartialKeyswitchKey, LwePartialKeyswitchKeyGenerationError<Self::EngineError>>;
+
+    /// Unsafely generates a new LWE partial keyswitch key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LwePartialKeyswitchKeyGenerationError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn generate_new_lwe_partial_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &InputSecretKey,
+        replacement_key: &ReplacementSecretKey,
+        switched_lwe_dimension: LweDimension,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> PartialKeyswitchKey;
+}
@@ -0,0 +1,50 @@
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::LweSecretKeyEntity;
+use concrete_commons::parameters::LweDimension;
+
+engine_error! {
+    LweSecretKeyGaussianGenerationError for LweSecretKeyGaussianGenerationEngine @
+    NullLweDimension => "The LWE dimension must be greater than zero."
+}
+
+impl<EngineError: std::error::Error> LweSecretKeyGaussianGenerationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks(lwe_dimension: LweDimension) -> Result<(), Self> {
+        if lwe_dimension.0 == 0 {
+            return Err(Self::NullLweDimension);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines generating new gaussian LWE secret keys.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a new LWE secret key, whose
+/// coefficients are sampled from a centered discrete gaussian distribution.
+///
+/// # Formal Definition
+///
+/// cf [`here`](`crate::specification::entities::LweSecretKeyEntity`)
+pub trait LweSecretKeyGaussianGenerationEngine<SecretKey>: AbstractEngine
+where
+    SecretKey: LweSecretKeyEntity,
+{
+    /// Generates a new gaussian LWE secret key.
+    fn generate_new_lwe_secret_key_gaussian(
+        &mut self,
+        lwe_dimension: LweDimension,
+    ) -> Result<SecretKey, LweSecretKeyGaussianGenerationError<Self::EngineError>>;
+
+    /// Unsafely generates a new gaussian LWE secret key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweSecretKeyGaussianGenerationError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn generate_new_lwe_secret_key_gaussian_unchecked(
+        &mut self,
+        lwe_dimension: LweDimension,
+    ) -> SecretKey;
+}
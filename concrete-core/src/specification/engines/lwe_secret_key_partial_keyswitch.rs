@@ -0,0 +1,82 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{LwePartialKeyswitchKeyEntity, LweSecretKeyEntity};
+
+engine_error! {
+    LweSecretKeyPartialKeyswitchError for LweSecretKeyPartialKeyswitchEngine @
+    InputLweDimensionMismatch => "The input key LWE dimension and partial keyswitch key input LWE \
+                                  dimensions must be the same.",
+    ReplacementLweDimensionMismatch => "The replacement key LWE dimension and partial keyswitch \
+                                        key replacement LWE dimensions must be the same."
+}
+
+impl<EngineError: std::error::Error> LweSecretKeyPartialKeyswitchError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<InputSecretKey, ReplacementSecretKey, PartialKeyswitchKey>(
+        input_key: &InputSecretKey,
+        replacement_key: &ReplacementSecretKey,
+        ksk: &PartialKeyswitchKey,
+    ) -> Result<(), Self>
+    where
+        InputSecretKey: LweSecretKeyEntity,
+        ReplacementSecretKey: LweSecretKeyEntity,
+        PartialKeyswitchKey: LwePartialKeyswitchKeyEntity,
+    {
+        if input_key.lwe_dimension() != ksk.input_lwe_dimension() {
+            return Err(Self::InputLweDimensionMismatch);
+        }
+        if replacement_key.lwe_dimension() != ksk.replacement_lwe_dimension() {
+            return Err(Self::ReplacementLweDimensionMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines creating the secret key of partially keyswitched LWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates the LWE secret key of the
+/// ciphertexts obtained by partially keyswitching ciphertexts encrypted under the `input_key` LWE
+/// secret key with the `ksk` LWE partial keyswitch key, with a
+/// [`LweCiphertextDiscardingPartialKeyswitchEngine`](`super::LweCiphertextDiscardingPartialKeyswitchEngine`).
+///
+/// # Formal Definition
+///
+/// The first $d$ coefficients of the input key $\vec{s}\_{\mathsf{in}} = \left( s\_0, \cdots,
+/// s\_{n\_{\mathsf{in}}-1} \right)$ are replaced by the replacement key
+/// $\vec{s}\_{\mathsf{rep}}$:
+/// $$\vec{s}\_{\mathsf{out}} = \left( \vec{s}\_{\mathsf{rep}}, s\_d, \cdots,
+/// s\_{n\_{\mathsf{in}}-1} \right)$$
+pub trait LweSecretKeyPartialKeyswitchEngine<
+    InputSecretKey,
+    ReplacementSecretKey,
+    PartialKeyswitchKey,
+    OutputSecretKey,
+>: AbstractEngine where
+    InputSecretKey: LweSecretKeyEntity,
+    ReplacementSecretKey: LweSecretKeyEntity,
+    PartialKeyswitchKey: LwePartialKeyswitchKeyEntity,
+    OutputSecretKey: LweSecretKeyEntity,
+{
+    /// Creates the secret key of the partially keyswitched ciphertexts.
+    fn partially_keyswitch_lwe_secret_key(
+        &mut self,
+        input_key: &InputSecretKey,
+        replacement_key: &ReplacementSecretKey,
+        ksk: &PartialKeyswitchKey,
+    ) -> Result<OutputSecretKey, LweSecretKeyPartialKeyswitchError<Self::EngineError>>;
+
+    /// Unsafely creates the secret key of the partially keyswitched ciphertexts.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweSecretKeyPartialKeyswitchError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn partially_keyswitch_lwe_secret_key_unchecked(
+        &mut self,
+        input_key: &InputSecretKey,
+        replacement_key: &ReplacementSecretKey,
+        ksk: &PartialKeyswitchKey,
+    ) -> OutputSecretKey;
+}
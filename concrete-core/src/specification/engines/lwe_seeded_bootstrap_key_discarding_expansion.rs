@@ -0,0 +1,75 @@
+use super::engine_error;
+use crate::commons::math::random::ByteRandomGenerator;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{LweBootstrapKeyEntity, LweSeededBootstrapKeyEntity};
+
+engine_error! {
+    LweSeededBootstrapKeyDiscardingExpansionError for LweSeededBootstrapKeyDiscardingExpansionEngine @
+    GlweDimensionMismatch => "The input and output keys must have the same GLWE dimension.",
+    PolynomialSizeMismatch => "The input and output keys must have the same polynomial size.",
+    InputLweDimensionMismatch => "The input and output keys must have the same input LWE dimension.",
+    DecompositionBaseLogMismatch => "The input and output keys must have the same decomposition base log.",
+    DecompositionLevelCountMismatch => "The input and output keys must have the same decomposition level count."
+}
+
+impl<EngineError: std::error::Error> LweSeededBootstrapKeyDiscardingExpansionError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<Input, Output>(output: &Output, input: &Input) -> Result<(), Self>
+    where
+        Input: LweSeededBootstrapKeyEntity,
+        Output: LweBootstrapKeyEntity,
+    {
+        if input.glwe_dimension() != output.glwe_dimension() {
+            return Err(Self::GlweDimensionMismatch);
+        }
+
+        if input.polynomial_size() != output.polynomial_size() {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+
+        if input.input_lwe_dimension() != output.input_lwe_dimension() {
+            return Err(Self::InputLweDimensionMismatch);
+        }
+
+        if input.decomposition_base_log() != output.decomposition_base_log() {
+            return Err(Self::DecompositionBaseLogMismatch);
+        }
+
+        if input.decomposition_level_count() != output.decomposition_level_count() {
+            return Err(Self::DecompositionLevelCountMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines expanding (discarding) LWE seeded bootstrap keys.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE bootstrap key with
+/// the expansion of the `input` LWE seeded bootstrap key, using the `Generator` CSPRNG (see
+/// [the expansion of seeded entities](super#expansion-of-seeded-entities)).
+pub trait LweSeededBootstrapKeyDiscardingExpansionEngine<Input, Output>: AbstractEngine
+where
+    Input: LweSeededBootstrapKeyEntity,
+    Output: LweBootstrapKeyEntity,
+{
+    /// Expands an LWE seeded bootstrap key into an LWE bootstrap key.
+    fn discard_expand_lwe_seeded_bootstrap_key<Generator: ByteRandomGenerator>(
+        &mut self,
+        output: &mut Output,
+        input: &Input,
+    ) -> Result<(), LweSeededBootstrapKeyDiscardingExpansionError<Self::EngineError>>;
+
+    /// Unsafely expands an LWE seeded bootstrap key into an LWE bootstrap key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweSeededBootstrapKeyDiscardingExpansionError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_expand_lwe_seeded_bootstrap_key_unchecked<Generator: ByteRandomGenerator>(
+        &mut self,
+        output: &mut Output,
+        input: &Input,
+    );
+}
@@ -0,0 +1,79 @@
+use super::engine_error;
+
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    LweSecretKeyEntity, LweSeededCiphertextEntity, PlaintextVectorEntity,
+};
+use concrete_commons::dispersion::Variance;
+
+engine_error! {
+    LweSeededCiphertextBatchEncryptionError for LweSeededCiphertextBatchEncryptionEngine @
+}
+
+/// A trait for engines encrypting batches of seeded LWE ciphertexts sharing a seed.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a vector of seeded LWE ciphertexts
+/// containing the element-wise encryption of the `input` plaintext vector, under the `key` secret
+/// key. All the ciphertexts share the same compression seed, and their masks are generated from
+/// consecutive parts of the CSPRNG stream, whose start is given by their
+/// [`generator_byte_index`](`LweSeededCiphertextEntity::generator_byte_index`).
+///
+/// Contrarily to a
+/// [`seeded LWE ciphertext vector`](`crate::specification::entities::LweSeededCiphertextVectorEntity`),
+/// each ciphertext of the batch is a standalone entity, which can be sent or expanded on its own,
+/// while a single seed is stored for the whole batch.
+///
+/// # Formal Definition
+///
+/// ## Seeded LWE batch encryption
+/// ###### inputs:
+/// - $\vec{\mathsf{pt}}\in\mathbb{Z}\_q^t$: a plaintext vector
+/// - $\vec{s}\in\mathbb{Z}\_q^n$: a secret key
+/// - $\mathsf{seed} \in\mathcal{S}$: a public seed
+/// - $G$: a CSPRNG working with seeds from $\mathcal{S}$
+/// - $\mathcal{D}\_{\sigma^2,\mu}$: a normal distribution of variance $\sigma^2$ and mean $\mu$
+///
+/// ###### outputs:
+/// - $\left(\tilde{\mathsf{ct}}\_i\right)\_{i < t} = \left( \mathsf{seed}, i \cdot B, b\_i
+///   \right)\_{i < t}$: $t$ seeded LWE ciphertexts, where $B$ is the number of bytes needed to
+///   generate a mask
+///
+/// ###### algorithm:
+/// 1. Seed $G$ with the seed $\mathsf{seed}\in\mathcal{S}$
+/// 2. for each $\mathsf{pt\_i}$ in $\vec{\mathsf{pt}}$
+///     - uniformly sample $n$ integers in $\mathbb{Z}\_q$ from $G$ and store them in
+///       $\vec{a}\in\mathbb{Z}^n\_q$
+///     - sample an integer error term $e \hookleftarrow\mathcal{D}\_{\sigma^2,\mu}$
+///     - compute $b\_i = \left\langle \vec{a} , \vec{s} \right\rangle + \mathsf{pt\_i} + e
+///       \in\mathbb{Z}\_q$
+/// 3. output $\left( \mathsf{seed}, i \cdot B, b\_i \right)\_{i < t}$
+pub trait LweSeededCiphertextBatchEncryptionEngine<SecretKey, PlaintextVector, Ciphertext>:
+    AbstractEngine
+where
+    SecretKey: LweSecretKeyEntity,
+    PlaintextVector: PlaintextVectorEntity,
+    Ciphertext: LweSeededCiphertextEntity,
+{
+    /// Encrypts a batch of seeded LWE ciphertexts.
+    fn encrypt_lwe_seeded_ciphertext_batch(
+        &mut self,
+        key: &SecretKey,
+        input: &PlaintextVector,
+        noise: Variance,
+    ) -> Result<Vec<Ciphertext>, LweSeededCiphertextBatchEncryptionError<Self::EngineError>>;
+
+    /// Unsafely encrypts a batch of seeded LWE ciphertexts.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweSeededCiphertextBatchEncryptionError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn encrypt_lwe_seeded_ciphertext_batch_unchecked(
+        &mut self,
+        key: &SecretKey,
+        input: &PlaintextVector,
+        noise: Variance,
+    ) -> Vec<Ciphertext>;
+}
@@ -0,0 +1,55 @@
+use super::engine_error;
+use crate::commons::math::random::ByteRandomGenerator;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{LweCiphertextEntity, LweSeededCiphertextEntity};
+
+engine_error! {
+    LweSeededCiphertextDiscardingExpansionError for LweSeededCiphertextDiscardingExpansionEngine @
+    LweDimensionMismatch => "The input and output LWE dimension must be the same."
+}
+
+impl<EngineError: std::error::Error> LweSeededCiphertextDiscardingExpansionError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<Input, Output>(output: &Output, input: &Input) -> Result<(), Self>
+    where
+        Input: LweSeededCiphertextEntity,
+        Output: LweCiphertextEntity,
+    {
+        if input.lwe_dimension() != output.lwe_dimension() {
+            return Err(Self::LweDimensionMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines expanding (discarding) LWE seeded ciphertexts.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext with
+/// the expansion of the `input` LWE seeded ciphertext, using the `Generator` CSPRNG (see
+/// [the expansion of seeded entities](super#expansion-of-seeded-entities)).
+pub trait LweSeededCiphertextDiscardingExpansionEngine<Input, Output>: AbstractEngine
+where
+    Input: LweSeededCiphertextEntity,
+    Output: LweCiphertextEntity,
+{
+    /// Expands an LWE seeded ciphertext into an LWE ciphertext.
+    fn discard_expand_lwe_seeded_ciphertext<Generator: ByteRandomGenerator>(
+        &mut self,
+        output: &mut Output,
+        input: &Input,
+    ) -> Result<(), LweSeededCiphertextDiscardingExpansionError<Self::EngineError>>;
+
+    /// Unsafely expands an LWE seeded ciphertext into an LWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweSeededCiphertextDiscardingExpansionError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_expand_lwe_seeded_ciphertext_unchecked<Generator: ByteRandomGenerator>(
+        &mut self,
+        output: &mut Output,
+        input: &Input,
+    );
+}
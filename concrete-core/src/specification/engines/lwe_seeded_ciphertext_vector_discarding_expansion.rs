@@ -0,0 +1,65 @@
+use super::engine_error;
+use crate::commons::math::random::ByteRandomGenerator;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{LweCiphertextVectorEntity, LweSeededCiphertextVectorEntity};
+
+engine_error! {
+    LweSeededCiphertextVectorDiscardingExpansionError for LweSeededCiphertextVectorDiscardingExpansionEngine @
+    LweDimensionMismatch => "The input and output LWE dimension must be the same.",
+    CiphertextCountMismatch => "The input and output ciphertext count must be the same."
+}
+
+impl<EngineError: std::error::Error>
+    LweSeededCiphertextVectorDiscardingExpansionError<EngineError>
+{
+    /// Validates the inputs
+    pub fn perform_generic_checks<Input, Output>(output: &Output, input: &Input) -> Result<(), Self>
+    where
+        Input: LweSeededCiphertextVectorEntity,
+        Output: LweCiphertextVectorEntity,
+    {
+        if input.lwe_dimension() != output.lwe_dimension() {
+            return Err(Self::LweDimensionMismatch);
+        }
+
+        if input.lwe_ciphertext_count() != output.lwe_ciphertext_count() {
+            return Err(Self::CiphertextCountMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines expanding (discarding) LWE seeded ciphertext vectors.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext vector
+/// with the expansion of the `input` LWE seeded ciphertext vector, using the `Generator` CSPRNG
+/// (see [the expansion of seeded entities](super#expansion-of-seeded-entities)).
+pub trait LweSeededCiphertextVectorDiscardingExpansionEngine<Input, Output>:
+    AbstractEngine
+where
+    Input: LweSeededCiphertextVectorEntity,
+    Output: LweCiphertextVectorEntity,
+{
+    /// Expands an LWE seeded ciphertext vector into an LWE ciphertext vector.
+    fn discard_expand_lwe_seeded_ciphertext_vector<Generator: ByteRandomGenerator>(
+        &mut self,
+        output: &mut Output,
+        input: &Input,
+    ) -> Result<(), LweSeededCiphertextVectorDiscardingExpansionError<Self::EngineError>>;
+
+    /// Unsafely expands an LWE seeded ciphertext vector into an LWE ciphertext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweSeededCiphertextVectorDiscardingExpansionError`]. For safety concerns _specific_ to
+    /// an engine, refer to the implementer safety section.
+    unsafe fn discard_expand_lwe_seeded_ciphertext_vector_unchecked<
+        Generator: ByteRandomGenerator,
+    >(
+        &mut self,
+        output: &mut Output,
+        input: &Input,
+    );
+}
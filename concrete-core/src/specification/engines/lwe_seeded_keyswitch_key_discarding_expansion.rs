@@ -0,0 +1,70 @@
+use super::engine_error;
+use crate::commons::math::random::ByteRandomGenerator;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{LweKeyswitchKeyEntity, LweSeededKeyswitchKeyEntity};
+
+engine_error! {
+    LweSeededKeyswitchKeyDiscardingExpansionError for LweSeededKeyswitchKeyDiscardingExpansionEngine @
+    InputLweDimensionMismatch => "The input and output keys must have the same input LWE dimension.",
+    OutputLweDimensionMismatch => "The input and output keys must have the same output LWE dimension.",
+    DecompositionBaseLogMismatch => "The input and output keys must have the same decomposition base log.",
+    DecompositionLevelCountMismatch => "The input and output keys must have the same decomposition level count."
+}
+
+impl<EngineError: std::error::Error> LweSeededKeyswitchKeyDiscardingExpansionError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<Input, Output>(output: &Output, input: &Input) -> Result<(), Self>
+    where
+        Input: LweSeededKeyswitchKeyEntity,
+        Output: LweKeyswitchKeyEntity,
+    {
+        if input.input_lwe_dimension() != output.input_lwe_dimension() {
+            return Err(Self::InputLweDimensionMismatch);
+        }
+
+        if input.output_lwe_dimension() != output.output_lwe_dimension() {
+            return Err(Self::OutputLweDimensionMismatch);
+        }
+
+        if input.decomposition_base_log() != output.decomposition_base_log() {
+            return Err(Self::DecompositionBaseLogMismatch);
+        }
+
+        if input.decomposition_level_count() != output.decomposition_level_count() {
+            return Err(Self::DecompositionLevelCountMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines expanding (discarding) LWE seeded keyswitch keys.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE keyswitch key with
+/// the expansion of the `input` LWE seeded keyswitch key, using the `Generator` CSPRNG (see
+/// [the expansion of seeded entities](super#expansion-of-seeded-entities)).
+pub trait LweSeededKeyswitchKeyDiscardingExpansionEngine<Input, Output>: AbstractEngine
+where
+    Input: LweSeededKeyswitchKeyEntity,
+    Output: LweKeyswitchKeyEntity,
+{
+    /// Expands an LWE seeded keyswitch key into an LWE keyswitch key.
+    fn discard_expand_lwe_seeded_keyswitch_key<Generator: ByteRandomGenerator>(
+        &mut self,
+        output: &mut Output,
+        input: &Input,
+    ) -> Result<(), LweSeededKeyswitchKeyDiscardingExpansionError<Self::EngineError>>;
+
+    /// Unsafely expands an LWE seeded keyswitch key into an LWE keyswitch key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweSeededKeyswitchKeyDiscardingExpansionError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_expand_lwe_seeded_keyswitch_key_unchecked<Generator: ByteRandomGenerator>(
+        &mut self,
+        output: &mut Output,
+        input: &Input,
+    );
+}
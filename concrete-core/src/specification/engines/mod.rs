@@ -61,6 +61,30 @@
 //! + __Fusing operations__ take both their inputs and outputs as arguments
 //! (example: [`LweCiphertextFusingAdditionEngine`]). In those operations though, the data
 //! originally contained in the output is used for computation.
+//!
+//! # Ordering of vector operations
+//!
+//! Operations on vector entities (`*VectorEngine` traits) are element-wise in index order: the
+//! `i`-th element of the output is computed from the `i`-th element(s) of the input(s), and an
+//! implementation may never reorder the elements of a vector, even when it processes them in
+//! parallel. This makes the results of a vector operation deterministic, and allows to pair the
+//! outputs of a vector operation with its inputs by index. Every vector fixture of the
+//! `concrete-core-fixture` crate checks this guarantee, by comparing the output elements to the
+//! inputs with the same index.
+//!
+//! When a vector must be reordered, for instance before a batch of bootstraps scheduled in a
+//! different order, the permutation must be made explicit, with an
+//! [`LweCiphertextVectorDiscardingPermutationEngine`].
+//!
+//! # Expansion of seeded entities
+//!
+//! The seeded entities only store the seed from which their masks were generated. The
+//! `*SeededDiscardingExpansionEngine` traits (for example
+//! [`LweSeededCiphertextDiscardingExpansionEngine`]) regenerate those masks with the CSPRNG given
+//! as their `Generator` type parameter, which must be the same generator as the one used at
+//! encryption time for the output to be meaningful. This lets the owner of the engine choose the
+//! (possibly hardware-accelerated) generator used for the expansion, independently of the
+//! generator the engine uses for its own randomness.
 
 // This makes it impossible for types outside concrete to implement operations.
 pub(crate) mod sealed {
@@ -112,6 +136,18 @@ macro_rules! engine_error {
             }
         }
         impl<EngineError: std::error::Error> std::error::Error for $name<EngineError>{}
+        impl<EngineError: std::error::Error> $name<EngineError>{
+            #[doc="Maps the _specific_ engine error with `f`, leaving the _generic_ errors as is."]
+            pub fn map_engine_error<F, OtherEngineError>(self, f: F) -> $name<OtherEngineError>
+            where
+                F: FnOnce(EngineError) -> OtherEngineError,
+                OtherEngineError: std::error::Error,
+            {
+                match self {
+                    Self::Engine(error) => $name::Engine(f(error)),
+                }
+            }
+        }
     };
     ($name:ident for $trait:ident @ $($variants:ident => $messages:literal),*) => {
         #[doc=concat!("An error used with the [`", stringify!($trait), "`] trait.")]
@@ -144,10 +180,34 @@ macro_rules! engine_error {
             }
         }
         impl<EngineError: std::error::Error> std::error::Error for $name<EngineError>{}
+        impl<EngineError: std::error::Error> $name<EngineError>{
+            #[doc="Maps the _specific_ engine error with `f`, leaving the _generic_ errors as is."]
+            pub fn map_engine_error<F, OtherEngineError>(self, f: F) -> $name<OtherEngineError>
+            where
+                F: FnOnce(EngineError) -> OtherEngineError,
+                OtherEngineError: std::error::Error,
+            {
+                match self {
+                    $(
+                        Self::$variants => $name::$variants,
+                    )*
+                    Self::Engine(error) => $name::Engine(f(error)),
+                }
+            }
+        }
     };
 }
 pub(crate) use engine_error;
 
+pub mod circuit;
+pub mod dynamic;
+#[cfg(feature = "fault_injection")]
+pub mod fault_injection;
+pub mod polynomial_multiplication;
+#[cfg(feature = "__commons_serialization")]
+pub mod recording;
+pub mod statistics;
+
 mod cleartext_conversion;
 mod cleartext_creation;
 mod cleartext_discarding_conversion;
@@ -163,12 +223,20 @@ mod cleartext_vector_retrieval;
 mod encoder_creation;
 mod encoder_vector_creation;
 mod entity_deserialization;
+mod entity_raw_export;
+mod entity_raw_import;
 mod entity_serialization;
+mod entity_validation;
 mod ggsw_ciphertext_conversion;
 mod ggsw_ciphertext_discarding_conversion;
 mod ggsw_ciphertext_scalar_discarding_encryption;
 mod ggsw_ciphertext_scalar_encryption;
+mod ggsw_ciphertext_scalar_seeded_encryption;
 mod ggsw_ciphertext_scalar_trivial_encryption;
+mod ggsw_seeded_ciphertext_to_ggsw_ciphertext_transformation;
+mod glev_ciphertext_decryption;
+mod glev_ciphertext_encryption;
+mod glwe_automorphism_keys_generation;
 mod glwe_ciphertext_consuming_retrieval;
 mod glwe_ciphertext_conversion;
 mod glwe_ciphertext_creation;
@@ -176,10 +244,19 @@ mod glwe_ciphertext_decryption;
 mod glwe_ciphertext_discarding_conversion;
 mod glwe_ciphertext_discarding_decryption;
 mod glwe_ciphertext_discarding_encryption;
+mod glwe_ciphertext_discarding_ring_reduction;
+mod glwe_ciphertext_discarding_ring_switching;
 mod glwe_ciphertext_discarding_trivial_encryption;
 mod glwe_ciphertext_encryption;
+mod glwe_ciphertext_fusing_partial_blind_rotation;
 mod glwe_ciphertext_ggsw_ciphertext_discarding_external_product;
+mod glwe_ciphertext_ggsw_ciphertext_discarding_external_product_add;
 mod glwe_ciphertext_ggsw_ciphertext_external_product;
+mod glwe_ciphertext_leveled_multiplication;
+mod glwe_ciphertext_relinearization;
+mod glwe_ciphertext_ring_splitting;
+mod glwe_ciphertext_sparse_cleartext_polynomial_discarding_multiplication;
+mod glwe_ciphertext_tensor_product_same_key;
 mod glwe_ciphertext_trivial_decryption;
 mod glwe_ciphertext_trivial_encryption;
 mod glwe_ciphertext_vector_conversion;
@@ -187,42 +264,68 @@ mod glwe_ciphertext_vector_decryption;
 mod glwe_ciphertext_vector_discarding_conversion;
 mod glwe_ciphertext_vector_discarding_decryption;
 mod glwe_ciphertext_vector_discarding_encryption;
+mod glwe_ciphertext_vector_discarding_variable_noise_encryption;
 mod glwe_ciphertext_vector_encryption;
 mod glwe_ciphertext_vector_trivial_decryption;
 mod glwe_ciphertext_vector_trivial_encryption;
+mod glwe_ciphertext_vector_variable_noise_encryption;
 mod glwe_ciphertext_vector_zero_encryption;
 mod glwe_ciphertext_zero_encryption;
 mod glwe_ciphertexts_ggsw_ciphertext_fusing_cmux;
+mod glwe_relinearization_key_generation;
+mod glwe_ring_reduction_key_generation;
+mod glwe_ring_switching_key_generation;
 mod glwe_secret_key_conversion;
 mod glwe_secret_key_discarding_conversion;
+mod glwe_secret_key_gaussian_generation;
 mod glwe_secret_key_generation;
+mod glwe_secret_key_ring_splitting;
+mod glwe_secret_key_tensor_product_same_key;
+mod glwe_secret_key_ternary_generation;
+mod glwe_seeded_ciphertext_discarding_expansion;
 mod glwe_seeded_ciphertext_encryption;
 mod glwe_seeded_ciphertext_to_glwe_ciphertext_transformation;
+mod glwe_seeded_ciphertext_vector_discarding_expansion;
 mod glwe_seeded_ciphertext_vector_encryption;
 mod glwe_seeded_ciphertext_vector_to_glwe_ciphertext_vector_transformation;
+mod glwe_tensor_product_ciphertext_conversion;
 mod glwe_to_lwe_secret_key_transformation;
+mod lev_ciphertext_decryption;
+mod lev_ciphertext_encryption;
 mod lwe_bootstrap_key_consuming_retrieval;
 mod lwe_bootstrap_key_conversion;
 mod lwe_bootstrap_key_creation;
 mod lwe_bootstrap_key_discarding_conversion;
 mod lwe_bootstrap_key_generation;
+mod lwe_bootstrap_key_ggsw_ciphertext_discarding_insertion;
+mod lwe_bootstrap_key_ggsw_ciphertext_extraction;
+mod lwe_bootstrap_key_shard_extraction;
+mod lwe_bootstrap_lookup_table_creation;
 mod lwe_ciphertext_cleartext_discarding_multiplication;
 mod lwe_ciphertext_cleartext_fusing_multiplication;
 mod lwe_ciphertext_consuming_retrieval;
 mod lwe_ciphertext_conversion;
 mod lwe_ciphertext_creation;
 mod lwe_ciphertext_decryption;
+mod lwe_ciphertext_decryption_decoding;
 mod lwe_ciphertext_discarding_addition;
 mod lwe_ciphertext_discarding_bit_extraction;
 mod lwe_ciphertext_discarding_bootstrap;
+mod lwe_ciphertext_discarding_bootstrap_many_lut;
 mod lwe_ciphertext_discarding_conversion;
 mod lwe_ciphertext_discarding_decryption;
 mod lwe_ciphertext_discarding_encryption;
 mod lwe_ciphertext_discarding_extraction;
 mod lwe_ciphertext_discarding_keyswitch;
+mod lwe_ciphertext_discarding_keyswitch_modulus_switch;
 mod lwe_ciphertext_discarding_loading;
+mod lwe_ciphertext_discarding_multiplication;
 mod lwe_ciphertext_discarding_opposite;
+mod lwe_ciphertext_discarding_partial_keyswitch;
+mod lwe_ciphertext_discarding_polynomial_evaluation;
 mod lwe_ciphertext_discarding_public_key_encryption;
+mod lwe_ciphertext_discarding_sanitization;
+mod lwe_ciphertext_discarding_stochastic_bootstrap;
 mod lwe_ciphertext_discarding_storing;
 mod lwe_ciphertext_discarding_subtraction;
 mod lwe_ciphertext_encryption;
@@ -234,21 +337,31 @@ mod lwe_ciphertext_plaintext_discarding_addition;
 mod lwe_ciphertext_plaintext_discarding_subtraction;
 mod lwe_ciphertext_plaintext_fusing_addition;
 mod lwe_ciphertext_plaintext_fusing_subtraction;
+mod lwe_ciphertext_public_key_encryption;
+#[cfg(feature = "verifiable_decryption")]
+mod lwe_ciphertext_transcript_decryption;
 mod lwe_ciphertext_trivial_decryption;
 mod lwe_ciphertext_trivial_encryption;
+mod lwe_ciphertext_vector_bytes_decryption;
+mod lwe_ciphertext_vector_bytes_encryption;
 mod lwe_ciphertext_vector_consuming_retrieval;
 mod lwe_ciphertext_vector_conversion;
 mod lwe_ciphertext_vector_creation;
 mod lwe_ciphertext_vector_decryption;
+mod lwe_ciphertext_vector_decryption_decoding;
 mod lwe_ciphertext_vector_discarding_addition;
 mod lwe_ciphertext_vector_discarding_affine_transformation;
+mod lwe_ciphertext_vector_discarding_affine_transformation_keyswitch;
 mod lwe_ciphertext_vector_discarding_bootstrap;
 mod lwe_ciphertext_vector_discarding_conversion;
 mod lwe_ciphertext_vector_discarding_decryption;
 mod lwe_ciphertext_vector_discarding_encryption;
+mod lwe_ciphertext_vector_discarding_gathering;
 mod lwe_ciphertext_vector_discarding_keyswitch;
 mod lwe_ciphertext_vector_discarding_loading;
 mod lwe_ciphertext_vector_discarding_opposite;
+mod lwe_ciphertext_vector_discarding_permutation;
+mod lwe_ciphertext_vector_discarding_scattering;
 mod lwe_ciphertext_vector_discarding_subtraction;
 mod lwe_ciphertext_vector_encryption;
 mod lwe_ciphertext_vector_fusing_addition;
@@ -256,7 +369,10 @@ mod lwe_ciphertext_vector_fusing_opposite;
 mod lwe_ciphertext_vector_fusing_subtraction;
 mod lwe_ciphertext_vector_glwe_ciphertext_discarding_packing_keyswitch;
 mod lwe_ciphertext_vector_glwe_ciphertext_discarding_private_functional_packing_keyswitch;
+mod lwe_ciphertext_vector_glwe_ciphertext_discarding_tree_packing;
 mod lwe_ciphertext_vector_loading;
+mod lwe_ciphertext_vector_streaming_decryption;
+mod lwe_ciphertext_vector_streaming_encryption;
 mod lwe_ciphertext_vector_trivial_decryption;
 mod lwe_ciphertext_vector_trivial_encryption;
 mod lwe_ciphertext_vector_zero_encryption;
@@ -266,19 +382,30 @@ mod lwe_keyswitch_key_conversion;
 mod lwe_keyswitch_key_discarding_conversion;
 mod lwe_keyswitch_key_generation;
 mod lwe_packing_keyswitch_key_generation;
+mod lwe_partial_keyswitch_key_generation;
 mod lwe_private_functional_packing_keyswitch_key_generation;
 mod lwe_public_key_generation;
 mod lwe_secret_key_conversion;
 mod lwe_secret_key_discarding_conversion;
+mod lwe_secret_key_gaussian_generation;
 mod lwe_secret_key_generation;
+mod lwe_secret_key_partial_keyswitch;
+mod lwe_secret_key_ternary_generation;
+mod lwe_seeded_bootstrap_key_discarding_expansion;
 mod lwe_seeded_bootstrap_key_generation;
 mod lwe_seeded_bootstrap_key_to_lwe_bootstrap_key_transformation;
+mod lwe_seeded_ciphertext_batch_encryption;
+mod lwe_seeded_ciphertext_discarding_expansion;
 mod lwe_seeded_ciphertext_encryption;
 mod lwe_seeded_ciphertext_to_lwe_ciphertext_transformation;
+mod lwe_seeded_ciphertext_vector_discarding_expansion;
 mod lwe_seeded_ciphertext_vector_encryption;
 mod lwe_seeded_ciphertext_vector_to_lwe_ciphertext_vector_transformation;
+mod lwe_seeded_keyswitch_key_discarding_expansion;
 mod lwe_seeded_keyswitch_key_generation;
 mod lwe_seeded_keyswitch_key_to_lwe_keyswitch_key_transformation;
+mod lwe_seeded_packing_keyswitch_key_generation;
+mod lwe_seeded_packing_keyswitch_key_to_lwe_packing_keyswitch_key_transformation;
 mod lwe_to_glwe_secret_key_transformation;
 mod plaintext_conversion;
 mod plaintext_creation;
@@ -291,7 +418,15 @@ mod plaintext_vector_creation;
 mod plaintext_vector_decoding;
 mod plaintext_vector_discarding_conversion;
 mod plaintext_vector_discarding_retrieval;
+mod plaintext_vector_iterator_creation;
+mod plaintext_vector_lookup_table_creation;
 mod plaintext_vector_retrieval;
+mod plaintext_vector_view_creation;
+mod sparse_cleartext_polynomial_creation;
+mod trivial_glwe_ciphertext_creation;
+mod trivial_glwe_ciphertext_to_glwe_ciphertext_transformation;
+mod trivial_lwe_ciphertext_creation;
+mod trivial_lwe_ciphertext_to_lwe_ciphertext_transformation;
 
 pub use cleartext_conversion::*;
 pub use cleartext_creation::*;
@@ -308,12 +443,20 @@ pub use cleartext_vector_retrieval::*;
 pub use encoder_creation::*;
 pub use encoder_vector_creation::*;
 pub use entity_deserialization::*;
+pub use entity_raw_export::*;
+pub use entity_raw_import::*;
 pub use entity_serialization::*;
+pub use entity_validation::*;
 pub use ggsw_ciphertext_conversion::*;
 pub use ggsw_ciphertext_discarding_conversion::*;
 pub use ggsw_ciphertext_scalar_discarding_encryption::*;
 pub use ggsw_ciphertext_scalar_encryption::*;
+pub use ggsw_ciphertext_scalar_seeded_encryption::*;
 pub use ggsw_ciphertext_scalar_trivial_encryption::*;
+pub use ggsw_seeded_ciphertext_to_ggsw_ciphertext_transformation::*;
+pub use glev_ciphertext_decryption::*;
+pub use glev_ciphertext_encryption::*;
+pub use glwe_automorphism_keys_generation::*;
 pub use glwe_ciphertext_consuming_retrieval::*;
 pub use glwe_ciphertext_conversion::*;
 pub use glwe_ciphertext_creation::*;
@@ -321,10 +464,19 @@ pub use glwe_ciphertext_decryption::*;
 pub use glwe_ciphertext_discarding_conversion::*;
 pub use glwe_ciphertext_discarding_decryption::*;
 pub use glwe_ciphertext_discarding_encryption::*;
+pub use glwe_ciphertext_discarding_ring_reduction::*;
+pub use glwe_ciphertext_discarding_ring_switching::*;
 pub use glwe_ciphertext_discarding_trivial_encryption::*;
 pub use glwe_ciphertext_encryption::*;
+pub use glwe_ciphertext_fusing_partial_blind_rotation::*;
 pub use glwe_ciphertext_ggsw_ciphertext_discarding_external_product::*;
+pub use glwe_ciphertext_ggsw_ciphertext_discarding_external_product_add::*;
 pub use glwe_ciphertext_ggsw_ciphertext_external_product::*;
+pub use glwe_ciphertext_leveled_multiplication::*;
+pub use glwe_ciphertext_relinearization::*;
+pub use glwe_ciphertext_ring_splitting::*;
+pub use glwe_ciphertext_sparse_cleartext_polynomial_discarding_multiplication::*;
+pub use glwe_ciphertext_tensor_product_same_key::*;
 pub use glwe_ciphertext_trivial_decryption::*;
 pub use glwe_ciphertext_trivial_encryption::*;
 pub use glwe_ciphertext_vector_conversion::*;
@@ -332,42 +484,68 @@ pub use glwe_ciphertext_vector_decryption::*;
 pub use glwe_ciphertext_vector_discarding_conversion::*;
 pub use glwe_ciphertext_vector_discarding_decryption::*;
 pub use glwe_ciphertext_vector_discarding_encryption::*;
+pub use glwe_ciphertext_vector_discarding_variable_noise_encryption::*;
 pub use glwe_ciphertext_vector_encryption::*;
 pub use glwe_ciphertext_vector_trivial_decryption::*;
 pub use glwe_ciphertext_vector_trivial_encryption::*;
+pub use glwe_ciphertext_vector_variable_noise_encryption::*;
 pub use glwe_ciphertext_vector_zero_encryption::*;
 pub use glwe_ciphertext_zero_encryption::*;
 pub use glwe_ciphertexts_ggsw_ciphertext_fusing_cmux::*;
+pub use glwe_relinearization_key_generation::*;
+pub use glwe_ring_reduction_key_generation::*;
+pub use glwe_ring_switching_key_generation::*;
 pub use glwe_secret_key_conversion::*;
 pub use glwe_secret_key_discarding_conversion::*;
+pub use glwe_secret_key_gaussian_generation::*;
 pub use glwe_secret_key_generation::*;
+pub use glwe_secret_key_ring_splitting::*;
+pub use glwe_secret_key_tensor_product_same_key::*;
+pub use glwe_secret_key_ternary_generation::*;
+pub use glwe_seeded_ciphertext_discarding_expansion::*;
 pub use glwe_seeded_ciphertext_encryption::*;
 pub use glwe_seeded_ciphertext_to_glwe_ciphertext_transformation::*;
+pub use glwe_seeded_ciphertext_vector_discarding_expansion::*;
 pub use glwe_seeded_ciphertext_vector_encryption::*;
 pub use glwe_seeded_ciphertext_vector_to_glwe_ciphertext_vector_transformation::*;
+pub use glwe_tensor_product_ciphertext_conversion::*;
 pub use glwe_to_lwe_secret_key_transformation::*;
+pub use lev_ciphertext_decryption::*;
+pub use lev_ciphertext_encryption::*;
 pub use lwe_bootstrap_key_consuming_retrieval::*;
 pub use lwe_bootstrap_key_conversion::*;
 pub use lwe_bootstrap_key_creation::*;
 pub use lwe_bootstrap_key_discarding_conversion::*;
 pub use lwe_bootstrap_key_generation::*;
+pub use lwe_bootstrap_key_ggsw_ciphertext_discarding_insertion::*;
+pub use lwe_bootstrap_key_ggsw_ciphertext_extraction::*;
+pub use lwe_bootstrap_key_shard_extraction::*;
+pub use lwe_bootstrap_lookup_table_creation::*;
 pub use lwe_ciphertext_cleartext_discarding_multiplication::*;
 pub use lwe_ciphertext_cleartext_fusing_multiplication::*;
 pub use lwe_ciphertext_consuming_retrieval::*;
 pub use lwe_ciphertext_conversion::*;
 pub use lwe_ciphertext_creation::*;
 pub use lwe_ciphertext_decryption::*;
+pub use lwe_ciphertext_decryption_decoding::*;
 pub use lwe_ciphertext_discarding_addition::*;
 pub use lwe_ciphertext_discarding_bit_extraction::*;
 pub use lwe_ciphertext_discarding_bootstrap::*;
+pub use lwe_ciphertext_discarding_bootstrap_many_lut::*;
 pub use lwe_ciphertext_discarding_conversion::*;
 pub use lwe_ciphertext_discarding_decryption::*;
 pub use lwe_ciphertext_discarding_encryption::*;
 pub use lwe_ciphertext_discarding_extraction::*;
 pub use lwe_ciphertext_discarding_keyswitch::*;
+pub use lwe_ciphertext_discarding_keyswitch_modulus_switch::*;
 pub use lwe_ciphertext_discarding_loading::*;
+pub use lwe_ciphertext_discarding_multiplication::*;
 pub use lwe_ciphertext_discarding_opposite::*;
+pub use lwe_ciphertext_discarding_partial_keyswitch::*;
+pub use lwe_ciphertext_discarding_polynomial_evaluation::*;
 pub use lwe_ciphertext_discarding_public_key_encryption::*;
+pub use lwe_ciphertext_discarding_sanitization::*;
+pub use lwe_ciphertext_discarding_stochastic_bootstrap::*;
 pub use lwe_ciphertext_discarding_storing::*;
 pub use lwe_ciphertext_discarding_subtraction::*;
 pub use lwe_ciphertext_encryption::*;
@@ -379,21 +557,31 @@ pub use lwe_ciphertext_plaintext_discarding_addition::*;
 pub use lwe_ciphertext_plaintext_discarding_subtraction::*;
 pub use lwe_ciphertext_plaintext_fusing_addition::*;
 pub use lwe_ciphertext_plaintext_fusing_subtraction::*;
+pub use lwe_ciphertext_public_key_encryption::*;
+#[cfg(feature = "verifiable_decryption")]
+pub use lwe_ciphertext_transcript_decryption::*;
 pub use lwe_ciphertext_trivial_decryption::*;
 pub use lwe_ciphertext_trivial_encryption::*;
+pub use lwe_ciphertext_vector_bytes_decryption::*;
+pub use lwe_ciphertext_vector_bytes_encryption::*;
 pub use lwe_ciphertext_vector_consuming_retrieval::*;
 pub use lwe_ciphertext_vector_conversion::*;
 pub use lwe_ciphertext_vector_creation::*;
 pub use lwe_ciphertext_vector_decryption::*;
+pub use lwe_ciphertext_vector_decryption_decoding::*;
 pub use lwe_ciphertext_vector_discarding_addition::*;
 pub use lwe_ciphertext_vector_discarding_affine_transformation::*;
+pub use lwe_ciphertext_vector_discarding_affine_transformation_keyswitch::*;
 pub use lwe_ciphertext_vector_discarding_bootstrap::*;
 pub use lwe_ciphertext_vector_discarding_conversion::*;
 pub use lwe_ciphertext_vector_discarding_decryption::*;
 pub use lwe_ciphertext_vector_discarding_encryption::*;
+pub use lwe_ciphertext_vector_discarding_gathering::*;
 pub use lwe_ciphertext_vector_discarding_keyswitch::*;
 pub use lwe_ciphertext_vector_discarding_loading::*;
 pub use lwe_ciphertext_vector_discarding_opposite::*;
+pub use lwe_ciphertext_vector_discarding_permutation::*;
+pub use lwe_ciphertext_vector_discarding_scattering::*;
 pub use lwe_ciphertext_vector_discarding_subtraction::*;
 pub use lwe_ciphertext_vector_encryption::*;
 pub use lwe_ciphertext_vector_fusing_addition::*;
@@ -401,7 +589,10 @@ pub use lwe_ciphertext_vector_fusing_opposite::*;
 pub use lwe_ciphertext_vector_fusing_subtraction::*;
 pub use lwe_ciphertext_vector_glwe_ciphertext_discarding_packing_keyswitch::*;
 pub use lwe_ciphertext_vector_glwe_ciphertext_discarding_private_functional_packing_keyswitch::*;
+pub use lwe_ciphertext_vector_glwe_ciphertext_discarding_tree_packing::*;
 pub use lwe_ciphertext_vector_loading::*;
+pub use lwe_ciphertext_vector_streaming_decryption::*;
+pub use lwe_ciphertext_vector_streaming_encryption::*;
 pub use lwe_ciphertext_vector_trivial_decryption::*;
 pub use lwe_ciphertext_vector_trivial_encryption::*;
 pub use lwe_ciphertext_vector_zero_encryption::*;
@@ -411,19 +602,30 @@ pub use lwe_keyswitch_key_conversion::*;
 pub use lwe_keyswitch_key_discarding_conversion::*;
 pub use lwe_keyswitch_key_generation::*;
 pub use lwe_packing_keyswitch_key_generation::*;
+pub use lwe_partial_keyswitch_key_generation::*;
 pub use lwe_private_functional_packing_keyswitch_key_generation::*;
 pub use lwe_public_key_generation::*;
 pub use lwe_secret_key_conversion::*;
 pub use lwe_secret_key_discarding_conversion::*;
+pub use lwe_secret_key_gaussian_generation::*;
 pub use lwe_secret_key_generation::*;
+pub use lwe_secret_key_partial_keyswitch::*;
+pub use lwe_secret_key_ternary_generation::*;
+pub use lwe_seeded_bootstrap_key_discarding_expansion::*;
 pub use lwe_seeded_bootstrap_key_generation::*;
 pub use lwe_seeded_bootstrap_key_to_lwe_bootstrap_key_transformation::*;
+pub use lwe_seeded_ciphertext_batch_encryption::*;
+pub use lwe_seeded_ciphertext_discarding_expansion::*;
 pub use lwe_seeded_ciphertext_encryption::*;
 pub use lwe_seeded_ciphertext_to_lwe_ciphertext_transformation::*;
+pub use lwe_seeded_ciphertext_vector_discarding_expansion::*;
 pub use lwe_seeded_ciphertext_vector_encryption::*;
 pub use lwe_seeded_ciphertext_vector_to_lwe_ciphertext_vector_transformation::*;
+pub use lwe_seeded_keyswitch_key_discarding_expansion::*;
 pub use lwe_seeded_keyswitch_key_generation::*;
 pub use lwe_seeded_keyswitch_key_to_lwe_keyswitch_key_transformation::*;
+pub use lwe_seeded_packing_keyswitch_key_generation::*;
+pub use lwe_seeded_packing_keyswitch_key_to_lwe_packing_keyswitch_key_transformation::*;
 pub use lwe_to_glwe_secret_key_transformation::*;
 pub use plaintext_conversion::*;
 pub use plaintext_creation::*;
@@ -436,4 +638,12 @@ pub use plaintext_vector_creation::*;
 pub use plaintext_vector_decoding::*;
 pub use plaintext_vector_discarding_conversion::*;
 pub use plaintext_vector_discarding_retrieval::*;
+pub use plaintext_vector_iterator_creation::*;
+pub use plaintext_vector_lookup_table_creation::*;
 pub use plaintext_vector_retrieval::*;
+pub use plaintext_vector_view_creation::*;
+pub use sparse_cleartext_polynomial_creation::*;
+pub use trivial_glwe_ciphertext_creation::*;
+pub use trivial_glwe_ciphertext_to_glwe_ciphertext_transformation::*;
+pub use trivial_lwe_ciphertext_creation::*;
+pub use trivial_lwe_ciphertext_to_lwe_ciphertext_transformation::*;
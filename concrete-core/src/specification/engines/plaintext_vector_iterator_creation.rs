@@ -0,0 +1,59 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::PlaintextVectorEntity;
+use concrete_commons::parameters::PlaintextCount;
+
+engine_error! {
+    PlaintextVectorIteratorCreationError for PlaintextVectorIteratorCreationEngine @
+    EmptyInput => "The input iterator must not be empty."
+}
+
+impl<EngineError: std::error::Error> PlaintextVectorIteratorCreationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks(plaintext_count: PlaintextCount) -> Result<(), Self> {
+        if plaintext_count.0 == 0 {
+            return Err(Self::EmptyInput);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines creating plaintext vectors from iterators over arbitrary values.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a plaintext vector from the values
+/// yielded by the `values` iterator. Contrary to
+/// [`PlaintextVectorCreationEngine`](super::PlaintextVectorCreationEngine), the values do not
+/// need to be stored in a slice beforehand, and are directly collected in the plaintext vector.
+///
+/// # Formal Definition
+///
+/// Given an iterator yielding the values $v\_0, \dots, v\_{n-1}$, with $n > 0$, the output is the
+/// plaintext vector $(p\_0, \dots, p\_{n-1})$, where $p\_i = v\_i$ for all $i$. The plaintext count
+/// of the output is the number $n$ of values yielded by the iterator.
+pub trait PlaintextVectorIteratorCreationEngine<Value, PlaintextVector>: AbstractEngine
+where
+    PlaintextVector: PlaintextVectorEntity,
+{
+    /// Creates a plaintext vector from an iterator over arbitrary values.
+    fn create_plaintext_vector_from_iter<Values>(
+        &mut self,
+        values: Values,
+    ) -> Result<PlaintextVector, PlaintextVectorIteratorCreationError<Self::EngineError>>
+    where
+        Values: IntoIterator<Item = Value>;
+
+    /// Unsafely creates a plaintext vector from an iterator over arbitrary values.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`PlaintextVectorIteratorCreationError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn create_plaintext_vector_from_iter_unchecked<Values>(
+        &mut self,
+        values: Values,
+    ) -> PlaintextVector
+    where
+        Values: IntoIterator<Item = Value>;
+}
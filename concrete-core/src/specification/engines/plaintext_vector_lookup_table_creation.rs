@@ -0,0 +1,77 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{CleartextVectorEntity, EncoderEntity, PlaintextVectorEntity};
+use concrete_commons::parameters::PolynomialSize;
+
+engine_error! {
+    PlaintextVectorLookupTableCreationError for PlaintextVectorLookupTableCreationEngine @
+    EmptyTable => "The table must not be empty.",
+    PolynomialSizeTooSmall => "The polynomial size must not be smaller than the table size."
+}
+
+impl<EngineError: std::error::Error> PlaintextVectorLookupTableCreationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<CleartextVector>(
+        table: &CleartextVector,
+        polynomial_size: PolynomialSize,
+    ) -> Result<(), Self>
+    where
+        CleartextVector: CleartextVectorEntity,
+    {
+        if table.cleartext_count().0 == 0 {
+            return Err(Self::EmptyTable);
+        }
+        if polynomial_size.0 < table.cleartext_count().0 {
+            return Err(Self::PolynomialSizeTooSmall);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines creating the lookup tables evaluated by the bootstraps.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a plaintext vector of
+/// `polynomial_size` plaintexts, which can be trivially encrypted into the accumulator of a
+/// bootstrap. Bootstrapping a ciphertext of a message $m$ encoded with `input_encoder` with this
+/// accumulator returns a ciphertext of the $m$-th cleartext of `table`, encoded with
+/// `output_encoder`.
+///
+/// # Formal Definition
+///
+/// The coefficient of index $j$ of the lookup table corresponds to the phase $j / 2N$. It encodes
+/// the image of the message whose encoding is the closest to this phase. The coefficients which
+/// are closer to the half of the torus than to any message encode the opposite of the image of
+/// zero, so that the negacyclic wrapping of the bootstrap maps the small negative phases to the
+/// image of zero.
+pub trait PlaintextVectorLookupTableCreationEngine<Encoder, CleartextVector, PlaintextVector>:
+    AbstractEngine
+where
+    Encoder: EncoderEntity,
+    CleartextVector: CleartextVectorEntity,
+    PlaintextVector: PlaintextVectorEntity,
+{
+    /// Creates a lookup table from a table of cleartexts.
+    fn create_lookup_table_plaintext_vector(
+        &mut self,
+        input_encoder: &Encoder,
+        output_encoder: &Encoder,
+        table: &CleartextVector,
+        polynomial_size: PolynomialSize,
+    ) -> Result<PlaintextVector, PlaintextVectorLookupTableCreationError<Self::EngineError>>;
+
+    /// Unsafely creates a lookup table from a table of cleartexts.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`PlaintextVectorLookupTableCreationError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn create_lookup_table_plaintext_vector_unchecked(
+        &mut self,
+        input_encoder: &Encoder,
+        output_encoder: &Encoder,
+        table: &CleartextVector,
+        polynomial_size: PolynomialSize,
+    ) -> PlaintextVector;
+}
@@ -0,0 +1,47 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::PlaintextVectorEntity;
+
+engine_error! {
+    PlaintextVectorViewCreationError for PlaintextVectorViewCreationEngine @
+    EmptyContainer => "The container used to create the plaintext vector is of length 0!"
+}
+
+impl<EngineError: std::error::Error> PlaintextVectorViewCreationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks(container_length: usize) -> Result<(), Self> {
+        if container_length == 0 {
+            return Err(Self::EmptyContainer);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines creating a plaintext vector view from an arbitrary container.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation creates a plaintext vector from the arbitrary
+/// `container`, without copying it. By arbitrary here, we mean that `Container` can be any type
+/// that allows to instantiate a `PlaintextVectorEntity` borrowing its data.
+pub trait PlaintextVectorViewCreationEngine<Container, PlaintextVector>: AbstractEngine
+where
+    PlaintextVector: PlaintextVectorEntity,
+{
+    /// Creates a plaintext vector view from an arbitrary container.
+    fn create_plaintext_vector_view_from(
+        &mut self,
+        container: Container,
+    ) -> Result<PlaintextVector, PlaintextVectorViewCreationError<Self::EngineError>>;
+
+    /// Unsafely creates a plaintext vector view from an arbitrary container.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`PlaintextVectorViewCreationError`]. For safety concerns _specific_ to an engine, refer
+    /// to the implementer safety section.
+    unsafe fn create_plaintext_vector_view_from_unchecked(
+        &mut self,
+        container: Container,
+    ) -> PlaintextVector;
+}
@@ -0,0 +1,44 @@
+//! A module containing the abstraction over the transforms used to multiply polynomials.
+//!
+//! The external products and the bootstraps spend most of their time multiplying polynomials,
+//! which the backends do in a transformed domain. Two families of transforms are available:
+//!
+//! + The floating point fast Fourier transforms, which are the fastest, but only support
+//!   polynomial sizes which are powers of two, and add a small rounding noise to the products.
+//! + The number-theoretic transforms, which compute the products exactly over a prime field, and
+//!   support any polynomial size, as long as the products do not overflow the field.
+//!
+//! Which one is preferable depends on the parameter set. An engine implementing the
+//! [`PolynomialMultiplicationProviderSelector`] trait can use both, and selects one
+//! [`PolynomialMultiplicationProvider`] for each parameter set, when the keys and ciphertexts are
+//! converted to the transformed domain. The operations on the converted entities then use the
+//! provider they were converted with, under the same engine type.
+use super::AbstractEngine;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+
+/// The transforms which can be used to compute the polynomial products of the external products
+/// and bootstraps.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PolynomialMultiplicationProvider {
+    /// The floating point fast Fourier transform.
+    Fft,
+    /// The number-theoretic transform over a 64 bits prime field.
+    Ntt,
+}
+
+/// A trait for engines computing the polynomial products with one of several
+/// [`PolynomialMultiplicationProvider`]s, selected for each parameter set.
+pub trait PolynomialMultiplicationProviderSelector: AbstractEngine {
+    /// Returns the provider used to compute the polynomial products of the external products and
+    /// bootstraps with the given parameters, or `None` if none of the providers of the engine
+    /// supports them.
+    fn select_polynomial_multiplication_provider(
+        &self,
+        polynomial_size: PolynomialSize,
+        glwe_dimension: GlweDimension,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Option<PolynomialMultiplicationProvider>;
+}
@@ -0,0 +1,917 @@
+//! A module containing an engine recording the operations it executes, and the replay of the
+//! recorded operations.
+//!
+//! The [`RecordingEngine`] wraps an engine, and records every operation executed through it in an
+//! [`OperationTrace`]. The trace refers to the entities by [`EntityId`]s, and stores the
+//! serialization of every entity which was not produced by a recorded operation, such as the keys
+//! and the ciphertexts received from a client. Since the trace can be serialized, it can be shipped
+//! along with a bug report, and replayed offline against another engine with
+//! [`OperationTrace::replay`].
+//!
+//! The recorded operations are the ones exposed by the [`dynamic`](super::dynamic) module:
+//!
+//! + The encryption of LWE ciphertexts.
+//! + The decryption of LWE ciphertexts.
+//! + The (discarding) keyswitch of LWE ciphertexts.
+//! + The (discarding) bootstrap of LWE ciphertexts.
+//!
+//! The entities used by these operations must implement [`RecordableEntity`], which is the case
+//! for the entities of the backends shipping a serialization engine, when the corresponding
+//! `*_serialization` feature is activated.
+//!
+//! ```
+//! use concrete_commons::dispersion::Variance;
+//! use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+//! use concrete_core::prelude::*;
+//! use concrete_core::specification::engines::recording::{
+//!     RecordedOperation, RecordingEngine, ReplayEngines, ReplayedEntity,
+//! };
+//! # use std::error::Error;
+//!
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+//! let (input_lwe_dimension, output_lwe_dimension) = (LweDimension(6), LweDimension(3));
+//! let (level, base_log) = (DecompositionLevelCount(2), DecompositionBaseLog(8));
+//! let noise = Variance(2_f64.powf(-50.));
+//!
+//! // Unix seeder must be given a secret input.
+//! // Here we just give it 0, which is totally unsafe.
+//! const UNSAFE_SECRET: u128 = 0;
+//! let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+//! let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+//! let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+//! let ksk = engine.generate_new_lwe_keyswitch_key(
+//!     &input_key,
+//!     &output_key,
+//!     level,
+//!     base_log,
+//!     noise,
+//! )?;
+//! let plaintext = engine.create_plaintext_from(&(3_u64 << 50))?;
+//! let input = engine.encrypt_lwe_ciphertext(&input_key, &plaintext, noise)?;
+//! let mut output = engine.zero_encrypt_lwe_ciphertext(&output_key, noise)?;
+//!
+//! // The server-side computation is recorded.
+//! let mut recording_engine = RecordingEngine::from_engine(engine);
+//! recording_engine.discard_keyswitch_lwe_ciphertext(&mut output, &input, &ksk)?;
+//! let decrypted = recording_engine.decrypt_lwe_ciphertext(&output_key, &output)?;
+//! let trace = recording_engine.take_trace();
+//! assert_eq!(trace.operations().len(), 2);
+//!
+//! // The computation is replayed offline, against a fresh engine.
+//! let mut replay_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+//! let mut replay_decryptor = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+//! let replayed = trace.replay::<(
+//!     LweSecretKey64,
+//!     Plaintext64,
+//!     LweCiphertext64,
+//!     LweKeyswitchKey64,
+//!     LweBootstrapKey64,
+//!     GlweCiphertext64,
+//! )>(ReplayEngines {
+//!     keyswitcher: Some(&mut replay_engine),
+//!     decryptor: Some(&mut replay_decryptor),
+//!     ..ReplayEngines::default()
+//! })?;
+//!
+//! // The keyswitch and the decryption are deterministic, so the replay gives the same plaintext.
+//! let output_id = match trace.operations()[1] {
+//!     RecordedOperation::LweCiphertextDecryption { output, .. } => output,
+//!     _ => unreachable!(),
+//! };
+//! match replayed.get(output_id) {
+//!     Some(ReplayedEntity::Plaintext(replayed_plaintext)) => {
+//!         assert_eq!(*replayed_plaintext, decrypted)
+//!     }
+//!     _ => unreachable!(),
+//! }
+//! #
+//! # Ok(())
+//! # }
+//! ```
+use super::dynamic::{DynBootstrapper, DynKeyswitcher, DynLweDecryptor, DynLweEncryptor};
+use super::sealed::AbstractEngineSeal;
+use super::{
+    AbstractEngine, LweCiphertextDecryptionEngine, LweCiphertextDecryptionError,
+    LweCiphertextDiscardingBootstrapEngine, LweCiphertextDiscardingBootstrapError,
+    LweCiphertextDiscardingKeyswitchEngine, LweCiphertextDiscardingKeyswitchError,
+    LweCiphertextEncryptionEngine, LweCiphertextEncryptionError,
+};
+use crate::specification::entities::{
+    AbstractEntity, GlweCiphertextEntity, LweBootstrapKeyEntity, LweCiphertextEntity,
+    LweKeyswitchKeyEntity, LweSecretKeyEntity, PlaintextEntity,
+};
+use concrete_commons::dispersion::Variance;
+use serde::{Deserialize, Serialize};
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+
+/// An entity which can be stored in an [`OperationTrace`].
+pub trait RecordableEntity: AbstractEntity + Sized + 'static {
+    /// Serializes the entity to the bytes stored in a trace.
+    fn to_trace_bytes(&self) -> Result<Vec<u8>, Box<dyn Error>>;
+
+    /// Deserializes an entity from the bytes stored in a trace.
+    fn from_trace_bytes(bytes: &[u8]) -> Result<Self, Box<dyn Error>>;
+}
+
+// Implements `RecordableEntity` for entities supported by a serialization engine, whose
+// constructor parameters are `()`.
+macro_rules! impl_recordable_entity {
+    ($serialization_engine: ident => $($entity: ident),+ $(,)?) => {
+        $(
+            impl $crate::specification::engines::recording::RecordableEntity for $entity {
+                fn to_trace_bytes(&self) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+                    let mut engine = <$serialization_engine as
+                        $crate::specification::engines::AbstractEngine>::new(())?;
+                    Ok(<$serialization_engine as
+                        $crate::specification::engines::EntitySerializationEngine<
+                            $entity,
+                            Vec<u8>,
+                        >>::serialize(&mut engine, self)?)
+                }
+
+                fn from_trace_bytes(bytes: &[u8]) -> Result<Self, Box<dyn std::error::Error>> {
+                    let mut engine = <$serialization_engine as
+                        $crate::specification::engines::AbstractEngine>::new(())?;
+                    Ok(<$serialization_engine as
+                        $crate::specification::engines::EntityDeserializationEngine<
+                            &[u8],
+                            $entity,
+                        >>::deserialize(&mut engine, bytes)?)
+                }
+            }
+        )+
+    };
+}
+pub(crate) use impl_recordable_entity;
+
+/// The identifier of an entity in an [`OperationTrace`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct EntityId(pub usize);
+
+/// An operation recorded in an [`OperationTrace`].
+///
+/// For the discarding operations, `output` identifies the output entity before the operation,
+/// and `result` identifies it after the operation.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum RecordedOperation {
+    /// An [`LweCiphertextEncryptionEngine::encrypt_lwe_ciphertext`] call.
+    LweCiphertextEncryption {
+        key: EntityId,
+        input: EntityId,
+        noise: Variance,
+        output: EntityId,
+    },
+    /// An [`LweCiphertextDecryptionEngine::decrypt_lwe_ciphertext`] call.
+    LweCiphertextDecryption {
+        key: EntityId,
+        input: EntityId,
+        output: EntityId,
+    },
+    /// An [`LweCiphertextDiscardingKeyswitchEngine::discard_keyswitch_lwe_ciphertext`] call.
+    LweCiphertextDiscardingKeyswitch {
+        ksk: EntityId,
+        input: EntityId,
+        output: EntityId,
+        result: EntityId,
+    },
+    /// An [`LweCiphertextDiscardingBootstrapEngine::discard_bootstrap_lwe_ciphertext`] call.
+    LweCiphertextDiscardingBootstrap {
+        bsk: EntityId,
+        acc: EntityId,
+        input: EntityId,
+        output: EntityId,
+        result: EntityId,
+    },
+}
+
+/// A trace of the operations executed by a [`RecordingEngine`].
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct OperationTrace {
+    // The serialized entities, or `None` for the entities produced by a recorded operation.
+    entities: Vec<Option<Vec<u8>>>,
+    operations: Vec<RecordedOperation>,
+}
+
+impl OperationTrace {
+    /// Returns the recorded operations, in the order they were executed.
+    pub fn operations(&self) -> &[RecordedOperation] {
+        self.operations.as_slice()
+    }
+
+    /// Returns the number of entities referred to by the trace.
+    pub fn entity_count(&self) -> usize {
+        self.entities.len()
+    }
+
+    /// Returns the serialization of an entity, if it was not produced by a recorded operation.
+    pub fn serialized_entity(&self, id: EntityId) -> Option<&[u8]> {
+        self.entities.get(id.0)?.as_deref()
+    }
+
+    /// Replays the recorded operations with the given engines, and returns all the entities of
+    /// the trace.
+    ///
+    /// The entities which were not produced by a recorded operation are deserialized from the
+    /// trace. The other ones are produced by the replayed operations. The deterministic
+    /// operations give the same results as during the recording, while the encryptions use the
+    /// randomness of the replay engine.
+    pub fn replay<Entities: TraceEntities>(
+        &self,
+        mut engines: ReplayEngines<'_, Entities>,
+    ) -> Result<ReplayedEntities<Entities>, ReplayError> {
+        let mut replayed: ReplayedEntities<Entities> = ReplayedEntities {
+            entities: self.entities.iter().map(|_| None).collect(),
+        };
+        for (index, operation) in self.operations.iter().enumerate() {
+            match operation {
+                RecordedOperation::LweCiphertextEncryption {
+                    key,
+                    input,
+                    noise,
+                    output,
+                } => {
+                    let encryptor = engines
+                        .encryptor
+                        .as_mut()
+                        .ok_or(ReplayError::MissingEngine(index))?;
+                    replayed.load(self, *key, ReplayedEntity::LweSecretKey)?;
+                    replayed.load(self, *input, ReplayedEntity::Plaintext)?;
+                    let ciphertext = encryptor
+                        .encrypt_lwe_ciphertext(
+                            replayed.lwe_secret_key(*key)?,
+                            replayed.plaintext(*input)?,
+                            *noise,
+                        )
+                        .map_err(|error| ReplayError::operation(index, error))?;
+                    replayed.insert(*output, ReplayedEntity::LweCiphertext(ciphertext));
+                }
+                RecordedOperation::LweCiphertextDecryption { key, input, output } => {
+                    let decryptor = engines
+                        .decryptor
+                        .as_mut()
+                        .ok_or(ReplayError::MissingEngine(index))?;
+                    replayed.load(self, *key, ReplayedEntity::LweSecretKey)?;
+                    replayed.load(self, *input, ReplayedEntity::LweCiphertext)?;
+                    let plaintext = decryptor
+                        .decrypt_lwe_ciphertext(
+                            replayed.lwe_secret_key(*key)?,
+                            replayed.lwe_ciphertext(*input)?,
+                        )
+                        .map_err(|error| ReplayError::operation(index, error))?;
+                    replayed.insert(*output, ReplayedEntity::Plaintext(plaintext));
+                }
+                RecordedOperation::LweCiphertextDiscardingKeyswitch {
+                    ksk,
+                    input,
+                    output,
+                    result,
+                } => {
+                    let keyswitcher = engines
+                        .keyswitcher
+                        .as_mut()
+                        .ok_or(ReplayError::MissingEngine(index))?;
+                    replayed.load(self, *ksk, ReplayedEntity::LweKeyswitchKey)?;
+                    replayed.load(self, *input, ReplayedEntity::LweCiphertext)?;
+                    replayed.load(self, *output, ReplayedEntity::LweCiphertext)?;
+                    let mut ciphertext = replayed.lwe_ciphertext(*output)?.clone();
+                    keyswitcher
+                        .discard_keyswitch_lwe_ciphertext(
+                            &mut ciphertext,
+                            replayed.lwe_ciphertext(*input)?,
+                            replayed.lwe_keyswitch_key(*ksk)?,
+                        )
+                        .map_err(|error| ReplayError::operation(index, error))?;
+                    replayed.insert(*result, ReplayedEntity::LweCiphertext(ciphertext));
+                }
+                RecordedOperation::LweCiphertextDiscardingBootstrap {
+                    bsk,
+                    acc,
+                    input,
+                    output,
+                    result,
+                } => {
+                    let bootstrapper = engines
+                        .bootstrapper
+                        .as_mut()
+                        .ok_or(ReplayError::MissingEngine(index))?;
+                    replayed.load(self, *bsk, ReplayedEntity::LweBootstrapKey)?;
+                    replayed.load(self, *acc, ReplayedEntity::GlweCiphertext)?;
+                    replayed.load(self, *input, ReplayedEntity::LweCiphertext)?;
+                    replayed.load(self, *output, ReplayedEntity::LweCiphertext)?;
+                    let mut ciphertext = replayed.lwe_ciphertext(*output)?.clone();
+                    bootstrapper
+                        .discard_bootstrap_lwe_ciphertext(
+                            &mut ciphertext,
+                            replayed.lwe_ciphertext(*input)?,
+                            replayed.glwe_ciphertext(*acc)?,
+                            replayed.lwe_bootstrap_key(*bsk)?,
+                        )
+                        .map_err(|error| ReplayError::operation(index, error))?;
+                    replayed.insert(*result, ReplayedEntity::LweCiphertext(ciphertext));
+                }
+            }
+        }
+        Ok(replayed)
+    }
+}
+
+/// The entity types an [`OperationTrace`] is replayed with.
+///
+/// This trait is implemented for the tuples
+/// `(SecretKey, Plaintext, Ciphertext, KeyswitchKey, BootstrapKey, Accumulator)`.
+pub trait TraceEntities {
+    type LweSecretKey: LweSecretKeyEntity + RecordableEntity;
+    type Plaintext: PlaintextEntity + RecordableEntity;
+    type LweCiphertext: LweCiphertextEntity + RecordableEntity + Clone;
+    type LweKeyswitchKey: LweKeyswitchKeyEntity + RecordableEntity;
+    type LweBootstrapKey: LweBootstrapKeyEntity + RecordableEntity;
+    type GlweCiphertext: GlweCiphertextEntity + RecordableEntity;
+}
+
+impl<SecretKey, Plaintext, Ciphertext, KeyswitchKey, BootstrapKey, Accumulator> TraceEntities
+    for (
+        SecretKey,
+        Plaintext,
+        Ciphertext,
+        KeyswitchKey,
+        BootstrapKey,
+        Accumulator,
+    )
+where
+    SecretKey: LweSecretKeyEntity + RecordableEntity,
+    Plaintext: PlaintextEntity + RecordableEntity,
+    Ciphertext: LweCiphertextEntity + RecordableEntity + Clone,
+    KeyswitchKey: LweKeyswitchKeyEntity + RecordableEntity,
+    BootstrapKey: LweBootstrapKeyEntity + RecordableEntity,
+    Accumulator: GlweCiphertextEntity + RecordableEntity,
+{
+    type LweSecretKey = SecretKey;
+    type Plaintext = Plaintext;
+    type LweCiphertext = Ciphertext;
+    type LweKeyswitchKey = KeyswitchKey;
+    type LweBootstrapKey = BootstrapKey;
+    type GlweCiphertext = Accumulator;
+}
+
+/// The engines an [`OperationTrace`] is replayed with.
+///
+/// An engine is only needed for the kinds of operations contained in the trace, and the same
+/// engine can not be used for several kinds of operations at once.
+pub struct ReplayEngines<'engines, Entities: TraceEntities> {
+    pub encryptor: Option<
+        &'engines mut dyn DynLweEncryptor<
+            Entities::LweSecretKey,
+            Entities::Plaintext,
+            Entities::LweCiphertext,
+        >,
+    >,
+    pub decryptor: Option<
+        &'engines mut dyn DynLweDecryptor<
+            Entities::LweSecretKey,
+            Entities::LweCiphertext,
+            Entities::Plaintext,
+        >,
+    >,
+    pub keyswitcher: Option<
+        &'engines mut dyn DynKeyswitcher<
+            Entities::LweKeyswitchKey,
+            Entities::LweCiphertext,
+            Entities::LweCiphertext,
+        >,
+    >,
+    #[allow(clippy::type_complexity)]
+    pub bootstrapper: Option<
+        &'engines mut dyn DynBootstrapper<
+            Entities::LweBootstrapKey,
+            Entities::GlweCiphertext,
+            Entities::LweCiphertext,
+            Entities::LweCiphertext,
+        >,
+    >,
+}
+
+impl<Entities: TraceEntities> Default for ReplayEngines<'_, Entities> {
+    fn default() -> Self {
+        ReplayEngines {
+            encryptor: None,
+            decryptor: None,
+            keyswitcher: None,
+            bootstrapper: None,
+        }
+    }
+}
+
+/// An entity of a replayed [`OperationTrace`].
+pub enum ReplayedEntity<Entities: TraceEntities> {
+    LweSecretKey(Entities::LweSecretKey),
+    Plaintext(Entities::Plaintext),
+    LweCiphertext(Entities::LweCiphertext),
+    LweKeyswitchKey(Entities::LweKeyswitchKey),
+    LweBootstrapKey(Entities::LweBootstrapKey),
+    GlweCiphertext(Entities::GlweCiphertext),
+}
+
+/// The entities of a replayed [`OperationTrace`].
+pub struct ReplayedEntities<Entities: TraceEntities> {
+    entities: Vec<Option<ReplayedEntity<Entities>>>,
+}
+
+impl<Entities: TraceEntities> ReplayedEntities<Entities> {
+    /// Returns the entity with the given id, if it was used by a replayed operation.
+    pub fn get(&self, id: EntityId) -> Option<&ReplayedEntity<Entities>> {
+        self.entities.get(id.0)?.as_ref()
+    }
+
+    // Deserializes an entity from the trace, unless it was already loaded or produced.
+    fn load<Entity: RecordableEntity>(
+        &mut self,
+        trace: &OperationTrace,
+        id: EntityId,
+        variant: fn(Entity) -> ReplayedEntity<Entities>,
+    ) -> Result<(), ReplayError> {
+        let slot = self
+            .entities
+            .get_mut(id.0)
+            .ok_or(ReplayError::MissingEntity(id))?;
+        if slot.is_none() {
+            let bytes = trace
+                .serialized_entity(id)
+                .ok_or(ReplayError::MissingEntity(id))?;
+            let entity = Entity::from_trace_bytes(bytes).map_err(ReplayError::Deserialization)?;
+            *slot = Some(variant(entity));
+        }
+        Ok(())
+    }
+
+    fn insert(&mut self, id: EntityId, entity: ReplayedEntity<Entities>) {
+        self.entities[id.0] = Some(entity);
+    }
+
+    fn lwe_secret_key(&self, id: EntityId) -> Result<&Entities::LweSecretKey, ReplayError> {
+        match self.get(id) {
+            Some(ReplayedEntity::LweSecretKey(entity)) => Ok(entity),
+            _ => Err(ReplayError::EntityTypeMismatch(id)),
+        }
+    }
+
+    fn plaintext(&self, id: EntityId) -> Result<&Entities::Plaintext, ReplayError> {
+        match self.get(id) {
+            Some(ReplayedEntity::Plaintext(entity)) => Ok(entity),
+            _ => Err(ReplayError::EntityTypeMismatch(id)),
+        }
+    }
+
+    fn lwe_ciphertext(&self, id: EntityId) -> Result<&Entities::LweCiphertext, ReplayError> {
+        match self.get(id) {
+            Some(ReplayedEntity::LweCiphertext(entity)) => Ok(entity),
+            _ => Err(ReplayError::EntityTypeMismatch(id)),
+        }
+    }
+
+    fn lwe_keyswitch_key(&self, id: EntityId) -> Result<&Entities::LweKeyswitchKey, ReplayError> {
+        match self.get(id) {
+            Some(ReplayedEntity::LweKeyswitchKey(entity)) => Ok(entity),
+            _ => Err(ReplayError::EntityTypeMismatch(id)),
+        }
+    }
+
+    fn lwe_bootstrap_key(&self, id: EntityId) -> Result<&Entities::LweBootstrapKey, ReplayError> {
+        match self.get(id) {
+            Some(ReplayedEntity::LweBootstrapKey(entity)) => Ok(entity),
+            _ => Err(ReplayError::EntityTypeMismatch(id)),
+        }
+    }
+
+    fn glwe_ciphertext(&self, id: EntityId) -> Result<&Entities::GlweCiphertext, ReplayError> {
+        match self.get(id) {
+            Some(ReplayedEntity::GlweCiphertext(entity)) => Ok(entity),
+            _ => Err(ReplayError::EntityTypeMismatch(id)),
+        }
+    }
+}
+
+/// The error which can occur while replaying an [`OperationTrace`].
+#[derive(Debug)]
+pub enum ReplayError {
+    /// No engine was given for the operation with the given index.
+    MissingEngine(usize),
+    /// The entity is neither stored in the trace, nor produced by a previous operation.
+    MissingEntity(EntityId),
+    /// The entity is used with two different types.
+    EntityTypeMismatch(EntityId),
+    /// An entity stored in the trace could not be deserialized.
+    Deserialization(Box<dyn Error>),
+    /// The operation with the given index failed.
+    Operation { index: usize, error: Box<dyn Error> },
+}
+
+impl ReplayError {
+    fn operation<OperationError: Error + 'static>(index: usize, error: OperationError) -> Self {
+        ReplayError::Operation {
+            index,
+            error: Box::new(error),
+        }
+    }
+}
+
+impl Display for ReplayError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReplayError::MissingEngine(index) => {
+                write!(f, "No engine was given to replay the operation {}.", index)
+            }
+            ReplayError::MissingEntity(id) => {
+                write!(f, "The entity {} is missing from the trace.", id.0)
+            }
+            ReplayError::EntityTypeMismatch(id) => {
+                write!(f, "The entity {} is used with two different types.", id.0)
+            }
+            ReplayError::Deserialization(error) => {
+                write!(f, "Failed to deserialize an entity of the trace: {}", error)
+            }
+            ReplayError::Operation { index, error } => {
+                write!(f, "The replay of the operation {} failed: {}", index, error)
+            }
+        }
+    }
+}
+
+impl Error for ReplayError {}
+
+/// The error used by the [`RecordingEngine`].
+#[derive(Debug)]
+pub enum RecordingError<EngineError: Error> {
+    /// An error of the wrapped engine.
+    Engine(EngineError),
+    /// An entity could not be serialized in the trace.
+    Serialization(Box<dyn Error>),
+}
+
+impl<EngineError: Error> Display for RecordingError<EngineError> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RecordingError::Engine(error) => write!(f, "{}", error),
+            RecordingError::Serialization(error) => {
+                write!(f, "Failed to record an entity in the trace: {}", error)
+            }
+        }
+    }
+}
+
+impl<EngineError: Error> Error for RecordingError<EngineError> {}
+
+/// An engine recording the operations executed by the engine it wraps.
+///
+/// The entities are identified by their serialization: an entity whose serialization is the one
+/// of the output of a previous operation is identified with this output, and any other entity is
+/// stored in the trace. In particular, the operations executed directly with the wrapped engine
+/// are not recorded, but their outputs are stored in the trace when they are used by a recorded
+/// operation.
+///
+/// # Note:
+///
+/// Every entity used by a recorded operation is serialized, which makes the recording expensive
+/// when large keys are involved.
+pub struct RecordingEngine<Engine> {
+    engine: Engine,
+    trace: OperationTrace,
+    // The last id given to every serialized entity seen so far.
+    ids: HashMap<(TypeId, Vec<u8>), EntityId>,
+}
+
+impl<Engine> RecordingEngine<Engine> {
+    /// Wraps an engine, with an empty trace.
+    pub fn from_engine(engine: Engine) -> Self {
+        RecordingEngine {
+            engine,
+            trace: OperationTrace::default(),
+            ids: HashMap::new(),
+        }
+    }
+
+    /// Returns the trace recorded so far.
+    pub fn trace(&self) -> &OperationTrace {
+        &self.trace
+    }
+
+    /// Returns the trace recorded so far, and starts a new one.
+    pub fn take_trace(&mut self) -> OperationTrace {
+        self.ids.clear();
+        std::mem::take(&mut self.trace)
+    }
+
+    /// Returns a mutable reference to the wrapped engine.
+    ///
+    /// The operations executed with the returned engine are not recorded.
+    pub fn engine_mut(&mut self) -> &mut Engine {
+        &mut self.engine
+    }
+
+    /// Returns the wrapped engine.
+    pub fn into_engine(self) -> Engine {
+        self.engine
+    }
+
+    /// Returns the id of an entity in the current trace, if it was used or produced by a recorded
+    /// operation.
+    pub fn entity_id<Entity: RecordableEntity>(&self, entity: &Entity) -> Option<EntityId> {
+        let bytes = entity.to_trace_bytes().ok()?;
+        self.ids.get(&(TypeId::of::<Entity>(), bytes)).copied()
+    }
+
+    // Returns the id of an entity used by an operation, storing it in the trace if needed.
+    fn record_input<Entity: RecordableEntity>(
+        &mut self,
+        entity: &Entity,
+    ) -> Result<EntityId, Box<dyn Error>> {
+        let key = (TypeId::of::<Entity>(), entity.to_trace_bytes()?);
+        if let Some(id) = self.ids.get(&key) {
+            return Ok(*id);
+        }
+        let id = EntityId(self.trace.entities.len());
+        self.trace.entities.push(Some(key.1.clone()));
+        self.ids.insert(key, id);
+        Ok(id)
+    }
+
+    // Returns a new id for an entity produced by an operation.
+    fn record_output<Entity: RecordableEntity>(
+        &mut self,
+        entity: &Entity,
+    ) -> Result<EntityId, Box<dyn Error>> {
+        let key = (TypeId::of::<Entity>(), entity.to_trace_bytes()?);
+        let id = EntityId(self.trace.entities.len());
+        self.trace.entities.push(None);
+        self.ids.insert(key, id);
+        Ok(id)
+    }
+}
+
+impl<Engine: AbstractEngine> AbstractEngineSeal for RecordingEngine<Engine> {}
+
+impl<Engine: AbstractEngine> AbstractEngine for RecordingEngine<Engine> {
+    type EngineError = RecordingError<Engine::EngineError>;
+    type Parameters = Engine::Parameters;
+
+    fn new(parameters: Self::Parameters) -> Result<Self, Self::EngineError> {
+        Engine::new(parameters)
+            .map(RecordingEngine::from_engine)
+            .map_err(RecordingError::Engine)
+    }
+}
+
+/// # Panics
+/// The unchecked entry point panics if an entity can not be serialized in the trace.
+impl<Engine, SecretKey, Plaintext, Ciphertext>
+    LweCiphertextEncryptionEngine<SecretKey, Plaintext, Ciphertext> for RecordingEngine<Engine>
+where
+    Engine: LweCiphertextEncryptionEngine<SecretKey, Plaintext, Ciphertext>,
+    SecretKey: LweSecretKeyEntity + RecordableEntity,
+    Plaintext: PlaintextEntity + RecordableEntity,
+    Ciphertext: LweCiphertextEntity + RecordableEntity,
+{
+    fn encrypt_lwe_ciphertext(
+        &mut self,
+        key: &SecretKey,
+        input: &Plaintext,
+        noise: Variance,
+    ) -> Result<Ciphertext, LweCiphertextEncryptionError<Self::EngineError>> {
+        let serialization_error =
+            |error| LweCiphertextEncryptionError::Engine(RecordingError::Serialization(error));
+        let key_id = self.record_input(key).map_err(serialization_error)?;
+        let input_id = self.record_input(input).map_err(serialization_error)?;
+        let output = self
+            .engine
+            .encrypt_lwe_ciphertext(key, input, noise)
+            .map_err(|error| error.map_engine_error(RecordingError::Engine))?;
+        let output_id = self.record_output(&output).map_err(serialization_error)?;
+        self.trace
+            .operations
+            .push(RecordedOperation::LweCiphertextEncryption {
+                key: key_id,
+                input: input_id,
+                noise,
+                output: output_id,
+            });
+        Ok(output)
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &SecretKey,
+        input: &Plaintext,
+        noise: Variance,
+    ) -> Ciphertext {
+        let key_id = self.record_input(key).unwrap();
+        let input_id = self.record_input(input).unwrap();
+        let output = self
+            .engine
+            .encrypt_lwe_ciphertext_unchecked(key, input, noise);
+        let output_id = self.record_output(&output).unwrap();
+        self.trace
+            .operations
+            .push(RecordedOperation::LweCiphertextEncryption {
+                key: key_id,
+                input: input_id,
+                noise,
+                output: output_id,
+            });
+        output
+    }
+}
+
+/// # Panics
+/// The unchecked entry point panics if an entity can not be serialized in the trace.
+impl<Engine, SecretKey, Ciphertext, Plaintext>
+    LweCiphertextDecryptionEngine<SecretKey, Ciphertext, Plaintext> for RecordingEngine<Engine>
+where
+    Engine: LweCiphertextDecryptionEngine<SecretKey, Ciphertext, Plaintext>,
+    SecretKey: LweSecretKeyEntity + RecordableEntity,
+    Ciphertext: LweCiphertextEntity + RecordableEntity,
+    Plaintext: PlaintextEntity + RecordableEntity,
+{
+    fn decrypt_lwe_ciphertext(
+        &mut self,
+        key: &SecretKey,
+        input: &Ciphertext,
+    ) -> Result<Plaintext, LweCiphertextDecryptionError<Self::EngineError>> {
+        let serialization_error =
+            |error| LweCiphertextDecryptionError::Engine(RecordingError::Serialization(error));
+        let key_id = self.record_input(key).map_err(serialization_error)?;
+        let input_id = self.record_input(input).map_err(serialization_error)?;
+        let output = self
+            .engine
+            .decrypt_lwe_ciphertext(key, input)
+            .map_err(|error| error.map_engine_error(RecordingError::Engine))?;
+        let output_id = self.record_output(&output).map_err(serialization_error)?;
+        self.trace
+            .operations
+            .push(RecordedOperation::LweCiphertextDecryption {
+                key: key_id,
+                input: input_id,
+                output: output_id,
+            });
+        Ok(output)
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &SecretKey,
+        input: &Ciphertext,
+    ) -> Plaintext {
+        let key_id = self.record_input(key).unwrap();
+        let input_id = self.record_input(input).unwrap();
+        let output = self.engine.decrypt_lwe_ciphertext_unchecked(key, input);
+        let output_id = self.record_output(&output).unwrap();
+        self.trace
+            .operations
+            .push(RecordedOperation::LweCiphertextDecryption {
+                key: key_id,
+                input: input_id,
+                output: output_id,
+            });
+        output
+    }
+}
+
+/// # Panics
+/// The unchecked entry point panics if an entity can not be serialized in the trace.
+impl<Engine, KeyswitchKey, InputCiphertext, OutputCiphertext>
+    LweCiphertextDiscardingKeyswitchEngine<KeyswitchKey, InputCiphertext, OutputCiphertext>
+    for RecordingEngine<Engine>
+where
+    Engine: LweCiphertextDiscardingKeyswitchEngine<KeyswitchKey, InputCiphertext, OutputCiphertext>,
+    KeyswitchKey: LweKeyswitchKeyEntity + RecordableEntity,
+    InputCiphertext: LweCiphertextEntity + RecordableEntity,
+    OutputCiphertext: LweCiphertextEntity + RecordableEntity,
+{
+    fn discard_keyswitch_lwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        ksk: &KeyswitchKey,
+    ) -> Result<(), LweCiphertextDiscardingKeyswitchError<Self::EngineError>> {
+        let serialization_error = |error| {
+            LweCiphertextDiscardingKeyswitchError::Engine(RecordingError::Serialization(error))
+        };
+        let ksk_id = self.record_input(ksk).map_err(serialization_error)?;
+        let input_id = self.record_input(input).map_err(serialization_error)?;
+        let output_id = self.record_input(output).map_err(serialization_error)?;
+        self.engine
+            .discard_keyswitch_lwe_ciphertext(output, input, ksk)
+            .map_err(|error| error.map_engine_error(RecordingError::Engine))?;
+        let result_id = self.record_output(output).map_err(serialization_error)?;
+        self.trace
+            .operations
+            .push(RecordedOperation::LweCiphertextDiscardingKeyswitch {
+                ksk: ksk_id,
+                input: input_id,
+                output: output_id,
+                result: result_id,
+            });
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        ksk: &KeyswitchKey,
+    ) {
+        let ksk_id = self.record_input(ksk).unwrap();
+        let input_id = self.record_input(input).unwrap();
+        let output_id = self.record_input(output).unwrap();
+        self.engine
+            .discard_keyswitch_lwe_ciphertext_unchecked(output, input, ksk);
+        let result_id = self.record_output(output).unwrap();
+        self.trace
+            .operations
+            .push(RecordedOperation::LweCiphertextDiscardingKeyswitch {
+                ksk: ksk_id,
+                input: input_id,
+                output: output_id,
+                result: result_id,
+            });
+    }
+}
+
+/// # Panics
+/// The unchecked entry point panics if an entity can not be serialized in the trace.
+impl<Engine, BootstrapKey, Accumulator, InputCiphertext, OutputCiphertext>
+    LweCiphertextDiscardingBootstrapEngine<
+        BootstrapKey,
+        Accumulator,
+        InputCiphertext,
+        OutputCiphertext,
+    > for RecordingEngine<Engine>
+where
+    Engine: LweCiphertextDiscardingBootstrapEngine<
+        BootstrapKey,
+        Accumulator,
+        InputCiphertext,
+        OutputCiphertext,
+    >,
+    BootstrapKey: LweBootstrapKeyEntity + RecordableEntity,
+    Accumulator: GlweCiphertextEntity + RecordableEntity,
+    InputCiphertext: LweCiphertextEntity + RecordableEntity,
+    OutputCiphertext: LweCiphertextEntity + RecordableEntity,
+{
+    fn discard_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        acc: &Accumulator,
+        bsk: &BootstrapKey,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapError<Self::EngineError>> {
+        let serialization_error = |error| {
+            LweCiphertextDiscardingBootstrapError::Engine(RecordingError::Serialization(error))
+        };
+        let bsk_id = self.record_input(bsk).map_err(serialization_error)?;
+        let acc_id = self.record_input(acc).map_err(serialization_error)?;
+        let input_id = self.record_input(input).map_err(serialization_error)?;
+        let output_id = self.record_input(output).map_err(serialization_error)?;
+        self.engine
+            .discard_bootstrap_lwe_ciphertext(output, input, acc, bsk)
+            .map_err(|error| error.map_engine_error(RecordingError::Engine))?;
+        let result_id = self.record_output(output).map_err(serialization_error)?;
+        self.trace
+            .operations
+            .push(RecordedOperation::LweCiphertextDiscardingBootstrap {
+                bsk: bsk_id,
+                acc: acc_id,
+                input: input_id,
+                output: output_id,
+                result: result_id,
+            });
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        acc: &Accumulator,
+        bsk: &BootstrapKey,
+    ) {
+        let bsk_id = self.record_input(bsk).unwrap();
+        let acc_id = self.record_input(acc).unwrap();
+        let input_id = self.record_input(input).unwrap();
+        let output_id = self.record_input(output).unwrap();
+        self.engine
+            .discard_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk);
+        let result_id = self.record_output(output).unwrap();
+        self.trace
+            .operations
+            .push(RecordedOperation::LweCiphertextDiscardingBootstrap {
+                bsk: bsk_id,
+                acc: acc_id,
+                input: input_id,
+                output: output_id,
+                result: result_id,
+            });
+    }
+}
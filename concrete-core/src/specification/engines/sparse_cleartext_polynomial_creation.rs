@@ -0,0 +1,69 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::SparseCleartextPolynomialEntity;
+use concrete_commons::parameters::{MonomialIndex, PolynomialSize};
+
+engine_error! {
+    SparseCleartextPolynomialCreationError for SparseCleartextPolynomialCreationEngine @
+    MonomialCountMismatch => "The number of values and the number of monomial indices must be the \
+                              same.",
+    MonomialIndexTooLarge => "The monomial indices must be smaller than the polynomial size."
+}
+
+impl<EngineError: std::error::Error> SparseCleartextPolynomialCreationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<Value>(
+        values: &[Value],
+        indices: &[MonomialIndex],
+        polynomial_size: PolynomialSize,
+    ) -> Result<(), Self> {
+        if values.len() != indices.len() {
+            return Err(Self::MonomialCountMismatch);
+        }
+        if indices.iter().any(|index| index.0 >= polynomial_size.0) {
+            return Err(Self::MonomialIndexTooLarge);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines creating sparse cleartext polynomials from arbitrary values.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a sparse cleartext polynomial
+/// operating on polynomials of size `polynomial_size`, whose coefficient of degree `indices[i]`
+/// is `values[i]`, and whose other coefficients are zero. If an index appears several times, the
+/// associated values are added together. By arbitrary here, we mean that `Value` can be any type
+/// that suits the backend implementor (an integer, a struct wrapping integers, a struct wrapping
+/// foreign data or any other thing).
+///
+/// # Formal Definition
+pub trait SparseCleartextPolynomialCreationEngine<Value, SparseCleartextPolynomial>:
+    AbstractEngine
+where
+    SparseCleartextPolynomial: SparseCleartextPolynomialEntity,
+{
+    /// Creates a sparse cleartext polynomial from a slice of arbitrary values and the indices of
+    /// the associated monomials.
+    fn create_sparse_cleartext_polynomial_from(
+        &mut self,
+        values: &[Value],
+        indices: &[MonomialIndex],
+        polynomial_size: PolynomialSize,
+    ) -> Result<SparseCleartextPolynomial, SparseCleartextPolynomialCreationError<Self::EngineError>>;
+
+    /// Unsafely creates a sparse cleartext polynomial from a slice of arbitrary values and the
+    /// indices of the associated monomials.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`SparseCleartextPolynomialCreationError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn create_sparse_cleartext_polynomial_from_unchecked(
+        &mut self,
+        values: &[Value],
+        indices: &[MonomialIndex],
+        polynomial_size: PolynomialSize,
+    ) -> SparseCleartextPolynomial;
+}
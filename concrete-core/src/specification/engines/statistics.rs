@@ -0,0 +1,469 @@
+//! A module containing an engine gathering statistics on the operations it executes.
+//!
+//! The [`StatisticsEngine`] wraps an engine, and counts the operations executed through it, along
+//! with the cumulative time spent in every kind of operation. It can also track the peak size of
+//! the scratch memory of the wrapped engine, for the engines exposing it. The statistics can be
+//! queried and reset at any time, which makes it possible to export them periodically to a
+//! monitoring system without instrumenting the calling code.
+//!
+//! The operations covered are the ones exposed by the [`dynamic`](super::dynamic) module:
+//!
+//! + The encryption of LWE ciphertexts.
+//! + The decryption of LWE ciphertexts.
+//! + The trivial encryption of LWE ciphertexts.
+//! + The (discarding) addition of LWE ciphertexts.
+//! + The (discarding) keyswitch of LWE ciphertexts.
+//! + The (discarding) bootstrap of LWE ciphertexts.
+//!
+//! ```
+//! use concrete_commons::dispersion::Variance;
+//! use concrete_commons::parameters::LweDimension;
+//! use concrete_core::prelude::*;
+//! use concrete_core::specification::engines::statistics::{OperationKind, StatisticsEngine};
+//! # use std::error::Error;
+//!
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+//! let lwe_dimension = LweDimension(2);
+//! let noise = Variance(2_f64.powf(-25.));
+//!
+//! // Unix seeder must be given a secret input.
+//! // Here we just give it 0, which is totally unsafe.
+//! const UNSAFE_SECRET: u128 = 0;
+//! let engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+//! let mut engine = StatisticsEngine::from_engine(engine);
+//! let key: LweSecretKey64 = engine
+//!     .engine_mut()
+//!     .generate_new_lwe_secret_key(lwe_dimension)?;
+//! let plaintext = engine.engine_mut().create_plaintext_from(&(3_u64 << 50))?;
+//!
+//! let ciphertext_1 = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+//! let ciphertext_2 = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+//! let mut output = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+//! engine.discard_add_lwe_ciphertext(&mut output, &ciphertext_1, &ciphertext_2)?;
+//!
+//! let statistics = engine.statistics();
+//! assert_eq!(
+//!     statistics
+//!         .operation(OperationKind::LweCiphertextEncryption)
+//!         .count,
+//!     3
+//! );
+//! assert_eq!(
+//!     statistics
+//!         .operation(OperationKind::LweCiphertextDiscardingAddition)
+//!         .count,
+//!     1
+//! );
+//! assert_eq!(statistics.total_count(), 4);
+//!
+//! // The statistics can be exported, then reset.
+//! let statistics = engine.take_statistics();
+//! for (kind, operation) in statistics.iter() {
+//!     println!("{} {} {:?}", kind.name(), operation.count, operation.total_time);
+//! }
+//! assert_eq!(engine.statistics().total_count(), 0);
+//! #
+//! # Ok(())
+//! # }
+//! ```
+use super::sealed::AbstractEngineSeal;
+use super::{
+    AbstractEngine, LweCiphertextDecryptionEngine, LweCiphertextDecryptionError,
+    LweCiphertextDiscardingAdditionEngine, LweCiphertextDiscardingAdditionError,
+    LweCiphertextDiscardingBootstrapEngine, LweCiphertextDiscardingBootstrapError,
+    LweCiphertextDiscardingKeyswitchEngine, LweCiphertextDiscardingKeyswitchError,
+    LweCiphertextEncryptionEngine, LweCiphertextEncryptionError,
+    LweCiphertextTrivialEncryptionEngine, LweCiphertextTrivialEncryptionError,
+};
+use crate::specification::entities::{
+    GlweCiphertextEntity, LweBootstrapKeyEntity, LweCiphertextEntity, LweKeyswitchKeyEntity,
+    LweSecretKeyEntity, PlaintextEntity,
+};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::LweSize;
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+/// The kinds of operations counted by a [`StatisticsEngine`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum OperationKind {
+    /// The [`LweCiphertextEncryptionEngine`] operations.
+    LweCiphertextEncryption,
+    /// The [`LweCiphertextDecryptionEngine`] operations.
+    LweCiphertextDecryption,
+    /// The [`LweCiphertextTrivialEncryptionEngine`] operations.
+    LweCiphertextTrivialEncryption,
+    /// The [`LweCiphertextDiscardingAdditionEngine`] operations.
+    LweCiphertextDiscardingAddition,
+    /// The [`LweCiphertextDiscardingKeyswitchEngine`] operations.
+    LweCiphertextDiscardingKeyswitch,
+    /// The [`LweCiphertextDiscardingBootstrapEngine`] operations.
+    LweCiphertextDiscardingBootstrap,
+}
+
+impl OperationKind {
+    /// Returns a snake case name of the operation kind, suited to label exported metrics.
+    pub fn name(&self) -> &'static str {
+        match self {
+            OperationKind::LweCiphertextEncryption => "lwe_ciphertext_encryption",
+            OperationKind::LweCiphertextDecryption => "lwe_ciphertext_decryption",
+            OperationKind::LweCiphertextTrivialEncryption => "lwe_ciphertext_trivial_encryption",
+            OperationKind::LweCiphertextDiscardingAddition => "lwe_ciphertext_discarding_addition",
+            OperationKind::LweCiphertextDiscardingKeyswitch => {
+                "lwe_ciphertext_discarding_keyswitch"
+            }
+            OperationKind::LweCiphertextDiscardingBootstrap => {
+                "lwe_ciphertext_discarding_bootstrap"
+            }
+        }
+    }
+}
+
+/// The statistics gathered on one kind of operations.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct OperationStatistics {
+    /// The number of operations executed.
+    pub count: u64,
+    /// The cumulative time spent in the operations.
+    pub total_time: Duration,
+}
+
+/// The statistics gathered by a [`StatisticsEngine`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EngineStatistics {
+    operations: BTreeMap<OperationKind, OperationStatistics>,
+    peak_scratch_memory: usize,
+}
+
+impl EngineStatistics {
+    /// Returns the statistics of a kind of operations, which are zero if no such operation was
+    /// executed.
+    pub fn operation(&self, kind: OperationKind) -> OperationStatistics {
+        self.operations.get(&kind).copied().unwrap_or_default()
+    }
+
+    /// Returns an iterator over the kinds of operations executed, and their statistics.
+    pub fn iter(&self) -> impl Iterator<Item = (OperationKind, OperationStatistics)> + '_ {
+        self.operations
+            .iter()
+            .map(|(kind, statistics)| (*kind, *statistics))
+    }
+
+    /// Returns the total number of operations executed.
+    pub fn total_count(&self) -> u64 {
+        self.operations
+            .values()
+            .map(|statistics| statistics.count)
+            .sum()
+    }
+
+    /// Returns the cumulative time spent in all the operations.
+    pub fn total_time(&self) -> Duration {
+        self.operations
+            .values()
+            .map(|statistics| statistics.total_time)
+            .sum()
+    }
+
+    /// Returns the peak size of the scratch memory of the wrapped engine, in bytes, as measured
+    /// after every operation. This is zero if the engine was not given a scratch memory probe.
+    pub fn peak_scratch_memory(&self) -> usize {
+        self.peak_scratch_memory
+    }
+}
+
+/// An engine gathering statistics on the operations executed by the engine it wraps.
+///
+/// Only the operations which succeed are counted, and the operations executed directly with the
+/// wrapped engine are not counted. The errors of the wrapped engine are returned unchanged.
+pub struct StatisticsEngine<Engine> {
+    engine: Engine,
+    statistics: EngineStatistics,
+    scratch_memory_probe: Option<fn(&Engine) -> usize>,
+}
+
+impl<Engine> StatisticsEngine<Engine> {
+    /// Wraps an engine, with empty statistics.
+    pub fn from_engine(engine: Engine) -> Self {
+        StatisticsEngine {
+            engine,
+            statistics: EngineStatistics::default(),
+            scratch_memory_probe: None,
+        }
+    }
+
+    /// Sets the function returning the current size of the scratch memory of the wrapped engine,
+    /// in bytes, which is called after every operation to track its peak.
+    pub fn with_scratch_memory_probe(mut self, probe: fn(&Engine) -> usize) -> Self {
+        self.scratch_memory_probe = Some(probe);
+        self
+    }
+
+    /// Returns the statistics gathered so far.
+    pub fn statistics(&self) -> &EngineStatistics {
+        &self.statistics
+    }
+
+    /// Resets the statistics.
+    pub fn reset_statistics(&mut self) {
+        self.statistics = EngineStatistics::default();
+    }
+
+    /// Returns the statistics gathered so far, and resets them.
+    pub fn take_statistics(&mut self) -> EngineStatistics {
+        std::mem::take(&mut self.statistics)
+    }
+
+    /// Returns a mutable reference to the wrapped engine.
+    ///
+    /// The operations executed with the returned engine are not counted.
+    pub fn engine_mut(&mut self) -> &mut Engine {
+        &mut self.engine
+    }
+
+    /// Returns the wrapped engine.
+    pub fn into_engine(self) -> Engine {
+        self.engine
+    }
+
+    // Counts an operation which started at `start`, and updates the peak scratch memory.
+    fn record(&mut self, kind: OperationKind, start: Instant) {
+        let elapsed = start.elapsed();
+        let statistics = self.statistics.operations.entry(kind).or_default();
+        statistics.count += 1;
+        statistics.total_time += elapsed;
+        if let Some(probe) = self.scratch_memory_probe {
+            let scratch_memory = probe(&self.engine);
+            self.statistics.peak_scratch_memory =
+                self.statistics.peak_scratch_memory.max(scratch_memory);
+        }
+    }
+}
+
+impl<Engine: AbstractEngine> AbstractEngineSeal for StatisticsEngine<Engine> {}
+
+impl<Engine: AbstractEngine> AbstractEngine for StatisticsEngine<Engine> {
+    type EngineError = Engine::EngineError;
+    type Parameters = Engine::Parameters;
+
+    fn new(parameters: Self::Parameters) -> Result<Self, Self::EngineError> {
+        Engine::new(parameters).map(StatisticsEngine::from_engine)
+    }
+}
+
+impl<Engine, SecretKey, Plaintext, Ciphertext>
+    LweCiphertextEncryptionEngine<SecretKey, Plaintext, Ciphertext> for StatisticsEngine<Engine>
+where
+    Engine: LweCiphertextEncryptionEngine<SecretKey, Plaintext, Ciphertext>,
+    SecretKey: LweSecretKeyEntity,
+    Plaintext: PlaintextEntity,
+    Ciphertext: LweCiphertextEntity,
+{
+    fn encrypt_lwe_ciphertext(
+        &mut self,
+        key: &SecretKey,
+        input: &Plaintext,
+        noise: Variance,
+    ) -> Result<Ciphertext, LweCiphertextEncryptionError<Self::EngineError>> {
+        let start = Instant::now();
+        let output = self.engine.encrypt_lwe_ciphertext(key, input, noise)?;
+        self.record(OperationKind::LweCiphertextEncryption, start);
+        Ok(output)
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &SecretKey,
+        input: &Plaintext,
+        noise: Variance,
+    ) -> Ciphertext {
+        let start = Instant::now();
+        let output = self
+            .engine
+            .encrypt_lwe_ciphertext_unchecked(key, input, noise);
+        self.record(OperationKind::LweCiphertextEncryption, start);
+        output
+    }
+}
+
+impl<Engine, SecretKey, Ciphertext, Plaintext>
+    LweCiphertextDecryptionEngine<SecretKey, Ciphertext, Plaintext> for StatisticsEngine<Engine>
+where
+    Engine: LweCiphertextDecryptionEngine<SecretKey, Ciphertext, Plaintext>,
+    SecretKey: LweSecretKeyEntity,
+    Ciphertext: LweCiphertextEntity,
+    Plaintext: PlaintextEntity,
+{
+    fn decrypt_lwe_ciphertext(
+        &mut self,
+        key: &SecretKey,
+        input: &Ciphertext,
+    ) -> Result<Plaintext, LweCiphertextDecryptionError<Self::EngineError>> {
+        let start = Instant::now();
+        let output = self.engine.decrypt_lwe_ciphertext(key, input)?;
+        self.record(OperationKind::LweCiphertextDecryption, start);
+        Ok(output)
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &SecretKey,
+        input: &Ciphertext,
+    ) -> Plaintext {
+        let start = Instant::now();
+        let output = self.engine.decrypt_lwe_ciphertext_unchecked(key, input);
+        self.record(OperationKind::LweCiphertextDecryption, start);
+        output
+    }
+}
+
+impl<Engine, Plaintext, Ciphertext> LweCiphertextTrivialEncryptionEngine<Plaintext, Ciphertext>
+    for StatisticsEngine<Engine>
+where
+    Engine: LweCiphertextTrivialEncryptionEngine<Plaintext, Ciphertext>,
+    Plaintext: PlaintextEntity,
+    Ciphertext: LweCiphertextEntity,
+{
+    fn trivially_encrypt_lwe_ciphertext(
+        &mut self,
+        lwe_size: LweSize,
+        input: &Plaintext,
+    ) -> Result<Ciphertext, LweCiphertextTrivialEncryptionError<Self::EngineError>> {
+        let start = Instant::now();
+        let output = self
+            .engine
+            .trivially_encrypt_lwe_ciphertext(lwe_size, input)?;
+        self.record(OperationKind::LweCiphertextTrivialEncryption, start);
+        Ok(output)
+    }
+
+    unsafe fn trivially_encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        lwe_size: LweSize,
+        input: &Plaintext,
+    ) -> Ciphertext {
+        let start = Instant::now();
+        let output = self
+            .engine
+            .trivially_encrypt_lwe_ciphertext_unchecked(lwe_size, input);
+        self.record(OperationKind::LweCiphertextTrivialEncryption, start);
+        output
+    }
+}
+
+impl<Engine, InputCiphertext, OutputCiphertext>
+    LweCiphertextDiscardingAdditionEngine<InputCiphertext, OutputCiphertext>
+    for StatisticsEngine<Engine>
+where
+    Engine: LweCiphertextDiscardingAdditionEngine<InputCiphertext, OutputCiphertext>,
+    InputCiphertext: LweCiphertextEntity,
+    OutputCiphertext: LweCiphertextEntity,
+{
+    fn discard_add_lwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input_1: &InputCiphertext,
+        input_2: &InputCiphertext,
+    ) -> Result<(), LweCiphertextDiscardingAdditionError<Self::EngineError>> {
+        let start = Instant::now();
+        self.engine
+            .discard_add_lwe_ciphertext(output, input_1, input_2)?;
+        self.record(OperationKind::LweCiphertextDiscardingAddition, start);
+        Ok(())
+    }
+
+    unsafe fn discard_add_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input_1: &InputCiphertext,
+        input_2: &InputCiphertext,
+    ) {
+        let start = Instant::now();
+        self.engine
+            .discard_add_lwe_ciphertext_unchecked(output, input_1, input_2);
+        self.record(OperationKind::LweCiphertextDiscardingAddition, start);
+    }
+}
+
+impl<Engine, KeyswitchKey, InputCiphertext, OutputCiphertext>
+    LweCiphertextDiscardingKeyswitchEngine<KeyswitchKey, InputCiphertext, OutputCiphertext>
+    for StatisticsEngine<Engine>
+where
+    Engine: LweCiphertextDiscardingKeyswitchEngine<KeyswitchKey, InputCiphertext, OutputCiphertext>,
+    KeyswitchKey: LweKeyswitchKeyEntity,
+    InputCiphertext: LweCiphertextEntity,
+    OutputCiphertext: LweCiphertextEntity,
+{
+    fn discard_keyswitch_lwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        ksk: &KeyswitchKey,
+    ) -> Result<(), LweCiphertextDiscardingKeyswitchError<Self::EngineError>> {
+        let start = Instant::now();
+        self.engine
+            .discard_keyswitch_lwe_ciphertext(output, input, ksk)?;
+        self.record(OperationKind::LweCiphertextDiscardingKeyswitch, start);
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        ksk: &KeyswitchKey,
+    ) {
+        let start = Instant::now();
+        self.engine
+            .discard_keyswitch_lwe_ciphertext_unchecked(output, input, ksk);
+        self.record(OperationKind::LweCiphertextDiscardingKeyswitch, start);
+    }
+}
+
+impl<Engine, BootstrapKey, Accumulator, InputCiphertext, OutputCiphertext>
+    LweCiphertextDiscardingBootstrapEngine<
+        BootstrapKey,
+        Accumulator,
+        InputCiphertext,
+        OutputCiphertext,
+    > for StatisticsEngine<Engine>
+where
+    Engine: LweCiphertextDiscardingBootstrapEngine<
+        BootstrapKey,
+        Accumulator,
+        InputCiphertext,
+        OutputCiphertext,
+    >,
+    BootstrapKey: LweBootstrapKeyEntity,
+    Accumulator: GlweCiphertextEntity,
+    InputCiphertext: LweCiphertextEntity,
+    OutputCiphertext: LweCiphertextEntity,
+{
+    fn discard_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        acc: &Accumulator,
+        bsk: &BootstrapKey,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapError<Self::EngineError>> {
+        let start = Instant::now();
+        self.engine
+            .discard_bootstrap_lwe_ciphertext(output, input, acc, bsk)?;
+        self.record(OperationKind::LweCiphertextDiscardingBootstrap, start);
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        acc: &Accumulator,
+        bsk: &BootstrapKey,
+    ) {
+        let start = Instant::now();
+        self.engine
+            .discard_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk);
+        self.record(OperationKind::LweCiphertextDiscardingBootstrap, start);
+    }
+}
@@ -0,0 +1,45 @@
+use super::engine_error;
+use concrete_commons::parameters::GlweSize;
+
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{PlaintextVectorEntity, TrivialGlweCiphertextEntity};
+
+engine_error! {
+    TrivialGlweCiphertextCreationError for TrivialGlweCiphertextCreationEngine @
+}
+
+/// A trait for engines creating trivial GLWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a trivial GLWE ciphertext
+/// containing the `input` plaintext vector with the requested `glwe_size`. The polynomial size of
+/// the output is the number of plaintexts in the `input` vector.
+///
+/// # Formal Definition
+///
+/// cf [`here`](`crate::specification::entities::TrivialGlweCiphertextEntity`)
+pub trait TrivialGlweCiphertextCreationEngine<PlaintextVector, Ciphertext>: AbstractEngine
+where
+    PlaintextVector: PlaintextVectorEntity,
+    Ciphertext: TrivialGlweCiphertextEntity,
+{
+    /// Creates a trivial GLWE ciphertext.
+    fn create_trivial_glwe_ciphertext(
+        &mut self,
+        glwe_size: GlweSize,
+        input: &PlaintextVector,
+    ) -> Result<Ciphertext, TrivialGlweCiphertextCreationError<Self::EngineError>>;
+
+    /// Unsafely creates a trivial GLWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`TrivialGlweCiphertextCreationError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn create_trivial_glwe_ciphertext_unchecked(
+        &mut self,
+        glwe_size: GlweSize,
+        input: &PlaintextVector,
+    ) -> Ciphertext;
+}
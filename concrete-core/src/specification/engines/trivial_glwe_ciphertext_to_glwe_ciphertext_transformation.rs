@@ -0,0 +1,48 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+
+use crate::specification::entities::{GlweCiphertextEntity, TrivialGlweCiphertextEntity};
+
+engine_error! {
+    TrivialGlweCiphertextToGlweCiphertextTransformationError
+    for TrivialGlweCiphertextToGlweCiphertextTransformationEngine @
+}
+
+/// A trait for engines transforming trivial GLWE ciphertexts into GLWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation moves the existing trivial GLWE ciphertext
+/// into a GLWE ciphertext.
+///
+/// # Formal Definition
+///
+/// A trivial GLWE ciphertext already is a valid GLWE ciphertext under any GLWE secret key of the
+/// same dimension, hence the transformation leaves the ciphertext unchanged.
+pub trait TrivialGlweCiphertextToGlweCiphertextTransformationEngine<
+    InputCiphertext,
+    OutputCiphertext,
+>: AbstractEngine where
+    InputCiphertext: TrivialGlweCiphertextEntity,
+    OutputCiphertext: GlweCiphertextEntity,
+{
+    /// Does the transformation of the trivial GLWE ciphertext into a GLWE ciphertext.
+    fn transform_trivial_glwe_ciphertext_to_glwe_ciphertext(
+        &mut self,
+        trivial_glwe_ciphertext: InputCiphertext,
+    ) -> Result<
+        OutputCiphertext,
+        TrivialGlweCiphertextToGlweCiphertextTransformationError<Self::EngineError>,
+    >;
+
+    /// Unsafely transforms a trivial GLWE ciphertext into a GLWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`TrivialGlweCiphertextToGlweCiphertextTransformationError`].
+    /// For safety concerns _specific_ to an engine, refer to the implementer safety section.
+    unsafe fn transform_trivial_glwe_ciphertext_to_glwe_ciphertext_unchecked(
+        &mut self,
+        trivial_glwe_ciphertext: InputCiphertext,
+    ) -> OutputCiphertext;
+}
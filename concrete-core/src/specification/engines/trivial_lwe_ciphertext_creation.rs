@@ -0,0 +1,44 @@
+use super::engine_error;
+use concrete_commons::parameters::LweSize;
+
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{PlaintextEntity, TrivialLweCiphertextEntity};
+
+engine_error! {
+    TrivialLweCiphertextCreationError for TrivialLweCiphertextCreationEngine @
+}
+
+/// A trait for engines creating trivial LWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a trivial LWE ciphertext containing
+/// the `input` plaintext with the requested `lwe_size`.
+///
+/// # Formal Definition
+///
+/// cf [`here`](`crate::specification::entities::TrivialLweCiphertextEntity`)
+pub trait TrivialLweCiphertextCreationEngine<Plaintext, Ciphertext>: AbstractEngine
+where
+    Plaintext: PlaintextEntity,
+    Ciphertext: TrivialLweCiphertextEntity,
+{
+    /// Creates a trivial LWE ciphertext.
+    fn create_trivial_lwe_ciphertext(
+        &mut self,
+        lwe_size: LweSize,
+        input: &Plaintext,
+    ) -> Result<Ciphertext, TrivialLweCiphertextCreationError<Self::EngineError>>;
+
+    /// Unsafely creates a trivial LWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`TrivialLweCiphertextCreationError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn create_trivial_lwe_ciphertext_unchecked(
+        &mut self,
+        lwe_size: LweSize,
+        input: &Plaintext,
+    ) -> Ciphertext;
+}
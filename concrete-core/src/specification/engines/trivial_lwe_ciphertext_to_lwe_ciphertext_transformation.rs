@@ -0,0 +1,47 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+
+use crate::specification::entities::{LweCiphertextEntity, TrivialLweCiphertextEntity};
+
+engine_error! {
+    TrivialLweCiphertextToLweCiphertextTransformationError
+    for TrivialLweCiphertextToLweCiphertextTransformationEngine @
+}
+
+/// A trait for engines transforming trivial LWE ciphertexts into LWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation moves the existing trivial LWE ciphertext
+/// into an LWE ciphertext.
+///
+/// # Formal Definition
+///
+/// A trivial LWE ciphertext already is a valid LWE ciphertext under any LWE secret key of the
+/// same dimension, hence the transformation leaves the ciphertext unchanged.
+pub trait TrivialLweCiphertextToLweCiphertextTransformationEngine<InputCiphertext, OutputCiphertext>:
+    AbstractEngine
+where
+    InputCiphertext: TrivialLweCiphertextEntity,
+    OutputCiphertext: LweCiphertextEntity,
+{
+    /// Does the transformation of the trivial LWE ciphertext into an LWE ciphertext.
+    fn transform_trivial_lwe_ciphertext_to_lwe_ciphertext(
+        &mut self,
+        trivial_lwe_ciphertext: InputCiphertext,
+    ) -> Result<
+        OutputCiphertext,
+        TrivialLweCiphertextToLweCiphertextTransformationError<Self::EngineError>,
+    >;
+
+    /// Unsafely transforms a trivial LWE ciphertext into an LWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`TrivialLweCiphertextToLweCiphertextTransformationError`].
+    /// For safety concerns _specific_ to an engine, refer to the implementer safety section.
+    unsafe fn transform_trivial_lwe_ciphertext_to_lwe_ciphertext_unchecked(
+        &mut self,
+        trivial_lwe_ciphertext: InputCiphertext,
+    ) -> OutputCiphertext;
+}
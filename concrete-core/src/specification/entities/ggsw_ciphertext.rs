@@ -33,7 +33,7 @@ use concrete_commons::parameters::{
 /// \mathcal{R}\_q^{\ell \cdot (k+1)}$.
 ///
 /// This type of ciphertext contains a lot of redundancy ($k+1$ GLev ciphertexts -- definition
-/// below -- each encrypting the same plaintext times an element of the secret key) .
+/// linked below -- each encrypting the same plaintext times an element of the secret key) .
 ///
 /// ## Levels and decomposition base
 /// A GGSW ciphertext contains GLev ciphertexts that are parametrized with an
@@ -90,71 +90,7 @@ use concrete_commons::parameters::{
 ///
 /// # GLev Ciphertext
 ///
-/// **Remark:** This type of ciphertexts is not yet directly exposed in the library but its
-/// description helps understanding GGSW ciphertext.
-///
-/// A GLev ciphertext is an encryption of a polynomial plaintext.
-/// It is a vector of GLev ciphertexts.
-/// It is a generalization of both Lev ciphertexts and RLev ciphertexts.
-///
-/// We call $q$ the ciphertext modulus.
-/// We use the notation $\mathcal{R}\_q$ for the following cyclotomic ring:
-/// $\mathbb{Z}\_q\[X\]/\left\langle X^N + 1\right\rangle$ where $N\in\mathbb{N}$ is a power of two.
-///
-/// We indicate a GLev ciphertext of a polynomial plaintext $\mathsf{PT} \in\mathcal{R}\_q^{k+1}$ as
-/// the following vector: $$\overline{\mathsf{CT}} = \left( \mathsf{CT}\_0 , \cdots ,
-/// \mathsf{CT}\_{\ell-1} \right) \in \mathsf{GLev}\_{\vec{S}}^{\beta, \ell}\left(\mathsf{PT}\right)
-/// \subseteq \mathcal{R}\_q^{(k+1)\cdot \ell}$$
-///
-/// Where $k=|\vec{S}|$ and for all $0\le i <\ell$, we have $\mathsf{CT}\_i \in
-/// \mathsf{GLWE}\_{\vec{S}}\left( \left\lfloor\mathsf{PT}\cdot \frac{q}{\beta^{i+1}} \right\rceil
-/// \right)\subseteq  \mathcal{R}\_q^{k+1}$ (we are using the encoding in the MSB with $\Delta =
-/// \frac{q}{\beta^{i+1}}$).
-///
-/// This type of ciphertext contains redundancy ($\ell$
-/// [`GLWE ciphertext`](`crate::specification::entities::GlweCiphertextEntity`),
-/// each encrypting the same plaintext times a different scaling factor).
-///
-/// ## Decomposition base
-/// A GLev ciphertext is parametrized with a decomposition base $\beta$, generally chosen as a power
-/// of 2.
-///
-/// ## Levels
-/// A GLev ciphertext contains a number of levels $\ell$ from level $0$ to level $\ell-1$.
-///
-/// ## Secret Key
-/// A GLev ciphertext is encrypted under a
-/// [`GLWE secret key`](`crate::specification::entities::GlweSecretKeyEntity`).
-///
-/// ## GLev Encryption
-/// ###### inputs:
-/// - $\mathsf{PT}\in \mathcal{R}\_q$: a polynomial plaintext
-/// - $\vec{S}\in  \mathcal{R}\_q^k$: a
-/// [`GLWE Secret Key`](`crate::specification::entities::GlweSecretKeyEntity`)
-/// - $\mathcal{D\_{\sigma^2,\mu}}$: a normal distribution of variance $\sigma^2$ and a mean of
-///   $\mu$
-/// - $\ell$: number of levels desired
-/// - $\beta$: decomposition base
-///
-/// ###### outputs:
-/// - $\overline{\mathsf{CT}} = \left( \mathsf{CT}\_0 , \cdots , \mathsf{CT}\_{\ell-1} \right) \in
-///   \mathsf{GLev}\_{\vec{S}}^{\beta, \ell}\left(\mathsf{PT}\right) \subseteq
-///   \mathcal{R}\_q^{(k+1)\cdot\ell}$: a GLev ciphertext
-///
-/// ###### algorithm:
-/// 1. for $0\le i < \ell-1$:
-///     - compute $\mathsf{PT}\_i = \left\lfloor\mathsf{PT}\cdot \frac{q}{\beta^{i+1}} \right\rceil
-///       \in
-///    \mathcal{R}\_q$
-///     - compute $\mathsf{CT}\_i \leftarrow \mathsf{GLWE}.\mathsf{encrypt}\left( \mathsf{PT}\_i,
-///    \vec{S} ,\mathcal{D\_{\sigma^2,\mu}} \right)$
-/// 2. output $\overline{\mathsf{CT}} = \left( \mathsf{CT}\_0 , \cdots , \mathsf{CT}\_{\ell-1}
-/// \right)$
-///
-/// ## GLev Decryption
-/// Simply use the
-/// [`GLWE decryption algorithm`](`crate::specification::engines::GlweCiphertextDecryptionEngine`)
-/// on one of the GLWE ciphertexts contained in the GLev ciphertext.
+/// cf [`here`](`crate::specification::entities::GlevCiphertextEntity`)
 pub trait GgswCiphertextEntity: AbstractEntity<Kind = GgswCiphertextKind> {
     /// Returns the GLWE dimension of the ciphertext.
     fn glwe_dimension(&self) -> GlweDimension;
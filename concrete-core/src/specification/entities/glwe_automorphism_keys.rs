@@ -0,0 +1,34 @@
+use crate::specification::entities::markers::GlweAutomorphismKeysKind;
+use crate::specification::entities::AbstractEntity;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+
+/// A trait implemented by types embodying a set of GLWE automorphism keys.
+///
+/// # Formal Definition
+///
+/// ## GLWE Automorphism Keys
+///
+/// For an odd integer $t$, the automorphism $\tau\_t$ maps a polynomial $P(X)$ to $P(X^t)$.
+/// Applying it to the polynomials of a GLWE ciphertext encrypting $M(X)$ under the key
+/// $\vec{S}(X) \in \mathcal{R}\_{N}^{k}$ gives a GLWE ciphertext encrypting $M(X^t)$ under the key
+/// $\vec{S}(X^t)$. An automorphism key allows to switch it back to the key $\vec{S}(X)$.
+///
+/// The set contains one key for each $t = 2^l + 1$, with $1 \leq l \leq \log\_2(N)$. The key of
+/// $\tau\_t$ is made of $k \cdot \ell$ GLWE ciphertexts encrypted under $\vec{S}$, where $\ell$ is
+/// the number of decomposition levels: for each polynomial $S\_j$ of the key and each level
+/// $i \in [1, \ell]$, the message $S\_j(X^t) \cdot q / B^i$, with $B$ the decomposition base.
+pub trait GlweAutomorphismKeysEntity: AbstractEntity<Kind = GlweAutomorphismKeysKind> {
+    /// Returns the GLWE dimension of the keys.
+    fn glwe_dimension(&self) -> GlweDimension;
+
+    /// Returns the polynomial size of the keys.
+    fn polynomial_size(&self) -> PolynomialSize;
+
+    /// Returns the number of decomposition levels of the keys.
+    fn decomposition_level_count(&self) -> DecompositionLevelCount;
+
+    /// Returns the logarithm of the base used in the keys.
+    fn decomposition_base_log(&self) -> DecompositionBaseLog;
+}
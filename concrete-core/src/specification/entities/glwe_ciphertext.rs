@@ -1,6 +1,6 @@
 use crate::specification::entities::markers::GlweCiphertextKind;
 use crate::specification::entities::AbstractEntity;
-use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+use concrete_commons::parameters::{CiphertextModulus, GlweDimension, PolynomialSize};
 
 /// A trait implemented by types embodying a GLWE ciphertext.
 ///
@@ -37,4 +37,11 @@ pub trait GlweCiphertextEntity: AbstractEntity<Kind = GlweCiphertextKind> {
 
     /// Returns the polynomial size of the ciphertext.
     fn polynomial_size(&self) -> PolynomialSize;
+
+    /// Returns the modulus of the ciphertext.
+    ///
+    /// cf [`here`](`crate::specification::entities::LweCiphertextEntity::ciphertext_modulus`)
+    fn ciphertext_modulus(&self) -> CiphertextModulus {
+        CiphertextModulus::Native
+    }
 }
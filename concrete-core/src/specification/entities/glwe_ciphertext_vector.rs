@@ -1,6 +1,8 @@
 use crate::specification::entities::markers::GlweCiphertextVectorKind;
 use crate::specification::entities::AbstractEntity;
-use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+use concrete_commons::parameters::{
+    CiphertextModulus, GlweCiphertextCount, GlweDimension, PolynomialSize,
+};
 
 /// A trait implemented by types embodying a GLWE ciphertext vector.
 ///
@@ -19,4 +21,11 @@ pub trait GlweCiphertextVectorEntity: AbstractEntity<Kind = GlweCiphertextVector
 
     /// Returns the number of ciphertexts in the vector.
     fn glwe_ciphertext_count(&self) -> GlweCiphertextCount;
+
+    /// Returns the modulus of the ciphertexts.
+    ///
+    /// cf [`here`](`crate::specification::entities::GlweCiphertextEntity::ciphertext_modulus`)
+    fn ciphertext_modulus(&self) -> CiphertextModulus {
+        CiphertextModulus::Native
+    }
 }
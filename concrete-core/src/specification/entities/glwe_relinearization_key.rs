@@ -0,0 +1,35 @@
+use crate::specification::entities::markers::GlweRelinearizationKeyKind;
+use crate::specification::entities::AbstractEntity;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+
+/// A trait implemented by types embodying a GLWE relinearization key.
+///
+/// # Formal Definition
+///
+/// ## GLWE Relinearization Key
+///
+/// A GLWE relinearization key allows to switch the tensor product of two GLWE ciphertexts
+/// encrypted under the same key $\vec{S} \in \mathcal{R}^k$, which is encrypted under the
+/// [`GLWE tensor product secret key`](`crate::specification::entities::GlweTensorProductSecretKeyEntity`)
+/// of $\vec{S}$, to a GLWE ciphertext encrypted under $\vec{S}$.
+///
+/// Only the quadratic part of the tensor product key needs to be switched. The key is then made
+/// of $k(k+1)/2 \cdot \ell$ GLWE ciphertexts encrypted under $\vec{S}$, where $\ell$ is the number
+/// of decomposition levels: for each polynomial $S\_i \cdot S\_j$ with $i \le j$ and each level
+/// $l \in [1, \ell]$, the message $S\_i \cdot S\_j \cdot q / B^l$, with $B$ the decomposition
+/// base.
+pub trait GlweRelinearizationKeyEntity: AbstractEntity<Kind = GlweRelinearizationKeyKind> {
+    /// Returns the GLWE dimension of the key.
+    fn glwe_dimension(&self) -> GlweDimension;
+
+    /// Returns the polynomial size of the key.
+    fn polynomial_size(&self) -> PolynomialSize;
+
+    /// Returns the number of decomposition levels of the key.
+    fn decomposition_level_count(&self) -> DecompositionLevelCount;
+
+    /// Returns the logarithm of the base used in the key.
+    fn decomposition_base_log(&self) -> DecompositionBaseLog;
+}
@@ -0,0 +1,44 @@
+use crate::specification::entities::markers::GlweRingReductionKeyKind;
+use crate::specification::entities::AbstractEntity;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+
+/// A trait implemented by types embodying a GLWE ring reduction key.
+///
+/// # Formal Definition
+///
+/// ## GLWE Ring Reduction Key
+///
+/// A GLWE ring reduction key is a keyswitch key between a GLWE key
+/// $\vec{S} \in \mathcal{R}\_{N\_1}^{k}$, with polynomials of size $N\_1$, and a GLWE key
+/// $\vec{S'} \in \mathcal{R}\_{N\_2}^{k'}$, with polynomials of size $N\_2$, where $N\_2$ divides
+/// $N\_1$. With $r = N\_1 / N\_2$, it allows to switch a GLWE ciphertext encrypting
+/// $M(X) = \sum\_{u=0}^{r-1} X^u M\_u(X^r)$ under $\vec{S}$, to a GLWE ciphertext encrypting
+/// $M\_0$ under $\vec{S'}$.
+///
+/// Every polynomial of the input key is split in the same way as
+/// $S\_j(X) = \sum\_{u=0}^{r-1} X^u S\_{j,u}(X^r)$. With $Y = X^r$, the key is made of
+/// $k \cdot r \cdot \ell$ GLWE ciphertexts encrypted under $\vec{S'}$, where $\ell$ is the number
+/// of decomposition levels: for each polynomial $S\_j$ of the input key, each $t \in [0, r)$ and
+/// each level $i \in [1, \ell]$, the message $S\_{j,0} \cdot q / B^i$ if $t = 0$, and
+/// $Y \cdot S\_{j,r-t} \cdot q / B^i$ otherwise, with $B$ the decomposition base.
+pub trait GlweRingReductionKeyEntity: AbstractEntity<Kind = GlweRingReductionKeyKind> {
+    /// Returns the input GLWE dimension of the key.
+    fn input_glwe_dimension(&self) -> GlweDimension;
+
+    /// Returns the input polynomial size of the key.
+    fn input_polynomial_size(&self) -> PolynomialSize;
+
+    /// Returns the output GLWE dimension of the key.
+    fn output_glwe_dimension(&self) -> GlweDimension;
+
+    /// Returns the output polynomial size of the key.
+    fn output_polynomial_size(&self) -> PolynomialSize;
+
+    /// Returns the number of decomposition levels of the key.
+    fn decomposition_level_count(&self) -> DecompositionLevelCount;
+
+    /// Returns the logarithm of the base used in the key.
+    fn decomposition_base_log(&self) -> DecompositionBaseLog;
+}
@@ -0,0 +1,39 @@
+use crate::specification::entities::markers::GlweRingSwitchingKeyKind;
+use crate::specification::entities::AbstractEntity;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+
+/// A trait implemented by types embodying a GLWE ring switching key.
+///
+/// # Formal Definition
+///
+/// ## GLWE Ring Switching Key
+///
+/// A GLWE ring switching key allows to switch a GLWE ciphertext encrypted under a key
+/// $\vec{S'} \in \mathcal{R}\_{N/2}^{k'}$, with polynomials of size $N/2$, to a GLWE ciphertext
+/// encrypted under a key $\vec{S} \in \mathcal{R}\_{N}^{k}$, with polynomials of size $N$. The
+/// message $M(X)$ of the input ciphertext becomes the message $M(X^2)$ of the output ciphertext.
+///
+/// It is made of $k' \cdot \ell$ GLWE ciphertexts encrypted under $\vec{S}$, where $\ell$ is the
+/// number of decomposition levels: for each polynomial $S'\_j$ of the input key and each level
+/// $i \in [1, \ell]$, the message $S'\_j(X^2) \cdot q / B^i$, with $B$ the decomposition base.
+pub trait GlweRingSwitchingKeyEntity: AbstractEntity<Kind = GlweRingSwitchingKeyKind> {
+    /// Returns the input GLWE dimension of the key.
+    fn input_glwe_dimension(&self) -> GlweDimension;
+
+    /// Returns the input polynomial size of the key.
+    fn input_polynomial_size(&self) -> PolynomialSize;
+
+    /// Returns the output GLWE dimension of the key.
+    fn output_glwe_dimension(&self) -> GlweDimension;
+
+    /// Returns the output polynomial size of the key.
+    fn output_polynomial_size(&self) -> PolynomialSize;
+
+    /// Returns the number of decomposition levels of the key.
+    fn decomposition_level_count(&self) -> DecompositionLevelCount;
+
+    /// Returns the logarithm of the base used in the key.
+    fn decomposition_base_log(&self) -> DecompositionBaseLog;
+}
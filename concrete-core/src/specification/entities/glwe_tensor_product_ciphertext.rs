@@ -0,0 +1,26 @@
+use crate::specification::entities::markers::GlweTensorProductCiphertextKind;
+use crate::specification::entities::AbstractEntity;
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+
+/// A trait implemented by types embodying the tensor product of two GLWE ciphertexts.
+///
+/// # Formal Definition
+///
+/// ## GLWE Tensor Product Ciphertext
+///
+/// The tensor product of two GLWE ciphertexts encrypted under the same key $\vec{S}$, of GLWE
+/// dimension $k$, is a GLWE ciphertext of GLWE dimension $k(k+3)/2$, encrypted under the
+/// [`GLWE tensor product secret
+/// key`](`crate::specification::entities::GlweTensorProductSecretKeyEntity`) of $\vec{S}$. It can
+/// be switched back to a GLWE ciphertext encrypted under $\vec{S}$ with a [`GLWE relinearization
+/// key`](`crate::specification::entities::GlweRelinearizationKeyEntity`).
+pub trait GlweTensorProductCiphertextEntity:
+    AbstractEntity<Kind = GlweTensorProductCiphertextKind>
+{
+    /// Returns the GLWE dimension of the ciphertext, that is the GLWE dimension of the tensor
+    /// product secret key.
+    fn glwe_dimension(&self) -> GlweDimension;
+
+    /// Returns the polynomial size of the ciphertext.
+    fn polynomial_size(&self) -> PolynomialSize;
+}
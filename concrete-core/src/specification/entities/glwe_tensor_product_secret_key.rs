@@ -0,0 +1,26 @@
+use crate::specification::entities::markers::GlweTensorProductSecretKeyKind;
+use crate::specification::entities::AbstractEntity;
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+
+/// A trait implemented by types embodying the secret key of GLWE tensor product ciphertexts.
+///
+/// # Formal Definition
+///
+/// ## GLWE Tensor Product Secret Key
+///
+/// Given a GLWE secret key:
+/// $$\vec{S} =\left( S\_0, \ldots, S\_{k-1}\right) \in \mathcal{R}^{k}$$
+/// the tensor product of two GLWE ciphertexts encrypted under $\vec{S}$ is a GLWE ciphertext
+/// encrypted under the following key, made of $k(k+3)/2$ polynomials:
+/// $$\left( S\_0, \ldots, S\_{k-1}, S\_0 \cdot S\_1, \ldots, S\_{k-2} \cdot S\_{k-1}, S\_0^2,
+/// \ldots, S\_{k-1}^2\right)$$
+/// where the products $S\_i \cdot S\_j$ are taken for $i < j$, ordered by $i$ and then by $j$.
+pub trait GlweTensorProductSecretKeyEntity:
+    AbstractEntity<Kind = GlweTensorProductSecretKeyKind>
+{
+    /// Returns the GLWE dimension of the key, that is the number of polynomials in the key.
+    fn glwe_dimension(&self) -> GlweDimension;
+
+    /// Returns the polynomial size of the key.
+    fn polynomial_size(&self) -> PolynomialSize;
+}
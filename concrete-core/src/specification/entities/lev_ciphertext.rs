@@ -0,0 +1,78 @@
+use crate::specification::entities::markers::LevCiphertextKind;
+use crate::specification::entities::AbstractEntity;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+
+/// A trait implemented by types embodying a Lev ciphertext.
+///
+/// # Formal Definition
+///
+/// A Lev ciphertext is an encryption of a plaintext.
+/// It is a vector of [`LWE ciphertexts`](`crate::specification::entities::LweCiphertextEntity`).
+/// It is a specialization of
+/// [`GLev ciphertexts`](`crate::specification::entities::GlevCiphertextEntity`).
+///
+/// We call $q$ the ciphertext modulus.
+///
+/// We indicate a Lev ciphertext of a plaintext $\mathsf{pt} \in\mathbb{Z}\_q$ as the following
+/// vector: $$\overline{\mathsf{ct}} = \left( \mathsf{ct}\_0 , \cdots , \mathsf{ct}\_{\ell-1}
+/// \right) \in \mathsf{Lev}\_{\vec{s}}^{\beta, \ell}\left(\mathsf{pt}\right) \subseteq
+/// \mathbb{Z}\_q^{(n+1)\cdot\ell}$$
+///
+/// Where $n=|\vec{s}|$ and for all $0\le i <\ell$, we have $\mathsf{ct}\_i \in
+/// \mathsf{LWE}^n\_{\vec{s}}\left( \left\lfloor\mathsf{pt}\cdot \frac{q}{\beta^{i+1}} \right\rceil
+/// \right)\subseteq \mathbb{Z}\_q^{(n+1)}$ (we are using the encoding in the MSB with $\Delta =
+/// \frac{q}{\beta^{i+1}}$).
+///
+/// This type of ciphertext contains redundancy ($\ell$
+/// [`LWE Ciphertext`](`crate::specification::entities::LweCiphertextEntity`),
+/// each encrypting the same plaintext times a different scaling factor).
+///
+/// ## Decomposition base
+/// A Lev ciphertext is parametrized with a decomposition base $\beta$, generally chosen as a power
+/// of 2.
+///
+/// ## Levels
+/// A Lev ciphertext contains a number of levels $\ell$ from level $0$ to level $\ell-1$.
+///
+/// ## Secret Key
+/// A Lev ciphertext is encrypted under an
+/// [`LWE secret key`](`crate::specification::entities::LweSecretKeyEntity`).
+///
+/// ## Lev Encryption
+/// ###### inputs:
+/// - $\mathsf{pt}\in\mathbb{Z}\_q$: a plaintext
+/// - $\vec{s}\in\mathbb{Z}\_q^n$: an
+///   [`LWE secret key`](`crate::specification::entities::LweSecretKeyEntity`)
+/// - $\mathcal{D\_{\sigma^2,\mu}}$: a normal distribution of variance $\sigma^2$ and a mean of
+///   $\mu$
+/// - $\ell$: number of levels desired
+/// - $\beta$: decomposition base
+///
+/// ###### outputs:
+/// - $\overline{\mathsf{ct}} = \left( \mathsf{ct}\_0 , \cdots , \mathsf{ct}\_{\ell-1} \right) \in
+///   \mathsf{Lev}\_{\vec{s}}^{\beta, \ell}\left(\mathsf{pt}\right) \subseteq
+///   \mathbb{Z}\_q^{(n+1)\cdot\ell}$: a Lev ciphertext
+///
+/// ###### algorithm:
+/// 1. for $0\le i < \ell$:
+///     - compute $\mathsf{pt}\_i = \left\lfloor\mathsf{pt}\cdot \frac{q}{\beta^{i+1}} \right\rceil
+///       \in \mathbb{Z}\_q$
+///     - compute $\mathsf{ct}\_i \leftarrow \mathsf{LWE}.\mathsf{encrypt}\left( \mathsf{pt}\_i,
+///       \vec{s} ,\mathcal{D\_{\sigma^2,\mu}} \right)$
+/// 2. output $\overline{\mathsf{ct}} = \left( \mathsf{ct}\_0 , \cdots , \mathsf{ct}\_{\ell-1}
+///    \right)$
+///
+/// ## Lev Decryption
+/// Simply use the
+/// [`LWE decryption algorithm`](`crate::specification::engines::LweCiphertextDecryptionEngine`)
+/// on one of the LWE ciphertexts contained in the Lev ciphertext.
+pub trait LevCiphertextEntity: AbstractEntity<Kind = LevCiphertextKind> {
+    /// Returns the LWE dimension of the ciphertext.
+    fn lwe_dimension(&self) -> LweDimension;
+
+    /// Returns the number of decomposition levels of the ciphertext.
+    fn decomposition_level_count(&self) -> DecompositionLevelCount;
+
+    /// Returns the logarithm of the base used in the ciphertext.
+    fn decomposition_base_log(&self) -> DecompositionBaseLog;
+}
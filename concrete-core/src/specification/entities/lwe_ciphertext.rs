@@ -1,6 +1,6 @@
 use crate::specification::entities::markers::LweCiphertextKind;
 use crate::specification::entities::AbstractEntity;
-use concrete_commons::parameters::LweDimension;
+use concrete_commons::parameters::{CiphertextModulus, LweDimension};
 
 /// A trait implemented by types embodying an LWE ciphertext.
 ///
@@ -26,4 +26,13 @@ use concrete_commons::parameters::LweDimension;
 pub trait LweCiphertextEntity: AbstractEntity<Kind = LweCiphertextKind> {
     /// Returns the LWE dimension of the ciphertext.
     fn lwe_dimension(&self) -> LweDimension;
+
+    /// Returns the modulus of the ciphertext.
+    ///
+    /// Engines use it to reject operations mixing entities with different moduli. The default
+    /// implementation returns the native modulus, and must be overridden by the entities using a
+    /// non-native one.
+    fn ciphertext_modulus(&self) -> CiphertextModulus {
+        CiphertextModulus::Native
+    }
 }
@@ -1,6 +1,6 @@
 use crate::specification::entities::markers::LweCiphertextVectorKind;
 use crate::specification::entities::AbstractEntity;
-use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+use concrete_commons::parameters::{CiphertextModulus, LweCiphertextCount, LweDimension};
 
 /// A trait implemented by types embodying an LWE ciphertext vector.
 ///
@@ -13,4 +13,11 @@ pub trait LweCiphertextVectorEntity: AbstractEntity<Kind = LweCiphertextVectorKi
 
     /// Returns the number of ciphertexts contained in the vector.
     fn lwe_ciphertext_count(&self) -> LweCiphertextCount;
+
+    /// Returns the modulus of the ciphertexts.
+    ///
+    /// cf [`here`](`crate::specification::entities::LweCiphertextEntity::ciphertext_modulus`)
+    fn ciphertext_modulus(&self) -> CiphertextModulus {
+        CiphertextModulus::Native
+    }
 }
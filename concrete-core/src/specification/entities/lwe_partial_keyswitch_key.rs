@@ -0,0 +1,44 @@
+use crate::specification::entities::markers::LwePartialKeyswitchKeyKind;
+use crate::specification::entities::AbstractEntity;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+
+/// A trait implemented by types embodying an LWE partial keyswitch key.
+///
+/// # Formal Definition
+///
+/// ## Partial Key Switching Key
+///
+/// A partial key switching key only switches the first $d$ coefficients of the input
+/// [`LWE secret key`](`crate::specification::entities::LweSecretKeyEntity`)
+/// $\vec{s}\_{\mathsf{in}} = \left( s\_0 , \cdots , s\_{n\_{\mathsf{in}}-1} \right)$ to a
+/// replacement [`LWE secret key`](`crate::specification::entities::LweSecretKeyEntity`)
+/// $\vec{s}\_{\mathsf{rep}}$ of dimension $n\_{\mathsf{rep}}$:
+///
+/// $$\mathsf{PKSK}\_{\vec{s}\_{\mathsf{in}}\rightarrow \vec{s}\_{\mathsf{rep}}} = \left(
+/// \overline{\mathsf{ct}\_0}, \cdots , \overline{\mathsf{ct}\_{d-1}}\right)
+/// \subseteq \mathbb{Z}\_q^{(n\_{\mathsf{rep}}+1)\cdot \ell \cdot d}$$
+///
+/// where for all $0\le i <d$ we have $\overline{\mathsf{ct}\_i} \in
+/// \mathsf{Lev}\_{\vec{s}\_{\mathsf{rep}}}^{\beta, \ell}\left(s\_i\right)$. The remaining
+/// coefficients of the input key are kept as is, so that the output key is
+/// $\vec{s}\_{\mathsf{out}} = \left( \vec{s}\_{\mathsf{rep}}, s\_d, \cdots,
+/// s\_{n\_{\mathsf{in}}-1} \right)$, of dimension $n\_{\mathsf{rep}} + n\_{\mathsf{in}} - d$.
+pub trait LwePartialKeyswitchKeyEntity: AbstractEntity<Kind = LwePartialKeyswitchKeyKind> {
+    /// Returns the input LWE dimension of the key.
+    fn input_lwe_dimension(&self) -> LweDimension;
+
+    /// Returns the number of input key coefficients which are switched.
+    fn switched_lwe_dimension(&self) -> LweDimension;
+
+    /// Returns the LWE dimension of the key replacing the switched coefficients.
+    fn replacement_lwe_dimension(&self) -> LweDimension;
+
+    /// Returns the output LWE dimension of the key.
+    fn output_lwe_dimension(&self) -> LweDimension;
+
+    /// Returns the number of decomposition levels of the key.
+    fn decomposition_level_count(&self) -> DecompositionLevelCount;
+
+    /// Returns the logarithm of the base used in the key.
+    fn decomposition_base_log(&self) -> DecompositionBaseLog;
+}
@@ -18,4 +18,9 @@ pub trait LweSeededCiphertextEntity: AbstractEntity<Kind = LweSeededCiphertextKi
     /// Returns the compression seed used to generate the mask of the LWE ciphertext during
     /// encryption.
     fn compression_seed(&self) -> CompressionSeed;
+
+    /// Returns the index of the first byte used to generate the mask, in the stream of the CSPRNG
+    /// seeded with the compression seed. Seeded ciphertexts sharing a seed use disjoint parts of
+    /// the stream.
+    fn generator_byte_index(&self) -> usize;
 }
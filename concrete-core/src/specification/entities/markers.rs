@@ -1,4 +1,5 @@
 //! A module containing various marker traits used for entities.
+use concrete_commons::parameters::RingStructure;
 use std::fmt::Debug;
 
 /// A trait implemented by marker types encoding the __kind__ of an FHE entity in
@@ -34,8 +35,13 @@ entity_kind_marker! {
             => "An empty type representing the cleartext kind in the type system.",
         CleartextVectorKind
             => "An empty type representing the cleartext vector kind in the type system.",
+        SparseCleartextPolynomialKind
+            => "An empty type representing the sparse cleartext polynomial kind in the type \
+            system.",
         LweCiphertextKind
             => "An empty type representing the LWE ciphertext kind in the type system.",
+        TrivialLweCiphertextKind
+            => "An empty type representing the trivial LWE ciphertext kind in the type system.",
         LweCiphertextVectorKind
             => "An empty type representing the LWE ciphertext vector kind in the type system.",
         LweSeededCiphertextKind
@@ -44,12 +50,19 @@ entity_kind_marker! {
             => "An empty type representing the seeded LWE ciphertext vector kind in the type system.",
         GlweCiphertextKind
             => "An empty type representing the GLWE ciphertext kind in the type system.",
+        TrivialGlweCiphertextKind
+            => "An empty type representing the trivial GLWE ciphertext kind in the type system.",
         GlweCiphertextVectorKind
             => "An empty type representing the GLWE ciphertext vector kind in the type system.",
         GlweSeededCiphertextKind
             => "An empty type representing the seeded GLWE ciphertext kind in the type system.",
         GlweSeededCiphertextVectorKind
             => "An empty type representing the seeded GLWE ciphertext vector kind in the type system.",
+        GlweTensorProductCiphertextKind
+            => "An empty type representing the GLWE tensor product ciphertext kind in the type \
+            system.",
+        GlevCiphertextKind
+            => "An empty type representing the GLev ciphertext kind in the type system.",
         GgswCiphertextKind
             => "An empty type representing the GGSW ciphertext kind in the type system.",
         GgswCiphertextVectorKind
@@ -60,18 +73,36 @@ entity_kind_marker! {
             => "An empty type representing the GSW ciphertext kind in the type system.",
         GswCiphertextVectorKind
             => "An empty type representing the GSW ciphertext vector kind in the type system.",
+        LevCiphertextKind
+            => "An empty type representing the Lev ciphertext kind in the type system.",
         LwePublicKeyKind
             => "An empty type representing the LWE public key kind in the type system.",
         LweSecretKeyKind
             => "An empty type representing the LWE secret key kind in the type system.",
         GlweSecretKeyKind
             => "An empty type representing the GLWE secret key kind in the type system.",
+        GlweTensorProductSecretKeyKind
+            => "An empty type representing the GLWE tensor product secret key kind in the type \
+            system.",
+        GlweRingReductionKeyKind
+            => "An empty type representing the GLWE ring reduction key kind in the type system.",
+        GlweRingSwitchingKeyKind
+            => "An empty type representing the GLWE ring switching key kind in the type system.",
+        GlweRelinearizationKeyKind
+            => "An empty type representing the GLWE relinearization key kind in the type system.",
+        GlweAutomorphismKeysKind
+            => "An empty type representing the GLWE automorphism keys kind in the type system.",
         LweKeyswitchKeyKind
             => "An empty type representing the LWE keyswitch key kind in the type system.",
         LweSeededKeyswitchKeyKind
             => "An empty type representing the seeded LWE keyswitch key kind in the type system.",
+        LwePartialKeyswitchKeyKind
+            => "An empty type representing the LWE partial keyswitch key kind in the type system.",
         LwePackingKeyswitchKeyKind
             => "An empty type representing the packing keyswitch key kind in the type system.",
+        LweSeededPackingKeyswitchKeyKind
+            => "An empty type representing the seeded packing keyswitch key kind in the type \
+            system.",
         LwePrivateFunctionalPackingKeyswitchKeyKind
             => "An empty type representing the private functional packing keyswitch key in the \
             type system.",
@@ -88,6 +119,41 @@ entity_kind_marker! {
             => "An empty type representing the encoder vector kind in the type system"
 }
 
+/// A trait implemented by marker types encoding the structure of the polynomial ring an entity
+/// is defined over in the type system.
+///
+/// All the entities of the library are defined over the negacyclic ring
+/// $\mathbb{Z}\[X\] / (X^N + 1)$. The cyclic marker allows experimental schemes over
+/// $\mathbb{Z}\[X\] / (X^N - 1)$ to tell their entities apart in the type system, while reusing
+/// the polynomial arithmetic of the library through [`RingStructureMarker::RING_STRUCTURE`].
+///
+/// # Note
+///
+/// [`RingStructureMarker`] types are only defined in the specification part of the library, and
+/// can not be defined by a backend.
+pub trait RingStructureMarker: seal::RingStructureMarkerSealed + Debug + Clone + Copy {
+    /// The structure of the ring, as used by the polynomial arithmetic.
+    const RING_STRUCTURE: RingStructure;
+}
+
+/// An empty type representing the negacyclic ring $\mathbb{Z}\[X\] / (X^N + 1)$ in the type
+/// system.
+#[derive(Debug, Clone, Copy)]
+pub struct NegacyclicRing {}
+impl seal::RingStructureMarkerSealed for NegacyclicRing {}
+impl RingStructureMarker for NegacyclicRing {
+    const RING_STRUCTURE: RingStructure = RingStructure::Negacyclic;
+}
+
+/// An empty type representing the cyclic ring $\mathbb{Z}\[X\] / (X^N - 1)$ in the type system.
+#[derive(Debug, Clone, Copy)]
+pub struct CyclicRing {}
+impl seal::RingStructureMarkerSealed for CyclicRing {}
+impl RingStructureMarker for CyclicRing {
+    const RING_STRUCTURE: RingStructure = RingStructure::Cyclic;
+}
+
 pub(crate) mod seal {
     pub trait EntityKindMarkerSealed {}
+    pub trait RingStructureMarkerSealed {}
 }
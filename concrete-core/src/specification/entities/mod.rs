@@ -4,6 +4,12 @@
 //!
 //! + The [`AbstractEntity`] super-trait.
 //! + One of the `*Entity` traits.
+//!
+//! # Entities destruction
+//!
+//! Entities own the resources they hold (host or device memory), and release them when they are
+//! dropped. There is hence no need to explicitly destroy an entity, but the resources can always
+//! be released early by calling [`drop`] on it.
 
 pub mod markers;
 
@@ -41,19 +47,28 @@ mod encoder_vector;
 mod ggsw_ciphertext;
 mod ggsw_ciphertext_vector;
 mod ggsw_seeded_ciphertext;
+mod glev_ciphertext;
+mod glwe_automorphism_keys;
 mod glwe_ciphertext;
 mod glwe_ciphertext_vector;
+mod glwe_relinearization_key;
+mod glwe_ring_reduction_key;
+mod glwe_ring_switching_key;
 mod glwe_secret_key;
 mod glwe_seeded_ciphertext;
 mod glwe_seeded_ciphertext_vector;
+mod glwe_tensor_product_ciphertext;
+mod glwe_tensor_product_secret_key;
 mod gsw_ciphertext;
 mod gsw_ciphertext_vector;
+mod lev_ciphertext;
 mod lwe_bootstrap_key;
 mod lwe_ciphertext;
 mod lwe_ciphertext_vector;
 mod lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys;
 mod lwe_keyswitch_key;
 mod lwe_packing_keyswitch_key;
+mod lwe_partial_keyswitch_key;
 mod lwe_private_functional_packing_keyswitch_key;
 mod lwe_public_key;
 mod lwe_secret_key;
@@ -61,8 +76,12 @@ mod lwe_seeded_bootstrap_key;
 mod lwe_seeded_ciphertext;
 mod lwe_seeded_ciphertext_vector;
 mod lwe_seeded_keyswitch_key;
+mod lwe_seeded_packing_keyswitch_key;
 mod plaintext;
 mod plaintext_vector;
+mod sparse_cleartext_polynomial;
+mod trivial_glwe_ciphertext;
+mod trivial_lwe_ciphertext;
 
 pub use cleartext::*;
 pub use cleartext_vector::*;
@@ -71,19 +90,28 @@ pub use encoder_vector::*;
 pub use ggsw_ciphertext::*;
 pub use ggsw_ciphertext_vector::*;
 pub use ggsw_seeded_ciphertext::*;
+pub use glev_ciphertext::*;
+pub use glwe_automorphism_keys::*;
 pub use glwe_ciphertext::*;
 pub use glwe_ciphertext_vector::*;
+pub use glwe_relinearization_key::*;
+pub use glwe_ring_reduction_key::*;
+pub use glwe_ring_switching_key::*;
 pub use glwe_secret_key::*;
 pub use glwe_seeded_ciphertext::*;
 pub use glwe_seeded_ciphertext_vector::*;
+pub use glwe_tensor_product_ciphertext::*;
+pub use glwe_tensor_product_secret_key::*;
 pub use gsw_ciphertext::*;
 pub use gsw_ciphertext_vector::*;
+pub use lev_ciphertext::*;
 pub use lwe_bootstrap_key::*;
 pub use lwe_ciphertext::*;
 pub use lwe_ciphertext_vector::*;
 pub use lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys::*;
 pub use lwe_keyswitch_key::*;
 pub use lwe_packing_keyswitch_key::*;
+pub use lwe_partial_keyswitch_key::*;
 pub use lwe_private_functional_packing_keyswitch_key::*;
 pub use lwe_public_key::*;
 pub use lwe_secret_key::*;
@@ -91,5 +119,9 @@ pub use lwe_seeded_bootstrap_key::*;
 pub use lwe_seeded_ciphertext::*;
 pub use lwe_seeded_ciphertext_vector::*;
 pub use lwe_seeded_keyswitch_key::*;
+pub use lwe_seeded_packing_keyswitch_key::*;
 pub use plaintext::*;
 pub use plaintext_vector::*;
+pub use sparse_cleartext_polynomial::*;
+pub use trivial_glwe_ciphertext::*;
+pub use trivial_lwe_ciphertext::*;
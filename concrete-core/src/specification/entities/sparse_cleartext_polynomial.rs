@@ -0,0 +1,21 @@
+use crate::specification::entities::markers::SparseCleartextPolynomialKind;
+use crate::specification::entities::AbstractEntity;
+use concrete_commons::parameters::{MonomialCount, PolynomialSize};
+
+/// A trait implemented by types embodying a sparse cleartext polynomial entity.
+///
+/// # Formal Definition
+///
+/// A sparse cleartext polynomial is a polynomial $P = \sum\_{i} p\_i X^{d\_i}$ of
+/// $\mathcal{R}\_q = \mathbb{Z}\_q\[X\] / (X^N + 1)$, with only a few nonzero coefficients. It is
+/// only represented by its nonzero monomials, which makes the product of a GLWE ciphertext with it
+/// a sum of a few negacyclic rotations of the ciphertext.
+pub trait SparseCleartextPolynomialEntity:
+    AbstractEntity<Kind = SparseCleartextPolynomialKind>
+{
+    /// Returns the size of the polynomials the sparse polynomial operates on.
+    fn polynomial_size(&self) -> PolynomialSize;
+
+    /// Returns the number of monomials of the sparse polynomial.
+    fn monomial_count(&self) -> MonomialCount;
+}
@@ -0,0 +1,25 @@
+use crate::specification::entities::markers::TrivialGlweCiphertextKind;
+use crate::specification::entities::AbstractEntity;
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+
+/// A trait implemented by types embodying a trivial GLWE ciphertext.
+///
+/// # Formal Definition
+///
+/// A trivial GLWE ciphertext is a
+/// [`GLWE ciphertext`](`crate::specification::entities::GlweCiphertextEntity`) whose mask
+/// polynomials are zero and which contains no noise: $$\mathsf{CT} = \left( 0, \cdots, 0 ,
+/// \mathsf{PT}\right) \in \mathcal{R}\_q^{(k+1)}$$
+///
+/// It hides nothing, but can be decrypted under any GLWE secret key of dimension $k$. Having a
+/// distinct entity allows operations to require (or forbid) trivial inputs, such as the
+/// accumulator of a bootstrap, at the type level. A trivial GLWE ciphertext can be turned into a
+/// regular GLWE ciphertext with a
+/// [`TrivialGlweCiphertextToGlweCiphertextTransformationEngine`](`crate::specification::engines::TrivialGlweCiphertextToGlweCiphertextTransformationEngine`).
+pub trait TrivialGlweCiphertextEntity: AbstractEntity<Kind = TrivialGlweCiphertextKind> {
+    /// Returns the GLWE dimension of the ciphertext.
+    fn glwe_dimension(&self) -> GlweDimension;
+
+    /// Returns the polynomial size of the ciphertext.
+    fn polynomial_size(&self) -> PolynomialSize;
+}
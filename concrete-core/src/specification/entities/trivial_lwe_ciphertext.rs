@@ -0,0 +1,21 @@
+use crate::specification::entities::markers::TrivialLweCiphertextKind;
+use crate::specification::entities::AbstractEntity;
+use concrete_commons::parameters::LweDimension;
+
+/// A trait implemented by types embodying a trivial LWE ciphertext.
+///
+/// # Formal Definition
+///
+/// A trivial LWE ciphertext is an
+/// [`LWE ciphertext`](`crate::specification::entities::LweCiphertextEntity`) whose mask is zero
+/// and which contains no noise: $$\mathsf{ct} = \left( 0, \cdots, 0 , \mathsf{pt}\right) \in
+/// \mathbb{Z}\_q^{(n+1)}$$
+///
+/// It hides nothing, but can be decrypted under any LWE secret key of dimension $n$. Having a
+/// distinct entity allows operations to require (or forbid) trivial inputs at the type level.
+/// A trivial LWE ciphertext can be turned into a regular LWE ciphertext with a
+/// [`TrivialLweCiphertextToLweCiphertextTransformationEngine`](`crate::specification::engines::TrivialLweCiphertextToLweCiphertextTransformationEngine`).
+pub trait TrivialLweCiphertextEntity: AbstractEntity<Kind = TrivialLweCiphertextKind> {
+    /// Returns the LWE dimension of the ciphertext.
+    fn lwe_dimension(&self) -> LweDimension;
+}
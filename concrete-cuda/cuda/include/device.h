@@ -7,6 +7,8 @@ int cuda_destroy_stream(void *v_stream, uint32_t gpu_index);
 
 void *cuda_malloc(uint64_t size, uint32_t gpu_index);
 
+void *cuda_malloc_host(uint64_t size);
+
 int cuda_check_valid_malloc(uint64_t size, uint32_t gpu_index);
 
 int cuda_memcpy_to_cpu(void *dest, const void *src, uint64_t size,
@@ -24,7 +26,19 @@ int cuda_get_number_of_gpus();
 
 int cuda_synchronize_device(uint32_t gpu_index);
 
+int cuda_synchronize_stream(void *v_stream, uint32_t gpu_index);
+
+int cuda_query_stream(void *v_stream, uint32_t gpu_index);
+
 int cuda_drop(void *ptr, uint32_t gpu_index);
 
+int cuda_drop_host(void *ptr);
+
 int cuda_get_max_shared_memory(uint32_t gpu_index);
+
+int cuda_get_number_of_sms(uint32_t gpu_index);
+
+int cuda_get_compute_capability(uint32_t gpu_index, int *major, int *minor);
+
+int cuda_get_global_memory(uint32_t gpu_index, uint64_t *global_memory);
 }
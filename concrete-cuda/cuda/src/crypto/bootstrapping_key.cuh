@@ -81,10 +81,9 @@ void cuda_convert_lwe_bootstrap_key(double2 *dest, ST *src, void *v_stream,
 
   int gridSize = total_polynomials;
   int blockSize = polynomial_size / choose_opt(polynomial_size);
-  // todo(Joao): let's use cudaMallocHost here,
-  // since it allocates page-staged memory which allows
-  // faster data copy
-  double2 *h_bsk = (double2 *)malloc(buffer_size);
+  // Page-locked memory allows faster data copy
+  double2 *h_bsk;
+  cudaMallocHost((void **)&h_bsk, buffer_size);
   double2 *d_bsk;
   cudaMalloc((void **)&d_bsk, buffer_size);
 
@@ -134,7 +133,7 @@ void cuda_convert_lwe_bootstrap_key(double2 *dest, ST *src, void *v_stream,
   }
 
   cudaFree(d_bsk);
-  free(h_bsk);
+  cudaFreeHost(h_bsk);
 
 }
 
@@ -1,5 +1,5 @@
 #ifndef CNCRT_CRYPTO_H
-#define CNCRT_CRPYTO_H
+#define CNCRT_CRYPTO_H
 
 #include "polynomial/polynomial.cuh"
 #include <cstdint>
@@ -88,4 +88,4 @@ public:
   }
 };
 
-#endif // CNCRT_CRPYTO_H
+#endif // CNCRT_CRYPTO_H
@@ -31,6 +31,15 @@ void *cuda_malloc(uint64_t size, uint32_t gpu_index) {
   return ptr;
 }
 
+/// Allocates page-locked memory on the host, which can be copied to and from
+/// any GPU faster than pageable memory
+void *cuda_malloc_host(uint64_t size) {
+  void *ptr;
+  checkCudaErrors(cudaMallocHost((void **)&ptr, size));
+
+  return ptr;
+}
+
 /// Checks that allocation is valid
 /// 0: valid
 /// -1: invalid, not enough memory in device
@@ -93,6 +102,43 @@ int cuda_synchronize_device(uint32_t gpu_index) {
   return 0;
 }
 
+/// Waits for the completion of the work enqueued on a stream
+/// 0: success
+/// -2: error, gpu index doesn't exist
+int cuda_synchronize_stream(void *v_stream, uint32_t gpu_index) {
+  if (gpu_index >= cuda_get_number_of_gpus()) {
+    // error code: invalid gpu_index
+    return -2;
+  }
+  auto stream = static_cast<cudaStream_t *>(v_stream);
+  cudaSetDevice(gpu_index);
+  checkCudaErrors(cudaStreamSynchronize(*stream));
+  return 0;
+}
+
+/// Checks whether all the work enqueued on a stream is complete, without
+/// blocking
+/// 1: complete
+/// 0: not complete
+/// -1: error, the query or the work enqueued on the stream failed
+/// -2: error, gpu index doesn't exist
+int cuda_query_stream(void *v_stream, uint32_t gpu_index) {
+  if (gpu_index >= cuda_get_number_of_gpus()) {
+    // error code: invalid gpu_index
+    return -2;
+  }
+  auto stream = static_cast<cudaStream_t *>(v_stream);
+  cudaSetDevice(gpu_index);
+  cudaError_t status = cudaStreamQuery(*stream);
+  if (status == cudaSuccess) {
+    return 1;
+  }
+  if (status == cudaErrorNotReady) {
+    return 0;
+  }
+  return -1;
+}
+
 /// Tries to copy memory to the GPU asynchronously
 /// 0: success
 /// -1: error, invalid device pointer
@@ -140,6 +186,12 @@ int cuda_drop(void *ptr, uint32_t gpu_index) {
   return 0;
 }
 
+/// Frees page-locked memory allocated with cuda_malloc_host
+int cuda_drop_host(void *ptr) {
+  checkCudaErrors(cudaFreeHost(ptr));
+  return 0;
+}
+
 /// Get the maximum size for the shared memory
 int cuda_get_max_shared_memory(uint32_t gpu_index) {
   if (gpu_index >= cuda_get_number_of_gpus()) {
@@ -157,3 +209,39 @@ int cuda_get_max_shared_memory(uint32_t gpu_index) {
   }
   return max_shared_memory;
 }
+
+/// Get the number of streaming multiprocessors
+int cuda_get_number_of_sms(uint32_t gpu_index) {
+  if (gpu_index >= cuda_get_number_of_gpus()) {
+    // error code: invalid gpu_index
+    return -2;
+  }
+  cudaDeviceProp prop;
+  cudaGetDeviceProperties(&prop, gpu_index);
+  return prop.multiProcessorCount;
+}
+
+/// Get the compute capability of the device, written in major and minor
+int cuda_get_compute_capability(uint32_t gpu_index, int *major, int *minor) {
+  if (gpu_index >= cuda_get_number_of_gpus()) {
+    // error code: invalid gpu_index
+    return -2;
+  }
+  cudaDeviceProp prop;
+  cudaGetDeviceProperties(&prop, gpu_index);
+  *major = prop.major;
+  *minor = prop.minor;
+  return 0;
+}
+
+/// Get the amount of global memory of the device, in bytes
+int cuda_get_global_memory(uint32_t gpu_index, uint64_t *global_memory) {
+  if (gpu_index >= cuda_get_number_of_gpus()) {
+    // error code: invalid gpu_index
+    return -2;
+  }
+  cudaDeviceProp prop;
+  cudaGetDeviceProperties(&prop, gpu_index);
+  *global_memory = prop.totalGlobalMem;
+  return 0;
+}
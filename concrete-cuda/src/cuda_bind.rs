@@ -12,6 +12,8 @@ extern "C" {
 
     pub fn cuda_malloc(size: u64, gpu_index: u32) -> *mut c_void;
 
+    pub fn cuda_malloc_host(size: u64) -> *mut c_void;
+
     pub fn cuda_check_valid_malloc(size: u64, gpu_index: u32) -> i32;
 
     pub fn cuda_memcpy_async_to_cpu(
@@ -34,10 +36,22 @@ extern "C" {
 
     pub fn cuda_synchronize_device(gpu_index: u32) -> i32;
 
+    pub fn cuda_synchronize_stream(v_stream: *mut c_void, gpu_index: u32) -> i32;
+
+    pub fn cuda_query_stream(v_stream: *mut c_void, gpu_index: u32) -> i32;
+
     pub fn cuda_drop(ptr: *mut c_void, gpu_index: u32) -> i32;
 
+    pub fn cuda_drop_host(ptr: *mut c_void) -> i32;
+
     pub fn cuda_get_max_shared_memory(gpu_index: u32) -> i32;
 
+    pub fn cuda_get_number_of_sms(gpu_index: u32) -> i32;
+
+    pub fn cuda_get_compute_capability(gpu_index: u32, major: *mut i32, minor: *mut i32) -> i32;
+
+    pub fn cuda_get_global_memory(gpu_index: u32, global_memory: *mut u64) -> i32;
+
     pub fn cuda_initialize_twiddles(polynomial_size: u32, gpu_index: u32);
 
     pub fn cuda_convert_lwe_bootstrap_key_32(
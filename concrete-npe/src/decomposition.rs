@@ -0,0 +1,345 @@
+use super::*;
+use concrete_commons::dispersion::{DispersionParameter, Variance};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+use std::time::{Duration, Instant};
+
+/// Lists the decomposition parameters of a relinearization key for which the dispersion of the
+/// relinearized GLWE stays below `max_dispersion`.
+///
+/// Every pair with $\beta \cdot \ell < \log\_2(q)$ is considered, as the decomposition must
+/// leave at least one bit of the integers out. The valid pairs are returned by increasing level
+/// count, and then by increasing base log, so that the first ones are usually the cheapest to use.
+///
+/// # Example
+/// ```rust
+/// use concrete_commons::dispersion::{DispersionParameter, Variance};
+/// use concrete_commons::key_kinds::BinaryKeyKind;
+/// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+/// use concrete_npe::{estimate_relinearization_noise, find_relinearization_decompositions};
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_dimension = GlweDimension(1);
+/// let dispersion_rlk = Variance(2_f64.powi(-50));
+/// let max_dispersion = Variance(2_f64.powi(-20));
+/// let candidates = find_relinearization_decompositions::<_, _, BinaryKeyKind>(
+///     polynomial_size,
+///     glwe_dimension,
+///     dispersion_rlk,
+///     max_dispersion,
+///     64,
+/// );
+/// for (base_log, level) in candidates {
+///     let dispersion = estimate_relinearization_noise::<_, BinaryKeyKind>(
+///         polynomial_size,
+///         glwe_dimension,
+///         dispersion_rlk,
+///         base_log,
+///         level,
+///         64,
+///     );
+///     assert!(dispersion.get_variance() <= max_dispersion.get_variance());
+/// }
+/// ```
+pub fn find_relinearization_decompositions<D1, D2, K>(
+    poly_size: PolynomialSize,
+    glwe_dimension: GlweDimension,
+    dispersion_rlk: D1,
+    max_dispersion: D2,
+    log2_modulus: u32,
+) -> Vec<(DecompositionBaseLog, DecompositionLevelCount)>
+where
+    D1: DispersionParameter,
+    D2: DispersionParameter,
+    K: KeyDispersion,
+{
+    find_decompositions(max_dispersion, log2_modulus, |base_log, level| {
+        estimate_relinearization_noise::<_, K>(
+            poly_size,
+            glwe_dimension,
+            dispersion_rlk,
+            base_log,
+            level,
+            log2_modulus,
+        )
+    })
+}
+
+/// Lists the decomposition parameters of an LWE keyswitch key for which the dispersion of the
+/// keyswitched LWE stays below `max_dispersion`.
+///
+/// The pairs are enumerated and ordered as in [`find_relinearization_decompositions`].
+///
+/// # Example
+/// ```rust
+/// use concrete_commons::dispersion::Variance;
+/// use concrete_commons::key_kinds::BinaryKeyKind;
+/// use concrete_commons::parameters::{DecompositionLevelCount, LweDimension};
+/// use concrete_npe::find_keyswitch_decompositions;
+/// let candidates = find_keyswitch_decompositions::<_, _, _, BinaryKeyKind>(
+///     LweDimension(630),
+///     Variance(2_f64.powi(-38)),
+///     Variance(2_f64.powi(-60)),
+///     Variance(2_f64.powi(-25)),
+///     64,
+/// );
+/// assert!(!candidates.is_empty());
+/// assert!(candidates
+///     .windows(2)
+///     .all(|pair| pair[0].1 .0 <= pair[1].1 .0));
+/// ```
+pub fn find_keyswitch_decompositions<D1, D2, D3, K>(
+    lwe_mask_size: LweDimension,
+    dispersion_lwe: D1,
+    dispersion_ksk: D2,
+    max_dispersion: D3,
+    log2_modulus: u32,
+) -> Vec<(DecompositionBaseLog, DecompositionLevelCount)>
+where
+    D1: DispersionParameter,
+    D2: DispersionParameter,
+    D3: DispersionParameter,
+    K: KeyDispersion,
+{
+    find_decompositions(max_dispersion, log2_modulus, |base_log, level| {
+        estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms::<_, _, K>(
+            lwe_mask_size,
+            dispersion_lwe,
+            dispersion_ksk,
+            base_log,
+            level,
+            log2_modulus,
+        )
+    })
+}
+
+/// Returns the decomposition parameters for which `benchmark` reports the smallest duration.
+///
+/// The `benchmark` closure is expected to time the operation of interest (for instance a
+/// keyswitch or a relinearization) with a key using the given decomposition parameters. This
+/// makes it possible to pick, among the candidates returned by [`find_keyswitch_decompositions`]
+/// or [`find_relinearization_decompositions`], the fastest one on the current machine.
+///
+/// Returns `None` if there are no candidates.
+///
+/// # Example
+/// ```rust
+/// use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+/// use concrete_npe::select_fastest_decomposition;
+/// use std::time::{Duration, Instant};
+/// let candidates = vec![
+///     (DecompositionBaseLog(4), DecompositionLevelCount(3)),
+///     (DecompositionBaseLog(2), DecompositionLevelCount(6)),
+/// ];
+/// let fastest = select_fastest_decomposition(&candidates, |_base_log, level| {
+///     let start = Instant::now();
+///     // Replace with the operation to tune, here we only simulate a cost growing with the level.
+///     std::thread::sleep(Duration::from_millis(level.0 as u64));
+///     start.elapsed()
+/// });
+/// // The timing depends on the load of the machine, so only the selection itself is checked.
+/// assert!(candidates.contains(&fastest.unwrap()));
+/// ```
+pub fn select_fastest_decomposition<F>(
+    candidates: &[(DecompositionBaseLog, DecompositionLevelCount)],
+    mut benchmark: F,
+) -> Option<(DecompositionBaseLog, DecompositionLevelCount)>
+where
+    F: FnMut(DecompositionBaseLog, DecompositionLevelCount) -> Duration,
+{
+    candidates
+        .iter()
+        .map(|&(base_log, level)| (benchmark(base_log, level), (base_log, level)))
+        .min_by_key(|(duration, _)| *duration)
+        .map(|(_, parameters)| parameters)
+}
+
+/// Measures the duration of `operation`, as the median of `runs` timed executions.
+///
+/// The operation is executed once before the measures, so that the caches and the lazily
+/// initialized resources (e.g. the fft plans) are warmed up.
+///
+/// # Example
+/// ```rust
+/// use concrete_npe::measure_duration;
+/// use std::time::Duration;
+/// let duration = measure_duration(5, || std::thread::sleep(Duration::from_millis(1)));
+/// assert!(duration >= Duration::from_millis(1));
+/// ```
+pub fn measure_duration<F>(runs: usize, mut operation: F) -> Duration
+where
+    F: FnMut(),
+{
+    assert!(
+        runs > 0,
+        "At least one run is needed to measure a duration."
+    );
+    operation();
+    let mut durations: Vec<Duration> = (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            operation();
+            start.elapsed()
+        })
+        .collect();
+    durations.sort_unstable();
+    durations[runs / 2]
+}
+
+/// Returns the candidate decomposition parameters for which `operation` is the fastest on the
+/// current machine.
+///
+/// For every candidate, `setup` is called once to build the inputs of the operation (for
+/// instance a key using the given decomposition parameters, and a ciphertext), which is not
+/// timed. The duration of `operation` on these inputs is then measured with
+/// [`measure_duration`] over `runs` executions, and the candidate with the smallest duration is
+/// selected with [`select_fastest_decomposition`].
+///
+/// Returns `None` if there are no candidates.
+///
+/// # Example
+/// ```rust
+/// use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+/// use concrete_npe::tune_decomposition;
+/// let candidates = vec![
+///     (DecompositionBaseLog(4), DecompositionLevelCount(3)),
+///     (DecompositionBaseLog(2), DecompositionLevelCount(6)),
+/// ];
+/// let fastest = tune_decomposition(
+///     &candidates,
+///     3,
+///     |base_log, level| vec![1_u64; base_log.0 * level.0 * 1000],
+///     |input| {
+///         let sum: u64 = input.iter().sum();
+///         assert_eq!(sum as usize, input.len());
+///     },
+/// );
+/// assert!(candidates.contains(&fastest.unwrap()));
+/// ```
+pub fn tune_decomposition<Input, S, F>(
+    candidates: &[(DecompositionBaseLog, DecompositionLevelCount)],
+    runs: usize,
+    mut setup: S,
+    mut operation: F,
+) -> Option<(DecompositionBaseLog, DecompositionLevelCount)>
+where
+    S: FnMut(DecompositionBaseLog, DecompositionLevelCount) -> Input,
+    F: FnMut(&mut Input),
+{
+    select_fastest_decomposition(candidates, |base_log, level| {
+        let mut input = setup(base_log, level);
+        measure_duration(runs, || operation(&mut input))
+    })
+}
+
+fn find_decompositions<D, F>(
+    max_dispersion: D,
+    log2_modulus: u32,
+    mut estimate: F,
+) -> Vec<(DecompositionBaseLog, DecompositionLevelCount)>
+where
+    D: DispersionParameter,
+    F: FnMut(DecompositionBaseLog, DecompositionLevelCount) -> Variance,
+{
+    let max_variance = max_dispersion.get_variance();
+    let log2_modulus = log2_modulus as usize;
+    let mut candidates = Vec::new();
+    for level in 1..log2_modulus {
+        for base_log in 1..=((log2_modulus - 1) / level) {
+            let base_log = DecompositionBaseLog(base_log);
+            let level = DecompositionLevelCount(level);
+            if estimate(base_log, level).get_variance() <= max_variance {
+                candidates.push((base_log, level));
+            }
+        }
+    }
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use concrete_commons::key_kinds::BinaryKeyKind;
+
+    #[test]
+    fn test_keyswitch_decompositions_are_valid() {
+        let lwe_mask_size = LweDimension(630);
+        let dispersion_lwe = Variance(2_f64.powi(-38));
+        let dispersion_ksk = Variance(2_f64.powi(-60));
+        let max_dispersion = Variance(2_f64.powi(-25));
+        let candidates = find_keyswitch_decompositions::<_, _, _, BinaryKeyKind>(
+            lwe_mask_size,
+            dispersion_lwe,
+            dispersion_ksk,
+            max_dispersion,
+            64,
+        );
+        assert!(!candidates.is_empty());
+        for (base_log, level) in candidates {
+            assert!(base_log.0 * level.0 < 64);
+            let dispersion =
+                estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms::<_, _, BinaryKeyKind>(
+                    lwe_mask_size,
+                    dispersion_lwe,
+                    dispersion_ksk,
+                    base_log,
+                    level,
+                    64,
+                );
+            assert!(dispersion.get_variance() <= max_dispersion.get_variance());
+        }
+    }
+
+    #[test]
+    fn test_unreachable_dispersion_has_no_decomposition() {
+        // The output dispersion can not be lower than the input one.
+        let candidates = find_keyswitch_decompositions::<_, _, _, BinaryKeyKind>(
+            LweDimension(630),
+            Variance(2_f64.powi(-38)),
+            Variance(2_f64.powi(-40)),
+            Variance(2_f64.powi(-40)),
+            64,
+        );
+        assert!(candidates.is_empty());
+        assert_eq!(
+            select_fastest_decomposition(&candidates, |_, _| unreachable!()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_tune_decomposition_measures_every_candidate() {
+        let candidates = vec![
+            (DecompositionBaseLog(2), DecompositionLevelCount(8)),
+            (DecompositionBaseLog(4), DecompositionLevelCount(4)),
+        ];
+        let mut setups = Vec::new();
+        let fastest = tune_decomposition(
+            &candidates,
+            3,
+            |base_log, level| {
+                setups.push((base_log, level));
+                0_usize
+            },
+            |executions| *executions += 1,
+        );
+        assert!(candidates.contains(&fastest.unwrap()));
+        assert_eq!(setups, candidates);
+    }
+
+    #[test]
+    fn test_select_fastest_decomposition() {
+        let candidates = vec![
+            (DecompositionBaseLog(2), DecompositionLevelCount(8)),
+            (DecompositionBaseLog(4), DecompositionLevelCount(4)),
+            (DecompositionBaseLog(8), DecompositionLevelCount(2)),
+        ];
+        let fastest = select_fastest_decomposition(&candidates, |base_log, level| {
+            Duration::from_nanos((base_log.0 * base_log.0 + level.0) as u64)
+        });
+        assert_eq!(
+            fastest,
+            Some((DecompositionBaseLog(2), DecompositionLevelCount(8)))
+        );
+    }
+}
@@ -36,10 +36,12 @@
 
 #![allow(clippy::upper_case_acronyms)]
 
+mod decomposition;
 mod key_dispersion;
 mod operators;
 mod tools;
 
+pub use decomposition::*;
 pub use key_dispersion::*;
 pub use operators::*;
 pub use tools::*;
@@ -461,6 +461,48 @@ where
     )
 }
 
+/// Computes the dispersion of a modulus switching of an LWE encrypted with binary keys, when the
+/// coefficients are rounded stochastically.
+///
+/// Each coefficient is rounded up with a probability equal to its fractional part, so that the
+/// rounding errors have a zero expectation, and a variance of $\frac{q^2}{6w^2} - \frac{1}{6}$
+/// when the fractional parts are uniform. This is twice the variance of the deterministic
+/// rounding errors, but they do not introduce any bias.
+/// # Example
+/// ```rust
+/// use concrete_commons::dispersion::Variance;
+/// use concrete_commons::parameters::LweDimension;
+/// use concrete_npe::estimate_stochastic_modulus_switching_noise_with_binary_key;
+/// let lwe_mask_size = LweDimension(630);
+/// let number_of_most_significant_bit: usize = 4;
+/// let dispersion_input = Variance(2_f64.powi(-40));
+/// let var_out = estimate_stochastic_modulus_switching_noise_with_binary_key::<_>(
+///     lwe_mask_size,
+///     number_of_most_significant_bit,
+///     dispersion_input,
+///     64,
+/// );
+/// ```
+pub fn estimate_stochastic_modulus_switching_noise_with_binary_key<D>(
+    lwe_mask_size: LweDimension,
+    nb_msb: usize,
+    var_in: D,
+    log2_modulus: u32,
+) -> Variance
+where
+    D: DispersionParameter,
+{
+    let w = 2_f64.powi(nb_msb as i32);
+    let n = lwe_mask_size.0 as f64;
+    let q_square = 2_f64.powi((2 * log2_modulus) as i32);
+    // The body error, and the n mask errors, each multiplied by a binary key coefficient.
+    let rounding_variance = 1. / 6. * q_square / square(w) - 1. / 6.;
+    Variance::from_modular_variance(
+        var_in.get_modular_variance(log2_modulus) + rounding_variance + n / 2. * rounding_variance,
+        log2_modulus,
+    )
+}
+
 /// Computes the dispersion of the constant terms of a GLWE after an LWE
 /// to GLWE keyswitch.
 /// # Example
@@ -890,3 +932,34 @@ mod tests_estimate_weighted_sum_noise {
         assert_float_eq!(47.0, variance_out.get_variance(), eps = 0.001);
     }
 }
+
+#[cfg(test)]
+mod tests_estimate_modulus_switching_noise {
+    use super::{
+        estimate_modulus_switching_noise_with_binary_key,
+        estimate_stochastic_modulus_switching_noise_with_binary_key,
+    };
+    use crate::tools::tests::assert_float_eq;
+    use concrete_commons::dispersion::{DispersionParameter, Variance};
+    use concrete_commons::parameters::LweDimension;
+    #[test]
+    fn stochastic_body_rounding_doubles_the_noise() {
+        let deterministic = estimate_modulus_switching_noise_with_binary_key(
+            LweDimension(0),
+            11,
+            Variance(0.0),
+            32,
+        );
+        let stochastic = estimate_stochastic_modulus_switching_noise_with_binary_key(
+            LweDimension(0),
+            11,
+            Variance(0.0),
+            32,
+        );
+        assert_float_eq!(
+            2. * deterministic.get_variance(),
+            stochastic.get_variance(),
+            eps = 1e-12
+        );
+    }
+}